@@ -47,6 +47,18 @@ class Dispatch {
 
     return payloadFuture;
   }
+
+  // Resolves the link, e.g. appflowy://workspace/<id>/view/<id>, into the event of its
+  // route and sends it. The payload is the response of that event.
+  static Future<Either<Uint8List, Uint8List>> openLink(String uri) {
+    final completer = Completer<Uint8List>();
+    final port = singleCompletePort(completer);
+    final input = uri.toNativeUtf8();
+    ffi.open_link(port.nativePort, input);
+    calloc.free(input);
+
+    return _extractPayload(_extractResponse(completer));
+  }
 }
 
 Future<Either<Uint8List, Uint8List>> _extractPayload(Future<Either<FFIResponse, FlowyError>> responseFuture) {
@@ -59,6 +59,24 @@ typedef _invoke_sync_Dart = Pointer<Uint8> Function(
   int len,
 );
 
+/// C function `open_link`.
+void open_link(
+  int port,
+  Pointer<ffi.Utf8> uri,
+) {
+  _open_link(port, uri);
+}
+
+final _open_link_Dart _open_link = _dl.lookupFunction<_open_link_C, _open_link_Dart>('open_link');
+typedef _open_link_C = Void Function(
+  Int64 port,
+  Pointer<ffi.Utf8> uri,
+);
+typedef _open_link_Dart = void Function(
+  int port,
+  Pointer<ffi.Utf8> uri,
+);
+
 /// C function `init_sdk`.
 int init_sdk(
   Pointer<ffi.Utf8> path,
@@ -9,10 +9,15 @@ import 'dart:core' as $core;
 
 import 'package:protobuf/protobuf.dart' as $pb;
 
+import 'ffi_request.pbenum.dart';
+
+export 'ffi_request.pbenum.dart';
+
 class FFIRequest extends $pb.GeneratedMessage {
   static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'FFIRequest', createEmptyInstance: create)
     ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'event')
     ..a<$core.List<$core.int>>(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'payload', $pb.PbFieldType.OY)
+    ..e<FFIPayloadEncoding>(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'encoding', $pb.PbFieldType.OE, defaultOrMaker: FFIPayloadEncoding.Native, valueOf: FFIPayloadEncoding.valueOf, enumValues: FFIPayloadEncoding.values)
     ..hasRequiredFields = false
   ;
 
@@ -20,6 +25,7 @@ class FFIRequest extends $pb.GeneratedMessage {
   factory FFIRequest({
     $core.String? event,
     $core.List<$core.int>? payload,
+    FFIPayloadEncoding? encoding,
   }) {
     final _result = create();
     if (event != null) {
@@ -28,6 +34,9 @@ class FFIRequest extends $pb.GeneratedMessage {
     if (payload != null) {
       _result.payload = payload;
     }
+    if (encoding != null) {
+      _result.encoding = encoding;
+    }
     return _result;
   }
   factory FFIRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -68,5 +77,14 @@ class FFIRequest extends $pb.GeneratedMessage {
   $core.bool hasPayload() => $_has(1);
   @$pb.TagNumber(2)
   void clearPayload() => clearField(2);
+
+  @$pb.TagNumber(3)
+  FFIPayloadEncoding get encoding => $_getN(2);
+  @$pb.TagNumber(3)
+  set encoding(FFIPayloadEncoding v) { setField(3, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasEncoding() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearEncoding() => clearField(3);
 }
 
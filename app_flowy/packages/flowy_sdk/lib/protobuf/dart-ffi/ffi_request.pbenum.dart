@@ -5,3 +5,22 @@
 // @dart = 2.12
 // ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
 
+// ignore_for_file: UNDEFINED_SHOWN_NAME
+import 'dart:core' as $core;
+import 'package:protobuf/protobuf.dart' as $pb;
+
+class FFIPayloadEncoding extends $pb.ProtobufEnum {
+  static const FFIPayloadEncoding Native = FFIPayloadEncoding._(0, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Native');
+  static const FFIPayloadEncoding Cbor = FFIPayloadEncoding._(1, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Cbor');
+
+  static const $core.List<FFIPayloadEncoding> values = <FFIPayloadEncoding> [
+    Native,
+    Cbor,
+  ];
+
+  static final $core.Map<$core.int, FFIPayloadEncoding> _byValue = $pb.ProtobufEnum.initByValue(values);
+  static FFIPayloadEncoding? valueOf($core.int value) => _byValue[value];
+
+  const FFIPayloadEncoding._($core.int v, $core.String n) : super(v, n);
+}
+
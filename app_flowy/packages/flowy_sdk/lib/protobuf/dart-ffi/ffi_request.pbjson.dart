@@ -8,14 +8,26 @@
 import 'dart:core' as $core;
 import 'dart:convert' as $convert;
 import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use fFIPayloadEncodingDescriptor instead')
+const FFIPayloadEncoding$json = const {
+  '1': 'FFIPayloadEncoding',
+  '2': const [
+    const {'1': 'Native', '2': 0},
+    const {'1': 'Cbor', '2': 1},
+  ],
+};
+
+/// Descriptor for `FFIPayloadEncoding`. Decode as a `google.protobuf.EnumDescriptorProto`.
+final $typed_data.Uint8List fFIPayloadEncodingDescriptor = $convert.base64Decode('ChJGRklQYXlsb2FkRW5jb2RpbmcSCgoGTmF0aXZlEAASCAoEQ2JvchAB');
 @$core.Deprecated('Use fFIRequestDescriptor instead')
 const FFIRequest$json = const {
   '1': 'FFIRequest',
   '2': const [
     const {'1': 'event', '3': 1, '4': 1, '5': 9, '10': 'event'},
     const {'1': 'payload', '3': 2, '4': 1, '5': 12, '10': 'payload'},
+    const {'1': 'encoding', '3': 3, '4': 1, '5': 14, '6': '.FFIPayloadEncoding', '10': 'encoding'},
   ],
 };
 
 /// Descriptor for `FFIRequest`. Decode as a `google.protobuf.DescriptorProto`.
-final $typed_data.Uint8List fFIRequestDescriptor = $convert.base64Decode('CgpGRklSZXF1ZXN0EhQKBWV2ZW50GAEgASgJUgVldmVudBIYCgdwYXlsb2FkGAIgASgMUgdwYXlsb2Fk');
+final $typed_data.Uint8List fFIRequestDescriptor = $convert.base64Decode('CgpGRklSZXF1ZXN0EhQKBWV2ZW50GAEgASgJUgVldmVudBIYCgdwYXlsb2FkGAIgASgMUgdwYXlsb2FkEi8KCGVuY29kaW5nGAMgASgOMhMuRkZJUGF5bG9hZEVuY29kaW5nUghlbmNvZGluZw==');
@@ -0,0 +1,2 @@
+-- Add migration script here
+ALTER TABLE user_table ADD COLUMN email_verified BOOLEAN NOT NULL DEFAULT FALSE;
\ No newline at end of file
@@ -85,10 +85,21 @@ fn user_scope() -> Scope {
         .service(web::resource("/auth")
             .route(web::post().to(user::sign_in_handler))
             .route(web::delete().to(user::sign_out_handler))
+            .route(web::put().to(user::refresh_token_handler))
         )
         .service(web::resource("/user")
             .route(web::patch().to(user::set_user_profile_handler))
             .route(web::get().to(user::get_user_profile_handler))
+            .route(web::delete().to(user::delete_account_handler))
+        )
+        .service(web::resource("/user/session")
+            .route(web::get().to(user::list_sessions_handler))
+            .route(web::delete().to(user::revoke_session_handler))
+        )
+        .service(web::resource("/user/verification")
+            .route(web::post().to(user::send_verification_email_handler))
+            .route(web::get().to(user::get_email_verification_handler))
+            .route(web::put().to(user::confirm_email_handler))
         )
         .service(web::resource("/register")
             .route(web::post().to(user::register_handler))
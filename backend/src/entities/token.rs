@@ -33,6 +33,8 @@ impl Claim {
     }
 
     pub fn user_id(self) -> String { self.user_id }
+
+    pub fn issued_at(&self) -> i64 { self.iat }
 }
 
 // impl From<Claim> for User {
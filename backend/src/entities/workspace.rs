@@ -65,6 +65,7 @@ impl std::convert::Into<Trash> for AppTable {
             modified_time: self.modified_time.timestamp(),
             create_time: self.create_time.timestamp(),
             ty: TrashType::App,
+            deleted_time: 0,
             unknown_fields: Default::default(),
             cached_size: Default::default(),
         }
@@ -109,6 +110,7 @@ impl std::convert::Into<Trash> for ViewTable {
             modified_time: self.modified_time.timestamp(),
             create_time: self.create_time.timestamp(),
             ty: TrashType::View,
+            deleted_time: 0,
             unknown_fields: Default::default(),
             cached_size: Default::default(),
         }
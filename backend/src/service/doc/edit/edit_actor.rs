@@ -4,6 +4,7 @@ use crate::service::{
 };
 use actix_web::web::Data;
 use async_stream::stream;
+use flowy_document::protobuf::DocPresence;
 use flowy_document_infra::protobuf::{Doc, Revision};
 use flowy_net::errors::{internal_error, Result as DocResult, ServerError};
 use futures::stream::StreamExt;
@@ -44,6 +45,12 @@ pub enum EditMsg {
         rev_id: i64,
         ret: oneshot::Sender<DocResult<()>>,
     },
+    Presence {
+        user: Arc<WsUser>,
+        socket: Socket,
+        presence: DocPresence,
+        ret: oneshot::Sender<DocResult<()>>,
+    },
 }
 
 pub struct EditDocActor {
@@ -116,6 +123,18 @@ impl EditDocActor {
                 };
                 let _ = ret.send(self.edit_doc.new_doc_user(user, rev_id).await);
             },
+            EditMsg::Presence {
+                user,
+                socket,
+                presence,
+                ret,
+            } => {
+                let user = EditUser {
+                    user: user.clone(),
+                    socket: socket.clone(),
+                };
+                let _ = ret.send(self.edit_doc.broadcast_presence(user, presence));
+            },
         }
     }
 }
@@ -10,6 +10,7 @@ use bytes::Bytes;
 use dashmap::DashMap;
 use flowy_document::{
     entities::ws::{WsDataType, WsDocumentData},
+    protobuf::DocPresence,
     services::doc::Document,
 };
 use flowy_document_infra::protobuf::{Doc, RevId, RevType, Revision, RevisionRange, UpdateDocParams};
@@ -123,6 +124,30 @@ impl ServerEditDoc {
         Ok(())
     }
 
+    // Sends the cursor of the user to the other users of the document. The user id
+    // and the time are the server's, the clients can't set them for other users.
+    #[tracing::instrument(level = "debug", skip(self, user, presence), fields(user_id = %user.id()), err)]
+    pub fn broadcast_presence(&self, user: EditUser, mut presence: DocPresence) -> Result<(), ServerError> {
+        presence.set_user_id(user.id());
+        presence.set_update_time(chrono::Utc::now().timestamp());
+        if presence.cleared {
+            self.users.remove(&user.id());
+        } else {
+            self.users.insert(user.id(), user.clone());
+        }
+
+        let msg = mk_presence_message(&self.doc_id, presence);
+        self.users
+            .iter()
+            .filter(|other| other.key() != &user.id())
+            .for_each(|other| {
+                if let Err(e) = other.socket.try_send(msg.clone()) {
+                    log::warn!("Send presence to {} failed: {:?}", other.key(), e);
+                }
+            });
+        Ok(())
+    }
+
     pub fn document_json(&self) -> String { self.document.read().to_json() }
 
     async fn compose_revision(&self, revision: &Revision, pg_pool: Data<PgPool>) -> Result<(), ServerError> {
@@ -216,6 +241,16 @@ fn mk_push_message(doc_id: &str, revision: Revision) -> WsMessageAdaptor {
     mk_ws_message(data)
 }
 
+fn mk_presence_message(doc_id: &str, presence: DocPresence) -> WsMessageAdaptor {
+    let bytes = presence.write_to_bytes().unwrap();
+    let data = WsDocumentData {
+        doc_id: doc_id.to_string(),
+        ty: WsDataType::Presence,
+        data: bytes,
+    };
+    mk_ws_message(data)
+}
+
 #[tracing::instrument(level = "debug", skip(socket, doc_id), err)]
 fn send_pull_message(socket: &Socket, doc_id: &str, from_rev_id: i64, to_rev_id: i64) -> Result<(), ServerError> {
     let msg = mk_pull_message(doc_id, from_rev_id, to_rev_id);
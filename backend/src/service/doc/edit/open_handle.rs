@@ -3,6 +3,7 @@ use crate::service::{
     ws::{entities::Socket, WsUser},
 };
 use actix_web::web::Data;
+use flowy_document::protobuf::DocPresence;
 use flowy_document_infra::protobuf::{Doc, Revision};
 use flowy_net::errors::{internal_error, Result as DocResult, ServerError};
 use sqlx::PgPool;
@@ -50,6 +51,23 @@ impl DocHandle {
         Ok(())
     }
 
+    pub async fn broadcast_presence(
+        &self,
+        user: Arc<WsUser>,
+        socket: Socket,
+        presence: DocPresence,
+    ) -> Result<(), ServerError> {
+        let (ret, rx) = oneshot::channel();
+        let msg = EditMsg::Presence {
+            user,
+            socket,
+            presence,
+            ret,
+        };
+        let _ = self.send(msg, rx).await?;
+        Ok(())
+    }
+
     pub async fn document_json(&self) -> DocResult<String> {
         let (ret, rx) = oneshot::channel();
         let msg = EditMsg::DocumentJson { ret };
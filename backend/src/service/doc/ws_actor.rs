@@ -6,7 +6,10 @@ use crate::service::{
 use actix_rt::task::spawn_blocking;
 use actix_web::web::Data;
 use async_stream::stream;
-use flowy_document::protobuf::{WsDataType, WsDocumentData};
+use flowy_document::{
+    entities::ws::decode_ws_document_data,
+    protobuf::{DocPresence, WsDataType, WsDocumentData},
+};
 use flowy_document_infra::protobuf::{NewDocUser, Revision};
 use flowy_net::errors::{internal_error, Result as DocResult, ServerError};
 use futures::stream::StreamExt;
@@ -64,6 +67,7 @@ impl DocWsActor {
     async fn handle_client_data(&self, client_data: WsClientData, pool: Data<PgPool>) -> DocResult<()> {
         let WsClientData { user, socket, data } = client_data;
         let document_data = spawn_blocking(move || {
+            let data = decode_ws_document_data(&data).map_err(internal_error)?;
             let document_data: WsDocumentData = parse_from_bytes(&data)?;
             DocResult::Ok(document_data)
         })
@@ -78,6 +82,7 @@ impl DocWsActor {
             WsDataType::NewDocUser => self.handle_new_doc_user(user, socket, data, pool).await,
             WsDataType::PullRev => Ok(()),
             WsDataType::Conflict => Ok(()),
+            WsDataType::Presence => self.handle_presence(user, socket, data, pool).await,
         }
     }
 
@@ -120,6 +125,25 @@ impl DocWsActor {
         Ok(())
     }
 
+    async fn handle_presence(
+        &self,
+        user: Arc<WsUser>,
+        socket: Socket,
+        data: Vec<u8>,
+        pool: Data<PgPool>,
+    ) -> DocResult<()> {
+        let presence = spawn_blocking(move || {
+            let presence: DocPresence = parse_from_bytes(&data)?;
+            DocResult::Ok(presence)
+        })
+        .await
+        .map_err(internal_error)??;
+        if let Some(handle) = self.doc_handle(&presence.doc_id, pool).await {
+            handle.broadcast_presence(user, socket, presence).await?;
+        }
+        Ok(())
+    }
+
     async fn doc_handle(&self, doc_id: &str, pool: Data<PgPool>) -> Option<Arc<DocHandle>> {
         match self.doc_manager.get(doc_id, pool).await {
             Ok(Some(edit_doc)) => Some(edit_doc),
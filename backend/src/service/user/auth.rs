@@ -10,8 +10,22 @@ use flowy_net::{
     response::FlowyResponse,
 };
 use flowy_user_infra::{
-    parser::{UserEmail, UserName, UserPassword},
-    protobuf::{SignInParams, SignInResponse, SignUpParams, SignUpResponse, UpdateUserParams, UserProfile},
+    parser::{PasswordPolicy, UserEmail, UserName, UserPassword},
+    protobuf::{
+        DeleteAccountParams,
+        RefreshTokenResponse,
+        RepeatedDeviceSession,
+        RevokeSessionParams,
+        SignInParams,
+        SignInResponse,
+        SignUpParams,
+        SignUpResponse,
+        UpdatePasswordParams,
+        UpdatePasswordResponse,
+        UpdateUserParams,
+        UserProfile,
+    },
+    signature::{delete_account_message, verify, SIGNATURE_MAX_AGE},
 };
 use sqlx::{PgPool, Postgres};
 
@@ -36,6 +50,7 @@ pub async fn sign_in(pool: &PgPool, params: SignInParams) -> Result<SignInRespon
     let token = Token::create_token(&user.id.to_string())?;
     let logged_user = LoggedUser::new(&user.id.to_string());
 
+    AUTHORIZED_USERS.add_session(logged_user.clone(), &token.0, &params.device_id, &params.device_name);
     AUTHORIZED_USERS.store_auth(logged_user, true);
     let mut response_data = SignInResponse::default();
     response_data.set_user_id(user.id.to_string());
@@ -46,11 +61,22 @@ pub async fn sign_in(pool: &PgPool, params: SignInParams) -> Result<SignInRespon
     Ok(response_data)
 }
 
-pub async fn sign_out(logged_user: LoggedUser) -> Result<FlowyResponse, ServerError> {
+pub async fn sign_out(token: Token, logged_user: LoggedUser) -> Result<FlowyResponse, ServerError> {
+    AUTHORIZED_USERS.remove_session(&token.0);
     AUTHORIZED_USERS.store_auth(logged_user, false);
     Ok(FlowyResponse::success())
 }
 
+pub async fn refresh_token(old_token: Token, logged_user: LoggedUser) -> Result<RefreshTokenResponse, ServerError> {
+    let token = Token::create_token(&logged_user.user_id)?;
+    AUTHORIZED_USERS.move_session(&old_token.0, &token.0);
+    AUTHORIZED_USERS.store_auth(logged_user, true);
+
+    let mut response_data = RefreshTokenResponse::default();
+    response_data.set_token(token.into());
+    Ok(response_data)
+}
+
 pub async fn register_user(pool: &PgPool, params: SignUpParams) -> Result<FlowyResponse, ServerError> {
     let name = UserName::parse(params.name).map_err(|e| ServerError::params_invalid().context(e))?;
     let email = UserEmail::parse(params.email).map_err(|e| ServerError::params_invalid().context(e))?;
@@ -67,6 +93,12 @@ pub async fn register_user(pool: &PgPool, params: SignUpParams) -> Result<FlowyR
         .context("Failed to insert user")?;
 
     let logged_user = LoggedUser::new(&response_data.user_id);
+    AUTHORIZED_USERS.add_session(
+        logged_user.clone(),
+        response_data.get_token(),
+        &params.device_id,
+        &params.device_name,
+    );
     AUTHORIZED_USERS.store_auth(logged_user, true);
     let _ = create_default_workspace(&mut transaction, response_data.get_user_id()).await?;
 
@@ -108,6 +140,7 @@ pub(crate) async fn get_user_profile(
     user_profile.set_email(user_table.email);
     user_profile.set_name(user_table.name);
     user_profile.set_token(token.0);
+    user_profile.set_email_verified(user_table.email_verified);
     FlowyResponse::success().pb(user_profile)
 }
 
@@ -144,9 +177,12 @@ pub(crate) async fn set_user_profile(
         },
     };
 
+    // The new email must be verified again
+    let email_verified = email.as_ref().map(|_| false);
     let (sql, args) = SqlBuilder::update("user_table")
         .add_some_arg("name", name)
         .add_some_arg("email", email)
+        .add_some_arg("email_verified", email_verified)
         .add_some_arg("password", password)
         .and_where_eq("id", &logged_user.as_uuid()?)
         .build()?;
@@ -164,6 +200,139 @@ pub(crate) async fn set_user_profile(
     Ok(FlowyResponse::success())
 }
 
+pub(crate) async fn update_password(
+    pool: &PgPool,
+    logged_user: LoggedUser,
+    params: UpdatePasswordParams,
+) -> Result<UpdatePasswordResponse, ServerError> {
+    PasswordPolicy::default()
+        .validate(params.get_new_password())
+        .map_err(invalid_params)?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to update password")?;
+
+    let id = logged_user.as_uuid()?;
+    let user = sqlx::query_as::<Postgres, UserTable>("SELECT * FROM user_table WHERE id = $1")
+        .bind(id)
+        .fetch_one(&mut transaction)
+        .await
+        .map_err(|err| ServerError::internal().context(err))?;
+
+    match verify_password(params.get_old_password(), &user.password) {
+        Ok(true) => {},
+        _ => return Err(ServerError::password_not_match()),
+    }
+
+    let password = hash_password(params.get_new_password())?;
+    let (sql, args) = SqlBuilder::update("user_table")
+        .add_arg("password", password)
+        .and_where_eq("id", &id)
+        .build()?;
+
+    sqlx::query_with(&sql, args)
+        .execute(&mut transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to update password.")?;
+
+    // Sign out every other session of the user, the new token is the only valid one.
+    let token: String = Token::create_token(&logged_user.user_id)?.into();
+    AUTHORIZED_USERS.revoke_tokens_except(&logged_user, &token);
+    AUTHORIZED_USERS.store_auth(logged_user, true);
+
+    let mut response_data = UpdatePasswordResponse::default();
+    response_data.set_token(token);
+    Ok(response_data)
+}
+
+pub(crate) async fn delete_account(
+    pool: &PgPool,
+    token: Token,
+    logged_user: LoggedUser,
+    params: DeleteAccountParams,
+) -> Result<FlowyResponse, ServerError> {
+    if params.get_user_id() != logged_user.user_id {
+        return Err(ServerError::unauthorized());
+    }
+
+    // The request is signed with the token, an old or forged request is rejected.
+    let message = delete_account_message(params.get_user_id(), params.get_timestamp());
+    let is_fresh = (Utc::now().timestamp() - params.get_timestamp()).abs() <= SIGNATURE_MAX_AGE;
+    if !is_fresh || !verify(&token.0, &message, params.get_signature()) {
+        return Err(ServerError::unauthorized().context("The signature of the request is invalid"));
+    }
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to delete account")?;
+
+    let id = logged_user.as_uuid()?;
+    let user = sqlx::query_as::<Postgres, UserTable>("SELECT * FROM user_table WHERE id = $1")
+        .bind(id)
+        .fetch_one(&mut transaction)
+        .await
+        .map_err(|err| ServerError::internal().context(err))?;
+
+    match verify_password(params.get_password(), &user.password) {
+        Ok(true) => {},
+        _ => return Err(ServerError::password_not_match()),
+    }
+
+    let user_id = logged_user.user_id.clone();
+    let statements = [
+        "DELETE FROM trash_table WHERE user_id = $1",
+        "DELETE FROM doc_table WHERE id::text IN (SELECT id::text FROM view_table WHERE belong_to_id IN (SELECT \
+         id::text FROM app_table WHERE user_id = $1))",
+        "DELETE FROM view_table WHERE belong_to_id IN (SELECT id::text FROM app_table WHERE user_id = $1)",
+        "DELETE FROM app_table WHERE user_id = $1",
+        "DELETE FROM workspace_table WHERE user_id = $1",
+    ];
+    for sql in statements.iter() {
+        sqlx::query(sql)
+            .bind(&user_id)
+            .execute(&mut transaction)
+            .await
+            .map_err(map_sqlx_error)?;
+    }
+
+    let (sql, args) = SqlBuilder::delete("user_table").and_where_eq("id", &id).build()?;
+    sqlx::query_with(&sql, args)
+        .execute(&mut transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to delete account.")?;
+
+    AUTHORIZED_USERS.remove_session(&token.0);
+    AUTHORIZED_USERS.store_auth(logged_user, false);
+    Ok(FlowyResponse::success())
+}
+
+pub(crate) async fn list_sessions(token: Token, logged_user: LoggedUser) -> Result<FlowyResponse, ServerError> {
+    let mut repeated_session = RepeatedDeviceSession::default();
+    repeated_session.set_items(AUTHORIZED_USERS.list_sessions(&logged_user, &token.0).into());
+    FlowyResponse::success().pb(repeated_session)
+}
+
+pub(crate) async fn revoke_session(
+    logged_user: LoggedUser,
+    params: RevokeSessionParams,
+) -> Result<FlowyResponse, ServerError> {
+    let _ = AUTHORIZED_USERS.revoke_session(&logged_user, params.get_session_id())?;
+    Ok(FlowyResponse::success())
+}
+
 async fn is_email_exist(transaction: &mut DBTransaction<'_>, email: &str) -> Result<(), ServerError> {
     let result = sqlx::query(r#"SELECT email FROM user_table WHERE email = $1"#)
         .bind(email)
@@ -3,6 +3,7 @@ use actix_web::http::HeaderValue;
 use chrono::{DateTime, Utc};
 use dashmap::DashMap;
 use flowy_net::errors::ServerError;
+use flowy_user_infra::protobuf::DeviceSession;
 use lazy_static::lazy_static;
 
 lazy_static! {
@@ -26,7 +27,16 @@ impl LoggedUser {
     }
 
     pub fn from_token(token: String) -> Result<Self, ServerError> {
-        let user: LoggedUser = Token::decode_token(&token.into())?.into();
+        let claim = Token::decode_token(&token.clone().into())?;
+        let issued_at = claim.issued_at();
+        let user: LoggedUser = claim.into();
+        if AUTHORIZED_USERS.is_session_revoked(&token) {
+            return Err(ServerError::session_revoked());
+        }
+        if AUTHORIZED_USERS.is_revoked(&user, &token, issued_at) {
+            return Err(ServerError::unauthorized());
+        }
+        AUTHORIZED_USERS.touch_session(&token);
         Ok(user)
     }
 
@@ -75,12 +85,33 @@ enum AuthStatus {
 
 pub const EXPIRED_DURATION_DAYS: i64 = 30;
 
-pub struct AuthorizedUsers(DashMap<LoggedUser, AuthStatus>);
+pub struct AuthorizedUsers {
+    users: DashMap<LoggedUser, AuthStatus>,
+    // Every token of the user that was issued until the timestamp is no longer valid, except
+    // the one kept, e.g. the token returned after the password was changed.
+    revoked: DashMap<LoggedUser, (i64, String)>,
+    // The device session of each token, the revoked ones are kept to report SessionRevoked.
+    sessions: DashMap<String, UserSession>,
+}
+
+#[derive(Clone, Debug)]
+struct UserSession {
+    user: LoggedUser,
+    session: DeviceSession,
+    revoked: bool,
+}
+
 impl AuthorizedUsers {
-    pub fn new() -> Self { Self(DashMap::new()) }
+    pub fn new() -> Self {
+        Self {
+            users: DashMap::new(),
+            revoked: DashMap::new(),
+            sessions: DashMap::new(),
+        }
+    }
 
     pub fn is_authorized(&self, user: &LoggedUser) -> bool {
-        match self.0.get(user) {
+        match self.users.get(user) {
             None => {
                 tracing::debug!("user not login yet or server was reboot");
                 false
@@ -105,6 +136,87 @@ impl AuthorizedUsers {
         } else {
             AuthStatus::NotAuthorized
         };
-        self.0.insert(user, status);
+        self.users.insert(user, status);
+    }
+
+    pub fn revoke_tokens_except(&self, user: &LoggedUser, token: &str) {
+        self.revoked
+            .insert(user.clone(), (Utc::now().timestamp(), token.to_owned()));
+    }
+
+    pub fn add_session(&self, user: LoggedUser, token: &str, device_id: &str, device_name: &str) {
+        let now = Utc::now().timestamp();
+        let mut session = DeviceSession::default();
+        session.set_id(uuid::Uuid::new_v4().to_string());
+        session.set_device_id(device_id.to_owned());
+        session.set_device_name(device_name.to_owned());
+        session.set_created_at(now);
+        session.set_last_active_at(now);
+        self.sessions.insert(
+            token.to_owned(),
+            UserSession {
+                user,
+                session,
+                revoked: false,
+            },
+        );
+    }
+
+    // The refreshed token continues the session of the old one.
+    pub fn move_session(&self, old_token: &str, new_token: &str) {
+        if let Some((_, session)) = self.sessions.remove(old_token) {
+            self.sessions.insert(new_token.to_owned(), session);
+        }
+    }
+
+    pub fn remove_session(&self, token: &str) { self.sessions.remove(token); }
+
+    pub fn touch_session(&self, token: &str) {
+        if let Some(mut session) = self.sessions.get_mut(token) {
+            session.session.set_last_active_at(Utc::now().timestamp());
+        }
+    }
+
+    pub fn list_sessions(&self, user: &LoggedUser, current_token: &str) -> Vec<DeviceSession> {
+        self.sessions
+            .iter()
+            .filter(|entry| &entry.value().user == user && !entry.value().revoked)
+            .map(|entry| {
+                let mut session = entry.value().session.clone();
+                session.set_is_current(entry.key() == current_token);
+                session
+            })
+            .collect()
+    }
+
+    pub fn revoke_session(&self, user: &LoggedUser, session_id: &str) -> Result<(), ServerError> {
+        match self
+            .sessions
+            .iter_mut()
+            .find(|entry| &entry.value().user == user && entry.value().session.get_id() == session_id)
+        {
+            None => Err(ServerError::record_not_found()),
+            Some(mut entry) => {
+                entry.value_mut().revoked = true;
+                Ok(())
+            },
+        }
+    }
+
+    pub fn is_session_revoked(&self, token: &str) -> bool {
+        match self.sessions.get(token) {
+            None => false,
+            Some(session) => session.revoked,
+        }
+    }
+
+    pub fn is_revoked(&self, user: &LoggedUser, token: &str, issued_at: i64) -> bool {
+        match self.revoked.get(user) {
+            None => false,
+            Some(revoked) => {
+                let (revoked_at, kept_token) = &*revoked;
+                issued_at <= *revoked_at && token != kept_token
+            },
+        }
     }
 }
@@ -1,9 +1,11 @@
 pub use auth::*;
 pub use logged_user::*;
 pub use utils::*;
+pub use verification::*;
 
 mod auth;
 mod logged_user;
 pub mod router;
 pub mod user_default;
 mod utils;
+mod verification;
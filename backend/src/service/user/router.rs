@@ -1,18 +1,40 @@
 use actix_identity::Identity;
 use actix_web::{
     web::{Data, Payload},
-    HttpRequest,
     HttpResponse,
 };
 use sqlx::PgPool;
 
 use flowy_net::{errors::ServerError, response::FlowyResponse};
-use flowy_user_infra::protobuf::{SignInParams, SignUpParams, UpdateUserParams};
+use flowy_user_infra::protobuf::{
+    ConfirmEmailParams,
+    DeleteAccountParams,
+    RevokeSessionParams,
+    SignInParams,
+    SignUpParams,
+    UpdatePasswordParams,
+    UpdateUserParams,
+};
 
 use crate::{
     entities::token::Token,
     service::{
-        user::{get_user_profile, register_user, set_user_profile, sign_in, sign_out, LoggedUser},
+        user::{
+            confirm_email,
+            delete_account,
+            get_email_verification,
+            get_user_profile,
+            list_sessions,
+            refresh_token,
+            register_user,
+            revoke_session,
+            send_verification_email,
+            set_user_profile,
+            sign_in,
+            sign_out,
+            update_password,
+            LoggedUser,
+        },
         util::parse_from_payload,
     },
 };
@@ -25,10 +47,25 @@ pub async fn sign_in_handler(payload: Payload, id: Identity, pool: Data<PgPool>)
     Ok(response.into())
 }
 
-pub async fn sign_out_handler(logged_user: LoggedUser, id: Identity) -> Result<HttpResponse, ServerError> {
+pub async fn sign_out_handler(
+    token: Token,
+    logged_user: LoggedUser,
+    id: Identity,
+) -> Result<HttpResponse, ServerError> {
     id.forget();
 
-    let response = sign_out(logged_user).await?;
+    let response = sign_out(token, logged_user).await?;
+    Ok(response.into())
+}
+
+pub async fn refresh_token_handler(
+    token: Token,
+    logged_user: LoggedUser,
+    id: Identity,
+) -> Result<HttpResponse, ServerError> {
+    let data = refresh_token(token, logged_user).await?;
+    id.remember(data.token.clone());
+    let response = FlowyResponse::success().pb(data)?;
     Ok(response.into())
 }
 
@@ -59,9 +96,64 @@ pub async fn register_handler(payload: Payload, pool: Data<PgPool>) -> Result<Ht
 }
 
 pub async fn change_password(
-    _request: HttpRequest,
-    _payload: Payload,
-    _pool: Data<PgPool>,
+    logged_user: LoggedUser,
+    payload: Payload,
+    id: Identity,
+    pool: Data<PgPool>,
+) -> Result<HttpResponse, ServerError> {
+    let params: UpdatePasswordParams = parse_from_payload(payload).await?;
+    let data = update_password(pool.get_ref(), logged_user, params).await?;
+    id.remember(data.token.clone());
+    let response = FlowyResponse::success().pb(data)?;
+    Ok(response.into())
+}
+
+pub async fn delete_account_handler(
+    token: Token,
+    logged_user: LoggedUser,
+    payload: Payload,
+    id: Identity,
+    pool: Data<PgPool>,
+) -> Result<HttpResponse, ServerError> {
+    let params: DeleteAccountParams = parse_from_payload(payload).await?;
+    let response = delete_account(pool.get_ref(), token, logged_user, params).await?;
+    id.forget();
+    Ok(response.into())
+}
+
+pub async fn list_sessions_handler(token: Token, logged_user: LoggedUser) -> Result<HttpResponse, ServerError> {
+    let response = list_sessions(token, logged_user).await?;
+    Ok(response.into())
+}
+
+pub async fn revoke_session_handler(logged_user: LoggedUser, payload: Payload) -> Result<HttpResponse, ServerError> {
+    let params: RevokeSessionParams = parse_from_payload(payload).await?;
+    let response = revoke_session(logged_user, params).await?;
+    Ok(response.into())
+}
+
+pub async fn send_verification_email_handler(
+    logged_user: LoggedUser,
+    pool: Data<PgPool>,
+) -> Result<HttpResponse, ServerError> {
+    let response = send_verification_email(pool.get_ref(), logged_user).await?;
+    Ok(response.into())
+}
+
+pub async fn get_email_verification_handler(
+    logged_user: LoggedUser,
+    pool: Data<PgPool>,
 ) -> Result<HttpResponse, ServerError> {
-    unimplemented!()
+    let response = get_email_verification(pool.get_ref(), logged_user).await?;
+    Ok(response.into())
+}
+
+pub async fn confirm_email_handler(
+    logged_user: LoggedUser,
+    payload: Payload,
+    pool: Data<PgPool>,
+) -> Result<HttpResponse, ServerError> {
+    let params: ConfirmEmailParams = parse_from_payload(payload).await?;
+    let response = confirm_email(pool.get_ref(), logged_user, params).await?;
+    Ok(response.into())
 }
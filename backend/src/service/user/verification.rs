@@ -0,0 +1,100 @@
+use crate::{
+    entities::user::UserTable,
+    service::user::LoggedUser,
+    sqlx_ext::{map_sqlx_error, SqlBuilder},
+};
+use anyhow::Context;
+use chrono::Utc;
+use dashmap::DashMap;
+use flowy_net::{errors::ServerError, response::FlowyResponse};
+use flowy_user_infra::protobuf::{ConfirmEmailParams, EmailVerification};
+use lazy_static::lazy_static;
+use sqlx::{PgPool, Postgres};
+
+// The code expires 30 minutes after it was sent.
+const VERIFICATION_CODE_EXPIRED_SECONDS: i64 = 30 * 60;
+
+lazy_static! {
+    // The last code sent to each user with the time it was sent.
+    static ref VERIFICATION_CODES: DashMap<LoggedUser, (String, i64)> = DashMap::new();
+}
+
+pub(crate) async fn send_verification_email(
+    pool: &PgPool,
+    logged_user: LoggedUser,
+) -> Result<FlowyResponse, ServerError> {
+    let user = read_user(pool, &logged_user).await?;
+    if user.email_verified {
+        return Ok(FlowyResponse::success());
+    }
+
+    let code = format!("{:06}", uuid::Uuid::new_v4().as_u128() % 1_000_000);
+    // TODO: send the code with the mail service once it's configured
+    tracing::debug!("Send verification code to {}", user.email);
+    VERIFICATION_CODES.insert(logged_user, (code, Utc::now().timestamp()));
+    Ok(FlowyResponse::success())
+}
+
+pub(crate) async fn get_email_verification(
+    pool: &PgPool,
+    logged_user: LoggedUser,
+) -> Result<FlowyResponse, ServerError> {
+    let user = read_user(pool, &logged_user).await?;
+    FlowyResponse::success().pb(mk_verification(user))
+}
+
+pub(crate) async fn confirm_email(
+    pool: &PgPool,
+    logged_user: LoggedUser,
+    params: ConfirmEmailParams,
+) -> Result<FlowyResponse, ServerError> {
+    let is_valid = match VERIFICATION_CODES.get(&logged_user) {
+        None => false,
+        Some(entry) => {
+            let (code, sent_at) = &*entry;
+            code == params.get_code() && Utc::now().timestamp() - sent_at <= VERIFICATION_CODE_EXPIRED_SECONDS
+        },
+    };
+    if !is_valid {
+        return Err(ServerError::verification_code_invalid());
+    }
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to confirm email")?;
+
+    let (sql, args) = SqlBuilder::update("user_table")
+        .add_arg("email_verified", true)
+        .and_where_eq("id", &logged_user.as_uuid()?)
+        .build()?;
+    sqlx::query_with(&sql, args)
+        .execute(&mut transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to confirm email.")?;
+
+    VERIFICATION_CODES.remove(&logged_user);
+    let user = read_user(pool, &logged_user).await?;
+    FlowyResponse::success().pb(mk_verification(user))
+}
+
+async fn read_user(pool: &PgPool, logged_user: &LoggedUser) -> Result<UserTable, ServerError> {
+    let user = sqlx::query_as::<Postgres, UserTable>("SELECT * FROM user_table WHERE id = $1")
+        .bind(logged_user.as_uuid()?)
+        .fetch_one(pool)
+        .await
+        .map_err(|err| ServerError::internal().context(err))?;
+    Ok(user)
+}
+
+fn mk_verification(user: UserTable) -> EmailVerification {
+    let mut verification = EmailVerification::default();
+    verification.set_email(user.email);
+    verification.set_is_verified(user.email_verified);
+    verification
+}
@@ -1,6 +1,6 @@
 use crate::helper::{spawn_user_server, TestUserServer};
 use flowy_net::errors::ErrorCode;
-use flowy_user_infra::entities::{SignInParams, SignUpParams, SignUpResponse, UpdateUserParams};
+use flowy_user_infra::entities::{SignInParams, SignUpParams, SignUpResponse, UpdatePasswordParams, UpdateUserParams};
 
 #[actix_rt::test]
 async fn user_register() {
@@ -37,6 +37,7 @@ async fn user_sign_in() {
         email: email.to_string(),
         password: password.to_string(),
         name: "rust".to_string(),
+        ..Default::default()
     };
     let _ = app.sign_in(params).await.unwrap();
 }
@@ -73,6 +74,7 @@ async fn user_update_password() {
         email: email.to_string(),
         password: password.to_string(),
         name: "rust".to_string(),
+        ..Default::default()
     };
 
     match server.sign_in(sign_in_params).await {
@@ -83,6 +85,38 @@ async fn user_update_password() {
     }
 }
 
+#[actix_rt::test]
+async fn user_change_password() {
+    let mut server = TestUserServer::new().await;
+    let old_token = server.user_token().to_owned();
+    let params = UpdatePasswordParams {
+        old_password: "HelloAppFlowy123!".to_string(),
+        new_password: "HelloFlowy!456".to_string(),
+    };
+    let resp = server.update_password(params).await.unwrap();
+
+    // the other sessions are signed out after the password was changed
+    let url = format!("{}/api/user", server.http_addr());
+    let error = flowy_backend_api::user_request::get_user_profile_request(&old_token, &url)
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized);
+
+    server.user_token = Some(resp.token);
+    let _ = server.get_user_profile().await;
+}
+
+#[actix_rt::test]
+async fn user_change_password_with_wrong_old_password() {
+    let server = TestUserServer::new().await;
+    let params = UpdatePasswordParams {
+        old_password: "WrongPassword!123".to_string(),
+        new_password: "HelloFlowy!456".to_string(),
+    };
+    let error = server.update_password(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PasswordNotMatch);
+}
+
 #[actix_rt::test]
 async fn user_update_name() {
     let server = TestUserServer::new().await;
@@ -119,6 +153,7 @@ async fn register_user(server: &TestUserServer, email: &str, password: &str) ->
         email: email.to_string(),
         name: "annie".to_string(),
         password: password.to_string(),
+        ..Default::default()
     };
 
     let response = server.register(params).await;
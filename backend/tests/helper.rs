@@ -56,6 +56,12 @@ impl TestUserServer {
         Ok(())
     }
 
+    pub async fn update_password(&self, params: UpdatePasswordParams) -> Result<UpdatePasswordResponse, ServerError> {
+        let url = format!("{}/api/password_change", self.http_addr());
+        let resp = update_password_request(self.user_token(), params, &url).await?;
+        Ok(resp)
+    }
+
     pub async fn create_workspace(&self, params: CreateWorkspaceParams) -> Workspace {
         let url = format!("{}/api/workspace", self.http_addr());
         let workspace = create_workspace_request(self.user_token(), params, &url).await.unwrap();
@@ -157,6 +163,7 @@ impl TestUserServer {
             email: "annie@appflowy.io".to_string(),
             name: "annie".to_string(),
             password: "HelloAppFlowy123!".to_string(),
+            ..Default::default()
         };
 
         self.register(params).await
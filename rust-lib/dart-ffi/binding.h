@@ -10,6 +10,12 @@ void async_command(int64_t port, const uint8_t *input, uintptr_t len);
 
 const uint8_t *sync_command(const uint8_t *input, uintptr_t len);
 
+void open_link(int64_t port, const char *uri);
+
+int32_t start_ipc_server(char *path);
+
+int32_t stop_ipc_server(void);
+
 int32_t set_stream_port(int64_t port);
 
 void link_me_please(void);
\ No newline at end of file
@@ -0,0 +1,279 @@
+use crate::model::{FFIRequest, FFIResponse};
+use bytes::Bytes;
+use flowy_dart_notify::{
+    dart::{add_subject_listener, remove_subject_listener},
+    entities::SubscribeObject,
+};
+use flowy_dispatch::prelude::*;
+use std::{convert::TryInto, sync::Arc};
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    sync::{mpsc, oneshot, Semaphore},
+};
+
+// The biggest frame that's read, the connection is closed on a bigger one.
+pub const MAX_FRAME_SIZE: usize = 16 * 1024 * 1024;
+
+pub const RESPONSE_FRAME: u8 = 0;
+pub const NOTIFICATION_FRAME: u8 = 1;
+
+// The frames queued for a connection that reads slowly. The notifications that
+// don't fit are dropped, the responses wait.
+const OUTGOING_FRAMES: usize = 256;
+
+// The requests of a connection that are handled at the same time, the next
+// ones aren't read until one of them is done.
+const CONNECTION_REQUESTS: usize = 64;
+
+// Serves the events of the dispatcher to the local processes, e.g. the
+// automation tools and the editor integrations, on a unix socket or on a named
+// pipe on windows. Each frame is the big endian u32 length of its body, then
+// the body:
+//
+//   request: the u32 id of the request, then the FFIRequest.
+//   response: RESPONSE_FRAME, the id of its request, then the FFIResponse.
+//   notification: NOTIFICATION_FRAME, then the SubscribeObject.
+//
+// The responses are sent once their events are handled, not in the order of
+// the requests. Each connection receives all the notifications. The server
+// stops when it's dropped.
+pub struct IpcServer {
+    path: String,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl IpcServer {
+    pub fn start(dispatch: Arc<EventDispatch>, path: &str) -> Result<Self, String> {
+        let listener = bind(path)?;
+        let (shutdown, stopped) = oneshot::channel();
+        dispatch.spawn(serve(dispatch.clone(), listener, stopped));
+        Ok(Self {
+            path: path.to_owned(),
+            shutdown: Some(shutdown),
+        })
+    }
+
+    pub fn path(&self) -> &str { &self.path }
+}
+
+impl Drop for IpcServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        #[cfg(unix)]
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(unix)]
+type Listener = std::os::unix::net::UnixListener;
+
+// Only the user can connect to the socket. It's bound in a directory that only
+// the user can open, then moved to the path once its permissions are set, so
+// another user can't connect to it in between.
+#[cfg(unix)]
+fn bind(path: &str) -> Result<Listener, String> {
+    use std::os::unix::{
+        fs::{DirBuilderExt, PermissionsExt},
+        net::UnixStream,
+    };
+    let socket_path = std::path::Path::new(path);
+    if socket_path.exists() {
+        if UnixStream::connect(path).is_ok() {
+            return Err(format!("{} is served by another process", path));
+        }
+        // Left by a process that didn't stop its server.
+        let _ = std::fs::remove_file(path);
+    }
+
+    let file_name = socket_path
+        .file_name()
+        .ok_or(format!("{} isn't the path of a file", path))?;
+    let private_dir = socket_path.with_file_name(format!(".{}.{}", file_name.to_string_lossy(), std::process::id()));
+    let _ = std::fs::remove_dir_all(&private_dir);
+    std::fs::DirBuilder::new()
+        .mode(0o700)
+        .create(&private_dir)
+        .map_err(|e| format!("Create {:?} failed: {}", private_dir, e))?;
+    let private_path = private_dir.join(file_name);
+    let bound = Listener::bind(&private_path)
+        .map_err(|e| format!("Bind {} failed: {}", path, e))
+        .and_then(|listener| {
+            std::fs::set_permissions(&private_path, std::fs::Permissions::from_mode(0o600))
+                .map_err(|e| e.to_string())?;
+            std::fs::rename(&private_path, path).map_err(|e| format!("Move the socket to {} failed: {}", path, e))?;
+            Ok(listener)
+        });
+    let _ = std::fs::remove_dir_all(&private_dir);
+    let listener = bound?;
+    listener.set_nonblocking(true).map_err(|e| e.to_string())?;
+    Ok(listener)
+}
+
+#[cfg(unix)]
+async fn serve(dispatch: Arc<EventDispatch>, listener: Listener, mut stopped: oneshot::Receiver<()>) {
+    let listener = match tokio::net::UnixListener::from_std(listener) {
+        Ok(listener) => listener,
+        Err(e) => {
+            log::error!("[IPC]: Listen failed: {:?}", e);
+            return;
+        },
+    };
+    loop {
+        tokio::select! {
+            _ = &mut stopped => break,
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    tokio::spawn(handle_connection(dispatch.clone(), stream));
+                },
+                Err(e) => log::error!("[IPC]: Accept failed: {:?}", e),
+            },
+        }
+    }
+}
+
+// The pipes are created once the runtime runs the server.
+#[cfg(windows)]
+type Listener = String;
+
+#[cfg(windows)]
+fn bind(path: &str) -> Result<Listener, String> { Ok(path.to_owned()) }
+
+#[cfg(windows)]
+async fn serve(dispatch: Arc<EventDispatch>, path: Listener, mut stopped: oneshot::Receiver<()>) {
+    use tokio::net::windows::named_pipe::ServerOptions;
+    let mut server = match ServerOptions::new().first_pipe_instance(true).create(&path) {
+        Ok(server) => server,
+        Err(e) => {
+            log::error!("[IPC]: Create the pipe {} failed: {:?}", path, e);
+            return;
+        },
+    };
+    loop {
+        tokio::select! {
+            _ = &mut stopped => break,
+            connected = server.connect() => {
+                // The next client connects to a new instance of the pipe. The
+                // instance that failed is dropped, connecting it again would fail
+                // right away.
+                let next = match ServerOptions::new().create(&path) {
+                    Ok(next) => next,
+                    Err(e) => {
+                        log::error!("[IPC]: Create the pipe {} failed: {:?}", path, e);
+                        break;
+                    },
+                };
+                let connected_server = std::mem::replace(&mut server, next);
+                match connected {
+                    Ok(_) => {
+                        tokio::spawn(handle_connection(dispatch.clone(), connected_server));
+                    },
+                    Err(e) => log::error!("[IPC]: Accept failed: {:?}", e),
+                }
+            },
+        }
+    }
+}
+
+async fn handle_connection<S>(dispatch: Arc<EventDispatch>, stream: S)
+where
+    S: AsyncRead + AsyncWrite + Send + 'static,
+{
+    let (mut reader, mut writer) = tokio::io::split(stream);
+    let (sender, mut outgoing) = mpsc::channel::<Vec<u8>>(OUTGOING_FRAMES);
+    let requests = Arc::new(Semaphore::new(CONNECTION_REQUESTS));
+    let notifications = sender.clone();
+    let listener_id = add_subject_listener(Arc::new(move |subject: &SubscribeObject| {
+        let body: Result<Bytes, _> = subject.clone().try_into();
+        if let Ok(body) = body {
+            if notifications
+                .try_send(frame(&[&[NOTIFICATION_FRAME][..], &body[..]]))
+                .is_err()
+            {
+                log::warn!("[IPC]: Drop the notification {} of a slow connection", subject.id);
+            }
+        }
+    }));
+    tokio::spawn(async move {
+        while let Some(frame) = outgoing.recv().await {
+            if writer.write_all(&frame).await.is_err() {
+                break;
+            }
+            recycle_buffer(frame);
+        }
+    });
+
+    loop {
+        let permit = match requests.clone().acquire_owned().await {
+            Ok(permit) => permit,
+            Err(_) => break,
+        };
+        let body = match read_frame(&mut reader).await {
+            Ok(Some(body)) if body.len() >= 4 => body,
+            Ok(Some(_)) => {
+                log::error!("[IPC]: The request has no id");
+                break;
+            },
+            Ok(None) => break,
+            Err(e) => {
+                log::error!("[IPC]: Read the request failed: {}", e);
+                break;
+            },
+        };
+        let id = u32::from_be_bytes([body[0], body[1], body[2], body[3]]);
+        let sender = sender.clone();
+        let request = FFIRequest::from_bytes(&body[4..]);
+        recycle_buffer(body);
+        match request {
+            Ok(request) => {
+                let dispatch = dispatch.clone();
+                tokio::spawn(async move {
+                    let response = EventDispatch::async_send(dispatch, request).await;
+                    let _ = sender.send(response_frame(id, &response)).await;
+                    drop(permit);
+                });
+            },
+            Err(e) => {
+                let _ = sender.send(response_frame(id, &EventResponse::from(e))).await;
+            },
+        }
+    }
+    remove_subject_listener(listener_id);
+}
+
+// None once the other side closed the connection.
+async fn read_frame<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Option<Vec<u8>>, String> {
+    let mut len = [0u8; 4];
+    match reader.read_exact(&mut len).await {
+        Ok(_) => {},
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e.to_string()),
+    }
+    let len = u32::from_be_bytes(len) as usize;
+    if len > MAX_FRAME_SIZE {
+        return Err(format!("The frame of {} bytes is too big", len));
+    }
+    let mut body = take_buffer(len);
+    body.resize(len, 0);
+    reader.read_exact(&mut body).await.map_err(|e| e.to_string())?;
+    Ok(Some(body))
+}
+
+fn frame(parts: &[&[u8]]) -> Vec<u8> {
+    let len = parts.iter().map(|part| part.len()).sum::<usize>();
+    let mut frame = take_buffer(4 + len);
+    frame.extend_from_slice(&(len as u32).to_be_bytes());
+    for part in parts {
+        frame.extend_from_slice(part);
+    }
+    frame
+}
+
+fn response_frame(id: u32, response: &EventResponse) -> Vec<u8> {
+    frame(&[
+        &[RESPONSE_FRAME][..],
+        &id.to_be_bytes()[..],
+        &FFIResponse::encode(response)[..],
+    ])
+}
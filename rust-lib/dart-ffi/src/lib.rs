@@ -1,20 +1,29 @@
 mod c;
-mod model;
+pub mod ipc;
+pub mod loopback;
+pub mod model;
 mod protobuf;
 mod util;
 
 use crate::{
     c::{extend_front_four_bytes_into_bytes, forget_rust},
+    ipc::IpcServer,
     model::{FFIRequest, FFIResponse},
 };
 use flowy_dispatch::prelude::*;
 use flowy_sdk::*;
 use lazy_static::lazy_static;
-use parking_lot::RwLock;
-use std::{ffi::CStr, os::raw::c_char, sync::Arc};
+use parking_lot::{Mutex, RwLock};
+use std::{
+    ffi::CStr,
+    os::raw::c_char,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 lazy_static! {
     static ref FLOWY_SDK: RwLock<Option<Arc<FlowySDK>>> = RwLock::new(None);
+    static ref IPC_SERVER: Mutex<Option<IpcServer>> = Mutex::new(None);
 }
 
 fn dispatch() -> Arc<EventDispatch> { FLOWY_SDK.read().as_ref().unwrap().dispatch() }
@@ -33,25 +42,47 @@ pub extern "C" fn init_sdk(path: *mut c_char) -> i64 {
 
 #[no_mangle]
 pub extern "C" fn async_command(port: i64, input: *const u8, len: usize) {
-    let request: ModuleRequest = FFIRequest::from_u8_pointer(input, len).into();
-    log::trace!(
-        "[FFI]: {} Async Event: {:?} with {} port",
-        &request.id,
-        &request.event,
-        port
-    );
+    let deserialize_at = Instant::now();
+    let request: ModuleRequest = match FFIRequest::from_u8_pointer(input, len) {
+        Ok(request) => request.into(),
+        Err(e) => {
+            log::error!("[FFI]: Decode the async event failed: {:?}", e);
+            post_error_to_flutter(e, port);
+            return;
+        },
+    };
+    send_to_flutter(request, port, deserialize_at);
+}
 
-    let _ = EventDispatch::async_send_with_callback(dispatch(), request, move |resp: EventResponse| {
-        log::trace!("[FFI]: Post data to dart through {} port", port);
-        Box::pin(post_to_flutter(resp, port))
-    });
+// Resolves the link, e.g. appflowy://workspace/<id>/view/<id>?action=open, and
+// posts the response of its event to the port like async_command.
+#[no_mangle]
+pub extern "C" fn open_link(port: i64, uri: *const c_char) {
+    let deserialize_at = Instant::now();
+    let c_str: &CStr = unsafe { CStr::from_ptr(uri) };
+    let resolved = match c_str.to_str() {
+        Ok(uri) => dispatch().resolve_link(uri).map_err(|e| e.to_string()),
+        Err(e) => Err(e.to_string()),
+    };
+    match resolved {
+        Ok(request) => send_to_flutter(request, port, deserialize_at),
+        Err(e) => {
+            log::error!("[FFI]: Open the link failed: {}", e);
+            post_error_to_flutter(e.into(), port);
+        },
+    }
 }
 
 #[no_mangle]
 pub extern "C" fn sync_command(input: *const u8, len: usize) -> *const u8 {
-    let request: ModuleRequest = FFIRequest::from_u8_pointer(input, len).into();
-    log::trace!("[FFI]: {} Sync Event: {:?}", &request.id, &request.event,);
-    let _response = EventDispatch::sync_send(dispatch(), request);
+    match FFIRequest::from_u8_pointer(input, len) {
+        Ok(request) => {
+            let request: ModuleRequest = request.into();
+            log::trace!("[FFI]: {} Sync Event: {:?}", &request.id, &request.event,);
+            let _response = EventDispatch::sync_send(dispatch(), request);
+        },
+        Err(e) => log::error!("[FFI]: Decode the sync event failed: {:?}", e),
+    }
 
     // FFIResponse {  }
     let response_bytes = vec![];
@@ -59,6 +90,39 @@ pub extern "C" fn sync_command(input: *const u8, len: usize) -> *const u8 {
     forget_rust(result)
 }
 
+// Serves the events to the local processes at the path, a unix socket or a
+// named pipe on windows, see IpcServer. It's off until it's started, starting
+// it again stops the previous one.
+#[no_mangle]
+pub extern "C" fn start_ipc_server(path: *mut c_char) -> i32 {
+    let c_str: &CStr = unsafe { CStr::from_ptr(path) };
+    let path = match c_str.to_str() {
+        Ok(path) => path,
+        Err(e) => {
+            log::error!("[FFI]: Invalid ipc path: {:?}", e);
+            return -1;
+        },
+    };
+    let mut server = IPC_SERVER.lock();
+    server.take();
+    match IpcServer::start(dispatch(), path) {
+        Ok(started) => {
+            *server = Some(started);
+            0
+        },
+        Err(e) => {
+            log::error!("[FFI]: Start the ipc server failed: {}", e);
+            -1
+        },
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn stop_ipc_server() -> i32 {
+    IPC_SERVER.lock().take();
+    0
+}
+
 #[no_mangle]
 pub extern "C" fn set_stream_port(port: i64) -> i32 {
     flowy_dart_notify::dart::DartStreamSender::set_port(port);
@@ -72,13 +136,36 @@ pub extern "C" fn link_me_please() {}
 use flowy_dispatch::prelude::ToBytes;
 use flowy_net::config::ServerConfig;
 
+fn send_to_flutter(request: ModuleRequest, port: i64, deserialize_at: Instant) {
+    log::trace!(
+        "[FFI]: {} Async Event: {:?} with {} port",
+        &request.id,
+        &request.event,
+        port
+    );
+
+    let deserialization = deserialize_at.elapsed();
+    let _ = EventDispatch::async_send_with_trace(dispatch(), request, move |resp: EventResponse, trace| {
+        log::trace!("[FFI]: Post data to dart through {} port", port);
+        Box::pin(post_to_flutter(resp, port, deserialization, trace))
+    });
+}
+
+// The request couldn't be decoded, the dart side is still waiting on the port.
+fn post_error_to_flutter(error: DispatchError, port: i64) {
+    let bytes = FFIResponse::encode(&EventResponse::from(error));
+    let _ = allo_isolate::Isolate::new(port).post(bytes);
+}
+
 #[inline(always)]
-async fn post_to_flutter(response: EventResponse, port: i64) {
+async fn post_to_flutter(response: EventResponse, port: i64, deserialization: Duration, trace: DispatchTrace) {
     let isolate = allo_isolate::Isolate::new(port);
     match isolate
-        .catch_unwind(async {
-            let ffi_resp = FFIResponse::from(response);
-            ffi_resp.into_bytes().unwrap().to_vec()
+        .catch_unwind(async move {
+            let serialize_at = Instant::now();
+            let bytes = FFIResponse::encode(&response);
+            tracer().record(trace, deserialization + serialize_at.elapsed());
+            bytes
         })
         .await
     {
@@ -0,0 +1,121 @@
+use crate::{
+    async_command,
+    c::reclaim_rust,
+    init_sdk,
+    model::{FFIRequest, FFIResponse},
+    sync_command,
+};
+use allo_isolate::ffi::{DartCObject, DartCObjectType, DartPort};
+use byteorder::{BigEndian, ByteOrder};
+use bytes::Bytes;
+use flowy_dispatch::prelude::ToBytes;
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use std::{
+    collections::HashMap,
+    convert::TryFrom,
+    ffi::CString,
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        mpsc,
+        Once,
+    },
+    time::Duration,
+};
+
+lazy_static! {
+    static ref PORTS: Mutex<HashMap<DartPort, mpsc::Sender<Vec<u8>>>> = Mutex::new(HashMap::new());
+}
+
+static INIT: Once = Once::new();
+static NEXT_PORT: AtomicI64 = AtomicI64::new(1);
+
+// Stands in for the flutter host, the requests go through the same entry
+// points the dart side calls: they're encoded, dispatched, posted to a port and
+// decoded again, so the framing and the ownership of the buffers at the
+// boundary are tested without flutter.
+//
+//     let loopback = Loopback::init(&root);
+//     let response = loopback.async_command(SignUp, request).unwrap();
+pub struct Loopback {
+    timeout: Duration,
+}
+
+impl Loopback {
+    // The sdk is initialized once per process, with the root of the first call.
+    pub fn init(root: &str) -> Self {
+        INIT.call_once(|| {
+            allo_isolate::store_dart_post_cobject(post_cobject);
+            let root = CString::new(root).unwrap();
+            init_sdk(root.into_raw());
+        });
+        Self {
+            timeout: Duration::from_secs(10),
+        }
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    // The response that was posted to the port, None if it wasn't posted
+    // before the timeout.
+    pub fn async_command<E: ToString, P: ToBytes>(&self, event: E, payload: P) -> Option<FFIResponse> {
+        let payload = payload.into_bytes().unwrap().to_vec();
+        self.async_command_bytes(&encode_request(event, payload))
+    }
+
+    pub fn async_event<E: ToString>(&self, event: E) -> Option<FFIResponse> {
+        self.async_command_bytes(&encode_request(event, vec![]))
+    }
+
+    // Sends the bytes as they are, e.g. a malformed request.
+    pub fn async_command_bytes(&self, bytes: &[u8]) -> Option<FFIResponse> {
+        let port = NEXT_PORT.fetch_add(1, Ordering::SeqCst);
+        let (sender, receiver) = mpsc::channel();
+        PORTS.lock().insert(port, sender);
+
+        async_command(port, bytes.as_ptr(), bytes.len());
+        let posted = receiver.recv_timeout(self.timeout).ok();
+        PORTS.lock().remove(&port);
+        posted.and_then(|bytes| FFIResponse::try_from(Bytes::from(bytes)).ok())
+    }
+
+    // The bytes that sync_command returned, without their length.
+    pub fn sync_event<E: ToString>(&self, event: E) -> Vec<u8> {
+        let bytes = encode_request(event, vec![]);
+        let ptr = sync_command(bytes.as_ptr(), bytes.len()) as *mut u8;
+        let len = BigEndian::read_u32(unsafe { std::slice::from_raw_parts(ptr, 4) });
+        let output = unsafe { std::slice::from_raw_parts(ptr.add(4), len as usize) }.to_vec();
+        reclaim_rust(ptr, len + 4);
+        output
+    }
+}
+
+fn encode_request<E: ToString>(event: E, payload: Vec<u8>) -> Vec<u8> {
+    let request = FFIRequest {
+        event: event.to_string(),
+        payload,
+    };
+    request.into_bytes().unwrap().to_vec()
+}
+
+// The bytes are copied before it returns, like the dart runtime does.
+unsafe extern "C" fn post_cobject(port: DartPort, message: *mut DartCObject) -> bool {
+    let message = &*message;
+    let bytes = match message.ty {
+        DartCObjectType::DartTypedData => {
+            let data = message.value.as_typed_data;
+            std::slice::from_raw_parts(data.values, data.length as usize).to_vec()
+        },
+        _ => {
+            log::error!("[Loopback]: Unexpected message type posted to {}", port);
+            return false;
+        },
+    };
+    match PORTS.lock().get(&port) {
+        Some(sender) => sender.send(bytes).is_ok(),
+        None => false,
+    }
+}
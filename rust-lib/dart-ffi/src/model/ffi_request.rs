@@ -1,8 +1,26 @@
-use bytes::Bytes;
-use flowy_derive::ProtoBuf;
-use flowy_dispatch::prelude::ModuleRequest;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_dispatch::prelude::{pooled_bytes, take_buffer, DispatchError, ModuleRequest, PayloadEncoding};
 use std::convert::TryFrom;
 
+#[derive(ProtoBuf_Enum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FFIPayloadEncoding {
+    Native = 0,
+    Cbor   = 1,
+}
+
+impl std::default::Default for FFIPayloadEncoding {
+    fn default() -> FFIPayloadEncoding { FFIPayloadEncoding::Native }
+}
+
+impl std::convert::From<FFIPayloadEncoding> for PayloadEncoding {
+    fn from(encoding: FFIPayloadEncoding) -> Self {
+        match encoding {
+            FFIPayloadEncoding::Native => PayloadEncoding::Native,
+            FFIPayloadEncoding::Cbor => PayloadEncoding::Cbor,
+        }
+    }
+}
+
 #[derive(Default, ProtoBuf)]
 pub struct FFIRequest {
     #[pb(index = 1)]
@@ -10,17 +28,51 @@ pub struct FFIRequest {
 
     #[pb(index = 2)]
     pub(crate) payload: Vec<u8>,
+
+    #[pb(index = 3)]
+    pub(crate) encoding: FFIPayloadEncoding,
 }
 
 impl FFIRequest {
-    pub fn from_u8_pointer(pointer: *const u8, len: usize) -> Self {
-        let buffer = unsafe { std::slice::from_raw_parts(pointer, len) }.to_vec();
-        let bytes = Bytes::from(buffer);
-        let request: FFIRequest = FFIRequest::try_from(bytes).unwrap();
-        request
+    pub fn new(event: &str, payload: Vec<u8>) -> Self {
+        Self {
+            event: event.to_owned(),
+            payload,
+            encoding: FFIPayloadEncoding::Native,
+        }
+    }
+
+    pub fn encoding(mut self, encoding: FFIPayloadEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    pub fn from_u8_pointer(pointer: *const u8, len: usize) -> Result<Self, DispatchError> {
+        if pointer.is_null() {
+            return Err(String::from("The ffi request is null").into());
+        }
+        let buffer = unsafe { std::slice::from_raw_parts(pointer, len) };
+        Self::from_bytes(buffer)
+    }
+
+    // The bytes come from the frontend, they are not trusted. They're parsed
+    // in place, without copying them first. The payload is read into a buffer
+    // of the pool, see take_buffer.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DispatchError> {
+        let mut pb = crate::protobuf::FFIRequest::new();
+        pb.set_payload(take_buffer(bytes.len()));
+        ::protobuf::Message::merge_from_bytes(&mut pb, bytes)?;
+        let payload = pb.take_payload();
+        let mut request = FFIRequest::try_from(&mut pb)?;
+        request.payload = payload;
+        Ok(request)
     }
 }
 
 impl std::convert::Into<ModuleRequest> for FFIRequest {
-    fn into(self) -> ModuleRequest { ModuleRequest::new(self.event).payload(self.payload) }
+    fn into(self) -> ModuleRequest {
+        ModuleRequest::new(self.event)
+            .payload(pooled_bytes(self.payload))
+            .encoding(self.encoding.into())
+    }
 }
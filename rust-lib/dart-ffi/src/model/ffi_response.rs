@@ -1,7 +1,7 @@
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
-use flowy_dispatch::prelude::{EventResponse, Payload, StatusCode};
+use flowy_dispatch::prelude::{EventResponse, StatusCode};
 
-#[derive(ProtoBuf_Enum, Clone, Copy)]
+#[derive(ProtoBuf_Enum, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum FFIStatusCode {
     Ok       = 0,
     Err      = 1,
@@ -21,18 +21,51 @@ pub struct FFIResponse {
     code: FFIStatusCode,
 }
 
+impl FFIResponse {
+    pub fn payload(&self) -> &[u8] { &self.payload }
+
+    pub fn code(&self) -> FFIStatusCode { self.code }
+
+    // The bytes of the FFIResponse of the response, written straight into the
+    // vector that's handed to the dart side instead of copying the payload into
+    // a FFIResponse and its encoding into a vector. It's the only allocation,
+    // the dart side owns it.
+    pub fn encode(response: &EventResponse) -> Vec<u8> {
+        let payload = response.payload.as_bytes().unwrap_or_default();
+        let code = status_code(&response.status_code);
+        let mut bytes = Vec::with_capacity(payload.len() + 16);
+        if let Err(e) = write_response(&mut bytes, payload, code) {
+            log::error!("[FFI]: Encode the response failed: {:?}", e);
+        }
+        bytes
+    }
+}
+
+// The fields are written like the generated FFIResponse does, the default
+// values are skipped.
+fn write_response(bytes: &mut Vec<u8>, payload: &[u8], code: FFIStatusCode) -> ::protobuf::ProtobufResult<()> {
+    let mut os = ::protobuf::CodedOutputStream::vec(bytes);
+    if !payload.is_empty() {
+        os.write_bytes(1, payload)?;
+    }
+    if code != FFIStatusCode::Ok {
+        os.write_enum(2, code as i32)?;
+    }
+    os.flush()
+}
+
+fn status_code(status_code: &StatusCode) -> FFIStatusCode {
+    match status_code {
+        StatusCode::Ok => FFIStatusCode::Ok,
+        StatusCode::Err => FFIStatusCode::Err,
+        StatusCode::Internal => FFIStatusCode::Internal,
+    }
+}
+
 impl std::convert::From<EventResponse> for FFIResponse {
     fn from(resp: EventResponse) -> Self {
-        let payload = match resp.payload {
-            Payload::Bytes(bytes) => bytes.to_vec(),
-            Payload::None => vec![],
-        };
-
-        let code = match resp.status_code {
-            StatusCode::Ok => FFIStatusCode::Ok,
-            StatusCode::Err => FFIStatusCode::Err,
-            StatusCode::Internal => FFIStatusCode::Internal,
-        };
+        let payload = resp.payload.as_bytes().map(<[u8]>::to_vec).unwrap_or_default();
+        let code = status_code(&resp.status_code);
 
         // let msg = match resp.error {
         //     None => "".to_owned(),
@@ -28,6 +28,7 @@ pub struct FFIRequest {
     // message fields
     pub event: ::std::string::String,
     pub payload: ::std::vec::Vec<u8>,
+    pub encoding: FFIPayloadEncoding,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -95,6 +96,21 @@ impl FFIRequest {
     pub fn take_payload(&mut self) -> ::std::vec::Vec<u8> {
         ::std::mem::replace(&mut self.payload, ::std::vec::Vec::new())
     }
+
+    // .FFIPayloadEncoding encoding = 3;
+
+
+    pub fn get_encoding(&self) -> FFIPayloadEncoding {
+        self.encoding
+    }
+    pub fn clear_encoding(&mut self) {
+        self.encoding = FFIPayloadEncoding::Native;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_encoding(&mut self, v: FFIPayloadEncoding) {
+        self.encoding = v;
+    }
 }
 
 impl ::protobuf::Message for FFIRequest {
@@ -112,6 +128,9 @@ impl ::protobuf::Message for FFIRequest {
                 2 => {
                     ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.payload)?;
                 },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.encoding, 3, &mut self.unknown_fields)?
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -130,6 +149,9 @@ impl ::protobuf::Message for FFIRequest {
         if !self.payload.is_empty() {
             my_size += ::protobuf::rt::bytes_size(2, &self.payload);
         }
+        if self.encoding != FFIPayloadEncoding::Native {
+            my_size += ::protobuf::rt::enum_size(3, self.encoding);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -142,6 +164,9 @@ impl ::protobuf::Message for FFIRequest {
         if !self.payload.is_empty() {
             os.write_bytes(2, &self.payload)?;
         }
+        if self.encoding != FFIPayloadEncoding::Native {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.encoding))?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -190,6 +215,11 @@ impl ::protobuf::Message for FFIRequest {
                 |m: &FFIRequest| { &m.payload },
                 |m: &mut FFIRequest| { &mut m.payload },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<FFIPayloadEncoding>>(
+                "encoding",
+                |m: &FFIRequest| { &m.encoding },
+                |m: &mut FFIRequest| { &mut m.encoding },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<FFIRequest>(
                 "FFIRequest",
                 fields,
@@ -208,6 +238,7 @@ impl ::protobuf::Clear for FFIRequest {
     fn clear(&mut self) {
         self.event.clear();
         self.payload.clear();
+        self.encoding = FFIPayloadEncoding::Native;
         self.unknown_fields.clear();
     }
 }
@@ -224,17 +255,62 @@ impl ::protobuf::reflect::ProtobufValue for FFIRequest {
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum FFIPayloadEncoding {
+    Native = 0,
+    Cbor = 1,
+}
+
+impl ::protobuf::ProtobufEnum for FFIPayloadEncoding {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<FFIPayloadEncoding> {
+        match value {
+            0 => ::std::option::Option::Some(FFIPayloadEncoding::Native),
+            1 => ::std::option::Option::Some(FFIPayloadEncoding::Cbor),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [FFIPayloadEncoding] = &[
+            FFIPayloadEncoding::Native,
+            FFIPayloadEncoding::Cbor,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<FFIPayloadEncoding>("FFIPayloadEncoding", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for FFIPayloadEncoding {
+}
+
+impl ::std::default::Default for FFIPayloadEncoding {
+    fn default() -> Self {
+        FFIPayloadEncoding::Native
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FFIPayloadEncoding {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x11ffi_request.proto\"<\n\nFFIRequest\x12\x14\n\x05event\x18\x01\x20\
-    \x01(\tR\x05event\x12\x18\n\x07payload\x18\x02\x20\x01(\x0cR\x07payloadJ\
-    \x98\x01\n\x06\x12\x04\0\0\x05\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\
-    \x02\x04\0\x12\x04\x02\0\x05\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x12\
-    \n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x15\n\x0c\n\x05\x04\0\x02\0\x05\
-    \x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x10\n\x0c\
-    \n\x05\x04\0\x02\0\x03\x12\x03\x03\x13\x14\n\x0b\n\x04\x04\0\x02\x01\x12\
-    \x03\x04\x04\x16\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\t\n\x0c\n\
-    \x05\x04\0\x02\x01\x01\x12\x03\x04\n\x11\n\x0c\n\x05\x04\0\x02\x01\x03\
-    \x12\x03\x04\x14\x15b\x06proto3\
+    \n\x11ffi_request.proto\"u\n\nFFIRequest\x12\x16\n\x05event\x18\x01\x20\
+    \x01(\tR\x05eventB\0\x12\x1a\n\x07payload\x18\x02\x20\x01(\x0cR\x07paylo\
+    adB\0\x121\n\x08encoding\x18\x03\x20\x01(\x0e2\x13.FFIPayloadEncodingR\
+    \x08encodingB\0:\0*,\n\x12FFIPayloadEncoding\x12\n\n\x06Native\x10\0\x12\
+    \x08\n\x04Cbor\x10\x01\x1a\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
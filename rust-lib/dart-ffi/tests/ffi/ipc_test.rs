@@ -0,0 +1,93 @@
+use dart_ffi::{
+    ipc::{IpcServer, NOTIFICATION_FRAME, RESPONSE_FRAME},
+    model::{FFIRequest, FFIResponse, FFIStatusCode},
+};
+use flowy_dart_notify::{dart::DartStreamSender, entities::SubscribeObject};
+use flowy_dispatch::prelude::*;
+use std::{
+    convert::TryFrom,
+    io::{Read, Write},
+    os::unix::{fs::PermissionsExt, net::UnixStream},
+    sync::Arc,
+    time::Duration,
+};
+
+async fn echo(data: String) -> String { data }
+
+fn write_request(stream: &mut UnixStream, id: u32, event: &str, payload: &[u8]) {
+    let request = FFIRequest::new(event, payload.to_vec());
+    let body = request.into_bytes().unwrap();
+    let len = (body.len() + 4) as u32;
+    stream.write_all(&len.to_be_bytes()).unwrap();
+    stream.write_all(&id.to_be_bytes()).unwrap();
+    stream.write_all(&body).unwrap();
+}
+
+fn read_frame(stream: &mut UnixStream) -> Vec<u8> {
+    let mut len = [0u8; 4];
+    stream.read_exact(&mut len).unwrap();
+    let mut body = vec![0; u32::from_be_bytes(len) as usize];
+    stream.read_exact(&mut body).unwrap();
+    body
+}
+
+#[test]
+fn ipc_server_forwards_the_frames() {
+    let dispatch = Arc::new(EventDispatch::construct(|| vec![Module::new().event("ipc/echo", echo)]));
+    // The paths of the sockets are short, the root could be too long.
+    let path = std::env::temp_dir().join(format!("flowy_ipc_{}.sock", std::process::id()));
+    let path = path.to_str().unwrap().to_owned();
+    let server = IpcServer::start(dispatch.clone(), &path).unwrap();
+    assert!(IpcServer::start(dispatch.clone(), &path).is_err());
+
+    // Only the user can connect, the socket isn't left in the directory it was
+    // bound in.
+    let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+    let pid = std::process::id();
+    let private_dir = std::env::temp_dir().join(format!(".flowy_ipc_{}.sock.{}", pid, pid));
+    assert!(!private_dir.exists());
+
+    let mut stream = UnixStream::connect(&path).unwrap();
+    stream.set_read_timeout(Some(Duration::from_secs(10))).unwrap();
+    write_request(&mut stream, 7, "ipc/echo", b"hello");
+    write_request(&mut stream, 8, "ipc/unknown", b"");
+
+    let mut responses = vec![];
+    while responses.len() < 2 {
+        let frame = read_frame(&mut stream);
+        if frame[0] == RESPONSE_FRAME {
+            let id = u32::from_be_bytes([frame[1], frame[2], frame[3], frame[4]]);
+            let response = FFIResponse::try_from(bytes::Bytes::copy_from_slice(&frame[5..])).unwrap();
+            responses.push((id, response.code(), response.payload().to_vec()));
+        }
+    }
+    responses.sort_by_key(|(id, _, _)| *id);
+    assert_eq!(responses[0], (7, FFIStatusCode::Ok, b"hello".to_vec()));
+    assert_eq!((responses[1].0, responses[1].1), (8, FFIStatusCode::Internal));
+
+    // The notifications are streamed to the connection.
+    std::thread::sleep(Duration::from_millis(100));
+    let subject = SubscribeObject {
+        source: "ipc".to_owned(),
+        ty: 1,
+        id: "ipc_subject".to_owned(),
+        payload: None,
+        error: None,
+    };
+    let _ = DartStreamSender::post(subject);
+    loop {
+        let frame = read_frame(&mut stream);
+        if frame[0] != NOTIFICATION_FRAME {
+            continue;
+        }
+        let subject = SubscribeObject::try_from(bytes::Bytes::copy_from_slice(&frame[1..])).unwrap();
+        if subject.id == "ipc_subject" {
+            break;
+        }
+    }
+
+    drop(server);
+    assert!(!std::path::Path::new(&path).exists());
+    std::mem::forget(dispatch);
+}
@@ -0,0 +1,41 @@
+use dart_ffi::{loopback::Loopback, model::FFIStatusCode};
+use flowy_test::prelude::{login_password, random_email, root_dir};
+use flowy_user::{
+    entities::{SignUpRequest, UserProfile},
+    event::UserEvent::*,
+};
+use std::convert::TryFrom;
+
+#[test]
+fn loopback_sign_up() {
+    let loopback = Loopback::init(&root_dir());
+    let email = random_email();
+    let request = SignUpRequest {
+        email: email.clone(),
+        name: "app flowy".to_owned(),
+        password: login_password(),
+    };
+    let response = loopback.async_command(SignUp, request).unwrap();
+    assert_eq!(response.code(), FFIStatusCode::Ok);
+
+    let user_profile = UserProfile::try_from(bytes::Bytes::copy_from_slice(response.payload())).unwrap();
+    assert_eq!(user_profile.email, email);
+}
+
+#[test]
+fn loopback_malformed_request() {
+    let loopback = Loopback::init(&root_dir());
+    let response = loopback.async_command_bytes(&[0xff, 0xff, 0xff]).unwrap();
+    assert_eq!(response.code(), FFIStatusCode::Internal);
+
+    let response = loopback.async_command_bytes(&[]).unwrap();
+    assert_eq!(response.code(), FFIStatusCode::Internal);
+}
+
+#[test]
+fn loopback_unknown_event() {
+    let loopback = Loopback::init(&root_dir());
+    let response = loopback.async_event("unknown_event").unwrap();
+    assert_eq!(response.code(), FFIStatusCode::Internal);
+    assert!(loopback.sync_event("unknown_event").is_empty());
+}
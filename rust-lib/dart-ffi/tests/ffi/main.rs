@@ -0,0 +1,5 @@
+#[cfg(unix)]
+mod ipc_test;
+mod loopback_test;
+mod request_test;
+mod response_test;
@@ -0,0 +1,39 @@
+use bytes::Bytes;
+use dart_ffi::model::{FFIPayloadEncoding, FFIRequest};
+use flowy_dispatch::prelude::*;
+use std::{convert::TryInto, mem::size_of};
+
+fn decode(request: FFIRequest) -> ModuleRequest {
+    let bytes: Bytes = request.try_into().unwrap();
+    FFIRequest::from_bytes(&bytes).unwrap().into()
+}
+
+#[test]
+fn request_encoding_test() {
+    let request = decode(FFIRequest::new("event", vec![1, 2, 3]));
+    assert_eq!(request.payload_encoding(), PayloadEncoding::Native);
+
+    let request = decode(FFIRequest::new("event", vec![1, 2, 3]).encoding(FFIPayloadEncoding::Cbor));
+    assert_eq!(request.payload_encoding(), PayloadEncoding::Cbor);
+    assert_eq!(request.payload_bytes(), Some(&[1u8, 2, 3][..]));
+}
+
+// The inline payload is stored in the request instead of in a buffer.
+fn is_inline(request: &ModuleRequest) -> bool {
+    let start = request as *const ModuleRequest as usize;
+    let payload = request.payload_bytes().unwrap().as_ptr() as usize;
+    payload >= start && payload < start + size_of::<ModuleRequest>()
+}
+
+#[test]
+fn request_inline_payload_test() {
+    let small = vec![1u8; INLINE_PAYLOAD_CAPACITY];
+    let request = decode(FFIRequest::new("event", small.clone()));
+    assert!(is_inline(&request));
+    assert_eq!(request.payload_bytes(), Some(&small[..]));
+
+    let large = vec![1u8; INLINE_PAYLOAD_CAPACITY + 1];
+    let request = decode(FFIRequest::new("event", large.clone()));
+    assert!(!is_inline(&request));
+    assert_eq!(request.payload_bytes(), Some(&large[..]));
+}
@@ -0,0 +1,33 @@
+use bytes::Bytes;
+use dart_ffi::model::{FFIResponse, FFIStatusCode};
+use flowy_dispatch::prelude::*;
+use std::convert::{TryFrom, TryInto};
+
+fn generated_encoding(response: EventResponse) -> Vec<u8> {
+    let bytes: Bytes = FFIResponse::from(response).try_into().unwrap();
+    bytes.to_vec()
+}
+
+#[test]
+fn response_encode_test() {
+    let responses = vec![
+        EventResponse::new(StatusCode::Ok),
+        ResponseBuilder::Ok().data("nathan").build(),
+        ResponseBuilder::Err().data(vec![7u8; 1024]).build(),
+        EventResponse::new(StatusCode::Internal),
+    ];
+    for response in responses {
+        let bytes = FFIResponse::encode(&response);
+        assert_eq!(bytes, generated_encoding(response.clone()));
+
+        let decoded = FFIResponse::try_from(Bytes::from(bytes)).unwrap();
+        assert_eq!(decoded.payload(), response.payload.as_bytes().unwrap_or_default());
+    }
+}
+
+#[test]
+fn response_encode_status_test() {
+    let bytes = FFIResponse::encode(&EventResponse::new(StatusCode::Err));
+    let decoded = FFIResponse::try_from(Bytes::from(bytes)).unwrap();
+    assert_eq!(decoded.code(), FFIStatusCode::Err);
+}
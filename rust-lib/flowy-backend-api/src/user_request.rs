@@ -1,4 +1,8 @@
-use flowy_net::{config::HEADER_TOKEN, errors::ServerError, request::HttpRequestBuilder};
+use flowy_net::{
+    config::HEADER_TOKEN,
+    errors::ServerError,
+    request::{HttpRequestBuilder, RetryConfig},
+};
 use flowy_user_infra::entities::prelude::*;
 
 pub(crate) fn request_builder() -> HttpRequestBuilder {
@@ -23,6 +27,15 @@ pub async fn user_sign_in_request(params: SignInParams, url: &str) -> Result<Sig
     Ok(response)
 }
 
+pub async fn oauth_sign_in_request(params: OAuthSignInParams, url: &str) -> Result<SignInResponse, ServerError> {
+    let response = request_builder()
+        .post(&url.to_owned())
+        .protobuf(params)?
+        .response()
+        .await?;
+    Ok(response)
+}
+
 pub async fn user_sign_out_request(token: &str, url: &str) -> Result<(), ServerError> {
     let _ = request_builder()
         .delete(&url.to_owned())
@@ -32,15 +45,48 @@ pub async fn user_sign_out_request(token: &str, url: &str) -> Result<(), ServerE
     Ok(())
 }
 
+pub async fn refresh_token_request(token: &str, url: &str) -> Result<RefreshTokenResponse, ServerError> {
+    let response = request_builder()
+        .put(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .response()
+        .await?;
+    Ok(response)
+}
+
+pub async fn update_password_request(
+    token: &str,
+    params: UpdatePasswordParams,
+    url: &str,
+) -> Result<UpdatePasswordResponse, ServerError> {
+    let response = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response()
+        .await?;
+    Ok(response)
+}
+
 pub async fn get_user_profile_request(token: &str, url: &str) -> Result<UserProfile, ServerError> {
     let user_profile = request_builder()
         .get(&url.to_owned())
+        .retry(RetryConfig::default())
         .header(HEADER_TOKEN, token)
         .response()
         .await?;
     Ok(user_profile)
 }
 
+pub async fn delete_account_request(token: &str, params: DeleteAccountParams, url: &str) -> Result<(), ServerError> {
+    request_builder()
+        .delete(url)
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await
+}
+
 pub async fn update_user_profile_request(token: &str, params: UpdateUserParams, url: &str) -> Result<(), ServerError> {
     let _ = request_builder()
         .patch(&url.to_owned())
@@ -50,3 +96,54 @@ pub async fn update_user_profile_request(token: &str, params: UpdateUserParams,
         .await?;
     Ok(())
 }
+
+pub async fn list_sessions_request(token: &str, url: &str) -> Result<RepeatedDeviceSession, ServerError> {
+    let sessions = request_builder()
+        .get(&url.to_owned())
+        .retry(RetryConfig::default())
+        .header(HEADER_TOKEN, token)
+        .response()
+        .await?;
+    Ok(sessions)
+}
+
+pub async fn revoke_session_request(token: &str, params: RevokeSessionParams, url: &str) -> Result<(), ServerError> {
+    request_builder()
+        .delete(url)
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await
+}
+
+pub async fn send_verification_email_request(token: &str, url: &str) -> Result<(), ServerError> {
+    request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .send()
+        .await
+}
+
+pub async fn get_verification_request(token: &str, url: &str) -> Result<EmailVerification, ServerError> {
+    let verification = request_builder()
+        .get(&url.to_owned())
+        .retry(RetryConfig::default())
+        .header(HEADER_TOKEN, token)
+        .response()
+        .await?;
+    Ok(verification)
+}
+
+pub async fn confirm_email_request(
+    token: &str,
+    params: ConfirmEmailParams,
+    url: &str,
+) -> Result<EmailVerification, ServerError> {
+    let verification = request_builder()
+        .put(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response()
+        .await?;
+    Ok(verification)
+}
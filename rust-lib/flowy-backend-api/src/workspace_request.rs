@@ -1,4 +1,8 @@
-use flowy_net::{config::HEADER_TOKEN, errors::ServerError, request::HttpRequestBuilder};
+use flowy_net::{
+    config::HEADER_TOKEN,
+    errors::ServerError,
+    request::{HttpRequestBuilder, RetryConfig},
+};
 use flowy_workspace_infra::entities::prelude::*;
 
 pub(crate) fn request_builder() -> HttpRequestBuilder {
@@ -26,6 +30,7 @@ pub async fn read_workspaces_request(
 ) -> Result<RepeatedWorkspace, ServerError> {
     let repeated_workspace = request_builder()
         .get(&url.to_owned())
+        .retry(RetryConfig::default())
         .header(HEADER_TOKEN, token)
         .protobuf(params)?
         .response::<RepeatedWorkspace>()
@@ -72,6 +77,7 @@ pub async fn create_app_request(token: &str, params: CreateAppParams, url: &str)
 pub async fn read_app_request(token: &str, params: AppIdentifier, url: &str) -> Result<Option<App>, ServerError> {
     let app = request_builder()
         .get(&url.to_owned())
+        .retry(RetryConfig::default())
         .header(HEADER_TOKEN, token)
         .protobuf(params)?
         .option_response()
@@ -114,6 +120,7 @@ pub async fn create_view_request(token: &str, params: CreateViewParams, url: &st
 pub async fn read_view_request(token: &str, params: ViewIdentifier, url: &str) -> Result<Option<View>, ServerError> {
     let view = request_builder()
         .get(&url.to_owned())
+        .retry(RetryConfig::default())
         .header(HEADER_TOKEN, token)
         .protobuf(params)?
         .option_response()
@@ -165,8 +172,24 @@ pub async fn delete_trash_request(token: &str, params: TrashIdentifiers, url: &s
 pub async fn read_trash_request(token: &str, url: &str) -> Result<RepeatedTrash, ServerError> {
     let repeated_trash = request_builder()
         .get(&url.to_owned())
+        .retry(RetryConfig::default())
         .header(HEADER_TOKEN, token)
         .response::<RepeatedTrash>()
         .await?;
     Ok(repeated_trash)
 }
+
+pub async fn pull_changes_request(
+    token: &str,
+    params: PullChangesParams,
+    url: &str,
+) -> Result<RepeatedRemoteChange, ServerError> {
+    let repeated_change = request_builder()
+        .get(&url.to_owned())
+        .retry(RetryConfig::default())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response::<RepeatedRemoteChange>()
+        .await?;
+    Ok(repeated_change)
+}
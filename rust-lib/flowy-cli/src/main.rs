@@ -0,0 +1,220 @@
+mod transport;
+
+use bytes::Bytes;
+use clap::{App, AppSettings, Arg, ArgMatches};
+use flowy_dispatch::prelude::*;
+use flowy_workspace::{entities::prelude::*, event::WorkspaceEvent};
+use std::{convert::TryFrom, io::Write};
+use transport::Transport;
+
+fn main() {
+    env_logger::init();
+    let matches = app().get_matches();
+    if let Err(e) = run(&matches) {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+pub fn app<'a, 'b>() -> App<'a, 'b> {
+    App::new("flowy-cli")
+        .version("0.1")
+        .about("Sends the events of the sdk from the terminal")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .arg(
+            Arg::with_name("socket")
+                .long("socket")
+                .value_name("PATH")
+                .global(true)
+                .help("The socket or the named pipe the running app serves the events on"),
+        )
+        .arg(
+            Arg::with_name("data_dir")
+                .long("data-dir")
+                .value_name("DIRECTORY")
+                .global(true)
+                .conflicts_with("socket")
+                .help("Runs the sdk against the data dir instead, the app mustn't be running"),
+        )
+        .subcommand(
+            App::new("send")
+                .about("Sends an event and writes the payload of its response to stdout")
+                .arg(
+                    Arg::with_name("event")
+                        .required(true)
+                        .help("The name of the event, e.g. ReadWorkspaces"),
+                )
+                .arg(
+                    Arg::with_name("payload")
+                        .long("payload")
+                        .value_name("FILE")
+                        .help("The file of the payload, - for stdin"),
+                ),
+        )
+        .subcommand(App::new("workspaces").about("Lists the workspaces of the user"))
+        .subcommand(
+            App::new("export")
+                .about("Exports a document")
+                .arg(Arg::with_name("doc_id").required(true))
+                .arg(
+                    Arg::with_name("format")
+                        .long("format")
+                        .value_name("FORMAT")
+                        .possible_values(&["text", "markdown", "link", "archive"])
+                        .default_value("markdown"),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .long("output")
+                        .value_name("FILE")
+                        .help("Writes the export to the file instead of stdout"),
+                ),
+        )
+        .subcommand(
+            App::new("maintenance")
+                .about("Checks and cleans the storage")
+                .setting(AppSettings::SubcommandRequiredElseHelp)
+                .subcommand(
+                    App::new("check").about("Checks the integrity of the storage").arg(
+                        Arg::with_name("repair")
+                            .long("repair")
+                            .help("Repairs the issues it finds"),
+                    ),
+                )
+                .subcommand(App::new("gc").about("Deletes the unreachable views, documents and blobs"))
+                .subcommand(App::new("purge-trash").about("Deletes the expired items of the trash"))
+                .subcommand(App::new("sync").about("Syncs the workspace with the server")),
+        )
+}
+
+fn run(matches: &ArgMatches) -> Result<(), String> {
+    let mut transport: Box<dyn Transport> = match (matches.value_of("socket"), matches.value_of("data_dir")) {
+        (Some(path), _) => Box::new(transport::connect(path)?),
+        (None, Some(data_dir)) => Box::new(transport::EmbeddedTransport::new(data_dir)?),
+        (None, None) => return Err("Either --socket or --data-dir is required".to_owned()),
+    };
+    let transport = transport.as_mut();
+
+    match matches.subcommand() {
+        ("send", Some(matches)) => {
+            let payload = match matches.value_of("payload") {
+                Some(path) => read_payload(path)?,
+                None => vec![],
+            };
+            let payload = transport.send(matches.value_of("event").unwrap(), payload)?;
+            std::io::stdout().write_all(&payload).map_err(|e| e.to_string())
+        },
+        ("workspaces", _) => {
+            let workspaces: RepeatedWorkspace = send(
+                transport,
+                WorkspaceEvent::ReadWorkspaces,
+                QueryWorkspaceRequest::new(None),
+            )?;
+            for workspace in workspaces.items {
+                println!("{}\t{}", workspace.id, workspace.name);
+            }
+            Ok(())
+        },
+        ("export", Some(matches)) => export(transport, matches),
+        ("maintenance", Some(matches)) => maintenance(transport, matches),
+        _ => Ok(()),
+    }
+}
+
+fn export(transport: &mut dyn Transport, matches: &ArgMatches) -> Result<(), String> {
+    let export_type = match matches.value_of("format").unwrap() {
+        "text" => ExportType::Text,
+        "link" => ExportType::Link,
+        "archive" => ExportType::Archive,
+        _ => ExportType::Markdown,
+    };
+    let request = ExportRequest {
+        doc_id: matches.value_of("doc_id").unwrap().to_owned(),
+        export_type,
+    };
+    let data: ExportData = send(transport, WorkspaceEvent::ExportDocument, request)?;
+    match (matches.value_of("output"), data.path.is_empty()) {
+        (Some(output), true) => std::fs::write(output, data.data).map_err(|e| e.to_string()),
+        (Some(output), false) => std::fs::copy(&data.path, output).map(|_| ()).map_err(|e| e.to_string()),
+        (None, true) => std::io::stdout()
+            .write_all(data.data.as_bytes())
+            .map_err(|e| e.to_string()),
+        // The large exports are already in a file.
+        (None, false) => {
+            println!("{}", data.path);
+            Ok(())
+        },
+    }
+}
+
+fn maintenance(transport: &mut dyn Transport, matches: &ArgMatches) -> Result<(), String> {
+    match matches.subcommand() {
+        ("check", Some(matches)) => {
+            let request = CheckStorageIntegrityRequest {
+                repair: matches.is_present("repair"),
+            };
+            let report: StorageIntegrityReport = send(transport, WorkspaceEvent::CheckStorageIntegrity, request)?;
+            for error in &report.database_errors {
+                println!("database: {}", error);
+            }
+            for issue in &report.issues {
+                let repaired = if issue.repaired { "repaired" } else { "not repaired" };
+                println!("{:?} {}: {}", issue.ty, issue.id, repaired);
+            }
+            if !report.is_healthy() {
+                return Err("The storage isn't healthy".to_owned());
+            }
+            println!("The storage is healthy");
+            Ok(())
+        },
+        ("gc", _) => {
+            let result: GarbageCollectionResult = send_empty(transport, WorkspaceEvent::RunGarbageCollection)?;
+            println!(
+                "Deleted {} views, {} documents and {} blobs of {} bytes",
+                result.view_ids.len(),
+                result.doc_ids.len(),
+                result.blob_count,
+                result.blob_size
+            );
+            Ok(())
+        },
+        ("purge-trash", _) => transport
+            .send(&WorkspaceEvent::PurgeTrash.to_string(), vec![])
+            .map(|_| ()),
+        ("sync", _) => {
+            let result: SyncResult = send_empty(transport, WorkspaceEvent::SyncWorkspace)?;
+            println!(
+                "Pushed {} and pulled {} changes, {:?}",
+                result.pushed, result.pulled, result.state
+            );
+            Ok(())
+        },
+        _ => Ok(()),
+    }
+}
+
+fn send<Req, Res>(transport: &mut dyn Transport, event: WorkspaceEvent, request: Req) -> Result<Res, String>
+where
+    Req: ToBytes,
+    Res: TryFrom<Bytes>,
+{
+    let payload = request.into_bytes().map_err(|e| format!("{:?}", e))?;
+    parse(transport.send(&event.to_string(), payload.to_vec())?)
+}
+
+fn send_empty<Res: TryFrom<Bytes>>(transport: &mut dyn Transport, event: WorkspaceEvent) -> Result<Res, String> {
+    parse(transport.send(&event.to_string(), vec![])?)
+}
+
+fn parse<Res: TryFrom<Bytes>>(payload: Vec<u8>) -> Result<Res, String> {
+    Res::try_from(Bytes::from(payload)).map_err(|_| "Invalid response".to_owned())
+}
+
+fn read_payload(path: &str) -> Result<Vec<u8>, String> {
+    if path == "-" {
+        let mut payload = vec![];
+        std::io::Read::read_to_end(&mut std::io::stdin(), &mut payload).map_err(|e| e.to_string())?;
+        return Ok(payload);
+    }
+    std::fs::read(path).map_err(|e| format!("Read {} failed: {}", path, e))
+}
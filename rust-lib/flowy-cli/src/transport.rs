@@ -0,0 +1,123 @@
+use bytes::Bytes;
+use dart_ffi::{
+    ipc::{MAX_FRAME_SIZE, RESPONSE_FRAME},
+    model::{FFIRequest, FFIResponse, FFIStatusCode},
+};
+use flowy_dispatch::prelude::*;
+use flowy_net::config::ServerConfig;
+use flowy_sdk::{FlowySDK, FlowySDKConfig};
+use flowy_workspace::errors::WorkspaceError;
+use std::{
+    convert::TryFrom,
+    io::{Read, Write},
+};
+
+// Sends the events, either to the app that serves them on its socket or to an
+// sdk the cli runs itself against a data dir. Returns the payload of the
+// response, or the message of its error.
+pub trait Transport {
+    fn send(&mut self, event: &str, payload: Vec<u8>) -> Result<Vec<u8>, String>;
+}
+
+// Talks to the IpcServer of a running app. The notifications streamed on the
+// connection are skipped.
+pub struct IpcTransport<S> {
+    stream: S,
+    next_id: u32,
+}
+
+#[cfg(unix)]
+pub fn connect(path: &str) -> Result<IpcTransport<std::os::unix::net::UnixStream>, String> {
+    let stream =
+        std::os::unix::net::UnixStream::connect(path).map_err(|e| format!("Connect {} failed: {}", path, e))?;
+    Ok(IpcTransport { stream, next_id: 0 })
+}
+
+// The named pipes are opened like files.
+#[cfg(windows)]
+pub fn connect(path: &str) -> Result<IpcTransport<std::fs::File>, String> {
+    let stream = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(path)
+        .map_err(|e| format!("Connect {} failed: {}", path, e))?;
+    Ok(IpcTransport { stream, next_id: 0 })
+}
+
+impl<S: Read + Write> IpcTransport<S> {
+    fn read_frame(&mut self) -> Result<Vec<u8>, String> {
+        let mut len = [0u8; 4];
+        self.stream.read_exact(&mut len).map_err(|e| e.to_string())?;
+        let len = u32::from_be_bytes(len) as usize;
+        if len > MAX_FRAME_SIZE {
+            return Err(format!("The frame of {} bytes is too big", len));
+        }
+        let mut body = vec![0; len];
+        self.stream.read_exact(&mut body).map_err(|e| e.to_string())?;
+        Ok(body)
+    }
+}
+
+impl<S: Read + Write> Transport for IpcTransport<S> {
+    fn send(&mut self, event: &str, payload: Vec<u8>) -> Result<Vec<u8>, String> {
+        self.next_id += 1;
+        let id = self.next_id;
+        let body = FFIRequest::new(event, payload)
+            .into_bytes()
+            .map_err(|e| format!("{:?}", e))?;
+        let mut frame = Vec::with_capacity(8 + body.len());
+        frame.extend_from_slice(&((body.len() + 4) as u32).to_be_bytes());
+        frame.extend_from_slice(&id.to_be_bytes());
+        frame.extend_from_slice(&body);
+        self.stream.write_all(&frame).map_err(|e| e.to_string())?;
+
+        loop {
+            let frame = self.read_frame()?;
+            if frame.len() < 5 || frame[0] != RESPONSE_FRAME {
+                continue;
+            }
+            if u32::from_be_bytes([frame[1], frame[2], frame[3], frame[4]]) != id {
+                continue;
+            }
+            let response =
+                FFIResponse::try_from(Bytes::copy_from_slice(&frame[5..])).map_err(|e| format!("{:?}", e))?;
+            return into_result(response);
+        }
+    }
+}
+
+// Runs the sdk in the process. The app mustn't use the data dir meanwhile.
+pub struct EmbeddedTransport {
+    sdk: FlowySDK,
+}
+
+impl EmbeddedTransport {
+    pub fn new(data_dir: &str) -> Result<Self, String> {
+        std::fs::create_dir_all(data_dir).map_err(|e| format!("Create {} failed: {}", data_dir, e))?;
+        let config = FlowySDKConfig::new(data_dir, ServerConfig::default(), "appflowy");
+        Ok(Self {
+            sdk: FlowySDK::new(config),
+        })
+    }
+}
+
+impl Transport for EmbeddedTransport {
+    fn send(&mut self, event: &str, payload: Vec<u8>) -> Result<Vec<u8>, String> {
+        let request = ModuleRequest::new(event.to_owned()).payload(payload);
+        let response = EventDispatch::sync_send(self.sdk.dispatch(), request);
+        into_result(FFIResponse::from(response))
+    }
+}
+
+// The errors of the modules are WorkspaceErrors or UserErrors, their messages
+// are in the same field. The internal errors are plain text.
+fn into_result(response: FFIResponse) -> Result<Vec<u8>, String> {
+    match response.code() {
+        FFIStatusCode::Ok => Ok(response.payload().to_vec()),
+        FFIStatusCode::Err => match WorkspaceError::try_from(Bytes::copy_from_slice(response.payload())) {
+            Ok(error) => Err(error.msg),
+            Err(_) => Err(String::from_utf8_lossy(response.payload()).into_owned()),
+        },
+        FFIStatusCode::Internal => Err(String::from_utf8_lossy(response.payload()).into_owned()),
+    }
+}
@@ -1,12 +1,32 @@
 use crate::entities::SubscribeObject;
 use bytes::Bytes;
+use flowy_dispatch::prelude::{notification_center, Notification, NotificationFilter};
 use lazy_static::lazy_static;
-use std::{convert::TryInto, sync::RwLock};
+use std::{
+    convert::TryInto,
+    sync::{Arc, Once, RwLock},
+};
 
 lazy_static! {
     static ref DART_STREAM_SENDER: RwLock<DartStreamSender> = RwLock::new(DartStreamSender::new());
 }
 
+static SUBSCRIBE_ISOLATE: Once = Once::new();
+
+// Receives the subjects in the process, e.g. the tests that don't have a
+// flutter isolate. It's a subscriber of all the notifications of the
+// notification center.
+pub type SubjectListener = Arc<dyn Fn(&SubscribeObject) + Send + Sync>;
+
+// Returns the id to remove the listener with.
+pub fn add_subject_listener(listener: SubjectListener) -> u64 {
+    notification_center().subscribe(NotificationFilter::all(), move |notification| {
+        listener(&SubscribeObject::from(notification))
+    })
+}
+
+pub fn remove_subject_listener(id: u64) { notification_center().unsubscribe(id); }
+
 pub struct DartStreamSender {
     #[allow(dead_code)]
     isolate: Option<allo_isolate::Isolate>,
@@ -32,24 +52,37 @@ impl DartStreamSender {
         }
     }
 
+    // The isolate subscribes to all the notifications once it has a port.
     pub fn set_port(port: i64) {
         match DART_STREAM_SENDER.write() {
             Ok(mut stream) => stream.inner_set_port(port),
             Err(e) => {
                 let msg = format!("Get rust to flutter stream lock fail. {:?}", e);
                 log::error!("{:?}", msg);
+                return;
             },
         }
+        SUBSCRIBE_ISOLATE.call_once(|| {
+            notification_center().subscribe(NotificationFilter::all(), post_to_isolate);
+        });
     }
 
-    pub fn post(_observable_subject: SubscribeObject) -> Result<(), String> {
-        #[cfg(feature = "dart")]
-        match DART_STREAM_SENDER.read() {
-            Ok(stream) => stream.inner_post(_observable_subject),
-            Err(e) => Err(format!("Get rust to flutter stream lock fail. {:?}", e)),
-        }
-
-        #[cfg(not(feature = "dart"))]
+    pub fn post(observable_subject: SubscribeObject) -> Result<(), String> {
+        notification_center().publish(Notification::from(observable_subject));
         Ok(())
     }
 }
+
+#[cfg(feature = "dart")]
+fn post_to_isolate(notification: &Notification) {
+    let result = match DART_STREAM_SENDER.read() {
+        Ok(stream) => stream.inner_post(SubscribeObject::from(notification)),
+        Err(e) => Err(format!("Get rust to flutter stream lock fail. {:?}", e)),
+    };
+    if let Err(e) = result {
+        log::error!("Post the notification {} failed: {}", notification.id, e);
+    }
+}
+
+#[cfg(not(feature = "dart"))]
+fn post_to_isolate(_notification: &Notification) {}
@@ -1,4 +1,5 @@
 use flowy_derive::ProtoBuf;
+use flowy_dispatch::prelude::Notification;
 use std::{fmt, fmt::Formatter};
 
 #[derive(Debug, Clone, ProtoBuf)]
@@ -45,3 +46,27 @@ impl std::default::Default for SubscribeObject {
         }
     }
 }
+
+impl std::convert::From<&Notification> for SubscribeObject {
+    fn from(notification: &Notification) -> Self {
+        Self {
+            source: notification.source.clone(),
+            ty: notification.ty,
+            id: notification.id.clone(),
+            payload: notification.payload.as_ref().map(|payload| payload.to_vec()),
+            error: notification.error.as_ref().map(|error| error.to_vec()),
+        }
+    }
+}
+
+impl std::convert::From<SubscribeObject> for Notification {
+    fn from(subject: SubscribeObject) -> Self {
+        Self {
+            source: subject.source,
+            id: subject.id,
+            ty: subject.ty,
+            payload: subject.payload.map(Into::into),
+            error: subject.error.map(Into::into),
+        }
+    }
+}
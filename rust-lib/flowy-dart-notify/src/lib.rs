@@ -4,8 +4,7 @@ pub mod dart;
 pub mod entities;
 mod protobuf;
 
-use crate::{dart::DartStreamSender, entities::SubscribeObject};
-use flowy_dispatch::prelude::ToBytes;
+use flowy_dispatch::prelude::{notification_center, Notification, ToBytes};
 
 pub struct DartNotifyBuilder {
     id: String,
@@ -40,6 +39,12 @@ impl DartNotifyBuilder {
         self
     }
 
+    // The payload as it is, e.g. the one of a plugin that encodes it by itself.
+    pub fn payload_bytes(mut self, payload: Bytes) -> Self {
+        self.payload = Some(payload);
+        self
+    }
+
     pub fn error<T>(mut self, error: T) -> Self
     where
         T: ToBytes,
@@ -53,28 +58,15 @@ impl DartNotifyBuilder {
         self
     }
 
+    // Published to the notification center, its subscribers include the
+    // flutter isolate.
     pub fn send(self) {
-        let payload = match self.payload {
-            None => None,
-            Some(bytes) => Some(bytes.to_vec()),
-        };
-
-        let error = match self.error {
-            None => None,
-            Some(bytes) => Some(bytes.to_vec()),
-        };
-
-        let subject = SubscribeObject {
+        notification_center().publish(Notification {
             source: self.source,
-            ty: self.ty,
             id: self.id,
-            payload,
-            error,
-        };
-
-        match DartStreamSender::post(subject) {
-            Ok(_) => {},
-            Err(error) => log::error!("Send observable subject failed: {}", error),
-        }
+            ty: self.ty,
+            payload: self.payload,
+            error: self.error,
+        });
     }
 }
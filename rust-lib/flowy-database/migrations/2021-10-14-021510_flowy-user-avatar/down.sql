@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE user_table DROP COLUMN avatar;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- The cleared tokens can't be restored
+SELECT 1;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- The tokens are kept by the secret store
+UPDATE user_table SET token = '';
\ No newline at end of file
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+CREATE TABLE user_preference_table (
+    key TEXT NOT NULL PRIMARY KEY,
+    str_value TEXT,
+    int_value BIGINT,
+    float_value DOUBLE,
+    bool_value BOOLEAN
+);
@@ -0,0 +1,7 @@
+-- Your SQL goes here
+CREATE TABLE workspace_member_table (
+    id TEXT NOT NULL PRIMARY KEY,
+    workspace_id TEXT NOT NULL,
+    user_id TEXT NOT NULL,
+    role INTEGER NOT NULL DEFAULT 0
+);
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+CREATE TABLE doc_snapshot_table (
+    id TEXT NOT NULL PRIMARY KEY,
+    doc_id TEXT NOT NULL DEFAULT '',
+    rev_id BIGINT NOT NULL DEFAULT 0,
+    data TEXT NOT NULL DEFAULT '',
+    create_time BIGINT NOT NULL DEFAULT 0
+);
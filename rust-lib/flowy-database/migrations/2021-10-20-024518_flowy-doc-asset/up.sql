@@ -0,0 +1,9 @@
+-- Your SQL goes here
+CREATE TABLE doc_asset_table (
+    id TEXT NOT NULL PRIMARY KEY,
+    doc_id TEXT NOT NULL DEFAULT '',
+    name TEXT NOT NULL DEFAULT '',
+    mime_type TEXT NOT NULL DEFAULT '',
+    size BIGINT NOT NULL DEFAULT 0,
+    create_time BIGINT NOT NULL DEFAULT 0
+);
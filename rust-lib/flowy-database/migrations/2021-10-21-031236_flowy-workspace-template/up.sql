@@ -0,0 +1,8 @@
+-- Your SQL goes here
+CREATE TABLE template_table (
+    id TEXT NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL DEFAULT '',
+    desc TEXT NOT NULL DEFAULT '',
+    data TEXT NOT NULL DEFAULT '',
+    create_time BIGINT NOT NULL DEFAULT 0
+);
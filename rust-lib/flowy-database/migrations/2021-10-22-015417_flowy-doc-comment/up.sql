@@ -0,0 +1,12 @@
+-- Your SQL goes here
+CREATE TABLE doc_comment_table (
+    id TEXT NOT NULL PRIMARY KEY,
+    doc_id TEXT NOT NULL DEFAULT '',
+    user_id TEXT NOT NULL DEFAULT '',
+    content TEXT NOT NULL DEFAULT '',
+    start_offset BIGINT NOT NULL DEFAULT 0,
+    end_offset BIGINT NOT NULL DEFAULT 0,
+    resolved BOOLEAN NOT NULL DEFAULT FALSE,
+    create_time BIGINT NOT NULL DEFAULT 0,
+    modified_time BIGINT NOT NULL DEFAULT 0
+);
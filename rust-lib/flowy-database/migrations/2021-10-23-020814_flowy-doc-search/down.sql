@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP TRIGGER doc_search_table_delete;
+DROP TRIGGER doc_search_table_insert;
+DROP TABLE doc_search_fts;
+DROP TABLE doc_search_table;
\ No newline at end of file
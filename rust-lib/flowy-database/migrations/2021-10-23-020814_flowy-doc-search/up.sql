@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TABLE doc_search_table (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    doc_id TEXT NOT NULL DEFAULT '',
+    content TEXT NOT NULL DEFAULT ''
+);
+CREATE INDEX doc_search_table_doc_id ON doc_search_table (doc_id);
+
+-- The full text index of the lines in the doc_search_table, it's kept in sync
+-- by the triggers. The lines are only inserted or deleted, never updated.
+CREATE VIRTUAL TABLE doc_search_fts USING fts5(content, content = 'doc_search_table', content_rowid = 'id');
+CREATE TRIGGER doc_search_table_insert AFTER INSERT ON doc_search_table BEGIN
+    INSERT INTO doc_search_fts (rowid, content) VALUES (new.id, new.content);
+END;
+CREATE TRIGGER doc_search_table_delete AFTER DELETE ON doc_search_table BEGIN
+    INSERT INTO doc_search_fts (doc_search_fts, rowid, content) VALUES ('delete', old.id, old.content);
+END;
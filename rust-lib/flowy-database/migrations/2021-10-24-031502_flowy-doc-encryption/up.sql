@@ -0,0 +1,5 @@
+-- Your SQL goes here
+CREATE TABLE doc_encryption_table (
+    doc_id TEXT NOT NULL PRIMARY KEY,
+    create_time BIGINT NOT NULL DEFAULT 0
+);
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE trash_table DROP COLUMN deleted_time;
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE trash_table ADD COLUMN deleted_time BIGINT NOT NULL DEFAULT 0;
+UPDATE trash_table SET deleted_time = CAST(strftime('%s', 'now') AS BIGINT);
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE view_table DROP COLUMN order_key;
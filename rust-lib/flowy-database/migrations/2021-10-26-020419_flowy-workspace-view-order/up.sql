@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE view_table ADD COLUMN order_key TEXT NOT NULL DEFAULT '';
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+CREATE TABLE favorite_table (
+    id TEXT NOT NULL PRIMARY KEY,
+    user_id TEXT NOT NULL,
+    view_id TEXT NOT NULL,
+    order_key TEXT NOT NULL DEFAULT '',
+    create_time BIGINT NOT NULL DEFAULT 0
+);
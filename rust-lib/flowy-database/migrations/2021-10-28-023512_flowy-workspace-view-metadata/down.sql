@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE view_table DROP COLUMN icon;
+ALTER TABLE view_table DROP COLUMN cover;
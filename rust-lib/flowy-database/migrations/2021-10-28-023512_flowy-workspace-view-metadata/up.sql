@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE view_table ADD COLUMN icon TEXT NOT NULL DEFAULT '';
+ALTER TABLE view_table ADD COLUMN cover TEXT NOT NULL DEFAULT '';
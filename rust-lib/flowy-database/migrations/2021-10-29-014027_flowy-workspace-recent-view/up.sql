@@ -0,0 +1,7 @@
+-- Your SQL goes here
+CREATE TABLE recent_view_table (
+    id TEXT NOT NULL PRIMARY KEY,
+    user_id TEXT NOT NULL,
+    view_id TEXT NOT NULL,
+    opened_time BIGINT NOT NULL DEFAULT 0
+);
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+CREATE TABLE workspace_setting_table (
+    id TEXT NOT NULL PRIMARY KEY,
+    workspace_id TEXT NOT NULL,
+    key TEXT NOT NULL,
+    str_value TEXT,
+    int_value BIGINT,
+    float_value DOUBLE,
+    bool_value BOOLEAN
+);
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+CREATE TABLE workspace_invitation_table (
+    id TEXT NOT NULL PRIMARY KEY,
+    workspace_id TEXT NOT NULL,
+    email TEXT NOT NULL,
+    role INTEGER NOT NULL DEFAULT 0,
+    invited_time BIGINT NOT NULL DEFAULT 0
+);
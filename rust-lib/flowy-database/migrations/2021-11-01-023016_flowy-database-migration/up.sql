@@ -0,0 +1,5 @@
+-- Your SQL goes here
+CREATE TABLE migration_table (
+    version TEXT NOT NULL PRIMARY KEY,
+    applied_time BIGINT NOT NULL DEFAULT 0
+);
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+CREATE TABLE operation_table (
+    id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    ty INTEGER NOT NULL DEFAULT 0,
+    data BLOB NOT NULL DEFAULT (x''),
+    state INTEGER NOT NULL DEFAULT 0,
+    created_time BIGINT NOT NULL DEFAULT 0
+);
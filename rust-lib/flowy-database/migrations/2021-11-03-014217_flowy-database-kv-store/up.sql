@@ -0,0 +1,6 @@
+-- Your SQL goes here
+CREATE TABLE kv_store_table (
+    key TEXT NOT NULL PRIMARY KEY,
+    value TEXT NOT NULL DEFAULT '',
+    modified_time BIGINT NOT NULL DEFAULT 0
+);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE doc_asset_table DROP COLUMN blob_hash;
+DROP TABLE blob_ref_table;
+DROP TABLE blob_table;
@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE blob_table (
+    hash TEXT NOT NULL PRIMARY KEY,
+    size BIGINT NOT NULL DEFAULT 0,
+    create_time BIGINT NOT NULL DEFAULT 0
+);
+CREATE TABLE blob_ref_table (
+    blob_hash TEXT NOT NULL,
+    owner_id TEXT NOT NULL,
+    create_time BIGINT NOT NULL DEFAULT 0,
+    PRIMARY KEY (blob_hash, owner_id)
+);
+CREATE INDEX blob_ref_table_owner_id ON blob_ref_table (owner_id);
+ALTER TABLE doc_asset_table ADD COLUMN blob_hash TEXT NOT NULL DEFAULT '';
@@ -0,0 +1,7 @@
+-- Your SQL goes here
+CREATE TABLE sync_rev_table (
+    object_id TEXT NOT NULL PRIMARY KEY,
+    ty INTEGER NOT NULL DEFAULT 0,
+    rev_id BIGINT NOT NULL DEFAULT 0,
+    sync_time BIGINT NOT NULL DEFAULT 0
+);
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+CREATE TABLE sync_conflict_table (
+    object_id TEXT NOT NULL PRIMARY KEY,
+    change_ty INTEGER NOT NULL DEFAULT 0,
+    rev_id BIGINT NOT NULL DEFAULT 0,
+    data BLOB NOT NULL DEFAULT (x''),
+    remote_time BIGINT NOT NULL DEFAULT 0,
+    local_time BIGINT NOT NULL DEFAULT 0,
+    create_time BIGINT NOT NULL DEFAULT 0
+);
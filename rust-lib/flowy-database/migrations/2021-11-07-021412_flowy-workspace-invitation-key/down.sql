@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE workspace_invitation_table DROP COLUMN wrapped_key;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE workspace_invitation_table ADD COLUMN wrapped_key TEXT NOT NULL DEFAULT '';
\ No newline at end of file
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+CREATE TABLE sync_base_table (
+    object_id TEXT NOT NULL PRIMARY KEY,
+    ty INTEGER NOT NULL DEFAULT 0,
+    data BLOB NOT NULL DEFAULT (x'')
+);
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+CREATE TABLE asset_transfer_table (
+    asset_id TEXT NOT NULL PRIMARY KEY,
+    direction INTEGER NOT NULL DEFAULT 0,
+    transferred BIGINT NOT NULL DEFAULT 0,
+    total BIGINT NOT NULL DEFAULT 0,
+    update_time BIGINT NOT NULL DEFAULT 0
+);
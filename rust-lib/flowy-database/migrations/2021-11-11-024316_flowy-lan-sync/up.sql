@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE lan_change_table (
+    seq INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    object_id TEXT NOT NULL DEFAULT '',
+    change_ty INTEGER NOT NULL DEFAULT 0,
+    data BLOB NOT NULL DEFAULT (x''),
+    modified_time BIGINT NOT NULL DEFAULT 0
+);
+CREATE TABLE lan_peer_table (
+    device_id TEXT NOT NULL PRIMARY KEY,
+    address TEXT NOT NULL DEFAULT '',
+    pulled_seq BIGINT NOT NULL DEFAULT 0,
+    last_seen BIGINT NOT NULL DEFAULT 0
+);
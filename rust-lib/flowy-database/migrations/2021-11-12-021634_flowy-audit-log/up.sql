@@ -0,0 +1,9 @@
+-- Your SQL goes here
+CREATE TABLE audit_log_table (
+    id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    user_id TEXT NOT NULL DEFAULT '',
+    event TEXT NOT NULL DEFAULT '',
+    object_id TEXT NOT NULL DEFAULT '',
+    create_time BIGINT NOT NULL DEFAULT 0
+);
+CREATE INDEX audit_log_object_index ON audit_log_table (object_id, create_time);
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP TRIGGER view_search_table_delete;
+DROP TRIGGER view_search_table_insert;
+DROP TABLE view_search_fts;
+DROP TABLE view_search_table;
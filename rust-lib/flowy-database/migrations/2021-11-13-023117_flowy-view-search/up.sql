@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TABLE view_search_table (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    view_id TEXT NOT NULL DEFAULT '',
+    name TEXT NOT NULL DEFAULT ''
+);
+CREATE UNIQUE INDEX view_search_table_view_id ON view_search_table (view_id);
+
+-- The full text index of the titles of the views. A renamed view is deleted
+-- and inserted again, like the lines of the doc_search_table.
+CREATE VIRTUAL TABLE view_search_fts USING fts5(name, content = 'view_search_table', content_rowid = 'id');
+CREATE TRIGGER view_search_table_insert AFTER INSERT ON view_search_table BEGIN
+    INSERT INTO view_search_fts (rowid, name) VALUES (new.id, new.name);
+END;
+CREATE TRIGGER view_search_table_delete AFTER DELETE ON view_search_table BEGIN
+    INSERT INTO view_search_fts (view_search_fts, rowid, name) VALUES ('delete', old.id, old.name);
+END;
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE grid_cell_table;
+DROP TABLE grid_row_table;
+DROP TABLE grid_field_table;
+DROP TABLE grid_table;
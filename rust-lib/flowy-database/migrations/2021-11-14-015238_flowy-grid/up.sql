@@ -0,0 +1,32 @@
+-- Your SQL goes here
+CREATE TABLE grid_table (
+    id TEXT NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL DEFAULT '',
+    setting BLOB NOT NULL DEFAULT (x''),
+    create_time BIGINT NOT NULL DEFAULT 0,
+    modified_time BIGINT NOT NULL DEFAULT 0
+);
+CREATE TABLE grid_field_table (
+    id TEXT NOT NULL PRIMARY KEY,
+    grid_id TEXT NOT NULL DEFAULT '',
+    name TEXT NOT NULL DEFAULT '',
+    field_type INTEGER NOT NULL DEFAULT 0,
+    width INTEGER NOT NULL DEFAULT 0,
+    type_option BLOB NOT NULL DEFAULT (x''),
+    position BIGINT NOT NULL DEFAULT 0
+);
+CREATE INDEX grid_field_table_grid_id ON grid_field_table (grid_id);
+CREATE TABLE grid_row_table (
+    id TEXT NOT NULL PRIMARY KEY,
+    grid_id TEXT NOT NULL DEFAULT '',
+    position BIGINT NOT NULL DEFAULT 0,
+    create_time BIGINT NOT NULL DEFAULT 0
+);
+CREATE INDEX grid_row_table_grid_id ON grid_row_table (grid_id);
+CREATE TABLE grid_cell_table (
+    row_id TEXT NOT NULL,
+    field_id TEXT NOT NULL,
+    data TEXT NOT NULL DEFAULT '',
+    PRIMARY KEY (row_id, field_id)
+);
+CREATE INDEX grid_cell_table_field_id ON grid_cell_table (field_id);
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE grid_table DROP COLUMN board_setting;
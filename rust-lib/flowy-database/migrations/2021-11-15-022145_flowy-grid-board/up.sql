@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE grid_table ADD COLUMN board_setting BLOB NOT NULL DEFAULT (x'');
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+CREATE TABLE user_setting_table (
+    section TEXT NOT NULL PRIMARY KEY,
+    data BLOB NOT NULL DEFAULT (x'')
+);
@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE doc_attachment_table (
+    id TEXT NOT NULL PRIMARY KEY,
+    doc_id TEXT NOT NULL DEFAULT '',
+    name TEXT NOT NULL DEFAULT '',
+    mime_type TEXT NOT NULL DEFAULT '',
+    size BIGINT NOT NULL DEFAULT 0,
+    blob_hash TEXT NOT NULL DEFAULT '',
+    thumbnail_hash TEXT NOT NULL DEFAULT '',
+    width INTEGER NOT NULL DEFAULT 0,
+    height INTEGER NOT NULL DEFAULT 0,
+    create_time BIGINT NOT NULL DEFAULT 0
+);
+CREATE INDEX doc_attachment_table_doc_id ON doc_attachment_table (doc_id);
@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE reminder_table (
+    id TEXT NOT NULL PRIMARY KEY,
+    object_type INTEGER NOT NULL DEFAULT 0,
+    object_id TEXT NOT NULL DEFAULT '',
+    child_id TEXT NOT NULL DEFAULT '',
+    title TEXT NOT NULL DEFAULT '',
+    message TEXT NOT NULL DEFAULT '',
+    remind_at BIGINT NOT NULL DEFAULT 0,
+    status INTEGER NOT NULL DEFAULT 0,
+    create_time BIGINT NOT NULL DEFAULT 0
+);
+CREATE INDEX reminder_table_object_id ON reminder_table (object_id);
+CREATE INDEX reminder_table_remind_at ON reminder_table (status, remind_at);
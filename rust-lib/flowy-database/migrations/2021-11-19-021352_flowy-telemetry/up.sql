@@ -0,0 +1,9 @@
+-- Your SQL goes here
+CREATE TABLE telemetry_table (
+    id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    event TEXT NOT NULL DEFAULT '',
+    duration_ms BIGINT NOT NULL DEFAULT 0,
+    succeeded BOOLEAN NOT NULL DEFAULT TRUE,
+    error_code INTEGER NOT NULL DEFAULT 0,
+    create_time BIGINT NOT NULL DEFAULT 0
+);
@@ -0,0 +1,118 @@
+use crate::{
+    migration::timestamp,
+    schema::{kv_store_table, kv_store_table::dsl},
+};
+use diesel::{EscapeExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl, TextExpressionMethods};
+use flowy_sqlite::ConnectionPool;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{fmt, sync::Arc};
+
+pub trait KVDatabase: Send + Sync {
+    // The pool of the database of the current user.
+    fn db_pool(&self) -> Result<Arc<ConnectionPool>, String>;
+}
+
+#[derive(Debug)]
+pub enum KVError {
+    Database(String),
+    // The value can't be serialized, or the one that was saved isn't of the type
+    // it was read with.
+    Encoding(String),
+}
+
+impl fmt::Display for KVError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KVError::Database(msg) => write!(f, "KV database error: {}", msg),
+            KVError::Encoding(msg) => write!(f, "KV encoding error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for KVError {}
+
+impl std::convert::From<diesel::result::Error> for KVError {
+    fn from(e: diesel::result::Error) -> Self { KVError::Database(format!("{:?}", e)) }
+}
+
+// The values that don't deserve their own table, like the caches, the cursors
+// and the sync tokens. They're kept in the database of the current user as
+// JSON, the keys are namespaced by prefixes, e.g. "sync:workspace:<id>", so the
+// ones of a namespace can be scanned.
+#[derive(Clone)]
+pub struct KVStore {
+    database: Arc<dyn KVDatabase>,
+}
+
+impl KVStore {
+    pub fn new(database: Arc<dyn KVDatabase>) -> Self { Self { database } }
+
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, KVError> {
+        let pool = self.database.db_pool().map_err(KVError::Database)?;
+        let conn = pool.get().map_err(|e| KVError::Database(format!("{:?}", e)))?;
+        let values = dsl::kv_store_table
+            .filter(kv_store_table::key.eq(key))
+            .select(kv_store_table::value)
+            .load::<String>(&*conn)?;
+        match values.first() {
+            None => Ok(None),
+            Some(value) => Ok(Some(decode(value)?)),
+        }
+    }
+
+    pub fn set<T: Serialize>(&self, key: &str, value: &T) -> Result<(), KVError> {
+        let value = serde_json::to_string(value).map_err(|e| KVError::Encoding(format!("{}", e)))?;
+        let pool = self.database.db_pool().map_err(KVError::Database)?;
+        let conn = pool.get_write().map_err(|e| KVError::Database(format!("{:?}", e)))?;
+        let _ = diesel::replace_into(kv_store_table::table)
+            .values((
+                dsl::key.eq(key),
+                dsl::value.eq(value),
+                dsl::modified_time.eq(timestamp()),
+            ))
+            .execute(&*conn)?;
+        Ok(())
+    }
+
+    // Returns false if there was no value for the key.
+    pub fn delete(&self, key: &str) -> Result<bool, KVError> {
+        let pool = self.database.db_pool().map_err(KVError::Database)?;
+        let conn = pool.get_write().map_err(|e| KVError::Database(format!("{:?}", e)))?;
+        let count = diesel::delete(dsl::kv_store_table.filter(kv_store_table::key.eq(key))).execute(&*conn)?;
+        Ok(count > 0)
+    }
+
+    // The values whose keys start with the prefix, ordered by key. All of them
+    // must be of the type.
+    pub fn scan_prefix<T: DeserializeOwned>(&self, prefix: &str) -> Result<Vec<(String, T)>, KVError> {
+        let pool = self.database.db_pool().map_err(KVError::Database)?;
+        let conn = pool.get().map_err(|e| KVError::Database(format!("{:?}", e)))?;
+        let pattern = format!("{}%", escape_like(prefix));
+        let rows = dsl::kv_store_table
+            .filter(kv_store_table::key.like(pattern).escape('\\'))
+            .select((kv_store_table::key, kv_store_table::value))
+            .order(kv_store_table::key.asc())
+            .load::<(String, String)>(&*conn)?;
+        rows.into_iter()
+            .filter(|(key, _)| key.starts_with(prefix))
+            .map(|(key, value)| Ok((key, decode::<T>(&value)?)))
+            .collect()
+    }
+}
+
+fn decode<T: DeserializeOwned>(value: &str) -> Result<T, KVError> {
+    serde_json::from_str(value).map_err(|e| KVError::Encoding(format!("{}", e)))
+}
+
+// LIKE matches the '%' and '_' of the prefix as wildcards otherwise. SQLite's
+// LIKE is also case insensitive for ASCII, so the keys are checked again after.
+fn escape_like(prefix: &str) -> String {
+    let mut escaped = String::with_capacity(prefix.len());
+    for c in prefix.chars() {
+        if c == '%' || c == '_' || c == '\\' {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
@@ -1,5 +1,12 @@
 pub mod schema;
 
+mod kv;
+mod migration;
+mod table;
+pub use kv::*;
+pub use migration::*;
+pub use table::*;
+
 #[macro_use]
 pub mod macros;
 
@@ -14,32 +21,145 @@ pub use diesel_derives::*;
 #[macro_use]
 extern crate diesel_migrations;
 
-pub use flowy_sqlite::{ConnectionPool, DBConnection, Database};
+pub use flowy_sqlite::{
+    backup_database,
+    check_integrity,
+    compact_database,
+    export_json_lines,
+    export_sqlite,
+    integrity_errors,
+    is_cipher_available,
+    Compaction,
+    ConnectionPool,
+    DBConnection,
+    Database,
+    DatabaseKey,
+    EncryptionProgress,
+    ExportedTable,
+    PoolConfig,
+    RedactedColumn,
+    RowChange,
+    RowChangeKind,
+    WriteConnection,
+};
 pub type Error = diesel::result::Error;
 
 use diesel_migrations::*;
-use flowy_sqlite::PoolConfig;
 use std::{fmt::Debug, io, path::Path};
 
 pub mod prelude {
-    pub use super::UserDatabaseConnection;
+    pub use super::{SqlTable, UserDatabaseConnection};
     pub use diesel::{query_dsl::*, BelongingToDsl, ExpressionMethods, RunQueryDsl};
 }
 
 embed_migrations!("../flowy-database/migrations/");
 pub const DB_NAME: &str = "flowy-database.db";
 
+// The columns that hold secrets, they're left out when the data of the user is
+// exported. Add the new ones here.
+pub const SECRET_COLUMNS: &[RedactedColumn] = &[("user_table", "token")];
+
 pub fn init(storage_path: &str) -> Result<Database, io::Error> {
-    if !Path::new(storage_path).exists() {
-        std::fs::create_dir_all(storage_path)?;
+    init_with_config(storage_path, PoolConfig::default(), &[]).map_err(|e| match e {
+        InitError::Io(e) => e,
+        InitError::Migration(e) => io::Error::new(io::ErrorKind::Other, e),
+        InitError::Encryption(e) => io::Error::new(io::ErrorKind::Other, format!("{}", e)),
+    })
+}
+
+// Opens the database at the path and applies its pending migrations, the SQL
+// ones first. Nothing is written to the path if the pool config is in memory.
+pub fn init_with_config(
+    storage_path: &str,
+    pool_config: PoolConfig,
+    rust_migrations: &[RustMigration],
+) -> Result<Database, InitError> {
+    if !pool_config.is_in_memory() && !Path::new(storage_path).exists() {
+        std::fs::create_dir_all(storage_path).map_err(InitError::Io)?;
     }
-    let pool_config = PoolConfig::default();
     let database = Database::new(storage_path, DB_NAME, pool_config).map_err(as_io_error)?;
     let conn = database.get_connection().map_err(as_io_error)?;
-    let _ = embedded_migrations::run(&*conn).map_err(as_io_error)?;
+    let _ = migrate(&*conn, rust_migrations).map_err(InitError::Migration)?;
     Ok(database)
 }
 
+// Encrypts the plaintext database at the path with SQLCipher, the pool config
+// of init_with_config must have the same key to open it afterwards. Returns
+// false if the database is already encrypted or doesn't exist yet, the new
+// ones are created encrypted by the pool.
+pub fn encrypt<F>(storage_path: &str, key: &DatabaseKey, progress: F) -> Result<bool, InitError>
+where
+    F: Fn(EncryptionProgress),
+{
+    if !is_cipher_available() {
+        return Err(InitError::Encryption(flowy_sqlite::ErrorKind::CipherUnsupported.into()));
+    }
+    let uri = flowy_sqlite::db_file_uri(storage_path, DB_NAME);
+    if !flowy_sqlite::is_plaintext_database(&uri) {
+        return Ok(false);
+    }
+    let _ = flowy_sqlite::encrypt_database(&uri, key, progress).map_err(InitError::Encryption)?;
+    Ok(true)
+}
+
+// Replaces the rows of the database of the connection with the ones of the
+// backup at the path. The backup is migrated on a copy first, so the ones taken
+// before the last migrations can be restored too. Check its integrity before.
+pub fn restore(
+    conn: &SqliteConnection,
+    backup_path: &str,
+    key: Option<&DatabaseKey>,
+    rust_migrations: &[RustMigration],
+) -> Result<(), InitError> {
+    let restoring_path = format!("{}.restoring", backup_path);
+    let _ = std::fs::remove_dir_all(&restoring_path);
+    std::fs::create_dir_all(&restoring_path)?;
+    let result = (|| {
+        let restoring_uri = flowy_sqlite::db_file_uri(&restoring_path, DB_NAME);
+        let _ = std::fs::copy(backup_path, &restoring_uri)?;
+        let mut pool_config = PoolConfig::default().max_size(1);
+        if let Some(key) = key {
+            pool_config = pool_config.encryption_key(key.clone());
+        }
+        let _ = init_with_config(&restoring_path, pool_config, rust_migrations)?;
+        flowy_sqlite::copy_database(conn, &restoring_uri, key).map_err(as_io_error)?;
+        Ok(())
+    })();
+    let _ = std::fs::remove_dir_all(&restoring_path);
+    result
+}
+
+#[derive(Debug)]
+pub enum InitError {
+    Io(io::Error),
+    Migration(MigrationFailure),
+    Encryption(flowy_sqlite::Error),
+}
+
+impl InitError {
+    // SQLite wasn't built with SQLCipher, see the sqlcipher feature.
+    pub fn is_cipher_unsupported(&self) -> bool {
+        match self {
+            InitError::Encryption(e) => matches!(e.kind(), flowy_sqlite::ErrorKind::CipherUnsupported),
+            InitError::Io(_) | InitError::Migration(_) => false,
+        }
+    }
+}
+
+impl std::convert::From<io::Error> for InitError {
+    fn from(e: io::Error) -> Self { InitError::Io(e) }
+}
+
+impl std::fmt::Display for InitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InitError::Io(e) => write!(f, "{}", e),
+            InitError::Migration(e) => write!(f, "{}", e),
+            InitError::Encryption(e) => write!(f, "{}", e),
+        }
+    }
+}
+
 fn as_io_error<E>(e: E) -> io::Error
 where
     E: Into<flowy_sqlite::Error> + Debug,
@@ -0,0 +1,95 @@
+use crate::{
+    embedded_migrations,
+    schema::{migration_table, migration_table::dsl},
+};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, SqliteConnection};
+use std::{
+    fmt,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+// The changes that can't be written in SQL, like the ones that rewrite the data
+// of the rows. They're applied after the SQL migrations, in the order they're
+// registered, and each one only once per database. The version is kept in the
+// migration_table, so don't change it once the migration is released.
+#[derive(Clone)]
+pub struct RustMigration {
+    pub version: &'static str,
+    pub run: fn(&SqliteConnection) -> Result<(), crate::Error>,
+}
+
+impl fmt::Debug for RustMigration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str(self.version) }
+}
+
+#[derive(Debug, Clone)]
+pub struct MigrationFailure {
+    // The version of the migration that failed, empty if it's unknown.
+    pub version: String,
+    pub msg: String,
+}
+
+impl fmt::Display for MigrationFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "migration {} failed: {}", self.version, self.msg)
+    }
+}
+
+impl std::error::Error for MigrationFailure {}
+
+// Applies the migrations that weren't applied to the database yet. Each one
+// runs in its own transaction, the migrations applied before the one that
+// failed are kept.
+pub fn migrate(conn: &SqliteConnection, rust_migrations: &[RustMigration]) -> Result<(), MigrationFailure> {
+    // Diesel writes "Running migration <version>" before each SQL migration,
+    // it's how the failed one is found.
+    let mut output = vec![];
+    if let Err(e) = embedded_migrations::run_with_output(conn, &mut output) {
+        let output = String::from_utf8_lossy(&output);
+        let version = output
+            .lines()
+            .last()
+            .and_then(|line| line.strip_prefix("Running migration "))
+            .unwrap_or("")
+            .to_owned();
+        return Err(MigrationFailure {
+            version,
+            msg: format!("{:?}", e),
+        });
+    }
+
+    let applied = dsl::migration_table
+        .select(migration_table::version)
+        .load::<String>(conn)
+        .map_err(|e| MigrationFailure {
+            version: "".to_owned(),
+            msg: format!("{:?}", e),
+        })?;
+
+    for migration in rust_migrations {
+        if applied.iter().any(|version| version == migration.version) {
+            continue;
+        }
+
+        conn.immediate_transaction::<_, crate::Error, _>(|| {
+            let _ = (migration.run)(conn)?;
+            let _ = diesel::insert_into(migration_table::table)
+                .values((dsl::version.eq(migration.version), dsl::applied_time.eq(timestamp())))
+                .execute(conn)?;
+            Ok(())
+        })
+        .map_err(|e| MigrationFailure {
+            version: migration.version.to_owned(),
+            msg: format!("{:?}", e),
+        })?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn timestamp() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or(0)
+}
@@ -13,6 +13,108 @@ table! {
     }
 }
 
+table! {
+    asset_transfer_table (asset_id) {
+        asset_id -> Text,
+        direction -> Integer,
+        transferred -> BigInt,
+        total -> BigInt,
+        update_time -> BigInt,
+    }
+}
+
+table! {
+    audit_log_table (id) {
+        id -> Integer,
+        user_id -> Text,
+        event -> Text,
+        object_id -> Text,
+        create_time -> BigInt,
+    }
+}
+
+table! {
+    blob_ref_table (blob_hash, owner_id) {
+        blob_hash -> Text,
+        owner_id -> Text,
+        create_time -> BigInt,
+    }
+}
+
+table! {
+    blob_table (hash) {
+        hash -> Text,
+        size -> BigInt,
+        create_time -> BigInt,
+    }
+}
+
+table! {
+    doc_asset_table (id) {
+        id -> Text,
+        doc_id -> Text,
+        name -> Text,
+        mime_type -> Text,
+        size -> BigInt,
+        create_time -> BigInt,
+        blob_hash -> Text,
+    }
+}
+
+table! {
+    doc_attachment_table (id) {
+        id -> Text,
+        doc_id -> Text,
+        name -> Text,
+        mime_type -> Text,
+        size -> BigInt,
+        blob_hash -> Text,
+        thumbnail_hash -> Text,
+        width -> Integer,
+        height -> Integer,
+        create_time -> BigInt,
+    }
+}
+
+table! {
+    doc_comment_table (id) {
+        id -> Text,
+        doc_id -> Text,
+        user_id -> Text,
+        content -> Text,
+        start_offset -> BigInt,
+        end_offset -> BigInt,
+        resolved -> Bool,
+        create_time -> BigInt,
+        modified_time -> BigInt,
+    }
+}
+
+table! {
+    doc_encryption_table (doc_id) {
+        doc_id -> Text,
+        create_time -> BigInt,
+    }
+}
+
+table! {
+    doc_search_table (id) {
+        id -> Integer,
+        doc_id -> Text,
+        content -> Text,
+    }
+}
+
+table! {
+    doc_snapshot_table (id) {
+        id -> Text,
+        doc_id -> Text,
+        rev_id -> BigInt,
+        data -> Text,
+        create_time -> BigInt,
+    }
+}
+
 table! {
     doc_table (id) {
         id -> Text,
@@ -21,6 +123,123 @@ table! {
     }
 }
 
+table! {
+    favorite_table (id) {
+        id -> Text,
+        user_id -> Text,
+        view_id -> Text,
+        order_key -> Text,
+        create_time -> BigInt,
+    }
+}
+
+table! {
+    grid_cell_table (row_id, field_id) {
+        row_id -> Text,
+        field_id -> Text,
+        data -> Text,
+    }
+}
+
+table! {
+    grid_field_table (id) {
+        id -> Text,
+        grid_id -> Text,
+        name -> Text,
+        field_type -> Integer,
+        width -> Integer,
+        type_option -> Binary,
+        position -> BigInt,
+    }
+}
+
+table! {
+    grid_row_table (id) {
+        id -> Text,
+        grid_id -> Text,
+        position -> BigInt,
+        create_time -> BigInt,
+    }
+}
+
+table! {
+    grid_table (id) {
+        id -> Text,
+        name -> Text,
+        setting -> Binary,
+        create_time -> BigInt,
+        modified_time -> BigInt,
+        board_setting -> Binary,
+    }
+}
+
+table! {
+    kv_store_table (key) {
+        key -> Text,
+        value -> Text,
+        modified_time -> BigInt,
+    }
+}
+
+table! {
+    lan_change_table (seq) {
+        seq -> Integer,
+        object_id -> Text,
+        change_ty -> Integer,
+        data -> Binary,
+        modified_time -> BigInt,
+    }
+}
+
+table! {
+    lan_peer_table (device_id) {
+        device_id -> Text,
+        address -> Text,
+        pulled_seq -> BigInt,
+        last_seen -> BigInt,
+    }
+}
+
+table! {
+    migration_table (version) {
+        version -> Text,
+        applied_time -> BigInt,
+    }
+}
+
+table! {
+    operation_table (id) {
+        id -> Integer,
+        ty -> Integer,
+        data -> Binary,
+        state -> Integer,
+        created_time -> BigInt,
+    }
+}
+
+table! {
+    recent_view_table (id) {
+        id -> Text,
+        user_id -> Text,
+        view_id -> Text,
+        opened_time -> BigInt,
+    }
+}
+
+table! {
+    reminder_table (id) {
+        id -> Text,
+        object_type -> Integer,
+        object_id -> Text,
+        child_id -> Text,
+        title -> Text,
+        message -> Text,
+        remind_at -> BigInt,
+        status -> Integer,
+        create_time -> BigInt,
+    }
+}
+
 table! {
     rev_table (id) {
         id -> Integer,
@@ -33,6 +252,64 @@ table! {
     }
 }
 
+table! {
+    sync_base_table (object_id) {
+        object_id -> Text,
+        ty -> Integer,
+        data -> Binary,
+    }
+}
+
+table! {
+    sync_conflict_table (object_id) {
+        object_id -> Text,
+        change_ty -> Integer,
+        rev_id -> BigInt,
+        data -> Binary,
+        remote_time -> BigInt,
+        local_time -> BigInt,
+        create_time -> BigInt,
+    }
+}
+
+table! {
+    sync_exclusion_table (object_id) {
+        object_id -> Text,
+        ty -> Integer,
+        create_time -> BigInt,
+    }
+}
+
+table! {
+    sync_rev_table (object_id) {
+        object_id -> Text,
+        ty -> Integer,
+        rev_id -> BigInt,
+        sync_time -> BigInt,
+    }
+}
+
+table! {
+    telemetry_table (id) {
+        id -> Integer,
+        event -> Text,
+        duration_ms -> BigInt,
+        succeeded -> Bool,
+        error_code -> Integer,
+        create_time -> BigInt,
+    }
+}
+
+table! {
+    template_table (id) {
+        id -> Text,
+        name -> Text,
+        desc -> Text,
+        data -> Text,
+        create_time -> BigInt,
+    }
+}
+
 table! {
     trash_table (id) {
         id -> Text,
@@ -41,6 +318,7 @@ table! {
         modified_time -> BigInt,
         create_time -> BigInt,
         ty -> Integer,
+        deleted_time -> BigInt,
     }
 }
 
@@ -51,6 +329,33 @@ table! {
         token -> Text,
         email -> Text,
         workspace -> Text,
+        avatar -> Text,
+        email_verified -> Bool,
+    }
+}
+
+table! {
+    user_preference_table (key) {
+        key -> Text,
+        str_value -> Nullable<Text>,
+        int_value -> Nullable<BigInt>,
+        float_value -> Nullable<Double>,
+        bool_value -> Nullable<Bool>,
+    }
+}
+
+table! {
+    user_setting_table (section) {
+        section -> Text,
+        data -> Binary,
+    }
+}
+
+table! {
+    view_search_table (id) {
+        id -> Integer,
+        view_id -> Text,
+        name -> Text,
     }
 }
 
@@ -66,6 +371,9 @@ table! {
         view_type -> Integer,
         version -> BigInt,
         is_trash -> Bool,
+        order_key -> Text,
+        icon -> Text,
+        cover -> Text,
     }
 }
 
@@ -81,12 +389,78 @@ table! {
     }
 }
 
+table! {
+    workspace_invitation_table (id) {
+        id -> Text,
+        workspace_id -> Text,
+        email -> Text,
+        role -> Integer,
+        invited_time -> BigInt,
+        wrapped_key -> Text,
+    }
+}
+
+table! {
+    workspace_member_table (id) {
+        id -> Text,
+        workspace_id -> Text,
+        user_id -> Text,
+        role -> Integer,
+    }
+}
+
+table! {
+    workspace_setting_table (id) {
+        id -> Text,
+        workspace_id -> Text,
+        key -> Text,
+        str_value -> Nullable<Text>,
+        int_value -> Nullable<BigInt>,
+        float_value -> Nullable<Double>,
+        bool_value -> Nullable<Bool>,
+    }
+}
+
 allow_tables_to_appear_in_same_query!(
     app_table,
+    asset_transfer_table,
+    audit_log_table,
+    blob_ref_table,
+    blob_table,
+    doc_asset_table,
+    doc_attachment_table,
+    doc_comment_table,
+    doc_encryption_table,
+    doc_search_table,
+    doc_snapshot_table,
     doc_table,
+    favorite_table,
+    grid_cell_table,
+    grid_field_table,
+    grid_row_table,
+    grid_table,
+    kv_store_table,
+    lan_change_table,
+    lan_peer_table,
+    migration_table,
+    operation_table,
+    recent_view_table,
+    reminder_table,
     rev_table,
+    sync_base_table,
+    sync_conflict_table,
+    sync_exclusion_table,
+    sync_rev_table,
+    telemetry_table,
+    template_table,
     trash_table,
+    user_preference_table,
+    user_setting_table,
     user_table,
+    view_search_table,
     view_table,
+    workspace_invitation_table,
+    workspace_member_table,
+    workspace_setting_table,
     workspace_table,
 );
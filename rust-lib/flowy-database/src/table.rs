@@ -0,0 +1,39 @@
+use diesel::{QueryResult, SqliteConnection};
+
+// The rows of a table mapped to a struct, derive it with #[derive(SqlTable)] of
+// flowy_derive. The columns are selected by name in the order of the fields, so
+// the fields don't have to follow the order of the schema like the ones of the
+// Queryable structs.
+pub trait SqlTable: Sized {
+    type Id: ?Sized;
+    // The columns of the fields, in their order.
+    type Columns;
+    type Query;
+
+    fn id(&self) -> &Self::Id;
+
+    fn columns() -> Self::Columns;
+
+    // Selects the columns of the fields, the query can be filtered and ordered
+    // before it's loaded as Self.
+    fn query() -> Self::Query;
+
+    // Returns the number of rows inserted.
+    fn insert(&self, conn: &SqliteConnection) -> QueryResult<usize>;
+
+    // Writes every column except the primary key, the None fields set the
+    // columns to NULL. Returns the number of rows updated.
+    fn update(&self, conn: &SqliteConnection) -> QueryResult<usize>;
+
+    // Inserts the row or updates the existing one with the same id.
+    fn upsert(&self, conn: &SqliteConnection) -> QueryResult<()>;
+
+    fn read(id: &Self::Id, conn: &SqliteConnection) -> QueryResult<Self>;
+
+    fn read_all(conn: &SqliteConnection) -> QueryResult<Vec<Self>>;
+
+    fn exists(id: &Self::Id, conn: &SqliteConnection) -> QueryResult<bool>;
+
+    // Returns the number of rows deleted.
+    fn delete(id: &Self::Id, conn: &SqliteConnection) -> QueryResult<usize>;
+}
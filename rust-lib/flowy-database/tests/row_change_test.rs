@@ -0,0 +1,120 @@
+use flowy_database::{Connection, Database, PoolConfig, RowChange, RowChangeKind};
+use std::sync::{Arc, Mutex};
+
+fn observed_database(name: &str) -> (Database, Arc<Mutex<Vec<RowChange>>>) {
+    let database = Database::new(
+        &format!("row_change_test_{}", name),
+        "test.db",
+        PoolConfig::default().in_memory().observe_rows(),
+    )
+    .unwrap();
+    let changes = Arc::new(Mutex::new(vec![]));
+    let observed = changes.clone();
+    database
+        .get_pool()
+        .observe_rows(move |changes| observed.lock().unwrap().extend_from_slice(changes));
+    (database, changes)
+}
+
+fn execute(database: &Database, query: &str) {
+    let conn = database.get_connection().unwrap();
+    conn.execute(query).unwrap();
+}
+
+fn take_changes(changes: &Mutex<Vec<RowChange>>) -> Vec<(String, String, RowChangeKind)> {
+    changes
+        .lock()
+        .unwrap()
+        .drain(..)
+        .map(|change| (change.table, change.key, change.kind))
+        .collect()
+}
+
+fn change(table: &str, key: &str, kind: RowChangeKind) -> (String, String, RowChangeKind) {
+    (table.to_owned(), key.to_owned(), kind)
+}
+
+#[test]
+fn row_change_observed() {
+    let (database, changes) = observed_database("observed");
+    execute(
+        &database,
+        "CREATE TABLE note (id TEXT PRIMARY KEY NOT NULL, title TEXT NOT NULL)",
+    );
+    execute(
+        &database,
+        "CREATE TABLE tag (note_id TEXT NOT NULL, name TEXT NOT NULL, PRIMARY KEY (note_id, name))",
+    );
+    assert!(take_changes(&changes).is_empty());
+
+    execute(
+        &database,
+        "INSERT INTO note (id, title) VALUES ('1', 'first'), ('2', 'second')",
+    );
+    execute(&database, "UPDATE note SET title = 'renamed' WHERE id = '1'");
+    execute(&database, "DELETE FROM note WHERE id = '2'");
+    execute(&database, "INSERT INTO tag (note_id, name) VALUES ('1', 'work')");
+    assert_eq!(
+        take_changes(&changes),
+        vec![
+            change("note", "1", RowChangeKind::Insert),
+            change("note", "2", RowChangeKind::Insert),
+            change("note", "1", RowChangeKind::Update),
+            change("note", "2", RowChangeKind::Delete),
+            change("tag", "1:work", RowChangeKind::Insert),
+        ]
+    );
+}
+
+#[test]
+fn row_change_of_transactions() {
+    let (database, changes) = observed_database("transactions");
+    execute(
+        &database,
+        "CREATE TABLE note (id TEXT PRIMARY KEY NOT NULL, title TEXT NOT NULL)",
+    );
+    execute(&database, "INSERT INTO note (id, title) VALUES ('1', 'first')");
+    let _ = take_changes(&changes);
+
+    {
+        let conn = database.get_connection().unwrap();
+        let result = conn.transaction::<(), diesel::result::Error, _>(|| {
+            conn.execute("INSERT INTO note (id, title) VALUES ('2', 'second')")?;
+            Err(diesel::result::Error::RollbackTransaction)
+        });
+        assert!(result.is_err());
+    }
+    assert!(take_changes(&changes).is_empty());
+
+    {
+        let conn = database.get_connection().unwrap();
+        conn.transaction::<(), diesel::result::Error, _>(|| {
+            conn.execute("INSERT INTO note (id, title) VALUES ('2', 'second'), ('3', 'third')")?;
+            conn.execute("UPDATE note SET title = 'renamed' WHERE id = '2'")?;
+            conn.execute("DELETE FROM note WHERE id IN ('1', '3')")?;
+            conn.execute("INSERT INTO note (id, title) VALUES ('1', 'again')")?;
+            Ok(())
+        })
+        .unwrap();
+    }
+    assert_eq!(
+        take_changes(&changes),
+        vec![
+            change("note", "2", RowChangeKind::Insert),
+            change("note", "1", RowChangeKind::Update),
+        ]
+    );
+}
+
+#[test]
+fn row_change_not_observed_by_default() {
+    let database = Database::new("row_change_test_default", "test.db", PoolConfig::default().in_memory()).unwrap();
+    let changes = Arc::new(Mutex::new(vec![]));
+    let observed = changes.clone();
+    database
+        .get_pool()
+        .observe_rows(move |changes| observed.lock().unwrap().extend_from_slice(changes));
+    execute(&database, "CREATE TABLE note (id TEXT PRIMARY KEY NOT NULL)");
+    execute(&database, "INSERT INTO note (id) VALUES ('1')");
+    assert!(take_changes(&changes).is_empty());
+}
@@ -0,0 +1,131 @@
+#[macro_use]
+extern crate diesel;
+
+use flowy_database::{prelude::*, Connection, OptionalExtension, SqliteConnection};
+use flowy_derive::SqlTable;
+
+table! {
+    note_table (id) {
+        id -> Text,
+        title -> Text,
+        rank -> BigInt,
+        body -> Nullable<Text>,
+    }
+}
+
+table! {
+    tag_table (name) {
+        name -> Text,
+        color -> Text,
+    }
+}
+
+// The fields aren't in the order of the columns.
+#[derive(PartialEq, Clone, Debug, Queryable, SqlTable)]
+#[table_name = "note_table"]
+struct NoteTable {
+    body: Option<String>,
+    id: String,
+    rank: i64,
+    #[column_name = "title"]
+    name: String,
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, SqlTable)]
+#[table_name = "tag_table"]
+#[primary_key(name)]
+struct TagTable {
+    name: String,
+    color: String,
+}
+
+fn connection() -> SqliteConnection {
+    let conn = SqliteConnection::establish(":memory:").unwrap();
+    conn.execute(
+        "CREATE TABLE note_table (id TEXT PRIMARY KEY NOT NULL, title TEXT NOT NULL, rank BIGINT NOT NULL, body TEXT)",
+    )
+    .unwrap();
+    conn.execute("CREATE TABLE tag_table (name TEXT PRIMARY KEY NOT NULL, color TEXT NOT NULL)")
+        .unwrap();
+    conn
+}
+
+fn note(id: &str, name: &str, rank: i64) -> NoteTable {
+    NoteTable {
+        body: Some(format!("{} body", name)),
+        id: id.to_owned(),
+        rank,
+        name: name.to_owned(),
+    }
+}
+
+#[test]
+fn table_insert_and_read() {
+    let conn = connection();
+    let first = note("1", "first", 2);
+    first.insert(&conn).unwrap();
+    assert_eq!(NoteTable::read("1", &conn).unwrap(), first);
+    assert!(NoteTable::exists("1", &conn).unwrap());
+    assert!(!NoteTable::exists("2", &conn).unwrap());
+    assert!(NoteTable::read("2", &conn).optional().unwrap().is_none());
+    assert!(first.insert(&conn).is_err());
+
+    let title = note_table::table
+        .filter(note_table::id.eq("1"))
+        .select(note_table::title)
+        .first::<String>(&conn)
+        .unwrap();
+    assert_eq!(title, "first");
+}
+
+#[test]
+fn table_update_and_upsert() {
+    let conn = connection();
+    let mut first = note("1", "first", 2);
+    first.upsert(&conn).unwrap();
+    first.name = "renamed".to_owned();
+    first.body = None;
+    first.upsert(&conn).unwrap();
+    assert_eq!(NoteTable::read_all(&conn).unwrap(), vec![first.clone()]);
+
+    first.rank = 3;
+    assert_eq!(first.update(&conn).unwrap(), 1);
+    assert_eq!(NoteTable::read("1", &conn).unwrap().rank, 3);
+    assert_eq!(note("2", "second", 0).update(&conn).unwrap(), 0);
+}
+
+#[test]
+fn table_query_and_delete() {
+    let conn = connection();
+    for (id, rank) in &[("1", 2), ("2", 0), ("3", 1)] {
+        note(id, id, *rank).insert(&conn).unwrap();
+    }
+    let ids: Vec<String> = NoteTable::query()
+        .filter(note_table::rank.ge(1))
+        .order(note_table::rank.asc())
+        .load::<NoteTable>(&conn)
+        .unwrap()
+        .into_iter()
+        .map(|note| note.id)
+        .collect();
+    assert_eq!(ids, vec!["3", "1"]);
+
+    assert_eq!(NoteTable::delete("1", &conn).unwrap(), 1);
+    assert_eq!(NoteTable::delete("1", &conn).unwrap(), 0);
+    assert_eq!(NoteTable::read_all(&conn).unwrap().len(), 2);
+}
+
+#[test]
+fn table_primary_key() {
+    let conn = connection();
+    let mut tag = TagTable {
+        name: "work".to_owned(),
+        color: "red".to_owned(),
+    };
+    tag.upsert(&conn).unwrap();
+    tag.color = "blue".to_owned();
+    tag.upsert(&conn).unwrap();
+    assert_eq!(tag.id(), "work");
+    assert_eq!(TagTable::read("work", &conn).unwrap(), tag);
+    assert_eq!(TagTable::read_all(&conn).unwrap().len(), 1);
+}
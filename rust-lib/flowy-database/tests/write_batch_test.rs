@@ -0,0 +1,62 @@
+use flowy_database::{dsl::sql, sql_types::BigInt, Connection, Database, PoolConfig, RunQueryDsl};
+use std::{sync::Arc, thread, time::Duration};
+
+fn batched_database(name: &str) -> Database {
+    let config = PoolConfig::default()
+        .in_memory()
+        .write_batch(Duration::from_millis(50), 64);
+    let database = Database::new(&format!("write_batch_test_{}", name), "test.db", config).unwrap();
+    let conn = database.get_connection().unwrap();
+    conn.execute("CREATE TABLE note (id INTEGER PRIMARY KEY NOT NULL)")
+        .unwrap();
+    database
+}
+
+fn count_notes(database: &Database) -> i64 {
+    let conn = database.get_connection().unwrap();
+    sql::<BigInt>("SELECT COUNT(*) FROM note").get_result(&*conn).unwrap()
+}
+
+#[test]
+fn write_batch_concurrent_writes() {
+    let database = batched_database("concurrent");
+    let pool = database.get_pool();
+    let writers = (0..16)
+        .map(|id| {
+            let pool = Arc::clone(&pool);
+            thread::spawn(move || {
+                pool.write_batched(move |conn| conn.execute(&format!("INSERT INTO note (id) VALUES ({})", id)))
+            })
+        })
+        .collect::<Vec<_>>();
+    for writer in writers {
+        assert_eq!(writer.join().unwrap().unwrap().unwrap(), 1);
+    }
+    assert_eq!(count_notes(&database), 16);
+}
+
+#[test]
+fn write_batch_failed_write_is_rolled_back_alone() {
+    let database = batched_database("failed");
+    let pool = database.get_pool();
+    let writers = (0..4)
+        .map(|id| {
+            let pool = Arc::clone(&pool);
+            thread::spawn(move || {
+                pool.write_batched(move |conn| {
+                    conn.execute(&format!("INSERT INTO note (id) VALUES ({})", id))?;
+                    match id {
+                        2 => Err(flowy_database::Error::NotFound),
+                        _ => Ok(()),
+                    }
+                })
+            })
+        })
+        .collect::<Vec<_>>();
+    let results = writers
+        .into_iter()
+        .map(|writer| writer.join().unwrap().unwrap().is_ok())
+        .collect::<Vec<_>>();
+    assert_eq!(results, vec![true, true, false, true]);
+    assert_eq!(count_notes(&database), 3);
+}
@@ -77,6 +77,262 @@ pub fn category_from_str(type_str: &str) -> TypeCategory {
         | "FFIResponse"
         | "SubscribeObject"
         | "UserError"
+        | "SchemaError"
+        | "SchemaState"
+        | "RefreshTokenResponse"
+        | "Account"
+        | "RepeatedAccount"
+        | "SwitchAccountRequest"
+        | "SwitchAccountParams"
+        | "UpdatePasswordRequest"
+        | "UpdatePasswordParams"
+        | "UpdatePasswordResponse"
+        | "OAuthURLRequest"
+        | "OAuthURL"
+        | "OAuthSignInRequest"
+        | "OAuthSignInParams"
+        | "UpdateUserProfileRequest"
+        | "PreferenceValue"
+        | "Preference"
+        | "RepeatedPreference"
+        | "SetPreferenceRequest"
+        | "GetPreferenceRequest"
+        | "SubscribePreferenceRequest"
+        | "DeleteAccountRequest"
+        | "DeleteAccountParams"
+        | "DeleteAccountProgress"
+        | "WorkspaceMember"
+        | "RepeatedWorkspaceMember"
+        | "UpdateWorkspaceMemberRequest"
+        | "DeviceSession"
+        | "RepeatedDeviceSession"
+        | "RevokeSessionRequest"
+        | "RevokeSessionParams"
+        | "EmailVerification"
+        | "ConfirmEmailRequest"
+        | "ConfirmEmailParams"
+        | "DocVersion"
+        | "RepeatedDocVersion"
+        | "DocVersionIdentifier"
+        | "ImportRequest"
+        | "CreateAssetRequest"
+        | "DocAsset"
+        | "RepeatedDocAsset"
+        | "AssetIdentifier"
+        | "AssetData"
+        | "AssetTransfer"
+        | "RepeatedAssetTransfer"
+        | "AssetChunk"
+        | "AssetChunkRequest"
+        | "LanPeer"
+        | "RepeatedLanPeer"
+        | "LanMessage"
+        | "LanHello"
+        | "LanPullRequest"
+        | "DocStats"
+        | "UpdatePresenceRequest"
+        | "DocPresence"
+        | "RepeatedDocPresence"
+        | "Template"
+        | "RepeatedTemplate"
+        | "CreateViewFromTemplateRequest"
+        | "SaveTemplateRequest"
+        | "DocBlock"
+        | "RepeatedDocBlock"
+        | "BlockOp"
+        | "ApplyBlockOpsRequest"
+        | "CreateCommentRequest"
+        | "DocComment"
+        | "RepeatedDocComment"
+        | "ResolveCommentRequest"
+        | "CommentIdentifier"
+        | "DiffDocumentsRequest"
+        | "DiffVersionsRequest"
+        | "AttributeChange"
+        | "BlockDiff"
+        | "DocDiff"
+        | "ReadDocumentRequest"
+        | "DocumentPage"
+        | "SearchDocumentsRequest"
+        | "SearchHighlight"
+        | "DocSearchResult"
+        | "RepeatedDocSearchResult"
+        | "DocEncryption"
+        | "DuplicateDocumentRequest"
+        | "CheckTextRequest"
+        | "TextDiagnostic"
+        | "RepeatedTextDiagnostic"
+        | "MoveViewRequest"
+        | "MoveFavoriteRequest"
+        | "ExportWorkspaceRequest"
+        | "ImportWorkspaceRequest"
+        | "WorkspaceArchive"
+        | "ArchivedApp"
+        | "ArchivedView"
+        | "ArchivedAsset"
+        | "ImportFailure"
+        | "ImportWorkspaceResult"
+        | "ImportWorkspaceProgress"
+        | "ReadViewTreeRequest"
+        | "WorkspaceSettingValue"
+        | "WorkspaceSettingItem"
+        | "RepeatedWorkspaceSettingItem"
+        | "SetWorkspaceSettingRequest"
+        | "GetWorkspaceSettingRequest"
+        | "RemoveWorkspaceMemberRequest"
+        | "WorkspaceInvitation"
+        | "RepeatedWorkspaceInvitation"
+        | "InviteWorkspaceMemberRequest"
+        | "CancelWorkspaceInvitationRequest"
+        | "ViewOperation"
+        | "BatchViewOperationRequest"
+        | "BatchViewChangeset"
+        | "ViewBelongings"
+        | "WorkspaceTemplate"
+        | "WorkspaceTemplateApp"
+        | "WorkspaceTemplateView"
+        | "RepeatedWorkspaceTemplate"
+        | "CreateWorkspaceFromTemplateRequest"
+        | "GarbageCollectionResult"
+        | "DatabaseMigrationFailure"
+        | "DatabaseEncryptionProgress"
+        | "DatabaseBackup"
+        | "RepeatedDatabaseBackup"
+        | "RestoreBackupRequest"
+        | "RestoreBackupParams"
+        | "CheckStorageIntegrityRequest"
+        | "IntegrityIssue"
+        | "StorageIntegrityReport"
+        | "ExportAllDataRequest"
+        | "DataExportTable"
+        | "DataExport"
+        | "StorageCompaction"
+        | "DatabaseRowChange"
+        | "RepeatedDatabaseRowChange"
+        | "WsConnectState"
+        | "RemoteChange"
+        | "RepeatedRemoteChange"
+        | "ServerPush"
+        | "SyncTraffic"
+        | "PullChangesParams"
+        | "SyncState"
+        | "SyncResult"
+        | "SyncSettings"
+        | "SyncConflict"
+        | "RepeatedSyncConflict"
+        | "ResolveConflictRequest"
+        | "ConflictField"
+        | "SubmitConflictResolutionRequest"
+        | "ResolvedConflictField"
+        | "ObjectSyncStatus"
+        | "RepeatedObjectSyncStatus"
+        | "QuerySyncStatusRequest"
+        | "NetworkState"
+        | "WorkspaceEncryption"
+        | "AcceptWorkspaceInvitationRequest"
+        | "SyncExclusionRequest"
+        | "SyncExclusion"
+        | "RepeatedSyncExclusion"
+        | "SyncBackfill"
+        | "LogFile"
+        | "RepeatedLogFile"
+        | "MetricLabel"
+        | "CounterMetric"
+        | "MetricBucket"
+        | "HistogramMetric"
+        | "MetricsSnapshot"
+        | "SetProfilingRequest"
+        | "ProfileReportRequest"
+        | "ProfiledEvent"
+        | "ProfileReport"
+        | "SetTraceModeRequest"
+        | "TraceRecord"
+        | "RepeatedTraceRecord"
+        | "AuditRecord"
+        | "RepeatedAuditRecord"
+        | "ReadAuditLogRequest"
+        | "SubscribeLogRequest"
+        | "LogRecord"
+        | "RepeatedLogRecord"
+        | "CrashBundle"
+        | "RepeatedCrashBundle"
+        | "CrashBundleIdentifier"
+        | "CrashBundleContent"
+        | "MemoryUsage"
+        | "SubsystemMemory" | "SetLogFilterRequest" | "LogFilterSetting"
+        | "TranslationsRequest"
+        | "Translation"
+        | "Translations"
+        | "SetLocaleRequest"
+        | "LocaleSetting"
+        | "SearchRequest"
+        | "SearchResultHighlight"
+        | "SearchResult"
+        | "RepeatedSearchResult"
+        | "SelectOption"
+        | "FieldTypeOption"
+        | "Field"
+        | "RepeatedField"
+        | "CreateFieldRequest"
+        | "UpdateFieldRequest"
+        | "SwitchFieldTypeRequest"
+        | "FieldIdentifier"
+        | "Cell"
+        | "Row"
+        | "RepeatedRow"
+        | "CreateRowRequest"
+        | "RowIdentifier"
+        | "CellChangeset"
+        | "GridSort"
+        | "GridFilter"
+        | "UpdateGridSortsRequest"
+        | "UpdateGridFiltersRequest"
+        | "CreateGridRequest"
+        | "Grid"
+        | "GridIdentifier"
+        | "GridError"
+        | "BoardGroup"
+        | "Board"
+        | "UpdateBoardSettingRequest"
+        | "MoveCardRequest"
+        | "CalendarRangeRequest"
+        | "CalendarEvent"
+        | "Calendar"
+        | "RescheduleRowRequest"
+        | "AppearanceSetting"
+        | "EditorSetting"
+        | "SyncSetting"
+        | "Shortcut"
+        | "ShortcutSetting"
+        | "PrivacySetting"
+        | "AppSetting"
+        | "UpdateSettingRequest"
+        | "ResetSettingRequest"
+        | "SettingChange"
+        | "ImportAttachmentRequest"
+        | "DocAttachment"
+        | "RepeatedDocAttachment"
+        | "AttachmentIdentifier"
+        | "AttachmentUsage"
+        | "ImportNotionRequest"
+        | "UnconvertedElement"
+        | "ImportNotionResult"
+        | "ImportEnexRequest"
+        | "ImportEnexResult"
+        | "IngestSharedContentRequest"
+        | "IngestSharedContentResult"
+        | "Reminder"
+        | "RepeatedReminder"
+        | "CreateReminderRequest"
+        | "QueryRemindersRequest"
+        | "ReminderIdentifier"
+        | "SnoozeReminderRequest"
+        | "ReminderError"
+        | "TelemetryRecord"
+        | "RepeatedTelemetryRecord"
+        | "TelemetryBatch"
+        | "UploadTelemetryResult"
+        | "TelemetryError"
         => TypeCategory::Protobuf,
         "TrashType"
         | "ViewType"
@@ -89,8 +345,51 @@ pub fn category_from_str(type_str: &str) -> TypeCategory {
         | "WsDataType"
         | "DocObservable"
         | "FFIStatusCode"
+        | "FFIPayloadEncoding"
         | "UserEvent"
         | "UserNotification"
+        | "SchemaErrorCode"
+        | "SDKNotification"
+        | "OAuthProviderType"
+        | "DeleteAccountStage"
+        | "WorkspaceRole"
+        | "DocumentEvent"
+        | "ImportType"
+        | "BlockType"
+        | "BlockOpType"
+        | "BlockDiffType"
+        | "DiagnosticKind"
+        | "ViewOperationType"
+        | "IntegrityIssueType"
+        | "DataExportFormat"
+        | "RowChangeType"
+        | "WsConnectStateType"
+        | "RemoteChangeType"
+        | "ServerPushType"
+        | "AssetTransferDirection"
+        | "LanMessageType"
+        | "ConflictStrategy"
+        | "SyncTrigger"
+        | "SyncEntityType"
+        | "ConflictResolution"
+        | "SyncStatusType"
+        | "NetworkStateType"
+        | "SearchResultType"
+        | "FieldType"
+        | "FilterCondition"
+        | "GridEvent"
+        | "GridObservable"
+        | "SettingSection"
+        | "ThemeMode"
+        | "UnconvertedKind"
+        | "EnexTagMapping"
+        | "SharedContentType"
+        | "IngestTarget"
+        | "ReminderObjectType"
+        | "ReminderStatus"
+        | "ReminderEvent"
+        | "ReminderObservable"
+        | "TelemetryEvent"
         => TypeCategory::Enum,
 
         "Option" => TypeCategory::Opt,
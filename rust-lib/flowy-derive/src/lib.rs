@@ -10,7 +10,9 @@ extern crate quote;
 
 mod dart_event;
 mod derive_cache;
+mod payload;
 mod proto_buf;
+mod sql_table;
 
 // Inspired by https://serde.rs/attributes.html
 #[proc_macro_derive(ProtoBuf, attributes(pb))]
@@ -37,6 +39,38 @@ pub fn derive_dart_event(input: TokenStream) -> TokenStream {
         .into()
 }
 
+#[proc_macro_derive(FromBytes, attributes(payload))]
+pub fn derive_from_bytes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    payload::expand_from_bytes_derive(&input)
+        .unwrap_or_else(to_compile_errors)
+        .into()
+}
+
+#[proc_macro_derive(FromPayload, attributes(payload))]
+pub fn derive_from_payload(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    payload::expand_from_payload_derive(&input)
+        .unwrap_or_else(to_compile_errors)
+        .into()
+}
+
+#[proc_macro_derive(ToBytes, attributes(payload))]
+pub fn derive_to_bytes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    payload::expand_to_bytes_derive(&input)
+        .unwrap_or_else(to_compile_errors)
+        .into()
+}
+
+#[proc_macro_derive(SqlTable, attributes(table_name, primary_key, column_name))]
+pub fn derive_sql_table(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    sql_table::expand_derive(&input)
+        .unwrap_or_else(to_compile_errors)
+        .into()
+}
+
 fn to_compile_errors(errors: Vec<syn::Error>) -> proc_macro2::TokenStream {
     let compile_errors = errors.iter().map(syn::Error::to_compile_error);
     quote!(#(#compile_errors)*)
@@ -0,0 +1,85 @@
+use proc_macro2::TokenStream;
+use syn::{Lit, Meta, NestedMeta};
+
+// #[derive(FromBytes)]
+// #[payload(from = "CreateAppRequest")]
+// pub struct CreateAppParams { ... }
+//
+// The payload is decoded as `CreateAppRequest` and then converted into
+// `CreateAppParams` through `TryInto`, so the handler receives the validated
+// params directly. The ToBytes works the other way around with `into`.
+pub fn expand_from_bytes_derive(input: &syn::DeriveInput) -> Result<TokenStream, Vec<syn::Error>> {
+    let ident = &input.ident;
+    let from = payload_attr(input, "from")?;
+    Ok(quote! {
+        impl flowy_dispatch::prelude::FromBytes for #ident {
+            fn parse_from_bytes(bytes: &[u8]) -> Result<Self, flowy_dispatch::prelude::DispatchError> {
+                let pb = <#from as flowy_dispatch::prelude::FromBytes>::parse_from_bytes(bytes)?;
+                let value: #ident = std::convert::TryInto::try_into(pb)?;
+                Ok(value)
+            }
+        }
+    })
+}
+
+// #[derive(FromPayload)]
+// #[payload(from = "SignInRequest")]
+// pub struct SignInParams { ... }
+//
+// The same as FromBytes for the params that are ProtoBuf themselves, and so
+// FromBytes already. The handler receives them with Params<SignInParams, E>,
+// the error of the `TryInto` is converted into the error E of the module.
+pub fn expand_from_payload_derive(input: &syn::DeriveInput) -> Result<TokenStream, Vec<syn::Error>> {
+    let ident = &input.ident;
+    let from = payload_attr(input, "from")?;
+    Ok(quote! {
+        impl flowy_dispatch::prelude::FromPayload for #ident {
+            type Request = #from;
+            type Error = <#from as std::convert::TryInto<#ident>>::Error;
+
+            fn from_payload(request: #from) -> Result<Self, Self::Error> {
+                std::convert::TryInto::try_into(request)
+            }
+        }
+    })
+}
+
+pub fn expand_to_bytes_derive(input: &syn::DeriveInput) -> Result<TokenStream, Vec<syn::Error>> {
+    let ident = &input.ident;
+    let into = payload_attr(input, "into")?;
+    Ok(quote! {
+        impl flowy_dispatch::prelude::ToBytes for #ident {
+            fn into_bytes(self) -> Result<bytes::Bytes, flowy_dispatch::prelude::DispatchError> {
+                let pb: #into = std::convert::Into::into(self);
+                flowy_dispatch::prelude::ToBytes::into_bytes(pb)
+            }
+        }
+    })
+}
+
+fn payload_attr(input: &syn::DeriveInput, name: &str) -> Result<syn::Type, Vec<syn::Error>> {
+    for attr in input.attrs.iter().filter(|attr| attr.path.is_ident("payload")) {
+        let meta_list = match attr.parse_meta() {
+            Ok(Meta::List(meta_list)) => meta_list,
+            Ok(other) => return Err(vec![syn::Error::new_spanned(other, "expected #[payload(...)]")]),
+            Err(e) => return Err(vec![e]),
+        };
+
+        for meta in meta_list.nested.iter() {
+            if let NestedMeta::Meta(Meta::NameValue(m)) = meta {
+                if !m.path.is_ident(name) {
+                    continue;
+                }
+                return match &m.lit {
+                    Lit::Str(s) => s.parse::<syn::Type>().map_err(|e| vec![e]),
+                    _ => Err(vec![syn::Error::new_spanned(&m.lit, "expected string literal")]),
+                };
+            }
+        }
+    }
+
+    Err(vec![syn::Error::new_spanned(
+        &input.ident,
+        format!("missing #[payload({} = \"...\")] attribute", name),
+    )])
+}
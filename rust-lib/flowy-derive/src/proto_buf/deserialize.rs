@@ -25,7 +25,14 @@ pub fn make_de_token_steam(ctxt: &Ctxt, ast: &ASTContainer) -> Option<TokenStrea
         impl std::convert::TryFrom<bytes::Bytes> for #struct_ident {
             type Error = ::protobuf::ProtobufError;
             fn try_from(bytes: bytes::Bytes) -> Result<Self, Self::Error> {
-                let mut pb: crate::protobuf::#pb_ty = ::protobuf::Message::parse_from_bytes(&bytes)?;
+                #struct_ident::try_from(&bytes[..])
+            }
+        }
+
+        impl std::convert::TryFrom<&[u8]> for #struct_ident {
+            type Error = ::protobuf::ProtobufError;
+            fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+                let mut pb: crate::protobuf::#pb_ty = ::protobuf::Message::parse_from_bytes(bytes)?;
                 #struct_ident::try_from(&mut pb)
             }
         }
@@ -0,0 +1,179 @@
+use proc_macro2::{Ident, TokenStream};
+use syn::{Lit, Meta, NestedMeta};
+
+// #[derive(SqlTable)]
+// #[table_name = "doc_table"]
+// pub(crate) struct DocTable { ... }
+//
+// Implements flowy_database::SqlTable with the columns named after the fields,
+// or after their #[column_name = "..."]. The primary key is the id column
+// unless it's set with #[primary_key(...)], like the Identifiable derive of
+// diesel. The table module of the schema must be in scope.
+pub fn expand_derive(input: &syn::DeriveInput) -> Result<TokenStream, Vec<syn::Error>> {
+    let ident = &input.ident;
+    let table = table_name(input)?;
+    let primary_key = primary_key(input)?;
+    let fields = match &input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => {
+            return Err(vec![syn::Error::new_spanned(
+                ident,
+                "SqlTable can only be derived for structs with named fields",
+            )])
+        },
+    };
+
+    let mut columns = vec![];
+    let mut field_idents = vec![];
+    let mut id_field = None;
+    for field in fields {
+        let field_ident = field.ident.clone().unwrap();
+        let column = column_name(field)?.unwrap_or_else(|| field_ident.clone());
+        if column == primary_key {
+            id_field = Some((field_ident.clone(), &field.ty));
+        }
+        columns.push(column);
+        field_idents.push(field_ident);
+    }
+    let (id_field, id_ty) = match id_field {
+        Some((id_field, id_ty)) => (id_field, id_type(id_ty)),
+        None => {
+            return Err(vec![syn::Error::new_spanned(
+                ident,
+                format!("missing the field of the primary key `{}`", primary_key),
+            )])
+        },
+    };
+    let (changed_columns, changed_fields): (Vec<&Ident>, Vec<&Ident>) = columns
+        .iter()
+        .zip(field_idents.iter())
+        .filter(|(column, _)| **column != primary_key)
+        .unzip();
+
+    Ok(quote! {
+        const _: () = {
+            use flowy_database::{
+                ExpressionMethods,
+                QueryDsl,
+                QueryResult,
+                RunQueryDsl,
+                SqliteConnection,
+            };
+
+            impl flowy_database::SqlTable for #ident {
+                type Id = #id_ty;
+                type Columns = (#(#table::#columns,)*);
+                type Query = flowy_database::dsl::Select<#table::table, Self::Columns>;
+
+                fn id(&self) -> &Self::Id { &self.#id_field }
+
+                fn columns() -> Self::Columns { (#(#table::#columns,)*) }
+
+                fn query() -> Self::Query { #table::table.select(Self::columns()) }
+
+                fn insert(&self, conn: &SqliteConnection) -> QueryResult<usize> {
+                    flowy_database::insert_into(#table::table)
+                        .values((#(#table::#columns.eq(&self.#field_idents),)*))
+                        .execute(conn)
+                }
+
+                fn update(&self, conn: &SqliteConnection) -> QueryResult<usize> {
+                    flowy_database::update(#table::table.filter(#table::#primary_key.eq(self.id())))
+                        .set((#(#table::#changed_columns.eq(&self.#changed_fields),)*))
+                        .execute(conn)
+                }
+
+                fn upsert(&self, conn: &SqliteConnection) -> QueryResult<()> {
+                    match Self::exists(self.id(), conn)? {
+                        true => {
+                            let _ = self.update(conn)?;
+                        },
+                        false => {
+                            let _ = self.insert(conn)?;
+                        },
+                    }
+                    Ok(())
+                }
+
+                fn read(id: &Self::Id, conn: &SqliteConnection) -> QueryResult<Self> {
+                    Self::query().filter(#table::#primary_key.eq(id)).first::<Self>(conn)
+                }
+
+                fn read_all(conn: &SqliteConnection) -> QueryResult<Vec<Self>> { Self::query().load::<Self>(conn) }
+
+                fn exists(id: &Self::Id, conn: &SqliteConnection) -> QueryResult<bool> {
+                    let count = #table::table
+                        .filter(#table::#primary_key.eq(id))
+                        .count()
+                        .get_result::<i64>(conn)?;
+                    Ok(count > 0)
+                }
+
+                fn delete(id: &Self::Id, conn: &SqliteConnection) -> QueryResult<usize> {
+                    flowy_database::delete(#table::table.filter(#table::#primary_key.eq(id))).execute(conn)
+                }
+            }
+        };
+    })
+}
+
+fn table_name(input: &syn::DeriveInput) -> Result<Ident, Vec<syn::Error>> {
+    match input.attrs.iter().find(|attr| attr.path.is_ident("table_name")) {
+        Some(attr) => parse_ident_value(attr, "expected #[table_name = \"...\"]"),
+        None => Err(vec![syn::Error::new_spanned(
+            &input.ident,
+            "missing #[table_name = \"...\"] attribute",
+        )]),
+    }
+}
+
+fn primary_key(input: &syn::DeriveInput) -> Result<Ident, Vec<syn::Error>> {
+    let attr = match input.attrs.iter().find(|attr| attr.path.is_ident("primary_key")) {
+        Some(attr) => attr,
+        None => return Ok(format_ident!("id")),
+    };
+    let meta_list = match attr.parse_meta() {
+        Ok(Meta::List(meta_list)) => meta_list,
+        Ok(other) => return Err(vec![syn::Error::new_spanned(other, "expected #[primary_key(...)]")]),
+        Err(e) => return Err(vec![e]),
+    };
+    let mut keys = meta_list.nested.iter();
+    match (keys.next(), keys.next()) {
+        (Some(NestedMeta::Meta(Meta::Path(path))), None) if path.get_ident().is_some() => {
+            Ok(path.get_ident().unwrap().clone())
+        },
+        _ => Err(vec![syn::Error::new_spanned(
+            meta_list,
+            "expected a single column, the composite primary keys aren't supported",
+        )]),
+    }
+}
+
+fn column_name(field: &syn::Field) -> Result<Option<Ident>, Vec<syn::Error>> {
+    match field.attrs.iter().find(|attr| attr.path.is_ident("column_name")) {
+        Some(attr) => parse_ident_value(attr, "expected #[column_name = \"...\"]").map(Some),
+        None => Ok(None),
+    }
+}
+
+fn parse_ident_value(attr: &syn::Attribute, expected: &str) -> Result<Ident, Vec<syn::Error>> {
+    match attr.parse_meta() {
+        Ok(Meta::NameValue(m)) => match &m.lit {
+            Lit::Str(s) => s.parse::<Ident>().map_err(|e| vec![e]),
+            _ => Err(vec![syn::Error::new_spanned(&m.lit, "expected string literal")]),
+        },
+        Ok(other) => Err(vec![syn::Error::new_spanned(other, expected)]),
+        Err(e) => Err(vec![e]),
+    }
+}
+
+// The ids are passed as &str rather than &String.
+fn id_type(ty: &syn::Type) -> TokenStream {
+    match ty {
+        syn::Type::Path(path) if path.qself.is_none() && path.path.is_ident("String") => quote!(str),
+        _ => quote!(#ty),
+    }
+}
@@ -0,0 +1,94 @@
+use bytes::Bytes;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use flowy_dispatch::{
+    mock::{MockModule, MockResponse},
+    prelude::*,
+};
+use futures::future::join_all;
+use futures_core::future::BoxFuture;
+use std::sync::Arc;
+
+// Compare with the baseline of the main branch:
+//     cargo bench -p flowy-dispatch -- --save-baseline main
+//     cargo bench -p flowy-dispatch -- --baseline main
+
+const PAYLOAD_SIZES: [usize; 5] = [100, 1024, 10 * 1024, 100 * 1024, 1024 * 1024];
+const MIDDLEWARE_DEPTHS: [usize; 4] = [0, 4, 16, 64];
+const BATCH: usize = 1000;
+
+struct PassMiddleware;
+impl EventMiddleware for PassMiddleware {
+    fn intercept(&self, _request: &ModuleRequest, _response: &EventResponse) -> BoxFuture<'static, bool> {
+        Box::pin(async { false })
+    }
+}
+
+struct PassGuard;
+impl EventGuard for PassGuard {
+    fn check(
+        &self,
+        _request: &ModuleRequest,
+        _permission: Option<EventPermission>,
+    ) -> BoxFuture<'static, Result<(), EventResponse>> {
+        Box::pin(async { Ok(()) })
+    }
+}
+
+fn mk_dispatch(depth: usize) -> Arc<EventDispatch> {
+    let mut mock = MockModule::new("bench").response("noop", MockResponse::empty());
+    for size in PAYLOAD_SIZES.iter() {
+        mock = mock.response(format!("payload_{}", size), MockResponse::bytes(vec![0u8; *size]));
+    }
+    let mut dispatch = EventDispatch::construct(|| vec![mock.build()]).slow_event_threshold(None);
+    for _ in 0..depth {
+        dispatch = dispatch.middleware(Arc::new(PassMiddleware)).guard(Arc::new(PassGuard));
+    }
+    Arc::new(dispatch)
+}
+
+fn bench_noop(c: &mut Criterion) {
+    let dispatch = mk_dispatch(0);
+    c.bench_function("dispatch_noop_latency", |b| {
+        b.iter(|| EventDispatch::sync_send(dispatch.clone(), ModuleRequest::new("noop")))
+    });
+
+    let mut group = c.benchmark_group("dispatch_noop_throughput");
+    group.throughput(Throughput::Elements(BATCH as u64));
+    group.bench_function(BenchmarkId::from_parameter(BATCH), |b| {
+        b.iter(|| {
+            let responses = (0..BATCH).map(|_| EventDispatch::async_send(dispatch.clone(), ModuleRequest::new("noop")));
+            futures::executor::block_on(join_all(responses))
+        })
+    });
+    group.finish();
+}
+
+// The request and the response both have the payload.
+fn bench_payload(c: &mut Criterion) {
+    let dispatch = mk_dispatch(0);
+    let mut group = c.benchmark_group("dispatch_payload");
+    for size in PAYLOAD_SIZES.iter() {
+        let payload = Bytes::from(vec![0u8; *size]);
+        let event = format!("payload_{}", size);
+        group.throughput(Throughput::Bytes(*size as u64 * 2));
+        group.bench_with_input(BenchmarkId::from_parameter(size), size, |b, _| {
+            b.iter(|| EventDispatch::sync_send(dispatch.clone(), ModuleRequest::new(&event).payload(payload.clone())))
+        });
+    }
+    group.finish();
+}
+
+// Each level is a middleware and a guard.
+fn bench_middlewares(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dispatch_middleware_depth");
+    for depth in MIDDLEWARE_DEPTHS.iter() {
+        let dispatch = mk_dispatch(*depth);
+        group.bench_with_input(BenchmarkId::from_parameter(depth), depth, |b, _| {
+            b.iter(|| EventDispatch::sync_send(dispatch.clone(), ModuleRequest::new("noop")))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_noop, bench_payload, bench_middlewares);
+criterion_main!(benches);
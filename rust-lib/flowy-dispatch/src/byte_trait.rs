@@ -1,9 +1,39 @@
 use crate::errors::{DispatchError, InternalError};
+#[cfg(feature = "use_serde")]
+use crate::request::{pooled_bytes, take_buffer};
 use bytes::Bytes;
 
+// The encoding of the payload, selected per request. The Native encoding is the
+// one enabled by the features, protobuf or bincode. The Cbor encoding is only
+// supported by the serde payloads with the use_cbor feature, it's mainly used by
+// the web client that doesn't have the protobuf codegen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PayloadEncoding {
+    Native,
+    Cbor,
+}
+
+impl std::default::Default for PayloadEncoding {
+    fn default() -> Self { PayloadEncoding::Native }
+}
+
+fn unsupported_encoding(encoding: PayloadEncoding) -> DispatchError {
+    InternalError::Other(format!("Unsupported payload encoding: {:?}", encoding)).into()
+}
+
 // To bytes
 pub trait ToBytes {
     fn into_bytes(self) -> Result<Bytes, DispatchError>;
+
+    fn into_bytes_with(self, encoding: PayloadEncoding) -> Result<Bytes, DispatchError>
+    where
+        Self: Sized,
+    {
+        match encoding {
+            PayloadEncoding::Native => self.into_bytes(),
+            _ => Err(unsupported_encoding(encoding)),
+        }
+    }
 }
 
 #[cfg(feature = "use_protobuf")]
@@ -25,50 +55,133 @@ where
     }
 }
 
-#[cfg(feature = "use_serde")]
+// The serde types are encoded with bincode, a compact binary format. It's mainly
+// used by the internal tools and tests that don't want to write the .proto files.
+// Only one of the blanket implementations can be enabled, the serde types are
+// wrapped in Serde while the protobuf one is, see Serde.
+#[cfg(all(feature = "use_serde", not(feature = "use_protobuf")))]
 impl<T> ToBytes for T
 where
     T: serde::Serialize,
 {
-    fn into_bytes(self) -> Result<Bytes, DispatchError> {
-        match serde_json::to_string(&self.0) {
-            Ok(s) => Ok(Bytes::from(s)),
-            Err(e) => Err(InternalError::SerializeToBytes(format!("{:?}", e)).into()),
-        }
+    fn into_bytes(self) -> Result<Bytes, DispatchError> { serde_into_bytes(&self, PayloadEncoding::Native) }
+
+    fn into_bytes_with(self, encoding: PayloadEncoding) -> Result<Bytes, DispatchError> {
+        serde_into_bytes(&self, encoding)
+    }
+}
+
+#[cfg(feature = "use_serde")]
+fn serde_into_bytes<T: serde::Serialize>(value: &T, encoding: PayloadEncoding) -> Result<Bytes, DispatchError> {
+    let mut buffer = take_buffer(0);
+    let result = match encoding {
+        PayloadEncoding::Native => bincode::serialize_into(&mut buffer, value).map_err(|e| format!("{:?}", e)),
+        #[cfg(feature = "use_cbor")]
+        PayloadEncoding::Cbor => serde_cbor::to_writer(&mut buffer, value).map_err(|e| format!("{:?}", e)),
+        #[cfg(not(feature = "use_cbor"))]
+        PayloadEncoding::Cbor => return Err(unsupported_encoding(encoding)),
+    };
+    match result {
+        Ok(_) => Ok(pooled_bytes(buffer)),
+        Err(e) => Err(InternalError::Other(e).into()),
     }
 }
 
 // From bytes
 
+// The bytes are borrowed from the payload, so the small payloads that are stored
+// inline are parsed without copying them to a buffer first.
 pub trait FromBytes: Sized {
-    fn parse_from_bytes(bytes: Bytes) -> Result<Self, DispatchError>;
+    fn parse_from_bytes(bytes: &[u8]) -> Result<Self, DispatchError>;
+
+    fn parse_from_bytes_with(bytes: &[u8], encoding: PayloadEncoding) -> Result<Self, DispatchError> {
+        match encoding {
+            PayloadEncoding::Native => Self::parse_from_bytes(bytes),
+            _ => Err(unsupported_encoding(encoding)),
+        }
+    }
 }
 
 #[cfg(feature = "use_protobuf")]
 impl<T> FromBytes for T
 where
-    // // https://stackoverflow.com/questions/62871045/tryfromu8-trait-bound-in-trait
-    // T: for<'a> std::convert::TryFrom<&'a Bytes, Error =
-    // protobuf::ProtobufError>,
-    T: std::convert::TryFrom<Bytes, Error = protobuf::ProtobufError>,
+    T: for<'a> std::convert::TryFrom<&'a [u8], Error = protobuf::ProtobufError>,
 {
-    fn parse_from_bytes(bytes: Bytes) -> Result<Self, DispatchError> {
-        let data = T::try_from(bytes.clone())?;
+    fn parse_from_bytes(bytes: &[u8]) -> Result<Self, DispatchError> {
+        let data = T::try_from(bytes)?;
         Ok(data)
     }
 }
 
-#[cfg(feature = "use_serde")]
+#[cfg(all(feature = "use_serde", not(feature = "use_protobuf")))]
 impl<T> FromBytes for T
 where
     T: serde::de::DeserializeOwned + 'static,
 {
-    fn parse_from_bytes(bytes: Bytes) -> Result<Self, String> {
-        let s = String::from_utf8_lossy(&bytes);
+    fn parse_from_bytes(bytes: &[u8]) -> Result<Self, DispatchError> {
+        serde_from_bytes(bytes, PayloadEncoding::Native)
+    }
 
-        match serde_json::from_str::<T>(s.as_ref()) {
-            Ok(data) => Ok(data),
-            Err(e) => Err(format!("{:?}", e)),
-        }
+    fn parse_from_bytes_with(bytes: &[u8], encoding: PayloadEncoding) -> Result<Self, DispatchError> {
+        serde_from_bytes(bytes, encoding)
+    }
+}
+
+#[cfg(feature = "use_serde")]
+fn serde_from_bytes<T>(bytes: &[u8], encoding: PayloadEncoding) -> Result<T, DispatchError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let result = match encoding {
+        PayloadEncoding::Native => bincode::deserialize::<T>(bytes).map_err(|e| format!("{:?}", e)),
+        #[cfg(feature = "use_cbor")]
+        PayloadEncoding::Cbor => serde_cbor::from_slice::<T>(bytes).map_err(|e| format!("{:?}", e)),
+        #[cfg(not(feature = "use_cbor"))]
+        PayloadEncoding::Cbor => return Err(unsupported_encoding(encoding)),
+    };
+    result.map_err(|e| InternalError::DeserializeFromBytes(e).into())
+}
+
+// The serde payload that works with either of the blanket implementations, e.g.
+// Data<Serde<Counter>>. The protobuf one is enabled by default, so the internal
+// tools wrap their serde types in it instead of adding the .proto files.
+#[cfg(feature = "use_serde")]
+pub struct Serde<T>(pub T);
+
+#[cfg(feature = "use_serde")]
+impl<T> Serde<T> {
+    pub fn into_inner(self) -> T { self.0 }
+}
+
+#[cfg(feature = "use_serde")]
+impl<T> std::ops::Deref for Serde<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T { &self.0 }
+}
+
+#[cfg(feature = "use_serde")]
+impl<T> ToBytes for Serde<T>
+where
+    T: serde::Serialize,
+{
+    fn into_bytes(self) -> Result<Bytes, DispatchError> { serde_into_bytes(&self.0, PayloadEncoding::Native) }
+
+    fn into_bytes_with(self, encoding: PayloadEncoding) -> Result<Bytes, DispatchError> {
+        serde_into_bytes(&self.0, encoding)
+    }
+}
+
+#[cfg(feature = "use_serde")]
+impl<T> FromBytes for Serde<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    fn parse_from_bytes(bytes: &[u8]) -> Result<Self, DispatchError> {
+        serde_from_bytes(bytes, PayloadEncoding::Native).map(Serde)
+    }
+
+    fn parse_from_bytes_with(bytes: &[u8], encoding: PayloadEncoding) -> Result<Self, DispatchError> {
+        serde_from_bytes(bytes, encoding).map(Serde)
     }
 }
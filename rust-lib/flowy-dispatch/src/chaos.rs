@@ -0,0 +1,188 @@
+use crate::{
+    errors::{Error, InternalError},
+    middleware::EventGuard,
+    module::{EventPermission, ModuleRequest},
+    response::EventResponse,
+};
+use futures_core::future::BoxFuture;
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+// What is injected into the events that match the pattern, each fault with its
+// own probability. The pattern is the name of the event, it can have one * that
+// matches any part of the name, e.g. "*" or "__system/*".
+#[derive(Clone, Debug)]
+pub struct FaultRule {
+    pattern: String,
+    latency: Option<(f64, Duration, Duration)>,
+    error: f64,
+    drop: f64,
+}
+
+impl FaultRule {
+    pub fn new(pattern: &str) -> Self {
+        Self {
+            pattern: pattern.to_owned(),
+            latency: None,
+            error: 0.0,
+            drop: 0.0,
+        }
+    }
+
+    // The handler is called after a delay between min and max.
+    pub fn latency(mut self, probability: f64, min: Duration, max: Duration) -> Self {
+        self.latency = Some((probability, min, max.max(min)));
+        self
+    }
+
+    // The request is answered with an internal error instead of calling the
+    // handler.
+    pub fn error(mut self, probability: f64) -> Self {
+        self.error = probability;
+        self
+    }
+
+    // The request is never answered, like a response lost on the way to the
+    // frontend. It stays in flight until the dispatcher is dropped.
+    pub fn drop(mut self, probability: f64) -> Self {
+        self.drop = probability;
+        self
+    }
+
+    fn matches(&self, event: &str) -> bool {
+        match self.pattern.split_once('*') {
+            Some((prefix, suffix)) => {
+                event.len() >= prefix.len() + suffix.len() && event.starts_with(prefix) && event.ends_with(suffix)
+            },
+            None => self.pattern == event,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FaultStats {
+    pub latencies: usize,
+    pub errors: usize,
+    pub drops: usize,
+}
+
+enum Fault {
+    Latency(Duration),
+    Error,
+    Drop,
+}
+
+// The guard that injects the faults of the first rule that matches the event,
+// to test how the frontend and the sync recover from them. It's only built in
+// the debug builds or with the chaos feature. The faults are drawn from the
+// seed, the same seed injects the same faults into the same requests.
+//
+//     let rule = FaultRule::new("*").error(0.1).latency(0.5, min, max);
+//     let injector = Arc::new(FaultInjector::new(seed).rule(rule));
+//     let dispatch = EventDispatch::construct(|| modules).guard(injector);
+#[derive(Debug)]
+pub struct FaultInjector {
+    rules: Vec<FaultRule>,
+    enabled: AtomicBool,
+    state: Mutex<u64>,
+    latencies: AtomicUsize,
+    errors: AtomicUsize,
+    drops: AtomicUsize,
+}
+
+impl FaultInjector {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rules: vec![],
+            enabled: AtomicBool::new(true),
+            state: Mutex::new(seed),
+            latencies: AtomicUsize::new(0),
+            errors: AtomicUsize::new(0),
+            drops: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn rule(mut self, rule: FaultRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    pub fn set_enabled(&self, enabled: bool) { self.enabled.store(enabled, Ordering::SeqCst); }
+
+    pub fn is_enabled(&self) -> bool { self.enabled.load(Ordering::SeqCst) }
+
+    // The faults injected since the injector was built.
+    pub fn stats(&self) -> FaultStats {
+        FaultStats {
+            latencies: self.latencies.load(Ordering::SeqCst),
+            errors: self.errors.load(Ordering::SeqCst),
+            drops: self.drops.load(Ordering::SeqCst),
+        }
+    }
+
+    fn draw(&self, event: &str) -> Option<Fault> {
+        if !self.is_enabled() {
+            return None;
+        }
+        let rule = self.rules.iter().find(|rule| rule.matches(event))?;
+        if self.next_f64() < rule.drop {
+            self.drops.fetch_add(1, Ordering::SeqCst);
+            return Some(Fault::Drop);
+        }
+        if self.next_f64() < rule.error {
+            self.errors.fetch_add(1, Ordering::SeqCst);
+            return Some(Fault::Error);
+        }
+        match rule.latency {
+            Some((probability, min, max)) if self.next_f64() < probability => {
+                self.latencies.fetch_add(1, Ordering::SeqCst);
+                Some(Fault::Latency(min + (max - min).mul_f64(self.next_f64())))
+            },
+            _ => None,
+        }
+    }
+
+    // splitmix64, the faults don't need a better generator.
+    fn next_f64(&self) -> f64 {
+        let mut state = self.state.lock().unwrap();
+        *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = *state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        (z >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+impl EventGuard for FaultInjector {
+    fn check(
+        &self,
+        request: &ModuleRequest,
+        _permission: Option<EventPermission>,
+    ) -> BoxFuture<'static, Result<(), EventResponse>> {
+        let event = request.event.name().to_owned();
+        let fault = self.draw(&event);
+        Box::pin(async move {
+            match fault {
+                None => Ok(()),
+                Some(Fault::Latency(delay)) => {
+                    tokio::time::sleep(delay).await;
+                    Ok(())
+                },
+                Some(Fault::Error) => {
+                    tracing::trace!("Inject an error into {}", event);
+                    Err(InternalError::Other(format!("Injected fault: {}", event)).as_response())
+                },
+                Some(Fault::Drop) => {
+                    tracing::trace!("Drop the response of {}", event);
+                    futures::future::pending().await
+                },
+            }
+        })
+    }
+}
@@ -33,11 +33,11 @@ where
 
     #[inline]
     fn from_request(req: &EventRequest, payload: &mut Payload) -> Self::Future {
-        match payload {
-            Payload::None => ready(Err(unexpected_none_payload(req))),
-            Payload::Bytes(bytes) => match T::parse_from_bytes(bytes.clone()) {
+        match payload.as_bytes() {
+            None => ready(Err(unexpected_none_payload(req))),
+            Some(bytes) => match T::parse_from_bytes_with(bytes, req.encoding) {
                 Ok(data) => ready(Ok(Data(data))),
-                Err(e) => ready(Err(InternalError::DeserializeFromBytes(format!("{}", e)).into())),
+                Err(e) => ready(Err(e)),
             },
         }
     }
@@ -47,8 +47,8 @@ impl<T> Responder for Data<T>
 where
     T: ToBytes,
 {
-    fn respond_to(self, _request: &EventRequest) -> EventResponse {
-        match self.into_inner().into_bytes() {
+    fn respond_to(self, request: &EventRequest) -> EventResponse {
+        match self.into_inner().into_bytes_with(request.encoding) {
             Ok(bytes) => ResponseBuilder::Ok().data(bytes).build(),
             Err(e) => e.into(),
         }
@@ -75,10 +75,10 @@ fn parse_payload<T>(payload: &Payload) -> Result<Data<T>, DispatchError>
 where
     T: FromBytes,
 {
-    match payload {
-        Payload::None => Err(InternalError::UnexpectedNone(format!("Parse fail, expected payload")).into()),
-        Payload::Bytes(bytes) => {
-            let data = T::parse_from_bytes(bytes.clone())?;
+    match payload.as_bytes() {
+        None => Err(InternalError::UnexpectedNone(format!("Parse fail, expected payload")).into()),
+        Some(bytes) => {
+            let data = T::parse_from_bytes(bytes)?;
             Ok(Data(data))
         },
     }
@@ -93,7 +93,7 @@ where
     fn try_into(self) -> Result<Payload, Self::Error> {
         let inner = self.into_inner();
         let bytes = inner.into_bytes()?;
-        Ok(Payload::Bytes(bytes))
+        Ok(bytes.into())
     }
 }
 
@@ -1,18 +1,44 @@
 use crate::{
     errors::{DispatchError, Error, InternalError},
-    module::{as_module_map, Module, ModuleMap, ModuleRequest},
-    response::EventResponse,
+    inline::{EventCosts, InlineTask},
+    link::{LinkError, LinkRouter},
+    middleware::{check_permission, Dispatched, EventGuard, EventMiddleware, MAX_RETRY},
+    module::{as_module_map, Event, Module, ModuleMap, ModuleRequest},
+    profile::Profiled,
+    response::{EventResponse, StatusCode},
     service::{Service, ServiceFactory},
+    shard::Shards,
     util::tokio_default_runtime,
 };
 use derivative::*;
 use futures_core::future::BoxFuture;
 use futures_util::task::Context;
 use pin_project::pin_project;
-use std::{future::Future, sync::Arc};
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
 use tokio::macros::support::{Pin, Poll};
+use tracing::Instrument;
+
+pub const DEFAULT_SLOW_EVENT_THRESHOLD: Duration = Duration::from_millis(500);
+pub const DEFAULT_INLINE_BUDGET: Duration = Duration::from_micros(100);
+
 pub struct EventDispatch {
     module_map: ModuleMap,
+    middlewares: Arc<Vec<Arc<dyn EventMiddleware>>>,
+    guards: Arc<Vec<Arc<dyn EventGuard>>>,
+    activity: Arc<DispatchActivity>,
+    slow_event_threshold: Option<Duration>,
+    shards: Option<Shards>,
+    inline_budget: Option<Duration>,
+    costs: Arc<EventCosts>,
+    links: LinkRouter,
     runtime: tokio::runtime::Runtime,
 }
 
@@ -24,12 +50,69 @@ impl EventDispatch {
         let runtime = tokio_default_runtime().unwrap();
         let modules = module_factory();
         tracing::trace!("{}", module_info(&modules));
+        let links = LinkRouter::new(modules.iter().flat_map(|module| module.links().to_vec()).collect());
         let module_map = as_module_map(modules);
 
-        let dispatch = EventDispatch { module_map, runtime };
+        let dispatch = EventDispatch {
+            module_map,
+            middlewares: Arc::new(vec![]),
+            guards: Arc::new(vec![]),
+            activity: Arc::new(DispatchActivity::new()),
+            slow_event_threshold: Some(DEFAULT_SLOW_EVENT_THRESHOLD),
+            shards: None,
+            inline_budget: Some(DEFAULT_INLINE_BUDGET),
+            costs: Arc::new(EventCosts::default()),
+            links,
+            runtime,
+        };
         dispatch
     }
 
+    // The list is copied, the requests that were already sent keep the middlewares
+    // they were sent with.
+    pub fn middleware(mut self, middleware: Arc<dyn EventMiddleware>) -> Self {
+        let mut middlewares = self.middlewares.to_vec();
+        middlewares.push(middleware);
+        self.middlewares = Arc::new(middlewares);
+        self
+    }
+
+    pub fn guard(mut self, guard: Arc<dyn EventGuard>) -> Self {
+        let mut guards = self.guards.to_vec();
+        guards.push(guard);
+        self.guards = Arc::new(guards);
+        self
+    }
+
+    // The events that take longer, from when they're sent until their response,
+    // are logged with the split of the time and the size of their payload, and
+    // counted with dispatch_slow_events_total. None disables it.
+    pub fn slow_event_threshold(mut self, threshold: Option<Duration>) -> Self {
+        self.slow_event_threshold = threshold;
+        self
+    }
+
+    // The requests with a key are handled by count threads, the ones with the
+    // same key by the same thread in the order they were sent, see
+    // ModuleRequest::key. The other requests are handled by the runtime.
+    pub fn shards(mut self, count: usize) -> Self {
+        self.shards = Some(Shards::new(count));
+        self
+    }
+
+    // The events registered with Module::inline_event, and the ones whose
+    // handler took less than the budget the last times, are run in the poll of
+    // the caller instead of being spawned on the runtime. The event that goes
+    // over the budget is spawned until it's cheap again. The callers outside of
+    // a tokio runtime always spawn them. None disables it.
+    pub fn inline_budget(mut self, budget: Option<Duration>) -> Self {
+        self.inline_budget = budget;
+        self
+    }
+
+    // How many shards handle the requests with a key, 0 if it's not sharded.
+    pub fn shard_count(&self) -> usize { self.shards.as_ref().map(|shards| shards.len()).unwrap_or(0) }
+
     pub fn async_send<Req>(dispatch: Arc<EventDispatch>, request: Req) -> DispatchFuture<EventResponse>
     where
         Req: std::convert::Into<ModuleRequest>,
@@ -37,6 +120,22 @@ impl EventDispatch {
         EventDispatch::async_send_with_callback(dispatch, request, |_| Box::pin(async {}))
     }
 
+    // Sends the request of the route the link resolves to, see Module::link. The
+    // link that doesn't resolve gets an error response.
+    pub fn open_link(dispatch: Arc<EventDispatch>, uri: &str) -> DispatchFuture<EventResponse> {
+        match dispatch.resolve_link(uri) {
+            Ok(request) => EventDispatch::async_send(dispatch, request),
+            Err(e) => {
+                let response: EventResponse = DispatchError::from(e.to_string()).into();
+                DispatchFuture {
+                    fut: Box::pin(async move { response }),
+                }
+            },
+        }
+    }
+
+    pub fn resolve_link(&self, uri: &str) -> Result<ModuleRequest, LinkError> { self.links.resolve(uri) }
+
     pub fn async_send_with_callback<Req, Callback>(
         dispatch: Arc<EventDispatch>,
         request: Req,
@@ -46,19 +145,150 @@ impl EventDispatch {
         Req: std::convert::Into<ModuleRequest>,
         Callback: FnOnce(EventResponse) -> BoxFuture<'static, ()> + 'static + Send + Sync,
     {
-        let request: ModuleRequest = request.into();
+        EventDispatch::async_send_with_trace(dispatch, request, move |resp, _| callback(resp))
+    }
+
+    // Same as async_send_with_callback, but the callback also receives the time the
+    // request spent waiting in the runtime's queue, the time spent in the handler
+    // and the size of the response, see tracer.
+    pub fn async_send_with_trace<Req, Callback>(
+        dispatch: Arc<EventDispatch>,
+        request: Req,
+        callback: Callback,
+    ) -> DispatchFuture<EventResponse>
+    where
+        Req: std::convert::Into<ModuleRequest>,
+        Callback: FnOnce(EventResponse, DispatchTrace) -> BoxFuture<'static, ()> + 'static + Send + Sync,
+    {
+        let activity = ActivityGuard::new(dispatch.activity.clone());
+        EventDispatch::send(dispatch, request.into(), Some(activity), callback)
+    }
+
+    fn send<Callback>(
+        dispatch: Arc<EventDispatch>,
+        mut request: ModuleRequest,
+        activity: Option<ActivityGuard>,
+        callback: Callback,
+    ) -> DispatchFuture<EventResponse>
+    where
+        Callback: FnOnce(EventResponse, DispatchTrace) -> BoxFuture<'static, ()> + 'static + Send + Sync,
+    {
+        // Resolved once, the lookups below only index the table.
+        request.event = dispatch.module_map.resolve(request.event);
         let module_map = dispatch.module_map.clone();
-        let service = Box::new(DispatchService { module_map });
+        let middlewares = dispatch.middlewares.clone();
+        let guards = dispatch.guards.clone();
+        let service = Box::new(DispatchService {
+            module_map,
+            middlewares,
+            guards,
+        });
         tracing::trace!("Async event: {:?}", &request.event);
-        let service_ctx = DispatchContext {
-            request,
-            callback: Some(Box::new(callback)),
-        };
-        let join_handle = dispatch.runtime.spawn(async move {
-            service
+        let span = event_span(&dispatch.module_map, &request);
+        let enqueued_at = Instant::now();
+        let event_name = request.event.name().to_owned();
+        let key = request.key.clone();
+        let slow_event_threshold = dispatch.slow_event_threshold;
+        let inline_budget = dispatch.inline_budget;
+        let costs = dispatch.costs.clone();
+        let marked = dispatch
+            .module_map
+            .get(&request.event)
+            .map(|module| module.is_inline(&request.event))
+            .unwrap_or(false);
+        let inline = inline_budget.is_some()
+            && tokio::runtime::Handle::try_current().is_ok()
+            && costs.is_cheap(&request.event, marked);
+        let trace_event = event_name.clone();
+        let task = async move {
+            let started_at = Instant::now();
+            let callback = move |resp: EventResponse| {
+                let trace = DispatchTrace {
+                    event: trace_event,
+                    queue_wait: started_at.duration_since(enqueued_at),
+                    handler: started_at.elapsed(),
+                    response_size: resp.payload.size(),
+                };
+                callback(resp, trace)
+            };
+            let event = request.event.clone();
+            let payload_size = request.payload.size();
+            let service_ctx = DispatchContext {
+                request,
+                callback: Some(Box::new(callback)),
+            };
+            let response = service
                 .call(service_ctx)
                 .await
-                .unwrap_or_else(|e| InternalError::Other(format!("{:?}", e)).as_response())
+                .unwrap_or_else(|e| InternalError::Other(format!("{:?}", e)).as_response());
+            let timing = EventTiming {
+                queue_wait: started_at.duration_since(enqueued_at),
+                handler: started_at.elapsed(),
+            };
+            record_metrics(&event, &response, &timing);
+            if let Some(budget) = inline_budget {
+                costs.record(&event, marked, timing.handler, budget);
+            }
+            if let Some(threshold) = slow_event_threshold {
+                log_slow_event(&event, &timing, payload_size, threshold);
+            }
+            drop(activity);
+            response
+        };
+        let task = Profiled::new(task, &event_name).instrument(span);
+        if let (Some(shards), Some(key)) = (&dispatch.shards, key) {
+            let (tx, rx) = tokio::sync::oneshot::channel();
+            shards.spawn(
+                &key,
+                Box::pin(async move {
+                    let _ = tx.send(task.await);
+                }),
+            );
+            return DispatchFuture {
+                fut: Box::pin(async move {
+                    rx.await.unwrap_or_else(|_| {
+                        let error = InternalError::JoinError("EVENT_DISPATCH shard dropped the event".to_owned());
+                        error.as_response()
+                    })
+                }),
+            };
+        }
+
+        if inline {
+            return DispatchFuture {
+                fut: Box::pin(InlineTask::new(task, dispatch.runtime.handle().clone())),
+            };
+        }
+
+        let join_handle = dispatch.runtime.spawn(task);
+        DispatchFuture {
+            fut: Box::pin(async move {
+                join_handle.await.unwrap_or_else(|e| {
+                    let error = InternalError::JoinError(format!("EVENT_DISPATCH join error: {:?}", e));
+                    error.as_response()
+                })
+            }),
+        }
+    }
+
+    // Same as async_send, but the request is only sent once the delay elapsed. It's
+    // how the periodic tasks of the modules are scheduled, so the request isn't
+    // counted as activity, see idle_since.
+    pub fn async_send_after<Req>(
+        dispatch: Arc<EventDispatch>,
+        request: Req,
+        delay: Duration,
+    ) -> DispatchFuture<EventResponse>
+    where
+        Req: std::convert::Into<ModuleRequest>,
+    {
+        let request: ModuleRequest = request.into();
+        let join_handle = dispatch.runtime.spawn({
+            let dispatch = dispatch.clone();
+            async move {
+                tokio::time::sleep(delay).await;
+                EventDispatch::send(dispatch, request, None, |_, _| Box::pin(async {})).await
+            }
         });
 
         DispatchFuture {
@@ -77,6 +307,20 @@ impl EventDispatch {
         })
     }
 
+    // When the last request was done, None while some are being handled. The
+    // maintenance tasks wait for the idle periods with it.
+    pub fn idle_since(&self) -> Option<Instant> { self.activity.idle_since() }
+
+    // The requests that are being handled, with the ones waiting in the queue.
+    pub fn in_flight(&self) -> usize { self.activity.in_flight.load(Ordering::SeqCst) }
+
+    // How many events the modules registered.
+    pub fn event_count(&self) -> usize { self.module_map.len() }
+
+    // The event with its slot in the dispatch table, the callers that send it
+    // often can keep it so it's not looked up by its name each time.
+    pub fn event<E: Into<Event>>(&self, event: E) -> Event { self.module_map.resolve(event) }
+
     pub fn spawn<F>(&self, f: F)
     where
         F: Future<Output = ()> + Send + 'static,
@@ -105,6 +349,94 @@ where
     }
 }
 
+struct DispatchActivity {
+    in_flight: AtomicUsize,
+    last_done: Mutex<Instant>,
+}
+
+impl DispatchActivity {
+    fn new() -> Self {
+        Self {
+            in_flight: AtomicUsize::new(0),
+            last_done: Mutex::new(Instant::now()),
+        }
+    }
+
+    fn idle_since(&self) -> Option<Instant> {
+        if self.in_flight.load(Ordering::SeqCst) > 0 {
+            return None;
+        }
+        self.last_done.lock().ok().map(|last_done| *last_done)
+    }
+}
+
+// Counts the request as being handled until it's dropped, even if the handler
+// panicked.
+struct ActivityGuard(Arc<DispatchActivity>);
+
+impl ActivityGuard {
+    fn new(activity: Arc<DispatchActivity>) -> Self {
+        activity.in_flight.fetch_add(1, Ordering::SeqCst);
+        Self(activity)
+    }
+}
+
+impl Drop for ActivityGuard {
+    fn drop(&mut self) {
+        if let Ok(mut last_done) = self.0.last_done.lock() {
+            *last_done = Instant::now();
+        }
+        self.0.in_flight.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+struct EventTiming {
+    queue_wait: Duration,
+    handler: Duration,
+}
+
+// The count of the events, the ones that failed, and the time they waited in
+// the queue and were handled, by event.
+fn record_metrics(event: &Event, response: &EventResponse, timing: &EventTiming) {
+    let registry = flowy_metrics::registry();
+    let labels = [("event", event.name())];
+    registry.counter("dispatch_events_total", &labels).inc();
+    if response.status_code != StatusCode::Ok {
+        registry.counter("dispatch_event_errors_total", &labels).inc();
+    }
+    registry
+        .histogram("dispatch_queue_wait_ms", &labels)
+        .observe_duration(timing.queue_wait);
+    registry
+        .histogram("dispatch_handler_duration_ms", &labels)
+        .observe_duration(timing.handler);
+}
+
+fn log_slow_event(event: &Event, timing: &EventTiming, payload_size: usize, threshold: Duration) {
+    if timing.queue_wait + timing.handler < threshold {
+        return;
+    }
+    flowy_metrics::registry()
+        .counter("dispatch_slow_events_total", &[("event", event.name())])
+        .inc();
+    tracing::warn!(
+        event = event.name(),
+        queue_wait_ms = timing.queue_wait.as_millis() as u64,
+        handler_ms = timing.handler.as_millis() as u64,
+        payload_size = payload_size as u64,
+        "Slow event"
+    );
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DispatchTrace {
+    pub event: String,
+    pub queue_wait: Duration,
+    pub handler: Duration,
+    // The size of the payload of the response.
+    pub response_size: usize,
+}
+
 pub type BoxFutureCallback = Box<dyn FnOnce(EventResponse) -> BoxFuture<'static, ()> + 'static + Send + Sync>;
 
 #[derive(Derivative)]
@@ -124,6 +456,8 @@ impl DispatchContext {
 
 pub(crate) struct DispatchService {
     pub(crate) module_map: ModuleMap,
+    pub(crate) middlewares: Arc<Vec<Arc<dyn EventMiddleware>>>,
+    pub(crate) guards: Arc<Vec<Arc<dyn EventGuard>>>,
 }
 
 impl Service<DispatchContext> for DispatchService {
@@ -137,26 +471,43 @@ impl Service<DispatchContext> for DispatchService {
     )]
     fn call(&self, ctx: DispatchContext) -> Self::Future {
         let module_map = self.module_map.clone();
+        let middlewares = self.middlewares.clone();
+        let guards = self.guards.clone();
         let (request, callback) = ctx.into_parts();
 
         Box::pin(async move {
-            let result = {
-                // print_module_map_info(&module_map);
-                match module_map.get(&request.event) {
-                    Some(module) => {
-                        let fut = module.new_service(());
-                        let service_fut = fut.await?.call(request);
-                        service_fut.await
-                    },
-                    None => {
-                        let msg = format!("Can not find the event handler. {:?}", request);
-                        log::error!("{}", msg);
-                        Err(InternalError::HandleNotFound(msg).into())
-                    },
+            let permission = module_map
+                .get(&request.event)
+                .and_then(|module| module.permission(&request.event));
+            if let Err(response) = check_permission(&guards, &request, permission).await {
+                tracing::trace!("Reject event: {:?}", &request.event);
+                if let Some(callback) = callback {
+                    callback(response.clone()).await;
+                }
+                return Ok(response);
+            }
+
+            // The request is only cloned if a middleware can ask for a retry.
+            let response = if middlewares.is_empty() {
+                call_module(&module_map, request).await.unwrap_or_else(|e| e.into())
+            } else {
+                let mut retry = 0;
+                loop {
+                    let dispatched = Dispatched::new(&middlewares, &request);
+                    let response = call_module(&module_map, request.clone())
+                        .await
+                        .unwrap_or_else(|e| e.into());
+                    // Every response is intercepted, the retry is ignored once the
+                    // request was dispatched again MAX_RETRY times.
+                    if dispatched.intercept(&response).await && retry < MAX_RETRY {
+                        retry += 1;
+                        tracing::trace!("Retry event: {:?}", &request.event);
+                        continue;
+                    }
+                    break response;
                 }
             };
 
-            let response = result.unwrap_or_else(|e| e.into());
             tracing::trace!("Dispatch result: {:?}", response);
             if let Some(callback) = callback {
                 callback(response.clone()).await;
@@ -167,6 +518,37 @@ impl Service<DispatchContext> for DispatchService {
     }
 }
 
+// The span of the event is entered each time its future is polled, the spans of
+// the handler, its database writes and its requests are nested in it.
+fn event_span(module_map: &ModuleMap, request: &ModuleRequest) -> tracing::Span {
+    let module = module_map
+        .get(&request.event)
+        .map(|module| module.name.as_str())
+        .unwrap_or("");
+    tracing::info_span!(
+        "event",
+        event = request.event.name(),
+        request_id = request.id.as_str(),
+        module = module
+    )
+}
+
+async fn call_module(module_map: &ModuleMap, request: ModuleRequest) -> Result<EventResponse, DispatchError> {
+    // print_module_map_info(&module_map);
+    match module_map.get(&request.event) {
+        Some(module) => {
+            let fut = module.new_service(());
+            let service_fut = fut.await?.call(request);
+            service_fut.await
+        },
+        None => {
+            let msg = format!("Can not find the event handler. {:?}", request);
+            log::error!("{}", msg);
+            Err(InternalError::HandleNotFound(msg).into())
+        },
+    }
+}
+
 #[allow(dead_code)]
 fn module_info(modules: &Vec<Module>) -> String {
     let mut info = format!("{} modules loaded\n", modules.len());
@@ -3,7 +3,6 @@ use crate::{
     request::EventRequest,
     response::{EventResponse, ResponseBuilder},
 };
-use bytes::Bytes;
 use dyn_clone::DynClone;
 
 use serde::{Serialize, Serializer};
@@ -57,7 +56,7 @@ impl From<protobuf::ProtobufError> for DispatchError {
 }
 
 impl FromBytes for DispatchError {
-    fn parse_from_bytes(bytes: Bytes) -> Result<Self, DispatchError> {
+    fn parse_from_bytes(bytes: &[u8]) -> Result<Self, DispatchError> {
         let s = String::from_utf8(bytes.to_vec()).unwrap();
         Ok(InternalError::DeserializeFromBytes(s).into())
     }
@@ -0,0 +1,99 @@
+use crate::module::Event;
+use dashmap::DashMap;
+use futures_core::ready;
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Mutex,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+// How many times in a row an event has to be handled under the budget before
+// it's run inline.
+const CHEAP_RUNS: u32 = 16;
+
+// The handlers that were cheap the last times they were called, by event. The
+// ones registered with Module::inline_event are trusted until they go over the
+// budget once.
+#[derive(Default)]
+pub(crate) struct EventCosts {
+    cheap_runs: DashMap<Event, u32>,
+}
+
+impl EventCosts {
+    pub(crate) fn is_cheap(&self, event: &Event, marked: bool) -> bool {
+        match self.cheap_runs.get(event) {
+            Some(runs) => *runs >= CHEAP_RUNS,
+            None => marked,
+        }
+    }
+
+    pub(crate) fn record(&self, event: &Event, marked: bool, elapsed: Duration, budget: Duration) {
+        let mut runs = self
+            .cheap_runs
+            .entry(event.clone())
+            .or_insert(if marked { CHEAP_RUNS } else { 0 });
+        *runs = match elapsed <= budget {
+            true => runs.saturating_add(1),
+            false => 0,
+        };
+    }
+}
+
+// Runs the task in the poll of the caller. If it's dropped before the task is
+// done, e.g. the caller only wanted the callback, the task is spawned on the
+// runtime instead so it's still handled.
+pub(crate) struct InlineTask<F>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    task: Mutex<Option<Pin<Box<F>>>>,
+    runtime: tokio::runtime::Handle,
+}
+
+impl<F> InlineTask<F>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    pub(crate) fn new(task: F, runtime: tokio::runtime::Handle) -> Self {
+        Self {
+            task: Mutex::new(Some(Box::pin(task))),
+            runtime,
+        }
+    }
+}
+
+impl<F> Future for InlineTask<F>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut task = self.task.lock().unwrap();
+        match task.as_mut() {
+            Some(fut) => {
+                let output = ready!(fut.as_mut().poll(cx));
+                *task = None;
+                Poll::Ready(output)
+            },
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl<F> Drop for InlineTask<F>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    fn drop(&mut self) {
+        if let Some(task) = self.task.get_mut().ok().and_then(|task| task.take()) {
+            self.runtime.spawn(task);
+        }
+    }
+}
@@ -8,13 +8,39 @@ mod util;
 mod byte_trait;
 mod data;
 mod dispatch;
+mod inline;
+mod link;
+mod middleware;
+mod notification;
+mod params;
+mod profile;
+mod shard;
 mod system;
+mod trace;
 
 #[macro_use]
 pub mod macros;
+#[cfg(any(debug_assertions, feature = "chaos"))]
+pub mod chaos;
+pub mod mock;
+pub mod stress;
 
 pub use errors::Error;
 
 pub mod prelude {
-    pub use crate::{byte_trait::*, data::*, dispatch::*, errors::*, module::*, request::*, response::*};
+    pub use crate::{
+        byte_trait::*,
+        data::*,
+        dispatch::*,
+        errors::*,
+        link::{LinkError, LinkParams, LinkRoute, LinkRouter},
+        middleware::{EventGuard, EventMiddleware},
+        module::*,
+        notification::{notification_center, Notification, NotificationCenter, NotificationFilter, NotificationHandler},
+        params::*,
+        profile::{profiler, CountingAllocator, EventProfile, EventProfiler},
+        request::*,
+        response::*,
+        trace::{tracer, EventTrace, EventTracer},
+    };
 }
@@ -0,0 +1,214 @@
+use crate::module::ModuleRequest;
+use percent_encoding::percent_decode_str;
+use std::{collections::HashMap, fmt, sync::Arc};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkError {
+    // The link isn't scheme://path?query.
+    Malformed(String),
+
+    // No route of its scheme matches the path of the link.
+    NotFound(String),
+
+    // The route matched, but its builder rejected the params, e.g. an unknown
+    // action.
+    Invalid(String),
+}
+
+impl fmt::Display for LinkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinkError::Malformed(uri) => write!(f, "Malformed link: {}", uri),
+            LinkError::NotFound(uri) => write!(f, "No route for the link: {}", uri),
+            LinkError::Invalid(msg) => write!(f, "Invalid link: {}", msg),
+        }
+    }
+}
+
+// What the link gave to its route: the segments captured by the names of the
+// pattern, and the query. They're percent-decoded.
+#[derive(Debug, Clone, Default)]
+pub struct LinkParams {
+    captures: HashMap<String, String>,
+    query: HashMap<String, String>,
+}
+
+impl LinkParams {
+    // The segment captured by :name in the pattern of the route.
+    pub fn get(&self, name: &str) -> Result<&str, LinkError> {
+        self.captures
+            .get(name)
+            .map(|value| value.as_str())
+            .ok_or_else(|| LinkError::Invalid(format!("the route doesn't capture {}", name)))
+    }
+
+    pub fn query(&self, name: &str) -> Option<&str> { self.query.get(name).map(|value| value.as_str()) }
+}
+
+pub type LinkBuilder = Arc<dyn Fn(&LinkParams) -> Result<ModuleRequest, LinkError> + Send + Sync>;
+
+#[derive(Clone, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Capture(String),
+}
+
+// The links of the scheme whose path matches the pattern, e.g.
+// workspace/:workspace_id/view/:view_id, are turned into the request the
+// builder makes from their params.
+#[derive(Clone)]
+pub struct LinkRoute {
+    scheme: String,
+    pattern: String,
+    segments: Vec<Segment>,
+    build: LinkBuilder,
+}
+
+impl LinkRoute {
+    pub fn new<F>(scheme: &str, pattern: &str, build: F) -> Self
+    where
+        F: Fn(&LinkParams) -> Result<ModuleRequest, LinkError> + Send + Sync + 'static,
+    {
+        let segments = pattern
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| match segment.strip_prefix(':') {
+                Some(name) => Segment::Capture(name.to_owned()),
+                None => Segment::Literal(segment.to_owned()),
+            })
+            .collect();
+        Self {
+            scheme: scheme.to_ascii_lowercase(),
+            pattern: pattern.to_owned(),
+            segments,
+            build: Arc::new(build),
+        }
+    }
+
+    pub fn scheme(&self) -> &str { &self.scheme }
+
+    pub fn pattern(&self) -> &str { &self.pattern }
+
+    fn literals(&self) -> usize {
+        self.segments
+            .iter()
+            .filter(|segment| matches!(segment, Segment::Literal(_)))
+            .count()
+    }
+
+    // Two routes with the same shape match the same links, whatever the names
+    // of their captures.
+    fn same_shape(&self, other: &LinkRoute) -> bool {
+        self.scheme == other.scheme
+            && self.segments.len() == other.segments.len()
+            && self.segments.iter().zip(other.segments.iter()).all(|pair| match pair {
+                (Segment::Literal(a), Segment::Literal(b)) => a == b,
+                (Segment::Capture(_), Segment::Capture(_)) => true,
+                _ => false,
+            })
+    }
+
+    fn captures(&self, link: &Link) -> Option<HashMap<String, String>> {
+        if self.scheme != link.scheme || self.segments.len() != link.path.len() {
+            return None;
+        }
+        let mut captures = HashMap::new();
+        for (segment, value) in self.segments.iter().zip(link.path.iter()) {
+            match segment {
+                Segment::Literal(literal) if literal == value => {},
+                Segment::Literal(_) => return None,
+                Segment::Capture(name) => {
+                    captures.insert(name.clone(), value.clone());
+                },
+            }
+        }
+        Some(captures)
+    }
+}
+
+impl fmt::Debug for LinkRoute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{}://{}", self.scheme, self.pattern) }
+}
+
+// Resolves the links with the routes the modules registered, so the deep links
+// of the os and the links inside the app go through the same path.
+#[derive(Clone, Default)]
+pub struct LinkRouter {
+    routes: Vec<LinkRoute>,
+}
+
+impl LinkRouter {
+    pub fn new(routes: Vec<LinkRoute>) -> Self {
+        let mut router = LinkRouter { routes: vec![] };
+        for route in routes {
+            if router.routes.iter().any(|other| other.same_shape(&route)) {
+                log::error!("Duplicate link route: {:?}", route);
+                continue;
+            }
+            router.routes.push(route);
+        }
+        // The routes with more literal segments are tried first, e.g.
+        // workspace/trash before workspace/:workspace_id.
+        router.routes.sort_by(|a, b| b.literals().cmp(&a.literals()));
+        router
+    }
+
+    pub fn resolve(&self, uri: &str) -> Result<ModuleRequest, LinkError> {
+        let link = parse_link(uri)?;
+        for route in &self.routes {
+            if let Some(captures) = route.captures(&link) {
+                let params = LinkParams {
+                    captures,
+                    query: link.query,
+                };
+                return (route.build)(&params);
+            }
+        }
+        Err(LinkError::NotFound(uri.to_owned()))
+    }
+
+    pub fn routes(&self) -> &[LinkRoute] { &self.routes }
+}
+
+struct Link {
+    scheme: String,
+    path: Vec<String>,
+    query: HashMap<String, String>,
+}
+
+// The fragment is dropped, the links address the items instead of the places
+// inside them.
+fn parse_link(uri: &str) -> Result<Link, LinkError> {
+    let malformed = || LinkError::Malformed(uri.to_owned());
+    let (scheme, rest) = uri.trim().split_once("://").ok_or_else(malformed)?;
+    let is_scheme = |c: char| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.';
+    if scheme.is_empty() || !scheme.chars().all(is_scheme) {
+        return Err(malformed());
+    }
+    let rest = rest.split('#').next().unwrap_or_default();
+    let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+    let path = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| decode(segment).ok_or_else(malformed))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut params = HashMap::new();
+    for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+        let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let name = decode(&name.replace('+', " ")).ok_or_else(malformed)?;
+        let value = decode(&value.replace('+', " ")).ok_or_else(malformed)?;
+        params.insert(name, value);
+    }
+    Ok(Link {
+        scheme: scheme.to_ascii_lowercase(),
+        path,
+        query: params,
+    })
+}
+
+fn decode(s: &str) -> Option<String> {
+    percent_decode_str(s)
+        .decode_utf8()
+        .ok()
+        .map(|decoded| decoded.into_owned())
+}
@@ -0,0 +1,98 @@
+use crate::{
+    module::{EventPermission, ModuleRequest},
+    response::EventResponse,
+};
+use futures_core::future::BoxFuture;
+
+// A request is dispatched again at most MAX_RETRY times, no matter how many
+// middlewares ask for it.
+pub(crate) const MAX_RETRY: usize = 1;
+
+// The middleware is called with every response before it's returned to the caller.
+// Returning true dispatches the same request again, e.g. after the middleware
+// refreshed the credentials that the handler failed with.
+pub trait EventMiddleware: Send + Sync {
+    // Called when the request is dispatched to its handler, each call is
+    // followed by the intercept of its response or by the cancelled, e.g. to
+    // keep the credentials the request was sent with.
+    fn dispatched(&self, _request: &ModuleRequest) {}
+
+    // Called instead of the intercept if the response of the dispatched request
+    // never comes, e.g. the caller dropped the future of the request.
+    fn cancelled(&self, _request: &ModuleRequest) {}
+
+    fn intercept(&self, request: &ModuleRequest, response: &EventResponse) -> BoxFuture<'static, bool>;
+}
+
+// The guard is called with every request before it's handled, the permission is None if
+// the event was registered without one. Returning the error response rejects the request
+// without calling the handler.
+pub trait EventGuard: Send + Sync {
+    fn check(
+        &self,
+        request: &ModuleRequest,
+        permission: Option<EventPermission>,
+    ) -> BoxFuture<'static, Result<(), EventResponse>>;
+}
+
+pub(crate) async fn check_permission(
+    guards: &[std::sync::Arc<dyn EventGuard>],
+    request: &ModuleRequest,
+    permission: Option<EventPermission>,
+) -> Result<(), EventResponse> {
+    for guard in guards {
+        guard.check(request, permission).await?;
+    }
+    Ok(())
+}
+
+// Notifies the middlewares that the request is dispatched, they're notified
+// that it's cancelled if it's dropped before its response is intercepted.
+pub(crate) struct Dispatched<'a> {
+    middlewares: &'a [std::sync::Arc<dyn EventMiddleware>],
+    request: &'a ModuleRequest,
+    intercepted: bool,
+}
+
+impl<'a> Dispatched<'a> {
+    pub(crate) fn new(middlewares: &'a [std::sync::Arc<dyn EventMiddleware>], request: &'a ModuleRequest) -> Self {
+        for middleware in middlewares {
+            middleware.dispatched(request);
+        }
+        Self {
+            middlewares,
+            request,
+            intercepted: false,
+        }
+    }
+
+    // Returns true if any middleware asks to dispatch the request again. All the
+    // middlewares are called before their futures are awaited, so each of them
+    // sees the response even if the request is dropped while it's awaited.
+    pub(crate) async fn intercept(mut self, response: &EventResponse) -> bool {
+        let futures = self
+            .middlewares
+            .iter()
+            .map(|middleware| middleware.intercept(self.request, response))
+            .collect::<Vec<_>>();
+        self.intercepted = true;
+
+        let mut retry = false;
+        for future in futures {
+            if future.await {
+                retry = true;
+            }
+        }
+        retry
+    }
+}
+
+impl<'a> Drop for Dispatched<'a> {
+    fn drop(&mut self) {
+        if !self.intercepted {
+            for middleware in self.middlewares {
+                middleware.cancelled(self.request);
+            }
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{
+    byte_trait::ToBytes,
+    errors::DispatchError,
+    module::Module,
+    request::{EventRequest, FromRequest, Payload},
+    response::{EventResponse, StatusCode},
+    util::ready::{ready, Ready},
+};
+use bytes::Bytes;
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+        Mutex,
+    },
+    time::Duration,
+};
+
+// The response a mock event sends, after the delay if it has one.
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    status_code: StatusCode,
+    payload: Option<Bytes>,
+    delay: Option<Duration>,
+}
+
+impl MockResponse {
+    pub fn ok<T: ToBytes>(data: T) -> Self { Self::new(StatusCode::Ok, payload_bytes(data)) }
+
+    pub fn bytes<B: Into<Bytes>>(bytes: B) -> Self { Self::new(StatusCode::Ok, Some(bytes.into())) }
+
+    pub fn empty() -> Self { Self::new(StatusCode::Ok, None) }
+
+    // The error of the module, e.g. the UserError the handlers return.
+    pub fn err<T: ToBytes>(error: T) -> Self { Self::new(StatusCode::Err, payload_bytes(error)) }
+
+    // Like the response of a handler that panicked or couldn't read its request.
+    pub fn internal() -> Self { Self::new(StatusCode::Internal, None) }
+
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    fn new(status_code: StatusCode, payload: Option<Bytes>) -> Self {
+        Self {
+            status_code,
+            payload,
+            delay: None,
+        }
+    }
+
+    fn to_event_response(&self) -> EventResponse {
+        EventResponse {
+            payload: match &self.payload {
+                Some(bytes) => bytes.clone().into(),
+                None => Payload::None,
+            },
+            status_code: self.status_code.clone(),
+        }
+    }
+}
+
+fn payload_bytes<T: ToBytes>(payload: T) -> Option<Bytes> {
+    match payload.into_bytes() {
+        Ok(bytes) => Some(bytes),
+        Err(e) => {
+            log::error!("Set mock payload failed: {:?}", e);
+            None
+        },
+    }
+}
+
+// A request the mock module received.
+#[derive(Clone, Debug)]
+pub struct MockRequest {
+    pub event: String,
+    pub payload: Option<Bytes>,
+}
+
+// The requests of the mock module in the order they were received, it can be
+// read after the module was moved into the dispatcher.
+#[derive(Clone, Default)]
+pub struct MockRecorder {
+    requests: Arc<Mutex<Vec<MockRequest>>>,
+}
+
+impl MockRecorder {
+    pub fn requests(&self) -> Vec<MockRequest> { self.requests.lock().unwrap().clone() }
+
+    pub fn count(&self, event: &str) -> usize {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|request| request.event == event)
+            .count()
+    }
+
+    pub fn clear(&self) { self.requests.lock().unwrap().clear(); }
+
+    fn record(&self, request: MockRequest) { self.requests.lock().unwrap().push(request); }
+}
+
+// Builds a module whose events send canned responses, to test the behaviors of
+// the dispatcher, e.g. its middlewares, without the real modules:
+//
+//     let fetch = vec![MockResponse::internal(), MockResponse::bytes("done")];
+//     let slow = MockResponse::empty().delay(Duration::from_secs(1));
+//     let mock = MockModule::new("mock").responses("fetch", fetch);
+//     let mock = mock.response("slow", slow);
+//     let recorder = mock.recorder();
+//     let dispatch = EventDispatch::construct(|| vec![mock.build()]);
+pub struct MockModule {
+    module: Module,
+    recorder: MockRecorder,
+}
+
+impl MockModule {
+    pub fn new(name: &str) -> Self {
+        Self {
+            module: Module::new().name(name),
+            recorder: MockRecorder::default(),
+        }
+    }
+
+    // The event always sends the response.
+    pub fn response<E>(self, event: E, response: MockResponse) -> Self
+    where
+        E: Eq + Hash + Debug + Clone + Display,
+    {
+        self.responses(event, vec![response])
+    }
+
+    // The event sends the responses one after another, the last one is repeated
+    // once they were all sent, e.g. an error then the data to test a retry.
+    pub fn responses<E>(mut self, event: E, responses: Vec<MockResponse>) -> Self
+    where
+        E: Eq + Hash + Debug + Clone + Display,
+    {
+        assert!(!responses.is_empty(), "The mock event needs a response");
+        let mock_event = Arc::new(MockEvent {
+            event: event.to_string(),
+            responses,
+            calls: AtomicUsize::new(0),
+            recorder: self.recorder.clone(),
+        });
+        let handler = move |payload: MockPayload| {
+            let mock_event = mock_event.clone();
+            async move { mock_event.handle(payload.0).await }
+        };
+        self.module = self.module.event(event, handler);
+        self
+    }
+
+    pub fn recorder(&self) -> MockRecorder { self.recorder.clone() }
+
+    pub fn build(self) -> Module { self.module }
+}
+
+struct MockEvent {
+    event: String,
+    responses: Vec<MockResponse>,
+    calls: AtomicUsize,
+    recorder: MockRecorder,
+}
+
+impl MockEvent {
+    async fn handle(&self, payload: Option<Bytes>) -> EventResponse {
+        self.recorder.record(MockRequest {
+            event: self.event.clone(),
+            payload,
+        });
+        let call = self.calls.fetch_add(1, Ordering::SeqCst);
+        let response = &self.responses[call.min(self.responses.len() - 1)];
+        if let Some(delay) = response.delay {
+            tokio::time::sleep(delay).await;
+        }
+        response.to_event_response()
+    }
+}
+
+// The raw payload of the request, the mock events don't parse it.
+pub(crate) struct MockPayload(pub(crate) Option<Bytes>);
+
+impl FromRequest for MockPayload {
+    type Error = DispatchError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(_req: &EventRequest, payload: &mut Payload) -> Self::Future {
+        ready(Ok(MockPayload(payload.to_bytes())))
+    }
+}
@@ -1,7 +1,9 @@
 pub use container::*;
 pub use data::*;
 pub use module::*;
+pub use table::*;
 
 mod container;
 mod data;
 mod module;
+mod table;
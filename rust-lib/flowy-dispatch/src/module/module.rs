@@ -1,9 +1,9 @@
 use std::{
-    collections::HashMap,
+    collections::{hash_map::DefaultHasher, HashMap},
     fmt,
     fmt::{Debug, Display},
     future::Future,
-    hash::Hash,
+    hash::{BuildHasherDefault, Hash, Hasher},
     pin::Pin,
     task::{Context, Poll},
 };
@@ -12,8 +12,10 @@ use futures_core::ready;
 use pin_project::pin_project;
 
 use crate::{
+    byte_trait::PayloadEncoding,
     errors::{DispatchError, InternalError},
-    module::{container::ModuleDataMap, Unit},
+    link::{LinkError, LinkParams, LinkRoute},
+    module::{container::ModuleDataMap, EventSlot, EventTable, Unit},
     request::{payload::Payload, EventRequest, FromRequest},
     response::{EventResponse, Responder},
     service::{
@@ -31,24 +33,76 @@ use crate::{
 use futures_core::future::BoxFuture;
 use std::sync::Arc;
 
-pub type ModuleMap = Arc<HashMap<Event, Arc<Module>>>;
-pub(crate) fn as_module_map(modules: Vec<Module>) -> ModuleMap {
-    let mut module_map = HashMap::new();
-    modules.into_iter().for_each(|m| {
-        let events = m.events();
-        let module = Arc::new(m);
-        events.into_iter().for_each(|e| {
-            module_map.insert(e, module.clone());
-        });
-    });
-    Arc::new(module_map)
-}
+// The maps keyed by the events, they use the hash the event computed when it
+// was built instead of hashing its name on each lookup.
+pub type EventMap<V> = HashMap<Event, V, BuildHasherDefault<EventHasher>>;
+
+// Built once, then shared by the requests without being cloned or locked.
+pub type ModuleMap = Arc<EventTable>;
+pub(crate) fn as_module_map(modules: Vec<Module>) -> ModuleMap { Arc::new(EventTable::new(modules)) }
 
-#[derive(PartialEq, Eq, Hash, Debug, Clone)]
-pub struct Event(String);
+#[derive(Clone)]
+pub struct Event {
+    name: String,
+    hash: u64,
+    pub(crate) slot: Option<EventSlot>,
+}
 
 impl<T: Display + Eq + Hash + Debug + Clone> std::convert::From<T> for Event {
-    fn from(t: T) -> Self { Event(format!("{}", t)) }
+    fn from(t: T) -> Self {
+        let name = format!("{}", t);
+        let mut hasher = DefaultHasher::new();
+        name.hash(&mut hasher);
+        Event {
+            name,
+            hash: hasher.finish(),
+            slot: None,
+        }
+    }
+}
+
+impl Event {
+    pub fn name(&self) -> &str { &self.name }
+}
+
+impl PartialEq for Event {
+    fn eq(&self, other: &Self) -> bool { self.hash == other.hash && self.name == other.name }
+}
+
+impl Eq for Event {}
+
+impl Hash for Event {
+    fn hash<H: Hasher>(&self, state: &mut H) { state.write_u64(self.hash) }
+}
+
+impl Debug for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("Event").field(&self.name).finish() }
+}
+
+// Passes the hash of the event through, the events are the only keys it's used
+// with.
+#[derive(Default)]
+pub struct EventHasher(u64);
+
+impl Hasher for EventHasher {
+    fn finish(&self) -> u64 { self.0 }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ *byte as u64).wrapping_mul(0x100_0000_01b3);
+        }
+    }
+
+    fn write_u64(&mut self, hash: u64) { self.0 = hash; }
+}
+
+// The access an event needs, declared when the event is registered. The dispatcher
+// passes it to the EventGuards before the event is handled.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub enum EventPermission {
+    Read,
+    Write,
+    Manage,
 }
 
 pub type EventServiceFactory = BoxServiceFactory<(), ServiceRequest, ServiceResponse, DispatchError>;
@@ -56,7 +110,10 @@ pub type EventServiceFactory = BoxServiceFactory<(), ServiceRequest, ServiceResp
 pub struct Module {
     pub name: String,
     module_data: Arc<ModuleDataMap>,
-    service_map: Arc<HashMap<Event, EventServiceFactory>>,
+    service_map: Arc<EventMap<EventServiceFactory>>,
+    permission_map: Arc<EventMap<EventPermission>>,
+    inline_events: Arc<EventMap<()>>,
+    links: Vec<LinkRoute>,
 }
 
 impl Module {
@@ -64,7 +121,10 @@ impl Module {
         Self {
             name: "".to_owned(),
             module_data: Arc::new(ModuleDataMap::new()),
-            service_map: Arc::new(HashMap::new()),
+            service_map: Arc::new(EventMap::default()),
+            permission_map: Arc::new(EventMap::default()),
+            inline_events: Arc::new(EventMap::default()),
+            links: vec![],
         }
     }
 
@@ -99,6 +159,58 @@ impl Module {
         self
     }
 
+    pub fn event_with_permission<E, H, T, R>(mut self, event: E, handler: H, permission: EventPermission) -> Self
+    where
+        H: Handler<T, R>,
+        T: FromRequest + 'static + Send + Sync,
+        <T as FromRequest>::Future: Sync + Send,
+        R: Future + 'static + Send + Sync,
+        R::Output: Responder + 'static,
+        E: Eq + Hash + Debug + Clone + Display,
+    {
+        Arc::get_mut(&mut self.permission_map)
+            .unwrap()
+            .insert(event.clone().into(), permission);
+        self.event(event, handler)
+    }
+
+    // The handler is cheap, e.g. it only reads the state of the module, so it's
+    // run in the poll of the caller instead of being spawned, see
+    // EventDispatch::inline_budget.
+    pub fn inline_event<E, H, T, R>(mut self, event: E, handler: H) -> Self
+    where
+        H: Handler<T, R>,
+        T: FromRequest + 'static + Send + Sync,
+        <T as FromRequest>::Future: Sync + Send,
+        R: Future + 'static + Send + Sync,
+        R::Output: Responder + 'static,
+        E: Eq + Hash + Debug + Clone + Display,
+    {
+        Arc::get_mut(&mut self.inline_events)
+            .unwrap()
+            .insert(event.clone().into(), ());
+        self.event(event, handler)
+    }
+
+    // The links of the scheme that match the pattern are sent as the request the
+    // builder makes from their params, see EventDispatch::open_link. The
+    // segments of the pattern that start with : capture the ones of the link,
+    // e.g. workspace/:workspace_id.
+    pub fn link<F>(mut self, scheme: &str, pattern: &str, build: F) -> Self
+    where
+        F: Fn(&LinkParams) -> Result<ModuleRequest, LinkError> + Send + Sync + 'static,
+    {
+        self.links.push(LinkRoute::new(scheme, pattern, build));
+        self
+    }
+
+    pub fn links(&self) -> &[LinkRoute] { &self.links }
+
+    pub fn is_inline(&self, event: &Event) -> bool { self.inline_events.contains_key(event) }
+
+    // None if the event was registered without a permission, it's not guarded then.
+    pub fn permission(&self, event: &Event) -> Option<EventPermission> { self.permission_map.get(event).cloned() }
+
     pub fn events(&self) -> Vec<Event> { self.service_map.keys().map(|key| key.clone()).collect::<Vec<_>>() }
 }
 
@@ -107,6 +219,8 @@ pub struct ModuleRequest {
     pub id: String,
     pub event: Event,
     pub(crate) payload: Payload,
+    pub(crate) encoding: PayloadEncoding,
+    pub(crate) key: Option<String>,
 }
 
 impl ModuleRequest {
@@ -118,6 +232,8 @@ impl ModuleRequest {
             id: uuid::Uuid::new_v4().to_string(),
             event: event.into(),
             payload: Payload::None,
+            encoding: PayloadEncoding::default(),
+            key: None,
         }
     }
 
@@ -128,6 +244,24 @@ impl ModuleRequest {
         self.payload = payload.into();
         self
     }
+
+    pub fn encoding(mut self, encoding: PayloadEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    // The resource the request is about, e.g. the id of the document. When the
+    // dispatcher is sharded, the requests with the same key are handled by the
+    // same shard, one after another in the order they were sent.
+    pub fn key(mut self, key: &str) -> Self {
+        self.key = Some(key.to_owned());
+        self
+    }
+
+    // The payload as it was sent, for the middlewares that inspect the requests.
+    pub fn payload_bytes(&self) -> Option<&[u8]> { self.payload.as_bytes() }
+
+    pub fn payload_encoding(&self) -> PayloadEncoding { self.encoding }
 }
 
 impl std::fmt::Display for ModuleRequest {
@@ -156,7 +290,7 @@ impl ServiceFactory<ModuleRequest> for Module {
 }
 
 pub struct ModuleService {
-    service_map: Arc<HashMap<Event, EventServiceFactory>>,
+    service_map: Arc<EventMap<EventServiceFactory>>,
     module_data: Arc<ModuleDataMap>,
 }
 
@@ -166,9 +300,15 @@ impl Service<ModuleRequest> for ModuleService {
     type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
 
     fn call(&self, request: ModuleRequest) -> Self::Future {
-        let ModuleRequest { id, event, payload } = request;
+        let ModuleRequest {
+            id,
+            event,
+            payload,
+            encoding,
+            ..
+        } = request;
         let module_data = self.module_data.clone();
-        let request = EventRequest::new(id.clone(), event, module_data);
+        let request = EventRequest::new(id, event, module_data).encoding(encoding);
 
         match self.service_map.get(&request.event) {
             Some(factory) => {
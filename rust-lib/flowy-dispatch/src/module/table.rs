@@ -0,0 +1,82 @@
+use crate::module::{Event, EventMap, Module};
+use std::sync::{
+    atomic::{AtomicU32, Ordering},
+    Arc,
+};
+
+static NEXT_TABLE_ID: AtomicU32 = AtomicU32::new(1);
+
+// Where the event is in the table that resolved it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct EventSlot {
+    table: u32,
+    index: u32,
+}
+
+// The events of the modules, compiled into a dense table when the dispatcher is
+// built. The event that was resolved by the table carries its slot, its module
+// is found by indexing the table. The others, e.g. the events resolved by
+// another dispatcher, are still looked up by their name.
+pub struct EventTable {
+    id: u32,
+    entries: Vec<(Event, Arc<Module>)>,
+    slots: EventMap<u32>,
+}
+
+impl EventTable {
+    pub(crate) fn new(modules: Vec<Module>) -> Self {
+        let id = NEXT_TABLE_ID.fetch_add(1, Ordering::Relaxed);
+        let mut entries: Vec<(Event, Arc<Module>)> = vec![];
+        let mut slots = EventMap::default();
+        for module in modules {
+            let mut events = module.events();
+            // The slots don't depend on the order of the hash map then.
+            events.sort_by(|a, b| a.name().cmp(b.name()));
+            let module = Arc::new(module);
+            for event in events {
+                match slots.get(&event) {
+                    // The module registered last handles the event, like before.
+                    Some(&index) => entries[index as usize].1 = module.clone(),
+                    None => {
+                        slots.insert(event.clone(), entries.len() as u32);
+                        entries.push((event, module.clone()));
+                    },
+                }
+            }
+        }
+        Self { id, entries, slots }
+    }
+
+    // The event with its slot in the table, it's sent without being looked up
+    // by its name again. It's returned as it is if it's not registered.
+    pub fn resolve<E: Into<Event>>(&self, event: E) -> Event {
+        let mut event = event.into();
+        if self.own_slot(&event).is_none() {
+            event.slot = self.slots.get(&event).map(|&index| EventSlot { table: self.id, index });
+        }
+        event
+    }
+
+    pub fn get(&self, event: &Event) -> Option<&Arc<Module>> {
+        let index = match self.own_slot(event) {
+            Some(index) => index,
+            None => *self.slots.get(event)?,
+        };
+        self.entries.get(index as usize).map(|(_, module)| module)
+    }
+
+    pub fn len(&self) -> usize { self.entries.len() }
+
+    pub fn is_empty(&self) -> bool { self.entries.is_empty() }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Event, &Arc<Module>)> {
+        self.entries.iter().map(|(event, module)| (event, module))
+    }
+
+    fn own_slot(&self, event: &Event) -> Option<u32> {
+        match event.slot {
+            Some(slot) if slot.table == self.id => Some(slot.index),
+            _ => None,
+        }
+    }
+}
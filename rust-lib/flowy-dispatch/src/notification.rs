@@ -0,0 +1,208 @@
+use bytes::Bytes;
+use std::{
+    collections::{hash_map::DefaultHasher, VecDeque},
+    hash::{Hash, Hasher},
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+        Mutex,
+        RwLock,
+    },
+};
+
+// The notifications of the objects that hash to the same lane are delivered
+// one after another.
+const LANES: usize = 16;
+
+lazy_static::lazy_static! {
+    static ref NOTIFICATION_CENTER: NotificationCenter = NotificationCenter::default();
+}
+
+// The center the modules of the process publish their notifications to.
+pub fn notification_center() -> &'static NotificationCenter { &NOTIFICATION_CENTER }
+
+// Tells the subscribers that an object changed, e.g. the views of an app. The
+// ty is the notification enum of the source module, the payload and the error
+// are encoded by the module.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Notification {
+    pub source: String,
+    pub id: String,
+    pub ty: i32,
+    pub payload: Option<Bytes>,
+    pub error: Option<Bytes>,
+}
+
+impl Notification {
+    pub fn new<T: Into<i32>>(source: &str, id: &str, ty: T) -> Self {
+        Self {
+            source: source.to_owned(),
+            id: id.to_owned(),
+            ty: ty.into(),
+            payload: None,
+            error: None,
+        }
+    }
+
+    pub fn payload(mut self, payload: Bytes) -> Self {
+        self.payload = Some(payload);
+        self
+    }
+
+    pub fn error(mut self, error: Bytes) -> Self {
+        self.error = Some(error);
+        self
+    }
+}
+
+// The notifications a subscriber receives, the unset fields match any value.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NotificationFilter {
+    source: Option<String>,
+    id: Option<String>,
+    ty: Option<i32>,
+}
+
+impl NotificationFilter {
+    pub fn all() -> Self { Self::default() }
+
+    pub fn source(mut self, source: &str) -> Self {
+        self.source = Some(source.to_owned());
+        self
+    }
+
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_owned());
+        self
+    }
+
+    pub fn ty<T: Into<i32>>(mut self, ty: T) -> Self {
+        self.ty = Some(ty.into());
+        self
+    }
+
+    pub fn matches(&self, notification: &Notification) -> bool {
+        self.source
+            .as_ref()
+            .map_or(true, |source| source == &notification.source)
+            && self.id.as_ref().map_or(true, |id| id == &notification.id)
+            && self.ty.map_or(true, |ty| ty == notification.ty)
+    }
+}
+
+pub type NotificationHandler = Arc<dyn Fn(&Notification) + Send + Sync>;
+
+struct Subscriber {
+    id: u64,
+    filter: NotificationFilter,
+    handler: NotificationHandler,
+}
+
+#[derive(Default)]
+struct Lane {
+    queue: VecDeque<Notification>,
+    delivering: bool,
+}
+
+// Delivers the notifications of the modules to the subscribers whose filter
+// matches them: the flutter port, the ipc connections, the modules and the
+// tests. The notifications of an object are delivered in the order they were
+// published, the handlers are called on the thread of a publisher. A handler
+// that publishes doesn't block, its notification is queued after the current
+// one.
+pub struct NotificationCenter {
+    subscribers: RwLock<Vec<Arc<Subscriber>>>,
+    lanes: Vec<Mutex<Lane>>,
+    next_id: AtomicU64,
+}
+
+impl std::default::Default for NotificationCenter {
+    fn default() -> Self {
+        Self {
+            subscribers: RwLock::new(vec![]),
+            lanes: (0..LANES).map(|_| Mutex::new(Lane::default())).collect(),
+            next_id: AtomicU64::new(1),
+        }
+    }
+}
+
+impl NotificationCenter {
+    // Returns the id to unsubscribe with.
+    pub fn subscribe<F>(&self, filter: NotificationFilter, handler: F) -> u64
+    where
+        F: Fn(&Notification) + Send + Sync + 'static,
+    {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        let subscriber = Subscriber {
+            id,
+            filter,
+            handler: Arc::new(handler),
+        };
+        self.subscribers.write().unwrap().push(Arc::new(subscriber));
+        id
+    }
+
+    // The notifications that are being delivered can still reach the handler.
+    pub fn unsubscribe(&self, id: u64) {
+        self.subscribers
+            .write()
+            .unwrap()
+            .retain(|subscriber| subscriber.id != id);
+    }
+
+    pub fn publish(&self, notification: Notification) {
+        let lane = &self.lanes[lane_index(&notification)];
+        {
+            let mut lane = lane.lock().unwrap();
+            lane.queue.push_back(notification);
+            // The publisher that's delivering the lane delivers it too.
+            if lane.delivering {
+                return;
+            }
+            lane.delivering = true;
+        }
+        loop {
+            let notification = {
+                let mut lane = lane.lock().unwrap();
+                match lane.queue.pop_front() {
+                    Some(notification) => notification,
+                    None => {
+                        lane.delivering = false;
+                        return;
+                    },
+                }
+            };
+            self.deliver(&notification);
+        }
+    }
+
+    fn deliver(&self, notification: &Notification) {
+        let subscribers = self
+            .subscribers
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|subscriber| subscriber.filter.matches(notification))
+            .cloned()
+            .collect::<Vec<_>>();
+        for subscriber in subscribers {
+            // The lane stays usable if a handler panics.
+            if catch_unwind(AssertUnwindSafe(|| (subscriber.handler)(notification))).is_err() {
+                log::error!(
+                    "The subscriber {} panicked on the notification {} of {}",
+                    subscriber.id,
+                    notification.ty,
+                    notification.id
+                );
+            }
+        }
+    }
+}
+
+fn lane_index(notification: &Notification) -> usize {
+    let mut hasher = DefaultHasher::new();
+    notification.source.hash(&mut hasher);
+    notification.id.hash(&mut hasher);
+    (hasher.finish() % LANES as u64) as usize
+}
@@ -0,0 +1,66 @@
+use crate::{
+    byte_trait::*,
+    errors::{DispatchError, Error},
+    request::{unexpected_none_payload, EventRequest, FromRequest, Payload},
+    util::ready::{ready, Ready},
+};
+use std::{marker::PhantomData, ops};
+
+// The params that are checked from a request, e.g. the SignInParams from the
+// SignInRequest. It's implemented by #[derive(FromPayload)].
+pub trait FromPayload: Sized {
+    type Request: FromBytes;
+    type Error;
+
+    fn from_payload(request: Self::Request) -> Result<Self, Self::Error>;
+}
+
+// The payload is parsed as the request of the params and then checked. The
+// handler isn't called if the check fails, its error is sent as the error E of
+// the module instead, e.g. Params<SignInParams, UserError>.
+pub struct Params<T, E> {
+    inner: T,
+    phantom: PhantomData<E>,
+}
+
+impl<T, E> Params<T, E> {
+    pub fn into_inner(self) -> T { self.inner }
+}
+
+impl<T, E> ops::Deref for Params<T, E> {
+    type Target = T;
+
+    fn deref(&self) -> &T { &self.inner }
+}
+
+impl<T, E> FromRequest for Params<T, E>
+where
+    T: FromPayload + 'static,
+    E: From<T::Error> + Error + 'static,
+{
+    type Error = DispatchError;
+    type Future = Ready<Result<Self, DispatchError>>;
+
+    #[inline]
+    fn from_request(req: &EventRequest, payload: &mut Payload) -> Self::Future {
+        match payload.as_bytes() {
+            None => ready(Err(unexpected_none_payload(req))),
+            Some(bytes) => ready(parse_params(bytes, req.encoding)),
+        }
+    }
+}
+
+fn parse_params<T, E>(bytes: &[u8], encoding: PayloadEncoding) -> Result<Params<T, E>, DispatchError>
+where
+    T: FromPayload,
+    E: From<T::Error> + Error + 'static,
+{
+    let request = T::Request::parse_from_bytes_with(bytes, encoding)?;
+    match T::from_payload(request) {
+        Ok(inner) => Ok(Params {
+            inner,
+            phantom: PhantomData,
+        }),
+        Err(e) => Err(E::from(e).into()),
+    }
+}
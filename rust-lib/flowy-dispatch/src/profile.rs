@@ -0,0 +1,197 @@
+use pin_project::pin_project;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+lazy_static::lazy_static! {
+    static ref PROFILER: EventProfiler = EventProfiler::default();
+}
+
+// The profiler of the events dispatched in the process.
+pub fn profiler() -> &'static EventProfiler { &PROFILER }
+
+// The invocations of an event since the profiling was enabled. The busy time is
+// the time spent in the polls of the handler, the wall time includes the waits.
+// The allocations are only counted if the app installed CountingAllocator.
+#[derive(Debug, Clone, Default)]
+pub struct EventProfile {
+    pub event: String,
+    pub count: u64,
+    pub wall_time: Duration,
+    pub max_wall_time: Duration,
+    pub busy_time: Duration,
+    pub polls: u64,
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+}
+
+// Measures each handler invocation when it's enabled, it's disabled by default.
+#[derive(Default)]
+pub struct EventProfiler {
+    enabled: AtomicBool,
+    profiles: Mutex<HashMap<String, EventProfile>>,
+}
+
+impl EventProfiler {
+    // The profiles of the previous run are dropped when it's enabled again.
+    pub fn set_enabled(&self, enabled: bool) {
+        let was_enabled = self.enabled.swap(enabled, Ordering::SeqCst);
+        if enabled && !was_enabled {
+            if let Ok(mut profiles) = self.profiles.lock() {
+                profiles.clear();
+            }
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool { self.enabled.load(Ordering::SeqCst) }
+
+    // The allocations of the profiles are 0 unless it's true.
+    pub fn is_counting_allocations(&self) -> bool { ALLOCATOR_INSTALLED.load(Ordering::Relaxed) }
+
+    // The top most expensive events, by their total wall time.
+    pub fn report(&self, top: usize) -> Vec<EventProfile> {
+        match self.profiles.lock() {
+            Ok(profiles) => top_profiles(&profiles, top),
+            Err(_) => vec![],
+        }
+    }
+
+    // None instead of waiting if the profiles are locked.
+    pub fn try_report(&self, top: usize) -> Option<Vec<EventProfile>> {
+        let profiles = self.profiles.try_lock().ok()?;
+        Some(top_profiles(&profiles, top))
+    }
+
+    fn record(&self, invocation: Invocation) {
+        let wall_time = invocation.started_at.elapsed();
+        if let Ok(mut profiles) = self.profiles.lock() {
+            let profile = profiles
+                .entry(invocation.event.clone())
+                .or_insert_with(|| EventProfile {
+                    event: invocation.event,
+                    ..Default::default()
+                });
+            profile.count += 1;
+            profile.wall_time += wall_time;
+            profile.max_wall_time = profile.max_wall_time.max(wall_time);
+            profile.busy_time += invocation.busy_time;
+            profile.polls += invocation.polls;
+            profile.allocations += invocation.allocations;
+            profile.allocated_bytes += invocation.allocated_bytes;
+        }
+    }
+}
+
+fn top_profiles(profiles: &HashMap<String, EventProfile>, top: usize) -> Vec<EventProfile> {
+    let mut profiles = profiles.values().cloned().collect::<Vec<_>>();
+    profiles.sort_by(|a, b| b.wall_time.cmp(&a.wall_time));
+    profiles.truncate(top);
+    profiles
+}
+
+struct Invocation {
+    event: String,
+    started_at: Instant,
+    busy_time: Duration,
+    polls: u64,
+    allocations: u64,
+    allocated_bytes: u64,
+}
+
+// Records the invocation once the future is done, it's only measured if the
+// profiler was enabled when it was created.
+#[pin_project]
+pub(crate) struct Profiled<F> {
+    #[pin]
+    fut: F,
+    invocation: Option<Invocation>,
+}
+
+impl<F> Profiled<F> {
+    pub(crate) fn new(fut: F, event: &str) -> Self {
+        let invocation = match profiler().is_enabled() {
+            true => Some(Invocation {
+                event: event.to_owned(),
+                started_at: Instant::now(),
+                busy_time: Duration::from_secs(0),
+                polls: 0,
+                allocations: 0,
+                allocated_bytes: 0,
+            }),
+            false => None,
+        };
+        Self { fut, invocation }
+    }
+}
+
+impl<F: Future> Future for Profiled<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let invocation = match this.invocation {
+            Some(invocation) => invocation,
+            None => return this.fut.poll(cx),
+        };
+
+        let (allocations, allocated_bytes) = thread_allocations();
+        let polled_at = Instant::now();
+        let poll = this.fut.poll(cx);
+        invocation.busy_time += polled_at.elapsed();
+        invocation.polls += 1;
+        let (allocations_after, allocated_bytes_after) = thread_allocations();
+        invocation.allocations += allocations_after - allocations;
+        invocation.allocated_bytes += allocated_bytes_after - allocated_bytes;
+
+        if poll.is_ready() {
+            if let Some(invocation) = this.invocation.take() {
+                profiler().record(invocation);
+            }
+        }
+        poll
+    }
+}
+
+static ALLOCATOR_INSTALLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static THREAD_ALLOCATIONS: Cell<(u64, u64)> = Cell::new((0, 0));
+}
+
+fn thread_allocations() -> (u64, u64) { THREAD_ALLOCATIONS.try_with(|count| count.get()).unwrap_or((0, 0)) }
+
+fn count_allocation(size: usize) {
+    ALLOCATOR_INSTALLED.store(true, Ordering::Relaxed);
+    let _ = THREAD_ALLOCATIONS.try_with(|count| {
+        let (allocations, bytes) = count.get();
+        count.set((allocations + 1, bytes + size as u64));
+    });
+}
+
+// The system allocator that counts the allocations of each thread, so the
+// profiles have them. The app installs it with #[global_allocator].
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count_allocation(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) { System.dealloc(ptr, layout) }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count_allocation(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{
+    errors::DispatchError,
+    request::{payload::Payload, EventRequest, FromRequest},
+    util::ready::{ready, Ready},
+};
+use bumpalo::Bump;
+use std::sync::{Arc, Mutex};
+
+// The scratch memory of a request, for the temporary buffers of the hot
+// handlers, e.g. the words of a search query. It's released all at once after
+// the response was sent to the callback, instead of buffer by buffer.
+//
+//     async fn handler(data: Data<Params>, arena: Arena) -> ... {
+//         let query = arena.scope(|bump| {
+//             let mut query = bumpalo::collections::String::new_in(bump);
+//             ...
+//             query.to_string()
+//         });
+//     }
+#[derive(Clone, Default)]
+pub struct Arena(Arc<RequestArena>);
+
+impl std::ops::Deref for Arena {
+    type Target = RequestArena;
+
+    fn deref(&self) -> &RequestArena { &self.0 }
+}
+
+#[derive(Default)]
+pub struct RequestArena {
+    bump: Mutex<Bump>,
+}
+
+impl RequestArena {
+    // What the closure returns can't borrow from the arena, so the allocations
+    // are only used while the arena is locked.
+    pub fn scope<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&Bump) -> R,
+    {
+        let bump = self.bump.lock().unwrap();
+        f(&bump)
+    }
+
+    pub fn allocated_bytes(&self) -> usize { self.bump.lock().unwrap().allocated_bytes() }
+}
+
+impl Drop for RequestArena {
+    fn drop(&mut self) {
+        let bytes = self.bump.get_mut().map(|bump| bump.allocated_bytes()).unwrap_or(0);
+        if bytes > 0 {
+            flowy_metrics::registry()
+                .histogram("dispatch_arena_bytes", &[])
+                .observe(bytes as f64);
+        }
+    }
+}
+
+impl FromRequest for Arena {
+    type Error = DispatchError;
+    type Future = Ready<Result<Self, DispatchError>>;
+
+    #[inline]
+    fn from_request(req: &EventRequest, _: &mut Payload) -> Self::Future { ready(Ok(req.arena.clone())) }
+}
@@ -0,0 +1,58 @@
+use bytes::Bytes;
+use std::cell::RefCell;
+
+// The payload buffers of the requests and the responses are taken from a pool
+// of the thread instead of being allocated for each event. A buffer goes back
+// to the pool of the thread that drops it, the pools aren't shared, so they're
+// never locked.
+//
+//     let mut buffer = take_buffer(bytes.len());
+//     buffer.extend_from_slice(bytes);
+//     let payload = pooled_bytes(buffer);
+const BUFFER_POOL_SIZE: usize = 16;
+
+// The buffers that grew over the limit aren't kept, a large document would keep
+// its memory in the pool otherwise.
+const BUFFER_RETAINED_BYTES: usize = 64 * 1024;
+
+thread_local! {
+    static BUFFER_POOL: RefCell<Vec<Vec<u8>>> = RefCell::new(Vec::with_capacity(BUFFER_POOL_SIZE));
+}
+
+// An empty buffer that holds at least the capacity without growing.
+pub fn take_buffer(capacity: usize) -> Vec<u8> {
+    let buffer = BUFFER_POOL.try_with(|pool| pool.borrow_mut().pop()).ok().flatten();
+    match buffer {
+        Some(mut buffer) => {
+            buffer.reserve(capacity);
+            buffer
+        },
+        None => Vec::with_capacity(capacity),
+    }
+}
+
+pub fn recycle_buffer(mut buffer: Vec<u8>) {
+    if buffer.capacity() == 0 || buffer.capacity() > BUFFER_RETAINED_BYTES {
+        return;
+    }
+    buffer.clear();
+    let _ = BUFFER_POOL.try_with(|pool| {
+        let mut pool = pool.borrow_mut();
+        if pool.len() < BUFFER_POOL_SIZE {
+            pool.push(buffer);
+        }
+    });
+}
+
+// The buffer is recycled once the bytes and their clones are dropped.
+pub fn pooled_bytes(buffer: Vec<u8>) -> Bytes { Bytes::from_owner(PooledBuffer(buffer)) }
+
+struct PooledBuffer(Vec<u8>);
+
+impl AsRef<[u8]> for PooledBuffer {
+    fn as_ref(&self) -> &[u8] { &self.0 }
+}
+
+impl Drop for PooledBuffer {
+    fn drop(&mut self) { recycle_buffer(std::mem::take(&mut self.0)); }
+}
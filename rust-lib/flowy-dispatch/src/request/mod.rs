@@ -1,5 +1,9 @@
+mod arena;
+mod buffer;
 pub mod payload;
 mod request;
 
+pub use arena::*;
+pub use buffer::*;
 pub use payload::*;
 pub use request::*;
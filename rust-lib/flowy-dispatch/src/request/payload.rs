@@ -1,13 +1,84 @@
+use crate::request::{pooled_bytes, take_buffer};
 use bytes::Bytes;
 use std::{fmt, fmt::Formatter};
 
 pub enum PayloadError {}
 
+// The payloads up to this size are stored in the request or the response
+// instead of on the heap, most of the events only carry an id or a flag.
+pub const INLINE_PAYLOAD_CAPACITY: usize = 64;
+
 // TODO: support stream data
 #[derive(Clone, serde::Serialize)]
 pub enum Payload {
     None,
     Bytes(Bytes),
+    Inline(InlinePayload),
+}
+
+impl Payload {
+    // The small payloads are copied inline, the others are copied to a buffer of
+    // the pool, see take_buffer.
+    pub fn from_slice(bytes: &[u8]) -> Payload {
+        match InlinePayload::new(bytes) {
+            Some(inline) => Payload::Inline(inline),
+            None => {
+                let mut buffer = take_buffer(bytes.len());
+                buffer.extend_from_slice(bytes);
+                Payload::Bytes(pooled_bytes(buffer))
+            },
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Payload::Bytes(bytes) => Some(bytes),
+            Payload::Inline(inline) => Some(inline.as_slice()),
+            Payload::None => None,
+        }
+    }
+
+    // The inline payloads are copied to a new buffer.
+    pub fn to_bytes(&self) -> Option<Bytes> {
+        match self {
+            Payload::Bytes(bytes) => Some(bytes.clone()),
+            Payload::Inline(inline) => Some(Bytes::copy_from_slice(inline.as_slice())),
+            Payload::None => None,
+        }
+    }
+
+    pub(crate) fn size(&self) -> usize { self.as_bytes().map(|bytes| bytes.len()).unwrap_or(0) }
+}
+
+#[derive(Clone, Copy)]
+pub struct InlinePayload {
+    len: u8,
+    data: [u8; INLINE_PAYLOAD_CAPACITY],
+}
+
+impl InlinePayload {
+    pub fn new(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() > INLINE_PAYLOAD_CAPACITY {
+            return None;
+        }
+        let mut data = [0; INLINE_PAYLOAD_CAPACITY];
+        data[..bytes.len()].copy_from_slice(bytes);
+        Some(Self {
+            len: bytes.len() as u8,
+            data,
+        })
+    }
+
+    pub fn as_slice(&self) -> &[u8] { &self.data[..self.len as usize] }
+}
+
+impl serde::Serialize for InlinePayload {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_bytes(self.as_slice())
+    }
 }
 
 impl std::fmt::Debug for Payload {
@@ -19,22 +90,30 @@ impl std::fmt::Display for Payload {
 }
 
 fn format_payload_print(payload: &Payload, f: &mut Formatter<'_>) -> fmt::Result {
-    match payload {
-        Payload::Bytes(bytes) => f.write_fmt(format_args!("{} bytes", bytes.len())),
-        Payload::None => f.write_str("Empty"),
+    match payload.as_bytes() {
+        Some(bytes) => f.write_fmt(format_args!("{} bytes", bytes.len())),
+        None => f.write_str("Empty"),
     }
 }
 
 impl std::convert::Into<Payload> for String {
-    fn into(self) -> Payload { Payload::Bytes(Bytes::from(self)) }
+    fn into(self) -> Payload { self.into_bytes().into() }
 }
 
 impl std::convert::Into<Payload> for &'_ String {
-    fn into(self) -> Payload { Payload::Bytes(Bytes::from(self.to_owned())) }
+    fn into(self) -> Payload { Payload::from_slice(self.as_bytes()) }
 }
 
+// The small payloads are copied inline even if their buffer is allocated
+// already, the buffer is freed, or goes back to its pool, before the event is
+// dispatched instead of living as long as the request.
 impl std::convert::Into<Payload> for Bytes {
-    fn into(self) -> Payload { Payload::Bytes(self) }
+    fn into(self) -> Payload {
+        match InlinePayload::new(&self) {
+            Some(inline) => Payload::Inline(inline),
+            None => Payload::Bytes(self),
+        }
+    }
 }
 
 impl std::convert::Into<Payload> for () {
@@ -42,9 +121,14 @@ impl std::convert::Into<Payload> for () {
 }
 
 impl std::convert::Into<Payload> for Vec<u8> {
-    fn into(self) -> Payload { Payload::Bytes(Bytes::from(self)) }
+    fn into(self) -> Payload {
+        match InlinePayload::new(&self) {
+            Some(inline) => Payload::Inline(inline),
+            None => Payload::Bytes(Bytes::from(self)),
+        }
+    }
 }
 
 impl std::convert::Into<Payload> for &str {
-    fn into(self) -> Payload { self.to_string().into() }
+    fn into(self) -> Payload { Payload::from_slice(self.as_bytes()) }
 }
@@ -1,9 +1,10 @@
 use std::future::Future;
 
 use crate::{
+    byte_trait::PayloadEncoding,
     errors::{DispatchError, InternalError},
     module::{Event, ModuleDataMap},
-    request::payload::Payload,
+    request::{payload::Payload, Arena},
     util::ready::{ready, Ready},
 };
 use derivative::*;
@@ -21,6 +22,9 @@ pub struct EventRequest {
     pub(crate) event: Event,
     #[derivative(Debug = "ignore")]
     pub(crate) module_data: Arc<ModuleDataMap>,
+    pub(crate) encoding: PayloadEncoding,
+    #[derivative(Debug = "ignore")]
+    pub(crate) arena: Arena,
 }
 
 impl EventRequest {
@@ -32,9 +36,16 @@ impl EventRequest {
             id,
             event: event.into(),
             module_data,
+            encoding: PayloadEncoding::default(),
+            arena: Arena::default(),
         }
     }
 
+    pub fn encoding(mut self, encoding: PayloadEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
     pub fn module_data<T: 'static>(&self) -> Option<&T>
     where
         T: Send + Sync,
@@ -68,9 +79,9 @@ impl FromRequest for String {
     type Future = Ready<Result<Self, Self::Error>>;
 
     fn from_request(req: &EventRequest, payload: &mut Payload) -> Self::Future {
-        match &payload {
-            Payload::None => ready(Err(unexpected_none_payload(req))),
-            Payload::Bytes(buf) => ready(Ok(String::from_utf8_lossy(buf).into_owned())),
+        match payload.as_bytes() {
+            None => ready(Err(unexpected_none_payload(req))),
+            Some(buf) => ready(Ok(String::from_utf8_lossy(buf).into_owned())),
         }
     }
 }
@@ -1,7 +1,7 @@
 use crate::{
-    byte_trait::FromBytes,
+    byte_trait::{FromBytes, PayloadEncoding},
     data::Data,
-    errors::DispatchError,
+    errors::{DispatchError, InternalError},
     request::{EventRequest, Payload},
     response::Responder,
 };
@@ -32,14 +32,23 @@ impl EventResponse {
     }
 
     pub fn parse<T, E>(self) -> Result<Result<T, E>, DispatchError>
+    where
+        T: FromBytes,
+        E: FromBytes,
+    {
+        self.parse_with::<T, E>(PayloadEncoding::Native)
+    }
+
+    // The errors are always encoded with the native encoding
+    pub fn parse_with<T, E>(self, encoding: PayloadEncoding) -> Result<Result<T, E>, DispatchError>
     where
         T: FromBytes,
         E: FromBytes,
     {
         match self.status_code {
-            StatusCode::Ok => {
-                let data = <Data<T>>::try_from(self.payload)?;
-                Ok(Ok(data.into_inner()))
+            StatusCode::Ok => match self.payload.as_bytes() {
+                None => Err(InternalError::UnexpectedNone("Parse fail, expected payload".to_owned()).into()),
+                Some(bytes) => Ok(Ok(T::parse_from_bytes_with(bytes, encoding)?)),
             },
             StatusCode::Err | StatusCode::Internal => {
                 let err = <Data<E>>::try_from(self.payload)?;
@@ -53,9 +62,9 @@ impl std::fmt::Display for EventResponse {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.write_fmt(format_args!("Status_Code: {:?}", self.status_code))?;
 
-        match &self.payload {
-            Payload::Bytes(b) => f.write_fmt(format_args!("Data: {} bytes", b.len()))?,
-            Payload::None => f.write_fmt(format_args!("Data: Empty"))?,
+        match self.payload.as_bytes() {
+            Some(b) => f.write_fmt(format_args!("Data: {} bytes", b.len()))?,
+            None => f.write_fmt(format_args!("Data: Empty"))?,
         }
 
         Ok(())
@@ -0,0 +1,97 @@
+use futures_core::future::BoxFuture;
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    thread,
+};
+use tokio::{
+    sync::{
+        mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender},
+        oneshot,
+        oneshot::error::TryRecvError,
+    },
+    task::LocalSet,
+};
+
+// The keys whose last request is done are dropped once there are more of them.
+const MAX_IDLE_KEYS: usize = 1024;
+
+struct ShardTask {
+    key: String,
+    task: BoxFuture<'static, ()>,
+}
+
+// The threads that handle the requests with a key, each one runs its tasks on
+// its own LocalSet. The key picks the shard, so the requests of a document are
+// handled one after another on the same thread, while the requests of other
+// documents are handled by the other shards.
+pub(crate) struct Shards {
+    senders: Vec<UnboundedSender<ShardTask>>,
+}
+
+impl Shards {
+    pub(crate) fn new(count: usize) -> Self {
+        let senders = (0..count.max(1))
+            .map(|index| {
+                let (sender, receiver) = unbounded_channel();
+                thread::Builder::new()
+                    .name(format!("flowy-shard-{}", index))
+                    .spawn(move || run_shard(receiver))
+                    .unwrap();
+                sender
+            })
+            .collect::<Vec<_>>();
+        Self { senders }
+    }
+
+    pub(crate) fn len(&self) -> usize { self.senders.len() }
+
+    // The task is dropped if the shard stopped, its response with it.
+    pub(crate) fn spawn(&self, key: &str, task: BoxFuture<'static, ()>) {
+        let index = shard_index(key, self.senders.len());
+        let task = ShardTask {
+            key: key.to_owned(),
+            task,
+        };
+        if self.senders[index].send(task).is_err() {
+            tracing::error!("The shard {} of the dispatcher stopped", index);
+        }
+    }
+}
+
+fn shard_index(key: &str, count: usize) -> usize {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    (hasher.finish() % count as u64) as usize
+}
+
+// Each task waits for the previous one with the same key, the tasks of the
+// other keys run while it's waiting.
+fn run_shard(mut receiver: UnboundedReceiver<ShardTask>) {
+    let runtime = match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            tracing::error!("Build the runtime of the shard failed: {:?}", e);
+            return;
+        },
+    };
+    let local = LocalSet::new();
+    local.block_on(&runtime, async move {
+        let mut last_done: HashMap<String, oneshot::Receiver<()>> = HashMap::new();
+        while let Some(ShardTask { key, task }) = receiver.recv().await {
+            if last_done.len() > MAX_IDLE_KEYS {
+                last_done.retain(|_, done| matches!(done.try_recv(), Err(TryRecvError::Empty)));
+            }
+            let (done_tx, done_rx) = oneshot::channel();
+            let previous = last_done.insert(key, done_rx);
+            tokio::task::spawn_local(async move {
+                // The previous task is done, or it panicked and dropped its sender.
+                if let Some(previous) = previous {
+                    let _ = previous.await;
+                }
+                task.await;
+                let _ = done_tx.send(());
+            });
+        }
+    });
+}
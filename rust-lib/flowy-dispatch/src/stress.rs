@@ -0,0 +1,297 @@
+use crate::{
+    dispatch::EventDispatch,
+    mock::MockPayload,
+    module::{Module, ModuleRequest},
+    response::{EventResponse, StatusCode},
+};
+use bytes::Bytes;
+use futures::future::join_all;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+const ECHO: &str = "__stress/echo";
+const YIELD: &str = "__stress/yield";
+const SLEEP: &str = "__stress/sleep";
+const FAIL: &str = "__stress/fail";
+const ORDERED: &str = "__stress/ordered";
+const EVENTS: [&str; 5] = [ECHO, YIELD, SLEEP, FAIL, ORDERED];
+
+// How long the callbacks and the activity of the dispatcher can lag behind the
+// responses.
+const SETTLE_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Debug)]
+pub struct StressConfig {
+    pub requests: usize,
+    // Each sender has its own key, it sends the ordered events of the key one
+    // after another and the others without waiting for their responses.
+    pub senders: usize,
+}
+
+impl std::default::Default for StressConfig {
+    fn default() -> Self {
+        StressConfig {
+            requests: 5000,
+            senders: 32,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct StressReport {
+    pub requests: usize,
+    pub responses: usize,
+    pub elapsed: Duration,
+    pub failures: Vec<String>,
+}
+
+impl StressReport {
+    pub fn is_ok(&self) -> bool { self.failures.is_empty() }
+
+    pub fn assert_ok(&self) {
+        if !self.is_ok() {
+            let failures = self.failures.iter().take(10).cloned().collect::<Vec<_>>();
+            panic!(
+                "{} of the {} requests failed the stress test:\n{}",
+                self.failures.len(),
+                self.requests,
+                failures.join("\n")
+            );
+        }
+    }
+}
+
+// Sends thousands of mixed events concurrently through a dispatcher and checks
+// that each request got its own response, that its callback was called exactly
+// once and that the ordered events of a key were handled in the order they were
+// sent. The module is added to the dispatcher that's tested, with its other
+// modules and middlewares:
+//
+//     let stress = StressModule::new();
+//     let dispatch = EventDispatch::construct(|| vec![stress.build()]);
+//     let report = stress.run(Arc::new(dispatch), config).await;
+#[derive(Clone, Default)]
+pub struct StressModule {
+    state: Arc<StressState>,
+}
+
+#[derive(Default)]
+struct StressState {
+    // The number of times each request was handled, by its id.
+    handled: Mutex<HashMap<usize, usize>>,
+    // The sequence numbers of the ordered events, by their key.
+    ordered: Mutex<HashMap<usize, Vec<usize>>>,
+}
+
+impl StressModule {
+    pub fn new() -> Self { Self::default() }
+
+    pub fn build(&self) -> Module {
+        let mut module = Module::new().name("__stress");
+        for event in EVENTS.iter().copied() {
+            let state = self.state.clone();
+            let handler = move |payload: MockPayload| {
+                let state = state.clone();
+                async move { state.handle(event, payload.0).await }
+            };
+            module = module.event(event, handler);
+        }
+        module
+    }
+
+    pub async fn run(&self, dispatch: Arc<EventDispatch>, config: StressConfig) -> StressReport {
+        self.state.handled.lock().unwrap().clear();
+        self.state.ordered.lock().unwrap().clear();
+        let callbacks = Arc::new((0..config.requests).map(|_| AtomicUsize::new(0)).collect::<Vec<_>>());
+        let started_at = Instant::now();
+
+        let senders = (0..config.senders.max(1)).map(|key| {
+            let requests = (key..config.requests)
+                .step_by(config.senders.max(1))
+                .collect::<Vec<_>>();
+            send_requests(dispatch.clone(), callbacks.clone(), key, requests)
+        });
+        let results = join_all(senders).await.into_iter().flatten().collect::<Vec<_>>();
+
+        let mut report = StressReport {
+            requests: config.requests,
+            responses: results.len(),
+            elapsed: started_at.elapsed(),
+            failures: vec![],
+        };
+        for (request, response) in &results {
+            if let Some(failure) = request.check(response) {
+                report.failures.push(failure);
+            }
+        }
+
+        // The callbacks are called by the tasks of the dispatcher, they can be
+        // done after the responses.
+        let settled_at = Instant::now();
+        while settled_at.elapsed() < SETTLE_TIMEOUT {
+            let called = callbacks.iter().all(|count| count.load(Ordering::SeqCst) >= 1);
+            if called && dispatch.in_flight() == 0 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        for (id, count) in callbacks.iter().enumerate() {
+            match count.load(Ordering::SeqCst) {
+                1 => {},
+                count => report
+                    .failures
+                    .push(format!("The callback of the request {} was called {} times", id, count)),
+            }
+        }
+        if dispatch.in_flight() != 0 {
+            report
+                .failures
+                .push(format!("{} requests are still in flight", dispatch.in_flight()));
+        }
+
+        let handled = self.state.handled.lock().unwrap();
+        for id in 0..config.requests {
+            match handled.get(&id).cloned().unwrap_or(0) {
+                1 => {},
+                count => report
+                    .failures
+                    .push(format!("The request {} was handled {} times", id, count)),
+            }
+        }
+        for (key, seqs) in self.state.ordered.lock().unwrap().iter() {
+            if seqs.windows(2).any(|seqs| seqs[0] >= seqs[1]) {
+                report.failures.push(format!(
+                    "The ordered events of the key {} were handled out of order: {:?}",
+                    key, seqs
+                ));
+            }
+        }
+        report
+    }
+}
+
+impl StressState {
+    async fn handle(&self, event: &str, payload: Option<Bytes>) -> EventResponse {
+        let request = match payload.as_ref().and_then(|bytes| StressRequest::decode(bytes)) {
+            Some(request) => request,
+            None => return EventResponse::new(StatusCode::Internal),
+        };
+        *self.handled.lock().unwrap().entry(request.id).or_insert(0) += 1;
+
+        let status_code = match event {
+            YIELD => {
+                for _ in 0..request.id % 8 {
+                    tokio::task::yield_now().await;
+                }
+                StatusCode::Ok
+            },
+            SLEEP => {
+                tokio::time::sleep(Duration::from_micros((request.id % 500) as u64)).await;
+                StatusCode::Ok
+            },
+            FAIL => StatusCode::Err,
+            ORDERED => {
+                self.ordered
+                    .lock()
+                    .unwrap()
+                    .entry(request.key)
+                    .or_insert_with(Vec::new)
+                    .push(request.seq);
+                StatusCode::Ok
+            },
+            _ => StatusCode::Ok,
+        };
+        EventResponse {
+            payload: request.encode().into(),
+            status_code,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct StressRequest {
+    id: usize,
+    key: usize,
+    seq: usize,
+    event: &'static str,
+}
+
+impl StressRequest {
+    fn encode(&self) -> Bytes { Bytes::from(format!("{}:{}:{}", self.id, self.key, self.seq)) }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        let s = std::str::from_utf8(bytes).ok()?;
+        let mut parts = s.split(':').map(|part| part.parse::<usize>().ok());
+        let id = parts.next()??;
+        Some(Self {
+            id,
+            key: parts.next()??,
+            seq: parts.next()??,
+            event: EVENTS[id % EVENTS.len()],
+        })
+    }
+
+    // The response is the one of this request, with the status of its event.
+    fn check(&self, response: &EventResponse) -> Option<String> {
+        let expected = match self.event {
+            FAIL => StatusCode::Err,
+            _ => StatusCode::Ok,
+        };
+        if response.status_code != expected {
+            return Some(format!(
+                "The request {} to {} got {:?}",
+                self.id, self.event, response.status_code
+            ));
+        }
+        match response.payload.as_bytes() {
+            Some(bytes) if bytes == &self.encode()[..] => None,
+            Some(bytes) => Some(format!(
+                "The request {} to {} got the response of another request: {}",
+                self.id,
+                self.event,
+                String::from_utf8_lossy(bytes)
+            )),
+            None => Some(format!("The request {} to {} got no payload", self.id, self.event)),
+        }
+    }
+}
+
+// The ordered events are sent once the previous one got its response, the
+// other ones are all sent at once.
+async fn send_requests(
+    dispatch: Arc<EventDispatch>,
+    callbacks: Arc<Vec<AtomicUsize>>,
+    key: usize,
+    ids: Vec<usize>,
+) -> Vec<(StressRequest, EventResponse)> {
+    let mut results = vec![];
+    let mut pending = vec![];
+    for (seq, id) in ids.into_iter().enumerate() {
+        let request = StressRequest {
+            id,
+            key,
+            seq,
+            event: EVENTS[id % EVENTS.len()],
+        };
+        let module_request = ModuleRequest::new(request.event).payload(request.encode());
+        let callbacks = callbacks.clone();
+        let response = EventDispatch::async_send_with_callback(dispatch.clone(), module_request, move |_| {
+            callbacks[id].fetch_add(1, Ordering::SeqCst);
+            Box::pin(async {})
+        });
+        if request.event == ORDERED {
+            results.push((request, response.await));
+        } else {
+            pending.push(async move { (request, response.await) });
+        }
+    }
+    results.extend(join_all(pending).await);
+    results
+}
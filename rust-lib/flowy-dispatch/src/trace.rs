@@ -0,0 +1,80 @@
+use crate::dispatch::DispatchTrace;
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+const MAX_TRACE_RECORDS: usize = 1000;
+
+lazy_static::lazy_static! {
+    static ref TRACER: EventTracer = EventTracer::default();
+}
+
+// The traces of the events sent through the ffi.
+pub fn tracer() -> &'static EventTracer { &TRACER }
+
+// The serialization is the time spent decoding the request and encoding the
+// response, outside of the dispatcher.
+#[derive(Debug, Clone, Default)]
+pub struct EventTrace {
+    pub event: String,
+    pub serialization: Duration,
+    pub queue_wait: Duration,
+    pub handler: Duration,
+    pub response_size: usize,
+}
+
+// Keeps the last MAX_TRACE_RECORDS traces when it's enabled, it's disabled by
+// default.
+#[derive(Default)]
+pub struct EventTracer {
+    enabled: AtomicBool,
+    records: Mutex<VecDeque<EventTrace>>,
+}
+
+impl EventTracer {
+    // The records are dropped when it's disabled.
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::SeqCst);
+        if !enabled {
+            if let Ok(mut records) = self.records.lock() {
+                records.clear();
+            }
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool { self.enabled.load(Ordering::SeqCst) }
+
+    pub fn record(&self, trace: DispatchTrace, serialization: Duration) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        let record = EventTrace {
+            event: trace.event,
+            serialization,
+            queue_wait: trace.queue_wait,
+            handler: trace.handler,
+            response_size: trace.response_size,
+        };
+        log::trace!("[Trace]: {:?}", record);
+        if let Ok(mut records) = self.records.lock() {
+            if records.len() >= MAX_TRACE_RECORDS {
+                records.pop_front();
+            }
+            records.push_back(record);
+        }
+    }
+
+    // Returns the records and clears them.
+    pub fn dump(&self) -> Vec<EventTrace> {
+        match self.records.lock() {
+            Ok(mut records) => records.drain(..).collect(),
+            Err(_) => vec![],
+        }
+    }
+}
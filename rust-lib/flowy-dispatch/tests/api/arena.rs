@@ -0,0 +1,30 @@
+use flowy_dispatch::prelude::*;
+use std::sync::Arc;
+
+// Returns the payload in upper case, with the bytes the arena had before.
+async fn shout(payload: String, arena: Arena) -> String {
+    let before = arena.allocated_bytes();
+    let shouted = arena.scope(|bump| {
+        let mut shouted = bumpalo::collections::String::with_capacity_in(payload.len(), bump);
+        for c in payload.chars() {
+            shouted.extend(c.to_uppercase());
+        }
+        shouted.as_str().to_owned()
+    });
+    assert!(arena.allocated_bytes() >= payload.len());
+    format!("{}:{}", before, shouted)
+}
+
+#[tokio::test]
+async fn test_request_arena() {
+    let event = "arena_1";
+    let dispatch = Arc::new(EventDispatch::construct(|| vec![Module::new().event(event, shout)]));
+    // Every request starts with an empty arena.
+    for _ in 0..2 {
+        let request = ModuleRequest::new(event).payload("flowy");
+        let response = EventDispatch::async_send(dispatch.clone(), request).await;
+        let payload = String::from_utf8(response.payload.as_bytes().unwrap().to_vec()).unwrap();
+        assert_eq!(payload, "0:FLOWY");
+    }
+    std::mem::forget(dispatch);
+}
@@ -0,0 +1,60 @@
+use flowy_dispatch::{
+    chaos::{FaultInjector, FaultRule, FaultStats},
+    mock::{MockModule, MockResponse},
+    prelude::*,
+};
+use std::{sync::Arc, time::Duration};
+
+#[tokio::test]
+async fn test_inject_faults_by_pattern() {
+    let mock = MockModule::new("mock")
+        .response("sync/push", MockResponse::bytes("pushed"))
+        .response("sync/pull", MockResponse::bytes("pulled"))
+        .response("read", MockResponse::bytes("read"));
+    let recorder = mock.recorder();
+    let injector = Arc::new(
+        FaultInjector::new(7)
+            .rule(FaultRule::new("sync/push").error(1.0))
+            .rule(FaultRule::new("sync/*").drop(1.0)),
+    );
+    let dispatch = Arc::new(EventDispatch::construct(|| vec![mock.build()]).guard(injector.clone()));
+
+    let response = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new("sync/push")).await;
+    assert_eq!(response.status_code, StatusCode::Internal);
+
+    let pull = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new("sync/pull"));
+    assert!(tokio::time::timeout(Duration::from_millis(200), pull).await.is_err());
+
+    let response = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new("read")).await;
+    assert_eq!(response.status_code, StatusCode::Ok);
+    assert_eq!(recorder.count("sync/push"), 0);
+    assert_eq!(recorder.count("sync/pull"), 0);
+    assert_eq!(
+        injector.stats(),
+        FaultStats {
+            latencies: 0,
+            errors: 1,
+            drops: 1,
+        }
+    );
+
+    injector.set_enabled(false);
+    let response = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new("sync/push")).await;
+    assert_eq!(response.status_code, StatusCode::Ok);
+    std::mem::forget(dispatch);
+}
+
+#[tokio::test]
+async fn test_inject_latency() {
+    let mock = MockModule::new("mock").response("read", MockResponse::empty());
+    let delay = Duration::from_millis(100);
+    let injector = Arc::new(FaultInjector::new(7).rule(FaultRule::new("*").latency(1.0, delay, delay)));
+    let dispatch = Arc::new(EventDispatch::construct(|| vec![mock.build()]).guard(injector.clone()));
+
+    let started_at = std::time::Instant::now();
+    let response = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new("read")).await;
+    assert_eq!(response.status_code, StatusCode::Ok);
+    assert!(started_at.elapsed() >= delay);
+    assert_eq!(injector.stats().latencies, 1);
+    std::mem::forget(dispatch);
+}
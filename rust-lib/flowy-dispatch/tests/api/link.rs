@@ -0,0 +1,63 @@
+use flowy_dispatch::prelude::*;
+use std::sync::Arc;
+
+async fn echo(data: String) -> String { data }
+
+fn echo_link(prefix: &'static str) -> impl Fn(&LinkParams) -> Result<ModuleRequest, LinkError> {
+    move |params: &LinkParams| {
+        let payload = format!(
+            "{}:{}:{}",
+            prefix,
+            params.get("id")?,
+            params.query("action").unwrap_or("open")
+        );
+        Ok(ModuleRequest::new("link").payload(payload.into_bytes()))
+    }
+}
+
+fn link_dispatch() -> Arc<EventDispatch> {
+    Arc::new(EventDispatch::construct(|| {
+        vec![
+            Module::new()
+                .event("link", echo)
+                .link("appflowy", "item/:id", echo_link("item"))
+                .link("appflowy", "item/trash", |_| {
+                    Err(LinkError::Invalid("trash".to_owned()))
+                }),
+            Module::new().link("plugin", "page/:id", echo_link("page")),
+        ]
+    }))
+}
+
+async fn open(dispatch: &Arc<EventDispatch>, uri: &str) -> Result<String, StatusCode> {
+    let response = EventDispatch::open_link(dispatch.clone(), uri).await;
+    match response.status_code {
+        StatusCode::Ok => Ok(String::from_utf8(response.payload.as_bytes().unwrap().to_vec()).unwrap()),
+        status => Err(status),
+    }
+}
+
+#[tokio::test]
+async fn test_open_link() {
+    let dispatch = link_dispatch();
+    assert_eq!(open(&dispatch, "appflowy://item/1").await, Ok("item:1:open".to_owned()));
+    assert_eq!(
+        open(&dispatch, "APPFLOWY://item/a%20b/?action=read#top").await,
+        Ok("item:a b:read".to_owned())
+    );
+    // The scheme registered by another module goes through the same router.
+    assert_eq!(open(&dispatch, "plugin://page/2").await, Ok("page:2:open".to_owned()));
+
+    // The literal segment is matched before the capture.
+    assert_eq!(
+        dispatch.resolve_link("appflowy://item/trash").unwrap_err(),
+        LinkError::Invalid("trash".to_owned())
+    );
+    assert!(matches!(
+        dispatch.resolve_link("appflowy://item/1/more"),
+        Err(LinkError::NotFound(_))
+    ));
+    assert!(matches!(dispatch.resolve_link("item/1"), Err(LinkError::Malformed(_))));
+    assert!(open(&dispatch, "other://item/1").await.is_err());
+    std::mem::forget(dispatch);
+}
@@ -1 +1,12 @@
+mod arena;
+mod chaos;
+mod link;
+mod mock;
 mod module;
+mod notification;
+#[cfg(feature = "use_protobuf")]
+mod payload;
+#[cfg(feature = "use_serde")]
+mod serde_payload;
+mod shard;
+mod stress;
@@ -0,0 +1,57 @@
+use bytes::Bytes;
+use flowy_dispatch::{
+    mock::{MockModule, MockResponse},
+    prelude::*,
+};
+use futures_core::future::BoxFuture;
+use std::{sync::Arc, time::Duration};
+
+struct RetryInternalError;
+impl EventMiddleware for RetryInternalError {
+    fn intercept(&self, _request: &ModuleRequest, response: &EventResponse) -> BoxFuture<'static, bool> {
+        let retry = response.status_code == StatusCode::Internal;
+        Box::pin(async move { retry })
+    }
+}
+
+#[tokio::test]
+async fn test_mock_responses_in_order() {
+    let event = "fetch";
+    let responses = vec![MockResponse::internal(), MockResponse::bytes("done")];
+    let mock = MockModule::new("mock").responses(event, responses);
+    let recorder = mock.recorder();
+    let dispatch = Arc::new(EventDispatch::construct(|| vec![mock.build()]).middleware(Arc::new(RetryInternalError)));
+
+    let request = ModuleRequest::new(event).payload(Bytes::from("query"));
+    let response = EventDispatch::async_send(dispatch.clone(), request).await;
+    assert_eq!(response.status_code, StatusCode::Ok);
+    match response.payload.as_bytes() {
+        Some(bytes) => assert_eq!(bytes, b"done"),
+        None => panic!("expected payload"),
+    }
+
+    assert_eq!(recorder.count(event), 2);
+    let requests = recorder.requests();
+    assert_eq!(requests[0].payload.as_deref(), Some(&b"query"[..]));
+
+    // The last response is repeated.
+    let _ = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new(event)).await;
+    assert_eq!(recorder.count(event), 3);
+    assert_eq!(recorder.requests()[2].payload, None);
+    std::mem::forget(dispatch);
+}
+
+#[tokio::test]
+async fn test_mock_delay() {
+    let event = "slow";
+    let mock = MockModule::new("mock").response(event, MockResponse::empty().delay(Duration::from_millis(200)));
+    let dispatch = Arc::new(EventDispatch::construct(|| vec![mock.build()]));
+
+    let response = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new(event));
+    let timeout = tokio::time::timeout(Duration::from_millis(50), response).await;
+    assert!(timeout.is_err());
+
+    let response = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new(event)).await;
+    assert_eq!(response.status_code, StatusCode::Ok);
+    std::mem::forget(dispatch);
+}
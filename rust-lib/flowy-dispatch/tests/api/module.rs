@@ -1,5 +1,9 @@
 use flowy_dispatch::prelude::*;
-use std::sync::Arc;
+use futures_core::future::BoxFuture;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
 
 pub async fn hello() -> String { "say hello".to_string() }
 
@@ -19,3 +23,283 @@ async fn test() {
 
     std::mem::forget(dispatch);
 }
+
+#[tokio::test]
+async fn test_trace() {
+    let event = "2";
+    let dispatch = Arc::new(EventDispatch::construct(|| vec![Module::new().event(event, hello)]));
+    let request = ModuleRequest::new(event);
+    let (tx, rx) = tokio::sync::oneshot::channel::<DispatchTrace>();
+    let _ = EventDispatch::async_send_with_trace(dispatch.clone(), request, move |_resp, trace| {
+        Box::pin(async move {
+            let _ = tx.send(trace);
+        })
+    })
+    .await;
+
+    let trace = rx.await.unwrap();
+    assert_eq!(trace.event, event);
+    assert_eq!(trace.response_size, "say hello".len());
+
+    tracer().set_enabled(true);
+    tracer().record(trace, std::time::Duration::from_millis(1));
+    let records = tracer().dump();
+    let record = records.iter().find(|record| record.event == event).unwrap();
+    assert_eq!(record.response_size, "say hello".len());
+    assert!(tracer().dump().is_empty());
+    std::mem::forget(dispatch);
+}
+
+static RETRY_EVENT_CALLS: AtomicUsize = AtomicUsize::new(0);
+async fn count_calls() -> String { format!("{}", RETRY_EVENT_CALLS.fetch_add(1, Ordering::SeqCst)) }
+
+static RETRY_EVENT_INTERCEPTS: AtomicUsize = AtomicUsize::new(0);
+
+struct RetryMiddleware;
+impl EventMiddleware for RetryMiddleware {
+    fn intercept(&self, _request: &ModuleRequest, _response: &EventResponse) -> BoxFuture<'static, bool> {
+        // Always asks for a retry, the dispatcher must stop after MAX_RETRY.
+        RETRY_EVENT_INTERCEPTS.fetch_add(1, Ordering::SeqCst);
+        Box::pin(async { true })
+    }
+}
+
+#[tokio::test]
+async fn test_middleware_retry() {
+    let event = "3";
+    let dispatch = Arc::new(
+        EventDispatch::construct(|| vec![Module::new().event(event, count_calls)]).middleware(Arc::new(RetryMiddleware)),
+    );
+    let response = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new(event)).await;
+
+    assert_eq!(RETRY_EVENT_CALLS.load(Ordering::SeqCst), 2);
+    // The response of the retry is intercepted too.
+    assert_eq!(RETRY_EVENT_INTERCEPTS.load(Ordering::SeqCst), 2);
+    match response.payload.as_bytes() {
+        Some(bytes) => assert_eq!(bytes, b"1"),
+        None => panic!("expected payload"),
+    }
+    std::mem::forget(dispatch);
+}
+
+struct ReadOnlyGuard;
+impl EventGuard for ReadOnlyGuard {
+    fn check(
+        &self,
+        _request: &ModuleRequest,
+        permission: Option<EventPermission>,
+    ) -> BoxFuture<'static, Result<(), EventResponse>> {
+        Box::pin(async move {
+            match permission {
+                None | Some(EventPermission::Read) => Ok(()),
+                Some(_) => Err(ResponseBuilder::Err().data("denied").build()),
+            }
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_guard_permission() {
+    let (read_event, write_event, free_event) = ("4", "5", "6");
+    let dispatch = Arc::new(
+        EventDispatch::construct(|| {
+            vec![Module::new()
+                .event_with_permission(read_event, hello, EventPermission::Read)
+                .event_with_permission(write_event, hello, EventPermission::Write)
+                .event(free_event, hello)]
+        })
+        .guard(Arc::new(ReadOnlyGuard)),
+    );
+
+    let response = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new(write_event)).await;
+    assert_eq!(response.status_code, StatusCode::Err);
+
+    for event in [read_event, free_event] {
+        let response = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new(event)).await;
+        assert_eq!(response.status_code, StatusCode::Ok);
+    }
+    std::mem::forget(dispatch);
+}
+
+#[tokio::test]
+async fn test_send_after() {
+    let event = "7";
+    let dispatch = Arc::new(EventDispatch::construct(|| vec![Module::new().event(event, hello)]));
+    let delay = std::time::Duration::from_millis(100);
+    let sent_at = std::time::Instant::now();
+    let response = EventDispatch::async_send_after(dispatch.clone(), ModuleRequest::new(event), delay).await;
+
+    assert_eq!(response.status_code, StatusCode::Ok);
+    assert!(sent_at.elapsed() >= delay);
+    std::mem::forget(dispatch);
+}
+
+async fn slow_hello() -> String {
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+    "say hello".to_string()
+}
+
+#[tokio::test]
+async fn test_idle_since() {
+    let (slow_event, event) = ("8", "9");
+    let dispatch = Arc::new(EventDispatch::construct(|| {
+        vec![Module::new().event(slow_event, slow_hello).event(event, hello)]
+    }));
+    let request = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new(slow_event));
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    assert!(dispatch.idle_since().is_none());
+
+    let sent_at = std::time::Instant::now();
+    let _ = request.await;
+    let idle_since = dispatch.idle_since().unwrap();
+    assert!(idle_since >= sent_at);
+
+    // The periodic requests aren't counted.
+    let zero = std::time::Duration::from_millis(0);
+    let _ = EventDispatch::async_send_after(dispatch.clone(), ModuleRequest::new(event), zero).await;
+    assert_eq!(dispatch.idle_since(), Some(idle_since));
+    std::mem::forget(dispatch);
+}
+
+async fn current_span() -> String {
+    tracing::Span::current()
+        .metadata()
+        .map(|metadata| metadata.name().to_owned())
+        .unwrap_or_default()
+}
+
+#[tokio::test]
+async fn test_event_span() {
+    let _ = tracing::subscriber::set_global_default(tracing_subscriber::registry());
+    let event = "10";
+    let dispatch = Arc::new(EventDispatch::construct(|| {
+        vec![Module::new().name("span").event(event, current_span)]
+    }));
+    let response = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new(event)).await;
+    match response.payload.as_bytes() {
+        Some(bytes) => assert_eq!(bytes, b"event"),
+        None => panic!("expected payload"),
+    }
+    std::mem::forget(dispatch);
+}
+
+async fn yield_once() -> String {
+    tokio::task::yield_now().await;
+    "yielded".to_string()
+}
+
+#[tokio::test]
+async fn test_profile() {
+    profiler().set_enabled(true);
+    let event = "11";
+    let module = Module::new().event(event, yield_once);
+    let dispatch = Arc::new(EventDispatch::construct(|| vec![module]));
+    let _ = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new(event)).await;
+    let _ = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new(event)).await;
+
+    let profile = profiler()
+        .report(usize::MAX)
+        .into_iter()
+        .find(|profile| profile.event == event)
+        .unwrap();
+    assert_eq!(profile.count, 2);
+    // The handler yields once, each invocation is polled at least twice.
+    assert!(profile.polls >= 4);
+    assert!(profile.wall_time >= profile.busy_time);
+    std::mem::forget(dispatch);
+}
+
+fn slow_events(event: &str) -> u64 {
+    flowy_metrics::registry()
+        .counter("dispatch_slow_events_total", &[("event", event)])
+        .get()
+}
+
+#[tokio::test]
+async fn test_slow_event() {
+    // Every event is slow with a zero threshold.
+    let event = "12";
+    let zero = std::time::Duration::from_secs(0);
+    let module = Module::new().event(event, hello);
+    let dispatch = Arc::new(EventDispatch::construct(|| vec![module]).slow_event_threshold(Some(zero)));
+    let _ = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new(event)).await;
+    assert_eq!(slow_events(event), 1);
+    std::mem::forget(dispatch);
+
+    let event = "13";
+    let module = Module::new().event(event, hello);
+    let dispatch = Arc::new(EventDispatch::construct(|| vec![module]).slow_event_threshold(None));
+    let _ = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new(event)).await;
+    assert_eq!(slow_events(event), 0);
+    std::mem::forget(dispatch);
+}
+
+#[test]
+fn test_event_map_lookup() {
+    let mut map = EventMap::default();
+    map.insert(Event::from("a"), 1);
+    map.insert(Event::from("b".to_owned()), 2);
+    assert_eq!(map.get(&Event::from("a".to_owned())), Some(&1));
+    assert_eq!(map.get(&Event::from("b")), Some(&2));
+    assert_eq!(map.get(&Event::from("c")), None);
+    assert_eq!(format!("{:?}", Event::from("a")), "Event(\"a\")");
+}
+
+async fn thread_id() -> String { format!("{:?}", std::thread::current().id()) }
+
+async fn handled_on(dispatch: Arc<EventDispatch>, event: &str) -> String {
+    let response = EventDispatch::async_send(dispatch, ModuleRequest::new(event)).await;
+    String::from_utf8(response.payload.as_bytes().unwrap().to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn test_inline_event() {
+    let current = format!("{:?}", std::thread::current().id());
+    let budget = Some(std::time::Duration::from_secs(1));
+
+    // The marked event is handled in the poll of the caller.
+    let event = "14";
+    let module = Module::new().inline_event(event, thread_id);
+    let dispatch = Arc::new(EventDispatch::construct(|| vec![module]).inline_budget(budget));
+    assert_eq!(handled_on(dispatch.clone(), event).await, current);
+    std::mem::forget(dispatch);
+
+    // The measured one is spawned until it was cheap enough times.
+    let event = "15";
+    let module = Module::new().event(event, thread_id);
+    let dispatch = Arc::new(EventDispatch::construct(|| vec![module]).inline_budget(budget));
+    assert_ne!(handled_on(dispatch.clone(), event).await, current);
+    for _ in 0..16 {
+        let _ = handled_on(dispatch.clone(), event).await;
+    }
+    assert_eq!(handled_on(dispatch.clone(), event).await, current);
+    std::mem::forget(dispatch);
+
+    let event = "16";
+    let module = Module::new().inline_event(event, thread_id);
+    let dispatch = Arc::new(EventDispatch::construct(|| vec![module]).inline_budget(None));
+    assert_ne!(handled_on(dispatch.clone(), event).await, current);
+    std::mem::forget(dispatch);
+}
+
+#[tokio::test]
+async fn test_event_table() {
+    let dispatch = Arc::new(EventDispatch::construct(|| {
+        vec![Module::new().event("17", hello), Module::new().event("18", hello)]
+    }));
+    let other = Arc::new(EventDispatch::construct(|| vec![Module::new().event("18", hello)]));
+    assert_eq!(dispatch.event_count(), 2);
+
+    // The event resolved by a dispatcher is still found by the others.
+    let event = dispatch.event("18");
+    assert_eq!(event, Event::from("18"));
+    for dispatch in &[&dispatch, &other] {
+        let response = EventDispatch::async_send((*dispatch).clone(), ModuleRequest::new(event.clone())).await;
+        assert_eq!(response.status_code, StatusCode::Ok);
+    }
+
+    let response = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new(dispatch.event("19"))).await;
+    assert_ne!(response.status_code, StatusCode::Ok);
+    std::mem::forget(dispatch);
+    std::mem::forget(other);
+}
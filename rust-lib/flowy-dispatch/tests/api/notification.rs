@@ -0,0 +1,103 @@
+use flowy_dispatch::prelude::*;
+use std::sync::{Arc, Mutex};
+
+type Received = Arc<Mutex<Vec<(String, i32)>>>;
+
+fn record(center: &NotificationCenter, filter: NotificationFilter) -> Received {
+    let received: Received = Arc::new(Mutex::new(vec![]));
+    let cloned = received.clone();
+    center.subscribe(filter, move |notification| {
+        cloned.lock().unwrap().push((notification.id.clone(), notification.ty))
+    });
+    received
+}
+
+#[test]
+fn notification_is_delivered_to_the_matching_filters() {
+    let center = NotificationCenter::default();
+    let all = record(&center, NotificationFilter::all());
+    let doc = record(&center, NotificationFilter::all().source("document").id("doc_1"));
+    let renamed = record(&center, NotificationFilter::all().ty(2));
+
+    center.publish(Notification::new("document", "doc_1", 1));
+    center.publish(Notification::new("document", "doc_2", 2));
+    center.publish(Notification::new("workspace", "doc_1", 2));
+
+    assert_eq!(all.lock().unwrap().len(), 3);
+    assert_eq!(*doc.lock().unwrap(), vec![("doc_1".to_owned(), 1)]);
+    assert_eq!(
+        *renamed.lock().unwrap(),
+        vec![("doc_2".to_owned(), 2), ("doc_1".to_owned(), 2)]
+    );
+}
+
+#[test]
+fn unsubscribed_handler_is_not_called() {
+    let center = NotificationCenter::default();
+    let received: Received = Arc::new(Mutex::new(vec![]));
+    let cloned = received.clone();
+    let id = center.subscribe(NotificationFilter::all(), move |notification| {
+        cloned.lock().unwrap().push((notification.id.clone(), notification.ty))
+    });
+    center.publish(Notification::new("app", "app_1", 1));
+    center.unsubscribe(id);
+    center.publish(Notification::new("app", "app_1", 2));
+    assert_eq!(*received.lock().unwrap(), vec![("app_1".to_owned(), 1)]);
+}
+
+#[test]
+fn notifications_of_an_object_are_delivered_in_order() {
+    let center = Arc::new(NotificationCenter::default());
+    let received = record(&center, NotificationFilter::all());
+    let threads = (0..4)
+        .map(|thread| {
+            let center = center.clone();
+            std::thread::spawn(move || {
+                let id = format!("view_{}", thread);
+                for ty in 0..100 {
+                    center.publish(Notification::new("view", &id, ty));
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+
+    let received = received.lock().unwrap();
+    assert_eq!(received.len(), 4 * 100);
+    for thread in 0..4 {
+        let id = format!("view_{}", thread);
+        let tys = received
+            .iter()
+            .filter(|(other, _)| other == &id)
+            .map(|(_, ty)| *ty)
+            .collect::<Vec<_>>();
+        assert_eq!(tys, (0..100).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn notification_published_by_a_handler_is_delivered_after_the_current_one() {
+    let center = Arc::new(NotificationCenter::default());
+    let publisher = center.clone();
+    center.subscribe(NotificationFilter::all().ty(1), move |notification| {
+        publisher.publish(Notification::new("app", &notification.id, 2))
+    });
+    let received = record(&center, NotificationFilter::all());
+    center.publish(Notification::new("app", "app_1", 1));
+    assert_eq!(
+        *received.lock().unwrap(),
+        vec![("app_1".to_owned(), 1), ("app_1".to_owned(), 2)]
+    );
+}
+
+#[test]
+fn panicking_handler_does_not_stop_the_delivery() {
+    let center = NotificationCenter::default();
+    center.subscribe(NotificationFilter::all().ty(1), |_| panic!("handler panicked"));
+    let received = record(&center, NotificationFilter::all());
+    center.publish(Notification::new("user", "user_1", 1));
+    center.publish(Notification::new("user", "user_1", 2));
+    assert_eq!(received.lock().unwrap().len(), 2);
+}
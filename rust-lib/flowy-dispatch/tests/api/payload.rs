@@ -0,0 +1,169 @@
+use bytes::Bytes;
+use flowy_derive::{FromBytes, FromPayload, ToBytes};
+use flowy_dispatch::prelude::*;
+use std::{
+    convert::{TryFrom, TryInto},
+    sync::Arc,
+};
+
+// Stands for the protobuf struct generated by ProtoBuf
+#[derive(Debug)]
+pub struct NameRequest {
+    name: String,
+}
+
+impl TryFrom<&[u8]> for NameRequest {
+    type Error = protobuf::ProtobufError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let name = String::from_utf8(bytes.to_vec())
+            .map_err(|_| protobuf::ProtobufError::WireError(protobuf::error::WireError::Utf8Error))?;
+        Ok(NameRequest { name })
+    }
+}
+
+impl TryInto<Bytes> for NameRequest {
+    type Error = protobuf::ProtobufError;
+
+    fn try_into(self) -> Result<Bytes, Self::Error> { Ok(Bytes::from(self.name)) }
+}
+
+#[derive(Debug, Clone)]
+pub struct NameError;
+
+impl flowy_dispatch::Error for NameError {
+    fn as_response(&self) -> EventResponse { ResponseBuilder::Err().data("name is empty").build() }
+}
+
+#[derive(Debug, FromBytes, ToBytes, FromPayload)]
+#[payload(from = "NameRequest", into = "NameRequest")]
+pub struct NameParams {
+    name: String,
+}
+
+impl TryInto<NameParams> for NameRequest {
+    type Error = NameError;
+
+    fn try_into(self) -> Result<NameParams, Self::Error> {
+        if self.name.is_empty() {
+            return Err(NameError);
+        }
+        Ok(NameParams { name: self.name })
+    }
+}
+
+impl std::convert::From<NameParams> for NameRequest {
+    fn from(params: NameParams) -> Self { NameRequest { name: params.name } }
+}
+
+pub async fn greeting(data: Data<NameParams>) -> Data<NameParams> {
+    let params = data.into_inner();
+    Data(NameParams {
+        name: format!("hello {}", params.name),
+    })
+}
+
+pub async fn checked_greeting(params: Params<NameParams, NameError>) -> Data<NameParams> {
+    Data(NameParams {
+        name: format!("hello {}", params.name),
+    })
+}
+
+#[tokio::test]
+async fn derive_payload_test() {
+    let event = "greeting";
+    let dispatch = Arc::new(EventDispatch::construct(|| vec![Module::new().event(event, greeting)]));
+
+    let request = ModuleRequest::new(event).payload("nathan");
+    let resp = EventDispatch::async_send(dispatch.clone(), request).await;
+    assert_eq!(resp.status_code, StatusCode::Ok);
+    match resp.payload.as_bytes() {
+        Some(bytes) => assert_eq!(bytes, b"hello nathan"),
+        None => panic!("expected payload"),
+    }
+
+    let request = ModuleRequest::new(event).payload("");
+    let resp = EventDispatch::async_send(dispatch.clone(), request).await;
+    assert_eq!(resp.status_code, StatusCode::Err);
+
+    std::mem::forget(dispatch);
+}
+
+#[tokio::test]
+async fn derive_params_test() {
+    let event = "checked_greeting";
+    let dispatch = Arc::new(EventDispatch::construct(|| {
+        vec![Module::new().event(event, checked_greeting)]
+    }));
+
+    let request = ModuleRequest::new(event).payload("nathan");
+    let resp = EventDispatch::async_send(dispatch.clone(), request).await;
+    assert_eq!(resp.status_code, StatusCode::Ok);
+    assert_eq!(resp.payload.as_bytes(), Some(&b"hello nathan"[..]));
+
+    // The error of the check is the one of the module.
+    let request = ModuleRequest::new(event).payload("");
+    let resp = EventDispatch::async_send(dispatch.clone(), request).await;
+    assert_eq!(resp.status_code, StatusCode::Err);
+    assert_eq!(resp.payload.as_bytes(), Some(&b"name is empty"[..]));
+
+    std::mem::forget(dispatch);
+}
+
+#[test]
+fn inline_payload_test() {
+    let payload: Payload = "nathan".into();
+    assert!(matches!(payload, Payload::Inline(_)));
+    assert_eq!(payload.as_bytes(), Some(&b"nathan"[..]));
+    assert_eq!(payload.to_bytes(), Some(Bytes::from("nathan")));
+
+    // The small buffers are copied inline, the large ones are kept.
+    let small = vec![1u8; INLINE_PAYLOAD_CAPACITY];
+    let payload: Payload = small.clone().into();
+    assert!(matches!(payload, Payload::Inline(_)));
+    assert_eq!(payload.as_bytes(), Some(&small[..]));
+    let payload: Payload = Bytes::from(small.clone()).into();
+    assert!(matches!(payload, Payload::Inline(_)));
+    assert_eq!(payload.as_bytes(), Some(&small[..]));
+
+    let large = vec![1u8; INLINE_PAYLOAD_CAPACITY + 1];
+    let payload: Payload = large.clone().into();
+    assert!(matches!(payload, Payload::Bytes(_)));
+    assert_eq!(payload.as_bytes(), Some(&large[..]));
+    let bytes = Bytes::from(large.clone());
+    let payload: Payload = bytes.clone().into();
+    match &payload {
+        Payload::Bytes(payload_bytes) => assert_eq!(payload_bytes.as_ptr(), bytes.as_ptr()),
+        _ => panic!("expected the buffer"),
+    }
+}
+
+#[test]
+fn parse_inline_payload_test() {
+    let payload: Payload = "nathan".into();
+    let data = <Data<NameRequest>>::try_from(&payload).unwrap();
+    assert_eq!(data.name, "nathan");
+}
+
+#[test]
+fn pooled_buffer_test() {
+    let mut buffer = take_buffer(INLINE_PAYLOAD_CAPACITY * 2);
+    buffer.extend_from_slice(&[1u8; INLINE_PAYLOAD_CAPACITY * 2]);
+    let ptr = buffer.as_ptr();
+    let payload: Payload = Payload::Bytes(pooled_bytes(buffer));
+
+    // The buffer is in use until every clone of the payload is dropped.
+    let clone = payload.clone();
+    drop(payload);
+    assert_ne!(take_buffer(INLINE_PAYLOAD_CAPACITY).as_ptr(), ptr);
+
+    // It's reused empty once it's free.
+    drop(clone);
+    let reused = take_buffer(INLINE_PAYLOAD_CAPACITY);
+    assert_eq!(reused.as_ptr(), ptr);
+    assert!(reused.is_empty());
+
+    // The large buffers aren't kept.
+    drop(pooled_bytes(vec![1u8; 128 * 1024]));
+    assert!(take_buffer(0).capacity() < 128 * 1024);
+}
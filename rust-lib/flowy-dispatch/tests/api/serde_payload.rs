@@ -0,0 +1,52 @@
+use flowy_dispatch::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Counter {
+    value: i64,
+}
+
+// The serde payloads are wrapped in Serde, so they work with the protobuf
+// payloads enabled too.
+pub async fn increment(data: Data<Serde<Counter>>) -> Data<Serde<Counter>> {
+    let counter = data.into_inner();
+    Data(Serde(Counter {
+        value: counter.value + 1,
+    }))
+}
+
+#[tokio::test]
+async fn serde_payload_test() {
+    let event = "increment";
+    let dispatch = Arc::new(EventDispatch::construct(|| vec![Module::new().event(event, increment)]));
+    let payload = Serde(Counter { value: 1 }).into_bytes().unwrap();
+    let request = ModuleRequest::new(event).payload(payload);
+    let counter = EventDispatch::async_send(dispatch.clone(), request)
+        .await
+        .parse::<Serde<Counter>, DispatchError>()
+        .unwrap()
+        .unwrap();
+    assert_eq!(counter.value, 2);
+    std::mem::forget(dispatch);
+}
+
+#[cfg(feature = "use_cbor")]
+#[tokio::test]
+async fn cbor_payload_test() {
+    let event = "increment";
+    let dispatch = Arc::new(EventDispatch::construct(|| vec![Module::new().event(event, increment)]));
+    let payload = Serde(Counter { value: 1 })
+        .into_bytes_with(PayloadEncoding::Cbor)
+        .unwrap();
+    let request = ModuleRequest::new(event)
+        .payload(payload)
+        .encoding(PayloadEncoding::Cbor);
+    let counter = EventDispatch::async_send(dispatch.clone(), request)
+        .await
+        .parse_with::<Serde<Counter>, DispatchError>(PayloadEncoding::Cbor)
+        .unwrap()
+        .unwrap();
+    assert_eq!(counter.value, 2);
+    std::mem::forget(dispatch);
+}
@@ -0,0 +1,60 @@
+use flowy_dispatch::prelude::*;
+use lazy_static::lazy_static;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+lazy_static! {
+    static ref HANDLED: Mutex<HashMap<String, Vec<usize>>> = Mutex::new(HashMap::new());
+}
+
+async fn record(payload: String) -> String {
+    let (key, seq) = payload.split_once(':').unwrap();
+    let seq = seq.parse::<usize>().unwrap();
+    // The later requests are faster, they'd overtake the earlier ones if they
+    // weren't ordered.
+    tokio::time::sleep(Duration::from_micros(((20 - seq % 20) * 50) as u64)).await;
+    HANDLED.lock().unwrap().entry(key.to_owned()).or_default().push(seq);
+    payload
+}
+
+fn sharded_dispatch(event: &'static str) -> Arc<EventDispatch> {
+    Arc::new(EventDispatch::construct(|| vec![Module::new().event(event, record)]).shards(4))
+}
+
+#[tokio::test]
+async fn test_shard_keep_order_of_key() {
+    let dispatch = sharded_dispatch("shard_1");
+    assert_eq!(dispatch.shard_count(), 4);
+
+    let keys = (0..8).map(|i| format!("doc_{}", i)).collect::<Vec<_>>();
+    let mut responses = vec![];
+    for seq in 0..50 {
+        for key in &keys {
+            let request = ModuleRequest::new("shard_1")
+                .key(key)
+                .payload(format!("{}:{}", key, seq));
+            responses.push(EventDispatch::async_send(dispatch.clone(), request));
+        }
+    }
+    let responses = futures_util::future::join_all(responses).await;
+    assert!(responses.iter().all(|response| response.status_code == StatusCode::Ok));
+
+    let handled = HANDLED.lock().unwrap();
+    for key in &keys {
+        assert_eq!(handled[key], (0..50).collect::<Vec<_>>());
+    }
+    std::mem::forget(dispatch);
+}
+
+#[tokio::test]
+async fn test_shard_request_without_key() {
+    let dispatch = sharded_dispatch("shard_2");
+    let request = ModuleRequest::new("shard_2").payload("unsharded:0");
+    let response = EventDispatch::async_send(dispatch.clone(), request).await;
+    assert_eq!(response.status_code, StatusCode::Ok);
+    assert_eq!(HANDLED.lock().unwrap()["unsharded"], vec![0]);
+    std::mem::forget(dispatch);
+}
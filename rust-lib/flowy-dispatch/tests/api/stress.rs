@@ -0,0 +1,23 @@
+use flowy_dispatch::{
+    prelude::*,
+    stress::{StressConfig, StressModule},
+};
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_stress_mixed_events() {
+    let stress = StressModule::new();
+    let dispatch = Arc::new(EventDispatch::construct(|| vec![stress.build()]));
+
+    let report = stress.run(dispatch.clone(), StressConfig::default()).await;
+    report.assert_ok();
+    assert_eq!(report.responses, report.requests);
+
+    // The state is reset, the module can be run again.
+    let config = StressConfig {
+        requests: 1000,
+        senders: 4,
+    };
+    stress.run(dispatch.clone(), config).await.assert_ok();
+    std::mem::forget(dispatch);
+}
@@ -0,0 +1,65 @@
+use flowy_derive::ProtoBuf;
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct CreateAssetRequest {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub mime_type: String,
+
+    #[pb(index = 4)]
+    pub data: Vec<u8>,
+}
+
+// The document refers to the asset by putting the url in the link attribute of
+// the text, the asset is removed once the document doesn't refer to it anymore.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct DocAsset {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub doc_id: String,
+
+    #[pb(index = 3)]
+    pub name: String,
+
+    #[pb(index = 4)]
+    pub mime_type: String,
+
+    #[pb(index = 5)]
+    pub size: i64,
+
+    #[pb(index = 6)]
+    pub url: String,
+
+    // The local file of the asset.
+    #[pb(index = 7)]
+    pub path: String,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedDocAsset {
+    #[pb(index = 1)]
+    pub items: Vec<DocAsset>,
+}
+
+// The asset_id can also be the url of the asset.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct AssetIdentifier {
+    #[pb(index = 1)]
+    pub asset_id: String,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct AssetData {
+    #[pb(index = 1)]
+    pub asset: DocAsset,
+
+    #[pb(index = 2)]
+    pub data: Vec<u8>,
+}
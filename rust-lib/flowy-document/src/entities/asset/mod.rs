@@ -0,0 +1,5 @@
+mod asset;
+mod transfer;
+
+pub use asset::*;
+pub use transfer::*;
@@ -0,0 +1,76 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ProtoBuf_Enum)]
+pub enum AssetTransferDirection {
+    Upload   = 0,
+    Download = 1,
+}
+
+impl std::default::Default for AssetTransferDirection {
+    fn default() -> Self { AssetTransferDirection::Upload }
+}
+
+// The progress of the upload or the download of an asset, it's sent with the
+// AssetTransferProgress notification after each chunk. The transfer that was
+// interrupted starts again from resumed_from, it's 0 for the new ones.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct AssetTransfer {
+    #[pb(index = 1)]
+    pub asset_id: String,
+
+    #[pb(index = 2)]
+    pub direction: AssetTransferDirection,
+
+    #[pb(index = 3)]
+    pub transferred: i64,
+
+    #[pb(index = 4)]
+    pub total: i64,
+
+    #[pb(index = 5)]
+    pub resumed_from: i64,
+
+    // Empty unless the transfer stopped on an error, it's resumed with the next
+    // UploadAsset or DownloadAsset, or when the network is back.
+    #[pb(index = 6)]
+    pub error: String,
+}
+
+impl AssetTransfer {
+    pub fn is_done(&self) -> bool { self.error.is_empty() && self.transferred >= self.total }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedAssetTransfer {
+    #[pb(index = 1)]
+    pub items: Vec<AssetTransfer>,
+}
+
+// The server keeps the bytes before the offset and replaces the rest with the
+// data, so a chunk that's sent again doesn't duplicate its bytes.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct AssetChunk {
+    #[pb(index = 1)]
+    pub asset_id: String,
+
+    #[pb(index = 2)]
+    pub offset: i64,
+
+    #[pb(index = 3)]
+    pub total_size: i64,
+
+    #[pb(index = 4)]
+    pub data: Vec<u8>,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct AssetChunkRequest {
+    #[pb(index = 1)]
+    pub asset_id: String,
+
+    #[pb(index = 2)]
+    pub offset: i64,
+
+    #[pb(index = 3)]
+    pub size: i64,
+}
@@ -0,0 +1,90 @@
+use flowy_derive::ProtoBuf;
+
+// Imports the file of the path into the document. The name defaults to the file
+// name and the mime type to the one guessed from the content.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct ImportAttachmentRequest {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub path: String,
+
+    #[pb(index = 3)]
+    pub name: String,
+
+    #[pb(index = 4)]
+    pub mime_type: String,
+}
+
+// The attachments with the same content share their blob, importing the same
+// file twice doesn't use more storage.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct DocAttachment {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub doc_id: String,
+
+    #[pb(index = 3)]
+    pub name: String,
+
+    #[pb(index = 4)]
+    pub mime_type: String,
+
+    #[pb(index = 5)]
+    pub size: i64,
+
+    // The hex sha256 of the content.
+    #[pb(index = 6)]
+    pub hash: String,
+
+    // The local file of the content.
+    #[pb(index = 7)]
+    pub path: String,
+
+    // The local PNG file of the thumbnail, it's empty if the attachment isn't an
+    // image. The width and the height are the ones of the image.
+    #[pb(index = 8)]
+    pub thumbnail_path: String,
+
+    #[pb(index = 9)]
+    pub width: i32,
+
+    #[pb(index = 10)]
+    pub height: i32,
+
+    #[pb(index = 11)]
+    pub create_time: i64,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedDocAttachment {
+    #[pb(index = 1)]
+    pub items: Vec<DocAttachment>,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct AttachmentIdentifier {
+    #[pb(index = 1)]
+    pub attachment_id: String,
+}
+
+// The bytes of the blobs that the attachments of the user refer to, counted
+// once per blob, and the quota they can't exceed.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct AttachmentUsage {
+    #[pb(index = 1)]
+    pub used: i64,
+
+    #[pb(index = 2)]
+    pub quota: i64,
+
+    #[pb(index = 3)]
+    pub count: i64,
+}
+
+impl AttachmentUsage {
+    pub fn available(&self) -> i64 { (self.quota - self.used).max(0) }
+}
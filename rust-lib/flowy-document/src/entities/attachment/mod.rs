@@ -0,0 +1,3 @@
+mod attachment;
+
+pub use attachment::*;
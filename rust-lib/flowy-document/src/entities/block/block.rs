@@ -0,0 +1,127 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+pub enum BlockType {
+    Paragraph = 0,
+    Heading   = 1,
+    Todo      = 2,
+    Code      = 3,
+    Embed     = 4,
+    List      = 5,
+    Quote     = 6,
+}
+
+impl std::default::Default for BlockType {
+    fn default() -> Self { BlockType::Paragraph }
+}
+
+// A block is a line of the document and it's addressed by its index. The data
+// is the delta of the line, the newline that holds the block attributes is
+// included.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct DocBlock {
+    #[pb(index = 1)]
+    pub index: i64,
+
+    #[pb(index = 2)]
+    pub block_type: BlockType,
+
+    #[pb(index = 3)]
+    pub data: String,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedDocBlock {
+    #[pb(index = 1)]
+    pub items: Vec<DocBlock>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+pub enum BlockOpType {
+    Insert = 0,
+    Delete = 1,
+    Move   = 2,
+    Update = 3,
+}
+
+impl std::default::Default for BlockOpType {
+    fn default() -> Self { BlockOpType::Insert }
+}
+
+// The insert puts the block before the block at the index, or at the end of
+// the document if the index is the number of blocks. The move puts the block
+// at the index `to` of the document without it.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct BlockOp {
+    #[pb(index = 1)]
+    pub ty: BlockOpType,
+
+    #[pb(index = 2)]
+    pub index: i64,
+
+    #[pb(index = 3)]
+    pub to: i64,
+
+    // The delta of the block for the insert and the update.
+    #[pb(index = 4)]
+    pub data: String,
+}
+
+// The operations are applied one after another as one change of the document.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct ApplyBlockOpsRequest {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub ops: Vec<BlockOp>,
+}
+
+// The page starts at the block that the cursor points to, an empty cursor is
+// the start of the document. At most limit blocks are read, the rest of the
+// document is read if it's not positive. The request with only the doc_id is
+// the DocIdentifier, it reads the whole document.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct ReadDocumentRequest {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub cursor: String,
+
+    #[pb(index = 3)]
+    pub limit: i64,
+}
+
+// The data is the delta of the blocks start..end. The offset is where the page
+// starts in the document, a change of the page is a change of the document
+// once it's retained by the offset, so the blocks that aren't loaded are kept.
+// The next cursor is empty if the page is the last one, the cursors are only
+// valid for the rev_id of the page. The doc_id and the data are the ones of
+// the DocDelta.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DocumentPage {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub data: String,
+
+    #[pb(index = 3)]
+    pub start: i64,
+
+    #[pb(index = 4)]
+    pub end: i64,
+
+    #[pb(index = 5)]
+    pub offset: i64,
+
+    #[pb(index = 6)]
+    pub block_count: i64,
+
+    #[pb(index = 7)]
+    pub rev_id: i64,
+
+    #[pb(index = 8)]
+    pub next_cursor: String,
+}
@@ -0,0 +1,57 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+// The utf16 range start..end of the document is checked, the end 0 checks to
+// the end of the document. The lines that the range touches are checked as a
+// whole, but only the diagnostics in the range are returned.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct CheckTextRequest {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub start: i64,
+
+    #[pb(index = 3)]
+    pub end: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+pub enum DiagnosticKind {
+    Spelling = 0,
+    Grammar  = 1,
+    Style    = 2,
+}
+
+impl std::default::Default for DiagnosticKind {
+    fn default() -> Self { DiagnosticKind::Spelling }
+}
+
+// The range start..end is in utf16 code units, it's the range the editor
+// underlines. The best suggestion comes first.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct TextDiagnostic {
+    #[pb(index = 1)]
+    pub start: i64,
+
+    #[pb(index = 2)]
+    pub end: i64,
+
+    #[pb(index = 3)]
+    pub kind: DiagnosticKind,
+
+    #[pb(index = 4)]
+    pub message: String,
+
+    #[pb(index = 5)]
+    pub suggestions: Vec<String>,
+}
+
+// Ordered by their start.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedTextDiagnostic {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub items: Vec<TextDiagnostic>,
+}
@@ -0,0 +1,72 @@
+use flowy_derive::ProtoBuf;
+
+// The comment is on the range start..end of the document. A comment on a block
+// is on the range of its line.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct CreateCommentRequest {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub start: i64,
+
+    #[pb(index = 3)]
+    pub end: i64,
+
+    #[pb(index = 4)]
+    pub content: String,
+}
+
+// The range follows the edits of the document. It's empty if the text that the
+// comment was on is deleted.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct DocComment {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub doc_id: String,
+
+    #[pb(index = 3)]
+    pub user_id: String,
+
+    #[pb(index = 4)]
+    pub content: String,
+
+    #[pb(index = 5)]
+    pub start: i64,
+
+    #[pb(index = 6)]
+    pub end: i64,
+
+    #[pb(index = 7)]
+    pub resolved: bool,
+
+    #[pb(index = 8)]
+    pub create_time: i64,
+
+    #[pb(index = 9)]
+    pub modified_time: i64,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedDocComment {
+    #[pb(index = 1)]
+    pub items: Vec<DocComment>,
+}
+
+// A resolved comment can be opened again.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct ResolveCommentRequest {
+    #[pb(index = 1)]
+    pub comment_id: String,
+
+    #[pb(index = 2)]
+    pub resolved: bool,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct CommentIdentifier {
+    #[pb(index = 1)]
+    pub comment_id: String,
+}
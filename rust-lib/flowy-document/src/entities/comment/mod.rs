@@ -0,0 +1,3 @@
+mod comment;
+
+pub use comment::*;
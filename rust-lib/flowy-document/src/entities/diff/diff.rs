@@ -0,0 +1,89 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+// The documents are the json of their deltas, like the local and the remote
+// document of a conflict.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DiffDocumentsRequest {
+    #[pb(index = 1)]
+    pub old_data: String,
+
+    #[pb(index = 2)]
+    pub new_data: String,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DiffVersionsRequest {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub old_rev_id: i64,
+
+    #[pb(index = 3)]
+    pub new_rev_id: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+pub enum BlockDiffType {
+    Inserted = 0,
+    Deleted  = 1,
+    Changed  = 2,
+}
+
+impl std::default::Default for BlockDiffType {
+    fn default() -> Self { BlockDiffType::Inserted }
+}
+
+// The value is empty if the attribute isn't set. The range start..end is in the
+// block, it's the newline of the block for the block attributes.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct AttributeChange {
+    #[pb(index = 1)]
+    pub key: String,
+
+    #[pb(index = 2)]
+    pub old_value: String,
+
+    #[pb(index = 3)]
+    pub new_value: String,
+
+    #[pb(index = 4)]
+    pub start: i64,
+
+    #[pb(index = 5)]
+    pub end: i64,
+}
+
+// The index of the block in the old and the new document, it's -1 if the block
+// isn't in that document. The attribute changes of the text are only computed
+// if the text isn't changed.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct BlockDiff {
+    #[pb(index = 1)]
+    pub ty: BlockDiffType,
+
+    #[pb(index = 2)]
+    pub old_index: i64,
+
+    #[pb(index = 3)]
+    pub new_index: i64,
+
+    #[pb(index = 4)]
+    pub old_data: String,
+
+    #[pb(index = 5)]
+    pub new_data: String,
+
+    #[pb(index = 6)]
+    pub text_changed: bool,
+
+    #[pb(index = 7)]
+    pub attributes: Vec<AttributeChange>,
+}
+
+// The blocks that aren't changed are left out.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DocDiff {
+    #[pb(index = 1)]
+    pub items: Vec<BlockDiff>,
+}
@@ -0,0 +1,14 @@
+use flowy_derive::ProtoBuf;
+
+// The encrypted document can't be opened while it's locked.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct DocEncryption {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub encrypted: bool,
+
+    #[pb(index = 3)]
+    pub locked: bool,
+}
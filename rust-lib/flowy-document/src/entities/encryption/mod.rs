@@ -0,0 +1,3 @@
+mod encryption;
+
+pub use encryption::*;
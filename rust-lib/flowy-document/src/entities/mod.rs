@@ -1 +1,12 @@
+pub mod asset;
+pub mod attachment;
+pub mod block;
+pub mod check;
+pub mod comment;
+pub mod diff;
+pub mod encryption;
+pub mod presence;
+pub mod search;
+pub mod stats;
+pub mod version;
 pub mod ws;
@@ -0,0 +1,3 @@
+mod presence;
+
+pub use presence::*;
@@ -0,0 +1,57 @@
+use crate::entities::ws::{WsDataType, WsDocumentData};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// The cursor is a selection that starts and ends at the same index.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct UpdatePresenceRequest {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub start: i64,
+
+    #[pb(index = 3)]
+    pub end: i64,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct DocPresence {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub user_id: String,
+
+    #[pb(index = 3)]
+    pub start: i64,
+
+    #[pb(index = 4)]
+    pub end: i64,
+
+    // The user left the document or cleared the selection.
+    #[pb(index = 5)]
+    pub cleared: bool,
+
+    #[pb(index = 6)]
+    pub update_time: i64,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedDocPresence {
+    #[pb(index = 1)]
+    pub items: Vec<DocPresence>,
+}
+
+impl std::convert::From<DocPresence> for WsDocumentData {
+    fn from(presence: DocPresence) -> Self {
+        let doc_id = presence.doc_id.clone();
+        let bytes: Bytes = presence.try_into().unwrap();
+        Self {
+            doc_id,
+            ty: WsDataType::Presence,
+            data: bytes.to_vec(),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use flowy_derive::ProtoBuf;
+
+// The words of the query are all matched, the last one is matched as a prefix
+// so the results show up while typing. At most limit documents are returned,
+// the default is used if it's not positive.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct SearchDocumentsRequest {
+    #[pb(index = 1)]
+    pub query: String,
+
+    #[pb(index = 2)]
+    pub limit: i64,
+}
+
+// The utf16 range of a matched term in the snippet.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct SearchHighlight {
+    #[pb(index = 1)]
+    pub start: i64,
+
+    #[pb(index = 2)]
+    pub end: i64,
+}
+
+// The snippet is the text around the terms of the best matching line.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct DocSearchResult {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub snippet: String,
+
+    #[pb(index = 3)]
+    pub highlights: Vec<SearchHighlight>,
+}
+
+// The best result comes first.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedDocSearchResult {
+    #[pb(index = 1)]
+    pub items: Vec<DocSearchResult>,
+}
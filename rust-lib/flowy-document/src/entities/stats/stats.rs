@@ -0,0 +1,35 @@
+use flowy_derive::ProtoBuf;
+
+// The block counts are the number of lines of each kind, the empty lines are
+// not counted as paragraphs.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct DocStats {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub word_count: i64,
+
+    // The newlines are not counted.
+    #[pb(index = 3)]
+    pub char_count: i64,
+
+    // In seconds.
+    #[pb(index = 4)]
+    pub reading_time: i64,
+
+    #[pb(index = 5)]
+    pub paragraph_count: i64,
+
+    #[pb(index = 6)]
+    pub header_count: i64,
+
+    #[pb(index = 7)]
+    pub list_count: i64,
+
+    #[pb(index = 8)]
+    pub quote_count: i64,
+
+    #[pb(index = 9)]
+    pub code_count: i64,
+}
@@ -0,0 +1,31 @@
+use flowy_derive::ProtoBuf;
+
+// A snapshot of the document that can be browsed and restored.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DocVersion {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub rev_id: i64,
+
+    #[pb(index = 3)]
+    pub create_time: i64,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedDocVersion {
+    #[pb(index = 1)]
+    pub items: Vec<DocVersion>,
+}
+
+// Any rev_id of the document can be read or restored, not only the ones of the
+// versions.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DocVersionIdentifier {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub rev_id: i64,
+}
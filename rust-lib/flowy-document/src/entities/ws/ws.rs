@@ -2,9 +2,25 @@ use crate::errors::DocError;
 use bytes::Bytes;
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use flowy_document_infra::entities::doc::{NewDocUser, Revision};
+use flowy_infra::envelope::PayloadMigrations;
 use flowy_ws::{WsMessage, WsModule};
+use lazy_static::lazy_static;
 use std::convert::{TryFrom, TryInto};
 
+// Bump the version and register the migration here if the format of the data
+// sent through the websocket changes. The data sent before the envelope was
+// introduced is version 0.
+const WS_DOCUMENT_DATA_VERSION: u32 = 1;
+
+lazy_static! {
+    static ref WS_DOCUMENT_DATA_MIGRATIONS: PayloadMigrations =
+        PayloadMigrations::new(WS_DOCUMENT_DATA_VERSION).register(0, |data| Ok(data));
+}
+
+// The WsDocumentData is sent in the envelope, it returns the bytes of the
+// WsDocumentData of the received WsMessage's data.
+pub fn decode_ws_document_data(bytes: &[u8]) -> Result<Vec<u8>, String> { WS_DOCUMENT_DATA_MIGRATIONS.decode(bytes) }
+
 #[derive(Debug, Clone, ProtoBuf_Enum, Eq, PartialEq, Hash)]
 pub enum WsDataType {
     Acked      = 0,
@@ -12,6 +28,8 @@ pub enum WsDataType {
     PullRev    = 2, // data should be Revision
     Conflict   = 3,
     NewDocUser = 4,
+    // data should be DocPresence
+    Presence   = 5,
 }
 
 impl WsDataType {
@@ -66,10 +84,12 @@ impl std::convert::From<NewDocUser> for WsDocumentData {
 
 impl std::convert::Into<WsMessage> for WsDocumentData {
     fn into(self) -> WsMessage {
+        let object_id = self.doc_id.clone();
         let bytes: Bytes = self.try_into().unwrap();
         let msg = WsMessage {
             module: WsModule::Doc,
-            data: bytes.to_vec(),
+            data: WS_DOCUMENT_DATA_MIGRATIONS.encode(&bytes),
+            object_id,
         };
         msg
     }
@@ -51,7 +51,20 @@ impl DocError {
     static_doc_error!(undo, ErrorCode::UndoFail);
     static_doc_error!(redo, ErrorCode::RedoFail);
     static_doc_error!(out_of_bound, ErrorCode::OutOfBound);
+    static_doc_error!(block_data, ErrorCode::BlockDataInvalid);
+    static_doc_error!(cursor_invalid, ErrorCode::CursorInvalid);
     static_doc_error!(duplicate_rev, ErrorCode::DuplicateRevision);
+    static_doc_error!(asset_too_large, ErrorCode::AssetTooLarge);
+    static_doc_error!(asset_not_found, ErrorCode::AssetNotFound);
+    static_doc_error!(blob_not_found, ErrorCode::BlobNotFound);
+    static_doc_error!(asset_transfer_failed, ErrorCode::AssetTransferFailed);
+    static_doc_error!(attachment_not_found, ErrorCode::AttachmentNotFound);
+    static_doc_error!(attachment_over_quota, ErrorCode::AttachmentOverQuota);
+    static_doc_error!(attachment_file_error, ErrorCode::AttachmentFileError);
+    static_doc_error!(comment_not_found, ErrorCode::CommentNotFound);
+    static_doc_error!(comment_content_empty, ErrorCode::CommentContentEmpty);
+    static_doc_error!(document_locked, ErrorCode::DocumentLocked);
+    static_doc_error!(decrypt_failed, ErrorCode::DecryptFailed);
 }
 
 pub fn internal_error<T>(e: T) -> DocError
@@ -64,30 +77,59 @@ where
 #[derive(Debug, Clone, ProtoBuf_Enum, Display, PartialEq, Eq)]
 pub enum ErrorCode {
     #[display(fmt = "DocIdInvalid")]
-    DocIdInvalid      = 0,
+    DocIdInvalid        = 0,
 
     #[display(fmt = "DocNotfound")]
-    DocNotfound       = 1,
+    DocNotfound         = 1,
 
     #[display(fmt = "Document websocket error")]
-    WsConnectError    = 10,
+    WsConnectError      = 10,
 
     #[display(fmt = "Undo failed")]
-    UndoFail          = 200,
+    UndoFail            = 200,
     #[display(fmt = "Redo failed")]
-    RedoFail          = 201,
+    RedoFail            = 201,
 
     #[display(fmt = "Interval out of bound")]
-    OutOfBound        = 202,
+    OutOfBound          = 202,
+    #[display(fmt = "The data of the block is invalid")]
+    BlockDataInvalid    = 203,
+    #[display(fmt = "The cursor of the page is invalid")]
+    CursorInvalid       = 204,
+
+    #[display(fmt = "The asset exceeds the size limit")]
+    AssetTooLarge       = 300,
+    #[display(fmt = "Asset not found")]
+    AssetNotFound       = 301,
+    #[display(fmt = "Blob not found")]
+    BlobNotFound        = 302,
+    #[display(fmt = "The transfer of the asset failed")]
+    AssetTransferFailed = 303,
+    #[display(fmt = "Attachment not found")]
+    AttachmentNotFound  = 304,
+    #[display(fmt = "The attachments exceed the storage quota")]
+    AttachmentOverQuota = 305,
+    #[display(fmt = "The file of the attachment can't be read")]
+    AttachmentFileError = 306,
+
+    #[display(fmt = "Comment not found")]
+    CommentNotFound     = 310,
+    #[display(fmt = "The content of the comment can not be empty")]
+    CommentContentEmpty = 311,
+
+    #[display(fmt = "The document is locked")]
+    DocumentLocked      = 320,
+    #[display(fmt = "The document can't be decrypted")]
+    DecryptFailed       = 321,
 
     #[display(fmt = "Duplicate revision")]
-    DuplicateRevision = 400,
+    DuplicateRevision   = 400,
 
     #[display(fmt = "UserUnauthorized")]
-    UserUnauthorized  = 999,
+    UserUnauthorized    = 999,
 
     #[display(fmt = "InternalError")]
-    InternalError     = 1000,
+    InternalError       = 1000,
 }
 
 impl std::default::Default for ErrorCode {
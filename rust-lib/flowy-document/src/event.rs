@@ -0,0 +1,120 @@
+use flowy_derive::{Flowy_Event, ProtoBuf_Enum};
+use strum_macros::Display;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Display, Hash, ProtoBuf_Enum, Flowy_Event)]
+#[event_err = "DocError"]
+pub enum DocumentEvent {
+    #[event(input = "DocIdentifier", output = "DocDelta")]
+    OpenDocument        = 0,
+
+    #[event(input = "DocIdentifier")]
+    CloseDocument       = 1,
+
+    #[event(input = "DocDelta", output = "DocDelta")]
+    ApplyChange         = 2,
+
+    #[event(input = "ReadDocumentRequest", output = "DocumentPage")]
+    ReadDocument        = 3,
+
+    #[event(input = "DocIdentifier", output = "DocDelta")]
+    Undo                = 4,
+
+    #[event(input = "DocIdentifier", output = "DocDelta")]
+    Redo                = 5,
+
+    #[event(input = "DocIdentifier")]
+    ForceSave           = 6,
+
+    #[event(input = "DocIdentifier", output = "RepeatedDocVersion")]
+    ListVersions        = 7,
+
+    #[event(input = "DocVersionIdentifier", output = "DocDelta")]
+    ReadVersion         = 8,
+
+    #[event(input = "DocVersionIdentifier", output = "DocDelta")]
+    RestoreVersion      = 9,
+
+    #[event(input = "CreateAssetRequest", output = "DocAsset")]
+    AttachAsset         = 10,
+
+    #[event(input = "AssetIdentifier", output = "AssetData")]
+    ReadAsset           = 11,
+
+    #[event(input = "DocIdentifier", output = "RepeatedDocAsset")]
+    ListAssets          = 12,
+
+    #[event(input = "DocIdentifier", output = "DocStats")]
+    GetDocumentStats    = 13,
+
+    #[event(input = "UpdatePresenceRequest")]
+    UpdatePresence      = 14,
+
+    #[event(input = "DocIdentifier")]
+    ClearPresence       = 15,
+
+    #[event(input = "DocIdentifier", output = "RepeatedDocPresence")]
+    ReadCollaborators   = 16,
+
+    #[event(input = "DocIdentifier", output = "RepeatedDocBlock")]
+    ReadBlocks          = 17,
+
+    #[event(input = "ApplyBlockOpsRequest", output = "RepeatedDocBlock")]
+    ApplyBlockOps       = 18,
+
+    #[event(input = "CreateCommentRequest", output = "DocComment")]
+    CreateComment       = 19,
+
+    #[event(input = "ResolveCommentRequest", output = "DocComment")]
+    ResolveComment      = 20,
+
+    #[event(input = "CommentIdentifier")]
+    DeleteComment       = 21,
+
+    #[event(input = "DocIdentifier", output = "RepeatedDocComment")]
+    ReadComments        = 22,
+
+    #[event(input = "DiffDocumentsRequest", output = "DocDiff")]
+    DiffDocuments       = 23,
+
+    #[event(input = "DiffVersionsRequest", output = "DocDiff")]
+    DiffVersions        = 24,
+
+    #[event(input = "SearchDocumentsRequest", output = "RepeatedDocSearchResult")]
+    SearchDocuments     = 26,
+
+    #[event(input = "DocIdentifier", output = "DocEncryption")]
+    EncryptDocument     = 27,
+
+    #[event(input = "DocIdentifier", output = "DocEncryption")]
+    DecryptDocument     = 28,
+
+    #[event(input = "DocIdentifier", output = "DocEncryption")]
+    LockDocument        = 29,
+
+    #[event(input = "DocIdentifier", output = "DocEncryption")]
+    UnlockDocument      = 30,
+
+    #[event(input = "CheckTextRequest", output = "RepeatedTextDiagnostic")]
+    CheckText           = 31,
+
+    #[event(input = "AssetIdentifier", output = "AssetTransfer")]
+    UploadAsset         = 32,
+
+    #[event(input = "AssetIdentifier", output = "AssetTransfer")]
+    DownloadAsset       = 33,
+
+    #[event(output = "RepeatedAssetTransfer")]
+    ReadAssetTransfers  = 34,
+
+    #[event(input = "ImportAttachmentRequest", output = "DocAttachment")]
+    ImportAttachment    = 35,
+
+    #[event(input = "DocIdentifier", output = "RepeatedDocAttachment")]
+    ListAttachments     = 36,
+
+    #[event(input = "AttachmentIdentifier")]
+    DeleteAttachment    = 37,
+
+    #[event(output = "AttachmentUsage")]
+    ReadAttachmentUsage = 38,
+}
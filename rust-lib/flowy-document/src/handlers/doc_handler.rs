@@ -0,0 +1,442 @@
+use crate::{
+    entities::{
+        asset::{
+            AssetData,
+            AssetIdentifier,
+            AssetTransfer,
+            CreateAssetRequest,
+            DocAsset,
+            RepeatedAssetTransfer,
+            RepeatedDocAsset,
+        },
+        attachment::{
+            AttachmentIdentifier,
+            AttachmentUsage,
+            DocAttachment,
+            ImportAttachmentRequest,
+            RepeatedDocAttachment,
+        },
+        block::{ApplyBlockOpsRequest, DocumentPage, ReadDocumentRequest, RepeatedDocBlock},
+        check::{CheckTextRequest, RepeatedTextDiagnostic},
+        comment::{CommentIdentifier, CreateCommentRequest, DocComment, RepeatedDocComment, ResolveCommentRequest},
+        diff::{DiffDocumentsRequest, DiffVersionsRequest, DocDiff},
+        encryption::DocEncryption,
+        presence::{RepeatedDocPresence, UpdatePresenceRequest},
+        search::{RepeatedDocSearchResult, SearchDocumentsRequest},
+        stats::DocStats,
+        version::{DocVersionIdentifier, RepeatedDocVersion},
+    },
+    errors::DocError,
+    module::FlowyDocument,
+};
+use flowy_dispatch::prelude::{data_result, Arena, Data, DataResult, Unit};
+use flowy_document_infra::entities::doc::{DocDelta, DocIdentifier};
+use std::sync::Arc;
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn open_document_handler(
+    data: Data<DocIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<DocDelta, DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let edit_doc = document.open(params).await?;
+    data_result(edit_doc.delta().await?)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn close_document_handler(
+    data: Data<DocIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> Result<(), DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    document.close(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn apply_change_handler(
+    data: Data<DocDelta>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<DocDelta, DocError> {
+    let delta = data.into_inner();
+    check_doc_id(&delta.doc_id)?;
+    let delta = document.apply_doc_delta(delta).await?;
+    data_result(delta)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn read_document_handler(
+    data: Data<ReadDocumentRequest>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<DocumentPage, DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let page = document.read_page(params).await?;
+    data_result(page)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn undo_handler(
+    data: Data<DocIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<DocDelta, DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let delta = document.undo(params).await?;
+    data_result(delta)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn redo_handler(
+    data: Data<DocIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<DocDelta, DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let delta = document.redo(params).await?;
+    data_result(delta)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn force_save_handler(
+    data: Data<DocIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> Result<(), DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    document.force_save(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn list_versions_handler(
+    data: Data<DocIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<RepeatedDocVersion, DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let versions = document.list_versions(params).await?;
+    data_result(versions)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn read_version_handler(
+    data: Data<DocVersionIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<DocDelta, DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let delta = document.read_version(params).await?;
+    data_result(delta)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn restore_version_handler(
+    data: Data<DocVersionIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<DocDelta, DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let delta = document.restore_version(params).await?;
+    data_result(delta)
+}
+
+#[tracing::instrument(skip(data, document, arena), err)]
+pub(crate) async fn search_documents_handler(
+    data: Data<SearchDocumentsRequest>,
+    document: Unit<Arc<FlowyDocument>>,
+    arena: Arena,
+) -> DataResult<RepeatedDocSearchResult, DocError> {
+    let results = document.search(data.into_inner(), &arena)?;
+    data_result(results)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn check_text_handler(
+    data: Data<CheckTextRequest>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<RepeatedTextDiagnostic, DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let diagnostics = document.check_text(params).await?;
+    data_result(diagnostics)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn diff_documents_handler(
+    data: Data<DiffDocumentsRequest>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<DocDiff, DocError> {
+    let diff = document.diff_documents(data.into_inner())?;
+    data_result(diff)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn diff_versions_handler(
+    data: Data<DiffVersionsRequest>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<DocDiff, DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let diff = document.diff_versions(params).await?;
+    data_result(diff)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn attach_asset_handler(
+    data: Data<CreateAssetRequest>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<DocAsset, DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let asset = document.attach_asset(params)?;
+    data_result(asset)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn read_asset_handler(
+    data: Data<AssetIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<AssetData, DocError> {
+    let params = data.into_inner();
+    let asset = document.read_asset(&params.asset_id)?;
+    data_result(asset)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn list_assets_handler(
+    data: Data<DocIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<RepeatedDocAsset, DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let assets = document.list_assets(&params.doc_id)?;
+    data_result(assets)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn upload_asset_handler(
+    data: Data<AssetIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<AssetTransfer, DocError> {
+    let params = data.into_inner();
+    let transfer = document.upload_asset(&params.asset_id).await?;
+    data_result(transfer)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn download_asset_handler(
+    data: Data<AssetIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<AssetTransfer, DocError> {
+    let params = data.into_inner();
+    let transfer = document.download_asset(&params.asset_id).await?;
+    data_result(transfer)
+}
+
+#[tracing::instrument(skip(document), err)]
+pub(crate) async fn read_asset_transfers_handler(
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<RepeatedAssetTransfer, DocError> {
+    let transfers = document.asset_transfers()?;
+    data_result(transfers)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn import_attachment_handler(
+    data: Data<ImportAttachmentRequest>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<DocAttachment, DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let attachment = document.import_attachment(params)?;
+    data_result(attachment)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn list_attachments_handler(
+    data: Data<DocIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<RepeatedDocAttachment, DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let attachments = document.list_attachments(&params.doc_id)?;
+    data_result(attachments)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn delete_attachment_handler(
+    data: Data<AttachmentIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> Result<(), DocError> {
+    let params = data.into_inner();
+    let _ = document.delete_attachment(&params.attachment_id)?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(document), err)]
+pub(crate) async fn read_attachment_usage_handler(
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<AttachmentUsage, DocError> {
+    let usage = document.attachment_usage()?;
+    data_result(usage)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn get_document_stats_handler(
+    data: Data<DocIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<DocStats, DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let stats = document.stats(params).await?;
+    data_result(stats)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn update_presence_handler(
+    data: Data<UpdatePresenceRequest>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> Result<(), DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let _ = document.update_presence(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn clear_presence_handler(
+    data: Data<DocIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> Result<(), DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let _ = document.clear_presence(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn read_collaborators_handler(
+    data: Data<DocIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<RepeatedDocPresence, DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let collaborators = document.read_collaborators(params).await?;
+    data_result(collaborators)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn read_blocks_handler(
+    data: Data<DocIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<RepeatedDocBlock, DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let blocks = document.read_blocks(params).await?;
+    data_result(blocks)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn apply_block_ops_handler(
+    data: Data<ApplyBlockOpsRequest>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<RepeatedDocBlock, DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let blocks = document.apply_block_ops(params).await?;
+    data_result(blocks)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn create_comment_handler(
+    data: Data<CreateCommentRequest>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<DocComment, DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let comment = document.create_comment(params).await?;
+    data_result(comment)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn resolve_comment_handler(
+    data: Data<ResolveCommentRequest>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<DocComment, DocError> {
+    let comment = document.resolve_comment(data.into_inner())?;
+    data_result(comment)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn delete_comment_handler(
+    data: Data<CommentIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> Result<(), DocError> {
+    let _ = document.delete_comment(data.into_inner()).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn read_comments_handler(
+    data: Data<DocIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<RepeatedDocComment, DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let comments = document.read_comments(params)?;
+    data_result(comments)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn encrypt_document_handler(
+    data: Data<DocIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<DocEncryption, DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let encryption = document.encrypt(params).await?;
+    data_result(encryption)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn decrypt_document_handler(
+    data: Data<DocIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<DocEncryption, DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let encryption = document.decrypt(params).await?;
+    data_result(encryption)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn lock_document_handler(
+    data: Data<DocIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<DocEncryption, DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let encryption = document.lock(params).await?;
+    data_result(encryption)
+}
+
+#[tracing::instrument(skip(data, document), err)]
+pub(crate) async fn unlock_document_handler(
+    data: Data<DocIdentifier>,
+    document: Unit<Arc<FlowyDocument>>,
+) -> DataResult<DocEncryption, DocError> {
+    let params = data.into_inner();
+    check_doc_id(&params.doc_id)?;
+    let encryption = document.unlock(params).await?;
+    data_result(encryption)
+}
+
+fn check_doc_id(doc_id: &str) -> Result<(), DocError> {
+    if doc_id.trim().is_empty() {
+        return Err(DocError::id_invalid());
+    }
+    Ok(())
+}
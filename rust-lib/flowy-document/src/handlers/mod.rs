@@ -0,0 +1,3 @@
+mod doc_handler;
+
+pub use doc_handler::*;
@@ -2,6 +2,8 @@
 
 pub mod entities;
 pub mod errors;
+pub mod event;
+mod handlers;
 pub mod module;
 mod notify;
 pub mod protobuf;
@@ -1,25 +1,121 @@
 use crate::{
-    errors::DocError,
+    entities::{
+        asset::{AssetData, AssetTransfer, CreateAssetRequest, DocAsset, RepeatedAssetTransfer, RepeatedDocAsset},
+        attachment::{AttachmentUsage, DocAttachment, ImportAttachmentRequest, RepeatedDocAttachment},
+        block::{ApplyBlockOpsRequest, DocumentPage, ReadDocumentRequest, RepeatedDocBlock},
+        check::{CheckTextRequest, RepeatedTextDiagnostic},
+        comment::{CommentIdentifier, CreateCommentRequest, DocComment, RepeatedDocComment, ResolveCommentRequest},
+        diff::{DiffDocumentsRequest, DiffVersionsRequest, DocDiff},
+        encryption::DocEncryption,
+        presence::{RepeatedDocPresence, UpdatePresenceRequest},
+        search::{RepeatedDocSearchResult, SearchDocumentsRequest},
+        stats::DocStats,
+        version::{DocVersionIdentifier, RepeatedDocVersion},
+    },
+    errors::{internal_error, DocError},
+    event::DocumentEvent,
+    handlers::*,
     services::{
-        doc::{doc_controller::DocController, ClientEditDoc},
+        asset::{parse_asset_id, AssetStore, AssetTransfers},
+        attachment::{AttachmentStore, DEFAULT_ATTACHMENT_QUOTA},
+        blob::{BlobStore, DeletedFiles},
+        check::{check_text, RepeatedWordChecker, TextChecker},
+        comment::CommentStore,
+        doc::{
+            diff_documents,
+            doc_controller::DocController,
+            AutosaveConfig,
+            BlockOperation,
+            ClientEditDoc,
+            DEFAULT_SNAPSHOT_INTERVAL,
+            MAX_UNDOS,
+        },
+        encryption::{DocKeyring, SyncKeyring},
+        export::write_export,
+        search::{update_search_lines, SearchIndex},
         server::construct_doc_server,
         ws::WsDocumentManager,
     },
+    sql_tables::{DocSnapshotTableSql, RevState, RevTableSql},
+};
+use flowy_database::{ConnectionPool, SqliteConnection};
+use flowy_dispatch::prelude::*;
+use flowy_document_infra::{
+    entities::doc::{revision_from_doc, Doc, DocDelta, DocIdentifier, RevType},
+    user_default::doc_initial_delta,
 };
-use flowy_database::ConnectionPool;
-use flowy_document_infra::entities::doc::{DocDelta, DocIdentifier};
+use flowy_infra::memory::{MemoryBudget, DEFAULT_MEMORY_BUDGET};
 use flowy_net::config::ServerConfig;
-use std::sync::Arc;
+use flowy_ot::core::{Delta, OperationTransformable};
+use std::{convert::TryFrom, sync::Arc};
+
+pub use crate::services::encryption::{SyncKey, SyncKeyProvider};
 
 pub trait DocumentUser: Send + Sync {
     fn user_dir(&self) -> Result<String, DocError>;
     fn user_id(&self) -> Result<String, DocError>;
     fn token(&self) -> Result<String, DocError>;
     fn db_pool(&self) -> Result<Arc<ConnectionPool>, DocError>;
+    // The keys of the encrypted documents are derived from this secret.
+    fn document_secret(&self) -> Result<String, DocError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct DocumentConfig {
+    // How many undo units each opened document keeps.
+    pub undo_capacity: usize,
+    pub autosave: AutosaveConfig,
+    // A snapshot is taken every this many revisions, it's where reading a version starts.
+    pub snapshot_interval: i64,
+    // Checks the text of the opened documents after their changes and for the
+    // CheckText event.
+    pub text_checker: Arc<dyn TextChecker>,
+    // Shared with the other caches of the app, the opened documents and the
+    // blobs that were read are evicted when it's over its limit.
+    pub memory_budget: Arc<MemoryBudget>,
+    // How many bytes the attachments of a user can take, see AttachmentStore.
+    pub attachment_quota: i64,
+}
+
+impl std::default::Default for DocumentConfig {
+    fn default() -> Self {
+        DocumentConfig {
+            undo_capacity: MAX_UNDOS,
+            autosave: AutosaveConfig::default(),
+            snapshot_interval: DEFAULT_SNAPSHOT_INTERVAL,
+            text_checker: Arc::new(RepeatedWordChecker::default()),
+            memory_budget: MemoryBudget::new(DEFAULT_MEMORY_BUDGET),
+            attachment_quota: DEFAULT_ATTACHMENT_QUOTA,
+        }
+    }
+}
+
+// The estimates of what the documents keep in memory, and the blobs they keep
+// on disk. The bytes are the sizes of the data, without the overhead.
+#[derive(Debug, Clone, Default)]
+pub struct DocumentMemoryUsage {
+    pub open_documents: usize,
+    pub document_bytes: usize,
+    pub cached_revisions: usize,
+    pub cached_revision_bytes: usize,
+    pub pending_revisions: usize,
+    pub unlocked_keys: usize,
+    pub running_transfers: usize,
+    pub blobs: usize,
+    pub blob_bytes: u64,
 }
 
 pub struct FlowyDocument {
     doc_ctrl: Arc<DocController>,
+    assets: Arc<AssetStore>,
+    attachments: Arc<AttachmentStore>,
+    transfers: Arc<AssetTransfers>,
+    blobs: Arc<BlobStore>,
+    comments: Arc<CommentStore>,
+    search_index: Arc<SearchIndex>,
+    keyring: Arc<DocKeyring>,
+    sync_keys: Arc<SyncKeyring>,
+    text_checker: Arc<dyn TextChecker>,
     user: Arc<dyn DocumentUser>,
 }
 
@@ -28,32 +124,172 @@ impl FlowyDocument {
         user: Arc<dyn DocumentUser>,
         ws_manager: Arc<WsDocumentManager>,
         server_config: &ServerConfig,
+        config: DocumentConfig,
     ) -> FlowyDocument {
         let server = construct_doc_server(server_config);
-        let doc_ctrl = Arc::new(DocController::new(server.clone(), user.clone(), ws_manager.clone()));
-        Self { doc_ctrl, user }
+        let keyring = Arc::new(DocKeyring::new(user.clone()));
+        let sync_keys = Arc::new(SyncKeyring::new());
+        let text_checker = config.text_checker.clone();
+        let memory_budget = config.memory_budget.clone();
+        let attachment_quota = config.attachment_quota;
+        let doc_ctrl = Arc::new(DocController::new(
+            server.clone(),
+            user.clone(),
+            ws_manager.clone(),
+            keyring.clone(),
+            sync_keys.clone(),
+            config,
+        ));
+        let blobs = Arc::new(BlobStore::new(user.clone(), &memory_budget));
+        let assets = Arc::new(AssetStore::new(user.clone(), blobs.clone()));
+        let attachments = Arc::new(AttachmentStore::new(user.clone(), blobs.clone(), attachment_quota));
+        let transfers = Arc::new(AssetTransfers::new(user.clone(), server, assets.clone()));
+        let comments = Arc::new(CommentStore::new(user.clone()));
+        let search_index = Arc::new(SearchIndex::new(user.clone()));
+        Self {
+            doc_ctrl,
+            assets,
+            attachments,
+            transfers,
+            blobs,
+            comments,
+            search_index,
+            keyring,
+            sync_keys,
+            text_checker,
+            user,
+        }
     }
 
+    // The documents are synced as they are until the provider is set.
+    pub fn set_sync_key_provider(&self, provider: Arc<dyn SyncKeyProvider>) { self.sync_keys.set_provider(provider); }
+
     pub fn init(&self) -> Result<(), DocError> {
         let _ = self.doc_ctrl.init()?;
         Ok(())
     }
 
     pub fn delete(&self, params: DocIdentifier) -> Result<(), DocError> {
-        let _ = self.doc_ctrl.delete(params)?;
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let files = conn.immediate_transaction::<_, DocError, _>(|| self.delete_with(params, conn))?;
+        self.remove_files(files);
         Ok(())
     }
 
+    // Deletes the document using the connection, so it's deleted in the
+    // transaction of the caller with the other records. The returned files are
+    // removed with remove_files once the transaction was committed.
+    pub fn delete_with(&self, params: DocIdentifier, conn: &SqliteConnection) -> Result<DeletedFiles, DocError> {
+        let doc_id = &params.doc_id;
+        let mut files = DeletedFiles::default();
+        let _ = self.doc_ctrl.delete(params.clone())?;
+        let _ = self.assets.delete_all(doc_id, conn, &mut files)?;
+        let _ = self.attachments.delete_all(doc_id, conn, &mut files)?;
+        let _ = self.comments.delete_all(doc_id, conn)?;
+        let _ = self.search_index.delete_all(doc_id, conn)?;
+        let _ = self.keyring.delete(doc_id, conn)?;
+        Ok(files)
+    }
+
+    pub fn remove_files(&self, files: DeletedFiles) { self.blobs.remove_files(files); }
+
+    // The ids of the documents that keep data locally, whether a view still owns
+    // them or not.
+    pub fn stored_doc_ids(&self) -> Result<Vec<String>, DocError> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        let mut doc_ids = RevTableSql {}.read_doc_ids(conn)?;
+        for doc_id in self.assets.doc_ids()?.into_iter().chain(self.attachments.doc_ids()?) {
+            if !doc_ids.contains(&doc_id) {
+                doc_ids.push(doc_id);
+            }
+        }
+        Ok(doc_ids)
+    }
+
+    // Deletes the document like delete does, and its revisions and snapshots too.
+    // It's for the documents that no view owns anymore.
+    pub fn purge(&self, params: DocIdentifier) -> Result<(), DocError> {
+        let _ = self.delete(params.clone())?;
+        let _ = self.blobs.release_all(&params.doc_id)?;
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        conn.immediate_transaction::<_, DocError, _>(|| {
+            let _ = RevTableSql {}.delete_rev_tables(&params.doc_id, conn)?;
+            let _ = DocSnapshotTableSql {}.delete_snapshot_tables(&params.doc_id, conn)?;
+            Ok(())
+        })
+    }
+
+    // Creates the document with the delta using the connection, so it's written
+    // in the transaction of the caller with the other records. It's stored like
+    // the documents read from the server, the document must not exist yet.
+    pub fn create_with_delta(&self, doc_id: &str, delta: &Delta, conn: &SqliteConnection) -> Result<(), DocError> {
+        let delta = doc_initial_delta().compose(delta)?;
+        let doc = Doc {
+            id: doc_id.to_owned(),
+            data: delta.to_json(),
+            rev_id: 0,
+            base_rev_id: 0,
+        };
+        let revision = revision_from_doc(doc, RevType::Local);
+        let _ = RevTableSql {}.create_rev_table(vec![(revision, RevState::Acked)], conn)?;
+        let _ = update_search_lines(doc_id, &delta, conn)?;
+        Ok(())
+    }
+
+    // The blobs are left out while no user is signed in.
+    pub async fn memory_usage(&self) -> DocumentMemoryUsage {
+        let mut usage = DocumentMemoryUsage {
+            unlocked_keys: self.keyring.unlocked_count(),
+            running_transfers: self.transfers.running_count(),
+            ..Default::default()
+        };
+        self.doc_ctrl.memory_usage(&mut usage).await;
+        if let Ok((blobs, blob_bytes)) = self.blobs.disk_usage() {
+            usage.blobs = blobs;
+            usage.blob_bytes = blob_bytes;
+        }
+        usage
+    }
+
+    // Removes the asset files that no asset refers to and the blobs that no owner
+    // refers to, returns how many files and bytes were removed.
+    pub fn remove_unreferenced_blobs(&self) -> Result<(usize, u64), DocError> {
+        self.assets.remove_unreferenced_files()
+    }
+
     pub async fn open(&self, params: DocIdentifier) -> Result<Arc<ClientEditDoc>, DocError> {
         let edit_context = self.doc_ctrl.open(params, self.user.db_pool()?).await?;
         Ok(edit_context)
     }
 
+    // The assets that the document doesn't refer to anymore are removed when it's
+    // closed. Removing them while editing would lose the assets that were attached
+    // but aren't inserted yet.
     pub async fn close(&self, params: DocIdentifier) -> Result<(), DocError> {
-        let _ = self.doc_ctrl.close(&params.doc_id)?;
+        if let Some(doc) = self.doc_ctrl.opened_delta(&params.doc_id).await? {
+            let delta = Delta::from_json(&doc.data)?;
+            let _ = self.assets.collect_garbage(&params.doc_id, &delta)?;
+        }
+        let _ = self.doc_ctrl.close(&params.doc_id).await?;
         Ok(())
     }
 
+    // Called when the current user changes. The assets aren't collected, they're
+    // in the directory of the previous user.
+    pub async fn close_all(&self) { self.doc_ctrl.close_all().await }
+
+    pub async fn force_save(&self, params: DocIdentifier) -> Result<(), DocError> {
+        let _ = self.doc_ctrl.force_save(&params.doc_id).await?;
+        Ok(())
+    }
+
+    pub async fn read(&self, params: DocIdentifier) -> Result<DocDelta, DocError> {
+        self.read_document_data(params, self.user.db_pool()?).await
+    }
+
     pub async fn read_document_data(
         &self,
         params: DocIdentifier,
@@ -64,6 +300,275 @@ impl FlowyDocument {
         Ok(delta)
     }
 
+    // Only the page is sent. The document that isn't opened isn't loaded into an
+    // edit session for it, the changes of the page open the document.
+    pub async fn read_page(&self, params: ReadDocumentRequest) -> Result<DocumentPage, DocError> {
+        let limit = params.limit.max(0) as usize;
+        let page = self
+            .doc_ctrl
+            .read_page(&params.doc_id, &params.cursor, limit, self.user.db_pool()?)
+            .await?;
+        Ok(DocumentPage {
+            doc_id: params.doc_id,
+            data: page.delta.to_json(),
+            start: page.start as i64,
+            end: page.end as i64,
+            offset: page.offset as i64,
+            block_count: page.block_count as i64,
+            rev_id: page.rev_id,
+            next_cursor: page.next_cursor,
+        })
+    }
+
+    pub async fn undo(&self, params: DocIdentifier) -> Result<DocDelta, DocError> {
+        let edit_context = self.doc_ctrl.open(params, self.user.db_pool()?).await?;
+        let _ = edit_context.undo().await?;
+        edit_context.delta().await
+    }
+
+    pub async fn redo(&self, params: DocIdentifier) -> Result<DocDelta, DocError> {
+        let edit_context = self.doc_ctrl.open(params, self.user.db_pool()?).await?;
+        let _ = edit_context.redo().await?;
+        edit_context.delta().await
+    }
+
+    pub async fn stats(&self, params: DocIdentifier) -> Result<DocStats, DocError> {
+        let edit_context = self.doc_ctrl.open(params, self.user.db_pool()?).await?;
+        edit_context.stats().await
+    }
+
+    pub async fn update_presence(&self, params: UpdatePresenceRequest) -> Result<(), DocError> {
+        let edit_context = self
+            .doc_ctrl
+            .open(params.doc_id.clone().into(), self.user.db_pool()?)
+            .await?;
+        edit_context.update_presence(params.start, params.end)
+    }
+
+    pub async fn clear_presence(&self, params: DocIdentifier) -> Result<(), DocError> {
+        let edit_context = self.doc_ctrl.open(params, self.user.db_pool()?).await?;
+        edit_context.clear_presence()
+    }
+
+    pub async fn read_collaborators(&self, params: DocIdentifier) -> Result<RepeatedDocPresence, DocError> {
+        let edit_context = self.doc_ctrl.open(params, self.user.db_pool()?).await?;
+        let items = edit_context.collaborators();
+        Ok(RepeatedDocPresence { items })
+    }
+
+    pub async fn read_blocks(&self, params: DocIdentifier) -> Result<RepeatedDocBlock, DocError> {
+        let edit_context = self.doc_ctrl.open(params, self.user.db_pool()?).await?;
+        let blocks = edit_context.blocks().await?;
+        let items = blocks
+            .iter()
+            .enumerate()
+            .map(|(index, block)| block.to_doc_block(index))
+            .collect();
+        Ok(RepeatedDocBlock { items })
+    }
+
+    pub async fn apply_block_ops(&self, params: ApplyBlockOpsRequest) -> Result<RepeatedDocBlock, DocError> {
+        let ops = params
+            .ops
+            .into_iter()
+            .map(BlockOperation::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let doc_identifier: DocIdentifier = params.doc_id.into();
+        let edit_context = self.doc_ctrl.open(doc_identifier.clone(), self.user.db_pool()?).await?;
+        let _ = edit_context.apply_block_ops(ops).await?;
+        self.read_blocks(doc_identifier).await
+    }
+
+    pub async fn create_comment(&self, params: CreateCommentRequest) -> Result<DocComment, DocError> {
+        let edit_context = self
+            .doc_ctrl
+            .open(params.doc_id.clone().into(), self.user.db_pool()?)
+            .await?;
+        self.comments.create(&edit_context, params).await
+    }
+
+    pub fn resolve_comment(&self, params: ResolveCommentRequest) -> Result<DocComment, DocError> {
+        self.comments.resolve(params)
+    }
+
+    pub async fn delete_comment(&self, params: CommentIdentifier) -> Result<(), DocError> {
+        let doc_id = self.comments.doc_id(&params.comment_id)?;
+        let edit_context = self.doc_ctrl.open(doc_id.into(), self.user.db_pool()?).await?;
+        self.comments.delete(&edit_context, &params.comment_id).await
+    }
+
+    pub fn read_comments(&self, params: DocIdentifier) -> Result<RepeatedDocComment, DocError> {
+        self.comments.comments(&params.doc_id)
+    }
+
+    // The documents are indexed when they're opened and after their changes, the
+    // ones that were never opened since the index was added aren't found.
+    pub fn search(
+        &self,
+        params: SearchDocumentsRequest,
+        arena: &RequestArena,
+    ) -> Result<RepeatedDocSearchResult, DocError> {
+        self.search_index.search(params, arena)
+    }
+
+    pub async fn check_text(&self, params: CheckTextRequest) -> Result<RepeatedTextDiagnostic, DocError> {
+        let edit_context = self
+            .doc_ctrl
+            .open(params.doc_id.clone().into(), self.user.db_pool()?)
+            .await?;
+        let delta = Delta::from_json(&edit_context.delta().await?.data)?;
+        let items = check_text(
+            self.text_checker.as_ref(),
+            &delta,
+            params.start.max(0) as usize,
+            params.end.max(0) as usize,
+        )?;
+        Ok(RepeatedTextDiagnostic {
+            doc_id: params.doc_id,
+            items,
+        })
+    }
+
+    // The document is closed before its data is rewritten, so its session picks up
+    // the key when it's opened again. Its index is removed, it would keep the
+    // text in plaintext.
+    pub async fn encrypt(&self, params: DocIdentifier) -> Result<DocEncryption, DocError> {
+        let _ = self.close(params.clone()).await?;
+        let encryption = self.keyring.encrypt(&params.doc_id)?;
+        let pool = self.user.db_pool()?;
+        let _ = self
+            .search_index
+            .delete_all(&params.doc_id, &*pool.get().map_err(internal_error)?)?;
+        Ok(encryption)
+    }
+
+    pub async fn decrypt(&self, params: DocIdentifier) -> Result<DocEncryption, DocError> {
+        let _ = self.keyring.cipher(&params.doc_id)?;
+        let _ = self.close(params.clone()).await?;
+        self.keyring.decrypt(&params.doc_id)
+    }
+
+    // The editing session is closed, the decrypted document doesn't stay in memory.
+    pub async fn lock(&self, params: DocIdentifier) -> Result<DocEncryption, DocError> {
+        let _ = self.close(params.clone()).await?;
+        self.keyring.lock(&params.doc_id)
+    }
+
+    pub fn encryption(&self, doc_id: &str) -> Result<DocEncryption, DocError> { self.keyring.state(doc_id) }
+
+    pub async fn unlock(&self, params: DocIdentifier) -> Result<DocEncryption, DocError> {
+        self.keyring.unlock(&params.doc_id)
+    }
+
+    pub async fn list_versions(&self, params: DocIdentifier) -> Result<RepeatedDocVersion, DocError> {
+        let edit_context = self.doc_ctrl.open(params, self.user.db_pool()?).await?;
+        let items = edit_context.versions().await?;
+        Ok(RepeatedDocVersion { items })
+    }
+
+    pub async fn read_version(&self, params: DocVersionIdentifier) -> Result<DocDelta, DocError> {
+        let edit_context = self
+            .doc_ctrl
+            .open(params.doc_id.clone().into(), self.user.db_pool()?)
+            .await?;
+        let delta = edit_context.read_version(params.rev_id).await?;
+        Ok(DocDelta {
+            doc_id: params.doc_id,
+            data: delta.to_json(),
+        })
+    }
+
+    pub fn diff_documents(&self, params: DiffDocumentsRequest) -> Result<DocDiff, DocError> {
+        let old = Delta::from_json(&params.old_data)?;
+        let new = Delta::from_json(&params.new_data)?;
+        let items = diff_documents(&old, &new);
+        Ok(DocDiff { items })
+    }
+
+    pub async fn diff_versions(&self, params: DiffVersionsRequest) -> Result<DocDiff, DocError> {
+        let edit_context = self
+            .doc_ctrl
+            .open(params.doc_id.clone().into(), self.user.db_pool()?)
+            .await?;
+        let old = edit_context.read_version(params.old_rev_id).await?;
+        let new = edit_context.read_version(params.new_rev_id).await?;
+        let items = diff_documents(&old, &new);
+        Ok(DocDiff { items })
+    }
+
+    pub async fn restore_version(&self, params: DocVersionIdentifier) -> Result<DocDelta, DocError> {
+        let edit_context = self
+            .doc_ctrl
+            .open(params.doc_id.clone().into(), self.user.db_pool()?)
+            .await?;
+        let _ = edit_context.restore_version(params.rev_id).await?;
+        edit_context.delta().await
+    }
+
+    // Writes the exported document to the export directory of the user and returns
+    // the path of the file.
+    pub fn write_export(&self, doc_id: &str, extension: &str, data: &[u8]) -> Result<String, DocError> {
+        write_export(&self.user.user_dir()?, doc_id, extension, data)
+    }
+
+    // The assets of the copied document are copied too, the copy links to them
+    // instead of the assets of the document.
+    pub fn duplicate_assets(&self, doc_id: &str, new_doc_id: &str, delta: &Delta) -> Result<Delta, DocError> {
+        self.assets.duplicate(doc_id, new_doc_id, delta)
+    }
+
+    // The documents and the views keep their large data in the blobs, referred to
+    // with their ids. The blobs they refer to are released when they're purged.
+    pub fn blobs(&self) -> Arc<BlobStore> { self.blobs.clone() }
+
+    pub fn attach_asset(&self, params: CreateAssetRequest) -> Result<DocAsset, DocError> { self.assets.attach(params) }
+
+    pub fn read_asset(&self, asset_id: &str) -> Result<AssetData, DocError> {
+        self.assets.read(parse_asset_id(asset_id))
+    }
+
+    pub fn list_assets(&self, doc_id: &str) -> Result<RepeatedDocAsset, DocError> {
+        let items = self.assets.assets(doc_id)?;
+        Ok(RepeatedDocAsset { items })
+    }
+
+    pub fn import_attachment(&self, params: ImportAttachmentRequest) -> Result<DocAttachment, DocError> {
+        self.attachments.import(params)
+    }
+
+    pub fn list_attachments(&self, doc_id: &str) -> Result<RepeatedDocAttachment, DocError> {
+        let items = self.attachments.attachments(doc_id)?;
+        Ok(RepeatedDocAttachment { items })
+    }
+
+    pub fn delete_attachment(&self, attachment_id: &str) -> Result<(), DocError> {
+        self.attachments.delete(attachment_id)
+    }
+
+    pub fn attachment_usage(&self) -> Result<AttachmentUsage, DocError> { self.attachments.usage() }
+
+    // Replaces the quota of the config, e.g. when the plan of the user changes.
+    // The attachments that are over the new quota are kept.
+    pub fn set_attachment_quota(&self, quota: i64) { self.attachments.set_quota(quota) }
+
+    // The asset is uploaded from where the last upload stopped, and downloaded
+    // the same way.
+    pub async fn upload_asset(&self, asset_id: &str) -> Result<AssetTransfer, DocError> {
+        self.transfers.upload(parse_asset_id(asset_id)).await
+    }
+
+    pub async fn download_asset(&self, asset_id: &str) -> Result<AssetTransfer, DocError> {
+        self.transfers.download(parse_asset_id(asset_id)).await
+    }
+
+    pub fn asset_transfers(&self) -> Result<RepeatedAssetTransfer, DocError> {
+        let items = self.transfers.transfers()?;
+        Ok(RepeatedAssetTransfer { items })
+    }
+
+    // Called when the network is back, the interrupted transfers go on.
+    pub async fn resume_asset_transfers(&self) { self.transfers.resume_all().await }
+
     pub async fn apply_doc_delta(&self, params: DocDelta) -> Result<DocDelta, DocError> {
         // workaround: compare the rust's delta with flutter's delta. Will be removed
         // very soon
@@ -73,4 +578,80 @@ impl FlowyDocument {
             .await?;
         Ok(doc)
     }
+
+    // Applies the delta that another device made, see the sync of the workspace.
+    pub async fn apply_remote_delta(&self, params: DocDelta) -> Result<DocDelta, DocError> {
+        self.doc_ctrl.apply_remote_delta(params, self.user.db_pool()?).await
+    }
+
+    // The revisions of the opened document that the server didn't acknowledge yet.
+    pub async fn pending_revisions(&self, doc_id: &str) -> usize { self.doc_ctrl.pending_revisions(doc_id).await }
+}
+
+pub fn create(document: Arc<FlowyDocument>) -> Module {
+    Module::new()
+        .name("Flowy-Document")
+        .data(document)
+        .event(DocumentEvent::OpenDocument, open_document_handler)
+        .event(DocumentEvent::CloseDocument, close_document_handler)
+        .event_with_permission(DocumentEvent::ApplyChange, apply_change_handler, EventPermission::Write)
+        .event(DocumentEvent::ReadDocument, read_document_handler)
+        .event(DocumentEvent::ForceSave, force_save_handler)
+        .event(DocumentEvent::ListVersions, list_versions_handler)
+        .event(DocumentEvent::ReadVersion, read_version_handler)
+        .event_with_permission(
+            DocumentEvent::RestoreVersion,
+            restore_version_handler,
+            EventPermission::Write,
+        )
+        .event(DocumentEvent::SearchDocuments, search_documents_handler)
+        .event(DocumentEvent::CheckText, check_text_handler)
+        .event(DocumentEvent::DiffDocuments, diff_documents_handler)
+        .event(DocumentEvent::DiffVersions, diff_versions_handler)
+        .event_with_permission(DocumentEvent::AttachAsset, attach_asset_handler, EventPermission::Write)
+        .event(DocumentEvent::ReadAsset, read_asset_handler)
+        .event(DocumentEvent::ListAssets, list_assets_handler)
+        .event(DocumentEvent::UploadAsset, upload_asset_handler)
+        .event(DocumentEvent::DownloadAsset, download_asset_handler)
+        .event(DocumentEvent::ReadAssetTransfers, read_asset_transfers_handler)
+        .event_with_permission(
+            DocumentEvent::ImportAttachment,
+            import_attachment_handler,
+            EventPermission::Write,
+        )
+        .event(DocumentEvent::ListAttachments, list_attachments_handler)
+        .event_with_permission(
+            DocumentEvent::DeleteAttachment,
+            delete_attachment_handler,
+            EventPermission::Write,
+        )
+        .event(DocumentEvent::ReadAttachmentUsage, read_attachment_usage_handler)
+        .event(DocumentEvent::GetDocumentStats, get_document_stats_handler)
+        .event(DocumentEvent::UpdatePresence, update_presence_handler)
+        .event(DocumentEvent::ClearPresence, clear_presence_handler)
+        .event(DocumentEvent::ReadCollaborators, read_collaborators_handler)
+        .event(DocumentEvent::ReadBlocks, read_blocks_handler)
+        .event_with_permission(
+            DocumentEvent::ApplyBlockOps,
+            apply_block_ops_handler,
+            EventPermission::Write,
+        )
+        .event(DocumentEvent::CreateComment, create_comment_handler)
+        .event(DocumentEvent::ResolveComment, resolve_comment_handler)
+        .event(DocumentEvent::DeleteComment, delete_comment_handler)
+        .event(DocumentEvent::ReadComments, read_comments_handler)
+        .event_with_permission(
+            DocumentEvent::EncryptDocument,
+            encrypt_document_handler,
+            EventPermission::Write,
+        )
+        .event_with_permission(
+            DocumentEvent::DecryptDocument,
+            decrypt_document_handler,
+            EventPermission::Write,
+        )
+        .event(DocumentEvent::LockDocument, lock_document_handler)
+        .event(DocumentEvent::UnlockDocument, unlock_document_handler)
+        .event_with_permission(DocumentEvent::Undo, undo_handler, EventPermission::Write)
+        .event_with_permission(DocumentEvent::Redo, redo_handler, EventPermission::Write)
 }
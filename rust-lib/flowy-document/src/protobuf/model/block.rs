@@ -0,0 +1,1697 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `block.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DocBlock {
+    // message fields
+    pub index: i64,
+    pub block_type: BlockType,
+    pub data: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocBlock {
+    fn default() -> &'a DocBlock {
+        <DocBlock as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DocBlock {
+    pub fn new() -> DocBlock {
+        ::std::default::Default::default()
+    }
+
+    // int64 index = 1;
+
+
+    pub fn get_index(&self) -> i64 {
+        self.index
+    }
+    pub fn clear_index(&mut self) {
+        self.index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: i64) {
+        self.index = v;
+    }
+
+    // .BlockType block_type = 2;
+
+
+    pub fn get_block_type(&self) -> BlockType {
+        self.block_type
+    }
+    pub fn clear_block_type(&mut self) {
+        self.block_type = BlockType::Paragraph;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_block_type(&mut self, v: BlockType) {
+        self.block_type = v;
+    }
+
+    // string data = 3;
+
+
+    pub fn get_data(&self) -> &str {
+        &self.data
+    }
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::string::String) {
+        self.data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::string::String {
+        &mut self.data
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.data, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for DocBlock {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.index = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.block_type, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.data)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.index != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.block_type != BlockType::Paragraph {
+            my_size += ::protobuf::rt::enum_size(2, self.block_type);
+        }
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.data);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.index != 0 {
+            os.write_int64(1, self.index)?;
+        }
+        if self.block_type != BlockType::Paragraph {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.block_type))?;
+        }
+        if !self.data.is_empty() {
+            os.write_string(3, &self.data)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocBlock {
+        DocBlock::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "index",
+                |m: &DocBlock| { &m.index },
+                |m: &mut DocBlock| { &mut m.index },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<BlockType>>(
+                "block_type",
+                |m: &DocBlock| { &m.block_type },
+                |m: &mut DocBlock| { &mut m.block_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "data",
+                |m: &DocBlock| { &m.data },
+                |m: &mut DocBlock| { &mut m.data },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocBlock>(
+                "DocBlock",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocBlock {
+        static instance: ::protobuf::rt::LazyV2<DocBlock> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocBlock::new)
+    }
+}
+
+impl ::protobuf::Clear for DocBlock {
+    fn clear(&mut self) {
+        self.index = 0;
+        self.block_type = BlockType::Paragraph;
+        self.data.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocBlock {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocBlock {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedDocBlock {
+    // message fields
+    pub items: ::protobuf::RepeatedField<DocBlock>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedDocBlock {
+    fn default() -> &'a RepeatedDocBlock {
+        <RepeatedDocBlock as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedDocBlock {
+    pub fn new() -> RepeatedDocBlock {
+        ::std::default::Default::default()
+    }
+
+    // repeated .DocBlock items = 1;
+
+
+    pub fn get_items(&self) -> &[DocBlock] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<DocBlock>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<DocBlock> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<DocBlock> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedDocBlock {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedDocBlock {
+        RepeatedDocBlock::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DocBlock>>(
+                "items",
+                |m: &RepeatedDocBlock| { &m.items },
+                |m: &mut RepeatedDocBlock| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedDocBlock>(
+                "RepeatedDocBlock",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedDocBlock {
+        static instance: ::protobuf::rt::LazyV2<RepeatedDocBlock> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedDocBlock::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedDocBlock {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedDocBlock {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedDocBlock {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BlockOp {
+    // message fields
+    pub ty: BlockOpType,
+    pub index: i64,
+    pub to: i64,
+    pub data: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BlockOp {
+    fn default() -> &'a BlockOp {
+        <BlockOp as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BlockOp {
+    pub fn new() -> BlockOp {
+        ::std::default::Default::default()
+    }
+
+    // .BlockOpType ty = 1;
+
+
+    pub fn get_ty(&self) -> BlockOpType {
+        self.ty
+    }
+    pub fn clear_ty(&mut self) {
+        self.ty = BlockOpType::Insert;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ty(&mut self, v: BlockOpType) {
+        self.ty = v;
+    }
+
+    // int64 index = 2;
+
+
+    pub fn get_index(&self) -> i64 {
+        self.index
+    }
+    pub fn clear_index(&mut self) {
+        self.index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: i64) {
+        self.index = v;
+    }
+
+    // int64 to = 3;
+
+
+    pub fn get_to(&self) -> i64 {
+        self.to
+    }
+    pub fn clear_to(&mut self) {
+        self.to = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_to(&mut self, v: i64) {
+        self.to = v;
+    }
+
+    // string data = 4;
+
+
+    pub fn get_data(&self) -> &str {
+        &self.data
+    }
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::string::String) {
+        self.data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::string::String {
+        &mut self.data
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.data, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for BlockOp {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.ty, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.index = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.to = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.data)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.ty != BlockOpType::Insert {
+            my_size += ::protobuf::rt::enum_size(1, self.ty);
+        }
+        if self.index != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.to != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.to, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.data);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.ty != BlockOpType::Insert {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.ty))?;
+        }
+        if self.index != 0 {
+            os.write_int64(2, self.index)?;
+        }
+        if self.to != 0 {
+            os.write_int64(3, self.to)?;
+        }
+        if !self.data.is_empty() {
+            os.write_string(4, &self.data)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BlockOp {
+        BlockOp::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<BlockOpType>>(
+                "ty",
+                |m: &BlockOp| { &m.ty },
+                |m: &mut BlockOp| { &mut m.ty },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "index",
+                |m: &BlockOp| { &m.index },
+                |m: &mut BlockOp| { &mut m.index },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "to",
+                |m: &BlockOp| { &m.to },
+                |m: &mut BlockOp| { &mut m.to },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "data",
+                |m: &BlockOp| { &m.data },
+                |m: &mut BlockOp| { &mut m.data },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BlockOp>(
+                "BlockOp",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BlockOp {
+        static instance: ::protobuf::rt::LazyV2<BlockOp> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BlockOp::new)
+    }
+}
+
+impl ::protobuf::Clear for BlockOp {
+    fn clear(&mut self) {
+        self.ty = BlockOpType::Insert;
+        self.index = 0;
+        self.to = 0;
+        self.data.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BlockOp {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BlockOp {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ApplyBlockOpsRequest {
+    // message fields
+    pub doc_id: ::std::string::String,
+    pub ops: ::protobuf::RepeatedField<BlockOp>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ApplyBlockOpsRequest {
+    fn default() -> &'a ApplyBlockOpsRequest {
+        <ApplyBlockOpsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ApplyBlockOpsRequest {
+    pub fn new() -> ApplyBlockOpsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // repeated .BlockOp ops = 2;
+
+
+    pub fn get_ops(&self) -> &[BlockOp] {
+        &self.ops
+    }
+    pub fn clear_ops(&mut self) {
+        self.ops.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ops(&mut self, v: ::protobuf::RepeatedField<BlockOp>) {
+        self.ops = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ops(&mut self) -> &mut ::protobuf::RepeatedField<BlockOp> {
+        &mut self.ops
+    }
+
+    // Take field
+    pub fn take_ops(&mut self) -> ::protobuf::RepeatedField<BlockOp> {
+        ::std::mem::replace(&mut self.ops, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for ApplyBlockOpsRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.ops {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.ops)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        for value in &self.ops {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        for v in &self.ops {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ApplyBlockOpsRequest {
+        ApplyBlockOpsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &ApplyBlockOpsRequest| { &m.doc_id },
+                |m: &mut ApplyBlockOpsRequest| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BlockOp>>(
+                "ops",
+                |m: &ApplyBlockOpsRequest| { &m.ops },
+                |m: &mut ApplyBlockOpsRequest| { &mut m.ops },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ApplyBlockOpsRequest>(
+                "ApplyBlockOpsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ApplyBlockOpsRequest {
+        static instance: ::protobuf::rt::LazyV2<ApplyBlockOpsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ApplyBlockOpsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ApplyBlockOpsRequest {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.ops.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ApplyBlockOpsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ApplyBlockOpsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ReadDocumentRequest {
+    // message fields
+    pub doc_id: ::std::string::String,
+    pub cursor: ::std::string::String,
+    pub limit: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ReadDocumentRequest {
+    fn default() -> &'a ReadDocumentRequest {
+        <ReadDocumentRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReadDocumentRequest {
+    pub fn new() -> ReadDocumentRequest {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // string cursor = 2;
+
+
+    pub fn get_cursor(&self) -> &str {
+        &self.cursor
+    }
+    pub fn clear_cursor(&mut self) {
+        self.cursor.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cursor(&mut self, v: ::std::string::String) {
+        self.cursor = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_cursor(&mut self) -> &mut ::std::string::String {
+        &mut self.cursor
+    }
+
+    // Take field
+    pub fn take_cursor(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.cursor, ::std::string::String::new())
+    }
+
+    // int64 limit = 3;
+
+
+    pub fn get_limit(&self) -> i64 {
+        self.limit
+    }
+    pub fn clear_limit(&mut self) {
+        self.limit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: i64) {
+        self.limit = v;
+    }
+}
+
+impl ::protobuf::Message for ReadDocumentRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.cursor)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.limit = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        if !self.cursor.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.cursor);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.limit, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        if !self.cursor.is_empty() {
+            os.write_string(2, &self.cursor)?;
+        }
+        if self.limit != 0 {
+            os.write_int64(3, self.limit)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ReadDocumentRequest {
+        ReadDocumentRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &ReadDocumentRequest| { &m.doc_id },
+                |m: &mut ReadDocumentRequest| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "cursor",
+                |m: &ReadDocumentRequest| { &m.cursor },
+                |m: &mut ReadDocumentRequest| { &mut m.cursor },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "limit",
+                |m: &ReadDocumentRequest| { &m.limit },
+                |m: &mut ReadDocumentRequest| { &mut m.limit },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ReadDocumentRequest>(
+                "ReadDocumentRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ReadDocumentRequest {
+        static instance: ::protobuf::rt::LazyV2<ReadDocumentRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ReadDocumentRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ReadDocumentRequest {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.cursor.clear();
+        self.limit = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ReadDocumentRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReadDocumentRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DocumentPage {
+    // message fields
+    pub doc_id: ::std::string::String,
+    pub data: ::std::string::String,
+    pub start: i64,
+    pub end: i64,
+    pub offset: i64,
+    pub block_count: i64,
+    pub rev_id: i64,
+    pub next_cursor: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocumentPage {
+    fn default() -> &'a DocumentPage {
+        <DocumentPage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DocumentPage {
+    pub fn new() -> DocumentPage {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // string data = 2;
+
+
+    pub fn get_data(&self) -> &str {
+        &self.data
+    }
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::string::String) {
+        self.data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::string::String {
+        &mut self.data
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.data, ::std::string::String::new())
+    }
+
+    // int64 start = 3;
+
+
+    pub fn get_start(&self) -> i64 {
+        self.start
+    }
+    pub fn clear_start(&mut self) {
+        self.start = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: i64) {
+        self.start = v;
+    }
+
+    // int64 end = 4;
+
+
+    pub fn get_end(&self) -> i64 {
+        self.end
+    }
+    pub fn clear_end(&mut self) {
+        self.end = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end(&mut self, v: i64) {
+        self.end = v;
+    }
+
+    // int64 offset = 5;
+
+
+    pub fn get_offset(&self) -> i64 {
+        self.offset
+    }
+    pub fn clear_offset(&mut self) {
+        self.offset = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_offset(&mut self, v: i64) {
+        self.offset = v;
+    }
+
+    // int64 block_count = 6;
+
+
+    pub fn get_block_count(&self) -> i64 {
+        self.block_count
+    }
+    pub fn clear_block_count(&mut self) {
+        self.block_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_block_count(&mut self, v: i64) {
+        self.block_count = v;
+    }
+
+    // int64 rev_id = 7;
+
+
+    pub fn get_rev_id(&self) -> i64 {
+        self.rev_id
+    }
+    pub fn clear_rev_id(&mut self) {
+        self.rev_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rev_id(&mut self, v: i64) {
+        self.rev_id = v;
+    }
+
+    // string next_cursor = 8;
+
+
+    pub fn get_next_cursor(&self) -> &str {
+        &self.next_cursor
+    }
+    pub fn clear_next_cursor(&mut self) {
+        self.next_cursor.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_next_cursor(&mut self, v: ::std::string::String) {
+        self.next_cursor = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_next_cursor(&mut self) -> &mut ::std::string::String {
+        &mut self.next_cursor
+    }
+
+    // Take field
+    pub fn take_next_cursor(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.next_cursor, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for DocumentPage {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.data)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.start = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.end = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.offset = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.block_count = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.rev_id = tmp;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.next_cursor)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.data);
+        }
+        if self.start != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.start, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.end != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.end, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.offset, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.block_count != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.block_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.rev_id != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.rev_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.next_cursor.is_empty() {
+            my_size += ::protobuf::rt::string_size(8, &self.next_cursor);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        if !self.data.is_empty() {
+            os.write_string(2, &self.data)?;
+        }
+        if self.start != 0 {
+            os.write_int64(3, self.start)?;
+        }
+        if self.end != 0 {
+            os.write_int64(4, self.end)?;
+        }
+        if self.offset != 0 {
+            os.write_int64(5, self.offset)?;
+        }
+        if self.block_count != 0 {
+            os.write_int64(6, self.block_count)?;
+        }
+        if self.rev_id != 0 {
+            os.write_int64(7, self.rev_id)?;
+        }
+        if !self.next_cursor.is_empty() {
+            os.write_string(8, &self.next_cursor)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocumentPage {
+        DocumentPage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &DocumentPage| { &m.doc_id },
+                |m: &mut DocumentPage| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "data",
+                |m: &DocumentPage| { &m.data },
+                |m: &mut DocumentPage| { &mut m.data },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "start",
+                |m: &DocumentPage| { &m.start },
+                |m: &mut DocumentPage| { &mut m.start },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "end",
+                |m: &DocumentPage| { &m.end },
+                |m: &mut DocumentPage| { &mut m.end },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "offset",
+                |m: &DocumentPage| { &m.offset },
+                |m: &mut DocumentPage| { &mut m.offset },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "block_count",
+                |m: &DocumentPage| { &m.block_count },
+                |m: &mut DocumentPage| { &mut m.block_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "rev_id",
+                |m: &DocumentPage| { &m.rev_id },
+                |m: &mut DocumentPage| { &mut m.rev_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "next_cursor",
+                |m: &DocumentPage| { &m.next_cursor },
+                |m: &mut DocumentPage| { &mut m.next_cursor },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocumentPage>(
+                "DocumentPage",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocumentPage {
+        static instance: ::protobuf::rt::LazyV2<DocumentPage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocumentPage::new)
+    }
+}
+
+impl ::protobuf::Clear for DocumentPage {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.data.clear();
+        self.start = 0;
+        self.end = 0;
+        self.offset = 0;
+        self.block_count = 0;
+        self.rev_id = 0;
+        self.next_cursor.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocumentPage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocumentPage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum BlockType {
+    Paragraph = 0,
+    Heading = 1,
+    Todo = 2,
+    Code = 3,
+    Embed = 4,
+    List = 5,
+    Quote = 6,
+}
+
+impl ::protobuf::ProtobufEnum for BlockType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<BlockType> {
+        match value {
+            0 => ::std::option::Option::Some(BlockType::Paragraph),
+            1 => ::std::option::Option::Some(BlockType::Heading),
+            2 => ::std::option::Option::Some(BlockType::Todo),
+            3 => ::std::option::Option::Some(BlockType::Code),
+            4 => ::std::option::Option::Some(BlockType::Embed),
+            5 => ::std::option::Option::Some(BlockType::List),
+            6 => ::std::option::Option::Some(BlockType::Quote),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [BlockType] = &[
+            BlockType::Paragraph,
+            BlockType::Heading,
+            BlockType::Todo,
+            BlockType::Code,
+            BlockType::Embed,
+            BlockType::List,
+            BlockType::Quote,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<BlockType>("BlockType", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for BlockType {
+}
+
+impl ::std::default::Default for BlockType {
+    fn default() -> Self {
+        BlockType::Paragraph
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BlockType {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum BlockOpType {
+    Insert = 0,
+    Delete = 1,
+    Move = 2,
+    Update = 3,
+}
+
+impl ::protobuf::ProtobufEnum for BlockOpType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<BlockOpType> {
+        match value {
+            0 => ::std::option::Option::Some(BlockOpType::Insert),
+            1 => ::std::option::Option::Some(BlockOpType::Delete),
+            2 => ::std::option::Option::Some(BlockOpType::Move),
+            3 => ::std::option::Option::Some(BlockOpType::Update),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [BlockOpType] = &[
+            BlockOpType::Insert,
+            BlockOpType::Delete,
+            BlockOpType::Move,
+            BlockOpType::Update,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<BlockOpType>("BlockOpType", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for BlockOpType {
+}
+
+impl ::std::default::Default for BlockOpType {
+    fn default() -> Self {
+        BlockOpType::Insert
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BlockOpType {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0bblock.proto\"g\n\x08DocBlock\x12\x16\n\x05index\x18\x01\x20\x01(\
+    \x03R\x05indexB\0\x12+\n\nblock_type\x18\x02\x20\x01(\x0e2\n.BlockTypeR\
+    \tblockTypeB\0\x12\x14\n\x04data\x18\x03\x20\x01(\tR\x04dataB\0:\0\"7\n\
+    \x10RepeatedDocBlock\x12!\n\x05items\x18\x01\x20\x03(\x0b2\t.DocBlockR\
+    \x05itemsB\0:\0\"k\n\x07BlockOp\x12\x1e\n\x02ty\x18\x01\x20\x01(\x0e2\
+    \x0c.BlockOpTypeR\x02tyB\0\x12\x16\n\x05index\x18\x02\x20\x01(\x03R\x05i\
+    ndexB\0\x12\x10\n\x02to\x18\x03\x20\x01(\x03R\x02toB\0\x12\x14\n\x04data\
+    \x18\x04\x20\x01(\tR\x04dataB\0:\0\"O\n\x14ApplyBlockOpsRequest\x12\x17\
+    \n\x06doc_id\x18\x01\x20\x01(\tR\x05docIdB\0\x12\x1c\n\x03ops\x18\x02\
+    \x20\x03(\x0b2\x08.BlockOpR\x03opsB\0:\0\"b\n\x13ReadDocumentRequest\x12\
+    \x17\n\x06doc_id\x18\x01\x20\x01(\tR\x05docIdB\0\x12\x18\n\x06cursor\x18\
+    \x02\x20\x01(\tR\x06cursorB\0\x12\x16\n\x05limit\x18\x03\x20\x01(\x03R\
+    \x05limitB\0:\0\"\xe4\x01\n\x0cDocumentPage\x12\x17\n\x06doc_id\x18\x01\
+    \x20\x01(\tR\x05docIdB\0\x12\x14\n\x04data\x18\x02\x20\x01(\tR\x04dataB\
+    \0\x12\x16\n\x05start\x18\x03\x20\x01(\x03R\x05startB\0\x12\x12\n\x03end\
+    \x18\x04\x20\x01(\x03R\x03endB\0\x12\x18\n\x06offset\x18\x05\x20\x01(\
+    \x03R\x06offsetB\0\x12!\n\x0bblock_count\x18\x06\x20\x01(\x03R\nblockCou\
+    ntB\0\x12\x17\n\x06rev_id\x18\x07\x20\x01(\x03R\x05revIdB\0\x12!\n\x0bne\
+    xt_cursor\x18\x08\x20\x01(\tR\nnextCursorB\0:\0*]\n\tBlockType\x12\r\n\t\
+    Paragraph\x10\0\x12\x0b\n\x07Heading\x10\x01\x12\x08\n\x04Todo\x10\x02\
+    \x12\x08\n\x04Code\x10\x03\x12\t\n\x05Embed\x10\x04\x12\x08\n\x04List\
+    \x10\x05\x12\t\n\x05Quote\x10\x06\x1a\0*=\n\x0bBlockOpType\x12\n\n\x06In\
+    sert\x10\0\x12\n\n\x06Delete\x10\x01\x12\x08\n\x04Move\x10\x02\x12\n\n\
+    \x06Update\x10\x03\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,842 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `check.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CheckTextRequest {
+    // message fields
+    pub doc_id: ::std::string::String,
+    pub start: i64,
+    pub end: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CheckTextRequest {
+    fn default() -> &'a CheckTextRequest {
+        <CheckTextRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CheckTextRequest {
+    pub fn new() -> CheckTextRequest {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // int64 start = 2;
+
+
+    pub fn get_start(&self) -> i64 {
+        self.start
+    }
+    pub fn clear_start(&mut self) {
+        self.start = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: i64) {
+        self.start = v;
+    }
+
+    // int64 end = 3;
+
+
+    pub fn get_end(&self) -> i64 {
+        self.end
+    }
+    pub fn clear_end(&mut self) {
+        self.end = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end(&mut self, v: i64) {
+        self.end = v;
+    }
+}
+
+impl ::protobuf::Message for CheckTextRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.start = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.end = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        if self.start != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.start, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.end != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.end, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        if self.start != 0 {
+            os.write_int64(2, self.start)?;
+        }
+        if self.end != 0 {
+            os.write_int64(3, self.end)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CheckTextRequest {
+        CheckTextRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &CheckTextRequest| { &m.doc_id },
+                |m: &mut CheckTextRequest| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "start",
+                |m: &CheckTextRequest| { &m.start },
+                |m: &mut CheckTextRequest| { &mut m.start },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "end",
+                |m: &CheckTextRequest| { &m.end },
+                |m: &mut CheckTextRequest| { &mut m.end },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CheckTextRequest>(
+                "CheckTextRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CheckTextRequest {
+        static instance: ::protobuf::rt::LazyV2<CheckTextRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CheckTextRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CheckTextRequest {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.start = 0;
+        self.end = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CheckTextRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CheckTextRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TextDiagnostic {
+    // message fields
+    pub start: i64,
+    pub end: i64,
+    pub kind: DiagnosticKind,
+    pub message: ::std::string::String,
+    pub suggestions: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TextDiagnostic {
+    fn default() -> &'a TextDiagnostic {
+        <TextDiagnostic as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TextDiagnostic {
+    pub fn new() -> TextDiagnostic {
+        ::std::default::Default::default()
+    }
+
+    // int64 start = 1;
+
+
+    pub fn get_start(&self) -> i64 {
+        self.start
+    }
+    pub fn clear_start(&mut self) {
+        self.start = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: i64) {
+        self.start = v;
+    }
+
+    // int64 end = 2;
+
+
+    pub fn get_end(&self) -> i64 {
+        self.end
+    }
+    pub fn clear_end(&mut self) {
+        self.end = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end(&mut self, v: i64) {
+        self.end = v;
+    }
+
+    // .DiagnosticKind kind = 3;
+
+
+    pub fn get_kind(&self) -> DiagnosticKind {
+        self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind = DiagnosticKind::Spelling;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: DiagnosticKind) {
+        self.kind = v;
+    }
+
+    // string message = 4;
+
+
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+    pub fn clear_message(&mut self) {
+        self.message.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message(&mut self, v: ::std::string::String) {
+        self.message = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message(&mut self) -> &mut ::std::string::String {
+        &mut self.message
+    }
+
+    // Take field
+    pub fn take_message(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.message, ::std::string::String::new())
+    }
+
+    // repeated string suggestions = 5;
+
+
+    pub fn get_suggestions(&self) -> &[::std::string::String] {
+        &self.suggestions
+    }
+    pub fn clear_suggestions(&mut self) {
+        self.suggestions.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_suggestions(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.suggestions = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_suggestions(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.suggestions
+    }
+
+    // Take field
+    pub fn take_suggestions(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.suggestions, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for TextDiagnostic {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.start = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.end = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.kind, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.message)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.suggestions)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.start != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.start, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.end != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.end, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.kind != DiagnosticKind::Spelling {
+            my_size += ::protobuf::rt::enum_size(3, self.kind);
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.message);
+        }
+        for value in &self.suggestions {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.start != 0 {
+            os.write_int64(1, self.start)?;
+        }
+        if self.end != 0 {
+            os.write_int64(2, self.end)?;
+        }
+        if self.kind != DiagnosticKind::Spelling {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.kind))?;
+        }
+        if !self.message.is_empty() {
+            os.write_string(4, &self.message)?;
+        }
+        for v in &self.suggestions {
+            os.write_string(5, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TextDiagnostic {
+        TextDiagnostic::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "start",
+                |m: &TextDiagnostic| { &m.start },
+                |m: &mut TextDiagnostic| { &mut m.start },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "end",
+                |m: &TextDiagnostic| { &m.end },
+                |m: &mut TextDiagnostic| { &mut m.end },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<DiagnosticKind>>(
+                "kind",
+                |m: &TextDiagnostic| { &m.kind },
+                |m: &mut TextDiagnostic| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "message",
+                |m: &TextDiagnostic| { &m.message },
+                |m: &mut TextDiagnostic| { &mut m.message },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "suggestions",
+                |m: &TextDiagnostic| { &m.suggestions },
+                |m: &mut TextDiagnostic| { &mut m.suggestions },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<TextDiagnostic>(
+                "TextDiagnostic",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static TextDiagnostic {
+        static instance: ::protobuf::rt::LazyV2<TextDiagnostic> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(TextDiagnostic::new)
+    }
+}
+
+impl ::protobuf::Clear for TextDiagnostic {
+    fn clear(&mut self) {
+        self.start = 0;
+        self.end = 0;
+        self.kind = DiagnosticKind::Spelling;
+        self.message.clear();
+        self.suggestions.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TextDiagnostic {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TextDiagnostic {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedTextDiagnostic {
+    // message fields
+    pub doc_id: ::std::string::String,
+    pub items: ::protobuf::RepeatedField<TextDiagnostic>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedTextDiagnostic {
+    fn default() -> &'a RepeatedTextDiagnostic {
+        <RepeatedTextDiagnostic as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedTextDiagnostic {
+    pub fn new() -> RepeatedTextDiagnostic {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // repeated .TextDiagnostic items = 2;
+
+
+    pub fn get_items(&self) -> &[TextDiagnostic] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<TextDiagnostic>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<TextDiagnostic> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<TextDiagnostic> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedTextDiagnostic {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        for v in &self.items {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedTextDiagnostic {
+        RepeatedTextDiagnostic::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &RepeatedTextDiagnostic| { &m.doc_id },
+                |m: &mut RepeatedTextDiagnostic| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TextDiagnostic>>(
+                "items",
+                |m: &RepeatedTextDiagnostic| { &m.items },
+                |m: &mut RepeatedTextDiagnostic| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedTextDiagnostic>(
+                "RepeatedTextDiagnostic",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedTextDiagnostic {
+        static instance: ::protobuf::rt::LazyV2<RepeatedTextDiagnostic> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedTextDiagnostic::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedTextDiagnostic {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedTextDiagnostic {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedTextDiagnostic {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum DiagnosticKind {
+    Spelling = 0,
+    Grammar = 1,
+    Style = 2,
+}
+
+impl ::protobuf::ProtobufEnum for DiagnosticKind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<DiagnosticKind> {
+        match value {
+            0 => ::std::option::Option::Some(DiagnosticKind::Spelling),
+            1 => ::std::option::Option::Some(DiagnosticKind::Grammar),
+            2 => ::std::option::Option::Some(DiagnosticKind::Style),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [DiagnosticKind] = &[
+            DiagnosticKind::Spelling,
+            DiagnosticKind::Grammar,
+            DiagnosticKind::Style,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<DiagnosticKind>("DiagnosticKind", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for DiagnosticKind {
+}
+
+impl ::std::default::Default for DiagnosticKind {
+    fn default() -> Self {
+        DiagnosticKind::Spelling
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DiagnosticKind {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0bcheck.proto\"Y\n\x10CheckTextRequest\x12\x17\n\x06doc_id\x18\x01\
+    \x20\x01(\tR\x05docIdB\0\x12\x16\n\x05start\x18\x02\x20\x01(\x03R\x05sta\
+    rtB\0\x12\x12\n\x03end\x18\x03\x20\x01(\x03R\x03endB\0:\0\"\xa5\x01\n\
+    \x0eTextDiagnostic\x12\x16\n\x05start\x18\x01\x20\x01(\x03R\x05startB\0\
+    \x12\x12\n\x03end\x18\x02\x20\x01(\x03R\x03endB\0\x12%\n\x04kind\x18\x03\
+    \x20\x01(\x0e2\x0f.DiagnosticKindR\x04kindB\0\x12\x1a\n\x07message\x18\
+    \x04\x20\x01(\tR\x07messageB\0\x12\"\n\x0bsuggestions\x18\x05\x20\x03(\t\
+    R\x0bsuggestionsB\0:\0\"\\\n\x16RepeatedTextDiagnostic\x12\x17\n\x06doc_\
+    id\x18\x01\x20\x01(\tR\x05docIdB\0\x12'\n\x05items\x18\x02\x20\x03(\x0b2\
+    \x0f.TextDiagnosticR\x05itemsB\0:\0*8\n\x0eDiagnosticKind\x12\x0c\n\x08S\
+    pelling\x10\0\x12\x0b\n\x07Grammar\x10\x01\x12\t\n\x05Style\x10\x02\x1a\
+    \0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,1406 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `diff.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DiffDocumentsRequest {
+    // message fields
+    pub old_data: ::std::string::String,
+    pub new_data: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DiffDocumentsRequest {
+    fn default() -> &'a DiffDocumentsRequest {
+        <DiffDocumentsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DiffDocumentsRequest {
+    pub fn new() -> DiffDocumentsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string old_data = 1;
+
+
+    pub fn get_old_data(&self) -> &str {
+        &self.old_data
+    }
+    pub fn clear_old_data(&mut self) {
+        self.old_data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_old_data(&mut self, v: ::std::string::String) {
+        self.old_data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_old_data(&mut self) -> &mut ::std::string::String {
+        &mut self.old_data
+    }
+
+    // Take field
+    pub fn take_old_data(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.old_data, ::std::string::String::new())
+    }
+
+    // string new_data = 2;
+
+
+    pub fn get_new_data(&self) -> &str {
+        &self.new_data
+    }
+    pub fn clear_new_data(&mut self) {
+        self.new_data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_new_data(&mut self, v: ::std::string::String) {
+        self.new_data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_new_data(&mut self) -> &mut ::std::string::String {
+        &mut self.new_data
+    }
+
+    // Take field
+    pub fn take_new_data(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.new_data, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for DiffDocumentsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.old_data)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.new_data)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.old_data.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.old_data);
+        }
+        if !self.new_data.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.new_data);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.old_data.is_empty() {
+            os.write_string(1, &self.old_data)?;
+        }
+        if !self.new_data.is_empty() {
+            os.write_string(2, &self.new_data)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DiffDocumentsRequest {
+        DiffDocumentsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "old_data",
+                |m: &DiffDocumentsRequest| { &m.old_data },
+                |m: &mut DiffDocumentsRequest| { &mut m.old_data },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "new_data",
+                |m: &DiffDocumentsRequest| { &m.new_data },
+                |m: &mut DiffDocumentsRequest| { &mut m.new_data },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DiffDocumentsRequest>(
+                "DiffDocumentsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DiffDocumentsRequest {
+        static instance: ::protobuf::rt::LazyV2<DiffDocumentsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DiffDocumentsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for DiffDocumentsRequest {
+    fn clear(&mut self) {
+        self.old_data.clear();
+        self.new_data.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DiffDocumentsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DiffDocumentsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DiffVersionsRequest {
+    // message fields
+    pub doc_id: ::std::string::String,
+    pub old_rev_id: i64,
+    pub new_rev_id: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DiffVersionsRequest {
+    fn default() -> &'a DiffVersionsRequest {
+        <DiffVersionsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DiffVersionsRequest {
+    pub fn new() -> DiffVersionsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // int64 old_rev_id = 2;
+
+
+    pub fn get_old_rev_id(&self) -> i64 {
+        self.old_rev_id
+    }
+    pub fn clear_old_rev_id(&mut self) {
+        self.old_rev_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_old_rev_id(&mut self, v: i64) {
+        self.old_rev_id = v;
+    }
+
+    // int64 new_rev_id = 3;
+
+
+    pub fn get_new_rev_id(&self) -> i64 {
+        self.new_rev_id
+    }
+    pub fn clear_new_rev_id(&mut self) {
+        self.new_rev_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_new_rev_id(&mut self, v: i64) {
+        self.new_rev_id = v;
+    }
+}
+
+impl ::protobuf::Message for DiffVersionsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.old_rev_id = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.new_rev_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        if self.old_rev_id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.old_rev_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.new_rev_id != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.new_rev_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        if self.old_rev_id != 0 {
+            os.write_int64(2, self.old_rev_id)?;
+        }
+        if self.new_rev_id != 0 {
+            os.write_int64(3, self.new_rev_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DiffVersionsRequest {
+        DiffVersionsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &DiffVersionsRequest| { &m.doc_id },
+                |m: &mut DiffVersionsRequest| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "old_rev_id",
+                |m: &DiffVersionsRequest| { &m.old_rev_id },
+                |m: &mut DiffVersionsRequest| { &mut m.old_rev_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "new_rev_id",
+                |m: &DiffVersionsRequest| { &m.new_rev_id },
+                |m: &mut DiffVersionsRequest| { &mut m.new_rev_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DiffVersionsRequest>(
+                "DiffVersionsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DiffVersionsRequest {
+        static instance: ::protobuf::rt::LazyV2<DiffVersionsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DiffVersionsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for DiffVersionsRequest {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.old_rev_id = 0;
+        self.new_rev_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DiffVersionsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DiffVersionsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AttributeChange {
+    // message fields
+    pub key: ::std::string::String,
+    pub old_value: ::std::string::String,
+    pub new_value: ::std::string::String,
+    pub start: i64,
+    pub end: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AttributeChange {
+    fn default() -> &'a AttributeChange {
+        <AttributeChange as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AttributeChange {
+    pub fn new() -> AttributeChange {
+        ::std::default::Default::default()
+    }
+
+    // string key = 1;
+
+
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+
+    // string old_value = 2;
+
+
+    pub fn get_old_value(&self) -> &str {
+        &self.old_value
+    }
+    pub fn clear_old_value(&mut self) {
+        self.old_value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_old_value(&mut self, v: ::std::string::String) {
+        self.old_value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_old_value(&mut self) -> &mut ::std::string::String {
+        &mut self.old_value
+    }
+
+    // Take field
+    pub fn take_old_value(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.old_value, ::std::string::String::new())
+    }
+
+    // string new_value = 3;
+
+
+    pub fn get_new_value(&self) -> &str {
+        &self.new_value
+    }
+    pub fn clear_new_value(&mut self) {
+        self.new_value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_new_value(&mut self, v: ::std::string::String) {
+        self.new_value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_new_value(&mut self) -> &mut ::std::string::String {
+        &mut self.new_value
+    }
+
+    // Take field
+    pub fn take_new_value(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.new_value, ::std::string::String::new())
+    }
+
+    // int64 start = 4;
+
+
+    pub fn get_start(&self) -> i64 {
+        self.start
+    }
+    pub fn clear_start(&mut self) {
+        self.start = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: i64) {
+        self.start = v;
+    }
+
+    // int64 end = 5;
+
+
+    pub fn get_end(&self) -> i64 {
+        self.end
+    }
+    pub fn clear_end(&mut self) {
+        self.end = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end(&mut self, v: i64) {
+        self.end = v;
+    }
+}
+
+impl ::protobuf::Message for AttributeChange {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.old_value)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.new_value)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.start = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.end = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key);
+        }
+        if !self.old_value.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.old_value);
+        }
+        if !self.new_value.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.new_value);
+        }
+        if self.start != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.start, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.end != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.end, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_string(1, &self.key)?;
+        }
+        if !self.old_value.is_empty() {
+            os.write_string(2, &self.old_value)?;
+        }
+        if !self.new_value.is_empty() {
+            os.write_string(3, &self.new_value)?;
+        }
+        if self.start != 0 {
+            os.write_int64(4, self.start)?;
+        }
+        if self.end != 0 {
+            os.write_int64(5, self.end)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AttributeChange {
+        AttributeChange::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "key",
+                |m: &AttributeChange| { &m.key },
+                |m: &mut AttributeChange| { &mut m.key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "old_value",
+                |m: &AttributeChange| { &m.old_value },
+                |m: &mut AttributeChange| { &mut m.old_value },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "new_value",
+                |m: &AttributeChange| { &m.new_value },
+                |m: &mut AttributeChange| { &mut m.new_value },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "start",
+                |m: &AttributeChange| { &m.start },
+                |m: &mut AttributeChange| { &mut m.start },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "end",
+                |m: &AttributeChange| { &m.end },
+                |m: &mut AttributeChange| { &mut m.end },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AttributeChange>(
+                "AttributeChange",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AttributeChange {
+        static instance: ::protobuf::rt::LazyV2<AttributeChange> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AttributeChange::new)
+    }
+}
+
+impl ::protobuf::Clear for AttributeChange {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.old_value.clear();
+        self.new_value.clear();
+        self.start = 0;
+        self.end = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AttributeChange {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AttributeChange {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BlockDiff {
+    // message fields
+    pub ty: BlockDiffType,
+    pub old_index: i64,
+    pub new_index: i64,
+    pub old_data: ::std::string::String,
+    pub new_data: ::std::string::String,
+    pub text_changed: bool,
+    pub attributes: ::protobuf::RepeatedField<AttributeChange>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BlockDiff {
+    fn default() -> &'a BlockDiff {
+        <BlockDiff as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BlockDiff {
+    pub fn new() -> BlockDiff {
+        ::std::default::Default::default()
+    }
+
+    // .BlockDiffType ty = 1;
+
+
+    pub fn get_ty(&self) -> BlockDiffType {
+        self.ty
+    }
+    pub fn clear_ty(&mut self) {
+        self.ty = BlockDiffType::Inserted;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ty(&mut self, v: BlockDiffType) {
+        self.ty = v;
+    }
+
+    // int64 old_index = 2;
+
+
+    pub fn get_old_index(&self) -> i64 {
+        self.old_index
+    }
+    pub fn clear_old_index(&mut self) {
+        self.old_index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_old_index(&mut self, v: i64) {
+        self.old_index = v;
+    }
+
+    // int64 new_index = 3;
+
+
+    pub fn get_new_index(&self) -> i64 {
+        self.new_index
+    }
+    pub fn clear_new_index(&mut self) {
+        self.new_index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_new_index(&mut self, v: i64) {
+        self.new_index = v;
+    }
+
+    // string old_data = 4;
+
+
+    pub fn get_old_data(&self) -> &str {
+        &self.old_data
+    }
+    pub fn clear_old_data(&mut self) {
+        self.old_data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_old_data(&mut self, v: ::std::string::String) {
+        self.old_data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_old_data(&mut self) -> &mut ::std::string::String {
+        &mut self.old_data
+    }
+
+    // Take field
+    pub fn take_old_data(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.old_data, ::std::string::String::new())
+    }
+
+    // string new_data = 5;
+
+
+    pub fn get_new_data(&self) -> &str {
+        &self.new_data
+    }
+    pub fn clear_new_data(&mut self) {
+        self.new_data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_new_data(&mut self, v: ::std::string::String) {
+        self.new_data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_new_data(&mut self) -> &mut ::std::string::String {
+        &mut self.new_data
+    }
+
+    // Take field
+    pub fn take_new_data(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.new_data, ::std::string::String::new())
+    }
+
+    // bool text_changed = 6;
+
+
+    pub fn get_text_changed(&self) -> bool {
+        self.text_changed
+    }
+    pub fn clear_text_changed(&mut self) {
+        self.text_changed = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_text_changed(&mut self, v: bool) {
+        self.text_changed = v;
+    }
+
+    // repeated .AttributeChange attributes = 7;
+
+
+    pub fn get_attributes(&self) -> &[AttributeChange] {
+        &self.attributes
+    }
+    pub fn clear_attributes(&mut self) {
+        self.attributes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attributes(&mut self, v: ::protobuf::RepeatedField<AttributeChange>) {
+        self.attributes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_attributes(&mut self) -> &mut ::protobuf::RepeatedField<AttributeChange> {
+        &mut self.attributes
+    }
+
+    // Take field
+    pub fn take_attributes(&mut self) -> ::protobuf::RepeatedField<AttributeChange> {
+        ::std::mem::replace(&mut self.attributes, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for BlockDiff {
+    fn is_initialized(&self) -> bool {
+        for v in &self.attributes {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.ty, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.old_index = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.new_index = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.old_data)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.new_data)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.text_changed = tmp;
+                },
+                7 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.attributes)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.ty != BlockDiffType::Inserted {
+            my_size += ::protobuf::rt::enum_size(1, self.ty);
+        }
+        if self.old_index != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.old_index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.new_index != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.new_index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.old_data.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.old_data);
+        }
+        if !self.new_data.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.new_data);
+        }
+        if self.text_changed != false {
+            my_size += 2;
+        }
+        for value in &self.attributes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.ty != BlockDiffType::Inserted {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.ty))?;
+        }
+        if self.old_index != 0 {
+            os.write_int64(2, self.old_index)?;
+        }
+        if self.new_index != 0 {
+            os.write_int64(3, self.new_index)?;
+        }
+        if !self.old_data.is_empty() {
+            os.write_string(4, &self.old_data)?;
+        }
+        if !self.new_data.is_empty() {
+            os.write_string(5, &self.new_data)?;
+        }
+        if self.text_changed != false {
+            os.write_bool(6, self.text_changed)?;
+        }
+        for v in &self.attributes {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BlockDiff {
+        BlockDiff::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<BlockDiffType>>(
+                "ty",
+                |m: &BlockDiff| { &m.ty },
+                |m: &mut BlockDiff| { &mut m.ty },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "old_index",
+                |m: &BlockDiff| { &m.old_index },
+                |m: &mut BlockDiff| { &mut m.old_index },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "new_index",
+                |m: &BlockDiff| { &m.new_index },
+                |m: &mut BlockDiff| { &mut m.new_index },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "old_data",
+                |m: &BlockDiff| { &m.old_data },
+                |m: &mut BlockDiff| { &mut m.old_data },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "new_data",
+                |m: &BlockDiff| { &m.new_data },
+                |m: &mut BlockDiff| { &mut m.new_data },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "text_changed",
+                |m: &BlockDiff| { &m.text_changed },
+                |m: &mut BlockDiff| { &mut m.text_changed },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AttributeChange>>(
+                "attributes",
+                |m: &BlockDiff| { &m.attributes },
+                |m: &mut BlockDiff| { &mut m.attributes },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BlockDiff>(
+                "BlockDiff",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BlockDiff {
+        static instance: ::protobuf::rt::LazyV2<BlockDiff> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BlockDiff::new)
+    }
+}
+
+impl ::protobuf::Clear for BlockDiff {
+    fn clear(&mut self) {
+        self.ty = BlockDiffType::Inserted;
+        self.old_index = 0;
+        self.new_index = 0;
+        self.old_data.clear();
+        self.new_data.clear();
+        self.text_changed = false;
+        self.attributes.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BlockDiff {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BlockDiff {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DocDiff {
+    // message fields
+    pub items: ::protobuf::RepeatedField<BlockDiff>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocDiff {
+    fn default() -> &'a DocDiff {
+        <DocDiff as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DocDiff {
+    pub fn new() -> DocDiff {
+        ::std::default::Default::default()
+    }
+
+    // repeated .BlockDiff items = 1;
+
+
+    pub fn get_items(&self) -> &[BlockDiff] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<BlockDiff>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<BlockDiff> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<BlockDiff> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for DocDiff {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocDiff {
+        DocDiff::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BlockDiff>>(
+                "items",
+                |m: &DocDiff| { &m.items },
+                |m: &mut DocDiff| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocDiff>(
+                "DocDiff",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocDiff {
+        static instance: ::protobuf::rt::LazyV2<DocDiff> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocDiff::new)
+    }
+}
+
+impl ::protobuf::Clear for DocDiff {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocDiff {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocDiff {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum BlockDiffType {
+    Inserted = 0,
+    Deleted = 1,
+    Changed = 2,
+}
+
+impl ::protobuf::ProtobufEnum for BlockDiffType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<BlockDiffType> {
+        match value {
+            0 => ::std::option::Option::Some(BlockDiffType::Inserted),
+            1 => ::std::option::Option::Some(BlockDiffType::Deleted),
+            2 => ::std::option::Option::Some(BlockDiffType::Changed),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [BlockDiffType] = &[
+            BlockDiffType::Inserted,
+            BlockDiffType::Deleted,
+            BlockDiffType::Changed,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<BlockDiffType>("BlockDiffType", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for BlockDiffType {
+}
+
+impl ::std::default::Default for BlockDiffType {
+    fn default() -> Self {
+        BlockDiffType::Inserted
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BlockDiffType {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\ndiff.proto\"R\n\x14DiffDocumentsRequest\x12\x1b\n\x08old_data\x18\
+    \x01\x20\x01(\tR\x07oldDataB\0\x12\x1b\n\x08new_data\x18\x02\x20\x01(\tR\
+    \x07newDataB\0:\0\"p\n\x13DiffVersionsRequest\x12\x17\n\x06doc_id\x18\
+    \x01\x20\x01(\tR\x05docIdB\0\x12\x1e\n\nold_rev_id\x18\x02\x20\x01(\x03R\
+    \x08oldRevIdB\0\x12\x1e\n\nnew_rev_id\x18\x03\x20\x01(\x03R\x08newRevIdB\
+    \0:\0\"\x91\x01\n\x0fAttributeChange\x12\x12\n\x03key\x18\x01\x20\x01(\t\
+    R\x03keyB\0\x12\x1d\n\told_value\x18\x02\x20\x01(\tR\x08oldValueB\0\x12\
+    \x1d\n\tnew_value\x18\x03\x20\x01(\tR\x08newValueB\0\x12\x16\n\x05start\
+    \x18\x04\x20\x01(\x03R\x05startB\0\x12\x12\n\x03end\x18\x05\x20\x01(\x03\
+    R\x03endB\0:\0\"\x80\x02\n\tBlockDiff\x12\x20\n\x02ty\x18\x01\x20\x01(\
+    \x0e2\x0e.BlockDiffTypeR\x02tyB\0\x12\x1d\n\told_index\x18\x02\x20\x01(\
+    \x03R\x08oldIndexB\0\x12\x1d\n\tnew_index\x18\x03\x20\x01(\x03R\x08newIn\
+    dexB\0\x12\x1b\n\x08old_data\x18\x04\x20\x01(\tR\x07oldDataB\0\x12\x1b\n\
+    \x08new_data\x18\x05\x20\x01(\tR\x07newDataB\0\x12#\n\x0ctext_changed\
+    \x18\x06\x20\x01(\x08R\x0btextChangedB\0\x122\n\nattributes\x18\x07\x20\
+    \x03(\x0b2\x10.AttributeChangeR\nattributesB\0:\0\"/\n\x07DocDiff\x12\"\
+    \n\x05items\x18\x01\x20\x03(\x0b2\n.BlockDiffR\x05itemsB\0:\0*9\n\rBlock\
+    DiffType\x12\x0c\n\x08Inserted\x10\0\x12\x0b\n\x07Deleted\x10\x01\x12\
+    \x0b\n\x07Changed\x10\x02\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
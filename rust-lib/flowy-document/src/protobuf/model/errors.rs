@@ -221,6 +221,19 @@ pub enum ErrorCode {
     UndoFail = 200,
     RedoFail = 201,
     OutOfBound = 202,
+    BlockDataInvalid = 203,
+    CursorInvalid = 204,
+    AssetTooLarge = 300,
+    AssetNotFound = 301,
+    BlobNotFound = 302,
+    AssetTransferFailed = 303,
+    AttachmentNotFound = 304,
+    AttachmentOverQuota = 305,
+    AttachmentFileError = 306,
+    CommentNotFound = 310,
+    CommentContentEmpty = 311,
+    DocumentLocked = 320,
+    DecryptFailed = 321,
     DuplicateRevision = 400,
     UserUnauthorized = 999,
     InternalError = 1000,
@@ -239,6 +252,19 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             200 => ::std::option::Option::Some(ErrorCode::UndoFail),
             201 => ::std::option::Option::Some(ErrorCode::RedoFail),
             202 => ::std::option::Option::Some(ErrorCode::OutOfBound),
+            203 => ::std::option::Option::Some(ErrorCode::BlockDataInvalid),
+            204 => ::std::option::Option::Some(ErrorCode::CursorInvalid),
+            300 => ::std::option::Option::Some(ErrorCode::AssetTooLarge),
+            301 => ::std::option::Option::Some(ErrorCode::AssetNotFound),
+            302 => ::std::option::Option::Some(ErrorCode::BlobNotFound),
+            303 => ::std::option::Option::Some(ErrorCode::AssetTransferFailed),
+            304 => ::std::option::Option::Some(ErrorCode::AttachmentNotFound),
+            305 => ::std::option::Option::Some(ErrorCode::AttachmentOverQuota),
+            306 => ::std::option::Option::Some(ErrorCode::AttachmentFileError),
+            310 => ::std::option::Option::Some(ErrorCode::CommentNotFound),
+            311 => ::std::option::Option::Some(ErrorCode::CommentContentEmpty),
+            320 => ::std::option::Option::Some(ErrorCode::DocumentLocked),
+            321 => ::std::option::Option::Some(ErrorCode::DecryptFailed),
             400 => ::std::option::Option::Some(ErrorCode::DuplicateRevision),
             999 => ::std::option::Option::Some(ErrorCode::UserUnauthorized),
             1000 => ::std::option::Option::Some(ErrorCode::InternalError),
@@ -254,6 +280,19 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             ErrorCode::UndoFail,
             ErrorCode::RedoFail,
             ErrorCode::OutOfBound,
+            ErrorCode::BlockDataInvalid,
+            ErrorCode::CursorInvalid,
+            ErrorCode::AssetTooLarge,
+            ErrorCode::AssetNotFound,
+            ErrorCode::BlobNotFound,
+            ErrorCode::AssetTransferFailed,
+            ErrorCode::AttachmentNotFound,
+            ErrorCode::AttachmentOverQuota,
+            ErrorCode::AttachmentFileError,
+            ErrorCode::CommentNotFound,
+            ErrorCode::CommentContentEmpty,
+            ErrorCode::DocumentLocked,
+            ErrorCode::DecryptFailed,
             ErrorCode::DuplicateRevision,
             ErrorCode::UserUnauthorized,
             ErrorCode::InternalError,
@@ -285,40 +324,21 @@ impl ::protobuf::reflect::ProtobufValue for ErrorCode {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0cerrors.proto\"<\n\x08DocError\x12\x1e\n\x04code\x18\x01\x20\x01(\
-    \x0e2\n.ErrorCodeR\x04code\x12\x10\n\x03msg\x18\x02\x20\x01(\tR\x03msg*\
-    \xb4\x01\n\tErrorCode\x12\x10\n\x0cDocIdInvalid\x10\0\x12\x0f\n\x0bDocNo\
-    tfound\x10\x01\x12\x12\n\x0eWsConnectError\x10\n\x12\r\n\x08UndoFail\x10\
-    \xc8\x01\x12\r\n\x08RedoFail\x10\xc9\x01\x12\x0f\n\nOutOfBound\x10\xca\
-    \x01\x12\x16\n\x11DuplicateRevision\x10\x90\x03\x12\x15\n\x10UserUnautho\
-    rized\x10\xe7\x07\x12\x12\n\rInternalError\x10\xe8\x07J\xa1\x04\n\x06\
-    \x12\x04\0\0\x10\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\
-    \x04\x02\0\x05\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x10\n\x0b\n\x04\
-    \x04\0\x02\0\x12\x03\x03\x04\x17\n\x0c\n\x05\x04\0\x02\0\x06\x12\x03\x03\
-    \x04\r\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0e\x12\n\x0c\n\x05\x04\0\
-    \x02\0\x03\x12\x03\x03\x15\x16\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\
-    \x13\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\
-    \x02\x01\x01\x12\x03\x04\x0b\x0e\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\
-    \x04\x11\x12\n\n\n\x02\x05\0\x12\x04\x06\0\x10\x01\n\n\n\x03\x05\0\x01\
-    \x12\x03\x06\x05\x0e\n\x0b\n\x04\x05\0\x02\0\x12\x03\x07\x04\x15\n\x0c\n\
-    \x05\x05\0\x02\0\x01\x12\x03\x07\x04\x10\n\x0c\n\x05\x05\0\x02\0\x02\x12\
-    \x03\x07\x13\x14\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x08\x04\x14\n\x0c\n\
-    \x05\x05\0\x02\x01\x01\x12\x03\x08\x04\x0f\n\x0c\n\x05\x05\0\x02\x01\x02\
-    \x12\x03\x08\x12\x13\n\x0b\n\x04\x05\0\x02\x02\x12\x03\t\x04\x18\n\x0c\n\
-    \x05\x05\0\x02\x02\x01\x12\x03\t\x04\x12\n\x0c\n\x05\x05\0\x02\x02\x02\
-    \x12\x03\t\x15\x17\n\x0b\n\x04\x05\0\x02\x03\x12\x03\n\x04\x13\n\x0c\n\
-    \x05\x05\0\x02\x03\x01\x12\x03\n\x04\x0c\n\x0c\n\x05\x05\0\x02\x03\x02\
-    \x12\x03\n\x0f\x12\n\x0b\n\x04\x05\0\x02\x04\x12\x03\x0b\x04\x13\n\x0c\n\
-    \x05\x05\0\x02\x04\x01\x12\x03\x0b\x04\x0c\n\x0c\n\x05\x05\0\x02\x04\x02\
-    \x12\x03\x0b\x0f\x12\n\x0b\n\x04\x05\0\x02\x05\x12\x03\x0c\x04\x15\n\x0c\
-    \n\x05\x05\0\x02\x05\x01\x12\x03\x0c\x04\x0e\n\x0c\n\x05\x05\0\x02\x05\
-    \x02\x12\x03\x0c\x11\x14\n\x0b\n\x04\x05\0\x02\x06\x12\x03\r\x04\x1c\n\
-    \x0c\n\x05\x05\0\x02\x06\x01\x12\x03\r\x04\x15\n\x0c\n\x05\x05\0\x02\x06\
-    \x02\x12\x03\r\x18\x1b\n\x0b\n\x04\x05\0\x02\x07\x12\x03\x0e\x04\x1b\n\
-    \x0c\n\x05\x05\0\x02\x07\x01\x12\x03\x0e\x04\x14\n\x0c\n\x05\x05\0\x02\
-    \x07\x02\x12\x03\x0e\x17\x1a\n\x0b\n\x04\x05\0\x02\x08\x12\x03\x0f\x04\
-    \x19\n\x0c\n\x05\x05\0\x02\x08\x01\x12\x03\x0f\x04\x11\n\x0c\n\x05\x05\0\
-    \x02\x08\x02\x12\x03\x0f\x14\x18b\x06proto3\
+    \n\x0cerrors.proto\"B\n\x08DocError\x12\x20\n\x04code\x18\x01\x20\x01(\
+    \x0e2\n.ErrorCodeR\x04codeB\0\x12\x12\n\x03msg\x18\x02\x20\x01(\tR\x03ms\
+    gB\0:\0*\xdc\x03\n\tErrorCode\x12\x10\n\x0cDocIdInvalid\x10\0\x12\x0f\n\
+    \x0bDocNotfound\x10\x01\x12\x12\n\x0eWsConnectError\x10\n\x12\r\n\x08Und\
+    oFail\x10\xc8\x01\x12\r\n\x08RedoFail\x10\xc9\x01\x12\x0f\n\nOutOfBound\
+    \x10\xca\x01\x12\x15\n\x10BlockDataInvalid\x10\xcb\x01\x12\x12\n\rCursor\
+    Invalid\x10\xcc\x01\x12\x12\n\rAssetTooLarge\x10\xac\x02\x12\x12\n\rAsse\
+    tNotFound\x10\xad\x02\x12\x11\n\x0cBlobNotFound\x10\xae\x02\x12\x18\n\
+    \x13AssetTransferFailed\x10\xaf\x02\x12\x17\n\x12AttachmentNotFound\x10\
+    \xb0\x02\x12\x18\n\x13AttachmentOverQuota\x10\xb1\x02\x12\x18\n\x13Attac\
+    hmentFileError\x10\xb2\x02\x12\x14\n\x0fCommentNotFound\x10\xb6\x02\x12\
+    \x18\n\x13CommentContentEmpty\x10\xb7\x02\x12\x13\n\x0eDocumentLocked\
+    \x10\xc0\x02\x12\x12\n\rDecryptFailed\x10\xc1\x02\x12\x16\n\x11Duplicate\
+    Revision\x10\x90\x03\x12\x15\n\x10UserUnauthorized\x10\xe7\x07\x12\x12\n\
+    \rInternalError\x10\xe8\x07\x1a\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
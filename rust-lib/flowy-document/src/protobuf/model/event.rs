@@ -0,0 +1,216 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `event.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum DocumentEvent {
+    OpenDocument = 0,
+    CloseDocument = 1,
+    ApplyChange = 2,
+    ReadDocument = 3,
+    Undo = 4,
+    Redo = 5,
+    ForceSave = 6,
+    ListVersions = 7,
+    ReadVersion = 8,
+    RestoreVersion = 9,
+    AttachAsset = 10,
+    ReadAsset = 11,
+    ListAssets = 12,
+    GetDocumentStats = 13,
+    UpdatePresence = 14,
+    ClearPresence = 15,
+    ReadCollaborators = 16,
+    ReadBlocks = 17,
+    ApplyBlockOps = 18,
+    CreateComment = 19,
+    ResolveComment = 20,
+    DeleteComment = 21,
+    ReadComments = 22,
+    DiffDocuments = 23,
+    DiffVersions = 24,
+    SearchDocuments = 26,
+    EncryptDocument = 27,
+    DecryptDocument = 28,
+    LockDocument = 29,
+    UnlockDocument = 30,
+    CheckText = 31,
+    UploadAsset = 32,
+    DownloadAsset = 33,
+    ReadAssetTransfers = 34,
+    ImportAttachment = 35,
+    ListAttachments = 36,
+    DeleteAttachment = 37,
+    ReadAttachmentUsage = 38,
+}
+
+impl ::protobuf::ProtobufEnum for DocumentEvent {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<DocumentEvent> {
+        match value {
+            0 => ::std::option::Option::Some(DocumentEvent::OpenDocument),
+            1 => ::std::option::Option::Some(DocumentEvent::CloseDocument),
+            2 => ::std::option::Option::Some(DocumentEvent::ApplyChange),
+            3 => ::std::option::Option::Some(DocumentEvent::ReadDocument),
+            4 => ::std::option::Option::Some(DocumentEvent::Undo),
+            5 => ::std::option::Option::Some(DocumentEvent::Redo),
+            6 => ::std::option::Option::Some(DocumentEvent::ForceSave),
+            7 => ::std::option::Option::Some(DocumentEvent::ListVersions),
+            8 => ::std::option::Option::Some(DocumentEvent::ReadVersion),
+            9 => ::std::option::Option::Some(DocumentEvent::RestoreVersion),
+            10 => ::std::option::Option::Some(DocumentEvent::AttachAsset),
+            11 => ::std::option::Option::Some(DocumentEvent::ReadAsset),
+            12 => ::std::option::Option::Some(DocumentEvent::ListAssets),
+            13 => ::std::option::Option::Some(DocumentEvent::GetDocumentStats),
+            14 => ::std::option::Option::Some(DocumentEvent::UpdatePresence),
+            15 => ::std::option::Option::Some(DocumentEvent::ClearPresence),
+            16 => ::std::option::Option::Some(DocumentEvent::ReadCollaborators),
+            17 => ::std::option::Option::Some(DocumentEvent::ReadBlocks),
+            18 => ::std::option::Option::Some(DocumentEvent::ApplyBlockOps),
+            19 => ::std::option::Option::Some(DocumentEvent::CreateComment),
+            20 => ::std::option::Option::Some(DocumentEvent::ResolveComment),
+            21 => ::std::option::Option::Some(DocumentEvent::DeleteComment),
+            22 => ::std::option::Option::Some(DocumentEvent::ReadComments),
+            23 => ::std::option::Option::Some(DocumentEvent::DiffDocuments),
+            24 => ::std::option::Option::Some(DocumentEvent::DiffVersions),
+            26 => ::std::option::Option::Some(DocumentEvent::SearchDocuments),
+            27 => ::std::option::Option::Some(DocumentEvent::EncryptDocument),
+            28 => ::std::option::Option::Some(DocumentEvent::DecryptDocument),
+            29 => ::std::option::Option::Some(DocumentEvent::LockDocument),
+            30 => ::std::option::Option::Some(DocumentEvent::UnlockDocument),
+            31 => ::std::option::Option::Some(DocumentEvent::CheckText),
+            32 => ::std::option::Option::Some(DocumentEvent::UploadAsset),
+            33 => ::std::option::Option::Some(DocumentEvent::DownloadAsset),
+            34 => ::std::option::Option::Some(DocumentEvent::ReadAssetTransfers),
+            35 => ::std::option::Option::Some(DocumentEvent::ImportAttachment),
+            36 => ::std::option::Option::Some(DocumentEvent::ListAttachments),
+            37 => ::std::option::Option::Some(DocumentEvent::DeleteAttachment),
+            38 => ::std::option::Option::Some(DocumentEvent::ReadAttachmentUsage),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [DocumentEvent] = &[
+            DocumentEvent::OpenDocument,
+            DocumentEvent::CloseDocument,
+            DocumentEvent::ApplyChange,
+            DocumentEvent::ReadDocument,
+            DocumentEvent::Undo,
+            DocumentEvent::Redo,
+            DocumentEvent::ForceSave,
+            DocumentEvent::ListVersions,
+            DocumentEvent::ReadVersion,
+            DocumentEvent::RestoreVersion,
+            DocumentEvent::AttachAsset,
+            DocumentEvent::ReadAsset,
+            DocumentEvent::ListAssets,
+            DocumentEvent::GetDocumentStats,
+            DocumentEvent::UpdatePresence,
+            DocumentEvent::ClearPresence,
+            DocumentEvent::ReadCollaborators,
+            DocumentEvent::ReadBlocks,
+            DocumentEvent::ApplyBlockOps,
+            DocumentEvent::CreateComment,
+            DocumentEvent::ResolveComment,
+            DocumentEvent::DeleteComment,
+            DocumentEvent::ReadComments,
+            DocumentEvent::DiffDocuments,
+            DocumentEvent::DiffVersions,
+            DocumentEvent::SearchDocuments,
+            DocumentEvent::EncryptDocument,
+            DocumentEvent::DecryptDocument,
+            DocumentEvent::LockDocument,
+            DocumentEvent::UnlockDocument,
+            DocumentEvent::CheckText,
+            DocumentEvent::UploadAsset,
+            DocumentEvent::DownloadAsset,
+            DocumentEvent::ReadAssetTransfers,
+            DocumentEvent::ImportAttachment,
+            DocumentEvent::ListAttachments,
+            DocumentEvent::DeleteAttachment,
+            DocumentEvent::ReadAttachmentUsage,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<DocumentEvent>("DocumentEvent", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for DocumentEvent {
+}
+
+impl ::std::default::Default for DocumentEvent {
+    fn default() -> Self {
+        DocumentEvent::OpenDocument
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocumentEvent {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0bevent.proto*\xd5\x05\n\rDocumentEvent\x12\x10\n\x0cOpenDocument\
+    \x10\0\x12\x11\n\rCloseDocument\x10\x01\x12\x0f\n\x0bApplyChange\x10\x02\
+    \x12\x10\n\x0cReadDocument\x10\x03\x12\x08\n\x04Undo\x10\x04\x12\x08\n\
+    \x04Redo\x10\x05\x12\r\n\tForceSave\x10\x06\x12\x10\n\x0cListVersions\
+    \x10\x07\x12\x0f\n\x0bReadVersion\x10\x08\x12\x12\n\x0eRestoreVersion\
+    \x10\t\x12\x0f\n\x0bAttachAsset\x10\n\x12\r\n\tReadAsset\x10\x0b\x12\x0e\
+    \n\nListAssets\x10\x0c\x12\x14\n\x10GetDocumentStats\x10\r\x12\x12\n\x0e\
+    UpdatePresence\x10\x0e\x12\x11\n\rClearPresence\x10\x0f\x12\x15\n\x11Rea\
+    dCollaborators\x10\x10\x12\x0e\n\nReadBlocks\x10\x11\x12\x11\n\rApplyBlo\
+    ckOps\x10\x12\x12\x11\n\rCreateComment\x10\x13\x12\x12\n\x0eResolveComme\
+    nt\x10\x14\x12\x11\n\rDeleteComment\x10\x15\x12\x10\n\x0cReadComments\
+    \x10\x16\x12\x11\n\rDiffDocuments\x10\x17\x12\x10\n\x0cDiffVersions\x10\
+    \x18\x12\x13\n\x0fSearchDocuments\x10\x1a\x12\x13\n\x0fEncryptDocument\
+    \x10\x1b\x12\x13\n\x0fDecryptDocument\x10\x1c\x12\x10\n\x0cLockDocument\
+    \x10\x1d\x12\x12\n\x0eUnlockDocument\x10\x1e\x12\r\n\tCheckText\x10\x1f\
+    \x12\x0f\n\x0bUploadAsset\x10\x20\x12\x11\n\rDownloadAsset\x10!\x12\x16\
+    \n\x12ReadAssetTransfers\x10\"\x12\x14\n\x10ImportAttachment\x10#\x12\
+    \x13\n\x0fListAttachments\x10$\x12\x14\n\x10DeleteAttachment\x10%\x12\
+    \x17\n\x13ReadAttachmentUsage\x10&\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
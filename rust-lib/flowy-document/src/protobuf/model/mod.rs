@@ -8,3 +8,42 @@ pub use observable::*;
 
 mod errors; 
 pub use errors::*; 
+
+mod event; 
+pub use event::*; 
+
+mod version; 
+pub use version::*; 
+
+mod asset; 
+pub use asset::*; 
+
+mod stats; 
+pub use stats::*; 
+
+mod presence; 
+pub use presence::*; 
+
+mod block; 
+pub use block::*; 
+
+mod comment; 
+pub use comment::*; 
+
+mod diff; 
+pub use diff::*; 
+
+mod search; 
+pub use search::*; 
+
+mod encryption; 
+pub use encryption::*; 
+
+mod check; 
+pub use check::*; 
+
+mod transfer; 
+pub use transfer::*; 
+
+mod attachment; 
+pub use attachment::*; 
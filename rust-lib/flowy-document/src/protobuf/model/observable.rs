@@ -26,6 +26,15 @@
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum DocObservable {
     UserCreateDoc = 0,
+    Saving = 1,
+    Saved = 2,
+    SaveFailed = 3,
+    StatsChanged = 4,
+    CollaboratorsChanged = 5,
+    CommentsChanged = 6,
+    DiagnosticsChanged = 7,
+    RemoteChanged = 8,
+    AssetTransferProgress = 9,
 }
 
 impl ::protobuf::ProtobufEnum for DocObservable {
@@ -36,6 +45,15 @@ impl ::protobuf::ProtobufEnum for DocObservable {
     fn from_i32(value: i32) -> ::std::option::Option<DocObservable> {
         match value {
             0 => ::std::option::Option::Some(DocObservable::UserCreateDoc),
+            1 => ::std::option::Option::Some(DocObservable::Saving),
+            2 => ::std::option::Option::Some(DocObservable::Saved),
+            3 => ::std::option::Option::Some(DocObservable::SaveFailed),
+            4 => ::std::option::Option::Some(DocObservable::StatsChanged),
+            5 => ::std::option::Option::Some(DocObservable::CollaboratorsChanged),
+            6 => ::std::option::Option::Some(DocObservable::CommentsChanged),
+            7 => ::std::option::Option::Some(DocObservable::DiagnosticsChanged),
+            8 => ::std::option::Option::Some(DocObservable::RemoteChanged),
+            9 => ::std::option::Option::Some(DocObservable::AssetTransferProgress),
             _ => ::std::option::Option::None
         }
     }
@@ -43,6 +61,15 @@ impl ::protobuf::ProtobufEnum for DocObservable {
     fn values() -> &'static [Self] {
         static values: &'static [DocObservable] = &[
             DocObservable::UserCreateDoc,
+            DocObservable::Saving,
+            DocObservable::Saved,
+            DocObservable::SaveFailed,
+            DocObservable::StatsChanged,
+            DocObservable::CollaboratorsChanged,
+            DocObservable::CommentsChanged,
+            DocObservable::DiagnosticsChanged,
+            DocObservable::RemoteChanged,
+            DocObservable::AssetTransferProgress,
         ];
         values
     }
@@ -71,12 +98,12 @@ impl ::protobuf::reflect::ProtobufValue for DocObservable {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x10observable.proto*\"\n\rDocObservable\x12\x11\n\rUserCreateDoc\x10\
-    \0JS\n\x06\x12\x04\0\0\x04\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\
-    \x05\0\x12\x04\x02\0\x04\x01\n\n\n\x03\x05\0\x01\x12\x03\x02\x05\x12\n\
-    \x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\x16\n\x0c\n\x05\x05\0\x02\0\x01\
-    \x12\x03\x03\x04\x11\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x03\x14\x15b\
-    \x06proto3\
+    \n\x10observable.proto*\xd2\x01\n\rDocObservable\x12\x11\n\rUserCreateDo\
+    c\x10\0\x12\n\n\x06Saving\x10\x01\x12\t\n\x05Saved\x10\x02\x12\x0e\n\nSa\
+    veFailed\x10\x03\x12\x10\n\x0cStatsChanged\x10\x04\x12\x18\n\x14Collabor\
+    atorsChanged\x10\x05\x12\x13\n\x0fCommentsChanged\x10\x06\x12\x16\n\x12D\
+    iagnosticsChanged\x10\x07\x12\x11\n\rRemoteChanged\x10\x08\x12\x19\n\x15\
+    AssetTransferProgress\x10\t\x1a\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,487 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `stats.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DocStats {
+    // message fields
+    pub doc_id: ::std::string::String,
+    pub word_count: i64,
+    pub char_count: i64,
+    pub reading_time: i64,
+    pub paragraph_count: i64,
+    pub header_count: i64,
+    pub list_count: i64,
+    pub quote_count: i64,
+    pub code_count: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocStats {
+    fn default() -> &'a DocStats {
+        <DocStats as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DocStats {
+    pub fn new() -> DocStats {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // int64 word_count = 2;
+
+
+    pub fn get_word_count(&self) -> i64 {
+        self.word_count
+    }
+    pub fn clear_word_count(&mut self) {
+        self.word_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_word_count(&mut self, v: i64) {
+        self.word_count = v;
+    }
+
+    // int64 char_count = 3;
+
+
+    pub fn get_char_count(&self) -> i64 {
+        self.char_count
+    }
+    pub fn clear_char_count(&mut self) {
+        self.char_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_char_count(&mut self, v: i64) {
+        self.char_count = v;
+    }
+
+    // int64 reading_time = 4;
+
+
+    pub fn get_reading_time(&self) -> i64 {
+        self.reading_time
+    }
+    pub fn clear_reading_time(&mut self) {
+        self.reading_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reading_time(&mut self, v: i64) {
+        self.reading_time = v;
+    }
+
+    // int64 paragraph_count = 5;
+
+
+    pub fn get_paragraph_count(&self) -> i64 {
+        self.paragraph_count
+    }
+    pub fn clear_paragraph_count(&mut self) {
+        self.paragraph_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_paragraph_count(&mut self, v: i64) {
+        self.paragraph_count = v;
+    }
+
+    // int64 header_count = 6;
+
+
+    pub fn get_header_count(&self) -> i64 {
+        self.header_count
+    }
+    pub fn clear_header_count(&mut self) {
+        self.header_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header_count(&mut self, v: i64) {
+        self.header_count = v;
+    }
+
+    // int64 list_count = 7;
+
+
+    pub fn get_list_count(&self) -> i64 {
+        self.list_count
+    }
+    pub fn clear_list_count(&mut self) {
+        self.list_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_list_count(&mut self, v: i64) {
+        self.list_count = v;
+    }
+
+    // int64 quote_count = 8;
+
+
+    pub fn get_quote_count(&self) -> i64 {
+        self.quote_count
+    }
+    pub fn clear_quote_count(&mut self) {
+        self.quote_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_quote_count(&mut self, v: i64) {
+        self.quote_count = v;
+    }
+
+    // int64 code_count = 9;
+
+
+    pub fn get_code_count(&self) -> i64 {
+        self.code_count
+    }
+    pub fn clear_code_count(&mut self) {
+        self.code_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_code_count(&mut self, v: i64) {
+        self.code_count = v;
+    }
+}
+
+impl ::protobuf::Message for DocStats {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.word_count = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.char_count = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.reading_time = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.paragraph_count = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.header_count = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.list_count = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.quote_count = tmp;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.code_count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        if self.word_count != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.word_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.char_count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.char_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.reading_time != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.reading_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.paragraph_count != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.paragraph_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.header_count != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.header_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.list_count != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.list_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.quote_count != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.quote_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.code_count != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.code_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        if self.word_count != 0 {
+            os.write_int64(2, self.word_count)?;
+        }
+        if self.char_count != 0 {
+            os.write_int64(3, self.char_count)?;
+        }
+        if self.reading_time != 0 {
+            os.write_int64(4, self.reading_time)?;
+        }
+        if self.paragraph_count != 0 {
+            os.write_int64(5, self.paragraph_count)?;
+        }
+        if self.header_count != 0 {
+            os.write_int64(6, self.header_count)?;
+        }
+        if self.list_count != 0 {
+            os.write_int64(7, self.list_count)?;
+        }
+        if self.quote_count != 0 {
+            os.write_int64(8, self.quote_count)?;
+        }
+        if self.code_count != 0 {
+            os.write_int64(9, self.code_count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocStats {
+        DocStats::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &DocStats| { &m.doc_id },
+                |m: &mut DocStats| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "word_count",
+                |m: &DocStats| { &m.word_count },
+                |m: &mut DocStats| { &mut m.word_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "char_count",
+                |m: &DocStats| { &m.char_count },
+                |m: &mut DocStats| { &mut m.char_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "reading_time",
+                |m: &DocStats| { &m.reading_time },
+                |m: &mut DocStats| { &mut m.reading_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "paragraph_count",
+                |m: &DocStats| { &m.paragraph_count },
+                |m: &mut DocStats| { &mut m.paragraph_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "header_count",
+                |m: &DocStats| { &m.header_count },
+                |m: &mut DocStats| { &mut m.header_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "list_count",
+                |m: &DocStats| { &m.list_count },
+                |m: &mut DocStats| { &mut m.list_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "quote_count",
+                |m: &DocStats| { &m.quote_count },
+                |m: &mut DocStats| { &mut m.quote_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "code_count",
+                |m: &DocStats| { &m.code_count },
+                |m: &mut DocStats| { &mut m.code_count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocStats>(
+                "DocStats",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocStats {
+        static instance: ::protobuf::rt::LazyV2<DocStats> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocStats::new)
+    }
+}
+
+impl ::protobuf::Clear for DocStats {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.word_count = 0;
+        self.char_count = 0;
+        self.reading_time = 0;
+        self.paragraph_count = 0;
+        self.header_count = 0;
+        self.list_count = 0;
+        self.quote_count = 0;
+        self.code_count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocStats {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocStats {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0bstats.proto\"\xc1\x02\n\x08DocStats\x12\x17\n\x06doc_id\x18\x01\
+    \x20\x01(\tR\x05docIdB\0\x12\x1f\n\nword_count\x18\x02\x20\x01(\x03R\two\
+    rdCountB\0\x12\x1f\n\nchar_count\x18\x03\x20\x01(\x03R\tcharCountB\0\x12\
+    #\n\x0creading_time\x18\x04\x20\x01(\x03R\x0breadingTimeB\0\x12)\n\x0fpa\
+    ragraph_count\x18\x05\x20\x01(\x03R\x0eparagraphCountB\0\x12#\n\x0cheade\
+    r_count\x18\x06\x20\x01(\x03R\x0bheaderCountB\0\x12\x1f\n\nlist_count\
+    \x18\x07\x20\x01(\x03R\tlistCountB\0\x12!\n\x0bquote_count\x18\x08\x20\
+    \x01(\x03R\nquoteCountB\0\x12\x1f\n\ncode_count\x18\t\x20\x01(\x03R\tcod\
+    eCountB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,1107 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `transfer.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AssetTransfer {
+    // message fields
+    pub asset_id: ::std::string::String,
+    pub direction: AssetTransferDirection,
+    pub transferred: i64,
+    pub total: i64,
+    pub resumed_from: i64,
+    pub error: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AssetTransfer {
+    fn default() -> &'a AssetTransfer {
+        <AssetTransfer as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AssetTransfer {
+    pub fn new() -> AssetTransfer {
+        ::std::default::Default::default()
+    }
+
+    // string asset_id = 1;
+
+
+    pub fn get_asset_id(&self) -> &str {
+        &self.asset_id
+    }
+    pub fn clear_asset_id(&mut self) {
+        self.asset_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_asset_id(&mut self, v: ::std::string::String) {
+        self.asset_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_asset_id(&mut self) -> &mut ::std::string::String {
+        &mut self.asset_id
+    }
+
+    // Take field
+    pub fn take_asset_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.asset_id, ::std::string::String::new())
+    }
+
+    // .AssetTransferDirection direction = 2;
+
+
+    pub fn get_direction(&self) -> AssetTransferDirection {
+        self.direction
+    }
+    pub fn clear_direction(&mut self) {
+        self.direction = AssetTransferDirection::Upload;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_direction(&mut self, v: AssetTransferDirection) {
+        self.direction = v;
+    }
+
+    // int64 transferred = 3;
+
+
+    pub fn get_transferred(&self) -> i64 {
+        self.transferred
+    }
+    pub fn clear_transferred(&mut self) {
+        self.transferred = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_transferred(&mut self, v: i64) {
+        self.transferred = v;
+    }
+
+    // int64 total = 4;
+
+
+    pub fn get_total(&self) -> i64 {
+        self.total
+    }
+    pub fn clear_total(&mut self) {
+        self.total = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total(&mut self, v: i64) {
+        self.total = v;
+    }
+
+    // int64 resumed_from = 5;
+
+
+    pub fn get_resumed_from(&self) -> i64 {
+        self.resumed_from
+    }
+    pub fn clear_resumed_from(&mut self) {
+        self.resumed_from = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resumed_from(&mut self, v: i64) {
+        self.resumed_from = v;
+    }
+
+    // string error = 6;
+
+
+    pub fn get_error(&self) -> &str {
+        &self.error
+    }
+    pub fn clear_error(&mut self) {
+        self.error.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.error = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        &mut self.error
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for AssetTransfer {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.asset_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.direction, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.transferred = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.total = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.resumed_from = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.asset_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.asset_id);
+        }
+        if self.direction != AssetTransferDirection::Upload {
+            my_size += ::protobuf::rt::enum_size(2, self.direction);
+        }
+        if self.transferred != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.transferred, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.total != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.total, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.resumed_from != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.resumed_from, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.error);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.asset_id.is_empty() {
+            os.write_string(1, &self.asset_id)?;
+        }
+        if self.direction != AssetTransferDirection::Upload {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.direction))?;
+        }
+        if self.transferred != 0 {
+            os.write_int64(3, self.transferred)?;
+        }
+        if self.total != 0 {
+            os.write_int64(4, self.total)?;
+        }
+        if self.resumed_from != 0 {
+            os.write_int64(5, self.resumed_from)?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(6, &self.error)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AssetTransfer {
+        AssetTransfer::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "asset_id",
+                |m: &AssetTransfer| { &m.asset_id },
+                |m: &mut AssetTransfer| { &mut m.asset_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<AssetTransferDirection>>(
+                "direction",
+                |m: &AssetTransfer| { &m.direction },
+                |m: &mut AssetTransfer| { &mut m.direction },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "transferred",
+                |m: &AssetTransfer| { &m.transferred },
+                |m: &mut AssetTransfer| { &mut m.transferred },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "total",
+                |m: &AssetTransfer| { &m.total },
+                |m: &mut AssetTransfer| { &mut m.total },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "resumed_from",
+                |m: &AssetTransfer| { &m.resumed_from },
+                |m: &mut AssetTransfer| { &mut m.resumed_from },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "error",
+                |m: &AssetTransfer| { &m.error },
+                |m: &mut AssetTransfer| { &mut m.error },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AssetTransfer>(
+                "AssetTransfer",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AssetTransfer {
+        static instance: ::protobuf::rt::LazyV2<AssetTransfer> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AssetTransfer::new)
+    }
+}
+
+impl ::protobuf::Clear for AssetTransfer {
+    fn clear(&mut self) {
+        self.asset_id.clear();
+        self.direction = AssetTransferDirection::Upload;
+        self.transferred = 0;
+        self.total = 0;
+        self.resumed_from = 0;
+        self.error.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AssetTransfer {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AssetTransfer {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedAssetTransfer {
+    // message fields
+    pub items: ::protobuf::RepeatedField<AssetTransfer>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedAssetTransfer {
+    fn default() -> &'a RepeatedAssetTransfer {
+        <RepeatedAssetTransfer as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedAssetTransfer {
+    pub fn new() -> RepeatedAssetTransfer {
+        ::std::default::Default::default()
+    }
+
+    // repeated .AssetTransfer items = 1;
+
+
+    pub fn get_items(&self) -> &[AssetTransfer] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<AssetTransfer>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<AssetTransfer> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<AssetTransfer> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedAssetTransfer {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedAssetTransfer {
+        RepeatedAssetTransfer::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AssetTransfer>>(
+                "items",
+                |m: &RepeatedAssetTransfer| { &m.items },
+                |m: &mut RepeatedAssetTransfer| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedAssetTransfer>(
+                "RepeatedAssetTransfer",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedAssetTransfer {
+        static instance: ::protobuf::rt::LazyV2<RepeatedAssetTransfer> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedAssetTransfer::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedAssetTransfer {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedAssetTransfer {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedAssetTransfer {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AssetChunk {
+    // message fields
+    pub asset_id: ::std::string::String,
+    pub offset: i64,
+    pub total_size: i64,
+    pub data: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AssetChunk {
+    fn default() -> &'a AssetChunk {
+        <AssetChunk as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AssetChunk {
+    pub fn new() -> AssetChunk {
+        ::std::default::Default::default()
+    }
+
+    // string asset_id = 1;
+
+
+    pub fn get_asset_id(&self) -> &str {
+        &self.asset_id
+    }
+    pub fn clear_asset_id(&mut self) {
+        self.asset_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_asset_id(&mut self, v: ::std::string::String) {
+        self.asset_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_asset_id(&mut self) -> &mut ::std::string::String {
+        &mut self.asset_id
+    }
+
+    // Take field
+    pub fn take_asset_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.asset_id, ::std::string::String::new())
+    }
+
+    // int64 offset = 2;
+
+
+    pub fn get_offset(&self) -> i64 {
+        self.offset
+    }
+    pub fn clear_offset(&mut self) {
+        self.offset = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_offset(&mut self, v: i64) {
+        self.offset = v;
+    }
+
+    // int64 total_size = 3;
+
+
+    pub fn get_total_size(&self) -> i64 {
+        self.total_size
+    }
+    pub fn clear_total_size(&mut self) {
+        self.total_size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total_size(&mut self, v: i64) {
+        self.total_size = v;
+    }
+
+    // bytes data = 4;
+
+
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
+    }
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.data
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for AssetChunk {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.asset_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.offset = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.total_size = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.asset_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.asset_id);
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.offset, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.total_size != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.total_size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.data);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.asset_id.is_empty() {
+            os.write_string(1, &self.asset_id)?;
+        }
+        if self.offset != 0 {
+            os.write_int64(2, self.offset)?;
+        }
+        if self.total_size != 0 {
+            os.write_int64(3, self.total_size)?;
+        }
+        if !self.data.is_empty() {
+            os.write_bytes(4, &self.data)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AssetChunk {
+        AssetChunk::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "asset_id",
+                |m: &AssetChunk| { &m.asset_id },
+                |m: &mut AssetChunk| { &mut m.asset_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "offset",
+                |m: &AssetChunk| { &m.offset },
+                |m: &mut AssetChunk| { &mut m.offset },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "total_size",
+                |m: &AssetChunk| { &m.total_size },
+                |m: &mut AssetChunk| { &mut m.total_size },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "data",
+                |m: &AssetChunk| { &m.data },
+                |m: &mut AssetChunk| { &mut m.data },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AssetChunk>(
+                "AssetChunk",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AssetChunk {
+        static instance: ::protobuf::rt::LazyV2<AssetChunk> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AssetChunk::new)
+    }
+}
+
+impl ::protobuf::Clear for AssetChunk {
+    fn clear(&mut self) {
+        self.asset_id.clear();
+        self.offset = 0;
+        self.total_size = 0;
+        self.data.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AssetChunk {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AssetChunk {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AssetChunkRequest {
+    // message fields
+    pub asset_id: ::std::string::String,
+    pub offset: i64,
+    pub size: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AssetChunkRequest {
+    fn default() -> &'a AssetChunkRequest {
+        <AssetChunkRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AssetChunkRequest {
+    pub fn new() -> AssetChunkRequest {
+        ::std::default::Default::default()
+    }
+
+    // string asset_id = 1;
+
+
+    pub fn get_asset_id(&self) -> &str {
+        &self.asset_id
+    }
+    pub fn clear_asset_id(&mut self) {
+        self.asset_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_asset_id(&mut self, v: ::std::string::String) {
+        self.asset_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_asset_id(&mut self) -> &mut ::std::string::String {
+        &mut self.asset_id
+    }
+
+    // Take field
+    pub fn take_asset_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.asset_id, ::std::string::String::new())
+    }
+
+    // int64 offset = 2;
+
+
+    pub fn get_offset(&self) -> i64 {
+        self.offset
+    }
+    pub fn clear_offset(&mut self) {
+        self.offset = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_offset(&mut self, v: i64) {
+        self.offset = v;
+    }
+
+    // int64 size = 3;
+
+
+    pub fn get_size(&self) -> i64 {
+        self.size
+    }
+    pub fn clear_size(&mut self) {
+        self.size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_size(&mut self, v: i64) {
+        self.size = v;
+    }
+}
+
+impl ::protobuf::Message for AssetChunkRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.asset_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.offset = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.size = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.asset_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.asset_id);
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.offset, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.size != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.asset_id.is_empty() {
+            os.write_string(1, &self.asset_id)?;
+        }
+        if self.offset != 0 {
+            os.write_int64(2, self.offset)?;
+        }
+        if self.size != 0 {
+            os.write_int64(3, self.size)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AssetChunkRequest {
+        AssetChunkRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "asset_id",
+                |m: &AssetChunkRequest| { &m.asset_id },
+                |m: &mut AssetChunkRequest| { &mut m.asset_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "offset",
+                |m: &AssetChunkRequest| { &m.offset },
+                |m: &mut AssetChunkRequest| { &mut m.offset },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "size",
+                |m: &AssetChunkRequest| { &m.size },
+                |m: &mut AssetChunkRequest| { &mut m.size },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AssetChunkRequest>(
+                "AssetChunkRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AssetChunkRequest {
+        static instance: ::protobuf::rt::LazyV2<AssetChunkRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AssetChunkRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for AssetChunkRequest {
+    fn clear(&mut self) {
+        self.asset_id.clear();
+        self.offset = 0;
+        self.size = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AssetChunkRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AssetChunkRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum AssetTransferDirection {
+    Upload = 0,
+    Download = 1,
+}
+
+impl ::protobuf::ProtobufEnum for AssetTransferDirection {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<AssetTransferDirection> {
+        match value {
+            0 => ::std::option::Option::Some(AssetTransferDirection::Upload),
+            1 => ::std::option::Option::Some(AssetTransferDirection::Download),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [AssetTransferDirection] = &[
+            AssetTransferDirection::Upload,
+            AssetTransferDirection::Download,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<AssetTransferDirection>("AssetTransferDirection", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for AssetTransferDirection {
+}
+
+impl ::std::default::Default for AssetTransferDirection {
+    fn default() -> Self {
+        AssetTransferDirection::Upload
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AssetTransferDirection {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0etransfer.proto\"\xe0\x01\n\rAssetTransfer\x12\x1b\n\x08asset_id\
+    \x18\x01\x20\x01(\tR\x07assetIdB\0\x127\n\tdirection\x18\x02\x20\x01(\
+    \x0e2\x17.AssetTransferDirectionR\tdirectionB\0\x12\"\n\x0btransferred\
+    \x18\x03\x20\x01(\x03R\x0btransferredB\0\x12\x16\n\x05total\x18\x04\x20\
+    \x01(\x03R\x05totalB\0\x12#\n\x0cresumed_from\x18\x05\x20\x01(\x03R\x0br\
+    esumedFromB\0\x12\x16\n\x05error\x18\x06\x20\x01(\tR\x05errorB\0:\0\"A\n\
+    \x15RepeatedAssetTransfer\x12&\n\x05items\x18\x01\x20\x03(\x0b2\x0e.Asse\
+    tTransferR\x05itemsB\0:\0\"|\n\nAssetChunk\x12\x1b\n\x08asset_id\x18\x01\
+    \x20\x01(\tR\x07assetIdB\0\x12\x18\n\x06offset\x18\x02\x20\x01(\x03R\x06\
+    offsetB\0\x12\x1f\n\ntotal_size\x18\x03\x20\x01(\x03R\ttotalSizeB\0\x12\
+    \x14\n\x04data\x18\x04\x20\x01(\x0cR\x04dataB\0:\0\"b\n\x11AssetChunkReq\
+    uest\x12\x1b\n\x08asset_id\x18\x01\x20\x01(\tR\x07assetIdB\0\x12\x18\n\
+    \x06offset\x18\x02\x20\x01(\x03R\x06offsetB\0\x12\x14\n\x04size\x18\x03\
+    \x20\x01(\x03R\x04sizeB\0:\0*4\n\x16AssetTransferDirection\x12\n\n\x06Up\
+    load\x10\0\x12\x0c\n\x08Download\x10\x01\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
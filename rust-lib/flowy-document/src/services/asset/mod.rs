@@ -0,0 +1,317 @@
+mod transfer;
+
+pub use transfer::*;
+
+use crate::{
+    entities::asset::{AssetData, CreateAssetRequest, DocAsset},
+    errors::{internal_error, DocError, DocResult},
+    module::DocumentUser,
+    services::blob::{BlobStore, DeletedFiles},
+    sql_tables::{AssetTransferTableSql, DocAssetTable, DocAssetTableSql},
+};
+use flowy_database::SqliteConnection;
+use flowy_ot::core::{Attribute, AttributeKey, Delta};
+use std::{
+    collections::{HashMap, HashSet},
+    io::Read,
+    path::PathBuf,
+    sync::Arc,
+};
+
+pub const ASSET_URL_SCHEME: &str = "asset://";
+
+// The large assets are uploaded and downloaded in chunks, see AssetTransfers.
+pub const MAX_ASSET_SIZE: usize = 64 * 1024 * 1024;
+
+pub fn asset_url(asset_id: &str) -> String { format!("{}{}", ASSET_URL_SCHEME, asset_id) }
+
+// The asset_id of the url, or the string itself if it isn't an asset url.
+pub fn parse_asset_id(s: &str) -> &str { s.strip_prefix(ASSET_URL_SCHEME).unwrap_or(s) }
+
+// The ids of the assets that the links of the document refer to.
+pub fn referenced_assets(delta: &Delta) -> HashSet<String> {
+    delta
+        .ops
+        .iter()
+        .flat_map(|op| {
+            op.get_attributes()
+                .get(&AttributeKey::Link)
+                .and_then(|value| value.0.clone())
+        })
+        .filter(|link| link.starts_with(ASSET_URL_SCHEME))
+        .map(|link| parse_asset_id(&link).to_owned())
+        .collect()
+}
+
+// Points the links to the assets in the map to the assets they're mapped to.
+pub fn replace_asset_links(delta: &Delta, asset_ids: &HashMap<String, String>) -> Delta {
+    let mut delta = delta.clone();
+    for op in delta.ops.iter_mut() {
+        let mut attributes = op.get_attributes();
+        let link = attributes
+            .get(&AttributeKey::Link)
+            .and_then(|value| value.0.clone())
+            .filter(|link| link.starts_with(ASSET_URL_SCHEME));
+        if let Some(asset_id) = link.and_then(|link| asset_ids.get(parse_asset_id(&link))) {
+            attributes.add(Attribute::Link(&asset_url(asset_id)));
+            op.set_attributes(attributes);
+        }
+    }
+    delta
+}
+
+// The data of the assets is kept in the blob store, each asset refers to its
+// blob, so the copies of an asset and the assets with the same data share it.
+// The assets attached before the blobs keep their data in the assets directory
+// of the user, one file per asset named after its id.
+pub(crate) struct AssetStore {
+    user: Arc<dyn DocumentUser>,
+    blobs: Arc<BlobStore>,
+    sql: DocAssetTableSql,
+}
+
+impl AssetStore {
+    pub(crate) fn new(user: Arc<dyn DocumentUser>, blobs: Arc<BlobStore>) -> Self {
+        Self {
+            user,
+            blobs,
+            sql: DocAssetTableSql {},
+        }
+    }
+
+    pub(crate) fn attach(&self, params: CreateAssetRequest) -> DocResult<DocAsset> {
+        if params.data.len() > MAX_ASSET_SIZE {
+            return Err(DocError::asset_too_large());
+        }
+
+        let mut table = DocAssetTable::new(
+            &params.doc_id,
+            &params.name,
+            &params.mime_type,
+            params.data.len() as i64,
+            "",
+        );
+        table.blob_hash = self.blobs.write(params.data.as_slice(), &table.id)?.hash;
+        self.create_asset(table)
+    }
+
+    pub(crate) fn read(&self, asset_id: &str) -> DocResult<AssetData> {
+        let table = self.asset_table(asset_id)?;
+        let data = match table.blob_hash.is_empty() {
+            true => std::fs::read(self.data_path(&table)?).map_err(|e| DocError::asset_not_found().context(e))?,
+            false => self
+                .blobs
+                .read(&table.blob_hash)
+                .map_err(|e| DocError::asset_not_found().context(e))?,
+        };
+        let asset = self.asset_from_table(table)?;
+        Ok(AssetData { asset, data })
+    }
+
+    pub(crate) fn asset_table(&self, asset_id: &str) -> DocResult<DocAssetTable> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        self.sql
+            .read_asset_table(asset_id, conn)
+            .map_err(|e| match e.is_record_not_found() {
+                true => DocError::asset_not_found(),
+                false => e,
+            })
+    }
+
+    // The file that keeps the data of the asset.
+    pub(crate) fn data_path(&self, table: &DocAssetTable) -> DocResult<PathBuf> {
+        match table.blob_hash.is_empty() {
+            true => self.asset_path(&table.id),
+            false => self.blobs.blob_path(&table.blob_hash),
+        }
+    }
+
+    // Keeps the downloaded data of the asset in the blob store. The data of an
+    // asset doesn't change, so it's rejected if it isn't the blob of the asset.
+    pub(crate) fn write_data<R: Read>(&self, table: &DocAssetTable, reader: R) -> DocResult<()> {
+        let blob = self.blobs.write(reader, &table.id)?;
+        if blob.hash == table.blob_hash {
+            return Ok(());
+        }
+        if !table.blob_hash.is_empty() || blob.size != table.size {
+            let _ = self.blobs.release(&blob.hash, &table.id)?;
+            return Err(DocError::asset_transfer_failed().context("The downloaded data isn't the data of the asset"));
+        }
+
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        let _ = self.sql.update_blob_hash(&table.id, &blob.hash, conn)?;
+        let path = self.asset_path(&table.id)?;
+        if path.exists() {
+            let _ = std::fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn assets(&self, doc_id: &str) -> DocResult<Vec<DocAsset>> {
+        self.asset_tables(doc_id)?
+            .into_iter()
+            .map(|table| self.asset_from_table(table))
+            .collect()
+    }
+
+    // Removes the assets of the document that the delta doesn't refer to, returns
+    // how many of them were removed.
+    pub(crate) fn collect_garbage(&self, doc_id: &str, delta: &Delta) -> DocResult<usize> {
+        let referenced = referenced_assets(delta);
+        let unreferenced = self
+            .asset_tables(doc_id)?
+            .into_iter()
+            .filter(|asset| !referenced.contains(&asset.id))
+            .collect::<Vec<_>>();
+        for asset in &unreferenced {
+            let _ = self.remove(asset)?;
+        }
+
+        if !unreferenced.is_empty() {
+            tracing::debug!("Remove {} unreferenced assets of {}", unreferenced.len(), doc_id);
+        }
+        Ok(unreferenced.len())
+    }
+
+    // Copies the assets of the document that the delta refers to into the other
+    // document, returns the delta with the links pointing to the copies. The
+    // copies refer to the blobs of the assets, the data isn't copied.
+    pub(crate) fn duplicate(&self, doc_id: &str, new_doc_id: &str, delta: &Delta) -> DocResult<Delta> {
+        let referenced = referenced_assets(delta);
+        let mut asset_ids = HashMap::new();
+        for asset in self.asset_tables(doc_id)? {
+            if !referenced.contains(&asset.id) {
+                continue;
+            }
+
+            let mut table = DocAssetTable::new(new_doc_id, &asset.name, &asset.mime_type, asset.size, "");
+            table.blob_hash = match asset.blob_hash.is_empty() {
+                true => {
+                    let file = std::fs::File::open(self.asset_path(&asset.id)?)
+                        .map_err(|e| DocError::asset_not_found().context(e))?;
+                    self.blobs.write(file, &table.id)?.hash
+                },
+                false => {
+                    let _ = self.blobs.retain(&asset.blob_hash, &table.id)?;
+                    asset.blob_hash.clone()
+                },
+            };
+            let new_asset_id = table.id.clone();
+            let _ = self.create_asset(table)?;
+            asset_ids.insert(asset.id, new_asset_id);
+        }
+        Ok(replace_asset_links(delta, &asset_ids))
+    }
+
+    // The assets are deleted in the transaction of the caller, their files are
+    // added to the files.
+    pub(crate) fn delete_all(&self, doc_id: &str, conn: &SqliteConnection, files: &mut DeletedFiles) -> DocResult<()> {
+        let user_dir = self.user.user_dir()?;
+        for asset in self.sql.read_asset_tables(doc_id, conn)? {
+            let _ = self.sql.delete_asset_table(&asset.id, conn)?;
+            let _ = AssetTransferTableSql {}.delete_transfer_table(&asset.id, conn)?;
+            files.paths.push(transfer_path(&user_dir, &asset.id)?);
+            if asset.blob_hash.is_empty() {
+                files.paths.push(self.asset_path(&asset.id)?);
+            } else {
+                let _ = self.blobs.release_with(&asset.blob_hash, &asset.id, conn, files)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn doc_ids(&self) -> DocResult<Vec<String>> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        self.sql.read_doc_ids(conn)
+    }
+
+    // Removes the files of the assets directory that aren't the data of any asset,
+    // and the blobs that aren't referred to. Returns how many files and bytes were
+    // removed.
+    pub(crate) fn remove_unreferenced_files(&self) -> DocResult<(usize, u64)> {
+        let (blob_count, blob_size) = self.blobs.collect_garbage()?;
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        let asset_ids = self.sql.read_asset_ids(conn)?.into_iter().collect::<HashSet<String>>();
+        let dir = PathBuf::from(self.user.user_dir()?).join("assets");
+        if !dir.exists() {
+            return Ok((blob_count, blob_size));
+        }
+
+        let (mut count, mut size) = (blob_count, blob_size);
+        for entry in std::fs::read_dir(&dir)? {
+            let entry = entry?;
+            if asset_ids.contains(entry.file_name().to_string_lossy().as_ref()) {
+                continue;
+            }
+
+            let len = entry.metadata()?.len();
+            match std::fs::remove_file(entry.path()) {
+                Ok(_) => {
+                    count += 1;
+                    size += len;
+                },
+                Err(e) => tracing::warn!("Remove the unreferenced file {:?} failed: {:?}", entry.path(), e),
+            }
+        }
+        Ok((count, size))
+    }
+}
+
+impl AssetStore {
+    fn create_asset(&self, table: DocAssetTable) -> DocResult<DocAsset> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        let asset = self.asset_from_table(table.clone())?;
+        if let Err(e) = self.sql.create_asset_table(table.clone(), conn) {
+            let _ = self.blobs.release(&table.blob_hash, &table.id);
+            return Err(e);
+        }
+        Ok(asset)
+    }
+
+    fn asset_tables(&self, doc_id: &str) -> DocResult<Vec<DocAssetTable>> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        self.sql.read_asset_tables(doc_id, conn)
+    }
+
+    fn remove(&self, asset: &DocAssetTable) -> DocResult<()> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        let _ = self.sql.delete_asset_table(&asset.id, conn)?;
+        let _ = AssetTransferTableSql {}.delete_transfer_table(&asset.id, conn)?;
+        let _ = remove_transfer_file(&self.user.user_dir()?, &asset.id);
+        if !asset.blob_hash.is_empty() {
+            return self.blobs.release(&asset.blob_hash, &asset.id);
+        }
+        if let Err(e) = std::fs::remove_file(self.asset_path(&asset.id)?) {
+            tracing::warn!("Remove the file of asset {} failed: {:?}", asset.id, e);
+        }
+        Ok(())
+    }
+
+    fn asset_path(&self, asset_id: &str) -> DocResult<PathBuf> {
+        let dir = PathBuf::from(self.user.user_dir()?).join("assets");
+        if !dir.exists() {
+            let _ = std::fs::create_dir_all(&dir)?;
+        }
+        Ok(dir.join(asset_id))
+    }
+
+    fn asset_from_table(&self, table: DocAssetTable) -> DocResult<DocAsset> {
+        let path = self.data_path(&table)?;
+        Ok(DocAsset {
+            url: asset_url(&table.id),
+            path: path.to_string_lossy().to_string(),
+            id: table.id,
+            doc_id: table.doc_id,
+            name: table.name,
+            mime_type: table.mime_type,
+            size: table.size,
+        })
+    }
+}
@@ -0,0 +1,283 @@
+use crate::{
+    entities::asset::{AssetChunk, AssetChunkRequest, AssetTransfer, AssetTransferDirection},
+    errors::{internal_error, DocError, DocResult, ErrorCode},
+    module::DocumentUser,
+    notify::{dart_notify, DocObservable},
+    services::{asset::AssetStore, server::Server},
+    sql_tables::{AssetTransferTable, AssetTransferTableSql, DocAssetTable},
+};
+use parking_lot::Mutex;
+use std::{
+    collections::HashSet,
+    fs::{File, OpenOptions},
+    io::{Read, Seek, SeekFrom, Write},
+    path::PathBuf,
+    sync::Arc,
+};
+
+pub const ASSET_CHUNK_SIZE: usize = 1024 * 1024;
+
+// The partial file of the download, it's moved into the blob store once the
+// download is done. The directory is out of the blobs one, so its garbage
+// collection leaves the interrupted downloads alone.
+pub(crate) fn transfer_path(user_dir: &str, asset_id: &str) -> DocResult<PathBuf> {
+    let dir = PathBuf::from(user_dir).join("transfers");
+    if !dir.exists() {
+        let _ = std::fs::create_dir_all(&dir)?;
+    }
+    Ok(dir.join(asset_id))
+}
+
+pub(crate) fn remove_transfer_file(user_dir: &str, asset_id: &str) -> DocResult<()> {
+    let path = transfer_path(user_dir, asset_id)?;
+    if path.exists() {
+        let _ = std::fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+// Uploads and downloads the data of the assets one chunk at a time. The offset
+// is saved after each chunk, so the transfer that was interrupted resumes from
+// the last chunk instead of the start. The progress is sent with the
+// AssetTransferProgress notification, the id is the id of the asset.
+pub(crate) struct AssetTransfers {
+    user: Arc<dyn DocumentUser>,
+    server: Server,
+    assets: Arc<AssetStore>,
+    sql: AssetTransferTableSql,
+    running: Mutex<HashSet<String>>,
+}
+
+impl AssetTransfers {
+    pub(crate) fn new(user: Arc<dyn DocumentUser>, server: Server, assets: Arc<AssetStore>) -> Self {
+        Self {
+            user,
+            server,
+            assets,
+            sql: AssetTransferTableSql {},
+            running: Mutex::new(HashSet::new()),
+        }
+    }
+
+    pub(crate) async fn upload(&self, asset_id: &str) -> DocResult<AssetTransfer> {
+        let _running = self.start(asset_id)?;
+        let asset = self.asset_table(asset_id)?;
+        let mut progress = self.resume(&asset, AssetTransferDirection::Upload)?;
+        let result = self.send_chunks(&asset, &mut progress).await;
+        self.finish(progress, result)
+    }
+
+    pub(crate) async fn download(&self, asset_id: &str) -> DocResult<AssetTransfer> {
+        let _running = self.start(asset_id)?;
+        let asset = self.asset_table(asset_id)?;
+        let mut progress = self.resume(&asset, AssetTransferDirection::Download)?;
+        let result = self.receive_chunks(&asset, &mut progress).await;
+        self.finish(progress, result)
+    }
+
+    pub(crate) fn running_count(&self) -> usize { self.running.lock().len() }
+
+    // The transfers that were interrupted, the oldest comes first.
+    pub(crate) fn transfers(&self) -> DocResult<Vec<AssetTransfer>> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        let transfers = self
+            .sql
+            .read_transfer_tables(conn)?
+            .into_iter()
+            .map(|table| AssetTransfer {
+                asset_id: table.asset_id,
+                direction: table.direction.into(),
+                transferred: table.transferred,
+                total: table.total,
+                ..Default::default()
+            })
+            .collect();
+        Ok(transfers)
+    }
+
+    // Called when the network is back. The transfers that are running already
+    // are left alone.
+    pub(crate) async fn resume_all(&self) {
+        let transfers = match self.transfers() {
+            Ok(transfers) => transfers,
+            Err(e) => {
+                tracing::error!("Read the asset transfers failed: {:?}", e);
+                return;
+            },
+        };
+        for transfer in transfers {
+            if self.running.lock().contains(&transfer.asset_id) {
+                continue;
+            }
+            let result = match transfer.direction {
+                AssetTransferDirection::Upload => self.upload(&transfer.asset_id).await,
+                AssetTransferDirection::Download => self.download(&transfer.asset_id).await,
+            };
+            if let Err(e) = result {
+                tracing::warn!("Resume the transfer of asset {} failed: {:?}", transfer.asset_id, e);
+            }
+        }
+    }
+}
+
+impl AssetTransfers {
+    fn start(&self, asset_id: &str) -> DocResult<RunningTransfer> {
+        if !self.running.lock().insert(asset_id.to_owned()) {
+            return Err(
+                DocError::asset_transfer_failed().context(format!("Asset {} is transferring already", asset_id))
+            );
+        }
+        Ok(RunningTransfer {
+            running: &self.running,
+            asset_id: asset_id.to_owned(),
+        })
+    }
+
+    // The transfer state of an asset that was removed meanwhile is dropped.
+    fn asset_table(&self, asset_id: &str) -> DocResult<DocAssetTable> {
+        self.assets.asset_table(asset_id).map_err(|e| {
+            if e.code == ErrorCode::AssetNotFound {
+                let _ = self.delete_state(asset_id);
+            }
+            e
+        })
+    }
+
+    // The saved offset is only used by the transfer in the same direction, of
+    // the same data.
+    fn resume(&self, asset: &DocAssetTable, direction: AssetTransferDirection) -> DocResult<AssetTransfer> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        let transferred = match self.sql.read_transfer_table(&asset.id, conn)? {
+            Some(table) if AssetTransferDirection::from(table.direction) == direction && table.total == asset.size => {
+                table.transferred.max(0).min(asset.size)
+            },
+            _ => 0,
+        };
+        Ok(AssetTransfer {
+            asset_id: asset.id.clone(),
+            direction,
+            transferred,
+            total: asset.size,
+            resumed_from: transferred,
+            error: "".to_owned(),
+        })
+    }
+
+    async fn send_chunks(&self, asset: &DocAssetTable, progress: &mut AssetTransfer) -> DocResult<()> {
+        let mut file = File::open(self.assets.data_path(asset)?).map_err(|e| DocError::asset_not_found().context(e))?;
+        let _ = file.seek(SeekFrom::Start(progress.transferred as u64))?;
+        let token = self.user.token()?;
+        let _ = self.save_state(progress)?;
+        self.notify(progress);
+
+        while progress.transferred < progress.total {
+            let size = ASSET_CHUNK_SIZE.min((progress.total - progress.transferred) as usize);
+            let mut data = vec![0; size];
+            let _ = file.read_exact(&mut data)?;
+            let chunk = AssetChunk {
+                asset_id: progress.asset_id.clone(),
+                offset: progress.transferred,
+                total_size: progress.total,
+                data,
+            };
+            let _ = self.server.upload_asset_chunk(&token, chunk).await?;
+            progress.transferred += size as i64;
+            let _ = self.save_state(progress)?;
+            self.notify(progress);
+        }
+        Ok(())
+    }
+
+    async fn receive_chunks(&self, asset: &DocAssetTable, progress: &mut AssetTransfer) -> DocResult<()> {
+        let path = transfer_path(&self.user.user_dir()?, &asset.id)?;
+        let mut file = OpenOptions::new().create(true).write(true).open(&path)?;
+        // The bytes after the saved offset may be from a chunk that wasn't written
+        // completely, they're downloaded again.
+        let len = file.metadata()?.len() as i64;
+        progress.transferred = progress.transferred.min(len);
+        progress.resumed_from = progress.transferred;
+        let _ = file.set_len(progress.transferred as u64)?;
+        let _ = file.seek(SeekFrom::End(0))?;
+        let token = self.user.token()?;
+        let _ = self.save_state(progress)?;
+        self.notify(progress);
+
+        while progress.transferred < progress.total {
+            let size = ASSET_CHUNK_SIZE.min((progress.total - progress.transferred) as usize);
+            let request = AssetChunkRequest {
+                asset_id: progress.asset_id.clone(),
+                offset: progress.transferred,
+                size: size as i64,
+            };
+            let mut chunk = self.server.download_asset_chunk(&token, request).await?;
+            if chunk.data.is_empty() || chunk.offset != progress.transferred {
+                return Err(DocError::asset_transfer_failed()
+                    .context(format!("The server returned no data at {}", progress.transferred)));
+            }
+            chunk.data.truncate(size);
+            let _ = file.write_all(&chunk.data)?;
+            // The saved offset can't be ahead of the file.
+            let _ = file.sync_data()?;
+            progress.transferred += chunk.data.len() as i64;
+            let _ = self.save_state(progress)?;
+            self.notify(progress);
+        }
+
+        let _ = self.assets.write_data(asset, File::open(&path)?)?;
+        let _ = std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    // The state is kept if the transfer failed, it's resumed the next time.
+    fn finish(&self, mut progress: AssetTransfer, result: DocResult<()>) -> DocResult<AssetTransfer> {
+        match result {
+            Ok(_) => {
+                let _ = self.delete_state(&progress.asset_id)?;
+                self.notify(&progress);
+                Ok(progress)
+            },
+            Err(e) => {
+                tracing::error!("Transfer asset {} failed: {:?}", progress.asset_id, e);
+                progress.error = e.msg.clone();
+                self.notify(&progress);
+                Err(e)
+            },
+        }
+    }
+
+    fn save_state(&self, progress: &AssetTransfer) -> DocResult<()> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        let table = AssetTransferTable::new(
+            &progress.asset_id,
+            progress.direction.into(),
+            progress.transferred,
+            progress.total,
+        );
+        self.sql.save_transfer_table(table, conn)
+    }
+
+    fn delete_state(&self, asset_id: &str) -> DocResult<()> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        self.sql.delete_transfer_table(asset_id, conn)
+    }
+
+    fn notify(&self, progress: &AssetTransfer) {
+        dart_notify(&progress.asset_id, DocObservable::AssetTransferProgress)
+            .payload(progress.clone())
+            .send();
+    }
+}
+
+// Only one transfer of an asset runs at a time.
+struct RunningTransfer<'a> {
+    running: &'a Mutex<HashSet<String>>,
+    asset_id: String,
+}
+
+impl<'a> Drop for RunningTransfer<'a> {
+    fn drop(&mut self) { self.running.lock().remove(&self.asset_id); }
+}
@@ -0,0 +1,256 @@
+mod thumbnail;
+
+pub use thumbnail::*;
+
+use crate::{
+    entities::attachment::{AttachmentUsage, DocAttachment, ImportAttachmentRequest},
+    errors::{internal_error, DocError, DocResult},
+    module::DocumentUser,
+    services::blob::{Blob, BlobStore, DeletedFiles},
+    sql_tables::{DocAttachmentTable, DocAttachmentTableSql},
+};
+use flowy_database::SqliteConnection;
+use parking_lot::Mutex;
+use std::{
+    fs::File,
+    path::Path,
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        Arc,
+    },
+};
+
+pub const DEFAULT_ATTACHMENT_QUOTA: i64 = 1024 * 1024 * 1024;
+
+// The thumbnail is a blob of its own, it's referred to with this owner so that
+// releasing it doesn't release the content when they're the same blob.
+fn thumbnail_owner(attachment_id: &str) -> String { format!("{}/thumbnail", attachment_id) }
+
+// The files imported into the documents. Their content and their thumbnails are
+// kept in the blob store, so the files with the same content are stored once,
+// and they're counted once against the quota of the user.
+pub(crate) struct AttachmentStore {
+    user: Arc<dyn DocumentUser>,
+    blobs: Arc<BlobStore>,
+    sql: DocAttachmentTableSql,
+    quota: AtomicI64,
+    // Two imports could both fit in the quota that only one of them fits in.
+    import_lock: Mutex<()>,
+}
+
+impl AttachmentStore {
+    pub(crate) fn new(user: Arc<dyn DocumentUser>, blobs: Arc<BlobStore>, quota: i64) -> Self {
+        Self {
+            user,
+            blobs,
+            sql: DocAttachmentTableSql {},
+            quota: AtomicI64::new(quota),
+            import_lock: Mutex::new(()),
+        }
+    }
+
+    pub(crate) fn set_quota(&self, quota: i64) { self.quota.store(quota, Ordering::SeqCst); }
+
+    // The file is copied into the blob store as it's hashed, it's rejected if its
+    // content and thumbnail don't fit in what's left of the quota.
+    pub(crate) fn import(&self, params: ImportAttachmentRequest) -> DocResult<DocAttachment> {
+        let path = Path::new(&params.path);
+        let file = File::open(path).map_err(|e| DocError::attachment_file_error().context(e))?;
+        if !file.metadata()?.is_file() {
+            return Err(DocError::attachment_file_error().context(format!("{} isn't a file", params.path)));
+        }
+        let name = match params.name.trim().is_empty() {
+            true => path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            false => params.name.clone(),
+        };
+
+        let mut table = DocAttachmentTable::new(&params.doc_id, &name, &params.mime_type);
+        let _guard = self.import_lock.lock();
+        let blob = self.blobs.write(file, &table.id)?;
+        let thumbnail = match blob.size as usize <= MAX_THUMBNAIL_SOURCE_SIZE {
+            true => self.blobs.read(&blob.hash).ok().and_then(|data| make_thumbnail(&data)),
+            false => None,
+        };
+        let thumbnail_blob = match &thumbnail {
+            None => None,
+            Some(thumbnail) => match self.blobs.write(thumbnail.data.as_slice(), &thumbnail_owner(&table.id)) {
+                Ok(thumbnail_blob) => Some(thumbnail_blob),
+                Err(e) => {
+                    self.release_blobs(&table.id, &blob.hash, "");
+                    return Err(e);
+                },
+            },
+        };
+        let thumbnail_hash = thumbnail_blob
+            .as_ref()
+            .map(|blob| blob.hash.clone())
+            .unwrap_or_default();
+        if let Err(e) = self.check_quota(&blob, thumbnail_blob.as_ref()) {
+            self.release_blobs(&table.id, &blob.hash, &thumbnail_hash);
+            return Err(e);
+        }
+
+        table.size = blob.size;
+        table.blob_hash = blob.hash.clone();
+        table.thumbnail_hash = thumbnail_hash;
+        if let Some(thumbnail) = thumbnail {
+            table.width = thumbnail.width as i32;
+            table.height = thumbnail.height as i32;
+            if table.mime_type.is_empty() {
+                table.mime_type = thumbnail.mime_type.to_owned();
+            }
+        }
+        if table.mime_type.is_empty() {
+            table.mime_type = DEFAULT_MIME_TYPE.to_owned();
+        }
+
+        let attachment = self.attachment_from_table(table.clone())?;
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        if let Err(e) = self.sql.create_attachment_table(table.clone(), conn) {
+            self.release_blobs(&table.id, &table.blob_hash, &table.thumbnail_hash);
+            return Err(e);
+        }
+        Ok(attachment)
+    }
+
+    pub(crate) fn attachments(&self, doc_id: &str) -> DocResult<Vec<DocAttachment>> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        self.sql
+            .read_attachment_tables(doc_id, conn)?
+            .into_iter()
+            .map(|table| self.attachment_from_table(table))
+            .collect()
+    }
+
+    pub(crate) fn delete(&self, attachment_id: &str) -> DocResult<()> {
+        let table = {
+            let pool = self.user.db_pool()?;
+            let conn = &*pool.get().map_err(internal_error)?;
+            self.sql
+                .read_attachment_table(attachment_id, conn)
+                .map_err(|e| match e.is_record_not_found() {
+                    true => DocError::attachment_not_found(),
+                    false => e,
+                })?
+        };
+        self.remove(&table)
+    }
+
+    // The attachments are deleted in the transaction of the caller, the blobs
+    // that aren't referred to anymore are added to the files.
+    pub(crate) fn delete_all(&self, doc_id: &str, conn: &SqliteConnection, files: &mut DeletedFiles) -> DocResult<()> {
+        let tables = self.sql.read_attachment_tables(doc_id, conn)?;
+        for table in &tables {
+            let _ = self.sql.delete_attachment_table(&table.id, conn)?;
+            let _ = self.blobs.release_with(&table.blob_hash, &table.id, conn, files)?;
+            if !table.thumbnail_hash.is_empty() {
+                let owner_id = thumbnail_owner(&table.id);
+                let _ = self.blobs.release_with(&table.thumbnail_hash, &owner_id, conn, files)?;
+            }
+        }
+
+        if !tables.is_empty() {
+            tracing::debug!("Remove {} attachments of {}", tables.len(), doc_id);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn usage(&self) -> DocResult<AttachmentUsage> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        let used = self
+            .sql
+            .read_blob_sizes(conn)?
+            .into_iter()
+            .map(|(_, size)| size)
+            .sum::<i64>();
+        let count = self.sql.count_attachments(conn)?;
+        Ok(AttachmentUsage {
+            used,
+            quota: self.quota.load(Ordering::SeqCst),
+            count,
+        })
+    }
+
+    pub(crate) fn doc_ids(&self) -> DocResult<Vec<String>> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        self.sql.read_doc_ids(conn)
+    }
+}
+
+impl AttachmentStore {
+    // The blobs that other attachments already refer to don't use more storage.
+    fn check_quota(&self, blob: &Blob, thumbnail_blob: Option<&Blob>) -> DocResult<()> {
+        let usage = self.usage()?;
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        let mut required = 0;
+        for blob in std::iter::once(blob).chain(thumbnail_blob) {
+            if !self.sql.is_blob_attached(&blob.hash, conn)? {
+                required += blob.size;
+            }
+        }
+        if required > usage.available() {
+            return Err(DocError::attachment_over_quota().context(format!(
+                "{} bytes are required, {} of {} bytes are used",
+                required, usage.used, usage.quota
+            )));
+        }
+        Ok(())
+    }
+
+    fn remove(&self, table: &DocAttachmentTable) -> DocResult<()> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        let _ = self.sql.delete_attachment_table(&table.id, conn)?;
+        let _ = self.blobs.release(&table.blob_hash, &table.id)?;
+        if !table.thumbnail_hash.is_empty() {
+            let _ = self.blobs.release(&table.thumbnail_hash, &thumbnail_owner(&table.id))?;
+        }
+        Ok(())
+    }
+
+    // Undoes the writes of an import that failed.
+    fn release_blobs(&self, attachment_id: &str, blob_hash: &str, thumbnail_hash: &str) {
+        if let Err(e) = self.blobs.release(blob_hash, attachment_id) {
+            tracing::warn!("Release the blob of attachment {} failed: {:?}", attachment_id, e);
+        }
+        if thumbnail_hash.is_empty() {
+            return;
+        }
+        if let Err(e) = self.blobs.release(thumbnail_hash, &thumbnail_owner(attachment_id)) {
+            tracing::warn!("Release the thumbnail of attachment {} failed: {:?}", attachment_id, e);
+        }
+    }
+
+    fn attachment_from_table(&self, table: DocAttachmentTable) -> DocResult<DocAttachment> {
+        let path = self.blobs.blob_path(&table.blob_hash)?;
+        let thumbnail_path = match table.thumbnail_hash.is_empty() {
+            true => "".to_owned(),
+            false => self
+                .blobs
+                .blob_path(&table.thumbnail_hash)?
+                .to_string_lossy()
+                .to_string(),
+        };
+        Ok(DocAttachment {
+            path: path.to_string_lossy().to_string(),
+            thumbnail_path,
+            id: table.id,
+            doc_id: table.doc_id,
+            name: table.name,
+            mime_type: table.mime_type,
+            size: table.size,
+            hash: table.blob_hash,
+            width: table.width,
+            height: table.height,
+            create_time: table.create_time,
+        })
+    }
+}
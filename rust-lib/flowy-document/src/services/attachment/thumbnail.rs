@@ -0,0 +1,58 @@
+use image::{GenericImageView, ImageFormat, ImageOutputFormat};
+
+// The thumbnails fit in a square of this size, they're PNG images.
+pub const THUMBNAIL_SIZE: u32 = 256;
+
+// The larger images are attached without a thumbnail, decoding them would take
+// too much memory.
+pub const MAX_THUMBNAIL_SOURCE_SIZE: usize = 32 * 1024 * 1024;
+
+pub const DEFAULT_MIME_TYPE: &str = "application/octet-stream";
+
+pub(crate) struct Thumbnail {
+    pub(crate) data: Vec<u8>,
+    pub(crate) mime_type: &'static str,
+    // The size of the image, not of the thumbnail.
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+}
+
+// Returns None if the data isn't an image that can be decoded. The images that
+// are smaller than the thumbnail aren't scaled up.
+pub(crate) fn make_thumbnail(data: &[u8]) -> Option<Thumbnail> {
+    let format = image::guess_format(data).ok()?;
+    let image = match image::load_from_memory_with_format(data, format) {
+        Ok(image) => image,
+        Err(e) => {
+            tracing::debug!("The {:?} image can't be decoded: {:?}", format, e);
+            return None;
+        },
+    };
+
+    let (width, height) = image.dimensions();
+    let thumbnail = match width > THUMBNAIL_SIZE || height > THUMBNAIL_SIZE {
+        true => image.thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE),
+        false => image,
+    };
+    let mut data = vec![];
+    if let Err(e) = thumbnail.write_to(&mut data, ImageOutputFormat::Png) {
+        tracing::warn!("Encode the thumbnail failed: {:?}", e);
+        return None;
+    }
+    Some(Thumbnail {
+        data,
+        mime_type: mime_type(format),
+        width,
+        height,
+    })
+}
+
+fn mime_type(format: ImageFormat) -> &'static str {
+    match format {
+        ImageFormat::Png => "image/png",
+        ImageFormat::Jpeg => "image/jpeg",
+        ImageFormat::Gif => "image/gif",
+        ImageFormat::Bmp => "image/bmp",
+        _ => DEFAULT_MIME_TYPE,
+    }
+}
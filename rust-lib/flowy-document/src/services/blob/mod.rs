@@ -0,0 +1,380 @@
+use crate::{
+    errors::{internal_error, DocError, DocResult},
+    module::DocumentUser,
+    sql_tables::{BlobRefTable, BlobTable, BlobTableSql},
+};
+use bytes::Bytes;
+use dashmap::DashMap;
+use flowy_database::SqliteConnection;
+use flowy_infra::memory::{CacheMemory, Evict, MemoryBudget};
+use parking_lot::Mutex;
+use sha2::{Digest, Sha256};
+use std::{
+    collections::HashSet,
+    fs::File,
+    io,
+    io::{Read, Write},
+    path::PathBuf,
+    sync::Arc,
+};
+
+// The larger blobs are read from their file each time.
+pub const MAX_CACHED_BLOB_SIZE: usize = 1024 * 1024;
+
+// The files of the records deleted in a transaction, they're removed once it
+// was committed, see BlobStore::remove_files.
+#[derive(Debug, Default)]
+pub struct DeletedFiles {
+    pub(crate) paths: Vec<PathBuf>,
+    pub(crate) blobs: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Blob {
+    // The hex sha256 of the content.
+    pub hash: String,
+    pub size: i64,
+    pub path: String,
+}
+
+// The large data, like the data of the assets, is kept out of the database in
+// the blobs directory of the user, one file per content named after its hash,
+// so the same content is only stored once. The owners refer to the blobs they
+// use, and a blob is removed once no owner refers to it anymore.
+pub struct BlobStore {
+    user: Arc<dyn DocumentUser>,
+    sql: BlobTableSql,
+    // The blob written by one writer could be removed by the release of
+    // another one between the file being renamed and the reference being added.
+    lock: Mutex<()>,
+    cache: Arc<BlobCache>,
+    memory: CacheMemory,
+}
+
+impl BlobStore {
+    pub(crate) fn new(user: Arc<dyn DocumentUser>, memory_budget: &Arc<MemoryBudget>) -> Self {
+        let cache = Arc::new(BlobCache::default());
+        let memory = memory_budget.register("blobs", cache.clone());
+        Self {
+            user,
+            sql: BlobTableSql {},
+            lock: Mutex::new(()),
+            cache,
+            memory,
+        }
+    }
+
+    // The content is written to a temporary file as it's hashed, call finish
+    // to store it. It's discarded if the writer is dropped before.
+    pub fn writer(&self) -> DocResult<BlobWriter<'_>> {
+        let temp_path = self.blob_dir()?.join(format!(".{}", flowy_infra::uuid()));
+        let file = File::create(&temp_path)?;
+        Ok(BlobWriter {
+            store: self,
+            file,
+            temp_path: Some(temp_path),
+            hasher: Sha256::new(),
+            size: 0,
+        })
+    }
+
+    // Writes the content of the reader and refers to it with the owner.
+    pub fn write<R: Read>(&self, mut reader: R, owner_id: &str) -> DocResult<Blob> {
+        let mut writer = self.writer()?;
+        let _ = io::copy(&mut reader, &mut writer)?;
+        writer.finish(owner_id)
+    }
+
+    pub fn open(&self, hash: &str) -> DocResult<File> {
+        let blob = self.blob(hash)?;
+        File::open(&blob.path).map_err(|e| DocError::blob_not_found().context(e))
+    }
+
+    // The content of a blob doesn't change, the small ones are kept in memory
+    // once they were read.
+    pub fn read(&self, hash: &str) -> DocResult<Vec<u8>> {
+        if let Some(data) = self.cache.blobs.get(hash) {
+            self.memory.touch(hash);
+            return Ok(data.to_vec());
+        }
+        let mut data = vec![];
+        let _ = self.open(hash)?.read_to_end(&mut data)?;
+        if data.len() <= MAX_CACHED_BLOB_SIZE {
+            self.cache.blobs.insert(hash.to_owned(), Bytes::copy_from_slice(&data));
+            self.memory.charge(hash, data.len());
+        }
+        Ok(data)
+    }
+
+    pub fn blob(&self, hash: &str) -> DocResult<Blob> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        match self.sql.read_blob_table(hash, conn)? {
+            None => Err(DocError::blob_not_found()),
+            Some(table) => Ok(Blob {
+                path: self.blob_path(&table.hash)?.to_string_lossy().to_string(),
+                hash: table.hash,
+                size: table.size,
+            }),
+        }
+    }
+
+    // Refers to the stored blob with another owner.
+    pub fn retain(&self, hash: &str, owner_id: &str) -> DocResult<()> {
+        let _guard = self.lock.lock();
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        conn.immediate_transaction::<_, DocError, _>(|| {
+            if self.sql.read_blob_table(hash, conn)?.is_none() {
+                return Err(DocError::blob_not_found());
+            }
+            self.sql.create_ref_table(BlobRefTable::new(hash, owner_id), conn)
+        })
+    }
+
+    pub fn ref_count(&self, hash: &str) -> DocResult<i64> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        self.sql.read_ref_count(hash, conn)
+    }
+
+    // Removes the reference of the owner, the blob is removed with its last one.
+    pub fn release(&self, hash: &str, owner_id: &str) -> DocResult<()> {
+        let _guard = self.lock.lock();
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let mut files = DeletedFiles::default();
+        let _ = conn.immediate_transaction::<_, DocError, _>(|| self.release_with(hash, owner_id, conn, &mut files))?;
+        for hash in files.blobs {
+            self.remove_file(&hash);
+        }
+        Ok(())
+    }
+
+    // Same as release, but the reference is removed in the transaction of the
+    // caller. The blob that isn't referred to anymore is added to the files.
+    pub(crate) fn release_with(
+        &self,
+        hash: &str,
+        owner_id: &str,
+        conn: &SqliteConnection,
+        files: &mut DeletedFiles,
+    ) -> DocResult<()> {
+        let _ = self.sql.delete_ref_table(hash, owner_id, conn)?;
+        if self.sql.read_ref_count(hash, conn)? == 0 {
+            let _ = self.sql.delete_blob_table(hash, conn)?;
+            files.blobs.push(hash.to_owned());
+        }
+        Ok(())
+    }
+
+    // The blobs are kept if they were stored again since they were released.
+    pub(crate) fn remove_files(&self, files: DeletedFiles) {
+        let _guard = self.lock.lock();
+        for path in files.paths.iter().filter(|path| path.exists()) {
+            if let Err(e) = std::fs::remove_file(path) {
+                tracing::warn!("Remove the file {:?} failed: {:?}", path, e);
+            }
+        }
+        for hash in files.blobs {
+            if let Ok(0) = self.ref_count(&hash) {
+                self.remove_file(&hash);
+            }
+        }
+    }
+
+    // Removes the references of the owner, e.g. when the document is purged.
+    pub fn release_all(&self, owner_id: &str) -> DocResult<()> {
+        let hashes = {
+            let pool = self.user.db_pool()?;
+            let conn = &*pool.get().map_err(internal_error)?;
+            self.sql.read_owned_hashes(owner_id, conn)?
+        };
+        for hash in hashes {
+            let _ = self.release(&hash, owner_id)?;
+        }
+        Ok(())
+    }
+
+    // Removes the blobs that no owner refers to, and the files of the blobs
+    // directory that aren't the content of any blob, like the ones of the writers
+    // that didn't finish. Returns how many files and bytes were removed.
+    pub fn collect_garbage(&self) -> DocResult<(usize, u64)> {
+        let _guard = self.lock.lock();
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let (unreferenced, hashes) = conn.immediate_transaction::<_, DocError, _>(|| {
+            let unreferenced = self.sql.read_unreferenced_blobs(conn)?;
+            for (hash, _) in &unreferenced {
+                let _ = self.sql.delete_blob_table(hash, conn)?;
+            }
+            let hashes = self.sql.read_hashes(conn)?.into_iter().collect::<HashSet<String>>();
+            Ok((unreferenced, hashes))
+        })?;
+
+        let (mut count, mut size) = (0, 0);
+        for (hash, _) in &unreferenced {
+            if let Some(len) = self.remove_file(hash) {
+                count += 1;
+                size += len;
+            }
+        }
+        // The writers that are still writing keep their temporary files.
+        let in_progress = flowy_infra::timestamp() - 60 * 60;
+        for entry in std::fs::read_dir(self.blob_dir()?)? {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            let name = entry.file_name().to_string_lossy().to_string();
+            let is_stale = match name.strip_prefix('.') {
+                Some(_) => modified_time(&metadata) < in_progress,
+                None => !hashes.contains(&name),
+            };
+            if !metadata.is_file() || !is_stale {
+                continue;
+            }
+            match std::fs::remove_file(entry.path()) {
+                Ok(_) => {
+                    count += 1;
+                    size += metadata.len();
+                },
+                Err(e) => tracing::warn!("Remove the unreferenced file {:?} failed: {:?}", entry.path(), e),
+            }
+        }
+
+        if count > 0 {
+            tracing::debug!("Remove {} unreferenced blobs, {} bytes", count, size);
+        }
+        Ok((count, size))
+    }
+}
+
+impl BlobStore {
+    pub(crate) fn blob_path(&self, hash: &str) -> DocResult<PathBuf> { Ok(self.blob_dir()?.join(hash)) }
+
+    fn store(&self, temp_path: &PathBuf, hash: &str, size: i64, owner_id: &str) -> DocResult<Blob> {
+        let _guard = self.lock.lock();
+        let path = self.blob_path(hash)?;
+        if path.exists() {
+            let _ = std::fs::remove_file(temp_path);
+        } else {
+            let _ = std::fs::rename(temp_path, &path)?;
+        }
+
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let _ = conn.immediate_transaction::<_, DocError, _>(|| {
+            let _ = self
+                .sql
+                .create_blob_table_if_not_exist(BlobTable::new(hash, size), conn)?;
+            self.sql.create_ref_table(BlobRefTable::new(hash, owner_id), conn)
+        })?;
+        Ok(Blob {
+            hash: hash.to_owned(),
+            size,
+            path: path.to_string_lossy().to_string(),
+        })
+    }
+
+    // Returns the size of the removed file.
+    fn remove_file(&self, hash: &str) -> Option<u64> {
+        if self.cache.blobs.remove(hash).is_some() {
+            self.memory.release(hash);
+        }
+        let path = self.blob_path(hash).ok()?;
+        let len = std::fs::metadata(&path).map(|metadata| metadata.len()).unwrap_or(0);
+        match std::fs::remove_file(&path) {
+            Ok(_) => Some(len),
+            Err(e) => {
+                tracing::warn!("Remove the file of blob {} failed: {:?}", hash, e);
+                None
+            },
+        }
+    }
+
+    // How many blobs are stored on disk and their size.
+    pub(crate) fn disk_usage(&self) -> DocResult<(usize, u64)> {
+        let mut count = 0;
+        let mut bytes = 0;
+        for entry in std::fs::read_dir(self.blob_dir()?)?.flatten() {
+            // The temporary files of the writers start with a dot.
+            if entry.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
+            if let Ok(metadata) = entry.metadata() {
+                count += 1;
+                bytes += metadata.len();
+            }
+        }
+        Ok((count, bytes))
+    }
+
+    fn blob_dir(&self) -> DocResult<PathBuf> {
+        let dir = PathBuf::from(self.user.user_dir()?).join("blobs");
+        if !dir.exists() {
+            let _ = std::fs::create_dir_all(&dir)?;
+        }
+        Ok(dir)
+    }
+}
+
+fn modified_time(metadata: &std::fs::Metadata) -> i64 {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+pub struct BlobWriter<'a> {
+    store: &'a BlobStore,
+    file: File,
+    // None once the content is stored.
+    temp_path: Option<PathBuf>,
+    hasher: Sha256,
+    size: i64,
+}
+
+impl<'a> BlobWriter<'a> {
+    // Stores the written content and refers to it with the owner. The content
+    // that's already stored isn't stored again.
+    pub fn finish(mut self, owner_id: &str) -> DocResult<Blob> {
+        let _ = self.file.flush()?;
+        let _ = self.file.sync_all()?;
+        let hash = format!("{:x}", std::mem::take(&mut self.hasher).finalize());
+        let temp_path = self.temp_path.take().unwrap();
+        let result = self.store.store(&temp_path, &hash, self.size, owner_id);
+        if result.is_err() {
+            let _ = std::fs::remove_file(&temp_path);
+        }
+        result
+    }
+}
+
+impl<'a> Write for BlobWriter<'a> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.file.write(buf)?;
+        self.hasher.update(&buf[..n]);
+        self.size += n as i64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> { self.file.flush() }
+}
+
+impl<'a> Drop for BlobWriter<'a> {
+    fn drop(&mut self) {
+        if let Some(temp_path) = self.temp_path.take() {
+            let _ = std::fs::remove_file(&temp_path);
+        }
+    }
+}
+
+#[derive(Default)]
+struct BlobCache {
+    blobs: DashMap<String, Bytes>,
+}
+
+impl Evict for BlobCache {
+    fn evict(&self, key: &str) { self.blobs.remove(key); }
+}
@@ -14,7 +14,6 @@ pub(crate) struct DocCache {
 impl DocCache {
     pub(crate) fn new() -> Self { Self { inner: DashMap::new() } }
 
-    #[allow(dead_code)]
     pub(crate) fn all_docs(&self) -> Vec<Arc<ClientEditDoc>> {
         self.inner
             .iter()
@@ -30,6 +29,8 @@ impl DocCache {
         self.inner.insert(doc_id, doc);
     }
 
+    pub(crate) fn len(&self) -> usize { self.inner.len() }
+
     pub(crate) fn contains(&self, doc_id: &str) -> bool { self.inner.get(doc_id).is_some() }
 
     pub(crate) fn get(&self, doc_id: &str) -> Result<Arc<ClientEditDoc>, DocError> {
@@ -0,0 +1,68 @@
+use crate::{
+    entities::check::RepeatedTextDiagnostic,
+    notify::{dart_notify, DocObservable},
+    services::{
+        check::{DiagnosticCache, TextChecker},
+        doc::Document,
+    },
+};
+use std::{sync::Arc, time::Duration};
+use tokio::{
+    sync::{Mutex, RwLock},
+    task::JoinHandle,
+};
+
+// Checks the opened document once no change was applied for the quiet period,
+// the changes made in the meantime are checked in one batch. The editor is
+// notified when the diagnostics of the document are changed.
+pub(crate) struct DocumentChecker {
+    doc_id: String,
+    document: Arc<RwLock<Document>>,
+    checker: Arc<dyn TextChecker>,
+    cache: Arc<Mutex<DiagnosticCache>>,
+    quiet_period: Duration,
+    defer_check: RwLock<Option<JoinHandle<()>>>,
+}
+
+impl DocumentChecker {
+    pub(crate) fn new(
+        doc_id: &str,
+        document: Arc<RwLock<Document>>,
+        checker: Arc<dyn TextChecker>,
+        quiet_period: Duration,
+    ) -> Self {
+        Self {
+            doc_id: doc_id.to_owned(),
+            document,
+            checker,
+            cache: Arc::new(Mutex::new(DiagnosticCache::default())),
+            quiet_period,
+            defer_check: RwLock::new(None),
+        }
+    }
+
+    // Restarts the quiet period.
+    pub(crate) async fn schedule(&self) {
+        if let Some(handler) = self.defer_check.write().await.take() {
+            handler.abort();
+        }
+
+        let doc_id = self.doc_id.clone();
+        let document = self.document.clone();
+        let checker = self.checker.clone();
+        let cache = self.cache.clone();
+        let quiet_period = self.quiet_period;
+        *self.defer_check.write().await = Some(tokio::spawn(async move {
+            tokio::time::sleep(quiet_period).await;
+            let delta = document.read().await.delta().clone();
+            if let Some(items) = cache.lock().await.check(checker.as_ref(), &delta) {
+                dart_notify(&doc_id, DocObservable::DiagnosticsChanged)
+                    .payload(RepeatedTextDiagnostic {
+                        doc_id: doc_id.clone(),
+                        items,
+                    })
+                    .send();
+            }
+        }));
+    }
+}
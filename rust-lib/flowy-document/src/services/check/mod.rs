@@ -0,0 +1,212 @@
+mod checker;
+
+pub(crate) use checker::*;
+
+use crate::{
+    entities::check::{DiagnosticKind, TextDiagnostic},
+    errors::{DocError, DocResult},
+};
+use flowy_ot::core::Delta;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+};
+
+// Checks the spelling and the grammar of the texts. The texts are the lines of
+// the documents, they're passed in batches so a checker that calls a service
+// can send them at once. The diagnostics of each text are returned in the same
+// order as the texts, their ranges are in the text.
+pub trait TextChecker: Send + Sync + Debug {
+    fn check(&self, texts: &[String]) -> Vec<Vec<TextDiagnostic>>;
+}
+
+// The checker that's used if no other is given. It doesn't know the words, it
+// only finds the words that are repeated, like "the the".
+#[derive(Debug, Default)]
+pub struct RepeatedWordChecker {}
+
+impl TextChecker for RepeatedWordChecker {
+    fn check(&self, texts: &[String]) -> Vec<Vec<TextDiagnostic>> {
+        texts.iter().map(|text| repeated_words(text)).collect()
+    }
+}
+
+fn repeated_words(text: &str) -> Vec<TextDiagnostic> {
+    let mut diagnostics = vec![];
+    let mut previous: Option<Word> = None;
+    for word in words(text) {
+        if let Some(previous) = &previous {
+            let between = &text[previous.end..word.start];
+            if between.chars().all(|c| c == ' ') && previous.text.to_lowercase() == word.text.to_lowercase() {
+                diagnostics.push(TextDiagnostic {
+                    start: previous.offset as i64,
+                    end: (word.offset + utf16_len(word.text)) as i64,
+                    kind: DiagnosticKind::Grammar,
+                    message: format!("The word \"{}\" is repeated", previous.text),
+                    suggestions: vec![previous.text.to_owned()],
+                });
+            }
+        }
+        previous = Some(word);
+    }
+    diagnostics
+}
+
+// The start and the end are the byte indexes of the word, the offset is its
+// utf16 offset in the text.
+struct Word<'a> {
+    text: &'a str,
+    start: usize,
+    end: usize,
+    offset: usize,
+}
+
+fn words(text: &str) -> Vec<Word<'_>> {
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '\'';
+    let mut words = vec![];
+    let mut start = None;
+    let mut offset = 0;
+    let mut word_offset = 0;
+    for (index, c) in text.char_indices() {
+        match (is_word_char(c), start) {
+            (true, None) => {
+                start = Some(index);
+                word_offset = offset;
+            },
+            (false, Some(word_start)) => {
+                words.push(Word {
+                    text: &text[word_start..index],
+                    start: word_start,
+                    end: index,
+                    offset: word_offset,
+                });
+                start = None;
+            },
+            _ => {},
+        }
+        offset += c.len_utf16();
+    }
+    if let Some(word_start) = start {
+        words.push(Word {
+            text: &text[word_start..],
+            start: word_start,
+            end: text.len(),
+            offset: word_offset,
+        });
+    }
+    words
+}
+
+fn utf16_len(s: &str) -> usize { s.chars().map(|c| c.len_utf16()).sum() }
+
+// A line of the document and its utf16 offset in the document, the newline
+// isn't part of it.
+pub(crate) struct TextLine {
+    pub(crate) offset: usize,
+    pub(crate) text: String,
+}
+
+pub(crate) fn text_lines(delta: &Delta) -> Vec<TextLine> {
+    let text = delta.ops.iter().map(|op| op.get_data()).collect::<String>();
+    let mut offset = 0;
+    let mut lines = vec![];
+    for line in text.split('\n') {
+        let len = utf16_len(line);
+        if !line.trim().is_empty() {
+            lines.push(TextLine {
+                offset,
+                text: line.to_owned(),
+            });
+        }
+        offset += len + 1;
+    }
+    lines
+}
+
+fn shift(diagnostic: &TextDiagnostic, offset: usize) -> TextDiagnostic {
+    let mut diagnostic = diagnostic.clone();
+    diagnostic.start += offset as i64;
+    diagnostic.end += offset as i64;
+    diagnostic
+}
+
+// Checks the lines that the range start..end of the document touches.
+pub fn check_text(
+    checker: &dyn TextChecker,
+    delta: &Delta,
+    start: usize,
+    end: usize,
+) -> DocResult<Vec<TextDiagnostic>> {
+    let end = if end == 0 { delta.target_len } else { end };
+    if start > end || end > delta.target_len {
+        return Err(DocError::out_of_bound().context(format!(
+            "The range {}..{} is not in the document of length {}",
+            start, end, delta.target_len
+        )));
+    }
+
+    let lines = text_lines(delta)
+        .into_iter()
+        .filter(|line| line.offset <= end && line.offset + utf16_len(&line.text) >= start)
+        .collect::<Vec<_>>();
+    let texts = lines.iter().map(|line| line.text.clone()).collect::<Vec<_>>();
+    let results = checker.check(&texts);
+    let diagnostics = lines
+        .iter()
+        .zip(results)
+        .flat_map(|(line, diagnostics)| {
+            diagnostics
+                .iter()
+                .map(|diagnostic| shift(diagnostic, line.offset))
+                .collect::<Vec<_>>()
+        })
+        .filter(|diagnostic| diagnostic.start >= start as i64 && diagnostic.end <= end as i64)
+        .collect();
+    Ok(diagnostics)
+}
+
+// Keeps the diagnostics of the lines that were checked, so only the lines that
+// were changed since the last check are sent to the checker.
+#[derive(Default)]
+pub struct DiagnosticCache {
+    lines: HashMap<String, Vec<TextDiagnostic>>,
+    diagnostics: Vec<TextDiagnostic>,
+}
+
+impl DiagnosticCache {
+    // Returns the diagnostics of the document if they're not the same as the
+    // ones of the last check.
+    pub fn check(&mut self, checker: &dyn TextChecker, delta: &Delta) -> Option<Vec<TextDiagnostic>> {
+        let lines = text_lines(delta);
+        let mut unchecked = lines
+            .iter()
+            .filter(|line| !self.lines.contains_key(&line.text))
+            .map(|line| line.text.clone())
+            .collect::<Vec<_>>();
+        unchecked.sort();
+        unchecked.dedup();
+        if !unchecked.is_empty() {
+            let results = checker.check(&unchecked);
+            self.lines.extend(unchecked.into_iter().zip(results));
+        }
+        let texts = lines.iter().map(|line| line.text.as_str()).collect::<HashSet<_>>();
+        self.lines.retain(|text, _| texts.contains(text.as_str()));
+
+        let diagnostics = lines
+            .iter()
+            .flat_map(|line| {
+                self.lines
+                    .get(&line.text)
+                    .into_iter()
+                    .flatten()
+                    .map(|diagnostic| shift(diagnostic, line.offset))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        if diagnostics == self.diagnostics {
+            return None;
+        }
+        self.diagnostics = diagnostics.clone();
+        Some(diagnostics)
+    }
+}
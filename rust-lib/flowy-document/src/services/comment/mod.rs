@@ -0,0 +1,160 @@
+use crate::{
+    entities::comment::{CreateCommentRequest, DocComment, RepeatedDocComment, ResolveCommentRequest},
+    errors::{internal_error, DocError, DocResult},
+    module::DocumentUser,
+    notify::{dart_notify, DocObservable},
+    services::doc::ClientEditDoc,
+    sql_tables::{DocCommentTable, DocCommentTableChangeset, DocCommentTableSql},
+};
+use flowy_database::SqliteConnection;
+use flowy_ot::core::Interval;
+use std::sync::Arc;
+
+// The comments are kept in the comment table. The range of each comment is an
+// anchor of the opened document, it's saved again whenever an edit moves it.
+pub(crate) struct CommentStore {
+    user: Arc<dyn DocumentUser>,
+    sql: DocCommentTableSql,
+}
+
+impl CommentStore {
+    pub(crate) fn new(user: Arc<dyn DocumentUser>) -> Self {
+        Self {
+            user,
+            sql: DocCommentTableSql {},
+        }
+    }
+
+    pub(crate) async fn create(&self, edit_doc: &ClientEditDoc, params: CreateCommentRequest) -> DocResult<DocComment> {
+        if params.start < 0 || params.end < params.start {
+            return Err(DocError::out_of_bound().context(format!("Invalid range {}..{}", params.start, params.end)));
+        }
+        if params.content.trim().is_empty() {
+            return Err(DocError::comment_content_empty());
+        }
+
+        let table = DocCommentTable::new(
+            &params.doc_id,
+            &self.user.user_id()?,
+            &params.content,
+            params.start,
+            params.end,
+        );
+        let comment: DocComment = table.clone().into();
+        let pool = self.user.db_pool()?;
+        let _ = self
+            .sql
+            .create_comment_table(table, &*pool.get().map_err(internal_error)?)?;
+
+        let interval = Interval::new(params.start as usize, params.end as usize);
+        let result = edit_doc.add_anchor(&comment.id, interval).await;
+        let conn = &*pool.get().map_err(internal_error)?;
+        if let Err(e) = result {
+            let _ = self.sql.delete_comment_table(&comment.id, conn)?;
+            return Err(e);
+        }
+
+        let _ = notify_comments_changed(&self.sql, &comment.doc_id, conn)?;
+        Ok(comment)
+    }
+
+    pub(crate) fn resolve(&self, params: ResolveCommentRequest) -> DocResult<DocComment> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        let table = self.read_table(&params.comment_id, conn)?;
+        let changeset = DocCommentTableChangeset {
+            id: table.id.clone(),
+            resolved: Some(params.resolved),
+            modified_time: Some(flowy_infra::timestamp()),
+            ..Default::default()
+        };
+        let _ = self.sql.update_comment_table(changeset, conn)?;
+
+        let _ = notify_comments_changed(&self.sql, &table.doc_id, conn)?;
+        let table = self.read_table(&params.comment_id, conn)?;
+        Ok(table.into())
+    }
+
+    // The doc that the comment is on.
+    pub(crate) fn doc_id(&self, comment_id: &str) -> DocResult<String> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        let table = self.read_table(comment_id, conn)?;
+        Ok(table.doc_id)
+    }
+
+    pub(crate) async fn delete(&self, edit_doc: &ClientEditDoc, comment_id: &str) -> DocResult<()> {
+        // The anchor is removed first, so the edits don't save the range of the
+        // deleted comment.
+        let _ = edit_doc.remove_anchor(comment_id).await?;
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        let _ = self.sql.delete_comment_table(comment_id, conn)?;
+        let _ = notify_comments_changed(&self.sql, &edit_doc.doc_id, conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn comments(&self, doc_id: &str) -> DocResult<RepeatedDocComment> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        read_comments(&self.sql, doc_id, conn)
+    }
+
+    pub(crate) fn delete_all(&self, doc_id: &str, conn: &SqliteConnection) -> DocResult<()> {
+        self.sql.delete_comment_tables(doc_id, conn)
+    }
+
+    fn read_table(&self, comment_id: &str, conn: &SqliteConnection) -> DocResult<DocCommentTable> {
+        self.sql
+            .read_comment_table(comment_id, conn)
+            .map_err(|e| match e.is_record_not_found() {
+                true => DocError::comment_not_found(),
+                false => e,
+            })
+    }
+}
+
+// Saves the ranges of the comments that the edit moved.
+pub(crate) fn save_anchors(doc_id: &str, anchors: Vec<(String, Interval)>, conn: &SqliteConnection) -> DocResult<()> {
+    let sql = DocCommentTableSql {};
+    for (comment_id, interval) in anchors {
+        let changeset = DocCommentTableChangeset {
+            id: comment_id,
+            start_offset: Some(interval.start() as i64),
+            end_offset: Some(interval.end() as i64),
+            ..Default::default()
+        };
+        let _ = sql.update_comment_table(changeset, conn)?;
+    }
+    notify_comments_changed(&sql, doc_id, conn)
+}
+
+pub(crate) fn read_anchors(doc_id: &str, conn: &SqliteConnection) -> DocResult<Vec<(String, Interval)>> {
+    let sql = DocCommentTableSql {};
+    let anchors = sql
+        .read_comment_tables(doc_id, conn)?
+        .into_iter()
+        .map(|table| {
+            let interval = Interval::new(table.start_offset as usize, table.end_offset as usize);
+            (table.id, interval)
+        })
+        .collect();
+    Ok(anchors)
+}
+
+fn read_comments(sql: &DocCommentTableSql, doc_id: &str, conn: &SqliteConnection) -> DocResult<RepeatedDocComment> {
+    let items = sql
+        .read_comment_tables(doc_id, conn)?
+        .into_iter()
+        .map(|table| table.into())
+        .collect();
+    Ok(RepeatedDocComment { items })
+}
+
+fn notify_comments_changed(sql: &DocCommentTableSql, doc_id: &str, conn: &SqliteConnection) -> DocResult<()> {
+    let comments = read_comments(sql, doc_id, conn)?;
+    dart_notify(doc_id, DocObservable::CommentsChanged)
+        .payload(comments)
+        .send();
+    Ok(())
+}
@@ -1,12 +1,15 @@
 use crate::{
-    errors::{DocError, DocResult},
-    module::DocumentUser,
+    errors::{internal_error, DocError, DocResult},
+    module::{DocumentConfig, DocumentMemoryUsage, DocumentUser},
     services::{
         cache::DocCache,
         doc::{
             edit::{ClientEditDoc, EditDocWsHandler},
-            revision::RevisionServer,
+            read_page,
+            revision::{RevisionServer, VersionHistory},
+            Page,
         },
+        encryption::{open_text, DocKeyring, SealedWebSocket, SealedWsHandler, SyncKeyring},
         server::Server,
         ws::WsDocumentManager,
     },
@@ -14,25 +17,53 @@ use crate::{
 use bytes::Bytes;
 use flowy_database::ConnectionPool;
 use flowy_document_infra::entities::doc::{Doc, DocDelta, DocIdentifier};
-use flowy_infra::future::{wrap_future, FnFuture, ResultFuture};
+use flowy_infra::{
+    future::{wrap_future, FnFuture, ResultFuture},
+    memory::{CacheMemory, Evict},
+    secret::Cipher,
+};
 use std::sync::Arc;
-use tokio::time::{interval, Duration};
+use tokio::{
+    task::spawn_blocking,
+    time::{interval, Duration},
+};
 
 pub(crate) struct DocController {
     server: Server,
     ws_manager: Arc<WsDocumentManager>,
     cache: Arc<DocCache>,
     user: Arc<dyn DocumentUser>,
+    keyring: Arc<DocKeyring>,
+    sync_keys: Arc<SyncKeyring>,
+    config: DocumentConfig,
+    // The opened documents are charged with the size of their delta.
+    memory: CacheMemory,
 }
 
 impl DocController {
-    pub(crate) fn new(server: Server, user: Arc<dyn DocumentUser>, ws: Arc<WsDocumentManager>) -> Self {
+    pub(crate) fn new(
+        server: Server,
+        user: Arc<dyn DocumentUser>,
+        ws: Arc<WsDocumentManager>,
+        keyring: Arc<DocKeyring>,
+        sync_keys: Arc<SyncKeyring>,
+        config: DocumentConfig,
+    ) -> Self {
         let cache = Arc::new(DocCache::new());
+        let evictor = Arc::new(DocEvictor {
+            cache: cache.clone(),
+            ws_manager: ws.clone(),
+        });
+        let memory = config.memory_budget.register("documents", evictor);
         let controller = Self {
             server,
             user,
             ws_manager: ws,
             cache: cache.clone(),
+            keyring,
+            sync_keys,
+            config,
+            memory,
         };
         controller
     }
@@ -53,18 +84,91 @@ impl DocController {
         }
 
         let edit_doc_ctx = self.cache.get(&params.doc_id)?;
+        self.memory.touch(&params.doc_id);
         Ok(edit_doc_ctx)
     }
 
-    pub(crate) fn close(&self, doc_id: &str) -> Result<(), DocError> {
-        self.cache.remove(doc_id);
-        self.ws_manager.remove_handler(doc_id);
+    // The edit session is dropped after its pending revisions are saved, so nothing
+    // is lost when the document is opened again. The other users stop showing the
+    // cursor of the user.
+    pub(crate) async fn close(&self, doc_id: &str) -> Result<(), DocError> {
+        self.memory.release(doc_id);
+        close_doc(&self.cache, &self.ws_manager, doc_id).await
+    }
+
+    // The size of a document is the one of its delta, the pending revisions are
+    // the ones queued for the server.
+    pub(crate) async fn memory_usage(&self, usage: &mut DocumentMemoryUsage) {
+        let docs = self.cache.all_docs();
+        usage.open_documents = docs.len();
+        for doc in docs {
+            if let Ok(delta) = doc.delta().await {
+                usage.document_bytes += delta.data.len();
+            }
+            let (revisions, bytes) = doc.cached_revisions();
+            usage.cached_revisions += revisions;
+            usage.cached_revision_bytes += bytes;
+            usage.pending_revisions += doc.pending_revisions().await;
+        }
+    }
+
+    // Each opened document keeps the pool of the database it was opened with, so
+    // they're closed when another user signs in. The pending revisions are saved
+    // to the database of the previous user.
+    pub(crate) async fn close_all(&self) {
+        for doc in self.cache.all_docs() {
+            if let Err(e) = self.close(&doc.doc_id).await {
+                log::error!("Close doc {} failed: {:?}", doc.doc_id, e);
+            }
+        }
+    }
+
+    pub(crate) async fn opened_delta(&self, doc_id: &str) -> Result<Option<DocDelta>, DocError> {
+        if !self.cache.contains(doc_id) {
+            return Ok(None);
+        }
+        let edit_doc_ctx = self.cache.get(doc_id)?;
+        Ok(Some(edit_doc_ctx.delta().await?))
+    }
+
+    // The page of the opened document is read from its edit session. The one that
+    // isn't opened is read from the database and stays closed, it's only opened
+    // if it isn't saved locally and has to be fetched from the server.
+    pub(crate) async fn read_page(
+        &self,
+        doc_id: &str,
+        cursor: &str,
+        limit: usize,
+        pool: Arc<ConnectionPool>,
+    ) -> Result<Page, DocError> {
+        if !self.cache.contains(doc_id) {
+            let cipher = self.keyring.cipher(doc_id)?;
+            let history = VersionHistory::new(doc_id, self.config.snapshot_interval, pool.clone(), cipher)?;
+            let latest = spawn_blocking(move || history.read_latest())
+                .await
+                .map_err(internal_error)??;
+            if let Some((delta, rev_id)) = latest {
+                return read_page(&delta, rev_id, cursor, limit);
+            }
+        }
+
+        let edit_doc_ctx = self.open(doc_id.to_owned().into(), pool).await?;
+        edit_doc_ctx.read_page(cursor, limit).await
+    }
+
+    // Nothing is waiting to be saved if the document isn't opened.
+    pub(crate) async fn force_save(&self, doc_id: &str) -> Result<(), DocError> {
+        if self.cache.contains(doc_id) {
+            let edit_doc_ctx = self.cache.get(doc_id)?;
+            edit_doc_ctx.flush().await?;
+        }
         Ok(())
     }
 
     #[tracing::instrument(level = "debug", skip(self), err)]
     pub(crate) fn delete(&self, params: DocIdentifier) -> Result<(), DocError> {
         let doc_id = &params.doc_id;
+        self.memory.release(doc_id);
         self.cache.remove(doc_id);
         self.ws_manager.remove_handler(doc_id);
         Ok(())
@@ -87,7 +191,35 @@ impl DocController {
 
         let edit_doc_ctx = self.cache.get(&delta.doc_id)?;
         let _ = edit_doc_ctx.composing_local_delta(Bytes::from(delta.data)).await?;
-        Ok(edit_doc_ctx.delta().await?)
+        let doc_delta = edit_doc_ctx.delta().await?;
+        self.memory.charge(&doc_delta.doc_id, doc_delta.data.len());
+        Ok(doc_delta)
+    }
+
+    // The documents that aren't opened have nothing to send.
+    pub(crate) async fn pending_revisions(&self, doc_id: &str) -> usize {
+        match self.cache.get(doc_id) {
+            Ok(edit_doc) => edit_doc.pending_revisions().await,
+            Err(_) => 0,
+        }
+    }
+
+    pub(crate) async fn apply_remote_delta(
+        &self,
+        delta: DocDelta,
+        db_pool: Arc<ConnectionPool>,
+    ) -> Result<DocDelta, DocError> {
+        if !self.cache.contains(&delta.doc_id) {
+            let doc_identifier: DocIdentifier = delta.doc_id.clone().into();
+            let _ = self.open(doc_identifier, db_pool).await?;
+        }
+
+        let data = self.sync_keys.open_text(&delta.doc_id, delta.data)?;
+        let edit_doc_ctx = self.cache.get(&delta.doc_id)?;
+        let _ = edit_doc_ctx.composing_remote_delta(Bytes::from(data)).await?;
+        let doc_delta = edit_doc_ctx.delta().await?;
+        self.memory.charge(&doc_delta.doc_id, doc_delta.data.len());
+        Ok(doc_delta)
     }
 }
 
@@ -96,25 +228,65 @@ impl DocController {
         // Opti: require upgradable_read lock and then upgrade to write lock using
         // RwLockUpgradableReadGuard::upgrade(xx) of ws
         // let doc = self.read_doc(doc_id, pool.clone()).await?;
-        let ws = self.ws_manager.ws();
+        // The encrypted document can only be opened when it's unlocked.
+        let cipher = self.keyring.cipher(doc_id)?;
+        // The revisions are synced with the key of the workspace.
+        let sync_cipher = self.sync_keys.cipher(doc_id)?;
+        let ws = SealedWebSocket::wrap(self.ws_manager.ws(), sync_cipher.clone());
         let token = self.user.token()?;
         let user = self.user.clone();
         let server = Arc::new(RevisionServerImpl {
             token,
             server: self.server.clone(),
+            sync_cipher: sync_cipher.clone(),
         });
 
-        let edit_ctx = Arc::new(ClientEditDoc::new(doc_id, pool, ws, server, user).await?);
-        let ws_handler = Arc::new(EditDocWsHandler(edit_ctx.clone()));
+        let edit_ctx = Arc::new(ClientEditDoc::new(doc_id, pool, ws, server, user, &self.config, cipher).await?);
+        let ws_handler = SealedWsHandler::wrap(Arc::new(EditDocWsHandler(edit_ctx.clone())), sync_cipher);
         self.ws_manager.register_handler(doc_id, ws_handler);
         self.cache.set(edit_ctx.clone());
+        self.memory.charge(doc_id, edit_ctx.delta().await?.data.len());
         Ok(edit_ctx)
     }
 }
 
+async fn close_doc(cache: &DocCache, ws_manager: &WsDocumentManager, doc_id: &str) -> Result<(), DocError> {
+    if cache.contains(doc_id) {
+        let edit_doc_ctx = cache.get(doc_id)?;
+        edit_doc_ctx.flush().await?;
+        let _ = edit_doc_ctx.clear_presence()?;
+    }
+    cache.remove(doc_id);
+    ws_manager.remove_handler(doc_id);
+    Ok(())
+}
+
+// The document evicted by the memory budget is closed like the ones the user
+// closed, it's opened again by its next change.
+struct DocEvictor {
+    cache: Arc<DocCache>,
+    ws_manager: Arc<WsDocumentManager>,
+}
+
+impl Evict for DocEvictor {
+    fn evict(&self, key: &str) {
+        let runtime = match tokio::runtime::Handle::try_current() {
+            Ok(runtime) => runtime,
+            Err(_) => return log::warn!("Evict doc {} outside of the runtime", key),
+        };
+        let (cache, ws_manager, doc_id) = (self.cache.clone(), self.ws_manager.clone(), key.to_owned());
+        runtime.spawn(async move {
+            if let Err(e) = close_doc(&cache, &ws_manager, &doc_id).await {
+                log::error!("Evict doc {} failed: {:?}", doc_id, e);
+            }
+        });
+    }
+}
+
 struct RevisionServerImpl {
     token: String,
     server: Server,
+    sync_cipher: Option<Arc<Cipher>>,
 }
 
 impl RevisionServer for RevisionServerImpl {
@@ -125,11 +297,17 @@ impl RevisionServer for RevisionServerImpl {
         };
         let server = self.server.clone();
         let token = self.token.clone();
+        let sync_cipher = self.sync_cipher.clone();
 
         ResultFuture::new(async move {
             match server.read_doc(&token, params).await? {
                 None => Err(DocError::record_not_found().context("Remote doesn't have this document")),
-                Some(doc) => Ok(doc),
+                Some(mut doc) => {
+                    if let Some(cipher) = sync_cipher {
+                        doc.data = open_text(&cipher, doc.data)?;
+                    }
+                    Ok(doc)
+                },
             }
         })
     }
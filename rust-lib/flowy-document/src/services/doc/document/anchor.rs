@@ -0,0 +1,79 @@
+use flowy_ot::core::{Delta, Interval, Operation};
+use std::collections::{HashMap, HashSet};
+
+// The ranges that the comments are on. The ranges are moved by the changes of
+// the document, so they stay on the same text while it's edited around them.
+#[derive(Debug, Clone, Default)]
+pub struct Anchors {
+    intervals: HashMap<String, Interval>,
+    // The anchors that were moved since the last take_changed.
+    changed: HashSet<String>,
+}
+
+impl Anchors {
+    pub fn insert(&mut self, id: &str, interval: Interval) { self.intervals.insert(id.to_owned(), interval); }
+
+    pub fn remove(&mut self, id: &str) {
+        self.intervals.remove(id);
+        self.changed.remove(id);
+    }
+
+    pub fn get(&self, id: &str) -> Option<Interval> { self.intervals.get(id).cloned() }
+
+    // The text inserted at the start or at the end of a range is not in it.
+    pub fn transform(&mut self, change: &Delta) {
+        for (id, interval) in self.intervals.iter_mut() {
+            let start = transform_index(change, interval.start(), false);
+            let end = transform_index(change, interval.end(), true).max(start);
+            let new_interval = Interval::new(start, end);
+            if new_interval != *interval {
+                *interval = new_interval;
+                self.changed.insert(id.clone());
+            }
+        }
+    }
+
+    // The ranges that the document no longer has are moved to its end.
+    pub fn clamp(&mut self, len: usize) {
+        for (id, interval) in self.intervals.iter_mut() {
+            if interval.end() > len {
+                *interval = Interval::new(interval.start().min(len), len);
+                self.changed.insert(id.clone());
+            }
+        }
+    }
+
+    pub fn take_changed(&mut self) -> Vec<(String, Interval)> {
+        let changed = std::mem::take(&mut self.changed);
+        changed
+            .into_iter()
+            .flat_map(|id| self.get(&id).map(|interval| (id, interval)))
+            .collect()
+    }
+}
+
+// The index in the document after the change. The text inserted at the index
+// goes before it, unless it's sticky, then the index stays before the text.
+pub fn transform_index(change: &Delta, index: usize, sticky: bool) -> usize {
+    let mut index = index;
+    let mut offset = 0;
+    for op in &change.ops {
+        if offset > index {
+            break;
+        }
+        match op {
+            Operation::Delete(n) => {
+                index -= (*n).min(index - offset);
+                continue;
+            },
+            Operation::Insert(insert) => {
+                if offset < index || !sticky {
+                    index += insert.s.count_utf16_code_units();
+                }
+            },
+            Operation::Retain(_) => {},
+        }
+        offset += op.len();
+    }
+    index
+}
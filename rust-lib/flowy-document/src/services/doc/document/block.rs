@@ -0,0 +1,207 @@
+use crate::{
+    entities::block::{BlockOp, BlockOpType, BlockType, DocBlock},
+    errors::DocError,
+};
+use flowy_ot::core::{AttributeKey, Attributes, Delta, DeltaIter, Interval, Operation, OperationTransformable};
+use std::convert::TryFrom;
+
+#[derive(Debug, Clone)]
+pub enum BlockOperation {
+    Insert { index: usize, block: Delta },
+    Delete { index: usize },
+    Move { from: usize, to: usize },
+    Update { index: usize, block: Delta },
+}
+
+impl TryFrom<BlockOp> for BlockOperation {
+    type Error = DocError;
+
+    fn try_from(op: BlockOp) -> Result<Self, Self::Error> {
+        let index = block_index(op.index)?;
+        let block = || Delta::from_json(&op.data).map_err(|e| DocError::block_data().context(e));
+        let operation = match op.ty {
+            BlockOpType::Insert => BlockOperation::Insert { index, block: block()? },
+            BlockOpType::Delete => BlockOperation::Delete { index },
+            BlockOpType::Move => BlockOperation::Move {
+                from: index,
+                to: block_index(op.to)?,
+            },
+            BlockOpType::Update => BlockOperation::Update { index, block: block()? },
+        };
+        Ok(operation)
+    }
+}
+
+fn block_index(index: i64) -> Result<usize, DocError> {
+    match index < 0 {
+        true => Err(DocError::out_of_bound().context(format!("Invalid block index {}", index))),
+        false => Ok(index as usize),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Block {
+    pub block_type: BlockType,
+    // The delta of the line, ended by the newline that holds the block attributes.
+    pub delta: Delta,
+}
+
+impl Block {
+    pub fn to_doc_block(&self, index: usize) -> DocBlock {
+        DocBlock {
+            index: index as i64,
+            block_type: self.block_type.clone(),
+            data: self.delta.to_json(),
+        }
+    }
+}
+
+pub fn split_blocks(delta: &Delta) -> Vec<Block> {
+    line_intervals(delta)
+        .into_iter()
+        .map(|interval| {
+            let delta = sub_delta(delta, interval);
+            Block {
+                block_type: block_type(&delta),
+                delta,
+            }
+        })
+        .collect()
+}
+
+// The change of the document that the operation makes. Only the lines that the
+// operation touches are deleted or inserted, the others are retained.
+pub fn block_change(delta: &Delta, op: &BlockOperation) -> Result<Delta, DocError> {
+    let lines = line_intervals(delta);
+    let mut change = Delta::new();
+    match op {
+        BlockOperation::Insert { index, block } => {
+            let _ = validate_block(block)?;
+            let offset = match lines.get(*index) {
+                Some(line) => line.start(),
+                None if *index == lines.len() => delta.target_len,
+                None => return Err(out_of_bound(*index, lines.len())),
+            };
+            change.retain(offset, Attributes::default());
+            block.ops.iter().for_each(|op| change.add(op.clone()));
+            change.retain(delta.target_len - offset, Attributes::default());
+        },
+        BlockOperation::Delete { index } => {
+            let line = line_at(&lines, *index)?;
+            change.retain(line.start(), Attributes::default());
+            change.delete(line.size());
+            change.retain(delta.target_len - line.end(), Attributes::default());
+        },
+        BlockOperation::Update { index, block } => {
+            let _ = validate_block(block)?;
+            let line = line_at(&lines, *index)?;
+            change.retain(line.start(), Attributes::default());
+            block.ops.iter().for_each(|op| change.add(op.clone()));
+            change.delete(line.size());
+            change.retain(delta.target_len - line.end(), Attributes::default());
+        },
+        BlockOperation::Move { from, to } => {
+            let line = line_at(&lines, *from)?;
+            let block = sub_delta(delta, line);
+            let removal = block_change(delta, &BlockOperation::Delete { index: *from })?;
+            let removed = delta.compose(&removal)?;
+            let insert = block_change(&removed, &BlockOperation::Insert { index: *to, block })?;
+            change = removal.compose(&insert)?;
+        },
+    }
+    Ok(change)
+}
+
+// The block must be one line ended by a newline.
+fn validate_block(block: &Delta) -> Result<(), DocError> {
+    if !block.ops.iter().all(|op| op.is_insert()) {
+        return Err(DocError::block_data().context("The block can only contain inserts"));
+    }
+
+    let text = block.ops.iter().map(|op| op.get_data()).collect::<String>();
+    match text.find('\n') {
+        Some(index) if index == text.len() - 1 => Ok(()),
+        _ => Err(DocError::block_data().context("The block must be one line ended by a newline")),
+    }
+}
+
+fn line_at(lines: &[Interval], index: usize) -> Result<Interval, DocError> {
+    lines
+        .get(index)
+        .cloned()
+        .ok_or_else(|| out_of_bound(index, lines.len()))
+}
+
+fn out_of_bound(index: usize, len: usize) -> DocError {
+    DocError::out_of_bound().context(format!("The block {} out of bounds, should 0..{}", index, len))
+}
+
+// The utf16 interval of each line, the newline included. The text after the
+// last newline is a line too.
+pub(crate) fn line_intervals(delta: &Delta) -> Vec<Interval> {
+    let mut lines = vec![];
+    let mut start = 0;
+    let mut end = 0;
+    for op in &delta.ops {
+        for c in op.get_data().chars() {
+            end += c.len_utf16();
+            if c == '\n' {
+                lines.push(Interval::new(start, end));
+                start = end;
+            }
+        }
+    }
+    if end > start {
+        lines.push(Interval::new(start, end));
+    }
+    lines
+}
+
+pub(crate) fn sub_delta(delta: &Delta, interval: Interval) -> Delta {
+    let mut sub = Delta::new();
+    DeltaIter::from_interval(delta, interval)
+        .ops()
+        .into_iter()
+        .for_each(|op| sub.add(op));
+    sub
+}
+
+fn block_type(block: &Delta) -> BlockType {
+    let newline_attributes = match block.ops.last() {
+        Some(op) if op.get_data().ends_with('\n') => op.get_attributes(),
+        _ => Attributes::default(),
+    };
+    let value = |key: AttributeKey| newline_attributes.get(&key).and_then(|value| value.0.clone());
+
+    if value(AttributeKey::CodeBlock).is_some() {
+        BlockType::Code
+    } else if value(AttributeKey::Header).is_some() {
+        BlockType::Heading
+    } else if let Some(list) = value(AttributeKey::List) {
+        match list.as_str() {
+            "checked" | "unchecked" => BlockType::Todo,
+            _ => BlockType::List,
+        }
+    } else if value(AttributeKey::BlockQuote).is_some() {
+        BlockType::Quote
+    } else if is_embed(block) {
+        BlockType::Embed
+    } else {
+        BlockType::Paragraph
+    }
+}
+
+// An embed is a line that holds a link and nothing else, like the images that
+// are shown by their url.
+fn is_embed(block: &Delta) -> bool {
+    match block.ops.as_slice() {
+        [Operation::Insert(embed), Operation::Insert(newline)] if newline.s.as_str() == "\n" => {
+            let link = embed
+                .attributes
+                .get(&AttributeKey::Link)
+                .and_then(|value| value.0.as_ref());
+            link.map(|link| link == embed.s.as_str()).unwrap_or(false)
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,200 @@
+use crate::{
+    entities::diff::{AttributeChange, BlockDiff, BlockDiffType},
+    services::doc::{split_blocks, Block},
+};
+use flowy_ot::core::{AttributeKey, Attributes, Delta};
+use std::collections::HashSet;
+
+// The blocks that are in both documents are found by their longest common
+// subsequence. The blocks between two common blocks are paired in order as the
+// changed blocks, the rest of them are inserted or deleted.
+pub fn diff_documents(old: &Delta, new: &Delta) -> Vec<BlockDiff> {
+    let old_blocks = split_blocks(old);
+    let new_blocks = split_blocks(new);
+    let mut diffs = vec![];
+    let (mut old_start, mut new_start) = (0, 0);
+    let ends = common_blocks(&old_blocks, &new_blocks)
+        .into_iter()
+        .chain(std::iter::once((old_blocks.len(), new_blocks.len())));
+    for (old_end, new_end) in ends {
+        let paired = (old_end - old_start).min(new_end - new_start);
+        for offset in 0..paired {
+            let (old_index, new_index) = (old_start + offset, new_start + offset);
+            diffs.push(changed_block(
+                old_index,
+                &old_blocks[old_index],
+                new_index,
+                &new_blocks[new_index],
+            ));
+        }
+        for (old_index, block) in old_blocks.iter().enumerate().take(old_end).skip(old_start + paired) {
+            diffs.push(BlockDiff {
+                ty: BlockDiffType::Deleted,
+                old_index: old_index as i64,
+                new_index: -1,
+                old_data: block.delta.to_json(),
+                ..BlockDiff::default()
+            });
+        }
+        for (new_index, block) in new_blocks.iter().enumerate().take(new_end).skip(new_start + paired) {
+            diffs.push(BlockDiff {
+                ty: BlockDiffType::Inserted,
+                old_index: -1,
+                new_index: new_index as i64,
+                new_data: block.delta.to_json(),
+                ..BlockDiff::default()
+            });
+        }
+        old_start = old_end + 1;
+        new_start = new_end + 1;
+    }
+    diffs
+}
+
+// The index pairs of the blocks that are in both documents, in order.
+fn common_blocks(old: &[Block], new: &[Block]) -> Vec<(usize, usize)> {
+    let prefix = old.iter().zip(new.iter()).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+
+    // lengths[i][j] is the length of the common subsequence of old_middle[i..]
+    // and new_middle[j..].
+    let mut lengths = vec![vec![0; new_middle.len() + 1]; old_middle.len() + 1];
+    for i in (0..old_middle.len()).rev() {
+        for j in (0..new_middle.len()).rev() {
+            lengths[i][j] = match old_middle[i] == new_middle[j] {
+                true => lengths[i + 1][j + 1] + 1,
+                false => lengths[i + 1][j].max(lengths[i][j + 1]),
+            };
+        }
+    }
+
+    let mut pairs = (0..prefix).map(|index| (index, index)).collect::<Vec<_>>();
+    let (mut i, mut j) = (0, 0);
+    while i < old_middle.len() && j < new_middle.len() {
+        if old_middle[i] == new_middle[j] {
+            pairs.push((prefix + i, prefix + j));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    pairs.extend((0..suffix).map(|index| (old.len() - suffix + index, new.len() - suffix + index)));
+    pairs
+}
+
+fn changed_block(old_index: usize, old: &Block, new_index: usize, new: &Block) -> BlockDiff {
+    let old_chars = block_chars(&old.delta);
+    let new_chars = block_chars(&new.delta);
+    let text_changed = old_chars.iter().map(|(c, _)| c).ne(new_chars.iter().map(|(c, _)| c));
+
+    let mut attributes = vec![];
+    if !text_changed {
+        let text_len = old_chars.iter().take_while(|(c, _)| *c != '\n').count();
+        let mut offset = 0;
+        for ((c, old_attributes), (_, new_attributes)) in old_chars.iter().zip(new_chars.iter()).take(text_len) {
+            let end = offset + c.len_utf16();
+            attribute_changes(old_attributes, new_attributes, offset, end)
+                .into_iter()
+                .for_each(|change| push_change(&mut attributes, change));
+            offset = end;
+        }
+    }
+
+    // The block attributes are the ones of the newline.
+    let newline_attributes = |chars: &[(char, Attributes)]| match chars.last() {
+        Some(('\n', attributes)) => attributes.clone(),
+        _ => Attributes::default(),
+    };
+    let len = new_chars.iter().map(|(c, _)| c.len_utf16()).sum::<usize>();
+    attributes.extend(attribute_changes(
+        &newline_attributes(&old_chars),
+        &newline_attributes(&new_chars),
+        len.saturating_sub(1),
+        len,
+    ));
+
+    BlockDiff {
+        ty: BlockDiffType::Changed,
+        old_index: old_index as i64,
+        new_index: new_index as i64,
+        old_data: old.delta.to_json(),
+        new_data: new.delta.to_json(),
+        text_changed,
+        attributes,
+    }
+}
+
+fn block_chars(block: &Delta) -> Vec<(char, Attributes)> {
+    block
+        .ops
+        .iter()
+        .flat_map(|op| {
+            let attributes = op.get_attributes();
+            op.get_data()
+                .chars()
+                .map(move |c| (c, attributes.clone()))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn attribute_changes(old: &Attributes, new: &Attributes, start: usize, end: usize) -> Vec<AttributeChange> {
+    let value = |attributes: &Attributes, key: &AttributeKey| {
+        attributes
+            .get(key)
+            .and_then(|value| value.0.clone())
+            .unwrap_or_default()
+    };
+    let keys = old.keys().chain(new.keys()).collect::<HashSet<_>>();
+    let mut changes = keys
+        .into_iter()
+        .filter_map(|key| {
+            let (old_value, new_value) = (value(old, key), value(new, key));
+            if old_value == new_value {
+                return None;
+            }
+            Some(AttributeChange {
+                key: attribute_key_name(key),
+                old_value,
+                new_value,
+                start: start as i64,
+                end: end as i64,
+            })
+        })
+        .collect::<Vec<_>>();
+    changes.sort_by(|a, b| a.key.cmp(&b.key));
+    changes
+}
+
+// The change is merged into the change of the text right before it if they
+// set the same attribute the same way.
+fn push_change(changes: &mut Vec<AttributeChange>, change: AttributeChange) {
+    let previous = changes.iter_mut().rev().find(|previous| {
+        previous.end == change.start
+            && previous.key == change.key
+            && previous.old_value == change.old_value
+            && previous.new_value == change.new_value
+    });
+    match previous {
+        Some(previous) => previous.end = change.end,
+        None => changes.push(change),
+    }
+}
+
+// The name of the attribute in the json of the delta.
+fn attribute_key_name(key: &AttributeKey) -> String {
+    match serde_json::to_value(key) {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => key.to_string(),
+    }
+}
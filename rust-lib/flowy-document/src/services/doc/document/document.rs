@@ -1,6 +1,17 @@
 use crate::{
     errors::DocError,
-    services::doc::{view::View, History, UndoResult, RECORD_THRESHOLD},
+    services::doc::{
+        block_change,
+        split_blocks,
+        view::View,
+        Anchors,
+        Block,
+        BlockOperation,
+        DocumentStats,
+        History,
+        UndoResult,
+        RECORD_THRESHOLD,
+    },
 };
 use flowy_document_infra::user_default::doc_initial_delta;
 use flowy_ot::core::*;
@@ -26,6 +37,8 @@ pub struct Document {
     view: View,
     last_edit_time: usize,
     notify: Option<mpsc::UnboundedSender<()>>,
+    stats: DocumentStats,
+    anchors: Anchors,
 }
 
 impl Document {
@@ -33,14 +46,23 @@ impl Document {
 
     pub fn from_delta(delta: Delta) -> Self {
         Document {
+            stats: DocumentStats::from_delta(&delta),
             delta,
             history: History::new(),
             view: View::new(),
             last_edit_time: 0,
             notify: None,
+            anchors: Anchors::default(),
         }
     }
 
+    // The number of undo units that are kept, the oldest one is dropped when it's
+    // exceeded.
+    pub fn undo_capacity(mut self, capacity: usize) -> Self {
+        self.history = History::with_capacity(capacity);
+        self
+    }
+
     pub fn from_json(json: &str) -> Result<Self, DocError> {
         let delta = Delta::from_json(json)?;
         Ok(Self::from_delta(delta))
@@ -54,9 +76,29 @@ impl Document {
 
     pub fn delta(&self) -> &Delta { &self.delta }
 
+    pub fn stats(&self) -> &DocumentStats { &self.stats }
+
+    pub fn anchors(&self) -> &Anchors { &self.anchors }
+
+    pub fn anchors_mut(&mut self) -> &mut Anchors { &mut self.anchors }
+
     pub fn set_notify(&mut self, notify: mpsc::UnboundedSender<()>) { self.notify = Some(notify); }
 
     pub fn set_delta(&mut self, data: Delta) {
+        self.stats = DocumentStats::from_delta(&data);
+        self.anchors.clamp(data.target_len);
+        self.update_delta(data);
+    }
+
+    // Same as set_delta, but only the lines that the change touches are counted
+    // again and the anchors are moved by the change.
+    fn apply_change(&mut self, change: &Delta, data: Delta) {
+        self.stats.apply(change, &data);
+        self.anchors.transform(change);
+        self.update_delta(data);
+    }
+
+    fn update_delta(&mut self, data: Delta) {
         self.delta = data;
 
         match &self.notify {
@@ -93,7 +135,7 @@ impl Document {
         tracing::trace!("compose result: {}", composed_delta.to_json());
         trim(&mut composed_delta);
 
-        self.set_delta(composed_delta);
+        self.apply_change(&delta, composed_delta);
         Ok(())
     }
 
@@ -147,18 +189,51 @@ impl Document {
         Ok(delta)
     }
 
+    pub fn blocks(&self) -> Vec<Block> { split_blocks(&self.delta) }
+
+    // The operations are applied one after another and their change is one undo
+    // unit. Nothing is applied if one of them fails.
+    pub fn apply_block_ops(&mut self, ops: &[BlockOperation]) -> Result<Delta, DocError> {
+        let mut delta = self.delta.clone();
+        let mut change = Delta::new();
+        for op in ops {
+            let op_change = block_change(&delta, op)?;
+            delta = delta.compose(&op_change)?;
+            change = change.compose(&op_change)?;
+        }
+
+        trim(&mut change);
+        if !change.is_empty() {
+            tracing::trace!("👉 receive change: {}", change);
+            let _ = self.compose_delta_as_unit(change.clone())?;
+        }
+        Ok(change)
+    }
+
     pub fn can_undo(&self) -> bool { self.history.can_undo() }
 
     pub fn can_redo(&self) -> bool { self.history.can_redo() }
 
+    // Same as compose_delta, but the change is an undo unit of its own instead of
+    // being grouped with the changes around it.
+    pub fn compose_delta_as_unit(&mut self, delta: Delta) -> Result<(), DocError> {
+        self.last_edit_time = 0;
+        let result = self.compose_delta(delta);
+        self.last_edit_time = 0;
+        result
+    }
+
     pub fn undo(&mut self) -> Result<UndoResult, DocError> {
         match self.history.undo() {
             None => Err(DocError::undo().context("Undo stack is empty")),
             Some(undo_delta) => {
                 let (new_delta, inverted_delta) = self.invert(&undo_delta)?;
-                let result = UndoResult::success(new_delta.target_len as usize);
-                self.set_delta(new_delta);
+                self.apply_change(&undo_delta, new_delta.clone());
+                let result = UndoResult::success(self.delta.target_len as usize, undo_delta);
                 self.history.add_redo(inverted_delta);
+                // The next change starts a new undo unit instead of being grouped with the
+                // undone one.
+                self.last_edit_time = 0;
 
                 Ok(result)
             },
@@ -167,13 +242,14 @@ impl Document {
 
     pub fn redo(&mut self) -> Result<UndoResult, DocError> {
         match self.history.redo() {
-            None => Err(DocError::redo()),
+            None => Err(DocError::redo().context("Redo stack is empty")),
             Some(redo_delta) => {
                 let (new_delta, inverted_delta) = self.invert(&redo_delta)?;
-                let result = UndoResult::success(new_delta.target_len as usize);
-                self.set_delta(new_delta);
+                self.apply_change(&redo_delta, new_delta.clone());
+                let result = UndoResult::success(self.delta.target_len as usize, redo_delta);
 
                 self.history.add_undo(inverted_delta);
+                self.last_edit_time = 0;
                 Ok(result)
             },
         }
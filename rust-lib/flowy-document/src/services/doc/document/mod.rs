@@ -1,6 +1,16 @@
+mod anchor;
+mod block;
 mod data;
+mod diff;
 mod document;
+mod page;
 mod selection;
+mod stats;
 
+pub use anchor::*;
+pub use block::*;
 pub use data::*;
+pub use diff::*;
 pub use document::*;
+pub use page::*;
+pub use stats::*;
@@ -0,0 +1,83 @@
+use crate::{
+    errors::DocError,
+    services::doc::document::block::{line_intervals, sub_delta},
+};
+use flowy_ot::core::Delta;
+
+#[derive(Debug, Clone)]
+pub struct Page {
+    pub start: usize,
+    pub end: usize,
+    // The utf16 offset of the first block of the page.
+    pub offset: usize,
+    pub block_count: usize,
+    pub rev_id: i64,
+    pub delta: Delta,
+    pub next_cursor: String,
+}
+
+// Only the blocks of the page are copied out of the delta. The page reads the
+// rest of the document if the limit is zero, so the request without a cursor
+// and a limit reads the whole document.
+pub fn read_page(delta: &Delta, rev_id: i64, cursor: &str, limit: usize) -> Result<Page, DocError> {
+    let start = parse_cursor(cursor, rev_id)?;
+    let lines = line_intervals(delta);
+    if start > lines.len() {
+        return Err(DocError::out_of_bound().context(format!(
+            "The block {} out of bounds, should 0..{}",
+            start,
+            lines.len()
+        )));
+    }
+
+    let end = match limit {
+        0 => lines.len(),
+        limit => (start + limit).min(lines.len()),
+    };
+    let offset = lines.get(start).map(|line| line.start).unwrap_or(delta.target_len);
+    let mut page = Delta::new();
+    lines[start..end]
+        .iter()
+        .flat_map(|line| sub_delta(delta, *line).ops)
+        .for_each(|op| page.add(op));
+
+    let next_cursor = match end < lines.len() {
+        true => format_cursor(rev_id, end),
+        false => "".to_owned(),
+    };
+    Ok(Page {
+        start,
+        end,
+        offset,
+        block_count: lines.len(),
+        rev_id,
+        delta: page,
+        next_cursor,
+    })
+}
+
+// The cursor is the rev_id of the document and the index of the block that the
+// page starts at, e.g. 12:100. The blocks are moved by the changes, so the
+// cursor of another rev_id is refused and the reading starts again.
+fn format_cursor(rev_id: i64, index: usize) -> String { format!("{}:{}", rev_id, index) }
+
+fn parse_cursor(cursor: &str, rev_id: i64) -> Result<usize, DocError> {
+    if cursor.is_empty() {
+        return Ok(0);
+    }
+    let invalid = || DocError::cursor_invalid().context(format!("Invalid cursor {}", cursor));
+    let (cursor_rev_id, index) = match cursor.split_once(':') {
+        None => return Err(invalid()),
+        Some((cursor_rev_id, index)) => (
+            cursor_rev_id.parse::<i64>().map_err(|_| invalid())?,
+            index.parse::<usize>().map_err(|_| invalid())?,
+        ),
+    };
+    if cursor_rev_id != rev_id {
+        return Err(DocError::cursor_invalid().context(format!(
+            "The cursor {} is for the revision {}, the document is at {}",
+            cursor, cursor_rev_id, rev_id
+        )));
+    }
+    Ok(index)
+}
@@ -0,0 +1,238 @@
+use crate::entities::stats::DocStats;
+use flowy_ot::core::{AttributeKey, Attributes, Delta, DeltaIter, Interval, Operation};
+
+// How many words are read per minute, it's what the reading time is estimated
+// with.
+pub const READING_WORDS_PER_MINUTE: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockKind {
+    Paragraph,
+    Header,
+    List,
+    Quote,
+    Code,
+}
+
+#[derive(Debug, Clone)]
+struct LineStats {
+    // The utf16 length of the line, the newline included.
+    len: usize,
+    has_newline: bool,
+    words: usize,
+    chars: usize,
+    // The empty paragraphs are not blocks.
+    block: Option<BlockKind>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StatsCount {
+    pub words: usize,
+    pub chars: usize,
+    pub paragraphs: usize,
+    pub headers: usize,
+    pub lists: usize,
+    pub quotes: usize,
+    pub codes: usize,
+}
+
+impl StatsCount {
+    // In seconds, rounded up.
+    pub fn reading_time(&self) -> usize { (self.words * 60).div_ceil(READING_WORDS_PER_MINUTE) }
+
+    fn add(&mut self, line: &LineStats) {
+        self.words += line.words;
+        self.chars += line.chars;
+        if let Some(block) = line.block {
+            *self.block_count(block) += 1;
+        }
+    }
+
+    fn remove(&mut self, line: &LineStats) {
+        self.words -= line.words;
+        self.chars -= line.chars;
+        if let Some(block) = line.block {
+            *self.block_count(block) -= 1;
+        }
+    }
+
+    fn block_count(&mut self, block: BlockKind) -> &mut usize {
+        match block {
+            BlockKind::Paragraph => &mut self.paragraphs,
+            BlockKind::Header => &mut self.headers,
+            BlockKind::List => &mut self.lists,
+            BlockKind::Quote => &mut self.quotes,
+            BlockKind::Code => &mut self.codes,
+        }
+    }
+}
+
+// The counts of the document, kept per line. A change only counts the lines it
+// touches again, the other lines are kept.
+#[derive(Debug, Clone, Default)]
+pub struct DocumentStats {
+    lines: Vec<LineStats>,
+    count: StatsCount,
+}
+
+impl DocumentStats {
+    pub fn from_delta(delta: &Delta) -> Self {
+        let lines = split_lines(delta.ops.iter());
+        let mut count = StatsCount::default();
+        lines.iter().for_each(|line| count.add(line));
+        DocumentStats { lines, count }
+    }
+
+    pub fn count(&self) -> &StatsCount { &self.count }
+
+    // The delta is the document after the change was composed.
+    pub fn apply(&mut self, change: &Delta, delta: &Delta) {
+        let old_len = self.lines.iter().map(|line| line.len).sum::<usize>();
+        let (start, end) = changed_range(change);
+        if end > old_len {
+            *self = Self::from_delta(delta);
+            return;
+        }
+
+        let first = self.line_at(start);
+        let last = match first < self.lines.len() {
+            true => (self.line_at(end) + 1).min(self.lines.len()),
+            false => first,
+        };
+        let region_start = self.lines[..first].iter().map(|line| line.len).sum::<usize>();
+        let region_len = self.lines[first..last].iter().map(|line| line.len).sum::<usize>();
+        let new_region_len = (region_len + delta.target_len).checked_sub(old_len);
+        let new_region_len = match new_region_len {
+            Some(len) if region_start + len <= delta.target_len => len,
+            _ => {
+                *self = Self::from_delta(delta);
+                return;
+            },
+        };
+
+        let interval = Interval::new(region_start, region_start + new_region_len);
+        let ops = DeltaIter::from_interval(delta, interval).ops();
+        let lines = split_lines(ops.iter());
+        for line in &self.lines[first..last] {
+            self.count.remove(line);
+        }
+        lines.iter().for_each(|line| self.count.add(line));
+        self.lines.splice(first..last, lines);
+    }
+}
+
+impl DocumentStats {
+    // The index of the line that contains the offset. The text inserted at the end
+    // of the document becomes a new line if the last line is ended by a newline.
+    fn line_at(&self, offset: usize) -> usize {
+        let mut end = 0;
+        for (index, line) in self.lines.iter().enumerate() {
+            end += line.len;
+            if offset < end {
+                return index;
+            }
+        }
+        match self.lines.last() {
+            Some(line) if !line.has_newline => self.lines.len() - 1,
+            _ => self.lines.len(),
+        }
+    }
+}
+
+// The range of the document that the change deletes, formats or inserts into.
+// The plain retains at the start and at the end leave the text as it is.
+fn changed_range(change: &Delta) -> (usize, usize) {
+    let mut ops = change.ops.as_slice();
+    let mut start = 0;
+    while let Some((Operation::Retain(retain), rest)) = ops.split_first() {
+        if !retain.is_plain() {
+            break;
+        }
+        start += retain.n;
+        ops = rest;
+    }
+    if let Some((Operation::Retain(retain), rest)) = ops.split_last() {
+        if retain.is_plain() {
+            ops = rest;
+        }
+    }
+
+    let len = ops
+        .iter()
+        .map(|op| match op {
+            Operation::Insert(_) => 0,
+            _ => op.len(),
+        })
+        .sum::<usize>();
+    (start, start + len)
+}
+
+fn split_lines<'a>(ops: impl Iterator<Item = &'a Operation>) -> Vec<LineStats> {
+    let mut lines = vec![];
+    let mut text = String::new();
+    let mut len = 0;
+    for op in ops {
+        for c in op.get_data().chars() {
+            len += c.len_utf16();
+            if c == '\n' {
+                lines.push(LineStats::new(&text, len, Some(&op.get_attributes())));
+                text.clear();
+                len = 0;
+            } else {
+                text.push(c);
+            }
+        }
+    }
+    if len > 0 {
+        lines.push(LineStats::new(&text, len, None));
+    }
+    lines
+}
+
+impl LineStats {
+    // The attributes of the newline are the block attributes of the line, the end
+    // of the document that isn't ended by a newline has none.
+    fn new(text: &str, len: usize, newline_attributes: Option<&Attributes>) -> Self {
+        let block = match newline_attributes.map(block_kind) {
+            Some(BlockKind::Paragraph) | None if text.trim().is_empty() => None,
+            Some(block) => Some(block),
+            None => Some(BlockKind::Paragraph),
+        };
+        LineStats {
+            len,
+            has_newline: newline_attributes.is_some(),
+            words: text.split_whitespace().count(),
+            chars: text.chars().count(),
+            block,
+        }
+    }
+}
+
+fn block_kind(attributes: &Attributes) -> BlockKind {
+    let has = |key: AttributeKey| attributes.get(&key).and_then(|value| value.0.as_ref()).is_some();
+    if has(AttributeKey::CodeBlock) {
+        BlockKind::Code
+    } else if has(AttributeKey::Header) {
+        BlockKind::Header
+    } else if has(AttributeKey::List) {
+        BlockKind::List
+    } else if has(AttributeKey::BlockQuote) {
+        BlockKind::Quote
+    } else {
+        BlockKind::Paragraph
+    }
+}
+
+pub(crate) fn doc_stats(doc_id: &str, count: &StatsCount) -> DocStats {
+    DocStats {
+        doc_id: doc_id.to_owned(),
+        word_count: count.words as i64,
+        char_count: count.chars as i64,
+        reading_time: count.reading_time() as i64,
+        paragraph_count: count.paragraphs as i64,
+        header_count: count.headers as i64,
+        list_count: count.lists as i64,
+        quote_count: count.quotes as i64,
+        code_count: count.codes as i64,
+    }
+}
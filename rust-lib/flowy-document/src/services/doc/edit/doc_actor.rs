@@ -1,33 +1,62 @@
 use crate::{
-    errors::DocResult,
-    services::doc::{Document, UndoResult},
+    errors::{internal_error, DocError, DocResult},
+    notify::{dart_notify, DocObservable},
+    services::{
+        check::{DocumentChecker, TextChecker},
+        comment::save_anchors,
+        doc::{doc_stats, read_page, Block, BlockOperation, Document, Page, StatsCount, UndoResult},
+        search::DocumentIndexer,
+    },
 };
 use async_stream::stream;
 use bytes::Bytes;
+use flowy_database::ConnectionPool;
 use flowy_document_infra::entities::doc::{RevId, Revision};
 use flowy_ot::core::{Attribute, Delta, Interval, OperationTransformable};
 use futures::stream::StreamExt;
-use std::{convert::TryFrom, sync::Arc};
+use std::{convert::TryFrom, sync::Arc, time::Duration};
 use tokio::sync::{mpsc, oneshot, RwLock};
 
 pub struct DocumentActor {
     doc_id: String,
     document: Arc<RwLock<Document>>,
     receiver: Option<mpsc::UnboundedReceiver<DocumentMsg>>,
+    pool: Arc<ConnectionPool>,
+    indexer: Option<DocumentIndexer>,
+    checker: DocumentChecker,
 }
 
 impl DocumentActor {
-    pub fn new(doc_id: &str, delta: Delta, receiver: mpsc::UnboundedReceiver<DocumentMsg>) -> Self {
-        let document = Arc::new(RwLock::new(Document::from_delta(delta)));
+    pub fn new(
+        doc_id: &str,
+        document: Document,
+        receiver: mpsc::UnboundedReceiver<DocumentMsg>,
+        pool: Arc<ConnectionPool>,
+        index_quiet_period: Option<Duration>,
+        text_checker: Arc<dyn TextChecker>,
+        check_quiet_period: Duration,
+    ) -> Self {
+        let document = Arc::new(RwLock::new(document));
+        let indexer = index_quiet_period
+            .map(|quiet_period| DocumentIndexer::new(doc_id, document.clone(), pool.clone(), quiet_period));
+        let checker = DocumentChecker::new(doc_id, document.clone(), text_checker, check_quiet_period);
         Self {
             doc_id: doc_id.to_owned(),
             document,
             receiver: Some(receiver),
+            pool,
+            indexer,
+            checker,
         }
     }
 
     pub async fn run(mut self) {
         let mut receiver = self.receiver.take().expect("Should only call once");
+        // The document may be changed before it was indexed.
+        if let Some(indexer) = &self.indexer {
+            indexer.schedule().await;
+        }
+        self.checker.schedule().await;
         let stream = stream! {
             loop {
                 match receiver.recv().await {
@@ -46,12 +75,46 @@ impl DocumentActor {
             .await;
     }
 
+    // The editor is notified when the stats are changed by the message, so it can
+    // show the live counts. The ranges of the comments that the message moved are
+    // saved and the document is indexed and checked again if the message changed
+    // it.
     async fn handle_message(&self, msg: DocumentMsg) -> DocResult<()> {
+        let count = self.document.read().await.stats().count().clone();
+        let is_change = msg.is_change();
+        let _ = self.apply_message(msg).await?;
+        if is_change {
+            if let Some(indexer) = &self.indexer {
+                indexer.schedule().await;
+            }
+            self.checker.schedule().await;
+        }
+
+        let new_count = self.document.read().await.stats().count().clone();
+        if new_count != count {
+            dart_notify(&self.doc_id, DocObservable::StatsChanged)
+                .payload(doc_stats(&self.doc_id, &new_count))
+                .send();
+        }
+
+        let anchors = self.document.write().await.anchors_mut().take_changed();
+        if !anchors.is_empty() {
+            let conn = &*self.pool.get().map_err(internal_error)?;
+            let _ = save_anchors(&self.doc_id, anchors, conn)?;
+        }
+        Ok(())
+    }
+
+    async fn apply_message(&self, msg: DocumentMsg) -> DocResult<()> {
         match msg {
             DocumentMsg::Delta { delta, ret } => {
                 let result = self.composed_delta(delta).await;
                 let _ = ret.send(result);
             },
+            DocumentMsg::Restore { delta, ret } => {
+                let result = self.document.write().await.compose_delta_as_unit(delta);
+                let _ = ret.send(result);
+            },
             DocumentMsg::RemoteRevision { bytes, ret } => {
                 let revision = Revision::try_from(bytes)?;
                 let delta = Delta::from_bytes(&revision.delta_data)?;
@@ -102,6 +165,49 @@ impl DocumentActor {
                 let data = self.document.read().await.to_json();
                 let _ = ret.send(Ok(data));
             },
+            DocumentMsg::Stats { ret } => {
+                let count = self.document.read().await.stats().count().clone();
+                let _ = ret.send(Ok(count));
+            },
+            DocumentMsg::Blocks { ret } => {
+                let blocks = self.document.read().await.blocks();
+                let _ = ret.send(Ok(blocks));
+            },
+            DocumentMsg::Page {
+                rev_id,
+                cursor,
+                limit,
+                ret,
+            } => {
+                let result = read_page(self.document.read().await.delta(), rev_id, &cursor, limit);
+                let _ = ret.send(result);
+            },
+            DocumentMsg::BlockOps { ops, ret } => {
+                let result = self.document.write().await.apply_block_ops(&ops);
+                let _ = ret.send(result);
+            },
+            DocumentMsg::AddAnchor { id, interval, ret } => {
+                let mut document = self.document.write().await;
+                let result = match interval.end() > document.delta().target_len {
+                    true => Err(DocError::out_of_bound()),
+                    false => {
+                        document.anchors_mut().insert(&id, interval);
+                        Ok(())
+                    },
+                };
+                let _ = ret.send(result);
+            },
+            DocumentMsg::RemoveAnchor { id, ret } => {
+                self.document.write().await.anchors_mut().remove(&id);
+                let _ = ret.send(Ok(()));
+            },
+            DocumentMsg::FlushIndex { ret } => {
+                let result = match &self.indexer {
+                    None => Ok(()),
+                    Some(indexer) => indexer.flush().await,
+                };
+                let _ = ret.send(result);
+            },
         }
         Ok(())
     }
@@ -144,6 +250,10 @@ pub enum DocumentMsg {
         delta: Delta,
         ret: Ret<()>,
     },
+    Restore {
+        delta: Delta,
+        ret: Ret<()>,
+    },
     RemoteRevision {
         bytes: Bytes,
         ret: Ret<TransformDeltas>,
@@ -183,6 +293,51 @@ pub enum DocumentMsg {
     Doc {
         ret: Ret<String>,
     },
+    Stats {
+        ret: Ret<StatsCount>,
+    },
+    Blocks {
+        ret: Ret<Vec<Block>>,
+    },
+    Page {
+        rev_id: i64,
+        cursor: String,
+        limit: usize,
+        ret: Ret<Page>,
+    },
+    BlockOps {
+        ops: Vec<BlockOperation>,
+        ret: Ret<Delta>,
+    },
+    AddAnchor {
+        id: String,
+        interval: Interval,
+        ret: Ret<()>,
+    },
+    RemoveAnchor {
+        id: String,
+        ret: Ret<()>,
+    },
+    FlushIndex {
+        ret: Ret<()>,
+    },
+}
+
+impl DocumentMsg {
+    // Whether the message may change the text of the document.
+    fn is_change(&self) -> bool {
+        matches!(
+            self,
+            DocumentMsg::Delta { .. }
+                | DocumentMsg::Restore { .. }
+                | DocumentMsg::Insert { .. }
+                | DocumentMsg::Delete { .. }
+                | DocumentMsg::Replace { .. }
+                | DocumentMsg::Undo { .. }
+                | DocumentMsg::Redo { .. }
+                | DocumentMsg::BlockOps { .. }
+        )
+    }
 }
 
 pub struct TransformDeltas {
@@ -1,16 +1,31 @@
 use crate::{
-    entities::ws::{WsDataType, WsDocumentData},
+    entities::{
+        presence::DocPresence,
+        stats::DocStats,
+        version::DocVersion,
+        ws::{WsDataType, WsDocumentData},
+    },
     errors::{internal_error, DocError, DocResult},
-    module::DocumentUser,
+    module::{DocumentConfig, DocumentUser},
+    notify::{dart_notify, DocObservable},
     services::{
+        comment::read_anchors,
         doc::{
+            doc_stats,
+            Block,
+            BlockOperation,
+            Collaborators,
+            Document,
             DocumentActor,
             DocumentMsg,
             OpenDocAction,
+            Page,
             RevisionManager,
             RevisionServer,
+            StatsCount,
             TransformDeltas,
             UndoResult,
+            VersionHistory,
         },
         ws::{DocumentWebSocket, WsDocumentHandler},
     },
@@ -18,7 +33,10 @@ use crate::{
 use bytes::Bytes;
 use flowy_database::ConnectionPool;
 use flowy_document_infra::entities::doc::{DocDelta, RevId, RevType, Revision, RevisionRange};
-use flowy_infra::retry::{ExponentialBackoff, Retry};
+use flowy_infra::{
+    retry::{ExponentialBackoff, Retry},
+    secret::Cipher,
+};
 use flowy_ot::core::{Attribute, Delta, Interval};
 use flowy_ws::WsState;
 use std::{convert::TryFrom, sync::Arc};
@@ -29,9 +47,11 @@ pub type DocId = String;
 pub struct ClientEditDoc {
     pub doc_id: DocId,
     rev_manager: Arc<RevisionManager>,
+    history: VersionHistory,
     document: UnboundedSender<DocumentMsg>,
     ws: Arc<dyn DocumentWebSocket>,
     user: Arc<dyn DocumentUser>,
+    collaborators: Collaborators,
 }
 
 impl ClientEditDoc {
@@ -41,21 +61,34 @@ impl ClientEditDoc {
         ws: Arc<dyn DocumentWebSocket>,
         server: Arc<dyn RevisionServer>,
         user: Arc<dyn DocumentUser>,
+        config: &DocumentConfig,
+        cipher: Option<Arc<Cipher>>,
     ) -> DocResult<Self> {
         let (sender, receiver) = mpsc::unbounded_channel();
-        let mut rev_manager = RevisionManager::new(doc_id, pool.clone(), server.clone(), sender);
+        let mut rev_manager = RevisionManager::new(
+            doc_id,
+            pool.clone(),
+            server.clone(),
+            sender,
+            config.autosave.clone(),
+            cipher.clone(),
+        );
         spawn_rev_receiver(receiver, ws.clone());
 
         let delta = rev_manager.load_document().await?;
-        let document = spawn_doc_edit_actor(doc_id, delta, pool.clone());
+        let indexed = cipher.is_none();
+        let history = VersionHistory::new(doc_id, config.snapshot_interval, pool.clone(), cipher)?;
+        let document = spawn_doc_edit_actor(doc_id, delta, config, pool.clone(), indexed)?;
         let doc_id = doc_id.to_string();
         let rev_manager = Arc::new(rev_manager);
         let edit_doc = Self {
             doc_id,
             rev_manager,
+            history,
             document,
             ws,
             user,
+            collaborators: Collaborators::new(),
         };
         edit_doc.notify_open_doc();
         Ok(edit_doc)
@@ -127,14 +160,49 @@ impl ClientEditDoc {
         let (ret, rx) = oneshot::channel::<DocResult<UndoResult>>();
         let msg = DocumentMsg::Undo { ret };
         let _ = self.document.send(msg);
-        rx.await.map_err(internal_error)?
+        let result = rx.await.map_err(internal_error)??;
+        let _ = self.save_local_delta(result.delta.clone()).await?;
+        Ok(result)
     }
 
     pub async fn redo(&self) -> Result<UndoResult, DocError> {
         let (ret, rx) = oneshot::channel::<DocResult<UndoResult>>();
         let msg = DocumentMsg::Redo { ret };
         let _ = self.document.send(msg);
-        rx.await.map_err(internal_error)?
+        let result = rx.await.map_err(internal_error)??;
+        let _ = self.save_local_delta(result.delta.clone()).await?;
+        Ok(result)
+    }
+
+    pub async fn versions(&self) -> DocResult<Vec<DocVersion>> { self.history.versions() }
+
+    pub async fn read_version(&self, rev_id: i64) -> DocResult<Delta> {
+        let _ = self.flush().await?;
+        self.history.read_version(rev_id)
+    }
+
+    // Replaces the document with the version. It's a new change, so the versions
+    // after it are kept and the restore can be undone.
+    pub async fn restore_version(&self, rev_id: i64) -> DocResult<()> {
+        let version = self.read_version(rev_id).await?;
+        let current = Delta::from_json(&self.delta().await?.data)?;
+        let mut change = Delta::new();
+        change.delete(current.target_len);
+        version
+            .ops
+            .iter()
+            .for_each(|op| change.insert(op.get_data(), op.get_attributes()));
+
+        let (ret, rx) = oneshot::channel::<DocResult<()>>();
+        let msg = DocumentMsg::Restore {
+            delta: change.clone(),
+            ret,
+        };
+        let _ = self.document.send(msg);
+        let _ = rx.await.map_err(internal_error)??;
+
+        let _ = self.save_local_delta(change).await?;
+        Ok(())
     }
 
     pub async fn delta(&self) -> DocResult<DocDelta> {
@@ -149,15 +217,135 @@ impl ClientEditDoc {
         })
     }
 
+    pub fn rev_id(&self) -> i64 { self.rev_manager.rev_id() }
+
+    pub async fn stats(&self) -> DocResult<DocStats> {
+        let (ret, rx) = oneshot::channel::<DocResult<StatsCount>>();
+        let msg = DocumentMsg::Stats { ret };
+        let _ = self.document.send(msg);
+        let count = rx.await.map_err(internal_error)??;
+        Ok(doc_stats(&self.doc_id, &count))
+    }
+
+    pub async fn blocks(&self) -> DocResult<Vec<Block>> {
+        let (ret, rx) = oneshot::channel::<DocResult<Vec<Block>>>();
+        let msg = DocumentMsg::Blocks { ret };
+        let _ = self.document.send(msg);
+        rx.await.map_err(internal_error)?
+    }
+
+    pub async fn read_page(&self, cursor: &str, limit: usize) -> DocResult<Page> {
+        let (ret, rx) = oneshot::channel::<DocResult<Page>>();
+        let msg = DocumentMsg::Page {
+            rev_id: self.rev_id(),
+            cursor: cursor.to_owned(),
+            limit,
+            ret,
+        };
+        let _ = self.document.send(msg);
+        rx.await.map_err(internal_error)?
+    }
+
+    // Only the lines that the operations touch are in the saved revision.
+    pub async fn apply_block_ops(&self, ops: Vec<BlockOperation>) -> DocResult<()> {
+        let (ret, rx) = oneshot::channel::<DocResult<Delta>>();
+        let msg = DocumentMsg::BlockOps { ops, ret };
+        let _ = self.document.send(msg);
+        let delta = rx.await.map_err(internal_error)??;
+        if !delta.is_empty() {
+            let _ = self.save_local_delta(delta).await?;
+        }
+        Ok(())
+    }
+
+    // The range of the comment is moved by the edits of the document from now on.
+    pub async fn add_anchor(&self, comment_id: &str, interval: Interval) -> DocResult<()> {
+        let (ret, rx) = oneshot::channel::<DocResult<()>>();
+        let msg = DocumentMsg::AddAnchor {
+            id: comment_id.to_owned(),
+            interval,
+            ret,
+        };
+        let _ = self.document.send(msg);
+        rx.await.map_err(internal_error)?
+    }
+
+    pub async fn remove_anchor(&self, comment_id: &str) -> DocResult<()> {
+        let (ret, rx) = oneshot::channel::<DocResult<()>>();
+        let msg = DocumentMsg::RemoveAnchor {
+            id: comment_id.to_owned(),
+            ret,
+        };
+        let _ = self.document.send(msg);
+        rx.await.map_err(internal_error)?
+    }
+
+    // The cursor and the selection of the user are sent to the other users that
+    // are editing the document.
+    pub fn update_presence(&self, start: i64, end: i64) -> DocResult<()> {
+        if start < 0 || end < start {
+            return Err(DocError::out_of_bound().context(format!("Invalid selection {}..{}", start, end)));
+        }
+        self.send_presence(start, end, false)
+    }
+
+    pub fn clear_presence(&self) -> DocResult<()> { self.send_presence(0, 0, true) }
+
+    pub fn collaborators(&self) -> Vec<DocPresence> { self.collaborators.active() }
+
+    fn send_presence(&self, start: i64, end: i64, cleared: bool) -> DocResult<()> {
+        let presence = DocPresence {
+            doc_id: self.doc_id.clone(),
+            user_id: self.user.user_id()?,
+            start,
+            end,
+            cleared,
+            update_time: flowy_infra::timestamp(),
+        };
+        let _ = self.ws.send(presence.into());
+        Ok(())
+    }
+
+    fn receive_presence(&self, presence: DocPresence) -> DocResult<()> {
+        if presence.doc_id != self.doc_id || presence.user_id == self.user.user_id()? {
+            return Ok(());
+        }
+
+        if self.collaborators.receive(presence.clone()) {
+            dart_notify(&self.doc_id, DocObservable::CollaboratorsChanged)
+                .payload(presence)
+                .send();
+        }
+        Ok(())
+    }
+
     async fn save_local_delta(&self, delta: Delta) -> Result<RevId, DocError> {
         let delta_data = delta.to_bytes();
         let (base_rev_id, rev_id) = self.rev_manager.next_rev_id();
         let delta_data = delta_data.to_vec();
         let revision = Revision::new(base_rev_id, rev_id, delta_data, &self.doc_id, RevType::Local);
         let _ = self.rev_manager.add_revision(&revision).await?;
+        self.snapshot_if_needed(rev_id).await;
         Ok(rev_id.into())
     }
 
+    // A failed snapshot only makes the versions after it slower to read.
+    async fn snapshot_if_needed(&self, rev_id: i64) {
+        if !self.history.should_snapshot(rev_id) {
+            return;
+        }
+
+        let result = match self.delta().await {
+            Ok(doc) => Delta::from_json(&doc.data)
+                .map_err(DocError::from)
+                .and_then(|delta| self.history.snapshot(rev_id, &delta)),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            log::error!("Take snapshot of {} failed: {:?}", self.doc_id, e);
+        }
+    }
+
     #[tracing::instrument(level = "debug", skip(self, data), err)]
     pub(crate) async fn composing_local_delta(&self, data: Bytes) -> Result<(), DocError> {
         let delta = Delta::from_bytes(&data)?;
@@ -173,6 +361,49 @@ impl ClientEditDoc {
         Ok(())
     }
 
+    pub(crate) async fn pending_revisions(&self) -> usize { self.rev_manager.pending_revisions().await }
+
+    pub(crate) fn cached_revisions(&self) -> (usize, usize) { self.rev_manager.cached_revisions() }
+
+    // The delta of a change pulled from the server, it's composed with the
+    // document like the local ones but isn't sent to the server again.
+    #[tracing::instrument(level = "debug", skip(self, data), err)]
+    pub(crate) async fn composing_remote_delta(&self, data: Bytes) -> Result<(), DocError> {
+        let delta = Delta::from_bytes(&data)?;
+        let (ret, rx) = oneshot::channel::<DocResult<()>>();
+        let msg = DocumentMsg::Delta {
+            delta: delta.clone(),
+            ret,
+        };
+        let _ = self.document.send(msg);
+        let _ = rx.await.map_err(internal_error)??;
+
+        let (base_rev_id, rev_id) = self.rev_manager.next_rev_id();
+        let revision = Revision::new(
+            base_rev_id,
+            rev_id,
+            delta.to_bytes().to_vec(),
+            &self.doc_id,
+            RevType::Remote,
+        );
+        let _ = self.rev_manager.add_remote_revision(&revision).await?;
+        self.snapshot_if_needed(rev_id).await;
+
+        dart_notify(&self.doc_id, DocObservable::RemoteChanged)
+            .payload(self.delta().await?)
+            .send();
+        Ok(())
+    }
+
+    // Saves the revisions and indexes the document if a change is waiting for it.
+    pub(crate) async fn flush(&self) -> DocResult<()> {
+        let _ = self.rev_manager.flush().await?;
+        let (ret, rx) = oneshot::channel::<DocResult<()>>();
+        let msg = DocumentMsg::FlushIndex { ret };
+        let _ = self.document.send(msg);
+        rx.await.map_err(internal_error)?
+    }
+
     #[cfg(feature = "flowy_test")]
     pub async fn doc_json(&self) -> DocResult<String> {
         let (ret, rx) = oneshot::channel::<DocResult<String>>();
@@ -267,6 +498,10 @@ impl ClientEditDoc {
                 let _ = self.rev_manager.ack_revision(rev_id).await?;
             },
             WsDataType::Conflict => {},
+            WsDataType::Presence => {
+                let presence = DocPresence::try_from(bytes)?;
+                let _ = self.receive_presence(presence)?;
+            },
         }
         Ok(())
     }
@@ -289,6 +524,7 @@ impl WsDocumentHandler for EditDocWsHandler {
             WsState::Init => {},
             WsState::Connected(_) => self.0.notify_open_doc(),
             WsState::Disconnected(_e) => {},
+            WsState::Reconnecting { .. } => {},
         }
     }
 }
@@ -307,9 +543,37 @@ fn spawn_rev_receiver(mut receiver: mpsc::UnboundedReceiver<Revision>, ws: Arc<d
     });
 }
 
-fn spawn_doc_edit_actor(doc_id: &str, delta: Delta, _pool: Arc<ConnectionPool>) -> UnboundedSender<DocumentMsg> {
+fn spawn_doc_edit_actor(
+    doc_id: &str,
+    delta: Delta,
+    config: &DocumentConfig,
+    pool: Arc<ConnectionPool>,
+    indexed: bool,
+) -> DocResult<UnboundedSender<DocumentMsg>> {
     let (sender, receiver) = mpsc::unbounded_channel::<DocumentMsg>();
-    let actor = DocumentActor::new(doc_id, delta, receiver);
+    let mut document = Document::from_delta(delta).undo_capacity(config.undo_capacity);
+    let conn = &*pool.get().map_err(internal_error)?;
+    for (comment_id, interval) in read_anchors(doc_id, conn)? {
+        document.anchors_mut().insert(&comment_id, interval);
+    }
+    let len = document.delta().target_len;
+    document.anchors_mut().clamp(len);
+
+    // The encrypted documents aren't indexed, the index would keep their text in
+    // plaintext.
+    let index_quiet_period = match indexed {
+        true => Some(config.autosave.quiet_period),
+        false => None,
+    };
+    let actor = DocumentActor::new(
+        doc_id,
+        document,
+        receiver,
+        pool.clone(),
+        index_quiet_period,
+        config.text_checker.clone(),
+        config.autosave.quiet_period,
+    );
     tokio::spawn(actor.run());
-    sender
+    Ok(sender)
 }
@@ -1,7 +1,9 @@
 mod doc_actor;
 mod edit_doc;
 mod model;
+mod presence;
 
 pub(crate) use doc_actor::*;
 pub use edit_doc::*;
 pub(crate) use model::*;
+pub use presence::*;
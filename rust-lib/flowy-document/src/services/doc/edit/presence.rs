@@ -0,0 +1,55 @@
+use crate::entities::presence::DocPresence;
+use parking_lot::RwLock;
+use std::collections::HashMap;
+
+// The users that haven't updated their presence for this many seconds are not
+// shown anymore, it covers the users that left without clearing it.
+pub const PRESENCE_TIMEOUT: i64 = 60;
+
+// The presences of the other users that are editing the document, one for each
+// user.
+pub struct Collaborators {
+    users: RwLock<HashMap<String, DocPresence>>,
+}
+
+impl Collaborators {
+    pub fn new() -> Self {
+        Self {
+            users: RwLock::new(HashMap::new()),
+        }
+    }
+
+    // Returns false if the presence is older than the one that is kept for the
+    // user, the messages may arrive out of order.
+    pub fn receive(&self, presence: DocPresence) -> bool {
+        let mut users = self.users.write();
+        if let Some(current) = users.get(&presence.user_id) {
+            if current.update_time > presence.update_time {
+                return false;
+            }
+        }
+
+        match presence.cleared {
+            true => users.remove(&presence.user_id).is_some(),
+            false => {
+                users.insert(presence.user_id.clone(), presence);
+                true
+            },
+        }
+    }
+
+    pub fn active_at(&self, now: i64) -> Vec<DocPresence> {
+        let mut users = self.users.write();
+        users.retain(|_, presence| now - presence.update_time < PRESENCE_TIMEOUT);
+
+        let mut presences = users.values().cloned().collect::<Vec<_>>();
+        presences.sort_by(|a, b| a.user_id.cmp(&b.user_id));
+        presences
+    }
+
+    pub fn active(&self) -> Vec<DocPresence> { self.active_at(flowy_infra::timestamp()) }
+}
+
+impl std::default::Default for Collaborators {
+    fn default() -> Self { Self::new() }
+}
@@ -1,17 +1,31 @@
 use flowy_ot::core::Delta;
 
-const MAX_UNDOS: usize = 20;
+pub const MAX_UNDOS: usize = 20;
 
 #[derive(Debug, Clone)]
 pub struct UndoResult {
     success: bool,
     len: usize,
+    // The delta that was composed with the document, it's saved as a revision.
+    pub(crate) delta: Delta,
 }
 
 impl UndoResult {
-    pub fn fail() -> Self { UndoResult { success: false, len: 0 } }
+    pub fn fail() -> Self {
+        UndoResult {
+            success: false,
+            len: 0,
+            delta: Delta::default(),
+        }
+    }
 
-    pub fn success(len: usize) -> Self { UndoResult { success: true, len } }
+    pub fn success(len: usize, delta: Delta) -> Self {
+        UndoResult {
+            success: true,
+            len,
+            delta,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -23,12 +37,14 @@ pub struct History {
 }
 
 impl History {
-    pub fn new() -> Self {
+    pub fn new() -> Self { Self::with_capacity(MAX_UNDOS) }
+
+    pub fn with_capacity(capacity: usize) -> Self {
         History {
             cur_undo: 1,
             undos: Vec::new(),
             redoes: Vec::new(),
-            capacity: MAX_UNDOS,
+            capacity,
         }
     }
 
@@ -36,7 +52,12 @@ impl History {
 
     pub fn can_redo(&self) -> bool { !self.redoes.is_empty() }
 
-    pub fn add_undo(&mut self, delta: Delta) { self.undos.push(delta); }
+    pub fn add_undo(&mut self, delta: Delta) {
+        self.undos.push(delta);
+        if self.undos.len() > self.capacity {
+            self.undos.remove(0);
+        }
+    }
 
     pub fn add_redo(&mut self, delta: Delta) { self.redoes.push(delta); }
 
@@ -47,10 +68,6 @@ impl History {
 
         self.redoes.clear();
         self.add_undo(delta);
-
-        if self.undos.len() > self.capacity {
-            self.undos.remove(0);
-        }
     }
 
     pub fn undo(&mut self) -> Option<Delta> {
@@ -14,3 +14,4 @@ mod revision;
 pub use edit::*;
 
 pub(crate) use revision::*;
+pub use revision::{AutosaveConfig, DEFAULT_SNAPSHOT_INTERVAL};
@@ -0,0 +1,127 @@
+use crate::{
+    errors::DocResult,
+    notify::{dart_notify, DocObservable},
+    services::doc::revision::model::*,
+    sql_tables::RevState,
+};
+use dashmap::DashMap;
+use flowy_document_infra::entities::doc::Revision;
+use flowy_infra::clock::{system_clock, Clock};
+use std::{sync::Arc, time::Duration};
+use tokio::{sync::RwLock, task::JoinHandle};
+
+#[derive(Debug, Clone)]
+pub struct AutosaveConfig {
+    // The revisions are saved once no change was applied for this long.
+    pub quiet_period: Duration,
+    // The revisions are saved right away when this many are waiting, even if the changes
+    // keep coming.
+    pub max_pending_revs: usize,
+    // The quiet period is measured with it.
+    pub clock: Arc<dyn Clock>,
+}
+
+impl std::default::Default for AutosaveConfig {
+    fn default() -> Self {
+        AutosaveConfig {
+            quiet_period: Duration::from_millis(300),
+            max_pending_revs: 50,
+            clock: system_clock(),
+        }
+    }
+}
+
+// Batches the revisions of the document and writes them to the database. The UI is told
+// about every save with the Saving, Saved and SaveFailed notifications.
+pub(crate) struct Autosave {
+    doc_id: String,
+    config: AutosaveConfig,
+    revs_map: Arc<DashMap<i64, RevisionRecord>>,
+    persistence: Arc<Persistence>,
+    defer_save: RwLock<Option<JoinHandle<()>>>,
+}
+
+impl Autosave {
+    pub(crate) fn new(
+        doc_id: &str,
+        config: AutosaveConfig,
+        revs_map: Arc<DashMap<i64, RevisionRecord>>,
+        persistence: Arc<Persistence>,
+    ) -> Self {
+        Self {
+            doc_id: doc_id.to_owned(),
+            config,
+            revs_map,
+            persistence,
+            defer_save: RwLock::new(None),
+        }
+    }
+
+    // Restarts the quiet period.
+    pub(crate) async fn schedule(&self) {
+        if let Some(handler) = self.defer_save.write().await.take() {
+            handler.abort();
+        }
+
+        if self.revs_map.is_empty() {
+            return;
+        }
+
+        if self.revs_map.len() >= self.config.max_pending_revs {
+            let _ = save(&self.doc_id, &self.revs_map, &self.persistence);
+            return;
+        }
+
+        let doc_id = self.doc_id.clone();
+        let revs_map = self.revs_map.clone();
+        let persistence = self.persistence.clone();
+        let quiet = self.config.clock.sleep(self.config.quiet_period);
+        *self.defer_save.write().await = Some(tokio::spawn(async move {
+            quiet.await;
+            let _ = save(&doc_id, &revs_map, &persistence);
+        }));
+    }
+
+    // Saves the revisions right away instead of waiting for the quiet period.
+    pub(crate) async fn flush(&self) -> DocResult<()> {
+        if let Some(handler) = self.defer_save.write().await.take() {
+            handler.abort();
+        }
+
+        if self.revs_map.is_empty() {
+            return Ok(());
+        }
+        save(&self.doc_id, &self.revs_map, &self.persistence)
+    }
+}
+
+fn save(doc_id: &str, revs_map: &DashMap<i64, RevisionRecord>, persistence: &Persistence) -> DocResult<()> {
+    dart_notify(doc_id, DocObservable::Saving).send();
+    match save_revs(revs_map, persistence) {
+        Ok(_) => {
+            dart_notify(doc_id, DocObservable::Saved).send();
+            Ok(())
+        },
+        Err(e) => {
+            log::error!("Save revision failed: {:?}", e);
+            dart_notify(doc_id, DocObservable::SaveFailed).error(e.clone()).send();
+            Err(e)
+        },
+    }
+}
+
+fn save_revs(revs_map: &DashMap<i64, RevisionRecord>, persistence: &Persistence) -> DocResult<()> {
+    let ids = revs_map.iter().map(|kv| *kv.key()).collect::<Vec<i64>>();
+    let revisions_state = revs_map
+        .iter()
+        .map(|kv| (kv.revision.clone(), kv.state))
+        .collect::<Vec<(Revision, RevState)>>();
+
+    persistence.create_revs(revisions_state.clone())?;
+    tracing::debug!(
+        "Revision State Changed: {:?}",
+        revisions_state.iter().map(|s| (s.0.rev_id, s.1)).collect::<Vec<_>>()
+    );
+    revs_map.retain(|k, _| !ids.contains(k));
+    Ok(())
+}
@@ -1,13 +1,13 @@
 use crate::{
     errors::{DocError, DocResult},
-    services::doc::revision::RevisionStore,
+    services::doc::revision::{AutosaveConfig, RevisionStore},
 };
 use flowy_database::ConnectionPool;
 use flowy_document_infra::{
     entities::doc::{Doc, RevId, RevType, Revision, RevisionRange},
     util::RevIdCounter,
 };
-use flowy_infra::future::ResultFuture;
+use flowy_infra::{future::ResultFuture, secret::Cipher};
 use flowy_ot::core::{Delta, OperationTransformable};
 use std::sync::Arc;
 use tokio::sync::mpsc;
@@ -28,8 +28,10 @@ impl RevisionManager {
         pool: Arc<ConnectionPool>,
         server: Arc<dyn RevisionServer>,
         pending_rev_sender: mpsc::UnboundedSender<Revision>,
+        autosave_config: AutosaveConfig,
+        cipher: Option<Arc<Cipher>>,
     ) -> Self {
-        let rev_store = RevisionStore::new(doc_id, pool, server, pending_rev_sender);
+        let rev_store = RevisionStore::new(doc_id, pool, server, pending_rev_sender, autosave_config, cipher);
         let rev_id_counter = RevIdCounter::new(0);
         Self {
             doc_id: doc_id.to_string(),
@@ -49,11 +51,22 @@ impl RevisionManager {
         Ok(())
     }
 
+    pub async fn add_remote_revision(&self, revision: &Revision) -> Result<(), DocError> {
+        let _ = self.rev_store.add_remote_revision(revision.clone()).await?;
+        Ok(())
+    }
+
+    pub async fn pending_revisions(&self) -> usize { self.rev_store.pending_count().await }
+
+    pub fn cached_revisions(&self) -> (usize, usize) { self.rev_store.cached_revisions() }
+
     pub async fn ack_revision(&self, rev_id: RevId) -> Result<(), DocError> {
         self.rev_store.ack_revision(rev_id).await;
         Ok(())
     }
 
+    pub async fn flush(&self) -> Result<(), DocError> { self.rev_store.flush().await }
+
     pub fn rev_id(&self) -> i64 { self.rev_id_counter.value() }
 
     pub fn next_rev_id(&self) -> (i64, i64) {
@@ -1,6 +1,11 @@
+mod autosave;
 mod manager;
 mod model;
 mod persistence;
+mod snapshot;
 
+pub use autosave::AutosaveConfig;
 pub use manager::*;
 pub use persistence::*;
+pub use snapshot::DEFAULT_SNAPSHOT_INTERVAL;
+pub(crate) use snapshot::*;
@@ -1,10 +1,11 @@
 use crate::{
     errors::{internal_error, DocError, DocResult},
+    services::encryption::{decrypt_revision, encrypt_revision},
     sql_tables::{RevState, RevTableSql},
 };
-use flowy_database::ConnectionPool;
+use flowy_database::{ConnectionPool, SqliteConnection};
 use flowy_document_infra::entities::doc::{Revision, RevisionRange};
-use flowy_infra::future::ResultFuture;
+use flowy_infra::{future::ResultFuture, secret::Cipher};
 
 use std::sync::Arc;
 use tokio::sync::broadcast;
@@ -44,35 +45,59 @@ impl PendingRevId {
     }
 }
 
+// The revisions of the encrypted document are encrypted with the cipher before they're
+// written and decrypted after they're read.
 pub(crate) struct Persistence {
     pub(crate) rev_sql: Arc<RevTableSql>,
     pub(crate) pool: Arc<ConnectionPool>,
+    cipher: Option<Arc<Cipher>>,
 }
 
 impl Persistence {
-    pub(crate) fn new(pool: Arc<ConnectionPool>) -> Self {
+    pub(crate) fn new(pool: Arc<ConnectionPool>, cipher: Option<Arc<Cipher>>) -> Self {
         let rev_sql = Arc::new(RevTableSql {});
-        Self { rev_sql, pool }
+        Self { rev_sql, pool, cipher }
     }
 
     pub(crate) fn create_revs(&self, revisions: Vec<(Revision, RevState)>) -> DocResult<()> {
-        let conn = &*self.pool.get().map_err(internal_error)?;
-        conn.immediate_transaction::<_, DocError, _>(|| {
-            let _ = self.rev_sql.create_rev_table(revisions, conn)?;
-            Ok(())
-        })
+        let revisions = revisions
+            .into_iter()
+            .map(|(revision, state)| (encrypt_revision(self.cipher.as_deref(), revision), state))
+            .collect::<Vec<_>>();
+        // The revisions of the documents that are saved at the same time are
+        // committed together.
+        let rev_sql = self.rev_sql.clone();
+        let result = self
+            .pool
+            .write_batched(move |conn| rev_sql.create_rev_table(revisions, conn))
+            .map_err(internal_error)?;
+        result
     }
 
     pub(crate) fn read_rev_with_range(&self, doc_id: &str, range: RevisionRange) -> DocResult<Vec<Revision>> {
         let conn = &*self.pool.get().map_err(internal_error).unwrap();
         let revisions = self.rev_sql.read_rev_tables_with_range(doc_id, range, conn)?;
-        Ok(revisions)
+        self.decrypt_revs(revisions)
     }
 
     pub(crate) fn read_rev(&self, doc_id: &str, rev_id: &i64) -> DocResult<Option<Revision>> {
         let conn = self.pool.get().map_err(internal_error)?;
-        let some = self.rev_sql.read_rev_table(&doc_id, rev_id, &*conn)?;
-        Ok(some)
+        match self.rev_sql.read_rev_table(&doc_id, rev_id, &*conn)? {
+            None => Ok(None),
+            Some(revision) => Ok(Some(decrypt_revision(self.cipher.as_deref(), revision)?)),
+        }
+    }
+
+    pub(crate) fn read_revs(&self, doc_id: &str, conn: &SqliteConnection) -> DocResult<Vec<Revision>> {
+        let revisions = self.rev_sql.read_rev_tables(doc_id, conn)?;
+        self.decrypt_revs(revisions)
+    }
+
+    fn decrypt_revs(&self, revisions: Vec<Revision>) -> DocResult<Vec<Revision>> {
+        revisions
+            .into_iter()
+            .map(|revision| decrypt_revision(self.cipher.as_deref(), revision))
+            .collect()
     }
 }
 
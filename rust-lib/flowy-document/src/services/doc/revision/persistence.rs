@@ -1,19 +1,19 @@
 use crate::{
     errors::{internal_error, DocError, DocResult},
-    services::doc::revision::{model::*, RevisionServer},
+    services::doc::revision::{autosave::Autosave, model::*, AutosaveConfig, RevisionServer},
     sql_tables::RevState,
 };
 use async_stream::stream;
 use dashmap::DashMap;
 use flowy_database::{ConnectionPool, SqliteConnection};
 use flowy_document_infra::entities::doc::{revision_from_doc, Doc, RevId, RevType, Revision, RevisionRange};
-use flowy_infra::future::ResultFuture;
+use flowy_infra::{future::ResultFuture, secret::Cipher};
 use flowy_ot::core::{Delta, Operation, OperationTransformable};
 use futures::stream::StreamExt;
 use std::{collections::VecDeque, sync::Arc, time::Duration};
 use tokio::{
     sync::{broadcast, mpsc, RwLock},
-    task::{spawn_blocking, JoinHandle},
+    task::spawn_blocking,
 };
 
 pub struct RevisionStore {
@@ -22,7 +22,7 @@ pub struct RevisionStore {
     revs_map: Arc<DashMap<i64, RevisionRecord>>,
     pending_tx: PendingSender,
     pending_revs: Arc<RwLock<VecDeque<PendingRevId>>>,
-    defer_save: RwLock<Option<JoinHandle<()>>>,
+    autosave: Autosave,
     server: Arc<dyn RevisionServer>,
 }
 
@@ -32,12 +32,15 @@ impl RevisionStore {
         pool: Arc<ConnectionPool>,
         server: Arc<dyn RevisionServer>,
         ws_revision_sender: mpsc::UnboundedSender<Revision>,
+        autosave_config: AutosaveConfig,
+        cipher: Option<Arc<Cipher>>,
     ) -> Arc<RevisionStore> {
         let doc_id = doc_id.to_owned();
-        let persistence = Arc::new(Persistence::new(pool));
+        let persistence = Arc::new(Persistence::new(pool, cipher));
         let revs_map = Arc::new(DashMap::new());
         let (pending_tx, pending_rx) = mpsc::unbounded_channel();
         let pending_revs = Arc::new(RwLock::new(VecDeque::new()));
+        let autosave = Autosave::new(&doc_id, autosave_config, revs_map.clone(), persistence.clone());
 
         let store = Arc::new(Self {
             doc_id,
@@ -45,7 +48,7 @@ impl RevisionStore {
             revs_map,
             pending_revs,
             pending_tx,
-            defer_save: RwLock::new(None),
+            autosave,
             server,
         });
 
@@ -78,7 +81,23 @@ impl RevisionStore {
         self.revs_map.insert(revision.rev_id, RevisionRecord::new(revision));
 
         let _ = self.pending_tx.send(PendingMsg::Revision { ret: receiver });
-        self.save_revisions().await;
+        self.autosave.schedule().await;
+        Ok(())
+    }
+
+    // The revision of a change that came from the server, it's saved but isn't
+    // sent back.
+    pub async fn add_remote_revision(&self, revision: Revision) -> DocResult<()> {
+        if self.revs_map.contains_key(&revision.rev_id) {
+            return Err(DocError::duplicate_rev().context(format!("Duplicate revision id: {}", revision.rev_id)));
+        }
+
+        let record = RevisionRecord {
+            revision,
+            state: RevState::Acked,
+        };
+        self.revs_map.insert(record.revision.rev_id, record);
+        self.autosave.schedule().await;
         Ok(())
     }
 
@@ -90,40 +109,22 @@ impl RevisionStore {
             .await
             .retain(|pending| !pending.finish(rev_id));
 
-        self.save_revisions().await;
+        self.autosave.schedule().await;
     }
 
-    async fn save_revisions(&self) {
-        if let Some(handler) = self.defer_save.write().await.take() {
-            handler.abort();
-        }
-
-        if self.revs_map.is_empty() {
-            return;
-        }
+    pub async fn flush(&self) -> DocResult<()> { self.autosave.flush().await }
 
-        let revs_map = self.revs_map.clone();
-        let persistence = self.persistence.clone();
+    // The local revisions that the server didn't acknowledge yet.
+    pub async fn pending_count(&self) -> usize { self.pending_revs.read().await.len() }
 
-        *self.defer_save.write().await = Some(tokio::spawn(async move {
-            tokio::time::sleep(Duration::from_millis(300)).await;
-            let ids = revs_map.iter().map(|kv| kv.key().clone()).collect::<Vec<i64>>();
-            let revisions_state = revs_map
-                .iter()
-                .map(|kv| (kv.revision.clone(), kv.state))
-                .collect::<Vec<(Revision, RevState)>>();
-
-            match persistence.create_revs(revisions_state.clone()) {
-                Ok(_) => {
-                    tracing::debug!(
-                        "Revision State Changed: {:?}",
-                        revisions_state.iter().map(|s| (s.0.rev_id, s.1)).collect::<Vec<_>>()
-                    );
-                    revs_map.retain(|k, _| !ids.contains(k));
-                },
-                Err(e) => log::error!("Save revision failed: {:?}", e),
-            }
-        }));
+    // How many revisions are kept in memory and the size of their deltas.
+    pub fn cached_revisions(&self) -> (usize, usize) {
+        let bytes = self
+            .revs_map
+            .iter()
+            .map(|record| record.revision.delta_data.len())
+            .sum();
+        (self.revs_map.len(), bytes)
     }
 
     pub async fn revs_in_range(&self, range: RevisionRange) -> DocResult<Vec<Revision>> {
@@ -182,7 +183,7 @@ async fn fetch_from_local(doc_id: &str, persistence: Arc<Persistence>) -> DocRes
     let doc_id = doc_id.to_owned();
     spawn_blocking(move || {
         let conn = &*persistence.pool.get().map_err(internal_error)?;
-        let revisions = persistence.rev_sql.read_rev_tables(&doc_id, conn)?;
+        let revisions = persistence.read_revs(&doc_id, conn)?;
         if revisions.is_empty() {
             return Err(DocError::record_not_found().context("Local doesn't have this document"));
         }
@@ -235,7 +236,7 @@ fn validate_delta(doc_id: &str, persistence: Arc<Persistence>, conn: &SqliteConn
     if !data.ends_with("\n") {
         log::error!("The op must end with newline");
         let result = || {
-            let revisions = persistence.rev_sql.read_rev_tables(&doc_id, conn)?;
+            let revisions = persistence.read_revs(&doc_id, conn)?;
             for revision in revisions {
                 let delta = Delta::from_bytes(revision.delta_data)?;
                 log::error!("Invalid revision: {}:{}", revision.rev_id, delta.to_json());
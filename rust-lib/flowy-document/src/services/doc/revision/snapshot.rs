@@ -0,0 +1,150 @@
+use crate::{
+    entities::version::DocVersion,
+    errors::{internal_error, DocError, DocResult},
+    services::encryption::{decrypt_revision, decrypt_snapshot, encrypt_snapshot},
+    sql_tables::{DocSnapshotTable, DocSnapshotTableSql, RevTableSql},
+};
+use flowy_database::ConnectionPool;
+use flowy_document_infra::entities::doc::RevisionRange;
+use flowy_infra::secret::Cipher;
+use flowy_ot::core::{Delta, Operation, OperationTransformable};
+use std::sync::{
+    atomic::{AtomicI64, Ordering::SeqCst},
+    Arc,
+};
+
+pub const DEFAULT_SNAPSHOT_INTERVAL: i64 = 100;
+
+// Takes a snapshot of the document every `interval` revisions. The revisions
+// are kept in the rev_table, so the document at any rev_id is rebuilt from the
+// snapshot before it and the revisions after the snapshot. The snapshots of the
+// encrypted document are encrypted like its revisions.
+pub(crate) struct VersionHistory {
+    doc_id: String,
+    interval: i64,
+    last_snapshot_rev_id: AtomicI64,
+    snapshot_sql: DocSnapshotTableSql,
+    rev_sql: RevTableSql,
+    pool: Arc<ConnectionPool>,
+    cipher: Option<Arc<Cipher>>,
+}
+
+impl VersionHistory {
+    pub(crate) fn new(
+        doc_id: &str,
+        interval: i64,
+        pool: Arc<ConnectionPool>,
+        cipher: Option<Arc<Cipher>>,
+    ) -> DocResult<Self> {
+        let snapshot_sql = DocSnapshotTableSql {};
+        let conn = &*pool.get().map_err(internal_error)?;
+        let last_snapshot_rev_id = snapshot_sql
+            .read_snapshot_tables(doc_id, conn)?
+            .first()
+            .map(|table| table.rev_id)
+            .unwrap_or(0);
+
+        Ok(Self {
+            doc_id: doc_id.to_owned(),
+            interval,
+            last_snapshot_rev_id: AtomicI64::new(last_snapshot_rev_id),
+            snapshot_sql,
+            rev_sql: RevTableSql {},
+            pool,
+            cipher,
+        })
+    }
+
+    pub(crate) fn should_snapshot(&self, rev_id: i64) -> bool {
+        rev_id - self.last_snapshot_rev_id.load(SeqCst) >= self.interval
+    }
+
+    // The delta is the document after the revision with the rev_id.
+    pub(crate) fn snapshot(&self, rev_id: i64, delta: &Delta) -> DocResult<()> {
+        let conn = &*self.pool.get().map_err(internal_error)?;
+        let data = encrypt_snapshot(self.cipher.as_deref(), delta.to_json());
+        let table = DocSnapshotTable::new(&self.doc_id, rev_id, data);
+        let _ = self.snapshot_sql.create_snapshot_table(table, conn)?;
+        self.last_snapshot_rev_id.store(rev_id, SeqCst);
+        tracing::debug!("Take snapshot of {} at {}", self.doc_id, rev_id);
+        Ok(())
+    }
+
+    pub(crate) fn versions(&self) -> DocResult<Vec<DocVersion>> {
+        let conn = &*self.pool.get().map_err(internal_error)?;
+        let versions = self
+            .snapshot_sql
+            .read_snapshot_tables(&self.doc_id, conn)?
+            .into_iter()
+            .map(|table| DocVersion {
+                doc_id: table.doc_id,
+                rev_id: table.rev_id,
+                create_time: table.create_time,
+            })
+            .collect::<Vec<_>>();
+        Ok(versions)
+    }
+
+    // The document that is saved locally and its rev_id, it's read without
+    // opening the document. None if there's no local revision.
+    pub(crate) fn read_latest(&self) -> DocResult<Option<(Delta, i64)>> {
+        let conn = &*self.pool.get().map_err(internal_error)?;
+        let (mut delta, mut rev_id) = match self.snapshot_sql.read_snapshot_before(&self.doc_id, i64::MAX, conn)? {
+            None => (Delta::new(), 0),
+            Some(table) => {
+                let data = decrypt_snapshot(self.cipher.as_deref(), table.data)?;
+                (Delta::from_json(&data)?, table.rev_id)
+            },
+        };
+
+        let range = RevisionRange {
+            doc_id: self.doc_id.clone(),
+            start: rev_id + 1,
+            end: i64::MAX,
+        };
+        let revisions = self.rev_sql.read_rev_tables_with_range(&self.doc_id, range, conn)?;
+        if rev_id == 0 && revisions.is_empty() {
+            return Ok(None);
+        }
+        for revision in revisions {
+            let revision = decrypt_revision(self.cipher.as_deref(), revision)?;
+            rev_id = revision.rev_id;
+            delta = delta.compose(&Delta::from_bytes(revision.delta_data)?)?;
+        }
+        // Like the document that is opened, it ends with a newline.
+        if let Some(op) = delta.ops.last() {
+            if !op.get_data().ends_with('\n') {
+                delta.ops.push(Operation::Insert("\n".into()));
+            }
+        }
+        Ok(Some((delta, rev_id)))
+    }
+
+    pub(crate) fn read_version(&self, rev_id: i64) -> DocResult<Delta> {
+        let conn = &*self.pool.get().map_err(internal_error)?;
+        let (mut delta, start) = match self.snapshot_sql.read_snapshot_before(&self.doc_id, rev_id, conn)? {
+            None => (Delta::new(), 0),
+            Some(table) => {
+                let data = decrypt_snapshot(self.cipher.as_deref(), table.data)?;
+                (Delta::from_json(&data)?, table.rev_id + 1)
+            },
+        };
+
+        if start <= rev_id {
+            let range = RevisionRange {
+                doc_id: self.doc_id.clone(),
+                start,
+                end: rev_id,
+            };
+            let revisions = self.rev_sql.read_rev_tables_with_range(&self.doc_id, range, conn)?;
+            if revisions.last().map(|revision| revision.rev_id) != Some(rev_id) {
+                return Err(DocError::record_not_found().context(format!("The version {} doesn't exist", rev_id)));
+            }
+            for revision in revisions {
+                let revision = decrypt_revision(self.cipher.as_deref(), revision)?;
+                delta = delta.compose(&Delta::from_bytes(revision.delta_data)?)?;
+            }
+        }
+        Ok(delta)
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{
+    entities::encryption::DocEncryption,
+    errors::{internal_error, DocError, DocResult},
+    module::DocumentUser,
+    sql_tables::{DocEncryptionTable, DocEncryptionTableSql, DocSnapshotTableSql, RevTableSql},
+};
+use dashmap::DashMap;
+use flowy_database::SqliteConnection;
+use flowy_document_infra::{entities::doc::Revision, util::md5};
+use flowy_infra::secret::Cipher;
+use std::sync::Arc;
+
+mod sync;
+pub use sync::*;
+
+// Keeps the keys of the unlocked documents. The key of the encrypted document
+// is derived from the secret of the user and it's only kept in memory between
+// the unlock and the lock, the revisions and the snapshots of the document are
+// encrypted with it before they're written. The revisions that are sent to the
+// server are encrypted with the key of the workspace instead, see SyncKeyring.
+pub(crate) struct DocKeyring {
+    user: Arc<dyn DocumentUser>,
+    sql: DocEncryptionTableSql,
+    rev_sql: RevTableSql,
+    snapshot_sql: DocSnapshotTableSql,
+    unlocked: DashMap<String, Arc<Cipher>>,
+}
+
+impl DocKeyring {
+    pub(crate) fn new(user: Arc<dyn DocumentUser>) -> Self {
+        Self {
+            user,
+            sql: DocEncryptionTableSql {},
+            rev_sql: RevTableSql {},
+            snapshot_sql: DocSnapshotTableSql {},
+            unlocked: DashMap::new(),
+        }
+    }
+
+    // The documents whose key is kept in memory.
+    pub(crate) fn unlocked_count(&self) -> usize { self.unlocked.len() }
+
+    pub(crate) fn state(&self, doc_id: &str) -> DocResult<DocEncryption> {
+        let conn = &*self.user.db_pool()?.get().map_err(internal_error)?;
+        let encrypted = self.sql.is_encrypted(doc_id, conn)?;
+        Ok(DocEncryption {
+            doc_id: doc_id.to_owned(),
+            encrypted,
+            locked: encrypted && !self.unlocked.contains_key(doc_id),
+        })
+    }
+
+    // None if the document isn't encrypted.
+    pub(crate) fn cipher(&self, doc_id: &str) -> DocResult<Option<Arc<Cipher>>> {
+        let state = self.state(doc_id)?;
+        if !state.encrypted {
+            return Ok(None);
+        }
+        match self.unlocked.get(doc_id) {
+            None => Err(DocError::document_locked().context(format!("Unlock the document {} first", doc_id))),
+            Some(cipher) => Ok(Some(cipher.clone())),
+        }
+    }
+
+    // The key is checked against the newest revision, the document can't be
+    // unlocked if the secret of the user was changed.
+    pub(crate) fn unlock(&self, doc_id: &str) -> DocResult<DocEncryption> {
+        let state = self.state(doc_id)?;
+        if !state.locked {
+            return Ok(state);
+        }
+
+        let cipher = self.make_cipher(doc_id)?;
+        let conn = &*self.user.db_pool()?.get().map_err(internal_error)?;
+        if let Some(revision) = self.rev_sql.read_rev_tables(doc_id, conn)?.pop() {
+            let _ = decrypt_revision(Some(&cipher), revision)?;
+        }
+        self.unlocked.insert(doc_id.to_owned(), Arc::new(cipher));
+        self.state(doc_id)
+    }
+
+    pub(crate) fn lock(&self, doc_id: &str) -> DocResult<DocEncryption> {
+        self.unlocked.remove(doc_id);
+        self.state(doc_id)
+    }
+
+    // The document must be closed, so no revision is saved while its data is
+    // rewritten. It stays unlocked after it's encrypted.
+    pub(crate) fn encrypt(&self, doc_id: &str) -> DocResult<DocEncryption> {
+        let state = self.state(doc_id)?;
+        if state.encrypted {
+            return Ok(state);
+        }
+
+        let cipher = self.make_cipher(doc_id)?;
+        let conn = &*self.user.db_pool()?.get_write().map_err(internal_error)?;
+        conn.immediate_transaction::<_, DocError, _>(|| {
+            let _ = self.rewrite(
+                doc_id,
+                conn,
+                |data| Ok(cipher.encrypt(data)),
+                |data| Ok(cipher.encrypt_text(data)),
+            )?;
+            let _ = self
+                .sql
+                .create_encryption_table(DocEncryptionTable::new(doc_id), conn)?;
+            Ok(())
+        })?;
+        self.unlocked.insert(doc_id.to_owned(), Arc::new(cipher));
+        self.state(doc_id)
+    }
+
+    // The document must be closed and unlocked.
+    pub(crate) fn decrypt(&self, doc_id: &str) -> DocResult<DocEncryption> {
+        let cipher = match self.cipher(doc_id)? {
+            None => return self.state(doc_id),
+            Some(cipher) => cipher,
+        };
+
+        let conn = &*self.user.db_pool()?.get_write().map_err(internal_error)?;
+        conn.immediate_transaction::<_, DocError, _>(|| {
+            let _ = self.rewrite(
+                doc_id,
+                conn,
+                |data| cipher.decrypt(data).map_err(decrypt_error),
+                |data| cipher.decrypt_text(data).map_err(decrypt_error),
+            )?;
+            let _ = self.sql.delete_encryption_table(doc_id, conn)?;
+            Ok(())
+        })?;
+        self.unlocked.remove(doc_id);
+        self.state(doc_id)
+    }
+
+    pub(crate) fn delete(&self, doc_id: &str, conn: &SqliteConnection) -> DocResult<()> {
+        self.unlocked.remove(doc_id);
+        let _ = self.sql.delete_encryption_table(doc_id, conn)?;
+        Ok(())
+    }
+
+    fn make_cipher(&self, doc_id: &str) -> DocResult<Cipher> {
+        let secret = self.user.document_secret()?;
+        Ok(Cipher::new(secret.as_bytes(), doc_id))
+    }
+
+    fn rewrite<R, S>(&self, doc_id: &str, conn: &SqliteConnection, rev_data: R, snapshot_data: S) -> DocResult<()>
+    where
+        R: Fn(&[u8]) -> DocResult<Vec<u8>>,
+        S: Fn(&str) -> DocResult<String>,
+    {
+        for revision in self.rev_sql.read_rev_tables(doc_id, conn)? {
+            let data = rev_data(&revision.delta_data)?;
+            let _ = self.rev_sql.update_rev_data(doc_id, revision.rev_id, &data, conn)?;
+        }
+        for snapshot in self.snapshot_sql.read_snapshot_tables(doc_id, conn)? {
+            let data = snapshot_data(&snapshot.data)?;
+            let _ = self.snapshot_sql.update_snapshot_data(&snapshot.id, &data, conn)?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn encrypt_revision(cipher: Option<&Cipher>, mut revision: Revision) -> Revision {
+    if let Some(cipher) = cipher {
+        revision.delta_data = cipher.encrypt(&revision.delta_data);
+    }
+    revision
+}
+
+pub(crate) fn decrypt_revision(cipher: Option<&Cipher>, mut revision: Revision) -> DocResult<Revision> {
+    if let Some(cipher) = cipher {
+        revision.delta_data = cipher.decrypt(&revision.delta_data).map_err(decrypt_error)?;
+        revision.md5 = md5(&revision.delta_data);
+    }
+    Ok(revision)
+}
+
+pub(crate) fn encrypt_snapshot(cipher: Option<&Cipher>, data: String) -> String {
+    match cipher {
+        None => data,
+        Some(cipher) => cipher.encrypt_text(&data),
+    }
+}
+
+pub(crate) fn decrypt_snapshot(cipher: Option<&Cipher>, data: String) -> DocResult<String> {
+    match cipher {
+        None => Ok(data),
+        Some(cipher) => cipher.decrypt_text(&data).map_err(decrypt_error),
+    }
+}
+
+fn decrypt_error(e: String) -> DocError { DocError::decrypt_failed().context(e) }
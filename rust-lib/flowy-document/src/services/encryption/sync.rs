@@ -0,0 +1,152 @@
+use crate::{
+    entities::ws::{WsDataType, WsDocumentData},
+    errors::{DocError, DocResult},
+    services::ws::{DocumentWebSocket, WsDocumentHandler, WsStateReceiver},
+};
+use bytes::Bytes;
+use flowy_document_infra::{entities::doc::Revision, util::md5};
+use flowy_infra::secret::{is_encrypted, is_encrypted_text, Cipher};
+use flowy_ws::WsState;
+use parking_lot::RwLock;
+use std::{
+    convert::{TryFrom, TryInto},
+    sync::Arc,
+};
+
+// The key of the workspace that the document belongs to. Every member of the
+// workspace has it, so the payloads encrypted with it by one member are
+// decrypted by the others.
+#[derive(Clone)]
+pub struct SyncKey {
+    pub workspace_id: String,
+    pub key: String,
+}
+
+impl SyncKey {
+    pub fn cipher(&self) -> Cipher { Cipher::new(self.key.as_bytes(), &self.workspace_id) }
+}
+
+pub trait SyncKeyProvider: Send + Sync {
+    // None if the workspace of the document isn't encrypted, its payloads are
+    // synced as they are.
+    fn sync_key(&self, doc_id: &str) -> DocResult<Option<SyncKey>>;
+}
+
+// Encrypts the revisions before they're sent to the server and decrypts the
+// ones that are pulled from it, so the server only keeps the encrypted
+// payloads. The payloads that were synced before the workspace was encrypted
+// are read as they are.
+pub(crate) struct SyncKeyring {
+    provider: RwLock<Option<Arc<dyn SyncKeyProvider>>>,
+}
+
+impl SyncKeyring {
+    pub(crate) fn new() -> Self {
+        Self {
+            provider: RwLock::new(None),
+        }
+    }
+
+    pub(crate) fn set_provider(&self, provider: Arc<dyn SyncKeyProvider>) { *self.provider.write() = Some(provider); }
+
+    pub(crate) fn cipher(&self, doc_id: &str) -> DocResult<Option<Arc<Cipher>>> {
+        let provider = match &*self.provider.read() {
+            None => return Ok(None),
+            Some(provider) => provider.clone(),
+        };
+        let key = provider.sync_key(doc_id)?;
+        Ok(key.map(|key| Arc::new(key.cipher())))
+    }
+
+    pub(crate) fn open_text(&self, doc_id: &str, text: String) -> DocResult<String> {
+        match self.cipher(doc_id)? {
+            None => Ok(text),
+            Some(cipher) => open_text(&cipher, text),
+        }
+    }
+}
+
+pub(crate) fn open_text(cipher: &Cipher, text: String) -> DocResult<String> {
+    match is_encrypted_text(&text) {
+        false => Ok(text),
+        true => cipher.decrypt_text(&text).map_err(sync_decrypt_error),
+    }
+}
+
+fn seal_data(cipher: &Cipher, mut data: WsDocumentData) -> DocResult<WsDocumentData> {
+    if data.ty == WsDataType::PushRev {
+        let mut revision = Revision::try_from(Bytes::from(data.data))?;
+        revision.delta_data = cipher.encrypt(&revision.delta_data);
+        revision.md5 = md5(&revision.delta_data);
+        let bytes: Bytes = revision.try_into()?;
+        data.data = bytes.to_vec();
+    }
+    Ok(data)
+}
+
+fn open_data(cipher: &Cipher, mut data: WsDocumentData) -> DocResult<WsDocumentData> {
+    if data.ty == WsDataType::PushRev {
+        let mut revision = Revision::try_from(Bytes::from(data.data))?;
+        if is_encrypted(&revision.delta_data) {
+            revision.delta_data = cipher.decrypt(&revision.delta_data).map_err(sync_decrypt_error)?;
+            revision.md5 = md5(&revision.delta_data);
+        }
+        let bytes: Bytes = revision.try_into()?;
+        data.data = bytes.to_vec();
+    }
+    Ok(data)
+}
+
+fn sync_decrypt_error(e: String) -> DocError {
+    DocError::decrypt_failed().context(format!(
+        "The synced data can't be decrypted with the workspace key: {}",
+        e
+    ))
+}
+
+// The cipher is picked when the document is opened, a workspace that's
+// encrypted after it applies to the document the next time it's opened.
+pub(crate) struct SealedWebSocket {
+    inner: Arc<dyn DocumentWebSocket>,
+    cipher: Arc<Cipher>,
+}
+
+impl SealedWebSocket {
+    pub(crate) fn wrap(inner: Arc<dyn DocumentWebSocket>, cipher: Option<Arc<Cipher>>) -> Arc<dyn DocumentWebSocket> {
+        match cipher {
+            None => inner,
+            Some(cipher) => Arc::new(SealedWebSocket { inner, cipher }),
+        }
+    }
+}
+
+impl DocumentWebSocket for SealedWebSocket {
+    fn send(&self, data: WsDocumentData) -> Result<(), DocError> { self.inner.send(seal_data(&self.cipher, data)?) }
+
+    fn state_notify(&self) -> WsStateReceiver { self.inner.state_notify() }
+}
+
+pub(crate) struct SealedWsHandler {
+    inner: Arc<dyn WsDocumentHandler>,
+    cipher: Arc<Cipher>,
+}
+
+impl SealedWsHandler {
+    pub(crate) fn wrap(inner: Arc<dyn WsDocumentHandler>, cipher: Option<Arc<Cipher>>) -> Arc<dyn WsDocumentHandler> {
+        match cipher {
+            None => inner,
+            Some(cipher) => Arc::new(SealedWsHandler { inner, cipher }),
+        }
+    }
+}
+
+impl WsDocumentHandler for SealedWsHandler {
+    fn receive(&self, data: WsDocumentData) {
+        match open_data(&self.cipher, data) {
+            Ok(data) => self.inner.receive(data),
+            Err(e) => log::error!("{:?}", e),
+        }
+    }
+
+    fn state_changed(&self, state: &WsState) { self.inner.state_changed(state) }
+}
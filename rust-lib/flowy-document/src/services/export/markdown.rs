@@ -0,0 +1,229 @@
+use flowy_ot::core::{AttributeKey, Attributes, Delta, Operation};
+
+// Converts the document to Markdown. The block attributes of a line are kept by
+// the newline that ends it, so the document is split into lines first. The
+// attributes that Markdown can't express, such as the color or the underline,
+// are dropped.
+pub fn delta_to_markdown(delta: &Delta) -> String {
+    let mut markdown = String::new();
+    let mut prev: Option<Block> = None;
+    let mut ordered_counters: Vec<usize> = vec![];
+
+    for line in split_lines(delta) {
+        let block = Block::from_attributes(&line.attributes);
+        let text = match &block {
+            Block::Code => line.segments.iter().map(|(s, _)| s.as_str()).collect::<String>(),
+            _ => line.render_inline(),
+        };
+
+        if block == Block::Paragraph && text.trim().is_empty() {
+            continue;
+        }
+
+        match &block {
+            Block::List(ListKind::Ordered, indent) => {
+                ordered_counters.truncate(*indent + 1);
+                ordered_counters.resize(*indent + 1, 0);
+                ordered_counters[*indent] += 1;
+            },
+            Block::List(_, indent) => ordered_counters.truncate(*indent),
+            _ => ordered_counters.clear(),
+        }
+
+        if let Some(prev) = &prev {
+            if prev == &Block::Code && block != Block::Code {
+                markdown.push_str("\n```");
+            }
+            markdown.push_str(if prev.is_continued_by(&block) { "\n" } else { "\n\n" });
+        }
+        if block == Block::Code && prev.as_ref() != Some(&Block::Code) {
+            markdown.push_str("```\n");
+        }
+
+        match &block {
+            Block::Paragraph => markdown.push_str(&escape_block_start(&text)),
+            Block::Header(level) => markdown.push_str(&format!("{} {}", "#".repeat(*level), text)),
+            Block::List(kind, indent) => {
+                let marker = match kind {
+                    ListKind::Bullet => "-".to_owned(),
+                    ListKind::Ordered => format!("{}.", ordered_counters[*indent]),
+                    ListKind::Checked => "- [x]".to_owned(),
+                    ListKind::Unchecked => "- [ ]".to_owned(),
+                };
+                markdown.push_str(&format!("{}{} {}", "    ".repeat(*indent), marker, text));
+            },
+            Block::Quote => markdown.push_str(&format!("> {}", text)),
+            Block::Code => markdown.push_str(&text),
+        }
+        prev = Some(block);
+    }
+
+    if prev == Some(Block::Code) {
+        markdown.push_str("\n```");
+    }
+    if !markdown.is_empty() {
+        markdown.push('\n');
+    }
+    markdown
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ListKind {
+    Bullet,
+    Ordered,
+    Checked,
+    Unchecked,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Block {
+    Paragraph,
+    Header(usize),
+    // The kind and the indent of the item.
+    List(ListKind, usize),
+    Quote,
+    Code,
+}
+
+impl Block {
+    fn from_attributes(attributes: &Attributes) -> Self {
+        if value(attributes, AttributeKey::CodeBlock).is_some() {
+            return Block::Code;
+        }
+
+        if let Some(level) = value(attributes, AttributeKey::Header).and_then(|s| s.parse::<usize>().ok()) {
+            return Block::Header(level.clamp(1, 6));
+        }
+
+        let indent = value(attributes, AttributeKey::Indent)
+            .and_then(|s| s.parse::<usize>().ok())
+            .unwrap_or(0);
+        let kind = match value(attributes, AttributeKey::List) {
+            Some("bullet") => Some(ListKind::Bullet),
+            Some("ordered") => Some(ListKind::Ordered),
+            Some("checked") => Some(ListKind::Checked),
+            Some("unchecked") => Some(ListKind::Unchecked),
+            _ => None,
+        };
+        if let Some(kind) = kind {
+            return Block::List(kind, indent);
+        }
+
+        if value(attributes, AttributeKey::BlockQuote).is_some() {
+            return Block::Quote;
+        }
+        Block::Paragraph
+    }
+
+    // The lines of a list, a quote or a code block are not separated by an empty
+    // line.
+    fn is_continued_by(&self, next: &Block) -> bool {
+        matches!(
+            (self, next),
+            (Block::List(..), Block::List(..)) | (Block::Quote, Block::Quote) | (Block::Code, Block::Code)
+        )
+    }
+}
+
+#[derive(Default)]
+struct Line {
+    segments: Vec<(String, Attributes)>,
+    attributes: Attributes,
+}
+
+impl Line {
+    fn render_inline(&self) -> String {
+        self.segments
+            .iter()
+            .map(|(s, attributes)| render_inline(s, attributes))
+            .collect::<String>()
+    }
+}
+
+fn split_lines(delta: &Delta) -> Vec<Line> {
+    let mut lines = vec![];
+    let mut line = Line::default();
+    for op in &delta.ops {
+        if let Operation::Insert(insert) = op {
+            for (index, s) in insert.s.split('\n').enumerate() {
+                if index > 0 {
+                    line.attributes = insert.attributes.clone();
+                    lines.push(std::mem::take(&mut line));
+                }
+                if !s.is_empty() {
+                    line.segments.push((s.to_owned(), insert.attributes.clone()));
+                }
+            }
+        }
+    }
+
+    if !line.segments.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+fn render_inline(s: &str, attributes: &Attributes) -> String {
+    if value(attributes, AttributeKey::InlineCode).is_some() {
+        return match s.contains('`') {
+            false => format!("`{}`", s),
+            true => format!("`` {} ``", s),
+        };
+    }
+
+    let mut text = escape(s);
+    if value(attributes, AttributeKey::StrikeThrough).is_some() {
+        text = wrap(&text, "~~");
+    }
+    if value(attributes, AttributeKey::Italic).is_some() {
+        text = wrap(&text, "_");
+    }
+    if value(attributes, AttributeKey::Bold).is_some() {
+        text = wrap(&text, "**");
+    }
+    if let Some(link) = value(attributes, AttributeKey::Link) {
+        text = format!("[{}]({})", text, link.replace(' ', "%20").replace(')', "%29"));
+    }
+    text
+}
+
+// The whitespace is kept outside the markers, "** a**" isn't bold in Markdown.
+fn wrap(text: &str, marker: &str) -> String {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return text.to_owned();
+    }
+
+    let start = text.len() - text.trim_start().len();
+    let end = start + trimmed.len();
+    format!("{}{}{}{}{}", &text[..start], marker, trimmed, marker, &text[end..])
+}
+
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '~') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+// A paragraph that starts like a heading, a quote or a list would be read as
+// one.
+fn escape_block_start(text: &str) -> String {
+    if text.starts_with(['#', '>', '-', '+']) {
+        return format!("\\{}", text);
+    }
+
+    let digits = text.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 && text[digits..].starts_with(['.', ')']) {
+        return format!("{}\\{}", &text[..digits], &text[digits..]);
+    }
+    text.to_owned()
+}
+
+fn value(attributes: &Attributes, key: AttributeKey) -> Option<&str> {
+    attributes.get(&key).and_then(|value| value.0.as_deref())
+}
@@ -0,0 +1,22 @@
+mod markdown;
+
+pub use markdown::*;
+
+use crate::errors::DocError;
+use std::path::Path;
+
+// The exported documents larger than this are written to a file instead of
+// being returned inline.
+pub const MAX_INLINE_EXPORT_SIZE: usize = 1024 * 1024;
+
+// Writes the data to {dir}/export/{doc_id}.{extension} and returns the path.
+pub(crate) fn write_export(dir: &str, doc_id: &str, extension: &str, data: &[u8]) -> Result<String, DocError> {
+    let export_dir = Path::new(dir).join("export");
+    if !export_dir.exists() {
+        let _ = std::fs::create_dir_all(&export_dir)?;
+    }
+
+    let path = export_dir.join(format!("{}.{}", doc_id, extension));
+    let _ = std::fs::write(&path, data)?;
+    Ok(path.to_string_lossy().to_string())
+}
@@ -0,0 +1,343 @@
+use crate::services::import::{image_text, DeltaWriter};
+use flowy_ot::core::{Attribute, Attributes, Delta};
+
+// Converts the HTML to the document. The unknown elements are skipped but their
+// text is kept, the content of the script and style elements is dropped.
+pub fn html_to_delta(html: &str) -> Delta {
+    let mut reader = HtmlReader::default();
+    for token in tokenize(html) {
+        match token {
+            Token::Text(text) => reader.text(&decode_entities(text)),
+            Token::Open(tag) => reader.open(&tag),
+            Token::Close(name) => reader.close(&name),
+        }
+    }
+    reader.writer.finish()
+}
+
+struct Tag {
+    name: String,
+    attributes: Vec<(String, String)>,
+}
+
+impl Tag {
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+enum Token<'a> {
+    Text(&'a str),
+    Open(Tag),
+    Close(String),
+}
+
+const SKIPPED_ELEMENTS: [&str; 5] = ["script", "style", "head", "title", "template"];
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+    while !rest.is_empty() {
+        let start = match rest.find('<') {
+            None => {
+                tokens.push(Token::Text(rest));
+                break;
+            },
+            Some(start) => start,
+        };
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+            rest = &rest[start..];
+        }
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        if rest.starts_with("<!") || rest.starts_with("<?") {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+            continue;
+        }
+
+        let end = match rest.find('>') {
+            None => {
+                tokens.push(Token::Text(rest));
+                break;
+            },
+            Some(end) => end,
+        };
+        let content = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if let Some(name) = content.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim().to_lowercase()));
+            continue;
+        }
+
+        let tag = parse_tag(content);
+        if tag.name.is_empty() {
+            tokens.push(Token::Text("<"));
+            continue;
+        }
+        if SKIPPED_ELEMENTS.contains(&tag.name.as_str()) {
+            let close = format!("</{}", tag.name);
+            // The ascii lowercase keeps the byte offsets of the html.
+            rest = rest
+                .to_ascii_lowercase()
+                .find(&close)
+                .map_or("", |index| rest[index..].find('>').map_or("", |end| &rest[index + end + 1..]));
+            continue;
+        }
+        tokens.push(Token::Open(tag));
+    }
+    tokens
+}
+
+fn parse_tag(content: &str) -> Tag {
+    let content = content.trim_end_matches('/');
+    let name_len = content.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(content.len());
+    let name = content[..name_len].to_lowercase();
+
+    let mut attributes = vec![];
+    let mut rest = content[name_len..].trim_start();
+    while !rest.is_empty() {
+        let key_len = rest
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let key = rest[..key_len].to_lowercase();
+        rest = rest[key_len..].trim_start();
+
+        let mut value = "";
+        if let Some(after) = rest.strip_prefix('=') {
+            let after = after.trim_start();
+            let (v, remaining) = match after.chars().next() {
+                Some(quote) if quote == '"' || quote == '\'' => {
+                    let end = after[1..].find(quote).map_or(after.len(), |end| end + 1);
+                    (&after[1..end], after.get(end + 1..).unwrap_or(""))
+                },
+                _ => {
+                    let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                    (&after[..end], &after[end..])
+                },
+            };
+            value = v;
+            rest = remaining.trim_start();
+        }
+        if !key.is_empty() {
+            attributes.push((key, decode_entities(value)));
+        }
+    }
+    Tag { name, attributes }
+}
+
+#[derive(Default)]
+struct HtmlReader {
+    writer: DeltaWriter,
+    // The inline elements that are open, with the format each of them applies.
+    inline: Vec<(String, Attribute)>,
+    header: Option<usize>,
+    quote_depth: usize,
+    in_pre: bool,
+    // The ul or ol elements that are open.
+    lists: Vec<String>,
+    list_item: Option<Attribute>,
+}
+
+impl HtmlReader {
+    fn text(&mut self, text: &str) {
+        let attributes = self.inline_attributes();
+        if self.in_pre {
+            for (index, line) in text.split('\n').enumerate() {
+                if index > 0 {
+                    self.writer.end_line(Attribute::CodeBlock(true).into());
+                }
+                self.writer.insert(line, attributes.clone());
+            }
+            return;
+        }
+
+        // The whitespace is collapsed like the browser does.
+        let mut collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if text.starts_with(char::is_whitespace) && self.writer.needs_space() {
+            collapsed.insert(0, ' ');
+        }
+        if !collapsed.trim().is_empty() && text.ends_with(char::is_whitespace) {
+            collapsed.push(' ');
+        }
+        self.writer.insert(&collapsed, attributes);
+    }
+
+    fn open(&mut self, tag: &Tag) {
+        let inline = match tag.name.as_str() {
+            "b" | "strong" => Some(Attribute::Bold(true)),
+            "i" | "em" => Some(Attribute::Italic(true)),
+            "u" => Some(Attribute::Underline(true)),
+            "s" | "del" | "strike" => Some(Attribute::StrikeThrough(true)),
+            "code" if !self.in_pre => Some(Attribute::InlineCode(true)),
+            "a" => tag.attribute("href").map(Attribute::Link),
+            _ => None,
+        };
+        if let Some(attribute) = inline {
+            self.inline.push((tag.name.clone(), attribute));
+            return;
+        }
+
+        match tag.name.as_str() {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.break_line();
+                self.header = tag.name[1..].parse::<usize>().ok();
+            },
+            "blockquote" => {
+                self.break_line();
+                self.quote_depth += 1;
+            },
+            "pre" => {
+                self.break_line();
+                self.in_pre = true;
+            },
+            "ul" | "ol" => {
+                self.break_line();
+                self.lists.push(tag.name.clone());
+            },
+            "li" => {
+                self.break_line();
+                self.list_item = match self.lists.last().map(|list| list.as_str()) {
+                    Some("ol") => Some(Attribute::Ordered(true)),
+                    _ => Some(Attribute::Bullet(true)),
+                };
+            },
+            "input" if tag.attribute("type") == Some("checkbox") && self.list_item.is_some() => {
+                self.list_item = match tag.attribute("checked") {
+                    Some(_) => Some(Attribute::Checked(true)),
+                    None => Some(Attribute::UnChecked(true)),
+                };
+            },
+            "br" => match self.in_pre {
+                true => self.writer.end_line(Attribute::CodeBlock(true).into()),
+                false => self.break_line(),
+            },
+            "img" => {
+                let src = tag.attribute("src").unwrap_or("");
+                let alt = tag.attribute("alt").unwrap_or("");
+                if !src.is_empty() {
+                    let mut attributes = self.inline_attributes();
+                    attributes.add(Attribute::Link(src));
+                    self.writer.insert(image_text(alt, src), attributes);
+                }
+            },
+            "p" | "div" | "section" | "article" | "header" | "footer" | "table" | "tr" | "hr" => self.break_line(),
+            _ => {},
+        }
+    }
+
+    fn close(&mut self, name: &str) {
+        if let Some(index) = self.inline.iter().rposition(|(tag, _)| tag == name) {
+            self.inline.remove(index);
+            return;
+        }
+
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.break_line();
+                self.header = None;
+            },
+            "blockquote" => {
+                self.break_line();
+                self.quote_depth = self.quote_depth.saturating_sub(1);
+            },
+            "pre" => {
+                if !self.writer.line_is_empty() {
+                    self.writer.end_line(Attribute::CodeBlock(true).into());
+                }
+                self.in_pre = false;
+            },
+            "ul" | "ol" => {
+                self.break_line();
+                self.lists.pop();
+            },
+            "li" => {
+                self.break_line();
+                self.list_item = None;
+            },
+            "p" | "div" | "section" | "article" | "header" | "footer" | "table" | "tr" => self.break_line(),
+            _ => {},
+        }
+    }
+
+    fn break_line(&mut self) {
+        self.writer.trim_line_end();
+        if !self.writer.line_is_empty() {
+            let attributes = self.block_attributes();
+            self.writer.end_line(attributes);
+        }
+    }
+
+    fn inline_attributes(&self) -> Attributes {
+        let mut attributes = Attributes::default();
+        self.inline
+            .iter()
+            .for_each(|(_, attribute)| attributes.add(attribute.clone()));
+        attributes
+    }
+
+    fn block_attributes(&self) -> Attributes {
+        if let Some(level) = self.header {
+            return Attribute::Header(level).into();
+        }
+
+        if let Some(list_item) = &self.list_item {
+            let mut attributes: Attributes = list_item.clone().into();
+            if self.lists.len() > 1 {
+                attributes.add(Attribute::Indent(self.lists.len() - 1));
+            }
+            return attributes;
+        }
+
+        if self.quote_depth > 0 {
+            return Attribute::BlockQuote(true).into();
+        }
+        Attributes::default()
+    }
+}
+
+fn decode_entities(s: &str) -> String {
+    let mut decoded = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest.find(';').filter(|end| *end <= 10).map(|end| &rest[1..end]);
+        let c = entity.and_then(|entity| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => {
+                let code = match entity.strip_prefix("#x").or_else(|| entity.strip_prefix("#X")) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => entity.strip_prefix('#').and_then(|dec| dec.parse::<u32>().ok()),
+                };
+                code.and_then(std::char::from_u32)
+            },
+        });
+
+        match (c, entity) {
+            (Some(c), Some(entity)) => {
+                decoded.push(c);
+                rest = &rest[entity.len() + 2..];
+            },
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            },
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
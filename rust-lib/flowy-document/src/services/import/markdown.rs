@@ -0,0 +1,238 @@
+use crate::services::import::{image_text, DeltaWriter};
+use flowy_ot::core::{Attribute, Attributes, Delta};
+
+// Converts the Markdown to the document. The headers, lists, quotes, code blocks,
+// emphasis, inline code and links are kept, the rest is imported as plain text.
+pub fn markdown_to_delta(markdown: &str) -> Delta {
+    let mut writer = DeltaWriter::default();
+    let mut paragraph: Vec<&str> = vec![];
+    let mut lines = markdown.lines();
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim_start();
+        if let Some(fence) = fence(trimmed) {
+            write_paragraph(&mut writer, &mut paragraph);
+            for code in lines.by_ref() {
+                if code.trim_start().starts_with(fence) {
+                    break;
+                }
+                writer.insert(code, Attributes::default());
+                writer.end_line(Attribute::CodeBlock(true).into());
+            }
+            continue;
+        }
+
+        if trimmed.is_empty() || is_thematic_break(trimmed) {
+            write_paragraph(&mut writer, &mut paragraph);
+            continue;
+        }
+
+        let block = match header(trimmed) {
+            Some((level, text)) => Some((Attribute::Header(level).into(), text)),
+            None => match trimmed.strip_prefix('>') {
+                Some(text) => Some((Attribute::BlockQuote(true).into(), text.trim_start())),
+                None => list_item(trimmed).map(|(list, text)| {
+                    let mut attributes: Attributes = list.into();
+                    let indent = indent(line);
+                    if indent > 0 {
+                        attributes.add(Attribute::Indent(indent));
+                    }
+                    (attributes, text)
+                }),
+            },
+        };
+
+        match block {
+            None => paragraph.push(trimmed),
+            Some((attributes, text)) => {
+                write_paragraph(&mut writer, &mut paragraph);
+                write_inline(&mut writer, text.trim_end(), &Attributes::default());
+                writer.end_line(attributes);
+            },
+        }
+    }
+
+    write_paragraph(&mut writer, &mut paragraph);
+    writer.finish()
+}
+
+// The lines of a paragraph are joined, a single newline doesn't break the line in
+// Markdown.
+fn write_paragraph(writer: &mut DeltaWriter, paragraph: &mut Vec<&str>) {
+    if paragraph.is_empty() {
+        return;
+    }
+
+    let text = paragraph.iter().map(|line| line.trim()).collect::<Vec<_>>().join(" ");
+    write_inline(writer, &text, &Attributes::default());
+    writer.end_line(Attributes::default());
+    paragraph.clear();
+}
+
+fn write_inline(writer: &mut DeltaWriter, s: &str, attributes: &Attributes) {
+    let mut attributes = attributes.clone();
+    let mut text = String::new();
+    let mut prev: Option<char> = None;
+    let mut rest = s;
+
+    while let Some(c) = rest.chars().next() {
+        if c == '\\' {
+            if let Some(next) = rest[1..].chars().next().filter(|c| c.is_ascii_punctuation()) {
+                text.push(next);
+                rest = &rest[1 + next.len_utf8()..];
+                prev = Some(next);
+                continue;
+            }
+        }
+
+        if c == '`' {
+            let ticks = rest.chars().take_while(|c| *c == '`').count();
+            if let Some(end) = rest[ticks..].find(&rest[..ticks]) {
+                writer.insert(&std::mem::take(&mut text), attributes.clone());
+                let mut code_attributes = attributes.clone();
+                code_attributes.add(Attribute::InlineCode(true));
+                writer.insert(rest[ticks..ticks + end].trim(), code_attributes);
+                rest = &rest[ticks + end + ticks..];
+                prev = Some('`');
+                continue;
+            }
+        }
+
+        let image = c == '!' && rest[1..].starts_with('[');
+        if c == '[' || image {
+            let start = if image { 1 } else { 0 };
+            if let Some((label, url, len)) = link(&rest[start..]) {
+                writer.insert(&std::mem::take(&mut text), attributes.clone());
+                let mut link_attributes = attributes.clone();
+                link_attributes.add(Attribute::Link(url));
+                match image {
+                    true => writer.insert(image_text(label, url), link_attributes),
+                    false => write_inline(writer, label, &link_attributes),
+                }
+                rest = &rest[start + len..];
+                prev = Some(')');
+                continue;
+            }
+        }
+
+        if let Some((marker, attribute)) = emphasis(rest) {
+            let after = &rest[marker.len()..];
+            let is_open = attributes.contains_key(&attribute.key);
+            // An underscore inside a word, like snake_case, isn't emphasis.
+            let intraword = marker.starts_with('_') && matches!(prev, Some(c) if c.is_alphanumeric());
+            let can_open = !after.starts_with(char::is_whitespace) && after.contains(marker) && !intraword;
+            if is_open || can_open {
+                writer.insert(&std::mem::take(&mut text), attributes.clone());
+                match is_open {
+                    true => attributes.remove(attribute.key),
+                    false => attributes.add(attribute),
+                }
+                rest = after;
+                continue;
+            }
+        }
+
+        text.push(c);
+        prev = Some(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    writer.insert(&text, attributes);
+}
+
+fn emphasis(s: &str) -> Option<(&'static str, Attribute)> {
+    for marker in ["**", "__"] {
+        if s.starts_with(marker) {
+            return Some((marker, Attribute::Bold(true)));
+        }
+    }
+    if s.starts_with("~~") {
+        return Some(("~~", Attribute::StrikeThrough(true)));
+    }
+    for marker in ["*", "_"] {
+        if s.starts_with(marker) {
+            return Some((marker, Attribute::Italic(true)));
+        }
+    }
+    None
+}
+
+// Parses [label](url "title") and returns the label, the url and the length of it.
+fn link(s: &str) -> Option<(&str, &str, usize)> {
+    let mut depth = 0;
+    let mut label_end = None;
+    for (index, c) in s.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    label_end = Some(index);
+                    break;
+                }
+            },
+            _ => {},
+        }
+    }
+
+    let label_end = label_end?;
+    let dest = s[label_end + 1..].strip_prefix('(')?;
+    let dest_end = dest.find(')')?;
+    let url = dest[..dest_end].split_whitespace().next().unwrap_or("");
+    let url = url.trim_start_matches('<').trim_end_matches('>');
+    Some((&s[1..label_end], url, label_end + 2 + dest_end + 1))
+}
+
+fn fence(line: &str) -> Option<&'static str> {
+    ["```", "~~~"].iter().find(|fence| line.starts_with(*fence)).copied()
+}
+
+fn is_thematic_break(line: &str) -> bool {
+    let chars = line.chars().filter(|c| !c.is_whitespace()).collect::<Vec<_>>();
+    chars.len() >= 3 && ['-', '*', '_'].iter().any(|marker| chars.iter().all(|c| c == marker))
+}
+
+fn header(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+
+    let text = &line[level..];
+    if !text.is_empty() && !text.starts_with(' ') {
+        return None;
+    }
+    Some((level, text.trim().trim_end_matches('#').trim_end()))
+}
+
+fn list_item(line: &str) -> Option<(Attribute, &str)> {
+    for marker in ["- ", "* ", "+ "] {
+        if let Some(text) = line.strip_prefix(marker) {
+            if let Some(text) = text.strip_prefix("[ ] ") {
+                return Some((Attribute::UnChecked(true), text));
+            }
+            if let Some(text) = text.strip_prefix("[x] ").or_else(|| text.strip_prefix("[X] ")) {
+                return Some((Attribute::Checked(true), text));
+            }
+            return Some((Attribute::Bullet(true), text));
+        }
+    }
+
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 && digits < 10 {
+        let rest = &line[digits..];
+        if let Some(text) = rest.strip_prefix(". ").or_else(|| rest.strip_prefix(") ")) {
+            return Some((Attribute::Ordered(true), text));
+        }
+    }
+    None
+}
+
+// Both two and four spaces are commonly used for a nested list.
+fn indent(line: &str) -> usize {
+    let spaces = line
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .map(|c| if c == '\t' { 4 } else { 1 })
+        .sum::<usize>();
+    (spaces + 2) / 4
+}
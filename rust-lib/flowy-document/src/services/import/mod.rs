@@ -0,0 +1,68 @@
+mod html;
+mod markdown;
+
+pub use html::*;
+pub use markdown::*;
+
+use flowy_ot::core::{Attributes, Delta};
+
+// Collects the lines of the imported document. The text of a line is kept until
+// the line ends, because its block attributes are set on the newline after it.
+#[derive(Default)]
+struct DeltaWriter {
+    delta: Delta,
+    line: Vec<(String, Attributes)>,
+}
+
+impl DeltaWriter {
+    fn insert(&mut self, s: &str, attributes: Attributes) {
+        if !s.is_empty() {
+            self.line.push((s.to_owned(), attributes));
+        }
+    }
+
+    fn line_is_empty(&self) -> bool { self.line.is_empty() }
+
+    // Whether the text after a whitespace should start with a space.
+    fn needs_space(&self) -> bool {
+        match self.line.last() {
+            None => false,
+            Some((s, _)) => !s.ends_with(char::is_whitespace),
+        }
+    }
+
+    fn trim_line_end(&mut self) {
+        while let Some((s, _)) = self.line.last_mut() {
+            let len = s.trim_end().len();
+            s.truncate(len);
+            if !s.is_empty() {
+                break;
+            }
+            self.line.pop();
+        }
+    }
+
+    fn end_line(&mut self, attributes: Attributes) {
+        for (s, attributes) in self.line.drain(..) {
+            self.delta.insert(&s, attributes);
+        }
+        self.delta.insert("\n", attributes);
+    }
+
+    // The document always ends with a newline.
+    fn finish(mut self) -> Delta {
+        if !self.line.is_empty() || self.delta.is_empty() {
+            self.end_line(Attributes::default());
+        }
+        self.delta
+    }
+}
+
+// The images can't be embedded in the document, they are kept as links to the
+// image with the alt text.
+fn image_text<'a>(alt: &'a str, src: &'a str) -> &'a str {
+    match alt.trim().is_empty() {
+        true => src,
+        false => alt,
+    }
+}
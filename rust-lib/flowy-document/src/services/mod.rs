@@ -1,4 +1,13 @@
+pub mod asset;
+pub mod attachment;
+pub mod blob;
 mod cache;
+pub mod check;
+pub(crate) mod comment;
 pub mod doc;
+pub(crate) mod encryption;
+pub mod export;
+pub mod import;
+pub(crate) mod search;
 pub mod server;
 pub mod ws;
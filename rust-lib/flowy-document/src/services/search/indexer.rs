@@ -0,0 +1,69 @@
+use crate::{
+    errors::{internal_error, DocResult},
+    services::{doc::Document, search::index_document},
+};
+use flowy_database::ConnectionPool;
+use std::{sync::Arc, time::Duration};
+use tokio::{sync::RwLock, task::JoinHandle};
+
+// Indexes the opened document once no change was applied for the quiet period,
+// so the index isn't written on every keystroke.
+pub(crate) struct DocumentIndexer {
+    doc_id: String,
+    document: Arc<RwLock<Document>>,
+    pool: Arc<ConnectionPool>,
+    quiet_period: Duration,
+    defer_index: RwLock<Option<JoinHandle<()>>>,
+}
+
+impl DocumentIndexer {
+    pub(crate) fn new(
+        doc_id: &str,
+        document: Arc<RwLock<Document>>,
+        pool: Arc<ConnectionPool>,
+        quiet_period: Duration,
+    ) -> Self {
+        Self {
+            doc_id: doc_id.to_owned(),
+            document,
+            pool,
+            quiet_period,
+            defer_index: RwLock::new(None),
+        }
+    }
+
+    // Restarts the quiet period.
+    pub(crate) async fn schedule(&self) {
+        if let Some(handler) = self.defer_index.write().await.take() {
+            handler.abort();
+        }
+
+        let doc_id = self.doc_id.clone();
+        let document = self.document.clone();
+        let pool = self.pool.clone();
+        let quiet_period = self.quiet_period;
+        *self.defer_index.write().await = Some(tokio::spawn(async move {
+            tokio::time::sleep(quiet_period).await;
+            if let Err(e) = index(&doc_id, &document, &pool).await {
+                log::error!("Index document {} failed: {:?}", doc_id, e);
+            }
+        }));
+    }
+
+    // Indexes the document right away if a change is waiting for the quiet period.
+    pub(crate) async fn flush(&self) -> DocResult<()> {
+        match self.defer_index.write().await.take() {
+            None => Ok(()),
+            Some(handler) => {
+                handler.abort();
+                index(&self.doc_id, &self.document, &self.pool).await
+            },
+        }
+    }
+}
+
+async fn index(doc_id: &str, document: &RwLock<Document>, pool: &ConnectionPool) -> DocResult<()> {
+    let delta = document.read().await.delta().clone();
+    let conn = &*pool.get().map_err(internal_error)?;
+    index_document(doc_id, &delta, conn)
+}
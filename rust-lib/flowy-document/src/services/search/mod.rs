@@ -0,0 +1,152 @@
+mod indexer;
+
+pub(crate) use indexer::*;
+
+use crate::{
+    entities::search::{DocSearchResult, RepeatedDocSearchResult, SearchDocumentsRequest, SearchHighlight},
+    errors::{internal_error, DocError, DocResult},
+    module::DocumentUser,
+    sql_tables::{DocSearchTableSql, NewDocSearchTable, SNIPPET_MATCH_END, SNIPPET_MATCH_START},
+};
+use flowy_database::SqliteConnection;
+use flowy_dispatch::prelude::RequestArena;
+use flowy_ot::core::Delta;
+use std::{collections::HashMap, sync::Arc};
+
+pub const DEFAULT_SEARCH_LIMIT: i64 = 20;
+
+// The documents are indexed line by line, a change of the document only
+// inserts and deletes the lines that it changed.
+pub(crate) struct SearchIndex {
+    user: Arc<dyn DocumentUser>,
+    sql: DocSearchTableSql,
+}
+
+impl SearchIndex {
+    pub(crate) fn new(user: Arc<dyn DocumentUser>) -> Self {
+        Self {
+            user,
+            sql: DocSearchTableSql {},
+        }
+    }
+
+    pub(crate) fn search(
+        &self,
+        params: SearchDocumentsRequest,
+        arena: &RequestArena,
+    ) -> DocResult<RepeatedDocSearchResult> {
+        let query = match fts_query(&params.query, arena) {
+            None => return Ok(RepeatedDocSearchResult::default()),
+            Some(query) => query,
+        };
+        let limit = if params.limit > 0 {
+            params.limit
+        } else {
+            DEFAULT_SEARCH_LIMIT
+        };
+        let conn = &*self.user.db_pool()?.get().map_err(internal_error)?;
+        let items = self
+            .sql
+            .search(&query, limit, conn)?
+            .into_iter()
+            .map(|row| {
+                let (snippet, highlights) = parse_snippet(&row.snippet);
+                DocSearchResult {
+                    doc_id: row.doc_id,
+                    snippet,
+                    highlights,
+                }
+            })
+            .collect();
+        Ok(RepeatedDocSearchResult { items })
+    }
+
+    pub(crate) fn delete_all(&self, doc_id: &str, conn: &SqliteConnection) -> DocResult<()> {
+        self.sql.delete_search_tables(doc_id, conn)
+    }
+}
+
+pub(crate) fn index_document(doc_id: &str, delta: &Delta, conn: &SqliteConnection) -> DocResult<()> {
+    conn.immediate_transaction::<_, DocError, _>(|| update_search_lines(doc_id, delta, conn))
+}
+
+// Same as index_document, but it's written in the transaction of the caller.
+pub(crate) fn update_search_lines(doc_id: &str, delta: &Delta, conn: &SqliteConnection) -> DocResult<()> {
+    let sql = DocSearchTableSql {};
+    let mut indexed: HashMap<String, Vec<i32>> = HashMap::new();
+    for table in sql.read_search_tables(doc_id, conn)? {
+        indexed.entry(table.content).or_default().push(table.id);
+    }
+
+    let mut new_lines = vec![];
+    for line in search_lines(delta) {
+        match indexed.get_mut(&line).and_then(|ids| ids.pop()) {
+            Some(_) => {},
+            None => new_lines.push(NewDocSearchTable::new(doc_id, &line)),
+        }
+    }
+    let removed_ids = indexed.into_values().flatten().collect::<Vec<_>>();
+    if !removed_ids.is_empty() {
+        let _ = sql.delete_search_tables_with_ids(removed_ids, conn)?;
+    }
+    if !new_lines.is_empty() {
+        let _ = sql.create_search_tables(new_lines, conn)?;
+    }
+    Ok(())
+}
+
+fn search_lines(delta: &Delta) -> Vec<String> {
+    let text = delta.ops.iter().map(|op| op.get_data()).collect::<String>();
+    text.split('\n')
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| line.to_owned())
+        .collect()
+}
+
+// Every word is quoted so the query can't be read as the fts5 syntax. It's
+// built in the arena of the request, only the query itself is allocated.
+fn fts_query(query: &str, arena: &RequestArena) -> Option<String> {
+    arena.scope(|bump| {
+        let mut fts = bumpalo::collections::String::with_capacity_in(query.len() * 2 + 1, bump);
+        for word in query.split_whitespace() {
+            if !fts.is_empty() {
+                fts.push(' ');
+            }
+            fts.push('"');
+            for c in word.chars() {
+                if c == '"' {
+                    fts.push('"');
+                }
+                fts.push(c);
+            }
+            fts.push('"');
+        }
+        if fts.is_empty() {
+            return None;
+        }
+        fts.push('*');
+        Some(fts.as_str().to_owned())
+    })
+}
+
+fn parse_snippet(snippet: &str) -> (String, Vec<SearchHighlight>) {
+    let mut text = String::with_capacity(snippet.len());
+    let mut highlights = vec![];
+    let mut offset = 0;
+    let mut start = 0;
+    for c in snippet.chars() {
+        match c {
+            SNIPPET_MATCH_START => start = offset,
+            SNIPPET_MATCH_END => highlights.push(SearchHighlight {
+                start: start as i64,
+                end: offset as i64,
+            }),
+            _ => {
+                text.push(c);
+                offset += c.len_utf16();
+            },
+        }
+    }
+    (text, highlights)
+}
@@ -4,7 +4,10 @@ mod server_api_mock;
 
 pub use server_api::*;
 // TODO: ignore mock files in production
-use crate::errors::DocError;
+use crate::{
+    entities::asset::{AssetChunk, AssetChunkRequest},
+    errors::DocError,
+};
 use flowy_document_infra::entities::doc::{CreateDocParams, Doc, DocIdentifier, UpdateDocParams};
 use flowy_infra::future::ResultFuture;
 use flowy_net::config::ServerConfig;
@@ -18,12 +21,17 @@ pub trait DocumentServerAPI {
     fn read_doc(&self, token: &str, params: DocIdentifier) -> ResultFuture<Option<Doc>, DocError>;
 
     fn update_doc(&self, token: &str, params: UpdateDocParams) -> ResultFuture<(), DocError>;
+
+    fn upload_asset_chunk(&self, token: &str, params: AssetChunk) -> ResultFuture<(), DocError>;
+
+    // The chunk is shorter than the size at the end of the asset.
+    fn download_asset_chunk(&self, token: &str, params: AssetChunkRequest) -> ResultFuture<AssetChunk, DocError>;
 }
 
 pub(crate) fn construct_doc_server(server_config: &ServerConfig) -> Arc<dyn DocumentServerAPI + Send + Sync> {
     if cfg!(feature = "http_server") {
         Arc::new(DocServer::new(server_config.clone()))
     } else {
-        Arc::new(DocServerMock {})
+        Arc::new(DocServerMock::default())
     }
 }
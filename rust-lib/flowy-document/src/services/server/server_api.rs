@@ -1,7 +1,14 @@
-use crate::{errors::DocError, services::server::DocumentServerAPI};
+use crate::{
+    entities::asset::{AssetChunk, AssetChunkRequest},
+    errors::DocError,
+    services::server::DocumentServerAPI,
+};
 use flowy_document_infra::entities::doc::{CreateDocParams, Doc, DocIdentifier, UpdateDocParams};
 use flowy_infra::future::ResultFuture;
-use flowy_net::{config::*, request::HttpRequestBuilder};
+use flowy_net::{
+    config::*,
+    request::{HttpRequestBuilder, RetryConfig},
+};
 
 pub struct DocServer {
     config: ServerConfig,
@@ -29,6 +36,18 @@ impl DocumentServerAPI for DocServer {
         let url = self.config.doc_url();
         ResultFuture::new(async move { update_doc_request(&token, params, &url).await })
     }
+
+    fn upload_asset_chunk(&self, token: &str, params: AssetChunk) -> ResultFuture<(), DocError> {
+        let token = token.to_owned();
+        let url = self.config.asset_url();
+        ResultFuture::new(async move { upload_asset_chunk_request(&token, params, &url).await })
+    }
+
+    fn download_asset_chunk(&self, token: &str, params: AssetChunkRequest) -> ResultFuture<AssetChunk, DocError> {
+        let token = token.to_owned();
+        let url = self.config.asset_url();
+        ResultFuture::new(async move { download_asset_chunk_request(&token, params, &url).await })
+    }
 }
 
 pub(crate) fn request_builder() -> HttpRequestBuilder {
@@ -48,6 +67,7 @@ pub async fn create_doc_request(token: &str, params: CreateDocParams, url: &str)
 pub async fn read_doc_request(token: &str, params: DocIdentifier, url: &str) -> Result<Option<Doc>, DocError> {
     let doc = request_builder()
         .get(&url.to_owned())
+        .retry(RetryConfig::default())
         .header(HEADER_TOKEN, token)
         .protobuf(params)?
         .option_response()
@@ -65,3 +85,28 @@ pub async fn update_doc_request(token: &str, params: UpdateDocParams, url: &str)
         .await?;
     Ok(())
 }
+
+pub async fn upload_asset_chunk_request(token: &str, params: AssetChunk, url: &str) -> Result<(), DocError> {
+    let _ = request_builder()
+        .put(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn download_asset_chunk_request(
+    token: &str,
+    params: AssetChunkRequest,
+    url: &str,
+) -> Result<AssetChunk, DocError> {
+    let chunk = request_builder()
+        .get(&url.to_owned())
+        .retry(RetryConfig::default())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response()
+        .await?;
+    Ok(chunk)
+}
@@ -1,11 +1,21 @@
-use crate::{errors::DocError, services::server::DocumentServerAPI};
+use crate::{
+    entities::asset::{AssetChunk, AssetChunkRequest},
+    errors::DocError,
+    services::server::DocumentServerAPI,
+};
 use flowy_document_infra::{
     entities::doc::{CreateDocParams, Doc, DocIdentifier, UpdateDocParams},
     user_default::doc_initial_string,
 };
 use flowy_infra::future::ResultFuture;
+use parking_lot::RwLock;
+use std::{collections::HashMap, sync::Arc};
 
-pub struct DocServerMock {}
+// The uploaded assets are kept in memory, they're downloaded from there.
+#[derive(Default)]
+pub struct DocServerMock {
+    assets: Arc<RwLock<HashMap<String, Vec<u8>>>>,
+}
 
 impl DocumentServerAPI for DocServerMock {
     fn create_doc(&self, _token: &str, _params: CreateDocParams) -> ResultFuture<(), DocError> {
@@ -25,4 +35,39 @@ impl DocumentServerAPI for DocServerMock {
     fn update_doc(&self, _token: &str, _params: UpdateDocParams) -> ResultFuture<(), DocError> {
         ResultFuture::new(async { Ok(()) })
     }
+
+    fn upload_asset_chunk(&self, _token: &str, params: AssetChunk) -> ResultFuture<(), DocError> {
+        let assets = self.assets.clone();
+        ResultFuture::new(async move {
+            let mut assets = assets.write();
+            let data = assets.entry(params.asset_id).or_insert_with(Vec::new);
+            let offset = params.offset as usize;
+            if offset > data.len() {
+                return Err(DocError::asset_transfer_failed().context(format!(
+                    "The chunk at {} is after the end {}",
+                    offset,
+                    data.len()
+                )));
+            }
+            data.truncate(offset);
+            data.extend(params.data);
+            Ok(())
+        })
+    }
+
+    fn download_asset_chunk(&self, _token: &str, params: AssetChunkRequest) -> ResultFuture<AssetChunk, DocError> {
+        let assets = self.assets.clone();
+        ResultFuture::new(async move {
+            let assets = assets.read();
+            let data = assets.get(&params.asset_id).ok_or_else(DocError::asset_not_found)?;
+            let start = (params.offset.max(0) as usize).min(data.len());
+            let end = (start + params.size.max(0) as usize).min(data.len());
+            Ok(AssetChunk {
+                asset_id: params.asset_id,
+                offset: start as i64,
+                total_size: data.len() as i64,
+                data: data[start..end].to_vec(),
+            })
+        })
+    }
 }
@@ -1,4 +1,7 @@
-use crate::{entities::ws::WsDocumentData, errors::DocError};
+use crate::{
+    entities::ws::{decode_ws_document_data, WsDocumentData},
+    errors::DocError,
+};
 use bytes::Bytes;
 
 use dashmap::DashMap;
@@ -40,7 +43,14 @@ impl WsDocumentManager {
     pub(crate) fn remove_handler(&self, id: &str) { self.handlers.remove(id); }
 
     pub fn handle_ws_data(&self, data: Bytes) {
-        let data: WsDocumentData = data.try_into().unwrap();
+        let data = match decode_ws_document_data(&data) {
+            Ok(data) => data,
+            Err(e) => {
+                log::error!("Decode the ws document data failed: {}", e);
+                return;
+            },
+        };
+        let data: WsDocumentData = Bytes::from(data).try_into().unwrap();
         match self.handlers.get(&data.doc_id) {
             None => {
                 log::error!("Can't find any source handler for {:?}", data.doc_id);
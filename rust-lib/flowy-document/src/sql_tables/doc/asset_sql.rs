@@ -0,0 +1,61 @@
+use crate::{errors::DocError, sql_tables::doc::DocAssetTable};
+use flowy_database::{prelude::*, schema::doc_asset_table::dsl, SqliteConnection};
+
+pub struct DocAssetTableSql {}
+
+impl DocAssetTableSql {
+    pub(crate) fn create_asset_table(&self, table: DocAssetTable, conn: &SqliteConnection) -> Result<(), DocError> {
+        let _ = diesel::insert_into(dsl::doc_asset_table).values(table).execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_asset_table(&self, asset_id: &str, conn: &SqliteConnection) -> Result<DocAssetTable, DocError> {
+        let table = dsl::doc_asset_table
+            .filter(dsl::id.eq(asset_id))
+            .first::<DocAssetTable>(conn)?;
+        Ok(table)
+    }
+
+    // The oldest asset comes first.
+    pub(crate) fn read_asset_tables(
+        &self,
+        doc_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<DocAssetTable>, DocError> {
+        let tables = dsl::doc_asset_table
+            .filter(dsl::doc_id.eq(doc_id))
+            .order(dsl::create_time.asc())
+            .load::<DocAssetTable>(conn)?;
+        Ok(tables)
+    }
+
+    pub(crate) fn read_asset_ids(&self, conn: &SqliteConnection) -> Result<Vec<String>, DocError> {
+        let asset_ids = dsl::doc_asset_table.select(dsl::id).load::<String>(conn)?;
+        Ok(asset_ids)
+    }
+
+    pub(crate) fn read_doc_ids(&self, conn: &SqliteConnection) -> Result<Vec<String>, DocError> {
+        let doc_ids = dsl::doc_asset_table
+            .select(dsl::doc_id)
+            .distinct()
+            .load::<String>(conn)?;
+        Ok(doc_ids)
+    }
+
+    pub(crate) fn update_blob_hash(
+        &self,
+        asset_id: &str,
+        blob_hash: &str,
+        conn: &SqliteConnection,
+    ) -> Result<(), DocError> {
+        let _ = diesel::update(dsl::doc_asset_table.filter(dsl::id.eq(asset_id)))
+            .set(dsl::blob_hash.eq(blob_hash))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn delete_asset_table(&self, asset_id: &str, conn: &SqliteConnection) -> Result<(), DocError> {
+        let _ = diesel::delete(dsl::doc_asset_table.filter(dsl::id.eq(asset_id))).execute(conn)?;
+        Ok(())
+    }
+}
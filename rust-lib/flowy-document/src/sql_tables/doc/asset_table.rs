@@ -0,0 +1,29 @@
+use flowy_database::schema::doc_asset_table;
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable)]
+#[table_name = "doc_asset_table"]
+pub(crate) struct DocAssetTable {
+    pub(crate) id: String,
+    pub(crate) doc_id: String,
+    pub(crate) name: String,
+    pub(crate) mime_type: String,
+    pub(crate) size: i64,
+    pub(crate) create_time: i64,
+    // The blob of the data, it's empty for the assets attached before the blobs,
+    // their data is in the assets directory.
+    pub(crate) blob_hash: String,
+}
+
+impl DocAssetTable {
+    pub(crate) fn new(doc_id: &str, name: &str, mime_type: &str, size: i64, blob_hash: &str) -> Self {
+        Self {
+            id: flowy_infra::uuid(),
+            doc_id: doc_id.to_owned(),
+            name: name.to_owned(),
+            mime_type: mime_type.to_owned(),
+            size,
+            create_time: flowy_infra::timestamp(),
+            blob_hash: blob_hash.to_owned(),
+        }
+    }
+}
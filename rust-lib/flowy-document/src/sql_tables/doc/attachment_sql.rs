@@ -0,0 +1,87 @@
+use crate::{errors::DocError, sql_tables::doc::DocAttachmentTable};
+use flowy_database::{
+    prelude::*,
+    schema::{blob_table, doc_attachment_table::dsl},
+    SqliteConnection,
+};
+
+pub struct DocAttachmentTableSql {}
+
+impl DocAttachmentTableSql {
+    pub(crate) fn create_attachment_table(
+        &self,
+        table: DocAttachmentTable,
+        conn: &SqliteConnection,
+    ) -> Result<(), DocError> {
+        let _ = diesel::insert_into(dsl::doc_attachment_table)
+            .values(table)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_attachment_table(
+        &self,
+        attachment_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<DocAttachmentTable, DocError> {
+        let table = dsl::doc_attachment_table
+            .filter(dsl::id.eq(attachment_id))
+            .first::<DocAttachmentTable>(conn)?;
+        Ok(table)
+    }
+
+    // The oldest attachment comes first.
+    pub(crate) fn read_attachment_tables(
+        &self,
+        doc_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<DocAttachmentTable>, DocError> {
+        let tables = dsl::doc_attachment_table
+            .filter(dsl::doc_id.eq(doc_id))
+            .order(dsl::create_time.asc())
+            .load::<DocAttachmentTable>(conn)?;
+        Ok(tables)
+    }
+
+    pub(crate) fn read_doc_ids(&self, conn: &SqliteConnection) -> Result<Vec<String>, DocError> {
+        let doc_ids = dsl::doc_attachment_table
+            .select(dsl::doc_id)
+            .distinct()
+            .load::<String>(conn)?;
+        Ok(doc_ids)
+    }
+
+    // The blobs of the data and the thumbnails of all the attachments, with their
+    // sizes. The blob that several attachments share is read once.
+    pub(crate) fn read_blob_sizes(&self, conn: &SqliteConnection) -> Result<Vec<(String, i64)>, DocError> {
+        let data_hashes = dsl::doc_attachment_table.select(dsl::blob_hash);
+        let thumbnail_hashes = dsl::doc_attachment_table.select(dsl::thumbnail_hash);
+        let blobs = blob_table::table
+            .select((blob_table::hash, blob_table::size))
+            .filter(
+                blob_table::hash
+                    .eq_any(data_hashes)
+                    .or(blob_table::hash.eq_any(thumbnail_hashes)),
+            )
+            .load::<(String, i64)>(conn)?;
+        Ok(blobs)
+    }
+
+    pub(crate) fn count_attachments(&self, conn: &SqliteConnection) -> Result<i64, DocError> {
+        let count = dsl::doc_attachment_table.count().get_result::<i64>(conn)?;
+        Ok(count)
+    }
+
+    pub(crate) fn is_blob_attached(&self, blob_hash: &str, conn: &SqliteConnection) -> Result<bool, DocError> {
+        let count = dsl::doc_attachment_table
+            .filter(dsl::blob_hash.eq(blob_hash).or(dsl::thumbnail_hash.eq(blob_hash)))
+            .count()
+            .get_result::<i64>(conn)?;
+        Ok(count > 0)
+    }
+
+    pub(crate) fn delete_attachment_table(&self, attachment_id: &str, conn: &SqliteConnection) -> Result<(), DocError> {
+        let _ = diesel::delete(dsl::doc_attachment_table.filter(dsl::id.eq(attachment_id))).execute(conn)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use flowy_database::schema::doc_attachment_table;
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable)]
+#[table_name = "doc_attachment_table"]
+pub(crate) struct DocAttachmentTable {
+    pub(crate) id: String,
+    pub(crate) doc_id: String,
+    pub(crate) name: String,
+    pub(crate) mime_type: String,
+    pub(crate) size: i64,
+    pub(crate) blob_hash: String,
+    // Empty if the attachment isn't an image.
+    pub(crate) thumbnail_hash: String,
+    pub(crate) width: i32,
+    pub(crate) height: i32,
+    pub(crate) create_time: i64,
+}
+
+impl DocAttachmentTable {
+    pub(crate) fn new(doc_id: &str, name: &str, mime_type: &str) -> Self {
+        Self {
+            id: flowy_infra::uuid(),
+            doc_id: doc_id.to_owned(),
+            name: name.to_owned(),
+            mime_type: mime_type.to_owned(),
+            size: 0,
+            blob_hash: "".to_owned(),
+            thumbnail_hash: "".to_owned(),
+            width: 0,
+            height: 0,
+            create_time: flowy_infra::timestamp(),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{
+    errors::DocError,
+    sql_tables::doc::{BlobRefTable, BlobTable},
+};
+use flowy_database::{
+    prelude::*,
+    schema::{blob_ref_table, blob_table},
+    OptionalExtension,
+    SqliteConnection,
+};
+
+pub struct BlobTableSql {}
+
+impl BlobTableSql {
+    // The blob is written once, the ones with the same content share it.
+    pub(crate) fn create_blob_table_if_not_exist(
+        &self,
+        table: BlobTable,
+        conn: &SqliteConnection,
+    ) -> Result<(), DocError> {
+        if !BlobTable::exists(&table.hash, conn)? {
+            let _ = table.insert(conn)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn read_blob_table(&self, hash: &str, conn: &SqliteConnection) -> Result<Option<BlobTable>, DocError> {
+        let table = BlobTable::read(hash, conn).optional()?;
+        Ok(table)
+    }
+
+    pub(crate) fn read_hashes(&self, conn: &SqliteConnection) -> Result<Vec<String>, DocError> {
+        let hashes = blob_table::table.select(blob_table::hash).load::<String>(conn)?;
+        Ok(hashes)
+    }
+
+    // The blobs that no owner refers to, with their sizes.
+    pub(crate) fn read_unreferenced_blobs(&self, conn: &SqliteConnection) -> Result<Vec<(String, i64)>, DocError> {
+        let blobs = blob_table::table
+            .select((blob_table::hash, blob_table::size))
+            .filter(blob_table::hash.ne_all(blob_ref_table::table.select(blob_ref_table::blob_hash)))
+            .load::<(String, i64)>(conn)?;
+        Ok(blobs)
+    }
+
+    pub(crate) fn delete_blob_table(&self, hash: &str, conn: &SqliteConnection) -> Result<(), DocError> {
+        let _ = BlobTable::delete(hash, conn)?;
+        Ok(())
+    }
+
+    // Referring to the blob again with the same owner does nothing.
+    pub(crate) fn create_ref_table(&self, table: BlobRefTable, conn: &SqliteConnection) -> Result<(), DocError> {
+        let _ = diesel::insert_or_ignore_into(blob_ref_table::table)
+            .values(table)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_ref_count(&self, hash: &str, conn: &SqliteConnection) -> Result<i64, DocError> {
+        let count = blob_ref_table::table
+            .filter(blob_ref_table::blob_hash.eq(hash))
+            .count()
+            .get_result::<i64>(conn)?;
+        Ok(count)
+    }
+
+    pub(crate) fn read_owned_hashes(&self, owner_id: &str, conn: &SqliteConnection) -> Result<Vec<String>, DocError> {
+        let hashes = blob_ref_table::table
+            .filter(blob_ref_table::owner_id.eq(owner_id))
+            .select(blob_ref_table::blob_hash)
+            .load::<String>(conn)?;
+        Ok(hashes)
+    }
+
+    pub(crate) fn delete_ref_table(&self, hash: &str, owner_id: &str, conn: &SqliteConnection) -> Result<(), DocError> {
+        let filter = blob_ref_table::table
+            .filter(blob_ref_table::blob_hash.eq(hash))
+            .filter(blob_ref_table::owner_id.eq(owner_id));
+        let _ = diesel::delete(filter).execute(conn)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use flowy_database::schema::{blob_ref_table, blob_table};
+use flowy_derive::SqlTable;
+
+// The blob named after the hash of its content, see BlobStore.
+#[derive(PartialEq, Clone, Debug, Queryable, SqlTable)]
+#[table_name = "blob_table"]
+#[primary_key(hash)]
+pub(crate) struct BlobTable {
+    pub(crate) hash: String,
+    pub(crate) size: i64,
+    pub(crate) create_time: i64,
+}
+
+impl BlobTable {
+    pub(crate) fn new(hash: &str, size: i64) -> Self {
+        Self {
+            hash: hash.to_owned(),
+            size,
+            create_time: flowy_infra::timestamp(),
+        }
+    }
+}
+
+// The blob is referenced once per owner, the owner is the id of the document,
+// the view or the asset that uses it.
+#[derive(PartialEq, Clone, Debug, Queryable, Insertable)]
+#[table_name = "blob_ref_table"]
+pub(crate) struct BlobRefTable {
+    pub(crate) blob_hash: String,
+    pub(crate) owner_id: String,
+    pub(crate) create_time: i64,
+}
+
+impl BlobRefTable {
+    pub(crate) fn new(blob_hash: &str, owner_id: &str) -> Self {
+        Self {
+            blob_hash: blob_hash.to_owned(),
+            owner_id: owner_id.to_owned(),
+            create_time: flowy_infra::timestamp(),
+        }
+    }
+}
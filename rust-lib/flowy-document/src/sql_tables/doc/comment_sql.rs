@@ -0,0 +1,61 @@
+use crate::{
+    errors::DocError,
+    sql_tables::doc::{DocCommentTable, DocCommentTableChangeset},
+};
+use flowy_database::{prelude::*, schema::doc_comment_table::dsl, SqliteConnection};
+
+pub struct DocCommentTableSql {}
+
+impl DocCommentTableSql {
+    pub(crate) fn create_comment_table(&self, table: DocCommentTable, conn: &SqliteConnection) -> Result<(), DocError> {
+        let _ = diesel::insert_into(dsl::doc_comment_table)
+            .values(table)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_comment_table(
+        &self,
+        comment_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<DocCommentTable, DocError> {
+        let table = dsl::doc_comment_table
+            .filter(dsl::id.eq(comment_id))
+            .first::<DocCommentTable>(conn)?;
+        Ok(table)
+    }
+
+    // The oldest comment comes first.
+    pub(crate) fn read_comment_tables(
+        &self,
+        doc_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<DocCommentTable>, DocError> {
+        let tables = dsl::doc_comment_table
+            .filter(dsl::doc_id.eq(doc_id))
+            .order(dsl::create_time.asc())
+            .load::<DocCommentTable>(conn)?;
+        Ok(tables)
+    }
+
+    pub(crate) fn update_comment_table(
+        &self,
+        changeset: DocCommentTableChangeset,
+        conn: &SqliteConnection,
+    ) -> Result<(), DocError> {
+        // The comment may be deleted while its anchor is moved by an edit.
+        let filter = dsl::doc_comment_table.filter(dsl::id.eq(changeset.id.clone()));
+        let _ = diesel::update(filter).set(changeset).execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn delete_comment_table(&self, comment_id: &str, conn: &SqliteConnection) -> Result<(), DocError> {
+        let _ = diesel::delete(dsl::doc_comment_table.filter(dsl::id.eq(comment_id))).execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn delete_comment_tables(&self, doc_id: &str, conn: &SqliteConnection) -> Result<(), DocError> {
+        let _ = diesel::delete(dsl::doc_comment_table.filter(dsl::doc_id.eq(doc_id))).execute(conn)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use crate::entities::comment::DocComment;
+use flowy_database::schema::doc_comment_table;
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable)]
+#[table_name = "doc_comment_table"]
+pub(crate) struct DocCommentTable {
+    pub(crate) id: String,
+    pub(crate) doc_id: String,
+    pub(crate) user_id: String,
+    pub(crate) content: String,
+    pub(crate) start_offset: i64,
+    pub(crate) end_offset: i64,
+    pub(crate) resolved: bool,
+    pub(crate) create_time: i64,
+    pub(crate) modified_time: i64,
+}
+
+impl DocCommentTable {
+    pub(crate) fn new(doc_id: &str, user_id: &str, content: &str, start: i64, end: i64) -> Self {
+        let time = flowy_infra::timestamp();
+        Self {
+            id: flowy_infra::uuid(),
+            doc_id: doc_id.to_owned(),
+            user_id: user_id.to_owned(),
+            content: content.to_owned(),
+            start_offset: start,
+            end_offset: end,
+            resolved: false,
+            create_time: time,
+            modified_time: time,
+        }
+    }
+}
+
+impl std::convert::From<DocCommentTable> for DocComment {
+    fn from(table: DocCommentTable) -> Self {
+        DocComment {
+            id: table.id,
+            doc_id: table.doc_id,
+            user_id: table.user_id,
+            content: table.content,
+            start: table.start_offset,
+            end: table.end_offset,
+            resolved: table.resolved,
+            create_time: table.create_time,
+            modified_time: table.modified_time,
+        }
+    }
+}
+
+#[derive(AsChangeset, Identifiable, Clone, Default, Debug)]
+#[table_name = "doc_comment_table"]
+pub(crate) struct DocCommentTableChangeset {
+    pub(crate) id: String,
+    pub(crate) start_offset: Option<i64>,
+    pub(crate) end_offset: Option<i64>,
+    pub(crate) resolved: Option<bool>,
+    pub(crate) modified_time: Option<i64>,
+}
@@ -14,7 +14,7 @@ pub struct DocTableSql {}
 
 impl DocTableSql {
     pub(crate) fn create_doc_table(&self, doc_table: DocTable, conn: &SqliteConnection) -> Result<(), DocError> {
-        let _ = diesel::insert_into(doc_table::table).values(doc_table).execute(conn)?;
+        let _ = doc_table.insert(conn)?;
         Ok(())
     }
 
@@ -29,19 +29,14 @@ impl DocTableSql {
 
     pub(crate) fn read_doc_table(&self, doc_id: &str, pool: Arc<ConnectionPool>) -> Result<DocTable, DocError> {
         let conn = &*pool.get().map_err(internal_error)?;
-        let doc_table = dsl::doc_table
-            .filter(doc_table::id.eq(doc_id))
-            .first::<DocTable>(conn)?;
-
+        let doc_table = DocTable::read(doc_id, conn)?;
         Ok(doc_table)
     }
 
     #[allow(dead_code)]
     pub(crate) fn delete_doc(&self, doc_id: &str, conn: &SqliteConnection) -> Result<DocTable, DocError> {
-        let doc_table = dsl::doc_table
-            .filter(doc_table::id.eq(doc_id))
-            .first::<DocTable>(conn)?;
-        diesel_delete_table!(doc_table, doc_id, conn);
+        let doc_table = DocTable::read(doc_id, conn)?;
+        let _ = DocTable::delete(doc_id, conn)?;
         Ok(doc_table)
     }
 }
@@ -1,7 +1,8 @@
 use crate::entities::doc::Doc;
 use flowy_database::schema::doc_table;
+use flowy_derive::SqlTable;
 
-#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
+#[derive(PartialEq, Clone, Debug, Queryable, SqlTable)]
 #[table_name = "doc_table"]
 pub(crate) struct DocTable {
     pub(crate) id: String,
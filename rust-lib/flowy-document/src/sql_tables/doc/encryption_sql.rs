@@ -0,0 +1,30 @@
+use crate::{errors::DocError, sql_tables::doc::DocEncryptionTable};
+use flowy_database::{prelude::*, schema::doc_encryption_table::dsl, SqliteConnection};
+
+pub struct DocEncryptionTableSql {}
+
+impl DocEncryptionTableSql {
+    pub(crate) fn create_encryption_table(
+        &self,
+        table: DocEncryptionTable,
+        conn: &SqliteConnection,
+    ) -> Result<(), DocError> {
+        let _ = diesel::insert_into(dsl::doc_encryption_table)
+            .values(table)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn is_encrypted(&self, doc_id: &str, conn: &SqliteConnection) -> Result<bool, DocError> {
+        let count = dsl::doc_encryption_table
+            .filter(dsl::doc_id.eq(doc_id))
+            .count()
+            .get_result::<i64>(conn)?;
+        Ok(count > 0)
+    }
+
+    pub(crate) fn delete_encryption_table(&self, doc_id: &str, conn: &SqliteConnection) -> Result<(), DocError> {
+        let _ = diesel::delete(dsl::doc_encryption_table.filter(dsl::doc_id.eq(doc_id))).execute(conn)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+use flowy_database::schema::doc_encryption_table;
+
+// The document has a row if it's encrypted.
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable)]
+#[table_name = "doc_encryption_table"]
+#[primary_key(doc_id)]
+pub(crate) struct DocEncryptionTable {
+    pub(crate) doc_id: String,
+    pub(crate) create_time: i64,
+}
+
+impl DocEncryptionTable {
+    pub(crate) fn new(doc_id: &str) -> Self {
+        Self {
+            doc_id: doc_id.to_owned(),
+            create_time: flowy_infra::timestamp(),
+        }
+    }
+}
@@ -1,5 +1,37 @@
+mod asset_sql;
+mod asset_table;
+mod attachment_sql;
+mod attachment_table;
+mod blob_sql;
+mod blob_table;
+mod comment_sql;
+mod comment_table;
+mod encryption_sql;
+mod encryption_table;
 mod rev_sql;
 mod rev_table;
+mod search_sql;
+mod search_table;
+mod snapshot_sql;
+mod snapshot_table;
+mod transfer_sql;
+mod transfer_table;
 
+pub(crate) use asset_sql::*;
+pub(crate) use asset_table::*;
+pub(crate) use attachment_sql::*;
+pub(crate) use attachment_table::*;
+pub(crate) use blob_sql::*;
+pub(crate) use blob_table::*;
+pub(crate) use comment_sql::*;
+pub(crate) use comment_table::*;
+pub(crate) use encryption_sql::*;
+pub(crate) use encryption_table::*;
 pub(crate) use rev_sql::*;
 pub(crate) use rev_table::*;
+pub(crate) use search_sql::*;
+pub(crate) use search_table::*;
+pub(crate) use snapshot_sql::*;
+pub(crate) use snapshot_table::*;
+pub(crate) use transfer_sql::*;
+pub(crate) use transfer_table::*;
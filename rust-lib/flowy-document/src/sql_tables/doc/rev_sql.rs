@@ -1,10 +1,16 @@
 use crate::{
     errors::DocError,
-    sql_tables::{doc::RevTable, RevChangeset, RevState, RevTableType},
+    sql_tables::{
+        doc::{encode_rev_data, RevTable},
+        RevChangeset,
+        RevState,
+        RevTableType,
+    },
 };
 use diesel::update;
 use flowy_database::{insert_or_ignore_into, prelude::*, schema::rev_table::dsl, SqliteConnection};
 use flowy_document_infra::entities::doc::{Revision, RevisionRange};
+use std::convert::TryFrom;
 
 pub struct RevTableSql {}
 
@@ -23,7 +29,7 @@ impl RevTableSql {
                     dsl::doc_id.eq(revision.doc_id),
                     dsl::base_rev_id.eq(revision.base_rev_id),
                     dsl::rev_id.eq(revision.rev_id),
-                    dsl::data.eq(revision.delta_data),
+                    dsl::data.eq(encode_rev_data(&revision.delta_data)),
                     dsl::state.eq(new_state),
                     dsl::ty.eq(rev_ty),
                 )
@@ -44,6 +50,21 @@ impl RevTableSql {
         Ok(())
     }
 
+    // The delta data is replaced when the document is encrypted or decrypted.
+    pub(crate) fn update_rev_data(
+        &self,
+        doc_id: &str,
+        rev_id: i64,
+        data: &[u8],
+        conn: &SqliteConnection,
+    ) -> Result<(), DocError> {
+        let filter = dsl::rev_table
+            .filter(dsl::rev_id.eq(rev_id))
+            .filter(dsl::doc_id.eq(doc_id));
+        let _ = update(filter).set(dsl::data.eq(encode_rev_data(data))).execute(conn)?;
+        Ok(())
+    }
+
     pub(crate) fn read_rev_tables(&self, doc_id: &str, conn: &SqliteConnection) -> Result<Vec<Revision>, DocError> {
         let filter = dsl::rev_table
             .filter(dsl::doc_id.eq(doc_id))
@@ -52,8 +73,8 @@ impl RevTableSql {
         let rev_tables = filter.load::<RevTable>(conn)?;
         let revisions = rev_tables
             .into_iter()
-            .map(|table| table.into())
-            .collect::<Vec<Revision>>();
+            .map(Revision::try_from)
+            .collect::<Result<Vec<Revision>, DocError>>()?;
         Ok(revisions)
     }
 
@@ -71,7 +92,7 @@ impl RevTableSql {
         if Err(diesel::NotFound) == result {
             Ok(None)
         } else {
-            Ok(Some(result?.into()))
+            Ok(Some(Revision::try_from(result?)?))
         }
     }
 
@@ -90,11 +111,21 @@ impl RevTableSql {
 
         let revisions = rev_tables
             .into_iter()
-            .map(|table| table.into())
-            .collect::<Vec<Revision>>();
+            .map(Revision::try_from)
+            .collect::<Result<Vec<Revision>, DocError>>()?;
         Ok(revisions)
     }
 
+    pub(crate) fn read_doc_ids(&self, conn: &SqliteConnection) -> Result<Vec<String>, DocError> {
+        let doc_ids = dsl::rev_table.select(dsl::doc_id).distinct().load::<String>(conn)?;
+        Ok(doc_ids)
+    }
+
+    pub(crate) fn delete_rev_tables(&self, doc_id: &str, conn: &SqliteConnection) -> Result<(), DocError> {
+        let _ = diesel::delete(dsl::rev_table.filter(dsl::doc_id.eq(doc_id))).execute(conn)?;
+        Ok(())
+    }
+
     #[allow(dead_code)]
     pub(crate) fn delete_rev_table(
         &self,
@@ -1,9 +1,24 @@
+use crate::errors::DocError;
 use diesel::sql_types::Integer;
 use flowy_database::schema::rev_table;
 use flowy_document_infra::{
     entities::doc::{RevId, RevType, Revision},
     util::md5,
 };
+use flowy_infra::envelope::PayloadMigrations;
+use lazy_static::lazy_static;
+use std::convert::TryFrom;
+
+// Bump the version and register the migration here if the format of the delta data
+// changes. The revisions persisted before the envelope was introduced are version 0.
+const REV_DATA_VERSION: u32 = 1;
+
+lazy_static! {
+    static ref REV_DATA_MIGRATIONS: PayloadMigrations =
+        PayloadMigrations::new(REV_DATA_VERSION).register(0, |data| Ok(data));
+}
+
+pub(crate) fn encode_rev_data(data: &[u8]) -> Vec<u8> { REV_DATA_MIGRATIONS.encode(data) }
 
 #[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
 #[table_name = "rev_table"]
@@ -46,17 +61,22 @@ impl RevState {
 }
 impl_sql_integer_expression!(RevState);
 
-impl std::convert::Into<Revision> for RevTable {
-    fn into(self) -> Revision {
-        let md5 = md5(&self.data);
-        Revision {
-            base_rev_id: self.base_rev_id,
-            rev_id: self.rev_id,
-            delta_data: self.data,
+impl TryFrom<RevTable> for Revision {
+    type Error = DocError;
+
+    fn try_from(table: RevTable) -> Result<Self, Self::Error> {
+        let data = REV_DATA_MIGRATIONS
+            .decode(&table.data)
+            .map_err(|e| DocError::internal().context(e))?;
+        let md5 = md5(&data);
+        Ok(Revision {
+            base_rev_id: table.base_rev_id,
+            rev_id: table.rev_id,
+            delta_data: data,
             md5,
-            doc_id: self.doc_id,
-            ty: self.ty.into(),
-        }
+            doc_id: table.doc_id,
+            ty: table.ty.into(),
+        })
     }
 }
 
@@ -0,0 +1,77 @@
+use crate::{
+    errors::DocError,
+    sql_tables::doc::{DocSearchRow, DocSearchTable, NewDocSearchTable},
+};
+use flowy_database::{
+    prelude::*,
+    schema::doc_search_table::dsl,
+    sql_types::{BigInt, Text},
+    SqliteConnection,
+};
+
+// How many words around the matched terms are in the snippet.
+const SNIPPET_WORDS: i64 = 16;
+
+pub struct DocSearchTableSql {}
+
+impl DocSearchTableSql {
+    pub(crate) fn create_search_tables(
+        &self,
+        tables: Vec<NewDocSearchTable>,
+        conn: &SqliteConnection,
+    ) -> Result<(), DocError> {
+        let _ = diesel::insert_into(dsl::doc_search_table)
+            .values(tables)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_search_tables(
+        &self,
+        doc_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<DocSearchTable>, DocError> {
+        let tables = dsl::doc_search_table
+            .filter(dsl::doc_id.eq(doc_id))
+            .load::<DocSearchTable>(conn)?;
+        Ok(tables)
+    }
+
+    pub(crate) fn delete_search_tables_with_ids(&self, ids: Vec<i32>, conn: &SqliteConnection) -> Result<(), DocError> {
+        let _ = diesel::delete(dsl::doc_search_table.filter(dsl::id.eq_any(ids))).execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn delete_search_tables(&self, doc_id: &str, conn: &SqliteConnection) -> Result<(), DocError> {
+        let _ = diesel::delete(dsl::doc_search_table.filter(dsl::doc_id.eq(doc_id))).execute(conn)?;
+        Ok(())
+    }
+
+    // The documents are ranked by the bm25 of their best matching line, the
+    // snippet is of that line since sqlite takes the bare columns from the row of
+    // the MIN. The LIMIT keeps sqlite from flattening the subquery, the fts5
+    // functions can't be used in the aggregate. The query is a fts5 query.
+    pub(crate) fn search(
+        &self,
+        query: &str,
+        limit: i64,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<DocSearchRow>, DocError> {
+        let sql = format!(
+            "SELECT doc_id, snippet, MIN(score) AS best FROM ( \
+                SELECT doc_search_table.doc_id AS doc_id, \
+                    snippet(doc_search_fts, 0, char(2), char(3), '…', {}) AS snippet, \
+                    bm25(doc_search_fts) AS score \
+                FROM doc_search_fts JOIN doc_search_table ON doc_search_table.id = doc_search_fts.rowid \
+                WHERE doc_search_fts MATCH ? LIMIT -1 \
+            ) \
+            GROUP BY doc_id ORDER BY best LIMIT ?",
+            SNIPPET_WORDS
+        );
+        let rows = diesel::sql_query(sql)
+            .bind::<Text, _>(query)
+            .bind::<BigInt, _>(limit)
+            .load::<DocSearchRow>(conn)?;
+        Ok(rows)
+    }
+}
@@ -0,0 +1,39 @@
+use flowy_database::{schema::doc_search_table, sql_types::Text};
+
+// A line of the document in the full text index.
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable)]
+#[table_name = "doc_search_table"]
+pub(crate) struct DocSearchTable {
+    pub(crate) id: i32,
+    pub(crate) doc_id: String,
+    pub(crate) content: String,
+}
+
+#[derive(Clone, Debug, Insertable)]
+#[table_name = "doc_search_table"]
+pub(crate) struct NewDocSearchTable {
+    pub(crate) doc_id: String,
+    pub(crate) content: String,
+}
+
+impl NewDocSearchTable {
+    pub(crate) fn new(doc_id: &str, content: &str) -> Self {
+        Self {
+            doc_id: doc_id.to_owned(),
+            content: content.to_owned(),
+        }
+    }
+}
+
+// The best matching line of the document. The matched terms of the snippet are
+// wrapped by the SNIPPET_MATCH_START and SNIPPET_MATCH_END.
+#[derive(Clone, Debug, QueryableByName)]
+pub(crate) struct DocSearchRow {
+    #[sql_type = "Text"]
+    pub(crate) doc_id: String,
+    #[sql_type = "Text"]
+    pub(crate) snippet: String,
+}
+
+pub(crate) const SNIPPET_MATCH_START: char = '\u{2}';
+pub(crate) const SNIPPET_MATCH_END: char = '\u{3}';
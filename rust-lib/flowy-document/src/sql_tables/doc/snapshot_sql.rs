@@ -0,0 +1,55 @@
+use crate::{errors::DocError, sql_tables::doc::DocSnapshotTable};
+use flowy_database::{prelude::*, schema::doc_snapshot_table::dsl, OptionalExtension, SqliteConnection};
+
+pub struct DocSnapshotTableSql {}
+
+impl DocSnapshotTableSql {
+    pub(crate) fn create_snapshot_table(
+        &self,
+        table: DocSnapshotTable,
+        conn: &SqliteConnection,
+    ) -> Result<(), DocError> {
+        let _ = table.insert(conn)?;
+        Ok(())
+    }
+
+    // The newest snapshot comes first.
+    pub(crate) fn read_snapshot_tables(
+        &self,
+        doc_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<DocSnapshotTable>, DocError> {
+        let tables = DocSnapshotTable::query()
+            .filter(dsl::doc_id.eq(doc_id))
+            .order(dsl::rev_id.desc())
+            .load::<DocSnapshotTable>(conn)?;
+        Ok(tables)
+    }
+
+    // The newest snapshot that was taken at or before the rev_id.
+    pub(crate) fn read_snapshot_before(
+        &self,
+        doc_id: &str,
+        rev_id: i64,
+        conn: &SqliteConnection,
+    ) -> Result<Option<DocSnapshotTable>, DocError> {
+        let table = DocSnapshotTable::query()
+            .filter(dsl::doc_id.eq(doc_id))
+            .filter(dsl::rev_id.le(rev_id))
+            .order(dsl::rev_id.desc())
+            .first::<DocSnapshotTable>(conn)
+            .optional()?;
+        Ok(table)
+    }
+
+    pub(crate) fn update_snapshot_data(&self, id: &str, data: &str, conn: &SqliteConnection) -> Result<(), DocError> {
+        let filter = dsl::doc_snapshot_table.filter(dsl::id.eq(id));
+        let _ = diesel::update(filter).set(dsl::data.eq(data)).execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn delete_snapshot_tables(&self, doc_id: &str, conn: &SqliteConnection) -> Result<(), DocError> {
+        let _ = diesel::delete(dsl::doc_snapshot_table.filter(dsl::doc_id.eq(doc_id))).execute(conn)?;
+        Ok(())
+    }
+}
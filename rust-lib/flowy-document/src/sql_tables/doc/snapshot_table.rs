@@ -0,0 +1,25 @@
+use flowy_database::schema::doc_snapshot_table;
+use flowy_derive::SqlTable;
+
+#[derive(PartialEq, Clone, Debug, Queryable, SqlTable)]
+#[table_name = "doc_snapshot_table"]
+pub(crate) struct DocSnapshotTable {
+    pub(crate) id: String,
+    pub(crate) doc_id: String,
+    pub(crate) rev_id: i64,
+    // The document's delta after the revision with the rev_id.
+    pub(crate) data: String,
+    pub(crate) create_time: i64,
+}
+
+impl DocSnapshotTable {
+    pub(crate) fn new(doc_id: &str, rev_id: i64, data: String) -> Self {
+        Self {
+            id: flowy_infra::uuid(),
+            doc_id: doc_id.to_owned(),
+            rev_id,
+            data,
+            create_time: flowy_infra::timestamp(),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{errors::DocError, sql_tables::doc::AssetTransferTable};
+use flowy_database::{prelude::*, schema::asset_transfer_table, OptionalExtension, SqliteConnection};
+
+pub struct AssetTransferTableSql {}
+
+impl AssetTransferTableSql {
+    pub(crate) fn save_transfer_table(
+        &self,
+        table: AssetTransferTable,
+        conn: &SqliteConnection,
+    ) -> Result<(), DocError> {
+        let _ = table.upsert(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_transfer_table(
+        &self,
+        asset_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Option<AssetTransferTable>, DocError> {
+        let table = AssetTransferTable::read(asset_id, conn).optional()?;
+        Ok(table)
+    }
+
+    // The oldest transfer comes first.
+    pub(crate) fn read_transfer_tables(&self, conn: &SqliteConnection) -> Result<Vec<AssetTransferTable>, DocError> {
+        let tables = AssetTransferTable::query()
+            .order(asset_transfer_table::update_time.asc())
+            .load::<AssetTransferTable>(conn)?;
+        Ok(tables)
+    }
+
+    pub(crate) fn delete_transfer_table(&self, asset_id: &str, conn: &SqliteConnection) -> Result<(), DocError> {
+        let _ = AssetTransferTable::delete(asset_id, conn)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use crate::entities::asset::AssetTransferDirection;
+use diesel::sql_types::Integer;
+use flowy_database::schema::asset_transfer_table;
+use flowy_derive::SqlTable;
+
+// The transfer of an asset that isn't done yet. It's saved after each chunk, so
+// the transfer resumes from there after the network was lost or the app was
+// closed, and it's removed once the asset is transferred.
+#[derive(PartialEq, Clone, Debug, Queryable, SqlTable)]
+#[table_name = "asset_transfer_table"]
+#[primary_key(asset_id)]
+pub(crate) struct AssetTransferTable {
+    pub(crate) asset_id: String,
+    pub(crate) direction: SqlTransferDirection,
+    // The bytes that the other side received.
+    pub(crate) transferred: i64,
+    pub(crate) total: i64,
+    pub(crate) update_time: i64,
+}
+
+impl AssetTransferTable {
+    pub(crate) fn new(asset_id: &str, direction: SqlTransferDirection, transferred: i64, total: i64) -> Self {
+        Self {
+            asset_id: asset_id.to_owned(),
+            direction,
+            transferred,
+            total,
+            update_time: flowy_infra::timestamp(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, FromSqlRow, AsExpression)]
+#[repr(i32)]
+#[sql_type = "Integer"]
+pub(crate) enum SqlTransferDirection {
+    Upload   = 0,
+    Download = 1,
+}
+
+impl std::default::Default for SqlTransferDirection {
+    fn default() -> Self { SqlTransferDirection::Upload }
+}
+
+impl std::convert::From<i32> for SqlTransferDirection {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => SqlTransferDirection::Upload,
+            1 => SqlTransferDirection::Download,
+            o => {
+                log::error!(
+                    "Unsupported transfer direction {}, fallback to SqlTransferDirection::Upload",
+                    o
+                );
+                SqlTransferDirection::Upload
+            },
+        }
+    }
+}
+impl SqlTransferDirection {
+    pub fn value(&self) -> i32 { *self as i32 }
+}
+impl_sql_integer_expression!(SqlTransferDirection);
+
+impl std::convert::From<SqlTransferDirection> for AssetTransferDirection {
+    fn from(direction: SqlTransferDirection) -> Self {
+        match direction {
+            SqlTransferDirection::Upload => AssetTransferDirection::Upload,
+            SqlTransferDirection::Download => AssetTransferDirection::Download,
+        }
+    }
+}
+
+impl std::convert::From<AssetTransferDirection> for SqlTransferDirection {
+    fn from(direction: AssetTransferDirection) -> Self {
+        match direction {
+            AssetTransferDirection::Upload => SqlTransferDirection::Upload,
+            AssetTransferDirection::Download => SqlTransferDirection::Download,
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use flowy_document::{
+    entities::block::BlockType,
+    errors::ErrorCode,
+    services::doc::{read_page, BlockOperation, Document},
+};
+use flowy_ot::core::*;
+
+fn document() -> Document {
+    let delta = DeltaBuilder::new()
+        .insert("Title")
+        .insert_with_attributes("\n", Attribute::Header(1).into())
+        .insert("123\n")
+        .insert("task")
+        .insert_with_attributes("\n", Attribute::UnChecked(true).into())
+        .insert_with_attributes("https://appflowy.io", Attribute::Link("https://appflowy.io").into())
+        .insert("\n")
+        .insert("let a = 1;")
+        .insert_with_attributes("\n", Attribute::CodeBlock(true).into())
+        .build();
+    Document::from_delta(delta)
+}
+
+fn block(s: &str, attributes: Attributes) -> Delta {
+    DeltaBuilder::new()
+        .insert(s)
+        .insert_with_attributes("\n", attributes)
+        .build()
+}
+
+fn block_texts(document: &Document) -> Vec<String> {
+    document
+        .blocks()
+        .iter()
+        .map(|block| block.delta.apply("").unwrap())
+        .collect()
+}
+
+#[test]
+fn block_split_with_types() {
+    let document = document();
+    let types = document
+        .blocks()
+        .into_iter()
+        .map(|block| block.block_type)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        types,
+        vec![
+            BlockType::Heading,
+            BlockType::Paragraph,
+            BlockType::Todo,
+            BlockType::Embed,
+            BlockType::Code,
+        ]
+    );
+    assert_eq!(document.blocks()[0].delta, block("Title", Attribute::Header(1).into()));
+}
+
+#[test]
+fn block_insert() {
+    let mut document = document();
+    let ops = vec![
+        BlockOperation::Insert {
+            index: 1,
+            block: block("456", Attribute::Bullet(true).into()),
+        },
+        BlockOperation::Insert {
+            index: 6,
+            block: block("end", Attributes::default()),
+        },
+    ];
+    let change = document.apply_block_ops(&ops).unwrap();
+    assert_eq!(
+        block_texts(&document),
+        vec![
+            "Title\n",
+            "456\n",
+            "123\n",
+            "task\n",
+            "https://appflowy.io\n",
+            "let a = 1;\n",
+            "end\n"
+        ]
+    );
+    assert_eq!(document.blocks()[1].block_type, BlockType::List);
+
+    // The change only holds the inserted blocks.
+    let inserted = change
+        .ops
+        .iter()
+        .filter(|op| op.is_insert())
+        .map(|op| op.len())
+        .sum::<usize>();
+    assert_eq!(inserted, 8);
+}
+
+#[test]
+fn block_delete() {
+    let mut document = document();
+    let _ = document
+        .apply_block_ops(&[BlockOperation::Delete { index: 0 }])
+        .unwrap();
+    assert_eq!(
+        block_texts(&document),
+        vec!["123\n", "task\n", "https://appflowy.io\n", "let a = 1;\n"]
+    );
+}
+
+#[test]
+fn block_update() {
+    let mut document = document();
+    let ops = [BlockOperation::Update {
+        index: 1,
+        block: block("Subtitle", Attribute::Header(2).into()),
+    }];
+    let _ = document.apply_block_ops(&ops).unwrap();
+    let blocks = document.blocks();
+    assert_eq!(blocks.len(), 5);
+    assert_eq!(blocks[1].delta, block("Subtitle", Attribute::Header(2).into()));
+    assert_eq!(blocks[1].block_type, BlockType::Heading);
+}
+
+#[test]
+fn block_move_keeps_attributes() {
+    let mut document = document();
+    let code = document.blocks()[4].clone();
+    let _ = document
+        .apply_block_ops(&[BlockOperation::Move { from: 4, to: 0 }])
+        .unwrap();
+    assert_eq!(document.blocks()[0], code);
+
+    let _ = document
+        .apply_block_ops(&[BlockOperation::Move { from: 0, to: 4 }])
+        .unwrap();
+    assert_eq!(document.delta(), self::document().delta());
+}
+
+#[test]
+fn block_ops_undo_as_one_unit() {
+    let mut document = document();
+    let ops = vec![
+        BlockOperation::Delete { index: 1 },
+        BlockOperation::Move { from: 0, to: 2 },
+        BlockOperation::Update {
+            index: 0,
+            block: block("done", Attribute::Checked(true).into()),
+        },
+    ];
+    let _ = document.apply_block_ops(&ops).unwrap();
+    assert_eq!(
+        block_texts(&document),
+        vec!["done\n", "https://appflowy.io\n", "Title\n", "let a = 1;\n"]
+    );
+
+    let _ = document.undo().unwrap();
+    assert_eq!(document.delta(), self::document().delta());
+}
+
+#[test]
+fn block_ops_out_of_bound() {
+    let mut document = document();
+    let ops = vec![BlockOperation::Delete { index: 0 }, BlockOperation::Delete { index: 4 }];
+    let error = document.apply_block_ops(&ops).unwrap_err();
+    assert_eq!(error.code, ErrorCode::OutOfBound);
+    // None of the operations is applied.
+    assert_eq!(document.delta(), self::document().delta());
+}
+
+#[test]
+fn block_insert_with_invalid_data() {
+    let mut document = document();
+    for data in vec![
+        DeltaBuilder::new().insert("456").build(),
+        DeltaBuilder::new().insert("4\n5\n").build(),
+    ] {
+        let ops = [BlockOperation::Insert { index: 0, block: data }];
+        let error = document.apply_block_ops(&ops).unwrap_err();
+        assert_eq!(error.code, ErrorCode::BlockDataInvalid);
+    }
+}
+
+#[test]
+fn block_read_pages() {
+    let document = document();
+    let first = read_page(document.delta(), 1, "", 2).unwrap();
+    assert_eq!((first.start, first.end, first.offset), (0, 2, 0));
+    assert_eq!(first.delta.apply("").unwrap(), "Title\n123\n");
+    assert_eq!(first.next_cursor, "1:2");
+
+    let second = read_page(document.delta(), 1, &first.next_cursor, 2).unwrap();
+    assert_eq!((second.start, second.end, second.offset), (2, 4, 10));
+    assert_eq!(second.delta.apply("").unwrap(), "task\nhttps://appflowy.io\n");
+
+    let last = read_page(document.delta(), 1, &second.next_cursor, 2).unwrap();
+    assert_eq!(last.end, last.block_count);
+    assert!(last.next_cursor.is_empty());
+
+    // The pages make up the document.
+    let mut delta = first.delta;
+    delta.extend(second.delta);
+    delta.extend(last.delta);
+    assert_eq!(&delta, document.delta());
+    assert_eq!(&read_page(document.delta(), 1, "", 0).unwrap().delta, document.delta());
+}
+
+#[test]
+fn block_read_page_with_invalid_cursor() {
+    let document = document();
+    let read = |cursor: &str| read_page(document.delta(), 2, cursor, 2).unwrap_err().code;
+    assert_eq!(read("a"), ErrorCode::CursorInvalid);
+    assert_eq!(read("2"), ErrorCode::CursorInvalid);
+    assert_eq!(read("2:10"), ErrorCode::OutOfBound);
+    // The cursor of another revision is refused.
+    assert_eq!(read("1:2"), ErrorCode::CursorInvalid);
+}
@@ -0,0 +1,108 @@
+use flowy_document::{
+    entities::check::{DiagnosticKind, TextDiagnostic},
+    services::check::{check_text, DiagnosticCache, RepeatedWordChecker, TextChecker},
+};
+use flowy_ot::core::*;
+use std::sync::Mutex;
+
+// Flags every "teh" and keeps the texts it was asked to check.
+#[derive(Debug, Default)]
+struct TypoChecker {
+    checked: Mutex<Vec<String>>,
+}
+
+impl TextChecker for TypoChecker {
+    fn check(&self, texts: &[String]) -> Vec<Vec<TextDiagnostic>> {
+        self.checked.lock().unwrap().extend(texts.iter().cloned());
+        texts
+            .iter()
+            .map(|text| {
+                text.match_indices("teh")
+                    .map(|(index, _)| TextDiagnostic {
+                        start: index as i64,
+                        end: index as i64 + 3,
+                        kind: DiagnosticKind::Spelling,
+                        message: "Unknown word".to_owned(),
+                        suggestions: vec!["the".to_owned()],
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl TypoChecker {
+    fn take_checked(&self) -> Vec<String> { std::mem::take(&mut *self.checked.lock().unwrap()) }
+}
+
+fn ranges(diagnostics: &[TextDiagnostic]) -> Vec<(i64, i64)> {
+    diagnostics
+        .iter()
+        .map(|diagnostic| (diagnostic.start, diagnostic.end))
+        .collect()
+}
+
+#[test]
+fn check_repeated_words() {
+    let texts = vec![
+        "This is is a test".to_owned(),
+        "The the end".to_owned(),
+        "is, is not repeated".to_owned(),
+    ];
+    let results = RepeatedWordChecker::default().check(&texts);
+    assert_eq!(ranges(&results[0]), vec![(5, 10)]);
+    assert_eq!(results[0][0].kind, DiagnosticKind::Grammar);
+    assert_eq!(results[0][0].suggestions, vec!["is".to_owned()]);
+    assert_eq!(ranges(&results[1]), vec![(0, 7)]);
+    assert_eq!(results[1][0].suggestions, vec!["The".to_owned()]);
+    assert!(results[2].is_empty());
+}
+
+#[test]
+fn check_repeated_words_utf16_range() {
+    let texts = vec!["😀 go go".to_owned()];
+    let results = RepeatedWordChecker::default().check(&texts);
+    assert_eq!(ranges(&results[0]), vec![(3, 8)]);
+}
+
+#[test]
+fn check_text_range() {
+    let delta = DeltaBuilder::new().insert("teh cat\n\nteh dog\nfine\n").build();
+    let checker = TypoChecker::default();
+    let diagnostics = check_text(&checker, &delta, 0, 0).unwrap();
+    assert_eq!(ranges(&diagnostics), vec![(0, 3), (9, 12)]);
+    assert_eq!(checker.take_checked().len(), 3);
+
+    // Only the lines in the range are checked.
+    let diagnostics = check_text(&checker, &delta, 10, 16).unwrap();
+    assert!(diagnostics.is_empty());
+    assert_eq!(checker.take_checked(), vec!["teh dog".to_owned()]);
+
+    let diagnostics = check_text(&checker, &delta, 9, 16).unwrap();
+    assert_eq!(ranges(&diagnostics), vec![(9, 12)]);
+
+    assert!(check_text(&checker, &delta, 5, 3).is_err());
+    assert!(check_text(&checker, &delta, 0, delta.target_len + 1).is_err());
+}
+
+#[test]
+fn check_only_changed_lines() {
+    let checker = TypoChecker::default();
+    let mut cache = DiagnosticCache::default();
+    let delta = DeltaBuilder::new().insert("teh cat\nteh dog\n").build();
+    assert_eq!(ranges(&cache.check(&checker, &delta).unwrap()), vec![(0, 3), (8, 11)]);
+    assert_eq!(checker.take_checked().len(), 2);
+
+    // The diagnostics are moved with the lines, only the new line is checked.
+    let delta = DeltaBuilder::new().insert("a bird\nteh cat\nteh dog\n").build();
+    assert_eq!(ranges(&cache.check(&checker, &delta).unwrap()), vec![(7, 10), (15, 18)]);
+    assert_eq!(checker.take_checked(), vec!["a bird".to_owned()]);
+
+    // Nothing is changed.
+    assert!(cache.check(&checker, &delta).is_none());
+    assert!(checker.take_checked().is_empty());
+
+    let delta = DeltaBuilder::new().insert("a bird\nthe cat\nteh dog\n").build();
+    assert_eq!(ranges(&cache.check(&checker, &delta).unwrap()), vec![(15, 18)]);
+    assert_eq!(checker.take_checked(), vec!["the cat".to_owned()]);
+}
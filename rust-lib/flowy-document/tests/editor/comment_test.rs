@@ -0,0 +1,85 @@
+use flowy_document::services::doc::{transform_index, Document, PlainDoc};
+use flowy_ot::core::*;
+
+fn document_with_anchor(s: &str, interval: Interval) -> Document {
+    let mut document = Document::new::<PlainDoc>();
+    let _ = document.insert(0, s).unwrap();
+    document.anchors_mut().insert("comment", interval);
+    document
+}
+
+fn anchor(document: &Document) -> (usize, usize) { document.anchors().get("comment").unwrap().start_end() }
+
+#[test]
+fn anchor_transform_index() {
+    let change = DeltaBuilder::new().retain(2).insert("ab").delete(1).build();
+    assert_eq!(transform_index(&change, 1, false), 1);
+    assert_eq!(transform_index(&change, 2, false), 4);
+    assert_eq!(transform_index(&change, 2, true), 2);
+    assert_eq!(transform_index(&change, 3, false), 4);
+    assert_eq!(transform_index(&change, 5, false), 6);
+}
+
+#[test]
+fn anchor_moved_by_insert_before() {
+    let mut document = document_with_anchor("123456", Interval::new(2, 4));
+    let _ = document.insert(0, "ab").unwrap();
+    assert_eq!(anchor(&document), (4, 6));
+
+    // The text after the range doesn't move it.
+    let _ = document.insert(7, "cd").unwrap();
+    assert_eq!(anchor(&document), (4, 6));
+}
+
+#[test]
+fn anchor_grows_with_insert_inside() {
+    let mut document = document_with_anchor("123456", Interval::new(2, 4));
+    let _ = document.insert(3, "ab").unwrap();
+    assert_eq!(anchor(&document), (2, 6));
+}
+
+#[test]
+fn anchor_excludes_insert_at_edges() {
+    let mut document = document_with_anchor("123456", Interval::new(2, 4));
+    let _ = document.insert(4, "ab").unwrap();
+    assert_eq!(anchor(&document), (2, 4));
+
+    let _ = document.insert(2, "cd").unwrap();
+    assert_eq!(anchor(&document), (4, 6));
+}
+
+#[test]
+fn anchor_shrinks_with_delete() {
+    let mut document = document_with_anchor("123456", Interval::new(2, 5));
+    let _ = document.delete(Interval::new(1, 3)).unwrap();
+    assert_eq!(anchor(&document), (1, 3));
+
+    // The range is empty once its text is deleted.
+    let _ = document.delete(Interval::new(0, 4)).unwrap();
+    assert_eq!(anchor(&document), (0, 0));
+}
+
+#[test]
+fn anchor_moved_back_by_undo() {
+    let mut document = document_with_anchor("123456", Interval::new(2, 4));
+    // The undo unit of the insert is apart from the one that made the document.
+    let _ = document
+        .compose_delta_as_unit(DeltaBuilder::new().insert("ab").build())
+        .unwrap();
+    assert_eq!(anchor(&document), (4, 6));
+
+    let _ = document.undo().unwrap();
+    assert_eq!(anchor(&document), (2, 4));
+}
+
+#[test]
+fn anchor_take_changed() {
+    let mut document = document_with_anchor("123456", Interval::new(2, 4));
+    let _ = document.insert(6, "ab").unwrap();
+    assert!(document.anchors_mut().take_changed().is_empty());
+
+    let _ = document.insert(0, "ab").unwrap();
+    let changed = document.anchors_mut().take_changed();
+    assert_eq!(changed, vec![("comment".to_owned(), Interval::new(4, 6))]);
+    assert!(document.anchors_mut().take_changed().is_empty());
+}
@@ -0,0 +1,187 @@
+use crate::editor::Rng;
+use flowy_ot::core::*;
+
+const ROUNDS: usize = 200;
+
+fn gen_doc(rng: &mut Rng, len: usize) -> (String, Delta) {
+    let s = rng.gen_string(len);
+    let mut doc = Delta::default();
+    s.chars()
+        .for_each(|c| doc.insert(&c.to_string(), rng.gen_attributes(false)));
+    (s, doc)
+}
+
+#[test]
+fn crdt_from_delta_round_trip() {
+    for _ in 0..ROUNDS {
+        let mut rng = Rng::default();
+        let (_, doc) = gen_doc(&mut rng, 20);
+        let crdt = CrdtDoc::from_delta(1, &doc).unwrap();
+        assert_eq!(crdt.len(), doc.target_len);
+        assert_eq!(crdt.to_delta(), doc);
+    }
+}
+
+#[test]
+fn crdt_from_delta_with_retain_fails() {
+    let delta = DeltaBuilder::new().insert("123").retain(3).build();
+    assert!(CrdtDoc::from_delta(1, &delta).is_err());
+}
+
+#[test]
+fn crdt_from_revision_deltas() {
+    let deltas = vec![
+        DeltaBuilder::new().insert("123").build(),
+        DeltaBuilder::new().retain(3).insert("456").build(),
+        DeltaBuilder::new().delete(1).retain(5).build(),
+    ];
+    let crdt = CrdtDoc::from_deltas(1, &deltas).unwrap();
+    assert_eq!(crdt.to_delta().apply("").unwrap(), "23456");
+}
+
+#[test]
+fn crdt_apply_delta_is_same_as_compose() {
+    for _ in 0..ROUNDS {
+        let mut rng = Rng::default();
+        let (s, doc) = gen_doc(&mut rng, 20);
+        let change = rng.gen_format_delta(&s);
+        let mut crdt = CrdtDoc::from_delta(1, &doc).unwrap();
+        crdt.apply_delta(&change).unwrap();
+        assert_eq!(crdt.to_delta(), doc.compose(&change).unwrap());
+    }
+}
+
+#[test]
+fn crdt_apply_delta_with_different_base_fails() {
+    let mut crdt = CrdtDoc::from_delta(1, &DeltaBuilder::new().insert("123").build()).unwrap();
+    let change = DeltaBuilder::new().retain(4).insert("4").build();
+    assert!(crdt.apply_delta(&change).is_err());
+}
+
+#[test]
+fn crdt_concurrent_edits_converge() {
+    for _ in 0..ROUNDS {
+        let mut rng = Rng::default();
+        let (s, doc) = gen_doc(&mut rng, 20);
+        let mut a = CrdtDoc::from_delta(1, &doc).unwrap();
+        let mut b = CrdtDoc::from_delta(2, &doc).unwrap();
+        a.apply_delta(&rng.gen_format_delta(&s)).unwrap();
+        b.apply_delta(&rng.gen_format_delta(&s)).unwrap();
+
+        // Both replicas keep editing before they are merged.
+        let a_text = a.to_delta().apply("").unwrap();
+        let b_text = b.to_delta().apply("").unwrap();
+        a.apply_delta(&rng.gen_format_delta(&a_text)).unwrap();
+        b.apply_delta(&rng.gen_format_delta(&b_text)).unwrap();
+
+        let b_before_merge = b.clone();
+        b.merge(&a);
+        a.merge(&b_before_merge);
+        assert_eq!(a.to_delta(), b.to_delta());
+        assert_eq!(a.len(), a.to_delta().target_len);
+    }
+}
+
+#[test]
+fn crdt_merge_is_idempotent() {
+    for _ in 0..ROUNDS {
+        let mut rng = Rng::default();
+        let (s, doc) = gen_doc(&mut rng, 20);
+        let mut a = CrdtDoc::from_delta(1, &doc).unwrap();
+        let mut b = CrdtDoc::from_delta(2, &doc).unwrap();
+        b.apply_delta(&rng.gen_format_delta(&s)).unwrap();
+
+        a.merge(&b);
+        let merged = a.to_delta();
+        a.merge(&b);
+        assert_eq!(a.to_delta(), merged);
+        assert_eq!(merged, b.to_delta());
+    }
+}
+
+#[test]
+fn crdt_merge_after_merge_keeps_editing() {
+    let doc = DeltaBuilder::new().insert("123").build();
+    let mut a = CrdtDoc::from_delta(1, &doc).unwrap();
+    let mut b = CrdtDoc::from_delta(2, &doc).unwrap();
+    a.apply_delta(&DeltaBuilder::new().retain(3).insert("a").build())
+        .unwrap();
+    b.merge(&a);
+    b.apply_delta(&DeltaBuilder::new().retain(4).insert("b").build())
+        .unwrap();
+    a.merge(&b);
+    assert_eq!(a.to_delta().apply("").unwrap(), "123ab");
+    assert_eq!(b.to_delta().apply("").unwrap(), "123ab");
+}
+
+#[test]
+fn crdt_concurrent_insert_at_same_position() {
+    let doc = DeltaBuilder::new().insert("123").build();
+    let mut a = CrdtDoc::from_delta(1, &doc).unwrap();
+    let mut b = CrdtDoc::from_delta(2, &doc).unwrap();
+    a.apply_delta(&DeltaBuilder::new().retain(1).insert("aa").retain(2).build())
+        .unwrap();
+    b.apply_delta(&DeltaBuilder::new().retain(1).insert("bb").retain(2).build())
+        .unwrap();
+
+    let b_before_merge = b.clone();
+    b.merge(&a);
+    a.merge(&b_before_merge);
+    // The insert of the replica with the greater id comes first, the inserts aren't
+    // interleaved.
+    assert_eq!(a.to_delta().apply("").unwrap(), "1bbaa23");
+    assert_eq!(b.to_delta().apply("").unwrap(), "1bbaa23");
+}
+
+#[test]
+fn crdt_insert_after_concurrently_deleted_char() {
+    let doc = DeltaBuilder::new().insert("123").build();
+    let mut a = CrdtDoc::from_delta(1, &doc).unwrap();
+    let mut b = CrdtDoc::from_delta(2, &doc).unwrap();
+    a.apply_delta(&DeltaBuilder::new().retain(1).delete(1).retain(1).build())
+        .unwrap();
+    b.apply_delta(&DeltaBuilder::new().retain(2).insert("b").retain(1).build())
+        .unwrap();
+
+    a.merge(&b);
+    assert_eq!(a.to_delta().apply("").unwrap(), "1b3");
+}
+
+#[test]
+fn crdt_concurrent_format_last_writer_wins() {
+    let doc = DeltaBuilder::new().insert("123").build();
+    let mut a = CrdtDoc::from_delta(1, &doc).unwrap();
+    let mut b = CrdtDoc::from_delta(2, &doc).unwrap();
+    a.apply_delta(
+        &DeltaBuilder::new()
+            .retain_with_attributes(3, Attribute::Bold(true).into())
+            .build(),
+    )
+    .unwrap();
+    b.apply_delta(
+        &DeltaBuilder::new()
+            .retain_with_attributes(3, Attribute::Bold(false).into())
+            .build(),
+    )
+    .unwrap();
+
+    let b_before_merge = b.clone();
+    b.merge(&a);
+    a.merge(&b_before_merge);
+    assert_eq!(a.to_delta(), b.to_delta());
+    assert_eq!(a.to_delta().to_json(), r#"[{"insert":"123"}]"#);
+}
+
+#[test]
+fn crdt_json_round_trip() {
+    for _ in 0..ROUNDS {
+        let mut rng = Rng::default();
+        let (s, doc) = gen_doc(&mut rng, 20);
+        let mut crdt = CrdtDoc::from_delta(1, &doc).unwrap();
+        crdt.apply_delta(&rng.gen_format_delta(&s)).unwrap();
+
+        let restored = CrdtDoc::from_bytes(crdt.to_bytes()).unwrap();
+        assert_eq!(restored.to_delta(), crdt.to_delta());
+        assert_eq!(restored.replica(), crdt.replica());
+    }
+}
@@ -0,0 +1,95 @@
+use flowy_document::{
+    entities::diff::{AttributeChange, BlockDiffType},
+    services::doc::diff_documents,
+};
+use flowy_ot::core::*;
+
+fn change(key: &str, old_value: &str, new_value: &str, start: i64, end: i64) -> AttributeChange {
+    AttributeChange {
+        key: key.to_owned(),
+        old_value: old_value.to_owned(),
+        new_value: new_value.to_owned(),
+        start,
+        end,
+    }
+}
+
+#[test]
+fn diff_same_documents() {
+    let delta = DeltaBuilder::new().insert("123\n456\n").build();
+    assert!(diff_documents(&delta, &delta).is_empty());
+}
+
+#[test]
+fn diff_inserted_and_deleted_blocks() {
+    let old = DeltaBuilder::new().insert("123\n456\n789\n").build();
+    let new = DeltaBuilder::new().insert("000\n123\n789\n").build();
+    let diffs = diff_documents(&old, &new);
+    assert_eq!(diffs.len(), 2);
+
+    assert_eq!(diffs[0].ty, BlockDiffType::Inserted);
+    assert_eq!((diffs[0].old_index, diffs[0].new_index), (-1, 0));
+    assert_eq!(diffs[0].new_data, DeltaBuilder::new().insert("000\n").build().to_json());
+
+    assert_eq!(diffs[1].ty, BlockDiffType::Deleted);
+    assert_eq!((diffs[1].old_index, diffs[1].new_index), (1, -1));
+    assert_eq!(diffs[1].old_data, DeltaBuilder::new().insert("456\n").build().to_json());
+}
+
+#[test]
+fn diff_changed_block_text() {
+    let old = DeltaBuilder::new().insert("123\n456\n789\n").build();
+    let new = DeltaBuilder::new().insert("123\n4056\n789\n").build();
+    let diffs = diff_documents(&old, &new);
+    assert_eq!(diffs.len(), 1);
+    assert_eq!(diffs[0].ty, BlockDiffType::Changed);
+    assert_eq!((diffs[0].old_index, diffs[0].new_index), (1, 1));
+    assert!(diffs[0].text_changed);
+    assert!(diffs[0].attributes.is_empty());
+}
+
+#[test]
+fn diff_changed_inline_attributes() {
+    let old = DeltaBuilder::new().insert("123456\n").build();
+    let new = DeltaBuilder::new()
+        .insert("1")
+        .insert_with_attributes("2345", Attribute::Bold(true).into())
+        .insert("6\n")
+        .build();
+    let diffs = diff_documents(&old, &new);
+    assert_eq!(diffs.len(), 1);
+    assert!(!diffs[0].text_changed);
+    assert_eq!(diffs[0].attributes, vec![change("bold", "", "true", 1, 5)]);
+}
+
+#[test]
+fn diff_changed_block_attributes() {
+    let old = DeltaBuilder::new().insert("123\n").build();
+    let new = DeltaBuilder::new()
+        .insert("123")
+        .insert_with_attributes("\n", Attribute::Header(1).into())
+        .build();
+    let diffs = diff_documents(&old, &new);
+    assert_eq!(diffs.len(), 1);
+    assert_eq!(diffs[0].ty, BlockDiffType::Changed);
+    assert_eq!(diffs[0].attributes, vec![change("header", "", "1", 3, 4)]);
+}
+
+#[test]
+fn diff_changed_blocks_are_paired_in_order() {
+    let old = DeltaBuilder::new().insert("123\n456\n789\n").build();
+    let new = DeltaBuilder::new().insert("123\nabc\ndef\nghi\n789\n").build();
+    let diffs = diff_documents(&old, &new);
+    let summary = diffs
+        .iter()
+        .map(|diff| (diff.ty.clone(), diff.old_index, diff.new_index))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        vec![
+            (BlockDiffType::Changed, 1, 1),
+            (BlockDiffType::Inserted, -1, 2),
+            (BlockDiffType::Inserted, -1, 3),
+        ]
+    );
+}
@@ -0,0 +1,78 @@
+use flowy_document::services::{
+    export::delta_to_markdown,
+    import::{html_to_delta, markdown_to_delta},
+};
+use flowy_ot::core::*;
+
+fn assert_delta(delta: Delta, json: &str) { assert_eq!(delta, Delta::from_json(json).unwrap()); }
+
+#[test]
+fn import_empty_markdown() {
+    assert_delta(markdown_to_delta(""), r#"[{"insert":"\n"}]"#);
+}
+
+#[test]
+fn import_markdown_paragraphs() {
+    let delta = markdown_to_delta("123\n456\n\n789");
+    assert_delta(delta, r#"[{"insert":"123 456\n789\n"}]"#);
+}
+
+#[test]
+fn import_markdown_blocks() {
+    let markdown = "# Title\n\n- a\n    - b\n1. one\n- [x] done\n- [ ] todo\n> quote\n\n```\nfn main() {\n    let a_b = 1;\n}\n```\n";
+    let delta = markdown_to_delta(markdown);
+    assert_delta(delta, r#"[{"insert":"Title"},{"insert":"\n","attributes":{"header":"1"}},{"insert":"a"},{"insert":"\n","attributes":{"list":"bullet"}},{"insert":"b"},{"insert":"\n","attributes":{"list":"bullet","indent":"1"}},{"insert":"one"},{"insert":"\n","attributes":{"list":"ordered"}},{"insert":"done"},{"insert":"\n","attributes":{"list":"checked"}},{"insert":"todo"},{"insert":"\n","attributes":{"list":"unchecked"}},{"insert":"quote"},{"insert":"\n","attributes":{"blockquote":"true"}},{"insert":"fn main() {"},{"insert":"\n","attributes":{"code_block":"true"}},{"insert":"    let a_b = 1;"},{"insert":"\n","attributes":{"code_block":"true"}},{"insert":"}"},{"insert":"\n","attributes":{"code_block":"true"}}]"#);
+}
+
+#[test]
+fn import_markdown_inline_formats() {
+    let delta = markdown_to_delta("**a** _b_ ~~c~~ `d()` snake_case 2*3");
+    assert_delta(delta, r#"[{"insert":"a","attributes":{"bold":"true"}},{"insert":" "},{"insert":"b","attributes":{"italic":"true"}},{"insert":" "},{"insert":"c","attributes":{"strike":"true"}},{"insert":" "},{"insert":"d()","attributes":{"code":"true"}},{"insert":" snake_case 2*3\n"}]"#);
+}
+
+#[test]
+fn import_markdown_link_and_image() {
+    let delta = markdown_to_delta("[**App**Flowy](https://appflowy.io) ![logo](logo.png \"Logo\")");
+    assert_delta(delta, r#"[{"insert":"App","attributes":{"bold":"true","link":"https://appflowy.io"}},{"insert":"Flowy","attributes":{"link":"https://appflowy.io"}},{"insert":" "},{"insert":"logo","attributes":{"link":"logo.png"}},{"insert":"\n"}]"#);
+}
+
+#[test]
+fn import_markdown_escapes() {
+    let delta = markdown_to_delta("\\# 1\\*2\\_3");
+    assert_delta(delta, r##"[{"insert":"# 1*2_3\n"}]"##);
+}
+
+#[test]
+fn import_markdown_of_exported_document() {
+    let json = r#"[{"insert":"Title"},{"insert":"\n","attributes":{"header":"2"}},{"insert":"a *b*","attributes":{"bold":"true"}},{"insert":" c"},{"insert":"\n","attributes":{"list":"bullet"}},{"insert":"d","attributes":{"link":"https://appflowy.io"}},{"insert":"\n"}]"#;
+    let delta = Delta::from_json(json).unwrap();
+    assert_eq!(markdown_to_delta(&delta_to_markdown(&delta)), delta);
+}
+
+#[test]
+fn import_html_blocks() {
+    let html = r#"<html><head><title>x</title><style>p { color: red; }</style></head><body>
+        <h1>Title</h1>
+        <p>Hello <b>bold</b> and <a href="https://appflowy.io">link</a></p>
+        <ul><li>a<ul><li>b</li></ul></li></ul>
+        <ol><li>one</li></ol>
+        <ul><li><input type="checkbox" checked>done</li><li><input type="checkbox">todo</li></ul>
+        <blockquote>quote</blockquote>
+        <pre><code>fn main() {
+    let a = 1;
+}</code></pre>
+        </body></html>"#;
+    assert_delta(html_to_delta(html), r#"[{"insert":"Title"},{"insert":"\n","attributes":{"header":"1"}},{"insert":"Hello "},{"insert":"bold","attributes":{"bold":"true"}},{"insert":" and "},{"insert":"link","attributes":{"link":"https://appflowy.io"}},{"insert":"\na"},{"insert":"\n","attributes":{"list":"bullet"}},{"insert":"b"},{"insert":"\n","attributes":{"list":"bullet","indent":"1"}},{"insert":"one"},{"insert":"\n","attributes":{"list":"ordered"}},{"insert":"done"},{"insert":"\n","attributes":{"list":"checked"}},{"insert":"todo"},{"insert":"\n","attributes":{"list":"unchecked"}},{"insert":"quote"},{"insert":"\n","attributes":{"blockquote":"true"}},{"insert":"fn main() {"},{"insert":"\n","attributes":{"code_block":"true"}},{"insert":"    let a = 1;"},{"insert":"\n","attributes":{"code_block":"true"}},{"insert":"}"},{"insert":"\n","attributes":{"code_block":"true"}}]"#);
+}
+
+#[test]
+fn import_html_unknown_elements_keep_text() {
+    let html = "<custom-element>a <span class=\"x\">b</span></custom-element><script>alert(1)</script><!-- c -->";
+    assert_delta(html_to_delta(html), r#"[{"insert":"a b\n"}]"#);
+}
+
+#[test]
+fn import_html_entities_and_image() {
+    let html = "<p>1 &lt; 2 &amp;&amp; &#x1F600; &unknown;</p><p><img src=\"a.png\" alt=\"pic\"></p>";
+    assert_delta(html_to_delta(html), r#"[{"insert":"1 < 2 && 😀 &unknown;\n"},{"insert":"pic","attributes":{"link":"a.png"}},{"insert":"\n"}]"#);
+}
@@ -0,0 +1,67 @@
+use flowy_document::services::export::delta_to_markdown;
+use flowy_ot::core::*;
+
+fn markdown(json: &str) -> String { delta_to_markdown(&Delta::from_json(json).unwrap()) }
+
+#[test]
+fn markdown_empty_document() {
+    assert_eq!(markdown(r#"[{"insert":"\n"}]"#), "");
+}
+
+#[test]
+fn markdown_paragraphs() {
+    assert_eq!(markdown(r#"[{"insert":"123\n\n456\n"}]"#), "123\n\n456\n");
+}
+
+#[test]
+fn markdown_headers() {
+    let json = r#"[{"insert":"Title"},{"insert":"\n","attributes":{"header":"1"}},{"insert":"Sub"},{"insert":"\n","attributes":{"header":"2"}}]"#;
+    assert_eq!(markdown(json), "# Title\n\n## Sub\n");
+}
+
+#[test]
+fn markdown_inline_formats() {
+    let json = r#"[{"insert":"a ","attributes":{"bold":"true"}},{"insert":"b","attributes":{"italic":"true"}},{"insert":" "},{"insert":"c","attributes":{"strike":"true"}},{"insert":" "},{"insert":"d()","attributes":{"code":"true"}},{"insert":"\n"}]"#;
+    assert_eq!(markdown(json), "**a** _b_ ~~c~~ `d()`\n");
+}
+
+#[test]
+fn markdown_link() {
+    let json = r#"[{"insert":"AppFlowy","attributes":{"link":"https://appflowy.io"}},{"insert":"\n"}]"#;
+    assert_eq!(markdown(json), "[AppFlowy](https://appflowy.io)\n");
+}
+
+#[test]
+fn markdown_lists() {
+    let json = r#"[{"insert":"a"},{"insert":"\n","attributes":{"list":"bullet"}},{"insert":"b"},{"insert":"\n","attributes":{"list":"bullet","indent":"1"}},{"insert":"one"},{"insert":"\n","attributes":{"list":"ordered"}},{"insert":"two"},{"insert":"\n","attributes":{"list":"ordered"}},{"insert":"done"},{"insert":"\n","attributes":{"list":"checked"}},{"insert":"todo"},{"insert":"\n","attributes":{"list":"unchecked"}}]"#;
+    assert_eq!(
+        markdown(json),
+        "- a\n    - b\n1. one\n2. two\n- [x] done\n- [ ] todo\n"
+    );
+}
+
+#[test]
+fn markdown_ordered_list_restarts_after_paragraph() {
+    let json = r#"[{"insert":"one"},{"insert":"\n","attributes":{"list":"ordered"}},{"insert":"text\none"},{"insert":"\n","attributes":{"list":"ordered"}}]"#;
+    assert_eq!(markdown(json), "1. one\n\ntext\n\n1. one\n");
+}
+
+#[test]
+fn markdown_code_block() {
+    let json = r#"[{"insert":"text\nfn main() {"},{"insert":"\n","attributes":{"code_block":"true"}},{"insert":"    let a_b = 1;"},{"insert":"\n","attributes":{"code_block":"true"}},{"insert":"}"},{"insert":"\n","attributes":{"code_block":"true"}}]"#;
+    assert_eq!(
+        markdown(json),
+        "text\n\n```\nfn main() {\n    let a_b = 1;\n}\n```\n"
+    );
+}
+
+#[test]
+fn markdown_quote() {
+    let json = r#"[{"insert":"a"},{"insert":"\n","attributes":{"blockquote":"true"}},{"insert":"b"},{"insert":"\n","attributes":{"blockquote":"true"}}]"#;
+    assert_eq!(markdown(json), "> a\n> b\n");
+}
+
+#[test]
+fn markdown_escapes_plain_text() {
+    assert_eq!(markdown(r##"[{"insert":"# 1*2_3\n1. a\n"}]"##), "\\# 1\\*2\\_3\n\n1\\. a\n");
+}
@@ -1,7 +1,19 @@
 mod attribute_test;
+mod block_test;
+mod check_test;
+mod comment_test;
+mod crdt_test;
+mod diff_test;
+mod import_test;
+mod markdown_test;
 mod op_test;
+mod presence_test;
+mod property_test;
+mod proptest_test;
 mod serde_test;
+mod stats_test;
 mod undo_redo_test;
+mod ws_test;
 
 use derive_more::Display;
 use flowy_document::services::doc::{CustomDocument, Document};
@@ -284,10 +296,14 @@ impl Rng {
 
     pub fn gen_string(&mut self, len: usize) -> String { (0..len).map(|_| self.0.gen::<char>()).collect() }
 
+    // The delta's lengths are utf16 code units, so the ops are cut on the char
+    // boundaries to not split a surrogate pair.
     pub fn gen_delta(&mut self, s: &str) -> Delta {
+        let chars = s.chars().collect::<Vec<char>>();
         let mut delta = Delta::default();
+        let mut index = 0;
         loop {
-            let left = s.chars().count() - delta.base_len;
+            let left = chars.len() - index;
             if left == 0 {
                 break;
             }
@@ -296,21 +312,50 @@ impl Rng {
             } else {
                 1 + self.0.gen_range(0, std::cmp::min(left - 1, 20))
             };
+            let len = chars[index..index + i].iter().map(|c| c.len_utf16()).sum();
             match self.0.gen_range(0.0, 1.0) {
                 f if f < 0.2 => {
                     delta.insert(&self.gen_string(i), Attributes::default());
+                    continue;
                 },
                 f if f < 0.4 => {
-                    delta.delete(i);
+                    delta.delete(len);
                 },
                 _ => {
-                    delta.retain(i, Attributes::default());
+                    delta.retain(len, Attributes::default());
                 },
             }
+            index += i;
         }
         if self.0.gen_range(0.0, 1.0) < 0.3 {
             delta.insert(&("1".to_owned() + &self.gen_string(10)), Attributes::default());
         }
         delta
     }
+
+    // Same as gen_delta, but the inserts and the retains are formatted randomly.
+    // Some of the retains remove the format instead.
+    pub fn gen_format_delta(&mut self, s: &str) -> Delta {
+        let mut delta = Delta::default();
+        for op in self.gen_delta(s).ops {
+            match op {
+                Operation::Insert(insert) => delta.insert(&insert.s, self.gen_attributes(false)),
+                Operation::Retain(retain) => delta.retain(retain.n, self.gen_attributes(true)),
+                Operation::Delete(n) => delta.delete(n),
+            }
+        }
+        delta
+    }
+
+    pub fn gen_attributes(&mut self, removable: bool) -> Attributes {
+        let mut attributes = Attributes::default();
+        for key in &[AttributeKey::Bold, AttributeKey::Italic] {
+            match self.0.gen_range(0.0, 1.0) {
+                f if f < 0.3 => attributes.add_kv(key.clone(), AttributeValue::from(true)),
+                f if removable && f < 0.4 => attributes.delete(key),
+                _ => {},
+            }
+        }
+        attributes
+    }
 }
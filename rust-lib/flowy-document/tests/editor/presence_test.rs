@@ -0,0 +1,59 @@
+use flowy_document::{
+    entities::presence::DocPresence,
+    services::doc::{Collaborators, PRESENCE_TIMEOUT},
+};
+
+fn presence(user_id: &str, start: i64, end: i64, update_time: i64) -> DocPresence {
+    DocPresence {
+        doc_id: "doc".to_owned(),
+        user_id: user_id.to_owned(),
+        start,
+        end,
+        cleared: false,
+        update_time,
+    }
+}
+
+#[test]
+fn presence_one_for_each_user() {
+    let collaborators = Collaborators::new();
+    assert!(collaborators.receive(presence("b", 1, 1, 10)));
+    assert!(collaborators.receive(presence("a", 0, 3, 10)));
+    assert!(collaborators.receive(presence("b", 2, 5, 11)));
+
+    let active = collaborators.active_at(12);
+    assert_eq!(active, vec![presence("a", 0, 3, 10), presence("b", 2, 5, 11)]);
+}
+
+#[test]
+fn presence_out_of_order_is_ignored() {
+    let collaborators = Collaborators::new();
+    assert!(collaborators.receive(presence("a", 2, 2, 11)));
+    assert!(!collaborators.receive(presence("a", 1, 1, 10)));
+    assert_eq!(collaborators.active_at(12), vec![presence("a", 2, 2, 11)]);
+}
+
+#[test]
+fn presence_cleared() {
+    let collaborators = Collaborators::new();
+    assert!(collaborators.receive(presence("a", 2, 2, 10)));
+
+    let mut cleared = presence("a", 0, 0, 11);
+    cleared.cleared = true;
+    assert!(collaborators.receive(cleared.clone()));
+    assert!(collaborators.active_at(12).is_empty());
+
+    // Nothing changes if the user has no presence.
+    assert!(!collaborators.receive(cleared));
+}
+
+#[test]
+fn presence_expired() {
+    let collaborators = Collaborators::new();
+    assert!(collaborators.receive(presence("a", 2, 2, 10)));
+    assert!(collaborators.receive(presence("b", 2, 2, 20)));
+    assert_eq!(
+        collaborators.active_at(10 + PRESENCE_TIMEOUT),
+        vec![presence("b", 2, 2, 20)]
+    );
+}
@@ -0,0 +1,124 @@
+use crate::editor::Rng;
+use flowy_ot::core::*;
+
+const ROUNDS: usize = 500;
+
+// The document that the deltas are applied to, it only contains inserts with random
+// formats.
+fn gen_doc(rng: &mut Rng, len: usize) -> (String, Delta) {
+    let s = rng.gen_string(len);
+    let mut doc = Delta::default();
+    s.chars().for_each(|c| doc.insert(&c.to_string(), rng.gen_attributes(false)));
+    (s, doc)
+}
+
+#[test]
+fn compose_is_associative() {
+    for _ in 0..ROUNDS {
+        let mut rng = Rng::default();
+        let s = rng.gen_string(20);
+        let a = rng.gen_format_delta(&s);
+        let b = rng.gen_format_delta(&a.apply(&s).unwrap());
+        let c = rng.gen_format_delta(&b.apply(&a.apply(&s).unwrap()).unwrap());
+
+        let ab_c = a.compose(&b).unwrap().compose(&c).unwrap();
+        let a_bc = a.compose(&b.compose(&c).unwrap()).unwrap();
+        assert_eq!(ab_c, a_bc);
+    }
+}
+
+#[test]
+fn compose_keeps_the_lengths() {
+    for _ in 0..ROUNDS {
+        let mut rng = Rng::default();
+        let s = rng.gen_string(20);
+        let a = rng.gen_format_delta(&s);
+        let b = rng.gen_format_delta(&a.apply(&s).unwrap());
+        let ab = a.compose(&b).unwrap();
+        assert_eq!(ab.base_len, a.base_len);
+        assert_eq!(ab.target_len, b.target_len);
+    }
+}
+
+#[test]
+fn compose_with_noop_is_identity() {
+    for _ in 0..ROUNDS {
+        let mut rng = Rng::default();
+        let (_, doc) = gen_doc(&mut rng, 20);
+        let noop = DeltaBuilder::new().retain(doc.target_len).build();
+        assert_eq!(doc.compose(&noop).unwrap(), doc);
+    }
+}
+
+#[test]
+fn transform_converges() {
+    for _ in 0..ROUNDS {
+        let mut rng = Rng::default();
+        let (s, doc) = gen_doc(&mut rng, 20);
+        let a = rng.gen_format_delta(&s);
+        let b = rng.gen_format_delta(&s);
+        let (a_prime, b_prime) = a.transform(&b).unwrap();
+
+        let left = doc.compose(&a).unwrap().compose(&b_prime).unwrap();
+        let right = doc.compose(&b).unwrap().compose(&a_prime).unwrap();
+        assert_eq!(left, right);
+    }
+}
+
+#[test]
+fn transform_keeps_the_lengths() {
+    for _ in 0..ROUNDS {
+        let mut rng = Rng::default();
+        let s = rng.gen_string(20);
+        let a = rng.gen_format_delta(&s);
+        let b = rng.gen_format_delta(&s);
+        let (a_prime, b_prime) = a.transform(&b).unwrap();
+        assert_eq!(a_prime.base_len, b.target_len);
+        assert_eq!(b_prime.base_len, a.target_len);
+        assert_eq!(a_prime.target_len, b_prime.target_len);
+    }
+}
+
+#[test]
+fn transform_with_different_base_fails() {
+    let mut rng = Rng::default();
+    let (s_a, s_b) = (rng.gen_string(10), rng.gen_string(30));
+    let a = rng.gen_delta(&s_a);
+    let b = rng.gen_delta(&s_b);
+    assert!(a.transform(&b).is_err());
+}
+
+#[test]
+fn invert_undoes_the_change() {
+    for _ in 0..ROUNDS {
+        let mut rng = Rng::default();
+        let (s, doc) = gen_doc(&mut rng, 20);
+        let change = rng.gen_delta(&s);
+        let undo = change.invert(&doc);
+
+        let changed = doc.compose(&change).unwrap();
+        assert_eq!(undo.base_len, changed.target_len);
+        assert_eq!(changed.compose(&undo).unwrap().apply("").unwrap(), s);
+    }
+}
+
+#[test]
+fn invert_str_is_reversible() {
+    for _ in 0..ROUNDS {
+        let mut rng = Rng::default();
+        let s = rng.gen_string(30);
+        let delta = rng.gen_delta(&s);
+        let inverted = delta.invert_str(&s);
+        assert_eq!(inverted.invert_str(&delta.apply(&s).unwrap()).apply(&s).unwrap(), delta.apply(&s).unwrap());
+    }
+}
+
+#[test]
+fn delta_json_round_trip() {
+    for _ in 0..ROUNDS {
+        let mut rng = Rng::default();
+        let s = rng.gen_string(20);
+        let delta = rng.gen_format_delta(&s);
+        assert_eq!(Delta::from_json(&delta.to_json()).unwrap(), delta);
+    }
+}
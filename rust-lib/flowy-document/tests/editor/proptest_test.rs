@@ -0,0 +1,21 @@
+use flowy_test::delta::*;
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn compose_laws_prop((s, deltas) in arb_sequence(20, 3)) {
+        check_compose_associative(&deltas[0], &deltas[1], &deltas[2])?;
+        check_compose_applies(&s, &deltas[0], &deltas[1])?;
+    }
+
+    #[test]
+    fn transform_converges_prop((doc, a, b) in arb_concurrent(20)) {
+        check_transform_converges(&doc, &a, &b)?;
+        check_invert(&doc, &a)?;
+    }
+
+    #[test]
+    fn sites_converge_prop((s, local, remote) in arb_sites(20, 1..4)) {
+        check_sites_converge(&s, &local, &remote)?;
+    }
+}
@@ -92,6 +92,12 @@ fn delta_deserialize_null_test() {
     assert_eq!(delta1, delta2);
 }
 
+#[test]
+fn delta_deserialize_unknown_field_test() {
+    assert!(Delta::from_json(r#"[{"replace":7}]"#).is_err());
+    assert!(Delta::from_bytes(&[0xff, 0xfe]).is_err());
+}
+
 #[test]
 fn delta_serde_null_test() {
     let mut attribute = Attribute::Bold(true);
@@ -0,0 +1,128 @@
+use crate::editor::Rng;
+use flowy_document::services::doc::{Document, DocumentStats, PlainDoc, StatsCount};
+use flowy_ot::core::*;
+use rand::Rng as WrappedRng;
+
+const ROUNDS: usize = 200;
+
+fn assert_stats_of_delta(document: &Document) {
+    assert_eq!(
+        document.stats().count(),
+        DocumentStats::from_delta(document.delta()).count(),
+        "{}",
+        document.to_json()
+    );
+}
+
+fn gen_text(rng: &mut Rng, len: usize) -> String {
+    (0..len)
+        .map(|_| match rng.0.gen_range(0, 6) {
+            0 => '\n',
+            1 => ' ',
+            2 => '😀',
+            _ => 'a',
+        })
+        .collect()
+}
+
+fn gen_block_attributes(rng: &mut Rng) -> Attributes {
+    match rng.0.gen_range(0, 5) {
+        0 => Attribute::Header(1).into(),
+        1 => Attribute::Bullet(true).into(),
+        2 => Attribute::CodeBlock(true).into(),
+        3 => Attribute::Bold(true).into(),
+        _ => Attributes::default(),
+    }
+}
+
+// The change inserts newlines and formats the lines, so the blocks are split,
+// merged and changed.
+fn gen_change(rng: &mut Rng, s: &str) -> Delta {
+    let mut delta = Delta::default();
+    for c in s.chars() {
+        let len = c.len_utf16();
+        if rng.0.gen_range(0, 10) == 0 {
+            delta.insert(&gen_text(rng, 3), gen_block_attributes(rng));
+        }
+        match rng.0.gen_range(0, 10) {
+            0 | 1 => delta.delete(len),
+            2 => delta.retain(len, gen_block_attributes(rng)),
+            _ => delta.retain(len, Attributes::default()),
+        }
+    }
+    if rng.0.gen_range(0, 3) == 0 {
+        delta.insert(&gen_text(rng, 5), Attributes::default());
+    }
+    delta
+}
+
+#[test]
+fn stats_of_document() {
+    let delta = DeltaBuilder::new()
+        .insert("Title")
+        .insert_with_attributes("\n", Attribute::Header(1).into())
+        .insert("Hello world 😀\n\n")
+        .insert("one")
+        .insert_with_attributes("\n", Attribute::Bullet(true).into())
+        .insert("two")
+        .insert_with_attributes("\n", Attribute::Bullet(true).into())
+        .insert("quote")
+        .insert_with_attributes("\n", Attribute::BlockQuote(true).into())
+        .insert("let a = 1;")
+        .insert_with_attributes("\n", Attribute::CodeBlock(true).into())
+        .build();
+    let expected = StatsCount {
+        words: 11,
+        chars: 39,
+        paragraphs: 1,
+        headers: 1,
+        lists: 2,
+        quotes: 1,
+        codes: 1,
+    };
+    assert_eq!(DocumentStats::from_delta(&delta).count(), &expected);
+}
+
+#[test]
+fn stats_reading_time() {
+    let mut count = StatsCount::default();
+    assert_eq!(count.reading_time(), 0);
+    count.words = 1;
+    assert_eq!(count.reading_time(), 1);
+    count.words = 400;
+    assert_eq!(count.reading_time(), 120);
+}
+
+#[test]
+fn stats_after_edits() {
+    let mut document = Document::new::<PlainDoc>();
+    document.insert(0, "Title\nHello world\n").unwrap();
+    assert_eq!(document.stats().count().words, 3);
+    assert_eq!(document.stats().count().paragraphs, 2);
+
+    document.format(Interval::new(0, 5), Attribute::Header(1)).unwrap();
+    assert_eq!(document.stats().count().headers, 1);
+    assert_eq!(document.stats().count().paragraphs, 1);
+
+    document.delete(Interval::new(5, 6)).unwrap();
+    assert_eq!(document.stats().count().words, 2);
+    assert_stats_of_delta(&document);
+
+    document.undo().unwrap();
+    assert_eq!(document.stats().count().words, 3);
+    assert_stats_of_delta(&document);
+}
+
+#[test]
+fn stats_incremental_is_same_as_counting_again() {
+    for _ in 0..ROUNDS {
+        let mut rng = Rng::default();
+        let text = gen_text(&mut rng, 20);
+        let mut document = Document::from_delta(DeltaBuilder::new().insert(&text).build());
+        for _ in 0..5 {
+            let change = gen_change(&mut rng, &document.to_plain_string());
+            document.compose_delta(change).unwrap();
+            assert_stats_of_delta(&document);
+        }
+    }
+}
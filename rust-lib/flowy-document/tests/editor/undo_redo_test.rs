@@ -1,6 +1,7 @@
 use crate::editor::{TestBuilder, TestOp::*};
-use flowy_document::services::doc::{FlowyDoc, PlainDoc, RECORD_THRESHOLD};
+use flowy_document::services::doc::{Document, FlowyDoc, PlainDoc, RECORD_THRESHOLD};
 use flowy_ot::core::{Interval, NEW_LINE, WHITESPACE};
+use std::time::Duration;
 
 #[test]
 fn history_insert_undo() {
@@ -370,3 +371,33 @@ fn history_undo_attribute_on_merge_between_line() {
 
     TestBuilder::new().run_script::<FlowyDoc>(ops);
 }
+
+#[test]
+fn history_undo_with_capacity() {
+    let mut document = Document::new::<FlowyDoc>().undo_capacity(2);
+    for s in ["1", "2", "3"] {
+        let len = document.delta().target_len;
+        document.insert(len - 1, s).unwrap();
+        std::thread::sleep(Duration::from_millis(RECORD_THRESHOLD as u64));
+    }
+
+    document.undo().unwrap();
+    document.undo().unwrap();
+    assert!(document.undo().is_err());
+    assert_eq!(document.to_plain_string(), "1\n");
+}
+
+#[test]
+fn history_insert_undo_after_undo_is_not_grouped() {
+    let mut document = Document::new::<FlowyDoc>();
+    document.insert(0, "123").unwrap();
+    std::thread::sleep(Duration::from_millis(RECORD_THRESHOLD as u64));
+    document.insert(3, "456").unwrap();
+    document.undo().unwrap();
+    document.insert(3, "789").unwrap();
+
+    document.undo().unwrap();
+    assert_eq!(document.to_plain_string(), "123\n");
+    document.undo().unwrap();
+    assert_eq!(document.to_plain_string(), "\n");
+}
@@ -0,0 +1,30 @@
+use bytes::Bytes;
+use flowy_document::entities::ws::{decode_ws_document_data, WsDataType, WsDocumentData};
+use flowy_ws::WsMessage;
+use std::convert::TryInto;
+
+fn document_data() -> WsDocumentData {
+    WsDocumentData {
+        doc_id: "doc".to_owned(),
+        ty: WsDataType::PushRev,
+        data: vec![1, 2, 3],
+    }
+}
+
+#[test]
+fn ws_document_data_envelope() {
+    let msg: WsMessage = document_data().into();
+    let data: WsDocumentData = Bytes::from(decode_ws_document_data(&msg.data).unwrap())
+        .try_into()
+        .unwrap();
+    assert_eq!(data.doc_id, "doc");
+    assert_eq!(data.ty, WsDataType::PushRev);
+    assert_eq!(data.data, vec![1, 2, 3]);
+}
+
+#[test]
+fn ws_document_data_without_envelope() {
+    // The data of the peers that send it without the envelope is version 0
+    let bytes: Bytes = document_data().try_into().unwrap();
+    assert_eq!(decode_ws_document_data(&bytes).unwrap(), bytes.to_vec());
+}
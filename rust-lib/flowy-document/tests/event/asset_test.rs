@@ -0,0 +1,315 @@
+use flowy_database::{prelude::*, schema::asset_transfer_table::dsl};
+use flowy_document::{
+    entities::asset::{
+        AssetData,
+        AssetIdentifier,
+        AssetTransfer,
+        AssetTransferDirection,
+        CreateAssetRequest,
+        DocAsset,
+        RepeatedAssetTransfer,
+        RepeatedDocAsset,
+    },
+    errors::ErrorCode,
+    event::DocumentEvent::*,
+    services::asset::{ASSET_CHUNK_SIZE, MAX_ASSET_SIZE},
+};
+use flowy_document_infra::entities::doc::{DocDelta, DocIdentifier};
+use flowy_ot::core::{Attribute, Delta, DeltaBuilder};
+use flowy_test::{builder::DocTest, workspace::ViewTest, FlowyTest};
+use serial_test::*;
+
+fn create_request(doc_id: &str, name: &str, data: Vec<u8>) -> CreateAssetRequest {
+    CreateAssetRequest {
+        doc_id: doc_id.to_owned(),
+        name: name.to_owned(),
+        mime_type: "image/png".to_owned(),
+        data,
+    }
+}
+
+async fn attach_asset(test: &FlowyTest, doc_id: &str, name: &str, data: Vec<u8>) -> DocAsset {
+    DocTest::new(test.sdk())
+        .event(AttachAsset)
+        .request(create_request(doc_id, name, data))
+        .async_send()
+        .await
+        .parse::<DocAsset>()
+}
+
+async fn read_asset(test: &FlowyTest, asset_id: &str) -> DocTest {
+    DocTest::new(test.sdk())
+        .event(ReadAsset)
+        .request(AssetIdentifier {
+            asset_id: asset_id.to_owned(),
+        })
+        .async_send()
+        .await
+}
+
+async fn list_assets(test: &FlowyTest, doc_id: &str) -> Vec<DocAsset> {
+    DocTest::new(test.sdk())
+        .event(ListAssets)
+        .request(DocIdentifier {
+            doc_id: doc_id.to_owned(),
+        })
+        .async_send()
+        .await
+        .parse::<RepeatedDocAsset>()
+        .items
+}
+
+async fn insert_asset_link(test: &FlowyTest, doc_id: &str, asset: &DocAsset) {
+    let doc = DocTest::new(test.sdk())
+        .event(OpenDocument)
+        .request(DocIdentifier {
+            doc_id: doc_id.to_owned(),
+        })
+        .async_send()
+        .await
+        .parse::<DocDelta>();
+    let len = Delta::from_json(&doc.data).unwrap().target_len;
+    let change = DeltaBuilder::new()
+        .insert_with_attributes(&asset.name, Attribute::Link(&asset.url).into())
+        .retain(len)
+        .build();
+    let _ = DocTest::new(test.sdk())
+        .event(ApplyChange)
+        .request(DocDelta {
+            doc_id: doc_id.to_owned(),
+            data: change.to_json(),
+        })
+        .async_send()
+        .await;
+}
+
+#[tokio::test]
+#[serial]
+async fn document_attach_and_read_asset() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+
+    let asset = attach_asset(&test, &doc_id, "a.png", vec![1, 2, 3]).await;
+    assert_eq!(asset.size, 3);
+    assert!(asset.url.ends_with(&asset.id));
+
+    let data = read_asset(&test, &asset.id).await.parse::<AssetData>();
+    assert_eq!(data.data, vec![1, 2, 3]);
+    assert_eq!(data.asset, asset);
+
+    // The url resolves to the same asset.
+    let data = read_asset(&test, &asset.url).await.parse::<AssetData>();
+    assert_eq!(data.asset.id, asset.id);
+    assert_eq!(list_assets(&test, &doc_id).await, vec![asset]);
+}
+
+#[tokio::test]
+#[serial]
+async fn document_attach_too_large_asset() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+
+    let error = DocTest::new(test.sdk())
+        .event(AttachAsset)
+        .request(create_request(&doc_id, "a.png", vec![0; MAX_ASSET_SIZE + 1]))
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::AssetTooLarge);
+    assert!(list_assets(&test, &doc_id).await.is_empty());
+}
+
+#[tokio::test]
+#[serial]
+async fn document_read_asset_not_exist() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let error = read_asset(&test, "not exist").await.error();
+    assert_eq!(error.code, ErrorCode::AssetNotFound);
+}
+
+#[tokio::test]
+#[serial]
+async fn document_close_removes_unreferenced_assets() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+
+    let referenced = attach_asset(&test, &doc_id, "a.png", vec![1]).await;
+    let unreferenced = attach_asset(&test, &doc_id, "b.png", vec![2]).await;
+    insert_asset_link(&test, &doc_id, &referenced).await;
+
+    // The assets are kept while the document is opened.
+    assert_eq!(list_assets(&test, &doc_id).await.len(), 2);
+
+    let _ = DocTest::new(test.sdk())
+        .event(CloseDocument)
+        .request(DocIdentifier { doc_id: doc_id.clone() })
+        .async_send()
+        .await;
+
+    assert_eq!(list_assets(&test, &doc_id).await, vec![referenced]);
+    let error = read_asset(&test, &unreferenced.id).await.error();
+    assert_eq!(error.code, ErrorCode::AssetNotFound);
+    assert!(!std::path::Path::new(&unreferenced.path).exists());
+}
+
+async fn transfer_asset(
+    test: &FlowyTest,
+    event: flowy_document::event::DocumentEvent,
+    asset_id: &str,
+) -> AssetTransfer {
+    DocTest::new(test.sdk())
+        .event(event)
+        .request(AssetIdentifier {
+            asset_id: asset_id.to_owned(),
+        })
+        .async_send()
+        .await
+        .parse::<AssetTransfer>()
+}
+
+async fn asset_transfers(test: &FlowyTest) -> Vec<AssetTransfer> {
+    DocTest::new(test.sdk())
+        .event(ReadAssetTransfers)
+        .async_send()
+        .await
+        .parse::<RepeatedAssetTransfer>()
+        .items
+}
+
+// The transfer as it's saved when the network was lost after some chunks.
+fn interrupt_transfer(test: &FlowyTest, asset: &DocAsset, direction: AssetTransferDirection, transferred: i64) {
+    let pool = test.sdk.user_session.db_pool().unwrap();
+    let conn = &*pool.get().unwrap();
+    let _ = diesel::insert_into(dsl::asset_transfer_table)
+        .values((
+            dsl::asset_id.eq(&asset.id),
+            dsl::direction.eq(direction as i32),
+            dsl::transferred.eq(transferred),
+            dsl::total.eq(asset.size),
+            dsl::update_time.eq(0),
+        ))
+        .execute(conn)
+        .unwrap();
+}
+
+fn large_data() -> Vec<u8> { (0..ASSET_CHUNK_SIZE * 5 / 2).map(|i| (i % 251) as u8).collect() }
+
+#[tokio::test]
+#[serial]
+async fn document_upload_and_download_asset() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+    let asset = attach_asset(&test, &doc_id, "a.png", large_data()).await;
+
+    let upload = transfer_asset(&test, UploadAsset, &asset.id).await;
+    assert_eq!(upload.direction, AssetTransferDirection::Upload);
+    assert_eq!(upload.transferred, asset.size);
+    assert_eq!(upload.resumed_from, 0);
+    assert!(upload.is_done());
+
+    let download = transfer_asset(&test, DownloadAsset, &asset.url).await;
+    assert_eq!(download.direction, AssetTransferDirection::Download);
+    assert!(download.is_done());
+    assert_eq!(
+        read_asset(&test, &asset.id).await.parse::<AssetData>().data,
+        large_data()
+    );
+    assert!(asset_transfers(&test).await.is_empty());
+}
+
+#[tokio::test]
+#[serial]
+async fn document_resume_interrupted_upload() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+    let asset = attach_asset(&test, &doc_id, "a.png", large_data()).await;
+    let _ = transfer_asset(&test, UploadAsset, &asset.id).await;
+
+    interrupt_transfer(&test, &asset, AssetTransferDirection::Upload, ASSET_CHUNK_SIZE as i64);
+    let transfers = asset_transfers(&test).await;
+    assert_eq!(transfers.len(), 1);
+    assert_eq!(transfers[0].transferred, ASSET_CHUNK_SIZE as i64);
+
+    // Only the chunks after the saved offset are sent.
+    let upload = transfer_asset(&test, UploadAsset, &asset.id).await;
+    assert_eq!(upload.resumed_from, ASSET_CHUNK_SIZE as i64);
+    assert!(upload.is_done());
+    assert!(asset_transfers(&test).await.is_empty());
+
+    let _ = transfer_asset(&test, DownloadAsset, &asset.id).await;
+    assert_eq!(
+        read_asset(&test, &asset.id).await.parse::<AssetData>().data,
+        large_data()
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn document_resume_interrupted_download() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+    let asset = attach_asset(&test, &doc_id, "a.png", large_data()).await;
+    let _ = transfer_asset(&test, UploadAsset, &asset.id).await;
+
+    // The first chunk was written, the saved offset is ahead of the partial file,
+    // the download goes on from the end of the file.
+    let dir = std::path::PathBuf::from(test.sdk.user_session.user_dir().unwrap()).join("transfers");
+    let _ = std::fs::create_dir_all(&dir).unwrap();
+    let _ = std::fs::write(dir.join(&asset.id), &large_data()[..ASSET_CHUNK_SIZE]).unwrap();
+    interrupt_transfer(
+        &test,
+        &asset,
+        AssetTransferDirection::Download,
+        ASSET_CHUNK_SIZE as i64 * 2,
+    );
+
+    let download = transfer_asset(&test, DownloadAsset, &asset.id).await;
+    assert_eq!(download.resumed_from, ASSET_CHUNK_SIZE as i64);
+    assert!(download.is_done());
+    assert!(!dir.join(&asset.id).exists());
+    assert_eq!(
+        read_asset(&test, &asset.id).await.parse::<AssetData>().data,
+        large_data()
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn document_download_asset_not_uploaded() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+    let asset = attach_asset(&test, &doc_id, "a.png", vec![1, 2, 3]).await;
+
+    let error = DocTest::new(test.sdk())
+        .event(DownloadAsset)
+        .request(AssetIdentifier {
+            asset_id: asset.id.clone(),
+        })
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::AssetNotFound);
+
+    // The download is kept to be resumed, it's dropped with the asset.
+    assert_eq!(asset_transfers(&test).await.len(), 1);
+    let _ = DocTest::new(test.sdk())
+        .event(OpenDocument)
+        .request(DocIdentifier { doc_id: doc_id.clone() })
+        .async_send()
+        .await;
+    let _ = DocTest::new(test.sdk())
+        .event(CloseDocument)
+        .request(DocIdentifier { doc_id: doc_id.clone() })
+        .async_send()
+        .await;
+    assert!(asset_transfers(&test).await.is_empty());
+}
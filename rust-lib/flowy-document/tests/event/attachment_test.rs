@@ -0,0 +1,187 @@
+use flowy_document::{
+    entities::attachment::{
+        AttachmentIdentifier,
+        AttachmentUsage,
+        DocAttachment,
+        ImportAttachmentRequest,
+        RepeatedDocAttachment,
+    },
+    errors::ErrorCode,
+    event::DocumentEvent::*,
+    services::attachment::THUMBNAIL_SIZE,
+};
+use flowy_document_infra::entities::doc::DocIdentifier;
+use flowy_infra::uuid;
+use flowy_test::{builder::DocTest, prelude::root_dir, workspace::ViewTest, FlowyTest};
+use image::GenericImageView;
+use serial_test::*;
+use std::path::Path;
+
+// Writes the file to import in a directory of its own.
+fn write_file(name: &str, data: &[u8]) -> String {
+    let dir = Path::new(&root_dir()).join(uuid());
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    std::fs::write(&path, data).unwrap();
+    path.to_string_lossy().to_string()
+}
+
+fn write_image(width: u32, height: u32) -> String {
+    let path = write_file("image.png", &[]);
+    image::RgbImage::from_pixel(width, height, image::Rgb([200, 80, 20]))
+        .save(&path)
+        .unwrap();
+    path
+}
+
+async fn import_attachment(test: &FlowyTest, doc_id: &str, path: &str) -> DocTest {
+    DocTest::new(test.sdk())
+        .event(ImportAttachment)
+        .request(ImportAttachmentRequest {
+            doc_id: doc_id.to_owned(),
+            path: path.to_owned(),
+            ..Default::default()
+        })
+        .async_send()
+        .await
+}
+
+async fn list_attachments(test: &FlowyTest, doc_id: &str) -> Vec<DocAttachment> {
+    DocTest::new(test.sdk())
+        .event(ListAttachments)
+        .request(DocIdentifier {
+            doc_id: doc_id.to_owned(),
+        })
+        .async_send()
+        .await
+        .parse::<RepeatedDocAttachment>()
+        .items
+}
+
+async fn attachment_usage(test: &FlowyTest) -> AttachmentUsage {
+    DocTest::new(test.sdk())
+        .event(ReadAttachmentUsage)
+        .async_send()
+        .await
+        .parse::<AttachmentUsage>()
+}
+
+#[tokio::test]
+#[serial]
+async fn attachment_import_the_same_content_once() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+
+    let first = import_attachment(&test, &doc_id, &write_file("a.txt", b"hello world"))
+        .await
+        .parse::<DocAttachment>();
+    assert_eq!(first.name, "a.txt");
+    assert_eq!(first.size, 11);
+    assert_eq!(first.mime_type, "application/octet-stream");
+    assert!(first.thumbnail_path.is_empty());
+    assert_eq!(std::fs::read(&first.path).unwrap(), b"hello world".to_vec());
+
+    let second = import_attachment(&test, &doc_id, &write_file("b.txt", b"hello world"))
+        .await
+        .parse::<DocAttachment>();
+    assert_ne!(first.id, second.id);
+    assert_eq!(first.path, second.path);
+    assert_eq!(
+        list_attachments(&test, &doc_id).await,
+        vec![first.clone(), second.clone()]
+    );
+
+    // The shared content is counted once.
+    let usage = attachment_usage(&test).await;
+    assert_eq!(usage.used, 11);
+    assert_eq!(usage.count, 2);
+
+    // The content is kept for the other attachment.
+    let _ = DocTest::new(test.sdk())
+        .event(DeleteAttachment)
+        .request(AttachmentIdentifier {
+            attachment_id: first.id.clone(),
+        })
+        .async_send()
+        .await;
+    assert_eq!(list_attachments(&test, &doc_id).await, vec![second.clone()]);
+    assert!(Path::new(&second.path).exists());
+}
+
+#[tokio::test]
+#[serial]
+async fn attachment_import_image_with_thumbnail() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+
+    let attachment = import_attachment(&test, &doc_id, &write_image(1024, 512))
+        .await
+        .parse::<DocAttachment>();
+    assert_eq!(attachment.mime_type, "image/png");
+    assert_eq!((attachment.width, attachment.height), (1024, 512));
+
+    let thumbnail = image::open(&attachment.thumbnail_path).unwrap();
+    assert_eq!(thumbnail.width(), THUMBNAIL_SIZE);
+    assert_eq!(thumbnail.height(), THUMBNAIL_SIZE / 2);
+
+    // The thumbnail is counted with the content.
+    let thumbnail_size = std::fs::metadata(&attachment.thumbnail_path).unwrap().len() as i64;
+    assert_eq!(attachment_usage(&test).await.used, attachment.size + thumbnail_size);
+}
+
+#[tokio::test]
+#[serial]
+async fn attachment_import_over_quota() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+    test.sdk.flowy_document.set_attachment_quota(16);
+
+    let attachment = import_attachment(&test, &doc_id, &write_file("a.txt", &[1; 10]))
+        .await
+        .parse::<DocAttachment>();
+    let error = import_attachment(&test, &doc_id, &write_file("b.txt", &[2; 10]))
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::AttachmentOverQuota);
+
+    // The content that is already attached fits.
+    let _ = import_attachment(&test, &doc_id, &write_file("c.txt", &[1; 10]))
+        .await
+        .parse::<DocAttachment>();
+    let usage = attachment_usage(&test).await;
+    assert_eq!((usage.used, usage.quota, usage.count), (10, 16, 2));
+    assert_eq!(list_attachments(&test, &doc_id).await[0], attachment);
+
+    let error = import_attachment(&test, &doc_id, "not exist").await.error();
+    assert_eq!(error.code, ErrorCode::AttachmentFileError);
+}
+
+#[tokio::test]
+#[serial]
+async fn attachment_removed_with_the_document() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+    let attachment = import_attachment(&test, &doc_id, &write_image(32, 32))
+        .await
+        .parse::<DocAttachment>();
+
+    test.sdk.flowy_document.delete((&doc_id).into()).unwrap();
+    assert!(list_attachments(&test, &doc_id).await.is_empty());
+    assert!(!Path::new(&attachment.path).exists());
+    assert!(!Path::new(&attachment.thumbnail_path).exists());
+    assert_eq!(attachment_usage(&test).await.used, 0);
+
+    let error = DocTest::new(test.sdk())
+        .event(DeleteAttachment)
+        .request(AttachmentIdentifier {
+            attachment_id: attachment.id,
+        })
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::AttachmentNotFound);
+}
@@ -0,0 +1,132 @@
+use flowy_document::{
+    entities::asset::{CreateAssetRequest, DocAsset},
+    errors::ErrorCode,
+    event::DocumentEvent::*,
+};
+use flowy_test::{builder::DocTest, workspace::ViewTest, FlowyTest};
+use serial_test::*;
+use std::{io::Write, path::Path};
+
+async fn attach_asset(test: &FlowyTest, doc_id: &str, data: Vec<u8>) -> DocAsset {
+    DocTest::new(test.sdk())
+        .event(AttachAsset)
+        .request(CreateAssetRequest {
+            doc_id: doc_id.to_owned(),
+            name: "a.png".to_owned(),
+            mime_type: "image/png".to_owned(),
+            data,
+        })
+        .async_send()
+        .await
+        .parse::<DocAsset>()
+}
+
+#[tokio::test]
+#[serial]
+async fn blob_write_the_same_content_once() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let blobs = test.sdk.flowy_document.blobs();
+
+    let first = blobs.write(&b"hello world"[..], "owner 1").unwrap();
+    assert_eq!(
+        first.hash,
+        "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"
+    );
+    assert_eq!(first.size, 11);
+
+    // The content written in parts is hashed as a whole.
+    let mut writer = blobs.writer().unwrap();
+    writer.write_all(b"hello ").unwrap();
+    writer.write_all(b"world").unwrap();
+    let second = writer.finish("owner 2").unwrap();
+    assert_eq!(second, first);
+    assert_eq!(blobs.ref_count(&first.hash).unwrap(), 2);
+
+    // Referring to the blob again with the same owner counts once.
+    blobs.retain(&first.hash, "owner 2").unwrap();
+    assert_eq!(blobs.ref_count(&first.hash).unwrap(), 2);
+    assert_eq!(blobs.read(&first.hash).unwrap(), b"hello world".to_vec());
+
+    let files = std::fs::read_dir(Path::new(&first.path).parent().unwrap())
+        .unwrap()
+        .count();
+    assert_eq!(files, 1);
+}
+
+#[tokio::test]
+#[serial]
+async fn blob_removed_with_the_last_reference() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let blobs = test.sdk.flowy_document.blobs();
+
+    let blob = blobs.write(&[1, 2, 3][..], "owner 1").unwrap();
+    blobs.retain(&blob.hash, "owner 2").unwrap();
+    blobs.release(&blob.hash, "owner 1").unwrap();
+    assert!(Path::new(&blob.path).exists());
+
+    blobs.release_all("owner 2").unwrap();
+    assert!(!Path::new(&blob.path).exists());
+    assert_eq!(blobs.open(&blob.hash).unwrap_err().code, ErrorCode::BlobNotFound);
+    assert_eq!(
+        blobs.retain(&blob.hash, "owner 3").unwrap_err().code,
+        ErrorCode::BlobNotFound
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn blob_collect_garbage() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let blobs = test.sdk.flowy_document.blobs();
+
+    let blob = blobs.write(&[1, 2, 3][..], "owner").unwrap();
+    let dir = Path::new(&blob.path).parent().unwrap().to_owned();
+
+    // The content of the dropped writer isn't stored.
+    let mut writer = blobs.writer().unwrap();
+    writer.write_all(&[4, 5]).unwrap();
+    drop(writer);
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+    std::fs::write(dir.join("stray"), [6, 7, 8, 9]).unwrap();
+    assert_eq!(blobs.collect_garbage().unwrap(), (1, 4));
+    assert!(!dir.join("stray").exists());
+    assert_eq!(blobs.read(&blob.hash).unwrap(), vec![1, 2, 3]);
+}
+
+#[tokio::test]
+#[serial]
+async fn blob_shared_by_the_assets_with_the_same_data() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let first_doc_id = ViewTest::new(&test).await.view.id;
+    let second_doc_id = ViewTest::new(&test).await.view.id;
+
+    let first = attach_asset(&test, &first_doc_id, vec![1, 2, 3]).await;
+    let second = attach_asset(&test, &second_doc_id, vec![1, 2, 3]).await;
+    assert_ne!(first.id, second.id);
+    assert_eq!(first.path, second.path);
+
+    // The blob is kept for the asset of the other document.
+    test.sdk.flowy_document.purge((&first_doc_id).into()).unwrap();
+    assert!(Path::new(&second.path).exists());
+
+    test.sdk.flowy_document.purge((&second_doc_id).into()).unwrap();
+    assert!(!Path::new(&second.path).exists());
+}
+
+#[tokio::test]
+#[serial]
+async fn blob_released_when_the_document_is_purged() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+    let document = test.sdk.flowy_document.clone();
+
+    let blob = document.blobs().write(&[1, 2, 3][..], &doc_id).unwrap();
+    document.purge((&doc_id).into()).unwrap();
+    assert!(!Path::new(&blob.path).exists());
+}
@@ -0,0 +1,84 @@
+use flowy_document::{
+    entities::check::{CheckTextRequest, DiagnosticKind, RepeatedTextDiagnostic},
+    errors::ErrorCode,
+    event::DocumentEvent::*,
+};
+use flowy_document_infra::entities::doc::{DocDelta, DocIdentifier};
+use flowy_ot::core::{Delta, DeltaBuilder};
+use flowy_test::{builder::DocTest, workspace::ViewTest, FlowyTest};
+use serial_test::*;
+
+async fn insert_text(test: &FlowyTest, doc_id: &str, s: &str) {
+    let doc = DocTest::new(test.sdk())
+        .event(OpenDocument)
+        .request(DocIdentifier {
+            doc_id: doc_id.to_owned(),
+        })
+        .async_send()
+        .await
+        .parse::<DocDelta>();
+    let len = Delta::from_json(&doc.data).unwrap().target_len;
+    let _ = DocTest::new(test.sdk())
+        .event(ApplyChange)
+        .request(DocDelta {
+            doc_id: doc_id.to_owned(),
+            data: DeltaBuilder::new().insert(s).retain(len).build().to_json(),
+        })
+        .async_send()
+        .await;
+}
+
+fn check_request(doc_id: &str, start: i64, end: i64) -> CheckTextRequest {
+    CheckTextRequest {
+        doc_id: doc_id.to_owned(),
+        start,
+        end,
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn document_check_text() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+    insert_text(&test, &doc_id, "It is is fine\nThe the end").await;
+
+    let diagnostics = DocTest::new(test.sdk())
+        .event(CheckText)
+        .request(check_request(&doc_id, 0, 0))
+        .async_send()
+        .await
+        .parse::<RepeatedTextDiagnostic>();
+    assert_eq!(diagnostics.doc_id, doc_id);
+    assert_eq!(diagnostics.items.len(), 2);
+    assert_eq!((diagnostics.items[0].start, diagnostics.items[0].end), (3, 8));
+    assert_eq!(diagnostics.items[0].kind, DiagnosticKind::Grammar);
+    assert_eq!(diagnostics.items[0].suggestions, vec!["is".to_owned()]);
+    assert_eq!((diagnostics.items[1].start, diagnostics.items[1].end), (14, 21));
+
+    let diagnostics = DocTest::new(test.sdk())
+        .event(CheckText)
+        .request(check_request(&doc_id, 14, 25))
+        .async_send()
+        .await
+        .parse::<RepeatedTextDiagnostic>();
+    assert_eq!(diagnostics.items.len(), 1);
+    assert_eq!(diagnostics.items[0].start, 14);
+}
+
+#[tokio::test]
+#[serial]
+async fn document_check_text_out_of_bound() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+
+    let error = DocTest::new(test.sdk())
+        .event(CheckText)
+        .request(check_request(&doc_id, 0, 100))
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::OutOfBound);
+}
@@ -0,0 +1,164 @@
+use flowy_document::{
+    entities::comment::{
+        CommentIdentifier,
+        CreateCommentRequest,
+        DocComment,
+        RepeatedDocComment,
+        ResolveCommentRequest,
+    },
+    errors::ErrorCode,
+    event::DocumentEvent::*,
+};
+use flowy_document_infra::entities::doc::{DocDelta, DocIdentifier};
+use flowy_ot::core::{Delta, DeltaBuilder};
+use flowy_test::{builder::DocTest, workspace::ViewTest, FlowyTest};
+use serial_test::*;
+
+fn create_request(doc_id: &str, start: i64, end: i64, content: &str) -> CreateCommentRequest {
+    CreateCommentRequest {
+        doc_id: doc_id.to_owned(),
+        start,
+        end,
+        content: content.to_owned(),
+    }
+}
+
+async fn create_comment(test: &FlowyTest, request: CreateCommentRequest) -> DocTest {
+    DocTest::new(test.sdk())
+        .event(CreateComment)
+        .request(request)
+        .async_send()
+        .await
+}
+
+async fn read_comments(test: &FlowyTest, doc_id: &str) -> Vec<DocComment> {
+    DocTest::new(test.sdk())
+        .event(ReadComments)
+        .request(DocIdentifier {
+            doc_id: doc_id.to_owned(),
+        })
+        .async_send()
+        .await
+        .parse::<RepeatedDocComment>()
+        .items
+}
+
+async fn insert_text(test: &FlowyTest, doc_id: &str, index: usize, s: &str) {
+    let doc = DocTest::new(test.sdk())
+        .event(OpenDocument)
+        .request(DocIdentifier {
+            doc_id: doc_id.to_owned(),
+        })
+        .async_send()
+        .await
+        .parse::<DocDelta>();
+    let len = Delta::from_json(&doc.data).unwrap().target_len;
+    let change = DeltaBuilder::new().retain(index).insert(s).retain(len - index).build();
+    let _ = DocTest::new(test.sdk())
+        .event(ApplyChange)
+        .request(DocDelta {
+            doc_id: doc_id.to_owned(),
+            data: change.to_json(),
+        })
+        .async_send()
+        .await;
+}
+
+#[tokio::test]
+#[serial]
+async fn document_create_and_read_comment() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+    insert_text(&test, &doc_id, 0, "123456").await;
+
+    let comment = create_comment(&test, create_request(&doc_id, 2, 4, "comment"))
+        .await
+        .parse::<DocComment>();
+    assert_eq!((comment.start, comment.end), (2, 4));
+    assert_eq!(comment.content, "comment");
+    assert!(!comment.resolved);
+    assert_eq!(read_comments(&test, &doc_id).await, vec![comment]);
+}
+
+#[tokio::test]
+#[serial]
+async fn document_comment_follows_edits() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+    insert_text(&test, &doc_id, 0, "123456").await;
+
+    let _ = create_comment(&test, create_request(&doc_id, 2, 4, "comment")).await;
+    insert_text(&test, &doc_id, 0, "ab").await;
+    insert_text(&test, &doc_id, 5, "cd").await;
+
+    let comments = read_comments(&test, &doc_id).await;
+    assert_eq!((comments[0].start, comments[0].end), (4, 8));
+}
+
+#[tokio::test]
+#[serial]
+async fn document_resolve_and_delete_comment() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+    insert_text(&test, &doc_id, 0, "123456").await;
+
+    let comment = create_comment(&test, create_request(&doc_id, 0, 6, "comment"))
+        .await
+        .parse::<DocComment>();
+    let resolved = DocTest::new(test.sdk())
+        .event(ResolveComment)
+        .request(ResolveCommentRequest {
+            comment_id: comment.id.clone(),
+            resolved: true,
+        })
+        .async_send()
+        .await
+        .parse::<DocComment>();
+    assert!(resolved.resolved);
+    assert!(read_comments(&test, &doc_id).await[0].resolved);
+
+    let _ = DocTest::new(test.sdk())
+        .event(DeleteComment)
+        .request(CommentIdentifier { comment_id: comment.id })
+        .async_send()
+        .await;
+    assert!(read_comments(&test, &doc_id).await.is_empty());
+}
+
+#[tokio::test]
+#[serial]
+async fn document_create_invalid_comment() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+    insert_text(&test, &doc_id, 0, "123456").await;
+
+    let error = create_comment(&test, create_request(&doc_id, 2, 4, " ")).await.error();
+    assert_eq!(error.code, ErrorCode::CommentContentEmpty);
+
+    let error = create_comment(&test, create_request(&doc_id, 2, 100, "comment"))
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::OutOfBound);
+    assert!(read_comments(&test, &doc_id).await.is_empty());
+}
+
+#[tokio::test]
+#[serial]
+async fn document_delete_comment_not_exist() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let error = DocTest::new(test.sdk())
+        .event(DeleteComment)
+        .request(CommentIdentifier {
+            comment_id: "not exist".to_owned(),
+        })
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::CommentNotFound);
+}
@@ -0,0 +1,551 @@
+use flowy_document::{
+    entities::{
+        block::{
+            ApplyBlockOpsRequest,
+            BlockOp,
+            BlockOpType,
+            BlockType,
+            DocumentPage,
+            ReadDocumentRequest,
+            RepeatedDocBlock,
+        },
+        diff::{BlockDiffType, DiffDocumentsRequest, DiffVersionsRequest, DocDiff},
+        presence::{RepeatedDocPresence, UpdatePresenceRequest},
+        stats::DocStats,
+        version::{DocVersionIdentifier, RepeatedDocVersion},
+    },
+    errors::ErrorCode,
+    event::DocumentEvent::*,
+};
+use flowy_document_infra::entities::doc::{DocDelta, DocIdentifier};
+use flowy_infra::{secret::SecretStoreKind, uuid};
+use flowy_net::config::ServerConfig;
+use flowy_ot::core::{Attribute, Delta, DeltaBuilder, OperationTransformable};
+use flowy_test::{builder::DocTest, prelude::*, workspace::ViewTest, FlowyTest};
+use serial_test::*;
+
+async fn send(test: &FlowyTest, event: flowy_document::event::DocumentEvent, doc_id: &str) -> DocDelta {
+    DocTest::new(test.sdk())
+        .event(event)
+        .request(DocIdentifier {
+            doc_id: doc_id.to_owned(),
+        })
+        .async_send()
+        .await
+        .parse::<DocDelta>()
+}
+
+async fn apply_change(test: &FlowyTest, doc_id: &str, delta: Delta) -> DocDelta {
+    DocTest::new(test.sdk())
+        .event(ApplyChange)
+        .request(DocDelta {
+            doc_id: doc_id.to_owned(),
+            data: delta.to_json(),
+        })
+        .async_send()
+        .await
+        .parse::<DocDelta>()
+}
+
+#[tokio::test]
+#[serial]
+async fn document_open_apply_change() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+
+    let doc = send(&test, OpenDocument, &doc_id).await;
+    let mut delta = Delta::from_json(&doc.data).unwrap();
+    let change = DeltaBuilder::new().insert("123").retain(delta.target_len).build();
+    delta = delta.compose(&change).unwrap();
+
+    let doc = apply_change(&test, &doc_id, change).await;
+    assert_eq!(doc.data, delta.to_json());
+}
+
+#[tokio::test]
+#[serial]
+async fn document_read_after_close() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+
+    let doc = send(&test, OpenDocument, &doc_id).await;
+    let delta = Delta::from_json(&doc.data).unwrap();
+    let doc = apply_change(
+        &test,
+        &doc_id,
+        DeltaBuilder::new().insert("123").retain(delta.target_len).build(),
+    )
+    .await;
+
+    let _ = DocTest::new(test.sdk())
+        .event(CloseDocument)
+        .request(DocIdentifier { doc_id: doc_id.clone() })
+        .async_send()
+        .await;
+
+    assert_eq!(send(&test, ReadDocument, &doc_id).await.data, doc.data);
+}
+
+#[tokio::test]
+#[serial]
+async fn document_undo_redo() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+
+    let origin = send(&test, OpenDocument, &doc_id).await;
+    let delta = Delta::from_json(&origin.data).unwrap();
+    let changed = apply_change(
+        &test,
+        &doc_id,
+        DeltaBuilder::new().insert("123").retain(delta.target_len).build(),
+    )
+    .await;
+
+    assert_eq!(send(&test, Undo, &doc_id).await.data, origin.data);
+    assert_eq!(send(&test, Redo, &doc_id).await.data, changed.data);
+
+    // The undo is saved like any other change.
+    assert_eq!(send(&test, Undo, &doc_id).await.data, origin.data);
+    let _ = DocTest::new(test.sdk())
+        .event(CloseDocument)
+        .request(DocIdentifier { doc_id: doc_id.clone() })
+        .async_send()
+        .await;
+    assert_eq!(send(&test, ReadDocument, &doc_id).await.data, origin.data);
+}
+
+#[tokio::test]
+#[serial]
+async fn document_undo_groups_rapid_changes() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+
+    let origin = send(&test, OpenDocument, &doc_id).await;
+    let mut len = Delta::from_json(&origin.data).unwrap().target_len;
+    for s in ["1", "2", "3"] {
+        let doc = apply_change(&test, &doc_id, DeltaBuilder::new().insert(s).retain(len).build()).await;
+        len = Delta::from_json(&doc.data).unwrap().target_len;
+    }
+
+    assert_eq!(send(&test, Undo, &doc_id).await.data, origin.data);
+}
+
+#[tokio::test]
+#[serial]
+async fn document_undo_with_empty_history() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+
+    let _ = send(&test, OpenDocument, &doc_id).await;
+    let error = DocTest::new(test.sdk())
+        .event(Undo)
+        .request(DocIdentifier { doc_id })
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::UndoFail);
+}
+
+#[tokio::test]
+#[serial]
+async fn document_force_save() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+
+    let doc = send(&test, OpenDocument, &doc_id).await;
+    let delta = Delta::from_json(&doc.data).unwrap();
+    let doc = apply_change(
+        &test,
+        &doc_id,
+        DeltaBuilder::new().insert("123").retain(delta.target_len).build(),
+    )
+    .await;
+
+    let _ = DocTest::new(test.sdk())
+        .event(ForceSave)
+        .request(DocIdentifier { doc_id: doc_id.clone() })
+        .async_send()
+        .await
+        .assert_success();
+    assert_eq!(send(&test, ReadDocument, &doc_id).await.data, doc.data);
+}
+
+#[tokio::test]
+#[serial]
+async fn document_force_save_without_open() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+
+    let _ = DocTest::new(test.sdk())
+        .event(ForceSave)
+        .request(DocIdentifier { doc_id })
+        .async_send()
+        .await
+        .assert_success();
+}
+
+fn setup_with_snapshot_interval(interval: i64) -> FlowyTest {
+    let config = FlowySDKConfig::new(&root_dir(), ServerConfig::default(), &uuid())
+        .secret_store(SecretStoreKind::File)
+        .snapshot_interval(interval);
+    FlowyTest::setup_with_config(config)
+}
+
+async fn list_versions(test: &FlowyTest, doc_id: &str) -> RepeatedDocVersion {
+    DocTest::new(test.sdk())
+        .event(ListVersions)
+        .request(DocIdentifier {
+            doc_id: doc_id.to_owned(),
+        })
+        .async_send()
+        .await
+        .parse::<RepeatedDocVersion>()
+}
+
+async fn send_version(
+    test: &FlowyTest,
+    event: flowy_document::event::DocumentEvent,
+    doc_id: &str,
+    rev_id: i64,
+) -> DocDelta {
+    DocTest::new(test.sdk())
+        .event(event)
+        .request(DocVersionIdentifier {
+            doc_id: doc_id.to_owned(),
+            rev_id,
+        })
+        .async_send()
+        .await
+        .parse::<DocDelta>()
+}
+
+// Applies the changes one by one and returns the document after each of them.
+async fn apply_inserts(test: &FlowyTest, doc_id: &str, count: usize) -> Vec<DocDelta> {
+    let doc = send(test, OpenDocument, doc_id).await;
+    let mut len = Delta::from_json(&doc.data).unwrap().target_len;
+    let mut docs = vec![];
+    for i in 0..count {
+        let s = i.to_string();
+        let doc = apply_change(test, doc_id, DeltaBuilder::new().retain(len).insert(&s).build()).await;
+        len += s.len();
+        docs.push(doc);
+    }
+    docs
+}
+
+#[tokio::test]
+#[serial]
+async fn document_list_versions() {
+    let test = setup_with_snapshot_interval(2);
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+
+    let docs = apply_inserts(&test, &doc_id, 6).await;
+    let versions = list_versions(&test, &doc_id).await.items;
+    assert!(versions.len() >= 2);
+
+    // The latest version comes first and every version is one of the documents.
+    assert!(versions[0].rev_id > versions[1].rev_id);
+    for version in versions {
+        let doc = send_version(&test, ReadVersion, &doc_id, version.rev_id).await;
+        assert!(docs.iter().any(|d| d.data == doc.data));
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn document_read_version_between_snapshots() {
+    let test = setup_with_snapshot_interval(3);
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+
+    let docs = apply_inserts(&test, &doc_id, 7).await;
+    let rev_id = list_versions(&test, &doc_id).await.items[1].rev_id;
+    let snapshot = send_version(&test, ReadVersion, &doc_id, rev_id).await;
+    let index = docs.iter().position(|d| d.data == snapshot.data).unwrap();
+
+    // The revision after the snapshot is composed with it.
+    let doc = send_version(&test, ReadVersion, &doc_id, rev_id + 1).await;
+    assert_eq!(doc.data, docs[index + 1].data);
+}
+
+#[tokio::test]
+#[serial]
+async fn document_read_version_not_exist() {
+    let test = setup_with_snapshot_interval(2);
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+
+    let _ = apply_inserts(&test, &doc_id, 2).await;
+    let error = DocTest::new(test.sdk())
+        .event(ReadVersion)
+        .request(DocVersionIdentifier { doc_id, rev_id: 10000 })
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::DocNotfound);
+}
+
+#[tokio::test]
+#[serial]
+async fn document_diff_versions() {
+    let test = setup_with_snapshot_interval(2);
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+
+    let _ = apply_inserts(&test, &doc_id, 4).await;
+    let rev_id = list_versions(&test, &doc_id).await.items[0].rev_id;
+    let diff = DocTest::new(test.sdk())
+        .event(DiffVersions)
+        .request(DiffVersionsRequest {
+            doc_id: doc_id.clone(),
+            old_rev_id: rev_id - 1,
+            new_rev_id: rev_id,
+        })
+        .async_send()
+        .await
+        .parse::<DocDiff>();
+    assert_eq!(diff.items.len(), 1);
+    assert_eq!(diff.items[0].ty, BlockDiffType::Changed);
+    assert!(diff.items[0].text_changed);
+
+    // It's the diff of the documents of the versions.
+    let documents_diff = DocTest::new(test.sdk())
+        .event(DiffDocuments)
+        .request(DiffDocumentsRequest {
+            old_data: send_version(&test, ReadVersion, &doc_id, rev_id - 1).await.data,
+            new_data: send_version(&test, ReadVersion, &doc_id, rev_id).await.data,
+        })
+        .async_send()
+        .await
+        .parse::<DocDiff>();
+    assert_eq!(documents_diff.items, diff.items);
+}
+
+#[tokio::test]
+#[serial]
+async fn document_restore_version() {
+    let test = setup_with_snapshot_interval(2);
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+
+    let docs = apply_inserts(&test, &doc_id, 6).await;
+    let versions = list_versions(&test, &doc_id).await.items;
+    let oldest = versions.last().unwrap().rev_id;
+    let version = send_version(&test, ReadVersion, &doc_id, oldest).await;
+
+    let restored = send_version(&test, RestoreVersion, &doc_id, oldest).await;
+    assert_eq!(restored.data, version.data);
+    assert_eq!(send(&test, ReadDocument, &doc_id).await.data, version.data);
+
+    // The restore is a change, it can be undone and the versions after it are kept.
+    assert_eq!(send(&test, Undo, &doc_id).await.data, docs.last().unwrap().data);
+    assert!(list_versions(&test, &doc_id).await.items.len() >= versions.len());
+}
+
+async fn stats(test: &FlowyTest, doc_id: &str) -> DocStats {
+    DocTest::new(test.sdk())
+        .event(GetDocumentStats)
+        .request(DocIdentifier {
+            doc_id: doc_id.to_owned(),
+        })
+        .async_send()
+        .await
+        .parse::<DocStats>()
+}
+
+#[tokio::test]
+#[serial]
+async fn document_stats_after_change() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+
+    let before = stats(&test, &doc_id).await;
+    let _ = apply_change(&test, &doc_id, DeltaBuilder::new().insert("Hello world\n").build()).await;
+
+    let after = stats(&test, &doc_id).await;
+    assert_eq!(after.doc_id, doc_id);
+    assert_eq!(after.word_count, before.word_count + 2);
+    assert_eq!(after.char_count, before.char_count + 11);
+    assert_eq!(after.paragraph_count, before.paragraph_count + 1);
+}
+
+#[tokio::test]
+#[serial]
+async fn document_apply_block_ops() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+
+    let blocks = DocTest::new(test.sdk())
+        .event(ReadBlocks)
+        .request(DocIdentifier { doc_id: doc_id.clone() })
+        .async_send()
+        .await
+        .parse::<RepeatedDocBlock>();
+
+    let heading = DeltaBuilder::new()
+        .insert("Title")
+        .insert_with_attributes("\n", Attribute::Header(1).into())
+        .build();
+    let ops = vec![BlockOp {
+        ty: BlockOpType::Insert,
+        index: 0,
+        to: 0,
+        data: heading.to_json(),
+    }];
+    let new_blocks = DocTest::new(test.sdk())
+        .event(ApplyBlockOps)
+        .request(ApplyBlockOpsRequest {
+            doc_id: doc_id.clone(),
+            ops,
+        })
+        .async_send()
+        .await
+        .parse::<RepeatedDocBlock>();
+    assert_eq!(new_blocks.items.len(), blocks.items.len() + 1);
+    assert_eq!(new_blocks.items[0].block_type, BlockType::Heading);
+    assert_eq!(new_blocks.items[0].data, heading.to_json());
+
+    let ops = vec![BlockOp {
+        ty: BlockOpType::Delete,
+        index: new_blocks.items.len() as i64,
+        to: 0,
+        data: "".to_owned(),
+    }];
+    let error = DocTest::new(test.sdk())
+        .event(ApplyBlockOps)
+        .request(ApplyBlockOpsRequest { doc_id, ops })
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::OutOfBound);
+}
+
+fn read_page_test(test: &FlowyTest, doc_id: &str, cursor: &str) -> DocTest {
+    DocTest::new(test.sdk())
+        .event(ReadDocument)
+        .request(ReadDocumentRequest {
+            doc_id: doc_id.to_owned(),
+            cursor: cursor.to_owned(),
+            limit: 1,
+        })
+}
+
+async fn read_page(test: &FlowyTest, doc_id: &str, cursor: &str) -> DocumentPage {
+    read_page_test(test, doc_id, cursor)
+        .async_send()
+        .await
+        .parse::<DocumentPage>()
+}
+
+#[tokio::test]
+#[serial]
+async fn document_read_page_and_apply_change() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+    let _ = apply_change(&test, &doc_id, DeltaBuilder::new().insert("123\n456\n").build()).await;
+
+    let first = read_page(&test, &doc_id, "").await;
+    assert_eq!(Delta::from_json(&first.data).unwrap().apply("").unwrap(), "123\n");
+    let second = read_page(&test, &doc_id, &first.next_cursor).await;
+    assert_eq!((second.start, second.offset), (1, 4));
+    assert_eq!(Delta::from_json(&second.data).unwrap().apply("").unwrap(), "456\n");
+
+    // The change of the second page keeps the first one.
+    let page_change = DeltaBuilder::new().retain(3).insert("7").build();
+    let mut change = DeltaBuilder::new().retain(second.offset as usize).build();
+    change.extend(page_change);
+    let doc = apply_change(&test, &doc_id, change).await;
+    let text = Delta::from_json(&doc.data).unwrap().apply("").unwrap();
+    assert!(text.starts_with("123\n4567\n"));
+
+    // The blocks were moved by the change, so its cursors are refused.
+    let error = read_page_test(&test, &doc_id, &second.next_cursor)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::CursorInvalid);
+}
+
+#[tokio::test]
+#[serial]
+async fn document_read_page_of_closed_document() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+    let _ = apply_change(&test, &doc_id, DeltaBuilder::new().insert("123\n456\n").build()).await;
+    let _ = DocTest::new(test.sdk())
+        .event(CloseDocument)
+        .request(DocIdentifier { doc_id: doc_id.clone() })
+        .async_send()
+        .await;
+
+    // The closed document is read from the database, its cursor is still valid
+    // once the document is opened.
+    let first = read_page(&test, &doc_id, "").await;
+    assert_eq!(Delta::from_json(&first.data).unwrap().apply("").unwrap(), "123\n");
+    let _ = send(&test, OpenDocument, &doc_id).await;
+    assert_eq!(read_page(&test, &doc_id, &first.next_cursor).await.start, 1);
+}
+
+#[tokio::test]
+#[serial]
+async fn document_update_presence() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+
+    let _ = DocTest::new(test.sdk())
+        .event(UpdatePresence)
+        .request(UpdatePresenceRequest {
+            doc_id: doc_id.clone(),
+            start: 0,
+            end: 1,
+        })
+        .async_send()
+        .await;
+
+    // The presence of the user isn't one of the collaborators.
+    let collaborators = DocTest::new(test.sdk())
+        .event(ReadCollaborators)
+        .request(DocIdentifier { doc_id: doc_id.clone() })
+        .async_send()
+        .await
+        .parse::<RepeatedDocPresence>();
+    assert!(collaborators.items.is_empty());
+
+    let error = DocTest::new(test.sdk())
+        .event(UpdatePresence)
+        .request(UpdatePresenceRequest {
+            doc_id,
+            start: 2,
+            end: 1,
+        })
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::OutOfBound);
+}
+
+#[tokio::test]
+async fn document_open_with_empty_id() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let error = DocTest::new(test.sdk())
+        .event(OpenDocument)
+        .request(DocIdentifier { doc_id: "".to_owned() })
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::DocIdInvalid);
+}
@@ -0,0 +1,173 @@
+use flowy_database::{prelude::*, schema::rev_table::dsl};
+use flowy_document::{
+    entities::{
+        encryption::DocEncryption,
+        search::{RepeatedDocSearchResult, SearchDocumentsRequest},
+    },
+    errors::ErrorCode,
+    event::DocumentEvent::*,
+};
+use flowy_document_infra::entities::doc::{DocDelta, DocIdentifier};
+use flowy_ot::core::{Delta, DeltaBuilder};
+use flowy_test::{builder::DocTest, workspace::ViewTest, FlowyTest};
+use serial_test::*;
+
+fn doc_identifier(doc_id: &str) -> DocIdentifier {
+    DocIdentifier {
+        doc_id: doc_id.to_owned(),
+    }
+}
+
+async fn open_document(test: &FlowyTest, doc_id: &str) -> DocTest {
+    DocTest::new(test.sdk())
+        .event(OpenDocument)
+        .request(doc_identifier(doc_id))
+        .async_send()
+        .await
+}
+
+async fn read_text(test: &FlowyTest, doc_id: &str) -> String {
+    let doc = open_document(test, doc_id).await.parse::<DocDelta>();
+    Delta::from_json(&doc.data).unwrap().apply("").unwrap()
+}
+
+async fn insert_text(test: &FlowyTest, doc_id: &str, s: &str) {
+    let len = Delta::from_json(&open_document(test, doc_id).await.parse::<DocDelta>().data)
+        .unwrap()
+        .target_len;
+    let _ = DocTest::new(test.sdk())
+        .event(ApplyChange)
+        .request(DocDelta {
+            doc_id: doc_id.to_owned(),
+            data: DeltaBuilder::new().insert(s).retain(len).build().to_json(),
+        })
+        .async_send()
+        .await;
+    let _ = DocTest::new(test.sdk())
+        .event(ForceSave)
+        .request(doc_identifier(doc_id))
+        .async_send()
+        .await;
+}
+
+async fn send_encryption_event(
+    test: &FlowyTest,
+    event: flowy_document::event::DocumentEvent,
+    doc_id: &str,
+) -> DocTest {
+    DocTest::new(test.sdk())
+        .event(event)
+        .request(doc_identifier(doc_id))
+        .async_send()
+        .await
+}
+
+// The revisions as they're kept in the database.
+fn persisted_data(test: &FlowyTest, doc_id: &str) -> Vec<Vec<u8>> {
+    let pool = test.sdk.user_session.db_pool().unwrap();
+    let conn = &*pool.get().unwrap();
+    dsl::rev_table
+        .filter(dsl::doc_id.eq(doc_id))
+        .select(dsl::data)
+        .load::<Vec<u8>>(conn)
+        .unwrap()
+}
+
+fn contains_text(data: &[Vec<u8>], s: &str) -> bool {
+    data.iter().any(|data| data.windows(s.len()).any(|window| window == s.as_bytes()))
+}
+
+async fn search(test: &FlowyTest, query: &str) -> RepeatedDocSearchResult {
+    DocTest::new(test.sdk())
+        .event(SearchDocuments)
+        .request(SearchDocumentsRequest {
+            query: query.to_owned(),
+            limit: 0,
+        })
+        .async_send()
+        .await
+        .parse::<RepeatedDocSearchResult>()
+}
+
+#[tokio::test]
+#[serial]
+async fn document_encrypt_lock_and_unlock() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+    insert_text(&test, &doc_id, "top secret").await;
+    assert!(contains_text(&persisted_data(&test, &doc_id), "top secret"));
+
+    let encryption = send_encryption_event(&test, EncryptDocument, &doc_id)
+        .await
+        .parse::<DocEncryption>();
+    assert!(encryption.encrypted);
+    assert!(!encryption.locked);
+    assert!(!contains_text(&persisted_data(&test, &doc_id), "top secret"));
+
+    // The new revisions are encrypted too.
+    insert_text(&test, &doc_id, "more ").await;
+    assert_eq!(read_text(&test, &doc_id).await, "more top secret\n");
+    assert!(!contains_text(&persisted_data(&test, &doc_id), "more"));
+
+    let encryption = send_encryption_event(&test, LockDocument, &doc_id)
+        .await
+        .parse::<DocEncryption>();
+    assert!(encryption.locked);
+    let error = open_document(&test, &doc_id).await.error();
+    assert_eq!(error.code, ErrorCode::DocumentLocked);
+
+    let encryption = send_encryption_event(&test, UnlockDocument, &doc_id)
+        .await
+        .parse::<DocEncryption>();
+    assert!(encryption.encrypted);
+    assert!(!encryption.locked);
+    assert_eq!(read_text(&test, &doc_id).await, "more top secret\n");
+}
+
+#[tokio::test]
+#[serial]
+async fn document_decrypt() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+    insert_text(&test, &doc_id, "top secret").await;
+    let _ = send_encryption_event(&test, EncryptDocument, &doc_id).await;
+    let _ = send_encryption_event(&test, LockDocument, &doc_id).await;
+
+    // The locked document can't be decrypted.
+    let error = send_encryption_event(&test, DecryptDocument, &doc_id).await.error();
+    assert_eq!(error.code, ErrorCode::DocumentLocked);
+
+    let _ = send_encryption_event(&test, UnlockDocument, &doc_id).await;
+    let encryption = send_encryption_event(&test, DecryptDocument, &doc_id)
+        .await
+        .parse::<DocEncryption>();
+    assert!(!encryption.encrypted);
+    assert!(!encryption.locked);
+    assert!(contains_text(&persisted_data(&test, &doc_id), "top secret"));
+    assert_eq!(read_text(&test, &doc_id).await, "top secret\n");
+
+    // Locking the document that isn't encrypted changes nothing.
+    let encryption = send_encryption_event(&test, LockDocument, &doc_id)
+        .await
+        .parse::<DocEncryption>();
+    assert!(!encryption.locked);
+    assert_eq!(read_text(&test, &doc_id).await, "top secret\n");
+}
+
+#[tokio::test]
+#[serial]
+async fn encrypted_document_is_not_indexed() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+    insert_text(&test, &doc_id, "searchable").await;
+    assert_eq!(search(&test, "searchable").await.items.len(), 1);
+
+    let _ = send_encryption_event(&test, EncryptDocument, &doc_id).await;
+    assert!(search(&test, "searchable").await.items.is_empty());
+
+    insert_text(&test, &doc_id, "hidden ").await;
+    assert!(search(&test, "hidden").await.items.is_empty());
+}
@@ -0,0 +1,8 @@
+mod asset_test;
+mod attachment_test;
+mod blob_test;
+mod check_test;
+mod comment_test;
+mod doc_test;
+mod encryption_test;
+mod search_test;
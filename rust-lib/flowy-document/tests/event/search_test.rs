@@ -0,0 +1,113 @@
+use flowy_document::{
+    entities::search::{RepeatedDocSearchResult, SearchDocumentsRequest, SearchHighlight},
+    event::DocumentEvent::*,
+};
+use flowy_document_infra::entities::doc::{DocDelta, DocIdentifier};
+use flowy_ot::core::{Delta, DeltaBuilder};
+use flowy_test::{builder::DocTest, workspace::ViewTest, FlowyTest};
+use serial_test::*;
+
+async fn open_document(test: &FlowyTest, doc_id: &str) -> Delta {
+    let doc = DocTest::new(test.sdk())
+        .event(OpenDocument)
+        .request(DocIdentifier {
+            doc_id: doc_id.to_owned(),
+        })
+        .async_send()
+        .await
+        .parse::<DocDelta>();
+    Delta::from_json(&doc.data).unwrap()
+}
+
+// Applies the change and indexes the document right away.
+async fn apply_change(test: &FlowyTest, doc_id: &str, change: Delta) {
+    let _ = DocTest::new(test.sdk())
+        .event(ApplyChange)
+        .request(DocDelta {
+            doc_id: doc_id.to_owned(),
+            data: change.to_json(),
+        })
+        .async_send()
+        .await;
+    let _ = DocTest::new(test.sdk())
+        .event(ForceSave)
+        .request(DocIdentifier {
+            doc_id: doc_id.to_owned(),
+        })
+        .async_send()
+        .await;
+}
+
+async fn insert_text(test: &FlowyTest, doc_id: &str, s: &str) {
+    let len = open_document(test, doc_id).await.target_len;
+    apply_change(test, doc_id, DeltaBuilder::new().insert(s).retain(len).build()).await;
+}
+
+async fn search(test: &FlowyTest, query: &str) -> RepeatedDocSearchResult {
+    DocTest::new(test.sdk())
+        .event(SearchDocuments)
+        .request(SearchDocumentsRequest {
+            query: query.to_owned(),
+            limit: 0,
+        })
+        .async_send()
+        .await
+        .parse::<RepeatedDocSearchResult>()
+}
+
+#[tokio::test]
+#[serial]
+async fn document_search() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+    let other_doc_id = ViewTest::new(&test).await.view.id;
+    insert_text(&test, &doc_id, "hello world\nsomething else\n").await;
+    insert_text(&test, &other_doc_id, "hello hello hello\n").await;
+
+    // The document that matches the term more often comes first.
+    let results = search(&test, "hello").await.items;
+    let doc_ids = results.iter().map(|result| result.doc_id.clone()).collect::<Vec<_>>();
+    assert_eq!(doc_ids, vec![other_doc_id, doc_id.clone()]);
+
+    // The last word is matched as a prefix.
+    let results = search(&test, "hello wor").await.items;
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, doc_id);
+    assert_eq!(results[0].snippet, "hello world");
+    assert_eq!(
+        results[0].highlights,
+        vec![
+            SearchHighlight { start: 0, end: 5 },
+            SearchHighlight { start: 6, end: 11 }
+        ]
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn document_search_after_change() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+    insert_text(&test, &doc_id, "hello world\n").await;
+    assert_eq!(search(&test, "world").await.items.len(), 1);
+
+    // The line is deleted.
+    let len = open_document(&test, &doc_id).await.target_len;
+    apply_change(&test, &doc_id, DeltaBuilder::new().delete(12).retain(len - 12).build()).await;
+    assert!(search(&test, "world").await.items.is_empty());
+}
+
+#[tokio::test]
+#[serial]
+async fn document_search_with_special_characters() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let doc_id = ViewTest::new(&test).await.view.id;
+    insert_text(&test, &doc_id, "hello world\n").await;
+
+    assert!(search(&test, "").await.items.is_empty());
+    assert!(search(&test, "\"hello AND\" OR (").await.items.is_empty());
+    assert_eq!(search(&test, "\"hello\"").await.items.len(), 1);
+}
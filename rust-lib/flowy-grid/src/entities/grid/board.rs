@@ -0,0 +1,59 @@
+use crate::entities::grid::Row;
+use flowy_derive::ProtoBuf;
+
+// A group of the board is an option of the select field it groups by. The rows
+// that select none of the options are in the group with the empty id, the first
+// one. A row of a multi select field is in the group of each of its options.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct BoardGroup {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub rows: Vec<Row>,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct Board {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub group_field_id: String,
+
+    #[pb(index = 3)]
+    pub groups: Vec<BoardGroup>,
+}
+
+// An empty group_field_id groups by the first select field of the grid.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct UpdateBoardSettingRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub group_field_id: String,
+}
+
+// Moves the card before the card of to_row_id, or at the end of the group
+// without it. The card stays in its group if the groups are the same.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct MoveCardRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub row_id: String,
+
+    #[pb(index = 3)]
+    pub from_group_id: String,
+
+    #[pb(index = 4)]
+    pub to_group_id: String,
+
+    #[pb(index = 5, one_of)]
+    pub to_row_id: Option<String>,
+}
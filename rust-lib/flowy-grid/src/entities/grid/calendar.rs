@@ -0,0 +1,64 @@
+use crate::entities::grid::Row;
+use flowy_derive::ProtoBuf;
+
+// The range is the one the calendar shows, the rows of a date in it are sent
+// with CalendarChanged until the calendar is closed. The dates are the seconds
+// of their timestamp, the end is excluded. An empty date_field_id shows the
+// first date field of the grid.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct CalendarRangeRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub date_field_id: String,
+
+    #[pb(index = 3)]
+    pub start: i64,
+
+    #[pb(index = 4)]
+    pub end: i64,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct CalendarEvent {
+    #[pb(index = 1)]
+    pub timestamp: i64,
+
+    #[pb(index = 2)]
+    pub row: Row,
+}
+
+// The events are in the order of their date, the rows of the same date in the
+// order of the grid.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct Calendar {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub date_field_id: String,
+
+    #[pb(index = 3)]
+    pub start: i64,
+
+    #[pb(index = 4)]
+    pub end: i64,
+
+    #[pb(index = 5)]
+    pub items: Vec<CalendarEvent>,
+}
+
+// Moves the row to the day of the timestamp. The time of the cell is kept if
+// its field includes the time.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RescheduleRowRequest {
+    #[pb(index = 1)]
+    pub row_id: String,
+
+    #[pb(index = 2)]
+    pub date_field_id: String,
+
+    #[pb(index = 3)]
+    pub timestamp: i64,
+}
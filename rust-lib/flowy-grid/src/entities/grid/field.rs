@@ -0,0 +1,125 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+#[derive(ProtoBuf_Enum, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldType {
+    RichText     = 0,
+    Number       = 1,
+    SingleSelect = 2,
+    MultiSelect  = 3,
+    DateTime     = 4,
+    Checkbox     = 5,
+}
+
+impl std::default::Default for FieldType {
+    fn default() -> Self { FieldType::RichText }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct SelectOption {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub color: String,
+}
+
+// The options of each field type, the ones of the other types are ignored.
+// The decimals are the ones of a number, include_time tells if a date shows
+// its time. The options without an id are given one when they're saved.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct FieldTypeOption {
+    #[pb(index = 1)]
+    pub decimals: i32,
+
+    #[pb(index = 2)]
+    pub include_time: bool,
+
+    #[pb(index = 3)]
+    pub options: Vec<SelectOption>,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct Field {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub grid_id: String,
+
+    #[pb(index = 3)]
+    pub name: String,
+
+    #[pb(index = 4)]
+    pub field_type: FieldType,
+
+    #[pb(index = 5)]
+    pub width: i32,
+
+    #[pb(index = 6)]
+    pub type_option: FieldTypeOption,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedField {
+    #[pb(index = 1)]
+    pub items: Vec<Field>,
+}
+
+// The field is added after the last one. The width is the default one if it's
+// not positive.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct CreateFieldRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub field_type: FieldType,
+
+    #[pb(index = 4)]
+    pub width: i32,
+
+    #[pb(index = 5)]
+    pub type_option: FieldTypeOption,
+}
+
+// The cells of a select field lose the options that were removed.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct UpdateFieldRequest {
+    #[pb(index = 1)]
+    pub field_id: String,
+
+    #[pb(index = 2, one_of)]
+    pub name: Option<String>,
+
+    #[pb(index = 3, one_of)]
+    pub width: Option<i32>,
+
+    #[pb(index = 4, one_of)]
+    pub type_option: Option<FieldTypeOption>,
+}
+
+// The cells are converted to the new type, the ones that can't be are cleared.
+// A select field switched from a text field gets an option for each text.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct SwitchFieldTypeRequest {
+    #[pb(index = 1)]
+    pub field_id: String,
+
+    #[pb(index = 2)]
+    pub field_type: FieldType,
+
+    #[pb(index = 3)]
+    pub type_option: FieldTypeOption,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct FieldIdentifier {
+    #[pb(index = 1)]
+    pub field_id: String,
+}
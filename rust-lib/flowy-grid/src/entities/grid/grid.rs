@@ -0,0 +1,37 @@
+use crate::entities::grid::{Field, GridFilter, GridSort, Row};
+use flowy_derive::ProtoBuf;
+
+// The grid starts with a text field named Name and no rows.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct CreateGridRequest {
+    #[pb(index = 1)]
+    pub name: String,
+}
+
+// The rows are the ones that pass the filters, in the order of the sorts.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct Grid {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub fields: Vec<Field>,
+
+    #[pb(index = 4)]
+    pub rows: Vec<Row>,
+
+    #[pb(index = 5)]
+    pub sorts: Vec<GridSort>,
+
+    #[pb(index = 6)]
+    pub filters: Vec<GridFilter>,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct GridIdentifier {
+    #[pb(index = 1)]
+    pub grid_id: String,
+}
@@ -0,0 +1,13 @@
+mod board;
+mod calendar;
+mod field;
+mod grid;
+mod row;
+mod setting;
+
+pub use board::*;
+pub use calendar::*;
+pub use field::*;
+pub use grid::*;
+pub use row::*;
+pub use setting::*;
@@ -0,0 +1,64 @@
+use flowy_derive::ProtoBuf;
+
+// The data of a cell is stored as text whatever the type of its field: the
+// number, the ids of the selected options separated by commas, the timestamp
+// of the date in seconds, or Yes for a checked checkbox. It's empty if the
+// cell is.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct Cell {
+    #[pb(index = 1)]
+    pub field_id: String,
+
+    #[pb(index = 2)]
+    pub data: String,
+}
+
+// A row has a cell for each field of the grid, in the order of the fields.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct Row {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub grid_id: String,
+
+    #[pb(index = 3)]
+    pub cells: Vec<Cell>,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedRow {
+    #[pb(index = 1)]
+    pub items: Vec<Row>,
+}
+
+// The row is inserted after the start row, or after the last row if there's
+// none.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct CreateRowRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2, one_of)]
+    pub start_row_id: Option<String>,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RowIdentifier {
+    #[pb(index = 1)]
+    pub row_id: String,
+}
+
+// The data is checked against the type of the field, e.g. a number cell
+// accepts 1,234.5 and stores 1234.5. An empty data clears the cell.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct CellChangeset {
+    #[pb(index = 1)]
+    pub row_id: String,
+
+    #[pb(index = 2)]
+    pub field_id: String,
+
+    #[pb(index = 3)]
+    pub data: String,
+}
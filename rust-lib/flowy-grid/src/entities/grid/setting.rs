@@ -0,0 +1,64 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+// The rows are sorted by the first sort, then by the next ones. The empty
+// cells come last whatever the order.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct GridSort {
+    #[pb(index = 1)]
+    pub field_id: String,
+
+    #[pb(index = 2)]
+    pub descending: bool,
+}
+
+// Is and IsNot compare the text of a cell, the ids of its options for a select
+// field. Contains and DoesNotContain look for the text, or for the option, in
+// the cell. Greater and Less compare numbers and dates.
+#[derive(ProtoBuf_Enum, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FilterCondition {
+    Is             = 0,
+    IsNot          = 1,
+    Contains       = 2,
+    DoesNotContain = 3,
+    IsEmpty        = 4,
+    IsNotEmpty     = 5,
+    Greater        = 6,
+    Less           = 7,
+}
+
+impl std::default::Default for FilterCondition {
+    fn default() -> Self { FilterCondition::Is }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct GridFilter {
+    #[pb(index = 1)]
+    pub field_id: String,
+
+    #[pb(index = 2)]
+    pub condition: FilterCondition,
+
+    #[pb(index = 3)]
+    pub value: String,
+}
+
+// Replaces the sorts of the grid, the rows keep the order they were created
+// in if there's none.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct UpdateGridSortsRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub items: Vec<GridSort>,
+}
+
+// Replaces the filters of the grid, a row is shown if it passes all of them.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct UpdateGridFiltersRequest {
+    #[pb(index = 1)]
+    pub grid_id: String,
+
+    #[pb(index = 2)]
+    pub items: Vec<GridFilter>,
+}
@@ -0,0 +1,122 @@
+use bytes::Bytes;
+use derive_more::Display;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_dispatch::prelude::{EventResponse, ResponseBuilder};
+use std::{convert::TryInto, fmt, fmt::Debug};
+
+pub type GridResult<T> = std::result::Result<T, GridError>;
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct GridError {
+    #[pb(index = 1)]
+    pub code: ErrorCode,
+
+    #[pb(index = 2)]
+    pub msg: String,
+}
+
+macro_rules! static_grid_error {
+    ($name:ident, $status:expr) => {
+        #[allow(non_snake_case, missing_docs)]
+        pub fn $name() -> GridError {
+            GridError {
+                code: $status,
+                msg: format!("{}", $status),
+            }
+        }
+    };
+}
+
+impl GridError {
+    pub fn context<T: Debug>(mut self, error: T) -> Self {
+        self.msg = format!("{:?}", error);
+        self
+    }
+
+    pub fn is_record_not_found(&self) -> bool { self.code == ErrorCode::RecordNotFound }
+
+    static_grid_error!(internal, ErrorCode::InternalError);
+    static_grid_error!(record_not_found, ErrorCode::RecordNotFound);
+    static_grid_error!(unauthorized, ErrorCode::UserUnauthorized);
+    static_grid_error!(grid_not_found, ErrorCode::GridNotFound);
+    static_grid_error!(field_not_found, ErrorCode::FieldNotFound);
+    static_grid_error!(row_not_found, ErrorCode::RowNotFound);
+    static_grid_error!(group_not_found, ErrorCode::GroupNotFound);
+    static_grid_error!(grid_name_invalid, ErrorCode::GridNameInvalid);
+    static_grid_error!(field_name_invalid, ErrorCode::FieldNameInvalid);
+    static_grid_error!(cell_data_invalid, ErrorCode::CellDataInvalid);
+    static_grid_error!(filter_invalid, ErrorCode::FilterInvalid);
+    static_grid_error!(last_field, ErrorCode::LastFieldCanNotBeDeleted);
+    static_grid_error!(group_field_invalid, ErrorCode::GroupFieldInvalid);
+    static_grid_error!(date_field_invalid, ErrorCode::DateFieldInvalid);
+    static_grid_error!(calendar_range_invalid, ErrorCode::CalendarRangeInvalid);
+}
+
+pub fn internal_error<T>(e: T) -> GridError
+where
+    T: std::fmt::Debug,
+{
+    GridError::internal().context(e)
+}
+
+#[derive(Debug, Clone, ProtoBuf_Enum, Display, PartialEq, Eq)]
+pub enum ErrorCode {
+    #[display(fmt = "Record not found")]
+    RecordNotFound       = 0,
+
+    #[display(fmt = "Grid not found")]
+    GridNotFound         = 10,
+    #[display(fmt = "Field not found")]
+    FieldNotFound        = 11,
+    #[display(fmt = "Row not found")]
+    RowNotFound          = 12,
+    #[display(fmt = "Group not found")]
+    GroupNotFound        = 13,
+
+    #[display(fmt = "The name of the grid can not be empty")]
+    GridNameInvalid      = 20,
+    #[display(fmt = "The name of the field can not be empty")]
+    FieldNameInvalid     = 21,
+    #[display(fmt = "The data doesn't match the type of the field")]
+    CellDataInvalid      = 22,
+    #[display(fmt = "The filter doesn't apply to the type of the field")]
+    FilterInvalid        = 23,
+    #[display(fmt = "The last field of the grid can not be deleted")]
+    LastFieldCanNotBeDeleted = 24,
+    #[display(fmt = "The board groups the rows by a select field")]
+    GroupFieldInvalid    = 25,
+    #[display(fmt = "The calendar shows the rows by a date field")]
+    DateFieldInvalid     = 26,
+    #[display(fmt = "The end of the range must be after its start")]
+    CalendarRangeInvalid = 27,
+
+    #[display(fmt = "UserUnauthorized")]
+    UserUnauthorized     = 999,
+
+    #[display(fmt = "InternalError")]
+    InternalError        = 1000,
+}
+
+impl std::default::Default for ErrorCode {
+    fn default() -> Self { ErrorCode::InternalError }
+}
+
+impl std::convert::From<flowy_database::Error> for GridError {
+    fn from(error: flowy_database::Error) -> Self {
+        match error {
+            flowy_database::Error::NotFound => GridError::record_not_found().context(error),
+            _ => GridError::internal().context(error),
+        }
+    }
+}
+
+impl flowy_dispatch::Error for GridError {
+    fn as_response(&self) -> EventResponse {
+        let bytes: Bytes = self.clone().try_into().unwrap();
+        ResponseBuilder::Err().data(bytes).build()
+    }
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{:?}: {}", &self.code, &self.msg) }
+}
@@ -0,0 +1,66 @@
+use flowy_derive::{Flowy_Event, ProtoBuf_Enum};
+use strum_macros::Display;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Display, Hash, ProtoBuf_Enum, Flowy_Event)]
+#[event_err = "GridError"]
+pub enum GridEvent {
+    #[event(input = "CreateGridRequest", output = "Grid")]
+    CreateGrid         = 0,
+
+    #[event(input = "GridIdentifier", output = "Grid")]
+    ReadGrid           = 1,
+
+    #[event(input = "GridIdentifier")]
+    DeleteGrid         = 2,
+
+    #[event(input = "CreateFieldRequest", output = "Field")]
+    CreateField        = 10,
+
+    #[event(input = "UpdateFieldRequest", output = "Field")]
+    UpdateField        = 11,
+
+    #[event(input = "SwitchFieldTypeRequest", output = "Field")]
+    SwitchFieldType    = 12,
+
+    #[event(input = "FieldIdentifier")]
+    DeleteField        = 13,
+
+    #[event(input = "GridIdentifier", output = "RepeatedField")]
+    ReadFields         = 14,
+
+    #[event(input = "CreateRowRequest", output = "Row")]
+    CreateRow          = 20,
+
+    #[event(input = "RowIdentifier")]
+    DeleteRow          = 21,
+
+    #[event(input = "GridIdentifier", output = "RepeatedRow")]
+    ReadRows           = 22,
+
+    #[event(input = "CellChangeset", output = "Cell")]
+    UpdateCell         = 30,
+
+    #[event(input = "UpdateGridSortsRequest", output = "RepeatedRow")]
+    UpdateSorts        = 40,
+
+    #[event(input = "UpdateGridFiltersRequest", output = "RepeatedRow")]
+    UpdateFilters      = 41,
+
+    #[event(input = "GridIdentifier", output = "Board")]
+    ReadBoard          = 50,
+
+    #[event(input = "UpdateBoardSettingRequest", output = "Board")]
+    UpdateBoardSetting = 51,
+
+    #[event(input = "MoveCardRequest", output = "Board")]
+    MoveCard           = 52,
+
+    #[event(input = "CalendarRangeRequest", output = "Calendar")]
+    ReadCalendar       = 60,
+
+    #[event(input = "GridIdentifier")]
+    CloseCalendar      = 61,
+
+    #[event(input = "RescheduleRowRequest", output = "Cell")]
+    RescheduleRow      = 62,
+}
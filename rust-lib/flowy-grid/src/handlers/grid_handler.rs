@@ -0,0 +1,191 @@
+use crate::{entities::grid::*, errors::GridError, services::grid::GridController};
+use flowy_dispatch::prelude::{data_result, Data, DataResult, Unit};
+use std::sync::Arc;
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn create_grid_handler(
+    data: Data<CreateGridRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Grid, GridError> {
+    let grid = controller.create_grid(data.into_inner())?;
+    data_result(grid)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_grid_handler(
+    data: Data<GridIdentifier>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Grid, GridError> {
+    let params = data.into_inner();
+    let grid = controller.read_grid(&params.grid_id)?;
+    data_result(grid)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn delete_grid_handler(
+    data: Data<GridIdentifier>,
+    controller: Unit<Arc<GridController>>,
+) -> Result<(), GridError> {
+    let params = data.into_inner();
+    let _ = controller.delete_grid(&params.grid_id)?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn create_field_handler(
+    data: Data<CreateFieldRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Field, GridError> {
+    let field = controller.create_field(data.into_inner())?;
+    data_result(field)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_field_handler(
+    data: Data<UpdateFieldRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Field, GridError> {
+    let field = controller.update_field(data.into_inner())?;
+    data_result(field)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn switch_field_type_handler(
+    data: Data<SwitchFieldTypeRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Field, GridError> {
+    let field = controller.switch_field_type(data.into_inner())?;
+    data_result(field)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn delete_field_handler(
+    data: Data<FieldIdentifier>,
+    controller: Unit<Arc<GridController>>,
+) -> Result<(), GridError> {
+    let params = data.into_inner();
+    let _ = controller.delete_field(&params.field_id)?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_fields_handler(
+    data: Data<GridIdentifier>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<RepeatedField, GridError> {
+    let params = data.into_inner();
+    let fields = controller.read_fields(&params.grid_id)?;
+    data_result(fields)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn create_row_handler(
+    data: Data<CreateRowRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Row, GridError> {
+    let row = controller.create_row(data.into_inner())?;
+    data_result(row)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn delete_row_handler(
+    data: Data<RowIdentifier>,
+    controller: Unit<Arc<GridController>>,
+) -> Result<(), GridError> {
+    let params = data.into_inner();
+    let _ = controller.delete_row(&params.row_id)?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_rows_handler(
+    data: Data<GridIdentifier>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<RepeatedRow, GridError> {
+    let params = data.into_inner();
+    let rows = controller.read_rows(&params.grid_id)?;
+    data_result(rows)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_cell_handler(
+    data: Data<CellChangeset>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Cell, GridError> {
+    let cell = controller.update_cell(data.into_inner())?;
+    data_result(cell)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_sorts_handler(
+    data: Data<UpdateGridSortsRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<RepeatedRow, GridError> {
+    let rows = controller.update_sorts(data.into_inner())?;
+    data_result(rows)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_filters_handler(
+    data: Data<UpdateGridFiltersRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<RepeatedRow, GridError> {
+    let rows = controller.update_filters(data.into_inner())?;
+    data_result(rows)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_board_handler(
+    data: Data<GridIdentifier>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Board, GridError> {
+    let params = data.into_inner();
+    let board = controller.read_board(&params.grid_id)?;
+    data_result(board)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_board_setting_handler(
+    data: Data<UpdateBoardSettingRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Board, GridError> {
+    let board = controller.update_board_setting(data.into_inner())?;
+    data_result(board)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn move_card_handler(
+    data: Data<MoveCardRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Board, GridError> {
+    let board = controller.move_card(data.into_inner())?;
+    data_result(board)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_calendar_handler(
+    data: Data<CalendarRangeRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Calendar, GridError> {
+    let calendar = controller.read_calendar(data.into_inner())?;
+    data_result(calendar)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn close_calendar_handler(
+    data: Data<GridIdentifier>,
+    controller: Unit<Arc<GridController>>,
+) -> Result<(), GridError> {
+    let params = data.into_inner();
+    controller.close_calendar(&params.grid_id);
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn reschedule_row_handler(
+    data: Data<RescheduleRowRequest>,
+    controller: Unit<Arc<GridController>>,
+) -> DataResult<Cell, GridError> {
+    let cell = controller.reschedule_row(data.into_inner())?;
+    data_result(cell)
+}
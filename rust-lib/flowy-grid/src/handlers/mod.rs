@@ -0,0 +1,3 @@
+mod grid_handler;
+
+pub use grid_handler::*;
@@ -0,0 +1,12 @@
+pub mod entities;
+pub mod errors;
+pub mod event;
+mod handlers;
+pub mod module;
+mod notify;
+pub mod protobuf;
+pub mod services;
+mod sql_tables;
+
+#[macro_use]
+extern crate flowy_database;
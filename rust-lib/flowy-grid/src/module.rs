@@ -0,0 +1,45 @@
+use crate::{errors::GridError, event::GridEvent, handlers::*, services::grid::GridController};
+use flowy_database::ConnectionPool;
+use flowy_dispatch::prelude::*;
+use std::sync::Arc;
+
+pub trait GridUser: Send + Sync {
+    fn user_id(&self) -> Result<String, GridError>;
+    fn db_pool(&self) -> Result<Arc<ConnectionPool>, GridError>;
+}
+
+pub fn mk_grid(user: Arc<dyn GridUser>) -> Arc<GridController> { Arc::new(GridController::new(user)) }
+
+pub fn create(controller: Arc<GridController>) -> Module {
+    Module::new()
+        .name("Flowy-Grid")
+        .data(controller)
+        .event_with_permission(GridEvent::CreateGrid, create_grid_handler, EventPermission::Write)
+        .event(GridEvent::ReadGrid, read_grid_handler)
+        .event_with_permission(GridEvent::DeleteGrid, delete_grid_handler, EventPermission::Write)
+        .event_with_permission(GridEvent::CreateField, create_field_handler, EventPermission::Write)
+        .event_with_permission(GridEvent::UpdateField, update_field_handler, EventPermission::Write)
+        .event_with_permission(
+            GridEvent::SwitchFieldType,
+            switch_field_type_handler,
+            EventPermission::Write,
+        )
+        .event_with_permission(GridEvent::DeleteField, delete_field_handler, EventPermission::Write)
+        .event(GridEvent::ReadFields, read_fields_handler)
+        .event_with_permission(GridEvent::CreateRow, create_row_handler, EventPermission::Write)
+        .event_with_permission(GridEvent::DeleteRow, delete_row_handler, EventPermission::Write)
+        .event(GridEvent::ReadRows, read_rows_handler)
+        .event_with_permission(GridEvent::UpdateCell, update_cell_handler, EventPermission::Write)
+        .event_with_permission(GridEvent::UpdateSorts, update_sorts_handler, EventPermission::Write)
+        .event_with_permission(GridEvent::UpdateFilters, update_filters_handler, EventPermission::Write)
+        .event(GridEvent::ReadBoard, read_board_handler)
+        .event_with_permission(
+            GridEvent::UpdateBoardSetting,
+            update_board_setting_handler,
+            EventPermission::Write,
+        )
+        .event_with_permission(GridEvent::MoveCard, move_card_handler, EventPermission::Write)
+        .event(GridEvent::ReadCalendar, read_calendar_handler)
+        .event(GridEvent::CloseCalendar, close_calendar_handler)
+        .event_with_permission(GridEvent::RescheduleRow, reschedule_row_handler, EventPermission::Write)
+}
@@ -0,0 +1,24 @@
+use flowy_dart_notify::DartNotifyBuilder;
+use flowy_derive::ProtoBuf_Enum;
+const OBSERVABLE_CATEGORY: &'static str = "Grid";
+
+// The id of FieldsChanged and RowsChanged is the one of the grid, the id of
+// CellUpdated is the one of the row. RowsChanged sends the rows that pass the
+// filters, in the order of the sorts. The id of BoardChanged and
+// CalendarChanged is the one of the grid.
+#[derive(ProtoBuf_Enum, Debug)]
+pub(crate) enum GridObservable {
+    FieldsChanged   = 0,
+    RowsChanged     = 1,
+    CellUpdated     = 2,
+    BoardChanged    = 3,
+    CalendarChanged = 4,
+}
+
+impl std::convert::Into<i32> for GridObservable {
+    fn into(self) -> i32 { self as i32 }
+}
+
+pub(crate) fn dart_notify(id: &str, ty: GridObservable) -> DartNotifyBuilder {
+    DartNotifyBuilder::new(id, ty, OBSERVABLE_CATEGORY)
+}
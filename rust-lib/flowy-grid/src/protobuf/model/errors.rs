@@ -0,0 +1,329 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `errors.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GridError {
+    // message fields
+    pub code: ErrorCode,
+    pub msg: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GridError {
+    fn default() -> &'a GridError {
+        <GridError as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GridError {
+    pub fn new() -> GridError {
+        ::std::default::Default::default()
+    }
+
+    // .ErrorCode code = 1;
+
+
+    pub fn get_code(&self) -> ErrorCode {
+        self.code
+    }
+    pub fn clear_code(&mut self) {
+        self.code = ErrorCode::RecordNotFound;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_code(&mut self, v: ErrorCode) {
+        self.code = v;
+    }
+
+    // string msg = 2;
+
+
+    pub fn get_msg(&self) -> &str {
+        &self.msg
+    }
+    pub fn clear_msg(&mut self) {
+        self.msg.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_msg(&mut self, v: ::std::string::String) {
+        self.msg = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_msg(&mut self) -> &mut ::std::string::String {
+        &mut self.msg
+    }
+
+    // Take field
+    pub fn take_msg(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.msg, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GridError {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.code, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.msg)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.code != ErrorCode::RecordNotFound {
+            my_size += ::protobuf::rt::enum_size(1, self.code);
+        }
+        if !self.msg.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.msg);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.code != ErrorCode::RecordNotFound {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.code))?;
+        }
+        if !self.msg.is_empty() {
+            os.write_string(2, &self.msg)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GridError {
+        GridError::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ErrorCode>>(
+                "code",
+                |m: &GridError| { &m.code },
+                |m: &mut GridError| { &mut m.code },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "msg",
+                |m: &GridError| { &m.msg },
+                |m: &mut GridError| { &mut m.msg },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GridError>(
+                "GridError",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GridError {
+        static instance: ::protobuf::rt::LazyV2<GridError> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GridError::new)
+    }
+}
+
+impl ::protobuf::Clear for GridError {
+    fn clear(&mut self) {
+        self.code = ErrorCode::RecordNotFound;
+        self.msg.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GridError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GridError {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum ErrorCode {
+    RecordNotFound = 0,
+    GridNotFound = 10,
+    FieldNotFound = 11,
+    RowNotFound = 12,
+    GroupNotFound = 13,
+    GridNameInvalid = 20,
+    FieldNameInvalid = 21,
+    CellDataInvalid = 22,
+    FilterInvalid = 23,
+    LastFieldCanNotBeDeleted = 24,
+    GroupFieldInvalid = 25,
+    DateFieldInvalid = 26,
+    CalendarRangeInvalid = 27,
+    UserUnauthorized = 999,
+    InternalError = 1000,
+}
+
+impl ::protobuf::ProtobufEnum for ErrorCode {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ErrorCode> {
+        match value {
+            0 => ::std::option::Option::Some(ErrorCode::RecordNotFound),
+            10 => ::std::option::Option::Some(ErrorCode::GridNotFound),
+            11 => ::std::option::Option::Some(ErrorCode::FieldNotFound),
+            12 => ::std::option::Option::Some(ErrorCode::RowNotFound),
+            13 => ::std::option::Option::Some(ErrorCode::GroupNotFound),
+            20 => ::std::option::Option::Some(ErrorCode::GridNameInvalid),
+            21 => ::std::option::Option::Some(ErrorCode::FieldNameInvalid),
+            22 => ::std::option::Option::Some(ErrorCode::CellDataInvalid),
+            23 => ::std::option::Option::Some(ErrorCode::FilterInvalid),
+            24 => ::std::option::Option::Some(ErrorCode::LastFieldCanNotBeDeleted),
+            25 => ::std::option::Option::Some(ErrorCode::GroupFieldInvalid),
+            26 => ::std::option::Option::Some(ErrorCode::DateFieldInvalid),
+            27 => ::std::option::Option::Some(ErrorCode::CalendarRangeInvalid),
+            999 => ::std::option::Option::Some(ErrorCode::UserUnauthorized),
+            1000 => ::std::option::Option::Some(ErrorCode::InternalError),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [ErrorCode] = &[
+            ErrorCode::RecordNotFound,
+            ErrorCode::GridNotFound,
+            ErrorCode::FieldNotFound,
+            ErrorCode::RowNotFound,
+            ErrorCode::GroupNotFound,
+            ErrorCode::GridNameInvalid,
+            ErrorCode::FieldNameInvalid,
+            ErrorCode::CellDataInvalid,
+            ErrorCode::FilterInvalid,
+            ErrorCode::LastFieldCanNotBeDeleted,
+            ErrorCode::GroupFieldInvalid,
+            ErrorCode::DateFieldInvalid,
+            ErrorCode::CalendarRangeInvalid,
+            ErrorCode::UserUnauthorized,
+            ErrorCode::InternalError,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<ErrorCode>("ErrorCode", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for ErrorCode {
+}
+
+impl ::std::default::Default for ErrorCode {
+    fn default() -> Self {
+        ErrorCode::RecordNotFound
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ErrorCode {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0cerrors.proto\"C\n\tGridError\x12\x20\n\x04code\x18\x01\x20\x01(\
+    \x0e2\n.ErrorCodeR\x04codeB\0\x12\x12\n\x03msg\x18\x02\x20\x01(\tR\x03ms\
+    gB\0:\0*\xcd\x02\n\tErrorCode\x12\x12\n\x0eRecordNotFound\x10\0\x12\x10\
+    \n\x0cGridNotFound\x10\n\x12\x11\n\rFieldNotFound\x10\x0b\x12\x0f\n\x0bR\
+    owNotFound\x10\x0c\x12\x11\n\rGroupNotFound\x10\r\x12\x13\n\x0fGridNameI\
+    nvalid\x10\x14\x12\x14\n\x10FieldNameInvalid\x10\x15\x12\x13\n\x0fCellDa\
+    taInvalid\x10\x16\x12\x11\n\rFilterInvalid\x10\x17\x12\x1c\n\x18LastFiel\
+    dCanNotBeDeleted\x10\x18\x12\x15\n\x11GroupFieldInvalid\x10\x19\x12\x14\
+    \n\x10DateFieldInvalid\x10\x1a\x12\x18\n\x14CalendarRangeInvalid\x10\x1b\
+    \x12\x15\n\x10UserUnauthorized\x10\xe7\x07\x12\x12\n\rInternalError\x10\
+    \xe8\x07\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,153 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `event.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum GridEvent {
+    CreateGrid = 0,
+    ReadGrid = 1,
+    DeleteGrid = 2,
+    CreateField = 10,
+    UpdateField = 11,
+    SwitchFieldType = 12,
+    DeleteField = 13,
+    ReadFields = 14,
+    CreateRow = 20,
+    DeleteRow = 21,
+    ReadRows = 22,
+    UpdateCell = 30,
+    UpdateSorts = 40,
+    UpdateFilters = 41,
+    ReadBoard = 50,
+    UpdateBoardSetting = 51,
+    MoveCard = 52,
+    ReadCalendar = 60,
+    CloseCalendar = 61,
+    RescheduleRow = 62,
+}
+
+impl ::protobuf::ProtobufEnum for GridEvent {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<GridEvent> {
+        match value {
+            0 => ::std::option::Option::Some(GridEvent::CreateGrid),
+            1 => ::std::option::Option::Some(GridEvent::ReadGrid),
+            2 => ::std::option::Option::Some(GridEvent::DeleteGrid),
+            10 => ::std::option::Option::Some(GridEvent::CreateField),
+            11 => ::std::option::Option::Some(GridEvent::UpdateField),
+            12 => ::std::option::Option::Some(GridEvent::SwitchFieldType),
+            13 => ::std::option::Option::Some(GridEvent::DeleteField),
+            14 => ::std::option::Option::Some(GridEvent::ReadFields),
+            20 => ::std::option::Option::Some(GridEvent::CreateRow),
+            21 => ::std::option::Option::Some(GridEvent::DeleteRow),
+            22 => ::std::option::Option::Some(GridEvent::ReadRows),
+            30 => ::std::option::Option::Some(GridEvent::UpdateCell),
+            40 => ::std::option::Option::Some(GridEvent::UpdateSorts),
+            41 => ::std::option::Option::Some(GridEvent::UpdateFilters),
+            50 => ::std::option::Option::Some(GridEvent::ReadBoard),
+            51 => ::std::option::Option::Some(GridEvent::UpdateBoardSetting),
+            52 => ::std::option::Option::Some(GridEvent::MoveCard),
+            60 => ::std::option::Option::Some(GridEvent::ReadCalendar),
+            61 => ::std::option::Option::Some(GridEvent::CloseCalendar),
+            62 => ::std::option::Option::Some(GridEvent::RescheduleRow),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [GridEvent] = &[
+            GridEvent::CreateGrid,
+            GridEvent::ReadGrid,
+            GridEvent::DeleteGrid,
+            GridEvent::CreateField,
+            GridEvent::UpdateField,
+            GridEvent::SwitchFieldType,
+            GridEvent::DeleteField,
+            GridEvent::ReadFields,
+            GridEvent::CreateRow,
+            GridEvent::DeleteRow,
+            GridEvent::ReadRows,
+            GridEvent::UpdateCell,
+            GridEvent::UpdateSorts,
+            GridEvent::UpdateFilters,
+            GridEvent::ReadBoard,
+            GridEvent::UpdateBoardSetting,
+            GridEvent::MoveCard,
+            GridEvent::ReadCalendar,
+            GridEvent::CloseCalendar,
+            GridEvent::RescheduleRow,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<GridEvent>("GridEvent", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for GridEvent {
+}
+
+impl ::std::default::Default for GridEvent {
+    fn default() -> Self {
+        GridEvent::CreateGrid
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GridEvent {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0bevent.proto*\xe0\x02\n\tGridEvent\x12\x0e\n\nCreateGrid\x10\0\x12\
+    \x0c\n\x08ReadGrid\x10\x01\x12\x0e\n\nDeleteGrid\x10\x02\x12\x0f\n\x0bCr\
+    eateField\x10\n\x12\x0f\n\x0bUpdateField\x10\x0b\x12\x13\n\x0fSwitchFiel\
+    dType\x10\x0c\x12\x0f\n\x0bDeleteField\x10\r\x12\x0e\n\nReadFields\x10\
+    \x0e\x12\r\n\tCreateRow\x10\x14\x12\r\n\tDeleteRow\x10\x15\x12\x0c\n\x08\
+    ReadRows\x10\x16\x12\x0e\n\nUpdateCell\x10\x1e\x12\x0f\n\x0bUpdateSorts\
+    \x10(\x12\x11\n\rUpdateFilters\x10)\x12\r\n\tReadBoard\x102\x12\x16\n\
+    \x12UpdateBoardSetting\x103\x12\x0c\n\x08MoveCard\x104\x12\x10\n\x0cRead\
+    Calendar\x10<\x12\x11\n\rCloseCalendar\x10=\x12\x11\n\rRescheduleRow\x10\
+    >\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,28 @@
+// Auto-generated, do not edit 
+
+mod board; 
+pub use board::*; 
+
+mod calendar; 
+pub use calendar::*; 
+
+mod grid; 
+pub use grid::*; 
+
+mod setting; 
+pub use setting::*; 
+
+mod row; 
+pub use row::*; 
+
+mod observable; 
+pub use observable::*; 
+
+mod errors; 
+pub use errors::*; 
+
+mod event; 
+pub use event::*; 
+
+mod field; 
+pub use field::*; 
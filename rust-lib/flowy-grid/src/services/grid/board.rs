@@ -0,0 +1,89 @@
+use crate::{
+    entities::grid::{BoardGroup, Field, FieldType, Row},
+    errors::{GridError, GridResult},
+    services::grid::cell::selected_option_ids,
+};
+
+pub(crate) fn is_select_field(field: &Field) -> bool {
+    field.field_type == FieldType::SingleSelect || field.field_type == FieldType::MultiSelect
+}
+
+// The field that the board groups by, the first select field of the grid if
+// none was set.
+pub(crate) fn group_field<'a>(fields: &'a [Field], group_field_id: &str) -> GridResult<&'a Field> {
+    let field = match group_field_id.is_empty() {
+        true => fields.iter().find(|field| is_select_field(field)),
+        false => fields.iter().find(|field| field.id == group_field_id),
+    };
+    match field {
+        Some(field) if is_select_field(field) => Ok(field),
+        Some(field) => Err(GridError::group_field_invalid().context(format!("{} is not a select field", field.name))),
+        None if group_field_id.is_empty() => {
+            Err(GridError::group_field_invalid().context("The grid has no select field"))
+        },
+        None => Err(GridError::field_not_found()),
+    }
+}
+
+// The rows keep their order in each group. The cells of the rows are in the
+// order of the fields, index is the one of the field.
+pub(crate) fn group_rows(field: &Field, index: usize, rows: Vec<Row>) -> Vec<BoardGroup> {
+    let mut groups = vec![BoardGroup::default()];
+    groups.extend(field.type_option.options.iter().map(|option| BoardGroup {
+        id: option.id.clone(),
+        name: option.name.clone(),
+        rows: vec![],
+    }));
+    for row in rows {
+        let data = row.cells.get(index).map_or("", |cell| cell.data.as_str());
+        let positions = selected_option_ids(data)
+            .into_iter()
+            .filter_map(|option_id| groups.iter().position(|group| group.id == option_id))
+            .collect::<Vec<_>>();
+        match positions.split_last() {
+            None => groups[0].rows.push(row),
+            Some((last, others)) => {
+                for position in others {
+                    groups[*position].rows.push(row.clone());
+                }
+                groups[*last].rows.push(row);
+            },
+        }
+    }
+    groups
+}
+
+// The data of the cell of a card that's moved from a group to another. The
+// other options of a multi select cell are kept, moving the card to the empty
+// group clears them.
+pub(crate) fn move_card_data(field: &Field, data: &str, from_group_id: &str, to_group_id: &str) -> GridResult<String> {
+    if !to_group_id.is_empty() && !field.type_option.options.iter().any(|option| option.id == to_group_id) {
+        return Err(GridError::group_not_found().context(to_group_id));
+    }
+    let selected = selected_option_ids(data);
+    let in_group = match from_group_id.is_empty() {
+        true => selected.is_empty(),
+        false => selected.contains(&from_group_id),
+    };
+    if !in_group {
+        return Err(GridError::group_not_found().context(format!("The card is not in the group {}", from_group_id)));
+    }
+    if to_group_id.is_empty() || field.field_type == FieldType::SingleSelect {
+        return Ok(to_group_id.to_owned());
+    }
+
+    let mut option_ids = vec![];
+    for option_id in selected {
+        let option_id = match option_id == from_group_id {
+            true => to_group_id,
+            false => option_id,
+        };
+        if !option_ids.contains(&option_id) {
+            option_ids.push(option_id);
+        }
+    }
+    if !option_ids.contains(&to_group_id) {
+        option_ids.push(to_group_id);
+    }
+    Ok(option_ids.join(","))
+}
@@ -0,0 +1,82 @@
+use crate::{
+    entities::grid::{CalendarEvent, CalendarRangeRequest, Field, FieldType, Row},
+    errors::{GridError, GridResult},
+    services::grid::cell::parse_timestamp,
+};
+use parking_lot::RwLock;
+use std::collections::HashMap;
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+// The field that the calendar shows the rows by, the first date field of the
+// grid if none was set.
+pub(crate) fn date_field<'a>(fields: &'a [Field], date_field_id: &str) -> GridResult<&'a Field> {
+    let field = match date_field_id.is_empty() {
+        true => fields.iter().find(|field| field.field_type == FieldType::DateTime),
+        false => fields.iter().find(|field| field.id == date_field_id),
+    };
+    match field {
+        Some(field) if field.field_type == FieldType::DateTime => Ok(field),
+        Some(field) => Err(GridError::date_field_invalid().context(format!("{} is not a date field", field.name))),
+        None if date_field_id.is_empty() => Err(GridError::date_field_invalid().context("The grid has no date field")),
+        None => Err(GridError::field_not_found()),
+    }
+}
+
+// The rows with a date in the range, the cells of the rows are in the order of
+// the fields, index is the one of the field.
+pub(crate) fn calendar_events(index: usize, rows: Vec<Row>, start: i64, end: i64) -> Vec<CalendarEvent> {
+    let mut items = rows
+        .into_iter()
+        .filter_map(|row| {
+            let timestamp = row.cells.get(index).and_then(|cell| parse_timestamp(&cell.data).ok())?;
+            match timestamp >= start && timestamp < end {
+                true => Some(CalendarEvent { timestamp, row }),
+                false => None,
+            }
+        })
+        .collect::<Vec<_>>();
+    items.sort_by_key(|item| item.timestamp);
+    items
+}
+
+// The timestamp of the day the row is moved to, with the time of the cell if
+// the field includes the time. The days are the ones of UTC like the cells.
+pub(crate) fn reschedule_timestamp(data: &str, include_time: bool, timestamp: i64) -> i64 {
+    let day = timestamp - timestamp.rem_euclid(SECONDS_PER_DAY);
+    match parse_timestamp(data) {
+        Ok(old) if include_time => day + old.rem_euclid(SECONDS_PER_DAY),
+        _ => day,
+    }
+}
+
+// The ranges that the calendars of the grids show, with the events that were
+// sent last, so the calendars are only notified when their events change.
+#[derive(Default)]
+pub(crate) struct VisibleCalendars {
+    inner: RwLock<HashMap<String, (CalendarRangeRequest, Vec<CalendarEvent>)>>,
+}
+
+impl VisibleCalendars {
+    pub(crate) fn range(&self, grid_id: &str) -> Option<CalendarRangeRequest> {
+        self.inner.read().get(grid_id).map(|(range, _)| range.clone())
+    }
+
+    pub(crate) fn show(&self, range: CalendarRangeRequest, items: Vec<CalendarEvent>) {
+        self.inner.write().insert(range.grid_id.clone(), (range, items));
+    }
+
+    // Returns if the events are different from the ones that were sent, they're
+    // kept if they are.
+    pub(crate) fn update(&self, grid_id: &str, items: &[CalendarEvent]) -> bool {
+        match self.inner.write().get_mut(grid_id) {
+            Some((_, sent)) if sent.as_slice() != items => {
+                *sent = items.to_vec();
+                true
+            },
+            _ => false,
+        }
+    }
+
+    pub(crate) fn close(&self, grid_id: &str) { self.inner.write().remove(grid_id); }
+}
@@ -0,0 +1,148 @@
+use crate::{
+    entities::grid::{FieldType, FieldTypeOption, SelectOption},
+    errors::{GridError, GridResult},
+};
+use chrono::{NaiveDate, NaiveDateTime};
+
+pub(crate) const CHECKED: &str = "Yes";
+const OPTION_SEPARATOR: char = ',';
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const DATE_TIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+// Checks the data of a cell against the type of its field and returns the
+// data that's stored, empty if the cell is cleared. The options of a select
+// field are matched by their id or their name, the ones that don't exist are
+// created if create_options is set.
+pub(crate) fn parse_cell_data(
+    field_type: FieldType,
+    type_option: &mut FieldTypeOption,
+    data: &str,
+    create_options: bool,
+) -> GridResult<String> {
+    let data = data.trim();
+    if data.is_empty() {
+        return Ok("".to_owned());
+    }
+    match field_type {
+        FieldType::RichText => Ok(data.to_owned()),
+        FieldType::Number => parse_number(data).map(|number| number.to_string()),
+        FieldType::DateTime => parse_timestamp(data).map(|timestamp| timestamp.to_string()),
+        FieldType::Checkbox => match parse_checkbox(data)? {
+            true => Ok(CHECKED.to_owned()),
+            false => Ok("".to_owned()),
+        },
+        FieldType::SingleSelect | FieldType::MultiSelect => {
+            let mut option_ids: Vec<String> = vec![];
+            for name in data.split(OPTION_SEPARATOR).map(|name| name.trim()) {
+                if name.is_empty() {
+                    continue;
+                }
+                let option_id = match find_option(&type_option.options, name) {
+                    Some(option) => option.id.clone(),
+                    None if create_options => {
+                        let option = SelectOption {
+                            id: flowy_infra::uuid(),
+                            name: name.to_owned(),
+                            color: "".to_owned(),
+                        };
+                        type_option.options.push(option.clone());
+                        option.id
+                    },
+                    None => return Err(GridError::cell_data_invalid().context(format!("No option {}", name))),
+                };
+                if !option_ids.contains(&option_id) {
+                    option_ids.push(option_id);
+                }
+            }
+            if field_type == FieldType::SingleSelect && option_ids.len() > 1 {
+                return Err(GridError::cell_data_invalid().context("A single select cell has one option"));
+            }
+            Ok(option_ids.join(&OPTION_SEPARATOR.to_string()))
+        },
+    }
+}
+
+// The text that a cell shows, the one it's converted from when the type of its
+// field changes.
+pub(crate) fn cell_text(field_type: FieldType, type_option: &FieldTypeOption, data: &str) -> String {
+    match field_type {
+        FieldType::SingleSelect | FieldType::MultiSelect => selected_options(type_option, data)
+            .into_iter()
+            .map(|option| option.name.clone())
+            .collect::<Vec<_>>()
+            .join(", "),
+        FieldType::DateTime => match data
+            .parse::<i64>()
+            .ok()
+            .and_then(|timestamp| NaiveDateTime::from_timestamp_opt(timestamp, 0))
+        {
+            Some(date) if type_option.include_time => date.format(DATE_TIME_FORMAT).to_string(),
+            Some(date) => date.format(DATE_FORMAT).to_string(),
+            None => "".to_owned(),
+        },
+        _ => data.to_owned(),
+    }
+}
+
+// The data of a cell converted to another type of field, empty if it can't be.
+pub(crate) fn convert_cell_data(
+    from_type: FieldType,
+    from_option: &FieldTypeOption,
+    to_type: FieldType,
+    to_option: &mut FieldTypeOption,
+    data: &str,
+) -> String {
+    let text = cell_text(from_type, from_option, data);
+    parse_cell_data(to_type, to_option, &text, true).unwrap_or_default()
+}
+
+// The selected options that still exist, in the order of the cell.
+pub(crate) fn selected_options<'a>(type_option: &'a FieldTypeOption, data: &str) -> Vec<&'a SelectOption> {
+    data.split(OPTION_SEPARATOR)
+        .filter_map(|option_id| type_option.options.iter().find(|option| option.id == option_id))
+        .collect()
+}
+
+pub(crate) fn selected_option_ids(data: &str) -> Vec<&str> {
+    data.split(OPTION_SEPARATOR)
+        .filter(|option_id| !option_id.is_empty())
+        .collect()
+}
+
+pub(crate) fn parse_number(data: &str) -> GridResult<f64> {
+    let text = data.replace(',', "").replace(' ', "");
+    match text.parse::<f64>() {
+        Ok(number) if number.is_finite() => Ok(number),
+        _ => Err(GridError::cell_data_invalid().context(format!("{} is not a number", data))),
+    }
+}
+
+// The seconds of the timestamp, or of a date like 2021-11-14 or 2021-11-14
+// 09:30 in UTC.
+pub(crate) fn parse_timestamp(data: &str) -> GridResult<i64> {
+    if let Ok(timestamp) = data.parse::<i64>() {
+        return Ok(timestamp);
+    }
+    if let Ok(date_time) = NaiveDateTime::parse_from_str(data, DATE_TIME_FORMAT) {
+        return Ok(date_time.timestamp());
+    }
+    match NaiveDate::parse_from_str(data, DATE_FORMAT) {
+        Ok(date) => Ok(date.and_hms(0, 0, 0).timestamp()),
+        Err(_) => Err(GridError::cell_data_invalid().context(format!("{} is not a date", data))),
+    }
+}
+
+pub(crate) fn parse_checkbox(data: &str) -> GridResult<bool> {
+    match data.to_lowercase().as_str() {
+        "yes" | "true" | "1" => Ok(true),
+        "no" | "false" | "0" => Ok(false),
+        _ => Err(GridError::cell_data_invalid().context(format!("{} is not a checkbox", data))),
+    }
+}
+
+fn find_option<'a>(options: &'a [SelectOption], name: &str) -> Option<&'a SelectOption> {
+    options
+        .iter()
+        .find(|option| option.id == name)
+        .or_else(|| options.iter().find(|option| option.name.eq_ignore_ascii_case(name)))
+}
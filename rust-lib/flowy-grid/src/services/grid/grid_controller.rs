@@ -0,0 +1,835 @@
+use crate::{
+    entities::grid::*,
+    errors::{internal_error, GridError, GridResult},
+    module::GridUser,
+    notify::{dart_notify, GridObservable},
+    services::grid::{
+        board::{group_field, group_rows, is_select_field, move_card_data},
+        calendar::{calendar_events, date_field, reschedule_timestamp, VisibleCalendars},
+        cell::{convert_cell_data, parse_cell_data, selected_option_ids},
+        import::{import_table, ImportGridParams, ImportGridResult},
+        setting::{apply_setting, check_filter},
+    },
+    sql_tables::grid::*,
+};
+use flowy_database::SqliteConnection;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+const DEFAULT_FIELD_NAME: &str = "Name";
+
+// The grids are kept in the grid tables: a row of the grid table for each
+// grid, with its sorts and filters, and the fields, the rows and the cells in
+// theirs. The rows are read whole and sorted and filtered in memory.
+pub struct GridController {
+    user: Arc<dyn GridUser>,
+    grid_sql: GridTableSql,
+    field_sql: GridFieldTableSql,
+    row_sql: GridRowTableSql,
+    cell_sql: GridCellTableSql,
+    calendars: VisibleCalendars,
+}
+
+impl GridController {
+    pub(crate) fn new(user: Arc<dyn GridUser>) -> Self {
+        Self {
+            user,
+            grid_sql: GridTableSql {},
+            field_sql: GridFieldTableSql {},
+            row_sql: GridRowTableSql {},
+            cell_sql: GridCellTableSql {},
+            calendars: VisibleCalendars::default(),
+        }
+    }
+
+    pub(crate) fn create_grid(&self, params: CreateGridRequest) -> GridResult<Grid> {
+        let name = params.name.trim();
+        if name.is_empty() {
+            return Err(GridError::grid_name_invalid());
+        }
+        let grid_table = GridTable::new(name);
+        let grid_id = grid_table.id.clone();
+        let field_table = GridFieldTable::new(&grid_id, DEFAULT_FIELD_NAME, FieldType::RichText, 0, 0);
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let _ = conn.immediate_transaction::<_, GridError, _>(|| {
+            let _ = self.grid_sql.create_grid_table(grid_table, conn)?;
+            let _ = self.field_sql.create_field_table(field_table, conn)?;
+            Ok(())
+        })?;
+        self.read_grid_with_conn(&grid_id, conn)
+    }
+
+    // Creates the grid from the table, each column becomes a field of the type
+    // that its values fit in. The values that don't fit are left empty and
+    // returned with the grid.
+    pub fn import_grid(&self, params: ImportGridParams) -> GridResult<ImportGridResult> {
+        let name = params.name.trim();
+        if name.is_empty() {
+            return Err(GridError::grid_name_invalid());
+        }
+        let mut grid_table = GridTable::new(name);
+        if !params.grid_id.is_empty() {
+            grid_table.id = params.grid_id.clone();
+        }
+        let grid_id = grid_table.id.clone();
+        let columns = match params.columns.is_empty() {
+            true => vec![DEFAULT_FIELD_NAME.to_owned()],
+            false => params.columns,
+        };
+        let (fields, cells, invalid_cells) = import_table(&columns, &params.rows);
+
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let _ = conn.immediate_transaction::<_, GridError, _>(|| {
+            let _ = self.grid_sql.create_grid_table(grid_table, conn)?;
+            let mut field_ids = vec![];
+            for (position, field) in fields.into_iter().enumerate() {
+                let mut table = GridFieldTable::new(&grid_id, &field.name, field.field_type, 0, position as i64);
+                table.type_option = field.type_option.into();
+                field_ids.push(table.id.clone());
+                let _ = self.field_sql.create_field_table(table, conn)?;
+            }
+            for (position, row_cells) in cells.into_iter().enumerate() {
+                let table = GridRowTable::new(&grid_id, position as i64);
+                let row_id = table.id.clone();
+                let _ = self.row_sql.create_row_table(table, conn)?;
+                for (field_id, data) in field_ids.iter().zip(row_cells) {
+                    if !data.is_empty() {
+                        let _ = self
+                            .cell_sql
+                            .set_cell_table(GridCellTable::new(&row_id, field_id, &data), conn)?;
+                    }
+                }
+            }
+            Ok(())
+        })?;
+        let grid = self.read_grid_with_conn(&grid_id, conn)?;
+        Ok(ImportGridResult { grid, invalid_cells })
+    }
+
+    pub(crate) fn read_grid(&self, grid_id: &str) -> GridResult<Grid> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        self.read_grid_with_conn(grid_id, conn)
+    }
+
+    pub(crate) fn delete_grid(&self, grid_id: &str) -> GridResult<()> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let _ = conn.immediate_transaction::<_, GridError, _>(|| {
+            let _ = self.read_grid_table(grid_id, conn)?;
+            self.grid_sql.delete_grid_table(grid_id, conn)
+        })?;
+        self.calendars.close(grid_id);
+        Ok(())
+    }
+
+    pub(crate) fn create_field(&self, params: CreateFieldRequest) -> GridResult<Field> {
+        let name = params.name.trim();
+        if name.is_empty() {
+            return Err(GridError::field_name_invalid());
+        }
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let field = conn.immediate_transaction::<_, GridError, _>(|| {
+            let _ = self.read_grid_table(&params.grid_id, conn)?;
+            let position = self
+                .field_sql
+                .read_field_tables(&params.grid_id, conn)?
+                .last()
+                .map_or(0, |table| table.position + 1);
+            let mut table = GridFieldTable::new(&params.grid_id, name, params.field_type, params.width, position);
+            table.type_option = with_option_ids(params.type_option).into();
+            let _ = self.field_sql.create_field_table(table.clone(), conn)?;
+            Ok(Field::from(table))
+        })?;
+        let _ = self.notify_fields_changed(&field.grid_id, conn)?;
+        Ok(field)
+    }
+
+    pub(crate) fn update_field(&self, params: UpdateFieldRequest) -> GridResult<Field> {
+        let name = match params.name {
+            Some(ref name) if name.trim().is_empty() => return Err(GridError::field_name_invalid()),
+            Some(ref name) => Some(name.trim().to_owned()),
+            None => None,
+        };
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let (field, cells_changed) = conn.immediate_transaction::<_, GridError, _>(|| {
+            let field = Field::from(self.read_field_table(&params.field_id, conn)?);
+            let type_option = params.type_option.clone().map(with_option_ids);
+            let mut cells_changed = false;
+            if let Some(ref type_option) = type_option {
+                cells_changed = self.remove_deleted_options(&field, type_option, conn)?;
+            }
+            let changeset = GridFieldTableChangeset {
+                id: field.id.clone(),
+                name,
+                width: params.width.filter(|width| *width > 0),
+                type_option: type_option.map(|type_option| type_option.into()),
+                ..Default::default()
+            };
+            let _ = self.field_sql.update_field_table(changeset, conn)?;
+            let field = Field::from(self.read_field_table(&params.field_id, conn)?);
+            Ok((field, cells_changed))
+        })?;
+        let _ = self.notify_fields_changed(&field.grid_id, conn)?;
+        if cells_changed {
+            let _ = self.notify_rows_changed(&field.grid_id, conn)?;
+        }
+        Ok(field)
+    }
+
+    pub(crate) fn switch_field_type(&self, params: SwitchFieldTypeRequest) -> GridResult<Field> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let field = conn.immediate_transaction::<_, GridError, _>(|| {
+            let field = Field::from(self.read_field_table(&params.field_id, conn)?);
+            let mut type_option = with_option_ids(params.type_option.clone());
+            for cell in self.cell_sql.read_field_cell_tables(&field.id, conn)? {
+                let data = convert_cell_data(
+                    field.field_type,
+                    &field.type_option,
+                    params.field_type,
+                    &mut type_option,
+                    &cell.data,
+                );
+                let _ = self
+                    .cell_sql
+                    .set_cell_table(GridCellTable::new(&cell.row_id, &cell.field_id, &data), conn)?;
+            }
+            let changeset = GridFieldTableChangeset {
+                id: field.id.clone(),
+                field_type: Some(params.field_type.into()),
+                type_option: Some(type_option.into()),
+                ..Default::default()
+            };
+            let _ = self.field_sql.update_field_table(changeset, conn)?;
+            let field = Field::from(self.read_field_table(&params.field_id, conn)?);
+
+            // The filters that don't apply to the new type are dropped.
+            let grid_table = self.read_grid_table(&field.grid_id, conn)?;
+            let mut setting = grid_table.setting.clone();
+            setting.filters.retain(|filter| {
+                filter.field_id != field.id || check_filter(&GridFilter::from(filter.clone()), &field).is_ok()
+            });
+            if setting != grid_table.setting {
+                let _ = self.update_setting(&grid_table.id, setting, conn)?;
+            }
+            // The board groups by the first select field again.
+            if grid_table.board_setting.group_field_id == field.id && !is_select_field(&field) {
+                let _ = self.update_board_setting_col(&grid_table.id, BoardSettingCol::default(), conn)?;
+            }
+            Ok(field)
+        })?;
+        let _ = self.notify_fields_changed(&field.grid_id, conn)?;
+        let _ = self.notify_rows_changed(&field.grid_id, conn)?;
+        Ok(field)
+    }
+
+    pub(crate) fn delete_field(&self, field_id: &str) -> GridResult<()> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let grid_id = conn.immediate_transaction::<_, GridError, _>(|| {
+            let field = self.read_field_table(field_id, conn)?;
+            if self.field_sql.read_field_tables(&field.grid_id, conn)?.len() <= 1 {
+                return Err(GridError::last_field());
+            }
+            let _ = self.field_sql.delete_field_table(field_id, conn)?;
+
+            let grid_table = self.read_grid_table(&field.grid_id, conn)?;
+            let mut setting = grid_table.setting.clone();
+            setting.sorts.retain(|sort| sort.field_id != field_id);
+            setting.filters.retain(|filter| filter.field_id != field_id);
+            if setting != grid_table.setting {
+                let _ = self.update_setting(&grid_table.id, setting, conn)?;
+            }
+            if grid_table.board_setting.group_field_id == field_id {
+                let _ = self.update_board_setting_col(&grid_table.id, BoardSettingCol::default(), conn)?;
+            }
+            Ok(field.grid_id)
+        })?;
+        let _ = self.notify_fields_changed(&grid_id, conn)?;
+        let _ = self.notify_rows_changed(&grid_id, conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_fields(&self, grid_id: &str) -> GridResult<RepeatedField> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        let _ = self.read_grid_table(grid_id, conn)?;
+        Ok(RepeatedField {
+            items: self.read_field_items(grid_id, conn)?,
+        })
+    }
+
+    pub(crate) fn create_row(&self, params: CreateRowRequest) -> GridResult<Row> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let row = conn.immediate_transaction::<_, GridError, _>(|| {
+            let _ = self.read_grid_table(&params.grid_id, conn)?;
+            let position = match params.start_row_id {
+                Some(ref start_row_id) => {
+                    let start_row = self.read_row_table(start_row_id, conn)?;
+                    if start_row.grid_id != params.grid_id {
+                        return Err(GridError::row_not_found());
+                    }
+                    let _ = self
+                        .row_sql
+                        .shift_row_tables(&params.grid_id, start_row.position + 1, conn)?;
+                    start_row.position + 1
+                },
+                None => self
+                    .row_sql
+                    .read_row_tables(&params.grid_id, conn)?
+                    .last()
+                    .map_or(0, |table| table.position + 1),
+            };
+            let table = GridRowTable::new(&params.grid_id, position);
+            let _ = self.row_sql.create_row_table(table.clone(), conn)?;
+            let cells = self
+                .field_sql
+                .read_field_tables(&params.grid_id, conn)?
+                .into_iter()
+                .map(|field| Cell {
+                    field_id: field.id,
+                    data: "".to_owned(),
+                })
+                .collect();
+            Ok(Row {
+                id: table.id,
+                grid_id: table.grid_id,
+                cells,
+            })
+        })?;
+        let _ = self.notify_rows_changed(&row.grid_id, conn)?;
+        Ok(row)
+    }
+
+    pub(crate) fn delete_row(&self, row_id: &str) -> GridResult<()> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let grid_id = conn.immediate_transaction::<_, GridError, _>(|| {
+            let row = self.read_row_table(row_id, conn)?;
+            let _ = self.row_sql.delete_row_table(row_id, conn)?;
+            Ok(row.grid_id)
+        })?;
+        self.notify_rows_changed(&grid_id, conn)
+    }
+
+    pub(crate) fn read_rows(&self, grid_id: &str) -> GridResult<RepeatedRow> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        let grid_table = self.read_grid_table(grid_id, conn)?;
+        Ok(RepeatedRow {
+            items: self.read_visible_rows(&grid_table, conn)?,
+        })
+    }
+
+    pub(crate) fn update_cell(&self, changeset: CellChangeset) -> GridResult<Cell> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let (grid_table, cell) = conn.immediate_transaction::<_, GridError, _>(|| {
+            let row = self.read_row_table(&changeset.row_id, conn)?;
+            let mut field = Field::from(self.read_field_table(&changeset.field_id, conn)?);
+            if field.grid_id != row.grid_id {
+                return Err(GridError::field_not_found());
+            }
+            let data = parse_cell_data(field.field_type, &mut field.type_option, &changeset.data, false)?;
+            let table = GridCellTable::new(&row.id, &field.id, &data);
+            let _ = self.cell_sql.set_cell_table(table, conn)?;
+            let grid_table = self.read_grid_table(&row.grid_id, conn)?;
+            Ok((
+                grid_table,
+                Cell {
+                    field_id: field.id,
+                    data,
+                },
+            ))
+        })?;
+        dart_notify(&changeset.row_id, GridObservable::CellUpdated)
+            .payload(cell.clone())
+            .send();
+        // The cell may move the row, or hide it.
+        if !grid_table.setting.sorts.is_empty() || !grid_table.setting.filters.is_empty() {
+            let _ = self.notify_rows_changed(&grid_table.id, conn)?;
+        } else {
+            self.notify_calendar_changed(&grid_table.id, conn);
+        }
+        Ok(cell)
+    }
+
+    pub(crate) fn update_sorts(&self, params: UpdateGridSortsRequest) -> GridResult<RepeatedRow> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let _ = conn.immediate_transaction::<_, GridError, _>(|| {
+            let grid_table = self.read_grid_table(&params.grid_id, conn)?;
+            let field_ids = self.read_field_ids(&params.grid_id, conn)?;
+            let mut sorted_ids = HashSet::new();
+            let mut sorts = vec![];
+            for sort in params.items.clone() {
+                if !field_ids.contains(&sort.field_id) {
+                    return Err(GridError::field_not_found().context(sort.field_id));
+                }
+                // The first sort of a field is the one that counts.
+                if sorted_ids.insert(sort.field_id.clone()) {
+                    sorts.push(SortCol::from(sort));
+                }
+            }
+            let mut setting = grid_table.setting;
+            setting.sorts = sorts;
+            self.update_setting(&params.grid_id, setting, conn)
+        })?;
+        self.notify_rows_changed(&params.grid_id, conn)
+    }
+
+    pub(crate) fn update_filters(&self, params: UpdateGridFiltersRequest) -> GridResult<RepeatedRow> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let _ = conn.immediate_transaction::<_, GridError, _>(|| {
+            let grid_table = self.read_grid_table(&params.grid_id, conn)?;
+            let fields = self.read_field_items(&params.grid_id, conn)?;
+            let mut filters = vec![];
+            for mut filter in params.items.clone() {
+                let field = match fields.iter().find(|field| field.id == filter.field_id) {
+                    Some(field) => field,
+                    None => return Err(GridError::field_not_found().context(filter.field_id)),
+                };
+                let _ = check_filter(&filter, field)?;
+                // The options of a select filter are matched by their id or their name
+                // like the cells, they're saved as their ids.
+                if is_select_field(field) {
+                    let mut type_option = field.type_option.clone();
+                    filter.value = parse_cell_data(FieldType::MultiSelect, &mut type_option, &filter.value, false)
+                        .map_err(|e| GridError::filter_invalid().context(e.msg))?;
+                }
+                filters.push(FilterCol::from(filter));
+            }
+            let mut setting = grid_table.setting;
+            setting.filters = filters;
+            self.update_setting(&params.grid_id, setting, conn)
+        })?;
+        self.notify_rows_changed(&params.grid_id, conn)
+    }
+
+    pub(crate) fn read_board(&self, grid_id: &str) -> GridResult<Board> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        self.read_board_with_conn(grid_id, conn)
+    }
+
+    pub(crate) fn update_board_setting(&self, params: UpdateBoardSettingRequest) -> GridResult<Board> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let _ = conn.immediate_transaction::<_, GridError, _>(|| {
+            let _ = self.read_grid_table(&params.grid_id, conn)?;
+            let fields = self.read_field_items(&params.grid_id, conn)?;
+            let _ = group_field(&fields, &params.group_field_id)?;
+            let setting = BoardSettingCol {
+                group_field_id: params.group_field_id.clone(),
+            };
+            self.update_board_setting_col(&params.grid_id, setting, conn)
+        })?;
+        self.notify_board_changed(&params.grid_id, conn)
+    }
+
+    // The cell of the card and its position are changed in one transaction, the
+    // card is never in both groups or in none.
+    pub(crate) fn move_card(&self, params: MoveCardRequest) -> GridResult<Board> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let cell = conn.immediate_transaction::<_, GridError, _>(|| {
+            let grid_table = self.read_grid_table(&params.grid_id, conn)?;
+            let fields = self.read_field_items(&params.grid_id, conn)?;
+            let field = group_field(&fields, &grid_table.board_setting.group_field_id)?;
+            let row = self.read_row_table(&params.row_id, conn)?;
+            if row.grid_id != params.grid_id {
+                return Err(GridError::row_not_found());
+            }
+            let data = self.cell_sql.read_cell_data(&row.id, &field.id, conn)?;
+            let data = move_card_data(field, &data, &params.from_group_id, &params.to_group_id)?;
+            let _ = self
+                .cell_sql
+                .set_cell_table(GridCellTable::new(&row.id, &field.id, &data), conn)?;
+
+            let position = match params.to_row_id {
+                Some(ref to_row_id) if to_row_id == &row.id => None,
+                Some(ref to_row_id) => {
+                    let to_row = self.read_row_table(to_row_id, conn)?;
+                    if to_row.grid_id != params.grid_id {
+                        return Err(GridError::row_not_found());
+                    }
+                    Some(to_row.position)
+                },
+                // After the last card of the group, the card stays where it is if it's
+                // the only one.
+                None => {
+                    let index = fields.iter().position(|other| other.id == field.id).unwrap_or(0);
+                    let rows = self.read_rows_of_fields(&params.grid_id, &fields, conn)?;
+                    let last_row_id = group_rows(field, index, rows)
+                        .into_iter()
+                        .find(|group| group.id == params.to_group_id)
+                        .and_then(|group| group.rows.into_iter().filter(|other| other.id != row.id).last())
+                        .map(|other| other.id);
+                    match last_row_id {
+                        Some(last_row_id) => Some(self.read_row_table(&last_row_id, conn)?.position + 1),
+                        None => None,
+                    }
+                },
+            };
+            if let Some(position) = position {
+                let _ = self.row_sql.shift_row_tables(&params.grid_id, position, conn)?;
+                let _ = self.row_sql.update_row_position(&row.id, position, conn)?;
+            }
+            Ok(Cell {
+                field_id: field.id.clone(),
+                data,
+            })
+        })?;
+        dart_notify(&params.row_id, GridObservable::CellUpdated)
+            .payload(cell)
+            .send();
+        let _ = self.notify_rows_changed(&params.grid_id, conn)?;
+        self.notify_board_changed(&params.grid_id, conn)
+    }
+
+    // The range is kept until the calendar is closed, the calendar is notified
+    // when the rows in it change.
+    pub(crate) fn read_calendar(&self, params: CalendarRangeRequest) -> GridResult<Calendar> {
+        if params.end <= params.start {
+            return Err(GridError::calendar_range_invalid());
+        }
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        let calendar = self.read_calendar_with_conn(&params, conn)?;
+        self.calendars.show(params, calendar.items.clone());
+        Ok(calendar)
+    }
+
+    pub(crate) fn close_calendar(&self, grid_id: &str) { self.calendars.close(grid_id) }
+
+    pub(crate) fn reschedule_row(&self, params: RescheduleRowRequest) -> GridResult<Cell> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let (grid_table, cell) = conn.immediate_transaction::<_, GridError, _>(|| {
+            let row = self.read_row_table(&params.row_id, conn)?;
+            let fields = self.read_field_items(&row.grid_id, conn)?;
+            let field = date_field(&fields, &params.date_field_id)?;
+            let data = self.cell_sql.read_cell_data(&row.id, &field.id, conn)?;
+            let data = reschedule_timestamp(&data, field.type_option.include_time, params.timestamp).to_string();
+            let _ = self
+                .cell_sql
+                .set_cell_table(GridCellTable::new(&row.id, &field.id, &data), conn)?;
+            let grid_table = self.read_grid_table(&row.grid_id, conn)?;
+            Ok((
+                grid_table,
+                Cell {
+                    field_id: field.id.clone(),
+                    data,
+                },
+            ))
+        })?;
+        dart_notify(&params.row_id, GridObservable::CellUpdated)
+            .payload(cell.clone())
+            .send();
+        if !grid_table.setting.sorts.is_empty() || !grid_table.setting.filters.is_empty() {
+            let _ = self.notify_rows_changed(&grid_table.id, conn)?;
+        } else {
+            self.notify_calendar_changed(&grid_table.id, conn);
+        }
+        Ok(cell)
+    }
+
+    fn read_grid_with_conn(&self, grid_id: &str, conn: &SqliteConnection) -> GridResult<Grid> {
+        let grid_table = self.read_grid_table(grid_id, conn)?;
+        let fields = self.read_field_items(grid_id, conn)?;
+        let rows = self.read_rows_of_fields(grid_id, &fields, conn)?;
+        let sorts = grid_table
+            .setting
+            .sorts
+            .into_iter()
+            .map(GridSort::from)
+            .collect::<Vec<_>>();
+        let filters = grid_table
+            .setting
+            .filters
+            .into_iter()
+            .map(GridFilter::from)
+            .collect::<Vec<_>>();
+        let rows = apply_setting(&fields, rows, &sorts, &filters);
+        Ok(Grid {
+            id: grid_table.id,
+            name: grid_table.name,
+            fields,
+            rows,
+            sorts,
+            filters,
+        })
+    }
+
+    fn read_visible_rows(&self, grid_table: &GridTable, conn: &SqliteConnection) -> GridResult<Vec<Row>> {
+        let fields = self.read_field_items(&grid_table.id, conn)?;
+        let rows = self.read_rows_of_fields(&grid_table.id, &fields, conn)?;
+        let sorts = grid_table
+            .setting
+            .sorts
+            .iter()
+            .cloned()
+            .map(GridSort::from)
+            .collect::<Vec<_>>();
+        let filters = grid_table
+            .setting
+            .filters
+            .iter()
+            .cloned()
+            .map(GridFilter::from)
+            .collect::<Vec<_>>();
+        Ok(apply_setting(&fields, rows, &sorts, &filters))
+    }
+
+    // The rows in the order they were inserted in, with a cell for each field.
+    fn read_rows_of_fields(&self, grid_id: &str, fields: &[Field], conn: &SqliteConnection) -> GridResult<Vec<Row>> {
+        let mut cells: HashMap<(String, String), String> = HashMap::new();
+        for cell in self.cell_sql.read_grid_cell_tables(grid_id, conn)? {
+            cells.insert((cell.row_id, cell.field_id), cell.data);
+        }
+        let rows = self
+            .row_sql
+            .read_row_tables(grid_id, conn)?
+            .into_iter()
+            .map(|row| {
+                let cells = fields
+                    .iter()
+                    .map(|field| Cell {
+                        field_id: field.id.clone(),
+                        data: cells.remove(&(row.id.clone(), field.id.clone())).unwrap_or_default(),
+                    })
+                    .collect();
+                Row {
+                    id: row.id,
+                    grid_id: row.grid_id,
+                    cells,
+                }
+            })
+            .collect();
+        Ok(rows)
+    }
+
+    fn read_field_items(&self, grid_id: &str, conn: &SqliteConnection) -> GridResult<Vec<Field>> {
+        let fields = self
+            .field_sql
+            .read_field_tables(grid_id, conn)?
+            .into_iter()
+            .map(Field::from)
+            .collect();
+        Ok(fields)
+    }
+
+    fn read_field_ids(&self, grid_id: &str, conn: &SqliteConnection) -> GridResult<HashSet<String>> {
+        let field_ids = self
+            .field_sql
+            .read_field_tables(grid_id, conn)?
+            .into_iter()
+            .map(|table| table.id)
+            .collect();
+        Ok(field_ids)
+    }
+
+    // Removes the options that the type option doesn't have anymore from the
+    // cells of the select field, returns if a cell changed.
+    fn remove_deleted_options(
+        &self,
+        field: &Field,
+        type_option: &FieldTypeOption,
+        conn: &SqliteConnection,
+    ) -> GridResult<bool> {
+        if !is_select_field(field) {
+            return Ok(false);
+        }
+        let option_ids = type_option
+            .options
+            .iter()
+            .map(|option| option.id.as_str())
+            .collect::<HashSet<_>>();
+        let mut changed = false;
+        for cell in self.cell_sql.read_field_cell_tables(&field.id, conn)? {
+            let selected = selected_option_ids(&cell.data);
+            let kept = selected
+                .iter()
+                .filter(|option_id| option_ids.contains(*option_id))
+                .cloned()
+                .collect::<Vec<_>>();
+            if kept.len() != selected.len() {
+                let data = kept.join(",");
+                let _ = self
+                    .cell_sql
+                    .set_cell_table(GridCellTable::new(&cell.row_id, &cell.field_id, &data), conn)?;
+                changed = true;
+            }
+        }
+        Ok(changed)
+    }
+
+    fn update_setting(&self, grid_id: &str, setting: GridSettingCol, conn: &SqliteConnection) -> GridResult<()> {
+        let changeset = GridTableChangeset {
+            id: grid_id.to_owned(),
+            setting: Some(setting),
+            modified_time: Some(flowy_infra::timestamp()),
+            ..Default::default()
+        };
+        self.grid_sql.update_grid_table(changeset, conn)
+    }
+
+    // The board shows the rows that pass the filters of the grid, in the order
+    // of the cards instead of the one of the sorts.
+    fn read_board_with_conn(&self, grid_id: &str, conn: &SqliteConnection) -> GridResult<Board> {
+        let grid_table = self.read_grid_table(grid_id, conn)?;
+        let fields = self.read_field_items(grid_id, conn)?;
+        let field = group_field(&fields, &grid_table.board_setting.group_field_id)?;
+        let index = fields.iter().position(|other| other.id == field.id).unwrap_or(0);
+        let filters = grid_table
+            .setting
+            .filters
+            .iter()
+            .cloned()
+            .map(GridFilter::from)
+            .collect::<Vec<_>>();
+        let rows = self.read_rows_of_fields(grid_id, &fields, conn)?;
+        let rows = apply_setting(&fields, rows, &[], &filters);
+        Ok(Board {
+            grid_id: grid_id.to_owned(),
+            group_field_id: field.id.clone(),
+            groups: group_rows(field, index, rows),
+        })
+    }
+
+    // The calendar shows the rows that pass the filters of the grid.
+    fn read_calendar_with_conn(&self, params: &CalendarRangeRequest, conn: &SqliteConnection) -> GridResult<Calendar> {
+        let grid_table = self.read_grid_table(&params.grid_id, conn)?;
+        let fields = self.read_field_items(&params.grid_id, conn)?;
+        let field = date_field(&fields, &params.date_field_id)?;
+        let index = fields.iter().position(|other| other.id == field.id).unwrap_or(0);
+        let filters = grid_table
+            .setting
+            .filters
+            .iter()
+            .cloned()
+            .map(GridFilter::from)
+            .collect::<Vec<_>>();
+        let rows = self.read_rows_of_fields(&params.grid_id, &fields, conn)?;
+        let rows = apply_setting(&fields, rows, &[], &filters);
+        Ok(Calendar {
+            grid_id: params.grid_id.clone(),
+            date_field_id: field.id.clone(),
+            start: params.start,
+            end: params.end,
+            items: calendar_events(index, rows, params.start, params.end),
+        })
+    }
+
+    fn update_board_setting_col(
+        &self,
+        grid_id: &str,
+        board_setting: BoardSettingCol,
+        conn: &SqliteConnection,
+    ) -> GridResult<()> {
+        let changeset = GridTableChangeset {
+            id: grid_id.to_owned(),
+            board_setting: Some(board_setting),
+            modified_time: Some(flowy_infra::timestamp()),
+            ..Default::default()
+        };
+        self.grid_sql.update_grid_table(changeset, conn)
+    }
+
+    fn read_grid_table(&self, grid_id: &str, conn: &SqliteConnection) -> GridResult<GridTable> {
+        self.grid_sql
+            .read_grid_table(grid_id, conn)
+            .map_err(|e| not_found_as(e, GridError::grid_not_found))
+    }
+
+    fn read_field_table(&self, field_id: &str, conn: &SqliteConnection) -> GridResult<GridFieldTable> {
+        self.field_sql
+            .read_field_table(field_id, conn)
+            .map_err(|e| not_found_as(e, GridError::field_not_found))
+    }
+
+    fn read_row_table(&self, row_id: &str, conn: &SqliteConnection) -> GridResult<GridRowTable> {
+        self.row_sql
+            .read_row_table(row_id, conn)
+            .map_err(|e| not_found_as(e, GridError::row_not_found))
+    }
+
+    fn notify_fields_changed(&self, grid_id: &str, conn: &SqliteConnection) -> GridResult<()> {
+        let fields = RepeatedField {
+            items: self.read_field_items(grid_id, conn)?,
+        };
+        dart_notify(grid_id, GridObservable::FieldsChanged)
+            .payload(fields)
+            .send();
+        Ok(())
+    }
+
+    // Returns the rows it sent. The calendar of the grid is notified too.
+    fn notify_rows_changed(&self, grid_id: &str, conn: &SqliteConnection) -> GridResult<RepeatedRow> {
+        let grid_table = self.read_grid_table(grid_id, conn)?;
+        let rows = RepeatedRow {
+            items: self.read_visible_rows(&grid_table, conn)?,
+        };
+        dart_notify(grid_id, GridObservable::RowsChanged)
+            .payload(rows.clone())
+            .send();
+        self.notify_calendar_changed(grid_id, conn);
+        Ok(rows)
+    }
+
+    // Only sent if the calendar of the grid is shown and its events changed. The
+    // calendar is closed if it can't be read anymore, its date field was deleted.
+    fn notify_calendar_changed(&self, grid_id: &str, conn: &SqliteConnection) {
+        let range = match self.calendars.range(grid_id) {
+            None => return,
+            Some(range) => range,
+        };
+        match self.read_calendar_with_conn(&range, conn) {
+            Ok(calendar) => {
+                if self.calendars.update(grid_id, &calendar.items) {
+                    dart_notify(grid_id, GridObservable::CalendarChanged)
+                        .payload(calendar)
+                        .send();
+                }
+            },
+            Err(e) => {
+                log::error!("Read the calendar of {} failed: {:?}", grid_id, e);
+                self.calendars.close(grid_id);
+            },
+        }
+    }
+
+    // Returns the board it sent.
+    fn notify_board_changed(&self, grid_id: &str, conn: &SqliteConnection) -> GridResult<Board> {
+        let board = self.read_board_with_conn(grid_id, conn)?;
+        dart_notify(grid_id, GridObservable::BoardChanged)
+            .payload(board.clone())
+            .send();
+        Ok(board)
+    }
+}
+
+fn not_found_as(error: GridError, not_found: fn() -> GridError) -> GridError {
+    match error.is_record_not_found() {
+        true => not_found(),
+        false => error,
+    }
+}
+
+fn with_option_ids(mut type_option: FieldTypeOption) -> FieldTypeOption {
+    for option in type_option.options.iter_mut() {
+        if option.id.is_empty() {
+            option.id = flowy_infra::uuid();
+        }
+    }
+    type_option
+}
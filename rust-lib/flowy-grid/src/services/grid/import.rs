@@ -0,0 +1,176 @@
+use crate::{
+    entities::grid::{FieldType, FieldTypeOption, Grid},
+    services::grid::cell::{parse_cell_data, parse_checkbox, parse_number, parse_timestamp},
+};
+use chrono::{NaiveDate, NaiveDateTime};
+use std::collections::HashSet;
+
+// The select fields with more options than this are imported as text.
+const MAX_IMPORTED_OPTIONS: usize = 20;
+
+// The columns are typed if this percent of their values fit the type.
+const MIN_FITTING_PERCENT: usize = 90;
+
+// The formats of the dates exported by the other apps, e.g. November 17, 2021
+// or 2021/11/17 3:04 PM.
+const IMPORTED_DATE_FORMATS: [&str; 3] = ["%B %d, %Y", "%b %d, %Y", "%Y/%m/%d"];
+const IMPORTED_DATE_TIME_FORMATS: [&str; 4] = [
+    "%B %d, %Y %I:%M %p",
+    "%b %d, %Y %I:%M %p",
+    "%Y/%m/%d %I:%M %p",
+    "%Y/%m/%d %H:%M",
+];
+
+// A table to create a grid from, the first column is the primary one. The
+// grid gets the id if it's set.
+#[derive(Default, Debug, Clone)]
+pub struct ImportGridParams {
+    pub grid_id: String,
+    pub name: String,
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportGridResult {
+    pub grid: Grid,
+    pub invalid_cells: Vec<InvalidCell>,
+}
+
+// The value that didn't fit the type of its column, the cell is left empty.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidCell {
+    pub row: usize,
+    pub column: String,
+    pub data: String,
+}
+
+pub(crate) struct ImportedField {
+    pub(crate) name: String,
+    pub(crate) field_type: FieldType,
+    pub(crate) type_option: FieldTypeOption,
+}
+
+// The fields of the columns and the data of the cells of each row, in the
+// order of the columns.
+pub(crate) fn import_table(
+    columns: &[String],
+    rows: &[Vec<String>],
+) -> (Vec<ImportedField>, Vec<Vec<String>>, Vec<InvalidCell>) {
+    let mut fields = vec![];
+    let mut cells = vec![vec![]; rows.len()];
+    let mut invalid_cells = vec![];
+    for (index, column) in columns.iter().enumerate() {
+        let values = rows
+            .iter()
+            .map(|row| row.get(index).map_or("", |data| data.trim()))
+            .collect::<Vec<_>>();
+        let field_type = match index {
+            0 => FieldType::RichText,
+            _ => infer_field_type(&values),
+        };
+        let mut type_option = FieldTypeOption::default();
+        if field_type == FieldType::DateTime {
+            type_option.include_time = values.iter().any(|data| has_time(data));
+        }
+
+        let name = match column.trim().is_empty() {
+            true => format!("Column {}", index + 1),
+            false => column.trim().to_owned(),
+        };
+        for (row, data) in values.iter().enumerate() {
+            let data = match field_type {
+                FieldType::DateTime => imported_timestamp(data).map_or(data.to_string(), |t| t.to_string()),
+                _ => data.to_string(),
+            };
+            match parse_cell_data(field_type, &mut type_option, &data, true) {
+                Ok(data) => cells[row].push(data),
+                Err(_) => {
+                    invalid_cells.push(InvalidCell {
+                        row,
+                        column: name.clone(),
+                        data,
+                    });
+                    cells[row].push("".to_owned());
+                },
+            }
+        }
+        fields.push(ImportedField {
+            name,
+            field_type,
+            type_option,
+        });
+    }
+    (fields, cells, invalid_cells)
+}
+
+// The type that most of the values of the column fit in, the others are left
+// empty. The values repeat in the columns of the select fields.
+pub(crate) fn infer_field_type(values: &[&str]) -> FieldType {
+    let values = values
+        .iter()
+        .copied()
+        .filter(|data| !data.is_empty())
+        .collect::<Vec<&str>>();
+    if values.is_empty() {
+        return FieldType::RichText;
+    }
+    let fit = |f: &dyn Fn(&str) -> bool| {
+        let count = values.iter().copied().filter(|data| f(data)).count();
+        count * 100 >= values.len() * MIN_FITTING_PERCENT
+    };
+    // The 0 and 1 columns are numbers.
+    if fit(&|data| parse_checkbox(data).is_ok() && data.parse::<f64>().is_err()) {
+        return FieldType::Checkbox;
+    }
+    if fit(&|data| parse_number(data).is_ok()) {
+        return FieldType::Number;
+    }
+    if fit(&|data| imported_timestamp(data).is_some()) {
+        return FieldType::DateTime;
+    }
+
+    let multiple = values.iter().any(|data| data.contains(','));
+    let options = values
+        .iter()
+        .flat_map(|data| match multiple {
+            true => data
+                .split(',')
+                .map(|name| name.trim().to_lowercase())
+                .collect::<Vec<_>>(),
+            false => vec![data.to_lowercase()],
+        })
+        .filter(|name| !name.is_empty())
+        .collect::<HashSet<_>>();
+    if options.len() > MAX_IMPORTED_OPTIONS || options.len() * 2 > values.len() {
+        return FieldType::RichText;
+    }
+    match multiple {
+        true => FieldType::MultiSelect,
+        false => FieldType::SingleSelect,
+    }
+}
+
+// The start of a range like November 17, 2021 → November 20, 2021 is kept.
+fn imported_timestamp(data: &str) -> Option<i64> {
+    let data = data.split('→').next().unwrap_or("").trim();
+    if data.is_empty() || data.parse::<f64>().is_ok() {
+        return None;
+    }
+    if let Ok(timestamp) = parse_timestamp(data) {
+        return Some(timestamp);
+    }
+    for format in IMPORTED_DATE_TIME_FORMATS.iter() {
+        if let Ok(date_time) = NaiveDateTime::parse_from_str(data, format) {
+            return Some(date_time.timestamp());
+        }
+    }
+    for format in IMPORTED_DATE_FORMATS.iter() {
+        if let Ok(date) = NaiveDate::parse_from_str(data, format) {
+            return Some(date.and_hms(0, 0, 0).timestamp());
+        }
+    }
+    None
+}
+
+fn has_time(data: &str) -> bool { data.contains(':') }
@@ -0,0 +1,9 @@
+mod board;
+mod calendar;
+mod cell;
+mod grid_controller;
+mod import;
+mod setting;
+
+pub use grid_controller::*;
+pub use import::{ImportGridParams, ImportGridResult, InvalidCell};
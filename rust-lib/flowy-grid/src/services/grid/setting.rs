@@ -0,0 +1,189 @@
+use crate::{
+    entities::grid::{Field, FieldType, FilterCondition, GridFilter, GridSort, Row},
+    errors::{GridError, GridResult},
+    services::grid::cell::{parse_checkbox, parse_number, parse_timestamp, selected_option_ids, CHECKED},
+};
+use std::{cmp::Ordering, collections::HashMap};
+
+// Checks that the filter applies to the type of its field and that its value
+// can be compared with the cells.
+pub(crate) fn check_filter(filter: &GridFilter, field: &Field) -> GridResult<()> {
+    let value = filter.value.trim();
+    let invalid = || {
+        GridError::filter_invalid().context(format!(
+            "{:?} doesn't apply to the {:?} field {}",
+            filter.condition, field.field_type, field.name
+        ))
+    };
+    match (filter.condition, field.field_type) {
+        (FilterCondition::IsEmpty, _) | (FilterCondition::IsNotEmpty, _) => Ok(()),
+        (FilterCondition::Greater, FieldType::Number) | (FilterCondition::Less, FieldType::Number) => {
+            parse_number(value).map(|_| ()).map_err(|_| invalid())
+        },
+        (FilterCondition::Greater, FieldType::DateTime) | (FilterCondition::Less, FieldType::DateTime) => {
+            parse_timestamp(value).map(|_| ()).map_err(|_| invalid())
+        },
+        (FilterCondition::Greater, _) | (FilterCondition::Less, _) => Err(invalid()),
+        (FilterCondition::Contains, FieldType::RichText)
+        | (FilterCondition::DoesNotContain, FieldType::RichText)
+        | (FilterCondition::Contains, FieldType::SingleSelect)
+        | (FilterCondition::DoesNotContain, FieldType::SingleSelect)
+        | (FilterCondition::Contains, FieldType::MultiSelect)
+        | (FilterCondition::DoesNotContain, FieldType::MultiSelect) => Ok(()),
+        (FilterCondition::Contains, _) | (FilterCondition::DoesNotContain, _) => Err(invalid()),
+        (_, FieldType::Number) => parse_number(value).map(|_| ()).map_err(|_| invalid()),
+        (_, FieldType::DateTime) => parse_timestamp(value).map(|_| ()).map_err(|_| invalid()),
+        (_, FieldType::Checkbox) => parse_checkbox(value).map(|_| ()).map_err(|_| invalid()),
+        (_, FieldType::RichText) | (_, FieldType::SingleSelect) | (_, FieldType::MultiSelect) => Ok(()),
+    }
+}
+
+// The rows that pass all the filters, sorted by the sorts. The rows that
+// compare equal keep their order. The sorts and the filters of the fields
+// that don't exist are ignored.
+pub(crate) fn apply_setting(fields: &[Field], rows: Vec<Row>, sorts: &[GridSort], filters: &[GridFilter]) -> Vec<Row> {
+    let field_index = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| (field.id.as_str(), index))
+        .collect::<HashMap<_, _>>();
+    let filters = filters
+        .iter()
+        .filter_map(|filter| field_index.get(filter.field_id.as_str()).map(|index| (*index, filter)))
+        .collect::<Vec<_>>();
+    let sorts = sorts
+        .iter()
+        .filter_map(|sort| field_index.get(sort.field_id.as_str()).map(|index| (*index, sort)))
+        .collect::<Vec<_>>();
+
+    let mut rows = rows
+        .into_iter()
+        .filter(|row| {
+            filters
+                .iter()
+                .all(|(index, filter)| matches_filter(&fields[*index], cell_data(row, *index), filter))
+        })
+        .collect::<Vec<_>>();
+    rows.sort_by(|a, b| {
+        for (index, sort) in &sorts {
+            let ordering = compare_cells(
+                &fields[*index],
+                cell_data(a, *index),
+                cell_data(b, *index),
+                sort.descending,
+            );
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        Ordering::Equal
+    });
+    rows
+}
+
+// The cells of a row are in the order of the fields.
+fn cell_data(row: &Row, index: usize) -> &str { row.cells.get(index).map_or("", |cell| cell.data.as_str()) }
+
+fn matches_filter(field: &Field, data: &str, filter: &GridFilter) -> bool {
+    let value = filter.value.trim();
+    match filter.condition {
+        FilterCondition::IsEmpty => return data.is_empty(),
+        FilterCondition::IsNotEmpty => return !data.is_empty(),
+        _ => {},
+    }
+    match field.field_type {
+        FieldType::RichText => {
+            let data = data.to_lowercase();
+            let value = value.to_lowercase();
+            match filter.condition {
+                FilterCondition::Is => data == value,
+                FilterCondition::IsNot => data != value,
+                FilterCondition::Contains => data.contains(&value),
+                FilterCondition::DoesNotContain => !data.contains(&value),
+                _ => false,
+            }
+        },
+        FieldType::SingleSelect | FieldType::MultiSelect => {
+            let selected = selected_option_ids(data);
+            let values = selected_option_ids(value);
+            let same = selected.len() == values.len() && values.iter().all(|value| selected.contains(value));
+            match filter.condition {
+                FilterCondition::Is => same,
+                FilterCondition::IsNot => !same,
+                FilterCondition::Contains => values.iter().all(|value| selected.contains(value)),
+                FilterCondition::DoesNotContain => !values.iter().any(|value| selected.contains(value)),
+                _ => false,
+            }
+        },
+        FieldType::Checkbox => {
+            let checked = data == CHECKED;
+            match (filter.condition, parse_checkbox(value)) {
+                (FilterCondition::Is, Ok(value)) => checked == value,
+                (FilterCondition::IsNot, Ok(value)) => checked != value,
+                _ => false,
+            }
+        },
+        FieldType::Number => compare_values(filter.condition, parse_number(data), parse_number(value)),
+        FieldType::DateTime => compare_values(
+            filter.condition,
+            parse_timestamp(data).map(|timestamp| timestamp as f64),
+            parse_timestamp(value).map(|timestamp| timestamp as f64),
+        ),
+    }
+}
+
+// An empty cell only passes IsNot.
+fn compare_values(condition: FilterCondition, data: GridResult<f64>, value: GridResult<f64>) -> bool {
+    let value = match value {
+        Ok(value) => value,
+        Err(_) => return false,
+    };
+    match (condition, data) {
+        (FilterCondition::Is, Ok(data)) => (data - value).abs() < f64::EPSILON,
+        (FilterCondition::IsNot, Ok(data)) => (data - value).abs() >= f64::EPSILON,
+        (FilterCondition::IsNot, Err(_)) => true,
+        (FilterCondition::Greater, Ok(data)) => data > value,
+        (FilterCondition::Less, Ok(data)) => data < value,
+        _ => false,
+    }
+}
+
+// The empty cells come last in both orders.
+fn compare_cells(field: &Field, a: &str, b: &str, descending: bool) -> Ordering {
+    let ordering = match (a.is_empty(), b.is_empty()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        (false, false) => match field.field_type {
+            FieldType::Number => compare_numbers(parse_number(a).ok(), parse_number(b).ok()),
+            FieldType::DateTime => parse_timestamp(a).ok().cmp(&parse_timestamp(b).ok()),
+            FieldType::SingleSelect | FieldType::MultiSelect => {
+                // The options are ordered like the options of the field.
+                let position = |data: &str| {
+                    selected_option_ids(data).first().and_then(|option_id| {
+                        field
+                            .type_option
+                            .options
+                            .iter()
+                            .position(|option| option.id == *option_id)
+                    })
+                };
+                position(a).cmp(&position(b))
+            },
+            FieldType::Checkbox => Ordering::Equal,
+            FieldType::RichText => a.to_lowercase().cmp(&b.to_lowercase()),
+        },
+    };
+    if descending {
+        ordering.reverse()
+    } else {
+        ordering
+    }
+}
+
+fn compare_numbers(a: Option<f64>, b: Option<f64>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        (a, b) => a.is_some().cmp(&b.is_some()),
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{
+    errors::GridError,
+    sql_tables::grid::{GridFieldTable, GridFieldTableChangeset},
+};
+use flowy_database::{
+    prelude::*,
+    schema::{grid_cell_table, grid_field_table::dsl},
+    SqliteConnection,
+};
+
+pub struct GridFieldTableSql {}
+
+impl GridFieldTableSql {
+    pub(crate) fn create_field_table(&self, table: GridFieldTable, conn: &SqliteConnection) -> Result<(), GridError> {
+        let _ = diesel::insert_into(dsl::grid_field_table).values(table).execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_field_table(
+        &self,
+        field_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<GridFieldTable, GridError> {
+        let table = dsl::grid_field_table
+            .filter(dsl::id.eq(field_id))
+            .first::<GridFieldTable>(conn)?;
+        Ok(table)
+    }
+
+    // The fields in the order they're shown.
+    pub(crate) fn read_field_tables(
+        &self,
+        grid_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<GridFieldTable>, GridError> {
+        let tables = dsl::grid_field_table
+            .filter(dsl::grid_id.eq(grid_id))
+            .order(dsl::position.asc())
+            .load::<GridFieldTable>(conn)?;
+        Ok(tables)
+    }
+
+    pub(crate) fn update_field_table(
+        &self,
+        changeset: GridFieldTableChangeset,
+        conn: &SqliteConnection,
+    ) -> Result<(), GridError> {
+        let filter = dsl::grid_field_table.filter(dsl::id.eq(changeset.id.clone()));
+        let _ = diesel::update(filter).set(changeset).execute(conn)?;
+        Ok(())
+    }
+
+    // Deletes the cells of the field too.
+    pub(crate) fn delete_field_table(&self, field_id: &str, conn: &SqliteConnection) -> Result<(), GridError> {
+        let _ =
+            diesel::delete(grid_cell_table::dsl::grid_cell_table.filter(grid_cell_table::dsl::field_id.eq(field_id)))
+                .execute(conn)?;
+        let _ = diesel::delete(dsl::grid_field_table.filter(dsl::id.eq(field_id))).execute(conn)?;
+        Ok(())
+    }
+}
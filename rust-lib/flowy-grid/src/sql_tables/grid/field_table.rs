@@ -0,0 +1,183 @@
+use crate::entities::grid::{Field, FieldType, FieldTypeOption, SelectOption};
+use diesel::sql_types::{Binary, Integer};
+use flowy_database::schema::grid_field_table;
+use serde::{Deserialize, Serialize};
+use std::convert::{TryFrom, TryInto};
+
+pub(crate) const DEFAULT_FIELD_WIDTH: i32 = 150;
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable)]
+#[table_name = "grid_field_table"]
+pub(crate) struct GridFieldTable {
+    pub(crate) id: String,
+    pub(crate) grid_id: String,
+    pub(crate) name: String,
+    pub(crate) field_type: SqlFieldType,
+    pub(crate) width: i32,
+    pub(crate) type_option: TypeOptionCol,
+    pub(crate) position: i64,
+}
+
+impl GridFieldTable {
+    pub(crate) fn new(grid_id: &str, name: &str, field_type: FieldType, width: i32, position: i64) -> Self {
+        Self {
+            id: flowy_infra::uuid(),
+            grid_id: grid_id.to_owned(),
+            name: name.to_owned(),
+            field_type: field_type.into(),
+            width: if width > 0 { width } else { DEFAULT_FIELD_WIDTH },
+            type_option: TypeOptionCol::default(),
+            position,
+        }
+    }
+}
+
+impl std::convert::From<GridFieldTable> for Field {
+    fn from(table: GridFieldTable) -> Self {
+        Field {
+            id: table.id,
+            grid_id: table.grid_id,
+            name: table.name,
+            field_type: table.field_type.into(),
+            width: table.width,
+            type_option: table.type_option.into(),
+        }
+    }
+}
+
+#[derive(AsChangeset, Identifiable, Clone, Default, Debug)]
+#[table_name = "grid_field_table"]
+pub(crate) struct GridFieldTableChangeset {
+    pub(crate) id: String,
+    pub(crate) name: Option<String>,
+    pub(crate) field_type: Option<SqlFieldType>,
+    pub(crate) width: Option<i32>,
+    pub(crate) type_option: Option<TypeOptionCol>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, FromSqlRow, AsExpression)]
+#[repr(i32)]
+#[sql_type = "Integer"]
+pub(crate) enum SqlFieldType {
+    RichText     = 0,
+    Number       = 1,
+    SingleSelect = 2,
+    MultiSelect  = 3,
+    DateTime     = 4,
+    Checkbox     = 5,
+}
+
+impl std::convert::From<i32> for SqlFieldType {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => SqlFieldType::RichText,
+            1 => SqlFieldType::Number,
+            2 => SqlFieldType::SingleSelect,
+            3 => SqlFieldType::MultiSelect,
+            4 => SqlFieldType::DateTime,
+            5 => SqlFieldType::Checkbox,
+            o => {
+                log::error!("Unsupported field type {}, fallback to RichText", o);
+                SqlFieldType::RichText
+            },
+        }
+    }
+}
+
+impl_sql_integer_expression!(SqlFieldType);
+
+impl std::convert::From<FieldType> for SqlFieldType {
+    fn from(ty: FieldType) -> Self {
+        match ty {
+            FieldType::RichText => SqlFieldType::RichText,
+            FieldType::Number => SqlFieldType::Number,
+            FieldType::SingleSelect => SqlFieldType::SingleSelect,
+            FieldType::MultiSelect => SqlFieldType::MultiSelect,
+            FieldType::DateTime => SqlFieldType::DateTime,
+            FieldType::Checkbox => SqlFieldType::Checkbox,
+        }
+    }
+}
+
+impl std::convert::From<SqlFieldType> for FieldType {
+    fn from(ty: SqlFieldType) -> Self {
+        match ty {
+            SqlFieldType::RichText => FieldType::RichText,
+            SqlFieldType::Number => FieldType::Number,
+            SqlFieldType::SingleSelect => FieldType::SingleSelect,
+            SqlFieldType::MultiSelect => FieldType::MultiSelect,
+            SqlFieldType::DateTime => FieldType::DateTime,
+            SqlFieldType::Checkbox => FieldType::Checkbox,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Default, FromSqlRow, AsExpression)]
+#[sql_type = "Binary"]
+pub(crate) struct TypeOptionCol {
+    pub(crate) decimals: i32,
+    pub(crate) include_time: bool,
+    pub(crate) options: Vec<SelectOptionCol>,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Default)]
+pub(crate) struct SelectOptionCol {
+    pub(crate) id: String,
+    pub(crate) name: String,
+    pub(crate) color: String,
+}
+
+impl std::convert::TryInto<Vec<u8>> for &TypeOptionCol {
+    type Error = String;
+
+    fn try_into(self) -> Result<Vec<u8>, Self::Error> { bincode::serialize(self).map_err(|e| format!("{:?}", e)) }
+}
+
+impl std::convert::TryFrom<&[u8]> for TypeOptionCol {
+    type Error = String;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            return Ok(TypeOptionCol::default());
+        }
+        bincode::deserialize(value).map_err(|e| format!("{:?}", e))
+    }
+}
+
+impl_sql_binary_expression!(TypeOptionCol);
+
+impl std::convert::From<FieldTypeOption> for TypeOptionCol {
+    fn from(type_option: FieldTypeOption) -> Self {
+        TypeOptionCol {
+            decimals: type_option.decimals,
+            include_time: type_option.include_time,
+            options: type_option
+                .options
+                .into_iter()
+                .map(|option| SelectOptionCol {
+                    id: option.id,
+                    name: option.name,
+                    color: option.color,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl std::convert::From<TypeOptionCol> for FieldTypeOption {
+    fn from(col: TypeOptionCol) -> Self {
+        FieldTypeOption {
+            decimals: col.decimals,
+            include_time: col.include_time,
+            options: col
+                .options
+                .into_iter()
+                .map(|option| SelectOption {
+                    id: option.id,
+                    name: option.name,
+                    color: option.color,
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{
+    errors::GridError,
+    sql_tables::grid::{GridTable, GridTableChangeset},
+};
+use flowy_database::{
+    prelude::*,
+    schema::{grid_cell_table, grid_field_table, grid_row_table, grid_table::dsl},
+    SqliteConnection,
+};
+
+pub struct GridTableSql {}
+
+impl GridTableSql {
+    pub(crate) fn create_grid_table(&self, table: GridTable, conn: &SqliteConnection) -> Result<(), GridError> {
+        let _ = diesel::insert_into(dsl::grid_table).values(table).execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_grid_table(&self, grid_id: &str, conn: &SqliteConnection) -> Result<GridTable, GridError> {
+        let table = dsl::grid_table.filter(dsl::id.eq(grid_id)).first::<GridTable>(conn)?;
+        Ok(table)
+    }
+
+    pub(crate) fn update_grid_table(
+        &self,
+        changeset: GridTableChangeset,
+        conn: &SqliteConnection,
+    ) -> Result<(), GridError> {
+        let filter = dsl::grid_table.filter(dsl::id.eq(changeset.id.clone()));
+        let _ = diesel::update(filter).set(changeset).execute(conn)?;
+        Ok(())
+    }
+
+    // Deletes the fields, the rows and the cells of the grid too.
+    pub(crate) fn delete_grid_table(&self, grid_id: &str, conn: &SqliteConnection) -> Result<(), GridError> {
+        let row_ids = grid_row_table::dsl::grid_row_table
+            .select(grid_row_table::dsl::id)
+            .filter(grid_row_table::dsl::grid_id.eq(grid_id));
+        let _ =
+            diesel::delete(grid_cell_table::dsl::grid_cell_table.filter(grid_cell_table::dsl::row_id.eq_any(row_ids)))
+                .execute(conn)?;
+        let _ = diesel::delete(grid_row_table::dsl::grid_row_table.filter(grid_row_table::dsl::grid_id.eq(grid_id)))
+            .execute(conn)?;
+        let _ =
+            diesel::delete(grid_field_table::dsl::grid_field_table.filter(grid_field_table::dsl::grid_id.eq(grid_id)))
+                .execute(conn)?;
+        let _ = diesel::delete(dsl::grid_table.filter(dsl::id.eq(grid_id))).execute(conn)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+use crate::entities::grid::{FilterCondition, GridFilter, GridSort};
+use diesel::sql_types::Binary;
+use flowy_database::schema::grid_table;
+use serde::{Deserialize, Serialize};
+use std::convert::{TryFrom, TryInto};
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable)]
+#[table_name = "grid_table"]
+pub(crate) struct GridTable {
+    pub(crate) id: String,
+    pub(crate) name: String,
+    pub(crate) setting: GridSettingCol,
+    pub(crate) create_time: i64,
+    pub(crate) modified_time: i64,
+    pub(crate) board_setting: BoardSettingCol,
+}
+
+impl GridTable {
+    pub(crate) fn new(name: &str) -> Self {
+        let time = flowy_infra::timestamp();
+        Self {
+            id: flowy_infra::uuid(),
+            name: name.to_owned(),
+            setting: GridSettingCol::default(),
+            create_time: time,
+            modified_time: time,
+            board_setting: BoardSettingCol::default(),
+        }
+    }
+}
+
+#[derive(AsChangeset, Identifiable, Clone, Default, Debug)]
+#[table_name = "grid_table"]
+pub(crate) struct GridTableChangeset {
+    pub(crate) id: String,
+    pub(crate) name: Option<String>,
+    pub(crate) setting: Option<GridSettingCol>,
+    pub(crate) modified_time: Option<i64>,
+    pub(crate) board_setting: Option<BoardSettingCol>,
+}
+
+// The sorts and the filters of the grid.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Default, FromSqlRow, AsExpression)]
+#[sql_type = "Binary"]
+pub(crate) struct GridSettingCol {
+    pub(crate) sorts: Vec<SortCol>,
+    pub(crate) filters: Vec<FilterCol>,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Default)]
+pub(crate) struct SortCol {
+    pub(crate) field_id: String,
+    pub(crate) descending: bool,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Default)]
+pub(crate) struct FilterCol {
+    pub(crate) field_id: String,
+    pub(crate) condition: i32,
+    pub(crate) value: String,
+}
+
+impl std::convert::TryInto<Vec<u8>> for &GridSettingCol {
+    type Error = String;
+
+    fn try_into(self) -> Result<Vec<u8>, Self::Error> { bincode::serialize(self).map_err(|e| format!("{:?}", e)) }
+}
+
+impl std::convert::TryFrom<&[u8]> for GridSettingCol {
+    type Error = String;
+
+    // The setting of a grid that never had one is empty.
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            return Ok(GridSettingCol::default());
+        }
+        bincode::deserialize(value).map_err(|e| format!("{:?}", e))
+    }
+}
+
+impl_sql_binary_expression!(GridSettingCol);
+
+// The field that the board groups the rows by, empty if it's the first select
+// field of the grid.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Default, FromSqlRow, AsExpression)]
+#[sql_type = "Binary"]
+pub(crate) struct BoardSettingCol {
+    pub(crate) group_field_id: String,
+}
+
+impl std::convert::TryInto<Vec<u8>> for &BoardSettingCol {
+    type Error = String;
+
+    fn try_into(self) -> Result<Vec<u8>, Self::Error> { bincode::serialize(self).map_err(|e| format!("{:?}", e)) }
+}
+
+impl std::convert::TryFrom<&[u8]> for BoardSettingCol {
+    type Error = String;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            return Ok(BoardSettingCol::default());
+        }
+        bincode::deserialize(value).map_err(|e| format!("{:?}", e))
+    }
+}
+
+impl_sql_binary_expression!(BoardSettingCol);
+
+impl std::convert::From<GridSort> for SortCol {
+    fn from(sort: GridSort) -> Self {
+        SortCol {
+            field_id: sort.field_id,
+            descending: sort.descending,
+        }
+    }
+}
+
+impl std::convert::From<SortCol> for GridSort {
+    fn from(col: SortCol) -> Self {
+        GridSort {
+            field_id: col.field_id,
+            descending: col.descending,
+        }
+    }
+}
+
+impl std::convert::From<GridFilter> for FilterCol {
+    fn from(filter: GridFilter) -> Self {
+        FilterCol {
+            field_id: filter.field_id,
+            condition: filter.condition as i32,
+            value: filter.value,
+        }
+    }
+}
+
+impl std::convert::From<FilterCol> for GridFilter {
+    fn from(col: FilterCol) -> Self {
+        let condition = match col.condition {
+            0 => FilterCondition::Is,
+            1 => FilterCondition::IsNot,
+            2 => FilterCondition::Contains,
+            3 => FilterCondition::DoesNotContain,
+            4 => FilterCondition::IsEmpty,
+            5 => FilterCondition::IsNotEmpty,
+            6 => FilterCondition::Greater,
+            7 => FilterCondition::Less,
+            o => {
+                log::error!("Unsupported filter condition {}, fallback to Is", o);
+                FilterCondition::Is
+            },
+        };
+        GridFilter {
+            field_id: col.field_id,
+            condition,
+            value: col.value,
+        }
+    }
+}
@@ -0,0 +1,13 @@
+mod field_sql;
+mod field_table;
+mod grid_sql;
+mod grid_table;
+mod row_sql;
+mod row_table;
+
+pub(crate) use field_sql::*;
+pub(crate) use field_table::*;
+pub(crate) use grid_sql::*;
+pub(crate) use grid_table::*;
+pub(crate) use row_sql::*;
+pub(crate) use row_table::*;
@@ -0,0 +1,143 @@
+use crate::{
+    errors::GridError,
+    sql_tables::grid::{GridCellTable, GridRowTable},
+};
+use flowy_database::{
+    prelude::*,
+    schema::{grid_cell_table, grid_row_table::dsl},
+    OptionalExtension,
+    SqliteConnection,
+};
+
+pub struct GridRowTableSql {}
+
+impl GridRowTableSql {
+    pub(crate) fn create_row_table(&self, table: GridRowTable, conn: &SqliteConnection) -> Result<(), GridError> {
+        let _ = diesel::insert_into(dsl::grid_row_table).values(table).execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_row_table(&self, row_id: &str, conn: &SqliteConnection) -> Result<GridRowTable, GridError> {
+        let table = dsl::grid_row_table
+            .filter(dsl::id.eq(row_id))
+            .first::<GridRowTable>(conn)?;
+        Ok(table)
+    }
+
+    // The rows in the order they were inserted in.
+    pub(crate) fn read_row_tables(
+        &self,
+        grid_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<GridRowTable>, GridError> {
+        let tables = dsl::grid_row_table
+            .filter(dsl::grid_id.eq(grid_id))
+            .order(dsl::position.asc())
+            .load::<GridRowTable>(conn)?;
+        Ok(tables)
+    }
+
+    // Makes room for a row at the position.
+    pub(crate) fn shift_row_tables(
+        &self,
+        grid_id: &str,
+        position: i64,
+        conn: &SqliteConnection,
+    ) -> Result<(), GridError> {
+        let filter = dsl::grid_row_table
+            .filter(dsl::grid_id.eq(grid_id))
+            .filter(dsl::position.ge(position));
+        let _ = diesel::update(filter)
+            .set(dsl::position.eq(dsl::position + 1))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn update_row_position(
+        &self,
+        row_id: &str,
+        position: i64,
+        conn: &SqliteConnection,
+    ) -> Result<(), GridError> {
+        let _ = diesel::update(dsl::grid_row_table.filter(dsl::id.eq(row_id)))
+            .set(dsl::position.eq(position))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    // Deletes the cells of the row too.
+    pub(crate) fn delete_row_table(&self, row_id: &str, conn: &SqliteConnection) -> Result<(), GridError> {
+        let _ = diesel::delete(grid_cell_table::dsl::grid_cell_table.filter(grid_cell_table::dsl::row_id.eq(row_id)))
+            .execute(conn)?;
+        let _ = diesel::delete(dsl::grid_row_table.filter(dsl::id.eq(row_id))).execute(conn)?;
+        Ok(())
+    }
+}
+
+pub struct GridCellTableSql {}
+
+impl GridCellTableSql {
+    // An empty data deletes the cell.
+    pub(crate) fn set_cell_table(&self, table: GridCellTable, conn: &SqliteConnection) -> Result<(), GridError> {
+        if table.data.is_empty() {
+            return self.delete_cell_table(&table.row_id, &table.field_id, conn);
+        }
+        let _ = diesel::replace_into(grid_cell_table::table)
+            .values(table)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn delete_cell_table(
+        &self,
+        row_id: &str,
+        field_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<(), GridError> {
+        let filter = grid_cell_table::dsl::grid_cell_table
+            .filter(grid_cell_table::dsl::row_id.eq(row_id))
+            .filter(grid_cell_table::dsl::field_id.eq(field_id));
+        let _ = diesel::delete(filter).execute(conn)?;
+        Ok(())
+    }
+
+    // The data of the cell, empty if it was never set.
+    pub(crate) fn read_cell_data(
+        &self,
+        row_id: &str,
+        field_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<String, GridError> {
+        let data = grid_cell_table::dsl::grid_cell_table
+            .select(grid_cell_table::dsl::data)
+            .filter(grid_cell_table::dsl::row_id.eq(row_id))
+            .filter(grid_cell_table::dsl::field_id.eq(field_id))
+            .first::<String>(conn)
+            .optional()?;
+        Ok(data.unwrap_or_default())
+    }
+
+    // The cells of all the rows of the grid.
+    pub(crate) fn read_grid_cell_tables(
+        &self,
+        grid_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<GridCellTable>, GridError> {
+        let row_ids = dsl::grid_row_table.select(dsl::id).filter(dsl::grid_id.eq(grid_id));
+        let tables = grid_cell_table::dsl::grid_cell_table
+            .filter(grid_cell_table::dsl::row_id.eq_any(row_ids))
+            .load::<GridCellTable>(conn)?;
+        Ok(tables)
+    }
+
+    pub(crate) fn read_field_cell_tables(
+        &self,
+        field_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<GridCellTable>, GridError> {
+        let tables = grid_cell_table::dsl::grid_cell_table
+            .filter(grid_cell_table::dsl::field_id.eq(field_id))
+            .load::<GridCellTable>(conn)?;
+        Ok(tables)
+    }
+}
@@ -0,0 +1,40 @@
+use flowy_database::schema::{grid_cell_table, grid_row_table};
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable)]
+#[table_name = "grid_row_table"]
+pub(crate) struct GridRowTable {
+    pub(crate) id: String,
+    pub(crate) grid_id: String,
+    pub(crate) position: i64,
+    pub(crate) create_time: i64,
+}
+
+impl GridRowTable {
+    pub(crate) fn new(grid_id: &str, position: i64) -> Self {
+        Self {
+            id: flowy_infra::uuid(),
+            grid_id: grid_id.to_owned(),
+            position,
+            create_time: flowy_infra::timestamp(),
+        }
+    }
+}
+
+// The cells that were never set, or were cleared, have no row in the table.
+#[derive(PartialEq, Clone, Debug, Queryable, Insertable)]
+#[table_name = "grid_cell_table"]
+pub(crate) struct GridCellTable {
+    pub(crate) row_id: String,
+    pub(crate) field_id: String,
+    pub(crate) data: String,
+}
+
+impl GridCellTable {
+    pub(crate) fn new(row_id: &str, field_id: &str, data: &str) -> Self {
+        Self {
+            row_id: row_id.to_owned(),
+            field_id: field_id.to_owned(),
+            data: data.to_owned(),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub(crate) mod grid;
+
+pub(crate) use grid::*;
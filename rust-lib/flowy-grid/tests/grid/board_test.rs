@@ -0,0 +1,238 @@
+use flowy_grid::{entities::grid::*, errors::ErrorCode, event::GridEvent::*};
+use flowy_test::{builder::GridTest, FlowyTest};
+use serial_test::*;
+
+struct BoardTest {
+    test: FlowyTest,
+    grid_id: String,
+    field: Field,
+    row_ids: Vec<String>,
+}
+
+impl BoardTest {
+    // A grid with a status field of the options Todo and Done, and three rows:
+    // the first two are Todo, the last one has no status.
+    async fn new() -> Self {
+        let test = FlowyTest::setup();
+        let _ = test.init_user().await;
+        let grid = GridTest::new(test.sdk())
+            .event(CreateGrid)
+            .request(CreateGridRequest {
+                name: "Board".to_owned(),
+            })
+            .async_send()
+            .await
+            .parse::<Grid>();
+        let option = |name: &str| SelectOption {
+            name: name.to_owned(),
+            ..Default::default()
+        };
+        let field = GridTest::new(test.sdk())
+            .event(CreateField)
+            .request(CreateFieldRequest {
+                grid_id: grid.id.clone(),
+                name: "Status".to_owned(),
+                field_type: FieldType::SingleSelect,
+                width: 0,
+                type_option: FieldTypeOption {
+                    options: vec![option("Todo"), option("Done")],
+                    ..Default::default()
+                },
+            })
+            .async_send()
+            .await
+            .parse::<Field>();
+        let mut board_test = Self {
+            test,
+            grid_id: grid.id,
+            field,
+            row_ids: vec![],
+        };
+        for status in &["Todo", "Todo", ""] {
+            let row = GridTest::new(board_test.test.sdk())
+                .event(CreateRow)
+                .request(CreateRowRequest {
+                    grid_id: board_test.grid_id.clone(),
+                    start_row_id: None,
+                })
+                .async_send()
+                .await
+                .parse::<Row>();
+            let _ = GridTest::new(board_test.test.sdk())
+                .event(UpdateCell)
+                .request(CellChangeset {
+                    row_id: row.id.clone(),
+                    field_id: board_test.field.id.clone(),
+                    data: status.to_string(),
+                })
+                .async_send()
+                .await;
+            board_test.row_ids.push(row.id);
+        }
+        board_test
+    }
+
+    fn option_id(&self, index: usize) -> String { self.field.type_option.options[index].id.clone() }
+
+    async fn read_board(&self) -> Board {
+        GridTest::new(self.test.sdk())
+            .event(ReadBoard)
+            .request(GridIdentifier {
+                grid_id: self.grid_id.clone(),
+            })
+            .async_send()
+            .await
+            .parse::<Board>()
+    }
+
+    async fn move_card(&self, row_id: &str, from: &str, to: &str, to_row_id: Option<&str>) -> GridTest {
+        GridTest::new(self.test.sdk())
+            .event(MoveCard)
+            .request(MoveCardRequest {
+                grid_id: self.grid_id.clone(),
+                row_id: row_id.to_owned(),
+                from_group_id: from.to_owned(),
+                to_group_id: to.to_owned(),
+                to_row_id: to_row_id.map(|row_id| row_id.to_owned()),
+            })
+            .async_send()
+            .await
+    }
+}
+
+fn group_row_ids(board: &Board) -> Vec<Vec<String>> {
+    board
+        .groups
+        .iter()
+        .map(|group| group.rows.iter().map(|row| row.id.clone()).collect())
+        .collect()
+}
+
+#[tokio::test]
+#[serial]
+async fn board_groups_rows_by_select_field() {
+    let test = BoardTest::new().await;
+    let board = test.read_board().await;
+    assert_eq!(board.group_field_id, test.field.id);
+    let names = board.groups.iter().map(|group| group.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["", "Todo", "Done"]);
+    assert_eq!(
+        group_row_ids(&board),
+        vec![
+            vec![test.row_ids[2].clone()],
+            vec![test.row_ids[0].clone(), test.row_ids[1].clone()],
+            vec![],
+        ]
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn board_move_card_between_groups() {
+    let test = BoardTest::new().await;
+    let board = test
+        .move_card(&test.row_ids[0], &test.option_id(0), &test.option_id(1), None)
+        .await
+        .parse::<Board>();
+    assert_eq!(
+        group_row_ids(&board),
+        vec![
+            vec![test.row_ids[2].clone()],
+            vec![test.row_ids[1].clone()],
+            vec![test.row_ids[0].clone()],
+        ]
+    );
+
+    // The cell of the card was changed with it.
+    let grid = GridTest::new(test.test.sdk())
+        .event(ReadGrid)
+        .request(GridIdentifier {
+            grid_id: test.grid_id.clone(),
+        })
+        .async_send()
+        .await
+        .parse::<Grid>();
+    let row = grid.rows.iter().find(|row| row.id == test.row_ids[0]).unwrap();
+    assert_eq!(row.cells[1].data, test.option_id(1));
+
+    let error = test
+        .move_card(&test.row_ids[1], &test.option_id(1), &test.option_id(0), None)
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::GroupNotFound);
+}
+
+#[tokio::test]
+#[serial]
+async fn board_reorder_cards_in_group() {
+    let test = BoardTest::new().await;
+    let todo = test.option_id(0);
+    let board = test
+        .move_card(&test.row_ids[1], &todo, &todo, Some(&test.row_ids[0]))
+        .await
+        .parse::<Board>();
+    assert_eq!(
+        group_row_ids(&board)[1],
+        vec![test.row_ids[1].clone(), test.row_ids[0].clone()]
+    );
+
+    // A card moved to a group without a card to go before goes last.
+    let board = test.move_card(&test.row_ids[2], "", &todo, None).await.parse::<Board>();
+    assert_eq!(
+        group_row_ids(&board)[1],
+        vec![
+            test.row_ids[1].clone(),
+            test.row_ids[0].clone(),
+            test.row_ids[2].clone(),
+        ]
+    );
+    assert!(board.groups[0].rows.is_empty());
+}
+
+#[tokio::test]
+#[serial]
+async fn board_setting_is_kept_with_the_grid() {
+    let test = BoardTest::new().await;
+    let tags = GridTest::new(test.test.sdk())
+        .event(CreateField)
+        .request(CreateFieldRequest {
+            grid_id: test.grid_id.clone(),
+            name: "Tags".to_owned(),
+            field_type: FieldType::MultiSelect,
+            width: 0,
+            type_option: FieldTypeOption::default(),
+        })
+        .async_send()
+        .await
+        .parse::<Field>();
+    let board = GridTest::new(test.test.sdk())
+        .event(UpdateBoardSetting)
+        .request(UpdateBoardSettingRequest {
+            grid_id: test.grid_id.clone(),
+            group_field_id: tags.id.clone(),
+        })
+        .async_send()
+        .await
+        .parse::<Board>();
+    assert_eq!(board.group_field_id, tags.id);
+    assert_eq!(test.read_board().await.group_field_id, tags.id);
+
+    let error = GridTest::new(test.test.sdk())
+        .event(UpdateBoardSetting)
+        .request(UpdateBoardSettingRequest {
+            grid_id: test.grid_id.clone(),
+            group_field_id: board.grid_id.clone(),
+        })
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::FieldNotFound);
+
+    // The board groups by the first select field again once its field is deleted.
+    let _ = GridTest::new(test.test.sdk())
+        .event(DeleteField)
+        .request(FieldIdentifier { field_id: tags.id })
+        .async_send()
+        .await;
+    assert_eq!(test.read_board().await.group_field_id, test.field.id);
+}
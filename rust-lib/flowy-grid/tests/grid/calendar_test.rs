@@ -0,0 +1,154 @@
+use flowy_grid::{
+    entities::grid::*,
+    errors::{ErrorCode, GridError},
+    event::GridEvent::*,
+    protobuf::GridObservable,
+};
+use flowy_test::EventTester;
+
+// 2021-11-01 and 2021-12-01 in UTC.
+const NOVEMBER: (i64, i64) = (1635724800, 1638316800);
+
+struct CalendarTest {
+    tester: EventTester,
+    grid_id: String,
+    field: Field,
+}
+
+impl CalendarTest {
+    async fn new(include_time: bool) -> Self {
+        let tester = EventTester::new();
+        let _ = tester.sign_up().await;
+        let request = CreateGridRequest {
+            name: "Calendar".to_owned(),
+        };
+        let grid = tester.send(CreateGrid, request).await.parse::<Grid>();
+        let request = CreateFieldRequest {
+            grid_id: grid.id.clone(),
+            name: "Due".to_owned(),
+            field_type: FieldType::DateTime,
+            width: 0,
+            type_option: FieldTypeOption {
+                include_time,
+                ..Default::default()
+            },
+        };
+        let field = tester.send(CreateField, request).await.parse::<Field>();
+        Self {
+            tester,
+            grid_id: grid.id,
+            field,
+        }
+    }
+
+    async fn create_row(&self, date: &str) -> Row {
+        let request = CreateRowRequest {
+            grid_id: self.grid_id.clone(),
+            start_row_id: None,
+        };
+        let row = self.tester.send(CreateRow, request).await.parse::<Row>();
+        let changeset = CellChangeset {
+            row_id: row.id.clone(),
+            field_id: self.field.id.clone(),
+            data: date.to_owned(),
+        };
+        let _ = self.tester.send(UpdateCell, changeset).await;
+        row
+    }
+
+    async fn read_calendar(&self, start: i64, end: i64) -> Calendar {
+        let request = CalendarRangeRequest {
+            grid_id: self.grid_id.clone(),
+            date_field_id: "".to_owned(),
+            start,
+            end,
+        };
+        self.tester.send(ReadCalendar, request).await.parse::<Calendar>()
+    }
+}
+
+#[tokio::test]
+async fn calendar_shows_rows_in_range() {
+    let test = CalendarTest::new(false).await;
+    let late = test.create_row("2021-11-20").await;
+    let early = test.create_row("2021-11-14").await;
+    let _ = test.create_row("2021-12-05").await;
+    let _ = test.create_row("").await;
+
+    let calendar = test.read_calendar(NOVEMBER.0, NOVEMBER.1).await;
+    assert_eq!(calendar.date_field_id, test.field.id);
+    let items = calendar
+        .items
+        .iter()
+        .map(|item| (item.row.id.clone(), item.timestamp))
+        .collect::<Vec<_>>();
+    assert_eq!(items, vec![(early.id, 1636848000), (late.id, 1637366400)]);
+}
+
+#[tokio::test]
+async fn calendar_reschedule_row_keeps_its_time() {
+    let test = CalendarTest::new(true).await;
+    let row = test.create_row("2021-11-14 09:30").await;
+    let _ = test.read_calendar(NOVEMBER.0, NOVEMBER.1).await;
+
+    // Dropped on 2021-11-20.
+    let request = RescheduleRowRequest {
+        row_id: row.id.clone(),
+        date_field_id: test.field.id.clone(),
+        timestamp: 1637366400,
+    };
+    let cell = test.tester.send(RescheduleRow, request).await.parse::<Cell>();
+    assert_eq!(cell.data, "1637400600");
+
+    let ty = GridObservable::CalendarChanged as i32;
+    let calendar = test
+        .tester
+        .expect_notification_payload::<_, Calendar>(&test.grid_id, ty)
+        .await;
+    assert_eq!(calendar.items.len(), 1);
+    assert_eq!(calendar.items[0].timestamp, 1637400600);
+}
+
+#[tokio::test]
+async fn calendar_needs_a_date_field_and_a_range() {
+    let test = CalendarTest::new(false).await;
+    let error = test
+        .tester
+        .send(
+            ReadCalendar,
+            CalendarRangeRequest {
+                grid_id: test.grid_id.clone(),
+                date_field_id: "".to_owned(),
+                start: NOVEMBER.1,
+                end: NOVEMBER.0,
+            },
+        )
+        .await
+        .error::<GridError>();
+    assert_eq!(error.code, ErrorCode::CalendarRangeInvalid);
+
+    let fields = test
+        .tester
+        .send(
+            ReadFields,
+            GridIdentifier {
+                grid_id: test.grid_id.clone(),
+            },
+        )
+        .await
+        .parse::<RepeatedField>();
+    let error = test
+        .tester
+        .send(
+            ReadCalendar,
+            CalendarRangeRequest {
+                grid_id: test.grid_id.clone(),
+                date_field_id: fields.items[0].id.clone(),
+                start: NOVEMBER.0,
+                end: NOVEMBER.1,
+            },
+        )
+        .await
+        .error::<GridError>();
+    assert_eq!(error.code, ErrorCode::DateFieldInvalid);
+}
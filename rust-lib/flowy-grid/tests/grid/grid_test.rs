@@ -0,0 +1,414 @@
+use flowy_grid::{
+    entities::grid::*,
+    errors::{ErrorCode, GridError},
+    event::GridEvent::*,
+    services::grid::{ImportGridParams, InvalidCell},
+};
+use flowy_test::{builder::GridTest, FlowyTest};
+use serial_test::*;
+
+async fn create_grid(test: &FlowyTest) -> Grid {
+    GridTest::new(test.sdk())
+        .event(CreateGrid)
+        .request(CreateGridRequest {
+            name: "Tasks".to_owned(),
+        })
+        .async_send()
+        .await
+        .parse::<Grid>()
+}
+
+async fn create_field(test: &FlowyTest, grid_id: &str, name: &str, field_type: FieldType) -> Field {
+    GridTest::new(test.sdk())
+        .event(CreateField)
+        .request(CreateFieldRequest {
+            grid_id: grid_id.to_owned(),
+            name: name.to_owned(),
+            field_type,
+            width: 0,
+            type_option: FieldTypeOption::default(),
+        })
+        .async_send()
+        .await
+        .parse::<Field>()
+}
+
+async fn create_row(test: &FlowyTest, grid_id: &str) -> Row {
+    GridTest::new(test.sdk())
+        .event(CreateRow)
+        .request(CreateRowRequest {
+            grid_id: grid_id.to_owned(),
+            start_row_id: None,
+        })
+        .async_send()
+        .await
+        .parse::<Row>()
+}
+
+async fn update_cell(test: &FlowyTest, row_id: &str, field_id: &str, data: &str) -> GridTest {
+    GridTest::new(test.sdk())
+        .event(UpdateCell)
+        .request(CellChangeset {
+            row_id: row_id.to_owned(),
+            field_id: field_id.to_owned(),
+            data: data.to_owned(),
+        })
+        .async_send()
+        .await
+}
+
+async fn read_grid(test: &FlowyTest, grid_id: &str) -> Grid {
+    GridTest::new(test.sdk())
+        .event(ReadGrid)
+        .request(GridIdentifier {
+            grid_id: grid_id.to_owned(),
+        })
+        .async_send()
+        .await
+        .parse::<Grid>()
+}
+
+fn cell_data(grid: &Grid, row_id: &str, field_id: &str) -> String {
+    let index = grid.fields.iter().position(|field| field.id == field_id).unwrap();
+    let row = grid.rows.iter().find(|row| row.id == row_id).unwrap();
+    row.cells[index].data.clone()
+}
+
+#[tokio::test]
+#[serial]
+async fn grid_create_and_read() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let grid = create_grid(&test).await;
+    assert_eq!(grid.name, "Tasks");
+    assert_eq!(grid.fields.len(), 1);
+    assert_eq!(grid.fields[0].name, "Name");
+    assert_eq!(grid.fields[0].field_type, FieldType::RichText);
+    assert!(grid.rows.is_empty());
+
+    let field = create_field(&test, &grid.id, "Estimate", FieldType::Number).await;
+    let row = create_row(&test, &grid.id).await;
+    assert_eq!(row.cells.len(), 2);
+    let _ = update_cell(&test, &row.id, &grid.fields[0].id, "Write the docs").await;
+    let _ = update_cell(&test, &row.id, &field.id, "1,250").await;
+
+    let grid = read_grid(&test, &grid.id).await;
+    assert_eq!(grid.fields[1], field);
+    assert_eq!(cell_data(&grid, &row.id, &grid.fields[0].id), "Write the docs");
+    assert_eq!(cell_data(&grid, &row.id, &field.id), "1250");
+}
+
+#[tokio::test]
+#[serial]
+async fn grid_cell_data_is_checked_against_its_field() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let grid = create_grid(&test).await;
+    let number = create_field(&test, &grid.id, "Estimate", FieldType::Number).await;
+    let checkbox = create_field(&test, &grid.id, "Done", FieldType::Checkbox).await;
+    let date = create_field(&test, &grid.id, "Due", FieldType::DateTime).await;
+    let row = create_row(&test, &grid.id).await;
+
+    let error = update_cell(&test, &row.id, &number.id, "a lot").await.error();
+    assert_eq!(error.code, ErrorCode::CellDataInvalid);
+    let cell = update_cell(&test, &row.id, &checkbox.id, "true").await.parse::<Cell>();
+    assert_eq!(cell.data, "Yes");
+    let cell = update_cell(&test, &row.id, &date.id, "2021-11-14")
+        .await
+        .parse::<Cell>();
+    assert_eq!(cell.data, "1636848000");
+    let cell = update_cell(&test, &row.id, &checkbox.id, "").await.parse::<Cell>();
+    assert_eq!(cell.data, "");
+}
+
+#[tokio::test]
+#[serial]
+async fn grid_select_cell_uses_the_options_of_its_field() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let grid = create_grid(&test).await;
+    let field = GridTest::new(test.sdk())
+        .event(CreateField)
+        .request(CreateFieldRequest {
+            grid_id: grid.id.clone(),
+            name: "Status".to_owned(),
+            field_type: FieldType::SingleSelect,
+            width: 0,
+            type_option: FieldTypeOption {
+                options: vec![SelectOption {
+                    name: "Done".to_owned(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        })
+        .async_send()
+        .await
+        .parse::<Field>();
+    let option_id = field.type_option.options[0].id.clone();
+    assert!(!option_id.is_empty());
+    let row = create_row(&test, &grid.id).await;
+
+    let cell = update_cell(&test, &row.id, &field.id, "done").await.parse::<Cell>();
+    assert_eq!(cell.data, option_id);
+    let error = update_cell(&test, &row.id, &field.id, "Blocked").await.error();
+    assert_eq!(error.code, ErrorCode::CellDataInvalid);
+
+    // Removing the option clears the cells that selected it.
+    let _ = GridTest::new(test.sdk())
+        .event(UpdateField)
+        .request(UpdateFieldRequest {
+            field_id: field.id.clone(),
+            type_option: Some(FieldTypeOption::default()),
+            ..Default::default()
+        })
+        .async_send()
+        .await
+        .parse::<Field>();
+    let grid = read_grid(&test, &grid.id).await;
+    assert_eq!(cell_data(&grid, &row.id, &field.id), "");
+}
+
+#[tokio::test]
+#[serial]
+async fn grid_switch_field_type_converts_cells() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let grid = create_grid(&test).await;
+    let field = create_field(&test, &grid.id, "Tags", FieldType::RichText).await;
+    let row = create_row(&test, &grid.id).await;
+    let _ = update_cell(&test, &row.id, &field.id, "rust, dart").await;
+
+    let field = GridTest::new(test.sdk())
+        .event(SwitchFieldType)
+        .request(SwitchFieldTypeRequest {
+            field_id: field.id.clone(),
+            field_type: FieldType::MultiSelect,
+            type_option: FieldTypeOption::default(),
+        })
+        .async_send()
+        .await
+        .parse::<Field>();
+    assert_eq!(field.field_type, FieldType::MultiSelect);
+    let names = field
+        .type_option
+        .options
+        .iter()
+        .map(|option| option.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["rust", "dart"]);
+
+    let grid = read_grid(&test, &grid.id).await;
+    let option_ids = field
+        .type_option
+        .options
+        .iter()
+        .map(|option| option.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(cell_data(&grid, &row.id, &field.id), option_ids.join(","));
+}
+
+#[tokio::test]
+#[serial]
+async fn grid_sort_and_filter_rows() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let grid = create_grid(&test).await;
+    let field = create_field(&test, &grid.id, "Estimate", FieldType::Number).await;
+    let mut row_ids = vec![];
+    for estimate in &["3", "1", "", "2"] {
+        let row = create_row(&test, &grid.id).await;
+        let _ = update_cell(&test, &row.id, &field.id, estimate).await;
+        row_ids.push(row.id);
+    }
+
+    let rows = GridTest::new(test.sdk())
+        .event(UpdateSorts)
+        .request(UpdateGridSortsRequest {
+            grid_id: grid.id.clone(),
+            items: vec![GridSort {
+                field_id: field.id.clone(),
+                descending: false,
+            }],
+        })
+        .async_send()
+        .await
+        .parse::<RepeatedRow>();
+    let ids = rows.items.iter().map(|row| row.id.clone()).collect::<Vec<_>>();
+    assert_eq!(
+        ids,
+        vec![
+            row_ids[1].clone(),
+            row_ids[3].clone(),
+            row_ids[0].clone(),
+            row_ids[2].clone()
+        ]
+    );
+
+    let rows = GridTest::new(test.sdk())
+        .event(UpdateFilters)
+        .request(UpdateGridFiltersRequest {
+            grid_id: grid.id.clone(),
+            items: vec![GridFilter {
+                field_id: field.id.clone(),
+                condition: FilterCondition::Greater,
+                value: "1".to_owned(),
+            }],
+        })
+        .async_send()
+        .await
+        .parse::<RepeatedRow>();
+    let ids = rows.items.iter().map(|row| row.id.clone()).collect::<Vec<_>>();
+    assert_eq!(ids, vec![row_ids[3].clone(), row_ids[0].clone()]);
+
+    // The setting is kept with the grid.
+    let grid = read_grid(&test, &grid.id).await;
+    assert_eq!(grid.rows, rows.items);
+    assert_eq!(grid.sorts.len(), 1);
+    assert_eq!(grid.filters.len(), 1);
+}
+
+#[tokio::test]
+#[serial]
+async fn grid_filter_must_apply_to_its_field() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let grid = create_grid(&test).await;
+    let field = create_field(&test, &grid.id, "Done", FieldType::Checkbox).await;
+    let error = GridTest::new(test.sdk())
+        .event(UpdateFilters)
+        .request(UpdateGridFiltersRequest {
+            grid_id: grid.id.clone(),
+            items: vec![GridFilter {
+                field_id: field.id.clone(),
+                condition: FilterCondition::Contains,
+                value: "Yes".to_owned(),
+            }],
+        })
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::FilterInvalid);
+}
+
+#[tokio::test]
+#[serial]
+async fn grid_delete_field_and_row() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let grid = create_grid(&test).await;
+    let error = GridTest::new(test.sdk())
+        .event(DeleteField)
+        .request(FieldIdentifier {
+            field_id: grid.fields[0].id.clone(),
+        })
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::LastFieldCanNotBeDeleted);
+
+    let field = create_field(&test, &grid.id, "Estimate", FieldType::Number).await;
+    let row = create_row(&test, &grid.id).await;
+    let _ = GridTest::new(test.sdk())
+        .event(DeleteField)
+        .request(FieldIdentifier {
+            field_id: field.id.clone(),
+        })
+        .async_send()
+        .await;
+    let _ = GridTest::new(test.sdk())
+        .event(DeleteRow)
+        .request(RowIdentifier { row_id: row.id })
+        .async_send()
+        .await;
+    let grid = read_grid(&test, &grid.id).await;
+    assert_eq!(grid.fields.len(), 1);
+    assert!(grid.rows.is_empty());
+
+    let _ = GridTest::new(test.sdk())
+        .event(DeleteGrid)
+        .request(GridIdentifier {
+            grid_id: grid.id.clone(),
+        })
+        .async_send()
+        .await;
+    let error: GridError = GridTest::new(test.sdk())
+        .event(ReadGrid)
+        .request(GridIdentifier { grid_id: grid.id })
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::GridNotFound);
+}
+
+#[tokio::test]
+#[serial]
+async fn grid_import_infers_the_field_types() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let row = |cells: &[&str]| cells.iter().map(|cell| cell.to_string()).collect::<Vec<_>>();
+    let params = ImportGridParams {
+        grid_id: "".to_owned(),
+        name: "Tasks".to_owned(),
+        columns: row(&["Name", "Estimate", "Done", "Due", "Status", "Tags"]),
+        rows: vec![
+            row(&["Write", "1,000", "Yes", "November 17, 2021", "Done", "a, b"]),
+            row(&["Review", "2.5", "No", "2021-11-18", "Done", "b"]),
+            row(&["Ship", "3", "", "soon", "Todo", "a"]),
+            row(&["Rest", "", "No", "", "Todo", "b"]),
+        ],
+    };
+    let result = test.sdk().grid.import_grid(params).unwrap();
+    let grid = read_grid(&test, &result.grid.id).await;
+    let field_types = grid.fields.iter().map(|field| field.field_type).collect::<Vec<_>>();
+    assert_eq!(
+        field_types,
+        vec![
+            FieldType::RichText,
+            FieldType::Number,
+            FieldType::Checkbox,
+            FieldType::RichText,
+            FieldType::SingleSelect,
+            FieldType::MultiSelect,
+        ]
+    );
+    assert_eq!(grid.rows.len(), 4);
+    assert_eq!(cell_data(&grid, &grid.rows[0].id, &grid.fields[1].id), "1000");
+    assert_eq!(grid.fields[4].type_option.options.len(), 2);
+
+    // The dates don't all fit, so the column is text.
+    assert!(result.invalid_cells.is_empty());
+    assert_eq!(cell_data(&grid, &grid.rows[2].id, &grid.fields[3].id), "soon");
+
+    let params = ImportGridParams {
+        grid_id: "".to_owned(),
+        name: "Events".to_owned(),
+        columns: row(&["Name", "Date", "Guests"]),
+        rows: (0..10)
+            .map(|i| {
+                row(&[
+                    "Launch",
+                    "November 17, 2021 3:04 PM → November 18, 2021",
+                    &i.to_string(),
+                ])
+            })
+            .chain(std::iter::once(row(&["Party", "2021-11-20", "n/a"])))
+            .collect(),
+    };
+    let result = test.sdk().grid.import_grid(params).unwrap();
+    assert_eq!(result.grid.fields[1].field_type, FieldType::DateTime);
+    assert!(result.grid.fields[1].type_option.include_time);
+    assert_eq!(result.grid.rows[0].cells[1].data, "1637161440");
+
+    // The value that doesn't fit the type of most of the column is dropped.
+    assert_eq!(result.grid.fields[2].field_type, FieldType::Number);
+    assert_eq!(result.grid.rows[10].cells[2].data, "");
+    assert_eq!(
+        result.invalid_cells,
+        vec![InvalidCell {
+            row: 10,
+            column: "Guests".to_owned(),
+            data: "n/a".to_owned(),
+        }]
+    );
+}
@@ -0,0 +1,3 @@
+mod board_test;
+mod calendar_test;
+mod grid_test;
@@ -0,0 +1,268 @@
+use parking_lot::RwLock;
+use serde_json::Value;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    path::Path,
+};
+use tokio::sync::broadcast;
+
+// The locale the other locales fall back to, its catalog has every key.
+pub const DEFAULT_LOCALE: &str = "en";
+
+const BUNDLED_CATALOGS: [(&str, &str); 2] = [
+    ("en", include_str!("../catalogs/en.json")),
+    ("fr", include_str!("../catalogs/fr.json")),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CatalogError {
+    LocaleNotFound(String),
+    CatalogInvalid(String),
+}
+
+impl fmt::Display for CatalogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CatalogError::LocaleNotFound(locale) => write!(f, "There is no catalog for the locale {}", locale),
+            CatalogError::CatalogInvalid(msg) => write!(f, "The catalog is invalid: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for CatalogError {}
+
+// The translations of the locales, their keys are the same as the ones of the
+// frontend, e.g. "error.user.EmailIsEmpty". The catalogs are JSON objects whose
+// nested objects are joined with dots.
+pub struct Catalog {
+    translations: RwLock<HashMap<String, HashMap<String, String>>>,
+    locale: RwLock<String>,
+    notifier: broadcast::Sender<String>,
+}
+
+impl Catalog {
+    pub fn new() -> Self {
+        let (notifier, _) = broadcast::channel(10);
+        Self {
+            translations: RwLock::new(HashMap::new()),
+            locale: RwLock::new(DEFAULT_LOCALE.to_owned()),
+            notifier,
+        }
+    }
+
+    // The catalogs that are built with the sdk.
+    pub fn bundled() -> Self {
+        let catalog = Self::new();
+        for (locale, json) in BUNDLED_CATALOGS.iter() {
+            if let Err(e) = catalog.load(locale, json) {
+                log::error!("Load the {} catalog failed: {}", locale, e);
+            }
+        }
+        catalog
+    }
+
+    // Adds the translations to the ones of the locale, the keys that are already
+    // there are replaced. Returns the number of translations of the json.
+    pub fn load(&self, locale: &str, json: &str) -> Result<usize, CatalogError> {
+        let value: Value = serde_json::from_str(json).map_err(|e| CatalogError::CatalogInvalid(format!("{}", e)))?;
+        let mut items = HashMap::new();
+        let _ = flatten("", &value, &mut items)?;
+        let count = items.len();
+        self.translations
+            .write()
+            .entry(locale.to_owned())
+            .or_insert_with(HashMap::new)
+            .extend(items);
+        Ok(count)
+    }
+
+    // Loads the <locale>.json files of the directory, the ones that are invalid
+    // are skipped. Returns the number of catalogs that were loaded.
+    pub fn load_dir(&self, dir: &str) -> usize {
+        let entries = match std::fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(e) => {
+                log::error!("Read the catalogs of {} failed: {}", dir, e);
+                return 0;
+            },
+        };
+
+        let mut count = 0;
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            let locale = match path.file_stem().and_then(|stem| stem.to_str()) {
+                Some(locale) => locale.to_owned(),
+                None => continue,
+            };
+            match read_catalog(&path).and_then(|json| self.load(&locale, &json)) {
+                Ok(_) => count += 1,
+                Err(e) => log::error!("Load the catalog {:?} failed: {}", path, e),
+            }
+        }
+        count
+    }
+
+    pub fn locales(&self) -> Vec<String> {
+        let mut locales = self.translations.read().keys().cloned().collect::<Vec<_>>();
+        locales.sort();
+        locales
+    }
+
+    pub fn locale(&self) -> String { self.locale.read().clone() }
+
+    // Returns the locale that is used, e.g. fr for fr-CA if there is no catalog
+    // for fr-CA. The subscribers are sent the locale if it changes.
+    pub fn set_locale(&self, locale: &str) -> Result<String, CatalogError> {
+        let locale = self.resolve(locale)?;
+        let changed = {
+            let mut current = self.locale.write();
+            let changed = *current != locale;
+            *current = locale.clone();
+            changed
+        };
+        if changed {
+            let _ = self.notifier.send(locale.clone());
+        }
+        Ok(locale)
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<String> { self.notifier.subscribe() }
+
+    // The translations of the locale with the ones of the default locale for the
+    // keys it doesn't have, and the locale they are for.
+    pub fn translations(&self, locale: &str) -> Result<(String, BTreeMap<String, String>), CatalogError> {
+        let locale = self.resolve(locale)?;
+        let translations = self.translations.read();
+        let mut items = BTreeMap::new();
+        for locale in &[DEFAULT_LOCALE, locale.as_str()] {
+            if let Some(catalog) = translations.get(*locale) {
+                items.extend(catalog.iter().map(|(key, value)| (key.clone(), value.clone())));
+            }
+        }
+        Ok((locale, items))
+    }
+
+    // Translates the key with the current locale, falls back to the default
+    // locale and then to the key itself.
+    pub fn tr(&self, key: &str) -> String { self.tr_or(key, key) }
+
+    pub fn tr_or(&self, key: &str, default: &str) -> String {
+        let locale = self.locale();
+        let translations = self.translations.read();
+        for locale in &[locale.as_str(), DEFAULT_LOCALE] {
+            if let Some(value) = translations.get(*locale).and_then(|catalog| catalog.get(key)) {
+                return value.clone();
+            }
+        }
+        default.to_owned()
+    }
+
+    // Replaces the {name} of the translation with the value of the name.
+    pub fn format(&self, key: &str, args: &[(&str, &str)]) -> String {
+        let mut text = self.tr(key);
+        for (name, value) in args {
+            text = text.replace(&format!("{{{}}}", name), value);
+        }
+        text
+    }
+
+    fn resolve(&self, locale: &str) -> Result<String, CatalogError> {
+        let locale = locale.trim().replace('_', "-");
+        let translations = self.translations.read();
+        if translations.contains_key(&locale) {
+            return Ok(locale);
+        }
+
+        let language = locale.split('-').next().unwrap_or("");
+        match translations.contains_key(language) {
+            true => Ok(language.to_owned()),
+            false => Err(CatalogError::LocaleNotFound(locale)),
+        }
+    }
+}
+
+impl std::default::Default for Catalog {
+    fn default() -> Self { Self::new() }
+}
+
+fn read_catalog(path: &Path) -> Result<String, CatalogError> {
+    std::fs::read_to_string(path).map_err(|e| CatalogError::CatalogInvalid(format!("{}", e)))
+}
+
+fn flatten(prefix: &str, value: &Value, items: &mut HashMap<String, String>) -> Result<(), CatalogError> {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let key = match prefix.is_empty() {
+                    true => key.clone(),
+                    false => format!("{}.{}", prefix, key),
+                };
+                let _ = flatten(&key, value, items)?;
+            }
+            Ok(())
+        },
+        Value::String(text) if !prefix.is_empty() => {
+            items.insert(prefix.to_owned(), text.clone());
+            Ok(())
+        },
+        _ => Err(CatalogError::CatalogInvalid(format!(
+            "{} must be a string or an object",
+            prefix
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catalog_falls_back_to_the_language_and_the_default_locale() {
+        let catalog = Catalog::new();
+        let _ = catalog
+            .load("en", r#"{"greeting": "Hello {name}", "menu": {"quit": "Quit"}}"#)
+            .unwrap();
+        let _ = catalog.load("fr", r#"{"greeting": "Bonjour {name}"}"#).unwrap();
+
+        assert_eq!(catalog.set_locale("fr_CA").unwrap(), "fr");
+        assert_eq!(catalog.format("greeting", &[("name", "Nathan")]), "Bonjour Nathan");
+        assert_eq!(catalog.tr("menu.quit"), "Quit");
+        assert_eq!(catalog.tr("menu.open"), "menu.open");
+        assert_eq!(catalog.tr_or("menu.open", "Open"), "Open");
+
+        let (locale, items) = catalog.translations("fr").unwrap();
+        assert_eq!(locale, "fr");
+        assert_eq!(items.len(), 2);
+        assert_eq!(items["menu.quit"], "Quit");
+        assert_eq!(
+            catalog.set_locale("de"),
+            Err(CatalogError::LocaleNotFound("de".to_owned()))
+        );
+        assert_eq!(catalog.locale(), "fr");
+    }
+
+    #[test]
+    fn catalog_rejects_the_values_that_are_not_strings() {
+        let catalog = Catalog::new();
+        assert!(catalog.load("en", r#"{"count": 1}"#).is_err());
+        assert!(catalog.load("en", r#""text""#).is_err());
+        assert!(catalog.locales().is_empty());
+    }
+
+    #[test]
+    fn bundled_catalogs_have_the_keys_of_the_default_locale() {
+        let catalog = Catalog::bundled();
+        let translations = catalog.translations.read();
+        let keys = |locale: &str| {
+            let mut keys = translations[locale].keys().cloned().collect::<Vec<_>>();
+            keys.sort();
+            keys
+        };
+        for locale in translations.keys() {
+            assert_eq!(keys(locale), keys(DEFAULT_LOCALE), "{}", locale);
+        }
+    }
+}
@@ -0,0 +1,21 @@
+mod catalog;
+
+pub use catalog::*;
+
+lazy_static::lazy_static! {
+    static ref CATALOG: Catalog = Catalog::bundled();
+}
+
+// The catalog the errors and the notifications of the modules are translated
+// with, its locale is the one the frontend set.
+pub fn catalog() -> &'static Catalog { &CATALOG }
+
+// The types whose messages are shown to the user, e.g. the error codes. The
+// message is the default one if the catalogs don't have its key.
+pub trait MessageKey {
+    fn message_key(&self) -> String;
+
+    fn default_message(&self) -> String;
+
+    fn localized_message(&self) -> String { catalog().tr_or(&self.message_key(), &self.default_message()) }
+}
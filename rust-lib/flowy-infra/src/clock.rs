@@ -0,0 +1,115 @@
+use crate::future::{wrap_future, FnFuture};
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::sync::oneshot;
+
+// The time of the timeouts, the expirations and the scheduled events. The
+// SystemClock is the one of the app, the tests drive the ManualClock instead
+// of sleeping.
+pub trait Clock: Send + Sync {
+    // Milliseconds since the epoch.
+    fn timestamp_millis(&self) -> i64;
+
+    // Seconds since the epoch, like flowy_infra::timestamp.
+    fn timestamp(&self) -> i64 { self.timestamp_millis() / 1000 }
+
+    fn sleep(&self, duration: Duration) -> FnFuture<()>;
+}
+
+impl fmt::Debug for dyn Clock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "Clock({})", self.timestamp_millis()) }
+}
+
+pub fn system_clock() -> Arc<dyn Clock> { Arc::new(SystemClock) }
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn timestamp_millis(&self) -> i64 { chrono::Utc::now().timestamp_millis() }
+
+    fn sleep(&self, duration: Duration) -> FnFuture<()> { wrap_future(tokio::time::sleep(duration)) }
+}
+
+// Stands still until it's advanced, the sleeps end when the time passes their
+// deadline.
+pub struct ManualClock {
+    state: Mutex<ManualClockState>,
+}
+
+struct ManualClockState {
+    now: i64,
+    sleepers: Vec<(i64, oneshot::Sender<()>)>,
+}
+
+impl ManualClock {
+    pub fn new(timestamp_millis: i64) -> Self {
+        Self {
+            state: Mutex::new(ManualClockState {
+                now: timestamp_millis,
+                sleepers: vec![],
+            }),
+        }
+    }
+
+    // Starts at the current time of the system.
+    pub fn now() -> Self { Self::new(SystemClock.timestamp_millis()) }
+
+    pub fn advance(&self, duration: Duration) {
+        let mut state = self.state.lock().unwrap();
+        state.now += duration.as_millis() as i64;
+        let now = state.now;
+        let (expired, sleepers) = std::mem::take(&mut state.sleepers)
+            .into_iter()
+            .partition::<Vec<_>, _>(|(deadline, _)| *deadline <= now);
+        state.sleepers = sleepers;
+        for (_, sender) in expired {
+            let _ = sender.send(());
+        }
+    }
+
+    // The sleeps that didn't end yet.
+    pub fn sleepers(&self) -> usize { self.state.lock().unwrap().sleepers.len() }
+}
+
+impl Clock for ManualClock {
+    fn timestamp_millis(&self) -> i64 { self.state.lock().unwrap().now }
+
+    fn sleep(&self, duration: Duration) -> FnFuture<()> {
+        let (sender, receiver) = oneshot::channel();
+        let mut state = self.state.lock().unwrap();
+        if duration.as_millis() == 0 {
+            let _ = sender.send(());
+        } else {
+            let deadline = state.now + duration.as_millis() as i64;
+            state.sleepers.push((deadline, sender));
+        }
+        wrap_future(async move {
+            let _ = receiver.await;
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn manual_clock_wakes_the_expired_sleeps() {
+        let clock = ManualClock::new(0);
+        let short = clock.sleep(Duration::from_secs(1));
+        let long = clock.sleep(Duration::from_secs(10));
+        assert_eq!(clock.sleepers(), 2);
+
+        clock.advance(Duration::from_secs(2));
+        assert_eq!(clock.timestamp(), 2);
+        assert_eq!(clock.sleepers(), 1);
+        short.await;
+
+        clock.advance(Duration::from_secs(8));
+        assert_eq!(clock.sleepers(), 0);
+        long.await;
+    }
+}
@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+// The envelope layout: MAGIC(2 bytes) + version(u32, big endian) + payload.
+// The bytes without the magic are treated as version 0, the data persisted before
+// the envelope was introduced.
+const MAGIC: [u8; 2] = [0xF1, 0x0E];
+const HEADER_LEN: usize = MAGIC.len() + 4;
+
+pub type MigrationFn = fn(Vec<u8>) -> Result<Vec<u8>, String>;
+
+pub fn wrap_envelope(version: u32, data: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(HEADER_LEN + data.len());
+    output.extend_from_slice(&MAGIC);
+    output.extend_from_slice(&version.to_be_bytes());
+    output.extend_from_slice(data);
+    output
+}
+
+pub fn unwrap_envelope(bytes: &[u8]) -> (u32, &[u8]) {
+    if bytes.len() < HEADER_LEN || bytes[0..MAGIC.len()] != MAGIC {
+        return (0, bytes);
+    }
+
+    let mut version_bytes = [0; 4];
+    version_bytes.copy_from_slice(&bytes[MAGIC.len()..HEADER_LEN]);
+    (u32::from_be_bytes(version_bytes), &bytes[HEADER_LEN..])
+}
+
+// Holds the migration functions of one kind of payload. Each function upgrades the
+// payload from `version` to `version + 1`, they run in order on decode until the
+// payload reaches the current version.
+pub struct PayloadMigrations {
+    current_version: u32,
+    migrations: HashMap<u32, MigrationFn>,
+}
+
+impl PayloadMigrations {
+    pub fn new(current_version: u32) -> Self {
+        Self {
+            current_version,
+            migrations: HashMap::new(),
+        }
+    }
+
+    pub fn register(mut self, from_version: u32, f: MigrationFn) -> Self {
+        debug_assert!(from_version < self.current_version);
+        self.migrations.insert(from_version, f);
+        self
+    }
+
+    pub fn current_version(&self) -> u32 { self.current_version }
+
+    pub fn encode(&self, data: &[u8]) -> Vec<u8> { wrap_envelope(self.current_version, data) }
+
+    pub fn decode(&self, bytes: &[u8]) -> Result<Vec<u8>, String> {
+        let (mut version, data) = unwrap_envelope(bytes);
+        if version > self.current_version {
+            return Err(format!(
+                "Unsupported payload version {}, the current version is {}",
+                version, self.current_version
+            ));
+        }
+
+        let mut data = data.to_vec();
+        while version < self.current_version {
+            match self.migrations.get(&version) {
+                None => return Err(format!("Missing the migration of payload version {}", version)),
+                Some(migrate) => data = migrate(data)?,
+            }
+            version += 1;
+        }
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::envelope::{unwrap_envelope, wrap_envelope, PayloadMigrations};
+
+    #[test]
+    fn envelope_test() {
+        let bytes = wrap_envelope(3, b"hello");
+        assert_eq!(unwrap_envelope(&bytes), (3, &b"hello"[..]));
+        assert_eq!(unwrap_envelope(b"hello"), (0, &b"hello"[..]));
+    }
+
+    #[test]
+    fn envelope_migration_test() {
+        let migrations = PayloadMigrations::new(2)
+            .register(0, |data| Ok(data))
+            .register(1, |mut data| {
+                data.extend_from_slice(b" world");
+                Ok(data)
+            });
+
+        assert_eq!(migrations.decode(b"hello").unwrap(), b"hello world".to_vec());
+        assert_eq!(migrations.decode(&wrap_envelope(1, b"hello")).unwrap(), b"hello world".to_vec());
+        assert_eq!(migrations.decode(&migrations.encode(b"hello")).unwrap(), b"hello".to_vec());
+        assert!(migrations.decode(&wrap_envelope(3, b"hello")).is_err());
+    }
+}
@@ -14,6 +14,8 @@ lazy_static! {
 pub struct KV {
     database: Option<Database>,
     cache: HashMap<String, KeyValue>,
+    // The values are only kept in the cache, see init_in_memory.
+    in_memory: bool,
 }
 
 impl KV {
@@ -21,12 +23,16 @@ impl KV {
         KV {
             database: None,
             cache: HashMap::new(),
+            in_memory: false,
         }
     }
 
     fn set(value: KeyValue) -> Result<(), String> {
         log::debug!("set value: {:?}", value);
         update_cache(value.clone());
+        if is_in_memory() {
+            return Ok(());
+        }
 
         let _ = diesel::replace_into(kv_table::table)
             .values(&value)
@@ -40,6 +46,9 @@ impl KV {
         if let Some(value) = read_cache(key) {
             return Ok(value);
         }
+        if is_in_memory() {
+            return Err(format!("KV get error: {} not found", key));
+        }
 
         let conn = get_connection()?;
         let value = dsl::kv_table
@@ -61,6 +70,9 @@ impl KV {
             },
             Err(e) => log::error!("Require write lock failed: {:?}", e),
         };
+        if is_in_memory() {
+            return Ok(());
+        }
 
         let conn = get_connection()?;
         let sql = dsl::kv_table.filter(kv_table::key.eq(key));
@@ -84,11 +96,33 @@ impl KV {
             .write()
             .map_err(|e| format!("KVStore write failed: {:?}", e))?;
         store.database = Some(database);
+        store.in_memory = false;
+
+        Ok(())
+    }
 
+    // Nothing is written to the disk, the values are lost when the process
+    // exits. For the tests. The values that were set before are kept.
+    pub fn init_in_memory() -> Result<(), String> {
+        let mut store = KV_HOLDER
+            .write()
+            .map_err(|e| format!("KVStore write failed: {:?}", e))?;
+        store.database = None;
+        store.in_memory = true;
         Ok(())
     }
 }
 
+fn is_in_memory() -> bool {
+    match KV_HOLDER.read() {
+        Ok(guard) => guard.in_memory,
+        Err(e) => {
+            log::error!("Require read lock failed: {:?}", e);
+            false
+        },
+    }
+}
+
 fn read_cache(key: &str) -> Option<KeyValue> {
     match KV_HOLDER.read() {
         Ok(guard) => guard.cache.get(key).cloned(),
@@ -4,10 +4,14 @@ extern crate diesel;
 #[macro_use]
 extern crate diesel_derives;
 
+pub mod clock;
+pub mod envelope;
 pub mod future;
 pub mod kv;
+pub mod memory;
 mod protobuf;
 pub mod retry;
+pub mod secret;
 
 #[allow(dead_code)]
 pub fn uuid() -> String { uuid::Uuid::new_v4().to_string() }
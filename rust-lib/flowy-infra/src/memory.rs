@@ -0,0 +1,199 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+pub const DEFAULT_MEMORY_BUDGET: usize = 64 * 1024 * 1024;
+
+// What a cache does when the budget evicts one of its entries. The entry isn't
+// charged anymore when it's called, the cache only drops it.
+pub trait Evict: Send + Sync {
+    fn evict(&self, key: &str);
+}
+
+type EntryId = (usize, String);
+
+#[derive(Default)]
+struct BudgetState {
+    used: usize,
+    tick: u64,
+    // The bytes of the entries and when they were used last.
+    entries: HashMap<EntryId, (usize, u64)>,
+    lru: BTreeMap<u64, EntryId>,
+    caches: Vec<(&'static str, Arc<dyn Evict>)>,
+}
+
+// One budget for the bytes all the caches keep in memory: the opened documents,
+// the blobs that were read, and so on. When they go over the limit, the entries
+// used least recently are evicted, whichever cache they're in. The host asks
+// for the memory back with trim, e.g. when the os warns that it's low.
+//
+//     let budget = MemoryBudget::new(DEFAULT_MEMORY_BUDGET);
+//     let memory = budget.register("blobs", cache.clone());
+//     memory.charge(&hash, data.len());
+pub struct MemoryBudget {
+    limit: usize,
+    state: Mutex<BudgetState>,
+}
+
+impl MemoryBudget {
+    pub fn new(limit: usize) -> Arc<Self> {
+        Arc::new(Self {
+            limit,
+            state: Mutex::new(BudgetState::default()),
+        })
+    }
+
+    pub fn register(self: &Arc<Self>, name: &'static str, cache: Arc<dyn Evict>) -> CacheMemory {
+        let mut state = self.state.lock().unwrap();
+        state.caches.push((name, cache));
+        CacheMemory {
+            budget: self.clone(),
+            cache: state.caches.len() - 1,
+        }
+    }
+
+    pub fn limit(&self) -> usize { self.limit }
+
+    pub fn used(&self) -> usize { self.state.lock().unwrap().used }
+
+    // The bytes each cache is charged with, by the name it was registered with.
+    pub fn usage(&self) -> Vec<(&'static str, usize, usize)> {
+        let state = self.state.lock().unwrap();
+        let mut usage = state.caches.iter().map(|(name, _)| (*name, 0, 0)).collect::<Vec<_>>();
+        for ((cache, _), (bytes, _)) in state.entries.iter() {
+            usage[*cache].1 += 1;
+            usage[*cache].2 += bytes;
+        }
+        usage
+    }
+
+    // Evicts the entries used least recently until the caches keep at most the
+    // target, returns how many bytes were released.
+    pub fn trim(&self, target: usize) -> usize { self.evict(target, None) }
+
+    fn evict(&self, target: usize, keep: Option<&EntryId>) -> usize {
+        let mut released = 0;
+        let mut evicted = vec![];
+        {
+            let mut state = self.state.lock().unwrap();
+            while state.used > target {
+                let (tick, id) = match state.lru.iter().next() {
+                    Some((tick, id)) => (*tick, id.clone()),
+                    None => break,
+                };
+                // The entry that is being charged is the one in use.
+                if Some(&id) == keep {
+                    break;
+                }
+                state.lru.remove(&tick);
+                if let Some((bytes, _)) = state.entries.remove(&id) {
+                    state.used -= bytes;
+                    released += bytes;
+                }
+                evicted.push((state.caches[id.0].1.clone(), id.1));
+            }
+        }
+        // The caches are called without the lock, they can charge again.
+        for (cache, key) in evicted {
+            cache.evict(&key);
+        }
+        released
+    }
+}
+
+impl fmt::Debug for MemoryBudget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MemoryBudget({}/{})", self.used(), self.limit)
+    }
+}
+
+// The entries of one cache in the budget, by the keys of the cache.
+#[derive(Clone)]
+pub struct CacheMemory {
+    budget: Arc<MemoryBudget>,
+    cache: usize,
+}
+
+impl CacheMemory {
+    // Charges the entry with its size, or its new size, as it's used now. The
+    // other entries are evicted if the budget is over its limit.
+    pub fn charge(&self, key: &str, bytes: usize) {
+        let id = (self.cache, key.to_owned());
+        {
+            let mut state = self.budget.state.lock().unwrap();
+            state.tick += 1;
+            let tick = state.tick;
+            if let Some((old_bytes, old_tick)) = state.entries.insert(id.clone(), (bytes, tick)) {
+                state.used -= old_bytes;
+                state.lru.remove(&old_tick);
+            }
+            state.used += bytes;
+            state.lru.insert(tick, id.clone());
+        }
+        self.budget.evict(self.budget.limit, Some(&id));
+    }
+
+    // Marks the entry as used, it's evicted after the others.
+    pub fn touch(&self, key: &str) {
+        let id = (self.cache, key.to_owned());
+        let mut guard = self.budget.state.lock().unwrap();
+        let state = &mut *guard;
+        state.tick += 1;
+        let tick = state.tick;
+        if let Some(entry) = state.entries.get_mut(&id) {
+            let old_tick = std::mem::replace(&mut entry.1, tick);
+            state.lru.remove(&old_tick);
+            state.lru.insert(tick, id);
+        }
+    }
+
+    // The cache dropped the entry by itself.
+    pub fn release(&self, key: &str) {
+        let id = (self.cache, key.to_owned());
+        let mut state = self.budget.state.lock().unwrap();
+        if let Some((bytes, tick)) = state.entries.remove(&id) {
+            state.used -= bytes;
+            state.lru.remove(&tick);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct EvictedKeys(Mutex<Vec<String>>);
+
+    impl Evict for EvictedKeys {
+        fn evict(&self, key: &str) { self.0.lock().unwrap().push(key.to_owned()); }
+    }
+
+    #[test]
+    fn budget_evicts_the_least_recently_used_entries_of_all_caches() {
+        let budget = MemoryBudget::new(100);
+        let (docs, blobs) = (Arc::new(EvictedKeys::default()), Arc::new(EvictedKeys::default()));
+        let doc_memory = budget.register("docs", docs.clone());
+        let blob_memory = budget.register("blobs", blobs.clone());
+
+        doc_memory.charge("a", 40);
+        blob_memory.charge("b", 40);
+        doc_memory.touch("a");
+        blob_memory.charge("c", 40);
+        assert_eq!(*blobs.0.lock().unwrap(), vec!["b".to_owned()]);
+        assert!(docs.0.lock().unwrap().is_empty());
+        assert_eq!(budget.used(), 80);
+        assert_eq!(budget.usage(), vec![("docs", 1, 40), ("blobs", 1, 40)]);
+
+        // The entry that is charged is kept even if it's over the limit alone.
+        doc_memory.charge("a", 120);
+        assert_eq!(*blobs.0.lock().unwrap(), vec!["b".to_owned(), "c".to_owned()]);
+        assert_eq!(budget.used(), 120);
+
+        assert_eq!(budget.trim(0), 120);
+        assert_eq!(*docs.0.lock().unwrap(), vec!["a".to_owned()]);
+        assert_eq!(budget.used(), 0);
+    }
+}
@@ -0,0 +1,151 @@
+use chacha20poly1305::{
+    aead::{Aead, NewAead, Payload},
+    Key,
+    XChaCha20Poly1305,
+    XNonce,
+};
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha2::Sha256;
+
+pub(crate) const KEY_LEN: usize = 32;
+
+// The layout of the encrypted payload: MAGIC(2 bytes) + nonce(24 bytes) +
+// ciphertext + tag(16 bytes). The magic is authenticated with the ciphertext.
+const MAGIC: [u8; 2] = [0xF1, 0x5E];
+const NONCE_LEN: usize = 24;
+const TAG_LEN: usize = 16;
+const HEADER_LEN: usize = MAGIC.len() + NONCE_LEN;
+
+type HmacSha256 = Hmac<Sha256>;
+
+// Encrypts the payloads that are kept at rest with XChaCha20-Poly1305, so a
+// payload that was changed or encrypted with another key is refused instead of
+// being decrypted into garbage. The nonces are random, they're long enough to
+// never repeat.
+#[derive(Clone)]
+pub struct Cipher {
+    key: [u8; KEY_LEN],
+}
+
+impl Cipher {
+    // The key is derived from the secret and the context, e.g. the id of the
+    // document, so each context gets its own key from the same secret.
+    pub fn new(secret: &[u8], context: &str) -> Self {
+        Self {
+            key: hmac_sha256(secret, context.as_bytes()),
+        }
+    }
+
+    pub fn encrypt(&self, data: &[u8]) -> Vec<u8> {
+        let mut nonce = [0u8; NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        let ciphertext = self
+            .aead()
+            .encrypt(XNonce::from_slice(&nonce), Payload { msg: data, aad: &MAGIC })
+            .expect("The payload is too large to be encrypted");
+
+        let mut output = Vec::with_capacity(HEADER_LEN + ciphertext.len());
+        output.extend_from_slice(&MAGIC);
+        output.extend_from_slice(&nonce);
+        output.extend_from_slice(&ciphertext);
+        output
+    }
+
+    pub fn decrypt(&self, bytes: &[u8]) -> Result<Vec<u8>, String> {
+        if !is_encrypted(bytes) {
+            return Err("The data isn't encrypted".to_owned());
+        }
+
+        let nonce = XNonce::from_slice(&bytes[MAGIC.len()..HEADER_LEN]);
+        let payload = Payload {
+            msg: &bytes[HEADER_LEN..],
+            aad: &MAGIC,
+        };
+        self.aead()
+            .decrypt(nonce, payload)
+            .map_err(|_| "The data was changed or encrypted with another key".to_owned())
+    }
+
+    // The text columns keep the encrypted payload in hex.
+    pub fn encrypt_text(&self, text: &str) -> String { hex_encode(&self.encrypt(text.as_bytes())) }
+
+    pub fn decrypt_text(&self, text: &str) -> Result<String, String> {
+        let bytes = hex_decode(text).ok_or_else(|| "The data isn't encrypted".to_owned())?;
+        let data = self.decrypt(&bytes)?;
+        String::from_utf8(data).map_err(|_| "The decrypted data isn't utf8".to_owned())
+    }
+
+    fn aead(&self) -> XChaCha20Poly1305 { XChaCha20Poly1305::new(Key::from_slice(&self.key)) }
+}
+
+pub fn is_encrypted(bytes: &[u8]) -> bool { bytes.len() >= HEADER_LEN + TAG_LEN && bytes[0..MAGIC.len()] == MAGIC }
+
+// The text that encrypt_text returned.
+pub fn is_encrypted_text(text: &str) -> bool { hex_decode(text).map_or(false, |bytes| is_encrypted(&bytes)) }
+
+// A random secret that can be kept in the secret store.
+pub fn generate_secret() -> String {
+    let mut secret = [0u8; KEY_LEN];
+    rand::thread_rng().fill_bytes(&mut secret);
+    hex_encode(&secret)
+}
+
+// A key for the context derived from the secret, for the ciphers that aren't
+// this one, e.g. SQLCipher's.
+pub fn derive_key(secret: &[u8], context: &str) -> [u8; KEY_LEN] { hmac_sha256(secret, context.as_bytes()) }
+
+// The hex HMAC-SHA256 of the data with the key, e.g. the signature of the body
+// of a webhook with its secret.
+pub fn sign(key: &[u8], data: &[u8]) -> String { hex_encode(&hmac_sha256(key, data)) }
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; KEY_LEN] {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    let mut output = [0u8; KEY_LEN];
+    output.copy_from_slice(&mac.finalize().into_bytes());
+    output
+}
+
+pub(crate) fn hex_encode(bytes: &[u8]) -> String { bytes.iter().map(|b| format!("{:02x}", b)).collect() }
+
+pub(crate) fn hex_decode(s: &str) -> Option<Vec<u8>> {
+    s.as_bytes()
+        .chunks(2)
+        .map(|chunk| match chunk {
+            [high, low] => u8::from_str_radix(std::str::from_utf8(&[*high, *low]).ok()?, 16).ok(),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cipher_encrypt_decrypt() {
+        let cipher = Cipher::new(b"secret", "doc_1");
+        let encrypted = cipher.encrypt(b"hello world");
+        assert!(is_encrypted(&encrypted));
+        assert!(!encrypted.windows(5).any(|window| window == b"hello"));
+        assert_eq!(cipher.decrypt(&encrypted).unwrap(), b"hello world".to_vec());
+
+        let text = cipher.encrypt_text("[{\"insert\":\"hello\"}]");
+        assert_eq!(cipher.decrypt_text(&text).unwrap(), "[{\"insert\":\"hello\"}]");
+        assert!(is_encrypted_text(&text));
+        assert!(!is_encrypted_text("[{\"insert\":\"hello\"}]"));
+    }
+
+    #[test]
+    fn cipher_refuses_other_keys_and_changed_data() {
+        let cipher = Cipher::new(b"secret", "doc_1");
+        let mut encrypted = cipher.encrypt(b"hello world");
+        assert!(Cipher::new(b"secret", "doc_2").decrypt(&encrypted).is_err());
+        assert!(Cipher::new(b"other secret", "doc_1").decrypt(&encrypted).is_err());
+        assert!(cipher.decrypt(b"hello world").is_err());
+
+        encrypted[HEADER_LEN] ^= 1;
+        assert!(cipher.decrypt(&encrypted).is_err());
+    }
+}
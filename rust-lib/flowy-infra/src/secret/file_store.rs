@@ -0,0 +1,219 @@
+use crate::secret::{
+    cipher::{hex_encode, KEY_LEN},
+    Cipher,
+    SecretStore,
+};
+use lazy_static::lazy_static;
+use rand::RngCore;
+use std::{
+    fs,
+    io,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+const SECRET_DIR: &str = "secrets";
+const SECRET_KEY_FILE: &str = ".key";
+
+lazy_static! {
+    // The threads that use the store for the first time wait for the one that
+    // creates the key instead of creating their own.
+    static ref SECRET_KEY_LOCK: Mutex<()> = Mutex::new(());
+}
+
+// The fallback for the platforms without a credential store. Each secret is
+// encrypted with the Cipher into its own file, a file that was changed or moved
+// to another secret is refused. The key is only readable by the current user.
+// It keeps the secrets out of the databases and the backups of the app
+// directory, but it can't protect them from someone who can read the key, so
+// the platform store is always preferred.
+pub struct FileSecretStore {
+    dir: PathBuf,
+}
+
+impl FileSecretStore {
+    pub fn new(root: &str) -> Self {
+        Self {
+            dir: Path::new(root).join(SECRET_DIR),
+        }
+    }
+
+    fn secret_path(&self, key: &str) -> PathBuf { self.dir.join(hex_encode(key.as_bytes())) }
+
+    fn read_or_create_key(&self) -> Result<[u8; KEY_LEN], String> {
+        let path = self.dir.join(SECRET_KEY_FILE);
+        let _guard = SECRET_KEY_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(key) = read_key(&path)? {
+            return Ok(key);
+        }
+
+        fs::create_dir_all(&self.dir).map_err(|e| format!("Create secret dir failed: {:?}", e))?;
+        let mut key = [0u8; KEY_LEN];
+        rand::thread_rng().fill_bytes(&mut key);
+        match private_file_options().create_new(true).open(&path) {
+            Ok(mut file) => match file.write_all(&key) {
+                Ok(()) => Ok(key),
+                Err(e) => {
+                    let _ = fs::remove_file(&path);
+                    Err(format!("Write secret key failed: {:?}", e))
+                },
+            },
+            // Another process created it first, the secrets are encrypted with its
+            // key.
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                read_key(&path)?.ok_or_else(|| "The secret key is corrupted".to_owned())
+            },
+            Err(e) => Err(format!("Create secret key failed: {:?}", e)),
+        }
+    }
+
+    // The name of the secret is the context, so a file can't be swapped with the
+    // one of another secret.
+    fn cipher(&self, key: &str) -> Result<Cipher, String> {
+        let cipher_key = self.read_or_create_key()?;
+        Ok(Cipher::new(&cipher_key, key))
+    }
+}
+
+impl SecretStore for FileSecretStore {
+    fn name(&self) -> &'static str { "file" }
+
+    fn set(&self, key: &str, secret: &str) -> Result<(), String> {
+        let cipher = self.cipher(key)?;
+        let content = cipher.encrypt_text(secret);
+        write_private_file(&self.secret_path(key), content.as_bytes())
+    }
+
+    fn get(&self, key: &str) -> Result<Option<String>, String> {
+        let path = self.secret_path(key);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(&path).map_err(|e| format!("Read secret failed: {:?}", e))?;
+        let secret = self
+            .cipher(key)?
+            .decrypt_text(&content)
+            .map_err(|e| format!("The secret of {} is corrupted: {}", key, e))?;
+        Ok(Some(secret))
+    }
+
+    fn remove(&self, key: &str) -> Result<(), String> {
+        let path = self.secret_path(key);
+        if path.exists() {
+            fs::remove_file(&path).map_err(|e| format!("Remove secret failed: {:?}", e))?;
+        }
+        Ok(())
+    }
+}
+
+fn read_key(path: &Path) -> Result<Option<[u8; KEY_LEN]>, String> {
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("Read secret key failed: {:?}", e)),
+    };
+    if bytes.len() != KEY_LEN {
+        return Err("The secret key is corrupted".to_owned());
+    }
+    let mut key = [0u8; KEY_LEN];
+    key.copy_from_slice(&bytes);
+    Ok(Some(key))
+}
+
+fn write_private_file(path: &Path, content: &[u8]) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Create secret dir failed: {:?}", e))?;
+    }
+
+    let mut file = private_file_options()
+        .create(true)
+        .truncate(true)
+        .open(path)
+        .map_err(|e| format!("Open secret file failed: {:?}", e))?;
+    file.write_all(content)
+        .map_err(|e| format!("Write secret file failed: {:?}", e))
+}
+
+// Only readable by the current user.
+fn private_file_options() -> fs::OpenOptions {
+    let mut options = fs::OpenOptions::new();
+    options.write(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_store() -> (FileSecretStore, PathBuf) {
+        let root = std::env::temp_dir().join(format!("flowy_secret_{}", crate::uuid()));
+        fs::create_dir_all(&root).unwrap();
+        (FileSecretStore::new(root.to_str().unwrap()), root)
+    }
+
+    #[test]
+    fn file_store_set_get_remove() {
+        let (store, root) = test_store();
+        assert_eq!(store.get("token").unwrap(), None);
+
+        store.set("token", "my secret token").unwrap();
+        assert_eq!(store.get("token").unwrap(), Some("my secret token".to_owned()));
+
+        store.remove("token").unwrap();
+        assert_eq!(store.get("token").unwrap(), None);
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn file_store_does_not_write_plaintext() {
+        let (store, root) = test_store();
+        store.set("token", "my secret token").unwrap();
+        let content = fs::read_to_string(store.secret_path("token")).unwrap();
+        assert!(!content.contains("my secret token"));
+        assert!(!content.contains(&hex_encode(b"my secret token")));
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn file_store_key_created_once() {
+        let (store, root) = test_store();
+        let root_path = root.to_str().unwrap().to_owned();
+        let handles = (0..8)
+            .map(|_| {
+                let root_path = root_path.clone();
+                std::thread::spawn(move || FileSecretStore::new(&root_path).read_or_create_key().unwrap())
+            })
+            .collect::<Vec<_>>();
+        let keys = handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>();
+        assert!(keys.iter().all(|key| *key == keys[0]));
+        assert_eq!(store.read_or_create_key().unwrap(), keys[0]);
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn file_store_refuses_changed_files() {
+        let (store, root) = test_store();
+        store.set("token", "my secret token").unwrap();
+        store.set("other", "other token").unwrap();
+
+        let mut content = fs::read(store.secret_path("token")).unwrap();
+        let last = content.len() - 1;
+        content[last] = if content[last] == b'0' { b'1' } else { b'0' };
+        fs::write(store.secret_path("token"), &content).unwrap();
+        assert!(store.get("token").is_err());
+
+        fs::copy(store.secret_path("other"), store.secret_path("token")).unwrap();
+        assert!(store.get("token").is_err());
+        let _ = fs::remove_dir_all(root);
+    }
+}
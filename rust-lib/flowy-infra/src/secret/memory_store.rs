@@ -0,0 +1,38 @@
+use crate::secret::SecretStore;
+use std::{collections::HashMap, sync::Mutex};
+
+// Keeps the secrets until the process exits, for the in-memory storage mode.
+#[derive(Default)]
+pub struct MemorySecretStore {
+    secrets: Mutex<HashMap<String, String>>,
+}
+
+impl SecretStore for MemorySecretStore {
+    fn name(&self) -> &'static str { "memory" }
+
+    fn set(&self, key: &str, secret: &str) -> Result<(), String> {
+        let mut secrets = self
+            .secrets
+            .lock()
+            .map_err(|e| format!("Lock secrets failed: {:?}", e))?;
+        secrets.insert(key.to_owned(), secret.to_owned());
+        Ok(())
+    }
+
+    fn get(&self, key: &str) -> Result<Option<String>, String> {
+        let secrets = self
+            .secrets
+            .lock()
+            .map_err(|e| format!("Lock secrets failed: {:?}", e))?;
+        Ok(secrets.get(key).cloned())
+    }
+
+    fn remove(&self, key: &str) -> Result<(), String> {
+        let mut secrets = self
+            .secrets
+            .lock()
+            .map_err(|e| format!("Lock secrets failed: {:?}", e))?;
+        secrets.remove(key);
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+mod cipher;
+mod file_store;
+mod memory_store;
+mod platform;
+mod secret_store;
+
+pub use cipher::{derive_key, generate_secret, is_encrypted, is_encrypted_text, sign, Cipher};
+pub use file_store::*;
+pub use memory_store::*;
+pub use platform::*;
+pub use secret_store::*;
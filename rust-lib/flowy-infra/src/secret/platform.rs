@@ -0,0 +1,187 @@
+use crate::secret::SecretStore;
+use std::{
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+const SERVICE_NAME: &str = "AppFlowy";
+
+// Talks to the credential store of the platform through its command line tool, the
+// Keychain on macOS and the Secret Service on Linux. The secrets are always passed
+// through the stdin, so they never show up in the process list.
+pub struct PlatformSecretStore {
+    // Each root dir is an independent app data, so its secrets are kept apart.
+    namespace: String,
+}
+
+impl PlatformSecretStore {
+    pub fn detect(root: &str) -> Option<Self> {
+        if !backend::is_available() {
+            return None;
+        }
+        Some(Self {
+            namespace: root.to_owned(),
+        })
+    }
+
+    fn account(&self, key: &str) -> String { format!("{}:{}", self.namespace, key) }
+}
+
+impl SecretStore for PlatformSecretStore {
+    fn name(&self) -> &'static str { backend::NAME }
+
+    fn set(&self, key: &str, secret: &str) -> Result<(), String> { backend::set(&self.account(key), secret) }
+
+    fn get(&self, key: &str) -> Result<Option<String>, String> { backend::get(&self.account(key)) }
+
+    fn remove(&self, key: &str) -> Result<(), String> { backend::remove(&self.account(key)) }
+}
+
+#[cfg(target_os = "macos")]
+mod backend {
+    use super::*;
+
+    pub(crate) const NAME: &str = "keychain";
+    // security(1) exits with errSecItemNotFound when the item doesn't exist
+    const ITEM_NOT_FOUND: i32 = 44;
+
+    pub(crate) fn is_available() -> bool { Command::new("security").arg("help").output().is_ok() }
+
+    pub(crate) fn set(account: &str, secret: &str) -> Result<(), String> {
+        // The interactive mode reads the command from the stdin
+        let command = format!(
+            "add-generic-password -U -s {} -a {} -w {}\n",
+            quote(SERVICE_NAME),
+            quote(account),
+            quote(secret)
+        );
+        let output = run_with_stdin("security", &["-i"], &command)?;
+        check_status(output).map(|_| ())
+    }
+
+    pub(crate) fn get(account: &str) -> Result<Option<String>, String> {
+        let output = Command::new("security")
+            .args(["find-generic-password", "-s", SERVICE_NAME, "-a", account, "-w"])
+            .output()
+            .map_err(|e| format!("Run security failed: {:?}", e))?;
+        if output.status.code() == Some(ITEM_NOT_FOUND) {
+            return Ok(None);
+        }
+        let stdout = check_status(output)?;
+        Ok(Some(stdout.trim_end_matches('\n').to_owned()))
+    }
+
+    pub(crate) fn remove(account: &str) -> Result<(), String> {
+        let output = Command::new("security")
+            .args(["delete-generic-password", "-s", SERVICE_NAME, "-a", account])
+            .output()
+            .map_err(|e| format!("Run security failed: {:?}", e))?;
+        if output.status.code() == Some(ITEM_NOT_FOUND) {
+            return Ok(());
+        }
+        check_status(output).map(|_| ())
+    }
+
+    fn quote(s: &str) -> String { format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\"")) }
+}
+
+#[cfg(target_os = "linux")]
+mod backend {
+    use super::*;
+
+    pub(crate) const NAME: &str = "secret-service";
+
+    pub(crate) fn is_available() -> bool {
+        // The secret service is provided over the session bus, which a headless
+        // environment usually doesn't have.
+        std::env::var("DBUS_SESSION_BUS_ADDRESS").is_ok() && Command::new("secret-tool").output().is_ok()
+    }
+
+    pub(crate) fn set(account: &str, secret: &str) -> Result<(), String> {
+        let label = format!("--label={} {}", SERVICE_NAME, account);
+        let output = run_with_stdin(
+            "secret-tool",
+            &["store", &label, "service", SERVICE_NAME, "account", account],
+            secret,
+        )?;
+        check_status(output).map(|_| ())
+    }
+
+    pub(crate) fn get(account: &str) -> Result<Option<String>, String> {
+        let output = Command::new("secret-tool")
+            .args(["lookup", "service", SERVICE_NAME, "account", account])
+            .output()
+            .map_err(|e| format!("Run secret-tool failed: {:?}", e))?;
+        // secret-tool exits with 1 and prints nothing when the secret doesn't exist
+        if !output.status.success() && output.stdout.is_empty() && output.stderr.is_empty() {
+            return Ok(None);
+        }
+        let stdout = check_status(output)?;
+        Ok(Some(stdout))
+    }
+
+    pub(crate) fn remove(account: &str) -> Result<(), String> {
+        let output = Command::new("secret-tool")
+            .args(["clear", "service", SERVICE_NAME, "account", account])
+            .output()
+            .map_err(|e| format!("Run secret-tool failed: {:?}", e))?;
+        if !output.status.success() && output.stderr.is_empty() {
+            return Ok(());
+        }
+        check_status(output).map(|_| ())
+    }
+}
+
+// Other platforms, e.g. Windows, use the file store until they get a backend.
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+mod backend {
+    pub(crate) const NAME: &str = "unsupported";
+
+    pub(crate) fn is_available() -> bool { false }
+
+    pub(crate) fn set(_account: &str, _secret: &str) -> Result<(), String> {
+        Err("The platform secret store is not supported".to_owned())
+    }
+
+    pub(crate) fn get(_account: &str) -> Result<Option<String>, String> {
+        Err("The platform secret store is not supported".to_owned())
+    }
+
+    pub(crate) fn remove(_account: &str) -> Result<(), String> {
+        Err("The platform secret store is not supported".to_owned())
+    }
+}
+
+#[allow(dead_code)]
+fn run_with_stdin(program: &str, args: &[&str], input: &str) -> Result<Output, String> {
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Run {} failed: {:?}", program, e))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(input.as_bytes())
+            .map_err(|e| format!("Write to {} failed: {:?}", program, e))?;
+    }
+
+    child
+        .wait_with_output()
+        .map_err(|e| format!("Run {} failed: {:?}", program, e))
+}
+
+#[allow(dead_code)]
+fn check_status(output: Output) -> Result<String, String> {
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(format!(
+            "The secret store failed with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
@@ -0,0 +1,45 @@
+use crate::secret::{FileSecretStore, MemorySecretStore, PlatformSecretStore};
+use std::sync::Arc;
+
+// Keeps the credentials, e.g. the tokens of the signed in users, out of the plaintext
+// databases in the app directory.
+pub trait SecretStore: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn set(&self, key: &str, secret: &str) -> Result<(), String>;
+
+    fn get(&self, key: &str) -> Result<Option<String>, String>;
+
+    fn remove(&self, key: &str) -> Result<(), String>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecretStoreKind {
+    // The credential store of the platform, or the file store if the platform doesn't
+    // provide one.
+    Platform,
+    File,
+    // Lost when the process exits.
+    Memory,
+}
+
+impl std::default::Default for SecretStoreKind {
+    fn default() -> Self { SecretStoreKind::Platform }
+}
+
+pub fn mk_secret_store(root: &str, kind: SecretStoreKind) -> Arc<dyn SecretStore> {
+    if kind == SecretStoreKind::Memory {
+        return Arc::new(MemorySecretStore::default());
+    }
+    if kind == SecretStoreKind::Platform {
+        match PlatformSecretStore::detect(root) {
+            Some(store) => {
+                log::debug!("Use {} secret store", store.name());
+                return Arc::new(store);
+            },
+            None => log::warn!("The platform secret store is not available, fallback to the file secret store"),
+        }
+    }
+
+    Arc::new(FileSecretStore::new(root))
+}
@@ -0,0 +1,238 @@
+use std::{
+    cell::Cell,
+    collections::VecDeque,
+    fmt,
+    fmt::Write,
+    sync::{Arc, Mutex},
+};
+use tracing::{
+    field::{Field, Visit},
+    Event,
+    Level,
+    Subscriber,
+};
+use tracing_log::NormalizeEvent;
+use tracing_subscriber::{layer::Context, Layer};
+
+pub const DEFAULT_LOG_BUFFER_CAPACITY: usize = 1000;
+
+lazy_static::lazy_static! {
+    static ref LOG_BUFFER: LogBuffer = LogBuffer::new(DEFAULT_LOG_BUFFER_CAPACITY);
+}
+
+// The recent records of the process, e.g. for the debug screen of the app that
+// can't read the files.
+pub fn log_buffer() -> &'static LogBuffer { &LOG_BUFFER }
+
+// The time is in milliseconds. The fields of the record follow the message as
+// key=value.
+#[derive(Debug, Clone)]
+pub struct LogRecord {
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+    pub time: i64,
+}
+
+// The records at the level or more severe. The records of every target pass if
+// the targets are empty, otherwise the target must start with one of them, e.g.
+// flowy_workspace.
+#[derive(Debug, Clone)]
+pub struct LogFilter {
+    pub level: Level,
+    pub targets: Vec<String>,
+}
+
+impl LogFilter {
+    pub fn matches(&self, record: &LogRecord) -> bool {
+        record.level <= self.level
+            && (self.targets.is_empty() || self.targets.iter().any(|target| record.target.starts_with(target)))
+    }
+}
+
+pub type LogListener = Arc<dyn Fn(&LogRecord) + Send + Sync>;
+
+struct LogSubscription {
+    filter: LogFilter,
+    listener: LogListener,
+}
+
+// Keeps the last capacity records, the older ones are dropped. There's one
+// subscription at a time, the one of the debug screen.
+pub struct LogBuffer {
+    capacity: Mutex<usize>,
+    records: Mutex<VecDeque<LogRecord>>,
+    subscription: Mutex<Option<LogSubscription>>,
+}
+
+impl LogBuffer {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity: Mutex::new(capacity),
+            records: Mutex::new(VecDeque::with_capacity(capacity)),
+            subscription: Mutex::new(None),
+        }
+    }
+
+    pub fn set_capacity(&self, capacity: usize) {
+        *self.capacity.lock().unwrap() = capacity;
+        let mut records = self.records.lock().unwrap();
+        while records.len() > capacity {
+            records.pop_front();
+        }
+    }
+
+    // The oldest first.
+    pub fn records(&self, filter: &LogFilter) -> Vec<LogRecord> {
+        self.records
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|record| filter.matches(record))
+            .cloned()
+            .collect()
+    }
+
+    // None if the records are locked, e.g. by the thread that panicked while it
+    // pushed one.
+    pub fn try_records(&self, filter: &LogFilter) -> Option<Vec<LogRecord>> {
+        let records = self.records.try_lock().ok()?;
+        let records = records
+            .iter()
+            .filter(|record| filter.matches(record))
+            .cloned()
+            .collect();
+        Some(records)
+    }
+
+    // How many records are kept and the size of their texts.
+    pub fn memory_usage(&self) -> (usize, usize) {
+        let records = self.records.lock().unwrap();
+        let bytes = records
+            .iter()
+            .map(|record| record.target.len() + record.message.len())
+            .sum();
+        (records.len(), bytes)
+    }
+
+    // The listener is called with each new record that matches the filter, it
+    // replaces the previous one.
+    pub fn subscribe(&self, filter: LogFilter, listener: LogListener) {
+        *self.subscription.lock().unwrap() = Some(LogSubscription { filter, listener });
+    }
+
+    pub fn unsubscribe(&self) { *self.subscription.lock().unwrap() = None; }
+
+    fn push(&self, record: LogRecord) {
+        let listener = match &*self.subscription.lock().unwrap() {
+            Some(subscription) if subscription.filter.matches(&record) => Some(subscription.listener.clone()),
+            _ => None,
+        };
+
+        {
+            let capacity = *self.capacity.lock().unwrap();
+            let mut records = self.records.lock().unwrap();
+            if records.len() >= capacity {
+                records.pop_front();
+            }
+            if capacity > 0 {
+                records.push_back(record.clone());
+            }
+        }
+
+        // The records the listener writes itself, e.g. when the notification can't
+        // be sent, aren't sent again.
+        if let Some(listener) = listener {
+            let _ = IN_LISTENER.try_with(|in_listener| {
+                if !in_listener.replace(true) {
+                    listener(&record);
+                    in_listener.set(false);
+                }
+            });
+        }
+    }
+}
+
+thread_local! {
+    static IN_LISTENER: Cell<bool> = Cell::new(false);
+}
+
+// Pushes the records of the events into the log_buffer.
+pub(crate) struct LogBufferLayer;
+
+impl<S: Subscriber> Layer<S> for LogBufferLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let normalized_metadata = event.normalized_metadata();
+        let metadata = normalized_metadata.as_ref().unwrap_or_else(|| event.metadata());
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        log_buffer().push(LogRecord {
+            level: *metadata.level(),
+            target: metadata.target().to_owned(),
+            message: visitor.message + &visitor.fields,
+            time: chrono::Local::now().timestamp_millis(),
+        });
+    }
+}
+
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_owned();
+        } else {
+            self.record_debug(field, &value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        match field.name() {
+            "message" => self.message = format!("{:?}", value),
+            name if name.starts_with("log.") => {},
+            name => {
+                let _ = write!(self.fields, " {}={:?}", name, value);
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(level: Level, target: &str) -> LogRecord {
+        LogRecord {
+            level,
+            target: target.to_owned(),
+            message: "".to_owned(),
+            time: 0,
+        }
+    }
+
+    #[test]
+    fn test_ring_buffer() {
+        let buffer = LogBuffer::new(2);
+        buffer.push(record(Level::INFO, "flowy_user"));
+        buffer.push(record(Level::DEBUG, "flowy_workspace::services"));
+        buffer.push(record(Level::ERROR, "flowy_workspace::services"));
+
+        let filter = LogFilter {
+            level: Level::TRACE,
+            targets: vec![],
+        };
+        assert_eq!(buffer.records(&filter).len(), 2);
+
+        let filter = LogFilter {
+            level: Level::INFO,
+            targets: vec!["flowy_workspace".to_owned()],
+        };
+        let records = buffer.records(&filter);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].level, Level::ERROR);
+    }
+}
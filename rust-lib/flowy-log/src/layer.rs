@@ -1,15 +1,18 @@
 use serde::ser::{SerializeMap, Serializer};
 use serde_json::Value;
-use std::{fmt, io::Write};
+use std::{fmt, io::Write, time::Instant};
 use tracing::{Event, Id, Subscriber};
 use tracing_bunyan_formatter::JsonStorage;
 use tracing_core::{metadata::Level, span::Attributes};
+use tracing_log::NormalizeEvent;
 
 use tracing_subscriber::{fmt::MakeWriter, layer::Context, registry::SpanRef, Layer};
 const LEVEL: &str = "level";
 const TIME: &str = "time";
 const MESSAGE: &str = "msg";
 
+const ELAPSED_MILLIS: &str = "elapsed_ms";
+
 const LOG_MODULE_PATH: &str = "log.module_path";
 const LOG_TARGET_PATH: &str = "log.target";
 
@@ -19,25 +22,34 @@ const IGNORE_FIELDS: [&str; 2] = [LOG_MODULE_PATH, LOG_TARGET_PATH];
 pub struct FlowyFormattingLayer<W: MakeWriter + 'static> {
     make_writer: W,
     with_target: bool,
+    with_spans: bool,
 }
 
 impl<W: MakeWriter + 'static> FlowyFormattingLayer<W> {
     pub fn new(make_writer: W) -> Self {
         Self {
             make_writer,
-            with_target: false,
+            with_target: true,
+            with_spans: true,
         }
     }
 
+    // The START and END records of the spans, the END one has how long the span
+    // lasted. The events in the spans have their fields either way.
+    pub fn with_spans(mut self, with_spans: bool) -> Self {
+        self.with_spans = with_spans;
+        self
+    }
+
     fn serialize_flowy_core_fields(
         &self,
         map_serializer: &mut impl SerializeMap<Error = serde_json::Error>,
         message: &str,
-        _level: &Level,
+        level: &Level,
     ) -> Result<(), std::io::Error> {
         map_serializer.serialize_entry(MESSAGE, &message)?;
-        // map_serializer.serialize_entry(LEVEL, &format!("{}", level))?;
-        // map_serializer.serialize_entry(TIME, &chrono::Utc::now().timestamp())?;
+        map_serializer.serialize_entry(LEVEL, &format!("{}", level))?;
+        map_serializer.serialize_entry(TIME, &chrono::Local::now().to_rfc3339())?;
         Ok(())
     }
 
@@ -57,6 +69,11 @@ impl<W: MakeWriter + 'static> FlowyFormattingLayer<W> {
 
         map_serializer.serialize_entry("line", &span.metadata().line())?;
         map_serializer.serialize_entry("file", &span.metadata().file())?;
+        if let Type::ExitSpan = ty {
+            if let Some(created_at) = span.extensions().get::<SpanCreatedAt>() {
+                map_serializer.serialize_entry(ELAPSED_MILLIS, &(created_at.0.elapsed().as_millis() as u64))?;
+            }
+        }
 
         let extensions = span.extensions();
         if let Some(visitor) = extensions.get::<JsonStorage>() {
@@ -78,6 +95,9 @@ impl<W: MakeWriter + 'static> FlowyFormattingLayer<W> {
     }
 }
 
+// When the span was created, the END record has the time since.
+struct SpanCreatedAt(Instant);
+
 /// The type of record we are dealing with: entering a span, exiting a span, an
 /// event.
 #[derive(Clone, Debug)]
@@ -152,14 +172,18 @@ where
             let mut serializer = serde_json::Serializer::new(&mut buffer);
             let mut map_serializer = serializer.serialize_map(None)?;
 
+            // The records of the log crate have their own target and level, not the
+            // ones of the bridge.
+            let normalized_metadata = event.normalized_metadata();
+            let metadata = normalized_metadata.as_ref().unwrap_or_else(|| event.metadata());
             let message = format_event_message(&current_span, event, &event_visitor);
-            self.serialize_flowy_core_fields(&mut map_serializer, &message, event.metadata().level())?;
+            self.serialize_flowy_core_fields(&mut map_serializer, &message, metadata.level())?;
             // Additional metadata useful for debugging
             // They should be nested under `src` (see https://github.com/trentm/node-bunyan#src )
             // but `tracing` does not support nested values yet
 
             if self.with_target {
-                map_serializer.serialize_entry("target", event.metadata().target())?;
+                map_serializer.serialize_entry("target", metadata.target())?;
             }
 
             // map_serializer.serialize_entry("line", &event.metadata().line())?;
@@ -198,6 +222,13 @@ where
 
     fn new_span(&self, _attrs: &Attributes, id: &Id, ctx: Context<'_, S>) {
         let span = ctx.span(id).expect("Span not found, this is a bug");
+        if !self.with_spans {
+            return;
+        }
+        // The layers of the other writers share it.
+        if span.extensions().get::<SpanCreatedAt>().is_none() {
+            span.extensions_mut().insert(SpanCreatedAt(Instant::now()));
+        }
         if let Ok(serialized) = self.serialize_span(&span, Type::EnterSpan) {
             let _ = self.emit(serialized);
         }
@@ -205,6 +236,9 @@ where
 
     fn on_close(&self, id: Id, ctx: Context<'_, S>) {
         let span = ctx.span(&id).expect("Span not found, this is a bug");
+        if !self.with_spans {
+            return;
+        }
         if let Ok(serialized) = self.serialize_span(&span, Type::ExitSpan) {
             let _ = self.emit(serialized);
         }
@@ -1,37 +1,57 @@
+mod buffer;
 mod layer;
+mod reload;
+mod rotation;
 
 use log::LevelFilter;
 
 use tracing::subscriber::set_global_default;
 
-use crate::layer::*;
+use crate::{buffer::LogBufferLayer, layer::*, reload::init_filter_reload, rotation::RotatingFile};
 use lazy_static::lazy_static;
-use std::sync::RwLock;
-use tracing_appender::{non_blocking::WorkerGuard, rolling::RollingFileAppender};
+use std::{path::PathBuf, sync::RwLock};
+use tracing_appender::non_blocking::WorkerGuard;
 use tracing_bunyan_formatter::JsonStorageLayer;
 use tracing_log::LogTracer;
-use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
+use tracing_subscriber::{layer::SubscriberExt, reload as filter_reload, EnvFilter};
 
 lazy_static! {
     static ref LOG_GUARD: RwLock<Option<WorkerGuard>> = RwLock::new(None);
+    static ref LOG_FILES: RwLock<Option<(PathBuf, String)>> = RwLock::new(None);
+}
+
+pub use buffer::{log_buffer, LogBuffer, LogFilter, LogListener, LogRecord, DEFAULT_LOG_BUFFER_CAPACITY};
+pub use reload::{is_default_log_filter, log_filter, reset_log_filter, set_log_filter};
+pub use rotation::{LogRotation, DEFAULT_MAX_LOG_FILES, DEFAULT_MAX_LOG_FILE_SIZE};
+
+// The files the records are written to, the current one first. It's empty
+// until the log was built.
+pub fn log_file_paths() -> Vec<PathBuf> {
+    match &*LOG_FILES.read().unwrap() {
+        Some((directory, name)) => rotation::log_file_paths(directory, name),
+        None => vec![],
+    }
 }
 
 pub struct Builder {
-    #[allow(dead_code)]
     name: String,
+    directory: PathBuf,
     env_filter: String,
-    file_appender: RollingFileAppender,
+    rotation: LogRotation,
+    with_spans: bool,
+    buffer_capacity: usize,
 }
 
 impl Builder {
+    // The records are written to the logs directory in the directory.
     pub fn new(name: &str, directory: &str) -> Self {
-        // let directory = directory.as_ref().to_str().unwrap().to_owned();
-        let local_file_name = format!("{}.log", name);
-
         Builder {
             name: name.to_owned(),
+            directory: PathBuf::from(directory).join("logs"),
             env_filter: "Info".to_owned(),
-            file_appender: tracing_appender::rolling::daily(directory, local_file_name),
+            rotation: LogRotation::default(),
+            with_spans: true,
+            buffer_capacity: DEFAULT_LOG_BUFFER_CAPACITY,
         }
     }
 
@@ -40,10 +60,30 @@ impl Builder {
         self
     }
 
+    // Writes the START and END records of the spans, e.g. the ones of the
+    // dispatched events, their database writes and their requests.
+    pub fn with_spans(mut self, with_spans: bool) -> Self {
+        self.with_spans = with_spans;
+        self
+    }
+
+    pub fn rotation(mut self, rotation: LogRotation) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    // How many of the recent records the log_buffer keeps.
+    pub fn buffer_capacity(mut self, capacity: usize) -> Self {
+        self.buffer_capacity = capacity;
+        self
+    }
+
     pub fn build(self) -> std::result::Result<(), String> {
-        let env_filter = EnvFilter::new(self.env_filter);
+        let env_filter = EnvFilter::new(&self.env_filter);
+        let (env_filter, filter_handle) = filter_reload::Layer::new(env_filter);
 
-        let (non_blocking, guard) = tracing_appender::non_blocking(self.file_appender);
+        let file = RotatingFile::new(&self.directory, &self.name, self.rotation).map_err(|e| format!("{:?}", e))?;
+        let (non_blocking, guard) = tracing_appender::non_blocking(file);
         let subscriber = tracing_subscriber::fmt()
             // .with_span_events(FmtSpan::NEW | FmtSpan::CLOSE)
             .with_ansi(false)
@@ -59,8 +99,9 @@ impl Builder {
             .finish()
             .with(env_filter)
             .with(JsonStorageLayer)
-            .with(FlowyFormattingLayer::new(std::io::stdout))
-            .with(FlowyFormattingLayer::new(non_blocking));
+            .with(FlowyFormattingLayer::new(std::io::stdout).with_spans(self.with_spans))
+            .with(FlowyFormattingLayer::new(non_blocking).with_spans(self.with_spans))
+            .with(LogBufferLayer);
 
         // if cfg!(feature = "use_bunyan") {
         //     let formatting_layer = BunyanFormattingLayer::new(self.name.clone(),
@@ -77,7 +118,13 @@ impl Builder {
             .map_err(|e| format!("{:?}", e))
             .unwrap();
 
+        init_filter_reload(
+            &self.env_filter,
+            Box::new(move |env_filter| filter_handle.reload(env_filter).map_err(|e| format!("{:?}", e))),
+        );
+        log_buffer().set_capacity(self.buffer_capacity);
         *LOG_GUARD.write().unwrap() = Some(guard);
+        *LOG_FILES.write().unwrap() = Some((self.directory, self.name));
         Ok(())
     }
 }
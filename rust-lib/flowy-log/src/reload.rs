@@ -0,0 +1,60 @@
+use lazy_static::lazy_static;
+use std::sync::RwLock;
+use tracing_subscriber::EnvFilter;
+
+pub(crate) type ReloadFilter = Box<dyn Fn(EnvFilter) -> Result<(), String> + Send + Sync>;
+
+struct FilterReload {
+    // The filter of the Builder, the one reset_log_filter goes back to.
+    default: String,
+    current: String,
+    reload: ReloadFilter,
+}
+
+lazy_static! {
+    static ref FILTER_RELOAD: RwLock<Option<FilterReload>> = RwLock::new(None);
+}
+
+pub(crate) fn init_filter_reload(default: &str, reload: ReloadFilter) {
+    *FILTER_RELOAD.write().unwrap() = Some(FilterReload {
+        default: default.to_owned(),
+        current: default.to_owned(),
+        reload,
+    });
+}
+
+// Replaces the filter of the records without building the log again, e.g.
+// "info,flowy_document=debug". The filter is kept if the new one can't be read.
+pub fn set_log_filter(filter: &str) -> Result<(), String> {
+    let env_filter = EnvFilter::try_new(filter).map_err(|e| format!("Invalid log filter {}: {}", filter, e))?;
+    let mut guard = FILTER_RELOAD.write().unwrap();
+    let filter_reload = guard.as_mut().ok_or_else(|| "The log isn't built".to_owned())?;
+    let _ = (filter_reload.reload)(env_filter)?;
+    filter_reload.current = filter.to_owned();
+    Ok(())
+}
+
+pub fn reset_log_filter() -> Result<(), String> {
+    let default = match &*FILTER_RELOAD.read().unwrap() {
+        Some(filter_reload) => filter_reload.default.clone(),
+        None => return Err("The log isn't built".to_owned()),
+    };
+    set_log_filter(&default)
+}
+
+// The filter the records pass now, None until the log was built.
+pub fn log_filter() -> Option<String> {
+    FILTER_RELOAD
+        .read()
+        .unwrap()
+        .as_ref()
+        .map(|filter_reload| filter_reload.current.clone())
+}
+
+// Whether the filter is the one of the Builder.
+pub fn is_default_log_filter() -> bool {
+    match &*FILTER_RELOAD.read().unwrap() {
+        Some(filter_reload) => filter_reload.current == filter_reload.default,
+        None => true,
+    }
+}
@@ -0,0 +1,165 @@
+use chrono::{DateTime, Local, NaiveDate};
+use std::{
+    fs::{File, OpenOptions},
+    io,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+pub const DEFAULT_MAX_LOG_FILE_SIZE: u64 = 10 * 1024 * 1024;
+pub const DEFAULT_MAX_LOG_FILES: usize = 7;
+
+// The current file is rotated when it would grow past max_file_size or when
+// the day changed since it was written, max_files of the rotated ones are
+// kept.
+#[derive(Debug, Clone)]
+pub struct LogRotation {
+    pub max_file_size: u64,
+    pub max_files: usize,
+}
+
+impl std::default::Default for LogRotation {
+    fn default() -> Self {
+        LogRotation {
+            max_file_size: DEFAULT_MAX_LOG_FILE_SIZE,
+            max_files: DEFAULT_MAX_LOG_FILES,
+        }
+    }
+}
+
+// Writes the records to <directory>/<name>.log, the rotated files are
+// <name>.<time of the rotation>.log next to it.
+pub(crate) struct RotatingFile {
+    directory: PathBuf,
+    name: String,
+    rotation: LogRotation,
+    file: File,
+    size: u64,
+    date: NaiveDate,
+}
+
+impl RotatingFile {
+    pub(crate) fn new(directory: &Path, name: &str, rotation: LogRotation) -> io::Result<Self> {
+        let _ = std::fs::create_dir_all(directory)?;
+        let path = current_path(directory, name);
+        let file = open_file(&path)?;
+        let metadata = file.metadata()?;
+        let date = match metadata.modified() {
+            Ok(modified) => DateTime::<Local>::from(modified).date().naive_local(),
+            Err(_) => today(),
+        };
+        Ok(Self {
+            directory: directory.to_owned(),
+            name: name.to_owned(),
+            rotation,
+            file,
+            size: metadata.len(),
+            date,
+        })
+    }
+
+    fn should_rotate(&self, len: usize) -> bool {
+        if self.size == 0 {
+            return false;
+        }
+        self.size + len as u64 > self.rotation.max_file_size || self.date != today()
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        let _ = self.file.flush()?;
+        let current = current_path(&self.directory, &self.name);
+        let rotated = self.directory.join(format!(
+            "{}.{}.log",
+            self.name,
+            Local::now().format("%Y-%m-%d-%H%M%S%3f")
+        ));
+        let _ = std::fs::rename(&current, rotated)?;
+        self.file = open_file(&current)?;
+        self.size = 0;
+        self.date = today();
+
+        // The names sort by the time of the rotation.
+        let rotated_files = rotated_paths(&self.directory, &self.name);
+        let expired = rotated_files.len().saturating_sub(self.rotation.max_files);
+        for path in rotated_files.into_iter().take(expired) {
+            let _ = std::fs::remove_file(path);
+        }
+        Ok(())
+    }
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.should_rotate(buf.len()) {
+            if let Err(e) = self.rotate() {
+                eprintln!("Rotate the log file failed: {:?}", e);
+            }
+        }
+        let len = self.file.write(buf)?;
+        self.size += len as u64;
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> { self.file.flush() }
+}
+
+// The current file first, then the rotated ones from the newest.
+pub(crate) fn log_file_paths(directory: &Path, name: &str) -> Vec<PathBuf> {
+    let mut paths = vec![];
+    let current = current_path(directory, name);
+    if current.exists() {
+        paths.push(current);
+    }
+    paths.extend(rotated_paths(directory, name).into_iter().rev());
+    paths
+}
+
+fn rotated_paths(directory: &Path, name: &str) -> Vec<PathBuf> {
+    let prefix = format!("{}.", name);
+    let current = format!("{}.log", name);
+    let mut paths = match std::fs::read_dir(directory) {
+        Ok(entries) => entries
+            .flatten()
+            .filter(|entry| {
+                let file_name = entry.file_name().to_string_lossy().into_owned();
+                file_name != current && file_name.starts_with(&prefix) && file_name.ends_with(".log")
+            })
+            .map(|entry| entry.path())
+            .collect::<Vec<_>>(),
+        Err(_) => vec![],
+    };
+    paths.sort();
+    paths
+}
+
+fn current_path(directory: &Path, name: &str) -> PathBuf { directory.join(format!("{}.log", name)) }
+
+fn open_file(path: &Path) -> io::Result<File> { OpenOptions::new().create(true).append(true).open(path) }
+
+fn today() -> NaiveDate { Local::now().date().naive_local() }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotate_by_size() {
+        let directory = std::env::temp_dir().join(format!("flowy_log_rotation_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+        let rotation = LogRotation {
+            max_file_size: 16,
+            max_files: 2,
+        };
+        let mut file = RotatingFile::new(&directory, "flowy", rotation).unwrap();
+        for _ in 0..5 {
+            file.write_all(b"0123456789\n").unwrap();
+            std::thread::sleep(std::time::Duration::from_millis(2));
+        }
+
+        let paths = log_file_paths(&directory, "flowy");
+        assert_eq!(paths.len(), 3);
+        assert_eq!(paths[0], directory.join("flowy.log"));
+        assert_eq!(std::fs::read(&paths[0]).unwrap(), b"0123456789\n");
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+}
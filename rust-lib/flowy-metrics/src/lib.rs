@@ -0,0 +1,11 @@
+mod registry;
+
+pub use registry::*;
+
+lazy_static::lazy_static! {
+    static ref REGISTRY: Registry = Registry::default();
+}
+
+// The registry the dispatcher, the databases and the sync record their metrics
+// in.
+pub fn registry() -> &'static Registry { &REGISTRY }
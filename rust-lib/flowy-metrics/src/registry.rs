@@ -0,0 +1,279 @@
+use parking_lot::{Mutex, MutexGuard, RwLock};
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    sync::{
+        atomic::{AtomicU64, Ordering::SeqCst},
+        Arc,
+    },
+    time::Duration,
+};
+
+// The upper bounds of the buckets of the histograms, the durations are in
+// milliseconds.
+pub const DEFAULT_BUCKETS: [f64; 12] = [
+    1.0, 5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1000.0, 2500.0, 5000.0, 10000.0,
+];
+
+pub type Labels = Vec<(String, String)>;
+
+#[derive(Default, Debug)]
+pub struct Counter(AtomicU64);
+
+impl Counter {
+    pub fn inc(&self) { self.add(1); }
+
+    pub fn add(&self, value: u64) { let _ = self.0.fetch_add(value, SeqCst); }
+
+    pub fn get(&self) -> u64 { self.0.load(SeqCst) }
+}
+
+#[derive(Debug)]
+pub struct Histogram {
+    bounds: Vec<f64>,
+    state: Mutex<HistogramState>,
+}
+
+#[derive(Debug)]
+struct HistogramState {
+    // Not cumulative, the last one is the count of the values above the bounds.
+    bucket_counts: Vec<u64>,
+    count: u64,
+    sum: f64,
+}
+
+impl Histogram {
+    pub fn new(bounds: &[f64]) -> Self {
+        Self {
+            bounds: bounds.to_vec(),
+            state: Mutex::new(HistogramState {
+                bucket_counts: vec![0; bounds.len() + 1],
+                count: 0,
+                sum: 0.0,
+            }),
+        }
+    }
+
+    pub fn observe(&self, value: f64) {
+        let index = self
+            .bounds
+            .iter()
+            .position(|bound| value <= *bound)
+            .unwrap_or(self.bounds.len());
+        let mut state = self.state.lock();
+        state.bucket_counts[index] += 1;
+        state.count += 1;
+        state.sum += value;
+    }
+
+    pub fn observe_duration(&self, duration: Duration) { self.observe(duration.as_secs_f64() * 1000.0); }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CounterSample {
+    pub name: String,
+    pub labels: Labels,
+    pub value: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistogramSample {
+    pub name: String,
+    pub labels: Labels,
+    pub count: u64,
+    pub sum: f64,
+    // The upper bound of each bucket and the count of the values up to it.
+    pub buckets: Vec<(f64, u64)>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MetricsSnapshot {
+    pub counters: Vec<CounterSample>,
+    pub histograms: Vec<HistogramSample>,
+}
+
+impl MetricsSnapshot {
+    // The text exposition format of Prometheus.
+    pub fn to_prometheus_text(&self) -> String {
+        let mut text = String::new();
+        let mut last_name = "";
+        for counter in &self.counters {
+            if counter.name != last_name {
+                let _ = writeln!(text, "# TYPE {} counter", counter.name);
+                last_name = &counter.name;
+            }
+            let _ = writeln!(
+                text,
+                "{}{} {}",
+                counter.name,
+                format_labels(&counter.labels, None),
+                counter.value
+            );
+        }
+
+        last_name = "";
+        for histogram in &self.histograms {
+            if histogram.name != last_name {
+                let _ = writeln!(text, "# TYPE {} histogram", histogram.name);
+                last_name = &histogram.name;
+            }
+            for (bound, count) in &histogram.buckets {
+                let bound = format!("{}", bound);
+                let labels = format_labels(&histogram.labels, Some(&bound));
+                let _ = writeln!(text, "{}_bucket{} {}", histogram.name, labels, count);
+            }
+            let labels = format_labels(&histogram.labels, Some("+Inf"));
+            let _ = writeln!(text, "{}_bucket{} {}", histogram.name, labels, histogram.count);
+            let labels = format_labels(&histogram.labels, None);
+            let _ = writeln!(text, "{}_sum{} {}", histogram.name, labels, histogram.sum);
+            let _ = writeln!(text, "{}_count{} {}", histogram.name, labels, histogram.count);
+        }
+        text
+    }
+}
+
+fn format_labels(labels: &Labels, le: Option<&str>) -> String {
+    let mut pairs = labels
+        .iter()
+        .map(|(key, value)| format!("{}=\"{}\"", key, escape_label(value)))
+        .collect::<Vec<_>>();
+    if let Some(le) = le {
+        pairs.push(format!("le=\"{}\"", le));
+    }
+    if pairs.is_empty() {
+        return "".to_owned();
+    }
+    format!("{{{}}}", pairs.join(","))
+}
+
+fn escape_label(value: &str) -> String { value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n") }
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct MetricKey {
+    name: String,
+    labels: Labels,
+}
+
+impl MetricKey {
+    fn new(name: &str, labels: &[(&str, &str)]) -> Self {
+        Self {
+            name: name.to_owned(),
+            labels: labels
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+}
+
+// The metrics are created the first time they're recorded, each name and
+// labels has one.
+#[derive(Default)]
+pub struct Registry {
+    counters: RwLock<BTreeMap<MetricKey, Arc<Counter>>>,
+    histograms: RwLock<BTreeMap<MetricKey, Arc<Histogram>>>,
+}
+
+impl Registry {
+    pub fn counter(&self, name: &str, labels: &[(&str, &str)]) -> Arc<Counter> {
+        let key = MetricKey::new(name, labels);
+        if let Some(counter) = self.counters.read().get(&key) {
+            return counter.clone();
+        }
+        self.counters.write().entry(key).or_default().clone()
+    }
+
+    pub fn histogram(&self, name: &str, labels: &[(&str, &str)]) -> Arc<Histogram> {
+        let key = MetricKey::new(name, labels);
+        if let Some(histogram) = self.histograms.read().get(&key) {
+            return histogram.clone();
+        }
+        self.histograms
+            .write()
+            .entry(key)
+            .or_insert_with(|| Arc::new(Histogram::new(&DEFAULT_BUCKETS)))
+            .clone()
+    }
+
+    // Sorted by the name and the labels.
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let counters = self.counters.read();
+        let histograms = self.histograms.read();
+        collect_snapshot(&counters, &histograms, |histogram| Some(histogram.state.lock())).unwrap_or_default()
+    }
+
+    // None instead of waiting if one of the metrics is locked, e.g. by the
+    // thread that panicked.
+    pub fn try_snapshot(&self) -> Option<MetricsSnapshot> {
+        let counters = self.counters.try_read()?;
+        let histograms = self.histograms.try_read()?;
+        collect_snapshot(&counters, &histograms, |histogram| histogram.state.try_lock())
+    }
+}
+
+fn collect_snapshot<F>(
+    counters: &BTreeMap<MetricKey, Arc<Counter>>,
+    histograms: &BTreeMap<MetricKey, Arc<Histogram>>,
+    lock_state: F,
+) -> Option<MetricsSnapshot>
+where
+    F: Fn(&Histogram) -> Option<MutexGuard<'_, HistogramState>>,
+{
+    let counters = counters
+        .iter()
+        .map(|(key, counter)| CounterSample {
+            name: key.name.clone(),
+            labels: key.labels.clone(),
+            value: counter.get(),
+        })
+        .collect();
+
+    let histograms = histograms
+        .iter()
+        .map(|(key, histogram)| {
+            let state = lock_state(histogram)?;
+            let mut cumulative = 0;
+            let buckets = histogram
+                .bounds
+                .iter()
+                .zip(state.bucket_counts.iter())
+                .map(|(bound, count)| {
+                    cumulative += count;
+                    (*bound, cumulative)
+                })
+                .collect();
+            Some(HistogramSample {
+                name: key.name.clone(),
+                labels: key.labels.clone(),
+                count: state.count,
+                sum: state.sum,
+                buckets,
+            })
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(MetricsSnapshot { counters, histograms })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prometheus_text() {
+        let registry = Registry::default();
+        registry.counter("events_total", &[("event", "SignIn")]).add(2);
+        registry.counter("events_total", &[("event", "SignIn")]).inc();
+        let histogram = registry.histogram("event_duration_ms", &[]);
+        histogram.observe(3.0);
+        histogram.observe(20000.0);
+
+        let text = registry.snapshot().to_prometheus_text();
+        assert!(text.contains("# TYPE events_total counter\nevents_total{event=\"SignIn\"} 3\n"));
+        assert!(text.contains("event_duration_ms_bucket{le=\"1\"} 0\n"));
+        assert!(text.contains("event_duration_ms_bucket{le=\"5\"} 1\n"));
+        assert!(text.contains("event_duration_ms_bucket{le=\"10000\"} 1\n"));
+        assert!(text.contains("event_duration_ms_bucket{le=\"+Inf\"} 2\n"));
+        assert!(text.contains("event_duration_ms_count 2\n"));
+    }
+}
@@ -37,8 +37,18 @@ impl ServerConfig {
 
     pub fn sign_out_url(&self) -> String { format!("{}{}/api/auth", self.scheme(), self.host) }
 
+    pub fn oauth_url(&self) -> String { format!("{}{}/api/oauth", self.scheme(), self.host) }
+
+    pub fn refresh_token_url(&self) -> String { format!("{}{}/api/auth", self.scheme(), self.host) }
+
+    pub fn password_url(&self) -> String { format!("{}{}/api/password_change", self.scheme(), self.host) }
+
     pub fn user_profile_url(&self) -> String { format!("{}{}/api/user", self.scheme(), self.host) }
 
+    pub fn session_url(&self) -> String { format!("{}{}/api/user/session", self.scheme(), self.host) }
+
+    pub fn verification_url(&self) -> String { format!("{}{}/api/user/verification", self.scheme(), self.host) }
+
     pub fn workspace_url(&self) -> String { format!("{}{}/api/workspace", self.scheme(), self.host) }
 
     pub fn app_url(&self) -> String { format!("{}{}/api/app", self.scheme(), self.host) }
@@ -47,7 +57,13 @@ impl ServerConfig {
 
     pub fn doc_url(&self) -> String { format!("{}{}/api/doc", self.scheme(), self.host) }
 
+    pub fn asset_url(&self) -> String { format!("{}{}/api/doc/asset", self.scheme(), self.host) }
+
     pub fn trash_url(&self) -> String { format!("{}{}/api/trash", self.scheme(), self.host) }
 
+    pub fn sync_url(&self) -> String { format!("{}{}/api/sync", self.scheme(), self.host) }
+
+    pub fn telemetry_url(&self) -> String { format!("{}{}/api/telemetry", self.scheme(), self.host) }
+
     pub fn ws_addr(&self) -> String { format!("{}://{}/ws", self.ws_schema, self.host) }
 }
@@ -37,6 +37,8 @@ impl ServerError {
     static_error!(connect_cancel, ErrorCode::ConnectCancel);
     static_error!(connect_refused, ErrorCode::ConnectRefused);
     static_error!(record_not_found, ErrorCode::RecordNotFound);
+    static_error!(session_revoked, ErrorCode::SessionRevoked);
+    static_error!(verification_code_invalid, ErrorCode::VerificationCodeInvalid);
 
     pub fn new(msg: String, code: ErrorCode) -> Self { Self { code, msg } }
 
@@ -48,6 +50,17 @@ impl ServerError {
     pub fn is_record_not_found(&self) -> bool { self.code == ErrorCode::RecordNotFound }
 
     pub fn is_unauthorized(&self) -> bool { self.code == ErrorCode::UserUnauthorized }
+
+    pub fn is_session_revoked(&self) -> bool { self.code == ErrorCode::SessionRevoked }
+
+    // The request didn't reach the server or it couldn't answer, sending it again
+    // may succeed.
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self.code,
+            ErrorCode::ConnectRefused | ErrorCode::ConnectTimeout | ErrorCode::ConnectClose
+        )
+    }
 }
 
 pub fn internal_error<T>(e: T) -> ServerError
@@ -88,6 +101,10 @@ pub enum ErrorCode {
     PayloadUnexpectedNone = 4,
     #[display(fmt = "Params is invalid")]
     ParamsInvalid      = 5,
+    #[display(fmt = "Session was revoked")]
+    SessionRevoked     = 6,
+    #[display(fmt = "Verification code is invalid or expired")]
+    VerificationCodeInvalid = 7,
 
     #[display(fmt = "Protobuf serde error")]
     ProtobufError      = 10,
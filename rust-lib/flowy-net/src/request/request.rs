@@ -1,19 +1,78 @@
 use crate::{config::HEADER_TOKEN, errors::ServerError, response::FlowyResponse};
 use bytes::Bytes;
+use flowy_infra::retry::jitter;
 use hyper::http;
+use lazy_static::lazy_static;
 use protobuf::ProtobufError;
 use reqwest::{header::HeaderMap, Client, Method, Response};
 use std::{
     convert::{TryFrom, TryInto},
-    sync::Arc,
+    sync::{Arc, RwLock},
     time::Duration,
 };
 use tokio::sync::oneshot;
+use tracing::Instrument;
 
 pub trait ResponseMiddleware {
     fn receive_response(&self, token: &Option<String>, response: &FlowyResponse);
 }
 
+// The token of the signed in user, it's sent with the requests that don't set
+// the token header themselves.
+pub trait TokenProvider: Send + Sync {
+    fn token(&self) -> Option<String>;
+}
+
+lazy_static! {
+    static ref TOKEN_PROVIDER: RwLock<Option<Arc<dyn TokenProvider>>> = RwLock::new(None);
+}
+
+// The provider of the requests that aren't given one, set it once the user
+// session is created.
+pub fn set_token_provider(provider: Arc<dyn TokenProvider>) {
+    match TOKEN_PROVIDER.write() {
+        Ok(mut token_provider) => *token_provider = Some(provider),
+        Err(e) => log::error!("Set the token provider failed: {:?}", e),
+    }
+}
+
+fn default_token_provider() -> Option<Arc<dyn TokenProvider>> {
+    TOKEN_PROVIDER.read().ok().and_then(|provider| provider.clone())
+}
+
+// The requests are sent once unless they're retried. The delay doubles after
+// each failed attempt and is shortened by up to half at random, so the clients
+// that failed together don't retry together.
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    pub max_retries: usize,
+    pub delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl std::default::Default for RetryConfig {
+    fn default() -> Self {
+        RetryConfig {
+            max_retries: 2,
+            delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(2),
+        }
+    }
+}
+
+impl RetryConfig {
+    fn delay(&self, attempt: usize) -> Duration {
+        let delay = self
+            .delay
+            .checked_mul(2u32.saturating_pow(attempt as u32))
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+        delay / 2 + jitter(delay / 2)
+    }
+}
+
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
 pub struct HttpRequestBuilder {
     url: String,
     body: Option<Bytes>,
@@ -21,6 +80,9 @@ pub struct HttpRequestBuilder {
     headers: HeaderMap,
     method: Method,
     middleware: Vec<Arc<dyn ResponseMiddleware + Send + Sync>>,
+    token_provider: Option<Arc<dyn TokenProvider>>,
+    timeout: Duration,
+    retry: Option<RetryConfig>,
 }
 
 impl HttpRequestBuilder {
@@ -32,6 +94,9 @@ impl HttpRequestBuilder {
             headers: HeaderMap::new(),
             method: Method::GET,
             middleware: Vec::new(),
+            token_provider: default_token_provider(),
+            timeout: DEFAULT_REQUEST_TIMEOUT,
+            retry: None,
         }
     }
 
@@ -43,6 +108,30 @@ impl HttpRequestBuilder {
         self
     }
 
+    pub fn token_provider(mut self, provider: Arc<dyn TokenProvider>) -> Self {
+        self.token_provider = Some(provider);
+        self
+    }
+
+    // The token isn't sent, not even the one of the default provider.
+    pub fn anonymous(mut self) -> Self {
+        self.token_provider = None;
+        self
+    }
+
+    // How long each attempt waits for the response.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    // Only the requests that failed before the server could answer are retried,
+    // see ServerError::is_transient. Don't retry the ones that aren't idempotent.
+    pub fn retry(mut self, config: RetryConfig) -> Self {
+        self.retry = Some(config);
+        self
+    }
+
     pub fn get(mut self, url: &str) -> Self {
         self.url = url.to_owned();
         self.method = Method::GET;
@@ -61,6 +150,12 @@ impl HttpRequestBuilder {
         self
     }
 
+    pub fn put(mut self, url: &str) -> Self {
+        self.url = url.to_owned();
+        self.method = Method::PUT;
+        self
+    }
+
     pub fn delete(mut self, url: &str) -> Self {
         self.url = url.to_owned();
         self.method = Method::DELETE;
@@ -128,17 +223,59 @@ impl HttpRequestBuilder {
         }
     }
 
-    async fn inner_send(mut self) -> Result<Self, ServerError> {
+    // The span of the request is nested in the one of the event that sent it, the
+    // retries are in it too.
+    async fn inner_send(self) -> Result<Self, ServerError> {
+        let span = tracing::debug_span!("http_request", method = %self.method, url = self.url.as_str());
+        self.send_with_retries().instrument(span).await
+    }
+
+    async fn send_with_retries(mut self) -> Result<Self, ServerError> {
+        if self.token().is_none() {
+            if let Some(token) = self.token_provider.as_ref().and_then(|provider| provider.token()) {
+                self = self.header(HEADER_TOKEN, &token);
+            }
+        }
+
+        let max_retries = self.retry.as_ref().map(|retry| retry.max_retries).unwrap_or(0);
+        let mut attempt = 0;
+        let flowy_response = loop {
+            match self.send_once().await {
+                Err(error) if error.is_transient() && attempt < max_retries => {
+                    let delay = self.retry.as_ref().unwrap().delay(attempt);
+                    tracing::debug!("Retry {} in {:?}: {:?}", self.url, delay, error);
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                },
+                result => break result?,
+            }
+        };
+
+        let token = self.token();
+        self.middleware.iter().for_each(|middleware| {
+            middleware.receive_response(&token, &flowy_response);
+        });
+        match flowy_response.error {
+            None => {
+                self.response = Some(flowy_response.data);
+                Ok(self)
+            },
+            Some(error) => Err(error),
+        }
+    }
+
+    async fn send_once(&self) -> Result<FlowyResponse, ServerError> {
         let (tx, rx) = oneshot::channel::<Result<Response, _>>();
         let url = self.url.clone();
-        let body = self.body.take();
+        let body = self.body.clone();
         let method = self.method.clone();
         let headers = self.headers.clone();
+        let timeout = self.timeout;
 
         // reqwest client is not 'Sync' by channel is.
         tokio::spawn(async move {
             let client = default_client();
-            let mut builder = client.request(method.clone(), url).headers(headers);
+            let mut builder = client.request(method.clone(), url).headers(headers).timeout(timeout);
             if let Some(body) = body {
                 builder = builder.body(body);
             }
@@ -149,18 +286,7 @@ impl HttpRequestBuilder {
 
         let response = rx.await??;
         tracing::trace!("Http Response: {:?}", response);
-        let flowy_response = flowy_response_from(response).await?;
-        let token = self.token();
-        self.middleware.iter().for_each(|middleware| {
-            middleware.receive_response(&token, &flowy_response);
-        });
-        match flowy_response.error {
-            None => {
-                self.response = Some(flowy_response.data);
-                Ok(self)
-            },
-            Some(error) => Err(error),
-        }
+        flowy_response_from(response).await
     }
 }
 
@@ -169,30 +295,32 @@ fn unexpected_empty_payload(url: &str) -> ServerError {
     ServerError::payload_none().context(msg)
 }
 
+// The server answers with a FlowyResponse, the errors of the proxies in between
+// don't, they're mapped from the status.
 async fn flowy_response_from(original: Response) -> Result<FlowyResponse, ServerError> {
+    let status = original.status();
     let bytes = original.bytes().await?;
-    let response: FlowyResponse = serde_json::from_slice(&bytes)?;
-    Ok(response)
+    match serde_json::from_slice::<FlowyResponse>(&bytes) {
+        Ok(response) => Ok(response),
+        Err(e) if status.is_success() => Err(e.into()),
+        Err(_) => Err(status_error(status)),
+    }
 }
 
-#[allow(dead_code)]
-async fn get_response_data(original: Response) -> Result<Bytes, ServerError> {
-    if original.status() == http::StatusCode::OK {
-        let bytes = original.bytes().await?;
-        let response: FlowyResponse = serde_json::from_slice(&bytes)?;
-        match response.error {
-            None => Ok(response.data),
-            Some(error) => Err(error),
-        }
-    } else {
-        Err(ServerError::http().context(original))
-    }
+fn status_error(status: http::StatusCode) -> ServerError {
+    let error = match status {
+        http::StatusCode::UNAUTHORIZED => ServerError::unauthorized(),
+        http::StatusCode::NOT_FOUND => ServerError::record_not_found(),
+        http::StatusCode::REQUEST_TIMEOUT | http::StatusCode::GATEWAY_TIMEOUT => ServerError::connect_timeout(),
+        http::StatusCode::BAD_GATEWAY | http::StatusCode::SERVICE_UNAVAILABLE => ServerError::connect_refused(),
+        _ => ServerError::http(),
+    };
+    error.context(status)
 }
 
 fn default_client() -> Client {
     let result = reqwest::Client::builder()
         .connect_timeout(Duration::from_millis(500))
-        .timeout(Duration::from_secs(5))
         .build();
 
     match result {
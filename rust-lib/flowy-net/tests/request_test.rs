@@ -0,0 +1,127 @@
+use flowy_net::{
+    config::HEADER_TOKEN,
+    errors::ErrorCode,
+    request::{HttpRequestBuilder, RetryConfig, TokenProvider},
+};
+use std::{sync::Arc, time::Duration};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+    sync::mpsc,
+};
+
+const OK: &str = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 11\r\n\r\n{\"data\":[]}";
+const BAD_GATEWAY: &str = "HTTP/1.1 502 Bad Gateway\r\nContent-Length: 11\r\n\r\nBad Gateway";
+const UNAUTHORIZED: &str = "HTTP/1.1 401 Unauthorized\r\nContent-Length: 0\r\n\r\n";
+
+// Answers each request with the response and passes the requests to the test.
+// It never answers when the response is None.
+async fn start_server(response: Option<&'static str>) -> (String, mpsc::UnboundedReceiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/api", listener.local_addr().unwrap());
+    let (tx, rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                let mut buf = vec![0; 4096];
+                let n = stream.read(&mut buf).await.unwrap_or(0);
+                let _ = tx.send(String::from_utf8_lossy(&buf[..n]).to_lowercase());
+                match response {
+                    Some(response) => {
+                        let _ = stream.write_all(response.as_bytes()).await;
+                    },
+                    None => tokio::time::sleep(Duration::from_secs(10)).await,
+                }
+            });
+        }
+    });
+    (url, rx)
+}
+
+fn retry_config() -> RetryConfig {
+    RetryConfig {
+        max_retries: 2,
+        delay: Duration::from_millis(10),
+        max_delay: Duration::from_millis(20),
+    }
+}
+
+struct StaticToken(&'static str);
+impl TokenProvider for StaticToken {
+    fn token(&self) -> Option<String> { Some(self.0.to_owned()) }
+}
+
+fn count(rx: &mut mpsc::UnboundedReceiver<String>) -> usize {
+    let mut count = 0;
+    while rx.try_recv().is_ok() {
+        count += 1;
+    }
+    count
+}
+
+#[tokio::test]
+async fn request_inject_token() {
+    let (url, mut rx) = start_server(Some(OK)).await;
+    let _ = HttpRequestBuilder::new()
+        .token_provider(Arc::new(StaticToken("provided")))
+        .get(&url)
+        .send()
+        .await
+        .unwrap();
+    assert!(rx
+        .recv()
+        .await
+        .unwrap()
+        .contains(&format!("{}: provided", HEADER_TOKEN)));
+
+    // The token that's set explicitly is sent instead.
+    let _ = HttpRequestBuilder::new()
+        .token_provider(Arc::new(StaticToken("provided")))
+        .get(&url)
+        .header(HEADER_TOKEN, "explicit")
+        .send()
+        .await
+        .unwrap();
+    let request = rx.recv().await.unwrap();
+    assert!(request.contains(&format!("{}: explicit", HEADER_TOKEN)));
+    assert!(!request.contains("provided"));
+}
+
+#[tokio::test]
+async fn request_retry_transient_error() {
+    let (url, mut rx) = start_server(Some(BAD_GATEWAY)).await;
+    let error = HttpRequestBuilder::new()
+        .get(&url)
+        .retry(retry_config())
+        .send()
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::ConnectRefused);
+    assert_eq!(count(&mut rx), 3);
+}
+
+#[tokio::test]
+async fn request_map_status_without_retry() {
+    let (url, mut rx) = start_server(Some(UNAUTHORIZED)).await;
+    let error = HttpRequestBuilder::new()
+        .get(&url)
+        .retry(retry_config())
+        .send()
+        .await
+        .unwrap_err();
+    assert!(error.is_unauthorized());
+    assert_eq!(count(&mut rx), 1);
+}
+
+#[tokio::test]
+async fn request_timeout() {
+    let (url, _rx) = start_server(None).await;
+    let error = HttpRequestBuilder::new()
+        .get(&url)
+        .timeout(Duration::from_millis(100))
+        .send()
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::ConnectTimeout);
+}
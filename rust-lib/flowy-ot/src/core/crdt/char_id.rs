@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use std::{cmp::Ordering, fmt};
+
+// Identifies the device that made the change. It must be unique for every
+// device that edits the document, 0 is reserved for the characters converted
+// from an existing delta.
+pub type ReplicaId = u64;
+
+pub const ROOT_REPLICA: ReplicaId = 0;
+
+// The unique id of an inserted character, it's also used as the timestamp of a
+// format. The ids are ordered by the lamport clock first, the replica breaks
+// the tie, so every replica orders the concurrent changes the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CharId {
+    pub clock: u64,
+    pub replica: ReplicaId,
+}
+
+impl CharId {
+    pub fn new(clock: u64, replica: ReplicaId) -> Self { Self { clock, replica } }
+}
+
+impl Ord for CharId {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.clock
+            .cmp(&other.clock)
+            .then_with(|| self.replica.cmp(&other.replica))
+    }
+}
+
+impl PartialOrd for CharId {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl fmt::Display for CharId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{}@{}", self.clock, self.replica) }
+}
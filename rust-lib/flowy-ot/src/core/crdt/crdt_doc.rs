@@ -0,0 +1,297 @@
+use crate::{
+    core::{
+        AttributeKey,
+        AttributeValue,
+        Attributes,
+        CharId,
+        Delta,
+        Operation,
+        OperationTransformable,
+        ReplicaId,
+        ROOT_REPLICA,
+    },
+    errors::{ErrorBuilder, OTError, OTErrorCode},
+};
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::{cmp::max, collections::HashMap, str};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct StampedAttribute {
+    key: AttributeKey,
+    value: AttributeValue,
+    stamp: CharId,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Element {
+    id: CharId,
+    // The element on the left when the character was inserted.
+    origin: Option<CharId>,
+    value: char,
+    // The deleted characters are kept, other replicas may still insert after them.
+    deleted: bool,
+    attributes: Vec<StampedAttribute>,
+}
+
+impl Element {
+    fn len(&self) -> usize { self.value.len_utf16() }
+
+    // The format with the greater stamp wins.
+    fn set_attribute(&mut self, key: &AttributeKey, value: &AttributeValue, stamp: CharId) {
+        match self.attributes.iter_mut().find(|attribute| &attribute.key == key) {
+            None => self.attributes.push(StampedAttribute {
+                key: key.clone(),
+                value: value.clone(),
+                stamp,
+            }),
+            Some(attribute) => {
+                if attribute.stamp < stamp {
+                    attribute.value = value.clone();
+                    attribute.stamp = stamp;
+                }
+            },
+        }
+    }
+
+    fn attributes(&self) -> Attributes {
+        let mut attributes = Attributes::new();
+        self.attributes
+            .iter()
+            .filter(|attribute| attribute.value.0.is_some())
+            .for_each(|attribute| attributes.add_kv(attribute.key.clone(), attribute.value.clone()));
+        attributes
+    }
+}
+
+// A replicated document, the concurrent changes of two replicas are merged
+// without transforming them against each other, so the replicas don't need a
+// server to agree on the order of the changes.
+//
+// Every character has a unique id and remembers its left neighbour when it was
+// inserted. Concurrent inserts after the same character are ordered by their
+// ids, the deleted characters are kept as tombstones and the formats are
+// last-writer-wins.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrdtDoc {
+    replica: ReplicaId,
+    clock: u64,
+    elements: Vec<Element>,
+}
+
+impl CrdtDoc {
+    pub fn new(replica: ReplicaId) -> Self {
+        debug_assert!(replica != ROOT_REPLICA);
+        Self {
+            replica,
+            clock: 0,
+            elements: vec![],
+        }
+    }
+
+    // Converts the stored document. The characters get the ids of the ROOT_REPLICA,
+    // so the replicas that convert the same delta share them and their changes
+    // can be merged.
+    pub fn from_delta(replica: ReplicaId, delta: &Delta) -> Result<Self, OTError> {
+        let mut doc = CrdtDoc::new(replica);
+        for op in &delta.ops {
+            match op {
+                Operation::Insert(insert) => {
+                    for c in insert.s.chars() {
+                        doc.clock += 1;
+                        let id = CharId::new(doc.clock, ROOT_REPLICA);
+                        let origin = doc.elements.last().map(|element| element.id);
+                        doc.elements.push(new_element(id, origin, c, &insert.attributes));
+                    }
+                },
+                _ => {
+                    return Err(ErrorBuilder::new(OTErrorCode::ApplyInsertFail)
+                        .msg(format!("The document should only contain inserts, but receive: {}", op))
+                        .build());
+                },
+            }
+        }
+        Ok(doc)
+    }
+
+    // Converts the stored revisions, they are composed in order.
+    pub fn from_deltas(replica: ReplicaId, deltas: &[Delta]) -> Result<Self, OTError> {
+        let mut doc = Delta::new();
+        for delta in deltas {
+            doc = doc.compose(delta)?;
+        }
+        Self::from_delta(replica, &doc)
+    }
+
+    pub fn replica(&self) -> ReplicaId { self.replica }
+
+    // The length of the visible characters in utf16 code units, same as the delta.
+    pub fn len(&self) -> usize { self.elements.iter().filter(|e| !e.deleted).map(|e| e.len()).sum() }
+
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    pub fn to_delta(&self) -> Delta {
+        let mut delta = Delta::new();
+        self.elements
+            .iter()
+            .filter(|element| !element.deleted)
+            .for_each(|element| delta.insert(&element.value.to_string(), element.attributes()));
+        delta
+    }
+
+    // Applies the local change, the delta is based on the current document.
+    pub fn apply_delta(&mut self, delta: &Delta) -> Result<(), OTError> {
+        if delta.base_len != self.len() {
+            return Err(ErrorBuilder::new(OTErrorCode::IncompatibleLength)
+                .msg(format!(
+                    "Expected base len {}, but receive {}",
+                    self.len(),
+                    delta.base_len
+                ))
+                .build());
+        }
+
+        let mut index = 0;
+        for op in &delta.ops {
+            match op {
+                Operation::Retain(retain) => {
+                    let stamp = self.tick();
+                    let mut n = retain.n;
+                    while n > 0 {
+                        index = self.next_visible(index)?;
+                        let element = &mut self.elements[index];
+                        retain
+                            .attributes
+                            .iter()
+                            .for_each(|(key, value)| element.set_attribute(key, value, stamp));
+                        n = n.saturating_sub(element.len());
+                        index += 1;
+                    }
+                },
+                Operation::Delete(n) => {
+                    let mut n = *n;
+                    while n > 0 {
+                        index = self.next_visible(index)?;
+                        let element = &mut self.elements[index];
+                        element.deleted = true;
+                        n = n.saturating_sub(element.len());
+                        index += 1;
+                    }
+                },
+                Operation::Insert(insert) => {
+                    for c in insert.s.chars() {
+                        let id = self.tick();
+                        let origin = match index {
+                            0 => None,
+                            _ => Some(self.elements[index - 1].id),
+                        };
+                        self.elements
+                            .insert(index, new_element(id, origin, c, &insert.attributes));
+                        index += 1;
+                    }
+                },
+            }
+        }
+        Ok(())
+    }
+
+    // Merges the changes of the other replica. The result doesn't depend on the
+    // order of the merges, and merging the same changes twice does nothing.
+    pub fn merge(&mut self, other: &CrdtDoc) {
+        let positions = self
+            .elements
+            .iter()
+            .enumerate()
+            .map(|(index, element)| (element.id, index))
+            .collect::<HashMap<CharId, usize>>();
+
+        let mut new_elements = vec![];
+        for element in &other.elements {
+            self.clock = max(self.clock, element.id.clock);
+            element
+                .attributes
+                .iter()
+                .for_each(|attribute| self.clock = max(self.clock, attribute.stamp.clock));
+
+            match positions.get(&element.id) {
+                None => new_elements.push(element),
+                Some(index) => {
+                    let own = &mut self.elements[*index];
+                    own.deleted |= element.deleted;
+                    element
+                        .attributes
+                        .iter()
+                        .for_each(|attribute| own.set_attribute(&attribute.key, &attribute.value, attribute.stamp));
+                },
+            }
+        }
+
+        // The origin is always on the left, so it's integrated before the element.
+        new_elements
+            .into_iter()
+            .for_each(|element| self.integrate(element.clone()));
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, OTError> {
+        let doc: CrdtDoc = serde_json::from_str(json)?;
+        Ok(doc)
+    }
+
+    pub fn to_json(&self) -> String { serde_json::to_string(self).unwrap_or("".to_owned()) }
+
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: T) -> Result<Self, OTError> {
+        let json = str::from_utf8(bytes.as_ref())?;
+        Self::from_json(json)
+    }
+
+    pub fn to_bytes(&self) -> Bytes { Bytes::from(self.to_json().into_bytes()) }
+}
+
+impl CrdtDoc {
+    fn tick(&mut self) -> CharId {
+        self.clock += 1;
+        CharId::new(self.clock, self.replica)
+    }
+
+    fn next_visible(&self, from: usize) -> Result<usize, OTError> {
+        match self.elements[from..].iter().position(|element| !element.deleted) {
+            None => Err(ErrorBuilder::new(OTErrorCode::IntervalOutOfBound).build()),
+            Some(offset) => Ok(from + offset),
+        }
+    }
+
+    // Skips the elements that were inserted after the same origin with a greater
+    // id. Their descendants have greater ids too, so they are skipped along
+    // with them.
+    fn integrate(&mut self, element: Element) {
+        let mut index = match element.origin {
+            None => 0,
+            Some(origin) => match self.elements.iter().position(|e| e.id == origin) {
+                None => {
+                    log::error!("The origin {} of {} is missing", origin, element.id);
+                    self.elements.len()
+                },
+                Some(index) => index + 1,
+            },
+        };
+
+        while index < self.elements.len() && self.elements[index].id > element.id {
+            index += 1;
+        }
+        self.elements.insert(index, element);
+    }
+}
+
+fn new_element(id: CharId, origin: Option<CharId>, value: char, attributes: &Attributes) -> Element {
+    let mut element = Element {
+        id,
+        origin,
+        value,
+        deleted: false,
+        attributes: vec![],
+    };
+    attributes
+        .iter()
+        .for_each(|(key, value)| element.set_attribute(key, value, id));
+    element
+}
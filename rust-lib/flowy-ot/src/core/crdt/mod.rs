@@ -0,0 +1,5 @@
+mod char_id;
+mod crdt_doc;
+
+pub use char_id::*;
+pub use crdt_doc::*;
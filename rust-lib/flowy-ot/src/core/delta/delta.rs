@@ -132,14 +132,19 @@ impl Delta {
         }
 
         self.target_len += s.count_utf16_code_units();
+        // The insert always goes before the delete at the same position, so that the equal
+        // deltas have the same ops.
         let new_last = match self.ops.as_mut_slice() {
             [.., Operation::Insert(insert)] => {
                 //
                 insert.merge_or_new_op(&s, attributes)
             },
             [.., Operation::Insert(pre_insert), Operation::Delete(_)] => {
-                //
-                pre_insert.merge_or_new_op(&s, attributes)
+                if let Some(new_op) = pre_insert.merge_or_new_op(&s, attributes) {
+                    let index = self.ops.len() - 1;
+                    self.ops.insert(index, new_op);
+                }
+                None
             },
             [.., op_last @ Operation::Delete(_)] => {
                 let new_last = op_last.clone();
@@ -182,14 +187,10 @@ impl Delta {
         for op in &self.ops {
             match &op {
                 Operation::Retain(retain) => {
-                    for c in chars.take(retain.n as usize) {
-                        new_s.push(c);
-                    }
+                    new_s += &take_code_units(chars, retain.n);
                 },
                 Operation::Delete(delete) => {
-                    for _ in 0..*delete {
-                        chars.next();
-                    }
+                    let _ = take_code_units(chars, *delete);
                 },
                 Operation::Insert(insert) => {
                     new_s += &insert.s;
@@ -208,17 +209,13 @@ impl Delta {
             match &op {
                 Operation::Retain(retain) => {
                     inverted.retain(retain.n, Attributes::default());
-                    // TODO: use advance_by instead, but it's unstable now
-                    // chars.advance_by(retain.num)
-                    for _ in 0..retain.n {
-                        chars.next();
-                    }
+                    let _ = take_code_units(chars, retain.n);
                 },
                 Operation::Insert(insert) => {
                     inverted.delete(insert.count_of_code_units());
                 },
                 Operation::Delete(delete) => {
-                    inverted.insert(&chars.take(*delete as usize).collect::<String>(), op.get_attributes());
+                    inverted.insert(&take_code_units(chars, *delete), op.get_attributes());
                 },
             }
         }
@@ -318,21 +315,21 @@ impl OperationTransformable for Delta {
         let mut ops1 = self.ops.iter().cloned();
         let mut ops2 = other.ops.iter().cloned();
 
+        // The self has the priority: its inserts go first and its attributes win over the
+        // other's when both of them format the same text.
         let mut next_op1 = ops1.next();
         let mut next_op2 = ops2.next();
         loop {
             match (&next_op1, &next_op2) {
                 (None, None) => break,
                 (Some(Operation::Insert(insert)), _) => {
-                    // let composed_attrs = transform_attributes(&next_op1, &next_op2, true);
                     a_prime.insert(&insert.s, insert.attributes.clone());
                     b_prime.retain(insert.count_of_code_units(), insert.attributes.clone());
                     next_op1 = ops1.next();
                 },
                 (_, Some(Operation::Insert(o_insert))) => {
-                    let composed_attrs = transform_op_attribute(&next_op1, &next_op2);
-                    a_prime.retain(o_insert.count_of_code_units(), composed_attrs.clone());
-                    b_prime.insert(&o_insert.s, composed_attrs);
+                    a_prime.retain(o_insert.count_of_code_units(), o_insert.attributes.clone());
+                    b_prime.insert(&o_insert.s, o_insert.attributes.clone());
                     next_op2 = ops2.next();
                 },
                 (None, _) => {
@@ -342,27 +339,12 @@ impl OperationTransformable for Delta {
                     return Err(ErrorBuilder::new(OTErrorCode::IncompatibleLength).build());
                 },
                 (Some(Operation::Retain(retain)), Some(Operation::Retain(o_retain))) => {
-                    let composed_attrs = transform_op_attribute(&next_op1, &next_op2);
-                    match retain.cmp(&o_retain) {
-                        Ordering::Less => {
-                            a_prime.retain(retain.n, composed_attrs.clone());
-                            b_prime.retain(retain.n, composed_attrs.clone());
-                            next_op2 = Some(OpBuilder::retain(o_retain.n - retain.n).build());
-                            next_op1 = ops1.next();
-                        },
-                        Ordering::Equal => {
-                            a_prime.retain(retain.n, composed_attrs.clone());
-                            b_prime.retain(retain.n, composed_attrs.clone());
-                            next_op1 = ops1.next();
-                            next_op2 = ops2.next();
-                        },
-                        Ordering::Greater => {
-                            a_prime.retain(o_retain.n, composed_attrs.clone());
-                            b_prime.retain(o_retain.n, composed_attrs.clone());
-                            next_op1 = Some(OpBuilder::retain(retain.n - o_retain.n).build());
-                            next_op2 = ops2.next();
-                        },
-                    };
+                    let (_, o_attributes) = retain.attributes.transform(&o_retain.attributes)?;
+                    let n = min(retain.n, o_retain.n);
+                    a_prime.retain(n, retain.attributes.clone());
+                    b_prime.retain(n, o_attributes);
+                    next_op1 = shrink_retain(retain, n).or_else(|| ops1.next());
+                    next_op2 = shrink_retain(o_retain, n).or_else(|| ops2.next());
                 },
                 (Some(Operation::Delete(i)), Some(Operation::Delete(j))) => match i.cmp(&j) {
                     Ordering::Less => {
@@ -379,41 +361,21 @@ impl OperationTransformable for Delta {
                     },
                 },
                 (Some(Operation::Delete(i)), Some(Operation::Retain(o_retain))) => {
-                    match i.cmp(&o_retain) {
-                        Ordering::Less => {
-                            a_prime.delete(*i);
-                            next_op2 = Some(OpBuilder::retain(o_retain.n - *i).build());
-                            next_op1 = ops1.next();
-                        },
-                        Ordering::Equal => {
-                            a_prime.delete(*i);
-                            next_op1 = ops1.next();
-                            next_op2 = ops2.next();
-                        },
-                        Ordering::Greater => {
-                            a_prime.delete(o_retain.n);
-                            next_op1 = Some(OpBuilder::delete(*i - o_retain.n).build());
-                            next_op2 = ops2.next();
-                        },
+                    let n = min(*i, o_retain.n);
+                    a_prime.delete(n);
+                    next_op1 = match *i > n {
+                        true => Some(OpBuilder::delete(*i - n).build()),
+                        false => ops1.next(),
                     };
+                    next_op2 = shrink_retain(o_retain, n).or_else(|| ops2.next());
                 },
                 (Some(Operation::Retain(retain)), Some(Operation::Delete(j))) => {
-                    match retain.cmp(&j) {
-                        Ordering::Less => {
-                            b_prime.delete(retain.n);
-                            next_op2 = Some(OpBuilder::delete(*j - retain.n).build());
-                            next_op1 = ops1.next();
-                        },
-                        Ordering::Equal => {
-                            b_prime.delete(retain.n);
-                            next_op1 = ops1.next();
-                            next_op2 = ops2.next();
-                        },
-                        Ordering::Greater => {
-                            b_prime.delete(*j);
-                            next_op1 = Some(OpBuilder::retain(retain.n - *j).build());
-                            next_op2 = ops2.next();
-                        },
+                    let n = min(retain.n, *j);
+                    b_prime.delete(n);
+                    next_op1 = shrink_retain(retain, n).or_else(|| ops1.next());
+                    next_op2 = match *j > n {
+                        true => Some(OpBuilder::delete(*j - n).build()),
+                        false => ops2.next(),
                     };
                 },
             }
@@ -459,6 +421,22 @@ impl OperationTransformable for Delta {
     }
 }
 
+// The lengths of the delta are utf16 code units, a char outside the BMP counts as two.
+fn take_code_units(chars: &mut str::Chars, n: usize) -> String {
+    let mut s = String::new();
+    let mut count = 0;
+    while count < n {
+        match chars.next() {
+            None => break,
+            Some(c) => {
+                count += c.len_utf16();
+                s.push(c);
+            },
+        }
+    }
+    s
+}
+
 fn invert_from_other(base: &mut Delta, other: &Delta, operation: &Operation, start: usize, end: usize) {
     tracing::trace!("invert op: {} [{}:{}]", operation, start, end);
     let other_ops = DeltaIter::from_interval(other, Interval::new(start, end)).ops();
@@ -482,15 +460,10 @@ fn invert_from_other(base: &mut Delta, other: &Delta, operation: &Operation, sta
     });
 }
 
-fn transform_op_attribute(left: &Option<Operation>, right: &Option<Operation>) -> Attributes {
-    if left.is_none() {
-        if right.is_none() {
-            return Attributes::default();
-        }
-        return right.as_ref().unwrap().get_attributes();
+// The rest of the retain after n code units, it keeps the attributes of the retain.
+fn shrink_retain(retain: &Retain, n: usize) -> Option<Operation> {
+    match retain.n > n {
+        true => Some(OpBuilder::retain(retain.n - n).attributes(retain.attributes.clone()).build()),
+        false => None,
     }
-    let left = left.as_ref().unwrap().get_attributes();
-    let right = right.as_ref().unwrap().get_attributes();
-    // TODO: It's ok to unwrap?
-    left.transform(&right).unwrap().0
 }
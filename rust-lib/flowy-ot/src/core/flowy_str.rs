@@ -1,5 +1,5 @@
 use serde::{de, de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
-use std::{fmt, fmt::Formatter, slice};
+use std::{fmt, fmt::Formatter};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct FlowyStr(pub String);
@@ -42,8 +42,6 @@ pub struct CodePointIterator<'a> {
     s: &'a FlowyStr,
     bytes_offset: usize,
     code_point_offset: usize,
-    iter_index: usize,
-    iter: slice::Iter<'a, u8>,
 }
 
 impl<'a> CodePointIterator<'a> {
@@ -52,49 +50,20 @@ impl<'a> CodePointIterator<'a> {
             s,
             bytes_offset: 0,
             code_point_offset: 0,
-            iter_index: 0,
-            iter: s.as_bytes().iter(),
         }
     }
 }
 
+// Yields the bytes of each char, the code_point_offset is the count of utf16 code units
+// that were yielded so far.
 impl<'a> Iterator for CodePointIterator<'a> {
     type Item = (&'a [u8], usize);
 
     fn next(&mut self) -> Option<Self::Item> {
         let start = self.bytes_offset;
-        let _end = start;
-
-        while let Some(&b) = self.iter.next() {
-            self.iter_index += 1;
-
-            let mut code_point_count = 0;
-            if self.bytes_offset > self.iter_index {
-                continue;
-            }
-
-            if self.bytes_offset == self.iter_index {
-                break;
-            }
-
-            if (b as i8) >= -0x40 {
-                code_point_count += 1
-            }
-            if b >= 0xf0 {
-                code_point_count += 1
-            }
-
-            self.bytes_offset += len_utf8_from_first_byte(b);
-            self.code_point_offset += code_point_count;
-
-            if code_point_count == 1 {
-                break;
-            }
-        }
-
-        if start == self.bytes_offset {
-            return None;
-        }
+        let c = self.s[start..].chars().next()?;
+        self.bytes_offset += c.len_utf8();
+        self.code_point_offset += c.len_utf16();
 
         let byte = &self.s.as_bytes()[start..self.bytes_offset];
         Some((byte, self.bytes_offset - start))
@@ -1,4 +1,5 @@
 mod attributes;
+mod crdt;
 mod delta;
 mod flowy_str;
 mod interval;
@@ -6,6 +7,7 @@ mod operation;
 
 use crate::errors::OTError;
 pub use attributes::*;
+pub use crdt::*;
 pub use delta::*;
 pub use flowy_str::*;
 pub use interval::*;
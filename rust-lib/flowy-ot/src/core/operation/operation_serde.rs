@@ -27,6 +27,8 @@ impl Serialize for Operation {
     }
 }
 
+const OPERATION_FIELDS: &[&str] = &["delete", "retain", "insert", "attributes"];
+
 impl<'de> Deserialize<'de> for Operation {
     fn deserialize<D>(deserializer: D) -> Result<Operation, D::Error>
     where
@@ -76,7 +78,7 @@ impl<'de> Deserialize<'de> for Operation {
                             let map: Attributes = map.next_value()?;
                             attributes = Some(map);
                         },
-                        _ => panic!(),
+                        _ => return Err(de::Error::unknown_field(key, OPERATION_FIELDS)),
                     }
                 }
                 match operation {
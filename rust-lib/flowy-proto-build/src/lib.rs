@@ -0,0 +1,320 @@
+// Used by the build.rs of the crates that have the `src/protobuf` directory.
+//
+// The .proto files are generated from the structs and enums of the
+// `proto_crates` in the Flowy.toml, and the rust files are generated from the
+// .proto files. The mode is selected by the FLOWY_PROTO_GEN environment
+// variable:
+// - check (default): generate both into OUT_DIR and fail the build if they
+//   don't match the files in `src/protobuf/proto` and `src/protobuf/model`.
+// - dev: regenerate the files in `src/protobuf` that drifted.
+// - skip: do nothing.
+mod proto_file;
+
+use protobuf::{descriptor::FileDescriptorProto, Message};
+use std::{
+    env,
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub use proto_file::*;
+
+const MODE_ENV: &str = "FLOWY_PROTO_GEN";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProtoGenMode {
+    Check,
+    Dev,
+    Skip,
+}
+
+impl ProtoGenMode {
+    pub fn from_env() -> Self {
+        match env::var(MODE_ENV).as_deref() {
+            Ok("dev") => ProtoGenMode::Dev,
+            Ok("skip") => ProtoGenMode::Skip,
+            _ => ProtoGenMode::Check,
+        }
+    }
+}
+
+pub fn gen_proto() {
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is not set");
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is not set");
+    let crate_dir = Path::new(&crate_dir);
+    let proto_dir = crate_dir.join("src/protobuf/proto");
+    let model_dir = crate_dir.join("src/protobuf/model");
+
+    println!("cargo:rerun-if-env-changed={}", MODE_ENV);
+    println!("cargo:rerun-if-changed={}", proto_dir.display());
+    println!("cargo:rerun-if-changed={}", crate_dir.join("Flowy.toml").display());
+    proto_crate_paths(crate_dir)
+        .unwrap_or_else(|e| panic!("{}", e))
+        .iter()
+        .for_each(|path| println!("cargo:rerun-if-changed={}", path.display()));
+
+    let mode = ProtoGenMode::from_env();
+    if mode == ProtoGenMode::Skip {
+        return;
+    }
+
+    let gen_proto_dir = Path::new(&out_dir).join("proto");
+    let gen_dir = Path::new(&out_dir).join("protobuf");
+    let mut drifted =
+        write_and_diff_proto_files(crate_dir, &proto_dir, &gen_proto_dir).unwrap_or_else(|e| panic!("{}", e));
+    drifted.extend(gen_and_diff(&gen_proto_dir, &model_dir, &gen_dir).unwrap_or_else(|e| panic!("{}", e)));
+    if drifted.is_empty() {
+        return;
+    }
+
+    match mode {
+        ProtoGenMode::Check => {
+            let files = drifted
+                .iter()
+                .map(|path| format!("  {}", path.display()))
+                .collect::<Vec<_>>()
+                .join("\n");
+            panic!(
+                "The generated protobuf files are out of date:\n{}\nRun with {}=dev to regenerate them",
+                files, MODE_ENV
+            );
+        },
+        ProtoGenMode::Dev => {
+            fs::create_dir_all(&proto_dir).unwrap_or_else(|e| panic!("{}", e));
+            for path in drifted {
+                let file_name = path.file_name().unwrap();
+                let gen_path = if path.extension().map(|ext| ext == "proto").unwrap_or(false) {
+                    gen_proto_dir.join(file_name)
+                } else {
+                    gen_dir.join(file_name)
+                };
+                fs::copy(gen_path, &path).unwrap_or_else(|e| panic!("{}", e));
+                println!("cargo:warning=Regenerate {}", path.display());
+            }
+            add_model_mods(&model_dir).unwrap_or_else(|e| panic!("{}", e));
+        },
+        ProtoGenMode::Skip => {},
+    }
+}
+
+// Writes the .proto files generated from the rust structs into the
+// gen_proto_dir, together with the ones in the proto_dir that aren't generated,
+// and returns the .proto files in the proto_dir that don't match the generated
+// ones.
+pub fn write_and_diff_proto_files(
+    crate_dir: &Path,
+    proto_dir: &Path,
+    gen_proto_dir: &Path,
+) -> Result<Vec<PathBuf>, String> {
+    let _ = fs::remove_dir_all(gen_proto_dir);
+    fs::create_dir_all(gen_proto_dir).map_err(|e| format!("{:?}", e))?;
+    for path in proto_files(proto_dir)? {
+        fs::copy(&path, gen_proto_dir.join(path.file_name().unwrap())).map_err(|e| format!("{:?}", e))?;
+    }
+
+    let mut drifted = vec![];
+    for proto_file in gen_proto_files(crate_dir, proto_dir)? {
+        fs::write(gen_proto_dir.join(&proto_file.file_name), &proto_file.content).map_err(|e| format!("{:?}", e))?;
+        let proto_path = proto_dir.join(&proto_file.file_name);
+        match fs::read_to_string(&proto_path) {
+            Ok(existing) if existing == proto_file.content => {},
+            _ => drifted.push(proto_path),
+        }
+    }
+    Ok(drifted)
+}
+
+// Returns the rust files in the model_dir that don't match the .proto files
+pub fn gen_and_diff(proto_dir: &Path, model_dir: &Path, gen_dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let proto_files = proto_files(proto_dir)?;
+    if proto_files.is_empty() {
+        return Ok(vec![]);
+    }
+
+    fs::create_dir_all(gen_dir).map_err(|e| format!("{:?}", e))?;
+    protobuf_codegen_pure::Codegen::new()
+        .out_dir(gen_dir)
+        .include(proto_dir)
+        .inputs(&proto_files)
+        .run()
+        .map_err(|e| format!("Generate rust files from {} failed: {:?}", proto_dir.display(), e))?;
+
+    let mut drifted = vec![];
+    for proto_file in proto_files {
+        let file_name = format!("{}.rs", proto_file.file_stem().unwrap().to_str().unwrap());
+        let generated = fs::read_to_string(gen_dir.join(&file_name)).map_err(|e| format!("{:?}", e))?;
+        let model_path = model_dir.join(&file_name);
+        match fs::read_to_string(&model_path) {
+            Ok(existing) if is_same_model(&existing, &generated) => {},
+            _ => drifted.push(model_path),
+        }
+    }
+    Ok(drifted)
+}
+
+// Declares the new rust files in the mod.rs of the model_dir
+fn add_model_mods(model_dir: &Path) -> Result<(), String> {
+    let mod_path = model_dir.join("mod.rs");
+    let mut content = fs::read_to_string(&mod_path).unwrap_or_else(|_| "// Auto-generated, do not edit \n".to_owned());
+    let mut names = fs::read_dir(model_dir)
+        .map_err(|e| format!("{:?}", e))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().map(|ext| ext == "rs").unwrap_or(false))
+        .map(|path| path.file_stem().unwrap().to_str().unwrap().to_owned())
+        .filter(|name| name != "mod")
+        .collect::<Vec<String>>();
+    names.sort();
+
+    let mut changed = false;
+    for name in names {
+        if !content.contains(&format!("mod {};", name)) {
+            content.push_str(&format!("\nmod {}; \npub use {}::*; \n", name, name));
+            changed = true;
+        }
+    }
+
+    if changed {
+        fs::write(&mod_path, content).map_err(|e| format!("{:?}", e))?;
+    }
+    Ok(())
+}
+
+fn proto_files(proto_dir: &Path) -> Result<Vec<PathBuf>, String> {
+    if !proto_dir.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut files = fs::read_dir(proto_dir)
+        .map_err(|e| format!("{:?}", e))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().map(|ext| ext == "proto").unwrap_or(false))
+        .collect::<Vec<PathBuf>>();
+    files.sort();
+    Ok(files)
+}
+
+// The files generated by a different version of rust-protobuf only differ in
+// the version header, which is excluded from the comparison.
+fn is_same_model(existing: &str, generated: &str) -> bool {
+    let (existing_code, existing_descriptor) = split_descriptor(existing);
+    let (generated_code, generated_descriptor) = split_descriptor(generated);
+    normalize(&existing_code) == normalize(&generated_code) && existing_descriptor == generated_descriptor
+}
+
+fn normalize(content: &str) -> String {
+    content
+        .lines()
+        .filter(|line| !line.contains("generated by rust-protobuf") && !line.contains("_PROTOBUF_VERSION_CHECK"))
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+const DESCRIPTOR_BEGIN: &str = "static file_descriptor_proto_data: &'static [u8] = b\"";
+
+// Splits the embedded descriptor out of the rust file. The source code info of
+// the descriptor differs between protoc and the pure rust parser, so it's
+// cleared before the descriptors are compared.
+fn split_descriptor(content: &str) -> (String, Option<FileDescriptorProto>) {
+    let start = match content.find(DESCRIPTOR_BEGIN) {
+        None => return (content.to_owned(), None),
+        Some(start) => start + DESCRIPTOR_BEGIN.len(),
+    };
+
+    match unescape_byte_str(&content[start..]) {
+        None => (content.to_owned(), None),
+        Some((bytes, len)) => {
+            let code = format!("{}{}", &content[..start], &content[start + len..]);
+            let descriptor = FileDescriptorProto::parse_from_bytes(&bytes)
+                .ok()
+                .map(|mut descriptor| {
+                    descriptor.clear_source_code_info();
+                    descriptor
+                });
+            (code, descriptor)
+        },
+    }
+}
+
+// Unescapes the content of a byte string literal until the closing quote,
+// returns the bytes and the length of the escaped content.
+fn unescape_byte_str(s: &str) -> Option<(Vec<u8>, usize)> {
+    let bytes = s.as_bytes();
+    let mut buf = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => return Some((buf, i)),
+            b'\\' => {
+                let c = *bytes.get(i + 1)?;
+                i += 2;
+                match c {
+                    b'n' => buf.push(b'\n'),
+                    b'r' => buf.push(b'\r'),
+                    b't' => buf.push(b'\t'),
+                    b'0' => buf.push(0),
+                    b'\\' | b'"' | b'\'' => buf.push(c),
+                    b'x' => {
+                        let hex = s.get(i..i + 2)?;
+                        buf.push(u8::from_str_radix(hex, 16).ok()?);
+                        i += 2;
+                    },
+                    b'\n' | b'\r' => {
+                        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+                            i += 1;
+                        }
+                    },
+                    _ => return None,
+                }
+            },
+            c => {
+                buf.push(c);
+                i += 1;
+            },
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{is_same_model, unescape_byte_str};
+    use protobuf::{descriptor::FileDescriptorProto, Message};
+
+    fn model(descriptor: &FileDescriptorProto) -> String {
+        let bytes = descriptor.write_to_bytes().unwrap();
+        let escaped = bytes
+            .iter()
+            .map(|b| format!("\\x{:02x}", b))
+            .collect::<Vec<String>>()
+            .join("\\\n    ");
+        format!(
+            "// This file is generated by rust-protobuf 2.22.1\nstatic file_descriptor_proto_data: &'static [u8] = \
+             b\"\\\n    {}\";\nfn parse_descriptor_proto() {{}}",
+            escaped
+        )
+    }
+
+    #[test]
+    fn unescape_byte_str_test() {
+        let (bytes, len) = unescape_byte_str("\\\n    \\n\\tmsg\\\"\\\n    \\x01\\x20\\0\";\nb").unwrap();
+        assert_eq!(bytes, b"\n\tmsg\"\x01 \0".to_vec());
+        assert_eq!(len, 31);
+    }
+
+    #[test]
+    fn compare_descriptor_test() {
+        let mut descriptor = FileDescriptorProto::new();
+        descriptor.set_name("auth.proto".to_owned());
+        let a = model(&descriptor);
+
+        // The source code info is ignored
+        let mut with_source_info = descriptor.clone();
+        with_source_info.mut_source_code_info();
+        let b = model(&with_source_info).replace("2.22.1", "2.25.2");
+        assert!(is_same_model(&a, &b));
+
+        let mut renamed = descriptor.clone();
+        renamed.set_name("user.proto".to_owned());
+        assert!(!is_same_model(&a, &model(&renamed)));
+    }
+}
@@ -0,0 +1,257 @@
+// Generates the .proto files from the structs and enums of the `proto_crates`
+// listed in the Flowy.toml of the crate, the same way as `flowy-tool pb-gen`.
+use flowy_ast::*;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use syn::Item;
+
+#[derive(serde::Deserialize)]
+struct FlowyConfig {
+    proto_crates: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct ProtoFile {
+    // e.g. auth.proto
+    pub file_name: String,
+    pub content: String,
+}
+
+pub fn proto_crate_paths(crate_dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let config_path = crate_dir.join("Flowy.toml");
+    if !config_path.exists() {
+        return Ok(vec![]);
+    }
+
+    let content = fs::read_to_string(&config_path).map_err(|e| format!("{:?}", e))?;
+    let config: FlowyConfig =
+        toml::from_str(&content).map_err(|e| format!("Parse {} failed: {:?}", config_path.display(), e))?;
+    Ok(config.proto_crates.iter().map(|path| crate_dir.join(path)).collect())
+}
+
+// The imports of the existing .proto files in proto_dir are kept.
+pub fn gen_proto_files(crate_dir: &Path, proto_dir: &Path) -> Result<Vec<ProtoFile>, String> {
+    let mut proto_files: Vec<ProtoFile> = vec![];
+    for proto_crate_path in proto_crate_paths(crate_dir)? {
+        for path in rust_files(&proto_crate_path)? {
+            let file_stem = path.file_stem().unwrap().to_str().unwrap().to_owned();
+            if file_stem == "mod" {
+                continue;
+            }
+
+            let source = fs::read_to_string(&path).map_err(|e| format!("{:?}", e))?;
+            let ast = syn::parse_file(&source).map_err(|e| format!("Parse {} failed: {:?}", path.display(), e))?;
+            let file_name = format!("{}.proto", file_stem);
+            let header = proto_file_header(&proto_dir.join(&file_name));
+            if let Some(content) = gen_proto_content(&ast, header)? {
+                proto_files.retain(|proto_file| proto_file.file_name != file_name);
+                proto_files.push(ProtoFile { file_name, content });
+            }
+        }
+    }
+    Ok(proto_files)
+}
+
+fn rust_files(path: &Path) -> Result<Vec<PathBuf>, String> {
+    if path.is_file() {
+        return Ok(vec![path.to_owned()]);
+    }
+
+    if !path.is_dir() {
+        return Ok(vec![]);
+    }
+
+    let mut files = vec![];
+    let mut entries = fs::read_dir(path)
+        .map_err(|e| format!("{:?}", e))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| !is_hidden(path))
+        .collect::<Vec<PathBuf>>();
+    entries.sort();
+    for entry in entries {
+        if entry.is_dir() {
+            files.extend(rust_files(&entry)?);
+        } else if entry.extension().map(|ext| ext == "rs").unwrap_or(false) {
+            files.push(entry);
+        }
+    }
+    Ok(files)
+}
+
+fn is_hidden(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .map(|name| name.starts_with('.'))
+        .unwrap_or(false)
+}
+
+fn proto_file_header(path: &Path) -> String {
+    let mut header = String::new();
+    let content = fs::read_to_string(path).unwrap_or_default();
+    content
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| line.starts_with("syntax") || line.starts_with("import "))
+        .for_each(|line| {
+            header.push_str(line);
+            header.push('\n');
+        });
+
+    if header.is_empty() {
+        header.push_str("syntax = \"proto3\";\n");
+    }
+    header
+}
+
+fn gen_proto_content(ast: &syn::File, header: String) -> Result<Option<String>, String> {
+    let ctxt = Ctxt::new();
+    let mut messages = vec![];
+    let mut enums = vec![];
+    ast.items.iter().for_each(|item| match item {
+        Item::Struct(item_struct) => {
+            let (_, fields) = struct_from_ast(&ctxt, &item_struct.fields);
+            let fields = fields
+                .iter()
+                .filter(|field| field.attrs.pb_index().is_some())
+                .collect::<Vec<&ASTField>>();
+            if !fields.is_empty() {
+                let items = fields.into_iter().flat_map(proto_field).collect::<Vec<String>>();
+                messages.push(render("message", &item_struct.ident.to_string(), &items));
+            }
+        },
+        Item::Enum(item_enum) => {
+            let items = enum_from_ast(&ctxt, &item_enum.ident, &item_enum.variants, &ast.attrs)
+                .iter()
+                .map(|variant| format!("{} = {};", variant.attrs.enum_item_name, variant.attrs.value))
+                .collect::<Vec<String>>();
+            enums.push(render("enum", &item_enum.ident.to_string(), &items));
+        },
+        _ => {},
+    });
+    ctxt.check().map_err(|errors| {
+        errors
+            .iter()
+            .map(|error| error.to_string())
+            .collect::<Vec<String>>()
+            .join("\n")
+    })?;
+
+    if messages.is_empty() && enums.is_empty() {
+        return Ok(None);
+    }
+
+    let mut content = header;
+    content.push('\n');
+    messages.into_iter().chain(enums.into_iter()).for_each(|item| {
+        content.push_str(&item);
+        content.push('\n');
+    });
+    Ok(Some(content))
+}
+
+fn render(kind: &str, name: &str, items: &[String]) -> String {
+    let mut s = format!("{} {} {{", kind, name);
+    items.iter().for_each(|item| {
+        s.push_str("\n    ");
+        s.push_str(item);
+    });
+    s.push_str("\n}");
+    s
+}
+
+// Protobuf data type : https://developers.google.com/protocol-buffers/docs/proto3
+fn proto_ty(ty: &str) -> &str {
+    match ty {
+        "String" => "string",
+        "i64" => "int64",
+        "i32" => "int32",
+        "u64" => "uint64",
+        "u32" => "uint32",
+        "Vec" => "repeated",
+        "f64" => "double",
+        "HashMap" => "map",
+        _ => ty,
+    }
+}
+
+fn proto_field(field: &ASTField) -> Option<String> {
+    let name = field.name()?.to_string();
+    let index = field.attrs.pb_index()?;
+    let ty = field.ty_as_str();
+    let mapped_ty = proto_ty(&ty);
+
+    match field.bracket_category.as_ref()? {
+        BracketCategory::Opt => {
+            let inner_ty = field.bracket_inner_ty.as_ref()?.to_string();
+            match inner_ty.as_str() {
+                "Vec" => Some(format!("oneof one_of_{} {{ bytes {} = {}; }};", name, name, index)),
+                _ => Some(format!(
+                    "oneof one_of_{} {{ {} {} = {}; }};",
+                    name, mapped_ty, name, index
+                )),
+            }
+        },
+        BracketCategory::Map((k, v)) => Some(format!("map<{}, {}> {} = {};", proto_ty(k), proto_ty(v), name, index)),
+        BracketCategory::Vec => {
+            let bracket_ty = field.bracket_ty.as_ref()?.to_string();
+            if mapped_ty == "u8" && bracket_ty == "Vec" {
+                Some(format!("bytes {} = {};", name, index))
+            } else {
+                Some(format!("{} {} {} = {};", proto_ty(&bracket_ty), mapped_ty, name, index))
+            }
+        },
+        BracketCategory::Other => Some(format!("{} {} = {};", mapped_ty, name, index)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::proto_file::gen_proto_content;
+
+    #[test]
+    fn gen_proto_content_test() {
+        let ast = syn::parse_file(
+            r#"
+            #[derive(ProtoBuf, Default)]
+            pub struct SignInRequest {
+                #[pb(index = 1)]
+                pub email: String,
+
+                #[pb(index = 2, one_of)]
+                pub name: Option<String>,
+
+                #[pb(index = 3)]
+                pub items: Vec<u8>,
+            }
+
+            #[derive(ProtoBuf_Enum)]
+            pub enum ViewType {
+                Blank = 0,
+                Doc = 1,
+            }
+            "#,
+        )
+        .unwrap();
+
+        let content = gen_proto_content(&ast, "syntax = \"proto3\";\n".to_owned())
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            content,
+            r#"syntax = "proto3";
+
+message SignInRequest {
+    string email = 1;
+    oneof one_of_name { string name = 2; };
+    bytes items = 3;
+}
+enum ViewType {
+    Blank = 0;
+    Doc = 1;
+}
+"#
+        );
+    }
+}
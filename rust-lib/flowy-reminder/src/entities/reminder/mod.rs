@@ -0,0 +1,3 @@
+mod reminder;
+
+pub use reminder::*;
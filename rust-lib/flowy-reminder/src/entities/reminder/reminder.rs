@@ -0,0 +1,116 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+// What the reminder is attached to. The object of a Document is the id of its
+// view. The object of a Block is the id of the document and its child the id
+// the editor gave the block. The object of a GridRow is the id of the grid and
+// its child the id of the row.
+#[derive(ProtoBuf_Enum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReminderObjectType {
+    Document = 0,
+    Block    = 1,
+    GridRow  = 2,
+}
+
+impl std::default::Default for ReminderObjectType {
+    fn default() -> Self { ReminderObjectType::Document }
+}
+
+// A Scheduled reminder fires once its time is due, it's Fired until it's
+// snoozed, which schedules it again, or completed.
+#[derive(ProtoBuf_Enum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReminderStatus {
+    Scheduled = 0,
+    Fired     = 1,
+    Completed = 2,
+}
+
+impl std::default::Default for ReminderStatus {
+    fn default() -> Self { ReminderStatus::Scheduled }
+}
+
+// The times are the seconds of their timestamp.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct Reminder {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub object_type: ReminderObjectType,
+
+    #[pb(index = 3)]
+    pub object_id: String,
+
+    #[pb(index = 4)]
+    pub child_id: String,
+
+    #[pb(index = 5)]
+    pub title: String,
+
+    #[pb(index = 6)]
+    pub message: String,
+
+    #[pb(index = 7)]
+    pub remind_at: i64,
+
+    #[pb(index = 8)]
+    pub status: ReminderStatus,
+
+    #[pb(index = 9)]
+    pub create_time: i64,
+}
+
+// The reminders in the order of their time.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedReminder {
+    #[pb(index = 1)]
+    pub items: Vec<Reminder>,
+}
+
+// The child_id is required by the blocks and the grid rows.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct CreateReminderRequest {
+    #[pb(index = 1)]
+    pub object_type: ReminderObjectType,
+
+    #[pb(index = 2)]
+    pub object_id: String,
+
+    #[pb(index = 3)]
+    pub child_id: String,
+
+    #[pb(index = 4)]
+    pub title: String,
+
+    #[pb(index = 5)]
+    pub message: String,
+
+    #[pb(index = 6)]
+    pub remind_at: i64,
+}
+
+// An empty object_id reads the reminders of all the objects. The completed
+// reminders are skipped unless they're included.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct QueryRemindersRequest {
+    #[pb(index = 1)]
+    pub object_id: String,
+
+    #[pb(index = 2)]
+    pub include_completed: bool,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct ReminderIdentifier {
+    #[pb(index = 1)]
+    pub reminder_id: String,
+}
+
+// The reminder fires again once the seconds passed.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct SnoozeReminderRequest {
+    #[pb(index = 1)]
+    pub reminder_id: String,
+
+    #[pb(index = 2)]
+    pub seconds: i64,
+}
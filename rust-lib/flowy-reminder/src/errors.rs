@@ -0,0 +1,101 @@
+use bytes::Bytes;
+use derive_more::Display;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_dispatch::prelude::{EventResponse, ResponseBuilder};
+use std::{convert::TryInto, fmt, fmt::Debug};
+
+pub type ReminderResult<T> = std::result::Result<T, ReminderError>;
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct ReminderError {
+    #[pb(index = 1)]
+    pub code: ErrorCode,
+
+    #[pb(index = 2)]
+    pub msg: String,
+}
+
+macro_rules! static_reminder_error {
+    ($name:ident, $status:expr) => {
+        #[allow(non_snake_case, missing_docs)]
+        pub fn $name() -> ReminderError {
+            ReminderError {
+                code: $status,
+                msg: format!("{}", $status),
+            }
+        }
+    };
+}
+
+impl ReminderError {
+    pub fn context<T: Debug>(mut self, error: T) -> Self {
+        self.msg = format!("{:?}", error);
+        self
+    }
+
+    pub fn is_record_not_found(&self) -> bool { self.code == ErrorCode::RecordNotFound }
+
+    static_reminder_error!(internal, ErrorCode::InternalError);
+    static_reminder_error!(record_not_found, ErrorCode::RecordNotFound);
+    static_reminder_error!(unauthorized, ErrorCode::UserUnauthorized);
+    static_reminder_error!(reminder_not_found, ErrorCode::ReminderNotFound);
+    static_reminder_error!(object_invalid, ErrorCode::ReminderObjectInvalid);
+    static_reminder_error!(time_invalid, ErrorCode::ReminderTimeInvalid);
+    static_reminder_error!(snooze_invalid, ErrorCode::SnoozeDurationInvalid);
+    static_reminder_error!(completed, ErrorCode::ReminderCompleted);
+}
+
+pub fn internal_error<T>(e: T) -> ReminderError
+where
+    T: std::fmt::Debug,
+{
+    ReminderError::internal().context(e)
+}
+
+#[derive(Debug, Clone, ProtoBuf_Enum, Display, PartialEq, Eq)]
+pub enum ErrorCode {
+    #[display(fmt = "Record not found")]
+    RecordNotFound      = 0,
+
+    #[display(fmt = "Reminder not found")]
+    ReminderNotFound    = 10,
+
+    #[display(fmt = "The reminder isn't attached to an object")]
+    ReminderObjectInvalid = 20,
+    #[display(fmt = "The time of the reminder is invalid")]
+    ReminderTimeInvalid = 21,
+    #[display(fmt = "The reminder must be snoozed for some time")]
+    SnoozeDurationInvalid = 22,
+    #[display(fmt = "The reminder is completed")]
+    ReminderCompleted   = 23,
+
+    #[display(fmt = "UserUnauthorized")]
+    UserUnauthorized    = 999,
+
+    #[display(fmt = "InternalError")]
+    InternalError       = 1000,
+}
+
+impl std::default::Default for ErrorCode {
+    fn default() -> Self { ErrorCode::InternalError }
+}
+
+impl std::convert::From<flowy_database::Error> for ReminderError {
+    fn from(error: flowy_database::Error) -> Self {
+        match error {
+            flowy_database::Error::NotFound => ReminderError::record_not_found().context(error),
+            _ => ReminderError::internal().context(error),
+        }
+    }
+}
+
+impl flowy_dispatch::Error for ReminderError {
+    fn as_response(&self) -> EventResponse {
+        let bytes: Bytes = self.clone().try_into().unwrap();
+        ResponseBuilder::Err().data(bytes).build()
+    }
+}
+
+impl fmt::Display for ReminderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{:?}: {}", &self.code, &self.msg) }
+}
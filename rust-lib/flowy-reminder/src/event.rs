@@ -0,0 +1,24 @@
+use flowy_derive::{Flowy_Event, ProtoBuf_Enum};
+use strum_macros::Display;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Display, Hash, ProtoBuf_Enum, Flowy_Event)]
+#[event_err = "ReminderError"]
+pub enum ReminderEvent {
+    #[event(input = "CreateReminderRequest", output = "Reminder")]
+    CreateReminder   = 0,
+
+    #[event(input = "QueryRemindersRequest", output = "RepeatedReminder")]
+    ReadReminders    = 1,
+
+    #[event(input = "ReminderIdentifier")]
+    DeleteReminder   = 2,
+
+    #[event(input = "SnoozeReminderRequest", output = "Reminder")]
+    SnoozeReminder   = 3,
+
+    #[event(input = "ReminderIdentifier", output = "Reminder")]
+    CompleteReminder = 4,
+
+    #[event(output = "RepeatedReminder")]
+    FireDueReminders = 10,
+}
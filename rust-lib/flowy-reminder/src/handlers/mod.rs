@@ -0,0 +1,3 @@
+mod reminder_handler;
+
+pub use reminder_handler::*;
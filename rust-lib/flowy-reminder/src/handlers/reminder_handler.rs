@@ -0,0 +1,58 @@
+use crate::{entities::reminder::*, errors::ReminderError, services::reminder::ReminderController};
+use flowy_dispatch::prelude::{data_result, Data, DataResult, Unit};
+use std::sync::Arc;
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn create_reminder_handler(
+    data: Data<CreateReminderRequest>,
+    controller: Unit<Arc<ReminderController>>,
+) -> DataResult<Reminder, ReminderError> {
+    let reminder = controller.create_reminder(data.into_inner())?;
+    data_result(reminder)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_reminders_handler(
+    data: Data<QueryRemindersRequest>,
+    controller: Unit<Arc<ReminderController>>,
+) -> DataResult<RepeatedReminder, ReminderError> {
+    let reminders = controller.read_reminders(data.into_inner())?;
+    data_result(reminders)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn delete_reminder_handler(
+    data: Data<ReminderIdentifier>,
+    controller: Unit<Arc<ReminderController>>,
+) -> Result<(), ReminderError> {
+    let params = data.into_inner();
+    let _ = controller.delete_reminder(&params.reminder_id)?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn snooze_reminder_handler(
+    data: Data<SnoozeReminderRequest>,
+    controller: Unit<Arc<ReminderController>>,
+) -> DataResult<Reminder, ReminderError> {
+    let reminder = controller.snooze_reminder(data.into_inner())?;
+    data_result(reminder)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn complete_reminder_handler(
+    data: Data<ReminderIdentifier>,
+    controller: Unit<Arc<ReminderController>>,
+) -> DataResult<Reminder, ReminderError> {
+    let params = data.into_inner();
+    let reminder = controller.complete_reminder(&params.reminder_id)?;
+    data_result(reminder)
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn fire_due_reminders_handler(
+    controller: Unit<Arc<ReminderController>>,
+) -> DataResult<RepeatedReminder, ReminderError> {
+    let reminders = controller.fire_due_reminders()?;
+    data_result(reminders)
+}
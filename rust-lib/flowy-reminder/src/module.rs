@@ -0,0 +1,47 @@
+use crate::{
+    entities::reminder::Reminder,
+    errors::ReminderError,
+    event::ReminderEvent,
+    handlers::*,
+    services::reminder::ReminderController,
+};
+use flowy_database::ConnectionPool;
+use flowy_dispatch::prelude::*;
+use flowy_infra::clock::Clock;
+use std::{fmt, sync::Arc};
+
+pub trait ReminderUser: Send + Sync {
+    fn user_id(&self) -> Result<String, ReminderError>;
+    fn db_pool(&self) -> Result<Arc<ConnectionPool>, ReminderError>;
+}
+
+// Implemented by the host to show the reminders that fire outside of the app,
+// e.g. with the notifications of the OS. The frontend is sent ReminderFired
+// too.
+pub trait ReminderNotifier: Send + Sync {
+    fn notify(&self, reminder: &Reminder);
+}
+
+impl fmt::Debug for dyn ReminderNotifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("ReminderNotifier") }
+}
+
+pub fn mk_reminder(
+    user: Arc<dyn ReminderUser>,
+    clock: Arc<dyn Clock>,
+    notifier: Option<Arc<dyn ReminderNotifier>>,
+) -> Arc<ReminderController> {
+    Arc::new(ReminderController::new(user, clock, notifier))
+}
+
+pub fn create(controller: Arc<ReminderController>) -> Module {
+    Module::new()
+        .name("Flowy-Reminder")
+        .data(controller)
+        .event(ReminderEvent::CreateReminder, create_reminder_handler)
+        .event(ReminderEvent::ReadReminders, read_reminders_handler)
+        .event(ReminderEvent::DeleteReminder, delete_reminder_handler)
+        .event(ReminderEvent::SnoozeReminder, snooze_reminder_handler)
+        .event(ReminderEvent::CompleteReminder, complete_reminder_handler)
+        .event(ReminderEvent::FireDueReminders, fire_due_reminders_handler)
+}
@@ -0,0 +1,3 @@
+mod observable;
+
+pub(crate) use observable::*;
@@ -0,0 +1,20 @@
+use flowy_dart_notify::DartNotifyBuilder;
+use flowy_derive::ProtoBuf_Enum;
+const OBSERVABLE_CATEGORY: &'static str = "Reminder";
+
+// The id of RemindersChanged is the one of the object, it sends the reminders
+// of the object that aren't completed. The id of ReminderFired is the one of
+// the user, it sends the reminder that fired.
+#[derive(ProtoBuf_Enum, Debug)]
+pub(crate) enum ReminderObservable {
+    RemindersChanged = 0,
+    ReminderFired    = 1,
+}
+
+impl std::convert::Into<i32> for ReminderObservable {
+    fn into(self) -> i32 { self as i32 }
+}
+
+pub(crate) fn dart_notify(id: &str, ty: ReminderObservable) -> DartNotifyBuilder {
+    DartNotifyBuilder::new(id, ty, OBSERVABLE_CATEGORY)
+}
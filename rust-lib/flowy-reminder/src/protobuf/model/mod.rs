@@ -0,0 +1,13 @@
+// Auto-generated, do not edit 
+
+mod reminder; 
+pub use reminder::*; 
+
+mod observable; 
+pub use observable::*; 
+
+mod errors; 
+pub use errors::*; 
+
+mod event; 
+pub use event::*; 
@@ -0,0 +1,1692 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `reminder.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Reminder {
+    // message fields
+    pub id: ::std::string::String,
+    pub object_type: ReminderObjectType,
+    pub object_id: ::std::string::String,
+    pub child_id: ::std::string::String,
+    pub title: ::std::string::String,
+    pub message: ::std::string::String,
+    pub remind_at: i64,
+    pub status: ReminderStatus,
+    pub create_time: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Reminder {
+    fn default() -> &'a Reminder {
+        <Reminder as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Reminder {
+    pub fn new() -> Reminder {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // .ReminderObjectType object_type = 2;
+
+
+    pub fn get_object_type(&self) -> ReminderObjectType {
+        self.object_type
+    }
+    pub fn clear_object_type(&mut self) {
+        self.object_type = ReminderObjectType::Document;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object_type(&mut self, v: ReminderObjectType) {
+        self.object_type = v;
+    }
+
+    // string object_id = 3;
+
+
+    pub fn get_object_id(&self) -> &str {
+        &self.object_id
+    }
+    pub fn clear_object_id(&mut self) {
+        self.object_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object_id(&mut self, v: ::std::string::String) {
+        self.object_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_object_id(&mut self) -> &mut ::std::string::String {
+        &mut self.object_id
+    }
+
+    // Take field
+    pub fn take_object_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.object_id, ::std::string::String::new())
+    }
+
+    // string child_id = 4;
+
+
+    pub fn get_child_id(&self) -> &str {
+        &self.child_id
+    }
+    pub fn clear_child_id(&mut self) {
+        self.child_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_child_id(&mut self, v: ::std::string::String) {
+        self.child_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_child_id(&mut self) -> &mut ::std::string::String {
+        &mut self.child_id
+    }
+
+    // Take field
+    pub fn take_child_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.child_id, ::std::string::String::new())
+    }
+
+    // string title = 5;
+
+
+    pub fn get_title(&self) -> &str {
+        &self.title
+    }
+    pub fn clear_title(&mut self) {
+        self.title.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_title(&mut self, v: ::std::string::String) {
+        self.title = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_title(&mut self) -> &mut ::std::string::String {
+        &mut self.title
+    }
+
+    // Take field
+    pub fn take_title(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.title, ::std::string::String::new())
+    }
+
+    // string message = 6;
+
+
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+    pub fn clear_message(&mut self) {
+        self.message.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message(&mut self, v: ::std::string::String) {
+        self.message = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message(&mut self) -> &mut ::std::string::String {
+        &mut self.message
+    }
+
+    // Take field
+    pub fn take_message(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.message, ::std::string::String::new())
+    }
+
+    // int64 remind_at = 7;
+
+
+    pub fn get_remind_at(&self) -> i64 {
+        self.remind_at
+    }
+    pub fn clear_remind_at(&mut self) {
+        self.remind_at = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_remind_at(&mut self, v: i64) {
+        self.remind_at = v;
+    }
+
+    // .ReminderStatus status = 8;
+
+
+    pub fn get_status(&self) -> ReminderStatus {
+        self.status
+    }
+    pub fn clear_status(&mut self) {
+        self.status = ReminderStatus::Scheduled;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: ReminderStatus) {
+        self.status = v;
+    }
+
+    // int64 create_time = 9;
+
+
+    pub fn get_create_time(&self) -> i64 {
+        self.create_time
+    }
+    pub fn clear_create_time(&mut self) {
+        self.create_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_create_time(&mut self, v: i64) {
+        self.create_time = v;
+    }
+}
+
+impl ::protobuf::Message for Reminder {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.object_type, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.object_id)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.child_id)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.title)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.message)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.remind_at = tmp;
+                },
+                8 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 8, &mut self.unknown_fields)?
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.create_time = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if self.object_type != ReminderObjectType::Document {
+            my_size += ::protobuf::rt::enum_size(2, self.object_type);
+        }
+        if !self.object_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.object_id);
+        }
+        if !self.child_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.child_id);
+        }
+        if !self.title.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.title);
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.message);
+        }
+        if self.remind_at != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.remind_at, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.status != ReminderStatus::Scheduled {
+            my_size += ::protobuf::rt::enum_size(8, self.status);
+        }
+        if self.create_time != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.create_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if self.object_type != ReminderObjectType::Document {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.object_type))?;
+        }
+        if !self.object_id.is_empty() {
+            os.write_string(3, &self.object_id)?;
+        }
+        if !self.child_id.is_empty() {
+            os.write_string(4, &self.child_id)?;
+        }
+        if !self.title.is_empty() {
+            os.write_string(5, &self.title)?;
+        }
+        if !self.message.is_empty() {
+            os.write_string(6, &self.message)?;
+        }
+        if self.remind_at != 0 {
+            os.write_int64(7, self.remind_at)?;
+        }
+        if self.status != ReminderStatus::Scheduled {
+            os.write_enum(8, ::protobuf::ProtobufEnum::value(&self.status))?;
+        }
+        if self.create_time != 0 {
+            os.write_int64(9, self.create_time)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Reminder {
+        Reminder::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &Reminder| { &m.id },
+                |m: &mut Reminder| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ReminderObjectType>>(
+                "object_type",
+                |m: &Reminder| { &m.object_type },
+                |m: &mut Reminder| { &mut m.object_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "object_id",
+                |m: &Reminder| { &m.object_id },
+                |m: &mut Reminder| { &mut m.object_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "child_id",
+                |m: &Reminder| { &m.child_id },
+                |m: &mut Reminder| { &mut m.child_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "title",
+                |m: &Reminder| { &m.title },
+                |m: &mut Reminder| { &mut m.title },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "message",
+                |m: &Reminder| { &m.message },
+                |m: &mut Reminder| { &mut m.message },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "remind_at",
+                |m: &Reminder| { &m.remind_at },
+                |m: &mut Reminder| { &mut m.remind_at },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ReminderStatus>>(
+                "status",
+                |m: &Reminder| { &m.status },
+                |m: &mut Reminder| { &mut m.status },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "create_time",
+                |m: &Reminder| { &m.create_time },
+                |m: &mut Reminder| { &mut m.create_time },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Reminder>(
+                "Reminder",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Reminder {
+        static instance: ::protobuf::rt::LazyV2<Reminder> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Reminder::new)
+    }
+}
+
+impl ::protobuf::Clear for Reminder {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.object_type = ReminderObjectType::Document;
+        self.object_id.clear();
+        self.child_id.clear();
+        self.title.clear();
+        self.message.clear();
+        self.remind_at = 0;
+        self.status = ReminderStatus::Scheduled;
+        self.create_time = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Reminder {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Reminder {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedReminder {
+    // message fields
+    pub items: ::protobuf::RepeatedField<Reminder>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedReminder {
+    fn default() -> &'a RepeatedReminder {
+        <RepeatedReminder as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedReminder {
+    pub fn new() -> RepeatedReminder {
+        ::std::default::Default::default()
+    }
+
+    // repeated .Reminder items = 1;
+
+
+    pub fn get_items(&self) -> &[Reminder] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<Reminder>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<Reminder> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<Reminder> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedReminder {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedReminder {
+        RepeatedReminder::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Reminder>>(
+                "items",
+                |m: &RepeatedReminder| { &m.items },
+                |m: &mut RepeatedReminder| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedReminder>(
+                "RepeatedReminder",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedReminder {
+        static instance: ::protobuf::rt::LazyV2<RepeatedReminder> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedReminder::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedReminder {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedReminder {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedReminder {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateReminderRequest {
+    // message fields
+    pub object_type: ReminderObjectType,
+    pub object_id: ::std::string::String,
+    pub child_id: ::std::string::String,
+    pub title: ::std::string::String,
+    pub message: ::std::string::String,
+    pub remind_at: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateReminderRequest {
+    fn default() -> &'a CreateReminderRequest {
+        <CreateReminderRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateReminderRequest {
+    pub fn new() -> CreateReminderRequest {
+        ::std::default::Default::default()
+    }
+
+    // .ReminderObjectType object_type = 1;
+
+
+    pub fn get_object_type(&self) -> ReminderObjectType {
+        self.object_type
+    }
+    pub fn clear_object_type(&mut self) {
+        self.object_type = ReminderObjectType::Document;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object_type(&mut self, v: ReminderObjectType) {
+        self.object_type = v;
+    }
+
+    // string object_id = 2;
+
+
+    pub fn get_object_id(&self) -> &str {
+        &self.object_id
+    }
+    pub fn clear_object_id(&mut self) {
+        self.object_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object_id(&mut self, v: ::std::string::String) {
+        self.object_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_object_id(&mut self) -> &mut ::std::string::String {
+        &mut self.object_id
+    }
+
+    // Take field
+    pub fn take_object_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.object_id, ::std::string::String::new())
+    }
+
+    // string child_id = 3;
+
+
+    pub fn get_child_id(&self) -> &str {
+        &self.child_id
+    }
+    pub fn clear_child_id(&mut self) {
+        self.child_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_child_id(&mut self, v: ::std::string::String) {
+        self.child_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_child_id(&mut self) -> &mut ::std::string::String {
+        &mut self.child_id
+    }
+
+    // Take field
+    pub fn take_child_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.child_id, ::std::string::String::new())
+    }
+
+    // string title = 4;
+
+
+    pub fn get_title(&self) -> &str {
+        &self.title
+    }
+    pub fn clear_title(&mut self) {
+        self.title.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_title(&mut self, v: ::std::string::String) {
+        self.title = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_title(&mut self) -> &mut ::std::string::String {
+        &mut self.title
+    }
+
+    // Take field
+    pub fn take_title(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.title, ::std::string::String::new())
+    }
+
+    // string message = 5;
+
+
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+    pub fn clear_message(&mut self) {
+        self.message.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message(&mut self, v: ::std::string::String) {
+        self.message = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message(&mut self) -> &mut ::std::string::String {
+        &mut self.message
+    }
+
+    // Take field
+    pub fn take_message(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.message, ::std::string::String::new())
+    }
+
+    // int64 remind_at = 6;
+
+
+    pub fn get_remind_at(&self) -> i64 {
+        self.remind_at
+    }
+    pub fn clear_remind_at(&mut self) {
+        self.remind_at = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_remind_at(&mut self, v: i64) {
+        self.remind_at = v;
+    }
+}
+
+impl ::protobuf::Message for CreateReminderRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.object_type, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.object_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.child_id)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.title)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.message)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.remind_at = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.object_type != ReminderObjectType::Document {
+            my_size += ::protobuf::rt::enum_size(1, self.object_type);
+        }
+        if !self.object_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.object_id);
+        }
+        if !self.child_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.child_id);
+        }
+        if !self.title.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.title);
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.message);
+        }
+        if self.remind_at != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.remind_at, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.object_type != ReminderObjectType::Document {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.object_type))?;
+        }
+        if !self.object_id.is_empty() {
+            os.write_string(2, &self.object_id)?;
+        }
+        if !self.child_id.is_empty() {
+            os.write_string(3, &self.child_id)?;
+        }
+        if !self.title.is_empty() {
+            os.write_string(4, &self.title)?;
+        }
+        if !self.message.is_empty() {
+            os.write_string(5, &self.message)?;
+        }
+        if self.remind_at != 0 {
+            os.write_int64(6, self.remind_at)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateReminderRequest {
+        CreateReminderRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ReminderObjectType>>(
+                "object_type",
+                |m: &CreateReminderRequest| { &m.object_type },
+                |m: &mut CreateReminderRequest| { &mut m.object_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "object_id",
+                |m: &CreateReminderRequest| { &m.object_id },
+                |m: &mut CreateReminderRequest| { &mut m.object_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "child_id",
+                |m: &CreateReminderRequest| { &m.child_id },
+                |m: &mut CreateReminderRequest| { &mut m.child_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "title",
+                |m: &CreateReminderRequest| { &m.title },
+                |m: &mut CreateReminderRequest| { &mut m.title },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "message",
+                |m: &CreateReminderRequest| { &m.message },
+                |m: &mut CreateReminderRequest| { &mut m.message },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "remind_at",
+                |m: &CreateReminderRequest| { &m.remind_at },
+                |m: &mut CreateReminderRequest| { &mut m.remind_at },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateReminderRequest>(
+                "CreateReminderRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateReminderRequest {
+        static instance: ::protobuf::rt::LazyV2<CreateReminderRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateReminderRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateReminderRequest {
+    fn clear(&mut self) {
+        self.object_type = ReminderObjectType::Document;
+        self.object_id.clear();
+        self.child_id.clear();
+        self.title.clear();
+        self.message.clear();
+        self.remind_at = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateReminderRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateReminderRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryRemindersRequest {
+    // message fields
+    pub object_id: ::std::string::String,
+    pub include_completed: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryRemindersRequest {
+    fn default() -> &'a QueryRemindersRequest {
+        <QueryRemindersRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryRemindersRequest {
+    pub fn new() -> QueryRemindersRequest {
+        ::std::default::Default::default()
+    }
+
+    // string object_id = 1;
+
+
+    pub fn get_object_id(&self) -> &str {
+        &self.object_id
+    }
+    pub fn clear_object_id(&mut self) {
+        self.object_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object_id(&mut self, v: ::std::string::String) {
+        self.object_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_object_id(&mut self) -> &mut ::std::string::String {
+        &mut self.object_id
+    }
+
+    // Take field
+    pub fn take_object_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.object_id, ::std::string::String::new())
+    }
+
+    // bool include_completed = 2;
+
+
+    pub fn get_include_completed(&self) -> bool {
+        self.include_completed
+    }
+    pub fn clear_include_completed(&mut self) {
+        self.include_completed = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_include_completed(&mut self, v: bool) {
+        self.include_completed = v;
+    }
+}
+
+impl ::protobuf::Message for QueryRemindersRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.object_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.include_completed = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.object_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.object_id);
+        }
+        if self.include_completed != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.object_id.is_empty() {
+            os.write_string(1, &self.object_id)?;
+        }
+        if self.include_completed != false {
+            os.write_bool(2, self.include_completed)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryRemindersRequest {
+        QueryRemindersRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "object_id",
+                |m: &QueryRemindersRequest| { &m.object_id },
+                |m: &mut QueryRemindersRequest| { &mut m.object_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "include_completed",
+                |m: &QueryRemindersRequest| { &m.include_completed },
+                |m: &mut QueryRemindersRequest| { &mut m.include_completed },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QueryRemindersRequest>(
+                "QueryRemindersRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QueryRemindersRequest {
+        static instance: ::protobuf::rt::LazyV2<QueryRemindersRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QueryRemindersRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for QueryRemindersRequest {
+    fn clear(&mut self) {
+        self.object_id.clear();
+        self.include_completed = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryRemindersRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryRemindersRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ReminderIdentifier {
+    // message fields
+    pub reminder_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ReminderIdentifier {
+    fn default() -> &'a ReminderIdentifier {
+        <ReminderIdentifier as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReminderIdentifier {
+    pub fn new() -> ReminderIdentifier {
+        ::std::default::Default::default()
+    }
+
+    // string reminder_id = 1;
+
+
+    pub fn get_reminder_id(&self) -> &str {
+        &self.reminder_id
+    }
+    pub fn clear_reminder_id(&mut self) {
+        self.reminder_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reminder_id(&mut self, v: ::std::string::String) {
+        self.reminder_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_reminder_id(&mut self) -> &mut ::std::string::String {
+        &mut self.reminder_id
+    }
+
+    // Take field
+    pub fn take_reminder_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.reminder_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ReminderIdentifier {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.reminder_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.reminder_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.reminder_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.reminder_id.is_empty() {
+            os.write_string(1, &self.reminder_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ReminderIdentifier {
+        ReminderIdentifier::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "reminder_id",
+                |m: &ReminderIdentifier| { &m.reminder_id },
+                |m: &mut ReminderIdentifier| { &mut m.reminder_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ReminderIdentifier>(
+                "ReminderIdentifier",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ReminderIdentifier {
+        static instance: ::protobuf::rt::LazyV2<ReminderIdentifier> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ReminderIdentifier::new)
+    }
+}
+
+impl ::protobuf::Clear for ReminderIdentifier {
+    fn clear(&mut self) {
+        self.reminder_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ReminderIdentifier {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReminderIdentifier {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SnoozeReminderRequest {
+    // message fields
+    pub reminder_id: ::std::string::String,
+    pub seconds: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SnoozeReminderRequest {
+    fn default() -> &'a SnoozeReminderRequest {
+        <SnoozeReminderRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SnoozeReminderRequest {
+    pub fn new() -> SnoozeReminderRequest {
+        ::std::default::Default::default()
+    }
+
+    // string reminder_id = 1;
+
+
+    pub fn get_reminder_id(&self) -> &str {
+        &self.reminder_id
+    }
+    pub fn clear_reminder_id(&mut self) {
+        self.reminder_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reminder_id(&mut self, v: ::std::string::String) {
+        self.reminder_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_reminder_id(&mut self) -> &mut ::std::string::String {
+        &mut self.reminder_id
+    }
+
+    // Take field
+    pub fn take_reminder_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.reminder_id, ::std::string::String::new())
+    }
+
+    // int64 seconds = 2;
+
+
+    pub fn get_seconds(&self) -> i64 {
+        self.seconds
+    }
+    pub fn clear_seconds(&mut self) {
+        self.seconds = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_seconds(&mut self, v: i64) {
+        self.seconds = v;
+    }
+}
+
+impl ::protobuf::Message for SnoozeReminderRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.reminder_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.seconds = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.reminder_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.reminder_id);
+        }
+        if self.seconds != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.seconds, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.reminder_id.is_empty() {
+            os.write_string(1, &self.reminder_id)?;
+        }
+        if self.seconds != 0 {
+            os.write_int64(2, self.seconds)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SnoozeReminderRequest {
+        SnoozeReminderRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "reminder_id",
+                |m: &SnoozeReminderRequest| { &m.reminder_id },
+                |m: &mut SnoozeReminderRequest| { &mut m.reminder_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "seconds",
+                |m: &SnoozeReminderRequest| { &m.seconds },
+                |m: &mut SnoozeReminderRequest| { &mut m.seconds },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SnoozeReminderRequest>(
+                "SnoozeReminderRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SnoozeReminderRequest {
+        static instance: ::protobuf::rt::LazyV2<SnoozeReminderRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SnoozeReminderRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SnoozeReminderRequest {
+    fn clear(&mut self) {
+        self.reminder_id.clear();
+        self.seconds = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SnoozeReminderRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SnoozeReminderRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum ReminderObjectType {
+    Document = 0,
+    Block = 1,
+    GridRow = 2,
+}
+
+impl ::protobuf::ProtobufEnum for ReminderObjectType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ReminderObjectType> {
+        match value {
+            0 => ::std::option::Option::Some(ReminderObjectType::Document),
+            1 => ::std::option::Option::Some(ReminderObjectType::Block),
+            2 => ::std::option::Option::Some(ReminderObjectType::GridRow),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [ReminderObjectType] = &[
+            ReminderObjectType::Document,
+            ReminderObjectType::Block,
+            ReminderObjectType::GridRow,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<ReminderObjectType>("ReminderObjectType", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for ReminderObjectType {
+}
+
+impl ::std::default::Default for ReminderObjectType {
+    fn default() -> Self {
+        ReminderObjectType::Document
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReminderObjectType {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum ReminderStatus {
+    Scheduled = 0,
+    Fired = 1,
+    Completed = 2,
+}
+
+impl ::protobuf::ProtobufEnum for ReminderStatus {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ReminderStatus> {
+        match value {
+            0 => ::std::option::Option::Some(ReminderStatus::Scheduled),
+            1 => ::std::option::Option::Some(ReminderStatus::Fired),
+            2 => ::std::option::Option::Some(ReminderStatus::Completed),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [ReminderStatus] = &[
+            ReminderStatus::Scheduled,
+            ReminderStatus::Fired,
+            ReminderStatus::Completed,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<ReminderStatus>("ReminderStatus", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for ReminderStatus {
+}
+
+impl ::std::default::Default for ReminderStatus {
+    fn default() -> Self {
+        ReminderStatus::Scheduled
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReminderStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0ereminder.proto\"\xb3\x02\n\x08Reminder\x12\x10\n\x02id\x18\x01\x20\
+    \x01(\tR\x02idB\0\x126\n\x0bobject_type\x18\x02\x20\x01(\x0e2\x13.Remind\
+    erObjectTypeR\nobjectTypeB\0\x12\x1d\n\tobject_id\x18\x03\x20\x01(\tR\
+    \x08objectIdB\0\x12\x1b\n\x08child_id\x18\x04\x20\x01(\tR\x07childIdB\0\
+    \x12\x16\n\x05title\x18\x05\x20\x01(\tR\x05titleB\0\x12\x1a\n\x07message\
+    \x18\x06\x20\x01(\tR\x07messageB\0\x12\x1d\n\tremind_at\x18\x07\x20\x01(\
+    \x03R\x08remindAtB\0\x12)\n\x06status\x18\x08\x20\x01(\x0e2\x0f.Reminder\
+    StatusR\x06statusB\0\x12!\n\x0bcreate_time\x18\t\x20\x01(\x03R\ncreateTi\
+    meB\0:\0\"7\n\x10RepeatedReminder\x12!\n\x05items\x18\x01\x20\x03(\x0b2\
+    \t.ReminderR\x05itemsB\0:\0\"\xe0\x01\n\x15CreateReminderRequest\x126\n\
+    \x0bobject_type\x18\x01\x20\x01(\x0e2\x13.ReminderObjectTypeR\nobjectTyp\
+    eB\0\x12\x1d\n\tobject_id\x18\x02\x20\x01(\tR\x08objectIdB\0\x12\x1b\n\
+    \x08child_id\x18\x03\x20\x01(\tR\x07childIdB\0\x12\x16\n\x05title\x18\
+    \x04\x20\x01(\tR\x05titleB\0\x12\x1a\n\x07message\x18\x05\x20\x01(\tR\
+    \x07messageB\0\x12\x1d\n\tremind_at\x18\x06\x20\x01(\x03R\x08remindAtB\0\
+    :\0\"g\n\x15QueryRemindersRequest\x12\x1d\n\tobject_id\x18\x01\x20\x01(\
+    \tR\x08objectIdB\0\x12-\n\x11include_completed\x18\x02\x20\x01(\x08R\x10\
+    includeCompletedB\0:\0\"9\n\x12ReminderIdentifier\x12!\n\x0breminder_id\
+    \x18\x01\x20\x01(\tR\nreminderIdB\0:\0\"X\n\x15SnoozeReminderRequest\x12\
+    !\n\x0breminder_id\x18\x01\x20\x01(\tR\nreminderIdB\0\x12\x1a\n\x07secon\
+    ds\x18\x02\x20\x01(\x03R\x07secondsB\0:\0*<\n\x12ReminderObjectType\x12\
+    \x0c\n\x08Document\x10\0\x12\t\n\x05Block\x10\x01\x12\x0b\n\x07GridRow\
+    \x10\x02\x1a\0*;\n\x0eReminderStatus\x12\r\n\tScheduled\x10\0\x12\t\n\
+    \x05Fired\x10\x01\x12\r\n\tCompleted\x10\x02\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,3 @@
+mod reminder_controller;
+
+pub use reminder_controller::*;
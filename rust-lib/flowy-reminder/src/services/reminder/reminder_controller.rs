@@ -0,0 +1,209 @@
+use crate::{
+    entities::reminder::*,
+    errors::{internal_error, ReminderError, ReminderResult},
+    module::{ReminderNotifier, ReminderUser},
+    notify::{dart_notify, ReminderObservable},
+    sql_tables::reminder::*,
+};
+use flowy_database::SqliteConnection;
+use flowy_infra::clock::Clock;
+use std::{collections::BTreeSet, sync::Arc, time::Duration};
+use tokio::sync::Notify;
+
+// The reminders of the user are kept in the reminder table. The sdk waits for
+// next_due and dispatches FireDueReminders, which sends ReminderFired for each
+// reminder that's due and hands it to the notifier of the host.
+pub struct ReminderController {
+    user: Arc<dyn ReminderUser>,
+    clock: Arc<dyn Clock>,
+    notifier: Option<Arc<dyn ReminderNotifier>>,
+    reminder_sql: ReminderTableSql,
+    wakeup: Notify,
+}
+
+impl ReminderController {
+    pub(crate) fn new(
+        user: Arc<dyn ReminderUser>,
+        clock: Arc<dyn Clock>,
+        notifier: Option<Arc<dyn ReminderNotifier>>,
+    ) -> Self {
+        Self {
+            user,
+            clock,
+            notifier,
+            reminder_sql: ReminderTableSql {},
+            wakeup: Notify::new(),
+        }
+    }
+
+    pub(crate) fn create_reminder(&self, request: CreateReminderRequest) -> ReminderResult<Reminder> {
+        if request.object_id.trim().is_empty() {
+            return Err(ReminderError::object_invalid());
+        }
+        if request.object_type != ReminderObjectType::Document && request.child_id.trim().is_empty() {
+            return Err(ReminderError::object_invalid().context("The block or the row of the reminder is missing"));
+        }
+        if request.remind_at <= 0 {
+            return Err(ReminderError::time_invalid());
+        }
+        let table = ReminderTable::new(request, self.clock.timestamp());
+        let reminder_id = table.id.clone();
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let _ = self.reminder_sql.create_reminder_table(table, conn)?;
+        let reminder = self.read_reminder_table(&reminder_id, conn)?;
+        let _ = self.did_change(&reminder.object_id, conn)?;
+        Ok(reminder.into())
+    }
+
+    pub(crate) fn read_reminders(&self, request: QueryRemindersRequest) -> ReminderResult<RepeatedReminder> {
+        let object_id = match request.object_id.is_empty() {
+            true => None,
+            false => Some(request.object_id.as_str()),
+        };
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        let items = self
+            .reminder_sql
+            .read_reminder_tables(object_id, request.include_completed, conn)?
+            .into_iter()
+            .map(Reminder::from)
+            .collect();
+        Ok(RepeatedReminder { items })
+    }
+
+    pub(crate) fn delete_reminder(&self, reminder_id: &str) -> ReminderResult<()> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let table = self.read_reminder_table(reminder_id, conn)?;
+        let _ = self.reminder_sql.delete_reminder_table(reminder_id, conn)?;
+        self.did_change(&table.object_id, conn)
+    }
+
+    // The reminder is scheduled again, from now, whether it fired or not.
+    pub(crate) fn snooze_reminder(&self, request: SnoozeReminderRequest) -> ReminderResult<Reminder> {
+        if request.seconds <= 0 {
+            return Err(ReminderError::snooze_invalid());
+        }
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let table = self.read_reminder_table(&request.reminder_id, conn)?;
+        if table.status == SqlReminderStatus::Completed {
+            return Err(ReminderError::completed());
+        }
+        let remind_at = self.clock.timestamp() + request.seconds;
+        let _ = self
+            .reminder_sql
+            .reschedule_reminder(&request.reminder_id, remind_at, conn)?;
+        let table = self.read_reminder_table(&request.reminder_id, conn)?;
+        let _ = self.did_change(&table.object_id, conn)?;
+        Ok(table.into())
+    }
+
+    pub(crate) fn complete_reminder(&self, reminder_id: &str) -> ReminderResult<Reminder> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let _ = self.read_reminder_table(reminder_id, conn)?;
+        let _ = self
+            .reminder_sql
+            .update_reminder_status(reminder_id, SqlReminderStatus::Completed, conn)?;
+        let table = self.read_reminder_table(reminder_id, conn)?;
+        let _ = self.did_change(&table.object_id, conn)?;
+        Ok(table.into())
+    }
+
+    // Returns the reminders that fired, in the order of their time.
+    pub(crate) fn fire_due_reminders(&self) -> ReminderResult<RepeatedReminder> {
+        let user_id = self.user.user_id()?;
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let tables = conn.immediate_transaction::<_, ReminderError, _>(|| {
+            let tables = self
+                .reminder_sql
+                .read_due_reminder_tables(self.clock.timestamp(), conn)?;
+            for table in &tables {
+                let _ = self
+                    .reminder_sql
+                    .update_reminder_status(&table.id, SqlReminderStatus::Fired, conn)?;
+            }
+            Ok(tables)
+        })?;
+
+        let mut object_ids = BTreeSet::new();
+        let mut items = vec![];
+        for mut table in tables {
+            table.status = SqlReminderStatus::Fired;
+            object_ids.insert(table.object_id.clone());
+            let reminder = Reminder::from(table);
+            tracing::debug!("Fire the reminder {} of {}", reminder.id, reminder.object_id);
+            dart_notify(&user_id, ReminderObservable::ReminderFired)
+                .payload(reminder.clone())
+                .send();
+            if let Some(notifier) = &self.notifier {
+                notifier.notify(&reminder);
+            }
+            items.push(reminder);
+        }
+        for object_id in object_ids {
+            let _ = self.did_change(&object_id, conn)?;
+        }
+        Ok(RepeatedReminder { items })
+    }
+
+    // Returns when a scheduled reminder is due. It's computed again when the
+    // reminders change or the user changes, and waits for one of them while no
+    // reminder is scheduled or no user is signed in.
+    pub async fn next_due(&self) {
+        loop {
+            let wakeup = self.wakeup.notified();
+            let remind_at = match self.read_next_remind_at() {
+                Ok(Some(remind_at)) => remind_at,
+                Ok(None) | Err(_) => {
+                    wakeup.await;
+                    continue;
+                },
+            };
+            let delay = remind_at * 1000 - self.clock.timestamp_millis();
+            if delay <= 0 {
+                return;
+            }
+            tokio::select! {
+                _ = self.clock.sleep(Duration::from_millis(delay as u64)) => return,
+                _ = wakeup => {},
+            }
+        }
+    }
+
+    pub fn user_did_change(&self) { self.wakeup.notify_one(); }
+
+    fn read_next_remind_at(&self) -> ReminderResult<Option<i64>> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        self.reminder_sql.read_next_remind_at(conn)
+    }
+
+    fn read_reminder_table(&self, reminder_id: &str, conn: &SqliteConnection) -> ReminderResult<ReminderTable> {
+        self.reminder_sql
+            .read_reminder_table(reminder_id, conn)
+            .map_err(|e| match e.is_record_not_found() {
+                true => ReminderError::reminder_not_found(),
+                false => e,
+            })
+    }
+
+    // The reminders of the object are sent, and the next one is waited for
+    // again.
+    fn did_change(&self, object_id: &str, conn: &SqliteConnection) -> ReminderResult<()> {
+        self.wakeup.notify_one();
+        let items = self
+            .reminder_sql
+            .read_reminder_tables(Some(object_id), false, conn)?
+            .into_iter()
+            .map(Reminder::from)
+            .collect();
+        dart_notify(object_id, ReminderObservable::RemindersChanged)
+            .payload(RepeatedReminder { items })
+            .send();
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+pub(crate) mod reminder;
+
+pub(crate) use reminder::*;
@@ -0,0 +1,5 @@
+mod reminder_sql;
+mod reminder_table;
+
+pub(crate) use reminder_sql::*;
+pub(crate) use reminder_table::*;
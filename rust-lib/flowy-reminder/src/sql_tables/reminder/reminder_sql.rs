@@ -0,0 +1,110 @@
+use crate::{
+    errors::ReminderError,
+    sql_tables::reminder::{ReminderTable, SqlReminderStatus},
+};
+use flowy_database::{prelude::*, schema::reminder_table::dsl, SqliteConnection};
+
+pub struct ReminderTableSql {}
+
+impl ReminderTableSql {
+    pub(crate) fn create_reminder_table(
+        &self,
+        table: ReminderTable,
+        conn: &SqliteConnection,
+    ) -> Result<(), ReminderError> {
+        let _ = diesel::insert_into(dsl::reminder_table).values(table).execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_reminder_table(
+        &self,
+        reminder_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<ReminderTable, ReminderError> {
+        let table = dsl::reminder_table
+            .filter(dsl::id.eq(reminder_id))
+            .first::<ReminderTable>(conn)?;
+        Ok(table)
+    }
+
+    // The reminders of the object, or of all the objects if it's None, in the
+    // order of their time.
+    pub(crate) fn read_reminder_tables(
+        &self,
+        object_id: Option<&str>,
+        include_completed: bool,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<ReminderTable>, ReminderError> {
+        let mut query = dsl::reminder_table.into_boxed();
+        if let Some(object_id) = object_id {
+            query = query.filter(dsl::object_id.eq(object_id.to_owned()));
+        }
+        if !include_completed {
+            query = query.filter(dsl::status.ne(SqlReminderStatus::Completed));
+        }
+        let tables = query
+            .order((dsl::remind_at.asc(), dsl::create_time.asc()))
+            .load::<ReminderTable>(conn)?;
+        Ok(tables)
+    }
+
+    // The scheduled reminders whose time is before the deadline.
+    pub(crate) fn read_due_reminder_tables(
+        &self,
+        deadline: i64,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<ReminderTable>, ReminderError> {
+        let tables = dsl::reminder_table
+            .filter(dsl::status.eq(SqlReminderStatus::Scheduled))
+            .filter(dsl::remind_at.le(deadline))
+            .order((dsl::remind_at.asc(), dsl::create_time.asc()))
+            .load::<ReminderTable>(conn)?;
+        Ok(tables)
+    }
+
+    // The time of the next scheduled reminder, None if there's none.
+    pub(crate) fn read_next_remind_at(&self, conn: &SqliteConnection) -> Result<Option<i64>, ReminderError> {
+        let remind_at = dsl::reminder_table
+            .select(diesel::dsl::min(dsl::remind_at))
+            .filter(dsl::status.eq(SqlReminderStatus::Scheduled))
+            .first::<Option<i64>>(conn)?;
+        Ok(remind_at)
+    }
+
+    pub(crate) fn update_reminder_status(
+        &self,
+        reminder_id: &str,
+        status: SqlReminderStatus,
+        conn: &SqliteConnection,
+    ) -> Result<(), ReminderError> {
+        let _ = diesel::update(dsl::reminder_table.filter(dsl::id.eq(reminder_id)))
+            .set(dsl::status.eq(status))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    // Schedules the reminder again at the time.
+    pub(crate) fn reschedule_reminder(
+        &self,
+        reminder_id: &str,
+        remind_at: i64,
+        conn: &SqliteConnection,
+    ) -> Result<(), ReminderError> {
+        let _ = diesel::update(dsl::reminder_table.filter(dsl::id.eq(reminder_id)))
+            .set((
+                dsl::remind_at.eq(remind_at),
+                dsl::status.eq(SqlReminderStatus::Scheduled),
+            ))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn delete_reminder_table(
+        &self,
+        reminder_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<(), ReminderError> {
+        let _ = diesel::delete(dsl::reminder_table.filter(dsl::id.eq(reminder_id))).execute(conn)?;
+        Ok(())
+    }
+}
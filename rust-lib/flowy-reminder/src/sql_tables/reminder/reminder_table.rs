@@ -0,0 +1,139 @@
+use crate::entities::reminder::{CreateReminderRequest, Reminder, ReminderObjectType, ReminderStatus};
+use diesel::sql_types::Integer;
+use flowy_database::schema::reminder_table;
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable)]
+#[table_name = "reminder_table"]
+pub(crate) struct ReminderTable {
+    pub(crate) id: String,
+    pub(crate) object_type: SqlReminderObjectType,
+    pub(crate) object_id: String,
+    pub(crate) child_id: String,
+    pub(crate) title: String,
+    pub(crate) message: String,
+    pub(crate) remind_at: i64,
+    pub(crate) status: SqlReminderStatus,
+    pub(crate) create_time: i64,
+}
+
+impl ReminderTable {
+    pub(crate) fn new(request: CreateReminderRequest, create_time: i64) -> Self {
+        Self {
+            id: flowy_infra::uuid(),
+            object_type: request.object_type.into(),
+            object_id: request.object_id,
+            child_id: request.child_id,
+            title: request.title,
+            message: request.message,
+            remind_at: request.remind_at,
+            status: SqlReminderStatus::Scheduled,
+            create_time,
+        }
+    }
+}
+
+impl std::convert::From<ReminderTable> for Reminder {
+    fn from(table: ReminderTable) -> Self {
+        Reminder {
+            id: table.id,
+            object_type: table.object_type.into(),
+            object_id: table.object_id,
+            child_id: table.child_id,
+            title: table.title,
+            message: table.message,
+            remind_at: table.remind_at,
+            status: table.status.into(),
+            create_time: table.create_time,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, FromSqlRow, AsExpression)]
+#[repr(i32)]
+#[sql_type = "Integer"]
+pub(crate) enum SqlReminderObjectType {
+    Document = 0,
+    Block    = 1,
+    GridRow  = 2,
+}
+
+impl std::convert::From<i32> for SqlReminderObjectType {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => SqlReminderObjectType::Document,
+            1 => SqlReminderObjectType::Block,
+            2 => SqlReminderObjectType::GridRow,
+            o => {
+                log::error!("Unsupported reminder object type {}, fallback to Document", o);
+                SqlReminderObjectType::Document
+            },
+        }
+    }
+}
+
+impl_sql_integer_expression!(SqlReminderObjectType);
+
+impl std::convert::From<ReminderObjectType> for SqlReminderObjectType {
+    fn from(ty: ReminderObjectType) -> Self {
+        match ty {
+            ReminderObjectType::Document => SqlReminderObjectType::Document,
+            ReminderObjectType::Block => SqlReminderObjectType::Block,
+            ReminderObjectType::GridRow => SqlReminderObjectType::GridRow,
+        }
+    }
+}
+
+impl std::convert::From<SqlReminderObjectType> for ReminderObjectType {
+    fn from(ty: SqlReminderObjectType) -> Self {
+        match ty {
+            SqlReminderObjectType::Document => ReminderObjectType::Document,
+            SqlReminderObjectType::Block => ReminderObjectType::Block,
+            SqlReminderObjectType::GridRow => ReminderObjectType::GridRow,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, FromSqlRow, AsExpression)]
+#[repr(i32)]
+#[sql_type = "Integer"]
+pub(crate) enum SqlReminderStatus {
+    Scheduled = 0,
+    Fired     = 1,
+    Completed = 2,
+}
+
+impl std::convert::From<i32> for SqlReminderStatus {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => SqlReminderStatus::Scheduled,
+            1 => SqlReminderStatus::Fired,
+            2 => SqlReminderStatus::Completed,
+            o => {
+                log::error!("Unsupported reminder status {}, fallback to Completed", o);
+                SqlReminderStatus::Completed
+            },
+        }
+    }
+}
+
+impl_sql_integer_expression!(SqlReminderStatus);
+
+impl std::convert::From<ReminderStatus> for SqlReminderStatus {
+    fn from(status: ReminderStatus) -> Self {
+        match status {
+            ReminderStatus::Scheduled => SqlReminderStatus::Scheduled,
+            ReminderStatus::Fired => SqlReminderStatus::Fired,
+            ReminderStatus::Completed => SqlReminderStatus::Completed,
+        }
+    }
+}
+
+impl std::convert::From<SqlReminderStatus> for ReminderStatus {
+    fn from(status: SqlReminderStatus) -> Self {
+        match status {
+            SqlReminderStatus::Scheduled => ReminderStatus::Scheduled,
+            SqlReminderStatus::Fired => ReminderStatus::Fired,
+            SqlReminderStatus::Completed => ReminderStatus::Completed,
+        }
+    }
+}
@@ -0,0 +1 @@
+mod reminder_test;
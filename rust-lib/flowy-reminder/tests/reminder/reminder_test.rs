@@ -0,0 +1,208 @@
+use flowy_infra::{
+    clock::{Clock, ManualClock},
+    uuid,
+};
+use flowy_net::config::ServerConfig;
+use flowy_reminder::{
+    entities::reminder::*,
+    errors::{ErrorCode, ReminderError},
+    event::ReminderEvent::*,
+    module::ReminderNotifier,
+    protobuf::ReminderObservable,
+};
+use flowy_test::{
+    prelude::{root_dir, FlowySDKConfig},
+    EventTester,
+};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+#[derive(Default)]
+struct RecordNotifier {
+    reminders: Mutex<Vec<Reminder>>,
+}
+
+impl ReminderNotifier for RecordNotifier {
+    fn notify(&self, reminder: &Reminder) { self.reminders.lock().unwrap().push(reminder.clone()); }
+}
+
+struct ReminderTest {
+    tester: EventTester,
+    clock: Arc<ManualClock>,
+    notifier: Arc<RecordNotifier>,
+    user_id: String,
+}
+
+impl ReminderTest {
+    async fn new() -> Self {
+        let clock = Arc::new(ManualClock::now());
+        let notifier = Arc::new(RecordNotifier::default());
+        let config = FlowySDKConfig::new(&root_dir(), ServerConfig::default(), &uuid())
+            .in_memory_storage(true)
+            .clock(clock.clone())
+            .reminder_notifier(notifier.clone());
+        let tester = EventTester::with_config(config);
+        let user = tester.sign_up().await;
+        Self {
+            tester,
+            clock,
+            notifier,
+            user_id: user.id,
+        }
+    }
+
+    fn request(
+        &self,
+        object_type: ReminderObjectType,
+        object_id: &str,
+        child_id: &str,
+        seconds: i64,
+    ) -> CreateReminderRequest {
+        CreateReminderRequest {
+            object_type,
+            object_id: object_id.to_owned(),
+            child_id: child_id.to_owned(),
+            title: "Review".to_owned(),
+            message: "Review the draft".to_owned(),
+            remind_at: self.clock.timestamp() + seconds,
+        }
+    }
+
+    async fn create(&self, object_id: &str, seconds: i64) -> Reminder {
+        let request = self.request(ReminderObjectType::Document, object_id, "", seconds);
+        self.tester.send(CreateReminder, request).await.parse::<Reminder>()
+    }
+
+    async fn read(&self, object_id: &str, include_completed: bool) -> Vec<Reminder> {
+        let request = QueryRemindersRequest {
+            object_id: object_id.to_owned(),
+            include_completed,
+        };
+        self.tester
+            .send(ReadReminders, request)
+            .await
+            .parse::<RepeatedReminder>()
+            .items
+    }
+
+    // Waits for the notifier to be handed as many reminders, once the clock
+    // passed their time. Returns the last one.
+    async fn expect_fired(&self, count: usize) -> Reminder {
+        for _ in 0..100 {
+            if self.notifier.reminders.lock().unwrap().len() >= count {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        let reminders = self.notifier.reminders.lock().unwrap().clone();
+        assert_eq!(reminders.len(), count);
+        reminders.last().cloned().unwrap()
+    }
+}
+
+#[tokio::test]
+async fn reminder_create_and_read() {
+    let test = ReminderTest::new().await;
+    let later = test.create("doc", 120).await;
+    let sooner = test.create("doc", 60).await;
+    let _ = test.create("other doc", 30).await;
+    assert_eq!(later.status, ReminderStatus::Scheduled);
+
+    let reminders = test.read("doc", false).await;
+    let ids = reminders.iter().map(|reminder| reminder.id.clone()).collect::<Vec<_>>();
+    assert_eq!(ids, vec![sooner.id.clone(), later.id.clone()]);
+    assert_eq!(test.read("", false).await.len(), 3);
+
+    let row = test.request(ReminderObjectType::GridRow, "grid", "row", 60);
+    let row = test.tester.send(CreateReminder, row).await.parse::<Reminder>();
+    assert_eq!(row.child_id, "row");
+
+    let _ = test
+        .tester
+        .send(DeleteReminder, ReminderIdentifier { reminder_id: sooner.id })
+        .await;
+    assert_eq!(test.read("doc", false).await.len(), 1);
+}
+
+#[tokio::test]
+async fn reminder_create_invalid() {
+    let test = ReminderTest::new().await;
+    let block = test.request(ReminderObjectType::Block, "doc", "", 60);
+    let error = test.tester.send(CreateReminder, block).await.error::<ReminderError>();
+    assert_eq!(error.code, ErrorCode::ReminderObjectInvalid);
+
+    let mut request = test.request(ReminderObjectType::Document, "doc", "", 60);
+    request.remind_at = 0;
+    let error = test.tester.send(CreateReminder, request).await.error::<ReminderError>();
+    assert_eq!(error.code, ErrorCode::ReminderTimeInvalid);
+
+    let identifier = ReminderIdentifier { reminder_id: uuid() };
+    let error = test
+        .tester
+        .send(CompleteReminder, identifier)
+        .await
+        .error::<ReminderError>();
+    assert_eq!(error.code, ErrorCode::ReminderNotFound);
+}
+
+#[tokio::test]
+async fn reminder_fire_when_due() {
+    let test = ReminderTest::new().await;
+    let reminder = test.create("doc", 60).await;
+    let _ = test.create("doc", 60 * 60).await;
+
+    test.clock.advance(Duration::from_secs(60));
+    let fired = test.expect_fired(1).await;
+    assert_eq!(fired.id, reminder.id);
+    assert_eq!(fired.status, ReminderStatus::Fired);
+
+    let fired = test
+        .tester
+        .expect_notification_payload::<_, Reminder>(&test.user_id, ReminderObservable::ReminderFired as i32)
+        .await;
+    assert_eq!(fired.id, reminder.id);
+    let statuses = test
+        .read("doc", false)
+        .await
+        .into_iter()
+        .map(|reminder| reminder.status)
+        .collect::<Vec<_>>();
+    assert_eq!(statuses, vec![ReminderStatus::Fired, ReminderStatus::Scheduled]);
+}
+
+#[tokio::test]
+async fn reminder_snooze_and_complete() {
+    let test = ReminderTest::new().await;
+    let reminder = test.create("doc", 60).await;
+    test.clock.advance(Duration::from_secs(60));
+    let _ = test.expect_fired(1).await;
+
+    let request = SnoozeReminderRequest {
+        reminder_id: reminder.id.clone(),
+        seconds: 5 * 60,
+    };
+    let snoozed = test.tester.send(SnoozeReminder, request).await.parse::<Reminder>();
+    assert_eq!(snoozed.status, ReminderStatus::Scheduled);
+    assert_eq!(snoozed.remind_at, test.clock.timestamp() + 5 * 60);
+
+    test.clock.advance(Duration::from_secs(5 * 60));
+    let fired = test.expect_fired(2).await;
+    assert_eq!(fired.id, reminder.id);
+
+    let identifier = ReminderIdentifier {
+        reminder_id: reminder.id.clone(),
+    };
+    let completed = test.tester.send(CompleteReminder, identifier).await.parse::<Reminder>();
+    assert_eq!(completed.status, ReminderStatus::Completed);
+    assert!(test.read("doc", false).await.is_empty());
+    assert_eq!(test.read("doc", true).await.len(), 1);
+
+    let request = SnoozeReminderRequest {
+        reminder_id: reminder.id.clone(),
+        seconds: 60,
+    };
+    let error = test.tester.send(SnoozeReminder, request).await.error::<ReminderError>();
+    assert_eq!(error.code, ErrorCode::ReminderCompleted);
+}
@@ -0,0 +1,225 @@
+use crate::{entities::CrashBundle, schema::SCHEMA_VERSION, FlowySDKConfig};
+use flowy_dispatch::prelude::*;
+use flowy_log::{log_buffer, LogFilter};
+use parking_lot::RwLock;
+use std::{
+    fmt::Write as _,
+    io,
+    path::{Path, PathBuf},
+    sync::{Arc, Once, Weak},
+};
+
+// The log records at the end of a bundle.
+pub const CRASH_LOG_LINES: usize = 200;
+pub const MAX_CRASH_BUNDLES: usize = 10;
+
+const CRASH_BUNDLE_PREFIX: &str = "crash-";
+const CRASH_BUNDLE_EXTENSION: &str = ".txt";
+
+static CRASH_REPORTER: RwLock<Option<CrashReporter>> = parking_lot::const_rwlock(None);
+static INSTALL_PANIC_HOOK: Once = Once::new();
+
+struct CrashReporter {
+    directory: PathBuf,
+    config: Vec<(&'static str, String)>,
+    dispatch: Weak<EventDispatch>,
+}
+
+// The bundles are written to the crashes directory of the root when the process
+// panics, the previous panic hook runs after.
+pub(crate) fn install(config: &FlowySDKConfig, dispatch: &Arc<EventDispatch>) {
+    *CRASH_REPORTER.write() = Some(CrashReporter {
+        directory: crash_directory(&config.root),
+        config: anonymized_config(config),
+        dispatch: Arc::downgrade(dispatch),
+    });
+
+    INSTALL_PANIC_HOOK.call_once(|| {
+        let previous_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            match write_crash_bundle(&info.to_string()) {
+                Ok(path) => eprintln!("The crash bundle was written to {:?}", path),
+                Err(e) => eprintln!("Write the crash bundle failed: {:?}", e),
+            }
+            previous_hook(info);
+        }));
+    });
+}
+
+// Writes the bundle for a fatal error, the panics write theirs by themselves.
+// The levels and the targets of the recent log records, the state of the
+// dispatcher, the versions and the config without the paths and the server are
+// in it.
+pub fn write_crash_bundle(reason: &str) -> io::Result<PathBuf> {
+    // The panic could have happened while the reporter was being replaced.
+    let reporter = CRASH_REPORTER.try_read();
+    let reporter = match reporter.as_ref().and_then(|reporter| reporter.as_ref()) {
+        Some(reporter) => reporter,
+        None => {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                "The crash reporter isn't installed",
+            ))
+        },
+    };
+
+    let _ = std::fs::create_dir_all(&reporter.directory)?;
+    let name = format!(
+        "{}{}{}",
+        CRASH_BUNDLE_PREFIX,
+        chrono::Local::now().format("%Y-%m-%d-%H%M%S%3f"),
+        CRASH_BUNDLE_EXTENSION
+    );
+    let path = reporter.directory.join(name);
+    let _ = std::fs::write(&path, reporter.bundle(reason))?;
+
+    // The names sort by the time they were written.
+    let bundles = bundle_paths(&reporter.directory);
+    let expired = bundles.len().saturating_sub(MAX_CRASH_BUNDLES);
+    for path in bundles.into_iter().take(expired) {
+        let _ = std::fs::remove_file(path);
+    }
+    Ok(path)
+}
+
+impl CrashReporter {
+    fn bundle(&self, reason: &str) -> String {
+        let mut bundle = String::new();
+        let _ = writeln!(bundle, "reason: {}", reason);
+        let _ = writeln!(bundle, "time: {}", chrono::Local::now().to_rfc3339());
+        let _ = writeln!(bundle, "sdk_version: {}", env!("CARGO_PKG_VERSION"));
+        let _ = writeln!(bundle, "schema_version: {}", SCHEMA_VERSION);
+        let _ = writeln!(bundle, "os: {} {}", std::env::consts::OS, std::env::consts::ARCH);
+
+        let _ = writeln!(bundle, "\n[config]");
+        for (key, value) in &self.config {
+            let _ = writeln!(bundle, "{}: {}", key, value);
+        }
+
+        let _ = writeln!(bundle, "\n[dispatch]");
+        match self.dispatch.upgrade() {
+            Some(dispatch) => {
+                let _ = writeln!(bundle, "events: {}", dispatch.event_count());
+                let _ = writeln!(bundle, "in_flight: {}", dispatch.in_flight());
+                if let Some(idle_since) = dispatch.idle_since() {
+                    let _ = writeln!(bundle, "idle_ms: {}", idle_since.elapsed().as_millis());
+                }
+            },
+            None => {
+                let _ = writeln!(bundle, "dropped");
+            },
+        }
+        // The panicking thread could hold the locks of the profiler, the metrics
+        // or the log buffer, the sections are skipped instead of waiting for them.
+        for profile in profiler().try_report(10).unwrap_or_default() {
+            let _ = writeln!(
+                bundle,
+                "profile: {} count={} wall_ms={}",
+                profile.event,
+                profile.count,
+                profile.wall_time.as_millis()
+            );
+        }
+
+        let _ = writeln!(bundle, "\n[metrics]");
+        match flowy_metrics::registry().try_snapshot() {
+            Some(snapshot) => bundle.push_str(&snapshot.to_prometheus_text()),
+            None => {
+                let _ = writeln!(bundle, "locked");
+            },
+        }
+
+        // The messages could contain the content of the documents, only the
+        // time, the level and the target of the records are kept.
+        let _ = writeln!(bundle, "\n[log]");
+        let filter = LogFilter {
+            level: tracing::Level::TRACE,
+            targets: vec![],
+        };
+        match log_buffer().try_records(&filter) {
+            Some(records) => {
+                for record in records.iter().skip(records.len().saturating_sub(CRASH_LOG_LINES)) {
+                    let _ = writeln!(bundle, "{} {} {}", record.time, record.level, record.target);
+                }
+            },
+            None => {
+                let _ = writeln!(bundle, "locked");
+            },
+        }
+        bundle
+    }
+}
+
+// The newest first.
+pub(crate) fn list_crash_bundles() -> Vec<CrashBundle> {
+    let directory = match &*CRASH_REPORTER.read() {
+        Some(reporter) => reporter.directory.clone(),
+        None => return vec![],
+    };
+    bundle_paths(&directory)
+        .into_iter()
+        .rev()
+        .map(|path| {
+            let metadata = std::fs::metadata(&path).ok();
+            CrashBundle {
+                name: file_name(&path),
+                size: metadata.as_ref().map(|metadata| metadata.len() as i64).unwrap_or(0),
+                create_time: metadata
+                    .and_then(|metadata| metadata.modified().ok())
+                    .map(|modified| chrono::DateTime::<chrono::Local>::from(modified).timestamp())
+                    .unwrap_or(0),
+                path: path.to_string_lossy().into_owned(),
+            }
+        })
+        .collect()
+}
+
+// Only the bundles of the crashes directory can be read.
+pub(crate) fn read_crash_bundle(name: &str) -> Result<String, String> {
+    let path = list_crash_bundles()
+        .into_iter()
+        .find(|bundle| bundle.name == name)
+        .map(|bundle| bundle.path)
+        .ok_or(format!("The crash bundle {} doesn't exist", name))?;
+    std::fs::read_to_string(path).map_err(|e| format!("Read the crash bundle {} failed: {:?}", name, e))
+}
+
+// The paths, the server and the secrets are left out.
+fn anonymized_config(config: &FlowySDKConfig) -> Vec<(&'static str, String)> {
+    vec![
+        ("name", config.name.clone()),
+        ("log_filter", config.log_filter.clone()),
+        ("log_spans", config.log_spans.to_string()),
+        ("event_profiling", config.event_profiling.to_string()),
+        ("slow_event_threshold", format!("{:?}", config.slow_event_threshold)),
+        ("schema_version", format!("{:?}", config.schema_version)),
+        ("oauth_providers", config.oauth_configs.len().to_string()),
+        ("secret_store", format!("{:?}", config.secret_store)),
+        ("encrypt_database", config.encrypt_database.to_string()),
+        ("in_memory_storage", config.in_memory_storage.to_string()),
+    ]
+}
+
+fn crash_directory(root: &str) -> PathBuf { PathBuf::from(root).join("crashes") }
+
+fn bundle_paths(directory: &Path) -> Vec<PathBuf> {
+    let mut paths = match std::fs::read_dir(directory) {
+        Ok(entries) => entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                let name = file_name(path);
+                name.starts_with(CRASH_BUNDLE_PREFIX) && name.ends_with(CRASH_BUNDLE_EXTENSION)
+            })
+            .collect::<Vec<_>>(),
+        Err(_) => vec![],
+    };
+    paths.sort();
+    paths
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
@@ -66,6 +66,8 @@ impl DocumentUser for DocumentUserImpl {
     fn token(&self) -> Result<String, DocError> { self.user.token().map_err(map_user_error) }
 
     fn db_pool(&self) -> Result<Arc<ConnectionPool>, DocError> { self.user.db_pool().map_err(map_user_error) }
+
+    fn document_secret(&self) -> Result<String, DocError> { self.user.document_secret().map_err(map_user_error) }
 }
 
 struct WsSenderImpl {
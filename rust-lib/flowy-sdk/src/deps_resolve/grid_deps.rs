@@ -0,0 +1,79 @@
+use flowy_database::ConnectionPool;
+use flowy_grid::{
+    errors::GridError,
+    module::GridUser,
+    services::grid::{GridController, ImportGridParams},
+};
+use flowy_user::{
+    errors::{ErrorCode, UserError},
+    services::user::UserSession,
+};
+use flowy_workspace::{errors::WorkspaceError, module::WorkspaceGrid};
+use std::sync::Arc;
+
+pub struct GridDepsResolver {
+    user_session: Arc<UserSession>,
+}
+
+impl GridDepsResolver {
+    pub fn new(user_session: Arc<UserSession>) -> Self { Self { user_session } }
+
+    pub fn split_into(self) -> Arc<dyn GridUser> {
+        Arc::new(GridUserImpl {
+            user: self.user_session,
+        })
+    }
+}
+
+struct GridUserImpl {
+    user: Arc<UserSession>,
+}
+
+fn map_user_error(error: UserError) -> GridError {
+    match ErrorCode::from_i32(error.code) {
+        ErrorCode::InternalError => GridError::internal().context(error.msg),
+        _ => GridError::internal().context(error),
+    }
+}
+
+impl GridUser for GridUserImpl {
+    fn user_id(&self) -> Result<String, GridError> { self.user.user_id().map_err(map_user_error) }
+
+    fn db_pool(&self) -> Result<Arc<ConnectionPool>, GridError> { self.user.db_pool().map_err(map_user_error) }
+}
+
+// The grids of the grid views of the workspace.
+pub struct WorkspaceGridResolver {
+    grid_controller: Arc<GridController>,
+}
+
+impl WorkspaceGridResolver {
+    pub fn new(grid_controller: Arc<GridController>) -> Self { Self { grid_controller } }
+}
+
+impl WorkspaceGrid for WorkspaceGridResolver {
+    fn import_grid(
+        &self,
+        grid_id: &str,
+        name: &str,
+        columns: Vec<String>,
+        rows: Vec<Vec<String>>,
+    ) -> Result<Vec<String>, WorkspaceError> {
+        let params = ImportGridParams {
+            grid_id: grid_id.to_owned(),
+            name: name.to_owned(),
+            columns,
+            rows,
+        };
+        let result = self
+            .grid_controller
+            .import_grid(params)
+            .map_err(|e| WorkspaceError::internal().context(e.msg))?;
+        let invalid_cells = result
+            .invalid_cells
+            .into_iter()
+            .map(|cell| format!("Row {}, {}: {}", cell.row + 1, cell.column, cell.data))
+            .collect();
+        Ok(invalid_cells)
+    }
+}
@@ -0,0 +1,17 @@
+use flowy_database::{ConnectionPool, KVDatabase};
+use flowy_user::services::user::UserSession;
+use std::sync::Arc;
+
+pub struct KVDepsResolver {
+    user_session: Arc<UserSession>,
+}
+
+impl KVDepsResolver {
+    pub fn new(user_session: Arc<UserSession>) -> Self { Self { user_session } }
+}
+
+impl KVDatabase for KVDepsResolver {
+    fn db_pool(&self) -> Result<Arc<ConnectionPool>, String> {
+        self.user_session.db_pool().map_err(|e| format!("{:?}", e))
+    }
+}
@@ -1,5 +1,13 @@
 mod document_deps;
+mod grid_deps;
+mod kv_deps;
+mod reminder_deps;
+mod telemetry_deps;
 mod workspace_deps;
 
 pub use document_deps::*;
+pub use grid_deps::*;
+pub use kv_deps::*;
+pub use reminder_deps::*;
+pub use telemetry_deps::*;
 pub use workspace_deps::*;
@@ -0,0 +1,38 @@
+use flowy_database::ConnectionPool;
+use flowy_reminder::{errors::ReminderError, module::ReminderUser};
+use flowy_user::{
+    errors::{ErrorCode, UserError},
+    services::user::UserSession,
+};
+use std::sync::Arc;
+
+pub struct ReminderDepsResolver {
+    user_session: Arc<UserSession>,
+}
+
+impl ReminderDepsResolver {
+    pub fn new(user_session: Arc<UserSession>) -> Self { Self { user_session } }
+
+    pub fn split_into(self) -> Arc<dyn ReminderUser> {
+        Arc::new(ReminderUserImpl {
+            user: self.user_session,
+        })
+    }
+}
+
+struct ReminderUserImpl {
+    user: Arc<UserSession>,
+}
+
+fn map_user_error(error: UserError) -> ReminderError {
+    match ErrorCode::from_i32(error.code) {
+        ErrorCode::InternalError => ReminderError::internal().context(error.msg),
+        _ => ReminderError::internal().context(error),
+    }
+}
+
+impl ReminderUser for ReminderUserImpl {
+    fn user_id(&self) -> Result<String, ReminderError> { self.user.user_id().map_err(map_user_error) }
+
+    fn db_pool(&self) -> Result<Arc<ConnectionPool>, ReminderError> { self.user.db_pool().map_err(map_user_error) }
+}
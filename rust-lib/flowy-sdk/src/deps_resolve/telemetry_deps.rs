@@ -0,0 +1,36 @@
+use flowy_database::ConnectionPool;
+use flowy_telemetry::{errors::TelemetryError, module::TelemetryUser};
+use flowy_user::{
+    errors::{ErrorCode, UserError},
+    services::user::UserSession,
+};
+use std::sync::Arc;
+
+pub struct TelemetryDepsResolver {
+    user_session: Arc<UserSession>,
+}
+
+impl TelemetryDepsResolver {
+    pub fn new(user_session: Arc<UserSession>) -> Self { Self { user_session } }
+
+    pub fn split_into(self) -> Arc<dyn TelemetryUser> {
+        Arc::new(TelemetryUserImpl {
+            user: self.user_session,
+        })
+    }
+}
+
+struct TelemetryUserImpl {
+    user: Arc<UserSession>,
+}
+
+fn map_user_error(error: UserError) -> TelemetryError {
+    match ErrorCode::from_i32(error.code) {
+        ErrorCode::InternalError => TelemetryError::internal().context(error.msg),
+        _ => TelemetryError::internal().context(error),
+    }
+}
+
+impl TelemetryUser for TelemetryUserImpl {
+    fn db_pool(&self) -> Result<Arc<ConnectionPool>, TelemetryError> { self.user.db_pool().map_err(map_user_error) }
+}
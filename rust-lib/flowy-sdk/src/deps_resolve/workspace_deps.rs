@@ -1,10 +1,14 @@
+use bytes::Bytes;
 use flowy_database::ConnectionPool;
 use flowy_user::services::user::UserSession;
 use flowy_workspace::{
+    entities::sync::ServerPush,
     errors::WorkspaceError,
     module::{WorkspaceDatabase, WorkspaceUser},
+    prelude::WorkspaceController,
 };
-use std::sync::Arc;
+use flowy_ws::{WsMessage, WsMessageHandler, WsModule};
+use std::{convert::TryFrom, sync::Arc};
 
 pub struct WorkspaceDepsResolver {
     inner: Arc<Resolver>,
@@ -48,4 +52,50 @@ impl WorkspaceUser for Resolver {
             .token()
             .map_err(|e| WorkspaceError::internal().context(e))
     }
+
+    fn is_online(&self) -> bool { self.user_session.connectivity.is_online() }
+
+    fn is_metered(&self) -> bool { self.user_session.connectivity.is_metered() }
+
+    fn workspace_key(&self, workspace_id: &str) -> Result<Option<String>, WorkspaceError> {
+        self.user_session
+            .workspace_key(workspace_id)
+            .map_err(|e| WorkspaceError::internal().context(e))
+    }
+
+    fn set_workspace_key(&self, workspace_id: &str, key: &str) -> Result<(), WorkspaceError> {
+        self.user_session
+            .set_workspace_key(workspace_id, key)
+            .map_err(|e| WorkspaceError::internal().context(e))
+    }
+}
+
+// Routes what the server pushes to the workspace, the changes of the others are
+// applied and notified as soon as they're received.
+pub struct WsWorkspaceReceiver {
+    workspace: Arc<WorkspaceController>,
+}
+
+impl WsWorkspaceReceiver {
+    pub fn new(workspace: Arc<WorkspaceController>) -> Self { Self { workspace } }
+}
+
+impl WsMessageHandler for WsWorkspaceReceiver {
+    fn source(&self) -> WsModule { WsModule::Workspace }
+
+    fn receive_message(&self, msg: WsMessage) {
+        let push = match ServerPush::try_from(Bytes::from(msg.data)) {
+            Ok(push) => push,
+            Err(e) => {
+                log::error!("Deserialize the server push failed: {:?}", e);
+                return;
+            },
+        };
+        let workspace = self.workspace.clone();
+        tokio::spawn(async move {
+            if let Err(e) = workspace.receive_server_push(push).await {
+                log::error!("Receive the server push failed: {:?}", e);
+            }
+        });
+    }
 }
@@ -0,0 +1,40 @@
+use flowy_derive::ProtoBuf;
+
+// A crash bundle written to the crashes directory, the size is in bytes and the
+// time in seconds.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct CrashBundle {
+    #[pb(index = 1)]
+    pub name: String,
+
+    #[pb(index = 2)]
+    pub path: String,
+
+    #[pb(index = 3)]
+    pub size: i64,
+
+    #[pb(index = 4)]
+    pub create_time: i64,
+}
+
+// The newest first.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RepeatedCrashBundle {
+    #[pb(index = 1)]
+    pub items: Vec<CrashBundle>,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct CrashBundleIdentifier {
+    #[pb(index = 1)]
+    pub name: String,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct CrashBundleContent {
+    #[pb(index = 1)]
+    pub name: String,
+
+    #[pb(index = 2)]
+    pub content: String,
+}
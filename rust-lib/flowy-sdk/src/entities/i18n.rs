@@ -0,0 +1,44 @@
+use flowy_derive::ProtoBuf;
+
+// The current locale if the locale is empty.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct TranslationsRequest {
+    #[pb(index = 1)]
+    pub locale: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, ProtoBuf)]
+pub struct Translation {
+    #[pb(index = 1)]
+    pub key: String,
+
+    #[pb(index = 2)]
+    pub value: String,
+}
+
+// The locale is the one the translations are for, e.g. fr for fr-CA if there is
+// no catalog for fr-CA. The keys it doesn't have are the ones of en.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct Translations {
+    #[pb(index = 1)]
+    pub locale: String,
+
+    #[pb(index = 2)]
+    pub items: Vec<Translation>,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct SetLocaleRequest {
+    #[pb(index = 1)]
+    pub locale: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, ProtoBuf)]
+pub struct LocaleSetting {
+    #[pb(index = 1)]
+    pub locale: String,
+
+    // The locales that have a catalog.
+    #[pb(index = 2)]
+    pub locales: Vec<String>,
+}
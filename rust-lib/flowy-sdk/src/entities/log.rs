@@ -0,0 +1,66 @@
+use flowy_derive::ProtoBuf;
+
+// The level is one of error, warn, info, debug or trace, the records at the
+// level or more severe are sent. It's info if it's empty. The targets are the
+// prefixes of the modules, e.g. flowy_workspace, every module if it's empty.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct SubscribeLogRequest {
+    #[pb(index = 1)]
+    pub level: String,
+
+    #[pb(index = 2)]
+    pub targets: Vec<String>,
+}
+
+// The time is in milliseconds.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct LogRecord {
+    #[pb(index = 1)]
+    pub level: String,
+
+    #[pb(index = 2)]
+    pub target: String,
+
+    #[pb(index = 3)]
+    pub message: String,
+
+    #[pb(index = 4)]
+    pub time: i64,
+}
+
+impl std::convert::From<flowy_log::LogRecord> for LogRecord {
+    fn from(record: flowy_log::LogRecord) -> Self {
+        LogRecord {
+            level: record.level.to_string(),
+            target: record.target,
+            message: record.message,
+            time: record.time,
+        }
+    }
+}
+
+// The oldest first.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RepeatedLogRecord {
+    #[pb(index = 1)]
+    pub items: Vec<LogRecord>,
+}
+
+// The filter is in the syntax of RUST_LOG, e.g. "info,flowy_document=debug" for
+// the debug records of the document and the info ones of the others. The filter
+// the sdk was configured with is restored if it's empty.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct SetLogFilterRequest {
+    #[pb(index = 1)]
+    pub filter: String,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct LogFilterSetting {
+    #[pb(index = 1)]
+    pub filter: String,
+
+    // Whether it's the filter the sdk was configured with.
+    #[pb(index = 2)]
+    pub is_default: bool,
+}
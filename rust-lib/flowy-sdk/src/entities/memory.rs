@@ -0,0 +1,41 @@
+use flowy_derive::ProtoBuf;
+
+// What a part of a subsystem keeps, e.g. the open documents of the document
+// subsystem. The bytes are an estimate of the size of the data, 0 if it isn't
+// known.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct SubsystemMemory {
+    #[pb(index = 1)]
+    pub subsystem: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub count: i64,
+
+    #[pb(index = 4)]
+    pub bytes: i64,
+}
+
+impl SubsystemMemory {
+    pub fn new(subsystem: &str, name: &str, count: usize, bytes: u64) -> Self {
+        Self {
+            subsystem: subsystem.to_owned(),
+            name: name.to_owned(),
+            count: count as i64,
+            bytes: bytes as i64,
+        }
+    }
+}
+
+// The rss is the resident memory of the process in bytes, it's only set where
+// it can be read.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct MemoryUsage {
+    #[pb(index = 1, one_of)]
+    pub rss: Option<i64>,
+
+    #[pb(index = 2)]
+    pub items: Vec<SubsystemMemory>,
+}
@@ -0,0 +1,110 @@
+use flowy_derive::ProtoBuf;
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct MetricLabel {
+    #[pb(index = 1)]
+    pub key: String,
+
+    #[pb(index = 2)]
+    pub value: String,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct CounterMetric {
+    #[pb(index = 1)]
+    pub name: String,
+
+    #[pb(index = 2)]
+    pub labels: Vec<MetricLabel>,
+
+    #[pb(index = 3)]
+    pub value: i64,
+}
+
+// The count of the values up to the upper bound.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct MetricBucket {
+    #[pb(index = 1)]
+    pub upper_bound: f64,
+
+    #[pb(index = 2)]
+    pub count: i64,
+}
+
+// The durations are in milliseconds.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct HistogramMetric {
+    #[pb(index = 1)]
+    pub name: String,
+
+    #[pb(index = 2)]
+    pub labels: Vec<MetricLabel>,
+
+    #[pb(index = 3)]
+    pub count: i64,
+
+    #[pb(index = 4)]
+    pub sum: f64,
+
+    #[pb(index = 5)]
+    pub buckets: Vec<MetricBucket>,
+}
+
+// The metrics since the app started, the text is the same snapshot in the text
+// format of Prometheus.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct MetricsSnapshot {
+    #[pb(index = 1)]
+    pub counters: Vec<CounterMetric>,
+
+    #[pb(index = 2)]
+    pub histograms: Vec<HistogramMetric>,
+
+    #[pb(index = 3)]
+    pub text: String,
+}
+
+fn labels(labels: Vec<(String, String)>) -> Vec<MetricLabel> {
+    labels
+        .into_iter()
+        .map(|(key, value)| MetricLabel { key, value })
+        .collect()
+}
+
+impl std::convert::From<flowy_metrics::MetricsSnapshot> for MetricsSnapshot {
+    fn from(snapshot: flowy_metrics::MetricsSnapshot) -> Self {
+        let text = snapshot.to_prometheus_text();
+        let counters = snapshot
+            .counters
+            .into_iter()
+            .map(|counter| CounterMetric {
+                name: counter.name,
+                labels: labels(counter.labels),
+                value: counter.value as i64,
+            })
+            .collect();
+        let histograms = snapshot
+            .histograms
+            .into_iter()
+            .map(|histogram| HistogramMetric {
+                name: histogram.name,
+                labels: labels(histogram.labels),
+                count: histogram.count as i64,
+                sum: histogram.sum,
+                buckets: histogram
+                    .buckets
+                    .into_iter()
+                    .map(|(upper_bound, count)| MetricBucket {
+                        upper_bound,
+                        count: count as i64,
+                    })
+                    .collect(),
+            })
+            .collect();
+        MetricsSnapshot {
+            counters,
+            histograms,
+            text,
+        }
+    }
+}
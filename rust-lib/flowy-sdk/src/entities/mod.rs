@@ -0,0 +1,17 @@
+mod crash;
+mod i18n;
+mod log;
+mod memory;
+mod metrics;
+mod profile;
+mod schema;
+mod trace;
+
+pub use crash::*;
+pub use i18n::*;
+pub use log::*;
+pub use memory::*;
+pub use metrics::*;
+pub use profile::*;
+pub use schema::*;
+pub use trace::*;
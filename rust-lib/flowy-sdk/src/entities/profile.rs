@@ -0,0 +1,73 @@
+use flowy_derive::ProtoBuf;
+use flowy_dispatch::prelude::EventProfile;
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct SetProfilingRequest {
+    #[pb(index = 1)]
+    pub enabled: bool,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct ProfileReportRequest {
+    // How many of the most expensive events are in the report.
+    #[pb(index = 1)]
+    pub top: i64,
+}
+
+// The invocations of an event, the durations are their sums in milliseconds.
+// The busy time is the time spent in the handler, without its waits.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct ProfiledEvent {
+    #[pb(index = 1)]
+    pub event: String,
+
+    #[pb(index = 2)]
+    pub count: i64,
+
+    #[pb(index = 3)]
+    pub wall_time: f64,
+
+    #[pb(index = 4)]
+    pub max_wall_time: f64,
+
+    #[pb(index = 5)]
+    pub busy_time: f64,
+
+    #[pb(index = 6)]
+    pub polls: i64,
+
+    #[pb(index = 7)]
+    pub allocations: i64,
+
+    #[pb(index = 8)]
+    pub allocated_bytes: i64,
+}
+
+impl std::convert::From<EventProfile> for ProfiledEvent {
+    fn from(profile: EventProfile) -> Self {
+        ProfiledEvent {
+            event: profile.event,
+            count: profile.count as i64,
+            wall_time: profile.wall_time.as_secs_f64() * 1000.0,
+            max_wall_time: profile.max_wall_time.as_secs_f64() * 1000.0,
+            busy_time: profile.busy_time.as_secs_f64() * 1000.0,
+            polls: profile.polls as i64,
+            allocations: profile.allocations as i64,
+            allocated_bytes: profile.allocated_bytes as i64,
+        }
+    }
+}
+
+// The most expensive events first, by their wall time. The allocations are 0
+// unless the app installed flowy_dispatch's CountingAllocator.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct ProfileReport {
+    #[pb(index = 1)]
+    pub enabled: bool,
+
+    #[pb(index = 2)]
+    pub counting_allocations: bool,
+
+    #[pb(index = 3)]
+    pub items: Vec<ProfiledEvent>,
+}
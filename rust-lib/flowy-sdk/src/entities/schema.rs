@@ -0,0 +1,53 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+#[derive(Debug, Clone, ProtoBuf_Enum, PartialEq, Eq)]
+pub enum SchemaErrorCode {
+    // The data on disk was written by a newer version of the app
+    PersistedSchemaTooNew = 0,
+
+    // The frontend was built against a different payload schema
+    FrontendSchemaMismatch = 1,
+
+    // The compiled descriptors changed but the SCHEMA_VERSION wasn't bumped
+    SchemaChangedWithoutVersion = 2,
+}
+
+impl std::default::Default for SchemaErrorCode {
+    fn default() -> Self { SchemaErrorCode::FrontendSchemaMismatch }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct SchemaError {
+    #[pb(index = 1)]
+    pub code: SchemaErrorCode,
+
+    #[pb(index = 2)]
+    pub msg: String,
+
+    #[pb(index = 3)]
+    pub compiled_version: i64,
+
+    #[pb(index = 4)]
+    pub compiled_hash: String,
+
+    #[pb(index = 5)]
+    pub found_version: i64,
+}
+
+// The result of the schema verification when the sdk was initialized, the
+// frontend reads it once it's listening to the notifications.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct SchemaState {
+    #[pb(index = 1)]
+    pub compiled_version: i64,
+
+    #[pb(index = 2)]
+    pub compiled_hash: String,
+
+    #[pb(index = 3, one_of)]
+    pub error: Option<SchemaError>,
+}
+
+impl std::fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { write!(f, "{:?}: {}", &self.code, &self.msg) }
+}
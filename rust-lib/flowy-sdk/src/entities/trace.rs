@@ -0,0 +1,47 @@
+use flowy_derive::ProtoBuf;
+use flowy_dispatch::prelude::EventTrace;
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct SetTraceModeRequest {
+    #[pb(index = 1)]
+    pub enabled: bool,
+}
+
+// The trace of an event sent through the ffi, the durations are in
+// microseconds. The serialization is the time spent decoding the request and
+// encoding the response.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct TraceRecord {
+    #[pb(index = 1)]
+    pub event: String,
+
+    #[pb(index = 2)]
+    pub serialization_us: i64,
+
+    #[pb(index = 3)]
+    pub queue_wait_us: i64,
+
+    #[pb(index = 4)]
+    pub handler_us: i64,
+
+    #[pb(index = 5)]
+    pub response_size: i64,
+}
+
+impl std::convert::From<EventTrace> for TraceRecord {
+    fn from(trace: EventTrace) -> Self {
+        TraceRecord {
+            event: trace.event,
+            serialization_us: trace.serialization.as_micros() as i64,
+            queue_wait_us: trace.queue_wait.as_micros() as i64,
+            handler_us: trace.handler.as_micros() as i64,
+            response_size: trace.response_size as i64,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RepeatedTraceRecord {
+    #[pb(index = 1)]
+    pub items: Vec<TraceRecord>,
+}
@@ -1,29 +1,120 @@
+mod crash;
 mod deps_resolve;
 // mod flowy_server;
+pub mod entities;
 pub mod module;
+mod notify;
+pub mod plugin;
+pub mod protobuf;
+pub mod schema;
+pub mod system;
 
-use crate::deps_resolve::WorkspaceDepsResolver;
+use crate::deps_resolve::{KVDepsResolver, WorkspaceDepsResolver, WorkspaceGridResolver, WsWorkspaceReceiver};
+use flowy_database::{KVStore, PoolConfig, RustMigration};
 use flowy_dispatch::prelude::*;
-use flowy_document::module::FlowyDocument;
+use flowy_document::{
+    module::FlowyDocument,
+    services::{check::TextChecker, doc::AutosaveConfig},
+};
+use flowy_grid::services::grid::GridController;
+use flowy_infra::{
+    clock::{system_clock, Clock},
+    memory::{MemoryBudget, DEFAULT_MEMORY_BUDGET},
+    secret::SecretStoreKind,
+};
+use flowy_log::LogRotation;
 use flowy_net::config::ServerConfig;
-use flowy_user::services::user::{UserSession, UserSessionBuilder, UserStatus};
-use flowy_workspace::{errors::WorkspaceError, prelude::WorkspaceController};
+use flowy_reminder::{event::ReminderEvent, module::ReminderNotifier, services::reminder::ReminderController};
+use flowy_telemetry::{
+    event::TelemetryEvent,
+    services::{server::TelemetryServerAPI, telemetry::TelemetryController},
+};
+use flowy_user::{
+    entities::{OAuthProviderType, SettingChange, SettingSection},
+    event::UserEvent,
+    services::user::{
+        mk_oauth_provider,
+        AuditLog,
+        OAuthConfig,
+        PasswordPolicy,
+        SessionGuard,
+        TokenRefresher,
+        UserSession,
+        UserSessionBuilder,
+        UserStatus,
+        VerificationGuard,
+        DEFAULT_BACKUP_INTERVAL,
+        DEFAULT_COMPACTION_IDLE_PERIOD,
+    },
+};
+use flowy_workspace::{
+    errors::WorkspaceError,
+    event::WorkspaceEvent,
+    module::WorkspaceConfig,
+    prelude::WorkspaceController,
+};
+use flowy_ws::{Connectivity, ConnectivityConfig, NetworkState, NetworkStateType, WsConfig, WsState};
 use module::mk_modules;
+pub use crash::{write_crash_bundle, CRASH_LOG_LINES, MAX_CRASH_BUNDLES};
 pub use module::*;
-use std::sync::{
-    atomic::{AtomicBool, Ordering},
-    Arc,
+use notify::{dart_notify, SDKNotification};
+use plugin::{mk_plugin_modules, FlowyPlugin, PluginContext};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
 };
 use tokio::sync::broadcast;
 
 static INIT_LOG: AtomicBool = AtomicBool::new(false);
 
+// How long the due reminders wait to be fired again when the event failed.
+const REMINDER_RETRY_DELAY: Duration = Duration::from_secs(60);
+
 #[derive(Debug, Clone)]
 pub struct FlowySDKConfig {
     name: String,
     root: String,
     log_filter: String,
+    log_spans: bool,
+    log_rotation: Option<LogRotation>,
+    event_profiling: bool,
+    slow_event_threshold: Option<Duration>,
     server_config: ServerConfig,
+    schema_version: Option<i64>,
+    session_expiration: Option<i64>,
+    password_policy: Option<PasswordPolicy>,
+    oauth_configs: Vec<(OAuthProviderType, OAuthConfig)>,
+    secret_store: Option<SecretStoreKind>,
+    undo_capacity: Option<usize>,
+    autosave: Option<AutosaveConfig>,
+    snapshot_interval: Option<i64>,
+    text_checker: Option<Arc<dyn TextChecker>>,
+    memory_budget: Arc<MemoryBudget>,
+    attachment_quota: Option<i64>,
+    plugins: Vec<Arc<dyn FlowyPlugin>>,
+    #[cfg(feature = "extensions")]
+    extensions: Option<(String, Arc<dyn plugin::extension::ExtensionPrompt>)>,
+    trash_retention: Option<i64>,
+    trash_purge_interval: Option<Duration>,
+    garbage_collection_interval: Option<Duration>,
+    backup_interval: Option<Duration>,
+    max_backups: Option<usize>,
+    compaction_idle_period: Option<Duration>,
+    db_pool: Option<PoolConfig>,
+    migrations: Vec<RustMigration>,
+    encrypt_database: bool,
+    in_memory_storage: bool,
+    ws_config: Option<WsConfig>,
+    connectivity_config: Option<ConnectivityConfig>,
+    clock: Arc<dyn Clock>,
+    reminder_notifier: Option<Arc<dyn ReminderNotifier>>,
+    telemetry_server: Option<Arc<dyn TelemetryServerAPI>>,
+    translations_dir: Option<String>,
+    #[cfg(any(debug_assertions, feature = "chaos"))]
+    fault_injector: Option<Arc<flowy_dispatch::chaos::FaultInjector>>,
 }
 
 impl FlowySDKConfig {
@@ -32,14 +123,323 @@ impl FlowySDKConfig {
             name: name.to_owned(),
             root: root.to_owned(),
             log_filter: crate_log_filter(None),
+            log_spans: true,
+            log_rotation: None,
+            event_profiling: false,
+            slow_event_threshold: Some(DEFAULT_SLOW_EVENT_THRESHOLD),
             server_config,
+            schema_version: None,
+            session_expiration: None,
+            password_policy: None,
+            oauth_configs: vec![],
+            secret_store: None,
+            undo_capacity: None,
+            autosave: None,
+            snapshot_interval: None,
+            text_checker: None,
+            memory_budget: MemoryBudget::new(DEFAULT_MEMORY_BUDGET),
+            attachment_quota: None,
+            plugins: vec![],
+            #[cfg(feature = "extensions")]
+            extensions: None,
+            trash_retention: None,
+            trash_purge_interval: None,
+            garbage_collection_interval: None,
+            backup_interval: None,
+            max_backups: None,
+            compaction_idle_period: None,
+            db_pool: None,
+            migrations: vec![],
+            encrypt_database: false,
+            in_memory_storage: false,
+            ws_config: None,
+            connectivity_config: None,
+            clock: system_clock(),
+            reminder_notifier: None,
+            telemetry_server: None,
+            translations_dir: None,
+            #[cfg(any(debug_assertions, feature = "chaos"))]
+            fault_injector: None,
         }
     }
 
+    // The schema version the frontend was generated with. It will be verified
+    // against the sdk's schema::SCHEMA_VERSION when initializing.
+    pub fn schema_version(mut self, version: i64) -> Self {
+        self.schema_version = Some(version);
+        self
+    }
+
+    // How long, in seconds, a signed in session stays valid. Defaults to
+    // flowy_user's DEFAULT_SESSION_EXPIRATION.
+    pub fn session_expiration(mut self, seconds: i64) -> Self {
+        self.session_expiration = Some(seconds);
+        self
+    }
+
+    // The rules a new password must follow when the user changes it.
+    pub fn password_policy(mut self, policy: PasswordPolicy) -> Self {
+        self.password_policy = Some(policy);
+        self
+    }
+
+    // Enables signing in with the provider, the client id must be registered with
+    // it.
+    pub fn oauth(mut self, provider: OAuthProviderType, config: OAuthConfig) -> Self {
+        self.oauth_configs.push((provider, config));
+        self
+    }
+
+    // Where the tokens are kept. Defaults to the credential store of the platform,
+    // with the encrypted file store as the fallback.
+    pub fn secret_store(mut self, kind: SecretStoreKind) -> Self {
+        self.secret_store = Some(kind);
+        self
+    }
+
+    // How many undo units each opened document keeps. Defaults to flowy_document's
+    // MAX_UNDOS.
+    pub fn undo_capacity(mut self, capacity: usize) -> Self {
+        self.undo_capacity = Some(capacity);
+        self
+    }
+
+    // When the changes of the opened documents are saved. Defaults to
+    // flowy_document's AutosaveConfig::default().
+    pub fn autosave(mut self, config: AutosaveConfig) -> Self {
+        self.autosave = Some(config);
+        self
+    }
+
+    // How many revisions of a document are between two of its versions. Defaults to
+    // flowy_document's DEFAULT_SNAPSHOT_INTERVAL.
+    pub fn snapshot_interval(mut self, interval: i64) -> Self {
+        self.snapshot_interval = Some(interval);
+        self
+    }
+
+    // Checks the spelling and the grammar of the documents. Defaults to
+    // flowy_document's RepeatedWordChecker.
+    pub fn text_checker(mut self, checker: Arc<dyn TextChecker>) -> Self {
+        self.text_checker = Some(checker);
+        self
+    }
+
+    // How many bytes the caches keep in memory together, the opened documents and
+    // the blobs that were read. Defaults to flowy_infra's DEFAULT_MEMORY_BUDGET.
+    pub fn memory_budget(mut self, bytes: usize) -> Self {
+        self.memory_budget = MemoryBudget::new(bytes);
+        self
+    }
+
+    // How many bytes the attachments of each user can take. Defaults to
+    // flowy_document's DEFAULT_ATTACHMENT_QUOTA.
+    pub fn attachment_quota(mut self, bytes: i64) -> Self {
+        self.attachment_quota = Some(bytes);
+        self
+    }
+
+    // Adds the module of the plugin to the ones of the sdk, see FlowyPlugin.
+    pub fn plugin(mut self, plugin: Arc<dyn FlowyPlugin>) -> Self {
+        self.plugins.push(plugin);
+        self
+    }
+
+    // Adds the ScriptPlugin, the scripts the user saved run on the events of the
+    // workspace.
+    #[cfg(feature = "scripting")]
+    pub fn scripting(self) -> Self { self.plugin(Arc::new(plugin::script::ScriptPlugin::default())) }
+
+    // Adds the WebhookPlugin, the events of the workspace are posted to the
+    // webhooks the user registered.
+    #[cfg(feature = "webhooks")]
+    pub fn webhooks(self) -> Self { self.plugin(Arc::new(plugin::webhook::WebhookPlugin::default())) }
+
+    // Adds the plugins of the .wasm files in the directory, see WasmPlugin.
+    #[cfg(feature = "wasm_plugins")]
+    pub fn wasm_plugins(mut self, dir: &str) -> Self {
+        self.plugins.extend(plugin::wasm::load_wasm_plugins(dir));
+        self
+    }
+
+    // Adds the extensions of the directory, see ExtensionManifest. They're
+    // loaded with the kv store, the prompt is asked for the capabilities the
+    // user didn't grant yet.
+    #[cfg(feature = "extensions")]
+    pub fn extensions(mut self, dir: &str, prompt: Arc<dyn plugin::extension::ExtensionPrompt>) -> Self {
+        self.extensions = Some((dir.to_owned(), prompt));
+        self
+    }
+
+    // How long, in seconds, the items are kept in the trash before they're purged.
+    // Defaults to flowy_workspace's DEFAULT_TRASH_RETENTION.
+    pub fn trash_retention(mut self, seconds: i64) -> Self {
+        self.trash_retention = Some(seconds);
+        self
+    }
+
+    // How often the expired trash is purged. Defaults to flowy_workspace's
+    // DEFAULT_TRASH_PURGE_INTERVAL.
+    pub fn trash_purge_interval(mut self, interval: Duration) -> Self {
+        self.trash_purge_interval = Some(interval);
+        self
+    }
+
+    // How often the orphaned views, documents and assets are removed. Defaults to
+    // flowy_workspace's DEFAULT_GARBAGE_COLLECTION_INTERVAL.
+    pub fn garbage_collection_interval(mut self, interval: Duration) -> Self {
+        self.garbage_collection_interval = Some(interval);
+        self
+    }
+
+    // How often the database of the signed in user is backed up. Defaults to
+    // flowy_user's DEFAULT_BACKUP_INTERVAL.
+    pub fn backup_interval(mut self, interval: Duration) -> Self {
+        self.backup_interval = Some(interval);
+        self
+    }
+
+    // How many backups of each user database are kept. Defaults to flowy_user's
+    // DEFAULT_MAX_BACKUPS.
+    pub fn max_backups(mut self, max_backups: usize) -> Self {
+        self.max_backups = Some(max_backups);
+        self
+    }
+
+    // How long no event must be dispatched before the database of the signed in
+    // user is compacted, it's compacted once per idle period. Defaults to
+    // flowy_user's DEFAULT_COMPACTION_IDLE_PERIOD.
+    pub fn compaction_idle_period(mut self, period: Duration) -> Self {
+        self.compaction_idle_period = Some(period);
+        self
+    }
+
+    // The size of the connection pool of each user database, how long to wait for
+    // a connection and how long SQLite retries a locked database. Defaults to
+    // flowy_database's PoolConfig::default().
+    pub fn db_pool(mut self, config: PoolConfig) -> Self {
+        self.db_pool = Some(config);
+        self
+    }
+
+    // Applied to each user database the first time it's opened, after the SQL
+    // migrations of flowy_database.
+    pub fn migration(mut self, migration: RustMigration) -> Self {
+        self.migrations.push(migration);
+        self
+    }
+
+    // Encrypts the user databases with SQLCipher, the key of each one is derived
+    // from a secret kept in the secret store. The sdk must be built with the
+    // sqlcipher feature, the databases fail to open otherwise.
+    pub fn encrypt_database(mut self, encrypt: bool) -> Self {
+        self.encrypt_database = encrypt;
+        self
+    }
+
+    // Keeps the user databases and the key-value store in memory instead of the
+    // root, so the tests can run in parallel without touching the disk. The data
+    // is lost when the user signs out or the process exits, and the backups
+    // aren't scheduled, and the anonymous users can't be migrated to an account.
+    // The secrets are kept in memory too unless another secret store is set.
+    pub fn in_memory_storage(mut self, in_memory: bool) -> Self {
+        self.in_memory_storage = in_memory;
+        self
+    }
+
+    // How the websocket connection is kept alive and connected again when it's
+    // lost. Defaults to flowy_ws's WsConfig::default().
+    pub fn ws_config(mut self, config: WsConfig) -> Self {
+        self.ws_config = Some(config);
+        self
+    }
+
+    // Where and how often the reachability of the network is probed. Defaults to
+    // flowy_ws's ConnectivityConfig::default(), which doesn't probe.
+    pub fn connectivity_config(mut self, config: ConnectivityConfig) -> Self {
+        self.connectivity_config = Some(config);
+        self
+    }
+
+    // The time the sessions expire by, the autosave waits with, the reminders
+    // fire by and the trash purge, the garbage collection, the backups and the
+    // compaction are scheduled with. The handlers read it with
+    // Unit<Arc<dyn Clock>>. Defaults to the SystemClock, the tests advance a
+    // ManualClock instead of sleeping.
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    // Shows the reminders when they fire, e.g. with the notifications of the OS.
+    // The frontend is sent ReminderFired whether it's set or not.
+    pub fn reminder_notifier(mut self, notifier: Arc<dyn ReminderNotifier>) -> Self {
+        self.reminder_notifier = Some(notifier);
+        self
+    }
+
+    // Where the telemetry is uploaded, once the user opted in with the privacy
+    // setting. Defaults to the server of the server config.
+    pub fn telemetry_server(mut self, server: Arc<dyn TelemetryServerAPI>) -> Self {
+        self.telemetry_server = Some(server);
+        self
+    }
+
+    // Loads the <locale>.json catalogs of the directory over the bundled ones of
+    // flowy_i18n, e.g. to add a locale or to replace some of the translations.
+    pub fn translations(mut self, dir: &str) -> Self {
+        self.translations_dir = Some(dir.to_owned());
+        self
+    }
+
+    // The filter set with the __system/set_log_filter event replaces it, until
+    // it's reset.
     pub fn log_filter(mut self, filter: &str) -> Self {
         self.log_filter = crate_log_filter(Some(filter.to_owned()));
         self
     }
+
+    // Each dispatched event has a span with its name, its request id and its
+    // module, the database writes and the requests of its handler are nested in
+    // it. Their START and END records are logged unless it's false, the END one
+    // with how long the span lasted.
+    pub fn log_spans(mut self, enabled: bool) -> Self {
+        self.log_spans = enabled;
+        self
+    }
+
+    // How big a log file can grow and how many of the rotated ones are kept, the
+    // files are in the logs directory of the root. Defaults to flowy_log's
+    // LogRotation::default().
+    pub fn log_rotation(mut self, rotation: LogRotation) -> Self {
+        self.log_rotation = Some(rotation);
+        self
+    }
+
+    // Measures the wall time, the polls and the allocations of each handler
+    // invocation from the start, see the __system/profile_report event. It can be
+    // enabled later with the __system/set_profiling one.
+    pub fn event_profiling(mut self, enabled: bool) -> Self {
+        self.event_profiling = enabled;
+        self
+    }
+
+    // The events that take longer are logged with the time they waited and were
+    // handled, and the size of their payload. None disables it. Defaults to
+    // flowy_dispatch's DEFAULT_SLOW_EVENT_THRESHOLD.
+    pub fn slow_event_threshold(mut self, threshold: Option<Duration>) -> Self {
+        self.slow_event_threshold = threshold;
+        self
+    }
+
+    // Injects latencies, errors and lost responses into the events, after the
+    // other guards accepted them. Only in the debug builds or with the chaos
+    // feature.
+    #[cfg(any(debug_assertions, feature = "chaos"))]
+    pub fn fault_injector(mut self, injector: Arc<flowy_dispatch::chaos::FaultInjector>) -> Self {
+        self.fault_injector = Some(injector);
+        self
+    }
 }
 
 fn crate_log_filter(level: Option<String>) -> String {
@@ -54,6 +454,9 @@ fn crate_log_filter(level: Option<String>) -> String {
     filters.push(format!("flowy_ws={}", level));
     filters.push(format!("flowy_ws={}", level));
     filters.push(format!("flowy_infra={}", level));
+    filters.push(format!("flowy_dispatch={}", level));
+    filters.push(format!("flowy_net={}", level));
+    filters.push(format!("flowy_sqlite={}", level));
     filters.join(",")
 }
 
@@ -63,32 +466,188 @@ pub struct FlowySDK {
     pub user_session: Arc<UserSession>,
     pub flowy_document: Arc<FlowyDocument>,
     pub workspace: Arc<WorkspaceController>,
+    pub grid: Arc<GridController>,
+    pub reminder: Arc<ReminderController>,
+    pub telemetry: Arc<TelemetryController>,
+    pub kv_store: KVStore,
     pub dispatch: Arc<EventDispatch>,
 }
 
 impl FlowySDK {
     pub fn new(config: FlowySDKConfig) -> Self {
         init_log(&config);
-        init_kv(&config.root);
+        init_kv(&config);
+        system::restore_log_filter();
+        init_i18n(&config);
+        init_schema(&config);
+        if config.event_profiling {
+            profiler().set_enabled(true);
+        }
         tracing::debug!("🔥 {:?}", config);
 
         let session_cache_key = format!("{}_session_cache", &config.name);
-        let user_session = Arc::new(
-            UserSessionBuilder::new()
-                .root_dir(&config.root, &config.server_config, &session_cache_key)
-                .build(),
+        let mut user_session_builder =
+            UserSessionBuilder::new().root_dir(&config.root, &config.server_config, &session_cache_key);
+        if let Some(seconds) = config.session_expiration {
+            user_session_builder = user_session_builder.session_expiration(seconds);
+        }
+        if let Some(policy) = config.password_policy.clone() {
+            user_session_builder = user_session_builder.password_policy(policy);
+        }
+        for (provider, oauth_config) in config.oauth_configs.clone() {
+            user_session_builder = user_session_builder.oauth_provider(mk_oauth_provider(provider, oauth_config));
+        }
+        match config.secret_store {
+            Some(kind) => user_session_builder = user_session_builder.secret_store(kind),
+            None if config.in_memory_storage => {
+                user_session_builder = user_session_builder.secret_store(SecretStoreKind::Memory)
+            },
+            None => {},
+        }
+        if config.in_memory_storage {
+            let pool_config = config.db_pool.clone().unwrap_or_default().in_memory();
+            user_session_builder = user_session_builder.pool_config(pool_config);
+        } else if let Some(pool_config) = config.db_pool.clone() {
+            user_session_builder = user_session_builder.pool_config(pool_config);
+        }
+        for migration in config.migrations.clone() {
+            user_session_builder = user_session_builder.migration(migration);
+        }
+        if let Some(max_backups) = config.max_backups {
+            user_session_builder = user_session_builder.max_backups(max_backups);
+        }
+        if let Some(ws_config) = config.ws_config.clone() {
+            user_session_builder = user_session_builder.ws_config(ws_config);
+        }
+        if let Some(connectivity_config) = config.connectivity_config.clone() {
+            user_session_builder = user_session_builder.connectivity_config(connectivity_config);
+        }
+        user_session_builder = user_session_builder
+            .encrypt_database(config.encrypt_database)
+            .clock(config.clock.clone());
+        let user_session = Arc::new(user_session_builder.build());
+        flowy_net::request::set_token_provider(user_session.clone());
+        let flowy_document = mk_document_module(user_session.clone(), &config);
+        let workspace_config = mk_workspace_config(&config);
+        let trash_purge_interval = workspace_config.trash_purge_interval;
+        let garbage_collection_interval = workspace_config.garbage_collection_interval;
+        let grid = mk_grid(user_session.clone());
+        let reminder = mk_reminder(user_session.clone(), &config);
+        let telemetry = mk_telemetry(user_session.clone(), &config);
+        let workspace = mk_workspace(
+            user_session.clone(),
+            flowy_document.clone(),
+            grid.clone(),
+            &config,
+            workspace_config,
+        );
+        let kv_store = KVStore::new(Arc::new(KVDepsResolver::new(user_session.clone())));
+        let mut modules = mk_modules(
+            workspace.clone(),
+            flowy_document.clone(),
+            grid.clone(),
+            reminder.clone(),
+            telemetry.clone(),
+            user_session.clone(),
+            kv_store.clone(),
+            config.clock.clone(),
+            config.memory_budget.clone(),
         );
-        let flowy_document = mk_document_module(user_session.clone(), &config.server_config);
-        let workspace = mk_workspace(user_session.clone(), flowy_document.clone(), &config.server_config);
-        let modules = mk_modules(workspace.clone(), user_session.clone());
-        let dispatch = Arc::new(EventDispatch::construct(|| modules));
-        _init(&dispatch, user_session.clone(), workspace.clone());
+        let plugin_context = PluginContext {
+            root: config.root.clone(),
+            kv_store: kv_store.clone(),
+            clock: config.clock.clone(),
+            secret_store: user_session.secret_store(),
+        };
+        #[allow(unused_mut)]
+        let mut plugins = config.plugins.clone();
+        #[cfg(feature = "extensions")]
+        if let Some((dir, prompt)) = &config.extensions {
+            plugins.extend(plugin::extension::load_extensions(dir, prompt.as_ref()));
+        }
+        let (plugin_modules, plugins) = mk_plugin_modules(&plugins, &modules, &plugin_context);
+        modules.extend(
+            plugin_modules
+                .into_iter()
+                .map(|module| module.data(kv_store.clone()).data(config.clock.clone())),
+        );
+        let token_refresher = mk_token_refresher(user_session.clone());
+        let audit_log = mk_audit_log(user_session.clone(), &modules);
+        let telemetry_recorder = flowy_telemetry::module::mk_recorder(telemetry.clone());
+        let dispatch = EventDispatch::construct(|| modules)
+            .slow_event_threshold(config.slow_event_threshold)
+            .middleware(token_refresher.clone())
+            .middleware(audit_log)
+            .middleware(telemetry_recorder.clone())
+            .guard(Arc::new(SessionGuard::new(user_session.clone())))
+            .guard(mk_verification_guard(user_session.clone()))
+            .guard(workspace.permission_guard());
+        let dispatch = plugins
+            .iter()
+            .filter_map(|plugin| plugin.middleware())
+            .fold(dispatch, |dispatch, middleware| dispatch.middleware(middleware));
+        #[cfg(any(debug_assertions, feature = "chaos"))]
+        let dispatch = match &config.fault_injector {
+            Some(injector) => dispatch.guard(injector.clone()),
+            None => dispatch,
+        };
+        // The last guard, only the requests that are handled are recorded.
+        let dispatch = Arc::new(dispatch.guard(telemetry_recorder));
+        crash::install(&config, &dispatch);
+        let plugins = Arc::new(plugins);
+        _init(
+            &dispatch,
+            user_session.clone(),
+            workspace.clone(),
+            flowy_document.clone(),
+            reminder.clone(),
+            telemetry.clone(),
+            plugins.clone(),
+        );
+        for plugin in plugins.iter() {
+            plugin.on_start(dispatch.clone());
+        }
+        dispatch.spawn(token_refresher.run());
+        dispatch.spawn(user_session.connectivity.clone().run());
+        dispatch.spawn(schedule_sync(dispatch.clone(), workspace.clone()));
+        dispatch.spawn(schedule_reminders(
+            dispatch.clone(),
+            reminder.clone(),
+            config.clock.clone(),
+        ));
+        dispatch.spawn(schedule_telemetry_upload(dispatch.clone(), telemetry.clone()));
+        dispatch.spawn(schedule_trash_purge(
+            dispatch.clone(),
+            config.clock.clone(),
+            trash_purge_interval,
+        ));
+        dispatch.spawn(schedule_garbage_collection(
+            dispatch.clone(),
+            config.clock.clone(),
+            garbage_collection_interval,
+        ));
+        if !config.in_memory_storage {
+            dispatch.spawn(schedule_backup(
+                dispatch.clone(),
+                config.clock.clone(),
+                config.backup_interval.unwrap_or(DEFAULT_BACKUP_INTERVAL),
+            ));
+        }
+        dispatch.spawn(schedule_compaction(
+            dispatch.clone(),
+            config.clock.clone(),
+            config.compaction_idle_period.unwrap_or(DEFAULT_COMPACTION_IDLE_PERIOD),
+        ));
 
         Self {
             config,
             user_session,
             flowy_document,
             workspace,
+            grid,
+            reminder,
+            telemetry,
+            kv_store,
             dispatch,
         }
     }
@@ -96,36 +655,195 @@ impl FlowySDK {
     pub fn dispatch(&self) -> Arc<EventDispatch> { self.dispatch.clone() }
 }
 
-fn _init(dispatch: &EventDispatch, user_session: Arc<UserSession>, workspace_controller: Arc<WorkspaceController>) {
+fn _init(
+    dispatch: &EventDispatch,
+    user_session: Arc<UserSession>,
+    workspace_controller: Arc<WorkspaceController>,
+    flowy_document: Arc<FlowyDocument>,
+    reminder: Arc<ReminderController>,
+    telemetry: Arc<TelemetryController>,
+    plugins: Arc<Vec<Arc<dyn FlowyPlugin>>>,
+) {
     let subscribe = user_session.status_subscribe();
+    let setting_subscribe = user_session.setting_subscribe();
+    let ws_subscribe = user_session.ws_controller.state_subscribe();
+    let network_subscribe = user_session.connectivity.state_subscribe();
+    let metered_subscribe = user_session.connectivity.metered_subscribe();
+    let connectivity = user_session.connectivity.clone();
+    let cloned_workspace_controller = workspace_controller.clone();
+    let network_workspace_controller = workspace_controller.clone();
+    let metered_workspace_controller = workspace_controller.clone();
+    let network_document = flowy_document.clone();
+    let setting_telemetry = telemetry.clone();
     dispatch.spawn(async move {
         user_session.init();
-        _listen_user_status(subscribe, workspace_controller).await;
+        telemetry.user_did_change(telemetry_enabled(&user_session));
+        _listen_user_status(
+            subscribe,
+            user_session,
+            workspace_controller,
+            flowy_document,
+            reminder,
+            telemetry,
+            plugins,
+        )
+        .await;
+    });
+    dispatch.spawn(async move {
+        _listen_setting_change(setting_subscribe, setting_telemetry).await;
+    });
+    dispatch.spawn(async move {
+        _listen_ws_state(ws_subscribe, cloned_workspace_controller).await;
+    });
+    let network_connectivity = connectivity.clone();
+    dispatch.spawn(async move {
+        _listen_network_state(
+            network_subscribe,
+            network_connectivity,
+            network_workspace_controller,
+            network_document,
+        )
+        .await;
+    });
+    dispatch.spawn(async move {
+        _listen_network_metered(metered_subscribe, connectivity, metered_workspace_controller).await;
     });
 }
 
+// The local changes made while offline are sent, and the ones made on the other
+// devices pulled, each time the websocket is connected, unless the user only
+// syncs manually.
+async fn _listen_ws_state(mut subscribe: broadcast::Receiver<WsState>, workspace_controller: Arc<WorkspaceController>) {
+    loop {
+        match subscribe.recv().await {
+            Ok(WsState::Connected(_)) => workspace_controller.sync_did_reconnect(),
+            Ok(_) => {},
+            Err(broadcast::error::RecvError::Lagged(_)) => {},
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+}
+
+// The changes that were kept while the network was unreachable are synced once
+// it's back, and the asset transfers that it interrupted are resumed.
+async fn _listen_network_state(
+    mut subscribe: broadcast::Receiver<NetworkStateType>,
+    connectivity: Arc<Connectivity>,
+    workspace_controller: Arc<WorkspaceController>,
+    flowy_document: Arc<FlowyDocument>,
+) {
+    loop {
+        match subscribe.recv().await {
+            Ok(ty) => {
+                dart_notify("", SDKNotification::NetworkStateChanged)
+                    .payload(NetworkState {
+                        ty,
+                        metered: connectivity.is_metered(),
+                    })
+                    .send();
+                if ty == NetworkStateType::Online {
+                    workspace_controller.sync_did_reconnect();
+                    let flowy_document = flowy_document.clone();
+                    tokio::spawn(async move { flowy_document.resume_asset_transfers().await });
+                }
+            },
+            Err(broadcast::error::RecvError::Lagged(_)) => {},
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+}
+
+// The sync that waited for an unmetered network is scheduled again.
+async fn _listen_network_metered(
+    mut subscribe: broadcast::Receiver<bool>,
+    connectivity: Arc<Connectivity>,
+    workspace_controller: Arc<WorkspaceController>,
+) {
+    loop {
+        match subscribe.recv().await {
+            Ok(metered) => {
+                dart_notify("", SDKNotification::NetworkStateChanged)
+                    .payload(NetworkState {
+                        ty: connectivity.state(),
+                        metered,
+                    })
+                    .send();
+                workspace_controller.sync_network_did_change();
+            },
+            Err(broadcast::error::RecvError::Lagged(_)) => {},
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+}
+
+// The telemetry is enabled or disabled as the user opts in or out with the
+// privacy setting.
+async fn _listen_setting_change(
+    mut subscribe: broadcast::Receiver<SettingChange>,
+    telemetry: Arc<TelemetryController>,
+) {
+    loop {
+        match subscribe.recv().await {
+            Ok(change) if change.section == SettingSection::Privacy => {
+                telemetry.set_enabled(change.setting.privacy.telemetry);
+            },
+            Ok(_) => {},
+            Err(broadcast::error::RecvError::Lagged(_)) => {},
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+}
+
+// The opened documents are closed whenever the current user changes, they're
+// opened again with the database of the new one. The plugins are told after the
+// workspace, the reminders of the new user are scheduled and the telemetry
+// follows the privacy setting of the new user.
 async fn _listen_user_status(
     mut subscribe: broadcast::Receiver<UserStatus>,
+    user_session: Arc<UserSession>,
     workspace_controller: Arc<WorkspaceController>,
+    flowy_document: Arc<FlowyDocument>,
+    reminder: Arc<ReminderController>,
+    telemetry: Arc<TelemetryController>,
+    plugins: Arc<Vec<Arc<dyn FlowyPlugin>>>,
 ) {
     loop {
         match subscribe.recv().await {
             Ok(status) => {
+                let signed_in = match &status {
+                    UserStatus::Login { .. } | UserStatus::SignUp { .. } | UserStatus::Migrated { .. } => Some(true),
+                    UserStatus::Logout { .. } => Some(false),
+                    UserStatus::Expired { .. } => None,
+                };
                 let result = || async {
                     match status {
                         UserStatus::Login { token } => {
+                            flowy_document.close_all().await;
                             let _ = workspace_controller.user_did_sign_in(&token).await?;
                         },
                         UserStatus::Logout { .. } => {
+                            flowy_document.close_all().await;
                             workspace_controller.user_did_logout().await;
                         },
                         UserStatus::Expired { .. } => {
                             workspace_controller.user_session_expired().await;
                         },
                         UserStatus::SignUp { profile, ret } => {
+                            flowy_document.close_all().await;
                             let _ = workspace_controller.user_did_sign_up(&profile.token).await?;
                             let _ = ret.send(());
                         },
+                        UserStatus::Migrated {
+                            anonymous_user_id,
+                            profile,
+                            ret,
+                        } => {
+                            flowy_document.close_all().await;
+                            let _ = workspace_controller
+                                .user_did_migrate(&anonymous_user_id, &profile.token)
+                                .await?;
+                            let _ = ret.send(());
+                        },
                     }
                     Ok::<(), WorkspaceError>(())
                 };
@@ -134,36 +852,234 @@ async fn _listen_user_status(
                     Ok(_) => {},
                     Err(e) => log::error!("{}", e),
                 }
+                notify_plugins(&plugins, &user_session, signed_in);
+                if signed_in.is_some() {
+                    reminder.user_did_change();
+                    telemetry.user_did_change(telemetry_enabled(&user_session));
+                }
             },
             Err(_) => {},
         }
     }
 }
 
-fn init_kv(root: &str) {
-    match flowy_infra::kv::KV::init(root) {
+// False while no user is signed in.
+fn telemetry_enabled(user_session: &UserSession) -> bool {
+    user_session
+        .read_setting()
+        .map(|setting| setting.privacy.telemetry)
+        .unwrap_or(false)
+}
+
+// None if the user didn't change, e.g. when the session expired.
+fn notify_plugins(plugins: &[Arc<dyn FlowyPlugin>], user_session: &UserSession, signed_in: Option<bool>) {
+    match signed_in {
+        Some(true) => match user_session.user_id() {
+            Ok(user_id) => plugins.iter().for_each(|plugin| plugin.on_sign_in(&user_id)),
+            Err(e) => log::error!("Notify the plugins failed: {:?}", e),
+        },
+        Some(false) => plugins.iter().for_each(|plugin| plugin.on_sign_out()),
+        None => {},
+    }
+}
+
+fn init_kv(config: &FlowySDKConfig) {
+    let result = match config.in_memory_storage {
+        true => flowy_infra::kv::KV::init_in_memory(),
+        false => flowy_infra::kv::KV::init(&config.root),
+    };
+    match result {
         Ok(_) => {},
         Err(e) => tracing::error!("Init kv store failedL: {}", e),
     }
 }
 
+fn init_i18n(config: &FlowySDKConfig) {
+    if let Some(dir) = &config.translations_dir {
+        let count = flowy_i18n::catalog().load_dir(dir);
+        tracing::debug!("Load {} catalogs of {}", count, dir);
+    }
+    system::restore_locale();
+}
+
+fn init_schema(config: &FlowySDKConfig) { schema::init_schema_state(schema::verify_schema(config.schema_version)); }
+
 fn init_log(config: &FlowySDKConfig) {
     if !INIT_LOG.load(Ordering::SeqCst) {
         INIT_LOG.store(true, Ordering::SeqCst);
 
         let _ = flowy_log::Builder::new("flowy-client", &config.root)
             .env_filter(&config.log_filter)
+            .with_spans(config.log_spans)
+            .rotation(config.log_rotation.clone().unwrap_or_default())
             .build();
     }
 }
 
+// The events that share the data with others need the email to be verified.
+fn mk_verification_guard(user_session: Arc<UserSession>) -> Arc<VerificationGuard> {
+    let events = vec![flowy_workspace::event::WorkspaceEvent::CopyLink.into()];
+    Arc::new(VerificationGuard::new(user_session, events))
+}
+
+// The events that need the write or manage permission are the ones that change
+// the data.
+fn mk_audit_log(user_session: Arc<UserSession>, modules: &[Module]) -> Arc<AuditLog> {
+    let events = modules
+        .iter()
+        .flat_map(|module| {
+            module.events().into_iter().filter(move |event| {
+                matches!(
+                    module.permission(event),
+                    Some(EventPermission::Write | EventPermission::Manage)
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+    Arc::new(AuditLog::new(user_session, events))
+}
+
+fn mk_token_refresher(user_session: Arc<UserSession>) -> Arc<TokenRefresher> {
+    let unauthorized_codes = vec![
+        flowy_user::errors::ErrorCode::UserUnauthorized.value(),
+        flowy_workspace::errors::ErrorCode::UserUnauthorized.value(),
+        flowy_document::errors::ErrorCode::UserUnauthorized as i32,
+        flowy_grid::errors::ErrorCode::UserUnauthorized as i32,
+        flowy_reminder::errors::ErrorCode::UserUnauthorized as i32,
+        flowy_telemetry::errors::ErrorCode::UserUnauthorized as i32,
+    ];
+    Arc::new(TokenRefresher::new(user_session).unauthorized_codes(unauthorized_codes))
+}
+
 fn mk_workspace(
     user_session: Arc<UserSession>,
     flowy_document: Arc<FlowyDocument>,
-    server_config: &ServerConfig,
+    grid_controller: Arc<GridController>,
+    config: &FlowySDKConfig,
+    workspace_config: WorkspaceConfig,
 ) -> Arc<WorkspaceController> {
     let workspace_deps = WorkspaceDepsResolver::new(user_session.clone());
     let (user, database) = workspace_deps.split_into();
-    let workspace_controller = flowy_workspace::module::mk_workspace(user, database, flowy_document, server_config);
+    let grid = Arc::new(WorkspaceGridResolver::new(grid_controller));
+    let workspace_controller = flowy_workspace::module::mk_workspace(
+        user,
+        database,
+        flowy_document,
+        grid,
+        &config.server_config,
+        workspace_config,
+    );
+    user_session.add_ws_handler(Arc::new(WsWorkspaceReceiver::new(workspace_controller.clone())));
     workspace_controller
 }
+
+fn mk_workspace_config(config: &FlowySDKConfig) -> WorkspaceConfig {
+    let mut workspace_config = WorkspaceConfig::default();
+    if let Some(seconds) = config.trash_retention {
+        workspace_config.trash_retention = seconds;
+    }
+    if let Some(interval) = config.trash_purge_interval {
+        workspace_config.trash_purge_interval = interval;
+    }
+    if let Some(interval) = config.garbage_collection_interval {
+        workspace_config.garbage_collection_interval = interval;
+    }
+    workspace_config
+}
+
+// The workspace decides when it's synced, see SyncSettings. The sync is sent
+// like the other scheduled events.
+async fn schedule_sync(dispatch: Arc<EventDispatch>, workspace_controller: Arc<WorkspaceController>) {
+    loop {
+        workspace_controller.next_scheduled_sync().await;
+        let request = ModuleRequest::new(WorkspaceEvent::SyncWorkspace);
+        let _ = EventDispatch::async_send_after(dispatch.clone(), request, Duration::from_secs(0)).await;
+    }
+}
+
+// The reminders are fired by an event like the other scheduled tasks. It's
+// sent again after a while if it failed, the reminders are still due.
+async fn schedule_reminders(dispatch: Arc<EventDispatch>, reminder: Arc<ReminderController>, clock: Arc<dyn Clock>) {
+    loop {
+        reminder.next_due().await;
+        let request = ModuleRequest::new(ReminderEvent::FireDueReminders);
+        let response = EventDispatch::async_send_after(dispatch.clone(), request, Duration::from_secs(0)).await;
+        if response.status_code != StatusCode::Ok {
+            clock.sleep(REMINDER_RETRY_DELAY).await;
+        }
+    }
+}
+
+// The telemetry is uploaded by an event like the other scheduled tasks, the
+// controller schedules the next upload, after the backoff if it failed.
+async fn schedule_telemetry_upload(dispatch: Arc<EventDispatch>, telemetry: Arc<TelemetryController>) {
+    loop {
+        telemetry.next_upload().await;
+        let request = ModuleRequest::new(TelemetryEvent::UploadTelemetry);
+        let _ = EventDispatch::async_send_after(dispatch.clone(), request, Duration::from_secs(0)).await;
+    }
+}
+
+// The purge is sent like any other event, so it's skipped by the guards while
+// no user is signed in.
+async fn schedule_trash_purge(dispatch: Arc<EventDispatch>, clock: Arc<dyn Clock>, interval: Duration) {
+    schedule_every(dispatch, clock, interval, WorkspaceEvent::PurgeTrash).await
+}
+
+async fn schedule_backup(dispatch: Arc<EventDispatch>, clock: Arc<dyn Clock>, interval: Duration) {
+    schedule_every(dispatch, clock, interval, UserEvent::CreateBackup).await
+}
+
+// The interval is waited with the clock, the request is then sent like the
+// other periodic ones.
+async fn schedule_every<E>(dispatch: Arc<EventDispatch>, clock: Arc<dyn Clock>, interval: Duration, event: E)
+where
+    E: Eq + std::hash::Hash + std::fmt::Debug + Clone + std::fmt::Display,
+{
+    loop {
+        clock.sleep(interval).await;
+        let request = ModuleRequest::new(event.clone());
+        let _ = EventDispatch::async_send_after(dispatch.clone(), request, Duration::from_secs(0)).await;
+    }
+}
+
+// The periodic events, like this one, don't end the idle period. The idle time
+// is measured with the clock from when the period was first seen.
+async fn schedule_compaction(dispatch: Arc<EventDispatch>, clock: Arc<dyn Clock>, idle_period: Duration) {
+    let mut idle_start: Option<(Instant, i64)> = None;
+    let mut compacted_since = None;
+    loop {
+        let delay = match dispatch.idle_since() {
+            None => idle_period,
+            Some(idle_since) => {
+                let now = clock.timestamp_millis();
+                let started_at = match idle_start {
+                    Some((since, started_at)) if since == idle_since => started_at,
+                    _ => {
+                        let started_at = now - idle_since.elapsed().as_millis() as i64;
+                        idle_start = Some((idle_since, started_at));
+                        started_at
+                    },
+                };
+                let idle_time = Duration::from_millis((now - started_at).max(0) as u64);
+                match idle_period.checked_sub(idle_time) {
+                    Some(remaining) if remaining > Duration::from_secs(0) => remaining,
+                    _ => {
+                        if compacted_since != Some(idle_since) {
+                            compacted_since = Some(idle_since);
+                            let request = ModuleRequest::new(UserEvent::CompactStorage);
+                            let _ = EventDispatch::async_send_after(dispatch.clone(), request, Duration::from_secs(0))
+                                .await;
+                        }
+                        idle_period
+                    },
+                }
+            },
+        };
+        clock.sleep(delay).await;
+    }
+}
+
+async fn schedule_garbage_collection(dispatch: Arc<EventDispatch>, clock: Arc<dyn Clock>, interval: Duration) {
+    schedule_every(dispatch, clock, interval, WorkspaceEvent::RunGarbageCollection).await
+}
@@ -1,13 +1,43 @@
-use crate::deps_resolve::DocumentDepsResolver;
+use crate::{
+    deps_resolve::{DocumentDepsResolver, GridDepsResolver, ReminderDepsResolver, TelemetryDepsResolver},
+    FlowySDKConfig,
+};
+use flowy_database::KVStore;
 use flowy_dispatch::prelude::Module;
-use flowy_document::module::FlowyDocument;
-use flowy_net::config::ServerConfig;
+use flowy_document::module::{DocumentConfig, FlowyDocument};
+use flowy_grid::services::grid::GridController;
+use flowy_infra::{clock::Clock, memory::MemoryBudget};
+use flowy_reminder::services::reminder::ReminderController;
+use flowy_telemetry::services::{server::construct_telemetry_server, telemetry::TelemetryController};
 use flowy_user::services::user::UserSession;
 use flowy_workspace::prelude::WorkspaceController;
 use std::sync::Arc;
 
-pub fn mk_modules(workspace_controller: Arc<WorkspaceController>, user_session: Arc<UserSession>) -> Vec<Module> {
-    vec![mk_user_module(user_session), mk_workspace_module(workspace_controller)]
+pub fn mk_modules(
+    workspace_controller: Arc<WorkspaceController>,
+    flowy_document: Arc<FlowyDocument>,
+    grid_controller: Arc<GridController>,
+    reminder_controller: Arc<ReminderController>,
+    telemetry_controller: Arc<TelemetryController>,
+    user_session: Arc<UserSession>,
+    kv_store: KVStore,
+    clock: Arc<dyn Clock>,
+    memory_budget: Arc<MemoryBudget>,
+) -> Vec<Module> {
+    // The handlers of every module read the store with Unit<KVStore> and the
+    // clock with Unit<Arc<dyn Clock>>.
+    vec![
+        mk_user_module(user_session),
+        mk_workspace_module(workspace_controller),
+        mk_doc_module(flowy_document.clone()),
+        mk_grid_module(grid_controller),
+        mk_reminder_module(reminder_controller),
+        mk_telemetry_module(telemetry_controller),
+        crate::system::create(flowy_document, memory_budget),
+    ]
+    .into_iter()
+    .map(|module| module.data(kv_store.clone()).data(clock.clone()))
+    .collect()
 }
 
 fn mk_user_module(user_session: Arc<UserSession>) -> Module { flowy_user::module::create(user_session.clone()) }
@@ -16,9 +46,63 @@ fn mk_workspace_module(workspace_controller: Arc<WorkspaceController>) -> Module
     flowy_workspace::module::create(workspace_controller)
 }
 
-pub fn mk_document_module(user_session: Arc<UserSession>, server_config: &ServerConfig) -> Arc<FlowyDocument> {
+fn mk_doc_module(flowy_document: Arc<FlowyDocument>) -> Module { flowy_document::module::create(flowy_document) }
+
+fn mk_grid_module(grid_controller: Arc<GridController>) -> Module { flowy_grid::module::create(grid_controller) }
+
+fn mk_reminder_module(reminder_controller: Arc<ReminderController>) -> Module {
+    flowy_reminder::module::create(reminder_controller)
+}
+
+fn mk_telemetry_module(telemetry_controller: Arc<TelemetryController>) -> Module {
+    flowy_telemetry::module::create(telemetry_controller)
+}
+
+pub fn mk_document_module(user_session: Arc<UserSession>, config: &FlowySDKConfig) -> Arc<FlowyDocument> {
     let document_deps = DocumentDepsResolver::new(user_session.clone());
     let (user, ws_manager) = document_deps.split_into();
-    let document = Arc::new(FlowyDocument::new(user, ws_manager, server_config));
+    let mut document_config = DocumentConfig::default();
+    if let Some(capacity) = config.undo_capacity {
+        document_config.undo_capacity = capacity;
+    }
+    if let Some(autosave) = config.autosave.clone() {
+        document_config.autosave = autosave;
+    }
+    document_config.autosave.clock = config.clock.clone();
+    if let Some(interval) = config.snapshot_interval {
+        document_config.snapshot_interval = interval;
+    }
+    if let Some(checker) = config.text_checker.clone() {
+        document_config.text_checker = checker;
+    }
+    document_config.memory_budget = config.memory_budget.clone();
+    if let Some(quota) = config.attachment_quota {
+        document_config.attachment_quota = quota;
+    }
+    let document = Arc::new(FlowyDocument::new(
+        user,
+        ws_manager,
+        &config.server_config,
+        document_config,
+    ));
     document
 }
+
+pub fn mk_grid(user_session: Arc<UserSession>) -> Arc<GridController> {
+    let user = GridDepsResolver::new(user_session).split_into();
+    flowy_grid::module::mk_grid(user)
+}
+
+pub fn mk_reminder(user_session: Arc<UserSession>, config: &FlowySDKConfig) -> Arc<ReminderController> {
+    let user = ReminderDepsResolver::new(user_session).split_into();
+    flowy_reminder::module::mk_reminder(user, config.clock.clone(), config.reminder_notifier.clone())
+}
+
+pub fn mk_telemetry(user_session: Arc<UserSession>, config: &FlowySDKConfig) -> Arc<TelemetryController> {
+    let user = TelemetryDepsResolver::new(user_session).split_into();
+    let server = config
+        .telemetry_server
+        .clone()
+        .unwrap_or_else(|| construct_telemetry_server(&config.server_config));
+    flowy_telemetry::module::mk_telemetry(user, config.clock.clone(), server)
+}
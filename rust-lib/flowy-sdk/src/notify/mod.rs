@@ -0,0 +1,2 @@
+mod observable;
+pub(crate) use observable::*;
@@ -0,0 +1,26 @@
+use flowy_dart_notify::DartNotifyBuilder;
+use flowy_derive::ProtoBuf_Enum;
+
+const OBSERVABLE_CATEGORY: &'static str = "SDK";
+
+#[derive(ProtoBuf_Enum, Debug)]
+pub(crate) enum SDKNotification {
+    Unknown             = 0,
+    SchemaIncompatible  = 1,
+    NetworkStateChanged = 2,
+    LogRecordReceived   = 3,
+    PluginNotification  = 4,
+    LocaleChanged       = 5,
+}
+
+impl std::default::Default for SDKNotification {
+    fn default() -> Self { SDKNotification::Unknown }
+}
+
+impl std::convert::Into<i32> for SDKNotification {
+    fn into(self) -> i32 { self as i32 }
+}
+
+pub(crate) fn dart_notify(id: &str, ty: SDKNotification) -> DartNotifyBuilder {
+    DartNotifyBuilder::new(id, ty, OBSERVABLE_CATEGORY)
+}
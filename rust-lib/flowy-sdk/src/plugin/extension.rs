@@ -0,0 +1,260 @@
+use crate::plugin::{
+    wasm::{Capability, WasmPlugin},
+    FlowyPlugin,
+};
+use flowy_infra::kv::KV;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, fmt, path::Path, sync::Arc};
+
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+// The version the min_sdk_version of the manifests is compared to.
+pub const SDK_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// Describes an extension, it's the manifest.json in the directory of the
+// extension, next to its module:
+//
+//     {
+//       "name": "time_tracking",
+//       "version": "1.2.0",
+//       "min_sdk_version": "0.1.0",
+//       "events": ["time_tracking/start", "time_tracking/stop"],
+//       "capabilities": ["storage", "notify"],
+//       "module": "time_tracking.wasm"
+//     }
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ExtensionManifest {
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub min_sdk_version: Option<String>,
+    // The events the module registers, they're prefixed with the name of the
+    // extension.
+    pub events: Vec<String>,
+    // The names of the Capabilities the extension requires.
+    #[serde(default)]
+    pub capabilities: Vec<String>,
+    // The .wasm file of the module, relative to the manifest.
+    pub module: String,
+}
+
+impl ExtensionManifest {
+    pub fn read(dir: &Path) -> Result<Self, String> {
+        let path = dir.join(MANIFEST_FILE);
+        let json = std::fs::read_to_string(&path).map_err(|e| format!("Read {:?} failed: {}", path, e))?;
+        serde_json::from_str(&json).map_err(|e| format!("Invalid manifest {:?}: {}", path, e))
+    }
+
+    // Checks the manifest before the user is asked for its capabilities.
+    pub fn validate(&self) -> Result<Vec<Capability>, String> {
+        let is_name = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-';
+        if self.name.is_empty() || !self.name.chars().all(is_name) {
+            return Err(format!("Invalid extension name: {}", self.name));
+        }
+        if let Some(min_sdk_version) = &self.min_sdk_version {
+            if parse_version(min_sdk_version)? > parse_version(SDK_VERSION)? {
+                return Err(format!(
+                    "The extension {} requires the sdk {}, it's {}",
+                    self.name, min_sdk_version, SDK_VERSION
+                ));
+            }
+        }
+        if self.events.is_empty() {
+            return Err(format!("The extension {} declares no events", self.name));
+        }
+        let prefix = format!("{}/", self.name);
+        if let Some(event) = self.events.iter().find(|event| !event.starts_with(&prefix)) {
+            return Err(format!("The event {} isn't prefixed with {}", event, prefix));
+        }
+        if self.module.contains("..") || Path::new(&self.module).is_absolute() {
+            return Err(format!("Invalid module path: {}", self.module));
+        }
+        self.capabilities
+            .iter()
+            .map(|name| Capability::from_name(name).ok_or(format!("Unknown capability: {}", name)))
+            .collect()
+    }
+}
+
+// Asks the user whether the extension can have the capabilities, e.g. with a
+// dialog of the app. It's only asked for the ones that weren't granted yet, the
+// extension is left out if they're denied.
+pub trait ExtensionPrompt: Send + Sync {
+    fn ask(&self, manifest: &ExtensionManifest, capabilities: &[Capability]) -> bool;
+}
+
+impl fmt::Debug for dyn ExtensionPrompt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("ExtensionPrompt") }
+}
+
+// The extensions of the directories in dir, each with its manifest. The ones
+// that are invalid, denied or that don't load are logged and left out.
+pub fn load_extensions(dir: &str, prompt: &dyn ExtensionPrompt) -> Vec<Arc<dyn FlowyPlugin>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            tracing::error!("Read the extensions in {} failed: {:?}", dir, e);
+            return vec![];
+        },
+    };
+    let mut plugins: Vec<Arc<dyn FlowyPlugin>> = vec![];
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if !path.join(MANIFEST_FILE).is_file() {
+            continue;
+        }
+        match load_extension(&path, prompt) {
+            Ok(plugin) => plugins.push(plugin),
+            Err(e) => tracing::error!("Load the extension {:?} failed: {}", path, e),
+        }
+    }
+    plugins
+}
+
+// The module is only instantiated once the user granted the capabilities, with
+// only the ones of the manifest.
+pub fn load_extension(dir: &Path, prompt: &dyn ExtensionPrompt) -> Result<Arc<WasmPlugin>, String> {
+    let manifest = ExtensionManifest::read(dir)?;
+    let capabilities = manifest.validate()?;
+    let mut granted = granted_capabilities(&manifest.name);
+    let missing = capabilities
+        .iter()
+        .filter(|capability| !granted.contains(*capability))
+        .copied()
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        if !prompt.ask(&manifest, &missing) {
+            return Err(format!("The extension {} wasn't granted {:?}", manifest.name, missing));
+        }
+        granted.extend(missing);
+        grant_capabilities(&manifest.name, &granted);
+    }
+
+    let path = dir.join(&manifest.module);
+    let bytes = std::fs::read(&path).map_err(|e| format!("Read {:?} failed: {}", path, e))?;
+    let plugin = WasmPlugin::with_capabilities(&manifest.name, &bytes, &capabilities)?;
+    let events = plugin.events().into_iter().collect::<HashSet<_>>();
+    if events != manifest.events.iter().cloned().collect() {
+        return Err(format!(
+            "The module of {} registers {:?} instead of its declared events",
+            manifest.name,
+            plugin.events()
+        ));
+    }
+    Ok(plugin)
+}
+
+// The user is asked again for all of them.
+pub fn revoke_extension(name: &str) { let _ = KV::remove(&grants_key(name)); }
+
+fn grants_key(name: &str) -> String { format!("extension_grants/{}", name) }
+
+fn granted_capabilities(name: &str) -> HashSet<Capability> {
+    KV::get_str(&grants_key(name))
+        .unwrap_or_default()
+        .split(',')
+        .filter_map(Capability::from_name)
+        .collect()
+}
+
+fn grant_capabilities(name: &str, capabilities: &HashSet<Capability>) {
+    let names = capabilities
+        .iter()
+        .map(|capability| capability.name())
+        .collect::<Vec<_>>();
+    KV::set_str(&grants_key(name), names.join(","));
+}
+
+// The major, minor and patch numbers, the missing ones are 0.
+fn parse_version(version: &str) -> Result<(u64, u64, u64), String> {
+    let mut numbers = version.trim().split('.').map(|number| {
+        number
+            .parse::<u64>()
+            .map_err(|_| format!("Invalid version: {}", version))
+    });
+    let mut next = || numbers.next().unwrap_or(Ok(0));
+    Ok((next()?, next()?, next()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // Declares the events of the manifest and imports kv_get.
+    const MODULE: &str = r#"
+    (module
+      (import "flowy" "kv_get" (func $kv_get (param i32 i32) (result i64)))
+      (memory (export "memory") 1)
+      (data (i32.const 0) "notes/read")
+      (func (export "flowy_alloc") (param $len i32) (result i32) (i32.const 1024))
+      (func (export "flowy_events") (result i64) (i64.const 10))
+      (func (export "flowy_handle") (param i32 i32 i32 i32) (result i64) (i64.const 0)))
+    "#;
+
+    struct CountingPrompt {
+        grant: bool,
+        asked: AtomicUsize,
+    }
+
+    impl ExtensionPrompt for CountingPrompt {
+        fn ask(&self, _manifest: &ExtensionManifest, _capabilities: &[Capability]) -> bool {
+            self.asked.fetch_add(1, Ordering::SeqCst);
+            self.grant
+        }
+    }
+
+    fn extension_dir(manifest: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("flowy_extension_{}", flowy_infra::uuid()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(MANIFEST_FILE), manifest).unwrap();
+        std::fs::write(dir.join("notes.wasm"), MODULE).unwrap();
+        dir
+    }
+
+    const MANIFEST: &str = r#"{"name":"notes","version":"1.0.0","min_sdk_version":"0.0.1",
+        "events":["notes/read"],"capabilities":["storage"],"module":"notes.wasm"}"#;
+
+    #[test]
+    fn extension_is_registered_once_its_capabilities_are_granted() {
+        KV::init_in_memory().unwrap();
+        revoke_extension("notes");
+        let dir = extension_dir(MANIFEST);
+        let denying = CountingPrompt {
+            grant: false,
+            asked: AtomicUsize::new(0),
+        };
+        assert!(load_extension(&dir, &denying).is_err());
+
+        let granting = CountingPrompt {
+            grant: true,
+            asked: AtomicUsize::new(0),
+        };
+        let plugin = load_extension(&dir, &granting).unwrap();
+        assert_eq!(plugin.events(), vec!["notes/read".to_owned()]);
+
+        // The user isn't asked again for the granted capabilities.
+        assert!(load_extension(&dir, &granting).is_ok());
+        assert_eq!(granting.asked.load(Ordering::SeqCst), 1);
+        revoke_extension("notes");
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn invalid_manifest_is_rejected_before_the_prompt() {
+        let prompt = CountingPrompt {
+            grant: true,
+            asked: AtomicUsize::new(0),
+        };
+        for manifest in &[
+            MANIFEST.replace("0.0.1", "999.0.0"),
+            MANIFEST.replace("\"notes/read\"", "\"read\""),
+            MANIFEST.replace("storage", "network"),
+            MANIFEST.replace("notes.wasm", "../notes.wasm"),
+        ] {
+            let dir = extension_dir(manifest);
+            assert!(load_extension(&dir, &prompt).is_err());
+            let _ = std::fs::remove_dir_all(dir);
+        }
+        assert_eq!(prompt.asked.load(Ordering::SeqCst), 0);
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(feature = "extensions")]
+pub mod extension;
+#[cfg(feature = "scripting")]
+pub mod script;
+#[cfg(feature = "wasm_plugins")]
+pub mod wasm;
+#[cfg(feature = "webhooks")]
+pub mod webhook;
+
+use flowy_database::KVStore;
+use flowy_dispatch::prelude::{Event, EventDispatch, EventMiddleware, Module};
+use flowy_infra::{clock::Clock, secret::SecretStore};
+use std::{collections::HashSet, fmt, sync::Arc};
+
+// What the sdk shares with the plugins when their modules are built.
+#[derive(Clone)]
+pub struct PluginContext {
+    // The directory of the sdk, the plugin keeps its files in its own directory
+    // in it.
+    pub root: String,
+    pub kv_store: KVStore,
+    pub clock: Arc<dyn Clock>,
+    // Where the plugin keeps its credentials, instead of the kv_store.
+    pub secret_store: Arc<dyn SecretStore>,
+}
+
+// A module of another crate, e.g. a time tracking module, that's dispatched
+// like the modules of the sdk. Its events are sent by their names, like the
+// ones of the system module. The hooks have defaults, so the plugins still
+// build when new ones are added.
+//
+//     let config = FlowySDKConfig::new(root, server_config, name)
+//         .plugin(Arc::new(TimeTracking));
+pub trait FlowyPlugin: Send + Sync {
+    // The name of the module, unique among the plugins.
+    fn name(&self) -> &str;
+
+    // The events the module registers. The plugin is left out if one of them is
+    // registered by the sdk or by another plugin, or if its module registers
+    // others.
+    fn events(&self) -> Vec<String>;
+
+    fn build_module(&self, context: &PluginContext) -> Module;
+
+    // Sees the responses of all the events, e.g. to react to the ones of the
+    // sdk. It's only asked for once the module is built.
+    fn middleware(&self) -> Option<Arc<dyn EventMiddleware>> { None }
+
+    // Called once the dispatcher is built, e.g. to spawn the tasks of the plugin.
+    fn on_start(&self, _dispatch: Arc<EventDispatch>) {}
+
+    // Called after the user signed in or up, the data of the previous user
+    // isn't there anymore.
+    fn on_sign_in(&self, _user_id: &str) {}
+
+    fn on_sign_out(&self) {}
+}
+
+impl fmt::Debug for dyn FlowyPlugin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "FlowyPlugin({})", self.name()) }
+}
+
+// The modules of the plugins whose events don't collide, with the plugins.
+pub(crate) fn mk_plugin_modules(
+    plugins: &[Arc<dyn FlowyPlugin>],
+    modules: &[Module],
+    context: &PluginContext,
+) -> (Vec<Module>, Vec<Arc<dyn FlowyPlugin>>) {
+    let mut registered = modules
+        .iter()
+        .flat_map(|module| module.events())
+        .collect::<HashSet<Event>>();
+    let mut names: HashSet<String> = HashSet::new();
+    let mut plugin_modules = vec![];
+    let mut accepted = vec![];
+    for plugin in plugins {
+        let events = plugin.events().into_iter().map(Event::from).collect::<HashSet<_>>();
+        if names.contains(plugin.name()) {
+            tracing::error!("The plugin {} is registered twice", plugin.name());
+            continue;
+        }
+        if let Some(event) = events.iter().find(|event| registered.contains(event)) {
+            tracing::error!(
+                "The event {:?} of the plugin {} is already registered",
+                event,
+                plugin.name()
+            );
+            continue;
+        }
+        let module = plugin.build_module(context).name(plugin.name());
+        if let Some(event) = module.events().into_iter().find(|event| !events.contains(event)) {
+            tracing::error!("The plugin {} didn't declare its event {:?}", plugin.name(), event);
+            continue;
+        }
+        names.insert(plugin.name().to_owned());
+        registered.extend(events);
+        plugin_modules.push(module);
+        accepted.push(plugin.clone());
+    }
+    (plugin_modules, accepted)
+}
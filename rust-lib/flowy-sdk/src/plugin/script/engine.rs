@@ -0,0 +1,158 @@
+use chrono::TimeZone;
+use flowy_dispatch::prelude::*;
+use flowy_document_infra::entities::doc::DocDelta;
+use flowy_infra::clock::Clock;
+use flowy_ot::core::{Attributes, Delta};
+use flowy_workspace::{
+    event::WorkspaceEvent,
+    prelude::{QueryViewRequest, WorkspaceError},
+};
+use rhai::{Engine, EvalAltResult, Map, Scope};
+use std::{
+    sync::{Arc, Weak},
+    time::{Duration, Instant},
+};
+
+// A script is stopped after this many operations, or once it ran this long,
+// whichever comes first.
+const MAX_OPERATIONS: u64 = 1_000_000;
+const SCRIPT_TIMEOUT: Duration = Duration::from_millis(500);
+
+// What the scripts can do besides computing, the engine has no access to the
+// files or the network.
+#[derive(Clone)]
+pub(crate) struct ScriptActions {
+    pub(crate) clock: Arc<dyn Clock>,
+    pub(crate) dispatch: Weak<EventDispatch>,
+}
+
+// Returns the error of the script that doesn't compile.
+pub(crate) fn check(source: &str) -> Result<(), String> {
+    sandboxed_engine()
+        .compile(source)
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+pub(crate) fn run(source: &str, actions: &ScriptActions, variable: (&'static str, Map)) -> Result<(), String> {
+    let mut engine = sandboxed_engine();
+    register_actions(&mut engine, actions);
+    let ast = engine.compile(source).map_err(|e| e.to_string())?;
+    let mut scope = Scope::new();
+    scope.push(variable.0, variable.1);
+    engine.run_ast_with_scope(&mut scope, &ast).map_err(|e| e.to_string())
+}
+
+fn sandboxed_engine() -> Engine {
+    let mut engine = Engine::new();
+    let deadline = Instant::now() + SCRIPT_TIMEOUT;
+    engine
+        .set_max_operations(MAX_OPERATIONS)
+        .set_max_call_levels(16)
+        .set_max_expr_depths(64, 32)
+        .set_max_string_size(64 * 1024)
+        .set_max_array_size(10_000)
+        .set_max_map_size(10_000)
+        .on_progress(move |_| match Instant::now() > deadline {
+            true => Some("The script timed out".into()),
+            false => None,
+        });
+    engine.disable_symbol("eval");
+    engine
+}
+
+// log(message), today() as 2021-09-30, now() in seconds, and
+// append_text(view_id, text) to the end of the document of the view.
+fn register_actions(engine: &mut Engine, actions: &ScriptActions) {
+    engine.register_fn("log", |message: &str| tracing::info!("Script: {}", message));
+
+    let clock = actions.clock.clone();
+    engine.register_fn("today", move || {
+        chrono::Local
+            .timestamp_millis(clock.timestamp_millis())
+            .format("%Y-%m-%d")
+            .to_string()
+    });
+
+    let clock = actions.clock.clone();
+    engine.register_fn("now", move || clock.timestamp());
+
+    let dispatch = actions.dispatch.clone();
+    engine.register_result_fn(
+        "append_text",
+        move |view_id: &str, text: &str| -> Result<(), Box<EvalAltResult>> {
+            append_text(&dispatch, view_id, text).map_err(|e| e.into())
+        },
+    );
+}
+
+fn append_text(dispatch: &Weak<EventDispatch>, view_id: &str, text: &str) -> Result<(), String> {
+    let dispatch = dispatch.upgrade().ok_or("The dispatcher is stopped")?;
+    let query = QueryViewRequest {
+        view_ids: vec![view_id.to_owned()],
+    };
+    let doc: DocDelta = send(&dispatch, WorkspaceEvent::OpenView, query)?;
+    let len = Delta::from_json(&doc.data).map_err(|e| format!("{:?}", e))?.target_len;
+
+    // The text goes before the newline that ends the document.
+    let mut delta = Delta::new();
+    delta.retain(len.saturating_sub(1), Attributes::default());
+    delta.insert(text, Attributes::default());
+    let delta = DocDelta {
+        doc_id: view_id.to_owned(),
+        data: delta.to_json(),
+    };
+    let _: DocDelta = send(&dispatch, WorkspaceEvent::ApplyDocDelta, delta)?;
+    Ok(())
+}
+
+// The scripts run on the blocking threads, they wait for the responses there.
+fn send<T, R>(dispatch: &Arc<EventDispatch>, event: WorkspaceEvent, request: R) -> Result<T, String>
+where
+    T: FromBytes,
+    R: ToBytes,
+{
+    let payload = request.into_bytes().map_err(|e| format!("{:?}", e))?;
+    let response = EventDispatch::sync_send(dispatch.clone(), ModuleRequest::new(event).payload(payload));
+    match response.parse::<T, WorkspaceError>() {
+        Ok(Ok(data)) => Ok(data),
+        Ok(Err(e)) => Err(format!("{:?}", e)),
+        Err(e) => Err(format!("{:?}", e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flowy_infra::clock::system_clock;
+
+    fn actions() -> ScriptActions {
+        ScriptActions {
+            clock: system_clock(),
+            dispatch: Weak::new(),
+        }
+    }
+
+    fn view(name: &str) -> (&'static str, Map) {
+        let mut view = Map::new();
+        view.insert("name".into(), name.to_owned().into());
+        ("view", view)
+    }
+
+    #[test]
+    fn script_sees_the_item_of_its_trigger() {
+        let source = r#"if view.name != "Daily" { throw "not the daily view" } log(today());"#;
+        assert!(run(source, &actions(), view("Daily")).is_ok());
+        assert!(run(source, &actions(), view("Weekly")).is_err());
+    }
+
+    #[test]
+    fn script_is_stopped_by_the_sandbox() {
+        assert!(check("let x = ;").is_err());
+        assert!(run("loop { }", &actions(), view("Daily")).is_err());
+        assert!(run(r#"eval("1")"#, &actions(), view("Daily")).is_err());
+        // The dispatcher isn't started, the action fails instead of the script
+        // reaching the workspace.
+        assert!(run(r#"append_text(view.name, "text")"#, &actions(), view("Daily")).is_err());
+    }
+}
@@ -0,0 +1,189 @@
+mod engine;
+mod trigger;
+
+pub use trigger::ScriptTrigger;
+
+use crate::plugin::{FlowyPlugin, PluginContext};
+use bytes::Bytes;
+use engine::ScriptActions;
+use flowy_database::KVStore;
+use flowy_dispatch::prelude::*;
+use futures_core::future::BoxFuture;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    sync::{Arc, Weak},
+};
+use trigger::triggered_payload;
+
+const SCRIPT_KEY_PREFIX: &str = "script/";
+
+// A script the user attached to an event of the workspace, e.g. the one that
+// inserts the template of the day in the views named Daily:
+//
+//     if view.name == "Daily" { append_text(view.id, "# " + today()) }
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct Script {
+    // Generated when the script is saved the first time.
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    // The name of the ScriptTrigger, e.g. view_created.
+    pub trigger: String,
+    pub source: String,
+    #[serde(default = "enabled_by_default")]
+    pub enabled: bool,
+}
+
+fn enabled_by_default() -> bool { true }
+
+// The events of the script module. Their payloads are json, the scripts are
+// edited by the user instead of being generated.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum ScriptEvent {
+    // Saves the Script, its source is compiled first. Returns the saved Script.
+    Save,
+
+    // Returns the Scripts of the user.
+    List,
+
+    // Deletes the Script with the id of the payload.
+    Delete,
+}
+
+impl fmt::Display for ScriptEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScriptEvent::Save => f.write_str("script/save"),
+            ScriptEvent::List => f.write_str("script/list"),
+            ScriptEvent::Delete => f.write_str("script/delete"),
+        }
+    }
+}
+
+// Runs the scripts of the user after the events of their triggers, in a
+// sandboxed Rhai engine with a time limit. The scripts are kept in the kv of
+// the user, they're managed with the ScriptEvents.
+#[derive(Default)]
+pub struct ScriptPlugin {
+    host: Arc<ScriptHost>,
+}
+
+impl FlowyPlugin for ScriptPlugin {
+    fn name(&self) -> &str { "script" }
+
+    fn events(&self) -> Vec<String> {
+        [ScriptEvent::Save, ScriptEvent::List, ScriptEvent::Delete]
+            .iter()
+            .map(|event| event.to_string())
+            .collect()
+    }
+
+    fn build_module(&self, context: &PluginContext) -> Module {
+        *self.host.context.write() = Some(context.clone());
+        Module::new()
+            .event(ScriptEvent::Save, save_script_handler)
+            .event(ScriptEvent::List, list_scripts_handler)
+            .event(ScriptEvent::Delete, delete_script_handler)
+    }
+
+    fn middleware(&self) -> Option<Arc<dyn EventMiddleware>> {
+        Some(Arc::new(ScriptHooks {
+            host: self.host.clone(),
+        }))
+    }
+
+    fn on_start(&self, dispatch: Arc<EventDispatch>) { *self.host.dispatch.write() = Arc::downgrade(&dispatch); }
+}
+
+#[derive(Default)]
+struct ScriptHost {
+    context: RwLock<Option<PluginContext>>,
+    dispatch: RwLock<Weak<EventDispatch>>,
+}
+
+impl ScriptHost {
+    fn run_scripts(&self, trigger: ScriptTrigger, payload: Bytes) {
+        let context = match self.context.read().clone() {
+            Some(context) => context,
+            None => return,
+        };
+        let scripts = match read_scripts(&context.kv_store) {
+            Ok(scripts) => scripts,
+            Err(e) => {
+                tracing::error!("Read the scripts failed: {}", e);
+                return;
+            },
+        };
+        let actions = ScriptActions {
+            clock: context.clock.clone(),
+            dispatch: self.dispatch.read().clone(),
+        };
+        let scripts = scripts
+            .iter()
+            .filter(|script| script.enabled && script.trigger == trigger.name());
+        for script in scripts {
+            let variable = match trigger.variable(payload.clone()) {
+                Some(variable) => variable,
+                None => return,
+            };
+            if let Err(e) = engine::run(&script.source, &actions, variable) {
+                tracing::error!("The script {} failed: {}", script.name, e);
+            }
+        }
+    }
+}
+
+// The scripts run after the response is returned, they don't slow the event
+// down and they can't fail it.
+struct ScriptHooks {
+    host: Arc<ScriptHost>,
+}
+
+impl EventMiddleware for ScriptHooks {
+    fn intercept(&self, request: &ModuleRequest, response: &EventResponse) -> BoxFuture<'static, bool> {
+        let triggered = triggered_payload(request, response);
+        let host = self.host.clone();
+        Box::pin(async move {
+            if let Some((trigger, payload)) = triggered {
+                tokio::task::spawn_blocking(move || host.run_scripts(trigger, payload));
+            }
+            false
+        })
+    }
+}
+
+fn read_scripts(kv_store: &KVStore) -> Result<Vec<Script>, String> {
+    kv_store
+        .scan_prefix::<Script>(SCRIPT_KEY_PREFIX)
+        .map(|scripts| scripts.into_iter().map(|(_, script)| script).collect())
+        .map_err(|e| format!("{:?}", e))
+}
+
+fn script_key(id: &str) -> String { format!("{}{}", SCRIPT_KEY_PREFIX, id) }
+
+async fn save_script_handler(data: String, kv_store: Unit<KVStore>) -> Result<String, DispatchError> {
+    let mut script: Script = serde_json::from_str(&data).map_err(|e| format!("Invalid script: {}", e))?;
+    if ScriptTrigger::from_name(&script.trigger).is_none() {
+        return Err(format!("Unknown trigger: {}", script.trigger).into());
+    }
+    engine::check(&script.source)?;
+    if script.id.is_empty() {
+        script.id = flowy_infra::uuid();
+    }
+    kv_store
+        .set(&script_key(&script.id), &script)
+        .map_err(|e| format!("{:?}", e))?;
+    Ok(serde_json::to_string(&script).map_err(|e| e.to_string())?)
+}
+
+async fn list_scripts_handler(kv_store: Unit<KVStore>) -> Result<String, DispatchError> {
+    let scripts = read_scripts(&kv_store)?;
+    Ok(serde_json::to_string(&scripts).map_err(|e| e.to_string())?)
+}
+
+async fn delete_script_handler(id: String, kv_store: Unit<KVStore>) -> Result<(), DispatchError> {
+    kv_store.delete(&script_key(&id)).map_err(|e| format!("{:?}", e))?;
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use bytes::Bytes;
+use flowy_dispatch::prelude::*;
+use flowy_workspace::{
+    event::WorkspaceEvent,
+    prelude::{App, View, Workspace},
+};
+use rhai::Map;
+use std::convert::TryFrom;
+
+// The events of the workspace the scripts are attached to. The script sees the
+// item of the response, e.g. the view that was created is the view variable.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ScriptTrigger {
+    ViewCreated,
+    ViewUpdated,
+    AppCreated,
+    WorkspaceCreated,
+}
+
+const TRIGGERS: &[ScriptTrigger] = &[
+    ScriptTrigger::ViewCreated,
+    ScriptTrigger::ViewUpdated,
+    ScriptTrigger::AppCreated,
+    ScriptTrigger::WorkspaceCreated,
+];
+
+impl ScriptTrigger {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ScriptTrigger::ViewCreated => "view_created",
+            ScriptTrigger::ViewUpdated => "view_updated",
+            ScriptTrigger::AppCreated => "app_created",
+            ScriptTrigger::WorkspaceCreated => "workspace_created",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> { TRIGGERS.iter().find(|trigger| trigger.name() == name).copied() }
+
+    // The trigger of the request, if it's one of the events the scripts are
+    // attached to.
+    pub(crate) fn of(event: &Event) -> Option<Self> {
+        TRIGGERS
+            .iter()
+            .find(|trigger| Event::from(trigger.event()) == *event)
+            .copied()
+    }
+
+    fn event(&self) -> WorkspaceEvent {
+        match self {
+            ScriptTrigger::ViewCreated => WorkspaceEvent::CreateView,
+            ScriptTrigger::ViewUpdated => WorkspaceEvent::UpdateView,
+            ScriptTrigger::AppCreated => WorkspaceEvent::CreateApp,
+            ScriptTrigger::WorkspaceCreated => WorkspaceEvent::CreateWorkspace,
+        }
+    }
+
+    // The name and the value of the variable the scripts see, decoded from the
+    // payload of the response.
+    pub(crate) fn variable(&self, payload: Bytes) -> Option<(&'static str, Map)> {
+        match self {
+            ScriptTrigger::ViewCreated | ScriptTrigger::ViewUpdated => {
+                let view = View::try_from(payload).ok()?;
+                Some(("view", view_variable(view)))
+            },
+            ScriptTrigger::AppCreated => {
+                let app = App::try_from(payload).ok()?;
+                Some(("app", app_variable(app)))
+            },
+            ScriptTrigger::WorkspaceCreated => {
+                let workspace = Workspace::try_from(payload).ok()?;
+                Some(("workspace", workspace_variable(workspace)))
+            },
+        }
+    }
+}
+
+// The payload of the response, if the scripts of the trigger run after it.
+pub(crate) fn triggered_payload(request: &ModuleRequest, response: &EventResponse) -> Option<(ScriptTrigger, Bytes)> {
+    if response.status_code != StatusCode::Ok {
+        return None;
+    }
+    let trigger = ScriptTrigger::of(&request.event)?;
+    Some((trigger, response.payload.to_bytes()?))
+}
+
+fn view_variable(view: View) -> Map {
+    let mut map = Map::new();
+    map.insert("id".into(), view.id.into());
+    map.insert("belong_to_id".into(), view.belong_to_id.into());
+    map.insert("name".into(), view.name.into());
+    map.insert("desc".into(), view.desc.into());
+    map.insert("view_type".into(), format!("{:?}", view.view_type).into());
+    map
+}
+
+fn app_variable(app: App) -> Map {
+    let mut map = Map::new();
+    map.insert("id".into(), app.id.into());
+    map.insert("workspace_id".into(), app.workspace_id.into());
+    map.insert("name".into(), app.name.into());
+    map.insert("desc".into(), app.desc.into());
+    map
+}
+
+fn workspace_variable(workspace: Workspace) -> Map {
+    let mut map = Map::new();
+    map.insert("id".into(), workspace.id.into());
+    map.insert("name".into(), workspace.name.into());
+    map.insert("desc".into(), workspace.desc.into());
+    map
+}
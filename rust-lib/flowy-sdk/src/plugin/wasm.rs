@@ -0,0 +1,579 @@
+use crate::{
+    notify::{dart_notify, SDKNotification},
+    plugin::{FlowyPlugin, PluginContext},
+};
+use bytes::Bytes;
+use flowy_database::KVStore;
+use flowy_dispatch::prelude::*;
+use parking_lot::Mutex;
+use std::{
+    collections::HashSet,
+    future::{ready, Ready},
+    path::Path,
+    sync::{Arc, Weak},
+};
+use wasmtime::{
+    AsContext,
+    AsContextMut,
+    Caller,
+    Config,
+    Engine,
+    Extern,
+    Instance,
+    Linker,
+    Memory,
+    Store,
+    StoreLimits,
+    StoreLimitsBuilder,
+    Trap,
+    TypedFunc,
+};
+
+// About how many instructions a handler runs before it's stopped.
+const MAX_CALL_FUEL: u64 = 100_000_000;
+
+// The memory of a plugin can't grow over it.
+const MAX_PLUGIN_MEMORY: usize = 64 * 1024 * 1024;
+
+// The biggest buffer that is copied in or out of the memory of a plugin.
+const MAX_MESSAGE_SIZE: usize = 16 * 1024 * 1024;
+
+// Returned instead of a buffer when there's none, e.g. the key isn't in the kv.
+const NONE: i64 = -1;
+
+// A plugin compiled to WebAssembly, it's loaded at runtime instead of being
+// built with the sdk. It can only reach the sdk through the imports of the
+// "flowy" module, and each call has its fuel, so a plugin that loops is
+// stopped instead of blocking its events.
+//
+// The buffers are passed as a pointer and a length in the memory of the
+// plugin, packed in an i64 with the pointer in the high half when they're
+// returned. The responses are the status code in their first byte, then the
+// payload.
+//
+// The plugin exports:
+//   memory
+//   flowy_alloc(len: i32) -> i32: a buffer the host copies its bytes to.
+//   flowy_events() -> i64: the names of its events, one per line.
+//   flowy_handle(event_ptr, event_len, payload_ptr, payload_len) -> i64: the
+//     response to the event.
+//
+// The host imports:
+//   dispatch(event_ptr, event_len, payload_ptr, payload_len) -> i64: the
+//     response to the event, sent to the dispatcher. The plugin can't send its
+//     own events, its handler is running.
+//   kv_get(key_ptr, key_len) -> i64: the value, or -1 if it's not set.
+//   kv_set(key_ptr, key_len, value_ptr, value_len) -> i32: 0, or -1 if it
+//     failed. The keys are only the ones of the plugin.
+//   notify(id_ptr, id_len, payload_ptr, payload_len): a PluginNotification,
+//     its id is prefixed with the name of the plugin.
+//
+// The imports of the Capabilities the plugin wasn't granted trap.
+pub struct WasmPlugin {
+    name: String,
+    events: Vec<String>,
+    instance: Arc<Mutex<PluginInstance>>,
+}
+
+impl WasmPlugin {
+    // Loads the module of the file, the plugin is named after it and has all the
+    // capabilities.
+    pub fn load(path: &Path) -> Result<Arc<Self>, String> {
+        let name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .ok_or(format!("Invalid plugin path: {:?}", path))?;
+        let bytes = std::fs::read(path).map_err(|e| format!("Read {:?} failed: {}", path, e))?;
+        Self::from_bytes(name, &bytes)
+    }
+
+    // The module is in the binary or in the text format.
+    pub fn from_bytes(name: &str, bytes: &[u8]) -> Result<Arc<Self>, String> {
+        Self::with_capabilities(name, bytes, Capability::ALL)
+    }
+
+    pub fn with_capabilities(name: &str, bytes: &[u8], capabilities: &[Capability]) -> Result<Arc<Self>, String> {
+        let mut instance = PluginInstance::new(name, bytes, capabilities)?;
+        let events = instance.events()?;
+        instance.store.data_mut().events = events.iter().cloned().collect();
+        Ok(Arc::new(Self {
+            name: name.to_owned(),
+            events,
+            instance: Arc::new(Mutex::new(instance)),
+        }))
+    }
+}
+
+impl FlowyPlugin for WasmPlugin {
+    fn name(&self) -> &str { &self.name }
+
+    fn events(&self) -> Vec<String> { self.events.clone() }
+
+    fn build_module(&self, context: &PluginContext) -> Module {
+        self.instance.lock().store.data_mut().kv_store = Some(context.kv_store.clone());
+        self.events.iter().fold(Module::new(), |module, event| {
+            let (instance, event_name) = (self.instance.clone(), event.clone());
+            module.event(event.clone(), move |payload: WasmPayload| {
+                let (instance, event_name) = (instance.clone(), event_name.clone());
+                async move { handle(instance, event_name, payload.0.unwrap_or_default()).await }
+            })
+        })
+    }
+
+    fn on_start(&self, dispatch: Arc<EventDispatch>) {
+        self.instance.lock().store.data_mut().dispatch = Arc::downgrade(&dispatch);
+    }
+}
+
+// The plugins of the .wasm files in the directory. The ones that don't load are
+// logged and left out.
+pub fn load_wasm_plugins(dir: &str) -> Vec<Arc<dyn FlowyPlugin>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            tracing::error!("Read the plugins in {} failed: {:?}", dir, e);
+            return vec![];
+        },
+    };
+    let mut plugins: Vec<Arc<dyn FlowyPlugin>> = vec![];
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if path.extension().and_then(|extension| extension.to_str()) != Some("wasm") {
+            continue;
+        }
+        match WasmPlugin::load(&path) {
+            Ok(plugin) => plugins.push(plugin),
+            Err(e) => tracing::error!("Load the plugin {:?} failed: {}", path, e),
+        }
+    }
+    plugins
+}
+
+// The handlers run one at a time on the blocking threads, the dispatch import
+// waits for its response there.
+async fn handle(
+    instance: Arc<Mutex<PluginInstance>>,
+    event: String,
+    payload: Bytes,
+) -> Result<EventResponse, DispatchError> {
+    match tokio::task::spawn_blocking(move || instance.lock().call(&event, &payload)).await {
+        Ok(Ok((status_code, payload))) => Ok(EventResponse {
+            payload: payload.into(),
+            status_code,
+        }),
+        Ok(Err(e)) => Err(e.into()),
+        Err(e) => Err(InternalError::from(e).into()),
+    }
+}
+
+// The raw payload of the request, the plugin parses it.
+struct WasmPayload(Option<Bytes>);
+
+impl FromRequest for WasmPayload {
+    type Error = DispatchError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(_req: &EventRequest, payload: &mut Payload) -> Self::Future {
+        ready(Ok(WasmPayload(payload.to_bytes())))
+    }
+}
+
+// What a plugin can reach through the imports of the host.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Capability {
+    // The dispatch import, the plugin sends the events of the sdk.
+    Dispatch,
+
+    // The kv_get and kv_set imports.
+    Storage,
+
+    // The notify import.
+    Notify,
+}
+
+impl Capability {
+    pub const ALL: &'static [Capability] = &[Capability::Dispatch, Capability::Storage, Capability::Notify];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Capability::Dispatch => "dispatch",
+            Capability::Storage => "storage",
+            Capability::Notify => "notify",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Capability::ALL
+            .iter()
+            .find(|capability| capability.name() == name)
+            .copied()
+    }
+}
+
+struct HostState {
+    plugin: String,
+    events: HashSet<String>,
+    capabilities: HashSet<Capability>,
+    kv_store: Option<KVStore>,
+    dispatch: Weak<EventDispatch>,
+    limits: StoreLimits,
+}
+
+struct PluginInstance {
+    store: Store<HostState>,
+    guest: Guest,
+    handle: TypedFunc<(i32, i32, i32, i32), i64>,
+    fuel_added: u64,
+}
+
+impl HostState {
+    fn check(&self, capability: Capability) -> Result<(), Trap> {
+        match self.capabilities.contains(&capability) {
+            true => Ok(()),
+            false => Err(Trap::new(format!(
+                "The plugin {} wasn't granted {}",
+                self.plugin,
+                capability.name()
+            ))),
+        }
+    }
+}
+
+impl PluginInstance {
+    fn new(name: &str, bytes: &[u8], capabilities: &[Capability]) -> Result<Self, String> {
+        let mut config = Config::new();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config).map_err(|e| e.to_string())?;
+        let module = wasmtime::Module::new(&engine, bytes).map_err(|e| e.to_string())?;
+        let state = HostState {
+            plugin: name.to_owned(),
+            events: HashSet::new(),
+            capabilities: capabilities.iter().copied().collect(),
+            kv_store: None,
+            dispatch: Weak::new(),
+            limits: StoreLimitsBuilder::new().memory_size(MAX_PLUGIN_MEMORY).build(),
+        };
+        let mut store = Store::new(&engine, state);
+        store.limiter(|state| &mut state.limits);
+        // The start function of the module runs with the fuel of a call.
+        store.add_fuel(MAX_CALL_FUEL).map_err(|e| e.to_string())?;
+
+        let mut linker = Linker::new(&engine);
+        link_host(&mut linker).map_err(|e| e.to_string())?;
+        let instance = linker.instantiate(&mut store, &module).map_err(|e| e.to_string())?;
+        let guest = Guest::from_instance(&mut store, &instance)?;
+        let handle = instance
+            .get_typed_func::<(i32, i32, i32, i32), i64, _>(&mut store, "flowy_handle")
+            .map_err(|e| e.to_string())?;
+        Ok(Self {
+            store,
+            guest,
+            handle,
+            fuel_added: MAX_CALL_FUEL,
+        })
+    }
+
+    fn events(&mut self) -> Result<Vec<String>, String> {
+        // The instance was just created, the fuel of the start function is left.
+        let events = self.guest.events.call(&mut self.store, ()).map_err(|e| e.to_string())?;
+        let events = self.guest.read_packed(&self.store, events)?;
+        let events = String::from_utf8(events).map_err(|e| e.to_string())?;
+        Ok(events
+            .lines()
+            .map(|event| event.trim())
+            .filter(|event| !event.is_empty())
+            .map(|event| event.to_owned())
+            .collect())
+    }
+
+    // Returns the status code and the payload of the response. The instance
+    // keeps its state if the handler traps, e.g. when it's out of fuel.
+    fn call(&mut self, event: &str, payload: &[u8]) -> Result<(StatusCode, Vec<u8>), String> {
+        self.refuel()?;
+        let guest = self.guest.clone();
+        let (event_ptr, event_len) = guest.write(&mut self.store, event.as_bytes())?;
+        let (payload_ptr, payload_len) = guest.write(&mut self.store, payload)?;
+        let response = self
+            .handle
+            .call(&mut self.store, (event_ptr, event_len, payload_ptr, payload_len))
+            .map_err(|e| {
+                format!(
+                    "The plugin {} failed to handle {}: {}",
+                    self.store.data().plugin,
+                    event,
+                    e
+                )
+            })?;
+        let response = guest.read_packed(&self.store, response)?;
+        decode_response(response)
+    }
+
+    // Tops the fuel up to the one of a call, what's left of the last one isn't
+    // carried over.
+    fn refuel(&mut self) -> Result<(), String> {
+        let consumed = self.store.fuel_consumed().unwrap_or(0);
+        let left = self.fuel_added.saturating_sub(consumed).min(MAX_CALL_FUEL);
+        self.store.add_fuel(MAX_CALL_FUEL - left).map_err(|e| e.to_string())?;
+        self.fuel_added += MAX_CALL_FUEL - left;
+        Ok(())
+    }
+}
+
+// The exports of the plugin the host calls.
+#[derive(Clone)]
+struct Guest {
+    memory: Memory,
+    alloc: TypedFunc<i32, i32>,
+    events: TypedFunc<(), i64>,
+}
+
+impl Guest {
+    fn from_instance(store: &mut Store<HostState>, instance: &Instance) -> Result<Self, String> {
+        let memory = instance
+            .get_memory(&mut *store, "memory")
+            .ok_or("The plugin doesn't export its memory")?;
+        let alloc = instance
+            .get_typed_func::<i32, i32, _>(&mut *store, "flowy_alloc")
+            .map_err(|e| e.to_string())?;
+        let events = instance
+            .get_typed_func::<(), i64, _>(&mut *store, "flowy_events")
+            .map_err(|e| e.to_string())?;
+        Ok(Self { memory, alloc, events })
+    }
+
+    fn from_caller(caller: &mut Caller<'_, HostState>) -> Result<Self, Trap> {
+        let memory = caller
+            .get_export("memory")
+            .and_then(Extern::into_memory)
+            .ok_or_else(|| Trap::new("The plugin doesn't export its memory"))?;
+        let alloc = caller
+            .get_export("flowy_alloc")
+            .and_then(Extern::into_func)
+            .ok_or_else(|| Trap::new("The plugin doesn't export flowy_alloc"))?
+            .typed::<i32, i32, _>(&*caller)
+            .map_err(|e| Trap::new(e.to_string()))?;
+        let events = caller
+            .get_export("flowy_events")
+            .and_then(Extern::into_func)
+            .ok_or_else(|| Trap::new("The plugin doesn't export flowy_events"))?
+            .typed::<(), i64, _>(&*caller)
+            .map_err(|e| Trap::new(e.to_string()))?;
+        Ok(Self { memory, alloc, events })
+    }
+
+    fn read(&self, store: impl AsContext, ptr: i32, len: i32) -> Result<Vec<u8>, String> {
+        if len < 0 || len as usize > MAX_MESSAGE_SIZE {
+            return Err(format!("Invalid buffer length: {}", len));
+        }
+        let mut buf = vec![0; len as usize];
+        self.memory
+            .read(store, ptr as u32 as usize, &mut buf)
+            .map_err(|e| e.to_string())?;
+        Ok(buf)
+    }
+
+    fn read_packed(&self, store: impl AsContext, packed: i64) -> Result<Vec<u8>, String> {
+        let (ptr, len) = unpack(packed);
+        self.read(store, ptr, len)
+    }
+
+    fn read_str(&self, store: impl AsContext, ptr: i32, len: i32) -> Result<String, String> {
+        String::from_utf8(self.read(store, ptr, len)?).map_err(|e| e.to_string())
+    }
+
+    // Copies the bytes to a buffer of the plugin, returns its pointer and length.
+    fn write(&self, mut store: impl AsContextMut, bytes: &[u8]) -> Result<(i32, i32), String> {
+        if bytes.len() > MAX_MESSAGE_SIZE {
+            return Err(format!("Invalid buffer length: {}", bytes.len()));
+        }
+        let len = bytes.len() as i32;
+        let ptr = self.alloc.call(&mut store, len).map_err(|e| e.to_string())?;
+        self.memory
+            .write(&mut store, ptr as u32 as usize, bytes)
+            .map_err(|e| e.to_string())?;
+        Ok((ptr, len))
+    }
+}
+
+fn link_host(linker: &mut Linker<HostState>) -> anyhow::Result<()> {
+    linker.func_wrap(
+        "flowy",
+        "dispatch",
+        |mut caller: Caller<'_, HostState>,
+         event_ptr: i32,
+         event_len: i32,
+         payload_ptr: i32,
+         payload_len: i32|
+         -> Result<i64, Trap> {
+            caller.data().check(Capability::Dispatch)?;
+            let guest = Guest::from_caller(&mut caller)?;
+            let event = guest.read_str(&caller, event_ptr, event_len).map_err(Trap::new)?;
+            let payload = guest.read(&caller, payload_ptr, payload_len).map_err(Trap::new)?;
+            let response = dispatch(caller.data(), event, payload);
+            let (ptr, len) = guest
+                .write(&mut caller, &encode_response(response))
+                .map_err(Trap::new)?;
+            Ok(pack(ptr, len))
+        },
+    )?;
+
+    linker.func_wrap(
+        "flowy",
+        "kv_get",
+        |mut caller: Caller<'_, HostState>, key_ptr: i32, key_len: i32| -> Result<i64, Trap> {
+            caller.data().check(Capability::Storage)?;
+            let guest = Guest::from_caller(&mut caller)?;
+            let key = guest.read_str(&caller, key_ptr, key_len).map_err(Trap::new)?;
+            let state = caller.data();
+            let value = match state.kv_store.as_ref() {
+                None => return Ok(NONE),
+                Some(kv_store) => kv_store.get::<Vec<u8>>(&kv_key(&state.plugin, &key)),
+            };
+            match value {
+                Ok(Some(value)) => {
+                    let (ptr, len) = guest.write(&mut caller, &value).map_err(Trap::new)?;
+                    Ok(pack(ptr, len))
+                },
+                Ok(None) => Ok(NONE),
+                Err(e) => {
+                    tracing::error!("The plugin {} failed to read {}: {:?}", state.plugin, key, e);
+                    Ok(NONE)
+                },
+            }
+        },
+    )?;
+
+    linker.func_wrap(
+        "flowy",
+        "kv_set",
+        |mut caller: Caller<'_, HostState>,
+         key_ptr: i32,
+         key_len: i32,
+         value_ptr: i32,
+         value_len: i32|
+         -> Result<i32, Trap> {
+            caller.data().check(Capability::Storage)?;
+            let guest = Guest::from_caller(&mut caller)?;
+            let key = guest.read_str(&caller, key_ptr, key_len).map_err(Trap::new)?;
+            let value = guest.read(&caller, value_ptr, value_len).map_err(Trap::new)?;
+            let state = caller.data();
+            let result = match state.kv_store.as_ref() {
+                None => return Ok(-1),
+                Some(kv_store) => kv_store.set(&kv_key(&state.plugin, &key), &value),
+            };
+            match result {
+                Ok(_) => Ok(0),
+                Err(e) => {
+                    tracing::error!("The plugin {} failed to write {}: {:?}", state.plugin, key, e);
+                    Ok(-1)
+                },
+            }
+        },
+    )?;
+
+    linker.func_wrap(
+        "flowy",
+        "notify",
+        |mut caller: Caller<'_, HostState>,
+         id_ptr: i32,
+         id_len: i32,
+         payload_ptr: i32,
+         payload_len: i32|
+         -> Result<(), Trap> {
+            caller.data().check(Capability::Notify)?;
+            let guest = Guest::from_caller(&mut caller)?;
+            let id = guest.read_str(&caller, id_ptr, id_len).map_err(Trap::new)?;
+            let payload = guest.read(&caller, payload_ptr, payload_len).map_err(Trap::new)?;
+            let id = format!("{}/{}", caller.data().plugin, id);
+            dart_notify(&id, SDKNotification::PluginNotification)
+                .payload_bytes(Bytes::from(payload))
+                .send();
+            Ok(())
+        },
+    )?;
+    Ok(())
+}
+
+fn dispatch(state: &HostState, event: String, payload: Vec<u8>) -> EventResponse {
+    let error = |msg: String| ResponseBuilder::Err().data(msg).build();
+    if state.events.contains(&event) {
+        return error(format!(
+            "The plugin {} can't send its own event {}",
+            state.plugin, event
+        ));
+    }
+    match state.dispatch.upgrade() {
+        Some(dispatch) => EventDispatch::sync_send(dispatch, ModuleRequest::new(event).payload(payload)),
+        None => error(format!("The dispatcher of the plugin {} isn't started", state.plugin)),
+    }
+}
+
+fn kv_key(plugin: &str, key: &str) -> String { format!("plugin/{}/{}", plugin, key) }
+
+fn pack(ptr: i32, len: i32) -> i64 { (((ptr as u32 as u64) << 32) | len as u32 as u64) as i64 }
+
+fn unpack(packed: i64) -> (i32, i32) { ((packed as u64 >> 32) as i32, packed as u32 as i32) }
+
+fn encode_response(response: EventResponse) -> Vec<u8> {
+    let mut bytes = vec![response.status_code as u8];
+    bytes.extend_from_slice(response.payload.as_bytes().unwrap_or_default());
+    bytes
+}
+
+fn decode_response(mut response: Vec<u8>) -> Result<(StatusCode, Vec<u8>), String> {
+    if response.is_empty() {
+        return Err("The plugin returned an empty response".to_owned());
+    }
+    let status_code = match response.remove(0) {
+        0 => StatusCode::Ok,
+        1 => StatusCode::Err,
+        _ => StatusCode::Internal,
+    };
+    Ok((status_code, response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Echoes the payload of echo/echo, spins in echo/loop.
+    const ECHO_PLUGIN: &str = r#"
+    (module
+      (memory (export "memory") 1)
+      (global $next (mut i32) (i32.const 1024))
+      (data (i32.const 0) "echo/echo\necho/loop")
+      (func $alloc (export "flowy_alloc") (param $len i32) (result i32)
+        (local $ptr i32)
+        (local.set $ptr (global.get $next))
+        (global.set $next (i32.add (global.get $next) (local.get $len)))
+        (local.get $ptr))
+      (func (export "flowy_events") (result i64)
+        (i64.const 19))
+      (func (export "flowy_handle")
+        (param $event_ptr i32) (param $event_len i32) (param $payload_ptr i32) (param $payload_len i32)
+        (result i64)
+        (local $ptr i32)
+        (if (i32.eq (i32.load8_u offset=5 (local.get $event_ptr)) (i32.const 108))
+          (then (loop $spin (br $spin))))
+        (local.set $ptr (call $alloc (i32.add (local.get $payload_len) (i32.const 1))))
+        (i32.store8 (local.get $ptr) (i32.const 0))
+        (memory.copy (i32.add (local.get $ptr) (i32.const 1)) (local.get $payload_ptr) (local.get $payload_len))
+        (i64.or
+          (i64.shl (i64.extend_i32_u (local.get $ptr)) (i64.const 32))
+          (i64.extend_i32_u (i32.add (local.get $payload_len) (i32.const 1))))))
+    "#;
+
+    #[test]
+    fn wasm_plugin_handles_its_events_within_its_fuel() {
+        let plugin = WasmPlugin::from_bytes("echo", ECHO_PLUGIN.as_bytes()).unwrap();
+        assert_eq!(plugin.events(), vec!["echo/echo".to_owned(), "echo/loop".to_owned()]);
+
+        let response = plugin.instance.lock().call("echo/echo", b"hello").unwrap();
+        assert_eq!(response, (StatusCode::Ok, b"hello".to_vec()));
+
+        // The handler that doesn't return is stopped, the next events are still
+        // handled.
+        assert!(plugin.instance.lock().call("echo/loop", b"").is_err());
+        let response = plugin.instance.lock().call("echo/echo", b"again").unwrap();
+        assert_eq!(response, (StatusCode::Ok, b"again".to_vec()));
+    }
+}
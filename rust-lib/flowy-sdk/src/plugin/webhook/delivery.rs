@@ -0,0 +1,233 @@
+use crate::plugin::webhook::Webhook;
+use bytes::Bytes;
+use flowy_database::KVStore;
+use flowy_infra::{clock::Clock, secret::sign};
+use serde::{Deserialize, Serialize};
+use std::{sync::Arc, time::Duration};
+
+// A delivery is sent at most this many times, the delay doubles after each
+// failed attempt.
+const MAX_ATTEMPTS: usize = 4;
+pub(crate) const FIRST_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+// How long each attempt waits for the response.
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+// The deliveries of a webhook that are kept, the oldest are dropped.
+const MAX_DELIVERIES: usize = 50;
+
+pub const EVENT_HEADER: &str = "X-Flowy-Event";
+pub const DELIVERY_HEADER: &str = "X-Flowy-Delivery";
+// sha256= and the hex HMAC-SHA256 of the body with the secret of the webhook.
+pub const SIGNATURE_HEADER: &str = "X-Flowy-Signature";
+
+// An event sent to a webhook, with the outcome of its last attempt.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Delivery {
+    pub id: String,
+    pub webhook_id: String,
+    pub event: String,
+    pub timestamp: i64,
+    pub attempts: usize,
+    pub delivered: bool,
+    // The status of the last response, None if the endpoint wasn't reached.
+    pub status: Option<u16>,
+    pub error: Option<String>,
+}
+
+// Posts the body to the url of the webhook. The attempts that fail before the
+// endpoint answers, or with a server error or a 429, are retried.
+pub(crate) async fn deliver(
+    client: &reqwest::Client,
+    clock: &Arc<dyn Clock>,
+    webhook: &Webhook,
+    event: &str,
+    delivery_id: &str,
+    body: Bytes,
+    retry_delay: Duration,
+) -> Delivery {
+    let signature = format!("sha256={}", sign(webhook.secret.as_bytes(), &body));
+    let mut delivery = Delivery {
+        id: delivery_id.to_owned(),
+        webhook_id: webhook.id.clone(),
+        event: event.to_owned(),
+        timestamp: clock.timestamp(),
+        attempts: 0,
+        delivered: false,
+        status: None,
+        error: None,
+    };
+    let mut delay = retry_delay;
+    while delivery.attempts < MAX_ATTEMPTS {
+        if delivery.attempts > 0 {
+            clock.sleep(delay).await;
+            delay *= 2;
+        }
+        delivery.attempts += 1;
+        let result = client
+            .post(&webhook.url)
+            .timeout(DELIVERY_TIMEOUT)
+            .header("Content-Type", "application/json")
+            .header(EVENT_HEADER, event)
+            .header(DELIVERY_HEADER, delivery_id)
+            .header(SIGNATURE_HEADER, &signature)
+            .body(body.clone())
+            .send()
+            .await;
+        match result {
+            Ok(response) => {
+                let status = response.status();
+                delivery.status = Some(status.as_u16());
+                if status.is_success() {
+                    delivery.delivered = true;
+                    delivery.error = None;
+                    break;
+                }
+                delivery.error = Some(format!("The endpoint answered {}", status));
+                // The endpoint won't take the same request later.
+                if status.is_client_error() && status != reqwest::StatusCode::TOO_MANY_REQUESTS {
+                    break;
+                }
+            },
+            Err(e) => {
+                delivery.status = None;
+                delivery.error = Some(e.to_string());
+            },
+        }
+    }
+    delivery
+}
+
+fn deliveries_key(webhook_id: &str) -> String { format!("webhook_deliveries/{}", webhook_id) }
+
+// The newest first.
+pub(crate) fn read_deliveries(kv_store: &KVStore, webhook_id: &str) -> Result<Vec<Delivery>, String> {
+    kv_store
+        .get::<Vec<Delivery>>(&deliveries_key(webhook_id))
+        .map(|deliveries| deliveries.unwrap_or_default())
+        .map_err(|e| format!("{:?}", e))
+}
+
+pub(crate) fn record_delivery(kv_store: &KVStore, delivery: Delivery) -> Result<(), String> {
+    let key = deliveries_key(&delivery.webhook_id);
+    let mut deliveries = read_deliveries(kv_store, &delivery.webhook_id)?;
+    deliveries.insert(0, delivery);
+    deliveries.truncate(MAX_DELIVERIES);
+    kv_store.set(&key, &deliveries).map_err(|e| format!("{:?}", e))
+}
+
+pub(crate) fn delete_deliveries(kv_store: &KVStore, webhook_id: &str) -> Result<(), String> {
+    kv_store
+        .delete(&deliveries_key(webhook_id))
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flowy_infra::clock::system_clock;
+    use parking_lot::Mutex;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    // Answers with the statuses in order, the last one is repeated. Returns the
+    // url and the requests it received.
+    async fn endpoint(statuses: Vec<u16>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+        let received = requests.clone();
+        tokio::spawn(async move {
+            let mut call = 0;
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut request = vec![];
+                let mut buf = [0u8; 4096];
+                while !is_complete(&request) {
+                    match stream.read(&mut buf).await {
+                        Ok(0) | Err(_) => break,
+                        Ok(n) => request.extend_from_slice(&buf[..n]),
+                    }
+                }
+                received.lock().push(String::from_utf8_lossy(&request).into_owned());
+                let status = statuses[call.min(statuses.len() - 1)];
+                call += 1;
+                let response = format!(
+                    "HTTP/1.1 {} Status\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                    status
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+        (url, requests)
+    }
+
+    fn is_complete(request: &[u8]) -> bool {
+        let text = String::from_utf8_lossy(request);
+        let head_end = match text.find("\r\n\r\n") {
+            Some(index) => index + 4,
+            None => return false,
+        };
+        let content_length = text[..head_end]
+            .lines()
+            .filter_map(|line| line.split_once(':'))
+            .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+            .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+            .unwrap_or(0);
+        request.len() >= head_end + content_length
+    }
+
+    fn webhook(url: &str) -> Webhook {
+        Webhook {
+            id: "webhook".to_owned(),
+            url: url.to_owned(),
+            secret: "secret".to_owned(),
+            has_secret: true,
+            events: vec!["view.created".to_owned()],
+            enabled: true,
+        }
+    }
+
+    async fn deliver_body(webhook: &Webhook, body: &'static str) -> Delivery {
+        let client = reqwest::Client::new();
+        let clock = system_clock();
+        let body = Bytes::from_static(body.as_bytes());
+        deliver(
+            &client,
+            &clock,
+            webhook,
+            "view.created",
+            "1",
+            body,
+            Duration::from_millis(1),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn delivery_is_signed_and_retried_after_a_server_error() {
+        let (url, requests) = endpoint(vec![500, 200]).await;
+        let delivery = deliver_body(&webhook(&url), r#"{"id":"1"}"#).await;
+        assert!(delivery.delivered);
+        assert_eq!(delivery.attempts, 2);
+        assert_eq!(delivery.status, Some(200));
+
+        let signature = format!("sha256={}", sign(b"secret", br#"{"id":"1"}"#));
+        let requests = requests.lock();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1].to_lowercase().contains(&signature));
+        assert!(requests[1].ends_with(r#"{"id":"1"}"#));
+    }
+
+    #[tokio::test]
+    async fn delivery_rejected_by_the_endpoint_is_not_retried() {
+        let (url, requests) = endpoint(vec![400]).await;
+        let delivery = deliver_body(&webhook(&url), "{}").await;
+        assert!(!delivery.delivered);
+        assert_eq!(delivery.attempts, 1);
+        assert_eq!(delivery.status, Some(400));
+        assert_eq!(requests.lock().len(), 1);
+    }
+}
@@ -0,0 +1,75 @@
+use flowy_dispatch::prelude::*;
+use flowy_workspace::event::WorkspaceEvent;
+use flowy_workspace_infra::protobuf::{QueryViewRequest, View, ViewType};
+use protobuf::Message;
+use serde_json::Value;
+
+// The events of the workspace the webhooks are registered for. Their data is
+// the json of the protobuf entity, e.g. the view that was created.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DomainEvent {
+    // A view of any type was created.
+    ViewCreated,
+
+    // A view of the doc type was created, it's a ViewCreated too.
+    DocumentCreated,
+
+    // The views were moved to the trash, the data is their QueryViewRequest.
+    ViewTrashed,
+}
+
+const DOMAIN_EVENTS: &[DomainEvent] = &[
+    DomainEvent::ViewCreated,
+    DomainEvent::DocumentCreated,
+    DomainEvent::ViewTrashed,
+];
+
+impl DomainEvent {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DomainEvent::ViewCreated => "view.created",
+            DomainEvent::DocumentCreated => "document.created",
+            DomainEvent::ViewTrashed => "view.trashed",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> { DOMAIN_EVENTS.iter().find(|event| event.name() == name).copied() }
+}
+
+// The domain events of the request that succeeded, with their data.
+pub(crate) fn domain_events(request: &ModuleRequest, response: &EventResponse) -> Vec<(DomainEvent, Value)> {
+    if response.status_code != StatusCode::Ok {
+        return vec![];
+    }
+    if request.event == Event::from(WorkspaceEvent::CreateView) {
+        let view = match response.payload.as_bytes().map(View::parse_from_bytes) {
+            Some(Ok(view)) => view,
+            _ => return vec![],
+        };
+        let data = match to_json(&view) {
+            Some(data) => data,
+            None => return vec![],
+        };
+        return match view.get_view_type() {
+            ViewType::Doc => vec![
+                (DomainEvent::ViewCreated, data.clone()),
+                (DomainEvent::DocumentCreated, data),
+            ],
+            _ => vec![(DomainEvent::ViewCreated, data)],
+        };
+    }
+    if request.event == Event::from(WorkspaceEvent::DeleteView) {
+        return match request.payload_bytes().map(QueryViewRequest::parse_from_bytes) {
+            Some(Ok(query)) => to_json(&query)
+                .map(|data| vec![(DomainEvent::ViewTrashed, data)])
+                .unwrap_or_default(),
+            _ => vec![],
+        };
+    }
+    vec![]
+}
+
+fn to_json(message: &dyn Message) -> Option<Value> {
+    let json = protobuf::json::print_to_string(message).ok()?;
+    serde_json::from_str(&json).ok()
+}
@@ -0,0 +1,296 @@
+mod delivery;
+mod event;
+
+pub use delivery::{Delivery, DELIVERY_HEADER, EVENT_HEADER, SIGNATURE_HEADER};
+pub use event::DomainEvent;
+
+use crate::plugin::{FlowyPlugin, PluginContext};
+use bytes::Bytes;
+use delivery::{delete_deliveries, deliver, read_deliveries, record_delivery, FIRST_RETRY_DELAY};
+use event::domain_events;
+use flowy_database::KVStore;
+use flowy_dispatch::prelude::*;
+use flowy_infra::secret::SecretStore;
+use futures_core::future::BoxFuture;
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::{fmt, sync::Arc};
+
+const WEBHOOK_KEY_PREFIX: &str = "webhook/";
+const WEBHOOK_SECRET_KEY_PREFIX: &str = "webhook_secret_";
+
+// An endpoint the domain events are posted to. The body is the json of the
+// delivery id, the event name, the timestamp and the data of the event, signed
+// with the secret in the SIGNATURE_HEADER. The secret is kept in the secret
+// store, not with the webhook in the kv_store.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct Webhook {
+    // Generated when the webhook is registered.
+    #[serde(default)]
+    pub id: String,
+    pub url: String,
+    // Generated when the webhook is registered without one. It's only returned
+    // by the registration that set it, the listed webhooks have has_secret.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub secret: String,
+    #[serde(default)]
+    pub has_secret: bool,
+    // The names of the DomainEvents, e.g. document.created.
+    pub events: Vec<String>,
+    #[serde(default = "enabled_by_default")]
+    pub enabled: bool,
+}
+
+fn enabled_by_default() -> bool { true }
+
+// The events of the webhook module. Their payloads are json, like the ones of
+// the script module.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum WebhookEvent {
+    // Registers the Webhook, or updates the one with its id. The secret of the
+    // updated webhook is kept if the payload has none. Returns the saved
+    // Webhook, with the secret if the registration set it.
+    Register,
+
+    // Returns the Webhooks of the user, without their secrets.
+    List,
+
+    // Deletes the Webhook with the id of the payload, and its deliveries.
+    Delete,
+
+    // Returns the Deliveries of the Webhook with the id of the payload, the
+    // newest first.
+    ReadDeliveries,
+}
+
+impl fmt::Display for WebhookEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WebhookEvent::Register => f.write_str("webhook/register"),
+            WebhookEvent::List => f.write_str("webhook/list"),
+            WebhookEvent::Delete => f.write_str("webhook/delete"),
+            WebhookEvent::ReadDeliveries => f.write_str("webhook/read_deliveries"),
+        }
+    }
+}
+
+// Posts the domain events to the webhooks of the user. The deliveries run after
+// the response is returned, the failed ones are retried and kept in the
+// history with their outcome.
+#[derive(Default)]
+pub struct WebhookPlugin {
+    host: Arc<WebhookHost>,
+}
+
+impl FlowyPlugin for WebhookPlugin {
+    fn name(&self) -> &str { "webhook" }
+
+    fn events(&self) -> Vec<String> {
+        [
+            WebhookEvent::Register,
+            WebhookEvent::List,
+            WebhookEvent::Delete,
+            WebhookEvent::ReadDeliveries,
+        ]
+        .iter()
+        .map(|event| event.to_string())
+        .collect()
+    }
+
+    fn build_module(&self, context: &PluginContext) -> Module {
+        *self.host.context.write() = Some(context.clone());
+        Module::new()
+            .data(context.secret_store.clone())
+            .event(WebhookEvent::Register, register_webhook_handler)
+            .event(WebhookEvent::List, list_webhooks_handler)
+            .event(WebhookEvent::Delete, delete_webhook_handler)
+            .event(WebhookEvent::ReadDeliveries, read_deliveries_handler)
+    }
+
+    fn middleware(&self) -> Option<Arc<dyn EventMiddleware>> {
+        Some(Arc::new(WebhookHooks {
+            host: self.host.clone(),
+        }))
+    }
+}
+
+#[derive(Default)]
+struct WebhookHost {
+    context: RwLock<Option<PluginContext>>,
+    client: reqwest::Client,
+    // The deliveries of a webhook are read and written back as one value.
+    history: Mutex<()>,
+}
+
+impl WebhookHost {
+    async fn post(self: Arc<Self>, event: DomainEvent, data: Value) {
+        let context = match self.context.read().clone() {
+            Some(context) => context,
+            None => return,
+        };
+        let webhooks = match read_webhooks(&context.kv_store, &context.secret_store) {
+            Ok(webhooks) => webhooks,
+            Err(e) => {
+                tracing::error!("Read the webhooks failed: {}", e);
+                return;
+            },
+        };
+        let webhooks = webhooks
+            .into_iter()
+            .filter(|webhook| webhook.enabled && webhook.events.iter().any(|name| name == event.name()));
+        for mut webhook in webhooks {
+            webhook.secret = match context.secret_store.get(&webhook_secret_key(&webhook.id)) {
+                Ok(Some(secret)) => secret,
+                Ok(None) => {
+                    tracing::error!("The webhook {} has no secret", webhook.id);
+                    continue;
+                },
+                Err(e) => {
+                    tracing::error!("Read the secret of the webhook {} failed: {}", webhook.id, e);
+                    continue;
+                },
+            };
+            let delivery_id = flowy_infra::uuid();
+            let body = json!({
+                "id": delivery_id,
+                "event": event.name(),
+                "timestamp": context.clock.timestamp(),
+                "data": data,
+            });
+            let (host, context) = (self.clone(), context.clone());
+            tokio::spawn(async move {
+                let body = Bytes::from(body.to_string());
+                let delivery = deliver(
+                    &host.client,
+                    &context.clock,
+                    &webhook,
+                    event.name(),
+                    &delivery_id,
+                    body,
+                    FIRST_RETRY_DELAY,
+                )
+                .await;
+                if !delivery.delivered {
+                    tracing::error!(
+                        "Deliver {} to {} failed: {:?}",
+                        event.name(),
+                        webhook.url,
+                        delivery.error
+                    );
+                }
+                let _history = host.history.lock();
+                if let Err(e) = record_delivery(&context.kv_store, delivery) {
+                    tracing::error!("Record the delivery failed: {}", e);
+                }
+            });
+        }
+    }
+}
+
+struct WebhookHooks {
+    host: Arc<WebhookHost>,
+}
+
+impl EventMiddleware for WebhookHooks {
+    fn intercept(&self, request: &ModuleRequest, response: &EventResponse) -> BoxFuture<'static, bool> {
+        let events = domain_events(request, response);
+        let host = self.host.clone();
+        Box::pin(async move {
+            for (event, data) in events {
+                tokio::spawn(host.clone().post(event, data));
+            }
+            false
+        })
+    }
+}
+
+// The webhooks without their secrets. The ones saved with their secret in the
+// kv_store, before the secret store was used, are moved to it.
+fn read_webhooks(kv_store: &KVStore, secret_store: &Arc<dyn SecretStore>) -> Result<Vec<Webhook>, String> {
+    let webhooks = kv_store
+        .scan_prefix::<Webhook>(WEBHOOK_KEY_PREFIX)
+        .map_err(|e| format!("{:?}", e))?;
+    webhooks
+        .into_iter()
+        .map(|(_, mut webhook)| {
+            if !webhook.secret.is_empty() {
+                secret_store.set(&webhook_secret_key(&webhook.id), &webhook.secret)?;
+                webhook.secret.clear();
+                webhook.has_secret = true;
+                kv_store
+                    .set(&webhook_key(&webhook.id), &webhook)
+                    .map_err(|e| format!("{:?}", e))?;
+            }
+            Ok(webhook)
+        })
+        .collect()
+}
+
+fn webhook_key(id: &str) -> String { format!("{}{}", WEBHOOK_KEY_PREFIX, id) }
+
+fn webhook_secret_key(id: &str) -> String { format!("{}{}", WEBHOOK_SECRET_KEY_PREFIX, id) }
+
+async fn register_webhook_handler(
+    data: String,
+    kv_store: Unit<KVStore>,
+    secret_store: Unit<Arc<dyn SecretStore>>,
+) -> Result<String, DispatchError> {
+    let mut webhook: Webhook = serde_json::from_str(&data).map_err(|e| format!("Invalid webhook: {}", e))?;
+    match reqwest::Url::parse(&webhook.url) {
+        Ok(url) if url.scheme() == "http" || url.scheme() == "https" => {},
+        _ => return Err(format!("Invalid webhook url: {}", webhook.url).into()),
+    }
+    if webhook.events.is_empty() {
+        return Err("The webhook has no events".to_owned().into());
+    }
+    if let Some(name) = webhook
+        .events
+        .iter()
+        .find(|name| DomainEvent::from_name(name).is_none())
+    {
+        return Err(format!("Unknown event: {}", name).into());
+    }
+    if webhook.id.is_empty() {
+        webhook.id = flowy_infra::uuid();
+    }
+    let secret_key = webhook_secret_key(&webhook.id);
+    if webhook.secret.is_empty() && secret_store.get(&secret_key)?.is_none() {
+        webhook.secret = flowy_infra::secret::generate_secret();
+    }
+    if !webhook.secret.is_empty() {
+        secret_store.set(&secret_key, &webhook.secret)?;
+    }
+
+    let secret = std::mem::take(&mut webhook.secret);
+    webhook.has_secret = true;
+    kv_store
+        .set(&webhook_key(&webhook.id), &webhook)
+        .map_err(|e| format!("{:?}", e))?;
+    webhook.secret = secret;
+    Ok(serde_json::to_string(&webhook).map_err(|e| e.to_string())?)
+}
+
+async fn list_webhooks_handler(
+    kv_store: Unit<KVStore>,
+    secret_store: Unit<Arc<dyn SecretStore>>,
+) -> Result<String, DispatchError> {
+    let webhooks = read_webhooks(&kv_store, &secret_store)?;
+    Ok(serde_json::to_string(&webhooks).map_err(|e| e.to_string())?)
+}
+
+async fn delete_webhook_handler(
+    id: String,
+    kv_store: Unit<KVStore>,
+    secret_store: Unit<Arc<dyn SecretStore>>,
+) -> Result<(), DispatchError> {
+    kv_store.delete(&webhook_key(&id)).map_err(|e| format!("{:?}", e))?;
+    secret_store.remove(&webhook_secret_key(&id))?;
+    delete_deliveries(&kv_store, &id)?;
+    Ok(())
+}
+
+async fn read_deliveries_handler(id: String, kv_store: Unit<KVStore>) -> Result<String, DispatchError> {
+    let deliveries = read_deliveries(&kv_store, &id)?;
+    Ok(serde_json::to_string(&deliveries).map_err(|e| e.to_string())?)
+}
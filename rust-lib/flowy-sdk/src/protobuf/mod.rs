@@ -0,0 +1,4 @@
+
+mod model;
+pub use model::*;
+        
\ No newline at end of file
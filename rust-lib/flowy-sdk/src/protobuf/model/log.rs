@@ -0,0 +1,1046 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `log.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SubscribeLogRequest {
+    // message fields
+    pub level: ::std::string::String,
+    pub targets: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SubscribeLogRequest {
+    fn default() -> &'a SubscribeLogRequest {
+        <SubscribeLogRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SubscribeLogRequest {
+    pub fn new() -> SubscribeLogRequest {
+        ::std::default::Default::default()
+    }
+
+    // string level = 1;
+
+
+    pub fn get_level(&self) -> &str {
+        &self.level
+    }
+    pub fn clear_level(&mut self) {
+        self.level.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_level(&mut self, v: ::std::string::String) {
+        self.level = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_level(&mut self) -> &mut ::std::string::String {
+        &mut self.level
+    }
+
+    // Take field
+    pub fn take_level(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.level, ::std::string::String::new())
+    }
+
+    // repeated string targets = 2;
+
+
+    pub fn get_targets(&self) -> &[::std::string::String] {
+        &self.targets
+    }
+    pub fn clear_targets(&mut self) {
+        self.targets.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_targets(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.targets = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_targets(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.targets
+    }
+
+    // Take field
+    pub fn take_targets(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.targets, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for SubscribeLogRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.level)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.targets)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.level.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.level);
+        }
+        for value in &self.targets {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.level.is_empty() {
+            os.write_string(1, &self.level)?;
+        }
+        for v in &self.targets {
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SubscribeLogRequest {
+        SubscribeLogRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "level",
+                |m: &SubscribeLogRequest| { &m.level },
+                |m: &mut SubscribeLogRequest| { &mut m.level },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "targets",
+                |m: &SubscribeLogRequest| { &m.targets },
+                |m: &mut SubscribeLogRequest| { &mut m.targets },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SubscribeLogRequest>(
+                "SubscribeLogRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SubscribeLogRequest {
+        static instance: ::protobuf::rt::LazyV2<SubscribeLogRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SubscribeLogRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SubscribeLogRequest {
+    fn clear(&mut self) {
+        self.level.clear();
+        self.targets.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SubscribeLogRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SubscribeLogRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LogRecord {
+    // message fields
+    pub level: ::std::string::String,
+    pub target: ::std::string::String,
+    pub message: ::std::string::String,
+    pub time: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a LogRecord {
+    fn default() -> &'a LogRecord {
+        <LogRecord as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LogRecord {
+    pub fn new() -> LogRecord {
+        ::std::default::Default::default()
+    }
+
+    // string level = 1;
+
+
+    pub fn get_level(&self) -> &str {
+        &self.level
+    }
+    pub fn clear_level(&mut self) {
+        self.level.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_level(&mut self, v: ::std::string::String) {
+        self.level = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_level(&mut self) -> &mut ::std::string::String {
+        &mut self.level
+    }
+
+    // Take field
+    pub fn take_level(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.level, ::std::string::String::new())
+    }
+
+    // string target = 2;
+
+
+    pub fn get_target(&self) -> &str {
+        &self.target
+    }
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: ::std::string::String) {
+        self.target = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut ::std::string::String {
+        &mut self.target
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.target, ::std::string::String::new())
+    }
+
+    // string message = 3;
+
+
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+    pub fn clear_message(&mut self) {
+        self.message.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message(&mut self, v: ::std::string::String) {
+        self.message = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message(&mut self) -> &mut ::std::string::String {
+        &mut self.message
+    }
+
+    // Take field
+    pub fn take_message(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.message, ::std::string::String::new())
+    }
+
+    // int64 time = 4;
+
+
+    pub fn get_time(&self) -> i64 {
+        self.time
+    }
+    pub fn clear_time(&mut self) {
+        self.time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_time(&mut self, v: i64) {
+        self.time = v;
+    }
+}
+
+impl ::protobuf::Message for LogRecord {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.level)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.target)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.message)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.time = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.level.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.level);
+        }
+        if !self.target.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.target);
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.message);
+        }
+        if self.time != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.level.is_empty() {
+            os.write_string(1, &self.level)?;
+        }
+        if !self.target.is_empty() {
+            os.write_string(2, &self.target)?;
+        }
+        if !self.message.is_empty() {
+            os.write_string(3, &self.message)?;
+        }
+        if self.time != 0 {
+            os.write_int64(4, self.time)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LogRecord {
+        LogRecord::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "level",
+                |m: &LogRecord| { &m.level },
+                |m: &mut LogRecord| { &mut m.level },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "target",
+                |m: &LogRecord| { &m.target },
+                |m: &mut LogRecord| { &mut m.target },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "message",
+                |m: &LogRecord| { &m.message },
+                |m: &mut LogRecord| { &mut m.message },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "time",
+                |m: &LogRecord| { &m.time },
+                |m: &mut LogRecord| { &mut m.time },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<LogRecord>(
+                "LogRecord",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static LogRecord {
+        static instance: ::protobuf::rt::LazyV2<LogRecord> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(LogRecord::new)
+    }
+}
+
+impl ::protobuf::Clear for LogRecord {
+    fn clear(&mut self) {
+        self.level.clear();
+        self.target.clear();
+        self.message.clear();
+        self.time = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LogRecord {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LogRecord {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedLogRecord {
+    // message fields
+    pub items: ::protobuf::RepeatedField<LogRecord>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedLogRecord {
+    fn default() -> &'a RepeatedLogRecord {
+        <RepeatedLogRecord as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedLogRecord {
+    pub fn new() -> RepeatedLogRecord {
+        ::std::default::Default::default()
+    }
+
+    // repeated .LogRecord items = 1;
+
+
+    pub fn get_items(&self) -> &[LogRecord] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<LogRecord>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<LogRecord> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<LogRecord> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedLogRecord {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedLogRecord {
+        RepeatedLogRecord::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LogRecord>>(
+                "items",
+                |m: &RepeatedLogRecord| { &m.items },
+                |m: &mut RepeatedLogRecord| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedLogRecord>(
+                "RepeatedLogRecord",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedLogRecord {
+        static instance: ::protobuf::rt::LazyV2<RepeatedLogRecord> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedLogRecord::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedLogRecord {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedLogRecord {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedLogRecord {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetLogFilterRequest {
+    // message fields
+    pub filter: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetLogFilterRequest {
+    fn default() -> &'a SetLogFilterRequest {
+        <SetLogFilterRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetLogFilterRequest {
+    pub fn new() -> SetLogFilterRequest {
+        ::std::default::Default::default()
+    }
+
+    // string filter = 1;
+
+
+    pub fn get_filter(&self) -> &str {
+        &self.filter
+    }
+    pub fn clear_filter(&mut self) {
+        self.filter.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_filter(&mut self, v: ::std::string::String) {
+        self.filter = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_filter(&mut self) -> &mut ::std::string::String {
+        &mut self.filter
+    }
+
+    // Take field
+    pub fn take_filter(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.filter, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for SetLogFilterRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.filter)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.filter.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.filter);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.filter.is_empty() {
+            os.write_string(1, &self.filter)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetLogFilterRequest {
+        SetLogFilterRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "filter",
+                |m: &SetLogFilterRequest| { &m.filter },
+                |m: &mut SetLogFilterRequest| { &mut m.filter },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetLogFilterRequest>(
+                "SetLogFilterRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetLogFilterRequest {
+        static instance: ::protobuf::rt::LazyV2<SetLogFilterRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetLogFilterRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SetLogFilterRequest {
+    fn clear(&mut self) {
+        self.filter.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetLogFilterRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetLogFilterRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LogFilterSetting {
+    // message fields
+    pub filter: ::std::string::String,
+    pub is_default: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a LogFilterSetting {
+    fn default() -> &'a LogFilterSetting {
+        <LogFilterSetting as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LogFilterSetting {
+    pub fn new() -> LogFilterSetting {
+        ::std::default::Default::default()
+    }
+
+    // string filter = 1;
+
+
+    pub fn get_filter(&self) -> &str {
+        &self.filter
+    }
+    pub fn clear_filter(&mut self) {
+        self.filter.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_filter(&mut self, v: ::std::string::String) {
+        self.filter = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_filter(&mut self) -> &mut ::std::string::String {
+        &mut self.filter
+    }
+
+    // Take field
+    pub fn take_filter(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.filter, ::std::string::String::new())
+    }
+
+    // bool is_default = 2;
+
+
+    pub fn get_is_default(&self) -> bool {
+        self.is_default
+    }
+    pub fn clear_is_default(&mut self) {
+        self.is_default = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_default(&mut self, v: bool) {
+        self.is_default = v;
+    }
+}
+
+impl ::protobuf::Message for LogFilterSetting {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.filter)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_default = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.filter.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.filter);
+        }
+        if self.is_default != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.filter.is_empty() {
+            os.write_string(1, &self.filter)?;
+        }
+        if self.is_default != false {
+            os.write_bool(2, self.is_default)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LogFilterSetting {
+        LogFilterSetting::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "filter",
+                |m: &LogFilterSetting| { &m.filter },
+                |m: &mut LogFilterSetting| { &mut m.filter },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_default",
+                |m: &LogFilterSetting| { &m.is_default },
+                |m: &mut LogFilterSetting| { &mut m.is_default },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<LogFilterSetting>(
+                "LogFilterSetting",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static LogFilterSetting {
+        static instance: ::protobuf::rt::LazyV2<LogFilterSetting> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(LogFilterSetting::new)
+    }
+}
+
+impl ::protobuf::Clear for LogFilterSetting {
+    fn clear(&mut self) {
+        self.filter.clear();
+        self.is_default = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LogFilterSetting {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LogFilterSetting {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\tlog.proto\"K\n\x13SubscribeLogRequest\x12\x16\n\x05level\x18\x01\x20\
+    \x01(\tR\x05levelB\0\x12\x1a\n\x07targets\x18\x02\x20\x03(\tR\x07targets\
+    B\0:\0\"q\n\tLogRecord\x12\x16\n\x05level\x18\x01\x20\x01(\tR\x05levelB\
+    \0\x12\x18\n\x06target\x18\x02\x20\x01(\tR\x06targetB\0\x12\x1a\n\x07mes\
+    sage\x18\x03\x20\x01(\tR\x07messageB\0\x12\x14\n\x04time\x18\x04\x20\x01\
+    (\x03R\x04timeB\0:\0\"9\n\x11RepeatedLogRecord\x12\"\n\x05items\x18\x01\
+    \x20\x03(\x0b2\n.LogRecordR\x05itemsB\0:\0\"1\n\x13SetLogFilterRequest\
+    \x12\x18\n\x06filter\x18\x01\x20\x01(\tR\x06filterB\0:\0\"O\n\x10LogFilt\
+    erSetting\x12\x18\n\x06filter\x18\x01\x20\x01(\tR\x06filterB\0\x12\x1f\n\
+    \nis_default\x18\x02\x20\x01(\x08R\tisDefaultB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,541 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `memory.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SubsystemMemory {
+    // message fields
+    pub subsystem: ::std::string::String,
+    pub name: ::std::string::String,
+    pub count: i64,
+    pub bytes: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SubsystemMemory {
+    fn default() -> &'a SubsystemMemory {
+        <SubsystemMemory as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SubsystemMemory {
+    pub fn new() -> SubsystemMemory {
+        ::std::default::Default::default()
+    }
+
+    // string subsystem = 1;
+
+
+    pub fn get_subsystem(&self) -> &str {
+        &self.subsystem
+    }
+    pub fn clear_subsystem(&mut self) {
+        self.subsystem.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_subsystem(&mut self, v: ::std::string::String) {
+        self.subsystem = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_subsystem(&mut self) -> &mut ::std::string::String {
+        &mut self.subsystem
+    }
+
+    // Take field
+    pub fn take_subsystem(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.subsystem, ::std::string::String::new())
+    }
+
+    // string name = 2;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // int64 count = 3;
+
+
+    pub fn get_count(&self) -> i64 {
+        self.count
+    }
+    pub fn clear_count(&mut self) {
+        self.count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: i64) {
+        self.count = v;
+    }
+
+    // int64 bytes = 4;
+
+
+    pub fn get_bytes(&self) -> i64 {
+        self.bytes
+    }
+    pub fn clear_bytes(&mut self) {
+        self.bytes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bytes(&mut self, v: i64) {
+        self.bytes = v;
+    }
+}
+
+impl ::protobuf::Message for SubsystemMemory {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.subsystem)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.count = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.bytes = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.subsystem.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.subsystem);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.bytes != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.subsystem.is_empty() {
+            os.write_string(1, &self.subsystem)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        if self.count != 0 {
+            os.write_int64(3, self.count)?;
+        }
+        if self.bytes != 0 {
+            os.write_int64(4, self.bytes)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SubsystemMemory {
+        SubsystemMemory::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "subsystem",
+                |m: &SubsystemMemory| { &m.subsystem },
+                |m: &mut SubsystemMemory| { &mut m.subsystem },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &SubsystemMemory| { &m.name },
+                |m: &mut SubsystemMemory| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "count",
+                |m: &SubsystemMemory| { &m.count },
+                |m: &mut SubsystemMemory| { &mut m.count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "bytes",
+                |m: &SubsystemMemory| { &m.bytes },
+                |m: &mut SubsystemMemory| { &mut m.bytes },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SubsystemMemory>(
+                "SubsystemMemory",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SubsystemMemory {
+        static instance: ::protobuf::rt::LazyV2<SubsystemMemory> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SubsystemMemory::new)
+    }
+}
+
+impl ::protobuf::Clear for SubsystemMemory {
+    fn clear(&mut self) {
+        self.subsystem.clear();
+        self.name.clear();
+        self.count = 0;
+        self.bytes = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SubsystemMemory {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SubsystemMemory {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MemoryUsage {
+    // message fields
+    pub items: ::protobuf::RepeatedField<SubsystemMemory>,
+    // message oneof groups
+    pub one_of_rss: ::std::option::Option<MemoryUsage_oneof_one_of_rss>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MemoryUsage {
+    fn default() -> &'a MemoryUsage {
+        <MemoryUsage as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum MemoryUsage_oneof_one_of_rss {
+    rss(i64),
+}
+
+impl MemoryUsage {
+    pub fn new() -> MemoryUsage {
+        ::std::default::Default::default()
+    }
+
+    // int64 rss = 1;
+
+
+    pub fn get_rss(&self) -> i64 {
+        match self.one_of_rss {
+            ::std::option::Option::Some(MemoryUsage_oneof_one_of_rss::rss(v)) => v,
+            _ => 0,
+        }
+    }
+    pub fn clear_rss(&mut self) {
+        self.one_of_rss = ::std::option::Option::None;
+    }
+
+    pub fn has_rss(&self) -> bool {
+        match self.one_of_rss {
+            ::std::option::Option::Some(MemoryUsage_oneof_one_of_rss::rss(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rss(&mut self, v: i64) {
+        self.one_of_rss = ::std::option::Option::Some(MemoryUsage_oneof_one_of_rss::rss(v))
+    }
+
+    // repeated .SubsystemMemory items = 2;
+
+
+    pub fn get_items(&self) -> &[SubsystemMemory] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<SubsystemMemory>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<SubsystemMemory> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<SubsystemMemory> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for MemoryUsage {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_rss = ::std::option::Option::Some(MemoryUsage_oneof_one_of_rss::rss(is.read_int64()?));
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let ::std::option::Option::Some(ref v) = self.one_of_rss {
+            match v {
+                &MemoryUsage_oneof_one_of_rss::rss(v) => {
+                    my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let ::std::option::Option::Some(ref v) = self.one_of_rss {
+            match v {
+                &MemoryUsage_oneof_one_of_rss::rss(v) => {
+                    os.write_int64(1, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MemoryUsage {
+        MemoryUsage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_i64_accessor::<_>(
+                "rss",
+                MemoryUsage::has_rss,
+                MemoryUsage::get_rss,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SubsystemMemory>>(
+                "items",
+                |m: &MemoryUsage| { &m.items },
+                |m: &mut MemoryUsage| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MemoryUsage>(
+                "MemoryUsage",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MemoryUsage {
+        static instance: ::protobuf::rt::LazyV2<MemoryUsage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MemoryUsage::new)
+    }
+}
+
+impl ::protobuf::Clear for MemoryUsage {
+    fn clear(&mut self) {
+        self.one_of_rss = ::std::option::Option::None;
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MemoryUsage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MemoryUsage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0cmemory.proto\"y\n\x0fSubsystemMemory\x12\x1e\n\tsubsystem\x18\x01\
+    \x20\x01(\tR\tsubsystemB\0\x12\x14\n\x04name\x18\x02\x20\x01(\tR\x04name\
+    B\0\x12\x16\n\x05count\x18\x03\x20\x01(\x03R\x05countB\0\x12\x16\n\x05by\
+    tes\x18\x04\x20\x01(\x03R\x05bytesB\0:\0\"]\n\x0bMemoryUsage\x12\x14\n\
+    \x03rss\x18\x01\x20\x01(\x03H\0R\x03rssB\0\x12(\n\x05items\x18\x02\x20\
+    \x03(\x0b2\x10.SubsystemMemoryR\x05itemsB\0B\x0c\n\none_of_rss:\0B\0b\
+    \x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
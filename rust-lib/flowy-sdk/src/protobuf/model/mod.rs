@@ -0,0 +1,28 @@
+// Auto-generated, do not edit 
+
+mod schema; 
+pub use schema::*; 
+
+mod observable; 
+pub use observable::*; 
+
+mod metrics; 
+pub use metrics::*; 
+
+mod profile; 
+pub use profile::*; 
+
+mod log; 
+pub use log::*; 
+
+mod crash; 
+pub use crash::*; 
+
+mod memory; 
+pub use memory::*; 
+
+mod i18n; 
+pub use i18n::*; 
+
+mod trace; 
+pub use trace::*; 
@@ -0,0 +1,106 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `observable.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum SDKNotification {
+    Unknown = 0,
+    SchemaIncompatible = 1,
+    NetworkStateChanged = 2,
+    LogRecordReceived = 3,
+    PluginNotification = 4,
+    LocaleChanged = 5,
+}
+
+impl ::protobuf::ProtobufEnum for SDKNotification {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<SDKNotification> {
+        match value {
+            0 => ::std::option::Option::Some(SDKNotification::Unknown),
+            1 => ::std::option::Option::Some(SDKNotification::SchemaIncompatible),
+            2 => ::std::option::Option::Some(SDKNotification::NetworkStateChanged),
+            3 => ::std::option::Option::Some(SDKNotification::LogRecordReceived),
+            4 => ::std::option::Option::Some(SDKNotification::PluginNotification),
+            5 => ::std::option::Option::Some(SDKNotification::LocaleChanged),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [SDKNotification] = &[
+            SDKNotification::Unknown,
+            SDKNotification::SchemaIncompatible,
+            SDKNotification::NetworkStateChanged,
+            SDKNotification::LogRecordReceived,
+            SDKNotification::PluginNotification,
+            SDKNotification::LocaleChanged,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<SDKNotification>("SDKNotification", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for SDKNotification {
+}
+
+impl ::std::default::Default for SDKNotification {
+    fn default() -> Self {
+        SDKNotification::Unknown
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SDKNotification {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x10observable.proto*\x93\x01\n\x0fSDKNotification\x12\x0b\n\x07Unknow\
+    n\x10\0\x12\x16\n\x12SchemaIncompatible\x10\x01\x12\x17\n\x13NetworkStat\
+    eChanged\x10\x02\x12\x15\n\x11LogRecordReceived\x10\x03\x12\x16\n\x12Plu\
+    ginNotification\x10\x04\x12\x11\n\rLocaleChanged\x10\x05\x1a\0B\0b\x06pr\
+    oto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
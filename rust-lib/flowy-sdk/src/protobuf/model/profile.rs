@@ -0,0 +1,997 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `profile.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetProfilingRequest {
+    // message fields
+    pub enabled: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetProfilingRequest {
+    fn default() -> &'a SetProfilingRequest {
+        <SetProfilingRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetProfilingRequest {
+    pub fn new() -> SetProfilingRequest {
+        ::std::default::Default::default()
+    }
+
+    // bool enabled = 1;
+
+
+    pub fn get_enabled(&self) -> bool {
+        self.enabled
+    }
+    pub fn clear_enabled(&mut self) {
+        self.enabled = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enabled(&mut self, v: bool) {
+        self.enabled = v;
+    }
+}
+
+impl ::protobuf::Message for SetProfilingRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.enabled = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.enabled != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.enabled != false {
+            os.write_bool(1, self.enabled)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetProfilingRequest {
+        SetProfilingRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "enabled",
+                |m: &SetProfilingRequest| { &m.enabled },
+                |m: &mut SetProfilingRequest| { &mut m.enabled },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetProfilingRequest>(
+                "SetProfilingRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetProfilingRequest {
+        static instance: ::protobuf::rt::LazyV2<SetProfilingRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetProfilingRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SetProfilingRequest {
+    fn clear(&mut self) {
+        self.enabled = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetProfilingRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetProfilingRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ProfileReportRequest {
+    // message fields
+    pub top: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ProfileReportRequest {
+    fn default() -> &'a ProfileReportRequest {
+        <ProfileReportRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ProfileReportRequest {
+    pub fn new() -> ProfileReportRequest {
+        ::std::default::Default::default()
+    }
+
+    // int64 top = 1;
+
+
+    pub fn get_top(&self) -> i64 {
+        self.top
+    }
+    pub fn clear_top(&mut self) {
+        self.top = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_top(&mut self, v: i64) {
+        self.top = v;
+    }
+}
+
+impl ::protobuf::Message for ProfileReportRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.top = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.top != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.top, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.top != 0 {
+            os.write_int64(1, self.top)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ProfileReportRequest {
+        ProfileReportRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "top",
+                |m: &ProfileReportRequest| { &m.top },
+                |m: &mut ProfileReportRequest| { &mut m.top },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ProfileReportRequest>(
+                "ProfileReportRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ProfileReportRequest {
+        static instance: ::protobuf::rt::LazyV2<ProfileReportRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ProfileReportRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ProfileReportRequest {
+    fn clear(&mut self) {
+        self.top = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ProfileReportRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ProfileReportRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ProfiledEvent {
+    // message fields
+    pub event: ::std::string::String,
+    pub count: i64,
+    pub wall_time: f64,
+    pub max_wall_time: f64,
+    pub busy_time: f64,
+    pub polls: i64,
+    pub allocations: i64,
+    pub allocated_bytes: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ProfiledEvent {
+    fn default() -> &'a ProfiledEvent {
+        <ProfiledEvent as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ProfiledEvent {
+    pub fn new() -> ProfiledEvent {
+        ::std::default::Default::default()
+    }
+
+    // string event = 1;
+
+
+    pub fn get_event(&self) -> &str {
+        &self.event
+    }
+    pub fn clear_event(&mut self) {
+        self.event.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_event(&mut self, v: ::std::string::String) {
+        self.event = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_event(&mut self) -> &mut ::std::string::String {
+        &mut self.event
+    }
+
+    // Take field
+    pub fn take_event(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.event, ::std::string::String::new())
+    }
+
+    // int64 count = 2;
+
+
+    pub fn get_count(&self) -> i64 {
+        self.count
+    }
+    pub fn clear_count(&mut self) {
+        self.count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: i64) {
+        self.count = v;
+    }
+
+    // double wall_time = 3;
+
+
+    pub fn get_wall_time(&self) -> f64 {
+        self.wall_time
+    }
+    pub fn clear_wall_time(&mut self) {
+        self.wall_time = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_wall_time(&mut self, v: f64) {
+        self.wall_time = v;
+    }
+
+    // double max_wall_time = 4;
+
+
+    pub fn get_max_wall_time(&self) -> f64 {
+        self.max_wall_time
+    }
+    pub fn clear_max_wall_time(&mut self) {
+        self.max_wall_time = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_wall_time(&mut self, v: f64) {
+        self.max_wall_time = v;
+    }
+
+    // double busy_time = 5;
+
+
+    pub fn get_busy_time(&self) -> f64 {
+        self.busy_time
+    }
+    pub fn clear_busy_time(&mut self) {
+        self.busy_time = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_busy_time(&mut self, v: f64) {
+        self.busy_time = v;
+    }
+
+    // int64 polls = 6;
+
+
+    pub fn get_polls(&self) -> i64 {
+        self.polls
+    }
+    pub fn clear_polls(&mut self) {
+        self.polls = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_polls(&mut self, v: i64) {
+        self.polls = v;
+    }
+
+    // int64 allocations = 7;
+
+
+    pub fn get_allocations(&self) -> i64 {
+        self.allocations
+    }
+    pub fn clear_allocations(&mut self) {
+        self.allocations = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_allocations(&mut self, v: i64) {
+        self.allocations = v;
+    }
+
+    // int64 allocated_bytes = 8;
+
+
+    pub fn get_allocated_bytes(&self) -> i64 {
+        self.allocated_bytes
+    }
+    pub fn clear_allocated_bytes(&mut self) {
+        self.allocated_bytes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_allocated_bytes(&mut self, v: i64) {
+        self.allocated_bytes = v;
+    }
+}
+
+impl ::protobuf::Message for ProfiledEvent {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.event)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.count = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.wall_time = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.max_wall_time = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.busy_time = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.polls = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.allocations = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.allocated_bytes = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.event.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.event);
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.wall_time != 0. {
+            my_size += 9;
+        }
+        if self.max_wall_time != 0. {
+            my_size += 9;
+        }
+        if self.busy_time != 0. {
+            my_size += 9;
+        }
+        if self.polls != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.polls, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.allocations != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.allocations, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.allocated_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.allocated_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.event.is_empty() {
+            os.write_string(1, &self.event)?;
+        }
+        if self.count != 0 {
+            os.write_int64(2, self.count)?;
+        }
+        if self.wall_time != 0. {
+            os.write_double(3, self.wall_time)?;
+        }
+        if self.max_wall_time != 0. {
+            os.write_double(4, self.max_wall_time)?;
+        }
+        if self.busy_time != 0. {
+            os.write_double(5, self.busy_time)?;
+        }
+        if self.polls != 0 {
+            os.write_int64(6, self.polls)?;
+        }
+        if self.allocations != 0 {
+            os.write_int64(7, self.allocations)?;
+        }
+        if self.allocated_bytes != 0 {
+            os.write_int64(8, self.allocated_bytes)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ProfiledEvent {
+        ProfiledEvent::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "event",
+                |m: &ProfiledEvent| { &m.event },
+                |m: &mut ProfiledEvent| { &mut m.event },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "count",
+                |m: &ProfiledEvent| { &m.count },
+                |m: &mut ProfiledEvent| { &mut m.count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "wall_time",
+                |m: &ProfiledEvent| { &m.wall_time },
+                |m: &mut ProfiledEvent| { &mut m.wall_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "max_wall_time",
+                |m: &ProfiledEvent| { &m.max_wall_time },
+                |m: &mut ProfiledEvent| { &mut m.max_wall_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "busy_time",
+                |m: &ProfiledEvent| { &m.busy_time },
+                |m: &mut ProfiledEvent| { &mut m.busy_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "polls",
+                |m: &ProfiledEvent| { &m.polls },
+                |m: &mut ProfiledEvent| { &mut m.polls },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "allocations",
+                |m: &ProfiledEvent| { &m.allocations },
+                |m: &mut ProfiledEvent| { &mut m.allocations },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "allocated_bytes",
+                |m: &ProfiledEvent| { &m.allocated_bytes },
+                |m: &mut ProfiledEvent| { &mut m.allocated_bytes },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ProfiledEvent>(
+                "ProfiledEvent",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ProfiledEvent {
+        static instance: ::protobuf::rt::LazyV2<ProfiledEvent> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ProfiledEvent::new)
+    }
+}
+
+impl ::protobuf::Clear for ProfiledEvent {
+    fn clear(&mut self) {
+        self.event.clear();
+        self.count = 0;
+        self.wall_time = 0.;
+        self.max_wall_time = 0.;
+        self.busy_time = 0.;
+        self.polls = 0;
+        self.allocations = 0;
+        self.allocated_bytes = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ProfiledEvent {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ProfiledEvent {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ProfileReport {
+    // message fields
+    pub enabled: bool,
+    pub counting_allocations: bool,
+    pub items: ::protobuf::RepeatedField<ProfiledEvent>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ProfileReport {
+    fn default() -> &'a ProfileReport {
+        <ProfileReport as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ProfileReport {
+    pub fn new() -> ProfileReport {
+        ::std::default::Default::default()
+    }
+
+    // bool enabled = 1;
+
+
+    pub fn get_enabled(&self) -> bool {
+        self.enabled
+    }
+    pub fn clear_enabled(&mut self) {
+        self.enabled = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enabled(&mut self, v: bool) {
+        self.enabled = v;
+    }
+
+    // bool counting_allocations = 2;
+
+
+    pub fn get_counting_allocations(&self) -> bool {
+        self.counting_allocations
+    }
+    pub fn clear_counting_allocations(&mut self) {
+        self.counting_allocations = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_counting_allocations(&mut self, v: bool) {
+        self.counting_allocations = v;
+    }
+
+    // repeated .ProfiledEvent items = 3;
+
+
+    pub fn get_items(&self) -> &[ProfiledEvent] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<ProfiledEvent>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<ProfiledEvent> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<ProfiledEvent> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for ProfileReport {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.enabled = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.counting_allocations = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.enabled != false {
+            my_size += 2;
+        }
+        if self.counting_allocations != false {
+            my_size += 2;
+        }
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.enabled != false {
+            os.write_bool(1, self.enabled)?;
+        }
+        if self.counting_allocations != false {
+            os.write_bool(2, self.counting_allocations)?;
+        }
+        for v in &self.items {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ProfileReport {
+        ProfileReport::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "enabled",
+                |m: &ProfileReport| { &m.enabled },
+                |m: &mut ProfileReport| { &mut m.enabled },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "counting_allocations",
+                |m: &ProfileReport| { &m.counting_allocations },
+                |m: &mut ProfileReport| { &mut m.counting_allocations },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ProfiledEvent>>(
+                "items",
+                |m: &ProfileReport| { &m.items },
+                |m: &mut ProfileReport| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ProfileReport>(
+                "ProfileReport",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ProfileReport {
+        static instance: ::protobuf::rt::LazyV2<ProfileReport> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ProfileReport::new)
+    }
+}
+
+impl ::protobuf::Clear for ProfileReport {
+    fn clear(&mut self) {
+        self.enabled = false;
+        self.counting_allocations = false;
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ProfileReport {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ProfileReport {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\rprofile.proto\"3\n\x13SetProfilingRequest\x12\x1a\n\x07enabled\x18\
+    \x01\x20\x01(\x08R\x07enabledB\0:\0\",\n\x14ProfileReportRequest\x12\x12\
+    \n\x03top\x18\x01\x20\x01(\x03R\x03topB\0:\0\"\x8c\x02\n\rProfiledEvent\
+    \x12\x16\n\x05event\x18\x01\x20\x01(\tR\x05eventB\0\x12\x16\n\x05count\
+    \x18\x02\x20\x01(\x03R\x05countB\0\x12\x1d\n\twall_time\x18\x03\x20\x01(\
+    \x01R\x08wallTimeB\0\x12$\n\rmax_wall_time\x18\x04\x20\x01(\x01R\x0bmaxW\
+    allTimeB\0\x12\x1d\n\tbusy_time\x18\x05\x20\x01(\x01R\x08busyTimeB\0\x12\
+    \x16\n\x05polls\x18\x06\x20\x01(\x03R\x05pollsB\0\x12\"\n\x0ballocations\
+    \x18\x07\x20\x01(\x03R\x0ballocationsB\0\x12)\n\x0fallocated_bytes\x18\
+    \x08\x20\x01(\x03R\x0eallocatedBytesB\0:\0\"\x8a\x01\n\rProfileReport\
+    \x12\x1a\n\x07enabled\x18\x01\x20\x01(\x08R\x07enabledB\0\x123\n\x14coun\
+    ting_allocations\x18\x02\x20\x01(\x08R\x13countingAllocationsB\0\x12&\n\
+    \x05items\x18\x03\x20\x03(\x0b2\x0e.ProfiledEventR\x05itemsB\0:\0B\0b\
+    \x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
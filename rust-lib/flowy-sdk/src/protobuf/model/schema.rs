@@ -0,0 +1,690 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `schema.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SchemaError {
+    // message fields
+    pub code: SchemaErrorCode,
+    pub msg: ::std::string::String,
+    pub compiled_version: i64,
+    pub compiled_hash: ::std::string::String,
+    pub found_version: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SchemaError {
+    fn default() -> &'a SchemaError {
+        <SchemaError as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SchemaError {
+    pub fn new() -> SchemaError {
+        ::std::default::Default::default()
+    }
+
+    // .SchemaErrorCode code = 1;
+
+
+    pub fn get_code(&self) -> SchemaErrorCode {
+        self.code
+    }
+    pub fn clear_code(&mut self) {
+        self.code = SchemaErrorCode::PersistedSchemaTooNew;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_code(&mut self, v: SchemaErrorCode) {
+        self.code = v;
+    }
+
+    // string msg = 2;
+
+
+    pub fn get_msg(&self) -> &str {
+        &self.msg
+    }
+    pub fn clear_msg(&mut self) {
+        self.msg.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_msg(&mut self, v: ::std::string::String) {
+        self.msg = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_msg(&mut self) -> &mut ::std::string::String {
+        &mut self.msg
+    }
+
+    // Take field
+    pub fn take_msg(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.msg, ::std::string::String::new())
+    }
+
+    // int64 compiled_version = 3;
+
+
+    pub fn get_compiled_version(&self) -> i64 {
+        self.compiled_version
+    }
+    pub fn clear_compiled_version(&mut self) {
+        self.compiled_version = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_compiled_version(&mut self, v: i64) {
+        self.compiled_version = v;
+    }
+
+    // string compiled_hash = 4;
+
+
+    pub fn get_compiled_hash(&self) -> &str {
+        &self.compiled_hash
+    }
+    pub fn clear_compiled_hash(&mut self) {
+        self.compiled_hash.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_compiled_hash(&mut self, v: ::std::string::String) {
+        self.compiled_hash = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_compiled_hash(&mut self) -> &mut ::std::string::String {
+        &mut self.compiled_hash
+    }
+
+    // Take field
+    pub fn take_compiled_hash(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.compiled_hash, ::std::string::String::new())
+    }
+
+    // int64 found_version = 5;
+
+
+    pub fn get_found_version(&self) -> i64 {
+        self.found_version
+    }
+    pub fn clear_found_version(&mut self) {
+        self.found_version = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_found_version(&mut self, v: i64) {
+        self.found_version = v;
+    }
+}
+
+impl ::protobuf::Message for SchemaError {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.code, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.msg)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.compiled_version = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.compiled_hash)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.found_version = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.code != SchemaErrorCode::PersistedSchemaTooNew {
+            my_size += ::protobuf::rt::enum_size(1, self.code);
+        }
+        if !self.msg.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.msg);
+        }
+        if self.compiled_version != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.compiled_version, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.compiled_hash.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.compiled_hash);
+        }
+        if self.found_version != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.found_version, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.code != SchemaErrorCode::PersistedSchemaTooNew {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.code))?;
+        }
+        if !self.msg.is_empty() {
+            os.write_string(2, &self.msg)?;
+        }
+        if self.compiled_version != 0 {
+            os.write_int64(3, self.compiled_version)?;
+        }
+        if !self.compiled_hash.is_empty() {
+            os.write_string(4, &self.compiled_hash)?;
+        }
+        if self.found_version != 0 {
+            os.write_int64(5, self.found_version)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SchemaError {
+        SchemaError::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<SchemaErrorCode>>(
+                "code",
+                |m: &SchemaError| { &m.code },
+                |m: &mut SchemaError| { &mut m.code },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "msg",
+                |m: &SchemaError| { &m.msg },
+                |m: &mut SchemaError| { &mut m.msg },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "compiled_version",
+                |m: &SchemaError| { &m.compiled_version },
+                |m: &mut SchemaError| { &mut m.compiled_version },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "compiled_hash",
+                |m: &SchemaError| { &m.compiled_hash },
+                |m: &mut SchemaError| { &mut m.compiled_hash },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "found_version",
+                |m: &SchemaError| { &m.found_version },
+                |m: &mut SchemaError| { &mut m.found_version },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SchemaError>(
+                "SchemaError",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SchemaError {
+        static instance: ::protobuf::rt::LazyV2<SchemaError> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SchemaError::new)
+    }
+}
+
+impl ::protobuf::Clear for SchemaError {
+    fn clear(&mut self) {
+        self.code = SchemaErrorCode::PersistedSchemaTooNew;
+        self.msg.clear();
+        self.compiled_version = 0;
+        self.compiled_hash.clear();
+        self.found_version = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SchemaError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SchemaError {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SchemaState {
+    // message fields
+    pub compiled_version: i64,
+    pub compiled_hash: ::std::string::String,
+    // message oneof groups
+    pub one_of_error: ::std::option::Option<SchemaState_oneof_one_of_error>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SchemaState {
+    fn default() -> &'a SchemaState {
+        <SchemaState as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum SchemaState_oneof_one_of_error {
+    error(SchemaError),
+}
+
+impl SchemaState {
+    pub fn new() -> SchemaState {
+        ::std::default::Default::default()
+    }
+
+    // int64 compiled_version = 1;
+
+
+    pub fn get_compiled_version(&self) -> i64 {
+        self.compiled_version
+    }
+    pub fn clear_compiled_version(&mut self) {
+        self.compiled_version = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_compiled_version(&mut self, v: i64) {
+        self.compiled_version = v;
+    }
+
+    // string compiled_hash = 2;
+
+
+    pub fn get_compiled_hash(&self) -> &str {
+        &self.compiled_hash
+    }
+    pub fn clear_compiled_hash(&mut self) {
+        self.compiled_hash.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_compiled_hash(&mut self, v: ::std::string::String) {
+        self.compiled_hash = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_compiled_hash(&mut self) -> &mut ::std::string::String {
+        &mut self.compiled_hash
+    }
+
+    // Take field
+    pub fn take_compiled_hash(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.compiled_hash, ::std::string::String::new())
+    }
+
+    // .SchemaError error = 3;
+
+
+    pub fn get_error(&self) -> &SchemaError {
+        match self.one_of_error {
+            ::std::option::Option::Some(SchemaState_oneof_one_of_error::error(ref v)) => v,
+            _ => <SchemaError as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_error(&mut self) {
+        self.one_of_error = ::std::option::Option::None;
+    }
+
+    pub fn has_error(&self) -> bool {
+        match self.one_of_error {
+            ::std::option::Option::Some(SchemaState_oneof_one_of_error::error(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: SchemaError) {
+        self.one_of_error = ::std::option::Option::Some(SchemaState_oneof_one_of_error::error(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_error(&mut self) -> &mut SchemaError {
+        if let ::std::option::Option::Some(SchemaState_oneof_one_of_error::error(_)) = self.one_of_error {
+        } else {
+            self.one_of_error = ::std::option::Option::Some(SchemaState_oneof_one_of_error::error(SchemaError::new()));
+        }
+        match self.one_of_error {
+            ::std::option::Option::Some(SchemaState_oneof_one_of_error::error(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> SchemaError {
+        if self.has_error() {
+            match self.one_of_error.take() {
+                ::std::option::Option::Some(SchemaState_oneof_one_of_error::error(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SchemaError::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for SchemaState {
+    fn is_initialized(&self) -> bool {
+        if let Some(SchemaState_oneof_one_of_error::error(ref v)) = self.one_of_error {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.compiled_version = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.compiled_hash)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_error = ::std::option::Option::Some(SchemaState_oneof_one_of_error::error(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.compiled_version != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.compiled_version, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.compiled_hash.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.compiled_hash);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_error {
+            match v {
+                &SchemaState_oneof_one_of_error::error(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.compiled_version != 0 {
+            os.write_int64(1, self.compiled_version)?;
+        }
+        if !self.compiled_hash.is_empty() {
+            os.write_string(2, &self.compiled_hash)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_error {
+            match v {
+                &SchemaState_oneof_one_of_error::error(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SchemaState {
+        SchemaState::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "compiled_version",
+                |m: &SchemaState| { &m.compiled_version },
+                |m: &mut SchemaState| { &mut m.compiled_version },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "compiled_hash",
+                |m: &SchemaState| { &m.compiled_hash },
+                |m: &mut SchemaState| { &mut m.compiled_hash },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, SchemaError>(
+                "error",
+                SchemaState::has_error,
+                SchemaState::get_error,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SchemaState>(
+                "SchemaState",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SchemaState {
+        static instance: ::protobuf::rt::LazyV2<SchemaState> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SchemaState::new)
+    }
+}
+
+impl ::protobuf::Clear for SchemaState {
+    fn clear(&mut self) {
+        self.compiled_version = 0;
+        self.compiled_hash.clear();
+        self.one_of_error = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SchemaState {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SchemaState {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum SchemaErrorCode {
+    PersistedSchemaTooNew = 0,
+    FrontendSchemaMismatch = 1,
+    SchemaChangedWithoutVersion = 2,
+}
+
+impl ::protobuf::ProtobufEnum for SchemaErrorCode {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<SchemaErrorCode> {
+        match value {
+            0 => ::std::option::Option::Some(SchemaErrorCode::PersistedSchemaTooNew),
+            1 => ::std::option::Option::Some(SchemaErrorCode::FrontendSchemaMismatch),
+            2 => ::std::option::Option::Some(SchemaErrorCode::SchemaChangedWithoutVersion),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [SchemaErrorCode] = &[
+            SchemaErrorCode::PersistedSchemaTooNew,
+            SchemaErrorCode::FrontendSchemaMismatch,
+            SchemaErrorCode::SchemaChangedWithoutVersion,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<SchemaErrorCode>("SchemaErrorCode", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for SchemaErrorCode {
+}
+
+impl ::std::default::Default for SchemaErrorCode {
+    fn default() -> Self {
+        SchemaErrorCode::PersistedSchemaTooNew
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SchemaErrorCode {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0cschema.proto\"\xc6\x01\n\x0bSchemaError\x12&\n\x04code\x18\x01\x20\
+    \x01(\x0e2\x10.SchemaErrorCodeR\x04codeB\0\x12\x12\n\x03msg\x18\x02\x20\
+    \x01(\tR\x03msgB\0\x12+\n\x10compiled_version\x18\x03\x20\x01(\x03R\x0fc\
+    ompiledVersionB\0\x12%\n\rcompiled_hash\x18\x04\x20\x01(\tR\x0ccompiledH\
+    ashB\0\x12%\n\rfound_version\x18\x05\x20\x01(\x03R\x0cfoundVersionB\0:\0\
+    \"\x9b\x01\n\x0bSchemaState\x12+\n\x10compiled_version\x18\x01\x20\x01(\
+    \x03R\x0fcompiledVersionB\0\x12%\n\rcompiled_hash\x18\x02\x20\x01(\tR\
+    \x0ccompiledHashB\0\x12&\n\x05error\x18\x03\x20\x01(\x0b2\x0c.SchemaErro\
+    rH\0R\x05errorB\0B\x0e\n\x0cone_of_error:\0*k\n\x0fSchemaErrorCode\x12\
+    \x19\n\x15PersistedSchemaTooNew\x10\0\x12\x1a\n\x16FrontendSchemaMismatc\
+    h\x10\x01\x12\x1f\n\x1bSchemaChangedWithoutVersion\x10\x02\x1a\0B\0b\x06\
+    proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,664 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `trace.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetTraceModeRequest {
+    // message fields
+    pub enabled: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetTraceModeRequest {
+    fn default() -> &'a SetTraceModeRequest {
+        <SetTraceModeRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetTraceModeRequest {
+    pub fn new() -> SetTraceModeRequest {
+        ::std::default::Default::default()
+    }
+
+    // bool enabled = 1;
+
+
+    pub fn get_enabled(&self) -> bool {
+        self.enabled
+    }
+    pub fn clear_enabled(&mut self) {
+        self.enabled = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enabled(&mut self, v: bool) {
+        self.enabled = v;
+    }
+}
+
+impl ::protobuf::Message for SetTraceModeRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.enabled = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.enabled != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.enabled != false {
+            os.write_bool(1, self.enabled)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetTraceModeRequest {
+        SetTraceModeRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "enabled",
+                |m: &SetTraceModeRequest| { &m.enabled },
+                |m: &mut SetTraceModeRequest| { &mut m.enabled },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetTraceModeRequest>(
+                "SetTraceModeRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetTraceModeRequest {
+        static instance: ::protobuf::rt::LazyV2<SetTraceModeRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetTraceModeRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SetTraceModeRequest {
+    fn clear(&mut self) {
+        self.enabled = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetTraceModeRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetTraceModeRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TraceRecord {
+    // message fields
+    pub event: ::std::string::String,
+    pub serialization_us: i64,
+    pub queue_wait_us: i64,
+    pub handler_us: i64,
+    pub response_size: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TraceRecord {
+    fn default() -> &'a TraceRecord {
+        <TraceRecord as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TraceRecord {
+    pub fn new() -> TraceRecord {
+        ::std::default::Default::default()
+    }
+
+    // string event = 1;
+
+
+    pub fn get_event(&self) -> &str {
+        &self.event
+    }
+    pub fn clear_event(&mut self) {
+        self.event.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_event(&mut self, v: ::std::string::String) {
+        self.event = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_event(&mut self) -> &mut ::std::string::String {
+        &mut self.event
+    }
+
+    // Take field
+    pub fn take_event(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.event, ::std::string::String::new())
+    }
+
+    // int64 serialization_us = 2;
+
+
+    pub fn get_serialization_us(&self) -> i64 {
+        self.serialization_us
+    }
+    pub fn clear_serialization_us(&mut self) {
+        self.serialization_us = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_serialization_us(&mut self, v: i64) {
+        self.serialization_us = v;
+    }
+
+    // int64 queue_wait_us = 3;
+
+
+    pub fn get_queue_wait_us(&self) -> i64 {
+        self.queue_wait_us
+    }
+    pub fn clear_queue_wait_us(&mut self) {
+        self.queue_wait_us = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_queue_wait_us(&mut self, v: i64) {
+        self.queue_wait_us = v;
+    }
+
+    // int64 handler_us = 4;
+
+
+    pub fn get_handler_us(&self) -> i64 {
+        self.handler_us
+    }
+    pub fn clear_handler_us(&mut self) {
+        self.handler_us = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_handler_us(&mut self, v: i64) {
+        self.handler_us = v;
+    }
+
+    // int64 response_size = 5;
+
+
+    pub fn get_response_size(&self) -> i64 {
+        self.response_size
+    }
+    pub fn clear_response_size(&mut self) {
+        self.response_size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_response_size(&mut self, v: i64) {
+        self.response_size = v;
+    }
+}
+
+impl ::protobuf::Message for TraceRecord {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.event)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.serialization_us = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.queue_wait_us = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.handler_us = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.response_size = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.event.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.event);
+        }
+        if self.serialization_us != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.serialization_us, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.queue_wait_us != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.queue_wait_us, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.handler_us != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.handler_us, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.response_size != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.response_size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.event.is_empty() {
+            os.write_string(1, &self.event)?;
+        }
+        if self.serialization_us != 0 {
+            os.write_int64(2, self.serialization_us)?;
+        }
+        if self.queue_wait_us != 0 {
+            os.write_int64(3, self.queue_wait_us)?;
+        }
+        if self.handler_us != 0 {
+            os.write_int64(4, self.handler_us)?;
+        }
+        if self.response_size != 0 {
+            os.write_int64(5, self.response_size)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TraceRecord {
+        TraceRecord::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "event",
+                |m: &TraceRecord| { &m.event },
+                |m: &mut TraceRecord| { &mut m.event },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "serialization_us",
+                |m: &TraceRecord| { &m.serialization_us },
+                |m: &mut TraceRecord| { &mut m.serialization_us },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "queue_wait_us",
+                |m: &TraceRecord| { &m.queue_wait_us },
+                |m: &mut TraceRecord| { &mut m.queue_wait_us },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "handler_us",
+                |m: &TraceRecord| { &m.handler_us },
+                |m: &mut TraceRecord| { &mut m.handler_us },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "response_size",
+                |m: &TraceRecord| { &m.response_size },
+                |m: &mut TraceRecord| { &mut m.response_size },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<TraceRecord>(
+                "TraceRecord",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static TraceRecord {
+        static instance: ::protobuf::rt::LazyV2<TraceRecord> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(TraceRecord::new)
+    }
+}
+
+impl ::protobuf::Clear for TraceRecord {
+    fn clear(&mut self) {
+        self.event.clear();
+        self.serialization_us = 0;
+        self.queue_wait_us = 0;
+        self.handler_us = 0;
+        self.response_size = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TraceRecord {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TraceRecord {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedTraceRecord {
+    // message fields
+    pub items: ::protobuf::RepeatedField<TraceRecord>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedTraceRecord {
+    fn default() -> &'a RepeatedTraceRecord {
+        <RepeatedTraceRecord as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedTraceRecord {
+    pub fn new() -> RepeatedTraceRecord {
+        ::std::default::Default::default()
+    }
+
+    // repeated .TraceRecord items = 1;
+
+
+    pub fn get_items(&self) -> &[TraceRecord] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<TraceRecord>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<TraceRecord> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<TraceRecord> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedTraceRecord {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedTraceRecord {
+        RepeatedTraceRecord::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TraceRecord>>(
+                "items",
+                |m: &RepeatedTraceRecord| { &m.items },
+                |m: &mut RepeatedTraceRecord| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedTraceRecord>(
+                "RepeatedTraceRecord",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedTraceRecord {
+        static instance: ::protobuf::rt::LazyV2<RepeatedTraceRecord> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedTraceRecord::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedTraceRecord {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedTraceRecord {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedTraceRecord {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0btrace.proto\"3\n\x13SetTraceModeRequest\x12\x1a\n\x07enabled\x18\
+    \x01\x20\x01(\x08R\x07enabledB\0:\0\"\xc2\x01\n\x0bTraceRecord\x12\x16\n\
+    \x05event\x18\x01\x20\x01(\tR\x05eventB\0\x12+\n\x10serialization_us\x18\
+    \x02\x20\x01(\x03R\x0fserializationUsB\0\x12$\n\rqueue_wait_us\x18\x03\
+    \x20\x01(\x03R\x0bqueueWaitUsB\0\x12\x1f\n\nhandler_us\x18\x04\x20\x01(\
+    \x03R\thandlerUsB\0\x12%\n\rresponse_size\x18\x05\x20\x01(\x03R\x0crespo\
+    nseSizeB\0:\0\"=\n\x13RepeatedTraceRecord\x12$\n\x05items\x18\x01\x20\
+    \x03(\x0b2\x0c.TraceRecordR\x05itemsB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,172 @@
+use crate::{
+    entities::{SchemaError, SchemaErrorCode, SchemaState},
+    notify::{dart_notify, SDKNotification},
+};
+use flowy_infra::kv::KV;
+use parking_lot::RwLock;
+use protobuf::Message;
+
+// Bump the version whenever the persisted or transmitted payloads change in a
+// way that the previous version can't decode.
+pub const SCHEMA_VERSION: i64 = 1;
+
+const SCHEMA_VERSION_CACHE_KEY: &str = "schema_version";
+const SCHEMA_HASH_CACHE_KEY: &str = "schema_hash";
+
+// The error of the verification when the sdk was initialized, it's kept until
+// the next initialization.
+static SCHEMA_ERROR: RwLock<Option<SchemaError>> = parking_lot::const_rwlock(None);
+
+// The manifest of the compiled protobuf descriptors that get persisted or sent
+// to the frontend.
+fn schema_manifest() -> Vec<&'static protobuf::reflect::MessageDescriptor> {
+    vec![
+        flowy_user_infra::protobuf::UserProfile::descriptor_static(),
+        flowy_workspace_infra::protobuf::Workspace::descriptor_static(),
+        flowy_workspace_infra::protobuf::App::descriptor_static(),
+        flowy_workspace_infra::protobuf::View::descriptor_static(),
+        flowy_workspace_infra::protobuf::Trash::descriptor_static(),
+        flowy_document_infra::protobuf::Doc::descriptor_static(),
+        flowy_document_infra::protobuf::Revision::descriptor_static(),
+    ]
+}
+
+pub fn schema_hash() -> String {
+    // FNV-1a, stable across builds unlike the std hasher
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for descriptor in schema_manifest() {
+        let bytes = descriptor.get_proto().write_to_bytes().unwrap_or_default();
+        for byte in bytes {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+    format!("{:016x}", hash)
+}
+
+pub(crate) fn verify_schema(frontend_version: Option<i64>) -> Result<(), SchemaError> {
+    let compiled_hash = schema_hash();
+    let mk_error = |code: SchemaErrorCode, found_version: i64, msg: String| SchemaError {
+        code,
+        msg,
+        compiled_version: SCHEMA_VERSION,
+        compiled_hash: compiled_hash.clone(),
+        found_version,
+    };
+
+    if let Some(persisted_version) = KV::get_int(SCHEMA_VERSION_CACHE_KEY) {
+        if persisted_version > SCHEMA_VERSION {
+            let msg = format!(
+                "The local data was written with schema v{}, but this build only supports v{}. Please upgrade the app",
+                persisted_version, SCHEMA_VERSION
+            );
+            return Err(mk_error(SchemaErrorCode::PersistedSchemaTooNew, persisted_version, msg));
+        }
+
+        if persisted_version == SCHEMA_VERSION {
+            match KV::get_str(SCHEMA_HASH_CACHE_KEY) {
+                Some(hash) if hash != compiled_hash => {
+                    let msg = format!(
+                        "The schema hash changed from {} to {} without bumping the SCHEMA_VERSION v{}",
+                        hash, compiled_hash, SCHEMA_VERSION
+                    );
+                    return Err(mk_error(
+                        SchemaErrorCode::SchemaChangedWithoutVersion,
+                        persisted_version,
+                        msg,
+                    ));
+                },
+                _ => {},
+            }
+        }
+    }
+
+    if let Some(frontend_version) = frontend_version {
+        if frontend_version != SCHEMA_VERSION {
+            let msg = format!(
+                "The frontend declares schema v{}, but the sdk is built with v{}. Please rebuild the frontend",
+                frontend_version, SCHEMA_VERSION
+            );
+            return Err(mk_error(SchemaErrorCode::FrontendSchemaMismatch, frontend_version, msg));
+        }
+    }
+
+    KV::set_int(SCHEMA_VERSION_CACHE_KEY, SCHEMA_VERSION);
+    KV::set_str(SCHEMA_HASH_CACHE_KEY, compiled_hash);
+    Ok(())
+}
+
+// Keeps the result of the verification, the error is also sent with the
+// SchemaIncompatible notification to the observers that are already attached.
+pub(crate) fn init_schema_state(result: Result<(), SchemaError>) {
+    match result {
+        Ok(_) => *SCHEMA_ERROR.write() = None,
+        Err(error) => {
+            tracing::error!("{}", error);
+            *SCHEMA_ERROR.write() = Some(error.clone());
+            dart_notify("", SDKNotification::SchemaIncompatible).error(error).send();
+        },
+    }
+}
+
+pub fn schema_state() -> SchemaState {
+    SchemaState {
+        compiled_version: SCHEMA_VERSION,
+        compiled_hash: schema_hash(),
+        error: SCHEMA_ERROR.read().clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        entities::SchemaErrorCode,
+        schema::{
+            init_schema_state,
+            schema_hash,
+            schema_state,
+            verify_schema,
+            SCHEMA_HASH_CACHE_KEY,
+            SCHEMA_VERSION,
+            SCHEMA_VERSION_CACHE_KEY,
+        },
+    };
+    use flowy_infra::kv::KV;
+
+    #[test]
+    fn schema_verify_test() {
+        let dir = "./temp/";
+        if !std::path::Path::new(dir).exists() {
+            std::fs::create_dir_all(dir).unwrap();
+        }
+        KV::init(dir).unwrap();
+        assert_eq!(schema_hash(), schema_hash());
+
+        verify_schema(Some(SCHEMA_VERSION)).unwrap();
+        assert_eq!(KV::get_int(SCHEMA_VERSION_CACHE_KEY).unwrap(), SCHEMA_VERSION);
+
+        let error = verify_schema(Some(SCHEMA_VERSION + 1)).unwrap_err();
+        assert_eq!(error.code, SchemaErrorCode::FrontendSchemaMismatch);
+
+        KV::set_int(SCHEMA_VERSION_CACHE_KEY, SCHEMA_VERSION + 1);
+        let error = verify_schema(None).unwrap_err();
+        assert_eq!(error.code, SchemaErrorCode::PersistedSchemaTooNew);
+        KV::set_int(SCHEMA_VERSION_CACHE_KEY, SCHEMA_VERSION);
+
+        KV::set_str(SCHEMA_HASH_CACHE_KEY, "0000000000000000".to_owned());
+        let result = verify_schema(None);
+        assert_eq!(
+            result.clone().unwrap_err().code,
+            SchemaErrorCode::SchemaChangedWithoutVersion
+        );
+        init_schema_state(result);
+        assert_eq!(
+            schema_state().error.unwrap().code,
+            SchemaErrorCode::SchemaChangedWithoutVersion
+        );
+
+        KV::set_str(SCHEMA_HASH_CACHE_KEY, schema_hash());
+        init_schema_state(verify_schema(None));
+        assert!(schema_state().error.is_none());
+    }
+}
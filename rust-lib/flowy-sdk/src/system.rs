@@ -0,0 +1,381 @@
+use crate::{
+    crash::{list_crash_bundles, read_crash_bundle},
+    entities::{
+        CrashBundleContent,
+        CrashBundleIdentifier,
+        LocaleSetting,
+        LogFilterSetting,
+        LogRecord,
+        MemoryUsage,
+        MetricsSnapshot,
+        ProfileReport,
+        ProfileReportRequest,
+        RepeatedCrashBundle,
+        RepeatedLogRecord,
+        RepeatedTraceRecord,
+        SchemaState,
+        SetLocaleRequest,
+        SetLogFilterRequest,
+        SetProfilingRequest,
+        SetTraceModeRequest,
+        SubscribeLogRequest,
+        SubsystemMemory,
+        Translation,
+        Translations,
+        TranslationsRequest,
+    },
+    notify::{dart_notify, SDKNotification},
+    schema::schema_state,
+};
+use flowy_dispatch::prelude::*;
+use flowy_document::module::FlowyDocument;
+use flowy_infra::{kv::KV, memory::MemoryBudget};
+use flowy_log::{log_buffer, LogFilter};
+use std::{fmt, sync::Arc};
+
+// The id of the LogRecordReceived notifications.
+pub const LOG_NOTIFICATION_ID: &str = "__system/log";
+
+// The id of the LocaleChanged notifications.
+pub const LOCALE_NOTIFICATION_ID: &str = "__system/locale";
+
+const LOG_FILTER_CACHE_KEY: &str = "log_filter";
+
+const LOCALE_CACHE_KEY: &str = "locale";
+
+// The events of the sdk itself. They're sent with their names, e.g.
+// "__system/metrics", they aren't generated with the ones of the modules.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum SystemEvent {
+    // Returns the MetricsSnapshot of the dispatcher, the databases and the sync.
+    Metrics,
+
+    // Measures each handler invocation while it's enabled, with SetProfilingRequest.
+    SetProfiling,
+
+    // Returns the ProfileReport of the most expensive events.
+    ProfileReport,
+
+    // Returns the recent log records that match the SubscribeLogRequest, then
+    // sends the new ones with LogRecordReceived notifications.
+    SubscribeLog,
+
+    // Stops the LogRecordReceived notifications.
+    UnsubscribeLog,
+
+    // Returns the crash bundles that were written, the newest first.
+    ListCrashBundles,
+
+    // Returns the content of the crash bundle, to attach it to a bug report.
+    ReadCrashBundle,
+
+    // Returns the MemoryUsage of the subsystems and of the process.
+    Memory,
+
+    // Replaces the log filter with the one of the SetLogFilterRequest, it's kept
+    // after a restart until it's reset.
+    SetLogFilter,
+
+    // Returns the LogFilterSetting the records pass now.
+    ReadLogFilter,
+
+    // The host is low on memory, the caches of the memory budget are emptied.
+    // The evicted documents are opened again by their next change.
+    LowMemory,
+
+    // Returns the Translations of the locale of the TranslationsRequest.
+    GetTranslations,
+
+    // Sets the locale the errors and the notifications are translated with, it's
+    // kept after a restart. The LocaleChanged notification is sent if it changes.
+    SetLocale,
+
+    // Returns the LocaleSetting.
+    ReadLocale,
+
+    // Traces the events sent through the ffi while it's enabled, with
+    // SetTraceModeRequest. The traces are dropped when it's disabled.
+    SetTraceMode,
+
+    // Returns the RepeatedTraceRecord of the last events and clears them.
+    DumpTrace,
+
+    // Returns the SchemaState, with the error of the verification when the sdk
+    // was initialized, before the frontend could observe the notification.
+    ReadSchemaState,
+}
+
+impl fmt::Display for SystemEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SystemEvent::Metrics => f.write_str("__system/metrics"),
+            SystemEvent::SetProfiling => f.write_str("__system/set_profiling"),
+            SystemEvent::ProfileReport => f.write_str("__system/profile_report"),
+            SystemEvent::SubscribeLog => f.write_str("__system/subscribe_log"),
+            SystemEvent::UnsubscribeLog => f.write_str("__system/unsubscribe_log"),
+            SystemEvent::ListCrashBundles => f.write_str("__system/list_crash_bundles"),
+            SystemEvent::ReadCrashBundle => f.write_str("__system/read_crash_bundle"),
+            SystemEvent::Memory => f.write_str("__system/memory"),
+            SystemEvent::SetLogFilter => f.write_str("__system/set_log_filter"),
+            SystemEvent::ReadLogFilter => f.write_str("__system/read_log_filter"),
+            SystemEvent::LowMemory => f.write_str("__system/low_memory"),
+            SystemEvent::GetTranslations => f.write_str("__system/get_translations"),
+            SystemEvent::SetLocale => f.write_str("__system/set_locale"),
+            SystemEvent::ReadLocale => f.write_str("__system/read_locale"),
+            SystemEvent::SetTraceMode => f.write_str("__system/set_trace_mode"),
+            SystemEvent::DumpTrace => f.write_str("__system/dump_trace"),
+            SystemEvent::ReadSchemaState => f.write_str("__system/read_schema_state"),
+        }
+    }
+}
+
+pub fn create(flowy_document: Arc<FlowyDocument>, memory_budget: Arc<MemoryBudget>) -> Module {
+    Module::new()
+        .name("Flowy-System")
+        .data(flowy_document)
+        .data(memory_budget)
+        .event(SystemEvent::Metrics, metrics_handler)
+        .event(SystemEvent::SetProfiling, set_profiling_handler)
+        .event(SystemEvent::ProfileReport, profile_report_handler)
+        .event(SystemEvent::SubscribeLog, subscribe_log_handler)
+        .event(SystemEvent::UnsubscribeLog, unsubscribe_log_handler)
+        .event(SystemEvent::ListCrashBundles, list_crash_bundles_handler)
+        .event(SystemEvent::ReadCrashBundle, read_crash_bundle_handler)
+        .event(SystemEvent::Memory, memory_handler)
+        .event(SystemEvent::SetLogFilter, set_log_filter_handler)
+        .event(SystemEvent::ReadLogFilter, read_log_filter_handler)
+        .event(SystemEvent::LowMemory, low_memory_handler)
+        .event(SystemEvent::GetTranslations, get_translations_handler)
+        .event(SystemEvent::SetLocale, set_locale_handler)
+        .event(SystemEvent::ReadLocale, read_locale_handler)
+        .event(SystemEvent::SetTraceMode, set_trace_mode_handler)
+        .event(SystemEvent::DumpTrace, dump_trace_handler)
+        .event(SystemEvent::ReadSchemaState, read_schema_state_handler)
+}
+
+// Applies the filter that was set before the restart, the kv store must be
+// initialized.
+pub(crate) fn restore_log_filter() {
+    if let Some(filter) = KV::get_str(LOG_FILTER_CACHE_KEY) {
+        if let Err(e) = flowy_log::set_log_filter(&filter) {
+            tracing::error!("Restore the log filter failed: {}", e);
+            let _ = KV::remove(LOG_FILTER_CACHE_KEY);
+        }
+    }
+}
+
+// Applies the locale that was set before the restart, the kv store must be
+// initialized.
+pub(crate) fn restore_locale() {
+    if let Some(locale) = KV::get_str(LOCALE_CACHE_KEY) {
+        if let Err(e) = flowy_i18n::catalog().set_locale(&locale) {
+            tracing::error!("Restore the locale failed: {}", e);
+            let _ = KV::remove(LOCALE_CACHE_KEY);
+        }
+    }
+}
+
+#[tracing::instrument]
+pub async fn metrics_handler() -> Data<MetricsSnapshot> { Data(flowy_metrics::registry().snapshot().into()) }
+
+#[tracing::instrument(skip(data))]
+pub async fn set_profiling_handler(data: Data<SetProfilingRequest>) {
+    profiler().set_enabled(data.into_inner().enabled);
+}
+
+// The top 10 events if the top isn't set.
+#[tracing::instrument(skip(data))]
+pub async fn profile_report_handler(data: Data<ProfileReportRequest>) -> Data<ProfileReport> {
+    let top = match data.into_inner().top {
+        top if top > 0 => top as usize,
+        _ => 10,
+    };
+    let profiler = profiler();
+    Data(ProfileReport {
+        enabled: profiler.is_enabled(),
+        counting_allocations: profiler.is_counting_allocations(),
+        items: profiler.report(top).into_iter().map(|profile| profile.into()).collect(),
+    })
+}
+
+#[tracing::instrument(skip(data))]
+pub async fn set_trace_mode_handler(data: Data<SetTraceModeRequest>) {
+    tracer().set_enabled(data.into_inner().enabled);
+}
+
+#[tracing::instrument]
+pub async fn dump_trace_handler() -> Data<RepeatedTraceRecord> {
+    let items = tracer().dump().into_iter().map(|trace| trace.into()).collect();
+    Data(RepeatedTraceRecord { items })
+}
+
+#[tracing::instrument]
+pub async fn read_schema_state_handler() -> Data<SchemaState> { Data(schema_state()) }
+
+#[tracing::instrument(skip(data))]
+pub async fn subscribe_log_handler(data: Data<SubscribeLogRequest>) -> Data<RepeatedLogRecord> {
+    let filter = log_filter(data.into_inner());
+    let items = log_buffer()
+        .records(&filter)
+        .into_iter()
+        .map(|record| record.into())
+        .collect();
+    log_buffer().subscribe(
+        filter,
+        Arc::new(|record| {
+            dart_notify(LOG_NOTIFICATION_ID, SDKNotification::LogRecordReceived)
+                .payload(LogRecord::from(record.clone()))
+                .send()
+        }),
+    );
+    Data(RepeatedLogRecord { items })
+}
+
+#[tracing::instrument]
+pub async fn unsubscribe_log_handler() { log_buffer().unsubscribe(); }
+
+#[tracing::instrument]
+pub async fn list_crash_bundles_handler() -> Data<RepeatedCrashBundle> {
+    Data(RepeatedCrashBundle {
+        items: list_crash_bundles(),
+    })
+}
+
+#[tracing::instrument(skip(data))]
+pub async fn read_crash_bundle_handler(
+    data: Data<CrashBundleIdentifier>,
+) -> DataResult<CrashBundleContent, DispatchError> {
+    let name = data.into_inner().name;
+    let content = read_crash_bundle(&name)?;
+    data_result(CrashBundleContent { name, content })
+}
+
+#[tracing::instrument(skip(flowy_document, memory_budget))]
+pub async fn memory_handler(
+    flowy_document: Unit<Arc<FlowyDocument>>,
+    memory_budget: Unit<Arc<MemoryBudget>>,
+) -> Data<MemoryUsage> {
+    let document = flowy_document.memory_usage().await;
+    let (log_records, log_bytes) = log_buffer().memory_usage();
+    let metrics = flowy_metrics::registry().snapshot();
+    let mut items = vec![
+        SubsystemMemory::new(
+            "document",
+            "open_documents",
+            document.open_documents,
+            document.document_bytes as u64,
+        ),
+        SubsystemMemory::new(
+            "document",
+            "cached_revisions",
+            document.cached_revisions,
+            document.cached_revision_bytes as u64,
+        ),
+        SubsystemMemory::new("document", "pending_revisions", document.pending_revisions, 0),
+        SubsystemMemory::new("document", "unlocked_keys", document.unlocked_keys, 0),
+        SubsystemMemory::new("document", "running_transfers", document.running_transfers, 0),
+        SubsystemMemory::new("document", "blobs", document.blobs, document.blob_bytes),
+        SubsystemMemory::new("log", "records", log_records, log_bytes as u64),
+        SubsystemMemory::new(
+            "metrics",
+            "series",
+            metrics.counters.len() + metrics.histograms.len(),
+            0,
+        ),
+    ];
+    for (name, count, bytes) in memory_budget.usage() {
+        items.push(SubsystemMemory::new("memory_budget", name, count, bytes as u64));
+    }
+    Data(MemoryUsage {
+        rss: process_rss(),
+        items,
+    })
+}
+
+#[tracing::instrument(skip(memory_budget))]
+pub async fn low_memory_handler(memory_budget: Unit<Arc<MemoryBudget>>) {
+    let released = memory_budget.trim(0);
+    tracing::info!("Release {} bytes of the caches on low memory", released);
+}
+
+#[tracing::instrument(skip(data))]
+pub async fn set_log_filter_handler(data: Data<SetLogFilterRequest>) -> DataResult<LogFilterSetting, DispatchError> {
+    let filter = data.into_inner().filter.trim().to_owned();
+    if filter.is_empty() {
+        let _ = flowy_log::reset_log_filter()?;
+        let _ = KV::remove(LOG_FILTER_CACHE_KEY);
+    } else {
+        let _ = flowy_log::set_log_filter(&filter)?;
+        KV::set_str(LOG_FILTER_CACHE_KEY, filter);
+    }
+    data_result(log_filter_setting())
+}
+
+#[tracing::instrument]
+pub async fn read_log_filter_handler() -> Data<LogFilterSetting> { Data(log_filter_setting()) }
+
+#[tracing::instrument(skip(data))]
+pub async fn get_translations_handler(data: Data<TranslationsRequest>) -> DataResult<Translations, DispatchError> {
+    let catalog = flowy_i18n::catalog();
+    let locale = match data.into_inner().locale {
+        locale if locale.trim().is_empty() => catalog.locale(),
+        locale => locale,
+    };
+    let (locale, items) = catalog.translations(&locale)?;
+    data_result(Translations {
+        locale,
+        items: items
+            .into_iter()
+            .map(|(key, value)| Translation { key, value })
+            .collect(),
+    })
+}
+
+#[tracing::instrument(skip(data))]
+pub async fn set_locale_handler(data: Data<SetLocaleRequest>) -> DataResult<LocaleSetting, DispatchError> {
+    let catalog = flowy_i18n::catalog();
+    let previous = catalog.locale();
+    let locale = catalog.set_locale(&data.into_inner().locale)?;
+    KV::set_str(LOCALE_CACHE_KEY, locale.clone());
+    let setting = locale_setting();
+    if previous != locale {
+        dart_notify(LOCALE_NOTIFICATION_ID, SDKNotification::LocaleChanged)
+            .payload(setting.clone())
+            .send();
+    }
+    data_result(setting)
+}
+
+#[tracing::instrument]
+pub async fn read_locale_handler() -> Data<LocaleSetting> { Data(locale_setting()) }
+
+fn locale_setting() -> LocaleSetting {
+    let catalog = flowy_i18n::catalog();
+    LocaleSetting {
+        locale: catalog.locale(),
+        locales: catalog.locales(),
+    }
+}
+
+fn log_filter_setting() -> LogFilterSetting {
+    LogFilterSetting {
+        filter: flowy_log::log_filter().unwrap_or_default(),
+        is_default: flowy_log::is_default_log_filter(),
+    }
+}
+
+// The VmRSS of /proc, it's only there on linux and android.
+fn process_rss() -> Option<i64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+    let kb = line.split_whitespace().nth(1)?.parse::<i64>().ok()?;
+    Some(kb * 1024)
+}
+
+// The level that can't be read is info, like the empty one.
+fn log_filter(request: SubscribeLogRequest) -> LogFilter {
+    LogFilter {
+        level: request.level.parse().unwrap_or(tracing::Level::INFO),
+        targets: request.targets,
+    }
+}
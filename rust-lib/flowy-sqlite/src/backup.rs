@@ -0,0 +1,87 @@
+use crate::{
+    cipher::{set_key, DatabaseKey},
+    conn_ext::ConnectionExtension,
+    errors::*,
+};
+use diesel::{
+    dsl::sql,
+    sql_types::{BigInt, Text},
+    Connection,
+    RunQueryDsl,
+    SqliteConnection,
+};
+use std::path::Path;
+
+// Writes a copy of the database of the connection to the path with VACUUM INTO.
+// The copy is consistent while the other connections write, and it's encrypted
+// with the key of the database if it has one.
+pub fn backup_database(conn: &SqliteConnection, path: &str) -> Result<()> {
+    let _ = conn.exec(format!("VACUUM INTO {}", quote_literal(path)))?;
+    Ok(())
+}
+
+// Opens the database at the uri and runs SQLite's integrity check on it, the
+// key is needed for the encrypted ones.
+pub fn check_integrity(uri: &str, key: Option<&DatabaseKey>) -> Result<()> {
+    // Establishing the connection would create the missing file.
+    if !Path::new(uri).exists() {
+        return Err(ErrorKind::IntegrityCheckFailed(format!("{} doesn't exist", uri)).into());
+    }
+    let conn = SqliteConnection::establish(uri)?;
+    if let Some(key) = key {
+        let _ = set_key(&conn, key).map_err(|e| ErrorKind::IntegrityCheckFailed(format!("{}", e)))?;
+    }
+    let messages = integrity_errors(&conn).map_err(|e| ErrorKind::IntegrityCheckFailed(format!("{}", e)))?;
+    if !messages.is_empty() {
+        return Err(ErrorKind::IntegrityCheckFailed(messages.join(", ")).into());
+    }
+    Ok(())
+}
+
+// The problems SQLite's integrity check finds in the database of the
+// connection, empty if there are none.
+pub fn integrity_errors(conn: &SqliteConnection) -> Result<Vec<String>> {
+    let messages = sql::<Text>("PRAGMA integrity_check").load::<String>(conn)?;
+    Ok(messages.into_iter().filter(|message| message != "ok").collect())
+}
+
+// Replaces the rows of every table of the connection's database with the ones
+// of the database at the uri, in one transaction. Both must have the same
+// schema. The other connections keep working while it runs, unlike replacing
+// the file.
+pub fn copy_database(conn: &SqliteConnection, uri: &str, key: Option<&DatabaseKey>) -> Result<()> {
+    let attach = match key {
+        None => format!("ATTACH DATABASE {} AS source", quote_literal(uri)),
+        Some(key) => format!(
+            "ATTACH DATABASE {} AS source KEY {}",
+            quote_literal(uri),
+            key.pragma_value()
+        ),
+    };
+    let _ = conn.exec(attach)?;
+    let copied = conn.immediate_transaction::<_, Error, _>(|| {
+        let tables = sql::<Text>(
+            "SELECT name FROM main.sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%' ORDER BY rowid",
+        )
+        .load::<String>(conn)?;
+        for table in tables {
+            let table = quote_identifier(&table);
+            let _ = conn.exec(format!("DELETE FROM main.{}", table))?;
+            let _ = conn.exec(format!("INSERT INTO main.{0} SELECT * FROM source.{0}", table))?;
+        }
+        // The next ids of the AUTOINCREMENT tables.
+        let sequences = sql::<BigInt>("SELECT count(*) FROM source.sqlite_master WHERE name = 'sqlite_sequence'")
+            .get_result::<i64>(conn)?;
+        if sequences > 0 {
+            let _ = conn.exec("DELETE FROM main.sqlite_sequence")?;
+            let _ = conn.exec("INSERT INTO main.sqlite_sequence SELECT * FROM source.sqlite_sequence")?;
+        }
+        Ok(())
+    });
+    let _ = conn.exec("DETACH DATABASE source")?;
+    copied
+}
+
+pub(crate) fn quote_literal(value: &str) -> String { format!("'{}'", value.replace('\'', "''")) }
+
+pub(crate) fn quote_identifier(name: &str) -> String { format!("\"{}\"", name.replace('"', "\"\"")) }
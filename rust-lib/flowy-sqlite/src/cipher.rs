@@ -0,0 +1,188 @@
+use crate::{backup::quote_identifier, conn_ext::ConnectionExtension, errors::*};
+use diesel::{
+    dsl::sql,
+    sql_types::{BigInt, Text},
+    Connection,
+    RunQueryDsl,
+    SqliteConnection,
+};
+use std::{fmt, fs::File, io::Read};
+
+const KEY_LEN: usize = 32;
+
+// The first bytes of the databases that SQLite writes, the ones encrypted by
+// SQLCipher start with the salt instead.
+const PLAINTEXT_HEADER: &[u8; 16] = b"SQLite format 3\0";
+
+// The raw key of a database encrypted by SQLCipher, it's used as is instead of
+// being derived from a passphrase by PBKDF2.
+#[derive(Clone)]
+pub struct DatabaseKey([u8; KEY_LEN]);
+
+impl DatabaseKey {
+    pub fn new(key: [u8; KEY_LEN]) -> Self { Self(key) }
+
+    // The value of the key pragma, e.g. "x'2DD29CA8...'".
+    pub(crate) fn pragma_value(&self) -> String {
+        let hex: String = self.0.iter().map(|b| format!("{:02x}", b)).collect();
+        format!("\"x'{}'\"", hex)
+    }
+}
+
+// The key is never written to the logs.
+impl fmt::Debug for DatabaseKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("DatabaseKey(..)") }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct EncryptionProgress {
+    // The tables copied to the encrypted database.
+    pub done: usize,
+    pub total: usize,
+}
+
+// Whether the SQLite that's linked is SQLCipher, see the sqlcipher feature.
+pub fn is_cipher_available() -> bool {
+    SqliteConnection::establish(":memory:")
+        .map(|conn| is_cipher_supported(&conn))
+        .unwrap_or(false)
+}
+
+// SQLite ignores the pragmas it doesn't know, so the key pragma doesn't fail
+// when the library wasn't built with SQLCipher.
+fn is_cipher_supported(conn: &SqliteConnection) -> bool {
+    sql::<Text>("PRAGMA cipher_version")
+        .load::<String>(conn)
+        .map(|versions| !versions.is_empty())
+        .unwrap_or(false)
+}
+
+// Returns false if the file doesn't exist.
+pub fn is_plaintext_database(uri: &str) -> bool {
+    let mut header = [0u8; PLAINTEXT_HEADER.len()];
+    match File::open(uri) {
+        Ok(mut file) => file.read_exact(&mut header).is_ok() && &header == PLAINTEXT_HEADER,
+        Err(_) => false,
+    }
+}
+
+// It must be the first statement of the connection.
+pub(crate) fn set_key(conn: &SqliteConnection, key: &DatabaseKey) -> Result<()> {
+    if !is_cipher_supported(conn) {
+        return Err(ErrorKind::CipherUnsupported.into());
+    }
+    let _ = conn.exec(format!("PRAGMA key = {}", key.pragma_value()))?;
+    // The key is only checked when the database is read.
+    let _ = conn.exec("SELECT count(*) FROM sqlite_master")?;
+    Ok(())
+}
+
+// Copies the plaintext database at the uri to a new one encrypted with the key,
+// then replaces it. The progress is called after each table. The plaintext
+// database is kept if the copy fails, the next attempt starts over.
+pub fn encrypt_database<F>(uri: &str, key: &DatabaseKey, progress: F) -> Result<()>
+where
+    F: Fn(EncryptionProgress),
+{
+    let encrypted_uri = format!("{}.encrypting", uri);
+    let _ = remove_database_files(&encrypted_uri);
+    match copy_to_encrypted(uri, &encrypted_uri, key, progress) {
+        Ok(_) => {
+            std::fs::rename(&encrypted_uri, uri)?;
+            // They belong to the plaintext database, the log was checkpointed
+            // before the copy.
+            let _ = std::fs::remove_file(format!("{}-wal", uri));
+            let _ = std::fs::remove_file(format!("{}-shm", uri));
+            Ok(())
+        },
+        Err(e) => {
+            let _ = remove_database_files(&encrypted_uri);
+            Err(e)
+        },
+    }
+}
+
+fn copy_to_encrypted<F>(uri: &str, encrypted_uri: &str, key: &DatabaseKey, progress: F) -> Result<()>
+where
+    F: Fn(EncryptionProgress),
+{
+    let plaintext = SqliteConnection::establish(uri)?;
+    if !is_cipher_supported(&plaintext) {
+        return Err(ErrorKind::CipherUnsupported.into());
+    }
+    // The changes still in the write-ahead log are written to the file first.
+    let _ = plaintext.exec("PRAGMA wal_checkpoint(TRUNCATE)")?;
+
+    let schema = sql::<(Text, Text, Text)>(
+        "SELECT type, name, sql FROM sqlite_master WHERE sql IS NOT NULL AND name NOT LIKE 'sqlite_%' ORDER BY rowid",
+    )
+    .load::<(String, String, String)>(&plaintext)?;
+    let tables: Vec<&String> = schema
+        .iter()
+        .filter(|(ty, _, _)| ty == "table")
+        .map(|(_, name, _)| name)
+        .collect();
+
+    {
+        let encrypted = SqliteConnection::establish(encrypted_uri)?;
+        let _ = set_key(&encrypted, key)?;
+        for (_, _, create_sql) in schema.iter().filter(|(ty, _, _)| ty == "table") {
+            let _ = encrypted.exec(create_sql)?;
+        }
+    }
+
+    let _ = plaintext.exec(format!(
+        "ATTACH DATABASE '{}' AS encrypted KEY {}",
+        encrypted_uri.replace('\'', "''"),
+        key.pragma_value()
+    ))?;
+    let total = tables.len();
+    progress(EncryptionProgress { done: 0, total });
+    let copied = plaintext.immediate_transaction::<_, Error, _>(|| {
+        for (index, table) in tables.iter().enumerate() {
+            let _ = plaintext.exec(format!(
+                "INSERT INTO encrypted.{0} SELECT * FROM main.{0}",
+                quote_identifier(table)
+            ))?;
+            progress(EncryptionProgress { done: index + 1, total });
+        }
+        // The next ids of the AUTOINCREMENT tables.
+        if has_table(&plaintext, "sqlite_sequence")? {
+            let _ = plaintext.exec("DELETE FROM encrypted.sqlite_sequence")?;
+            let _ = plaintext.exec("INSERT INTO encrypted.sqlite_sequence SELECT * FROM main.sqlite_sequence")?;
+        }
+        Ok(())
+    });
+    let _ = plaintext.exec("DETACH DATABASE encrypted")?;
+    let _ = copied?;
+
+    let encrypted = SqliteConnection::establish(encrypted_uri)?;
+    let _ = set_key(&encrypted, key)?;
+    for (_, _, create_sql) in schema.iter().filter(|(ty, _, _)| ty != "table") {
+        let _ = encrypted.exec(create_sql)?;
+    }
+    for table in tables {
+        let count = format!("SELECT count(*) FROM {}", quote_identifier(table));
+        let expected = sql::<BigInt>(&count).get_result::<i64>(&plaintext)?;
+        let actual = sql::<BigInt>(&count).get_result::<i64>(&encrypted)?;
+        if expected != actual {
+            return Err(ErrorKind::CipherCopyMismatch(table.clone()).into());
+        }
+    }
+    Ok(())
+}
+
+fn has_table(conn: &SqliteConnection, name: &str) -> Result<bool> {
+    let count = sql::<BigInt>(&format!(
+        "SELECT count(*) FROM sqlite_master WHERE type = 'table' AND name = '{}'",
+        name.replace('\'', "''")
+    ))
+    .get_result::<i64>(conn)?;
+    Ok(count > 0)
+}
+
+fn remove_database_files(uri: &str) -> std::io::Result<()> {
+    let _ = std::fs::remove_file(format!("{}-wal", uri));
+    let _ = std::fs::remove_file(format!("{}-shm", uri));
+    std::fs::remove_file(uri)
+}
@@ -0,0 +1,123 @@
+use crate::{errors::*, pool::ConnectionPool};
+use diesel::{connection::Connection, SqliteConnection};
+use parking_lot::{Condvar, Mutex};
+use std::{
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::{mpsc, Arc},
+    time::{Duration, Instant},
+};
+
+// Returns false if the write failed, its savepoint is rolled back then.
+type BatchedWrite = Box<dyn FnOnce(&SqliteConnection) -> bool + Send>;
+
+struct PendingWrite {
+    write: BatchedWrite,
+    committed: mpsc::Sender<std::result::Result<(), String>>,
+}
+
+#[derive(Default)]
+struct Queue {
+    writes: Vec<PendingWrite>,
+    leading: bool,
+}
+
+// Batches the small writes that arrive within the window into one transaction,
+// so they're synced to the disk once. The first writer waits for the window
+// and commits the writes of the others with its own, they wait for it. Each
+// write runs in its own savepoint, the one that fails doesn't roll back the
+// others.
+#[derive(Default)]
+pub(crate) struct WriteCoalescer {
+    queue: Mutex<Queue>,
+    full: Condvar,
+}
+
+impl WriteCoalescer {
+    pub(crate) fn write<T, E, F>(
+        &self,
+        pool: &ConnectionPool,
+        window: Duration,
+        max_writes: usize,
+        f: F,
+    ) -> Result<std::result::Result<T, E>>
+    where
+        F: FnOnce(&SqliteConnection) -> std::result::Result<T, E> + Send + 'static,
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        let output = Arc::new(Mutex::new(None));
+        let write: BatchedWrite = {
+            let output = output.clone();
+            Box::new(move |conn| {
+                let result = f(conn);
+                let ok = result.is_ok();
+                *output.lock() = Some(result);
+                ok
+            })
+        };
+        let (committed, receiver) = mpsc::channel();
+
+        let lead = {
+            let mut queue = self.queue.lock();
+            queue.writes.push(PendingWrite { write, committed });
+            if queue.writes.len() >= max_writes {
+                self.full.notify_one();
+            }
+            !std::mem::replace(&mut queue.leading, true)
+        };
+        if lead {
+            let writes = self.take_writes(window, max_writes);
+            commit(pool, writes);
+        }
+
+        match receiver.recv() {
+            Ok(Ok(())) => match output.lock().take() {
+                Some(result) => Ok(result),
+                None => Err("The batched write panicked".into()),
+            },
+            Ok(Err(e)) => Err(e.into()),
+            Err(_) => Err("The batched write was dropped".into()),
+        }
+    }
+
+    // Waits until the window is over or the batch is full.
+    fn take_writes(&self, window: Duration, max_writes: usize) -> Vec<PendingWrite> {
+        let deadline = Instant::now() + window;
+        let mut queue = self.queue.lock();
+        while queue.writes.len() < max_writes {
+            if self.full.wait_until(&mut queue, deadline).timed_out() {
+                break;
+            }
+        }
+        queue.leading = false;
+        std::mem::take(&mut queue.writes)
+    }
+}
+
+fn commit(pool: &ConnectionPool, writes: Vec<PendingWrite>) {
+    flowy_metrics::registry()
+        .histogram("db_write_batch_size", &[])
+        .observe(writes.len() as f64);
+    let (writes, senders): (Vec<_>, Vec<_>) = writes
+        .into_iter()
+        .map(|pending| (pending.write, pending.committed))
+        .unzip();
+    let result = pool.get_write().map_err(|e| format!("{}", e)).and_then(|conn| {
+        conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            for write in writes {
+                let _ = conn.transaction::<_, diesel::result::Error, _>(|| {
+                    match catch_unwind(AssertUnwindSafe(|| write(conn))) {
+                        Ok(true) => Ok(()),
+                        _ => Err(diesel::result::Error::RollbackTransaction),
+                    }
+                });
+            }
+            Ok(())
+        })
+        .map_err(|e| format!("{}", e))
+    });
+
+    for sender in senders {
+        let _ = sender.send(result.clone());
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{conn_ext::ConnectionExtension, errors::*};
+use diesel::{
+    dsl::sql,
+    sql_types::{Integer, Text},
+    RunQueryDsl,
+    SqliteConnection,
+};
+
+const AUTO_VACUUM_INCREMENTAL: i32 = 2;
+
+// The sizes of the database file and its write-ahead log, in bytes. They're 0
+// for the in-memory databases.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Compaction {
+    pub size_before: i64,
+    pub size_after: i64,
+}
+
+impl Compaction {
+    pub fn reclaimed_bytes(&self) -> i64 { (self.size_before - self.size_after).max(0) }
+}
+
+// Gives the free pages of the connection's database back to the file system,
+// then checkpoints the write-ahead log into the database and truncates it. The
+// databases that weren't created with the incremental auto vacuum are vacuumed
+// once to enable it, it takes longer than the next ones. Run it with the write
+// connection, outside of a transaction.
+pub fn compact_database(conn: &SqliteConnection) -> Result<Compaction> {
+    let path = sql::<Text>("SELECT file FROM pragma_database_list WHERE name = 'main'").get_result::<String>(conn)?;
+    let size_before = storage_size(&path);
+
+    let auto_vacuum = sql::<Integer>("PRAGMA auto_vacuum").get_result::<i32>(conn)?;
+    if auto_vacuum == AUTO_VACUUM_INCREMENTAL {
+        let _ = conn.exec("PRAGMA incremental_vacuum")?;
+    } else {
+        let _ = conn.exec(format!("PRAGMA auto_vacuum = {}", AUTO_VACUUM_INCREMENTAL))?;
+        let _ = conn.exec("VACUUM")?;
+    }
+    let _ = conn.exec("PRAGMA wal_checkpoint(TRUNCATE)")?;
+
+    Ok(Compaction {
+        size_before,
+        size_after: storage_size(&path),
+    })
+}
+
+fn storage_size(path: &str) -> i64 {
+    if path.is_empty() {
+        return 0;
+    }
+    let file_size = |path: &str| std::fs::metadata(path).map_or(0, |metadata| metadata.len() as i64);
+    file_size(path) + file_size(&format!("{}-wal", path))
+}
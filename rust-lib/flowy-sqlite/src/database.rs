@@ -2,18 +2,37 @@ use crate::{
     errors::*,
     pool::{ConnectionManager, ConnectionPool, PoolConfig},
 };
+use diesel::{Connection, SqliteConnection};
+use parking_lot::Mutex;
 use r2d2::PooledConnection;
 use std::sync::Arc;
 
 pub struct Database {
     uri: String,
     pool: Arc<ConnectionPool>,
+    // An in-memory database is dropped when its last connection is closed, the
+    // pool may close all of its idle ones.
+    _memory_holder: Option<Mutex<SqliteConnection>>,
 }
 
 pub type DBConnection = PooledConnection<ConnectionManager>;
 
 impl Database {
+    // The in-memory databases, see PoolConfig::in_memory, are named after the
+    // path of the file so each one is shared by the connections of its pool
+    // only. The directory isn't created.
     pub fn new(dir: &str, name: &str, pool_config: PoolConfig) -> Result<Self> {
+        if pool_config.is_in_memory() {
+            let uri = db_memory_uri(dir, name);
+            let holder = SqliteConnection::establish(&uri)?;
+            let pool = ConnectionPool::new(pool_config, &uri)?;
+            return Ok(Self {
+                uri,
+                pool: Arc::new(pool),
+                _memory_holder: Some(Mutex::new(holder)),
+            });
+        }
+
         let uri = db_file_uri(dir, name);
 
         if !std::path::PathBuf::from(dir).exists() {
@@ -24,6 +43,7 @@ impl Database {
         Ok(Self {
             uri,
             pool: Arc::new(pool),
+            _memory_holder: None,
         })
     }
 
@@ -47,3 +67,11 @@ pub fn db_file_uri(dir: &str, name: &str) -> String {
     uri.push_str(name);
     uri
 }
+
+fn db_memory_uri(dir: &str, name: &str) -> String {
+    let path = db_file_uri(dir, name)
+        .replace('%', "%25")
+        .replace('?', "%3f")
+        .replace('#', "%23");
+    format!("file:{}?mode=memory&cache=shared", path)
+}
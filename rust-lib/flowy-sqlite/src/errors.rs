@@ -11,6 +11,18 @@ error_chain! {
         UnknownMigrationExists(v: String) {
              display("unknown migration version: '{}'", v),
         }
+        WriteLockTimeout {
+             display("timed out waiting for the other writers of the database"),
+        }
+        CipherUnsupported {
+             display("SQLite wasn't built with SQLCipher, the database can't be encrypted"),
+        }
+        CipherCopyMismatch(table: String) {
+             display("the rows of '{}' weren't all copied to the encrypted database", table),
+        }
+        IntegrityCheckFailed(msg: String) {
+             display("the database is corrupted: {}", msg),
+        }
     }
     foreign_links {
         R2D2(::r2d2::Error);
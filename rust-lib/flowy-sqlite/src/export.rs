@@ -0,0 +1,204 @@
+use crate::{
+    backup::{quote_identifier, quote_literal},
+    conn_ext::ConnectionExtension,
+    errors::*,
+};
+use diesel::{
+    dsl::sql,
+    sql_types::{BigInt, Text},
+    Connection,
+    RunQueryDsl,
+    SqliteConnection,
+};
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+// A column whose values are left out of the exports, e.g. ("user_table",
+// "token").
+pub type RedactedColumn = (&'static str, &'static str);
+
+#[derive(Debug, Clone)]
+pub struct ExportedTable {
+    pub name: String,
+    pub rows: i64,
+}
+
+// Writes each table of the connection's database to "{dir}/{table}.jsonl", one
+// JSON object per row keyed by the column names. The blobs are written as hex
+// strings and the redacted columns are left out. The tables are read in one
+// transaction, so they're consistent with each other.
+pub fn export_json_lines(
+    conn: &SqliteConnection,
+    dir: &str,
+    redacted: &[RedactedColumn],
+) -> Result<Vec<ExportedTable>> {
+    let _ = std::fs::create_dir_all(dir)?;
+    conn.transaction::<_, Error, _>(|| {
+        let mut exported = vec![];
+        for table in data_tables(&read_schema(conn)?) {
+            let columns: Vec<String> = sql::<Text>(&format!(
+                "SELECT name FROM pragma_table_info({})",
+                quote_literal(&table)
+            ))
+            .load::<String>(conn)?
+            .into_iter()
+            .filter(|column| !redacted.contains(&(table.as_str(), column.as_str())))
+            .collect();
+            let fields: Vec<String> = columns
+                .iter()
+                .map(|column| {
+                    let identifier = quote_identifier(column);
+                    format!(
+                        "{}, CASE WHEN typeof({1}) = 'blob' THEN hex({1}) ELSE {1} END",
+                        quote_literal(column),
+                        identifier
+                    )
+                })
+                .collect();
+            let rows = sql::<Text>(&format!(
+                "SELECT json_object({}) FROM {}",
+                fields.join(", "),
+                quote_identifier(&table)
+            ))
+            .load::<String>(conn)?;
+
+            let mut writer = BufWriter::new(File::create(Path::new(dir).join(format!("{}.jsonl", table)))?);
+            for row in &rows {
+                let _ = writeln!(writer, "{}", row)?;
+            }
+            let _ = writer.flush()?;
+            exported.push(ExportedTable {
+                name: table,
+                rows: rows.len() as i64,
+            });
+        }
+        Ok(exported)
+    })
+}
+
+// Copies the connection's database to a new plaintext one at the path, with
+// the redacted columns emptied. The pages that held their values are vacuumed,
+// so they can't be recovered from the file.
+pub fn export_sqlite(
+    conn: &SqliteConnection,
+    path: &str,
+    is_encrypted: bool,
+    redacted: &[RedactedColumn],
+) -> Result<Vec<ExportedTable>> {
+    let _ = std::fs::remove_file(path);
+    let schema = read_schema(conn)?;
+    let tables = data_tables(&schema);
+    let virtual_tables: Vec<&String> = schema
+        .iter()
+        .filter(|entry| entry.is_virtual_table())
+        .map(|entry| &entry.name)
+        .collect();
+    {
+        // The virtual tables create their shadow tables.
+        let export = SqliteConnection::establish(path)?;
+        for entry in schema.iter().filter(|entry| entry.ty == "table") {
+            if !is_shadow_table(&entry.name, &virtual_tables) {
+                let _ = export.exec(&entry.sql)?;
+            }
+        }
+    }
+
+    // The attached database shares the key of the main one unless it's given
+    // an empty key.
+    let attach = match is_encrypted {
+        false => format!("ATTACH DATABASE {} AS export", quote_literal(path)),
+        true => format!("ATTACH DATABASE {} AS export KEY ''", quote_literal(path)),
+    };
+    let _ = conn.exec(attach)?;
+    let copied = conn.transaction::<_, Error, _>(|| {
+        for table in &tables {
+            let table = quote_identifier(table);
+            let _ = conn.exec(format!("INSERT INTO export.{0} SELECT * FROM main.{0}", table))?;
+        }
+        for (table, column) in redacted {
+            if tables.contains(&table.to_string()) {
+                let _ = conn.exec(format!(
+                    "UPDATE export.{} SET {} = ''",
+                    quote_identifier(table),
+                    quote_identifier(column)
+                ))?;
+            }
+        }
+        // The full text indexes are built again from the copied rows.
+        for table in &virtual_tables {
+            let table = quote_identifier(table);
+            let _ = conn.exec(format!("INSERT INTO export.{0} ({0}) VALUES ('rebuild')", table))?;
+        }
+        Ok(())
+    });
+    let _ = conn.exec("DETACH DATABASE export")?;
+    let _ = copied?;
+
+    // The triggers are created after the copy, they would fill the indexes
+    // again otherwise.
+    let export = SqliteConnection::establish(path)?;
+    for entry in schema.iter().filter(|entry| entry.ty != "table") {
+        let _ = export.exec(&entry.sql)?;
+    }
+    let _ = export.exec("VACUUM")?;
+    let mut exported = vec![];
+    for table in tables {
+        let rows =
+            sql::<BigInt>(&format!("SELECT count(*) FROM {}", quote_identifier(&table))).get_result::<i64>(&export)?;
+        exported.push(ExportedTable { name: table, rows });
+    }
+    Ok(exported)
+}
+
+pub(crate) struct SchemaEntry {
+    ty: String,
+    name: String,
+    sql: String,
+}
+
+impl SchemaEntry {
+    fn is_virtual_table(&self) -> bool {
+        self.ty == "table" && self.sql.to_uppercase().starts_with("CREATE VIRTUAL TABLE")
+    }
+}
+
+pub(crate) fn read_schema(conn: &SqliteConnection) -> Result<Vec<SchemaEntry>> {
+    let schema = sql::<(Text, Text, Text)>(
+        "SELECT type, name, sql FROM main.sqlite_master WHERE sql IS NOT NULL AND name NOT LIKE 'sqlite_%' ORDER BY rowid",
+    )
+    .load::<(String, String, String)>(conn)?
+    .into_iter()
+    .map(|(ty, name, sql)| SchemaEntry { ty, name, sql })
+    .collect();
+    Ok(schema)
+}
+
+// The tables that hold the rows, ordered by name. The virtual tables, the full
+// text indexes, and the shadow tables where they keep their data are derived
+// from them.
+pub(crate) fn data_tables(schema: &[SchemaEntry]) -> Vec<String> {
+    let virtual_tables: Vec<&String> = schema
+        .iter()
+        .filter(|entry| entry.is_virtual_table())
+        .map(|entry| &entry.name)
+        .collect();
+    let mut tables: Vec<String> = schema
+        .iter()
+        .filter(|entry| entry.ty == "table" && !entry.is_virtual_table())
+        .filter(|entry| !is_shadow_table(&entry.name, &virtual_tables))
+        .map(|entry| entry.name.clone())
+        .collect();
+    tables.sort();
+    tables
+}
+
+// The shadow tables are named after their virtual table, e.g.
+// "doc_search_fts_data".
+fn is_shadow_table(name: &str, virtual_tables: &[&String]) -> bool {
+    virtual_tables
+        .iter()
+        .any(|virtual_table| name.starts_with(&format!("{}_", virtual_table)))
+}
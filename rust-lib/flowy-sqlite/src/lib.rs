@@ -1,11 +1,22 @@
+mod backup;
+mod cipher;
+mod coalesce;
+mod compaction;
 mod conn_ext;
 mod database;
 #[allow(deprecated, clippy::large_enum_variant)]
 mod errors;
+mod export;
 mod pool;
 mod pragma;
+mod row_change;
 
+pub use backup::{backup_database, check_integrity, copy_database, integrity_errors};
+pub use cipher::{encrypt_database, is_cipher_available, is_plaintext_database, DatabaseKey, EncryptionProgress};
+pub use compaction::{compact_database, Compaction};
 pub use database::*;
+pub use export::{export_json_lines, export_sqlite, ExportedTable, RedactedColumn};
 pub use pool::*;
+pub use row_change::{RowChange, RowChangeKind};
 
 pub use errors::{Error, ErrorKind, Result};
@@ -1,8 +1,19 @@
-use crate::{errors::*, pragma::*};
+use crate::{
+    cipher::{set_key, DatabaseKey},
+    coalesce::WriteCoalescer,
+    conn_ext::ConnectionExtension,
+    errors::*,
+    pragma::*,
+    row_change::{prepare_row_triggers, publish_row_changes, RowChange, RowObservers},
+};
 use diesel::{connection::Connection, SqliteConnection};
-use r2d2::{CustomizeConnection, ManageConnection, Pool};
+use parking_lot::{lock_api::ArcReentrantMutexGuard, RawMutex, RawThreadId, ReentrantMutex};
+use r2d2::{CustomizeConnection, ManageConnection, Pool, PooledConnection};
 use scheduled_thread_pool::ScheduledThreadPool;
-use std::{sync::Arc, time::Duration};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 lazy_static::lazy_static! {
     static ref DB_POOL: Arc<ScheduledThreadPool> = Arc::new(
@@ -12,6 +23,15 @@ lazy_static::lazy_static! {
 
 pub struct ConnectionPool {
     pub(crate) inner: Pool<ConnectionManager>,
+    // SQLite allows one writer at a time. The writers wait for each other here
+    // instead of failing with SQLITE_BUSY when a deferred transaction can't be
+    // upgraded. It's reentrant, the writes that call other writes on the same
+    // thread don't wait for themselves.
+    write_lock: Arc<ReentrantMutex<()>>,
+    write_timeout: Duration,
+    row_observers: Arc<RowObservers>,
+    coalescer: WriteCoalescer,
+    write_batch: (Duration, usize),
 }
 
 impl std::ops::Deref for ConnectionPool {
@@ -25,10 +45,22 @@ impl ConnectionPool {
     where
         T: Into<String>,
     {
-        let manager = ConnectionManager::new(uri);
+        let row_observers = Arc::new(RowObservers::default());
+        let manager = ConnectionManager {
+            db_uri: uri.into(),
+            row_observers: match config.observe_rows {
+                true => Some(row_observers.clone()),
+                false => None,
+            },
+        };
         let thread_pool = DB_POOL.clone();
         let config = Arc::new(config);
-        let customizer_config = DatabaseCustomizerConfig::default();
+        let customizer_config = DatabaseCustomizerConfig {
+            busy_timeout: config.busy_timeout,
+            key: config.encryption_key.clone(),
+            read_uncommitted: config.in_memory,
+            ..DatabaseCustomizerConfig::default()
+        };
 
         let pool = r2d2::Pool::builder()
             .thread_pool(thread_pool)
@@ -39,17 +71,132 @@ impl ConnectionPool {
             .connection_timeout(config.connection_timeout)
             .idle_timeout(Some(config.idle_timeout))
             .build_unchecked(manager);
-        Ok(ConnectionPool { inner: pool })
+        Ok(ConnectionPool {
+            inner: pool,
+            write_lock: Arc::new(ReentrantMutex::new(())),
+            write_timeout: config.connection_timeout,
+            row_observers,
+            coalescer: WriteCoalescer::default(),
+            write_batch: (config.write_batch_window, config.write_batch_size),
+        })
+    }
+
+    // Calls the observer with the rows each connection inserted, updated or
+    // deleted, once the connection is returned to the pool. It's only called
+    // if the pool config observes the rows.
+    pub fn observe_rows<F>(&self, observer: F)
+    where
+        F: Fn(&[RowChange]) + Send + Sync + 'static,
+    {
+        self.row_observers.add(Box::new(observer));
+    }
+
+    // Returns the connection once the other writers of the pool are done with
+    // theirs. The lock is held until the connection is dropped, so keep it only
+    // for the time of the write. It can't be sent to other threads. The db_write
+    // span lasts as long as the connection, from the wait for the other writers.
+    pub fn get_write(&self) -> Result<WriteConnection> {
+        let span = tracing::debug_span!("db_write");
+        let registry = flowy_metrics::registry();
+        let wait_at = Instant::now();
+        let guard = span.in_scope(|| {
+            self.write_lock
+                .try_lock_arc_for(self.write_timeout)
+                .ok_or(ErrorKind::WriteLockTimeout)
+        });
+        registry
+            .histogram("db_write_lock_wait_ms", &[])
+            .observe_duration(wait_at.elapsed());
+        let guard = guard.map_err(|e| {
+            registry.counter("db_write_lock_timeouts_total", &[]).inc();
+            e
+        })?;
+        let conn = self.inner.get()?;
+        registry.counter("db_writes_total", &[]).inc();
+        Ok(WriteConnection {
+            conn,
+            _guard: guard,
+            span,
+            locked_at: Instant::now(),
+        })
+    }
+
+    // Runs the write in the same transaction as the other batched writes that
+    // arrive within the window of the pool config, see PoolConfig::write_batch.
+    // It returns once they're committed, the outer error is the one of the
+    // commit. The write runs in a savepoint, it can't open an immediate
+    // transaction, and on the thread of the first write of the batch. Don't
+    // call it while holding a WriteConnection, the batch would wait for it.
+    //
+    //     let result = pool.write_batched(move |conn| sql.create(rows, conn))?;
+    pub fn write_batched<T, E, F>(&self, f: F) -> Result<std::result::Result<T, E>>
+    where
+        F: FnOnce(&SqliteConnection) -> std::result::Result<T, E> + Send + 'static,
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        let (window, size) = self.write_batch;
+        self.coalescer.write(self, window, size, f)
     }
 }
 
+pub struct WriteConnection {
+    conn: PooledConnection<ConnectionManager>,
+    _guard: ArcReentrantMutexGuard<RawMutex, RawThreadId, ()>,
+    span: tracing::Span,
+    locked_at: Instant,
+}
+
+// How long the other writers were kept waiting.
+impl Drop for WriteConnection {
+    fn drop(&mut self) {
+        flowy_metrics::registry()
+            .histogram("db_write_duration_ms", &[])
+            .observe_duration(self.locked_at.elapsed());
+    }
+}
+
+impl WriteConnection {
+    // Runs the operations with the connection in one transaction, none of their
+    // writes are kept if one of them fails. The operations can't open an
+    // immediate transaction with the connection, diesel doesn't nest them, but
+    // the ones opened with Connection::transaction run in a savepoint.
+    pub fn transaction<T, E, F>(&self, f: F) -> std::result::Result<T, E>
+    where
+        F: FnOnce(&SqliteConnection) -> std::result::Result<T, E>,
+        E: From<diesel::result::Error>,
+    {
+        self.span.in_scope(|| self.conn.immediate_transaction(|| f(&self.conn)))
+    }
+}
+
+impl std::ops::Deref for WriteConnection {
+    type Target = SqliteConnection;
+
+    fn deref(&self) -> &Self::Target { &self.conn }
+}
+
 pub type OnExecFunc = Box<dyn Fn() -> Box<dyn Fn(&SqliteConnection, &str)> + Send + Sync>;
 
+#[derive(Debug, Clone)]
 pub struct PoolConfig {
     min_idle: u32,
     max_size: u32,
+    // How long to wait for a connection of the pool, or for the other writers.
     connection_timeout: Duration,
     idle_timeout: Duration,
+    // How long, in milliseconds, SQLite retries a locked database.
+    busy_timeout: i32,
+    // Opens the database with SQLCipher, see the sqlcipher feature.
+    encryption_key: Option<DatabaseKey>,
+    // Keeps the database in memory instead of the file, see Database::new.
+    in_memory: bool,
+    // Logs the rows changed by the connections, see ConnectionPool::observe_rows.
+    observe_rows: bool,
+    // How long the first batched write waits for the others, and how many are
+    // committed together at most, see ConnectionPool::write_batched.
+    write_batch_window: Duration,
+    write_batch_size: usize,
 }
 
 impl Default for PoolConfig {
@@ -59,6 +206,12 @@ impl Default for PoolConfig {
             max_size: 10,
             connection_timeout: Duration::from_secs(10),
             idle_timeout: Duration::from_secs(5 * 60),
+            busy_timeout: 5000,
+            encryption_key: None,
+            in_memory: false,
+            observe_rows: false,
+            write_batch_window: Duration::from_millis(4),
+            write_batch_size: 64,
         }
     }
 }
@@ -70,15 +223,52 @@ impl PoolConfig {
         self
     }
 
-    #[allow(dead_code)]
     pub fn max_size(mut self, max_size: u32) -> Self {
         self.max_size = max_size;
         self
     }
+
+    pub fn connection_timeout(mut self, timeout: Duration) -> Self {
+        self.connection_timeout = timeout;
+        self
+    }
+
+    pub fn busy_timeout(mut self, timeout_ms: i32) -> Self {
+        self.busy_timeout = timeout_ms;
+        self
+    }
+
+    pub fn encryption_key(mut self, key: DatabaseKey) -> Self {
+        self.encryption_key = Some(key);
+        self
+    }
+
+    // Nothing is written to the disk, the database is dropped with the Database
+    // that opened it. For the tests.
+    pub fn in_memory(mut self) -> Self {
+        self.in_memory = true;
+        self
+    }
+
+    pub fn is_in_memory(&self) -> bool { self.in_memory }
+
+    // The changes are logged with temporary triggers on each connection, the
+    // rolled back transactions don't log theirs.
+    pub fn observe_rows(mut self) -> Self {
+        self.observe_rows = true;
+        self
+    }
+
+    pub fn write_batch(mut self, window: Duration, size: usize) -> Self {
+        self.write_batch_window = window;
+        self.write_batch_size = size.max(1);
+        self
+    }
 }
 
 pub struct ConnectionManager {
     db_uri: String,
+    row_observers: Option<Arc<RowObservers>>,
 }
 
 impl ManageConnection for ConnectionManager {
@@ -87,13 +277,34 @@ impl ManageConnection for ConnectionManager {
 
     fn connect(&self) -> Result<Self::Connection> { Ok(SqliteConnection::establish(&self.db_uri)?) }
 
-    fn is_valid(&self, conn: &mut Self::Connection) -> Result<()> { Ok(conn.execute("SELECT 1").map(|_| ())?) }
+    fn is_valid(&self, conn: &mut Self::Connection) -> Result<()> {
+        let _ = conn.execute("SELECT 1")?;
+        if self.row_observers.is_some() {
+            if let Err(e) = prepare_row_triggers(conn) {
+                log::error!("Prepare the row change triggers failed: {:?}", e);
+            }
+        }
+        Ok(())
+    }
 
-    fn has_broken(&self, _conn: &mut Self::Connection) -> bool { false }
+    // Called when the connection is returned to the pool.
+    fn has_broken(&self, conn: &mut Self::Connection) -> bool {
+        if let Some(row_observers) = &self.row_observers {
+            if let Err(e) = publish_row_changes(conn, row_observers) {
+                log::error!("Publish the row changes failed: {:?}", e);
+            }
+        }
+        false
+    }
 }
 
 impl ConnectionManager {
-    pub fn new<S: Into<String>>(uri: S) -> Self { ConnectionManager { db_uri: uri.into() } }
+    pub fn new<S: Into<String>>(uri: S) -> Self {
+        ConnectionManager {
+            db_uri: uri.into(),
+            row_observers: None,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -102,6 +313,11 @@ pub struct DatabaseCustomizerConfig {
     pub(crate) synchronous: SQLiteSynchronous,
     pub(crate) busy_timeout: i32,
     pub(crate) secure_delete: bool,
+    pub(crate) key: Option<DatabaseKey>,
+    // The connections of an in-memory database share its cache, they lock the
+    // tables instead of the file. The readers don't wait for the writers with
+    // it, like they don't with the write-ahead log.
+    pub(crate) read_uncommitted: bool,
 }
 
 impl Default for DatabaseCustomizerConfig {
@@ -111,6 +327,8 @@ impl Default for DatabaseCustomizerConfig {
             synchronous: SQLiteSynchronous::NORMAL,
             busy_timeout: 5000,
             secure_delete: true,
+            key: None,
+            read_uncommitted: false,
         }
     }
 }
@@ -131,11 +349,17 @@ impl DatabaseCustomizer {
 
 impl CustomizeConnection<SqliteConnection, crate::Error> for DatabaseCustomizer {
     fn on_acquire(&self, conn: &mut SqliteConnection) -> Result<()> {
+        if let Some(key) = &self.config.key {
+            let _ = set_key(conn, key)?;
+        }
         conn.pragma_set_busy_timeout(self.config.busy_timeout)?;
         if self.config.journal_mode != SQLiteJournalMode::WAL {
             conn.pragma_set_journal_mode(self.config.journal_mode, None)?;
         }
         conn.pragma_set_synchronous(self.config.synchronous, None)?;
+        if self.config.read_uncommitted {
+            let _ = conn.exec("PRAGMA read_uncommitted = true")?;
+        }
 
         Ok(())
     }
@@ -0,0 +1,200 @@
+use crate::{
+    backup::{quote_identifier, quote_literal},
+    conn_ext::ConnectionExtension,
+    errors::*,
+    export::{data_tables, read_schema},
+};
+use diesel::{
+    dsl::sql,
+    sql_types::{BigInt, Integer, Text},
+    RunQueryDsl,
+    SqliteConnection,
+};
+use parking_lot::RwLock;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowChangeKind {
+    Insert,
+    Update,
+    Delete,
+}
+
+impl RowChangeKind {
+    fn from_i32(value: i32) -> Option<Self> {
+        match value {
+            1 => Some(RowChangeKind::Insert),
+            2 => Some(RowChangeKind::Update),
+            3 => Some(RowChangeKind::Delete),
+            _ => None,
+        }
+    }
+}
+
+// The key is the primary key of the row as text, the columns of the composite
+// ones are joined with ':'. It's the rowid for the tables without.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowChange {
+    pub table: String,
+    pub key: String,
+    pub kind: RowChangeKind,
+}
+
+pub(crate) type RowObserver = Box<dyn Fn(&[RowChange]) + Send + Sync>;
+
+#[derive(Default)]
+pub(crate) struct RowObservers {
+    observers: RwLock<Vec<RowObserver>>,
+}
+
+impl RowObservers {
+    pub(crate) fn add(&self, observer: RowObserver) { self.observers.write().push(observer); }
+
+    fn notify(&self, changes: &[RowChange]) {
+        for observer in self.observers.read().iter() {
+            observer(changes);
+        }
+    }
+}
+
+const LOG_TABLE: &str = "temp.row_change_log";
+const STATE_TABLE: &str = "temp.row_change_state";
+const TRIGGER_PREFIX: &str = "row_change_";
+
+// The triggers are created on each connection, the temporary tables and
+// triggers aren't shared with the other ones. They're created again when the
+// schema changes, e.g. after the migrations.
+pub(crate) fn prepare_row_triggers(conn: &SqliteConnection) -> Result<()> {
+    let is_current = sql::<BigInt>(&format!(
+        "SELECT count(*) FROM {}, pragma_schema_version WHERE row_change_state.version = pragma_schema_version.schema_version",
+        STATE_TABLE
+    ))
+    .get_result::<i64>(conn);
+    match is_current {
+        Ok(count) if count > 0 => Ok(()),
+        _ => install_row_triggers(conn),
+    }
+}
+
+fn install_row_triggers(conn: &SqliteConnection) -> Result<()> {
+    let _ = conn.exec(format!(
+        "CREATE TEMP TABLE IF NOT EXISTS {} (id INTEGER PRIMARY KEY, table_name TEXT NOT NULL, key TEXT NOT NULL, \
+         kind INTEGER NOT NULL)",
+        LOG_TABLE
+    ))?;
+    let _ = conn.exec(format!(
+        "CREATE TEMP TABLE IF NOT EXISTS {} (version INTEGER NOT NULL)",
+        STATE_TABLE
+    ))?;
+    let triggers = sql::<Text>(&format!(
+        "SELECT name FROM temp.sqlite_master WHERE type = 'trigger' AND name LIKE '{}%'",
+        TRIGGER_PREFIX
+    ))
+    .load::<String>(conn)?;
+    for trigger in triggers {
+        let _ = conn.exec(format!("DROP TRIGGER temp.{}", quote_identifier(&trigger)))?;
+    }
+
+    for table in data_tables(&read_schema(conn)?) {
+        let keys = sql::<Text>(&format!(
+            "SELECT name FROM pragma_table_info({}) WHERE pk > 0 ORDER BY pk",
+            quote_literal(&table)
+        ))
+        .load::<String>(conn)?;
+        for (event, row, kind) in &[("INSERT", "NEW", 1), ("UPDATE", "NEW", 2), ("DELETE", "OLD", 3)] {
+            let key = match keys.is_empty() {
+                true => format!("{}.rowid", row),
+                false => keys
+                    .iter()
+                    .map(|key| format!("{}.{}", row, quote_identifier(key)))
+                    .collect::<Vec<_>>()
+                    .join(" || ':' || "),
+            };
+            let _ = conn.exec(format!(
+                "CREATE TEMP TRIGGER {} AFTER {} ON {} BEGIN INSERT INTO row_change_log (table_name, key, kind) VALUES \
+                 ({}, CAST({} AS TEXT), {}); END",
+                quote_identifier(&format!("{}{}_{}", TRIGGER_PREFIX, table, event.to_lowercase())),
+                event,
+                quote_identifier(&table),
+                quote_literal(&table),
+                key,
+                kind
+            ))?;
+        }
+    }
+
+    let _ = conn.exec(format!("DELETE FROM {}", STATE_TABLE))?;
+    let _ = conn.exec(format!(
+        "INSERT INTO {} (version) SELECT schema_version FROM pragma_schema_version",
+        STATE_TABLE
+    ))?;
+    Ok(())
+}
+
+// Takes the changes the triggers logged on the connection and passes them to
+// the observers. The ones of the rolled back transactions were rolled back with
+// them.
+pub(crate) fn publish_row_changes(conn: &SqliteConnection, observers: &RowObservers) -> Result<()> {
+    let rows =
+        match sql::<(Text, Text, Integer)>(&format!("SELECT table_name, key, kind FROM {} ORDER BY id", LOG_TABLE))
+            .load::<(String, String, i32)>(conn)
+        {
+            Ok(rows) => rows,
+            // The connection wasn't checked out since the triggers were turned on.
+            Err(_) => return Ok(()),
+        };
+    if rows.is_empty() {
+        return Ok(());
+    }
+    let _ = conn.exec(format!("DELETE FROM {}", LOG_TABLE))?;
+
+    let changes = coalesce(
+        rows.into_iter()
+            .filter_map(|(table, key, kind)| Some((table, key, RowChangeKind::from_i32(kind)?))),
+    );
+    if !changes.is_empty() {
+        observers.notify(&changes);
+    }
+    Ok(())
+}
+
+// One change per row, in the order the rows were first changed. A row that was
+// inserted and updated is inserted, one that was inserted and deleted is left
+// out, and one that was deleted and inserted again is updated.
+fn coalesce<I>(rows: I) -> Vec<RowChange>
+where
+    I: Iterator<Item = (String, String, RowChangeKind)>,
+{
+    let mut positions: HashMap<(String, String), usize> = HashMap::new();
+    let mut changes: Vec<(String, String, Option<RowChangeKind>)> = vec![];
+    for (table, key, kind) in rows {
+        let row = (table, key);
+        let previous = positions
+            .get(&row)
+            .and_then(|position| Some((*position, changes[*position].2?)));
+        match previous {
+            Some((position, previous)) => {
+                changes[position].2 = match (previous, kind) {
+                    (RowChangeKind::Insert, RowChangeKind::Update) => Some(RowChangeKind::Insert),
+                    (RowChangeKind::Insert, RowChangeKind::Delete) => None,
+                    (RowChangeKind::Delete, RowChangeKind::Insert) => Some(RowChangeKind::Update),
+                    (_, kind) => Some(kind),
+                };
+            },
+            None => {
+                positions.insert(row.clone(), changes.len());
+                changes.push((row.0, row.1, Some(kind)));
+            },
+        }
+    }
+    changes
+        .into_iter()
+        .filter_map(|(table, key, kind)| {
+            Some(RowChange {
+                table,
+                key,
+                kind: kind?,
+            })
+        })
+        .collect()
+}
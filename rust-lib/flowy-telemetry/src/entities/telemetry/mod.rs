@@ -0,0 +1,3 @@
+mod telemetry;
+
+pub use telemetry::*;
@@ -0,0 +1,52 @@
+use flowy_derive::ProtoBuf;
+
+// An event that was dispatched while the telemetry was enabled. Only its name,
+// how long it took and how it failed are kept, never its payload or the ids it
+// carried. The error code is the one the error was sent with, -1 if it couldn't
+// be read.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct TelemetryRecord {
+    #[pb(index = 1)]
+    pub event: String,
+
+    #[pb(index = 2)]
+    pub duration_ms: i64,
+
+    #[pb(index = 3)]
+    pub succeeded: bool,
+
+    #[pb(index = 4)]
+    pub error_code: i32,
+
+    #[pb(index = 5)]
+    pub create_time: i64,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedTelemetryRecord {
+    #[pb(index = 1)]
+    pub items: Vec<TelemetryRecord>,
+}
+
+// What's uploaded. The batch id is random and the batches of a user can't be
+// told apart from the ones of the others, there's no user id or token.
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct TelemetryBatch {
+    #[pb(index = 1)]
+    pub batch_id: String,
+
+    #[pb(index = 2)]
+    pub platform: String,
+
+    #[pb(index = 3)]
+    pub version: String,
+
+    #[pb(index = 4)]
+    pub items: Vec<TelemetryRecord>,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct UploadTelemetryResult {
+    #[pb(index = 1)]
+    pub uploaded: i64,
+}
@@ -0,0 +1,88 @@
+use bytes::Bytes;
+use derive_more::Display;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_dispatch::prelude::{EventResponse, ResponseBuilder};
+use std::{convert::TryInto, fmt, fmt::Debug};
+
+pub type TelemetryResult<T> = std::result::Result<T, TelemetryError>;
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct TelemetryError {
+    #[pb(index = 1)]
+    pub code: ErrorCode,
+
+    #[pb(index = 2)]
+    pub msg: String,
+}
+
+macro_rules! static_telemetry_error {
+    ($name:ident, $status:expr) => {
+        #[allow(non_snake_case, missing_docs)]
+        pub fn $name() -> TelemetryError {
+            TelemetryError {
+                code: $status,
+                msg: format!("{}", $status),
+            }
+        }
+    };
+}
+
+impl TelemetryError {
+    pub fn context<T: Debug>(mut self, error: T) -> Self {
+        self.msg = format!("{:?}", error);
+        self
+    }
+
+    pub fn is_record_not_found(&self) -> bool { self.code == ErrorCode::RecordNotFound }
+
+    static_telemetry_error!(internal, ErrorCode::InternalError);
+    static_telemetry_error!(record_not_found, ErrorCode::RecordNotFound);
+    static_telemetry_error!(unauthorized, ErrorCode::UserUnauthorized);
+    static_telemetry_error!(upload_failed, ErrorCode::TelemetryUploadFailed);
+}
+
+pub fn internal_error<T>(e: T) -> TelemetryError
+where
+    T: std::fmt::Debug,
+{
+    TelemetryError::internal().context(e)
+}
+
+#[derive(Debug, Clone, ProtoBuf_Enum, Display, PartialEq, Eq)]
+pub enum ErrorCode {
+    #[display(fmt = "Record not found")]
+    RecordNotFound   = 0,
+
+    #[display(fmt = "The telemetry can't be uploaded")]
+    TelemetryUploadFailed = 10,
+
+    #[display(fmt = "UserUnauthorized")]
+    UserUnauthorized = 999,
+
+    #[display(fmt = "InternalError")]
+    InternalError    = 1000,
+}
+
+impl std::default::Default for ErrorCode {
+    fn default() -> Self { ErrorCode::InternalError }
+}
+
+impl std::convert::From<flowy_database::Error> for TelemetryError {
+    fn from(error: flowy_database::Error) -> Self {
+        match error {
+            flowy_database::Error::NotFound => TelemetryError::record_not_found().context(error),
+            _ => TelemetryError::internal().context(error),
+        }
+    }
+}
+
+impl flowy_dispatch::Error for TelemetryError {
+    fn as_response(&self) -> EventResponse {
+        let bytes: Bytes = self.clone().try_into().unwrap();
+        ResponseBuilder::Err().data(bytes).build()
+    }
+}
+
+impl fmt::Display for TelemetryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{:?}: {}", &self.code, &self.msg) }
+}
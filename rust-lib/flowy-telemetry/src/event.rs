@@ -0,0 +1,15 @@
+use flowy_derive::{Flowy_Event, ProtoBuf_Enum};
+use strum_macros::Display;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Display, Hash, ProtoBuf_Enum, Flowy_Event)]
+#[event_err = "TelemetryError"]
+pub enum TelemetryEvent {
+    #[event(output = "RepeatedTelemetryRecord")]
+    ReadTelemetry   = 0,
+
+    #[event()]
+    PurgeTelemetry  = 1,
+
+    #[event(output = "UploadTelemetryResult")]
+    UploadTelemetry = 10,
+}
@@ -0,0 +1,3 @@
+mod telemetry_handler;
+
+pub use telemetry_handler::*;
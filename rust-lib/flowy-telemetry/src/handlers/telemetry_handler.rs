@@ -0,0 +1,25 @@
+use crate::{entities::telemetry::*, errors::TelemetryError, services::telemetry::TelemetryController};
+use flowy_dispatch::prelude::{data_result, DataResult, Unit};
+use std::sync::Arc;
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn read_telemetry_handler(
+    controller: Unit<Arc<TelemetryController>>,
+) -> DataResult<RepeatedTelemetryRecord, TelemetryError> {
+    let records = controller.read_records()?;
+    data_result(records)
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn purge_telemetry_handler(controller: Unit<Arc<TelemetryController>>) -> Result<(), TelemetryError> {
+    let _ = controller.purge()?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn upload_telemetry_handler(
+    controller: Unit<Arc<TelemetryController>>,
+) -> DataResult<UploadTelemetryResult, TelemetryError> {
+    let result = controller.upload().await?;
+    data_result(result)
+}
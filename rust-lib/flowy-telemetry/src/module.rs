@@ -0,0 +1,46 @@
+use crate::{
+    errors::TelemetryError,
+    event::TelemetryEvent,
+    handlers::*,
+    services::{
+        server::TelemetryServerAPI,
+        telemetry::{TelemetryController, TelemetryRecorder},
+    },
+};
+use flowy_database::ConnectionPool;
+use flowy_dispatch::prelude::*;
+use flowy_infra::clock::Clock;
+use std::sync::Arc;
+
+// The records are anonymous, the user is only needed for the database they're
+// kept in.
+pub trait TelemetryUser: Send + Sync {
+    fn db_pool(&self) -> Result<Arc<ConnectionPool>, TelemetryError>;
+}
+
+pub fn mk_telemetry(
+    user: Arc<dyn TelemetryUser>,
+    clock: Arc<dyn Clock>,
+    server: Arc<dyn TelemetryServerAPI>,
+) -> Arc<TelemetryController> {
+    Arc::new(TelemetryController::new(user, clock, server))
+}
+
+// Register it as the last guard and as a middleware of the dispatch.
+pub fn mk_recorder(controller: Arc<TelemetryController>) -> Arc<TelemetryRecorder> {
+    let skipped = vec![
+        TelemetryEvent::ReadTelemetry.into(),
+        TelemetryEvent::PurgeTelemetry.into(),
+        TelemetryEvent::UploadTelemetry.into(),
+    ];
+    Arc::new(TelemetryRecorder::new(controller, skipped))
+}
+
+pub fn create(controller: Arc<TelemetryController>) -> Module {
+    Module::new()
+        .name("Flowy-Telemetry")
+        .data(controller)
+        .event(TelemetryEvent::ReadTelemetry, read_telemetry_handler)
+        .event(TelemetryEvent::PurgeTelemetry, purge_telemetry_handler)
+        .event(TelemetryEvent::UploadTelemetry, upload_telemetry_handler)
+}
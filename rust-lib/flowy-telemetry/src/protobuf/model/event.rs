@@ -0,0 +1,95 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `event.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum TelemetryEvent {
+    ReadTelemetry = 0,
+    PurgeTelemetry = 1,
+    UploadTelemetry = 10,
+}
+
+impl ::protobuf::ProtobufEnum for TelemetryEvent {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<TelemetryEvent> {
+        match value {
+            0 => ::std::option::Option::Some(TelemetryEvent::ReadTelemetry),
+            1 => ::std::option::Option::Some(TelemetryEvent::PurgeTelemetry),
+            10 => ::std::option::Option::Some(TelemetryEvent::UploadTelemetry),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [TelemetryEvent] = &[
+            TelemetryEvent::ReadTelemetry,
+            TelemetryEvent::PurgeTelemetry,
+            TelemetryEvent::UploadTelemetry,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<TelemetryEvent>("TelemetryEvent", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for TelemetryEvent {
+}
+
+impl ::std::default::Default for TelemetryEvent {
+    fn default() -> Self {
+        TelemetryEvent::ReadTelemetry
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TelemetryEvent {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0bevent.proto*N\n\x0eTelemetryEvent\x12\x11\n\rReadTelemetry\x10\0\
+    \x12\x12\n\x0ePurgeTelemetry\x10\x01\x12\x13\n\x0fUploadTelemetry\x10\n\
+    \x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,10 @@
+// Auto-generated, do not edit 
+
+mod telemetry; 
+pub use telemetry::*; 
+
+mod errors; 
+pub use errors::*; 
+
+mod event; 
+pub use event::*; 
@@ -0,0 +1,960 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `telemetry.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TelemetryRecord {
+    // message fields
+    pub event: ::std::string::String,
+    pub duration_ms: i64,
+    pub succeeded: bool,
+    pub error_code: i32,
+    pub create_time: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TelemetryRecord {
+    fn default() -> &'a TelemetryRecord {
+        <TelemetryRecord as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TelemetryRecord {
+    pub fn new() -> TelemetryRecord {
+        ::std::default::Default::default()
+    }
+
+    // string event = 1;
+
+
+    pub fn get_event(&self) -> &str {
+        &self.event
+    }
+    pub fn clear_event(&mut self) {
+        self.event.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_event(&mut self, v: ::std::string::String) {
+        self.event = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_event(&mut self) -> &mut ::std::string::String {
+        &mut self.event
+    }
+
+    // Take field
+    pub fn take_event(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.event, ::std::string::String::new())
+    }
+
+    // int64 duration_ms = 2;
+
+
+    pub fn get_duration_ms(&self) -> i64 {
+        self.duration_ms
+    }
+    pub fn clear_duration_ms(&mut self) {
+        self.duration_ms = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_duration_ms(&mut self, v: i64) {
+        self.duration_ms = v;
+    }
+
+    // bool succeeded = 3;
+
+
+    pub fn get_succeeded(&self) -> bool {
+        self.succeeded
+    }
+    pub fn clear_succeeded(&mut self) {
+        self.succeeded = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_succeeded(&mut self, v: bool) {
+        self.succeeded = v;
+    }
+
+    // int32 error_code = 4;
+
+
+    pub fn get_error_code(&self) -> i32 {
+        self.error_code
+    }
+    pub fn clear_error_code(&mut self) {
+        self.error_code = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error_code(&mut self, v: i32) {
+        self.error_code = v;
+    }
+
+    // int64 create_time = 5;
+
+
+    pub fn get_create_time(&self) -> i64 {
+        self.create_time
+    }
+    pub fn clear_create_time(&mut self) {
+        self.create_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_create_time(&mut self, v: i64) {
+        self.create_time = v;
+    }
+}
+
+impl ::protobuf::Message for TelemetryRecord {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.event)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.duration_ms = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.succeeded = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.error_code = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.create_time = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.event.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.event);
+        }
+        if self.duration_ms != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.duration_ms, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.succeeded != false {
+            my_size += 2;
+        }
+        if self.error_code != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.error_code, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.create_time != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.create_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.event.is_empty() {
+            os.write_string(1, &self.event)?;
+        }
+        if self.duration_ms != 0 {
+            os.write_int64(2, self.duration_ms)?;
+        }
+        if self.succeeded != false {
+            os.write_bool(3, self.succeeded)?;
+        }
+        if self.error_code != 0 {
+            os.write_int32(4, self.error_code)?;
+        }
+        if self.create_time != 0 {
+            os.write_int64(5, self.create_time)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TelemetryRecord {
+        TelemetryRecord::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "event",
+                |m: &TelemetryRecord| { &m.event },
+                |m: &mut TelemetryRecord| { &mut m.event },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "duration_ms",
+                |m: &TelemetryRecord| { &m.duration_ms },
+                |m: &mut TelemetryRecord| { &mut m.duration_ms },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "succeeded",
+                |m: &TelemetryRecord| { &m.succeeded },
+                |m: &mut TelemetryRecord| { &mut m.succeeded },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "error_code",
+                |m: &TelemetryRecord| { &m.error_code },
+                |m: &mut TelemetryRecord| { &mut m.error_code },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "create_time",
+                |m: &TelemetryRecord| { &m.create_time },
+                |m: &mut TelemetryRecord| { &mut m.create_time },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<TelemetryRecord>(
+                "TelemetryRecord",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static TelemetryRecord {
+        static instance: ::protobuf::rt::LazyV2<TelemetryRecord> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(TelemetryRecord::new)
+    }
+}
+
+impl ::protobuf::Clear for TelemetryRecord {
+    fn clear(&mut self) {
+        self.event.clear();
+        self.duration_ms = 0;
+        self.succeeded = false;
+        self.error_code = 0;
+        self.create_time = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TelemetryRecord {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TelemetryRecord {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedTelemetryRecord {
+    // message fields
+    pub items: ::protobuf::RepeatedField<TelemetryRecord>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedTelemetryRecord {
+    fn default() -> &'a RepeatedTelemetryRecord {
+        <RepeatedTelemetryRecord as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedTelemetryRecord {
+    pub fn new() -> RepeatedTelemetryRecord {
+        ::std::default::Default::default()
+    }
+
+    // repeated .TelemetryRecord items = 1;
+
+
+    pub fn get_items(&self) -> &[TelemetryRecord] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<TelemetryRecord>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<TelemetryRecord> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<TelemetryRecord> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedTelemetryRecord {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedTelemetryRecord {
+        RepeatedTelemetryRecord::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TelemetryRecord>>(
+                "items",
+                |m: &RepeatedTelemetryRecord| { &m.items },
+                |m: &mut RepeatedTelemetryRecord| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedTelemetryRecord>(
+                "RepeatedTelemetryRecord",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedTelemetryRecord {
+        static instance: ::protobuf::rt::LazyV2<RepeatedTelemetryRecord> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedTelemetryRecord::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedTelemetryRecord {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedTelemetryRecord {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedTelemetryRecord {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TelemetryBatch {
+    // message fields
+    pub batch_id: ::std::string::String,
+    pub platform: ::std::string::String,
+    pub version: ::std::string::String,
+    pub items: ::protobuf::RepeatedField<TelemetryRecord>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TelemetryBatch {
+    fn default() -> &'a TelemetryBatch {
+        <TelemetryBatch as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TelemetryBatch {
+    pub fn new() -> TelemetryBatch {
+        ::std::default::Default::default()
+    }
+
+    // string batch_id = 1;
+
+
+    pub fn get_batch_id(&self) -> &str {
+        &self.batch_id
+    }
+    pub fn clear_batch_id(&mut self) {
+        self.batch_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_batch_id(&mut self, v: ::std::string::String) {
+        self.batch_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_batch_id(&mut self) -> &mut ::std::string::String {
+        &mut self.batch_id
+    }
+
+    // Take field
+    pub fn take_batch_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.batch_id, ::std::string::String::new())
+    }
+
+    // string platform = 2;
+
+
+    pub fn get_platform(&self) -> &str {
+        &self.platform
+    }
+    pub fn clear_platform(&mut self) {
+        self.platform.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_platform(&mut self, v: ::std::string::String) {
+        self.platform = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_platform(&mut self) -> &mut ::std::string::String {
+        &mut self.platform
+    }
+
+    // Take field
+    pub fn take_platform(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.platform, ::std::string::String::new())
+    }
+
+    // string version = 3;
+
+
+    pub fn get_version(&self) -> &str {
+        &self.version
+    }
+    pub fn clear_version(&mut self) {
+        self.version.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: ::std::string::String) {
+        self.version = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_version(&mut self) -> &mut ::std::string::String {
+        &mut self.version
+    }
+
+    // Take field
+    pub fn take_version(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.version, ::std::string::String::new())
+    }
+
+    // repeated .TelemetryRecord items = 4;
+
+
+    pub fn get_items(&self) -> &[TelemetryRecord] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<TelemetryRecord>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<TelemetryRecord> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<TelemetryRecord> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for TelemetryBatch {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.batch_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.platform)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.version)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.batch_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.batch_id);
+        }
+        if !self.platform.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.platform);
+        }
+        if !self.version.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.version);
+        }
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.batch_id.is_empty() {
+            os.write_string(1, &self.batch_id)?;
+        }
+        if !self.platform.is_empty() {
+            os.write_string(2, &self.platform)?;
+        }
+        if !self.version.is_empty() {
+            os.write_string(3, &self.version)?;
+        }
+        for v in &self.items {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TelemetryBatch {
+        TelemetryBatch::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "batch_id",
+                |m: &TelemetryBatch| { &m.batch_id },
+                |m: &mut TelemetryBatch| { &mut m.batch_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "platform",
+                |m: &TelemetryBatch| { &m.platform },
+                |m: &mut TelemetryBatch| { &mut m.platform },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "version",
+                |m: &TelemetryBatch| { &m.version },
+                |m: &mut TelemetryBatch| { &mut m.version },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TelemetryRecord>>(
+                "items",
+                |m: &TelemetryBatch| { &m.items },
+                |m: &mut TelemetryBatch| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<TelemetryBatch>(
+                "TelemetryBatch",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static TelemetryBatch {
+        static instance: ::protobuf::rt::LazyV2<TelemetryBatch> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(TelemetryBatch::new)
+    }
+}
+
+impl ::protobuf::Clear for TelemetryBatch {
+    fn clear(&mut self) {
+        self.batch_id.clear();
+        self.platform.clear();
+        self.version.clear();
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TelemetryBatch {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TelemetryBatch {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UploadTelemetryResult {
+    // message fields
+    pub uploaded: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UploadTelemetryResult {
+    fn default() -> &'a UploadTelemetryResult {
+        <UploadTelemetryResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UploadTelemetryResult {
+    pub fn new() -> UploadTelemetryResult {
+        ::std::default::Default::default()
+    }
+
+    // int64 uploaded = 1;
+
+
+    pub fn get_uploaded(&self) -> i64 {
+        self.uploaded
+    }
+    pub fn clear_uploaded(&mut self) {
+        self.uploaded = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_uploaded(&mut self, v: i64) {
+        self.uploaded = v;
+    }
+}
+
+impl ::protobuf::Message for UploadTelemetryResult {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.uploaded = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.uploaded != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.uploaded, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.uploaded != 0 {
+            os.write_int64(1, self.uploaded)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UploadTelemetryResult {
+        UploadTelemetryResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "uploaded",
+                |m: &UploadTelemetryResult| { &m.uploaded },
+                |m: &mut UploadTelemetryResult| { &mut m.uploaded },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UploadTelemetryResult>(
+                "UploadTelemetryResult",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UploadTelemetryResult {
+        static instance: ::protobuf::rt::LazyV2<UploadTelemetryResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UploadTelemetryResult::new)
+    }
+}
+
+impl ::protobuf::Clear for UploadTelemetryResult {
+    fn clear(&mut self) {
+        self.uploaded = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UploadTelemetryResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UploadTelemetryResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0ftelemetry.proto\"\xb2\x01\n\x0fTelemetryRecord\x12\x16\n\x05event\
+    \x18\x01\x20\x01(\tR\x05eventB\0\x12!\n\x0bduration_ms\x18\x02\x20\x01(\
+    \x03R\ndurationMsB\0\x12\x1e\n\tsucceeded\x18\x03\x20\x01(\x08R\tsucceed\
+    edB\0\x12\x1f\n\nerror_code\x18\x04\x20\x01(\x05R\terrorCodeB\0\x12!\n\
+    \x0bcreate_time\x18\x05\x20\x01(\x03R\ncreateTimeB\0:\0\"E\n\x17Repeated\
+    TelemetryRecord\x12(\n\x05items\x18\x01\x20\x03(\x0b2\x10.TelemetryRecor\
+    dR\x05itemsB\0:\0\"\x93\x01\n\x0eTelemetryBatch\x12\x1b\n\x08batch_id\
+    \x18\x01\x20\x01(\tR\x07batchIdB\0\x12\x1c\n\x08platform\x18\x02\x20\x01\
+    (\tR\x08platformB\0\x12\x1a\n\x07version\x18\x03\x20\x01(\tR\x07versionB\
+    \0\x12(\n\x05items\x18\x04\x20\x03(\x0b2\x10.TelemetryRecordR\x05itemsB\
+    \0:\0\"7\n\x15UploadTelemetryResult\x12\x1c\n\x08uploaded\x18\x01\x20\
+    \x01(\x03R\x08uploadedB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
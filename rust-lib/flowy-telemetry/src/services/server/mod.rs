@@ -0,0 +1,28 @@
+mod server_api;
+mod server_api_mock;
+
+pub use server_api::*;
+pub use server_api_mock::*;
+
+use crate::{entities::telemetry::TelemetryBatch, errors::TelemetryError};
+use flowy_infra::future::ResultFuture;
+use flowy_net::config::ServerConfig;
+use std::{fmt, sync::Arc};
+
+// Where the batches are uploaded. It's replaced by the host to send them to
+// another collector, or by the tests.
+pub trait TelemetryServerAPI: Send + Sync {
+    fn upload(&self, batch: TelemetryBatch) -> ResultFuture<(), TelemetryError>;
+}
+
+impl fmt::Debug for dyn TelemetryServerAPI {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("TelemetryServerAPI") }
+}
+
+pub fn construct_telemetry_server(config: &ServerConfig) -> Arc<dyn TelemetryServerAPI> {
+    if cfg!(feature = "http_server") {
+        Arc::new(TelemetryServer::new(config.clone()))
+    } else {
+        Arc::new(TelemetryServerMock {})
+    }
+}
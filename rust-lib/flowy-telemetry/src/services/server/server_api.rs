@@ -0,0 +1,29 @@
+use crate::{entities::telemetry::TelemetryBatch, errors::TelemetryError, services::server::TelemetryServerAPI};
+use flowy_infra::future::ResultFuture;
+use flowy_net::{config::ServerConfig, request::HttpRequestBuilder};
+
+pub struct TelemetryServer {
+    config: ServerConfig,
+}
+
+impl TelemetryServer {
+    pub fn new(config: ServerConfig) -> TelemetryServer { Self { config } }
+}
+
+impl TelemetryServerAPI for TelemetryServer {
+    // Sent without the token of the user.
+    fn upload(&self, batch: TelemetryBatch) -> ResultFuture<(), TelemetryError> {
+        let url = self.config.telemetry_url();
+        ResultFuture::new(async move {
+            let _ = HttpRequestBuilder::new()
+                .anonymous()
+                .post(&url)
+                .protobuf(batch)
+                .map_err(|e| TelemetryError::upload_failed().context(e))?
+                .send()
+                .await
+                .map_err(|e| TelemetryError::upload_failed().context(e))?;
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,8 @@
+use crate::{entities::telemetry::TelemetryBatch, errors::TelemetryError, services::server::TelemetryServerAPI};
+use flowy_infra::future::ResultFuture;
+
+pub struct TelemetryServerMock {}
+
+impl TelemetryServerAPI for TelemetryServerMock {
+    fn upload(&self, _batch: TelemetryBatch) -> ResultFuture<(), TelemetryError> { ResultFuture::new(async { Ok(()) }) }
+}
@@ -0,0 +1,5 @@
+mod telemetry_controller;
+mod telemetry_recorder;
+
+pub use telemetry_controller::*;
+pub use telemetry_recorder::*;
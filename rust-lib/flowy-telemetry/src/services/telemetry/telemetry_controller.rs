@@ -0,0 +1,207 @@
+use crate::{
+    entities::telemetry::*,
+    errors::{internal_error, TelemetryResult},
+    module::TelemetryUser,
+    services::server::TelemetryServerAPI,
+    sql_tables::telemetry::*,
+};
+use flowy_infra::{clock::Clock, retry::ExponentialBackoff};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+        Mutex,
+    },
+    time::Duration,
+};
+use tokio::sync::Notify;
+
+pub const TELEMETRY_UPLOAD_INTERVAL: Duration = Duration::from_secs(60 * 60);
+// The oldest records are dropped beyond it, e.g. while the uploads fail.
+pub const MAX_TELEMETRY_RECORDS: i64 = 10_000;
+pub const TELEMETRY_BATCH_SIZE: i64 = 500;
+const MAX_UPLOAD_BACKOFF: Duration = Duration::from_secs(6 * 60 * 60);
+
+// The records are kept in the database of the user while the telemetry is
+// enabled in the privacy setting, nothing is recorded otherwise. The sdk waits
+// for next_upload and dispatches UploadTelemetry, the failed uploads are
+// retried later and later until one succeeds.
+pub struct TelemetryController {
+    user: Arc<dyn TelemetryUser>,
+    clock: Arc<dyn Clock>,
+    server: Arc<dyn TelemetryServerAPI>,
+    telemetry_sql: TelemetryTableSql,
+    enabled: AtomicBool,
+    schedule: Mutex<UploadSchedule>,
+    wakeup: Notify,
+}
+
+struct UploadSchedule {
+    // Milliseconds since the epoch.
+    upload_at: i64,
+    backoff: ExponentialBackoff,
+}
+
+impl TelemetryController {
+    pub(crate) fn new(
+        user: Arc<dyn TelemetryUser>,
+        clock: Arc<dyn Clock>,
+        server: Arc<dyn TelemetryServerAPI>,
+    ) -> Self {
+        let schedule = UploadSchedule {
+            upload_at: clock.timestamp_millis() + TELEMETRY_UPLOAD_INTERVAL.as_millis() as i64,
+            backoff: upload_backoff(),
+        };
+        Self {
+            user,
+            clock,
+            server,
+            telemetry_sql: TelemetryTableSql {},
+            enabled: AtomicBool::new(false),
+            schedule: Mutex::new(schedule),
+            wakeup: Notify::new(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool { self.enabled.load(Ordering::SeqCst) }
+
+    // Called when the user changes the privacy setting. The records are purged
+    // once the user opts out.
+    pub fn set_enabled(&self, enabled: bool) {
+        if self.enabled.swap(enabled, Ordering::SeqCst) == enabled {
+            return;
+        }
+        if enabled {
+            self.schedule_upload_after(TELEMETRY_UPLOAD_INTERVAL);
+        } else {
+            if let Err(e) = self.purge() {
+                log::error!("Purge the telemetry failed: {:?}", e);
+            }
+            self.wakeup.notify_one();
+        }
+    }
+
+    // Called with the privacy setting of the new user.
+    pub fn user_did_change(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::SeqCst);
+        self.schedule_upload_after(TELEMETRY_UPLOAD_INTERVAL);
+    }
+
+    // The error code is None if the event succeeded. It's checked again with the
+    // write connection, so nothing is left after the purge of set_enabled.
+    pub(crate) fn record(&self, event: &str, duration: Duration, error_code: Option<i32>) -> TelemetryResult<()> {
+        let table = NewTelemetryTable {
+            event: event.to_owned(),
+            duration_ms: duration.as_millis() as i64,
+            succeeded: error_code.is_none(),
+            error_code: error_code.unwrap_or(0),
+            create_time: self.clock.timestamp(),
+        };
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        if !self.is_enabled() {
+            return Ok(());
+        }
+        let _ = self.telemetry_sql.create_telemetry_table(table, conn)?;
+        self.telemetry_sql
+            .truncate_telemetry_tables(MAX_TELEMETRY_RECORDS, conn)
+    }
+
+    pub(crate) fn read_records(&self) -> TelemetryResult<RepeatedTelemetryRecord> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get().map_err(internal_error)?;
+        let items = self
+            .telemetry_sql
+            .read_telemetry_tables(MAX_TELEMETRY_RECORDS, conn)?
+            .into_iter()
+            .map(TelemetryRecord::from)
+            .collect();
+        Ok(RepeatedTelemetryRecord { items })
+    }
+
+    pub(crate) fn purge(&self) -> TelemetryResult<()> {
+        let pool = self.user.db_pool()?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        self.telemetry_sql.delete_all_telemetry_tables(conn)
+    }
+
+    // Uploads the oldest records and deletes them once they're uploaded. The
+    // next upload is after the interval, or after the backoff if it failed.
+    pub(crate) async fn upload(&self) -> TelemetryResult<UploadTelemetryResult> {
+        if !self.is_enabled() {
+            return Ok(UploadTelemetryResult::default());
+        }
+        match self.upload_batch().await {
+            Ok(uploaded) => {
+                self.schedule_upload_after(TELEMETRY_UPLOAD_INTERVAL);
+                Ok(UploadTelemetryResult { uploaded })
+            },
+            Err(e) => {
+                let mut schedule = self.schedule.lock().unwrap();
+                let delay = schedule.backoff.next().unwrap_or(MAX_UPLOAD_BACKOFF);
+                schedule.upload_at = self.clock.timestamp_millis() + delay.as_millis() as i64;
+                self.wakeup.notify_one();
+                tracing::debug!("Upload the telemetry failed, retry in {:?}: {:?}", delay, e);
+                Err(e)
+            },
+        }
+    }
+
+    async fn upload_batch(&self) -> TelemetryResult<i64> {
+        let pool = self.user.db_pool()?;
+        let tables = {
+            let conn = &*pool.get().map_err(internal_error)?;
+            self.telemetry_sql.read_telemetry_tables(TELEMETRY_BATCH_SIZE, conn)?
+        };
+        let last_id = match tables.last() {
+            None => return Ok(0),
+            Some(table) => table.id,
+        };
+        let uploaded = tables.len() as i64;
+        let batch = TelemetryBatch {
+            batch_id: flowy_infra::uuid(),
+            platform: std::env::consts::OS.to_owned(),
+            version: env!("CARGO_PKG_VERSION").to_owned(),
+            items: tables.into_iter().map(TelemetryRecord::from).collect(),
+        };
+        let _ = self.server.upload(batch).await?;
+        let conn = &*pool.get_write().map_err(internal_error)?;
+        let _ = self.telemetry_sql.delete_telemetry_tables_until(last_id, conn)?;
+        Ok(uploaded)
+    }
+
+    // Returns when the next upload is due. It waits while the telemetry is
+    // disabled, and is computed again when it's enabled or the user changes.
+    pub async fn next_upload(&self) {
+        loop {
+            let wakeup = self.wakeup.notified();
+            if !self.is_enabled() {
+                wakeup.await;
+                continue;
+            }
+            let delay = self.schedule.lock().unwrap().upload_at - self.clock.timestamp_millis();
+            if delay <= 0 {
+                return;
+            }
+            tokio::select! {
+                _ = self.clock.sleep(Duration::from_millis(delay as u64)) => return,
+                _ = wakeup => {},
+            }
+        }
+    }
+
+    // The backoff starts over.
+    fn schedule_upload_after(&self, delay: Duration) {
+        let mut schedule = self.schedule.lock().unwrap();
+        schedule.backoff = upload_backoff();
+        schedule.upload_at = self.clock.timestamp_millis() + delay.as_millis() as i64;
+        self.wakeup.notify_one();
+    }
+}
+
+// 2, 4, 8... minutes.
+fn upload_backoff() -> ExponentialBackoff {
+    ExponentialBackoff::from_millis(2)
+        .factor(60 * 1000)
+        .max_delay(MAX_UPLOAD_BACKOFF)
+}
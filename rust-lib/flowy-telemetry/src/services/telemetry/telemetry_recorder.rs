@@ -0,0 +1,82 @@
+use crate::services::telemetry::TelemetryController;
+use flowy_dispatch::prelude::*;
+use futures_core::future::BoxFuture;
+use protobuf::{wire_format::WireType, CodedInputStream};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Instant,
+};
+
+// The requests that a later guard rejected are never intercepted, their start
+// is dropped once there are that many.
+const MAX_PENDING_REQUESTS: usize = 1024;
+
+// The guard notes when each request starts, after the other guards accepted
+// it, and the middleware records how long it took and the code it failed with.
+// A retried request is recorded once, with its first response. The events of
+// the telemetry module aren't recorded.
+pub struct TelemetryRecorder {
+    controller: Arc<TelemetryController>,
+    skipped: Vec<Event>,
+    started: Mutex<HashMap<String, Instant>>,
+}
+
+impl TelemetryRecorder {
+    pub fn new(controller: Arc<TelemetryController>, skipped: Vec<Event>) -> Self {
+        Self {
+            controller,
+            skipped,
+            started: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl EventGuard for TelemetryRecorder {
+    fn check(
+        &self,
+        request: &ModuleRequest,
+        _permission: Option<EventPermission>,
+    ) -> BoxFuture<'static, Result<(), EventResponse>> {
+        if self.controller.is_enabled() && !self.skipped.contains(&request.event) {
+            let mut started = self.started.lock().unwrap();
+            if started.len() >= MAX_PENDING_REQUESTS {
+                started.clear();
+            }
+            started.insert(request.id.clone(), Instant::now());
+        }
+        Box::pin(async { Ok(()) })
+    }
+}
+
+impl EventMiddleware for TelemetryRecorder {
+    fn intercept(&self, request: &ModuleRequest, response: &EventResponse) -> BoxFuture<'static, bool> {
+        let started = self.started.lock().unwrap().remove(&request.id);
+        if let Some(started) = started {
+            let error_code = match response.status_code {
+                StatusCode::Ok => None,
+                StatusCode::Err => Some(error_code(response).unwrap_or(-1)),
+                StatusCode::Internal => Some(-1),
+            };
+            if let Err(e) = self
+                .controller
+                .record(request.event.name(), started.elapsed(), error_code)
+            {
+                log::error!("Record the telemetry of {:?} failed: {:?}", request.event, e);
+            }
+        }
+        Box::pin(async { false })
+    }
+}
+
+// The errors of the modules put their code first. It isn't written if it's 0,
+// the message comes first then. The rest of the error isn't read.
+fn error_code(response: &EventResponse) -> Option<i32> {
+    let bytes = response.payload.as_bytes()?;
+    let mut input = CodedInputStream::from_bytes(bytes);
+    match input.read_tag_unpack().ok()? {
+        (1, WireType::WireTypeVarint) => input.read_int32().ok(),
+        (2, WireType::WireTypeLengthDelimited) => Some(0),
+        _ => None,
+    }
+}
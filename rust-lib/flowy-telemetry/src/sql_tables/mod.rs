@@ -0,0 +1,3 @@
+pub(crate) mod telemetry;
+
+pub(crate) use telemetry::*;
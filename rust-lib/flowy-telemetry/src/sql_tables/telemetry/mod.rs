@@ -0,0 +1,5 @@
+mod telemetry_sql;
+mod telemetry_table;
+
+pub(crate) use telemetry_sql::*;
+pub(crate) use telemetry_table::*;
@@ -0,0 +1,56 @@
+use crate::{
+    errors::TelemetryError,
+    sql_tables::telemetry::{NewTelemetryTable, TelemetryTable},
+};
+use flowy_database::{prelude::*, schema::telemetry_table::dsl, OptionalExtension, SqliteConnection};
+
+pub struct TelemetryTableSql {}
+
+impl TelemetryTableSql {
+    pub(crate) fn create_telemetry_table(
+        &self,
+        table: NewTelemetryTable,
+        conn: &SqliteConnection,
+    ) -> Result<(), TelemetryError> {
+        let _ = diesel::insert_into(dsl::telemetry_table).values(table).execute(conn)?;
+        Ok(())
+    }
+
+    // The oldest records first.
+    pub(crate) fn read_telemetry_tables(
+        &self,
+        limit: i64,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<TelemetryTable>, TelemetryError> {
+        let tables = dsl::telemetry_table
+            .order(dsl::id.asc())
+            .limit(limit)
+            .load::<TelemetryTable>(conn)?;
+        Ok(tables)
+    }
+
+    // Deletes the records up to the id, e.g. the ones that were uploaded.
+    pub(crate) fn delete_telemetry_tables_until(&self, id: i32, conn: &SqliteConnection) -> Result<(), TelemetryError> {
+        let _ = diesel::delete(dsl::telemetry_table.filter(dsl::id.le(id))).execute(conn)?;
+        Ok(())
+    }
+
+    // Keeps the newest records, the older ones are deleted.
+    pub(crate) fn truncate_telemetry_tables(&self, keep: i64, conn: &SqliteConnection) -> Result<(), TelemetryError> {
+        let id = dsl::telemetry_table
+            .select(dsl::id)
+            .order(dsl::id.desc())
+            .offset(keep)
+            .first::<i32>(conn)
+            .optional()?;
+        if let Some(id) = id {
+            let _ = self.delete_telemetry_tables_until(id, conn)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn delete_all_telemetry_tables(&self, conn: &SqliteConnection) -> Result<(), TelemetryError> {
+        let _ = diesel::delete(dsl::telemetry_table).execute(conn)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use crate::entities::telemetry::TelemetryRecord;
+use flowy_database::schema::telemetry_table;
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable)]
+#[table_name = "telemetry_table"]
+pub(crate) struct TelemetryTable {
+    pub(crate) id: i32,
+    pub(crate) event: String,
+    pub(crate) duration_ms: i64,
+    pub(crate) succeeded: bool,
+    pub(crate) error_code: i32,
+    pub(crate) create_time: i64,
+}
+
+// The id is given by the database, the records are uploaded in its order.
+#[derive(Clone, Debug, Insertable)]
+#[table_name = "telemetry_table"]
+pub(crate) struct NewTelemetryTable {
+    pub(crate) event: String,
+    pub(crate) duration_ms: i64,
+    pub(crate) succeeded: bool,
+    pub(crate) error_code: i32,
+    pub(crate) create_time: i64,
+}
+
+impl std::convert::From<TelemetryTable> for TelemetryRecord {
+    fn from(table: TelemetryTable) -> Self {
+        TelemetryRecord {
+            event: table.event,
+            duration_ms: table.duration_ms,
+            succeeded: table.succeeded,
+            error_code: table.error_code,
+            create_time: table.create_time,
+        }
+    }
+}
@@ -0,0 +1 @@
+mod telemetry_test;
@@ -0,0 +1,185 @@
+use flowy_infra::{clock::ManualClock, future::ResultFuture, uuid};
+use flowy_net::config::ServerConfig;
+use flowy_telemetry::{
+    entities::telemetry::*,
+    errors::{ErrorCode, TelemetryError},
+    event::TelemetryEvent::*,
+    services::server::TelemetryServerAPI,
+};
+use flowy_test::{
+    prelude::{root_dir, FlowySDKConfig},
+    EventTester,
+};
+use flowy_user::{
+    entities::{AppSetting, PrivacySetting, UpdateSettingRequest},
+    errors::{ErrorCode as UserErrorCode, UserError},
+    event::UserEvent::*,
+};
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+        Mutex,
+    },
+    time::Duration,
+};
+
+#[derive(Default)]
+struct RecordServer {
+    batches: Mutex<Vec<TelemetryBatch>>,
+    offline: AtomicBool,
+    attempts: AtomicUsize,
+}
+
+impl TelemetryServerAPI for RecordServer {
+    fn upload(&self, batch: TelemetryBatch) -> ResultFuture<(), TelemetryError> {
+        self.attempts.fetch_add(1, Ordering::SeqCst);
+        if self.offline.load(Ordering::SeqCst) {
+            return ResultFuture::new(async { Err(TelemetryError::upload_failed()) });
+        }
+        self.batches.lock().unwrap().push(batch);
+        ResultFuture::new(async { Ok(()) })
+    }
+}
+
+struct TelemetryTest {
+    tester: EventTester,
+    clock: Arc<ManualClock>,
+    server: Arc<RecordServer>,
+}
+
+impl TelemetryTest {
+    async fn new() -> Self {
+        let clock = Arc::new(ManualClock::now());
+        let server = Arc::new(RecordServer::default());
+        let config = FlowySDKConfig::new(&root_dir(), ServerConfig::default(), &uuid())
+            .in_memory_storage(true)
+            .clock(clock.clone())
+            .telemetry_server(server.clone());
+        let tester = EventTester::with_config(config);
+        let _ = tester.sign_up().await;
+        Self { tester, clock, server }
+    }
+
+    // Waits for the sdk to see the change of the privacy setting.
+    async fn set_telemetry(&self, enabled: bool) {
+        let request = UpdateSettingRequest {
+            privacy: Some(PrivacySetting { telemetry: enabled }),
+            ..Default::default()
+        };
+        let _ = self.tester.send(UpdateSetting, request).await.parse::<AppSetting>();
+        for _ in 0..100 {
+            if self.tester.sdk().telemetry.is_enabled() == enabled {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        panic!("The telemetry wasn't set to {}", enabled);
+    }
+
+    async fn read(&self) -> Vec<TelemetryRecord> {
+        self.tester
+            .send_event(ReadTelemetry)
+            .await
+            .parse::<RepeatedTelemetryRecord>()
+            .items
+    }
+
+    // Waits for the server to be asked as many times to upload a batch.
+    async fn expect_attempts(&self, count: usize) -> Vec<TelemetryBatch> {
+        for _ in 0..100 {
+            if self.server.attempts.load(Ordering::SeqCst) >= count {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        assert_eq!(self.server.attempts.load(Ordering::SeqCst), count);
+        self.server.batches.lock().unwrap().clone()
+    }
+}
+
+#[tokio::test]
+async fn telemetry_is_opt_in() {
+    let test = TelemetryTest::new().await;
+    let _ = test.tester.send_event(ReadSetting).await;
+    assert!(test.read().await.is_empty());
+
+    test.set_telemetry(true).await;
+    let _ = test.tester.send_event(ReadSetting).await;
+    let error = test
+        .tester
+        .send(UpdateSetting, UpdateSettingRequest::default())
+        .await
+        .error::<UserError>();
+    assert_eq!(error.code, UserErrorCode::SettingIsEmpty.value());
+
+    // Reading the telemetry isn't recorded.
+    let records = test.read().await;
+    let events = records.iter().map(|record| record.event.as_str()).collect::<Vec<_>>();
+    assert_eq!(events, vec!["ReadSetting", "UpdateSetting"]);
+    assert!(records[0].succeeded);
+    assert!(!records[1].succeeded);
+    assert_eq!(records[1].error_code, UserErrorCode::SettingIsEmpty.value());
+
+    let _ = test.tester.send_event(PurgeTelemetry).await;
+    assert!(test.read().await.is_empty());
+
+    // Opting out purges what was recorded.
+    let _ = test.tester.send_event(ReadSetting).await;
+    test.set_telemetry(false).await;
+    let _ = test.tester.send_event(ReadSetting).await;
+    assert!(test.read().await.is_empty());
+}
+
+#[tokio::test]
+async fn telemetry_upload_is_anonymous() {
+    let test = TelemetryTest::new().await;
+    test.set_telemetry(true).await;
+    let _ = test.tester.send_event(ReadSetting).await;
+    let _ = test.tester.send_event(GetUserProfile).await;
+
+    let result = test
+        .tester
+        .send_event(UploadTelemetry)
+        .await
+        .parse::<UploadTelemetryResult>();
+    assert_eq!(result.uploaded, 2);
+    assert!(test.read().await.is_empty());
+
+    // Only the names, durations and errors of the events are sent.
+    let batch = test.expect_attempts(1).await.remove(0);
+    assert!(!batch.batch_id.is_empty());
+    assert_eq!(batch.platform, std::env::consts::OS);
+    let events = batch
+        .items
+        .iter()
+        .map(|record| record.event.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(events, vec!["ReadSetting", "GetUserProfile"]);
+}
+
+#[tokio::test]
+async fn telemetry_upload_backs_off() {
+    let test = TelemetryTest::new().await;
+    test.set_telemetry(true).await;
+    test.server.offline.store(true, Ordering::SeqCst);
+    let _ = test.tester.send_event(ReadSetting).await;
+
+    let error = test.tester.send_event(UploadTelemetry).await.error::<TelemetryError>();
+    assert_eq!(error.code, ErrorCode::TelemetryUploadFailed);
+    assert_eq!(test.read().await.len(), 1);
+
+    // Retried after 2 minutes, then after 4 more.
+    test.clock.advance(Duration::from_secs(2 * 60));
+    let _ = test.expect_attempts(2).await;
+    test.server.offline.store(false, Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    test.clock.advance(Duration::from_secs(2 * 60));
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert!(test.expect_attempts(2).await.is_empty());
+
+    test.clock.advance(Duration::from_secs(2 * 60));
+    let batches = test.expect_attempts(3).await;
+    assert_eq!(batches[0].items.len(), 1);
+    assert!(test.read().await.is_empty());
+}
@@ -8,6 +8,8 @@ use std::{
 use crate::FlowyTestSDK;
 use flowy_dispatch::prelude::*;
 
+use flowy_document::errors::DocError;
+use flowy_grid::errors::GridError;
 use flowy_sdk::*;
 use flowy_user::errors::UserError;
 use flowy_workspace::errors::WorkspaceError;
@@ -18,6 +20,16 @@ impl FlowyWorkspaceTest {
     pub fn new(sdk: FlowyTestSDK) -> Self { Builder::test(TestContext::new(sdk)) }
 }
 
+pub type DocTest = Builder<DocError>;
+impl DocTest {
+    pub fn new(sdk: FlowyTestSDK) -> Self { Builder::test(TestContext::new(sdk)) }
+}
+
+pub type GridTest = Builder<GridError>;
+impl GridTest {
+    pub fn new(sdk: FlowyTestSDK) -> Self { Builder::test(TestContext::new(sdk)) }
+}
+
 pub type UserTest = Builder<UserError>;
 impl UserTest {
     pub fn new(sdk: FlowyTestSDK) -> Self { Builder::test(TestContext::new(sdk)) }
@@ -111,6 +123,8 @@ where
         self
     }
 
+    pub fn response(&self) -> EventResponse { self.get_response() }
+
     pub fn sdk(&self) -> FlowySDK { self.context.sdk.clone() }
 
     fn dispatch(&self) -> Arc<EventDispatch> { self.context.sdk.dispatch() }
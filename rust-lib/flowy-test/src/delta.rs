@@ -0,0 +1,209 @@
+use flowy_ot::{core::*, errors::OTError};
+use proptest::{collection::SizeRange, prelude::*, test_runner::TestCaseError};
+
+// The texts are mostly ascii, the other chars can be outside the BMP so their
+// utf16 length differs from the count of the chars.
+pub fn arb_char() -> impl Strategy<Value = char> {
+    prop_oneof![4 => proptest::char::range('a', 'z'), 1 => any::<char>()]
+}
+
+pub fn arb_text(max_len: usize) -> impl Strategy<Value = String> {
+    prop::collection::vec(arb_char(), 0..=max_len).prop_map(|chars| chars.into_iter().collect())
+}
+
+// The removable attributes can remove the format, they're only valid in the
+// retains.
+pub fn arb_attributes(removable: bool) -> impl Strategy<Value = Attributes> {
+    let choices = if removable { 3u8 } else { 2u8 };
+    (0..choices, 0..choices).prop_map(|(bold, italic)| {
+        let mut attributes = Attributes::default();
+        for (key, choice) in &[(AttributeKey::Bold, bold), (AttributeKey::Italic, italic)] {
+            match *choice {
+                1 => attributes.add_kv(key.clone(), AttributeValue::from(true)),
+                2 => attributes.delete(key),
+                _ => {},
+            }
+        }
+        attributes
+    })
+}
+
+// A document made of formatted inserts, with its text.
+pub fn arb_document(max_len: usize) -> impl Strategy<Value = (String, Delta)> {
+    arb_text(max_len)
+        .prop_flat_map(|s| {
+            let len = s.chars().count();
+            (Just(s), prop::collection::vec(arb_attributes(false), len))
+        })
+        .prop_map(|(s, attributes)| {
+            let mut doc = Delta::default();
+            s.chars()
+                .zip(attributes)
+                .for_each(|(c, attributes)| doc.insert(&c.to_string(), attributes));
+            (s, doc)
+        })
+}
+
+// A change of the text, its base_len is the utf16 length of s.
+pub fn arb_delta(s: String) -> impl Strategy<Value = Delta> {
+    prop::collection::vec(arb_step(), 0..16).prop_map(move |steps| build_delta(&s, steps))
+}
+
+// A text with a change of it.
+pub fn arb_change(max_len: usize) -> impl Strategy<Value = (String, Delta)> {
+    arb_text(max_len).prop_flat_map(|s| (Just(s.clone()), arb_delta(s)))
+}
+
+// Two changes made concurrently to the same document.
+pub fn arb_concurrent(max_len: usize) -> impl Strategy<Value = (Delta, Delta, Delta)> {
+    arb_document(max_len).prop_flat_map(|(s, doc)| (Just(doc), arb_delta(s.clone()), arb_delta(s)))
+}
+
+// The changes made one after the other, each one is based on the text the
+// previous ones produced.
+pub fn arb_sequence(max_len: usize, count: impl Into<SizeRange>) -> impl Strategy<Value = (String, Vec<Delta>)> {
+    let count = count.into();
+    arb_text(max_len).prop_flat_map(move |s| (Just(s.clone()), arb_sequence_of(s, count.clone())))
+}
+
+// Two sites that changed the same text, each one with its own sequence.
+pub fn arb_sites(
+    max_len: usize,
+    count: impl Into<SizeRange>,
+) -> impl Strategy<Value = (String, Vec<Delta>, Vec<Delta>)> {
+    let count = count.into();
+    arb_text(max_len).prop_flat_map(move |s| {
+        let local = arb_sequence_of(s.clone(), count.clone());
+        let remote = arb_sequence_of(s.clone(), count.clone());
+        (Just(s), local, remote)
+    })
+}
+
+pub fn arb_sequence_of(s: String, count: impl Into<SizeRange>) -> impl Strategy<Value = Vec<Delta>> {
+    let steps = prop::collection::vec(prop::collection::vec(arb_step(), 0..16), count);
+    steps.prop_map(move |steps| {
+        let mut text = s.clone();
+        steps
+            .into_iter()
+            .map(|steps| {
+                let delta = build_delta(&text, steps);
+                text = delta.apply(&text).expect("The delta is built from the text");
+                delta
+            })
+            .collect()
+    })
+}
+
+#[derive(Debug, Clone)]
+enum Step {
+    Retain(usize, Attributes),
+    Delete(usize),
+    Insert(String, Attributes),
+}
+
+fn arb_step() -> impl Strategy<Value = Step> {
+    let insert = prop::collection::vec(arb_char(), 1..10).prop_map(|chars| chars.into_iter().collect::<String>());
+    prop_oneof![
+        (1usize..10, arb_attributes(true)).prop_map(|(n, attributes)| Step::Retain(n, attributes)),
+        (1usize..10).prop_map(Step::Delete),
+        (insert, arb_attributes(false)).prop_map(|(s, attributes)| Step::Insert(s, attributes)),
+    ]
+}
+
+// The retains and the deletes are cut to the chars that are left, on the char
+// boundaries to not split a surrogate pair. The rest of the text is retained.
+fn build_delta(s: &str, steps: Vec<Step>) -> Delta {
+    let chars = s.chars().collect::<Vec<char>>();
+    let mut index = 0;
+    let mut delta = Delta::default();
+    for step in steps {
+        match step {
+            Step::Retain(n, attributes) => match take_chars(&chars, &mut index, n) {
+                0 => {},
+                len => delta.retain(len, attributes),
+            },
+            Step::Delete(n) => match take_chars(&chars, &mut index, n) {
+                0 => {},
+                len => delta.delete(len),
+            },
+            Step::Insert(s, attributes) => delta.insert(&s, attributes),
+        }
+    }
+    let rest = take_chars(&chars, &mut index, chars.len());
+    if rest > 0 {
+        delta.retain(rest, Attributes::default());
+    }
+    delta
+}
+
+// The utf16 length of the n chars that are taken.
+fn take_chars(chars: &[char], index: &mut usize, n: usize) -> usize {
+    let end = (*index + n).min(chars.len());
+    let len = chars[*index..end].iter().map(|c| c.len_utf16()).sum();
+    *index = end;
+    len
+}
+
+// (a.b).c == a.(b.c)
+pub fn check_compose_associative(a: &Delta, b: &Delta, c: &Delta) -> Result<(), TestCaseError> {
+    let ab_c = compose(&compose(a, b)?, c)?;
+    let a_bc = compose(a, &compose(b, c)?)?;
+    prop_assert_eq!(ab_c, a_bc);
+    Ok(())
+}
+
+// Applying the composed delta is the same as applying one after the other.
+pub fn check_compose_applies(s: &str, a: &Delta, b: &Delta) -> Result<(), TestCaseError> {
+    let ab = compose(a, b)?;
+    prop_assert_eq!(ab.base_len, a.base_len);
+    prop_assert_eq!(ab.target_len, b.target_len);
+    prop_assert_eq!(apply(&ab, s)?, apply(b, &apply(a, s)?)?);
+    Ok(())
+}
+
+// doc.a.b' == doc.b.a', where (a', b') = a.transform(b).
+pub fn check_transform_converges(doc: &Delta, a: &Delta, b: &Delta) -> Result<(), TestCaseError> {
+    let (a_prime, b_prime) = a.transform(b).map_err(fail)?;
+    prop_assert_eq!(a_prime.base_len, b.target_len);
+    prop_assert_eq!(b_prime.base_len, a.target_len);
+
+    let left = compose(&compose(doc, a)?, &b_prime)?;
+    let right = compose(&compose(doc, b)?, &a_prime)?;
+    prop_assert_eq!(left, right);
+    Ok(())
+}
+
+// doc.change.undo == doc, where undo = change.invert(doc).
+pub fn check_invert(doc: &Delta, change: &Delta) -> Result<(), TestCaseError> {
+    let undo = change.invert(doc);
+    let changed = compose(doc, change)?;
+    prop_assert_eq!(undo.base_len, changed.target_len);
+    prop_assert_eq!(apply(&compose(&changed, &undo)?, "")?, apply(doc, "")?);
+    Ok(())
+}
+
+// Each site applies its own changes, then the ones of the other site
+// transformed against them. Both end with the same text.
+pub fn check_sites_converge(s: &str, local: &[Delta], remote: &[Delta]) -> Result<(), TestCaseError> {
+    let local = compose_all(s, local)?;
+    let remote = compose_all(s, remote)?;
+    let (local_prime, remote_prime) = local.transform(&remote).map_err(fail)?;
+
+    let local_text = apply(&remote_prime, &apply(&local, s)?)?;
+    let remote_text = apply(&local_prime, &apply(&remote, s)?)?;
+    prop_assert_eq!(local_text, remote_text);
+    Ok(())
+}
+
+fn compose_all(s: &str, deltas: &[Delta]) -> Result<Delta, TestCaseError> {
+    let noop = DeltaBuilder::new().retain(s.encode_utf16().count()).build();
+    deltas
+        .iter()
+        .try_fold(noop, |composed, delta| compose(&composed, delta))
+}
+
+fn compose(a: &Delta, b: &Delta) -> Result<Delta, TestCaseError> { a.compose(b).map_err(fail) }
+
+fn apply(delta: &Delta, s: &str) -> Result<String, TestCaseError> { delta.apply(s).map_err(fail) }
+
+fn fail(e: OTError) -> TestCaseError { TestCaseError::fail(format!("{:?}", e)) }
@@ -0,0 +1,157 @@
+use crate::{helper::async_sign_up, FlowyTest, FlowyTestSDK};
+use flowy_dart_notify::{
+    dart::{add_subject_listener, remove_subject_listener},
+    entities::SubscribeObject,
+};
+use flowy_dispatch::prelude::*;
+use flowy_sdk::FlowySDKConfig;
+use flowy_user::entities::UserProfile;
+use std::{
+    convert::TryFrom,
+    fmt::{Debug, Display},
+    hash::Hash,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::sync::Notify;
+
+const NOTIFICATION_TIMEOUT: Duration = Duration::from_secs(5);
+
+// Boots an in-memory sdk and records the notifications it sends, so a test of
+// a module is a few events and assertions:
+//
+//     let tester = EventTester::new();
+//     let _ = tester.sign_up().await;
+//     let app = tester.send(CreateApp, request).await.parse::<App>();
+//     let subject = tester.expect_notification(&app.workspace_id, ty).await;
+//
+// The notifications of the other sdks in the process are recorded too, they're
+// told apart by their ids.
+pub struct EventTester {
+    test: FlowyTest,
+    notifications: Arc<Notifications>,
+    listener_id: u64,
+}
+
+#[derive(Default)]
+struct Notifications {
+    subjects: Mutex<Vec<SubscribeObject>>,
+    notify: Notify,
+}
+
+impl EventTester {
+    pub fn new() -> Self { Self::with_test(FlowyTest::setup_in_memory()) }
+
+    pub fn with_config(config: FlowySDKConfig) -> Self { Self::with_test(FlowyTest::setup_with_config(config)) }
+
+    fn with_test(test: FlowyTest) -> Self {
+        let notifications = Arc::new(Notifications::default());
+        let cloned_notifications = notifications.clone();
+        let listener_id = add_subject_listener(Arc::new(move |subject| {
+            cloned_notifications.subjects.lock().unwrap().push(subject.clone());
+            cloned_notifications.notify.notify_waiters();
+        }));
+        Self {
+            test,
+            notifications,
+            listener_id,
+        }
+    }
+
+    pub fn sdk(&self) -> FlowyTestSDK { self.test.sdk() }
+
+    pub async fn sign_up(&self) -> UserProfile { async_sign_up(self.test.sdk.dispatch()).await.user_profile }
+
+    pub async fn send<Event, P>(&self, event: Event, payload: P) -> TestResponse
+    where
+        Event: Eq + Hash + Debug + Clone + Display,
+        P: ToBytes,
+    {
+        let bytes = payload.into_bytes().expect("Serialize the payload failed");
+        self.send_request(ModuleRequest::new(event).payload(bytes)).await
+    }
+
+    pub async fn send_event<Event>(&self, event: Event) -> TestResponse
+    where
+        Event: Eq + Hash + Debug + Clone + Display,
+    {
+        self.send_request(ModuleRequest::new(event)).await
+    }
+
+    async fn send_request(&self, request: ModuleRequest) -> TestResponse {
+        let response = EventDispatch::async_send(self.test.sdk.dispatch(), request).await;
+        TestResponse { response }
+    }
+
+    // The notifications with the id that were sent since the tester was
+    // created, the oldest first.
+    pub fn notifications(&self, id: &str) -> Vec<SubscribeObject> {
+        self.notifications
+            .subjects
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|subject| subject.id == id)
+            .cloned()
+            .collect()
+    }
+
+    // Waits for the notification of the type with the id, it may have been sent
+    // already. Panics if it isn't sent in 5 seconds.
+    pub async fn expect_notification<T: Into<i32>>(&self, id: &str, ty: T) -> SubscribeObject {
+        let ty = ty.into();
+        let wait = async {
+            loop {
+                let notified = self.notifications.notify.notified();
+                if let Some(subject) = self.notifications(id).into_iter().find(|subject| subject.ty == ty) {
+                    return subject;
+                }
+                notified.await;
+            }
+        };
+        match tokio::time::timeout(NOTIFICATION_TIMEOUT, wait).await {
+            Ok(subject) => subject,
+            Err(_) => panic!("The notification {} of {} wasn't sent", ty, id),
+        }
+    }
+
+    // The payload of the notification, parsed like the responses.
+    pub async fn expect_notification_payload<T: Into<i32>, R: FromBytes>(&self, id: &str, ty: T) -> R {
+        let subject = self.expect_notification(id, ty).await;
+        let payload = subject.payload.expect("The notification has no payload");
+        R::parse_from_bytes(&payload).expect("Parse the notification payload failed")
+    }
+}
+
+impl std::default::Default for EventTester {
+    fn default() -> Self { Self::new() }
+}
+
+impl std::ops::Drop for EventTester {
+    fn drop(&mut self) { remove_subject_listener(self.listener_id); }
+}
+
+pub struct TestResponse {
+    response: EventResponse,
+}
+
+impl TestResponse {
+    pub fn status_code(&self) -> StatusCode { self.response.status_code.clone() }
+
+    // Panics with the status code if the event failed.
+    pub fn parse<R: FromBytes>(self) -> R {
+        assert_eq!(self.response.status_code, StatusCode::Ok, "The event failed");
+        <Data<R>>::try_from(self.response.payload)
+            .expect("Parse the response failed")
+            .into_inner()
+    }
+
+    // The error of the module, e.g. WorkspaceError. Panics if the event
+    // succeeded.
+    pub fn error<E: FromBytes>(self) -> E {
+        assert_ne!(self.response.status_code, StatusCode::Ok, "The event succeeded");
+        <Data<E>>::try_from(self.response.payload)
+            .expect("Parse the error failed")
+            .into_inner()
+    }
+}
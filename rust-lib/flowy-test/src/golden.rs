@@ -0,0 +1,123 @@
+use flowy_dispatch::prelude::EventResponse;
+use std::path::PathBuf;
+
+const REDACTED: &str = "\"<redacted>\"";
+
+// Compares the response with the golden file tests/golden/<name>.txt of the
+// crate that runs the test. The payload is printed with the protobuf text
+// format, one field per line, so a change of the wire format shows up in the
+// diff. The goldens are written when they don't exist or when UPDATE_GOLDENS is
+// set.
+pub struct Golden {
+    name: String,
+    redacted: Vec<String>,
+}
+
+impl Golden {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            redacted: vec![],
+        }
+    }
+
+    // The field changes on every run, e.g. an id or a timestamp. It's matched
+    // at any depth.
+    pub fn redact(mut self, field: &str) -> Self {
+        self.redacted.push(field.to_owned());
+        self
+    }
+
+    pub fn assert<M>(&self, response: &EventResponse)
+    where
+        M: protobuf::Message,
+    {
+        let text = golden_text::<M>(response);
+        self.assert_text(&self.redact_text(&text));
+    }
+
+    pub fn assert_text(&self, actual: &str) {
+        let path = self.path();
+        let update = std::env::var("UPDATE_GOLDENS").is_ok();
+        match std::fs::read_to_string(&path) {
+            Ok(expected) if !update => {
+                if expected != actual {
+                    panic!(
+                        "The response doesn't match the golden {:?}, run with UPDATE_GOLDENS=1 if the change is \
+                         intended:\n{}",
+                        path,
+                        diff(&expected, actual)
+                    );
+                }
+            },
+            _ => {
+                let _ = std::fs::create_dir_all(path.parent().unwrap());
+                std::fs::write(&path, actual).unwrap();
+                log::info!("Write the golden {:?}", path);
+            },
+        }
+    }
+
+    fn path(&self) -> PathBuf {
+        let dir = std::env::var("CARGO_MANIFEST_DIR").expect("The goldens are only used by the cargo tests");
+        PathBuf::from(dir)
+            .join("tests")
+            .join("golden")
+            .join(format!("{}.txt", self.name))
+    }
+
+    fn redact_text(&self, text: &str) -> String {
+        text.lines()
+            .map(|line| {
+                let content = line.trim_start();
+                let indent = &line[..line.len() - content.len()];
+                match self
+                    .redacted
+                    .iter()
+                    .find(|field| content.starts_with(&format!("{}: ", field)))
+                {
+                    Some(field) => format!("{}{}: {}\n", indent, field, REDACTED),
+                    None => format!("{}\n", line),
+                }
+            })
+            .collect()
+    }
+}
+
+// The status, then the payload in the protobuf text format. The error
+// responses have the payload of the error of the module, M is the type that
+// is expected for the status.
+pub fn golden_text<M>(response: &EventResponse) -> String
+where
+    M: protobuf::Message,
+{
+    let payload = match response.payload.as_bytes() {
+        None => "<none>\n".to_owned(),
+        Some(bytes) => match M::parse_from_bytes(bytes) {
+            Ok(message) => format!("{:#?}", message),
+            Err(e) => panic!("The payload is not a {}: {:?}", std::any::type_name::<M>(), e),
+        },
+    };
+    format!("status: {:?}\n{}", response.status_code, payload)
+}
+
+// The lines that differ, - for the golden and + for the response.
+fn diff(expected: &str, actual: &str) -> String {
+    let expected = expected.lines().collect::<Vec<_>>();
+    let actual = actual.lines().collect::<Vec<_>>();
+    let mut output = String::new();
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(a), Some(b)) if a == b => output.push_str(&format!("  {}\n", a)),
+            (a, b) => {
+                if let Some(a) = a {
+                    output.push_str(&format!("- {}\n", a));
+                }
+                if let Some(b) = b {
+                    output.push_str(&format!("+ {}\n", b));
+                }
+            },
+        }
+    }
+    output
+}
@@ -1,16 +1,41 @@
 pub mod builder;
+pub mod delta;
+pub mod golden;
+mod event_tester;
 mod helper;
 pub mod workspace;
 
+pub use crate::event_tester::{EventTester, TestResponse};
 use crate::helper::*;
-use flowy_infra::uuid;
+use flowy_infra::{secret::SecretStoreKind, uuid};
 use flowy_net::config::ServerConfig;
 use flowy_sdk::{FlowySDK, FlowySDKConfig};
 use flowy_user::entities::UserProfile;
 
 pub mod prelude {
-    pub use crate::{builder::*, helper::*, *};
+    pub use crate::{builder::*, golden::Golden, helper::*, *};
     pub use flowy_dispatch::prelude::*;
+    pub use flowy_sdk::{
+        entities::{
+            CrashBundleContent,
+            CrashBundleIdentifier,
+            LocaleSetting,
+            LogFilterSetting,
+            MemoryUsage,
+            MetricsSnapshot,
+            RepeatedCrashBundle,
+            RepeatedLogRecord,
+            SetLocaleRequest,
+            SetLogFilterRequest,
+            SubscribeLogRequest,
+            Translations,
+            TranslationsRequest,
+        },
+        plugin::{FlowyPlugin, PluginContext},
+        system::SystemEvent,
+        write_crash_bundle,
+        FlowySDKConfig,
+    };
 }
 
 pub type FlowyTestSDK = FlowySDK;
@@ -39,10 +64,29 @@ impl FlowyTest {
     }
 
     pub fn setup_with(server_config: ServerConfig) -> Self {
-        let config = FlowySDKConfig::new(&root_dir(), server_config, &uuid().to_string()).log_filter("debug");
+        // Keep the test sessions out of the credential store of the machine
+        let config = FlowySDKConfig::new(&root_dir(), server_config, &uuid().to_string())
+            .log_filter("debug")
+            .secret_store(SecretStoreKind::File);
         let sdk = FlowySDK::new(config);
         Self { sdk }
     }
 
+    // Nothing is written to the disk, the tests with it can run in parallel.
+    pub fn setup_in_memory() -> Self {
+        let config = FlowySDKConfig::new(&root_dir(), ServerConfig::default(), &uuid().to_string())
+            .log_filter("debug")
+            .in_memory_storage(true);
+        Self::setup_with_config(config)
+    }
+
+    pub fn setup_with_config(config: FlowySDKConfig) -> Self {
+        let test = Self {
+            sdk: FlowySDK::new(config),
+        };
+        std::mem::forget(test.sdk.dispatch());
+        test
+    }
+
     pub fn sdk(&self) -> FlowyTestSDK { self.sdk.clone() }
 }
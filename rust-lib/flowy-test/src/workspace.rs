@@ -220,6 +220,8 @@ pub async fn create_view(sdk: &FlowyTestSDK, app_id: &str) -> View {
         desc: "".to_string(),
         thumbnail: Some("http://1.png".to_string()),
         view_type: ViewType::Doc,
+        // The views created by the tests share the name.
+        auto_rename: true,
     };
 
     create_view_with_request(sdk, request).await
@@ -0,0 +1 @@
+fn main() { flowy_proto_build::gen_proto(); }
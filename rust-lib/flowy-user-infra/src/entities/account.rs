@@ -0,0 +1,141 @@
+use flowy_derive::{FromPayload, ProtoBuf, ProtoBuf_Enum};
+use std::convert::TryInto;
+
+use crate::{errors::ErrorCode, parser::UserId};
+
+#[derive(ProtoBuf, Default, Debug, PartialEq, Eq, Clone)]
+pub struct Account {
+    #[pb(index = 1)]
+    pub user_id: String,
+
+    #[pb(index = 2)]
+    pub email: String,
+
+    #[pb(index = 3)]
+    pub is_current: bool,
+}
+
+#[derive(ProtoBuf, Default, Debug, PartialEq, Eq, Clone)]
+pub struct RepeatedAccount {
+    #[pb(index = 1)]
+    pub items: Vec<Account>,
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct SwitchAccountRequest {
+    #[pb(index = 1)]
+    pub user_id: String,
+}
+
+impl SwitchAccountRequest {
+    pub fn new(user_id: &str) -> Self {
+        Self {
+            user_id: user_id.to_owned(),
+        }
+    }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone, FromPayload)]
+#[payload(from = "SwitchAccountRequest")]
+pub struct SwitchAccountParams {
+    #[pb(index = 1)]
+    pub user_id: String,
+}
+
+impl TryInto<SwitchAccountParams> for SwitchAccountRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<SwitchAccountParams, Self::Error> {
+        let user_id = UserId::parse(self.user_id)?;
+        Ok(SwitchAccountParams { user_id: user_id.0 })
+    }
+}
+
+// The anonymous user doesn't have an email, it confirms with this text instead.
+pub const DELETE_ACCOUNT_CONFIRMATION: &str = "DELETE";
+
+#[derive(ProtoBuf, Default)]
+pub struct DeleteAccountRequest {
+    // The email of the current user, typed by the user to confirm the deletion.
+    #[pb(index = 1)]
+    pub confirmation: String,
+
+    // Not needed by the anonymous user.
+    #[pb(index = 2)]
+    pub password: String,
+}
+
+impl DeleteAccountRequest {
+    pub fn new(confirmation: &str, password: &str) -> Self {
+        Self {
+            confirmation: confirmation.to_owned(),
+            password: password.to_owned(),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, FromPayload)]
+#[payload(from = "DeleteAccountRequest")]
+pub struct DeleteAccountConfirmation {
+    pub confirmation: String,
+    pub password: String,
+}
+
+impl TryInto<DeleteAccountConfirmation> for DeleteAccountRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<DeleteAccountConfirmation, Self::Error> {
+        if self.confirmation.trim().is_empty() {
+            return Err(ErrorCode::DeleteAccountNotConfirmed);
+        }
+
+        Ok(DeleteAccountConfirmation {
+            confirmation: self.confirmation.trim().to_owned(),
+            password: self.password,
+        })
+    }
+}
+
+// Sent to the server, the signature is signature::sign(token, delete_account_message).
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DeleteAccountParams {
+    #[pb(index = 1)]
+    pub user_id: String,
+
+    #[pb(index = 2)]
+    pub password: String,
+
+    #[pb(index = 3)]
+    pub timestamp: i64,
+
+    #[pb(index = 4)]
+    pub signature: String,
+}
+
+#[derive(ProtoBuf_Enum, Debug, Clone, PartialEq, Eq)]
+pub enum DeleteAccountStage {
+    DeleteRemote    = 0,
+    // The server is unreachable, only the local data is deleted.
+    SkipRemote      = 1,
+    WipeDatabase    = 2,
+    WipeCache       = 3,
+    WipeAttachments = 4,
+    Completed       = 5,
+}
+
+impl std::default::Default for DeleteAccountStage {
+    fn default() -> Self { DeleteAccountStage::DeleteRemote }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DeleteAccountProgress {
+    #[pb(index = 1)]
+    pub stage: DeleteAccountStage,
+
+    // The stages that have been finished, out of total.
+    #[pb(index = 2)]
+    pub finished: i32,
+
+    #[pb(index = 3)]
+    pub total: i32,
+}
@@ -0,0 +1,42 @@
+use flowy_derive::ProtoBuf;
+
+// A mutating event that succeeded, the object is the one it was sent for, e.g.
+// the view that was updated. The time is in seconds.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct AuditRecord {
+    #[pb(index = 1)]
+    pub user_id: String,
+
+    #[pb(index = 2)]
+    pub event: String,
+
+    #[pb(index = 3)]
+    pub object_id: String,
+
+    #[pb(index = 4)]
+    pub create_time: i64,
+}
+
+// The newest first.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedAuditRecord {
+    #[pb(index = 1)]
+    pub items: Vec<AuditRecord>,
+}
+
+// The records of every object are read if object_id isn't set, the times are
+// inclusive.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct ReadAuditLogRequest {
+    #[pb(index = 1, one_of)]
+    pub object_id: Option<String>,
+
+    #[pb(index = 2, one_of)]
+    pub from_time: Option<i64>,
+
+    #[pb(index = 3, one_of)]
+    pub to_time: Option<i64>,
+
+    #[pb(index = 4, one_of)]
+    pub limit: Option<i64>,
+}
@@ -1,6 +1,6 @@
 use std::convert::TryInto;
 
-use flowy_derive::ProtoBuf;
+use flowy_derive::{FromPayload, ProtoBuf};
 
 use crate::{errors::*, parser::*};
 
@@ -16,7 +16,8 @@ pub struct SignInRequest {
     pub name: String,
 }
 
-#[derive(Default, ProtoBuf, Debug)]
+#[derive(Default, ProtoBuf, Debug, FromPayload)]
+#[payload(from = "SignInRequest")]
 pub struct SignInParams {
     #[pb(index = 1)]
     pub email: String,
@@ -26,6 +27,13 @@ pub struct SignInParams {
 
     #[pb(index = 3)]
     pub name: String,
+
+    // Identifies the session of this device on the server, see DeviceSession.
+    #[pb(index = 4)]
+    pub device_id: String,
+
+    #[pb(index = 5)]
+    pub device_name: String,
 }
 
 #[derive(Debug, Default, ProtoBuf)]
@@ -54,6 +62,7 @@ impl TryInto<SignInParams> for SignInRequest {
             email: email.0,
             password: password.0,
             name: self.name,
+            ..Default::default()
         })
     }
 }
@@ -81,11 +90,13 @@ impl TryInto<SignUpParams> for SignUpRequest {
             email: email.0,
             name: name.0,
             password: password.0,
+            ..Default::default()
         })
     }
 }
 
-#[derive(ProtoBuf, Default, Debug)]
+#[derive(ProtoBuf, Default, Debug, FromPayload)]
+#[payload(from = "SignUpRequest")]
 pub struct SignUpParams {
     #[pb(index = 1)]
     pub email: String,
@@ -95,6 +106,13 @@ pub struct SignUpParams {
 
     #[pb(index = 3)]
     pub password: String,
+
+    // Identifies the session of this device on the server, see DeviceSession.
+    #[pb(index = 4)]
+    pub device_id: String,
+
+    #[pb(index = 5)]
+    pub device_name: String,
 }
 
 #[derive(ProtoBuf, Debug, Default)]
@@ -111,3 +129,60 @@ pub struct SignUpResponse {
     #[pb(index = 4)]
     pub token: String,
 }
+
+#[derive(ProtoBuf, Debug, Default)]
+pub struct RefreshTokenResponse {
+    #[pb(index = 1)]
+    pub token: String,
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct UpdatePasswordRequest {
+    #[pb(index = 1)]
+    pub old_password: String,
+
+    #[pb(index = 2)]
+    pub new_password: String,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone, FromPayload)]
+#[payload(from = "UpdatePasswordRequest")]
+pub struct UpdatePasswordParams {
+    #[pb(index = 1)]
+    pub old_password: String,
+
+    #[pb(index = 2)]
+    pub new_password: String,
+}
+
+impl UpdatePasswordRequest {
+    pub fn new(old_password: &str, new_password: &str) -> Self {
+        Self {
+            old_password: old_password.to_owned(),
+            new_password: new_password.to_owned(),
+        }
+    }
+}
+
+// The new password is checked against the PasswordPolicy of the user session, which
+// may be configured differently from the default rules of UserPassword.
+impl TryInto<UpdatePasswordParams> for UpdatePasswordRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdatePasswordParams, Self::Error> {
+        if self.old_password.trim().is_empty() || self.new_password.trim().is_empty() {
+            return Err(ErrorCode::PasswordIsEmpty);
+        }
+
+        Ok(UpdatePasswordParams {
+            old_password: self.old_password,
+            new_password: self.new_password,
+        })
+    }
+}
+
+#[derive(ProtoBuf, Debug, Default)]
+pub struct UpdatePasswordResponse {
+    #[pb(index = 1)]
+    pub token: String,
+}
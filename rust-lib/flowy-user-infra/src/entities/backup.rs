@@ -0,0 +1,61 @@
+use crate::errors::ErrorCode;
+use flowy_derive::{FromPayload, ProtoBuf};
+use std::convert::TryInto;
+
+// A copy of the database of the user, kept in the backups directory next to it.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct DatabaseBackup {
+    #[pb(index = 1)]
+    pub id: String,
+
+    // In milliseconds.
+    #[pb(index = 2)]
+    pub created_time: i64,
+
+    // The size of the file, in bytes.
+    #[pb(index = 3)]
+    pub size: i64,
+}
+
+// The newest first.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedDatabaseBackup {
+    #[pb(index = 1)]
+    pub items: Vec<DatabaseBackup>,
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct RestoreBackupRequest {
+    #[pb(index = 1)]
+    pub backup_id: String,
+}
+
+impl RestoreBackupRequest {
+    pub fn new(backup_id: &str) -> Self {
+        Self {
+            backup_id: backup_id.to_owned(),
+        }
+    }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone, FromPayload)]
+#[payload(from = "RestoreBackupRequest")]
+pub struct RestoreBackupParams {
+    #[pb(index = 1)]
+    pub backup_id: String,
+}
+
+impl TryInto<RestoreBackupParams> for RestoreBackupRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<RestoreBackupParams, Self::Error> {
+        // The ids are the names of the files in the backups directory.
+        if self.backup_id.is_empty() || !self.backup_id.chars().all(|c| c.is_ascii_digit()) {
+            return Err(ErrorCode::BackupNotExist);
+        }
+
+        Ok(RestoreBackupParams {
+            backup_id: self.backup_id,
+        })
+    }
+}
@@ -0,0 +1,15 @@
+use flowy_derive::ProtoBuf;
+
+// The sizes are the ones of the user database and its write-ahead log, in
+// bytes.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct StorageCompaction {
+    #[pb(index = 1)]
+    pub size_before: i64,
+
+    #[pb(index = 2)]
+    pub size_after: i64,
+
+    #[pb(index = 3)]
+    pub reclaimed_bytes: i64,
+}
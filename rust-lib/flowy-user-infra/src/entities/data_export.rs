@@ -0,0 +1,51 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+#[derive(ProtoBuf_Enum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataExportFormat {
+    // A "{table}.jsonl" file per table, one JSON object per row.
+    JsonLines = 0,
+    // A plaintext copy of the database, "data.db".
+    Sqlite    = 1,
+}
+
+impl std::default::Default for DataExportFormat {
+    fn default() -> Self { DataExportFormat::JsonLines }
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct ExportAllDataRequest {
+    #[pb(index = 1)]
+    pub format: DataExportFormat,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct DataExportTable {
+    #[pb(index = 1)]
+    pub name: String,
+
+    #[pb(index = 2)]
+    pub rows: i64,
+}
+
+// The export of all the data of the user, the directory also has a
+// "manifest.json" that describes it.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DataExport {
+    #[pb(index = 1)]
+    pub dir: String,
+
+    #[pb(index = 2)]
+    pub format: DataExportFormat,
+
+    // The version of the layout of the export, it's increased when the files
+    // or the fields of the manifest change.
+    #[pb(index = 3)]
+    pub version: i32,
+
+    // In milliseconds.
+    #[pb(index = 4)]
+    pub created_time: i64,
+
+    #[pb(index = 5)]
+    pub tables: Vec<DataExportTable>,
+}
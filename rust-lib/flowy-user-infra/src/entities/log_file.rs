@@ -0,0 +1,18 @@
+use flowy_derive::ProtoBuf;
+
+// A file the log records are written to, the size is in bytes.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct LogFile {
+    #[pb(index = 1)]
+    pub path: String,
+
+    #[pb(index = 2)]
+    pub size: i64,
+}
+
+// The current file first, then the rotated ones from the newest.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedLogFile {
+    #[pb(index = 1)]
+    pub items: Vec<LogFile>,
+}
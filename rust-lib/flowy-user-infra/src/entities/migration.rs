@@ -0,0 +1,30 @@
+use flowy_derive::ProtoBuf;
+
+// Sent when a migration of the user database fails. The database stays closed,
+// the migrations are applied again the next time it's opened.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DatabaseMigrationFailure {
+    #[pb(index = 1)]
+    pub user_id: String,
+
+    // The version of the migration that failed, empty if it's unknown.
+    #[pb(index = 2)]
+    pub version: String,
+
+    #[pb(index = 3)]
+    pub msg: String,
+}
+
+// Sent while the plaintext database of the user is copied to an encrypted one,
+// after each table and once before the first.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DatabaseEncryptionProgress {
+    #[pb(index = 1)]
+    pub user_id: String,
+
+    #[pb(index = 2)]
+    pub done: i64,
+
+    #[pb(index = 3)]
+    pub total: i64,
+}
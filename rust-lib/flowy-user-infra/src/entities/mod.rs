@@ -1,9 +1,35 @@
+pub use account::*;
+pub use audit::*;
 pub use auth::*;
+pub use backup::*;
+pub use compaction::*;
+pub use data_export::*;
+pub use log_file::*;
+pub use migration::*;
+pub use oauth::*;
+pub use preference::*;
+pub use row_change::*;
+pub use session::*;
+pub use setting::*;
 pub use user_profile::*;
+pub use verification::*;
 
+mod account;
+mod audit;
 pub mod auth;
+mod backup;
+mod compaction;
+mod data_export;
+mod log_file;
+mod migration;
+mod oauth;
+mod preference;
+mod row_change;
+mod session;
+mod setting;
 mod user_profile;
+mod verification;
 
 pub mod prelude {
-    pub use crate::entities::{auth::*, user_profile::*};
+    pub use crate::entities::{account::*, audit::*, auth::*, backup::*, compaction::*, data_export::*, log_file::*, migration::*, oauth::*, preference::*, row_change::*, session::*, setting::*, user_profile::*, verification::*};
 }
@@ -0,0 +1,105 @@
+use flowy_derive::{FromPayload, ProtoBuf, ProtoBuf_Enum};
+use std::convert::TryInto;
+
+use crate::errors::ErrorCode;
+
+#[derive(PartialEq, Eq, Hash, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum OAuthProviderType {
+    Google = 0,
+    GitHub = 1,
+}
+
+impl std::default::Default for OAuthProviderType {
+    fn default() -> Self { OAuthProviderType::Google }
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct OAuthURLRequest {
+    #[pb(index = 1)]
+    pub provider: OAuthProviderType,
+}
+
+impl OAuthURLRequest {
+    pub fn new(provider: OAuthProviderType) -> Self { Self { provider } }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct OAuthURL {
+    #[pb(index = 1)]
+    pub url: String,
+
+    // Opaque value that the provider sends back along with the code. It must be passed to
+    // the OAuthSignIn event to complete the flow.
+    #[pb(index = 2)]
+    pub state: String,
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct OAuthSignInRequest {
+    #[pb(index = 1)]
+    pub provider: OAuthProviderType,
+
+    #[pb(index = 2)]
+    pub code: String,
+
+    #[pb(index = 3)]
+    pub state: String,
+}
+
+impl OAuthSignInRequest {
+    pub fn new(provider: OAuthProviderType, code: &str, state: &str) -> Self {
+        Self {
+            provider,
+            code: code.to_owned(),
+            state: state.to_owned(),
+        }
+    }
+}
+
+#[derive(Debug, FromPayload)]
+#[payload(from = "OAuthSignInRequest")]
+pub struct OAuthCompletion {
+    pub provider: OAuthProviderType,
+    pub code: String,
+    pub state: String,
+}
+
+impl TryInto<OAuthCompletion> for OAuthSignInRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<OAuthCompletion, Self::Error> {
+        if self.code.trim().is_empty() {
+            return Err(ErrorCode::OAuthCodeIsEmpty);
+        }
+
+        if self.state.trim().is_empty() {
+            return Err(ErrorCode::OAuthStateInvalid);
+        }
+
+        Ok(OAuthCompletion {
+            provider: self.provider,
+            code: self.code,
+            state: self.state,
+        })
+    }
+}
+
+// Sent to the server, which exchanges the code for the provider's tokens with the client
+// secret, then signs in the user that owns the provider account.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct OAuthSignInParams {
+    #[pb(index = 1)]
+    pub provider: OAuthProviderType,
+
+    #[pb(index = 2)]
+    pub code: String,
+
+    #[pb(index = 3)]
+    pub redirect_uri: String,
+
+    #[pb(index = 4)]
+    pub device_id: String,
+
+    #[pb(index = 5)]
+    pub device_name: String,
+}
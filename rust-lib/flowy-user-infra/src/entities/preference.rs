@@ -0,0 +1,177 @@
+use flowy_derive::{FromPayload, ProtoBuf};
+use std::convert::TryInto;
+
+use crate::errors::ErrorCode;
+
+// Only one of the values is set, it's the type of the preference.
+#[derive(ProtoBuf, Default, Debug, PartialEq, Clone)]
+pub struct PreferenceValue {
+    #[pb(index = 1, one_of)]
+    pub str_value: Option<String>,
+
+    #[pb(index = 2, one_of)]
+    pub int_value: Option<i64>,
+
+    #[pb(index = 3, one_of)]
+    pub float_value: Option<f64>,
+
+    #[pb(index = 4, one_of)]
+    pub bool_value: Option<bool>,
+}
+
+impl PreferenceValue {
+    pub fn str(value: &str) -> Self {
+        Self {
+            str_value: Some(value.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    pub fn int(value: i64) -> Self {
+        Self {
+            int_value: Some(value),
+            ..Default::default()
+        }
+    }
+
+    pub fn float(value: f64) -> Self {
+        Self {
+            float_value: Some(value),
+            ..Default::default()
+        }
+    }
+
+    pub fn bool(value: bool) -> Self {
+        Self {
+            bool_value: Some(value),
+            ..Default::default()
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> { self.str_value.as_deref() }
+
+    pub fn as_int(&self) -> Option<i64> { self.int_value }
+
+    pub fn as_float(&self) -> Option<f64> { self.float_value }
+
+    pub fn as_bool(&self) -> Option<bool> { self.bool_value }
+
+    pub fn type_name(&self) -> Option<&'static str> {
+        match (&self.str_value, &self.int_value, &self.float_value, &self.bool_value) {
+            (Some(_), None, None, None) => Some("str"),
+            (None, Some(_), None, None) => Some("int"),
+            (None, None, Some(_), None) => Some("float"),
+            (None, None, None, Some(_)) => Some("bool"),
+            _ => None,
+        }
+    }
+}
+
+#[derive(ProtoBuf, Default, Debug, PartialEq, Clone, FromPayload)]
+#[payload(from = "SetPreferenceRequest")]
+pub struct Preference {
+    #[pb(index = 1)]
+    pub key: String,
+
+    #[pb(index = 2)]
+    pub value: PreferenceValue,
+}
+
+#[derive(ProtoBuf, Default, Debug, PartialEq, Clone)]
+pub struct RepeatedPreference {
+    #[pb(index = 1)]
+    pub items: Vec<Preference>,
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct SetPreferenceRequest {
+    #[pb(index = 1)]
+    pub key: String,
+
+    #[pb(index = 2)]
+    pub value: PreferenceValue,
+}
+
+impl SetPreferenceRequest {
+    pub fn new(key: &str, value: PreferenceValue) -> Self {
+        Self {
+            key: key.to_owned(),
+            value,
+        }
+    }
+}
+
+impl TryInto<Preference> for SetPreferenceRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<Preference, Self::Error> {
+        let key = parse_preference_key(self.key)?;
+        if self.value.type_name().is_none() {
+            return Err(ErrorCode::PreferenceValueInvalid);
+        }
+
+        Ok(Preference { key, value: self.value })
+    }
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct GetPreferenceRequest {
+    #[pb(index = 1)]
+    pub key: String,
+}
+
+impl GetPreferenceRequest {
+    pub fn new(key: &str) -> Self { Self { key: key.to_owned() } }
+}
+
+#[derive(Default, Debug, Clone, FromPayload)]
+#[payload(from = "GetPreferenceRequest")]
+pub struct PreferenceKey(pub String);
+
+impl TryInto<PreferenceKey> for GetPreferenceRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<PreferenceKey, Self::Error> { Ok(PreferenceKey(parse_preference_key(self.key)?)) }
+}
+
+// The frontend is notified with PreferenceChanged, whose id is the key, when one of
+// the keys changes.
+#[derive(ProtoBuf, Default)]
+pub struct SubscribePreferenceRequest {
+    #[pb(index = 1)]
+    pub keys: Vec<String>,
+}
+
+impl SubscribePreferenceRequest {
+    pub fn new(keys: Vec<&str>) -> Self {
+        Self {
+            keys: keys.into_iter().map(|key| key.to_owned()).collect(),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, FromPayload)]
+#[payload(from = "SubscribePreferenceRequest")]
+pub struct SubscribePreferenceParams {
+    pub keys: Vec<String>,
+}
+
+impl TryInto<SubscribePreferenceParams> for SubscribePreferenceRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<SubscribePreferenceParams, Self::Error> {
+        let keys = self
+            .keys
+            .into_iter()
+            .map(parse_preference_key)
+            .collect::<Result<Vec<String>, ErrorCode>>()?;
+        Ok(SubscribePreferenceParams { keys })
+    }
+}
+
+fn parse_preference_key(key: String) -> Result<String, ErrorCode> {
+    if key.trim().is_empty() {
+        return Err(ErrorCode::PreferenceKeyIsEmpty);
+    }
+    Ok(key)
+}
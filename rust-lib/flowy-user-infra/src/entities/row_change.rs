@@ -0,0 +1,34 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+#[derive(ProtoBuf_Enum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowChangeType {
+    Insert = 0,
+    Update = 1,
+    Delete = 2,
+}
+
+impl std::default::Default for RowChangeType {
+    fn default() -> Self { RowChangeType::Insert }
+}
+
+// A row of the user database that was changed, the key is its primary key as
+// text.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct DatabaseRowChange {
+    #[pb(index = 1)]
+    pub table: String,
+
+    #[pb(index = 2)]
+    pub key: String,
+
+    #[pb(index = 3)]
+    pub ty: RowChangeType,
+}
+
+// The rows changed by one connection of the database, in the order they were
+// first changed.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedDatabaseRowChange {
+    #[pb(index = 1)]
+    pub items: Vec<DatabaseRowChange>,
+}
@@ -0,0 +1,67 @@
+use crate::errors::ErrorCode;
+use flowy_derive::{FromPayload, ProtoBuf};
+use std::convert::TryInto;
+
+// A signed in session of the user, each device keeps its own.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct DeviceSession {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub device_id: String,
+
+    #[pb(index = 3)]
+    pub device_name: String,
+
+    #[pb(index = 4)]
+    pub created_at: i64,
+
+    #[pb(index = 5)]
+    pub last_active_at: i64,
+
+    // The session the request was sent with
+    #[pb(index = 6)]
+    pub is_current: bool,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedDeviceSession {
+    #[pb(index = 1)]
+    pub items: Vec<DeviceSession>,
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct RevokeSessionRequest {
+    #[pb(index = 1)]
+    pub session_id: String,
+}
+
+impl RevokeSessionRequest {
+    pub fn new(session_id: &str) -> Self {
+        Self {
+            session_id: session_id.to_owned(),
+        }
+    }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone, FromPayload)]
+#[payload(from = "RevokeSessionRequest")]
+pub struct RevokeSessionParams {
+    #[pb(index = 1)]
+    pub session_id: String,
+}
+
+impl TryInto<RevokeSessionParams> for RevokeSessionRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<RevokeSessionParams, Self::Error> {
+        if self.session_id.trim().is_empty() {
+            return Err(ErrorCode::SessionIdIsEmpty);
+        }
+
+        Ok(RevokeSessionParams {
+            session_id: self.session_id,
+        })
+    }
+}
@@ -0,0 +1,317 @@
+use flowy_derive::{FromPayload, ProtoBuf, ProtoBuf_Enum};
+use std::{collections::HashSet, convert::TryInto};
+
+use crate::errors::ErrorCode;
+
+#[derive(PartialEq, Eq, Hash, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum SettingSection {
+    Appearance = 0,
+    Editor     = 1,
+    Sync       = 2,
+    Shortcuts  = 3,
+    Privacy    = 4,
+}
+
+impl SettingSection {
+    pub fn all() -> Vec<SettingSection> {
+        vec![
+            SettingSection::Appearance,
+            SettingSection::Editor,
+            SettingSection::Sync,
+            SettingSection::Shortcuts,
+            SettingSection::Privacy,
+        ]
+    }
+
+    // The key of the section in the database and the id of its SettingChanged
+    // notifications, don't change it once it's released.
+    pub fn name(&self) -> &'static str {
+        match self {
+            SettingSection::Appearance => "appearance",
+            SettingSection::Editor => "editor",
+            SettingSection::Sync => "sync",
+            SettingSection::Shortcuts => "shortcuts",
+            SettingSection::Privacy => "privacy",
+        }
+    }
+}
+
+impl std::default::Default for SettingSection {
+    fn default() -> Self { SettingSection::Appearance }
+}
+
+#[derive(PartialEq, Eq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum ThemeMode {
+    System = 0,
+    Light  = 1,
+    Dark   = 2,
+}
+
+impl std::default::Default for ThemeMode {
+    fn default() -> Self { ThemeMode::System }
+}
+
+#[derive(ProtoBuf, Debug, PartialEq, Clone)]
+pub struct AppearanceSetting {
+    #[pb(index = 1)]
+    pub theme: ThemeMode,
+
+    #[pb(index = 2)]
+    pub locale: String,
+
+    // The scale of the text in percent, 50..=200.
+    #[pb(index = 3)]
+    pub text_scale: i32,
+}
+
+impl std::default::Default for AppearanceSetting {
+    fn default() -> Self {
+        Self {
+            theme: ThemeMode::System,
+            locale: "en".to_owned(),
+            text_scale: 100,
+        }
+    }
+}
+
+impl AppearanceSetting {
+    pub fn check(&self) -> Result<(), ErrorCode> {
+        if self.locale.trim().is_empty() || !(50..=200).contains(&self.text_scale) {
+            return Err(ErrorCode::SettingValueInvalid);
+        }
+        Ok(())
+    }
+}
+
+#[derive(ProtoBuf, Debug, PartialEq, Clone)]
+pub struct EditorSetting {
+    // 8..=72
+    #[pb(index = 1)]
+    pub font_size: i32,
+
+    #[pb(index = 2)]
+    pub spell_check: bool,
+
+    // 1..=8
+    #[pb(index = 3)]
+    pub tab_size: i32,
+}
+
+impl std::default::Default for EditorSetting {
+    fn default() -> Self {
+        Self {
+            font_size: 14,
+            spell_check: true,
+            tab_size: 4,
+        }
+    }
+}
+
+impl EditorSetting {
+    pub fn check(&self) -> Result<(), ErrorCode> {
+        if !(8..=72).contains(&self.font_size) || !(1..=8).contains(&self.tab_size) {
+            return Err(ErrorCode::SettingValueInvalid);
+        }
+        Ok(())
+    }
+}
+
+#[derive(ProtoBuf, Debug, PartialEq, Clone)]
+pub struct SyncSetting {
+    #[pb(index = 1)]
+    pub enabled: bool,
+
+    // The seconds between two syncs, 5..=3600.
+    #[pb(index = 2)]
+    pub interval_secs: i64,
+
+    #[pb(index = 3)]
+    pub wifi_only: bool,
+}
+
+impl std::default::Default for SyncSetting {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_secs: 30,
+            wifi_only: false,
+        }
+    }
+}
+
+impl SyncSetting {
+    pub fn check(&self) -> Result<(), ErrorCode> {
+        if !(5..=3600).contains(&self.interval_secs) {
+            return Err(ErrorCode::SettingValueInvalid);
+        }
+        Ok(())
+    }
+}
+
+// The keys are written like "Ctrl+Shift+Z", the modifiers first.
+#[derive(ProtoBuf, Default, Debug, PartialEq, Clone)]
+pub struct Shortcut {
+    #[pb(index = 1)]
+    pub action: String,
+
+    #[pb(index = 2)]
+    pub keys: String,
+}
+
+impl Shortcut {
+    pub fn new(action: &str, keys: &str) -> Self {
+        Self {
+            action: action.to_owned(),
+            keys: keys.to_owned(),
+        }
+    }
+}
+
+#[derive(ProtoBuf, Debug, PartialEq, Clone)]
+pub struct ShortcutSetting {
+    #[pb(index = 1)]
+    pub items: Vec<Shortcut>,
+}
+
+impl std::default::Default for ShortcutSetting {
+    fn default() -> Self {
+        Self {
+            items: vec![
+                Shortcut::new("undo", "Ctrl+Z"),
+                Shortcut::new("redo", "Ctrl+Shift+Z"),
+                Shortcut::new("search", "Ctrl+P"),
+                Shortcut::new("new_page", "Ctrl+N"),
+            ],
+        }
+    }
+}
+
+impl ShortcutSetting {
+    // An action has one shortcut and the keys trigger one action.
+    pub fn check(&self) -> Result<(), ErrorCode> {
+        let mut actions = HashSet::new();
+        let mut keys = HashSet::new();
+        for shortcut in &self.items {
+            if shortcut.action.trim().is_empty() || shortcut.keys.trim().is_empty() {
+                return Err(ErrorCode::ShortcutInvalid);
+            }
+
+            if !actions.insert(shortcut.action.as_str()) || !keys.insert(shortcut.keys.to_lowercase()) {
+                return Err(ErrorCode::ShortcutConflict);
+            }
+        }
+        Ok(())
+    }
+}
+
+// Nothing is collected or sent unless the user enables the telemetry.
+#[derive(ProtoBuf, Default, Debug, PartialEq, Clone)]
+pub struct PrivacySetting {
+    #[pb(index = 1)]
+    pub telemetry: bool,
+}
+
+impl PrivacySetting {
+    pub fn check(&self) -> Result<(), ErrorCode> { Ok(()) }
+}
+
+#[derive(ProtoBuf, Default, Debug, PartialEq, Clone)]
+pub struct AppSetting {
+    #[pb(index = 1)]
+    pub appearance: AppearanceSetting,
+
+    #[pb(index = 2)]
+    pub editor: EditorSetting,
+
+    #[pb(index = 3)]
+    pub sync: SyncSetting,
+
+    #[pb(index = 4)]
+    pub shortcuts: ShortcutSetting,
+
+    #[pb(index = 5)]
+    pub privacy: PrivacySetting,
+}
+
+// The sections that aren't set are kept.
+#[derive(ProtoBuf, Default)]
+pub struct UpdateSettingRequest {
+    #[pb(index = 1, one_of)]
+    pub appearance: Option<AppearanceSetting>,
+
+    #[pb(index = 2, one_of)]
+    pub editor: Option<EditorSetting>,
+
+    #[pb(index = 3, one_of)]
+    pub sync: Option<SyncSetting>,
+
+    #[pb(index = 4, one_of)]
+    pub shortcuts: Option<ShortcutSetting>,
+
+    #[pb(index = 5, one_of)]
+    pub privacy: Option<PrivacySetting>,
+}
+
+#[derive(Default, Debug, Clone, FromPayload)]
+#[payload(from = "UpdateSettingRequest")]
+pub struct UpdateSettingParams {
+    pub appearance: Option<AppearanceSetting>,
+    pub editor: Option<EditorSetting>,
+    pub sync: Option<SyncSetting>,
+    pub shortcuts: Option<ShortcutSetting>,
+    pub privacy: Option<PrivacySetting>,
+}
+
+impl TryInto<UpdateSettingParams> for UpdateSettingRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateSettingParams, Self::Error> {
+        if self.appearance.is_none()
+            && self.editor.is_none()
+            && self.sync.is_none()
+            && self.shortcuts.is_none()
+            && self.privacy.is_none()
+        {
+            return Err(ErrorCode::SettingIsEmpty);
+        }
+
+        if let Some(appearance) = &self.appearance {
+            let _ = appearance.check()?;
+        }
+        if let Some(editor) = &self.editor {
+            let _ = editor.check()?;
+        }
+        if let Some(sync) = &self.sync {
+            let _ = sync.check()?;
+        }
+        if let Some(shortcuts) = &self.shortcuts {
+            let _ = shortcuts.check()?;
+        }
+
+        Ok(UpdateSettingParams {
+            appearance: self.appearance,
+            editor: self.editor,
+            sync: self.sync,
+            shortcuts: self.shortcuts,
+            privacy: self.privacy,
+        })
+    }
+}
+
+// Sets the section back to its defaults.
+#[derive(ProtoBuf, Default)]
+pub struct ResetSettingRequest {
+    #[pb(index = 1)]
+    pub section: SettingSection,
+}
+
+// The payload of the SettingChanged notifications, whose id is the name of the
+// section, and of the stream of the setting changes.
+#[derive(ProtoBuf, Default, Debug, PartialEq, Clone)]
+pub struct SettingChange {
+    #[pb(index = 1)]
+    pub section: SettingSection,
+
+    #[pb(index = 2)]
+    pub setting: AppSetting,
+}
@@ -1,9 +1,9 @@
-use flowy_derive::ProtoBuf;
+use flowy_derive::{FromPayload, ProtoBuf};
 use std::convert::TryInto;
 
 use crate::{
     errors::ErrorCode,
-    parser::{UserEmail, UserId, UserName, UserPassword},
+    parser::{UserAvatar, UserEmail, UserId, UserName, UserPassword},
 };
 
 #[derive(Default, ProtoBuf)]
@@ -25,6 +25,12 @@ pub struct UserProfile {
 
     #[pb(index = 4)]
     pub token: String,
+
+    #[pb(index = 5)]
+    pub avatar: String,
+
+    #[pb(index = 6)]
+    pub email_verified: bool,
 }
 
 #[derive(ProtoBuf, Default)]
@@ -40,6 +46,9 @@ pub struct UpdateUserRequest {
 
     #[pb(index = 4, one_of)]
     pub password: Option<String>,
+
+    #[pb(index = 5, one_of)]
+    pub avatar: Option<String>,
 }
 
 impl UpdateUserRequest {
@@ -64,9 +73,15 @@ impl UpdateUserRequest {
         self.password = Some(password.to_owned());
         self
     }
+
+    pub fn avatar(mut self, avatar: &str) -> Self {
+        self.avatar = Some(avatar.to_owned());
+        self
+    }
 }
 
-#[derive(ProtoBuf, Default, Clone, Debug)]
+#[derive(ProtoBuf, Default, Clone, Debug, FromPayload)]
+#[payload(from = "UpdateUserRequest")]
 pub struct UpdateUserParams {
     // TODO: remove user id
     #[pb(index = 1)]
@@ -80,6 +95,9 @@ pub struct UpdateUserParams {
 
     #[pb(index = 4, one_of)]
     pub password: Option<String>,
+
+    #[pb(index = 5, one_of)]
+    pub avatar: Option<String>,
 }
 
 impl UpdateUserParams {
@@ -104,6 +122,30 @@ impl UpdateUserParams {
         self.password = Some(password.to_owned());
         self
     }
+
+    pub fn avatar(mut self, avatar: &str) -> Self {
+        self.avatar = Some(avatar.to_owned());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none() && self.email.is_none() && self.password.is_none() && self.avatar.is_none()
+    }
+
+    // Drops the fields that are the same as the profile's. The password is kept,
+    // only its hash is known.
+    pub fn without_unchanged(mut self, profile: &UserProfile) -> Self {
+        if self.name.as_ref() == Some(&profile.name) {
+            self.name = None;
+        }
+        if self.email.as_ref() == Some(&profile.email) {
+            self.email = None;
+        }
+        if self.avatar.as_ref() == Some(&profile.avatar) {
+            self.avatar = None;
+        }
+        self
+    }
 }
 
 impl TryInto<UpdateUserParams> for UpdateUserRequest {
@@ -127,11 +169,92 @@ impl TryInto<UpdateUserParams> for UpdateUserRequest {
             Some(password) => Some(UserPassword::parse(password)?.0),
         };
 
+        let avatar = match self.avatar {
+            None => None,
+            Some(avatar) => Some(UserAvatar::parse(avatar)?.0),
+        };
+
         Ok(UpdateUserParams {
             id,
             name,
             email,
             password,
+            avatar,
         })
     }
 }
+
+// Updates the profile of the current user, so the id isn't needed.
+#[derive(ProtoBuf, Default)]
+pub struct UpdateUserProfileRequest {
+    #[pb(index = 1, one_of)]
+    pub name: Option<String>,
+
+    #[pb(index = 2, one_of)]
+    pub avatar: Option<String>,
+
+    #[pb(index = 3, one_of)]
+    pub email: Option<String>,
+}
+
+impl UpdateUserProfileRequest {
+    pub fn new() -> Self { Self::default() }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_owned());
+        self
+    }
+
+    pub fn avatar(mut self, avatar: &str) -> Self {
+        self.avatar = Some(avatar.to_owned());
+        self
+    }
+
+    pub fn email(mut self, email: &str) -> Self {
+        self.email = Some(email.to_owned());
+        self
+    }
+}
+
+#[derive(Default, Debug, Clone, FromPayload)]
+#[payload(from = "UpdateUserProfileRequest")]
+pub struct UpdateUserProfileParams {
+    pub name: Option<String>,
+    pub avatar: Option<String>,
+    pub email: Option<String>,
+}
+
+impl UpdateUserProfileParams {
+    pub fn into_update_user_params(self, user_id: &str) -> UpdateUserParams {
+        UpdateUserParams {
+            id: user_id.to_owned(),
+            name: self.name,
+            email: self.email,
+            password: None,
+            avatar: self.avatar,
+        }
+    }
+}
+
+impl TryInto<UpdateUserProfileParams> for UpdateUserProfileRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateUserProfileParams, Self::Error> {
+        let name = match self.name {
+            None => None,
+            Some(name) => Some(UserName::parse(name)?.0),
+        };
+
+        let avatar = match self.avatar {
+            None => None,
+            Some(avatar) => Some(UserAvatar::parse(avatar)?.0),
+        };
+
+        let email = match self.email {
+            None => None,
+            Some(email) => Some(UserEmail::parse(email)?.0),
+        };
+
+        Ok(UpdateUserProfileParams { name, avatar, email })
+    }
+}
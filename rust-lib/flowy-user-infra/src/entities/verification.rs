@@ -0,0 +1,47 @@
+use crate::errors::ErrorCode;
+use flowy_derive::{FromPayload, ProtoBuf};
+use std::convert::TryInto;
+
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct EmailVerification {
+    #[pb(index = 1)]
+    pub email: String,
+
+    #[pb(index = 2)]
+    pub is_verified: bool,
+
+    // The status is read from this device if the server can't be reached, it's updated
+    // the next time the server is reachable.
+    #[pb(index = 3)]
+    pub is_local: bool,
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct ConfirmEmailRequest {
+    #[pb(index = 1)]
+    pub code: String,
+}
+
+impl ConfirmEmailRequest {
+    pub fn new(code: &str) -> Self { Self { code: code.to_owned() } }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone, FromPayload)]
+#[payload(from = "ConfirmEmailRequest")]
+pub struct ConfirmEmailParams {
+    #[pb(index = 1)]
+    pub code: String,
+}
+
+impl TryInto<ConfirmEmailParams> for ConfirmEmailRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ConfirmEmailParams, Self::Error> {
+        let code = self.code.trim();
+        if code.is_empty() {
+            return Err(ErrorCode::VerificationCodeIsEmpty);
+        }
+
+        Ok(ConfirmEmailParams { code: code.to_owned() })
+    }
+}
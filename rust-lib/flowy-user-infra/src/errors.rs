@@ -1,6 +1,7 @@
 use crate::protobuf::ErrorCode as ProtoBufErrorCode;
 use derive_more::Display;
 use flowy_derive::ProtoBuf_Enum;
+use flowy_i18n::MessageKey;
 use protobuf::ProtobufEnum;
 use std::convert::{TryFrom, TryInto};
 
@@ -22,6 +23,16 @@ pub enum ErrorCode {
     PasswordFormatInvalid = 13,
     #[display(fmt = "Password not match")]
     PasswordNotMatch   = 14,
+    #[display(fmt = "Password should contain a minimum of 6 characters")]
+    PasswordTooShort   = 15,
+    #[display(fmt = "Password should contain at least one number")]
+    PasswordMissingDigit = 16,
+    #[display(fmt = "Password should contain at least one lowercase letter")]
+    PasswordMissingLowercase = 17,
+    #[display(fmt = "Password should contain at least one uppercase letter")]
+    PasswordMissingUppercase = 18,
+    #[display(fmt = "Password should contain at least one special character")]
+    PasswordMissingSpecialCharacter = 19,
     #[display(fmt = "User name is too long")]
     UserNameTooLong    = 20,
     #[display(fmt = "User name contain forbidden characters")]
@@ -34,6 +45,64 @@ pub enum ErrorCode {
     UserUnauthorized   = 24,
     #[display(fmt = "User not exist")]
     UserNotExist       = 25,
+    #[display(fmt = "User session is expired")]
+    SessionExpired     = 26,
+    #[display(fmt = "New password can not be the same as the old one")]
+    PasswordNotChanged = 27,
+    #[display(fmt = "OAuth provider is not supported")]
+    OAuthProviderNotSupported = 28,
+    #[display(fmt = "OAuth state is invalid or expired")]
+    OAuthStateInvalid  = 29,
+    #[display(fmt = "OAuth authorization code can not be empty")]
+    OAuthCodeIsEmpty   = 30,
+    #[display(fmt = "Current user is not anonymous")]
+    NotAnonymousUser   = 31,
+    #[display(fmt = "User avatar is invalid")]
+    UserAvatarInvalid  = 32,
+    #[display(fmt = "Preference key can not be empty")]
+    PreferenceKeyIsEmpty = 33,
+    #[display(fmt = "Preference value must have exactly one type")]
+    PreferenceValueInvalid = 34,
+    #[display(fmt = "Preference not exist")]
+    PreferenceNotExist = 35,
+    #[display(fmt = "Preference type is different from the stored one")]
+    PreferenceTypeMismatch = 36,
+    #[display(fmt = "Account deletion is not confirmed")]
+    DeleteAccountNotConfirmed = 37,
+    #[display(fmt = "Request signature is invalid or expired")]
+    SignatureInvalid   = 38,
+    #[display(fmt = "Session was revoked, sign in again")]
+    SessionRevoked     = 39,
+    #[display(fmt = "Session id can not be empty or whitespace")]
+    SessionIdIsEmpty   = 40,
+    #[display(fmt = "Session does not exist")]
+    SessionNotExist    = 41,
+    #[display(fmt = "Verification code can not be empty")]
+    VerificationCodeIsEmpty = 42,
+    #[display(fmt = "Verification code is invalid or expired")]
+    VerificationCodeInvalid = 43,
+    #[display(fmt = "Email address is not verified")]
+    EmailNotVerified   = 44,
+    #[display(fmt = "Too many sign in attempts, try again later")]
+    TooManyAttempts    = 45,
+    #[display(fmt = "The database of the user can't be migrated")]
+    DatabaseMigrationFailed = 46,
+    #[display(fmt = "The database can't be encrypted, SQLCipher isn't available")]
+    DatabaseEncryptionUnsupported = 47,
+    #[display(fmt = "The database of the user can't be encrypted")]
+    DatabaseEncryptionFailed = 48,
+    #[display(fmt = "The backup doesn't exist")]
+    BackupNotExist     = 49,
+    #[display(fmt = "The backup is corrupted and can't be restored")]
+    BackupCorrupted    = 50,
+    #[display(fmt = "The update must set at least one section of the setting")]
+    SettingIsEmpty     = 51,
+    #[display(fmt = "The setting has a value out of its range")]
+    SettingValueInvalid = 52,
+    #[display(fmt = "The shortcut must have an action and keys")]
+    ShortcutInvalid    = 53,
+    #[display(fmt = "The action or the keys of the shortcut are already used")]
+    ShortcutConflict   = 54,
 
     #[display(fmt = "Server error")]
     ServerError        = 99,
@@ -56,6 +125,13 @@ impl ErrorCode {
     }
 }
 
+// The keys are error.user.<code>, e.g. error.user.EmailIsEmpty.
+impl MessageKey for ErrorCode {
+    fn message_key(&self) -> String { format!("error.user.{:?}", self) }
+
+    fn default_message(&self) -> String { self.to_string() }
+}
+
 impl std::default::Default for ErrorCode {
     fn default() -> Self { ErrorCode::InternalError }
 }
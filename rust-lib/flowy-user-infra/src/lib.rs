@@ -2,4 +2,5 @@ pub mod entities;
 pub mod errors;
 pub mod parser;
 pub mod protobuf;
+pub mod signature;
 pub mod user_default;
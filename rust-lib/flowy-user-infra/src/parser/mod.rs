@@ -1,10 +1,12 @@
 // https://lexi-lambda.github.io/blog/2019/11/05/parse-don-t-validate/
+mod user_avatar;
 mod user_email;
 mod user_id;
 mod user_name;
 mod user_password;
 mod user_workspace;
 
+pub use user_avatar::*;
 pub use user_email::*;
 pub use user_id::*;
 pub use user_name::*;
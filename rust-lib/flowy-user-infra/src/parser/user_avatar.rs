@@ -0,0 +1,56 @@
+use crate::errors::ErrorCode;
+use unicode_segmentation::UnicodeSegmentation;
+
+const AVATAR_MAX_LEN: usize = 2048;
+
+// The avatar is the url of the image. An empty avatar means the user doesn't have one,
+// so it can be used to remove the avatar.
+#[derive(Debug)]
+pub struct UserAvatar(pub String);
+
+impl UserAvatar {
+    pub fn parse(s: String) -> Result<UserAvatar, ErrorCode> {
+        let s = s.trim().to_owned();
+        if s.is_empty() {
+            return Ok(Self(s));
+        }
+
+        if s.graphemes(true).count() > AVATAR_MAX_LEN {
+            return Err(ErrorCode::UserAvatarInvalid);
+        }
+
+        let is_url = ["http://", "https://", "file://"].iter().any(|scheme| s.starts_with(scheme));
+        if !is_url || s.chars().any(|c| c.is_whitespace()) {
+            return Err(ErrorCode::UserAvatarInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for UserAvatar {
+    fn as_ref(&self) -> &str { &self.0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn empty_avatar_is_valid() {
+        assert_eq!(UserAvatar::parse("  ".to_owned()).unwrap().0, "");
+    }
+
+    #[test]
+    fn avatar_url_is_valid() {
+        assert_ok!(UserAvatar::parse("https://appflowy.io/avatar.png".to_owned()));
+        assert_ok!(UserAvatar::parse("file:///tmp/avatar.png".to_owned()));
+    }
+
+    #[test]
+    fn avatar_that_is_not_a_url_is_rejected() {
+        assert_err!(UserAvatar::parse("avatar.png".to_owned()));
+        assert_err!(UserAvatar::parse("https://appflowy.io/my avatar.png".to_owned()));
+    }
+}
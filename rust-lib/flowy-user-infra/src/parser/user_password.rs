@@ -3,6 +3,10 @@ use fancy_regex::Regex;
 use lazy_static::lazy_static;
 use unicode_segmentation::UnicodeSegmentation;
 
+pub const PASSWORD_MIN_LEN: usize = 6;
+pub const PASSWORD_MAX_LEN: usize = 100;
+const FORBIDDEN_CHARACTERS: [char; 9] = ['/', '(', ')', '"', '<', '>', '\\', '{', '}'];
+
 #[derive(Debug)]
 pub struct UserPassword(pub String);
 
@@ -12,12 +16,16 @@ impl UserPassword {
             return Err(ErrorCode::PasswordIsEmpty);
         }
 
-        if s.graphemes(true).count() > 100 {
+        let len = s.graphemes(true).count();
+        if len < PASSWORD_MIN_LEN {
+            return Err(ErrorCode::PasswordTooShort);
+        }
+
+        if len > PASSWORD_MAX_LEN {
             return Err(ErrorCode::PasswordTooLong);
         }
 
-        let forbidden_characters = ['/', '(', ')', '"', '<', '>', '\\', '{', '}'];
-        let contains_forbidden_characters = s.chars().any(|g| forbidden_characters.contains(&g));
+        let contains_forbidden_characters = s.chars().any(|g| FORBIDDEN_CHARACTERS.contains(&g));
         if contains_forbidden_characters {
             return Err(ErrorCode::PasswordContainsForbidCharacters);
         }
@@ -34,6 +42,71 @@ impl AsRef<str> for UserPassword {
     fn as_ref(&self) -> &str { &self.0 }
 }
 
+// The rules a new password must follow when it gets changed. Unlike UserPassword::parse,
+// every rule reports its own error code, so the frontend can tell the user exactly which
+// rule was broken.
+#[derive(Debug, Clone)]
+pub struct PasswordPolicy {
+    pub min_len: usize,
+    pub max_len: usize,
+    pub require_digit: bool,
+    pub require_lowercase: bool,
+    pub require_uppercase: bool,
+    pub require_special_character: bool,
+}
+
+impl std::default::Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_len: PASSWORD_MIN_LEN,
+            max_len: PASSWORD_MAX_LEN,
+            require_digit: true,
+            require_lowercase: true,
+            require_uppercase: true,
+            require_special_character: true,
+        }
+    }
+}
+
+impl PasswordPolicy {
+    pub fn validate(&self, s: &str) -> Result<(), ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::PasswordIsEmpty);
+        }
+
+        let len = s.graphemes(true).count();
+        if len < self.min_len {
+            return Err(ErrorCode::PasswordTooShort);
+        }
+
+        if len > self.max_len {
+            return Err(ErrorCode::PasswordTooLong);
+        }
+
+        if s.chars().any(|c| FORBIDDEN_CHARACTERS.contains(&c)) {
+            return Err(ErrorCode::PasswordContainsForbidCharacters);
+        }
+
+        if self.require_digit && !s.chars().any(|c| c.is_ascii_digit()) {
+            return Err(ErrorCode::PasswordMissingDigit);
+        }
+
+        if self.require_lowercase && !s.chars().any(|c| c.is_lowercase()) {
+            return Err(ErrorCode::PasswordMissingLowercase);
+        }
+
+        if self.require_uppercase && !s.chars().any(|c| c.is_uppercase()) {
+            return Err(ErrorCode::PasswordMissingUppercase);
+        }
+
+        if self.require_special_character && !s.chars().any(|c| !c.is_alphanumeric() && !c.is_whitespace()) {
+            return Err(ErrorCode::PasswordMissingSpecialCharacter);
+        }
+
+        Ok(())
+    }
+}
+
 lazy_static! {
     // Test it in https://regex101.com/
     // https://stackoverflow.com/questions/2370015/regular-expression-for-password-validation/2370045
@@ -60,3 +133,67 @@ pub fn validate_password(password: &str) -> bool {
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn empty_password_is_rejected() {
+        assert_eq!(UserPassword::parse("  ".to_owned()).unwrap_err(), ErrorCode::PasswordIsEmpty);
+    }
+
+    #[test]
+    fn short_password_is_rejected() {
+        assert_eq!(UserPassword::parse("He1!".to_owned()).unwrap_err(), ErrorCode::PasswordTooShort);
+    }
+
+    #[test]
+    fn long_password_is_rejected() {
+        let password = format!("Hello1!{}", "a".repeat(PASSWORD_MAX_LEN));
+        assert_eq!(UserPassword::parse(password).unwrap_err(), ErrorCode::PasswordTooLong);
+    }
+
+    #[test]
+    fn password_with_forbidden_characters_is_rejected() {
+        assert_eq!(
+            UserPassword::parse("Hello1!<>".to_owned()).unwrap_err(),
+            ErrorCode::PasswordContainsForbidCharacters
+        );
+    }
+
+    #[test]
+    fn password_without_number_or_upper_case_is_rejected() {
+        assert_err!(UserPassword::parse("Hello!!".to_owned()));
+        assert_err!(UserPassword::parse("hello12!".to_owned()));
+    }
+
+    #[test]
+    fn valid_password_is_parsed_successfully() { assert_ok!(UserPassword::parse("HelloWorld!123".to_owned())); }
+
+    #[test]
+    fn policy_reports_each_failed_rule() {
+        let policy = PasswordPolicy::default();
+        assert_eq!(policy.validate("Hello!!").unwrap_err(), ErrorCode::PasswordMissingDigit);
+        assert_eq!(policy.validate("HELLO12!").unwrap_err(), ErrorCode::PasswordMissingLowercase);
+        assert_eq!(policy.validate("hello12!").unwrap_err(), ErrorCode::PasswordMissingUppercase);
+        assert_eq!(
+            policy.validate("Hello123").unwrap_err(),
+            ErrorCode::PasswordMissingSpecialCharacter
+        );
+        assert_ok!(policy.validate("HelloWorld!123"));
+    }
+
+    #[test]
+    fn policy_rules_are_configurable() {
+        let policy = PasswordPolicy {
+            min_len: 10,
+            require_uppercase: false,
+            require_special_character: false,
+            ..Default::default()
+        };
+        assert_eq!(policy.validate("hello123").unwrap_err(), ErrorCode::PasswordTooShort);
+        assert_ok!(policy.validate("helloworld123"));
+    }
+}
@@ -0,0 +1,863 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `audit.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AuditRecord {
+    // message fields
+    pub user_id: ::std::string::String,
+    pub event: ::std::string::String,
+    pub object_id: ::std::string::String,
+    pub create_time: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AuditRecord {
+    fn default() -> &'a AuditRecord {
+        <AuditRecord as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AuditRecord {
+    pub fn new() -> AuditRecord {
+        ::std::default::Default::default()
+    }
+
+    // string user_id = 1;
+
+
+    pub fn get_user_id(&self) -> &str {
+        &self.user_id
+    }
+    pub fn clear_user_id(&mut self) {
+        self.user_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: ::std::string::String) {
+        self.user_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_user_id(&mut self) -> &mut ::std::string::String {
+        &mut self.user_id
+    }
+
+    // Take field
+    pub fn take_user_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.user_id, ::std::string::String::new())
+    }
+
+    // string event = 2;
+
+
+    pub fn get_event(&self) -> &str {
+        &self.event
+    }
+    pub fn clear_event(&mut self) {
+        self.event.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_event(&mut self, v: ::std::string::String) {
+        self.event = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_event(&mut self) -> &mut ::std::string::String {
+        &mut self.event
+    }
+
+    // Take field
+    pub fn take_event(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.event, ::std::string::String::new())
+    }
+
+    // string object_id = 3;
+
+
+    pub fn get_object_id(&self) -> &str {
+        &self.object_id
+    }
+    pub fn clear_object_id(&mut self) {
+        self.object_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object_id(&mut self, v: ::std::string::String) {
+        self.object_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_object_id(&mut self) -> &mut ::std::string::String {
+        &mut self.object_id
+    }
+
+    // Take field
+    pub fn take_object_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.object_id, ::std::string::String::new())
+    }
+
+    // int64 create_time = 4;
+
+
+    pub fn get_create_time(&self) -> i64 {
+        self.create_time
+    }
+    pub fn clear_create_time(&mut self) {
+        self.create_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_create_time(&mut self, v: i64) {
+        self.create_time = v;
+    }
+}
+
+impl ::protobuf::Message for AuditRecord {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.user_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.event)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.object_id)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.create_time = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.user_id);
+        }
+        if !self.event.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.event);
+        }
+        if !self.object_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.object_id);
+        }
+        if self.create_time != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.create_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.user_id.is_empty() {
+            os.write_string(1, &self.user_id)?;
+        }
+        if !self.event.is_empty() {
+            os.write_string(2, &self.event)?;
+        }
+        if !self.object_id.is_empty() {
+            os.write_string(3, &self.object_id)?;
+        }
+        if self.create_time != 0 {
+            os.write_int64(4, self.create_time)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AuditRecord {
+        AuditRecord::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "user_id",
+                |m: &AuditRecord| { &m.user_id },
+                |m: &mut AuditRecord| { &mut m.user_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "event",
+                |m: &AuditRecord| { &m.event },
+                |m: &mut AuditRecord| { &mut m.event },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "object_id",
+                |m: &AuditRecord| { &m.object_id },
+                |m: &mut AuditRecord| { &mut m.object_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "create_time",
+                |m: &AuditRecord| { &m.create_time },
+                |m: &mut AuditRecord| { &mut m.create_time },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AuditRecord>(
+                "AuditRecord",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AuditRecord {
+        static instance: ::protobuf::rt::LazyV2<AuditRecord> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AuditRecord::new)
+    }
+}
+
+impl ::protobuf::Clear for AuditRecord {
+    fn clear(&mut self) {
+        self.user_id.clear();
+        self.event.clear();
+        self.object_id.clear();
+        self.create_time = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AuditRecord {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AuditRecord {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedAuditRecord {
+    // message fields
+    pub items: ::protobuf::RepeatedField<AuditRecord>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedAuditRecord {
+    fn default() -> &'a RepeatedAuditRecord {
+        <RepeatedAuditRecord as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedAuditRecord {
+    pub fn new() -> RepeatedAuditRecord {
+        ::std::default::Default::default()
+    }
+
+    // repeated .AuditRecord items = 1;
+
+
+    pub fn get_items(&self) -> &[AuditRecord] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<AuditRecord>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<AuditRecord> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<AuditRecord> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedAuditRecord {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedAuditRecord {
+        RepeatedAuditRecord::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AuditRecord>>(
+                "items",
+                |m: &RepeatedAuditRecord| { &m.items },
+                |m: &mut RepeatedAuditRecord| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedAuditRecord>(
+                "RepeatedAuditRecord",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedAuditRecord {
+        static instance: ::protobuf::rt::LazyV2<RepeatedAuditRecord> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedAuditRecord::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedAuditRecord {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedAuditRecord {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedAuditRecord {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ReadAuditLogRequest {
+    // message oneof groups
+    pub one_of_object_id: ::std::option::Option<ReadAuditLogRequest_oneof_one_of_object_id>,
+    pub one_of_from_time: ::std::option::Option<ReadAuditLogRequest_oneof_one_of_from_time>,
+    pub one_of_to_time: ::std::option::Option<ReadAuditLogRequest_oneof_one_of_to_time>,
+    pub one_of_limit: ::std::option::Option<ReadAuditLogRequest_oneof_one_of_limit>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ReadAuditLogRequest {
+    fn default() -> &'a ReadAuditLogRequest {
+        <ReadAuditLogRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ReadAuditLogRequest_oneof_one_of_object_id {
+    object_id(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ReadAuditLogRequest_oneof_one_of_from_time {
+    from_time(i64),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ReadAuditLogRequest_oneof_one_of_to_time {
+    to_time(i64),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ReadAuditLogRequest_oneof_one_of_limit {
+    limit(i64),
+}
+
+impl ReadAuditLogRequest {
+    pub fn new() -> ReadAuditLogRequest {
+        ::std::default::Default::default()
+    }
+
+    // string object_id = 1;
+
+
+    pub fn get_object_id(&self) -> &str {
+        match self.one_of_object_id {
+            ::std::option::Option::Some(ReadAuditLogRequest_oneof_one_of_object_id::object_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_object_id(&mut self) {
+        self.one_of_object_id = ::std::option::Option::None;
+    }
+
+    pub fn has_object_id(&self) -> bool {
+        match self.one_of_object_id {
+            ::std::option::Option::Some(ReadAuditLogRequest_oneof_one_of_object_id::object_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object_id(&mut self, v: ::std::string::String) {
+        self.one_of_object_id = ::std::option::Option::Some(ReadAuditLogRequest_oneof_one_of_object_id::object_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_object_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ReadAuditLogRequest_oneof_one_of_object_id::object_id(_)) = self.one_of_object_id {
+        } else {
+            self.one_of_object_id = ::std::option::Option::Some(ReadAuditLogRequest_oneof_one_of_object_id::object_id(::std::string::String::new()));
+        }
+        match self.one_of_object_id {
+            ::std::option::Option::Some(ReadAuditLogRequest_oneof_one_of_object_id::object_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_object_id(&mut self) -> ::std::string::String {
+        if self.has_object_id() {
+            match self.one_of_object_id.take() {
+                ::std::option::Option::Some(ReadAuditLogRequest_oneof_one_of_object_id::object_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // int64 from_time = 2;
+
+
+    pub fn get_from_time(&self) -> i64 {
+        match self.one_of_from_time {
+            ::std::option::Option::Some(ReadAuditLogRequest_oneof_one_of_from_time::from_time(v)) => v,
+            _ => 0,
+        }
+    }
+    pub fn clear_from_time(&mut self) {
+        self.one_of_from_time = ::std::option::Option::None;
+    }
+
+    pub fn has_from_time(&self) -> bool {
+        match self.one_of_from_time {
+            ::std::option::Option::Some(ReadAuditLogRequest_oneof_one_of_from_time::from_time(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_from_time(&mut self, v: i64) {
+        self.one_of_from_time = ::std::option::Option::Some(ReadAuditLogRequest_oneof_one_of_from_time::from_time(v))
+    }
+
+    // int64 to_time = 3;
+
+
+    pub fn get_to_time(&self) -> i64 {
+        match self.one_of_to_time {
+            ::std::option::Option::Some(ReadAuditLogRequest_oneof_one_of_to_time::to_time(v)) => v,
+            _ => 0,
+        }
+    }
+    pub fn clear_to_time(&mut self) {
+        self.one_of_to_time = ::std::option::Option::None;
+    }
+
+    pub fn has_to_time(&self) -> bool {
+        match self.one_of_to_time {
+            ::std::option::Option::Some(ReadAuditLogRequest_oneof_one_of_to_time::to_time(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_to_time(&mut self, v: i64) {
+        self.one_of_to_time = ::std::option::Option::Some(ReadAuditLogRequest_oneof_one_of_to_time::to_time(v))
+    }
+
+    // int64 limit = 4;
+
+
+    pub fn get_limit(&self) -> i64 {
+        match self.one_of_limit {
+            ::std::option::Option::Some(ReadAuditLogRequest_oneof_one_of_limit::limit(v)) => v,
+            _ => 0,
+        }
+    }
+    pub fn clear_limit(&mut self) {
+        self.one_of_limit = ::std::option::Option::None;
+    }
+
+    pub fn has_limit(&self) -> bool {
+        match self.one_of_limit {
+            ::std::option::Option::Some(ReadAuditLogRequest_oneof_one_of_limit::limit(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: i64) {
+        self.one_of_limit = ::std::option::Option::Some(ReadAuditLogRequest_oneof_one_of_limit::limit(v))
+    }
+}
+
+impl ::protobuf::Message for ReadAuditLogRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_object_id = ::std::option::Option::Some(ReadAuditLogRequest_oneof_one_of_object_id::object_id(is.read_string()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_from_time = ::std::option::Option::Some(ReadAuditLogRequest_oneof_one_of_from_time::from_time(is.read_int64()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_to_time = ::std::option::Option::Some(ReadAuditLogRequest_oneof_one_of_to_time::to_time(is.read_int64()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_limit = ::std::option::Option::Some(ReadAuditLogRequest_oneof_one_of_limit::limit(is.read_int64()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.one_of_object_id {
+            match v {
+                &ReadAuditLogRequest_oneof_one_of_object_id::object_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(1, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_from_time {
+            match v {
+                &ReadAuditLogRequest_oneof_one_of_from_time::from_time(v) => {
+                    my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_to_time {
+            match v {
+                &ReadAuditLogRequest_oneof_one_of_to_time::to_time(v) => {
+                    my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_limit {
+            match v {
+                &ReadAuditLogRequest_oneof_one_of_limit::limit(v) => {
+                    my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.one_of_object_id {
+            match v {
+                &ReadAuditLogRequest_oneof_one_of_object_id::object_id(ref v) => {
+                    os.write_string(1, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_from_time {
+            match v {
+                &ReadAuditLogRequest_oneof_one_of_from_time::from_time(v) => {
+                    os.write_int64(2, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_to_time {
+            match v {
+                &ReadAuditLogRequest_oneof_one_of_to_time::to_time(v) => {
+                    os.write_int64(3, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_limit {
+            match v {
+                &ReadAuditLogRequest_oneof_one_of_limit::limit(v) => {
+                    os.write_int64(4, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ReadAuditLogRequest {
+        ReadAuditLogRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "object_id",
+                ReadAuditLogRequest::has_object_id,
+                ReadAuditLogRequest::get_object_id,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_i64_accessor::<_>(
+                "from_time",
+                ReadAuditLogRequest::has_from_time,
+                ReadAuditLogRequest::get_from_time,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_i64_accessor::<_>(
+                "to_time",
+                ReadAuditLogRequest::has_to_time,
+                ReadAuditLogRequest::get_to_time,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_i64_accessor::<_>(
+                "limit",
+                ReadAuditLogRequest::has_limit,
+                ReadAuditLogRequest::get_limit,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ReadAuditLogRequest>(
+                "ReadAuditLogRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ReadAuditLogRequest {
+        static instance: ::protobuf::rt::LazyV2<ReadAuditLogRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ReadAuditLogRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ReadAuditLogRequest {
+    fn clear(&mut self) {
+        self.one_of_object_id = ::std::option::Option::None;
+        self.one_of_from_time = ::std::option::Option::None;
+        self.one_of_to_time = ::std::option::Option::None;
+        self.one_of_limit = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ReadAuditLogRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReadAuditLogRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0baudit.proto\"\x84\x01\n\x0bAuditRecord\x12\x19\n\x07user_id\x18\
+    \x01\x20\x01(\tR\x06userIdB\0\x12\x16\n\x05event\x18\x02\x20\x01(\tR\x05\
+    eventB\0\x12\x1d\n\tobject_id\x18\x03\x20\x01(\tR\x08objectIdB\0\x12!\n\
+    \x0bcreate_time\x18\x04\x20\x01(\x03R\ncreateTimeB\0:\0\"=\n\x13Repeated\
+    AuditRecord\x12$\n\x05items\x18\x01\x20\x03(\x0b2\x0c.AuditRecordR\x05it\
+    emsB\0:\0\"\xda\x01\n\x13ReadAuditLogRequest\x12\x1f\n\tobject_id\x18\
+    \x01\x20\x01(\tH\0R\x08objectIdB\0\x12\x1f\n\tfrom_time\x18\x02\x20\x01(\
+    \x03H\x01R\x08fromTimeB\0\x12\x1b\n\x07to_time\x18\x03\x20\x01(\x03H\x02\
+    R\x06toTimeB\0\x12\x18\n\x05limit\x18\x04\x20\x01(\x03H\x03R\x05limitB\0\
+    B\x12\n\x10one_of_object_idB\x12\n\x10one_of_from_timeB\x10\n\x0eone_of_\
+    to_timeB\x0e\n\x0cone_of_limit:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
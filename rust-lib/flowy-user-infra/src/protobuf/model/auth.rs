@@ -272,6 +272,8 @@ pub struct SignInParams {
     pub email: ::std::string::String,
     pub password: ::std::string::String,
     pub name: ::std::string::String,
+    pub device_id: ::std::string::String,
+    pub device_name: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -365,6 +367,58 @@ impl SignInParams {
     pub fn take_name(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.name, ::std::string::String::new())
     }
+
+    // string device_id = 4;
+
+
+    pub fn get_device_id(&self) -> &str {
+        &self.device_id
+    }
+    pub fn clear_device_id(&mut self) {
+        self.device_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_device_id(&mut self, v: ::std::string::String) {
+        self.device_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_device_id(&mut self) -> &mut ::std::string::String {
+        &mut self.device_id
+    }
+
+    // Take field
+    pub fn take_device_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.device_id, ::std::string::String::new())
+    }
+
+    // string device_name = 5;
+
+
+    pub fn get_device_name(&self) -> &str {
+        &self.device_name
+    }
+    pub fn clear_device_name(&mut self) {
+        self.device_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_device_name(&mut self, v: ::std::string::String) {
+        self.device_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_device_name(&mut self) -> &mut ::std::string::String {
+        &mut self.device_name
+    }
+
+    // Take field
+    pub fn take_device_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.device_name, ::std::string::String::new())
+    }
 }
 
 impl ::protobuf::Message for SignInParams {
@@ -385,6 +439,12 @@ impl ::protobuf::Message for SignInParams {
                 3 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
                 },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.device_id)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.device_name)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -406,6 +466,12 @@ impl ::protobuf::Message for SignInParams {
         if !self.name.is_empty() {
             my_size += ::protobuf::rt::string_size(3, &self.name);
         }
+        if !self.device_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.device_id);
+        }
+        if !self.device_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.device_name);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -421,6 +487,12 @@ impl ::protobuf::Message for SignInParams {
         if !self.name.is_empty() {
             os.write_string(3, &self.name)?;
         }
+        if !self.device_id.is_empty() {
+            os.write_string(4, &self.device_id)?;
+        }
+        if !self.device_name.is_empty() {
+            os.write_string(5, &self.device_name)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -474,6 +546,16 @@ impl ::protobuf::Message for SignInParams {
                 |m: &SignInParams| { &m.name },
                 |m: &mut SignInParams| { &mut m.name },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "device_id",
+                |m: &SignInParams| { &m.device_id },
+                |m: &mut SignInParams| { &mut m.device_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "device_name",
+                |m: &SignInParams| { &m.device_name },
+                |m: &mut SignInParams| { &mut m.device_name },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<SignInParams>(
                 "SignInParams",
                 fields,
@@ -493,6 +575,8 @@ impl ::protobuf::Clear for SignInParams {
         self.email.clear();
         self.password.clear();
         self.name.clear();
+        self.device_id.clear();
+        self.device_name.clear();
         self.unknown_fields.clear();
     }
 }
@@ -1043,6 +1127,8 @@ pub struct SignUpParams {
     pub email: ::std::string::String,
     pub name: ::std::string::String,
     pub password: ::std::string::String,
+    pub device_id: ::std::string::String,
+    pub device_name: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -1136,6 +1222,58 @@ impl SignUpParams {
     pub fn take_password(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.password, ::std::string::String::new())
     }
+
+    // string device_id = 4;
+
+
+    pub fn get_device_id(&self) -> &str {
+        &self.device_id
+    }
+    pub fn clear_device_id(&mut self) {
+        self.device_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_device_id(&mut self, v: ::std::string::String) {
+        self.device_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_device_id(&mut self) -> &mut ::std::string::String {
+        &mut self.device_id
+    }
+
+    // Take field
+    pub fn take_device_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.device_id, ::std::string::String::new())
+    }
+
+    // string device_name = 5;
+
+
+    pub fn get_device_name(&self) -> &str {
+        &self.device_name
+    }
+    pub fn clear_device_name(&mut self) {
+        self.device_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_device_name(&mut self, v: ::std::string::String) {
+        self.device_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_device_name(&mut self) -> &mut ::std::string::String {
+        &mut self.device_name
+    }
+
+    // Take field
+    pub fn take_device_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.device_name, ::std::string::String::new())
+    }
 }
 
 impl ::protobuf::Message for SignUpParams {
@@ -1156,6 +1294,12 @@ impl ::protobuf::Message for SignUpParams {
                 3 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.password)?;
                 },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.device_id)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.device_name)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1177,6 +1321,12 @@ impl ::protobuf::Message for SignUpParams {
         if !self.password.is_empty() {
             my_size += ::protobuf::rt::string_size(3, &self.password);
         }
+        if !self.device_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.device_id);
+        }
+        if !self.device_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.device_name);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1192,6 +1342,12 @@ impl ::protobuf::Message for SignUpParams {
         if !self.password.is_empty() {
             os.write_string(3, &self.password)?;
         }
+        if !self.device_id.is_empty() {
+            os.write_string(4, &self.device_id)?;
+        }
+        if !self.device_name.is_empty() {
+            os.write_string(5, &self.device_name)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1245,6 +1401,16 @@ impl ::protobuf::Message for SignUpParams {
                 |m: &SignUpParams| { &m.password },
                 |m: &mut SignUpParams| { &mut m.password },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "device_id",
+                |m: &SignUpParams| { &m.device_id },
+                |m: &mut SignUpParams| { &mut m.device_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "device_name",
+                |m: &SignUpParams| { &m.device_name },
+                |m: &mut SignUpParams| { &mut m.device_name },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<SignUpParams>(
                 "SignUpParams",
                 fields,
@@ -1264,6 +1430,8 @@ impl ::protobuf::Clear for SignUpParams {
         self.email.clear();
         self.name.clear();
         self.password.clear();
+        self.device_id.clear();
+        self.device_name.clear();
         self.unknown_fields.clear();
     }
 }
@@ -1565,87 +1733,755 @@ impl ::protobuf::reflect::ProtobufValue for SignUpResponse {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct RefreshTokenResponse {
+    // message fields
+    pub token: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RefreshTokenResponse {
+    fn default() -> &'a RefreshTokenResponse {
+        <RefreshTokenResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RefreshTokenResponse {
+    pub fn new() -> RefreshTokenResponse {
+        ::std::default::Default::default()
+    }
+
+    // string token = 1;
+
+
+    pub fn get_token(&self) -> &str {
+        &self.token
+    }
+    pub fn clear_token(&mut self) {
+        self.token.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_token(&mut self, v: ::std::string::String) {
+        self.token = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_token(&mut self) -> &mut ::std::string::String {
+        &mut self.token
+    }
+
+    // Take field
+    pub fn take_token(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.token, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RefreshTokenResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.token)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.token.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.token);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.token.is_empty() {
+            os.write_string(1, &self.token)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RefreshTokenResponse {
+        RefreshTokenResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "token",
+                |m: &RefreshTokenResponse| { &m.token },
+                |m: &mut RefreshTokenResponse| { &mut m.token },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RefreshTokenResponse>(
+                "RefreshTokenResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RefreshTokenResponse {
+        static instance: ::protobuf::rt::LazyV2<RefreshTokenResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RefreshTokenResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RefreshTokenResponse {
+    fn clear(&mut self) {
+        self.token.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RefreshTokenResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RefreshTokenResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdatePasswordRequest {
+    // message fields
+    pub old_password: ::std::string::String,
+    pub new_password: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdatePasswordRequest {
+    fn default() -> &'a UpdatePasswordRequest {
+        <UpdatePasswordRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UpdatePasswordRequest {
+    pub fn new() -> UpdatePasswordRequest {
+        ::std::default::Default::default()
+    }
+
+    // string old_password = 1;
+
+
+    pub fn get_old_password(&self) -> &str {
+        &self.old_password
+    }
+    pub fn clear_old_password(&mut self) {
+        self.old_password.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_old_password(&mut self, v: ::std::string::String) {
+        self.old_password = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_old_password(&mut self) -> &mut ::std::string::String {
+        &mut self.old_password
+    }
+
+    // Take field
+    pub fn take_old_password(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.old_password, ::std::string::String::new())
+    }
+
+    // string new_password = 2;
+
+
+    pub fn get_new_password(&self) -> &str {
+        &self.new_password
+    }
+    pub fn clear_new_password(&mut self) {
+        self.new_password.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_new_password(&mut self, v: ::std::string::String) {
+        self.new_password = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_new_password(&mut self) -> &mut ::std::string::String {
+        &mut self.new_password
+    }
+
+    // Take field
+    pub fn take_new_password(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.new_password, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for UpdatePasswordRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.old_password)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.new_password)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.old_password.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.old_password);
+        }
+        if !self.new_password.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.new_password);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.old_password.is_empty() {
+            os.write_string(1, &self.old_password)?;
+        }
+        if !self.new_password.is_empty() {
+            os.write_string(2, &self.new_password)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdatePasswordRequest {
+        UpdatePasswordRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "old_password",
+                |m: &UpdatePasswordRequest| { &m.old_password },
+                |m: &mut UpdatePasswordRequest| { &mut m.old_password },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "new_password",
+                |m: &UpdatePasswordRequest| { &m.new_password },
+                |m: &mut UpdatePasswordRequest| { &mut m.new_password },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdatePasswordRequest>(
+                "UpdatePasswordRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdatePasswordRequest {
+        static instance: ::protobuf::rt::LazyV2<UpdatePasswordRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdatePasswordRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdatePasswordRequest {
+    fn clear(&mut self) {
+        self.old_password.clear();
+        self.new_password.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdatePasswordRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdatePasswordRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdatePasswordParams {
+    // message fields
+    pub old_password: ::std::string::String,
+    pub new_password: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdatePasswordParams {
+    fn default() -> &'a UpdatePasswordParams {
+        <UpdatePasswordParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UpdatePasswordParams {
+    pub fn new() -> UpdatePasswordParams {
+        ::std::default::Default::default()
+    }
+
+    // string old_password = 1;
+
+
+    pub fn get_old_password(&self) -> &str {
+        &self.old_password
+    }
+    pub fn clear_old_password(&mut self) {
+        self.old_password.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_old_password(&mut self, v: ::std::string::String) {
+        self.old_password = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_old_password(&mut self) -> &mut ::std::string::String {
+        &mut self.old_password
+    }
+
+    // Take field
+    pub fn take_old_password(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.old_password, ::std::string::String::new())
+    }
+
+    // string new_password = 2;
+
+
+    pub fn get_new_password(&self) -> &str {
+        &self.new_password
+    }
+    pub fn clear_new_password(&mut self) {
+        self.new_password.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_new_password(&mut self, v: ::std::string::String) {
+        self.new_password = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_new_password(&mut self) -> &mut ::std::string::String {
+        &mut self.new_password
+    }
+
+    // Take field
+    pub fn take_new_password(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.new_password, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for UpdatePasswordParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.old_password)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.new_password)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.old_password.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.old_password);
+        }
+        if !self.new_password.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.new_password);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.old_password.is_empty() {
+            os.write_string(1, &self.old_password)?;
+        }
+        if !self.new_password.is_empty() {
+            os.write_string(2, &self.new_password)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdatePasswordParams {
+        UpdatePasswordParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "old_password",
+                |m: &UpdatePasswordParams| { &m.old_password },
+                |m: &mut UpdatePasswordParams| { &mut m.old_password },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "new_password",
+                |m: &UpdatePasswordParams| { &m.new_password },
+                |m: &mut UpdatePasswordParams| { &mut m.new_password },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdatePasswordParams>(
+                "UpdatePasswordParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdatePasswordParams {
+        static instance: ::protobuf::rt::LazyV2<UpdatePasswordParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdatePasswordParams::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdatePasswordParams {
+    fn clear(&mut self) {
+        self.old_password.clear();
+        self.new_password.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdatePasswordParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdatePasswordParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdatePasswordResponse {
+    // message fields
+    pub token: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdatePasswordResponse {
+    fn default() -> &'a UpdatePasswordResponse {
+        <UpdatePasswordResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UpdatePasswordResponse {
+    pub fn new() -> UpdatePasswordResponse {
+        ::std::default::Default::default()
+    }
+
+    // string token = 1;
+
+
+    pub fn get_token(&self) -> &str {
+        &self.token
+    }
+    pub fn clear_token(&mut self) {
+        self.token.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_token(&mut self, v: ::std::string::String) {
+        self.token = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_token(&mut self) -> &mut ::std::string::String {
+        &mut self.token
+    }
+
+    // Take field
+    pub fn take_token(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.token, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for UpdatePasswordResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.token)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.token.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.token);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.token.is_empty() {
+            os.write_string(1, &self.token)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdatePasswordResponse {
+        UpdatePasswordResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "token",
+                |m: &UpdatePasswordResponse| { &m.token },
+                |m: &mut UpdatePasswordResponse| { &mut m.token },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdatePasswordResponse>(
+                "UpdatePasswordResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdatePasswordResponse {
+        static instance: ::protobuf::rt::LazyV2<UpdatePasswordResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdatePasswordResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdatePasswordResponse {
+    fn clear(&mut self) {
+        self.token.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdatePasswordResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdatePasswordResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\nauth.proto\"U\n\rSignInRequest\x12\x14\n\x05email\x18\x01\x20\x01(\t\
-    R\x05email\x12\x1a\n\x08password\x18\x02\x20\x01(\tR\x08password\x12\x12\
-    \n\x04name\x18\x03\x20\x01(\tR\x04name\"T\n\x0cSignInParams\x12\x14\n\
-    \x05email\x18\x01\x20\x01(\tR\x05email\x12\x1a\n\x08password\x18\x02\x20\
-    \x01(\tR\x08password\x12\x12\n\x04name\x18\x03\x20\x01(\tR\x04name\"i\n\
-    \x0eSignInResponse\x12\x17\n\x07user_id\x18\x01\x20\x01(\tR\x06userId\
-    \x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x14\n\x05email\x18\
-    \x03\x20\x01(\tR\x05email\x12\x14\n\x05token\x18\x04\x20\x01(\tR\x05toke\
-    n\"U\n\rSignUpRequest\x12\x14\n\x05email\x18\x01\x20\x01(\tR\x05email\
-    \x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x1a\n\x08password\x18\
-    \x03\x20\x01(\tR\x08password\"T\n\x0cSignUpParams\x12\x14\n\x05email\x18\
-    \x01\x20\x01(\tR\x05email\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\
-    \x12\x1a\n\x08password\x18\x03\x20\x01(\tR\x08password\"i\n\x0eSignUpRes\
-    ponse\x12\x17\n\x07user_id\x18\x01\x20\x01(\tR\x06userId\x12\x12\n\x04na\
-    me\x18\x02\x20\x01(\tR\x04name\x12\x14\n\x05email\x18\x03\x20\x01(\tR\
-    \x05email\x12\x14\n\x05token\x18\x04\x20\x01(\tR\x05tokenJ\xee\t\n\x06\
-    \x12\x04\0\0!\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\
-    \x04\x02\0\x06\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x15\n\x0b\n\x04\
-    \x04\0\x02\0\x12\x03\x03\x04\x15\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\
-    \x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x10\n\x0c\n\x05\x04\0\
-    \x02\0\x03\x12\x03\x03\x13\x14\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\
-    \x18\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\
-    \x02\x01\x01\x12\x03\x04\x0b\x13\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\
-    \x04\x16\x17\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x04\x14\n\x0c\n\x05\
-    \x04\0\x02\x02\x05\x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\x02\x01\x12\
-    \x03\x05\x0b\x0f\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\x12\x13\n\n\n\
-    \x02\x04\x01\x12\x04\x07\0\x0b\x01\n\n\n\x03\x04\x01\x01\x12\x03\x07\x08\
-    \x14\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x08\x04\x15\n\x0c\n\x05\x04\x01\
-    \x02\0\x05\x12\x03\x08\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x08\
-    \x0b\x10\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x08\x13\x14\n\x0b\n\x04\
-    \x04\x01\x02\x01\x12\x03\t\x04\x18\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\
-    \x03\t\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\t\x0b\x13\n\x0c\n\
-    \x05\x04\x01\x02\x01\x03\x12\x03\t\x16\x17\n\x0b\n\x04\x04\x01\x02\x02\
-    \x12\x03\n\x04\x14\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\n\x04\n\n\x0c\
-    \n\x05\x04\x01\x02\x02\x01\x12\x03\n\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x02\
-    \x03\x12\x03\n\x12\x13\n\n\n\x02\x04\x02\x12\x04\x0c\0\x11\x01\n\n\n\x03\
-    \x04\x02\x01\x12\x03\x0c\x08\x16\n\x0b\n\x04\x04\x02\x02\0\x12\x03\r\x04\
-    \x17\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\r\x04\n\n\x0c\n\x05\x04\x02\
-    \x02\0\x01\x12\x03\r\x0b\x12\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\r\x15\
-    \x16\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x0e\x04\x14\n\x0c\n\x05\x04\x02\
-    \x02\x01\x05\x12\x03\x0e\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\
-    \x0e\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0e\x12\x13\n\x0b\n\
-    \x04\x04\x02\x02\x02\x12\x03\x0f\x04\x15\n\x0c\n\x05\x04\x02\x02\x02\x05\
-    \x12\x03\x0f\x04\n\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x0f\x0b\x10\n\
-    \x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x0f\x13\x14\n\x0b\n\x04\x04\x02\
-    \x02\x03\x12\x03\x10\x04\x15\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\x10\
-    \x04\n\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x10\x0b\x10\n\x0c\n\x05\
-    \x04\x02\x02\x03\x03\x12\x03\x10\x13\x14\n\n\n\x02\x04\x03\x12\x04\x12\0\
-    \x16\x01\n\n\n\x03\x04\x03\x01\x12\x03\x12\x08\x15\n\x0b\n\x04\x04\x03\
-    \x02\0\x12\x03\x13\x04\x15\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x13\x04\
-    \n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x13\x0b\x10\n\x0c\n\x05\x04\x03\
-    \x02\0\x03\x12\x03\x13\x13\x14\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x14\
-    \x04\x14\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x14\x04\n\n\x0c\n\x05\
-    \x04\x03\x02\x01\x01\x12\x03\x14\x0b\x0f\n\x0c\n\x05\x04\x03\x02\x01\x03\
-    \x12\x03\x14\x12\x13\n\x0b\n\x04\x04\x03\x02\x02\x12\x03\x15\x04\x18\n\
-    \x0c\n\x05\x04\x03\x02\x02\x05\x12\x03\x15\x04\n\n\x0c\n\x05\x04\x03\x02\
-    \x02\x01\x12\x03\x15\x0b\x13\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x03\x15\
-    \x16\x17\n\n\n\x02\x04\x04\x12\x04\x17\0\x1b\x01\n\n\n\x03\x04\x04\x01\
-    \x12\x03\x17\x08\x14\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x18\x04\x15\n\x0c\
-    \n\x05\x04\x04\x02\0\x05\x12\x03\x18\x04\n\n\x0c\n\x05\x04\x04\x02\0\x01\
-    \x12\x03\x18\x0b\x10\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x18\x13\x14\n\
-    \x0b\n\x04\x04\x04\x02\x01\x12\x03\x19\x04\x14\n\x0c\n\x05\x04\x04\x02\
-    \x01\x05\x12\x03\x19\x04\n\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03\x19\
-    \x0b\x0f\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x03\x19\x12\x13\n\x0b\n\x04\
-    \x04\x04\x02\x02\x12\x03\x1a\x04\x18\n\x0c\n\x05\x04\x04\x02\x02\x05\x12\
-    \x03\x1a\x04\n\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x03\x1a\x0b\x13\n\x0c\
-    \n\x05\x04\x04\x02\x02\x03\x12\x03\x1a\x16\x17\n\n\n\x02\x04\x05\x12\x04\
-    \x1c\0!\x01\n\n\n\x03\x04\x05\x01\x12\x03\x1c\x08\x16\n\x0b\n\x04\x04\
-    \x05\x02\0\x12\x03\x1d\x04\x17\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03\x1d\
-    \x04\n\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03\x1d\x0b\x12\n\x0c\n\x05\x04\
-    \x05\x02\0\x03\x12\x03\x1d\x15\x16\n\x0b\n\x04\x04\x05\x02\x01\x12\x03\
-    \x1e\x04\x14\n\x0c\n\x05\x04\x05\x02\x01\x05\x12\x03\x1e\x04\n\n\x0c\n\
-    \x05\x04\x05\x02\x01\x01\x12\x03\x1e\x0b\x0f\n\x0c\n\x05\x04\x05\x02\x01\
-    \x03\x12\x03\x1e\x12\x13\n\x0b\n\x04\x04\x05\x02\x02\x12\x03\x1f\x04\x15\
-    \n\x0c\n\x05\x04\x05\x02\x02\x05\x12\x03\x1f\x04\n\n\x0c\n\x05\x04\x05\
-    \x02\x02\x01\x12\x03\x1f\x0b\x10\n\x0c\n\x05\x04\x05\x02\x02\x03\x12\x03\
-    \x1f\x13\x14\n\x0b\n\x04\x04\x05\x02\x03\x12\x03\x20\x04\x15\n\x0c\n\x05\
-    \x04\x05\x02\x03\x05\x12\x03\x20\x04\n\n\x0c\n\x05\x04\x05\x02\x03\x01\
-    \x12\x03\x20\x0b\x10\n\x0c\n\x05\x04\x05\x02\x03\x03\x12\x03\x20\x13\x14\
-    b\x06proto3\
+    \n\nauth.proto\"]\n\rSignInRequest\x12\x16\n\x05email\x18\x01\x20\x01(\t\
+    R\x05emailB\0\x12\x1c\n\x08password\x18\x02\x20\x01(\tR\x08passwordB\0\
+    \x12\x14\n\x04name\x18\x03\x20\x01(\tR\x04nameB\0:\0\"\x9e\x01\n\x0cSign\
+    InParams\x12\x16\n\x05email\x18\x01\x20\x01(\tR\x05emailB\0\x12\x1c\n\
+    \x08password\x18\x02\x20\x01(\tR\x08passwordB\0\x12\x14\n\x04name\x18\
+    \x03\x20\x01(\tR\x04nameB\0\x12\x1d\n\tdevice_id\x18\x04\x20\x01(\tR\x08\
+    deviceIdB\0\x12!\n\x0bdevice_name\x18\x05\x20\x01(\tR\ndeviceNameB\0:\0\
+    \"s\n\x0eSignInResponse\x12\x19\n\x07user_id\x18\x01\x20\x01(\tR\x06user\
+    IdB\0\x12\x14\n\x04name\x18\x02\x20\x01(\tR\x04nameB\0\x12\x16\n\x05emai\
+    l\x18\x03\x20\x01(\tR\x05emailB\0\x12\x16\n\x05token\x18\x04\x20\x01(\tR\
+    \x05tokenB\0:\0\"]\n\rSignUpRequest\x12\x16\n\x05email\x18\x01\x20\x01(\
+    \tR\x05emailB\0\x12\x14\n\x04name\x18\x02\x20\x01(\tR\x04nameB\0\x12\x1c\
+    \n\x08password\x18\x03\x20\x01(\tR\x08passwordB\0:\0\"\x9e\x01\n\x0cSign\
+    UpParams\x12\x16\n\x05email\x18\x01\x20\x01(\tR\x05emailB\0\x12\x14\n\
+    \x04name\x18\x02\x20\x01(\tR\x04nameB\0\x12\x1c\n\x08password\x18\x03\
+    \x20\x01(\tR\x08passwordB\0\x12\x1d\n\tdevice_id\x18\x04\x20\x01(\tR\x08\
+    deviceIdB\0\x12!\n\x0bdevice_name\x18\x05\x20\x01(\tR\ndeviceNameB\0:\0\
+    \"s\n\x0eSignUpResponse\x12\x19\n\x07user_id\x18\x01\x20\x01(\tR\x06user\
+    IdB\0\x12\x14\n\x04name\x18\x02\x20\x01(\tR\x04nameB\0\x12\x16\n\x05emai\
+    l\x18\x03\x20\x01(\tR\x05emailB\0\x12\x16\n\x05token\x18\x04\x20\x01(\tR\
+    \x05tokenB\0:\0\"0\n\x14RefreshTokenResponse\x12\x16\n\x05token\x18\x01\
+    \x20\x01(\tR\x05tokenB\0:\0\"c\n\x15UpdatePasswordRequest\x12#\n\x0cold_\
+    password\x18\x01\x20\x01(\tR\x0boldPasswordB\0\x12#\n\x0cnew_password\
+    \x18\x02\x20\x01(\tR\x0bnewPasswordB\0:\0\"b\n\x14UpdatePasswordParams\
+    \x12#\n\x0cold_password\x18\x01\x20\x01(\tR\x0boldPasswordB\0\x12#\n\x0c\
+    new_password\x18\x02\x20\x01(\tR\x0bnewPasswordB\0:\0\"2\n\x16UpdatePass\
+    wordResponse\x12\x16\n\x05token\x18\x01\x20\x01(\tR\x05tokenB\0:\0B\0b\
+    \x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
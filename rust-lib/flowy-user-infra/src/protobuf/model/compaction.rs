@@ -0,0 +1,265 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `compaction.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StorageCompaction {
+    // message fields
+    pub size_before: i64,
+    pub size_after: i64,
+    pub reclaimed_bytes: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StorageCompaction {
+    fn default() -> &'a StorageCompaction {
+        <StorageCompaction as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StorageCompaction {
+    pub fn new() -> StorageCompaction {
+        ::std::default::Default::default()
+    }
+
+    // int64 size_before = 1;
+
+
+    pub fn get_size_before(&self) -> i64 {
+        self.size_before
+    }
+    pub fn clear_size_before(&mut self) {
+        self.size_before = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_size_before(&mut self, v: i64) {
+        self.size_before = v;
+    }
+
+    // int64 size_after = 2;
+
+
+    pub fn get_size_after(&self) -> i64 {
+        self.size_after
+    }
+    pub fn clear_size_after(&mut self) {
+        self.size_after = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_size_after(&mut self, v: i64) {
+        self.size_after = v;
+    }
+
+    // int64 reclaimed_bytes = 3;
+
+
+    pub fn get_reclaimed_bytes(&self) -> i64 {
+        self.reclaimed_bytes
+    }
+    pub fn clear_reclaimed_bytes(&mut self) {
+        self.reclaimed_bytes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reclaimed_bytes(&mut self, v: i64) {
+        self.reclaimed_bytes = v;
+    }
+}
+
+impl ::protobuf::Message for StorageCompaction {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.size_before = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.size_after = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.reclaimed_bytes = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.size_before != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.size_before, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.size_after != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.size_after, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.reclaimed_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.reclaimed_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.size_before != 0 {
+            os.write_int64(1, self.size_before)?;
+        }
+        if self.size_after != 0 {
+            os.write_int64(2, self.size_after)?;
+        }
+        if self.reclaimed_bytes != 0 {
+            os.write_int64(3, self.reclaimed_bytes)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StorageCompaction {
+        StorageCompaction::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "size_before",
+                |m: &StorageCompaction| { &m.size_before },
+                |m: &mut StorageCompaction| { &mut m.size_before },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "size_after",
+                |m: &StorageCompaction| { &m.size_after },
+                |m: &mut StorageCompaction| { &mut m.size_after },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "reclaimed_bytes",
+                |m: &StorageCompaction| { &m.reclaimed_bytes },
+                |m: &mut StorageCompaction| { &mut m.reclaimed_bytes },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<StorageCompaction>(
+                "StorageCompaction",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static StorageCompaction {
+        static instance: ::protobuf::rt::LazyV2<StorageCompaction> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(StorageCompaction::new)
+    }
+}
+
+impl ::protobuf::Clear for StorageCompaction {
+    fn clear(&mut self) {
+        self.size_before = 0;
+        self.size_after = 0;
+        self.reclaimed_bytes = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StorageCompaction {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StorageCompaction {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x10compaction.proto\"\x84\x01\n\x11StorageCompaction\x12!\n\x0bsize_b\
+    efore\x18\x01\x20\x01(\x03R\nsizeBeforeB\0\x12\x1f\n\nsize_after\x18\x02\
+    \x20\x01(\x03R\tsizeAfterB\0\x12)\n\x0freclaimed_bytes\x18\x03\x20\x01(\
+    \x03R\x0ereclaimedBytesB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
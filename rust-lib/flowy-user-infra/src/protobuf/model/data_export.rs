@@ -0,0 +1,750 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `data_export.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExportAllDataRequest {
+    // message fields
+    pub format: DataExportFormat,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExportAllDataRequest {
+    fn default() -> &'a ExportAllDataRequest {
+        <ExportAllDataRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExportAllDataRequest {
+    pub fn new() -> ExportAllDataRequest {
+        ::std::default::Default::default()
+    }
+
+    // .DataExportFormat format = 1;
+
+
+    pub fn get_format(&self) -> DataExportFormat {
+        self.format
+    }
+    pub fn clear_format(&mut self) {
+        self.format = DataExportFormat::JsonLines;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_format(&mut self, v: DataExportFormat) {
+        self.format = v;
+    }
+}
+
+impl ::protobuf::Message for ExportAllDataRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.format, 1, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.format != DataExportFormat::JsonLines {
+            my_size += ::protobuf::rt::enum_size(1, self.format);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.format != DataExportFormat::JsonLines {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.format))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExportAllDataRequest {
+        ExportAllDataRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<DataExportFormat>>(
+                "format",
+                |m: &ExportAllDataRequest| { &m.format },
+                |m: &mut ExportAllDataRequest| { &mut m.format },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExportAllDataRequest>(
+                "ExportAllDataRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ExportAllDataRequest {
+        static instance: ::protobuf::rt::LazyV2<ExportAllDataRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ExportAllDataRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ExportAllDataRequest {
+    fn clear(&mut self) {
+        self.format = DataExportFormat::JsonLines;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExportAllDataRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExportAllDataRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DataExportTable {
+    // message fields
+    pub name: ::std::string::String,
+    pub rows: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DataExportTable {
+    fn default() -> &'a DataExportTable {
+        <DataExportTable as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DataExportTable {
+    pub fn new() -> DataExportTable {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // int64 rows = 2;
+
+
+    pub fn get_rows(&self) -> i64 {
+        self.rows
+    }
+    pub fn clear_rows(&mut self) {
+        self.rows = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rows(&mut self, v: i64) {
+        self.rows = v;
+    }
+}
+
+impl ::protobuf::Message for DataExportTable {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.rows = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.rows != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.rows, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.rows != 0 {
+            os.write_int64(2, self.rows)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DataExportTable {
+        DataExportTable::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &DataExportTable| { &m.name },
+                |m: &mut DataExportTable| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "rows",
+                |m: &DataExportTable| { &m.rows },
+                |m: &mut DataExportTable| { &mut m.rows },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DataExportTable>(
+                "DataExportTable",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DataExportTable {
+        static instance: ::protobuf::rt::LazyV2<DataExportTable> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DataExportTable::new)
+    }
+}
+
+impl ::protobuf::Clear for DataExportTable {
+    fn clear(&mut self) {
+        self.name.clear();
+        self.rows = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DataExportTable {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DataExportTable {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DataExport {
+    // message fields
+    pub dir: ::std::string::String,
+    pub format: DataExportFormat,
+    pub version: i32,
+    pub created_time: i64,
+    pub tables: ::protobuf::RepeatedField<DataExportTable>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DataExport {
+    fn default() -> &'a DataExport {
+        <DataExport as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DataExport {
+    pub fn new() -> DataExport {
+        ::std::default::Default::default()
+    }
+
+    // string dir = 1;
+
+
+    pub fn get_dir(&self) -> &str {
+        &self.dir
+    }
+    pub fn clear_dir(&mut self) {
+        self.dir.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dir(&mut self, v: ::std::string::String) {
+        self.dir = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_dir(&mut self) -> &mut ::std::string::String {
+        &mut self.dir
+    }
+
+    // Take field
+    pub fn take_dir(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.dir, ::std::string::String::new())
+    }
+
+    // .DataExportFormat format = 2;
+
+
+    pub fn get_format(&self) -> DataExportFormat {
+        self.format
+    }
+    pub fn clear_format(&mut self) {
+        self.format = DataExportFormat::JsonLines;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_format(&mut self, v: DataExportFormat) {
+        self.format = v;
+    }
+
+    // int32 version = 3;
+
+
+    pub fn get_version(&self) -> i32 {
+        self.version
+    }
+    pub fn clear_version(&mut self) {
+        self.version = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: i32) {
+        self.version = v;
+    }
+
+    // int64 created_time = 4;
+
+
+    pub fn get_created_time(&self) -> i64 {
+        self.created_time
+    }
+    pub fn clear_created_time(&mut self) {
+        self.created_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created_time(&mut self, v: i64) {
+        self.created_time = v;
+    }
+
+    // repeated .DataExportTable tables = 5;
+
+
+    pub fn get_tables(&self) -> &[DataExportTable] {
+        &self.tables
+    }
+    pub fn clear_tables(&mut self) {
+        self.tables.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tables(&mut self, v: ::protobuf::RepeatedField<DataExportTable>) {
+        self.tables = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tables(&mut self) -> &mut ::protobuf::RepeatedField<DataExportTable> {
+        &mut self.tables
+    }
+
+    // Take field
+    pub fn take_tables(&mut self) -> ::protobuf::RepeatedField<DataExportTable> {
+        ::std::mem::replace(&mut self.tables, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for DataExport {
+    fn is_initialized(&self) -> bool {
+        for v in &self.tables {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.dir)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.format, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.version = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.created_time = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.tables)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.dir.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.dir);
+        }
+        if self.format != DataExportFormat::JsonLines {
+            my_size += ::protobuf::rt::enum_size(2, self.format);
+        }
+        if self.version != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.version, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.created_time != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.created_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.tables {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.dir.is_empty() {
+            os.write_string(1, &self.dir)?;
+        }
+        if self.format != DataExportFormat::JsonLines {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.format))?;
+        }
+        if self.version != 0 {
+            os.write_int32(3, self.version)?;
+        }
+        if self.created_time != 0 {
+            os.write_int64(4, self.created_time)?;
+        }
+        for v in &self.tables {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DataExport {
+        DataExport::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "dir",
+                |m: &DataExport| { &m.dir },
+                |m: &mut DataExport| { &mut m.dir },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<DataExportFormat>>(
+                "format",
+                |m: &DataExport| { &m.format },
+                |m: &mut DataExport| { &mut m.format },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "version",
+                |m: &DataExport| { &m.version },
+                |m: &mut DataExport| { &mut m.version },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "created_time",
+                |m: &DataExport| { &m.created_time },
+                |m: &mut DataExport| { &mut m.created_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DataExportTable>>(
+                "tables",
+                |m: &DataExport| { &m.tables },
+                |m: &mut DataExport| { &mut m.tables },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DataExport>(
+                "DataExport",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DataExport {
+        static instance: ::protobuf::rt::LazyV2<DataExport> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DataExport::new)
+    }
+}
+
+impl ::protobuf::Clear for DataExport {
+    fn clear(&mut self) {
+        self.dir.clear();
+        self.format = DataExportFormat::JsonLines;
+        self.version = 0;
+        self.created_time = 0;
+        self.tables.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DataExport {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DataExport {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum DataExportFormat {
+    JsonLines = 0,
+    Sqlite = 1,
+}
+
+impl ::protobuf::ProtobufEnum for DataExportFormat {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<DataExportFormat> {
+        match value {
+            0 => ::std::option::Option::Some(DataExportFormat::JsonLines),
+            1 => ::std::option::Option::Some(DataExportFormat::Sqlite),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [DataExportFormat] = &[
+            DataExportFormat::JsonLines,
+            DataExportFormat::Sqlite,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<DataExportFormat>("DataExportFormat", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for DataExportFormat {
+}
+
+impl ::std::default::Default for DataExportFormat {
+    fn default() -> Self {
+        DataExportFormat::JsonLines
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DataExportFormat {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x11data_export.proto\"E\n\x14ExportAllDataRequest\x12+\n\x06format\
+    \x18\x01\x20\x01(\x0e2\x11.DataExportFormatR\x06formatB\0:\0\"?\n\x0fDat\
+    aExportTable\x12\x14\n\x04name\x18\x01\x20\x01(\tR\x04nameB\0\x12\x14\n\
+    \x04rows\x18\x02\x20\x01(\x03R\x04rowsB\0:\0\"\xbc\x01\n\nDataExport\x12\
+    \x12\n\x03dir\x18\x01\x20\x01(\tR\x03dirB\0\x12+\n\x06format\x18\x02\x20\
+    \x01(\x0e2\x11.DataExportFormatR\x06formatB\0\x12\x1a\n\x07version\x18\
+    \x03\x20\x01(\x05R\x07versionB\0\x12#\n\x0ccreated_time\x18\x04\x20\x01(\
+    \x03R\x0bcreatedTimeB\0\x12*\n\x06tables\x18\x05\x20\x03(\x0b2\x10.DataE\
+    xportTableR\x06tablesB\0:\0*/\n\x10DataExportFormat\x12\r\n\tJsonLines\
+    \x10\0\x12\n\n\x06Sqlite\x10\x01\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
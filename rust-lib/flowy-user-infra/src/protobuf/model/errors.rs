@@ -33,12 +33,46 @@ pub enum ErrorCode {
     PasswordContainsForbidCharacters = 12,
     PasswordFormatInvalid = 13,
     PasswordNotMatch = 14,
+    PasswordTooShort = 15,
+    PasswordMissingDigit = 16,
+    PasswordMissingLowercase = 17,
+    PasswordMissingUppercase = 18,
+    PasswordMissingSpecialCharacter = 19,
     UserNameTooLong = 20,
     UserNameContainForbiddenCharacters = 21,
     UserNameIsEmpty = 22,
     UserIdInvalid = 23,
     UserUnauthorized = 24,
     UserNotExist = 25,
+    SessionExpired = 26,
+    PasswordNotChanged = 27,
+    OAuthProviderNotSupported = 28,
+    OAuthStateInvalid = 29,
+    OAuthCodeIsEmpty = 30,
+    NotAnonymousUser = 31,
+    UserAvatarInvalid = 32,
+    PreferenceKeyIsEmpty = 33,
+    PreferenceValueInvalid = 34,
+    PreferenceNotExist = 35,
+    PreferenceTypeMismatch = 36,
+    DeleteAccountNotConfirmed = 37,
+    SignatureInvalid = 38,
+    SessionRevoked = 39,
+    SessionIdIsEmpty = 40,
+    SessionNotExist = 41,
+    VerificationCodeIsEmpty = 42,
+    VerificationCodeInvalid = 43,
+    EmailNotVerified = 44,
+    TooManyAttempts = 45,
+    DatabaseMigrationFailed = 46,
+    DatabaseEncryptionUnsupported = 47,
+    DatabaseEncryptionFailed = 48,
+    BackupNotExist = 49,
+    BackupCorrupted = 50,
+    SettingIsEmpty = 51,
+    SettingValueInvalid = 52,
+    ShortcutInvalid = 53,
+    ShortcutConflict = 54,
     ServerError = 99,
     InternalError = 100,
 }
@@ -58,12 +92,46 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             12 => ::std::option::Option::Some(ErrorCode::PasswordContainsForbidCharacters),
             13 => ::std::option::Option::Some(ErrorCode::PasswordFormatInvalid),
             14 => ::std::option::Option::Some(ErrorCode::PasswordNotMatch),
+            15 => ::std::option::Option::Some(ErrorCode::PasswordTooShort),
+            16 => ::std::option::Option::Some(ErrorCode::PasswordMissingDigit),
+            17 => ::std::option::Option::Some(ErrorCode::PasswordMissingLowercase),
+            18 => ::std::option::Option::Some(ErrorCode::PasswordMissingUppercase),
+            19 => ::std::option::Option::Some(ErrorCode::PasswordMissingSpecialCharacter),
             20 => ::std::option::Option::Some(ErrorCode::UserNameTooLong),
             21 => ::std::option::Option::Some(ErrorCode::UserNameContainForbiddenCharacters),
             22 => ::std::option::Option::Some(ErrorCode::UserNameIsEmpty),
             23 => ::std::option::Option::Some(ErrorCode::UserIdInvalid),
             24 => ::std::option::Option::Some(ErrorCode::UserUnauthorized),
             25 => ::std::option::Option::Some(ErrorCode::UserNotExist),
+            26 => ::std::option::Option::Some(ErrorCode::SessionExpired),
+            27 => ::std::option::Option::Some(ErrorCode::PasswordNotChanged),
+            28 => ::std::option::Option::Some(ErrorCode::OAuthProviderNotSupported),
+            29 => ::std::option::Option::Some(ErrorCode::OAuthStateInvalid),
+            30 => ::std::option::Option::Some(ErrorCode::OAuthCodeIsEmpty),
+            31 => ::std::option::Option::Some(ErrorCode::NotAnonymousUser),
+            32 => ::std::option::Option::Some(ErrorCode::UserAvatarInvalid),
+            33 => ::std::option::Option::Some(ErrorCode::PreferenceKeyIsEmpty),
+            34 => ::std::option::Option::Some(ErrorCode::PreferenceValueInvalid),
+            35 => ::std::option::Option::Some(ErrorCode::PreferenceNotExist),
+            36 => ::std::option::Option::Some(ErrorCode::PreferenceTypeMismatch),
+            37 => ::std::option::Option::Some(ErrorCode::DeleteAccountNotConfirmed),
+            38 => ::std::option::Option::Some(ErrorCode::SignatureInvalid),
+            39 => ::std::option::Option::Some(ErrorCode::SessionRevoked),
+            40 => ::std::option::Option::Some(ErrorCode::SessionIdIsEmpty),
+            41 => ::std::option::Option::Some(ErrorCode::SessionNotExist),
+            42 => ::std::option::Option::Some(ErrorCode::VerificationCodeIsEmpty),
+            43 => ::std::option::Option::Some(ErrorCode::VerificationCodeInvalid),
+            44 => ::std::option::Option::Some(ErrorCode::EmailNotVerified),
+            45 => ::std::option::Option::Some(ErrorCode::TooManyAttempts),
+            46 => ::std::option::Option::Some(ErrorCode::DatabaseMigrationFailed),
+            47 => ::std::option::Option::Some(ErrorCode::DatabaseEncryptionUnsupported),
+            48 => ::std::option::Option::Some(ErrorCode::DatabaseEncryptionFailed),
+            49 => ::std::option::Option::Some(ErrorCode::BackupNotExist),
+            50 => ::std::option::Option::Some(ErrorCode::BackupCorrupted),
+            51 => ::std::option::Option::Some(ErrorCode::SettingIsEmpty),
+            52 => ::std::option::Option::Some(ErrorCode::SettingValueInvalid),
+            53 => ::std::option::Option::Some(ErrorCode::ShortcutInvalid),
+            54 => ::std::option::Option::Some(ErrorCode::ShortcutConflict),
             99 => ::std::option::Option::Some(ErrorCode::ServerError),
             100 => ::std::option::Option::Some(ErrorCode::InternalError),
             _ => ::std::option::Option::None
@@ -80,12 +148,46 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             ErrorCode::PasswordContainsForbidCharacters,
             ErrorCode::PasswordFormatInvalid,
             ErrorCode::PasswordNotMatch,
+            ErrorCode::PasswordTooShort,
+            ErrorCode::PasswordMissingDigit,
+            ErrorCode::PasswordMissingLowercase,
+            ErrorCode::PasswordMissingUppercase,
+            ErrorCode::PasswordMissingSpecialCharacter,
             ErrorCode::UserNameTooLong,
             ErrorCode::UserNameContainForbiddenCharacters,
             ErrorCode::UserNameIsEmpty,
             ErrorCode::UserIdInvalid,
             ErrorCode::UserUnauthorized,
             ErrorCode::UserNotExist,
+            ErrorCode::SessionExpired,
+            ErrorCode::PasswordNotChanged,
+            ErrorCode::OAuthProviderNotSupported,
+            ErrorCode::OAuthStateInvalid,
+            ErrorCode::OAuthCodeIsEmpty,
+            ErrorCode::NotAnonymousUser,
+            ErrorCode::UserAvatarInvalid,
+            ErrorCode::PreferenceKeyIsEmpty,
+            ErrorCode::PreferenceValueInvalid,
+            ErrorCode::PreferenceNotExist,
+            ErrorCode::PreferenceTypeMismatch,
+            ErrorCode::DeleteAccountNotConfirmed,
+            ErrorCode::SignatureInvalid,
+            ErrorCode::SessionRevoked,
+            ErrorCode::SessionIdIsEmpty,
+            ErrorCode::SessionNotExist,
+            ErrorCode::VerificationCodeIsEmpty,
+            ErrorCode::VerificationCodeInvalid,
+            ErrorCode::EmailNotVerified,
+            ErrorCode::TooManyAttempts,
+            ErrorCode::DatabaseMigrationFailed,
+            ErrorCode::DatabaseEncryptionUnsupported,
+            ErrorCode::DatabaseEncryptionFailed,
+            ErrorCode::BackupNotExist,
+            ErrorCode::BackupCorrupted,
+            ErrorCode::SettingIsEmpty,
+            ErrorCode::SettingValueInvalid,
+            ErrorCode::ShortcutInvalid,
+            ErrorCode::ShortcutConflict,
             ErrorCode::ServerError,
             ErrorCode::InternalError,
         ];
@@ -116,50 +218,34 @@ impl ::protobuf::reflect::ProtobufValue for ErrorCode {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0cerrors.proto*\xff\x02\n\tErrorCode\x12\x10\n\x0cEmailIsEmpty\x10\0\
+    \n\x0cerrors.proto*\xd9\t\n\tErrorCode\x12\x10\n\x0cEmailIsEmpty\x10\0\
     \x12\x16\n\x12EmailFormatInvalid\x10\x01\x12\x16\n\x12EmailAlreadyExists\
     \x10\x02\x12\x13\n\x0fPasswordIsEmpty\x10\n\x12\x13\n\x0fPasswordTooLong\
     \x10\x0b\x12$\n\x20PasswordContainsForbidCharacters\x10\x0c\x12\x19\n\
     \x15PasswordFormatInvalid\x10\r\x12\x14\n\x10PasswordNotMatch\x10\x0e\
-    \x12\x13\n\x0fUserNameTooLong\x10\x14\x12&\n\"UserNameContainForbiddenCh\
-    aracters\x10\x15\x12\x13\n\x0fUserNameIsEmpty\x10\x16\x12\x11\n\rUserIdI\
-    nvalid\x10\x17\x12\x14\n\x10UserUnauthorized\x10\x18\x12\x10\n\x0cUserNo\
-    tExist\x10\x19\x12\x0f\n\x0bServerError\x10c\x12\x11\n\rInternalError\
-    \x10dJ\xba\x05\n\x06\x12\x04\0\0\x13\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\
-    \n\n\n\x02\x05\0\x12\x04\x02\0\x13\x01\n\n\n\x03\x05\0\x01\x12\x03\x02\
-    \x05\x0e\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\x15\n\x0c\n\x05\x05\0\
-    \x02\0\x01\x12\x03\x03\x04\x10\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x03\
-    \x13\x14\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\x04\x1b\n\x0c\n\x05\x05\0\
-    \x02\x01\x01\x12\x03\x04\x04\x16\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\
-    \x04\x19\x1a\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x05\x04\x1b\n\x0c\n\x05\
-    \x05\0\x02\x02\x01\x12\x03\x05\x04\x16\n\x0c\n\x05\x05\0\x02\x02\x02\x12\
-    \x03\x05\x19\x1a\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x06\x04\x19\n\x0c\n\
-    \x05\x05\0\x02\x03\x01\x12\x03\x06\x04\x13\n\x0c\n\x05\x05\0\x02\x03\x02\
-    \x12\x03\x06\x16\x18\n\x0b\n\x04\x05\0\x02\x04\x12\x03\x07\x04\x19\n\x0c\
-    \n\x05\x05\0\x02\x04\x01\x12\x03\x07\x04\x13\n\x0c\n\x05\x05\0\x02\x04\
-    \x02\x12\x03\x07\x16\x18\n\x0b\n\x04\x05\0\x02\x05\x12\x03\x08\x04*\n\
-    \x0c\n\x05\x05\0\x02\x05\x01\x12\x03\x08\x04$\n\x0c\n\x05\x05\0\x02\x05\
-    \x02\x12\x03\x08')\n\x0b\n\x04\x05\0\x02\x06\x12\x03\t\x04\x1f\n\x0c\n\
-    \x05\x05\0\x02\x06\x01\x12\x03\t\x04\x19\n\x0c\n\x05\x05\0\x02\x06\x02\
-    \x12\x03\t\x1c\x1e\n\x0b\n\x04\x05\0\x02\x07\x12\x03\n\x04\x1a\n\x0c\n\
-    \x05\x05\0\x02\x07\x01\x12\x03\n\x04\x14\n\x0c\n\x05\x05\0\x02\x07\x02\
-    \x12\x03\n\x17\x19\n\x0b\n\x04\x05\0\x02\x08\x12\x03\x0b\x04\x19\n\x0c\n\
-    \x05\x05\0\x02\x08\x01\x12\x03\x0b\x04\x13\n\x0c\n\x05\x05\0\x02\x08\x02\
-    \x12\x03\x0b\x16\x18\n\x0b\n\x04\x05\0\x02\t\x12\x03\x0c\x04,\n\x0c\n\
-    \x05\x05\0\x02\t\x01\x12\x03\x0c\x04&\n\x0c\n\x05\x05\0\x02\t\x02\x12\
-    \x03\x0c)+\n\x0b\n\x04\x05\0\x02\n\x12\x03\r\x04\x19\n\x0c\n\x05\x05\0\
-    \x02\n\x01\x12\x03\r\x04\x13\n\x0c\n\x05\x05\0\x02\n\x02\x12\x03\r\x16\
-    \x18\n\x0b\n\x04\x05\0\x02\x0b\x12\x03\x0e\x04\x17\n\x0c\n\x05\x05\0\x02\
-    \x0b\x01\x12\x03\x0e\x04\x11\n\x0c\n\x05\x05\0\x02\x0b\x02\x12\x03\x0e\
-    \x14\x16\n\x0b\n\x04\x05\0\x02\x0c\x12\x03\x0f\x04\x1a\n\x0c\n\x05\x05\0\
-    \x02\x0c\x01\x12\x03\x0f\x04\x14\n\x0c\n\x05\x05\0\x02\x0c\x02\x12\x03\
-    \x0f\x17\x19\n\x0b\n\x04\x05\0\x02\r\x12\x03\x10\x04\x16\n\x0c\n\x05\x05\
-    \0\x02\r\x01\x12\x03\x10\x04\x10\n\x0c\n\x05\x05\0\x02\r\x02\x12\x03\x10\
-    \x13\x15\n\x0b\n\x04\x05\0\x02\x0e\x12\x03\x11\x04\x15\n\x0c\n\x05\x05\0\
-    \x02\x0e\x01\x12\x03\x11\x04\x0f\n\x0c\n\x05\x05\0\x02\x0e\x02\x12\x03\
-    \x11\x12\x14\n\x0b\n\x04\x05\0\x02\x0f\x12\x03\x12\x04\x18\n\x0c\n\x05\
-    \x05\0\x02\x0f\x01\x12\x03\x12\x04\x11\n\x0c\n\x05\x05\0\x02\x0f\x02\x12\
-    \x03\x12\x14\x17b\x06proto3\
+    \x12\x14\n\x10PasswordTooShort\x10\x0f\x12\x18\n\x14PasswordMissingDigit\
+    \x10\x10\x12\x1c\n\x18PasswordMissingLowercase\x10\x11\x12\x1c\n\x18Pass\
+    wordMissingUppercase\x10\x12\x12#\n\x1fPasswordMissingSpecialCharacter\
+    \x10\x13\x12\x13\n\x0fUserNameTooLong\x10\x14\x12&\n\"UserNameContainFor\
+    biddenCharacters\x10\x15\x12\x13\n\x0fUserNameIsEmpty\x10\x16\x12\x11\n\
+    \rUserIdInvalid\x10\x17\x12\x14\n\x10UserUnauthorized\x10\x18\x12\x10\n\
+    \x0cUserNotExist\x10\x19\x12\x12\n\x0eSessionExpired\x10\x1a\x12\x16\n\
+    \x12PasswordNotChanged\x10\x1b\x12\x1d\n\x19OAuthProviderNotSupported\
+    \x10\x1c\x12\x15\n\x11OAuthStateInvalid\x10\x1d\x12\x14\n\x10OAuthCodeIs\
+    Empty\x10\x1e\x12\x14\n\x10NotAnonymousUser\x10\x1f\x12\x15\n\x11UserAva\
+    tarInvalid\x10\x20\x12\x18\n\x14PreferenceKeyIsEmpty\x10!\x12\x1a\n\x16P\
+    referenceValueInvalid\x10\"\x12\x16\n\x12PreferenceNotExist\x10#\x12\x1a\
+    \n\x16PreferenceTypeMismatch\x10$\x12\x1d\n\x19DeleteAccountNotConfirmed\
+    \x10%\x12\x14\n\x10SignatureInvalid\x10&\x12\x12\n\x0eSessionRevoked\x10\
+    '\x12\x14\n\x10SessionIdIsEmpty\x10(\x12\x13\n\x0fSessionNotExist\x10)\
+    \x12\x1b\n\x17VerificationCodeIsEmpty\x10*\x12\x1b\n\x17VerificationCode\
+    Invalid\x10+\x12\x14\n\x10EmailNotVerified\x10,\x12\x13\n\x0fTooManyAtte\
+    mpts\x10-\x12\x1b\n\x17DatabaseMigrationFailed\x10.\x12!\n\x1dDatabaseEn\
+    cryptionUnsupported\x10/\x12\x1c\n\x18DatabaseEncryptionFailed\x100\x12\
+    \x12\n\x0eBackupNotExist\x101\x12\x13\n\x0fBackupCorrupted\x102\x12\x12\
+    \n\x0eSettingIsEmpty\x103\x12\x17\n\x13SettingValueInvalid\x104\x12\x13\
+    \n\x0fShortcutInvalid\x105\x12\x14\n\x10ShortcutConflict\x106\x12\x0f\n\
+    \x0bServerError\x10c\x12\x11\n\rInternalError\x10d\x1a\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
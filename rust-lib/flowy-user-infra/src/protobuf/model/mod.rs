@@ -8,3 +8,42 @@ pub use user_profile::*;
 
 mod auth; 
 pub use auth::*; 
+
+mod account; 
+pub use account::*; 
+
+mod oauth; 
+pub use oauth::*; 
+
+mod preference; 
+pub use preference::*; 
+
+mod session; 
+pub use session::*; 
+
+mod verification; 
+pub use verification::*; 
+
+mod migration; 
+pub use migration::*; 
+
+mod backup; 
+pub use backup::*; 
+
+mod data_export; 
+pub use data_export::*; 
+
+mod compaction; 
+pub use compaction::*; 
+
+mod row_change; 
+pub use row_change::*; 
+
+mod log_file; 
+pub use log_file::*; 
+
+mod audit; 
+pub use audit::*; 
+
+mod setting; 
+pub use setting::*; 
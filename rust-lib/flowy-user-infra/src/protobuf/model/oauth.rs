@@ -0,0 +1,999 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `oauth.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct OAuthURLRequest {
+    // message fields
+    pub provider: OAuthProviderType,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a OAuthURLRequest {
+    fn default() -> &'a OAuthURLRequest {
+        <OAuthURLRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl OAuthURLRequest {
+    pub fn new() -> OAuthURLRequest {
+        ::std::default::Default::default()
+    }
+
+    // .OAuthProviderType provider = 1;
+
+
+    pub fn get_provider(&self) -> OAuthProviderType {
+        self.provider
+    }
+    pub fn clear_provider(&mut self) {
+        self.provider = OAuthProviderType::Google;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_provider(&mut self, v: OAuthProviderType) {
+        self.provider = v;
+    }
+}
+
+impl ::protobuf::Message for OAuthURLRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.provider, 1, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.provider != OAuthProviderType::Google {
+            my_size += ::protobuf::rt::enum_size(1, self.provider);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.provider != OAuthProviderType::Google {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.provider))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> OAuthURLRequest {
+        OAuthURLRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<OAuthProviderType>>(
+                "provider",
+                |m: &OAuthURLRequest| { &m.provider },
+                |m: &mut OAuthURLRequest| { &mut m.provider },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<OAuthURLRequest>(
+                "OAuthURLRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static OAuthURLRequest {
+        static instance: ::protobuf::rt::LazyV2<OAuthURLRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(OAuthURLRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for OAuthURLRequest {
+    fn clear(&mut self) {
+        self.provider = OAuthProviderType::Google;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for OAuthURLRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OAuthURLRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct OAuthURL {
+    // message fields
+    pub url: ::std::string::String,
+    pub state: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a OAuthURL {
+    fn default() -> &'a OAuthURL {
+        <OAuthURL as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl OAuthURL {
+    pub fn new() -> OAuthURL {
+        ::std::default::Default::default()
+    }
+
+    // string url = 1;
+
+
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+    pub fn clear_url(&mut self) {
+        self.url.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_url(&mut self, v: ::std::string::String) {
+        self.url = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_url(&mut self) -> &mut ::std::string::String {
+        &mut self.url
+    }
+
+    // Take field
+    pub fn take_url(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.url, ::std::string::String::new())
+    }
+
+    // string state = 2;
+
+
+    pub fn get_state(&self) -> &str {
+        &self.state
+    }
+    pub fn clear_state(&mut self) {
+        self.state.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: ::std::string::String) {
+        self.state = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_state(&mut self) -> &mut ::std::string::String {
+        &mut self.state
+    }
+
+    // Take field
+    pub fn take_state(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.state, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for OAuthURL {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.url)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.state)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.url.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.url);
+        }
+        if !self.state.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.state);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.url.is_empty() {
+            os.write_string(1, &self.url)?;
+        }
+        if !self.state.is_empty() {
+            os.write_string(2, &self.state)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> OAuthURL {
+        OAuthURL::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "url",
+                |m: &OAuthURL| { &m.url },
+                |m: &mut OAuthURL| { &mut m.url },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "state",
+                |m: &OAuthURL| { &m.state },
+                |m: &mut OAuthURL| { &mut m.state },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<OAuthURL>(
+                "OAuthURL",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static OAuthURL {
+        static instance: ::protobuf::rt::LazyV2<OAuthURL> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(OAuthURL::new)
+    }
+}
+
+impl ::protobuf::Clear for OAuthURL {
+    fn clear(&mut self) {
+        self.url.clear();
+        self.state.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for OAuthURL {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OAuthURL {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct OAuthSignInRequest {
+    // message fields
+    pub provider: OAuthProviderType,
+    pub code: ::std::string::String,
+    pub state: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a OAuthSignInRequest {
+    fn default() -> &'a OAuthSignInRequest {
+        <OAuthSignInRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl OAuthSignInRequest {
+    pub fn new() -> OAuthSignInRequest {
+        ::std::default::Default::default()
+    }
+
+    // .OAuthProviderType provider = 1;
+
+
+    pub fn get_provider(&self) -> OAuthProviderType {
+        self.provider
+    }
+    pub fn clear_provider(&mut self) {
+        self.provider = OAuthProviderType::Google;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_provider(&mut self, v: OAuthProviderType) {
+        self.provider = v;
+    }
+
+    // string code = 2;
+
+
+    pub fn get_code(&self) -> &str {
+        &self.code
+    }
+    pub fn clear_code(&mut self) {
+        self.code.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_code(&mut self, v: ::std::string::String) {
+        self.code = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_code(&mut self) -> &mut ::std::string::String {
+        &mut self.code
+    }
+
+    // Take field
+    pub fn take_code(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.code, ::std::string::String::new())
+    }
+
+    // string state = 3;
+
+
+    pub fn get_state(&self) -> &str {
+        &self.state
+    }
+    pub fn clear_state(&mut self) {
+        self.state.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: ::std::string::String) {
+        self.state = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_state(&mut self) -> &mut ::std::string::String {
+        &mut self.state
+    }
+
+    // Take field
+    pub fn take_state(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.state, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for OAuthSignInRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.provider, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.code)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.state)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.provider != OAuthProviderType::Google {
+            my_size += ::protobuf::rt::enum_size(1, self.provider);
+        }
+        if !self.code.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.code);
+        }
+        if !self.state.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.state);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.provider != OAuthProviderType::Google {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.provider))?;
+        }
+        if !self.code.is_empty() {
+            os.write_string(2, &self.code)?;
+        }
+        if !self.state.is_empty() {
+            os.write_string(3, &self.state)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> OAuthSignInRequest {
+        OAuthSignInRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<OAuthProviderType>>(
+                "provider",
+                |m: &OAuthSignInRequest| { &m.provider },
+                |m: &mut OAuthSignInRequest| { &mut m.provider },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "code",
+                |m: &OAuthSignInRequest| { &m.code },
+                |m: &mut OAuthSignInRequest| { &mut m.code },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "state",
+                |m: &OAuthSignInRequest| { &m.state },
+                |m: &mut OAuthSignInRequest| { &mut m.state },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<OAuthSignInRequest>(
+                "OAuthSignInRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static OAuthSignInRequest {
+        static instance: ::protobuf::rt::LazyV2<OAuthSignInRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(OAuthSignInRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for OAuthSignInRequest {
+    fn clear(&mut self) {
+        self.provider = OAuthProviderType::Google;
+        self.code.clear();
+        self.state.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for OAuthSignInRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OAuthSignInRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct OAuthSignInParams {
+    // message fields
+    pub provider: OAuthProviderType,
+    pub code: ::std::string::String,
+    pub redirect_uri: ::std::string::String,
+    pub device_id: ::std::string::String,
+    pub device_name: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a OAuthSignInParams {
+    fn default() -> &'a OAuthSignInParams {
+        <OAuthSignInParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl OAuthSignInParams {
+    pub fn new() -> OAuthSignInParams {
+        ::std::default::Default::default()
+    }
+
+    // .OAuthProviderType provider = 1;
+
+
+    pub fn get_provider(&self) -> OAuthProviderType {
+        self.provider
+    }
+    pub fn clear_provider(&mut self) {
+        self.provider = OAuthProviderType::Google;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_provider(&mut self, v: OAuthProviderType) {
+        self.provider = v;
+    }
+
+    // string code = 2;
+
+
+    pub fn get_code(&self) -> &str {
+        &self.code
+    }
+    pub fn clear_code(&mut self) {
+        self.code.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_code(&mut self, v: ::std::string::String) {
+        self.code = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_code(&mut self) -> &mut ::std::string::String {
+        &mut self.code
+    }
+
+    // Take field
+    pub fn take_code(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.code, ::std::string::String::new())
+    }
+
+    // string redirect_uri = 3;
+
+
+    pub fn get_redirect_uri(&self) -> &str {
+        &self.redirect_uri
+    }
+    pub fn clear_redirect_uri(&mut self) {
+        self.redirect_uri.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_redirect_uri(&mut self, v: ::std::string::String) {
+        self.redirect_uri = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_redirect_uri(&mut self) -> &mut ::std::string::String {
+        &mut self.redirect_uri
+    }
+
+    // Take field
+    pub fn take_redirect_uri(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.redirect_uri, ::std::string::String::new())
+    }
+
+    // string device_id = 4;
+
+
+    pub fn get_device_id(&self) -> &str {
+        &self.device_id
+    }
+    pub fn clear_device_id(&mut self) {
+        self.device_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_device_id(&mut self, v: ::std::string::String) {
+        self.device_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_device_id(&mut self) -> &mut ::std::string::String {
+        &mut self.device_id
+    }
+
+    // Take field
+    pub fn take_device_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.device_id, ::std::string::String::new())
+    }
+
+    // string device_name = 5;
+
+
+    pub fn get_device_name(&self) -> &str {
+        &self.device_name
+    }
+    pub fn clear_device_name(&mut self) {
+        self.device_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_device_name(&mut self, v: ::std::string::String) {
+        self.device_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_device_name(&mut self) -> &mut ::std::string::String {
+        &mut self.device_name
+    }
+
+    // Take field
+    pub fn take_device_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.device_name, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for OAuthSignInParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.provider, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.code)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.redirect_uri)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.device_id)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.device_name)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.provider != OAuthProviderType::Google {
+            my_size += ::protobuf::rt::enum_size(1, self.provider);
+        }
+        if !self.code.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.code);
+        }
+        if !self.redirect_uri.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.redirect_uri);
+        }
+        if !self.device_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.device_id);
+        }
+        if !self.device_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.device_name);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.provider != OAuthProviderType::Google {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.provider))?;
+        }
+        if !self.code.is_empty() {
+            os.write_string(2, &self.code)?;
+        }
+        if !self.redirect_uri.is_empty() {
+            os.write_string(3, &self.redirect_uri)?;
+        }
+        if !self.device_id.is_empty() {
+            os.write_string(4, &self.device_id)?;
+        }
+        if !self.device_name.is_empty() {
+            os.write_string(5, &self.device_name)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> OAuthSignInParams {
+        OAuthSignInParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<OAuthProviderType>>(
+                "provider",
+                |m: &OAuthSignInParams| { &m.provider },
+                |m: &mut OAuthSignInParams| { &mut m.provider },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "code",
+                |m: &OAuthSignInParams| { &m.code },
+                |m: &mut OAuthSignInParams| { &mut m.code },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "redirect_uri",
+                |m: &OAuthSignInParams| { &m.redirect_uri },
+                |m: &mut OAuthSignInParams| { &mut m.redirect_uri },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "device_id",
+                |m: &OAuthSignInParams| { &m.device_id },
+                |m: &mut OAuthSignInParams| { &mut m.device_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "device_name",
+                |m: &OAuthSignInParams| { &m.device_name },
+                |m: &mut OAuthSignInParams| { &mut m.device_name },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<OAuthSignInParams>(
+                "OAuthSignInParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static OAuthSignInParams {
+        static instance: ::protobuf::rt::LazyV2<OAuthSignInParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(OAuthSignInParams::new)
+    }
+}
+
+impl ::protobuf::Clear for OAuthSignInParams {
+    fn clear(&mut self) {
+        self.provider = OAuthProviderType::Google;
+        self.code.clear();
+        self.redirect_uri.clear();
+        self.device_id.clear();
+        self.device_name.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for OAuthSignInParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OAuthSignInParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum OAuthProviderType {
+    Google = 0,
+    GitHub = 1,
+}
+
+impl ::protobuf::ProtobufEnum for OAuthProviderType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<OAuthProviderType> {
+        match value {
+            0 => ::std::option::Option::Some(OAuthProviderType::Google),
+            1 => ::std::option::Option::Some(OAuthProviderType::GitHub),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [OAuthProviderType] = &[
+            OAuthProviderType::Google,
+            OAuthProviderType::GitHub,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<OAuthProviderType>("OAuthProviderType", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for OAuthProviderType {
+}
+
+impl ::std::default::Default for OAuthProviderType {
+    fn default() -> Self {
+        OAuthProviderType::Google
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OAuthProviderType {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0boauth.proto\"E\n\x0fOAuthURLRequest\x120\n\x08provider\x18\x01\x20\
+    \x01(\x0e2\x12.OAuthProviderTypeR\x08providerB\0:\0\"8\n\x08OAuthURL\x12\
+    \x12\n\x03url\x18\x01\x20\x01(\tR\x03urlB\0\x12\x16\n\x05state\x18\x02\
+    \x20\x01(\tR\x05stateB\0:\0\"v\n\x12OAuthSignInRequest\x120\n\x08provide\
+    r\x18\x01\x20\x01(\x0e2\x12.OAuthProviderTypeR\x08providerB\0\x12\x14\n\
+    \x04code\x18\x02\x20\x01(\tR\x04codeB\0\x12\x16\n\x05state\x18\x03\x20\
+    \x01(\tR\x05stateB\0:\0\"\xc4\x01\n\x11OAuthSignInParams\x120\n\x08provi\
+    der\x18\x01\x20\x01(\x0e2\x12.OAuthProviderTypeR\x08providerB\0\x12\x14\
+    \n\x04code\x18\x02\x20\x01(\tR\x04codeB\0\x12#\n\x0credirect_uri\x18\x03\
+    \x20\x01(\tR\x0bredirectUriB\0\x12\x1d\n\tdevice_id\x18\x04\x20\x01(\tR\
+    \x08deviceIdB\0\x12!\n\x0bdevice_name\x18\x05\x20\x01(\tR\ndeviceNameB\0\
+    :\0*-\n\x11OAuthProviderType\x12\n\n\x06Google\x10\0\x12\n\n\x06GitHub\
+    \x10\x01\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
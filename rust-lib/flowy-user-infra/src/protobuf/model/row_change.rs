@@ -0,0 +1,497 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `row_change.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DatabaseRowChange {
+    // message fields
+    pub table: ::std::string::String,
+    pub key: ::std::string::String,
+    pub ty: RowChangeType,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DatabaseRowChange {
+    fn default() -> &'a DatabaseRowChange {
+        <DatabaseRowChange as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DatabaseRowChange {
+    pub fn new() -> DatabaseRowChange {
+        ::std::default::Default::default()
+    }
+
+    // string table = 1;
+
+
+    pub fn get_table(&self) -> &str {
+        &self.table
+    }
+    pub fn clear_table(&mut self) {
+        self.table.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_table(&mut self, v: ::std::string::String) {
+        self.table = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_table(&mut self) -> &mut ::std::string::String {
+        &mut self.table
+    }
+
+    // Take field
+    pub fn take_table(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.table, ::std::string::String::new())
+    }
+
+    // string key = 2;
+
+
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+
+    // .RowChangeType ty = 3;
+
+
+    pub fn get_ty(&self) -> RowChangeType {
+        self.ty
+    }
+    pub fn clear_ty(&mut self) {
+        self.ty = RowChangeType::Insert;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ty(&mut self, v: RowChangeType) {
+        self.ty = v;
+    }
+}
+
+impl ::protobuf::Message for DatabaseRowChange {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.table)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.ty, 3, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.table.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.table);
+        }
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.key);
+        }
+        if self.ty != RowChangeType::Insert {
+            my_size += ::protobuf::rt::enum_size(3, self.ty);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.table.is_empty() {
+            os.write_string(1, &self.table)?;
+        }
+        if !self.key.is_empty() {
+            os.write_string(2, &self.key)?;
+        }
+        if self.ty != RowChangeType::Insert {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.ty))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DatabaseRowChange {
+        DatabaseRowChange::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "table",
+                |m: &DatabaseRowChange| { &m.table },
+                |m: &mut DatabaseRowChange| { &mut m.table },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "key",
+                |m: &DatabaseRowChange| { &m.key },
+                |m: &mut DatabaseRowChange| { &mut m.key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<RowChangeType>>(
+                "ty",
+                |m: &DatabaseRowChange| { &m.ty },
+                |m: &mut DatabaseRowChange| { &mut m.ty },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DatabaseRowChange>(
+                "DatabaseRowChange",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DatabaseRowChange {
+        static instance: ::protobuf::rt::LazyV2<DatabaseRowChange> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DatabaseRowChange::new)
+    }
+}
+
+impl ::protobuf::Clear for DatabaseRowChange {
+    fn clear(&mut self) {
+        self.table.clear();
+        self.key.clear();
+        self.ty = RowChangeType::Insert;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DatabaseRowChange {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DatabaseRowChange {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedDatabaseRowChange {
+    // message fields
+    pub items: ::protobuf::RepeatedField<DatabaseRowChange>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedDatabaseRowChange {
+    fn default() -> &'a RepeatedDatabaseRowChange {
+        <RepeatedDatabaseRowChange as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedDatabaseRowChange {
+    pub fn new() -> RepeatedDatabaseRowChange {
+        ::std::default::Default::default()
+    }
+
+    // repeated .DatabaseRowChange items = 1;
+
+
+    pub fn get_items(&self) -> &[DatabaseRowChange] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<DatabaseRowChange>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<DatabaseRowChange> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<DatabaseRowChange> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedDatabaseRowChange {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedDatabaseRowChange {
+        RepeatedDatabaseRowChange::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DatabaseRowChange>>(
+                "items",
+                |m: &RepeatedDatabaseRowChange| { &m.items },
+                |m: &mut RepeatedDatabaseRowChange| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedDatabaseRowChange>(
+                "RepeatedDatabaseRowChange",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedDatabaseRowChange {
+        static instance: ::protobuf::rt::LazyV2<RepeatedDatabaseRowChange> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedDatabaseRowChange::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedDatabaseRowChange {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedDatabaseRowChange {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedDatabaseRowChange {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum RowChangeType {
+    Insert = 0,
+    Update = 1,
+    Delete = 2,
+}
+
+impl ::protobuf::ProtobufEnum for RowChangeType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<RowChangeType> {
+        match value {
+            0 => ::std::option::Option::Some(RowChangeType::Insert),
+            1 => ::std::option::Option::Some(RowChangeType::Update),
+            2 => ::std::option::Option::Some(RowChangeType::Delete),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [RowChangeType] = &[
+            RowChangeType::Insert,
+            RowChangeType::Update,
+            RowChangeType::Delete,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<RowChangeType>("RowChangeType", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for RowChangeType {
+}
+
+impl ::std::default::Default for RowChangeType {
+    fn default() -> Self {
+        RowChangeType::Insert
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RowChangeType {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x10row_change.proto\"c\n\x11DatabaseRowChange\x12\x16\n\x05table\x18\
+    \x01\x20\x01(\tR\x05tableB\0\x12\x12\n\x03key\x18\x02\x20\x01(\tR\x03key\
+    B\0\x12\x20\n\x02ty\x18\x03\x20\x01(\x0e2\x0e.RowChangeTypeR\x02tyB\0:\0\
+    \"I\n\x19RepeatedDatabaseRowChange\x12*\n\x05items\x18\x01\x20\x03(\x0b2\
+    \x12.DatabaseRowChangeR\x05itemsB\0:\0*5\n\rRowChangeType\x12\n\n\x06Ins\
+    ert\x10\0\x12\n\n\x06Update\x10\x01\x12\n\n\x06Delete\x10\x02\x1a\0B\0b\
+    \x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,881 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `session.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DeviceSession {
+    // message fields
+    pub id: ::std::string::String,
+    pub device_id: ::std::string::String,
+    pub device_name: ::std::string::String,
+    pub created_at: i64,
+    pub last_active_at: i64,
+    pub is_current: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DeviceSession {
+    fn default() -> &'a DeviceSession {
+        <DeviceSession as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeviceSession {
+    pub fn new() -> DeviceSession {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string device_id = 2;
+
+
+    pub fn get_device_id(&self) -> &str {
+        &self.device_id
+    }
+    pub fn clear_device_id(&mut self) {
+        self.device_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_device_id(&mut self, v: ::std::string::String) {
+        self.device_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_device_id(&mut self) -> &mut ::std::string::String {
+        &mut self.device_id
+    }
+
+    // Take field
+    pub fn take_device_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.device_id, ::std::string::String::new())
+    }
+
+    // string device_name = 3;
+
+
+    pub fn get_device_name(&self) -> &str {
+        &self.device_name
+    }
+    pub fn clear_device_name(&mut self) {
+        self.device_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_device_name(&mut self, v: ::std::string::String) {
+        self.device_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_device_name(&mut self) -> &mut ::std::string::String {
+        &mut self.device_name
+    }
+
+    // Take field
+    pub fn take_device_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.device_name, ::std::string::String::new())
+    }
+
+    // int64 created_at = 4;
+
+
+    pub fn get_created_at(&self) -> i64 {
+        self.created_at
+    }
+    pub fn clear_created_at(&mut self) {
+        self.created_at = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created_at(&mut self, v: i64) {
+        self.created_at = v;
+    }
+
+    // int64 last_active_at = 5;
+
+
+    pub fn get_last_active_at(&self) -> i64 {
+        self.last_active_at
+    }
+    pub fn clear_last_active_at(&mut self) {
+        self.last_active_at = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_active_at(&mut self, v: i64) {
+        self.last_active_at = v;
+    }
+
+    // bool is_current = 6;
+
+
+    pub fn get_is_current(&self) -> bool {
+        self.is_current
+    }
+    pub fn clear_is_current(&mut self) {
+        self.is_current = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_current(&mut self, v: bool) {
+        self.is_current = v;
+    }
+}
+
+impl ::protobuf::Message for DeviceSession {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.device_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.device_name)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.created_at = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.last_active_at = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_current = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.device_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.device_id);
+        }
+        if !self.device_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.device_name);
+        }
+        if self.created_at != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.created_at, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.last_active_at != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.last_active_at, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.is_current != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.device_id.is_empty() {
+            os.write_string(2, &self.device_id)?;
+        }
+        if !self.device_name.is_empty() {
+            os.write_string(3, &self.device_name)?;
+        }
+        if self.created_at != 0 {
+            os.write_int64(4, self.created_at)?;
+        }
+        if self.last_active_at != 0 {
+            os.write_int64(5, self.last_active_at)?;
+        }
+        if self.is_current != false {
+            os.write_bool(6, self.is_current)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DeviceSession {
+        DeviceSession::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &DeviceSession| { &m.id },
+                |m: &mut DeviceSession| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "device_id",
+                |m: &DeviceSession| { &m.device_id },
+                |m: &mut DeviceSession| { &mut m.device_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "device_name",
+                |m: &DeviceSession| { &m.device_name },
+                |m: &mut DeviceSession| { &mut m.device_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "created_at",
+                |m: &DeviceSession| { &m.created_at },
+                |m: &mut DeviceSession| { &mut m.created_at },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "last_active_at",
+                |m: &DeviceSession| { &m.last_active_at },
+                |m: &mut DeviceSession| { &mut m.last_active_at },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_current",
+                |m: &DeviceSession| { &m.is_current },
+                |m: &mut DeviceSession| { &mut m.is_current },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DeviceSession>(
+                "DeviceSession",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DeviceSession {
+        static instance: ::protobuf::rt::LazyV2<DeviceSession> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DeviceSession::new)
+    }
+}
+
+impl ::protobuf::Clear for DeviceSession {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.device_id.clear();
+        self.device_name.clear();
+        self.created_at = 0;
+        self.last_active_at = 0;
+        self.is_current = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DeviceSession {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeviceSession {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedDeviceSession {
+    // message fields
+    pub items: ::protobuf::RepeatedField<DeviceSession>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedDeviceSession {
+    fn default() -> &'a RepeatedDeviceSession {
+        <RepeatedDeviceSession as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedDeviceSession {
+    pub fn new() -> RepeatedDeviceSession {
+        ::std::default::Default::default()
+    }
+
+    // repeated .DeviceSession items = 1;
+
+
+    pub fn get_items(&self) -> &[DeviceSession] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<DeviceSession>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<DeviceSession> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<DeviceSession> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedDeviceSession {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedDeviceSession {
+        RepeatedDeviceSession::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DeviceSession>>(
+                "items",
+                |m: &RepeatedDeviceSession| { &m.items },
+                |m: &mut RepeatedDeviceSession| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedDeviceSession>(
+                "RepeatedDeviceSession",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedDeviceSession {
+        static instance: ::protobuf::rt::LazyV2<RepeatedDeviceSession> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedDeviceSession::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedDeviceSession {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedDeviceSession {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedDeviceSession {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RevokeSessionRequest {
+    // message fields
+    pub session_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RevokeSessionRequest {
+    fn default() -> &'a RevokeSessionRequest {
+        <RevokeSessionRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RevokeSessionRequest {
+    pub fn new() -> RevokeSessionRequest {
+        ::std::default::Default::default()
+    }
+
+    // string session_id = 1;
+
+
+    pub fn get_session_id(&self) -> &str {
+        &self.session_id
+    }
+    pub fn clear_session_id(&mut self) {
+        self.session_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_session_id(&mut self, v: ::std::string::String) {
+        self.session_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_session_id(&mut self) -> &mut ::std::string::String {
+        &mut self.session_id
+    }
+
+    // Take field
+    pub fn take_session_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.session_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RevokeSessionRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.session_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.session_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.session_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.session_id.is_empty() {
+            os.write_string(1, &self.session_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RevokeSessionRequest {
+        RevokeSessionRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "session_id",
+                |m: &RevokeSessionRequest| { &m.session_id },
+                |m: &mut RevokeSessionRequest| { &mut m.session_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RevokeSessionRequest>(
+                "RevokeSessionRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RevokeSessionRequest {
+        static instance: ::protobuf::rt::LazyV2<RevokeSessionRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RevokeSessionRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for RevokeSessionRequest {
+    fn clear(&mut self) {
+        self.session_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RevokeSessionRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RevokeSessionRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RevokeSessionParams {
+    // message fields
+    pub session_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RevokeSessionParams {
+    fn default() -> &'a RevokeSessionParams {
+        <RevokeSessionParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RevokeSessionParams {
+    pub fn new() -> RevokeSessionParams {
+        ::std::default::Default::default()
+    }
+
+    // string session_id = 1;
+
+
+    pub fn get_session_id(&self) -> &str {
+        &self.session_id
+    }
+    pub fn clear_session_id(&mut self) {
+        self.session_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_session_id(&mut self, v: ::std::string::String) {
+        self.session_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_session_id(&mut self) -> &mut ::std::string::String {
+        &mut self.session_id
+    }
+
+    // Take field
+    pub fn take_session_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.session_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RevokeSessionParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.session_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.session_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.session_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.session_id.is_empty() {
+            os.write_string(1, &self.session_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RevokeSessionParams {
+        RevokeSessionParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "session_id",
+                |m: &RevokeSessionParams| { &m.session_id },
+                |m: &mut RevokeSessionParams| { &mut m.session_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RevokeSessionParams>(
+                "RevokeSessionParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RevokeSessionParams {
+        static instance: ::protobuf::rt::LazyV2<RevokeSessionParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RevokeSessionParams::new)
+    }
+}
+
+impl ::protobuf::Clear for RevokeSessionParams {
+    fn clear(&mut self) {
+        self.session_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RevokeSessionParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RevokeSessionParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\rsession.proto\"\xcf\x01\n\rDeviceSession\x12\x10\n\x02id\x18\x01\x20\
+    \x01(\tR\x02idB\0\x12\x1d\n\tdevice_id\x18\x02\x20\x01(\tR\x08deviceIdB\
+    \0\x12!\n\x0bdevice_name\x18\x03\x20\x01(\tR\ndeviceNameB\0\x12\x1f\n\nc\
+    reated_at\x18\x04\x20\x01(\x03R\tcreatedAtB\0\x12&\n\x0elast_active_at\
+    \x18\x05\x20\x01(\x03R\x0clastActiveAtB\0\x12\x1f\n\nis_current\x18\x06\
+    \x20\x01(\x08R\tisCurrentB\0:\0\"A\n\x15RepeatedDeviceSession\x12&\n\x05\
+    items\x18\x01\x20\x03(\x0b2\x0e.DeviceSessionR\x05itemsB\0:\0\"9\n\x14Re\
+    vokeSessionRequest\x12\x1f\n\nsession_id\x18\x01\x20\x01(\tR\tsessionIdB\
+    \0:\0\"8\n\x13RevokeSessionParams\x12\x1f\n\nsession_id\x18\x01\x20\x01(\
+    \tR\tsessionIdB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
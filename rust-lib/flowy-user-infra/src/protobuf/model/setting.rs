@@ -0,0 +1,2690 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `setting.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AppearanceSetting {
+    // message fields
+    pub theme: ThemeMode,
+    pub locale: ::std::string::String,
+    pub text_scale: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AppearanceSetting {
+    fn default() -> &'a AppearanceSetting {
+        <AppearanceSetting as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AppearanceSetting {
+    pub fn new() -> AppearanceSetting {
+        ::std::default::Default::default()
+    }
+
+    // .ThemeMode theme = 1;
+
+
+    pub fn get_theme(&self) -> ThemeMode {
+        self.theme
+    }
+    pub fn clear_theme(&mut self) {
+        self.theme = ThemeMode::System;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_theme(&mut self, v: ThemeMode) {
+        self.theme = v;
+    }
+
+    // string locale = 2;
+
+
+    pub fn get_locale(&self) -> &str {
+        &self.locale
+    }
+    pub fn clear_locale(&mut self) {
+        self.locale.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_locale(&mut self, v: ::std::string::String) {
+        self.locale = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_locale(&mut self) -> &mut ::std::string::String {
+        &mut self.locale
+    }
+
+    // Take field
+    pub fn take_locale(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.locale, ::std::string::String::new())
+    }
+
+    // int32 text_scale = 3;
+
+
+    pub fn get_text_scale(&self) -> i32 {
+        self.text_scale
+    }
+    pub fn clear_text_scale(&mut self) {
+        self.text_scale = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_text_scale(&mut self, v: i32) {
+        self.text_scale = v;
+    }
+}
+
+impl ::protobuf::Message for AppearanceSetting {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.theme, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.locale)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.text_scale = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.theme != ThemeMode::System {
+            my_size += ::protobuf::rt::enum_size(1, self.theme);
+        }
+        if !self.locale.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.locale);
+        }
+        if self.text_scale != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.text_scale, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.theme != ThemeMode::System {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.theme))?;
+        }
+        if !self.locale.is_empty() {
+            os.write_string(2, &self.locale)?;
+        }
+        if self.text_scale != 0 {
+            os.write_int32(3, self.text_scale)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AppearanceSetting {
+        AppearanceSetting::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ThemeMode>>(
+                "theme",
+                |m: &AppearanceSetting| { &m.theme },
+                |m: &mut AppearanceSetting| { &mut m.theme },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "locale",
+                |m: &AppearanceSetting| { &m.locale },
+                |m: &mut AppearanceSetting| { &mut m.locale },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "text_scale",
+                |m: &AppearanceSetting| { &m.text_scale },
+                |m: &mut AppearanceSetting| { &mut m.text_scale },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AppearanceSetting>(
+                "AppearanceSetting",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AppearanceSetting {
+        static instance: ::protobuf::rt::LazyV2<AppearanceSetting> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AppearanceSetting::new)
+    }
+}
+
+impl ::protobuf::Clear for AppearanceSetting {
+    fn clear(&mut self) {
+        self.theme = ThemeMode::System;
+        self.locale.clear();
+        self.text_scale = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AppearanceSetting {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AppearanceSetting {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct EditorSetting {
+    // message fields
+    pub font_size: i32,
+    pub spell_check: bool,
+    pub tab_size: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a EditorSetting {
+    fn default() -> &'a EditorSetting {
+        <EditorSetting as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl EditorSetting {
+    pub fn new() -> EditorSetting {
+        ::std::default::Default::default()
+    }
+
+    // int32 font_size = 1;
+
+
+    pub fn get_font_size(&self) -> i32 {
+        self.font_size
+    }
+    pub fn clear_font_size(&mut self) {
+        self.font_size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_font_size(&mut self, v: i32) {
+        self.font_size = v;
+    }
+
+    // bool spell_check = 2;
+
+
+    pub fn get_spell_check(&self) -> bool {
+        self.spell_check
+    }
+    pub fn clear_spell_check(&mut self) {
+        self.spell_check = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_spell_check(&mut self, v: bool) {
+        self.spell_check = v;
+    }
+
+    // int32 tab_size = 3;
+
+
+    pub fn get_tab_size(&self) -> i32 {
+        self.tab_size
+    }
+    pub fn clear_tab_size(&mut self) {
+        self.tab_size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tab_size(&mut self, v: i32) {
+        self.tab_size = v;
+    }
+}
+
+impl ::protobuf::Message for EditorSetting {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.font_size = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.spell_check = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.tab_size = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.font_size != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.font_size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.spell_check != false {
+            my_size += 2;
+        }
+        if self.tab_size != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.tab_size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.font_size != 0 {
+            os.write_int32(1, self.font_size)?;
+        }
+        if self.spell_check != false {
+            os.write_bool(2, self.spell_check)?;
+        }
+        if self.tab_size != 0 {
+            os.write_int32(3, self.tab_size)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> EditorSetting {
+        EditorSetting::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "font_size",
+                |m: &EditorSetting| { &m.font_size },
+                |m: &mut EditorSetting| { &mut m.font_size },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "spell_check",
+                |m: &EditorSetting| { &m.spell_check },
+                |m: &mut EditorSetting| { &mut m.spell_check },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "tab_size",
+                |m: &EditorSetting| { &m.tab_size },
+                |m: &mut EditorSetting| { &mut m.tab_size },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<EditorSetting>(
+                "EditorSetting",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static EditorSetting {
+        static instance: ::protobuf::rt::LazyV2<EditorSetting> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(EditorSetting::new)
+    }
+}
+
+impl ::protobuf::Clear for EditorSetting {
+    fn clear(&mut self) {
+        self.font_size = 0;
+        self.spell_check = false;
+        self.tab_size = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for EditorSetting {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EditorSetting {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SyncSetting {
+    // message fields
+    pub enabled: bool,
+    pub interval_secs: i64,
+    pub wifi_only: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SyncSetting {
+    fn default() -> &'a SyncSetting {
+        <SyncSetting as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SyncSetting {
+    pub fn new() -> SyncSetting {
+        ::std::default::Default::default()
+    }
+
+    // bool enabled = 1;
+
+
+    pub fn get_enabled(&self) -> bool {
+        self.enabled
+    }
+    pub fn clear_enabled(&mut self) {
+        self.enabled = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enabled(&mut self, v: bool) {
+        self.enabled = v;
+    }
+
+    // int64 interval_secs = 2;
+
+
+    pub fn get_interval_secs(&self) -> i64 {
+        self.interval_secs
+    }
+    pub fn clear_interval_secs(&mut self) {
+        self.interval_secs = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_interval_secs(&mut self, v: i64) {
+        self.interval_secs = v;
+    }
+
+    // bool wifi_only = 3;
+
+
+    pub fn get_wifi_only(&self) -> bool {
+        self.wifi_only
+    }
+    pub fn clear_wifi_only(&mut self) {
+        self.wifi_only = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_wifi_only(&mut self, v: bool) {
+        self.wifi_only = v;
+    }
+}
+
+impl ::protobuf::Message for SyncSetting {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.enabled = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.interval_secs = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.wifi_only = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.enabled != false {
+            my_size += 2;
+        }
+        if self.interval_secs != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.interval_secs, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.wifi_only != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.enabled != false {
+            os.write_bool(1, self.enabled)?;
+        }
+        if self.interval_secs != 0 {
+            os.write_int64(2, self.interval_secs)?;
+        }
+        if self.wifi_only != false {
+            os.write_bool(3, self.wifi_only)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SyncSetting {
+        SyncSetting::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "enabled",
+                |m: &SyncSetting| { &m.enabled },
+                |m: &mut SyncSetting| { &mut m.enabled },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "interval_secs",
+                |m: &SyncSetting| { &m.interval_secs },
+                |m: &mut SyncSetting| { &mut m.interval_secs },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "wifi_only",
+                |m: &SyncSetting| { &m.wifi_only },
+                |m: &mut SyncSetting| { &mut m.wifi_only },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SyncSetting>(
+                "SyncSetting",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SyncSetting {
+        static instance: ::protobuf::rt::LazyV2<SyncSetting> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SyncSetting::new)
+    }
+}
+
+impl ::protobuf::Clear for SyncSetting {
+    fn clear(&mut self) {
+        self.enabled = false;
+        self.interval_secs = 0;
+        self.wifi_only = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SyncSetting {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyncSetting {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Shortcut {
+    // message fields
+    pub action: ::std::string::String,
+    pub keys: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Shortcut {
+    fn default() -> &'a Shortcut {
+        <Shortcut as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Shortcut {
+    pub fn new() -> Shortcut {
+        ::std::default::Default::default()
+    }
+
+    // string action = 1;
+
+
+    pub fn get_action(&self) -> &str {
+        &self.action
+    }
+    pub fn clear_action(&mut self) {
+        self.action.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_action(&mut self, v: ::std::string::String) {
+        self.action = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_action(&mut self) -> &mut ::std::string::String {
+        &mut self.action
+    }
+
+    // Take field
+    pub fn take_action(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.action, ::std::string::String::new())
+    }
+
+    // string keys = 2;
+
+
+    pub fn get_keys(&self) -> &str {
+        &self.keys
+    }
+    pub fn clear_keys(&mut self) {
+        self.keys.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_keys(&mut self, v: ::std::string::String) {
+        self.keys = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_keys(&mut self) -> &mut ::std::string::String {
+        &mut self.keys
+    }
+
+    // Take field
+    pub fn take_keys(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.keys, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Shortcut {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.action)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.keys)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.action.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.action);
+        }
+        if !self.keys.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.keys);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.action.is_empty() {
+            os.write_string(1, &self.action)?;
+        }
+        if !self.keys.is_empty() {
+            os.write_string(2, &self.keys)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Shortcut {
+        Shortcut::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "action",
+                |m: &Shortcut| { &m.action },
+                |m: &mut Shortcut| { &mut m.action },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "keys",
+                |m: &Shortcut| { &m.keys },
+                |m: &mut Shortcut| { &mut m.keys },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Shortcut>(
+                "Shortcut",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Shortcut {
+        static instance: ::protobuf::rt::LazyV2<Shortcut> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Shortcut::new)
+    }
+}
+
+impl ::protobuf::Clear for Shortcut {
+    fn clear(&mut self) {
+        self.action.clear();
+        self.keys.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Shortcut {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Shortcut {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ShortcutSetting {
+    // message fields
+    pub items: ::protobuf::RepeatedField<Shortcut>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ShortcutSetting {
+    fn default() -> &'a ShortcutSetting {
+        <ShortcutSetting as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ShortcutSetting {
+    pub fn new() -> ShortcutSetting {
+        ::std::default::Default::default()
+    }
+
+    // repeated .Shortcut items = 1;
+
+
+    pub fn get_items(&self) -> &[Shortcut] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<Shortcut>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<Shortcut> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<Shortcut> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for ShortcutSetting {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ShortcutSetting {
+        ShortcutSetting::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Shortcut>>(
+                "items",
+                |m: &ShortcutSetting| { &m.items },
+                |m: &mut ShortcutSetting| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ShortcutSetting>(
+                "ShortcutSetting",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ShortcutSetting {
+        static instance: ::protobuf::rt::LazyV2<ShortcutSetting> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ShortcutSetting::new)
+    }
+}
+
+impl ::protobuf::Clear for ShortcutSetting {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ShortcutSetting {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ShortcutSetting {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PrivacySetting {
+    // message fields
+    pub telemetry: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PrivacySetting {
+    fn default() -> &'a PrivacySetting {
+        <PrivacySetting as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PrivacySetting {
+    pub fn new() -> PrivacySetting {
+        ::std::default::Default::default()
+    }
+
+    // bool telemetry = 1;
+
+
+    pub fn get_telemetry(&self) -> bool {
+        self.telemetry
+    }
+    pub fn clear_telemetry(&mut self) {
+        self.telemetry = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_telemetry(&mut self, v: bool) {
+        self.telemetry = v;
+    }
+}
+
+impl ::protobuf::Message for PrivacySetting {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.telemetry = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.telemetry != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.telemetry != false {
+            os.write_bool(1, self.telemetry)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PrivacySetting {
+        PrivacySetting::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "telemetry",
+                |m: &PrivacySetting| { &m.telemetry },
+                |m: &mut PrivacySetting| { &mut m.telemetry },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PrivacySetting>(
+                "PrivacySetting",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PrivacySetting {
+        static instance: ::protobuf::rt::LazyV2<PrivacySetting> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PrivacySetting::new)
+    }
+}
+
+impl ::protobuf::Clear for PrivacySetting {
+    fn clear(&mut self) {
+        self.telemetry = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PrivacySetting {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PrivacySetting {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AppSetting {
+    // message fields
+    pub appearance: ::protobuf::SingularPtrField<AppearanceSetting>,
+    pub editor: ::protobuf::SingularPtrField<EditorSetting>,
+    pub sync: ::protobuf::SingularPtrField<SyncSetting>,
+    pub shortcuts: ::protobuf::SingularPtrField<ShortcutSetting>,
+    pub privacy: ::protobuf::SingularPtrField<PrivacySetting>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AppSetting {
+    fn default() -> &'a AppSetting {
+        <AppSetting as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AppSetting {
+    pub fn new() -> AppSetting {
+        ::std::default::Default::default()
+    }
+
+    // .AppearanceSetting appearance = 1;
+
+
+    pub fn get_appearance(&self) -> &AppearanceSetting {
+        self.appearance.as_ref().unwrap_or_else(|| <AppearanceSetting as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_appearance(&mut self) {
+        self.appearance.clear();
+    }
+
+    pub fn has_appearance(&self) -> bool {
+        self.appearance.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_appearance(&mut self, v: AppearanceSetting) {
+        self.appearance = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_appearance(&mut self) -> &mut AppearanceSetting {
+        if self.appearance.is_none() {
+            self.appearance.set_default();
+        }
+        self.appearance.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_appearance(&mut self) -> AppearanceSetting {
+        self.appearance.take().unwrap_or_else(|| AppearanceSetting::new())
+    }
+
+    // .EditorSetting editor = 2;
+
+
+    pub fn get_editor(&self) -> &EditorSetting {
+        self.editor.as_ref().unwrap_or_else(|| <EditorSetting as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_editor(&mut self) {
+        self.editor.clear();
+    }
+
+    pub fn has_editor(&self) -> bool {
+        self.editor.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_editor(&mut self, v: EditorSetting) {
+        self.editor = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_editor(&mut self) -> &mut EditorSetting {
+        if self.editor.is_none() {
+            self.editor.set_default();
+        }
+        self.editor.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_editor(&mut self) -> EditorSetting {
+        self.editor.take().unwrap_or_else(|| EditorSetting::new())
+    }
+
+    // .SyncSetting sync = 3;
+
+
+    pub fn get_sync(&self) -> &SyncSetting {
+        self.sync.as_ref().unwrap_or_else(|| <SyncSetting as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_sync(&mut self) {
+        self.sync.clear();
+    }
+
+    pub fn has_sync(&self) -> bool {
+        self.sync.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sync(&mut self, v: SyncSetting) {
+        self.sync = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sync(&mut self) -> &mut SyncSetting {
+        if self.sync.is_none() {
+            self.sync.set_default();
+        }
+        self.sync.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_sync(&mut self) -> SyncSetting {
+        self.sync.take().unwrap_or_else(|| SyncSetting::new())
+    }
+
+    // .ShortcutSetting shortcuts = 4;
+
+
+    pub fn get_shortcuts(&self) -> &ShortcutSetting {
+        self.shortcuts.as_ref().unwrap_or_else(|| <ShortcutSetting as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_shortcuts(&mut self) {
+        self.shortcuts.clear();
+    }
+
+    pub fn has_shortcuts(&self) -> bool {
+        self.shortcuts.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shortcuts(&mut self, v: ShortcutSetting) {
+        self.shortcuts = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_shortcuts(&mut self) -> &mut ShortcutSetting {
+        if self.shortcuts.is_none() {
+            self.shortcuts.set_default();
+        }
+        self.shortcuts.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_shortcuts(&mut self) -> ShortcutSetting {
+        self.shortcuts.take().unwrap_or_else(|| ShortcutSetting::new())
+    }
+
+    // .PrivacySetting privacy = 5;
+
+
+    pub fn get_privacy(&self) -> &PrivacySetting {
+        self.privacy.as_ref().unwrap_or_else(|| <PrivacySetting as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_privacy(&mut self) {
+        self.privacy.clear();
+    }
+
+    pub fn has_privacy(&self) -> bool {
+        self.privacy.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_privacy(&mut self, v: PrivacySetting) {
+        self.privacy = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_privacy(&mut self) -> &mut PrivacySetting {
+        if self.privacy.is_none() {
+            self.privacy.set_default();
+        }
+        self.privacy.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_privacy(&mut self) -> PrivacySetting {
+        self.privacy.take().unwrap_or_else(|| PrivacySetting::new())
+    }
+}
+
+impl ::protobuf::Message for AppSetting {
+    fn is_initialized(&self) -> bool {
+        for v in &self.appearance {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.editor {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.sync {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.shortcuts {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.privacy {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.appearance)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.editor)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.sync)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.shortcuts)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.privacy)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.appearance.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.editor.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.sync.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.shortcuts.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.privacy.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.appearance.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.editor.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.sync.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.shortcuts.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.privacy.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AppSetting {
+        AppSetting::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AppearanceSetting>>(
+                "appearance",
+                |m: &AppSetting| { &m.appearance },
+                |m: &mut AppSetting| { &mut m.appearance },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<EditorSetting>>(
+                "editor",
+                |m: &AppSetting| { &m.editor },
+                |m: &mut AppSetting| { &mut m.editor },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SyncSetting>>(
+                "sync",
+                |m: &AppSetting| { &m.sync },
+                |m: &mut AppSetting| { &mut m.sync },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ShortcutSetting>>(
+                "shortcuts",
+                |m: &AppSetting| { &m.shortcuts },
+                |m: &mut AppSetting| { &mut m.shortcuts },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PrivacySetting>>(
+                "privacy",
+                |m: &AppSetting| { &m.privacy },
+                |m: &mut AppSetting| { &mut m.privacy },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AppSetting>(
+                "AppSetting",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AppSetting {
+        static instance: ::protobuf::rt::LazyV2<AppSetting> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AppSetting::new)
+    }
+}
+
+impl ::protobuf::Clear for AppSetting {
+    fn clear(&mut self) {
+        self.appearance.clear();
+        self.editor.clear();
+        self.sync.clear();
+        self.shortcuts.clear();
+        self.privacy.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AppSetting {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AppSetting {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateSettingRequest {
+    // message oneof groups
+    pub one_of_appearance: ::std::option::Option<UpdateSettingRequest_oneof_one_of_appearance>,
+    pub one_of_editor: ::std::option::Option<UpdateSettingRequest_oneof_one_of_editor>,
+    pub one_of_sync: ::std::option::Option<UpdateSettingRequest_oneof_one_of_sync>,
+    pub one_of_shortcuts: ::std::option::Option<UpdateSettingRequest_oneof_one_of_shortcuts>,
+    pub one_of_privacy: ::std::option::Option<UpdateSettingRequest_oneof_one_of_privacy>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateSettingRequest {
+    fn default() -> &'a UpdateSettingRequest {
+        <UpdateSettingRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateSettingRequest_oneof_one_of_appearance {
+    appearance(AppearanceSetting),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateSettingRequest_oneof_one_of_editor {
+    editor(EditorSetting),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateSettingRequest_oneof_one_of_sync {
+    sync(SyncSetting),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateSettingRequest_oneof_one_of_shortcuts {
+    shortcuts(ShortcutSetting),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateSettingRequest_oneof_one_of_privacy {
+    privacy(PrivacySetting),
+}
+
+impl UpdateSettingRequest {
+    pub fn new() -> UpdateSettingRequest {
+        ::std::default::Default::default()
+    }
+
+    // .AppearanceSetting appearance = 1;
+
+
+    pub fn get_appearance(&self) -> &AppearanceSetting {
+        match self.one_of_appearance {
+            ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_appearance::appearance(ref v)) => v,
+            _ => <AppearanceSetting as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_appearance(&mut self) {
+        self.one_of_appearance = ::std::option::Option::None;
+    }
+
+    pub fn has_appearance(&self) -> bool {
+        match self.one_of_appearance {
+            ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_appearance::appearance(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_appearance(&mut self, v: AppearanceSetting) {
+        self.one_of_appearance = ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_appearance::appearance(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_appearance(&mut self) -> &mut AppearanceSetting {
+        if let ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_appearance::appearance(_)) = self.one_of_appearance {
+        } else {
+            self.one_of_appearance = ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_appearance::appearance(AppearanceSetting::new()));
+        }
+        match self.one_of_appearance {
+            ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_appearance::appearance(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_appearance(&mut self) -> AppearanceSetting {
+        if self.has_appearance() {
+            match self.one_of_appearance.take() {
+                ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_appearance::appearance(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            AppearanceSetting::new()
+        }
+    }
+
+    // .EditorSetting editor = 2;
+
+
+    pub fn get_editor(&self) -> &EditorSetting {
+        match self.one_of_editor {
+            ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_editor::editor(ref v)) => v,
+            _ => <EditorSetting as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_editor(&mut self) {
+        self.one_of_editor = ::std::option::Option::None;
+    }
+
+    pub fn has_editor(&self) -> bool {
+        match self.one_of_editor {
+            ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_editor::editor(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_editor(&mut self, v: EditorSetting) {
+        self.one_of_editor = ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_editor::editor(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_editor(&mut self) -> &mut EditorSetting {
+        if let ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_editor::editor(_)) = self.one_of_editor {
+        } else {
+            self.one_of_editor = ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_editor::editor(EditorSetting::new()));
+        }
+        match self.one_of_editor {
+            ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_editor::editor(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_editor(&mut self) -> EditorSetting {
+        if self.has_editor() {
+            match self.one_of_editor.take() {
+                ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_editor::editor(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            EditorSetting::new()
+        }
+    }
+
+    // .SyncSetting sync = 3;
+
+
+    pub fn get_sync(&self) -> &SyncSetting {
+        match self.one_of_sync {
+            ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_sync::sync(ref v)) => v,
+            _ => <SyncSetting as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_sync(&mut self) {
+        self.one_of_sync = ::std::option::Option::None;
+    }
+
+    pub fn has_sync(&self) -> bool {
+        match self.one_of_sync {
+            ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_sync::sync(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sync(&mut self, v: SyncSetting) {
+        self.one_of_sync = ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_sync::sync(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_sync(&mut self) -> &mut SyncSetting {
+        if let ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_sync::sync(_)) = self.one_of_sync {
+        } else {
+            self.one_of_sync = ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_sync::sync(SyncSetting::new()));
+        }
+        match self.one_of_sync {
+            ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_sync::sync(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_sync(&mut self) -> SyncSetting {
+        if self.has_sync() {
+            match self.one_of_sync.take() {
+                ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_sync::sync(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SyncSetting::new()
+        }
+    }
+
+    // .ShortcutSetting shortcuts = 4;
+
+
+    pub fn get_shortcuts(&self) -> &ShortcutSetting {
+        match self.one_of_shortcuts {
+            ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_shortcuts::shortcuts(ref v)) => v,
+            _ => <ShortcutSetting as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_shortcuts(&mut self) {
+        self.one_of_shortcuts = ::std::option::Option::None;
+    }
+
+    pub fn has_shortcuts(&self) -> bool {
+        match self.one_of_shortcuts {
+            ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_shortcuts::shortcuts(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shortcuts(&mut self, v: ShortcutSetting) {
+        self.one_of_shortcuts = ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_shortcuts::shortcuts(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_shortcuts(&mut self) -> &mut ShortcutSetting {
+        if let ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_shortcuts::shortcuts(_)) = self.one_of_shortcuts {
+        } else {
+            self.one_of_shortcuts = ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_shortcuts::shortcuts(ShortcutSetting::new()));
+        }
+        match self.one_of_shortcuts {
+            ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_shortcuts::shortcuts(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_shortcuts(&mut self) -> ShortcutSetting {
+        if self.has_shortcuts() {
+            match self.one_of_shortcuts.take() {
+                ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_shortcuts::shortcuts(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ShortcutSetting::new()
+        }
+    }
+
+    // .PrivacySetting privacy = 5;
+
+
+    pub fn get_privacy(&self) -> &PrivacySetting {
+        match self.one_of_privacy {
+            ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_privacy::privacy(ref v)) => v,
+            _ => <PrivacySetting as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_privacy(&mut self) {
+        self.one_of_privacy = ::std::option::Option::None;
+    }
+
+    pub fn has_privacy(&self) -> bool {
+        match self.one_of_privacy {
+            ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_privacy::privacy(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_privacy(&mut self, v: PrivacySetting) {
+        self.one_of_privacy = ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_privacy::privacy(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_privacy(&mut self) -> &mut PrivacySetting {
+        if let ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_privacy::privacy(_)) = self.one_of_privacy {
+        } else {
+            self.one_of_privacy = ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_privacy::privacy(PrivacySetting::new()));
+        }
+        match self.one_of_privacy {
+            ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_privacy::privacy(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_privacy(&mut self) -> PrivacySetting {
+        if self.has_privacy() {
+            match self.one_of_privacy.take() {
+                ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_privacy::privacy(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            PrivacySetting::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for UpdateSettingRequest {
+    fn is_initialized(&self) -> bool {
+        if let Some(UpdateSettingRequest_oneof_one_of_appearance::appearance(ref v)) = self.one_of_appearance {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(UpdateSettingRequest_oneof_one_of_editor::editor(ref v)) = self.one_of_editor {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(UpdateSettingRequest_oneof_one_of_sync::sync(ref v)) = self.one_of_sync {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(UpdateSettingRequest_oneof_one_of_shortcuts::shortcuts(ref v)) = self.one_of_shortcuts {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(UpdateSettingRequest_oneof_one_of_privacy::privacy(ref v)) = self.one_of_privacy {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_appearance = ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_appearance::appearance(is.read_message()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_editor = ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_editor::editor(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_sync = ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_sync::sync(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_shortcuts = ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_shortcuts::shortcuts(is.read_message()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_privacy = ::std::option::Option::Some(UpdateSettingRequest_oneof_one_of_privacy::privacy(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.one_of_appearance {
+            match v {
+                &UpdateSettingRequest_oneof_one_of_appearance::appearance(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_editor {
+            match v {
+                &UpdateSettingRequest_oneof_one_of_editor::editor(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_sync {
+            match v {
+                &UpdateSettingRequest_oneof_one_of_sync::sync(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_shortcuts {
+            match v {
+                &UpdateSettingRequest_oneof_one_of_shortcuts::shortcuts(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_privacy {
+            match v {
+                &UpdateSettingRequest_oneof_one_of_privacy::privacy(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.one_of_appearance {
+            match v {
+                &UpdateSettingRequest_oneof_one_of_appearance::appearance(ref v) => {
+                    os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_editor {
+            match v {
+                &UpdateSettingRequest_oneof_one_of_editor::editor(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_sync {
+            match v {
+                &UpdateSettingRequest_oneof_one_of_sync::sync(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_shortcuts {
+            match v {
+                &UpdateSettingRequest_oneof_one_of_shortcuts::shortcuts(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_privacy {
+            match v {
+                &UpdateSettingRequest_oneof_one_of_privacy::privacy(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateSettingRequest {
+        UpdateSettingRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, AppearanceSetting>(
+                "appearance",
+                UpdateSettingRequest::has_appearance,
+                UpdateSettingRequest::get_appearance,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, EditorSetting>(
+                "editor",
+                UpdateSettingRequest::has_editor,
+                UpdateSettingRequest::get_editor,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, SyncSetting>(
+                "sync",
+                UpdateSettingRequest::has_sync,
+                UpdateSettingRequest::get_sync,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ShortcutSetting>(
+                "shortcuts",
+                UpdateSettingRequest::has_shortcuts,
+                UpdateSettingRequest::get_shortcuts,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, PrivacySetting>(
+                "privacy",
+                UpdateSettingRequest::has_privacy,
+                UpdateSettingRequest::get_privacy,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateSettingRequest>(
+                "UpdateSettingRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateSettingRequest {
+        static instance: ::protobuf::rt::LazyV2<UpdateSettingRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateSettingRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateSettingRequest {
+    fn clear(&mut self) {
+        self.one_of_appearance = ::std::option::Option::None;
+        self.one_of_editor = ::std::option::Option::None;
+        self.one_of_sync = ::std::option::Option::None;
+        self.one_of_shortcuts = ::std::option::Option::None;
+        self.one_of_privacy = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateSettingRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateSettingRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ResetSettingRequest {
+    // message fields
+    pub section: SettingSection,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ResetSettingRequest {
+    fn default() -> &'a ResetSettingRequest {
+        <ResetSettingRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ResetSettingRequest {
+    pub fn new() -> ResetSettingRequest {
+        ::std::default::Default::default()
+    }
+
+    // .SettingSection section = 1;
+
+
+    pub fn get_section(&self) -> SettingSection {
+        self.section
+    }
+    pub fn clear_section(&mut self) {
+        self.section = SettingSection::Appearance;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_section(&mut self, v: SettingSection) {
+        self.section = v;
+    }
+}
+
+impl ::protobuf::Message for ResetSettingRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.section, 1, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.section != SettingSection::Appearance {
+            my_size += ::protobuf::rt::enum_size(1, self.section);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.section != SettingSection::Appearance {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.section))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ResetSettingRequest {
+        ResetSettingRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<SettingSection>>(
+                "section",
+                |m: &ResetSettingRequest| { &m.section },
+                |m: &mut ResetSettingRequest| { &mut m.section },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ResetSettingRequest>(
+                "ResetSettingRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ResetSettingRequest {
+        static instance: ::protobuf::rt::LazyV2<ResetSettingRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ResetSettingRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ResetSettingRequest {
+    fn clear(&mut self) {
+        self.section = SettingSection::Appearance;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ResetSettingRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ResetSettingRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SettingChange {
+    // message fields
+    pub section: SettingSection,
+    pub setting: ::protobuf::SingularPtrField<AppSetting>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SettingChange {
+    fn default() -> &'a SettingChange {
+        <SettingChange as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SettingChange {
+    pub fn new() -> SettingChange {
+        ::std::default::Default::default()
+    }
+
+    // .SettingSection section = 1;
+
+
+    pub fn get_section(&self) -> SettingSection {
+        self.section
+    }
+    pub fn clear_section(&mut self) {
+        self.section = SettingSection::Appearance;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_section(&mut self, v: SettingSection) {
+        self.section = v;
+    }
+
+    // .AppSetting setting = 2;
+
+
+    pub fn get_setting(&self) -> &AppSetting {
+        self.setting.as_ref().unwrap_or_else(|| <AppSetting as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_setting(&mut self) {
+        self.setting.clear();
+    }
+
+    pub fn has_setting(&self) -> bool {
+        self.setting.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_setting(&mut self, v: AppSetting) {
+        self.setting = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_setting(&mut self) -> &mut AppSetting {
+        if self.setting.is_none() {
+            self.setting.set_default();
+        }
+        self.setting.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_setting(&mut self) -> AppSetting {
+        self.setting.take().unwrap_or_else(|| AppSetting::new())
+    }
+}
+
+impl ::protobuf::Message for SettingChange {
+    fn is_initialized(&self) -> bool {
+        for v in &self.setting {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.section, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.setting)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.section != SettingSection::Appearance {
+            my_size += ::protobuf::rt::enum_size(1, self.section);
+        }
+        if let Some(ref v) = self.setting.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.section != SettingSection::Appearance {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.section))?;
+        }
+        if let Some(ref v) = self.setting.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SettingChange {
+        SettingChange::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<SettingSection>>(
+                "section",
+                |m: &SettingChange| { &m.section },
+                |m: &mut SettingChange| { &mut m.section },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AppSetting>>(
+                "setting",
+                |m: &SettingChange| { &m.setting },
+                |m: &mut SettingChange| { &mut m.setting },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SettingChange>(
+                "SettingChange",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SettingChange {
+        static instance: ::protobuf::rt::LazyV2<SettingChange> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SettingChange::new)
+    }
+}
+
+impl ::protobuf::Clear for SettingChange {
+    fn clear(&mut self) {
+        self.section = SettingSection::Appearance;
+        self.setting.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SettingChange {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SettingChange {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum SettingSection {
+    Appearance = 0,
+    Editor = 1,
+    Sync = 2,
+    Shortcuts = 3,
+    Privacy = 4,
+}
+
+impl ::protobuf::ProtobufEnum for SettingSection {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<SettingSection> {
+        match value {
+            0 => ::std::option::Option::Some(SettingSection::Appearance),
+            1 => ::std::option::Option::Some(SettingSection::Editor),
+            2 => ::std::option::Option::Some(SettingSection::Sync),
+            3 => ::std::option::Option::Some(SettingSection::Shortcuts),
+            4 => ::std::option::Option::Some(SettingSection::Privacy),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [SettingSection] = &[
+            SettingSection::Appearance,
+            SettingSection::Editor,
+            SettingSection::Sync,
+            SettingSection::Shortcuts,
+            SettingSection::Privacy,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<SettingSection>("SettingSection", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for SettingSection {
+}
+
+impl ::std::default::Default for SettingSection {
+    fn default() -> Self {
+        SettingSection::Appearance
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SettingSection {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum ThemeMode {
+    System = 0,
+    Light = 1,
+    Dark = 2,
+}
+
+impl ::protobuf::ProtobufEnum for ThemeMode {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ThemeMode> {
+        match value {
+            0 => ::std::option::Option::Some(ThemeMode::System),
+            1 => ::std::option::Option::Some(ThemeMode::Light),
+            2 => ::std::option::Option::Some(ThemeMode::Dark),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [ThemeMode] = &[
+            ThemeMode::System,
+            ThemeMode::Light,
+            ThemeMode::Dark,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<ThemeMode>("ThemeMode", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for ThemeMode {
+}
+
+impl ::std::default::Default for ThemeMode {
+    fn default() -> Self {
+        ThemeMode::System
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ThemeMode {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\rsetting.proto\"t\n\x11AppearanceSetting\x12\"\n\x05theme\x18\x01\x20\
+    \x01(\x0e2\n.ThemeModeR\x05themeB\0\x12\x18\n\x06locale\x18\x02\x20\x01(\
+    \tR\x06localeB\0\x12\x1f\n\ntext_scale\x18\x03\x20\x01(\x05R\ttextScaleB\
+    \0:\0\"p\n\rEditorSetting\x12\x1d\n\tfont_size\x18\x01\x20\x01(\x05R\x08\
+    fontSizeB\0\x12!\n\x0bspell_check\x18\x02\x20\x01(\x08R\nspellCheckB\0\
+    \x12\x1b\n\x08tab_size\x18\x03\x20\x01(\x05R\x07tabSizeB\0:\0\"q\n\x0bSy\
+    ncSetting\x12\x1a\n\x07enabled\x18\x01\x20\x01(\x08R\x07enabledB\0\x12%\
+    \n\rinterval_secs\x18\x02\x20\x01(\x03R\x0cintervalSecsB\0\x12\x1d\n\twi\
+    fi_only\x18\x03\x20\x01(\x08R\x08wifiOnlyB\0:\0\"<\n\x08Shortcut\x12\x18\
+    \n\x06action\x18\x01\x20\x01(\tR\x06actionB\0\x12\x14\n\x04keys\x18\x02\
+    \x20\x01(\tR\x04keysB\0:\0\"6\n\x0fShortcutSetting\x12!\n\x05items\x18\
+    \x01\x20\x03(\x0b2\t.ShortcutR\x05itemsB\0:\0\"2\n\x0ePrivacySetting\x12\
+    \x1e\n\ttelemetry\x18\x01\x20\x01(\x08R\ttelemetryB\0:\0\"\xf1\x01\n\nAp\
+    pSetting\x124\n\nappearance\x18\x01\x20\x01(\x0b2\x12.AppearanceSettingR\
+    \nappearanceB\0\x12(\n\x06editor\x18\x02\x20\x01(\x0b2\x0e.EditorSetting\
+    R\x06editorB\0\x12\"\n\x04sync\x18\x03\x20\x01(\x0b2\x0c.SyncSettingR\
+    \x04syncB\0\x120\n\tshortcuts\x18\x04\x20\x01(\x0b2\x10.ShortcutSettingR\
+    \tshortcutsB\0\x12+\n\x07privacy\x18\x05\x20\x01(\x0b2\x0f.PrivacySettin\
+    gR\x07privacyB\0:\0\"\xe0\x02\n\x14UpdateSettingRequest\x126\n\nappearan\
+    ce\x18\x01\x20\x01(\x0b2\x12.AppearanceSettingH\0R\nappearanceB\0\x12*\n\
+    \x06editor\x18\x02\x20\x01(\x0b2\x0e.EditorSettingH\x01R\x06editorB\0\
+    \x12$\n\x04sync\x18\x03\x20\x01(\x0b2\x0c.SyncSettingH\x02R\x04syncB\0\
+    \x122\n\tshortcuts\x18\x04\x20\x01(\x0b2\x10.ShortcutSettingH\x03R\tshor\
+    tcutsB\0\x12-\n\x07privacy\x18\x05\x20\x01(\x0b2\x0f.PrivacySettingH\x04\
+    R\x07privacyB\0B\x13\n\x11one_of_appearanceB\x0f\n\rone_of_editorB\r\n\
+    \x0bone_of_syncB\x12\n\x10one_of_shortcutsB\x10\n\x0eone_of_privacy:\0\"\
+    D\n\x13ResetSettingRequest\x12+\n\x07section\x18\x01\x20\x01(\x0e2\x0f.S\
+    ettingSectionR\x07sectionB\0:\0\"g\n\rSettingChange\x12+\n\x07section\
+    \x18\x01\x20\x01(\x0e2\x0f.SettingSectionR\x07sectionB\0\x12'\n\x07setti\
+    ng\x18\x02\x20\x01(\x0b2\x0b.AppSettingR\x07settingB\0:\0*T\n\x0eSetting\
+    Section\x12\x0e\n\nAppearance\x10\0\x12\n\n\x06Editor\x10\x01\x12\x08\n\
+    \x04Sync\x10\x02\x12\r\n\tShortcuts\x10\x03\x12\x0b\n\x07Privacy\x10\x04\
+    \x1a\0*.\n\tThemeMode\x12\n\n\x06System\x10\0\x12\t\n\x05Light\x10\x01\
+    \x12\x08\n\x04Dark\x10\x02\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
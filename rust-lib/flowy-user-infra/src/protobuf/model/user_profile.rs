@@ -189,6 +189,8 @@ pub struct UserProfile {
     pub email: ::std::string::String,
     pub name: ::std::string::String,
     pub token: ::std::string::String,
+    pub avatar: ::std::string::String,
+    pub email_verified: bool,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -308,6 +310,47 @@ impl UserProfile {
     pub fn take_token(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.token, ::std::string::String::new())
     }
+
+    // string avatar = 5;
+
+
+    pub fn get_avatar(&self) -> &str {
+        &self.avatar
+    }
+    pub fn clear_avatar(&mut self) {
+        self.avatar.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_avatar(&mut self, v: ::std::string::String) {
+        self.avatar = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_avatar(&mut self) -> &mut ::std::string::String {
+        &mut self.avatar
+    }
+
+    // Take field
+    pub fn take_avatar(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.avatar, ::std::string::String::new())
+    }
+
+    // bool email_verified = 6;
+
+
+    pub fn get_email_verified(&self) -> bool {
+        self.email_verified
+    }
+    pub fn clear_email_verified(&mut self) {
+        self.email_verified = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_email_verified(&mut self, v: bool) {
+        self.email_verified = v;
+    }
 }
 
 impl ::protobuf::Message for UserProfile {
@@ -331,6 +374,16 @@ impl ::protobuf::Message for UserProfile {
                 4 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.token)?;
                 },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.avatar)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.email_verified = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -355,6 +408,12 @@ impl ::protobuf::Message for UserProfile {
         if !self.token.is_empty() {
             my_size += ::protobuf::rt::string_size(4, &self.token);
         }
+        if !self.avatar.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.avatar);
+        }
+        if self.email_verified != false {
+            my_size += 2;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -373,6 +432,12 @@ impl ::protobuf::Message for UserProfile {
         if !self.token.is_empty() {
             os.write_string(4, &self.token)?;
         }
+        if !self.avatar.is_empty() {
+            os.write_string(5, &self.avatar)?;
+        }
+        if self.email_verified != false {
+            os.write_bool(6, self.email_verified)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -431,6 +496,16 @@ impl ::protobuf::Message for UserProfile {
                 |m: &UserProfile| { &m.token },
                 |m: &mut UserProfile| { &mut m.token },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "avatar",
+                |m: &UserProfile| { &m.avatar },
+                |m: &mut UserProfile| { &mut m.avatar },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "email_verified",
+                |m: &UserProfile| { &m.email_verified },
+                |m: &mut UserProfile| { &mut m.email_verified },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<UserProfile>(
                 "UserProfile",
                 fields,
@@ -451,6 +526,8 @@ impl ::protobuf::Clear for UserProfile {
         self.email.clear();
         self.name.clear();
         self.token.clear();
+        self.avatar.clear();
+        self.email_verified = false;
         self.unknown_fields.clear();
     }
 }
@@ -475,6 +552,7 @@ pub struct UpdateUserRequest {
     pub one_of_name: ::std::option::Option<UpdateUserRequest_oneof_one_of_name>,
     pub one_of_email: ::std::option::Option<UpdateUserRequest_oneof_one_of_email>,
     pub one_of_password: ::std::option::Option<UpdateUserRequest_oneof_one_of_password>,
+    pub one_of_avatar: ::std::option::Option<UpdateUserRequest_oneof_one_of_avatar>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -501,6 +579,11 @@ pub enum UpdateUserRequest_oneof_one_of_password {
     password(::std::string::String),
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateUserRequest_oneof_one_of_avatar {
+    avatar(::std::string::String),
+}
+
 impl UpdateUserRequest {
     pub fn new() -> UpdateUserRequest {
         ::std::default::Default::default()
@@ -678,6 +761,55 @@ impl UpdateUserRequest {
             ::std::string::String::new()
         }
     }
+
+    // string avatar = 5;
+
+
+    pub fn get_avatar(&self) -> &str {
+        match self.one_of_avatar {
+            ::std::option::Option::Some(UpdateUserRequest_oneof_one_of_avatar::avatar(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_avatar(&mut self) {
+        self.one_of_avatar = ::std::option::Option::None;
+    }
+
+    pub fn has_avatar(&self) -> bool {
+        match self.one_of_avatar {
+            ::std::option::Option::Some(UpdateUserRequest_oneof_one_of_avatar::avatar(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_avatar(&mut self, v: ::std::string::String) {
+        self.one_of_avatar = ::std::option::Option::Some(UpdateUserRequest_oneof_one_of_avatar::avatar(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_avatar(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateUserRequest_oneof_one_of_avatar::avatar(_)) = self.one_of_avatar {
+        } else {
+            self.one_of_avatar = ::std::option::Option::Some(UpdateUserRequest_oneof_one_of_avatar::avatar(::std::string::String::new()));
+        }
+        match self.one_of_avatar {
+            ::std::option::Option::Some(UpdateUserRequest_oneof_one_of_avatar::avatar(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_avatar(&mut self) -> ::std::string::String {
+        if self.has_avatar() {
+            match self.one_of_avatar.take() {
+                ::std::option::Option::Some(UpdateUserRequest_oneof_one_of_avatar::avatar(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for UpdateUserRequest {
@@ -710,6 +842,12 @@ impl ::protobuf::Message for UpdateUserRequest {
                     }
                     self.one_of_password = ::std::option::Option::Some(UpdateUserRequest_oneof_one_of_password::password(is.read_string()?));
                 },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_avatar = ::std::option::Option::Some(UpdateUserRequest_oneof_one_of_avatar::avatar(is.read_string()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -746,6 +884,13 @@ impl ::protobuf::Message for UpdateUserRequest {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_avatar {
+            match v {
+                &UpdateUserRequest_oneof_one_of_avatar::avatar(ref v) => {
+                    my_size += ::protobuf::rt::string_size(5, &v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -776,6 +921,13 @@ impl ::protobuf::Message for UpdateUserRequest {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_avatar {
+            match v {
+                &UpdateUserRequest_oneof_one_of_avatar::avatar(ref v) => {
+                    os.write_string(5, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -834,6 +986,11 @@ impl ::protobuf::Message for UpdateUserRequest {
                 UpdateUserRequest::has_password,
                 UpdateUserRequest::get_password,
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "avatar",
+                UpdateUserRequest::has_avatar,
+                UpdateUserRequest::get_avatar,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateUserRequest>(
                 "UpdateUserRequest",
                 fields,
@@ -854,6 +1011,7 @@ impl ::protobuf::Clear for UpdateUserRequest {
         self.one_of_name = ::std::option::Option::None;
         self.one_of_email = ::std::option::Option::None;
         self.one_of_password = ::std::option::Option::None;
+        self.one_of_avatar = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -878,6 +1036,7 @@ pub struct UpdateUserParams {
     pub one_of_name: ::std::option::Option<UpdateUserParams_oneof_one_of_name>,
     pub one_of_email: ::std::option::Option<UpdateUserParams_oneof_one_of_email>,
     pub one_of_password: ::std::option::Option<UpdateUserParams_oneof_one_of_password>,
+    pub one_of_avatar: ::std::option::Option<UpdateUserParams_oneof_one_of_avatar>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -904,6 +1063,11 @@ pub enum UpdateUserParams_oneof_one_of_password {
     password(::std::string::String),
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateUserParams_oneof_one_of_avatar {
+    avatar(::std::string::String),
+}
+
 impl UpdateUserParams {
     pub fn new() -> UpdateUserParams {
         ::std::default::Default::default()
@@ -1081,6 +1245,55 @@ impl UpdateUserParams {
             ::std::string::String::new()
         }
     }
+
+    // string avatar = 5;
+
+
+    pub fn get_avatar(&self) -> &str {
+        match self.one_of_avatar {
+            ::std::option::Option::Some(UpdateUserParams_oneof_one_of_avatar::avatar(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_avatar(&mut self) {
+        self.one_of_avatar = ::std::option::Option::None;
+    }
+
+    pub fn has_avatar(&self) -> bool {
+        match self.one_of_avatar {
+            ::std::option::Option::Some(UpdateUserParams_oneof_one_of_avatar::avatar(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_avatar(&mut self, v: ::std::string::String) {
+        self.one_of_avatar = ::std::option::Option::Some(UpdateUserParams_oneof_one_of_avatar::avatar(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_avatar(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateUserParams_oneof_one_of_avatar::avatar(_)) = self.one_of_avatar {
+        } else {
+            self.one_of_avatar = ::std::option::Option::Some(UpdateUserParams_oneof_one_of_avatar::avatar(::std::string::String::new()));
+        }
+        match self.one_of_avatar {
+            ::std::option::Option::Some(UpdateUserParams_oneof_one_of_avatar::avatar(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_avatar(&mut self) -> ::std::string::String {
+        if self.has_avatar() {
+            match self.one_of_avatar.take() {
+                ::std::option::Option::Some(UpdateUserParams_oneof_one_of_avatar::avatar(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for UpdateUserParams {
@@ -1113,6 +1326,12 @@ impl ::protobuf::Message for UpdateUserParams {
                     }
                     self.one_of_password = ::std::option::Option::Some(UpdateUserParams_oneof_one_of_password::password(is.read_string()?));
                 },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_avatar = ::std::option::Option::Some(UpdateUserParams_oneof_one_of_avatar::avatar(is.read_string()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1149,6 +1368,13 @@ impl ::protobuf::Message for UpdateUserParams {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_avatar {
+            match v {
+                &UpdateUserParams_oneof_one_of_avatar::avatar(ref v) => {
+                    my_size += ::protobuf::rt::string_size(5, &v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1179,6 +1405,13 @@ impl ::protobuf::Message for UpdateUserParams {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_avatar {
+            match v {
+                &UpdateUserParams_oneof_one_of_avatar::avatar(ref v) => {
+                    os.write_string(5, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1237,6 +1470,11 @@ impl ::protobuf::Message for UpdateUserParams {
                 UpdateUserParams::has_password,
                 UpdateUserParams::get_password,
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "avatar",
+                UpdateUserParams::has_avatar,
+                UpdateUserParams::get_avatar,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateUserParams>(
                 "UpdateUserParams",
                 fields,
@@ -1257,6 +1495,7 @@ impl ::protobuf::Clear for UpdateUserParams {
         self.one_of_name = ::std::option::Option::None;
         self.one_of_email = ::std::option::Option::None;
         self.one_of_password = ::std::option::Option::None;
+        self.one_of_avatar = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -1273,69 +1512,389 @@ impl ::protobuf::reflect::ProtobufValue for UpdateUserParams {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateUserProfileRequest {
+    // message oneof groups
+    pub one_of_name: ::std::option::Option<UpdateUserProfileRequest_oneof_one_of_name>,
+    pub one_of_avatar: ::std::option::Option<UpdateUserProfileRequest_oneof_one_of_avatar>,
+    pub one_of_email: ::std::option::Option<UpdateUserProfileRequest_oneof_one_of_email>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateUserProfileRequest {
+    fn default() -> &'a UpdateUserProfileRequest {
+        <UpdateUserProfileRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateUserProfileRequest_oneof_one_of_name {
+    name(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateUserProfileRequest_oneof_one_of_avatar {
+    avatar(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateUserProfileRequest_oneof_one_of_email {
+    email(::std::string::String),
+}
+
+impl UpdateUserProfileRequest {
+    pub fn new() -> UpdateUserProfileRequest {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+
+    pub fn get_name(&self) -> &str {
+        match self.one_of_name {
+            ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_name::name(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_name(&mut self) {
+        self.one_of_name = ::std::option::Option::None;
+    }
+
+    pub fn has_name(&self) -> bool {
+        match self.one_of_name {
+            ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_name::name(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.one_of_name = ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_name::name(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_name::name(_)) = self.one_of_name {
+        } else {
+            self.one_of_name = ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_name::name(::std::string::String::new()));
+        }
+        match self.one_of_name {
+            ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_name::name(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        if self.has_name() {
+            match self.one_of_name.take() {
+                ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_name::name(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string avatar = 2;
+
+
+    pub fn get_avatar(&self) -> &str {
+        match self.one_of_avatar {
+            ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_avatar::avatar(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_avatar(&mut self) {
+        self.one_of_avatar = ::std::option::Option::None;
+    }
+
+    pub fn has_avatar(&self) -> bool {
+        match self.one_of_avatar {
+            ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_avatar::avatar(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_avatar(&mut self, v: ::std::string::String) {
+        self.one_of_avatar = ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_avatar::avatar(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_avatar(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_avatar::avatar(_)) = self.one_of_avatar {
+        } else {
+            self.one_of_avatar = ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_avatar::avatar(::std::string::String::new()));
+        }
+        match self.one_of_avatar {
+            ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_avatar::avatar(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_avatar(&mut self) -> ::std::string::String {
+        if self.has_avatar() {
+            match self.one_of_avatar.take() {
+                ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_avatar::avatar(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string email = 3;
+
+
+    pub fn get_email(&self) -> &str {
+        match self.one_of_email {
+            ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_email::email(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_email(&mut self) {
+        self.one_of_email = ::std::option::Option::None;
+    }
+
+    pub fn has_email(&self) -> bool {
+        match self.one_of_email {
+            ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_email::email(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_email(&mut self, v: ::std::string::String) {
+        self.one_of_email = ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_email::email(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_email(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_email::email(_)) = self.one_of_email {
+        } else {
+            self.one_of_email = ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_email::email(::std::string::String::new()));
+        }
+        match self.one_of_email {
+            ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_email::email(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_email(&mut self) -> ::std::string::String {
+        if self.has_email() {
+            match self.one_of_email.take() {
+                ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_email::email(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for UpdateUserProfileRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_name = ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_name::name(is.read_string()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_avatar = ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_avatar::avatar(is.read_string()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_email = ::std::option::Option::Some(UpdateUserProfileRequest_oneof_one_of_email::email(is.read_string()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &UpdateUserProfileRequest_oneof_one_of_name::name(ref v) => {
+                    my_size += ::protobuf::rt::string_size(1, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_avatar {
+            match v {
+                &UpdateUserProfileRequest_oneof_one_of_avatar::avatar(ref v) => {
+                    my_size += ::protobuf::rt::string_size(2, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_email {
+            match v {
+                &UpdateUserProfileRequest_oneof_one_of_email::email(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &UpdateUserProfileRequest_oneof_one_of_name::name(ref v) => {
+                    os.write_string(1, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_avatar {
+            match v {
+                &UpdateUserProfileRequest_oneof_one_of_avatar::avatar(ref v) => {
+                    os.write_string(2, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_email {
+            match v {
+                &UpdateUserProfileRequest_oneof_one_of_email::email(ref v) => {
+                    os.write_string(3, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateUserProfileRequest {
+        UpdateUserProfileRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "name",
+                UpdateUserProfileRequest::has_name,
+                UpdateUserProfileRequest::get_name,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "avatar",
+                UpdateUserProfileRequest::has_avatar,
+                UpdateUserProfileRequest::get_avatar,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "email",
+                UpdateUserProfileRequest::has_email,
+                UpdateUserProfileRequest::get_email,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateUserProfileRequest>(
+                "UpdateUserProfileRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateUserProfileRequest {
+        static instance: ::protobuf::rt::LazyV2<UpdateUserProfileRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateUserProfileRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateUserProfileRequest {
+    fn clear(&mut self) {
+        self.one_of_name = ::std::option::Option::None;
+        self.one_of_avatar = ::std::option::Option::None;
+        self.one_of_email = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateUserProfileRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateUserProfileRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x12user_profile.proto\"!\n\tUserToken\x12\x14\n\x05token\x18\x01\x20\
-    \x01(\tR\x05token\"]\n\x0bUserProfile\x12\x0e\n\x02id\x18\x01\x20\x01(\t\
-    R\x02id\x12\x14\n\x05email\x18\x02\x20\x01(\tR\x05email\x12\x12\n\x04nam\
-    e\x18\x03\x20\x01(\tR\x04name\x12\x14\n\x05token\x18\x04\x20\x01(\tR\x05\
-    token\"\xa1\x01\n\x11UpdateUserRequest\x12\x0e\n\x02id\x18\x01\x20\x01(\
-    \tR\x02id\x12\x14\n\x04name\x18\x02\x20\x01(\tH\0R\x04name\x12\x16\n\x05\
-    email\x18\x03\x20\x01(\tH\x01R\x05email\x12\x1c\n\x08password\x18\x04\
-    \x20\x01(\tH\x02R\x08passwordB\r\n\x0bone_of_nameB\x0e\n\x0cone_of_email\
-    B\x11\n\x0fone_of_password\"\xa0\x01\n\x10UpdateUserParams\x12\x0e\n\x02\
-    id\x18\x01\x20\x01(\tR\x02id\x12\x14\n\x04name\x18\x02\x20\x01(\tH\0R\
-    \x04name\x12\x16\n\x05email\x18\x03\x20\x01(\tH\x01R\x05email\x12\x1c\n\
-    \x08password\x18\x04\x20\x01(\tH\x02R\x08passwordB\r\n\x0bone_of_nameB\
-    \x0e\n\x0cone_of_emailB\x11\n\x0fone_of_passwordJ\xdf\x07\n\x06\x12\x04\
-    \0\0\x16\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\
-    \0\x04\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x11\n\x0b\n\x04\x04\0\x02\
-    \0\x12\x03\x03\x04\x15\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\
-    \x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x10\n\x0c\n\x05\x04\0\x02\0\
-    \x03\x12\x03\x03\x13\x14\n\n\n\x02\x04\x01\x12\x04\x05\0\n\x01\n\n\n\x03\
-    \x04\x01\x01\x12\x03\x05\x08\x13\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x06\
-    \x04\x12\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x06\x04\n\n\x0c\n\x05\x04\
-    \x01\x02\0\x01\x12\x03\x06\x0b\r\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\
-    \x06\x10\x11\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x07\x04\x15\n\x0c\n\x05\
-    \x04\x01\x02\x01\x05\x12\x03\x07\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\
-    \x12\x03\x07\x0b\x10\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x07\x13\x14\
-    \n\x0b\n\x04\x04\x01\x02\x02\x12\x03\x08\x04\x14\n\x0c\n\x05\x04\x01\x02\
-    \x02\x05\x12\x03\x08\x04\n\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x08\
-    \x0b\x0f\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\x08\x12\x13\n\x0b\n\x04\
-    \x04\x01\x02\x03\x12\x03\t\x04\x15\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\
-    \x03\t\x04\n\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\t\x0b\x10\n\x0c\n\
-    \x05\x04\x01\x02\x03\x03\x12\x03\t\x13\x14\n\n\n\x02\x04\x02\x12\x04\x0b\
-    \0\x10\x01\n\n\n\x03\x04\x02\x01\x12\x03\x0b\x08\x19\n\x0b\n\x04\x04\x02\
-    \x02\0\x12\x03\x0c\x04\x12\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0c\x04\
-    \n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x0c\x0b\r\n\x0c\n\x05\x04\x02\
-    \x02\0\x03\x12\x03\x0c\x10\x11\n\x0b\n\x04\x04\x02\x08\0\x12\x03\r\x04*\
-    \n\x0c\n\x05\x04\x02\x08\0\x01\x12\x03\r\n\x15\n\x0b\n\x04\x04\x02\x02\
-    \x01\x12\x03\r\x18(\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\r\x18\x1e\n\
-    \x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\r\x1f#\n\x0c\n\x05\x04\x02\x02\
-    \x01\x03\x12\x03\r&'\n\x0b\n\x04\x04\x02\x08\x01\x12\x03\x0e\x04,\n\x0c\
-    \n\x05\x04\x02\x08\x01\x01\x12\x03\x0e\n\x16\n\x0b\n\x04\x04\x02\x02\x02\
-    \x12\x03\x0e\x19*\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x0e\x19\x1f\n\
-    \x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x0e\x20%\n\x0c\n\x05\x04\x02\x02\
-    \x02\x03\x12\x03\x0e()\n\x0b\n\x04\x04\x02\x08\x02\x12\x03\x0f\x042\n\
-    \x0c\n\x05\x04\x02\x08\x02\x01\x12\x03\x0f\n\x19\n\x0b\n\x04\x04\x02\x02\
-    \x03\x12\x03\x0f\x1c0\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\x0f\x1c\"\
-    \n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x0f#+\n\x0c\n\x05\x04\x02\x02\
-    \x03\x03\x12\x03\x0f./\n\n\n\x02\x04\x03\x12\x04\x11\0\x16\x01\n\n\n\x03\
-    \x04\x03\x01\x12\x03\x11\x08\x18\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x12\
-    \x04\x12\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x12\x04\n\n\x0c\n\x05\x04\
-    \x03\x02\0\x01\x12\x03\x12\x0b\r\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\
-    \x12\x10\x11\n\x0b\n\x04\x04\x03\x08\0\x12\x03\x13\x04*\n\x0c\n\x05\x04\
-    \x03\x08\0\x01\x12\x03\x13\n\x15\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x13\
-    \x18(\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x13\x18\x1e\n\x0c\n\x05\
-    \x04\x03\x02\x01\x01\x12\x03\x13\x1f#\n\x0c\n\x05\x04\x03\x02\x01\x03\
-    \x12\x03\x13&'\n\x0b\n\x04\x04\x03\x08\x01\x12\x03\x14\x04,\n\x0c\n\x05\
-    \x04\x03\x08\x01\x01\x12\x03\x14\n\x16\n\x0b\n\x04\x04\x03\x02\x02\x12\
-    \x03\x14\x19*\n\x0c\n\x05\x04\x03\x02\x02\x05\x12\x03\x14\x19\x1f\n\x0c\
-    \n\x05\x04\x03\x02\x02\x01\x12\x03\x14\x20%\n\x0c\n\x05\x04\x03\x02\x02\
-    \x03\x12\x03\x14()\n\x0b\n\x04\x04\x03\x08\x02\x12\x03\x15\x042\n\x0c\n\
-    \x05\x04\x03\x08\x02\x01\x12\x03\x15\n\x19\n\x0b\n\x04\x04\x03\x02\x03\
-    \x12\x03\x15\x1c0\n\x0c\n\x05\x04\x03\x02\x03\x05\x12\x03\x15\x1c\"\n\
-    \x0c\n\x05\x04\x03\x02\x03\x01\x12\x03\x15#+\n\x0c\n\x05\x04\x03\x02\x03\
-    \x03\x12\x03\x15./b\x06proto3\
+    \n\x12user_profile.proto\"%\n\tUserToken\x12\x16\n\x05token\x18\x01\x20\
+    \x01(\tR\x05tokenB\0:\0\"\xaa\x01\n\x0bUserProfile\x12\x10\n\x02id\x18\
+    \x01\x20\x01(\tR\x02idB\0\x12\x16\n\x05email\x18\x02\x20\x01(\tR\x05emai\
+    lB\0\x12\x14\n\x04name\x18\x03\x20\x01(\tR\x04nameB\0\x12\x16\n\x05token\
+    \x18\x04\x20\x01(\tR\x05tokenB\0\x12\x18\n\x06avatar\x18\x05\x20\x01(\tR\
+    \x06avatarB\0\x12'\n\x0eemail_verified\x18\x06\x20\x01(\x08R\remailVerif\
+    iedB\0:\0\"\xd8\x01\n\x11UpdateUserRequest\x12\x10\n\x02id\x18\x01\x20\
+    \x01(\tR\x02idB\0\x12\x16\n\x04name\x18\x02\x20\x01(\tH\0R\x04nameB\0\
+    \x12\x18\n\x05email\x18\x03\x20\x01(\tH\x01R\x05emailB\0\x12\x1e\n\x08pa\
+    ssword\x18\x04\x20\x01(\tH\x02R\x08passwordB\0\x12\x1a\n\x06avatar\x18\
+    \x05\x20\x01(\tH\x03R\x06avatarB\0B\r\n\x0bone_of_nameB\x0e\n\x0cone_of_\
+    emailB\x11\n\x0fone_of_passwordB\x0f\n\rone_of_avatar:\0\"\xd7\x01\n\x10\
+    UpdateUserParams\x12\x10\n\x02id\x18\x01\x20\x01(\tR\x02idB\0\x12\x16\n\
+    \x04name\x18\x02\x20\x01(\tH\0R\x04nameB\0\x12\x18\n\x05email\x18\x03\
+    \x20\x01(\tH\x01R\x05emailB\0\x12\x1e\n\x08password\x18\x04\x20\x01(\tH\
+    \x02R\x08passwordB\0\x12\x1a\n\x06avatar\x18\x05\x20\x01(\tH\x03R\x06ava\
+    tarB\0B\r\n\x0bone_of_nameB\x0e\n\x0cone_of_emailB\x11\n\x0fone_of_passw\
+    ordB\x0f\n\rone_of_avatar:\0\"\x9a\x01\n\x18UpdateUserProfileRequest\x12\
+    \x16\n\x04name\x18\x01\x20\x01(\tH\0R\x04nameB\0\x12\x1a\n\x06avatar\x18\
+    \x02\x20\x01(\tH\x01R\x06avatarB\0\x12\x18\n\x05email\x18\x03\x20\x01(\t\
+    H\x02R\x05emailB\0B\r\n\x0bone_of_nameB\x0f\n\rone_of_avatarB\x0e\n\x0co\
+    ne_of_email:\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,592 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `verification.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct EmailVerification {
+    // message fields
+    pub email: ::std::string::String,
+    pub is_verified: bool,
+    pub is_local: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a EmailVerification {
+    fn default() -> &'a EmailVerification {
+        <EmailVerification as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl EmailVerification {
+    pub fn new() -> EmailVerification {
+        ::std::default::Default::default()
+    }
+
+    // string email = 1;
+
+
+    pub fn get_email(&self) -> &str {
+        &self.email
+    }
+    pub fn clear_email(&mut self) {
+        self.email.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_email(&mut self, v: ::std::string::String) {
+        self.email = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_email(&mut self) -> &mut ::std::string::String {
+        &mut self.email
+    }
+
+    // Take field
+    pub fn take_email(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.email, ::std::string::String::new())
+    }
+
+    // bool is_verified = 2;
+
+
+    pub fn get_is_verified(&self) -> bool {
+        self.is_verified
+    }
+    pub fn clear_is_verified(&mut self) {
+        self.is_verified = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_verified(&mut self, v: bool) {
+        self.is_verified = v;
+    }
+
+    // bool is_local = 3;
+
+
+    pub fn get_is_local(&self) -> bool {
+        self.is_local
+    }
+    pub fn clear_is_local(&mut self) {
+        self.is_local = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_local(&mut self, v: bool) {
+        self.is_local = v;
+    }
+}
+
+impl ::protobuf::Message for EmailVerification {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.email)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_verified = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_local = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.email.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.email);
+        }
+        if self.is_verified != false {
+            my_size += 2;
+        }
+        if self.is_local != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.email.is_empty() {
+            os.write_string(1, &self.email)?;
+        }
+        if self.is_verified != false {
+            os.write_bool(2, self.is_verified)?;
+        }
+        if self.is_local != false {
+            os.write_bool(3, self.is_local)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> EmailVerification {
+        EmailVerification::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "email",
+                |m: &EmailVerification| { &m.email },
+                |m: &mut EmailVerification| { &mut m.email },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_verified",
+                |m: &EmailVerification| { &m.is_verified },
+                |m: &mut EmailVerification| { &mut m.is_verified },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_local",
+                |m: &EmailVerification| { &m.is_local },
+                |m: &mut EmailVerification| { &mut m.is_local },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<EmailVerification>(
+                "EmailVerification",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static EmailVerification {
+        static instance: ::protobuf::rt::LazyV2<EmailVerification> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(EmailVerification::new)
+    }
+}
+
+impl ::protobuf::Clear for EmailVerification {
+    fn clear(&mut self) {
+        self.email.clear();
+        self.is_verified = false;
+        self.is_local = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for EmailVerification {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EmailVerification {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ConfirmEmailRequest {
+    // message fields
+    pub code: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ConfirmEmailRequest {
+    fn default() -> &'a ConfirmEmailRequest {
+        <ConfirmEmailRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ConfirmEmailRequest {
+    pub fn new() -> ConfirmEmailRequest {
+        ::std::default::Default::default()
+    }
+
+    // string code = 1;
+
+
+    pub fn get_code(&self) -> &str {
+        &self.code
+    }
+    pub fn clear_code(&mut self) {
+        self.code.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_code(&mut self, v: ::std::string::String) {
+        self.code = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_code(&mut self) -> &mut ::std::string::String {
+        &mut self.code
+    }
+
+    // Take field
+    pub fn take_code(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.code, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ConfirmEmailRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.code)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.code.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.code);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.code.is_empty() {
+            os.write_string(1, &self.code)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ConfirmEmailRequest {
+        ConfirmEmailRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "code",
+                |m: &ConfirmEmailRequest| { &m.code },
+                |m: &mut ConfirmEmailRequest| { &mut m.code },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ConfirmEmailRequest>(
+                "ConfirmEmailRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ConfirmEmailRequest {
+        static instance: ::protobuf::rt::LazyV2<ConfirmEmailRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ConfirmEmailRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ConfirmEmailRequest {
+    fn clear(&mut self) {
+        self.code.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ConfirmEmailRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ConfirmEmailRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ConfirmEmailParams {
+    // message fields
+    pub code: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ConfirmEmailParams {
+    fn default() -> &'a ConfirmEmailParams {
+        <ConfirmEmailParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ConfirmEmailParams {
+    pub fn new() -> ConfirmEmailParams {
+        ::std::default::Default::default()
+    }
+
+    // string code = 1;
+
+
+    pub fn get_code(&self) -> &str {
+        &self.code
+    }
+    pub fn clear_code(&mut self) {
+        self.code.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_code(&mut self, v: ::std::string::String) {
+        self.code = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_code(&mut self) -> &mut ::std::string::String {
+        &mut self.code
+    }
+
+    // Take field
+    pub fn take_code(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.code, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ConfirmEmailParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.code)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.code.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.code);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.code.is_empty() {
+            os.write_string(1, &self.code)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ConfirmEmailParams {
+        ConfirmEmailParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "code",
+                |m: &ConfirmEmailParams| { &m.code },
+                |m: &mut ConfirmEmailParams| { &mut m.code },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ConfirmEmailParams>(
+                "ConfirmEmailParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ConfirmEmailParams {
+        static instance: ::protobuf::rt::LazyV2<ConfirmEmailParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ConfirmEmailParams::new)
+    }
+}
+
+impl ::protobuf::Clear for ConfirmEmailParams {
+    fn clear(&mut self) {
+        self.code.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ConfirmEmailParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ConfirmEmailParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x12verification.proto\"m\n\x11EmailVerification\x12\x16\n\x05email\
+    \x18\x01\x20\x01(\tR\x05emailB\0\x12!\n\x0bis_verified\x18\x02\x20\x01(\
+    \x08R\nisVerifiedB\0\x12\x1b\n\x08is_local\x18\x03\x20\x01(\x08R\x07isLo\
+    calB\0:\0\"-\n\x13ConfirmEmailRequest\x12\x14\n\x04code\x18\x01\x20\x01(\
+    \tR\x04codeB\0:\0\",\n\x12ConfirmEmailParams\x12\x14\n\x04code\x18\x01\
+    \x20\x01(\tR\x04codeB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
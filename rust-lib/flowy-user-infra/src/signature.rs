@@ -0,0 +1,68 @@
+use sha1::{Digest, Sha1};
+
+// The signed requests older than this, in seconds, are rejected by the server, so a
+// captured request can't be replayed later.
+pub const SIGNATURE_MAX_AGE: i64 = 5 * 60;
+
+const BLOCK_SIZE: usize = 64;
+
+// HMAC-SHA1 of the message, hex encoded. The token of the session is the key, so the
+// signature proves the request was made by the holder of the token.
+pub fn sign(key: &str, message: &str) -> String {
+    let mut key_block = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        let digest = Sha1::digest(key.as_bytes());
+        key_block[..digest.len()].copy_from_slice(&digest);
+    } else {
+        key_block[..key.len()].copy_from_slice(key.as_bytes());
+    }
+
+    let mut inner = Sha1::new();
+    inner.update(key_block.iter().map(|b| b ^ 0x36).collect::<Vec<u8>>());
+    inner.update(message.as_bytes());
+    let inner = inner.finalize();
+
+    let mut outer = Sha1::new();
+    outer.update(key_block.iter().map(|b| b ^ 0x5c).collect::<Vec<u8>>());
+    outer.update(inner);
+    outer.finalize().iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+pub fn verify(key: &str, message: &str, signature: &str) -> bool {
+    let expected = sign(key, message);
+    // Compare every byte, so the time doesn't tell how much of the signature is right.
+    expected.len() == signature.len()
+        && expected
+            .bytes()
+            .zip(signature.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+pub fn delete_account_message(user_id: &str, timestamp: i64) -> String {
+    format!("delete_account:{}:{}", user_id, timestamp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sign_matches_hmac_sha1() {
+        assert_eq!(
+            sign("key", "The quick brown fox jumps over the lazy dog"),
+            "de7c9b85b8b78aa6bc8a7a36f70a90701c9db4d9"
+        );
+        assert_eq!(sign("", ""), "fbdb1d1b18aa6c08324b7d64b71fb76370690e1d");
+    }
+
+    #[test]
+    fn verify_rejects_other_signatures() {
+        let message = delete_account_message("user", 1634200000);
+        let signature = sign("token", &message);
+        assert!(verify("token", &message, &signature));
+        assert!(!verify("other token", &message, &signature));
+        assert!(!verify("token", &delete_account_message("user", 1634200001), &signature));
+        assert!(!verify("token", &message, &signature[1..]));
+    }
+}
@@ -2,6 +2,7 @@ use bytes::Bytes;
 
 use flowy_derive::ProtoBuf;
 use flowy_dispatch::prelude::{EventResponse, ResponseBuilder};
+use flowy_i18n::MessageKey;
 pub use flowy_user_infra::errors::ErrorCode;
 use std::{convert::TryInto, fmt, fmt::Debug};
 
@@ -12,6 +13,10 @@ pub struct UserError {
 
     #[pb(index = 2)]
     pub msg: String,
+
+    // The seconds to wait before retrying, only set with ErrorCode::TooManyAttempts.
+    #[pb(index = 3)]
+    pub retry_after: i64,
 }
 
 impl std::fmt::Display for UserError {
@@ -30,9 +35,16 @@ impl UserError {
         Self {
             code: code.value(),
             msg: msg.to_owned(),
+            retry_after: 0,
         }
     }
 
+    pub fn too_many_attempts(retry_after: i64) -> Self {
+        let mut error: UserError = ErrorCode::TooManyAttempts.into();
+        error.retry_after = retry_after;
+        error
+    }
+
     pub fn context<T: Debug>(mut self, error: T) -> Self {
         self.msg = format!("{:?}", error);
         self
@@ -46,12 +58,31 @@ impl UserError {
     static_user_error!(password_forbid_char, ErrorCode::PasswordContainsForbidCharacters);
     static_user_error!(password_format, ErrorCode::PasswordFormatInvalid);
     static_user_error!(password_not_match, ErrorCode::PasswordNotMatch);
+    static_user_error!(password_too_short, ErrorCode::PasswordTooShort);
+    static_user_error!(password_not_changed, ErrorCode::PasswordNotChanged);
+    static_user_error!(oauth_provider_not_supported, ErrorCode::OAuthProviderNotSupported);
+    static_user_error!(oauth_state_invalid, ErrorCode::OAuthStateInvalid);
+    static_user_error!(not_anonymous, ErrorCode::NotAnonymousUser);
+    static_user_error!(preference_not_exist, ErrorCode::PreferenceNotExist);
+    static_user_error!(preference_type_mismatch, ErrorCode::PreferenceTypeMismatch);
+    static_user_error!(delete_account_not_confirmed, ErrorCode::DeleteAccountNotConfirmed);
+    static_user_error!(signature_invalid, ErrorCode::SignatureInvalid);
     static_user_error!(name_too_long, ErrorCode::UserNameTooLong);
     static_user_error!(name_forbid_char, ErrorCode::UserNameContainForbiddenCharacters);
     static_user_error!(name_empty, ErrorCode::UserNameIsEmpty);
     static_user_error!(user_id, ErrorCode::UserIdInvalid);
     static_user_error!(unauthorized, ErrorCode::UserUnauthorized);
     static_user_error!(user_not_exist, ErrorCode::UserNotExist);
+    static_user_error!(session_expired, ErrorCode::SessionExpired);
+    static_user_error!(session_revoked, ErrorCode::SessionRevoked);
+    static_user_error!(session_not_exist, ErrorCode::SessionNotExist);
+    static_user_error!(verification_code_invalid, ErrorCode::VerificationCodeInvalid);
+    static_user_error!(email_not_verified, ErrorCode::EmailNotVerified);
+    static_user_error!(migration_failed, ErrorCode::DatabaseMigrationFailed);
+    static_user_error!(encryption_unsupported, ErrorCode::DatabaseEncryptionUnsupported);
+    static_user_error!(encryption_failed, ErrorCode::DatabaseEncryptionFailed);
+    static_user_error!(backup_not_exist, ErrorCode::BackupNotExist);
+    static_user_error!(backup_corrupted, ErrorCode::BackupCorrupted);
     static_user_error!(internal, ErrorCode::InternalError);
 }
 
@@ -59,7 +90,8 @@ impl std::convert::From<ErrorCode> for UserError {
     fn from(code: ErrorCode) -> Self {
         UserError {
             code: code.value(),
-            msg: format!("{}", code),
+            msg: code.localized_message(),
+            retry_after: 0,
         }
     }
 }
@@ -105,6 +137,8 @@ fn server_error_to_user_error(error: flowy_net::errors::ServerError) -> (ErrorCo
         ServerErrorCode::UserUnauthorized => ErrorCode::UserUnauthorized,
         ServerErrorCode::PasswordNotMatch => ErrorCode::PasswordNotMatch,
         ServerErrorCode::RecordNotFound => ErrorCode::UserNotExist,
+        ServerErrorCode::SessionRevoked => ErrorCode::SessionRevoked,
+        ServerErrorCode::VerificationCodeInvalid => ErrorCode::VerificationCodeInvalid,
         ServerErrorCode::ConnectRefused | ServerErrorCode::ConnectTimeout | ServerErrorCode::ConnectClose => {
             ErrorCode::ServerError
         },
@@ -24,4 +24,91 @@ pub enum UserEvent {
 
     #[event(output = "UserProfile")]
     CheckUser      = 6,
+
+    #[event(output = "UserProfile")]
+    AutoSignIn     = 7,
+
+    #[event(output = "RepeatedAccount")]
+    ListAccounts   = 8,
+
+    #[event(input = "SwitchAccountRequest", output = "UserProfile")]
+    SwitchAccount  = 9,
+
+    #[event(input = "UpdatePasswordRequest")]
+    UpdatePassword = 10,
+
+    #[event(input = "OAuthURLRequest", output = "OAuthURL")]
+    GetOAuthURL    = 11,
+
+    #[event(input = "OAuthSignInRequest", output = "UserProfile")]
+    OAuthSignIn    = 12,
+
+    #[event(output = "UserProfile")]
+    StartAnonymous = 13,
+
+    #[event(input = "SignUpRequest", output = "UserProfile")]
+    MigrateToAccount = 14,
+
+    #[event(input = "UpdateUserProfileRequest", output = "UserProfile")]
+    UpdateUserProfile = 15,
+
+    #[event(input = "SetPreferenceRequest")]
+    SetPreference  = 16,
+
+    #[event(input = "GetPreferenceRequest", output = "Preference")]
+    GetPreference  = 17,
+
+    #[event(input = "SubscribePreferenceRequest", output = "RepeatedPreference")]
+    SubscribePreference = 18,
+
+    #[event(input = "DeleteAccountRequest")]
+    DeleteAccount  = 19,
+
+    #[event(output = "RepeatedDeviceSession")]
+    ListSessions   = 20,
+
+    #[event(input = "RevokeSessionRequest")]
+    RevokeSession  = 21,
+
+    #[event()]
+    SendVerificationEmail = 22,
+
+    #[event(output = "EmailVerification")]
+    GetEmailVerification = 23,
+
+    #[event(input = "ConfirmEmailRequest", output = "EmailVerification")]
+    ConfirmEmail   = 24,
+
+    #[event(output = "DatabaseBackup")]
+    CreateBackup   = 25,
+
+    #[event(output = "RepeatedDatabaseBackup")]
+    ListBackups    = 26,
+
+    #[event(input = "RestoreBackupRequest")]
+    RestoreBackup  = 27,
+
+    #[event(input = "ExportAllDataRequest", output = "DataExport")]
+    ExportAllData  = 28,
+
+    #[event(output = "StorageCompaction")]
+    CompactStorage = 29,
+
+    #[event(input = "NetworkState")]
+    UpdateNetworkState = 30,
+
+    #[event(output = "RepeatedLogFile")]
+    ReadLogFiles   = 31,
+
+    #[event(input = "ReadAuditLogRequest", output = "RepeatedAuditRecord")]
+    ReadAuditLog   = 32,
+
+    #[event(output = "AppSetting")]
+    ReadSetting    = 33,
+
+    #[event(input = "UpdateSettingRequest", output = "AppSetting")]
+    UpdateSetting  = 34,
+
+    #[event(input = "ResetSettingRequest", output = "AppSetting")]
+    ResetSetting   = 35,
 }
@@ -1,11 +1,14 @@
 use crate::{entities::*, errors::UserError, services::user::UserSession};
 use flowy_dispatch::prelude::*;
-use std::{convert::TryInto, sync::Arc};
+use std::sync::Arc;
 
 // tracing instrument 👉🏻 https://docs.rs/tracing/0.1.26/tracing/attr.instrument.html
 #[tracing::instrument(name = "sign_in", skip(data, session), fields(email = %data.email), err)]
-pub async fn sign_in(data: Data<SignInRequest>, session: Unit<Arc<UserSession>>) -> DataResult<UserProfile, UserError> {
-    let params: SignInParams = data.into_inner().try_into()?;
+pub async fn sign_in(
+    data: Params<SignInParams, UserError>,
+    session: Unit<Arc<UserSession>>,
+) -> DataResult<UserProfile, UserError> {
+    let params = data.into_inner();
     let user_profile = session.sign_in(params).await?;
     data_result(user_profile)
 }
@@ -19,9 +22,81 @@ pub async fn sign_in(data: Data<SignInRequest>, session: Unit<Arc<UserSession>>)
     ),
     err
 )]
-pub async fn sign_up(data: Data<SignUpRequest>, session: Unit<Arc<UserSession>>) -> DataResult<UserProfile, UserError> {
-    let params: SignUpParams = data.into_inner().try_into()?;
+pub async fn sign_up(
+    data: Params<SignUpParams, UserError>,
+    session: Unit<Arc<UserSession>>,
+) -> DataResult<UserProfile, UserError> {
+    let params = data.into_inner();
     let user_profile = session.sign_up(params).await?;
 
     data_result(user_profile)
 }
+
+#[tracing::instrument(name = "auto_sign_in", skip(session), err)]
+pub async fn auto_sign_in(session: Unit<Arc<UserSession>>) -> DataResult<UserProfile, UserError> {
+    let user_profile = session.auto_sign_in().await?;
+    data_result(user_profile)
+}
+
+#[tracing::instrument(name = "update_password", skip(data, session), err)]
+pub async fn update_password_handler(
+    data: Params<UpdatePasswordParams, UserError>,
+    session: Unit<Arc<UserSession>>,
+) -> Result<(), UserError> {
+    let params = data.into_inner();
+    session.update_password(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(name = "oauth_url", skip(data, session), fields(provider = ?data.provider), err)]
+pub async fn oauth_url_handler(
+    data: Data<OAuthURLRequest>,
+    session: Unit<Arc<UserSession>>,
+) -> DataResult<OAuthURL, UserError> {
+    let oauth_url = session.oauth_url(data.into_inner().provider)?;
+    data_result(oauth_url)
+}
+
+#[tracing::instrument(name = "oauth_sign_in", skip(data, session), err)]
+pub async fn oauth_sign_in(
+    data: Params<OAuthCompletion, UserError>,
+    session: Unit<Arc<UserSession>>,
+) -> DataResult<UserProfile, UserError> {
+    let completion = data.into_inner();
+    let user_profile = session.oauth_sign_in(completion).await?;
+    data_result(user_profile)
+}
+
+#[tracing::instrument(name = "start_anonymous", skip(session), err)]
+pub async fn start_anonymous(session: Unit<Arc<UserSession>>) -> DataResult<UserProfile, UserError> {
+    let user_profile = session.start_anonymous().await?;
+    data_result(user_profile)
+}
+
+#[tracing::instrument(
+    name = "migrate_to_account",
+    skip(data, session),
+    fields(
+        email = %data.email,
+        name = %data.name,
+    ),
+    err
+)]
+pub async fn migrate_to_account(
+    data: Params<SignUpParams, UserError>,
+    session: Unit<Arc<UserSession>>,
+) -> DataResult<UserProfile, UserError> {
+    let params = data.into_inner();
+    let user_profile = session.migrate_to_account(params).await?;
+    data_result(user_profile)
+}
+
+#[tracing::instrument(name = "delete_account", skip(data, session), err)]
+pub async fn delete_account_handler(
+    data: Params<DeleteAccountConfirmation, UserError>,
+    session: Unit<Arc<UserSession>>,
+) -> Result<(), UserError> {
+    let confirmation = data.into_inner();
+    session.delete_account(confirmation).await?;
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use crate::{entities::*, errors::UserError, services::user::UserSession};
 use flowy_dispatch::prelude::*;
-
-use std::{convert::TryInto, sync::Arc};
+use flowy_ws::{NetworkState, NetworkStateType};
+use std::sync::Arc;
 
 #[tracing::instrument(skip(session))]
 pub async fn init_user_handler(session: Unit<Arc<UserSession>>) -> Result<(), UserError> {
@@ -29,10 +29,204 @@ pub async fn sign_out(session: Unit<Arc<UserSession>>) -> Result<(), UserError>
 
 #[tracing::instrument(name = "update_user", skip(data, session))]
 pub async fn update_user_handler(
-    data: Data<UpdateUserRequest>,
+    data: Params<UpdateUserParams, UserError>,
     session: Unit<Arc<UserSession>>,
 ) -> Result<(), UserError> {
-    let params: UpdateUserParams = data.into_inner().try_into()?;
+    let params = data.into_inner();
     session.update_user(params).await?;
     Ok(())
 }
+
+#[tracing::instrument(name = "update_user_profile", skip(data, session))]
+pub async fn update_user_profile_handler(
+    data: Params<UpdateUserProfileParams, UserError>,
+    session: Unit<Arc<UserSession>>,
+) -> DataResult<UserProfile, UserError> {
+    let params = data.into_inner();
+    let user_profile = session.update_user_profile(params).await?;
+    data_result(user_profile)
+}
+
+#[tracing::instrument(skip(session))]
+pub async fn list_accounts_handler(session: Unit<Arc<UserSession>>) -> DataResult<RepeatedAccount, UserError> {
+    data_result(session.list_accounts())
+}
+
+#[tracing::instrument(name = "switch_account", skip(data, session))]
+pub async fn switch_account_handler(
+    data: Params<SwitchAccountParams, UserError>,
+    session: Unit<Arc<UserSession>>,
+) -> DataResult<UserProfile, UserError> {
+    let params = data.into_inner();
+    let user_profile = session.switch_account(params).await?;
+    data_result(user_profile)
+}
+
+#[tracing::instrument(name = "set_preference", skip(data, session))]
+pub async fn set_preference_handler(
+    data: Params<Preference, UserError>,
+    session: Unit<Arc<UserSession>>,
+) -> Result<(), UserError> {
+    let preference = data.into_inner();
+    session.set_preference(preference)?;
+    Ok(())
+}
+
+#[tracing::instrument(name = "get_preference", skip(data, session))]
+pub async fn get_preference_handler(
+    data: Params<PreferenceKey, UserError>,
+    session: Unit<Arc<UserSession>>,
+) -> DataResult<Preference, UserError> {
+    let key = data.into_inner();
+    let preference = session.get_preference(&key.0)?;
+    data_result(preference)
+}
+
+#[tracing::instrument(name = "subscribe_preference", skip(data, session))]
+pub async fn subscribe_preference_handler(
+    data: Params<SubscribePreferenceParams, UserError>,
+    session: Unit<Arc<UserSession>>,
+) -> DataResult<RepeatedPreference, UserError> {
+    let params = data.into_inner();
+    let preferences = session.subscribe_preference(params)?;
+    data_result(preferences)
+}
+
+#[tracing::instrument(skip(session))]
+pub async fn read_setting_handler(session: Unit<Arc<UserSession>>) -> DataResult<AppSetting, UserError> {
+    let setting = session.read_setting()?;
+    data_result(setting)
+}
+
+#[tracing::instrument(name = "update_setting", skip(data, session))]
+pub async fn update_setting_handler(
+    data: Params<UpdateSettingParams, UserError>,
+    session: Unit<Arc<UserSession>>,
+) -> DataResult<AppSetting, UserError> {
+    let params = data.into_inner();
+    let setting = session.update_setting(params)?;
+    data_result(setting)
+}
+
+#[tracing::instrument(name = "reset_setting", skip(data, session))]
+pub async fn reset_setting_handler(
+    data: Data<ResetSettingRequest>,
+    session: Unit<Arc<UserSession>>,
+) -> DataResult<AppSetting, UserError> {
+    let setting = session.reset_setting(data.into_inner().section)?;
+    data_result(setting)
+}
+
+#[tracing::instrument(skip(session))]
+pub async fn list_sessions_handler(session: Unit<Arc<UserSession>>) -> DataResult<RepeatedDeviceSession, UserError> {
+    let sessions = session.list_sessions().await?;
+    data_result(sessions)
+}
+
+#[tracing::instrument(name = "revoke_session", skip(data, session))]
+pub async fn revoke_session_handler(
+    data: Params<RevokeSessionParams, UserError>,
+    session: Unit<Arc<UserSession>>,
+) -> Result<(), UserError> {
+    let params = data.into_inner();
+    session.revoke_session(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(session))]
+pub async fn send_verification_email_handler(session: Unit<Arc<UserSession>>) -> Result<(), UserError> {
+    session.send_verification_email().await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(session))]
+pub async fn get_email_verification_handler(
+    session: Unit<Arc<UserSession>>,
+) -> DataResult<EmailVerification, UserError> {
+    let verification = session.email_verification().await?;
+    data_result(verification)
+}
+
+#[tracing::instrument(name = "confirm_email", skip(data, session))]
+pub async fn confirm_email_handler(
+    data: Params<ConfirmEmailParams, UserError>,
+    session: Unit<Arc<UserSession>>,
+) -> DataResult<EmailVerification, UserError> {
+    let params = data.into_inner();
+    let verification = session.confirm_email(params).await?;
+    data_result(verification)
+}
+
+#[tracing::instrument(skip(session))]
+pub async fn create_backup_handler(session: Unit<Arc<UserSession>>) -> DataResult<DatabaseBackup, UserError> {
+    let backup = session.create_backup()?;
+    data_result(backup)
+}
+
+#[tracing::instrument(skip(session))]
+pub async fn list_backups_handler(session: Unit<Arc<UserSession>>) -> DataResult<RepeatedDatabaseBackup, UserError> {
+    let backups = session.list_backups()?;
+    data_result(backups)
+}
+
+#[tracing::instrument(name = "restore_backup", skip(data, session))]
+pub async fn restore_backup_handler(
+    data: Params<RestoreBackupParams, UserError>,
+    session: Unit<Arc<UserSession>>,
+) -> Result<(), UserError> {
+    let params = data.into_inner();
+    session.restore_backup(params)?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, session))]
+pub async fn export_all_data_handler(
+    data: Data<ExportAllDataRequest>,
+    session: Unit<Arc<UserSession>>,
+) -> DataResult<DataExport, UserError> {
+    let export = session.export_all_data(data.into_inner().format)?;
+    data_result(export)
+}
+
+#[tracing::instrument(skip(session))]
+pub async fn compact_storage_handler(session: Unit<Arc<UserSession>>) -> DataResult<StorageCompaction, UserError> {
+    let compaction = session.compact_storage()?;
+    data_result(compaction)
+}
+
+// The host reports the changes of the network it detects, the reachability
+// goes through the same confirmations as the probes.
+#[tracing::instrument(skip(data, session))]
+pub async fn update_network_state_handler(
+    data: Data<NetworkState>,
+    session: Unit<Arc<UserSession>>,
+) -> Result<(), UserError> {
+    let state = data.into_inner();
+    session.connectivity.report(state.ty == NetworkStateType::Online);
+    session.connectivity.set_metered(state.metered);
+    Ok(())
+}
+
+// The files of the logs, for the user to export them from the settings.
+#[tracing::instrument]
+pub async fn read_log_files_handler() -> DataResult<RepeatedLogFile, UserError> {
+    let items = flowy_log::log_file_paths()
+        .into_iter()
+        .map(|path| LogFile {
+            size: std::fs::metadata(&path)
+                .map(|metadata| metadata.len() as i64)
+                .unwrap_or(0),
+            path: path.to_string_lossy().into_owned(),
+        })
+        .collect();
+    data_result(RepeatedLogFile { items })
+}
+
+#[tracing::instrument(skip(data, session))]
+pub async fn read_audit_log_handler(
+    data: Data<ReadAuditLogRequest>,
+    session: Unit<Arc<UserSession>>,
+) -> DataResult<RepeatedAuditRecord, UserError> {
+    let records = session.read_audit_log(data.into_inner())?;
+    data_result(records)
+}
@@ -14,4 +14,33 @@ pub fn create(user_session: Arc<UserSession>) -> Module {
         .event(UserEvent::SignOut, sign_out)
         .event(UserEvent::UpdateUser, update_user_handler)
         .event(UserEvent::CheckUser, check_user_handler)
+        .event(UserEvent::AutoSignIn, auto_sign_in)
+        .event(UserEvent::ListAccounts, list_accounts_handler)
+        .event(UserEvent::SwitchAccount, switch_account_handler)
+        .event(UserEvent::UpdatePassword, update_password_handler)
+        .event(UserEvent::GetOAuthURL, oauth_url_handler)
+        .event(UserEvent::OAuthSignIn, oauth_sign_in)
+        .event(UserEvent::StartAnonymous, start_anonymous)
+        .event(UserEvent::MigrateToAccount, migrate_to_account)
+        .event(UserEvent::UpdateUserProfile, update_user_profile_handler)
+        .event(UserEvent::SetPreference, set_preference_handler)
+        .event(UserEvent::GetPreference, get_preference_handler)
+        .event(UserEvent::SubscribePreference, subscribe_preference_handler)
+        .event(UserEvent::DeleteAccount, delete_account_handler)
+        .event(UserEvent::ListSessions, list_sessions_handler)
+        .event(UserEvent::RevokeSession, revoke_session_handler)
+        .event(UserEvent::SendVerificationEmail, send_verification_email_handler)
+        .event(UserEvent::GetEmailVerification, get_email_verification_handler)
+        .event(UserEvent::ConfirmEmail, confirm_email_handler)
+        .event(UserEvent::CreateBackup, create_backup_handler)
+        .event(UserEvent::ListBackups, list_backups_handler)
+        .event(UserEvent::RestoreBackup, restore_backup_handler)
+        .event(UserEvent::ExportAllData, export_all_data_handler)
+        .event(UserEvent::CompactStorage, compact_storage_handler)
+        .event(UserEvent::UpdateNetworkState, update_network_state_handler)
+        .event(UserEvent::ReadLogFiles, read_log_files_handler)
+        .event(UserEvent::ReadAuditLog, read_audit_log_handler)
+        .event(UserEvent::ReadSetting, read_setting_handler)
+        .event(UserEvent::UpdateSetting, update_setting_handler)
+        .event(UserEvent::ResetSetting, reset_setting_handler)
 }
@@ -8,8 +8,17 @@ const OBSERVABLE_CATEGORY: &'static str = "User";
 pub(crate) enum UserNotification {
     Unknown            = 0,
     UserAuthChanged    = 1,
-    UserProfileUpdated = 2,
+    UserProfileChanged = 2,
     UserUnauthorized   = 3,
+    PreferenceChanged  = 4,
+    DeleteAccountProgress = 5,
+    SessionRevoked     = 6,
+    EmailVerificationChanged = 7,
+    DatabaseMigrationFailed = 8,
+    DatabaseEncryptionProgress = 9,
+    DatabaseRowsChanged = 10,
+    WsConnectStateChanged = 11,
+    SettingChanged     = 12,
 }
 
 impl std::default::Default for UserNotification {
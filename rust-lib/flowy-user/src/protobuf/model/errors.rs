@@ -28,6 +28,7 @@ pub struct UserError {
     // message fields
     pub code: i32,
     pub msg: ::std::string::String,
+    pub retry_after: i64,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -84,6 +85,21 @@ impl UserError {
     pub fn take_msg(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.msg, ::std::string::String::new())
     }
+
+    // int64 retry_after = 3;
+
+
+    pub fn get_retry_after(&self) -> i64 {
+        self.retry_after
+    }
+    pub fn clear_retry_after(&mut self) {
+        self.retry_after = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_retry_after(&mut self, v: i64) {
+        self.retry_after = v;
+    }
 }
 
 impl ::protobuf::Message for UserError {
@@ -105,6 +121,13 @@ impl ::protobuf::Message for UserError {
                 2 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.msg)?;
                 },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.retry_after = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -123,6 +146,9 @@ impl ::protobuf::Message for UserError {
         if !self.msg.is_empty() {
             my_size += ::protobuf::rt::string_size(2, &self.msg);
         }
+        if self.retry_after != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.retry_after, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -135,6 +161,9 @@ impl ::protobuf::Message for UserError {
         if !self.msg.is_empty() {
             os.write_string(2, &self.msg)?;
         }
+        if self.retry_after != 0 {
+            os.write_int64(3, self.retry_after)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -183,6 +212,11 @@ impl ::protobuf::Message for UserError {
                 |m: &UserError| { &m.msg },
                 |m: &mut UserError| { &mut m.msg },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "retry_after",
+                |m: &UserError| { &m.retry_after },
+                |m: &mut UserError| { &mut m.retry_after },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<UserError>(
                 "UserError",
                 fields,
@@ -201,6 +235,7 @@ impl ::protobuf::Clear for UserError {
     fn clear(&mut self) {
         self.code = 0;
         self.msg.clear();
+        self.retry_after = 0;
         self.unknown_fields.clear();
     }
 }
@@ -218,16 +253,9 @@ impl ::protobuf::reflect::ProtobufValue for UserError {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0cerrors.proto\"1\n\tUserError\x12\x12\n\x04code\x18\x01\x20\x01(\
-    \x05R\x04code\x12\x10\n\x03msg\x18\x02\x20\x01(\tR\x03msgJ\x98\x01\n\x06\
-    \x12\x04\0\0\x05\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\
-    \x04\x02\0\x05\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x11\n\x0b\n\x04\
-    \x04\0\x02\0\x12\x03\x03\x04\x13\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\
-    \x04\t\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\n\x0e\n\x0c\n\x05\x04\0\
-    \x02\0\x03\x12\x03\x03\x11\x12\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\
-    \x13\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\
-    \x02\x01\x01\x12\x03\x04\x0b\x0e\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\
-    \x04\x11\x12b\x06proto3\
+    \n\x0cerrors.proto\"Z\n\tUserError\x12\x14\n\x04code\x18\x01\x20\x01(\
+    \x05R\x04codeB\0\x12\x12\n\x03msg\x18\x02\x20\x01(\tR\x03msgB\0\x12!\n\
+    \x0bretry_after\x18\x03\x20\x01(\x03R\nretryAfterB\0:\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -32,6 +32,30 @@ pub enum UserEvent {
     UpdateUser = 4,
     GetUserProfile = 5,
     CheckUser = 6,
+    AutoSignIn = 7,
+    ListAccounts = 8,
+    SwitchAccount = 9,
+    UpdatePassword = 10,
+    GetOAuthURL = 11,
+    OAuthSignIn = 12,
+    StartAnonymous = 13,
+    MigrateToAccount = 14,
+    UpdateUserProfile = 15,
+    SetPreference = 16,
+    GetPreference = 17,
+    SubscribePreference = 18,
+    DeleteAccount = 19,
+    ListSessions = 20,
+    RevokeSession = 21,
+    SendVerificationEmail = 22,
+    GetEmailVerification = 23,
+    ConfirmEmail = 24,
+    CreateBackup = 25,
+    ListBackups = 26,
+    RestoreBackup = 27,
+    ExportAllData = 28,
+    CompactStorage = 29,
+    UpdateNetworkState = 30,
 }
 
 impl ::protobuf::ProtobufEnum for UserEvent {
@@ -48,6 +72,30 @@ impl ::protobuf::ProtobufEnum for UserEvent {
             4 => ::std::option::Option::Some(UserEvent::UpdateUser),
             5 => ::std::option::Option::Some(UserEvent::GetUserProfile),
             6 => ::std::option::Option::Some(UserEvent::CheckUser),
+            7 => ::std::option::Option::Some(UserEvent::AutoSignIn),
+            8 => ::std::option::Option::Some(UserEvent::ListAccounts),
+            9 => ::std::option::Option::Some(UserEvent::SwitchAccount),
+            10 => ::std::option::Option::Some(UserEvent::UpdatePassword),
+            11 => ::std::option::Option::Some(UserEvent::GetOAuthURL),
+            12 => ::std::option::Option::Some(UserEvent::OAuthSignIn),
+            13 => ::std::option::Option::Some(UserEvent::StartAnonymous),
+            14 => ::std::option::Option::Some(UserEvent::MigrateToAccount),
+            15 => ::std::option::Option::Some(UserEvent::UpdateUserProfile),
+            16 => ::std::option::Option::Some(UserEvent::SetPreference),
+            17 => ::std::option::Option::Some(UserEvent::GetPreference),
+            18 => ::std::option::Option::Some(UserEvent::SubscribePreference),
+            19 => ::std::option::Option::Some(UserEvent::DeleteAccount),
+            20 => ::std::option::Option::Some(UserEvent::ListSessions),
+            21 => ::std::option::Option::Some(UserEvent::RevokeSession),
+            22 => ::std::option::Option::Some(UserEvent::SendVerificationEmail),
+            23 => ::std::option::Option::Some(UserEvent::GetEmailVerification),
+            24 => ::std::option::Option::Some(UserEvent::ConfirmEmail),
+            25 => ::std::option::Option::Some(UserEvent::CreateBackup),
+            26 => ::std::option::Option::Some(UserEvent::ListBackups),
+            27 => ::std::option::Option::Some(UserEvent::RestoreBackup),
+            28 => ::std::option::Option::Some(UserEvent::ExportAllData),
+            29 => ::std::option::Option::Some(UserEvent::CompactStorage),
+            30 => ::std::option::Option::Some(UserEvent::UpdateNetworkState),
             _ => ::std::option::Option::None
         }
     }
@@ -61,6 +109,30 @@ impl ::protobuf::ProtobufEnum for UserEvent {
             UserEvent::UpdateUser,
             UserEvent::GetUserProfile,
             UserEvent::CheckUser,
+            UserEvent::AutoSignIn,
+            UserEvent::ListAccounts,
+            UserEvent::SwitchAccount,
+            UserEvent::UpdatePassword,
+            UserEvent::GetOAuthURL,
+            UserEvent::OAuthSignIn,
+            UserEvent::StartAnonymous,
+            UserEvent::MigrateToAccount,
+            UserEvent::UpdateUserProfile,
+            UserEvent::SetPreference,
+            UserEvent::GetPreference,
+            UserEvent::SubscribePreference,
+            UserEvent::DeleteAccount,
+            UserEvent::ListSessions,
+            UserEvent::RevokeSession,
+            UserEvent::SendVerificationEmail,
+            UserEvent::GetEmailVerification,
+            UserEvent::ConfirmEmail,
+            UserEvent::CreateBackup,
+            UserEvent::ListBackups,
+            UserEvent::RestoreBackup,
+            UserEvent::ExportAllData,
+            UserEvent::CompactStorage,
+            UserEvent::UpdateNetworkState,
         ];
         values
     }
@@ -89,26 +161,22 @@ impl ::protobuf::reflect::ProtobufValue for UserEvent {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0bevent.proto*q\n\tUserEvent\x12\x0c\n\x08InitUser\x10\0\x12\n\n\x06\
-    SignIn\x10\x01\x12\n\n\x06SignUp\x10\x02\x12\x0b\n\x07SignOut\x10\x03\
-    \x12\x0e\n\nUpdateUser\x10\x04\x12\x12\n\x0eGetUserProfile\x10\x05\x12\r\
-    \n\tCheckUser\x10\x06J\xc9\x02\n\x06\x12\x04\0\0\n\x01\n\x08\n\x01\x0c\
-    \x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0\n\x01\n\n\n\x03\x05\0\x01\
-    \x12\x03\x02\x05\x0e\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\x11\n\x0c\n\
-    \x05\x05\0\x02\0\x01\x12\x03\x03\x04\x0c\n\x0c\n\x05\x05\0\x02\0\x02\x12\
-    \x03\x03\x0f\x10\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\x04\x0f\n\x0c\n\
-    \x05\x05\0\x02\x01\x01\x12\x03\x04\x04\n\n\x0c\n\x05\x05\0\x02\x01\x02\
-    \x12\x03\x04\r\x0e\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x05\x04\x0f\n\x0c\n\
-    \x05\x05\0\x02\x02\x01\x12\x03\x05\x04\n\n\x0c\n\x05\x05\0\x02\x02\x02\
-    \x12\x03\x05\r\x0e\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x06\x04\x10\n\x0c\n\
-    \x05\x05\0\x02\x03\x01\x12\x03\x06\x04\x0b\n\x0c\n\x05\x05\0\x02\x03\x02\
-    \x12\x03\x06\x0e\x0f\n\x0b\n\x04\x05\0\x02\x04\x12\x03\x07\x04\x13\n\x0c\
-    \n\x05\x05\0\x02\x04\x01\x12\x03\x07\x04\x0e\n\x0c\n\x05\x05\0\x02\x04\
-    \x02\x12\x03\x07\x11\x12\n\x0b\n\x04\x05\0\x02\x05\x12\x03\x08\x04\x17\n\
-    \x0c\n\x05\x05\0\x02\x05\x01\x12\x03\x08\x04\x12\n\x0c\n\x05\x05\0\x02\
-    \x05\x02\x12\x03\x08\x15\x16\n\x0b\n\x04\x05\0\x02\x06\x12\x03\t\x04\x12\
-    \n\x0c\n\x05\x05\0\x02\x06\x01\x12\x03\t\x04\r\n\x0c\n\x05\x05\0\x02\x06\
-    \x02\x12\x03\t\x10\x11b\x06proto3\
+    \n\x0bevent.proto*\xd2\x04\n\tUserEvent\x12\x0c\n\x08InitUser\x10\0\x12\
+    \n\n\x06SignIn\x10\x01\x12\n\n\x06SignUp\x10\x02\x12\x0b\n\x07SignOut\
+    \x10\x03\x12\x0e\n\nUpdateUser\x10\x04\x12\x12\n\x0eGetUserProfile\x10\
+    \x05\x12\r\n\tCheckUser\x10\x06\x12\x0e\n\nAutoSignIn\x10\x07\x12\x10\n\
+    \x0cListAccounts\x10\x08\x12\x11\n\rSwitchAccount\x10\t\x12\x12\n\x0eUpd\
+    atePassword\x10\n\x12\x0f\n\x0bGetOAuthURL\x10\x0b\x12\x0f\n\x0bOAuthSig\
+    nIn\x10\x0c\x12\x12\n\x0eStartAnonymous\x10\r\x12\x14\n\x10MigrateToAcco\
+    unt\x10\x0e\x12\x15\n\x11UpdateUserProfile\x10\x0f\x12\x11\n\rSetPrefere\
+    nce\x10\x10\x12\x11\n\rGetPreference\x10\x11\x12\x17\n\x13SubscribePrefe\
+    rence\x10\x12\x12\x11\n\rDeleteAccount\x10\x13\x12\x10\n\x0cListSessions\
+    \x10\x14\x12\x11\n\rRevokeSession\x10\x15\x12\x19\n\x15SendVerificationE\
+    mail\x10\x16\x12\x18\n\x14GetEmailVerification\x10\x17\x12\x10\n\x0cConf\
+    irmEmail\x10\x18\x12\x10\n\x0cCreateBackup\x10\x19\x12\x0f\n\x0bListBack\
+    ups\x10\x1a\x12\x11\n\rRestoreBackup\x10\x1b\x12\x11\n\rExportAllData\
+    \x10\x1c\x12\x12\n\x0eCompactStorage\x10\x1d\x12\x16\n\x12UpdateNetworkS\
+    tate\x10\x1e\x1a\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -27,8 +27,17 @@
 pub enum UserNotification {
     Unknown = 0,
     UserAuthChanged = 1,
-    UserProfileUpdated = 2,
+    UserProfileChanged = 2,
     UserUnauthorized = 3,
+    PreferenceChanged = 4,
+    DeleteAccountProgress = 5,
+    SessionRevoked = 6,
+    EmailVerificationChanged = 7,
+    DatabaseMigrationFailed = 8,
+    DatabaseEncryptionProgress = 9,
+    DatabaseRowsChanged = 10,
+    WsConnectStateChanged = 11,
+    SettingChanged = 12,
 }
 
 impl ::protobuf::ProtobufEnum for UserNotification {
@@ -40,8 +49,17 @@ impl ::protobuf::ProtobufEnum for UserNotification {
         match value {
             0 => ::std::option::Option::Some(UserNotification::Unknown),
             1 => ::std::option::Option::Some(UserNotification::UserAuthChanged),
-            2 => ::std::option::Option::Some(UserNotification::UserProfileUpdated),
+            2 => ::std::option::Option::Some(UserNotification::UserProfileChanged),
             3 => ::std::option::Option::Some(UserNotification::UserUnauthorized),
+            4 => ::std::option::Option::Some(UserNotification::PreferenceChanged),
+            5 => ::std::option::Option::Some(UserNotification::DeleteAccountProgress),
+            6 => ::std::option::Option::Some(UserNotification::SessionRevoked),
+            7 => ::std::option::Option::Some(UserNotification::EmailVerificationChanged),
+            8 => ::std::option::Option::Some(UserNotification::DatabaseMigrationFailed),
+            9 => ::std::option::Option::Some(UserNotification::DatabaseEncryptionProgress),
+            10 => ::std::option::Option::Some(UserNotification::DatabaseRowsChanged),
+            11 => ::std::option::Option::Some(UserNotification::WsConnectStateChanged),
+            12 => ::std::option::Option::Some(UserNotification::SettingChanged),
             _ => ::std::option::Option::None
         }
     }
@@ -50,8 +68,17 @@ impl ::protobuf::ProtobufEnum for UserNotification {
         static values: &'static [UserNotification] = &[
             UserNotification::Unknown,
             UserNotification::UserAuthChanged,
-            UserNotification::UserProfileUpdated,
+            UserNotification::UserProfileChanged,
             UserNotification::UserUnauthorized,
+            UserNotification::PreferenceChanged,
+            UserNotification::DeleteAccountProgress,
+            UserNotification::SessionRevoked,
+            UserNotification::EmailVerificationChanged,
+            UserNotification::DatabaseMigrationFailed,
+            UserNotification::DatabaseEncryptionProgress,
+            UserNotification::DatabaseRowsChanged,
+            UserNotification::WsConnectStateChanged,
+            UserNotification::SettingChanged,
         ];
         values
     }
@@ -80,19 +107,15 @@ impl ::protobuf::reflect::ProtobufValue for UserNotification {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x10observable.proto*b\n\x10UserNotification\x12\x0b\n\x07Unknown\x10\
-    \0\x12\x13\n\x0fUserAuthChanged\x10\x01\x12\x16\n\x12UserProfileUpdated\
-    \x10\x02\x12\x14\n\x10UserUnauthorized\x10\x03J\xce\x01\n\x06\x12\x04\0\
-    \0\x07\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0\
-    \x07\x01\n\n\n\x03\x05\0\x01\x12\x03\x02\x05\x15\n\x0b\n\x04\x05\0\x02\0\
-    \x12\x03\x03\x04\x10\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x04\x0b\n\
-    \x0c\n\x05\x05\0\x02\0\x02\x12\x03\x03\x0e\x0f\n\x0b\n\x04\x05\0\x02\x01\
-    \x12\x03\x04\x04\x18\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\x04\x13\n\
-    \x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x04\x16\x17\n\x0b\n\x04\x05\0\x02\
-    \x02\x12\x03\x05\x04\x1b\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x05\x04\
-    \x16\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x05\x19\x1a\n\x0b\n\x04\x05\0\
-    \x02\x03\x12\x03\x06\x04\x19\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x06\
-    \x04\x14\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x06\x17\x18b\x06proto3\
+    \n\x10observable.proto*\xcd\x02\n\x10UserNotification\x12\x0b\n\x07Unkno\
+    wn\x10\0\x12\x13\n\x0fUserAuthChanged\x10\x01\x12\x16\n\x12UserProfileCh\
+    anged\x10\x02\x12\x14\n\x10UserUnauthorized\x10\x03\x12\x15\n\x11Prefere\
+    nceChanged\x10\x04\x12\x19\n\x15DeleteAccountProgress\x10\x05\x12\x12\n\
+    \x0eSessionRevoked\x10\x06\x12\x1c\n\x18EmailVerificationChanged\x10\x07\
+    \x12\x1b\n\x17DatabaseMigrationFailed\x10\x08\x12\x1e\n\x1aDatabaseEncry\
+    ptionProgress\x10\t\x12\x17\n\x13DatabaseRowsChanged\x10\n\x12\x19\n\x15\
+    WsConnectStateChanged\x10\x0b\x12\x12\n\x0eSettingChanged\x10\x0c\x1a\0B\
+    \0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -1,2 +1,4 @@
+pub mod preference;
 pub mod server;
+pub mod setting;
 pub mod user;
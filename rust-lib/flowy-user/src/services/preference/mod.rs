@@ -0,0 +1,3 @@
+mod preference_controller;
+
+pub use preference_controller::*;
@@ -0,0 +1,96 @@
+use crate::{
+    entities::{Preference, RepeatedPreference},
+    errors::UserError,
+    notify::{dart_notify, UserNotification},
+    sql_tables::UserPreferenceTable,
+};
+use flowy_database::{
+    query_dsl::*,
+    schema::{user_preference_table, user_preference_table::dsl},
+    ExpressionMethods,
+    OptionalExtension,
+    SqliteConnection,
+};
+use parking_lot::RwLock;
+use std::collections::HashSet;
+use tokio::sync::broadcast;
+
+// The preferences are stored in the database of each user, so they follow the user
+// instead of the device.
+pub struct PreferenceController {
+    // The keys the frontend subscribed with SubscribePreference.
+    subscribed_keys: RwLock<HashSet<String>>,
+    notifier: broadcast::Sender<Preference>,
+}
+
+impl PreferenceController {
+    pub(crate) fn new() -> Self {
+        let (notifier, _) = broadcast::channel(10);
+        Self {
+            subscribed_keys: RwLock::new(HashSet::new()),
+            notifier,
+        }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<Preference> { self.notifier.subscribe() }
+
+    pub(crate) fn set(&self, preference: Preference, conn: &SqliteConnection) -> Result<(), UserError> {
+        if let Some(stored) = self.read(&preference.key, conn)? {
+            if stored.value.type_name() != preference.value.type_name() {
+                return Err(UserError::preference_type_mismatch().context(format!(
+                    "{} is {:?}, but got {:?}",
+                    preference.key,
+                    stored.value.type_name(),
+                    preference.value.type_name()
+                )));
+            }
+
+            if stored == preference {
+                return Ok(());
+            }
+        }
+
+        let row: UserPreferenceTable = preference.clone().into();
+        let _ = diesel::replace_into(user_preference_table::table)
+            .values(&row)
+            .execute(conn)?;
+
+        if self.subscribed_keys.read().contains(&preference.key) {
+            dart_notify(&preference.key, UserNotification::PreferenceChanged)
+                .payload(preference.clone())
+                .send();
+        }
+        let _ = self.notifier.send(preference);
+        Ok(())
+    }
+
+    pub(crate) fn get(&self, key: &str, conn: &SqliteConnection) -> Result<Preference, UserError> {
+        match self.read(key, conn)? {
+            None => Err(UserError::preference_not_exist().context(key)),
+            Some(preference) => Ok(preference),
+        }
+    }
+
+    // Returns the current values of the keys that have been set.
+    pub(crate) fn subscribe_keys(&self, keys: Vec<String>, conn: &SqliteConnection) -> Result<RepeatedPreference, UserError> {
+        let mut items = vec![];
+        for key in &keys {
+            if let Some(preference) = self.read(key, conn)? {
+                items.push(preference);
+            }
+        }
+        self.subscribed_keys.write().extend(keys);
+        Ok(RepeatedPreference { items })
+    }
+
+    // The subscriptions belong to the screens of the current user.
+    pub(crate) fn clear_subscriptions(&self) { self.subscribed_keys.write().clear(); }
+
+    fn read(&self, key: &str, conn: &SqliteConnection) -> Result<Option<Preference>, UserError> {
+        let row = dsl::user_preference_table
+            .filter(user_preference_table::key.eq(key))
+            .first::<UserPreferenceTable>(conn)
+            .optional()?;
+        Ok(row.map(|row| row.into()))
+    }
+}
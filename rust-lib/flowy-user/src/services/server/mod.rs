@@ -6,7 +6,23 @@ pub use server_api_mock::*;
 use std::sync::Arc;
 pub(crate) type Server = Arc<dyn UserServerAPI + Send + Sync>;
 use crate::{
-    entities::{SignInParams, SignInResponse, SignUpParams, SignUpResponse, UpdateUserParams, UserProfile},
+    entities::{
+        ConfirmEmailParams,
+        DeleteAccountParams,
+        EmailVerification,
+        OAuthSignInParams,
+        RefreshTokenResponse,
+        RepeatedDeviceSession,
+        RevokeSessionParams,
+        SignInParams,
+        SignInResponse,
+        SignUpParams,
+        SignUpResponse,
+        UpdatePasswordParams,
+        UpdatePasswordResponse,
+        UpdateUserParams,
+        UserProfile,
+    },
     errors::UserError,
 };
 use flowy_infra::future::ResultFuture;
@@ -15,9 +31,22 @@ use flowy_net::config::ServerConfig;
 pub trait UserServerAPI {
     fn sign_up(&self, params: SignUpParams) -> ResultFuture<SignUpResponse, UserError>;
     fn sign_in(&self, params: SignInParams) -> ResultFuture<SignInResponse, UserError>;
+    fn oauth_sign_in(&self, params: OAuthSignInParams) -> ResultFuture<SignInResponse, UserError>;
     fn sign_out(&self, token: &str) -> ResultFuture<(), UserError>;
+    fn refresh_token(&self, token: &str) -> ResultFuture<RefreshTokenResponse, UserError>;
+    fn update_password(
+        &self,
+        token: &str,
+        params: UpdatePasswordParams,
+    ) -> ResultFuture<UpdatePasswordResponse, UserError>;
     fn update_user(&self, token: &str, params: UpdateUserParams) -> ResultFuture<(), UserError>;
+    fn delete_account(&self, token: &str, params: DeleteAccountParams) -> ResultFuture<(), UserError>;
     fn get_user(&self, token: &str) -> ResultFuture<UserProfile, UserError>;
+    fn list_sessions(&self, token: &str) -> ResultFuture<RepeatedDeviceSession, UserError>;
+    fn revoke_session(&self, token: &str, params: RevokeSessionParams) -> ResultFuture<(), UserError>;
+    fn send_verification_email(&self, token: &str) -> ResultFuture<(), UserError>;
+    fn get_verification(&self, token: &str) -> ResultFuture<EmailVerification, UserError>;
+    fn confirm_email(&self, token: &str, params: ConfirmEmailParams) -> ResultFuture<EmailVerification, UserError>;
     fn ws_addr(&self) -> String;
 }
 
@@ -25,6 +54,6 @@ pub(crate) fn construct_user_server(config: &ServerConfig) -> Arc<dyn UserServer
     if cfg!(feature = "http_server") {
         Arc::new(UserServer::new(config.clone()))
     } else {
-        Arc::new(UserServerMock {})
+        Arc::new(UserServerMock::default())
     }
 }
@@ -1,5 +1,21 @@
 use crate::{
-    entities::{SignInParams, SignInResponse, SignUpParams, SignUpResponse, UpdateUserParams, UserProfile},
+    entities::{
+        ConfirmEmailParams,
+        DeleteAccountParams,
+        EmailVerification,
+        OAuthSignInParams,
+        RefreshTokenResponse,
+        RepeatedDeviceSession,
+        RevokeSessionParams,
+        SignInParams,
+        SignInResponse,
+        SignUpParams,
+        SignUpResponse,
+        UpdatePasswordParams,
+        UpdatePasswordResponse,
+        UpdateUserParams,
+        UserProfile,
+    },
     errors::UserError,
     services::server::UserServerAPI,
 };
@@ -31,6 +47,14 @@ impl UserServerAPI for UserServer {
         })
     }
 
+    fn oauth_sign_in(&self, params: OAuthSignInParams) -> ResultFuture<SignInResponse, UserError> {
+        let url = self.config.oauth_url();
+        ResultFuture::new(async move {
+            let resp = oauth_sign_in_request(params, &url).await?;
+            Ok(resp)
+        })
+    }
+
     fn sign_out(&self, token: &str) -> ResultFuture<(), UserError> {
         let token = token.to_owned();
         let url = self.config.sign_out_url();
@@ -40,6 +64,37 @@ impl UserServerAPI for UserServer {
         })
     }
 
+    fn refresh_token(&self, token: &str) -> ResultFuture<RefreshTokenResponse, UserError> {
+        let token = token.to_owned();
+        let url = self.config.refresh_token_url();
+        ResultFuture::new(async move {
+            let resp = refresh_token_request(&token, &url).await?;
+            Ok(resp)
+        })
+    }
+
+    fn delete_account(&self, token: &str, params: DeleteAccountParams) -> ResultFuture<(), UserError> {
+        let token = token.to_owned();
+        let url = self.config.user_profile_url();
+        ResultFuture::new(async move {
+            delete_account_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn update_password(
+        &self,
+        token: &str,
+        params: UpdatePasswordParams,
+    ) -> ResultFuture<UpdatePasswordResponse, UserError> {
+        let token = token.to_owned();
+        let url = self.config.password_url();
+        ResultFuture::new(async move {
+            let resp = update_password_request(&token, params, &url).await?;
+            Ok(resp)
+        })
+    }
+
     fn update_user(&self, token: &str, params: UpdateUserParams) -> ResultFuture<(), UserError> {
         let token = token.to_owned();
         let url = self.config.user_profile_url();
@@ -58,6 +113,51 @@ impl UserServerAPI for UserServer {
         })
     }
 
+    fn list_sessions(&self, token: &str) -> ResultFuture<RepeatedDeviceSession, UserError> {
+        let token = token.to_owned();
+        let url = self.config.session_url();
+        ResultFuture::new(async move {
+            let sessions = list_sessions_request(&token, &url).await?;
+            Ok(sessions)
+        })
+    }
+
+    fn revoke_session(&self, token: &str, params: RevokeSessionParams) -> ResultFuture<(), UserError> {
+        let token = token.to_owned();
+        let url = self.config.session_url();
+        ResultFuture::new(async move {
+            revoke_session_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn send_verification_email(&self, token: &str) -> ResultFuture<(), UserError> {
+        let token = token.to_owned();
+        let url = self.config.verification_url();
+        ResultFuture::new(async move {
+            send_verification_email_request(&token, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn get_verification(&self, token: &str) -> ResultFuture<EmailVerification, UserError> {
+        let token = token.to_owned();
+        let url = self.config.verification_url();
+        ResultFuture::new(async move {
+            let verification = get_verification_request(&token, &url).await?;
+            Ok(verification)
+        })
+    }
+
+    fn confirm_email(&self, token: &str, params: ConfirmEmailParams) -> ResultFuture<EmailVerification, UserError> {
+        let token = token.to_owned();
+        let url = self.config.verification_url();
+        ResultFuture::new(async move {
+            let verification = confirm_email_request(&token, params, &url).await?;
+            Ok(verification)
+        })
+    }
+
     fn ws_addr(&self) -> String { self.config.ws_addr() }
 }
 
@@ -1,48 +1,318 @@
 use crate::{
-    entities::{SignInParams, SignInResponse, SignUpParams, SignUpResponse, UpdateUserParams, UserProfile},
+    entities::{
+        ConfirmEmailParams,
+        DeleteAccountParams,
+        DeviceSession,
+        EmailVerification,
+        OAuthSignInParams,
+        RefreshTokenResponse,
+        RepeatedDeviceSession,
+        RevokeSessionParams,
+        SignInParams,
+        SignInResponse,
+        SignUpParams,
+        SignUpResponse,
+        UpdatePasswordParams,
+        UpdatePasswordResponse,
+        UpdateUserParams,
+        UserProfile,
+    },
     errors::UserError,
 };
 
 use crate::services::server::UserServerAPI;
-use flowy_infra::{future::ResultFuture, uuid};
+use flowy_infra::{future::ResultFuture, timestamp, uuid};
+use flowy_user_infra::signature::{delete_account_message, verify, SIGNATURE_MAX_AGE};
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use std::{collections::HashMap, sync::Arc};
 
-pub struct UserServerMock {}
+lazy_static! {
+    // The sessions of every token the mock issued. It's shared by all the mocks, so the
+    // sessions of the same email on different sdks are listed together as they would
+    // be on the server.
+    static ref MOCK_SESSIONS: RwLock<HashMap<String, MockSession>> = RwLock::new(HashMap::new());
 
-impl UserServerMock {}
+    // The last verification code sent to each email, and whether the email was verified.
+    static ref MOCK_VERIFICATIONS: RwLock<HashMap<String, (Option<String>, bool)>> = RwLock::new(HashMap::new());
+
+    // The password of each email. The first sign in of an unknown email registers it, so
+    // signing in doesn't need signing up first.
+    static ref MOCK_PASSWORDS: RwLock<HashMap<String, String>> = RwLock::new(HashMap::new());
+}
+
+#[derive(Clone)]
+struct MockSession {
+    email: String,
+    session: DeviceSession,
+    revoked: bool,
+}
+
+fn add_session(token: &str, email: &str, device_id: &str, device_name: &str) {
+    let now = timestamp();
+    let session = DeviceSession {
+        id: uuid(),
+        device_id: device_id.to_owned(),
+        device_name: device_name.to_owned(),
+        created_at: now,
+        last_active_at: now,
+        is_current: false,
+    };
+    MOCK_SESSIONS.write().insert(
+        token.to_owned(),
+        MockSession {
+            email: email.to_owned(),
+            session,
+            revoked: false,
+        },
+    );
+}
+
+// The tokens that weren't issued by the mock are accepted, only the revoked ones fail.
+fn check_session(token: &str) -> Result<(), UserError> {
+    match MOCK_SESSIONS.write().get_mut(token) {
+        Some(session) if session.revoked => Err(UserError::session_revoked()),
+        Some(session) => {
+            session.session.last_active_at = timestamp();
+            Ok(())
+        },
+        None => Ok(()),
+    }
+}
+
+fn session_email(token: &str) -> Result<String, UserError> {
+    check_session(token)?;
+    match MOCK_SESSIONS.read().get(token) {
+        None => Err(UserError::unauthorized()),
+        Some(session) => Ok(session.email.clone()),
+    }
+}
+
+fn mock_verification(email: &str) -> EmailVerification {
+    let is_verified = MOCK_VERIFICATIONS
+        .read()
+        .get(email)
+        .map(|(_, is_verified)| *is_verified)
+        .unwrap_or(false);
+    EmailVerification {
+        email: email.to_owned(),
+        is_verified,
+        is_local: false,
+    }
+}
+
+#[derive(Default)]
+pub struct UserServerMock {
+    // The password of each token, so that changing the password can verify the old one
+    // and revoke the token it was requested with.
+    passwords: Arc<RwLock<HashMap<String, String>>>,
+}
+
+impl UserServerMock {
+    // No email is sent by the mock, the tests read the code from here.
+    pub fn verification_code(email: &str) -> Option<String> {
+        MOCK_VERIFICATIONS.read().get(email).and_then(|(code, _)| code.clone())
+    }
+}
 
 impl UserServerAPI for UserServerMock {
     fn sign_up(&self, params: SignUpParams) -> ResultFuture<SignUpResponse, UserError> {
         let uid = uuid();
+        let token = uuid();
+        self.passwords.write().insert(token.clone(), params.password.clone());
+        MOCK_PASSWORDS.write().insert(params.email.clone(), params.password.clone());
+        add_session(&token, &params.email, &params.device_id, &params.device_name);
         ResultFuture::new(async move {
             Ok(SignUpResponse {
-                user_id: uid.clone(),
+                user_id: uid,
                 name: params.name,
                 email: params.email,
-                token: uid,
+                token,
             })
         })
     }
 
     fn sign_in(&self, params: SignInParams) -> ResultFuture<SignInResponse, UserError> {
+        let is_match = MOCK_PASSWORDS
+            .write()
+            .entry(params.email.clone())
+            .or_insert_with(|| params.password.clone())
+            == &params.password;
+        if !is_match {
+            return ResultFuture::new(async { Err(UserError::password_not_match()) });
+        }
+
         let user_id = uuid();
+        let token = uuid();
+        self.passwords.write().insert(token.clone(), params.password.clone());
+        add_session(&token, &params.email, &params.device_id, &params.device_name);
         ResultFuture::new(async {
             Ok(SignInResponse {
-                user_id: user_id.clone(),
+                user_id,
                 name: params.name,
                 email: params.email,
-                token: user_id,
+                token,
             })
         })
     }
 
-    fn sign_out(&self, _token: &str) -> ResultFuture<(), UserError> { ResultFuture::new(async { Ok(()) }) }
+    fn oauth_sign_in(&self, params: OAuthSignInParams) -> ResultFuture<SignInResponse, UserError> {
+        let user_id = uuid();
+        let token = uuid();
+        let email = format!("{}@appflowy.io", params.code);
+        add_session(&token, &email, &params.device_id, &params.device_name);
+        ResultFuture::new(async move {
+            Ok(SignInResponse {
+                user_id,
+                name: format!("{:?} user", params.provider),
+                email,
+                token,
+            })
+        })
+    }
 
-    fn update_user(&self, _token: &str, _params: UpdateUserParams) -> ResultFuture<(), UserError> {
+    fn sign_out(&self, token: &str) -> ResultFuture<(), UserError> {
+        MOCK_SESSIONS.write().remove(token);
         ResultFuture::new(async { Ok(()) })
     }
 
-    fn get_user(&self, _token: &str) -> ResultFuture<UserProfile, UserError> {
-        ResultFuture::new(async { Ok(UserProfile::default()) })
+    fn refresh_token(&self, token: &str) -> ResultFuture<RefreshTokenResponse, UserError> {
+        if let Err(e) = check_session(token) {
+            return ResultFuture::new(async { Err(e) });
+        }
+
+        let new_token = uuid();
+        let mut sessions = MOCK_SESSIONS.write();
+        if let Some(session) = sessions.remove(token) {
+            sessions.insert(new_token.clone(), session);
+        }
+        let mut passwords = self.passwords.write();
+        if let Some(password) = passwords.remove(token) {
+            passwords.insert(new_token.clone(), password);
+        }
+        ResultFuture::new(async { Ok(RefreshTokenResponse { token: new_token }) })
+    }
+
+    fn update_password(
+        &self,
+        token: &str,
+        params: UpdatePasswordParams,
+    ) -> ResultFuture<UpdatePasswordResponse, UserError> {
+        if let Err(e) = check_session(token) {
+            return ResultFuture::new(async { Err(e) });
+        }
+
+        let mut passwords = self.passwords.write();
+        let result = match passwords.get(token) {
+            Some(password) if password != &params.old_password => Err(UserError::password_not_match()),
+            _ => {
+                let new_token = uuid();
+                passwords.remove(token);
+                if let Ok(email) = session_email(token) {
+                    MOCK_PASSWORDS.write().insert(email, params.new_password.clone());
+                }
+                passwords.insert(new_token.clone(), params.new_password);
+                Ok(UpdatePasswordResponse { token: new_token })
+            },
+        };
+        ResultFuture::new(async { result })
+    }
+
+    fn delete_account(&self, token: &str, params: DeleteAccountParams) -> ResultFuture<(), UserError> {
+        let message = delete_account_message(&params.user_id, params.timestamp);
+        let is_fresh = (timestamp() - params.timestamp).abs() <= SIGNATURE_MAX_AGE;
+        let mut passwords = self.passwords.write();
+        let result = if !is_fresh || !verify(token, &message, &params.signature) {
+            Err(UserError::signature_invalid())
+        } else {
+            match passwords.get(token) {
+                Some(password) if password != &params.password => Err(UserError::password_not_match()),
+                _ => {
+                    passwords.remove(token);
+                    MOCK_SESSIONS.write().remove(token);
+                    Ok(())
+                },
+            }
+        };
+        ResultFuture::new(async { result })
+    }
+
+    fn update_user(&self, token: &str, _params: UpdateUserParams) -> ResultFuture<(), UserError> {
+        let result = check_session(token);
+        ResultFuture::new(async { result })
+    }
+
+    fn get_user(&self, token: &str) -> ResultFuture<UserProfile, UserError> {
+        let result = check_session(token).map(|_| UserProfile::default());
+        ResultFuture::new(async { result })
+    }
+
+    fn list_sessions(&self, token: &str) -> ResultFuture<RepeatedDeviceSession, UserError> {
+        let result = check_session(token).map(|_| {
+            let sessions = MOCK_SESSIONS.read();
+            let email = sessions.get(token).map(|session| session.email.clone());
+            let items = sessions
+                .iter()
+                .filter(|(_, session)| !session.revoked && Some(&session.email) == email.as_ref())
+                .map(|(session_token, session)| DeviceSession {
+                    is_current: session_token == token,
+                    ..session.session.clone()
+                })
+                .collect::<Vec<DeviceSession>>();
+            RepeatedDeviceSession { items }
+        });
+        ResultFuture::new(async { result })
+    }
+
+    fn revoke_session(&self, token: &str, params: RevokeSessionParams) -> ResultFuture<(), UserError> {
+        let result = check_session(token).and_then(|_| {
+            let mut sessions = MOCK_SESSIONS.write();
+            let email = sessions.get(token).map(|session| session.email.clone());
+            match sessions
+                .values_mut()
+                .find(|session| session.session.id == params.session_id && Some(&session.email) == email.as_ref())
+            {
+                None => Err(UserError::session_not_exist()),
+                Some(session) => {
+                    session.revoked = true;
+                    Ok(())
+                },
+            }
+        });
+        ResultFuture::new(async { result })
+    }
+
+    fn send_verification_email(&self, token: &str) -> ResultFuture<(), UserError> {
+        let result = session_email(token).map(|email| {
+            let code = uuid()[..6].to_owned();
+            let mut verifications = MOCK_VERIFICATIONS.write();
+            let verification = verifications.entry(email).or_insert((None, false));
+            verification.0 = Some(code);
+        });
+        ResultFuture::new(async { result })
+    }
+
+    fn get_verification(&self, token: &str) -> ResultFuture<EmailVerification, UserError> {
+        let result = session_email(token).map(|email| mock_verification(&email));
+        ResultFuture::new(async { result })
+    }
+
+    fn confirm_email(&self, token: &str, params: ConfirmEmailParams) -> ResultFuture<EmailVerification, UserError> {
+        let result = session_email(token).and_then(|email| {
+            let mut verifications = MOCK_VERIFICATIONS.write();
+            match verifications.get_mut(&email) {
+                Some(verification) if verification.0.as_ref() == Some(&params.code) => {
+                    *verification = (None, true);
+                    Ok(EmailVerification {
+                        email,
+                        is_verified: true,
+                        is_local: false,
+                    })
+                },
+                _ => Err(UserError::verification_code_invalid()),
+            }
+        });
+        ResultFuture::new(async { result })
     }
 
     fn ws_addr(&self) -> String { "ws://localhost:8000/ws/".to_owned() }
@@ -0,0 +1,112 @@
+use crate::{
+    entities::{AppearanceSetting, EditorSetting, Preference, SyncSetting, ThemeMode},
+    services::setting::{write_section, SectionData},
+    sql_tables::UserPreferenceTable,
+};
+use flowy_database::{
+    query_dsl::*,
+    schema::{user_preference_table, user_preference_table::dsl},
+    ExpressionMethods,
+    RustMigration,
+    SqliteConnection,
+};
+use std::convert::TryFrom;
+
+// The settings were flat preferences, e.g. "editor.font_size", before they had
+// their sections. The preferences are moved to the sections once, the values
+// that don't fit their section are dropped.
+pub const SETTING_MIGRATION: RustMigration = RustMigration {
+    version: "2021-11-16-flowy-user-setting",
+    run: migrate_preference_keys,
+};
+
+const LEGACY_KEYS: [&str; 6] = [
+    "appearance.theme",
+    "appearance.locale",
+    "editor.font_size",
+    "editor.spell_check",
+    "editor.tab_size",
+    "sync.enabled",
+];
+
+fn migrate_preference_keys(conn: &SqliteConnection) -> Result<(), flowy_database::Error> {
+    let rows = dsl::user_preference_table
+        .filter(user_preference_table::key.eq_any(LEGACY_KEYS.to_vec()))
+        .load::<UserPreferenceTable>(conn)?;
+    if rows.is_empty() {
+        return Ok(());
+    }
+
+    let mut appearance = AppearanceSetting::default();
+    let mut editor = EditorSetting::default();
+    let mut sync = SyncSetting::default();
+    for row in rows {
+        let preference: Preference = row.into();
+        let value = preference.value;
+        match preference.key.as_str() {
+            "appearance.theme" => {
+                if let Some(theme) = value.as_str().and_then(parse_theme) {
+                    migrate_value(&mut appearance, |appearance| appearance.theme = theme);
+                }
+            },
+            "appearance.locale" => {
+                if let Some(locale) = value.as_str() {
+                    migrate_value(&mut appearance, |appearance| appearance.locale = locale.to_owned());
+                }
+            },
+            "editor.font_size" => {
+                if let Some(font_size) = value.as_int().and_then(|value| i32::try_from(value).ok()) {
+                    migrate_value(&mut editor, |editor| editor.font_size = font_size);
+                }
+            },
+            "editor.spell_check" => {
+                if let Some(spell_check) = value.as_bool() {
+                    migrate_value(&mut editor, |editor| editor.spell_check = spell_check);
+                }
+            },
+            "editor.tab_size" => {
+                if let Some(tab_size) = value.as_int().and_then(|value| i32::try_from(value).ok()) {
+                    migrate_value(&mut editor, |editor| editor.tab_size = tab_size);
+                }
+            },
+            "sync.enabled" => {
+                if let Some(enabled) = value.as_bool() {
+                    migrate_value(&mut sync, |sync| sync.enabled = enabled);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    let _ = write_migrated(appearance, conn)?;
+    let _ = write_migrated(editor, conn)?;
+    let _ = write_migrated(sync, conn)?;
+    let _ = diesel::delete(dsl::user_preference_table.filter(user_preference_table::key.eq_any(LEGACY_KEYS.to_vec())))
+        .execute(conn)?;
+    Ok(())
+}
+
+fn migrate_value<T: SectionData, F: FnOnce(&mut T)>(section: &mut T, f: F) {
+    let mut migrated = section.clone();
+    f(&mut migrated);
+    if migrated.check().is_ok() {
+        *section = migrated;
+    }
+}
+
+// The sections that are their defaults are left to be read as their defaults.
+fn write_migrated<T: SectionData>(section: T, conn: &SqliteConnection) -> Result<(), flowy_database::Error> {
+    if section == T::default() {
+        return Ok(());
+    }
+    write_section(section, conn)
+}
+
+fn parse_theme(theme: &str) -> Option<ThemeMode> {
+    match theme.to_lowercase().as_str() {
+        "system" => Some(ThemeMode::System),
+        "light" => Some(ThemeMode::Light),
+        "dark" => Some(ThemeMode::Dark),
+        _ => None,
+    }
+}
@@ -0,0 +1,5 @@
+mod migration;
+mod setting_controller;
+
+pub use migration::*;
+pub use setting_controller::*;
@@ -0,0 +1,206 @@
+use crate::{
+    entities::{
+        AppSetting,
+        AppearanceSetting,
+        EditorSetting,
+        PrivacySetting,
+        SettingChange,
+        SettingSection,
+        ShortcutSetting,
+        SyncSetting,
+        UpdateSettingParams,
+    },
+    errors::{ErrorCode, UserError},
+    notify::{dart_notify, UserNotification},
+    sql_tables::UserSettingTable,
+};
+use bytes::Bytes;
+use flowy_database::{
+    query_dsl::*,
+    schema::{user_setting_table, user_setting_table::dsl},
+    Connection,
+    ExpressionMethods,
+    OptionalExtension,
+    SqliteConnection,
+};
+use protobuf::ProtobufError;
+use std::convert::{TryFrom, TryInto};
+use tokio::sync::broadcast;
+
+// The sections are stored in the database of each user, the ones that weren't
+// changed yet are read as their defaults.
+pub struct SettingController {
+    notifier: broadcast::Sender<SettingChange>,
+}
+
+impl SettingController {
+    pub(crate) fn new() -> Self {
+        let (notifier, _) = broadcast::channel(10);
+        Self { notifier }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<SettingChange> { self.notifier.subscribe() }
+
+    pub(crate) fn read(&self, conn: &SqliteConnection) -> Result<AppSetting, UserError> { read_app_setting(conn) }
+
+    // The sections that are the same as the stored ones aren't notified.
+    pub(crate) fn update(&self, params: UpdateSettingParams, conn: &SqliteConnection) -> Result<AppSetting, UserError> {
+        let changed = conn.immediate_transaction::<_, UserError, _>(|| {
+            let mut changed = vec![];
+            if let Some(appearance) = params.appearance {
+                if update_section(&appearance, conn)? {
+                    changed.push(SettingSection::Appearance);
+                }
+            }
+            if let Some(editor) = params.editor {
+                if update_section(&editor, conn)? {
+                    changed.push(SettingSection::Editor);
+                }
+            }
+            if let Some(sync) = params.sync {
+                if update_section(&sync, conn)? {
+                    changed.push(SettingSection::Sync);
+                }
+            }
+            if let Some(shortcuts) = params.shortcuts {
+                if update_section(&shortcuts, conn)? {
+                    changed.push(SettingSection::Shortcuts);
+                }
+            }
+            if let Some(privacy) = params.privacy {
+                if update_section(&privacy, conn)? {
+                    changed.push(SettingSection::Privacy);
+                }
+            }
+            Ok(changed)
+        })?;
+
+        let setting = read_app_setting(conn)?;
+        self.notify(changed, &setting);
+        Ok(setting)
+    }
+
+    pub(crate) fn reset(&self, section: SettingSection, conn: &SqliteConnection) -> Result<AppSetting, UserError> {
+        let changed = match section {
+            SettingSection::Appearance => reset_section::<AppearanceSetting>(conn)?,
+            SettingSection::Editor => reset_section::<EditorSetting>(conn)?,
+            SettingSection::Sync => reset_section::<SyncSetting>(conn)?,
+            SettingSection::Shortcuts => reset_section::<ShortcutSetting>(conn)?,
+            SettingSection::Privacy => reset_section::<PrivacySetting>(conn)?,
+        };
+
+        let setting = read_app_setting(conn)?;
+        if changed {
+            self.notify(vec![section], &setting);
+        }
+        Ok(setting)
+    }
+
+    fn notify(&self, sections: Vec<SettingSection>, setting: &AppSetting) {
+        for section in sections {
+            let change = SettingChange {
+                section,
+                setting: setting.clone(),
+            };
+            dart_notify(section.name(), UserNotification::SettingChanged)
+                .payload(change.clone())
+                .send();
+            let _ = self.notifier.send(change);
+        }
+    }
+}
+
+pub(crate) trait SectionData:
+    Default + PartialEq + TryFrom<Bytes, Error = ProtobufError> + TryInto<Bytes, Error = ProtobufError> + Clone
+{
+    const SECTION: SettingSection;
+
+    fn check(&self) -> Result<(), ErrorCode>;
+}
+
+macro_rules! impl_section_data {
+    ($target:ident, $section:expr) => {
+        impl SectionData for $target {
+            const SECTION: SettingSection = $section;
+
+            fn check(&self) -> Result<(), ErrorCode> { $target::check(self) }
+        }
+    };
+}
+
+impl_section_data!(AppearanceSetting, SettingSection::Appearance);
+impl_section_data!(EditorSetting, SettingSection::Editor);
+impl_section_data!(SyncSetting, SettingSection::Sync);
+impl_section_data!(ShortcutSetting, SettingSection::Shortcuts);
+impl_section_data!(PrivacySetting, SettingSection::Privacy);
+
+pub fn read_app_setting(conn: &SqliteConnection) -> Result<AppSetting, UserError> {
+    Ok(AppSetting {
+        appearance: read_section(conn)?,
+        editor: read_section(conn)?,
+        sync: read_section(conn)?,
+        shortcuts: read_section(conn)?,
+        privacy: read_section(conn)?,
+    })
+}
+
+// A section that can't be read anymore, or whose values are out of the ranges
+// of this version, is read as its defaults.
+fn read_section<T: SectionData>(conn: &SqliteConnection) -> Result<T, UserError> {
+    let data = dsl::user_setting_table
+        .filter(user_setting_table::section.eq(T::SECTION.name()))
+        .select(user_setting_table::data)
+        .first::<Vec<u8>>(conn)
+        .optional()?;
+    let data = match data {
+        None => return Ok(T::default()),
+        Some(data) => data,
+    };
+
+    match T::try_from(Bytes::from(data)) {
+        Ok(section) if section.check().is_ok() => Ok(section),
+        Ok(_) => {
+            log::warn!(
+                "The {} setting is out of range, it's read as its defaults",
+                T::SECTION.name()
+            );
+            Ok(T::default())
+        },
+        Err(e) => {
+            log::warn!("The {} setting can't be read: {:?}", T::SECTION.name(), e);
+            Ok(T::default())
+        },
+    }
+}
+
+// Returns false if the section is the same as the stored one.
+fn update_section<T: SectionData>(section: &T, conn: &SqliteConnection) -> Result<bool, UserError> {
+    if &read_section::<T>(conn)? == section {
+        return Ok(false);
+    }
+
+    let _ = write_section(section.clone(), conn)?;
+    Ok(true)
+}
+
+pub(crate) fn write_section<T: SectionData>(section: T, conn: &SqliteConnection) -> Result<(), flowy_database::Error> {
+    let bytes: Bytes = section
+        .try_into()
+        .map_err(|e| flowy_database::Error::SerializationError(Box::new(e)))?;
+    let row = UserSettingTable {
+        section: T::SECTION.name().to_owned(),
+        data: bytes.to_vec(),
+    };
+    let _ = diesel::replace_into(user_setting_table::table)
+        .values(&row)
+        .execute(conn)?;
+    Ok(())
+}
+
+// Returns false if the section was already its defaults.
+fn reset_section<T: SectionData>(conn: &SqliteConnection) -> Result<bool, UserError> {
+    let changed = read_section::<T>(conn)? != T::default();
+    let _ = diesel::delete(dsl::user_setting_table.filter(user_setting_table::section.eq(T::SECTION.name())))
+        .execute(conn)?;
+    Ok(changed)
+}
@@ -0,0 +1,64 @@
+use std::{
+    fs,
+    io::{self, Seek, SeekFrom, Write},
+    path::Path,
+};
+
+// The layout of the user dir, the documents are cached in the doc dir by flowy-document.
+pub(crate) const CACHE_DIRS: [&str; 1] = ["doc"];
+pub(crate) const ATTACHMENT_DIR: &str = "attachments";
+const DATABASE_FILE_PREFIX: &str = "flowy-database.db";
+const WIPE_CHUNK_SIZE: usize = 64 * 1024;
+
+// Overwrites the content with zeros before removing the file, so it can't be restored
+// from the freed blocks. It's best effort, the file system may still keep copies.
+pub(crate) fn secure_remove_file(path: &Path) -> io::Result<()> {
+    let len = fs::metadata(path)?.len();
+    {
+        let mut file = fs::OpenOptions::new().write(true).open(path)?;
+        let _ = file.seek(SeekFrom::Start(0))?;
+        let zeros = vec![0u8; WIPE_CHUNK_SIZE];
+        let mut remaining = len;
+        while remaining > 0 {
+            let n = std::cmp::min(remaining, WIPE_CHUNK_SIZE as u64) as usize;
+            file.write_all(&zeros[..n])?;
+            remaining -= n as u64;
+        }
+        file.sync_all()?;
+    }
+    fs::remove_file(path)
+}
+
+pub(crate) fn secure_remove_dir(dir: &Path) -> io::Result<()> {
+    if !dir.exists() {
+        return Ok(());
+    }
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            secure_remove_dir(&path)?;
+        } else {
+            secure_remove_file(&path)?;
+        }
+    }
+    fs::remove_dir(dir)
+}
+
+// Including the -wal and -shm files of the database.
+pub(crate) fn secure_remove_database(user_dir: &Path) -> io::Result<()> {
+    if !user_dir.exists() {
+        return Ok(());
+    }
+
+    for entry in fs::read_dir(user_dir)? {
+        let path = entry?.path();
+        let is_database = path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with(DATABASE_FILE_PREFIX));
+        if path.is_file() && is_database {
+            secure_remove_file(&path)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use crate::{
+    entities::{AuditRecord, ReadAuditLogRequest, RepeatedAuditRecord},
+    errors::UserError,
+    services::user::UserSession,
+    sql_tables::AuditLogTable,
+};
+use flowy_database::{
+    prelude::*,
+    schema::{audit_log_table, audit_log_table::dsl},
+    SqliteConnection,
+};
+use flowy_dispatch::prelude::*;
+use futures_core::future::BoxFuture;
+use protobuf::{wire_format::WireType, CodedInputStream};
+use std::sync::Arc;
+
+pub const DEFAULT_AUDIT_LOG_LIMIT: i64 = 100;
+
+// Records the mutating events that succeeded into the database of the user, who
+// sent which event for which object and when. The events are the ones that need
+// the write or manage permission.
+pub struct AuditLog {
+    session: Arc<UserSession>,
+    events: Vec<Event>,
+}
+
+impl AuditLog {
+    pub fn new(session: Arc<UserSession>, events: Vec<Event>) -> Self { Self { session, events } }
+
+    fn write(&self, request: &ModuleRequest) -> Result<(), UserError> {
+        let user_id = self.session.user_id()?;
+        let conn = self.session.db_write_connection()?;
+        let _ = diesel::insert_into(audit_log_table::table)
+            .values((
+                dsl::user_id.eq(user_id),
+                dsl::event.eq(request.event.name()),
+                dsl::object_id.eq(target_object_id(request).unwrap_or_default()),
+                dsl::create_time.eq(flowy_infra::timestamp()),
+            ))
+            .execute(&*conn)?;
+        Ok(())
+    }
+}
+
+impl EventMiddleware for AuditLog {
+    fn intercept(&self, request: &ModuleRequest, response: &EventResponse) -> BoxFuture<'static, bool> {
+        if response.status_code == StatusCode::Ok && self.events.contains(&request.event) {
+            if let Err(e) = self.write(request) {
+                log::error!("Write the audit log of {:?} failed: {:?}", request.event, e);
+            }
+        }
+        Box::pin(async { false })
+    }
+}
+
+// The requests put the id of the object they change first, e.g. the view_id of
+// UpdateViewRequest. The field 1 is read if it's a string, the nested messages
+// start with a tag, so they aren't taken as one.
+fn target_object_id(request: &ModuleRequest) -> Option<String> {
+    if request.payload_encoding() != PayloadEncoding::Native {
+        return None;
+    }
+    let bytes = request.payload_bytes()?;
+    let mut input = CodedInputStream::from_bytes(bytes);
+    match input.read_tag_unpack().ok()? {
+        (1, WireType::WireTypeLengthDelimited) => {
+            let object_id = String::from_utf8(input.read_bytes().ok()?).ok()?;
+            if object_id.chars().any(|c| c.is_control()) {
+                return None;
+            }
+            Some(object_id)
+        },
+        _ => None,
+    }
+}
+
+pub(crate) fn read_audit_log(
+    request: ReadAuditLogRequest,
+    conn: &SqliteConnection,
+) -> Result<RepeatedAuditRecord, UserError> {
+    let mut query = dsl::audit_log_table.into_boxed();
+    if let Some(object_id) = request.object_id {
+        query = query.filter(audit_log_table::object_id.eq(object_id));
+    }
+    if let Some(from_time) = request.from_time {
+        query = query.filter(audit_log_table::create_time.ge(from_time));
+    }
+    if let Some(to_time) = request.to_time {
+        query = query.filter(audit_log_table::create_time.le(to_time));
+    }
+    let items = query
+        .order(audit_log_table::id.desc())
+        .limit(request.limit.unwrap_or(DEFAULT_AUDIT_LOG_LIMIT))
+        .load::<AuditLogTable>(conn)?
+        .into_iter()
+        .map(AuditRecord::from)
+        .collect();
+    Ok(RepeatedAuditRecord { items })
+}
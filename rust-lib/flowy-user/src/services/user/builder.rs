@@ -1,5 +1,10 @@
-use crate::services::user::{UserSession, UserSessionConfig};
+use crate::services::user::{OAuthProvider, UserSession, UserSessionConfig};
+use flowy_database::{PoolConfig, RustMigration};
+use flowy_infra::{clock::Clock, secret::SecretStoreKind};
 use flowy_net::config::ServerConfig;
+use flowy_user_infra::parser::PasswordPolicy;
+use flowy_ws::{ConnectivityConfig, WsConfig};
+use std::sync::Arc;
 
 pub struct UserSessionBuilder {
     config: Option<UserSessionConfig>,
@@ -13,6 +18,86 @@ impl UserSessionBuilder {
         self
     }
 
+    pub fn session_expiration(mut self, seconds: i64) -> Self {
+        if let Some(config) = self.config.as_mut() {
+            config.session_expiration = seconds;
+        }
+        self
+    }
+
+    pub fn password_policy(mut self, policy: PasswordPolicy) -> Self {
+        if let Some(config) = self.config.as_mut() {
+            config.password_policy = policy;
+        }
+        self
+    }
+
+    pub fn oauth_provider(mut self, provider: Arc<dyn OAuthProvider>) -> Self {
+        if let Some(config) = self.config.as_mut() {
+            config.oauth_providers.insert(provider.provider_type(), provider);
+        }
+        self
+    }
+
+    pub fn secret_store(mut self, kind: SecretStoreKind) -> Self {
+        if let Some(config) = self.config.as_mut() {
+            config.secret_store_kind = kind;
+        }
+        self
+    }
+
+    pub fn pool_config(mut self, pool_config: PoolConfig) -> Self {
+        if let Some(config) = self.config.as_mut() {
+            config.pool_config = pool_config;
+        }
+        self
+    }
+
+    pub fn migration(mut self, migration: RustMigration) -> Self {
+        if let Some(config) = self.config.as_mut() {
+            config.migrations.push(migration);
+        }
+        self
+    }
+
+    // Opens the user databases with SQLCipher, the ones that aren't encrypted yet
+    // are encrypted the first time they're opened. It needs the sqlcipher feature.
+    pub fn encrypt_database(mut self, encrypt: bool) -> Self {
+        if let Some(config) = self.config.as_mut() {
+            config.encrypt_database = encrypt;
+        }
+        self
+    }
+
+    pub fn max_backups(mut self, max_backups: usize) -> Self {
+        if let Some(config) = self.config.as_mut() {
+            config.max_backups = max_backups;
+        }
+        self
+    }
+
+    pub fn ws_config(mut self, ws_config: WsConfig) -> Self {
+        if let Some(config) = self.config.as_mut() {
+            config.ws_config = ws_config;
+        }
+        self
+    }
+
+    pub fn connectivity_config(mut self, connectivity_config: ConnectivityConfig) -> Self {
+        if let Some(config) = self.config.as_mut() {
+            config.connectivity_config = connectivity_config;
+        }
+        self
+    }
+
+    // The clock the sessions expire by.
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        if let Some(config) = self.config.as_mut() {
+            config.clock = clock;
+        }
+        self
+    }
+
     pub fn build(mut self) -> UserSession {
         let config = self.config.take().unwrap();
         UserSession::new(config)
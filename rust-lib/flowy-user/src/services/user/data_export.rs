@@ -0,0 +1,100 @@
+use crate::errors::UserError;
+use flowy_database::{ExportedTable, SqliteConnection, SECRET_COLUMNS};
+use flowy_user_infra::entities::{DataExport, DataExportFormat, DataExportTable};
+use serde::Serialize;
+use std::path::Path;
+
+// The layout of the exports, see the manifest.
+//
+// Version 1: the directory has a "manifest.json" and the data, in one of the
+// formats:
+// - json_lines: a "{table}.jsonl" file per table of the database, one JSON
+//   object per row keyed by the column names. The blobs, like the revisions of
+//   the documents, are hex strings.
+// - sqlite: "data.db", a plaintext copy of the database.
+// The secrets, the columns of SECRET_COLUMNS, are left out of both.
+pub const DATA_EXPORT_VERSION: i32 = 1;
+
+const MANIFEST_NAME: &str = "manifest.json";
+const SQLITE_EXPORT_NAME: &str = "data.db";
+
+#[derive(Serialize)]
+struct DataExportManifest<'a> {
+    version: i32,
+    format: &'a str,
+    user_id: &'a str,
+    created_time: i64,
+    tables: Vec<ManifestTable<'a>>,
+}
+
+#[derive(Serialize)]
+struct ManifestTable<'a> {
+    name: &'a str,
+    rows: i64,
+    // Relative to the directory of the export.
+    file: String,
+}
+
+pub(crate) fn export_data(
+    conn: &SqliteConnection,
+    user_id: &str,
+    dir: &str,
+    format: DataExportFormat,
+    is_encrypted: bool,
+    created_time: i64,
+) -> Result<DataExport, UserError> {
+    let _ = std::fs::create_dir_all(dir).map_err(|e| UserError::internal().context(e))?;
+    let tables = match format {
+        DataExportFormat::JsonLines => flowy_database::export_json_lines(conn, dir, SECRET_COLUMNS),
+        DataExportFormat::Sqlite => {
+            let path = Path::new(dir).join(SQLITE_EXPORT_NAME);
+            flowy_database::export_sqlite(conn, &path.to_string_lossy(), is_encrypted, SECRET_COLUMNS)
+        },
+    }
+    .map_err(|e| UserError::internal().context(e))?;
+
+    let manifest = DataExportManifest {
+        version: DATA_EXPORT_VERSION,
+        format: format_name(format),
+        user_id,
+        created_time,
+        tables: tables
+            .iter()
+            .map(|table| ManifestTable {
+                name: &table.name,
+                rows: table.rows,
+                file: table_file(format, table),
+            })
+            .collect(),
+    };
+    let json = serde_json::to_string_pretty(&manifest).map_err(|e| UserError::internal().context(e))?;
+    let _ = std::fs::write(Path::new(dir).join(MANIFEST_NAME), json).map_err(|e| UserError::internal().context(e))?;
+
+    Ok(DataExport {
+        dir: dir.to_owned(),
+        format,
+        version: DATA_EXPORT_VERSION,
+        created_time,
+        tables: tables
+            .into_iter()
+            .map(|table| DataExportTable {
+                name: table.name,
+                rows: table.rows,
+            })
+            .collect(),
+    })
+}
+
+fn format_name(format: DataExportFormat) -> &'static str {
+    match format {
+        DataExportFormat::JsonLines => "json_lines",
+        DataExportFormat::Sqlite => "sqlite",
+    }
+}
+
+fn table_file(format: DataExportFormat, table: &ExportedTable) -> String {
+    match format {
+        DataExportFormat::JsonLines => format!("{}.jsonl", table.name),
+        DataExportFormat::Sqlite => SQLITE_EXPORT_NAME.to_owned(),
+    }
+}
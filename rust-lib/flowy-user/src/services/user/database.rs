@@ -1,101 +1,371 @@
-use crate::errors::UserError;
-use flowy_database::{DBConnection, Database};
+use crate::{errors::UserError, notify::*, services::user::data_export};
+use flowy_database::{
+    DBConnection,
+    Database,
+    DatabaseKey,
+    EncryptionProgress,
+    InitError,
+    PoolConfig,
+    RowChange,
+    RowChangeKind,
+    RustMigration,
+    WriteConnection,
+};
+use flowy_infra::secret::{derive_key, generate_secret, SecretStore};
+use flowy_user_infra::entities::{
+    DataExport,
+    DataExportFormat,
+    DatabaseBackup,
+    DatabaseEncryptionProgress,
+    DatabaseMigrationFailure,
+    DatabaseRowChange,
+    RepeatedDatabaseRowChange,
+    RowChangeType,
+    StorageCompaction,
+};
 use flowy_sqlite::ConnectionPool;
-use lazy_static::lazy_static;
-use once_cell::sync::Lazy;
 use parking_lot::{Mutex, RwLock};
-use std::{collections::HashMap, sync::Arc, time::Duration};
-lazy_static! {
-    static ref DB: RwLock<Option<Database>> = RwLock::new(None);
-}
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tokio::sync::broadcast;
 
+// Each user has their own database in the directory named after the user id.
+// The database is opened when the user signs in, or when the app restores the
+// session, and closed when the user signs out or another account becomes the
+// current one.
 pub(crate) struct UserDB {
     db_dir: String,
+    databases: RwLock<HashMap<String, Database>>,
+    // Opening the database applies its migrations, it's only done once.
+    open_lock: Mutex<()>,
+    pool_config: PoolConfig,
+    migrations: Vec<RustMigration>,
+    // The keys of the encrypted databases are derived from the secrets kept
+    // here, None if the databases aren't encrypted.
+    encryption: Option<Arc<dyn SecretStore>>,
+    row_change_notifier: broadcast::Sender<RepeatedDatabaseRowChange>,
 }
 
 impl UserDB {
-    pub(crate) fn new(db_dir: &str) -> Self {
+    pub(crate) fn new(
+        db_dir: &str,
+        pool_config: PoolConfig,
+        migrations: Vec<RustMigration>,
+        encryption: Option<Arc<dyn SecretStore>>,
+    ) -> Self {
+        let (row_change_notifier, _) = broadcast::channel(100);
         Self {
             db_dir: db_dir.to_owned(),
+            databases: RwLock::new(HashMap::new()),
+            open_lock: Mutex::new(()),
+            pool_config,
+            migrations,
+            encryption,
+            row_change_notifier,
         }
     }
 
-    fn open_user_db(&self, user_id: &str) -> Result<(), UserError> {
+    pub(crate) fn row_change_subscribe(&self) -> broadcast::Receiver<RepeatedDatabaseRowChange> {
+        self.row_change_notifier.subscribe()
+    }
+
+    // The database can't be opened anymore if the secret is lost.
+    fn database_key(&self, secret_store: &Arc<dyn SecretStore>, user_id: &str) -> Result<DatabaseKey, UserError> {
+        let key = format!("{}_database_secret", user_id);
+        let secret = match secret_store.get(&key).map_err(|e| UserError::internal().context(e))? {
+            Some(secret) => secret,
+            None => {
+                let secret = generate_secret();
+                let _ = secret_store
+                    .set(&key, &secret)
+                    .map_err(|e| UserError::internal().context(e))?;
+                secret
+            },
+        };
+        Ok(DatabaseKey::new(derive_key(secret.as_bytes(), user_id)))
+    }
+
+    // The databases that were created before the encryption was enabled are
+    // encrypted once, the first time they're opened.
+    fn encrypt_user_db(&self, dir: &str, key: &DatabaseKey, user_id: &str) -> Result<(), UserError> {
+        let notify_progress = |progress: EncryptionProgress| {
+            let progress = DatabaseEncryptionProgress {
+                user_id: user_id.to_owned(),
+                done: progress.done as i64,
+                total: progress.total as i64,
+            };
+            dart_notify(user_id, UserNotification::DatabaseEncryptionProgress)
+                .payload(progress)
+                .send();
+        };
+        match flowy_database::encrypt(dir, key, notify_progress) {
+            Ok(true) => {
+                tracing::info!("user db {} encrypted", user_id);
+                Ok(())
+            },
+            Ok(false) => Ok(()),
+            Err(e) => {
+                log::error!("encrypt user db failed, {:?}, user_id: {}", e, user_id);
+                if e.is_cipher_unsupported() {
+                    Err(UserError::encryption_unsupported().context(e))
+                } else {
+                    Err(UserError::encryption_failed().context(e))
+                }
+            },
+        }
+    }
+
+    // Nothing is done if the database is already opened.
+    pub(crate) fn open_user_db(&self, user_id: &str) -> Result<Arc<ConnectionPool>, UserError> {
         if user_id.is_empty() {
             return Err(UserError::internal().context("user id is empty"));
         }
+        let _guard = self.open_lock.lock();
+        if let Some(database) = self.databases.read().get(user_id) {
+            return Ok(database.get_pool());
+        }
 
         tracing::info!("open user db {}", user_id);
         let dir = format!("{}/{}", self.db_dir, user_id);
-        let db = flowy_database::init(&dir).map_err(|e| {
+        let mut pool_config = self.pool_config.clone().observe_rows();
+        if let Some(key) = self.encryption_key(user_id)? {
+            let _ = self.encrypt_user_db(&dir, &key, user_id)?;
+            pool_config = pool_config.encryption_key(key);
+        }
+
+        let db = flowy_database::init_with_config(&dir, pool_config, &self.migrations).map_err(|e| {
             log::error!("init user db failed, {:?}, user_id: {}", e, user_id);
-            UserError::internal().context(e)
+            match e {
+                InitError::Io(e) => UserError::internal().context(e),
+                InitError::Migration(e) => {
+                    let failure = DatabaseMigrationFailure {
+                        user_id: user_id.to_owned(),
+                        version: e.version.clone(),
+                        msg: e.msg.clone(),
+                    };
+                    dart_notify(user_id, UserNotification::DatabaseMigrationFailed)
+                        .payload(failure)
+                        .send();
+                    UserError::migration_failed().context(e)
+                },
+                InitError::Encryption(e) => UserError::encryption_failed().context(e),
+            }
         })?;
 
-        match DB_MAP.try_write_for(Duration::from_millis(300)) {
+        let pool = db.get_pool();
+        let owner = user_id.to_owned();
+        let row_change_notifier = self.row_change_notifier.clone();
+        pool.observe_rows(move |changes| notify_row_changes(&owner, changes, &row_change_notifier));
+        match self.databases.try_write_for(Duration::from_millis(300)) {
             None => Err(UserError::internal().context(format!("Acquire write lock to save user db failed"))),
             Some(mut write_guard) => {
                 write_guard.insert(user_id.to_owned(), db);
-                Ok(())
+                Ok(pool)
             },
         }
     }
 
+    // The backups are kept next to the database, the newest max_backups ones
+    // are kept.
+    pub(crate) fn create_backup(&self, user_id: &str, max_backups: usize) -> Result<DatabaseBackup, UserError> {
+        let backup_dir = self.backup_dir(user_id);
+        let _ = std::fs::create_dir_all(&backup_dir).map_err(|e| UserError::internal().context(e))?;
+        let mut created_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis() as i64)
+            .unwrap_or(0);
+        while std::path::Path::new(&backup_path(&backup_dir, &created_time.to_string())).exists() {
+            created_time += 1;
+        }
+
+        let id = created_time.to_string();
+        let path = backup_path(&backup_dir, &id);
+        {
+            let conn = self.get_connection(user_id)?;
+            let _ = flowy_database::backup_database(&*conn, &path).map_err(|e| UserError::internal().context(e))?;
+        }
+        tracing::info!("user db {} backed up to {}", user_id, path);
+
+        for expired in self.list_backups(user_id)?.into_iter().skip(max_backups.max(1)) {
+            let _ = std::fs::remove_file(backup_path(&backup_dir, &expired.id));
+        }
+        let size = std::fs::metadata(&path)
+            .map(|metadata| metadata.len() as i64)
+            .unwrap_or(0);
+        Ok(DatabaseBackup { id, created_time, size })
+    }
+
+    // The writers wait for the compaction, the readers don't.
+    pub(crate) fn compact(&self, user_id: &str) -> Result<StorageCompaction, UserError> {
+        let conn = self.get_write_connection(user_id)?;
+        let compaction = flowy_database::compact_database(&*conn).map_err(|e| UserError::internal().context(e))?;
+        tracing::debug!("user db {} compacted, {:?}", user_id, compaction);
+        Ok(StorageCompaction {
+            size_before: compaction.size_before,
+            size_after: compaction.size_after,
+            reclaimed_bytes: compaction.reclaimed_bytes(),
+        })
+    }
+
+    // The newest first.
+    pub(crate) fn list_backups(&self, user_id: &str) -> Result<Vec<DatabaseBackup>, UserError> {
+        let entries = match std::fs::read_dir(self.backup_dir(user_id)) {
+            Ok(entries) => entries,
+            Err(_) => return Ok(vec![]),
+        };
+        let mut backups: Vec<DatabaseBackup> = entries
+            .flatten()
+            .flat_map(|entry| {
+                let file_name = entry.file_name().into_string().ok()?;
+                let id = file_name.strip_suffix(BACKUP_EXTENSION)?;
+                let created_time = id.parse::<i64>().ok()?;
+                let size = entry.metadata().map(|metadata| metadata.len() as i64).unwrap_or(0);
+                Some(DatabaseBackup {
+                    id: id.to_owned(),
+                    created_time,
+                    size,
+                })
+            })
+            .collect();
+        backups.sort_by(|a, b| b.created_time.cmp(&a.created_time));
+        Ok(backups)
+    }
+
+    // The backup is verified before any row of the database is replaced.
+    pub(crate) fn restore_backup(&self, user_id: &str, backup_id: &str) -> Result<(), UserError> {
+        let path = backup_path(&self.backup_dir(user_id), backup_id);
+        if !std::path::Path::new(&path).exists() {
+            return Err(UserError::backup_not_exist());
+        }
+
+        let key = self.encryption_key(user_id)?;
+        let _ = flowy_database::check_integrity(&path, key.as_ref()).map_err(|e| {
+            log::error!("backup {} of user db {} is corrupted: {:?}", backup_id, user_id, e);
+            UserError::backup_corrupted().context(e)
+        })?;
+        let conn = self.get_write_connection(user_id)?;
+        let _ = flowy_database::restore(&*conn, &path, key.as_ref(), &self.migrations).map_err(|e| match e {
+            InitError::Migration(e) => UserError::migration_failed().context(e),
+            e => UserError::internal().context(e),
+        })?;
+        tracing::info!("user db {} restored from {}", user_id, backup_id);
+        Ok(())
+    }
+
+    // Each export is kept in its own directory, in the export directory of the
+    // user.
+    pub(crate) fn export_data(&self, user_id: &str, format: DataExportFormat) -> Result<DataExport, UserError> {
+        let mut created_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis() as i64)
+            .unwrap_or(0);
+        let export_dir = format!("{}/{}/export", self.db_dir, user_id);
+        while std::path::Path::new(&format!("{}/data_{}", export_dir, created_time)).exists() {
+            created_time += 1;
+        }
+
+        let dir = format!("{}/data_{}", export_dir, created_time);
+        let conn = self.get_connection(user_id)?;
+        let export = data_export::export_data(&*conn, user_id, &dir, format, self.encryption.is_some(), created_time)?;
+        tracing::info!("user db {} exported to {}", user_id, dir);
+        Ok(export)
+    }
+
+    fn backup_dir(&self, user_id: &str) -> String { format!("{}/{}/backups", self.db_dir, user_id) }
+
+    fn encryption_key(&self, user_id: &str) -> Result<Option<DatabaseKey>, UserError> {
+        match &self.encryption {
+            None => Ok(None),
+            Some(secret_store) => Ok(Some(self.database_key(secret_store, user_id)?)),
+        }
+    }
+
+    // The connections that are still used are closed once they're dropped.
     pub(crate) fn close_user_db(&self, user_id: &str) -> Result<(), UserError> {
-        match DB_MAP.try_write_for(Duration::from_millis(300)) {
+        match self.databases.try_write_for(Duration::from_millis(300)) {
             None => Err(UserError::internal().context(format!("Acquire write lock to close user db failed"))),
             Some(mut write_guard) => {
-                set_user_db_init(false, user_id);
-                write_guard.remove(user_id);
+                if write_guard.remove(user_id).is_some() {
+                    tracing::info!("close user db {}", user_id);
+                }
                 Ok(())
             },
         }
     }
 
+    // Closes the databases of the users other than the current one, all of them
+    // if no user is signed in.
+    pub(crate) fn close_other_user_dbs(&self, current_user_id: Option<&str>) -> Result<(), UserError> {
+        let user_ids: Vec<String> = self
+            .databases
+            .read()
+            .keys()
+            .filter(|user_id| Some(user_id.as_str()) != current_user_id)
+            .cloned()
+            .collect();
+        for user_id in user_ids {
+            let _ = self.close_user_db(&user_id)?;
+        }
+        Ok(())
+    }
+
     pub(crate) fn get_connection(&self, user_id: &str) -> Result<DBConnection, UserError> {
         let conn = self.get_pool(user_id)?.get()?;
         Ok(conn)
     }
 
-    pub(crate) fn get_pool(&self, user_id: &str) -> Result<Arc<ConnectionPool>, UserError> {
-        // Opti: INIT_LOCK try to lock the INIT_RECORD accesses. Because the write guard
-        // can not nested in the read guard that will cause the deadlock.
-        match INIT_LOCK.try_lock_for(Duration::from_millis(300)) {
-            None => log::error!("get_pool fail"),
-            Some(_) => {
-                if !is_user_db_init(user_id) {
-                    let _ = self.open_user_db(user_id)?;
-                    set_user_db_init(true, user_id);
-                }
-            },
-        }
+    pub(crate) fn get_write_connection(&self, user_id: &str) -> Result<WriteConnection, UserError> {
+        let conn = self.get_pool(user_id)?.get_write()?;
+        Ok(conn)
+    }
 
-        match DB_MAP.try_read_for(Duration::from_millis(300)) {
+    // The database is opened if it isn't yet, e.g. the one of the session that
+    // was restored.
+    pub(crate) fn get_pool(&self, user_id: &str) -> Result<Arc<ConnectionPool>, UserError> {
+        match self.databases.try_read_for(Duration::from_millis(300)) {
             None => Err(UserError::internal().context(format!("Acquire read lock to read user db failed"))),
             Some(read_guard) => match read_guard.get(user_id) {
-                None => Err(UserError::internal().context("Get connection failed. The database is not initialization")),
                 Some(database) => Ok(database.get_pool()),
+                None => {
+                    drop(read_guard);
+                    self.open_user_db(user_id)
+                },
             },
         }
     }
 }
 
-lazy_static! {
-    static ref DB_MAP: RwLock<HashMap<String, Database>> = RwLock::new(HashMap::new());
-}
+const BACKUP_EXTENSION: &str = ".db";
 
-static INIT_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
-static INIT_RECORD: Lazy<Mutex<HashMap<String, bool>>> = Lazy::new(|| Mutex::new(HashMap::new()));
-fn set_user_db_init(is_init: bool, user_id: &str) {
-    let mut record = INIT_RECORD.lock();
-    record.insert(user_id.to_owned(), is_init);
-}
+fn backup_path(backup_dir: &str, id: &str) -> String { format!("{}/{}{}", backup_dir, id, BACKUP_EXTENSION) }
 
-fn is_user_db_init(user_id: &str) -> bool {
-    match INIT_RECORD.lock().get(user_id) {
-        None => false,
-        Some(flag) => flag.clone(),
-    }
+// The changes are sent to the Dart side and to the subscribers of the session,
+// the lists can be read again from the database.
+fn notify_row_changes(
+    user_id: &str,
+    changes: &[RowChange],
+    row_change_notifier: &broadcast::Sender<RepeatedDatabaseRowChange>,
+) {
+    let items = changes
+        .iter()
+        .map(|change| DatabaseRowChange {
+            table: change.table.clone(),
+            key: change.key.clone(),
+            ty: match change.kind {
+                RowChangeKind::Insert => RowChangeType::Insert,
+                RowChangeKind::Update => RowChangeType::Update,
+                RowChangeKind::Delete => RowChangeType::Delete,
+            },
+        })
+        .collect();
+    let changes = RepeatedDatabaseRowChange { items };
+    dart_notify(user_id, UserNotification::DatabaseRowsChanged)
+        .payload(changes.clone())
+        .send();
+    let _ = row_change_notifier.send(changes);
 }
 
 #[cfg(test)]
@@ -0,0 +1,22 @@
+use flowy_infra::{kv::KV, uuid};
+
+const DEVICE_ID_CACHE_KEY: &str = "device_id";
+
+// The device id is generated once and kept in the KV, so the server can tell the sessions
+// of this device from the others.
+pub(crate) fn device_id() -> String {
+    match KV::get_str(DEVICE_ID_CACHE_KEY) {
+        Some(device_id) if !device_id.is_empty() => device_id,
+        _ => {
+            let device_id = uuid();
+            KV::set_str(DEVICE_ID_CACHE_KEY, device_id.clone());
+            device_id
+        },
+    }
+}
+
+pub(crate) fn device_name() -> String {
+    std::env::var("HOSTNAME")
+        .or_else(|_| std::env::var("COMPUTERNAME"))
+        .unwrap_or_else(|_| std::env::consts::OS.to_owned())
+}
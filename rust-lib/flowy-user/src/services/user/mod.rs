@@ -1,6 +1,22 @@
+pub use audit::{AuditLog, DEFAULT_AUDIT_LOG_LIMIT};
 pub use builder::*;
+pub use data_export::DATA_EXPORT_VERSION;
+pub use flowy_user_infra::parser::PasswordPolicy;
+pub use oauth::*;
+pub use session_guard::*;
+pub use token_refresher::*;
 pub use user_session::*;
+pub use verification_guard::*;
 
+mod account_wipe;
+mod audit;
 mod builder;
+mod data_export;
 pub mod database;
+mod device;
+mod oauth;
+mod session_guard;
+mod sign_in_throttle;
+mod token_refresher;
 mod user_session;
+mod verification_guard;
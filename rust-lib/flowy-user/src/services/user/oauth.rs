@@ -0,0 +1,118 @@
+use crate::entities::{OAuthProviderType, OAuthSignInParams};
+use std::sync::Arc;
+use url::Url;
+
+const GOOGLE_AUTHORIZATION_URL: &str = "https://accounts.google.com/o/oauth2/v2/auth";
+const GITHUB_AUTHORIZATION_URL: &str = "https://github.com/login/oauth/authorize";
+
+#[derive(Debug, Clone)]
+pub struct OAuthConfig {
+    pub client_id: String,
+    // The uri the provider redirects to with the code, it must be registered with the provider.
+    pub redirect_uri: String,
+    pub scopes: Vec<String>,
+}
+
+impl OAuthConfig {
+    pub fn new(client_id: &str, redirect_uri: &str) -> Self {
+        Self {
+            client_id: client_id.to_owned(),
+            redirect_uri: redirect_uri.to_owned(),
+            scopes: vec![],
+        }
+    }
+
+    pub fn scopes(mut self, scopes: Vec<&str>) -> Self {
+        self.scopes = scopes.into_iter().map(|s| s.to_owned()).collect();
+        self
+    }
+}
+
+// The client only builds the authorization url. Exchanging the code for the tokens needs
+// the client secret, so the server does it with the OAuthSignInParams.
+pub trait OAuthProvider: Send + Sync {
+    fn provider_type(&self) -> OAuthProviderType;
+
+    fn authorization_url(&self, state: &str) -> String;
+
+    fn redirect_uri(&self) -> String;
+
+    fn sign_in_params(&self, code: &str) -> OAuthSignInParams {
+        OAuthSignInParams {
+            provider: self.provider_type(),
+            code: code.to_owned(),
+            redirect_uri: self.redirect_uri(),
+            ..Default::default()
+        }
+    }
+}
+
+pub fn mk_oauth_provider(provider: OAuthProviderType, config: OAuthConfig) -> Arc<dyn OAuthProvider> {
+    match provider {
+        OAuthProviderType::Google => Arc::new(GoogleOAuthProvider::new(config)),
+        OAuthProviderType::GitHub => Arc::new(GitHubOAuthProvider::new(config)),
+    }
+}
+
+pub struct GoogleOAuthProvider {
+    config: OAuthConfig,
+}
+
+impl GoogleOAuthProvider {
+    pub fn new(mut config: OAuthConfig) -> Self {
+        if config.scopes.is_empty() {
+            config.scopes = vec!["openid".to_owned(), "email".to_owned(), "profile".to_owned()];
+        }
+        Self { config }
+    }
+}
+
+impl OAuthProvider for GoogleOAuthProvider {
+    fn provider_type(&self) -> OAuthProviderType { OAuthProviderType::Google }
+
+    fn authorization_url(&self, state: &str) -> String {
+        authorization_url(
+            GOOGLE_AUTHORIZATION_URL,
+            &self.config,
+            state,
+            &[("response_type", "code"), ("access_type", "offline")],
+        )
+    }
+
+    fn redirect_uri(&self) -> String { self.config.redirect_uri.clone() }
+}
+
+pub struct GitHubOAuthProvider {
+    config: OAuthConfig,
+}
+
+impl GitHubOAuthProvider {
+    pub fn new(mut config: OAuthConfig) -> Self {
+        if config.scopes.is_empty() {
+            config.scopes = vec!["read:user".to_owned(), "user:email".to_owned()];
+        }
+        Self { config }
+    }
+}
+
+impl OAuthProvider for GitHubOAuthProvider {
+    fn provider_type(&self) -> OAuthProviderType { OAuthProviderType::GitHub }
+
+    fn authorization_url(&self, state: &str) -> String {
+        authorization_url(GITHUB_AUTHORIZATION_URL, &self.config, state, &[])
+    }
+
+    fn redirect_uri(&self) -> String { self.config.redirect_uri.clone() }
+}
+
+fn authorization_url(base: &str, config: &OAuthConfig, state: &str, extra: &[(&str, &str)]) -> String {
+    let scope = config.scopes.join(" ");
+    let mut params = vec![
+        ("client_id", config.client_id.as_str()),
+        ("redirect_uri", config.redirect_uri.as_str()),
+        ("scope", scope.as_str()),
+        ("state", state),
+    ];
+    params.extend_from_slice(extra);
+    Url::parse_with_params(base, &params).unwrap().to_string()
+}
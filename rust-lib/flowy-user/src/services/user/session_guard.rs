@@ -0,0 +1,48 @@
+use crate::{errors::UserError, event::UserEvent, services::user::UserSession};
+use flowy_dispatch::prelude::*;
+use futures_core::future::BoxFuture;
+use lazy_static::lazy_static;
+use std::sync::Arc;
+
+lazy_static! {
+    // The events that replace or leave the revoked session, and the ones the host
+    // sends whoever is signed in. The logs can be exported either way.
+    static ref SESSION_FREE_EVENTS: Vec<Event> = vec![
+        UserEvent::SignIn.into(),
+        UserEvent::SignUp.into(),
+        UserEvent::SignOut.into(),
+        UserEvent::GetOAuthURL.into(),
+        UserEvent::OAuthSignIn.into(),
+        UserEvent::StartAnonymous.into(),
+        UserEvent::ListAccounts.into(),
+        UserEvent::SwitchAccount.into(),
+        UserEvent::UpdateNetworkState.into(),
+        UserEvent::ReadLogFiles.into(),
+    ];
+}
+
+// Rejects every request with SessionRevoked once the session was revoked on another
+// device, until the user signs in again.
+pub struct SessionGuard {
+    session: Arc<UserSession>,
+}
+
+impl SessionGuard {
+    pub fn new(session: Arc<UserSession>) -> Self { Self { session } }
+}
+
+impl EventGuard for SessionGuard {
+    fn check(
+        &self,
+        request: &ModuleRequest,
+        _permission: Option<EventPermission>,
+    ) -> BoxFuture<'static, Result<(), EventResponse>> {
+        let is_rejected = self.session.is_session_revoked() && !SESSION_FREE_EVENTS.contains(&request.event);
+        Box::pin(async move {
+            if is_rejected {
+                return Err(UserError::session_revoked().as_response());
+            }
+            Ok(())
+        })
+    }
+}
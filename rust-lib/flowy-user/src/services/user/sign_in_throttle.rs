@@ -0,0 +1,81 @@
+use crate::errors::{ErrorCode, UserError};
+use flowy_infra::{kv::KV, timestamp};
+use serde::{Deserialize, Serialize};
+
+// The failed attempts that are allowed before the email gets locked.
+const MAX_FREE_ATTEMPTS: u32 = 5;
+// The first lockout, it's doubled by every failed attempt after it.
+const LOCKOUT_BASE_SECONDS: i64 = 30;
+const LOCKOUT_MAX_SECONDS: i64 = 60 * 60;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct SignInAttempts {
+    failures: u32,
+    locked_until: i64,
+}
+
+// Throttles the sign in of each email. The attempts are kept in the KV, so restarting the
+// app doesn't reset the lockout.
+pub(crate) struct SignInThrottle;
+
+impl SignInThrottle {
+    pub(crate) fn check(email: &str) -> Result<(), UserError> {
+        let attempts = read_attempts(email);
+        let retry_after = attempts.locked_until - timestamp();
+        if retry_after > 0 {
+            return Err(UserError::too_many_attempts(retry_after));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn record<T>(email: &str, result: &Result<T, UserError>) {
+        match result {
+            Ok(_) => {
+                let _ = KV::remove(&cache_key(email));
+            },
+            // Being offline isn't an attempt with the wrong credentials.
+            Err(e) if e.code == ErrorCode::ServerError.value() => {},
+            Err(_) => {
+                let mut attempts = read_attempts(email);
+                attempts.failures += 1;
+                if attempts.failures >= MAX_FREE_ATTEMPTS {
+                    attempts.locked_until = timestamp() + lockout_seconds(attempts.failures);
+                }
+                save_attempts(email, &attempts);
+            },
+        }
+    }
+}
+
+fn lockout_seconds(failures: u32) -> i64 {
+    let exponent = (failures - MAX_FREE_ATTEMPTS).min(16);
+    (LOCKOUT_BASE_SECONDS << exponent).min(LOCKOUT_MAX_SECONDS)
+}
+
+fn cache_key(email: &str) -> String { format!("sign_in_attempts_{}", email) }
+
+fn read_attempts(email: &str) -> SignInAttempts {
+    KV::get_str(&cache_key(email))
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+fn save_attempts(email: &str, attempts: &SignInAttempts) {
+    match serde_json::to_string(attempts) {
+        Ok(s) => KV::set_str(&cache_key(email), s),
+        Err(e) => log::error!("Serialize sign in attempts failed: {:?}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lockout_doubles_after_each_failure() {
+        assert_eq!(lockout_seconds(5), 30);
+        assert_eq!(lockout_seconds(6), 60);
+        assert_eq!(lockout_seconds(7), 120);
+        assert_eq!(lockout_seconds(100), LOCKOUT_MAX_SECONDS);
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{
+    errors::{ErrorCode, UserError},
+    services::user::UserSession,
+};
+use flowy_dispatch::prelude::*;
+use futures_core::future::BoxFuture;
+use std::{collections::HashMap, convert::TryFrom, sync::Arc, time::Duration};
+use tokio::sync::Mutex;
+
+// Refresh the token one day before the session expires.
+pub const DEFAULT_REFRESH_BEFORE_EXPIRED: i64 = 24 * 60 * 60;
+const REFRESH_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+pub struct TokenRefresher {
+    session: Arc<UserSession>,
+    refresh_before_expired: i64,
+    unauthorized_codes: Vec<i32>,
+    // Serializes the refreshing. The requests that failed with the same token
+    // only trigger one refresh.
+    refreshing: Arc<Mutex<()>>,
+    // The token each request was dispatched with, by the request id. The token of
+    // the session may have been refreshed by the time its response is intercepted.
+    dispatched_tokens: parking_lot::Mutex<HashMap<String, String>>,
+}
+
+impl TokenRefresher {
+    pub fn new(session: Arc<UserSession>) -> Self {
+        Self {
+            session,
+            refresh_before_expired: DEFAULT_REFRESH_BEFORE_EXPIRED,
+            unauthorized_codes: vec![ErrorCode::UserUnauthorized.value()],
+            refreshing: Arc::new(Mutex::new(())),
+            dispatched_tokens: parking_lot::Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn refresh_before_expired(mut self, seconds: i64) -> Self {
+        self.refresh_before_expired = seconds;
+        self
+    }
+
+    // Each module reports the unauthorized response with its own error code. The
+    // errors share the same { code, msg } layout, so only the codes are needed.
+    pub fn unauthorized_codes(mut self, codes: Vec<i32>) -> Self {
+        self.unauthorized_codes = codes;
+        self
+    }
+
+    // Refreshes the token if the session will expire within refresh_before_expired.
+    // Returns true if the token was refreshed. It's checked again once the network
+    // is reachable.
+    pub async fn refresh_if_needed(&self) -> Result<bool, UserError> {
+        if !self.session.connectivity.is_online() {
+            return Ok(false);
+        }
+        let _guard = self.refreshing.lock().await;
+        match self.session.session_expires_in()? {
+            Some(expires_in) if expires_in <= self.refresh_before_expired => {
+                let _ = self.session.refresh_token().await?;
+                Ok(true)
+            },
+            _ => Ok(false),
+        }
+    }
+
+    // Checks the session periodically and refreshes the token in the background.
+    pub fn run(self: Arc<Self>) -> BoxFuture<'static, ()> {
+        Box::pin(async move {
+            let mut interval = tokio::time::interval(REFRESH_CHECK_INTERVAL);
+            loop {
+                interval.tick().await;
+                match self.refresh_if_needed().await {
+                    Ok(_) => {},
+                    Err(e) => log::debug!("Refresh token failed: {:?}", e),
+                }
+            }
+        })
+    }
+
+    fn is_unauthorized(&self, response: &EventResponse) -> bool {
+        if response.status_code != StatusCode::Err {
+            return false;
+        }
+
+        match response.payload.to_bytes() {
+            Some(bytes) => match UserError::try_from(bytes) {
+                Ok(error) => self.unauthorized_codes.contains(&error.code),
+                Err(_) => false,
+            },
+            None => false,
+        }
+    }
+}
+
+impl EventMiddleware for TokenRefresher {
+    fn dispatched(&self, request: &ModuleRequest) {
+        if let Ok(token) = self.session.token() {
+            self.dispatched_tokens.lock().insert(request.id.clone(), token);
+        }
+    }
+
+    fn cancelled(&self, request: &ModuleRequest) { self.dispatched_tokens.lock().remove(&request.id); }
+
+    fn intercept(&self, request: &ModuleRequest, response: &EventResponse) -> BoxFuture<'static, bool> {
+        let failed_token = self.dispatched_tokens.lock().remove(&request.id);
+        if !self.is_unauthorized(response) || !self.session.connectivity.is_online() {
+            return Box::pin(async { false });
+        }
+
+        let session = self.session.clone();
+        let refreshing = self.refreshing.clone();
+        match failed_token {
+            Some(failed_token) => Box::pin(refresh_after_unauthorized(session, refreshing, failed_token)),
+            None => Box::pin(async { false }),
+        }
+    }
+}
+
+async fn refresh_after_unauthorized(
+    session: Arc<UserSession>,
+    refreshing: Arc<Mutex<()>>,
+    failed_token: String,
+) -> bool {
+    let _guard = refreshing.lock().await;
+    match session.token() {
+        // Another request refreshed the token while this one was waiting
+        Ok(token) if token != failed_token => true,
+        Ok(_) => match session.refresh_token().await {
+            Ok(_) => true,
+            Err(e) => {
+                log::error!("Refresh token failed: {:?}", e);
+                false
+            },
+        },
+        Err(_) => false,
+    }
+}
@@ -1,7 +1,56 @@
 use crate::{
-    entities::{SignInParams, SignUpParams, UpdateUserParams, UserProfile},
+    entities::{
+        Account,
+        AppSetting,
+        ConfirmEmailParams,
+        DataExport,
+        DataExportFormat,
+        DatabaseBackup,
+        DeleteAccountConfirmation,
+        DeleteAccountParams,
+        DeleteAccountProgress,
+        DeleteAccountStage,
+        EmailVerification,
+        OAuthCompletion,
+        OAuthProviderType,
+        OAuthURL,
+        Preference,
+        ReadAuditLogRequest,
+        RepeatedAccount,
+        RepeatedAuditRecord,
+        RepeatedDatabaseBackup,
+        RepeatedDatabaseRowChange,
+        RepeatedDeviceSession,
+        RepeatedPreference,
+        RestoreBackupParams,
+        RevokeSessionParams,
+        SettingChange,
+        SettingSection,
+        SignInParams,
+        SignInResponse,
+        SignUpParams,
+        StorageCompaction,
+        SubscribePreferenceParams,
+        SwitchAccountParams,
+        UpdatePasswordParams,
+        UpdateSettingParams,
+        UpdateUserParams,
+        UpdateUserProfileParams,
+        UserProfile,
+    },
     errors::{ErrorCode, UserError},
-    services::user::database::UserDB,
+    services::{
+        preference::PreferenceController,
+        setting::{SettingController, SETTING_MIGRATION},
+        user::{
+            account_wipe::*,
+            audit::read_audit_log,
+            database::UserDB,
+            device::*,
+            sign_in_throttle::SignInThrottle,
+            OAuthProvider,
+        },
+    },
     sql_tables::{UserTable, UserTableChangeset},
 };
 
@@ -14,16 +63,33 @@ use flowy_database::{
     schema::{user_table, user_table::dsl},
     DBConnection,
     ExpressionMethods,
+    PoolConfig,
+    RustMigration,
     UserDatabaseConnection,
+    WriteConnection,
+};
+use flowy_infra::{
+    clock::{system_clock, Clock},
+    kv::KV,
+    secret::{generate_secret, mk_secret_store, SecretStore, SecretStoreKind},
+    timestamp,
+    uuid,
+};
+use flowy_net::{config::ServerConfig, request::TokenProvider};
+use flowy_user_infra::{
+    entities::DELETE_ACCOUNT_CONFIRMATION,
+    parser::PasswordPolicy,
+    signature::{delete_account_message, sign},
 };
-use flowy_infra::kv::KV;
-use flowy_net::config::ServerConfig;
 use flowy_sqlite::ConnectionPool;
-use flowy_ws::{WsController, WsMessageHandler, WsState};
+use flowy_ws::{Connectivity, ConnectivityConfig, WsConfig, WsConnectState, WsController, WsMessageHandler};
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
-use tokio::sync::{broadcast, mpsc};
+use std::{collections::HashMap, path::Path, sync::Arc, time::Duration};
+use tokio::{
+    sync::{broadcast, mpsc},
+    task::JoinHandle,
+};
 
 #[derive(Clone)]
 pub enum UserStatus {
@@ -40,12 +106,40 @@ pub enum UserStatus {
         profile: UserProfile,
         ret: mpsc::Sender<()>,
     },
+    Migrated {
+        anonymous_user_id: String,
+        profile: UserProfile,
+        ret: mpsc::Sender<()>,
+    },
 }
 
+// The session is kept for 30 days after signing in by default.
+pub const DEFAULT_SESSION_EXPIRATION: i64 = 30 * 24 * 60 * 60;
+// The user database is backed up once a day by default, the backups of the
+// last week are kept.
+pub const DEFAULT_BACKUP_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+pub const DEFAULT_MAX_BACKUPS: usize = 7;
+// The user database is compacted once the app has been idle for 5 minutes by
+// default.
+pub const DEFAULT_COMPACTION_IDLE_PERIOD: Duration = Duration::from_secs(5 * 60);
+const ANONYMOUS_USER_NAME: &str = "Anonymous";
+
 pub struct UserSessionConfig {
     root_dir: String,
     server_config: ServerConfig,
     session_cache_key: String,
+    accounts_cache_key: String,
+    pub(crate) session_expiration: i64,
+    pub(crate) password_policy: PasswordPolicy,
+    pub(crate) oauth_providers: HashMap<OAuthProviderType, Arc<dyn OAuthProvider>>,
+    pub(crate) secret_store_kind: SecretStoreKind,
+    pub(crate) pool_config: PoolConfig,
+    pub(crate) migrations: Vec<RustMigration>,
+    pub(crate) encrypt_database: bool,
+    pub(crate) max_backups: usize,
+    pub(crate) ws_config: WsConfig,
+    pub(crate) connectivity_config: ConnectivityConfig,
+    pub(crate) clock: Arc<dyn Clock>,
 }
 
 impl UserSessionConfig {
@@ -54,6 +148,18 @@ impl UserSessionConfig {
             root_dir: root_dir.to_owned(),
             server_config: server_config.clone(),
             session_cache_key: session_cache_key.to_owned(),
+            accounts_cache_key: format!("{}_accounts", session_cache_key),
+            session_expiration: DEFAULT_SESSION_EXPIRATION,
+            password_policy: PasswordPolicy::default(),
+            oauth_providers: HashMap::new(),
+            secret_store_kind: SecretStoreKind::default(),
+            pool_config: PoolConfig::default(),
+            migrations: vec![SETTING_MIGRATION],
+            encrypt_database: false,
+            max_backups: DEFAULT_MAX_BACKUPS,
+            ws_config: WsConfig::default(),
+            connectivity_config: ConnectivityConfig::default(),
+            clock: system_clock(),
         }
     }
 }
@@ -64,22 +170,48 @@ pub struct UserSession {
     #[allow(dead_code)]
     server: Server,
     session: RwLock<Option<Session>>,
+    // The sessions carry the tokens, so they are kept in the secret store instead of the KV.
+    secret_store: Arc<dyn SecretStore>,
+    preferences: PreferenceController,
+    settings: SettingController,
+    // The states of the authorization urls that haven't been completed yet.
+    oauth_states: RwLock<HashMap<String, OAuthProviderType>>,
     pub ws_controller: Arc<WsController>,
+    // Forwards the states of the connection to the UI.
+    ws_listener: RwLock<Option<JoinHandle<()>>>,
+    pub connectivity: Arc<Connectivity>,
     status_notifier: broadcast::Sender<UserStatus>,
 }
 
 impl UserSession {
     pub fn new(config: UserSessionConfig) -> Self {
-        let db = UserDB::new(&config.root_dir);
+        let secret_store = mk_secret_store(&config.root_dir, config.secret_store_kind);
+        let encryption = match config.encrypt_database {
+            true => Some(secret_store.clone()),
+            false => None,
+        };
+        let db = UserDB::new(
+            &config.root_dir,
+            config.pool_config.clone(),
+            config.migrations.clone(),
+            encryption,
+        );
         let server = construct_user_server(&config.server_config);
-        let ws_controller = Arc::new(WsController::new());
+        let ws_controller = Arc::new(WsController::with_config(config.ws_config.clone()));
+        let connectivity = Arc::new(Connectivity::new(config.connectivity_config.clone()));
         let (status_notifier, _) = broadcast::channel(10);
         let user_session = Self {
             database: db,
             config,
             server,
             session: RwLock::new(None),
+            secret_store,
+            preferences: PreferenceController::new(),
+            settings: SettingController::new(),
+            oauth_states: RwLock::new(HashMap::new()),
             ws_controller,
+            ws_listener: RwLock::new(None),
+            connectivity,
             status_notifier,
         };
         user_session
@@ -88,7 +220,17 @@ impl UserSession {
     pub fn init(&self) {
         match self.get_session() {
             Ok(session) => {
-                let _ = self.status_notifier.send(UserStatus::Login { token: session.token });
+                // Opening the database applies its pending migrations, the
+                // failure is notified with DatabaseMigrationFailed.
+                if let Err(e) = self.db_pool() {
+                    log::error!("Open user db failed: {:?}", e);
+                }
+
+                if session.is_expired_at(self.config.clock.timestamp()) {
+                    let _ = self.status_notifier.send(UserStatus::Expired { token: session.token });
+                } else {
+                    let _ = self.status_notifier.send(UserStatus::Login { token: session.token });
+                }
             },
             Err(_) => {},
         }
@@ -96,11 +238,24 @@ impl UserSession {
 
     pub fn status_subscribe(&self) -> broadcast::Receiver<UserStatus> { self.status_notifier.subscribe() }
 
+    // The rows changed in the database of the current user, see
+    // UserNotification::DatabaseRowsChanged.
+    pub fn row_change_subscribe(&self) -> broadcast::Receiver<RepeatedDatabaseRowChange> {
+        self.database.row_change_subscribe()
+    }
+
     pub fn db_connection(&self) -> Result<DBConnection, UserError> {
         let user_id = self.get_session()?.user_id;
         self.database.get_connection(&user_id)
     }
 
+    // Same as db_connection, but the connection is only returned once the
+    // other writers are done with theirs. Use it for the writes.
+    pub fn db_write_connection(&self) -> Result<WriteConnection, UserError> {
+        let user_id = self.get_session()?.user_id;
+        self.database.get_write_connection(&user_id)
+    }
+
     // The caller will be not 'Sync' before of the return value,
     // PooledConnection<ConnectionManager> is not sync. You can use
     // db_connection_pool function to require the ConnectionPool that is 'Sync'.
@@ -113,29 +268,56 @@ impl UserSession {
     }
 
     #[tracing::instrument(level = "debug", skip(self))]
-    pub async fn sign_in(&self, params: SignInParams) -> Result<UserProfile, UserError> {
+    pub async fn sign_in(&self, mut params: SignInParams) -> Result<UserProfile, UserError> {
         if self.is_login(&params.email) {
             self.user_profile().await
         } else {
-            let resp = self.server.sign_in(params).await?;
-            let session = Session::new(&resp.user_id, &resp.token, &resp.email);
-            let _ = self.set_session(Some(session))?;
-            let user_table = self.save_user(resp.into()).await?;
-            let user_profile: UserProfile = user_table.into();
-            let _ = self.status_notifier.send(UserStatus::Login {
-                token: user_profile.token.clone(),
-            });
-            Ok(user_profile)
+            SignInThrottle::check(&params.email)?;
+            self.clear_revoked_session()?;
+            params.device_id = device_id();
+            params.device_name = device_name();
+            let email = params.email.clone();
+            let result = self.server.sign_in(params).await;
+            SignInThrottle::record(&email, &result);
+            self.save_signed_in_user(result?).await
+        }
+    }
+
+    pub fn oauth_url(&self, provider: OAuthProviderType) -> Result<OAuthURL, UserError> {
+        let oauth_provider = self.oauth_provider(&provider)?;
+        let state = uuid();
+        let url = oauth_provider.authorization_url(&state);
+        self.oauth_states.write().insert(state.clone(), provider);
+        Ok(OAuthURL { url, state })
+    }
+
+    // Completes the flow started by oauth_url. Each state can only be used once.
+    #[tracing::instrument(level = "debug", skip(self, completion), fields(provider = ?completion.provider))]
+    pub async fn oauth_sign_in(&self, completion: OAuthCompletion) -> Result<UserProfile, UserError> {
+        match self.oauth_states.write().remove(&completion.state) {
+            Some(provider) if provider == completion.provider => {},
+            _ => return Err(UserError::oauth_state_invalid()),
         }
+
+        let oauth_provider = self.oauth_provider(&completion.provider)?;
+        let mut params = oauth_provider.sign_in_params(&completion.code);
+        params.device_id = device_id();
+        params.device_name = device_name();
+        self.clear_revoked_session()?;
+        let resp = self.server.oauth_sign_in(params).await?;
+        self.save_signed_in_user(resp).await
     }
 
     #[tracing::instrument(level = "debug", skip(self))]
-    pub async fn sign_up(&self, params: SignUpParams) -> Result<UserProfile, UserError> {
+    pub async fn sign_up(&self, mut params: SignUpParams) -> Result<UserProfile, UserError> {
         if self.is_login(&params.email) {
             self.user_profile().await
         } else {
+            self.clear_revoked_session()?;
+            params.device_id = device_id();
+            params.device_name = device_name();
             let resp = self.server.sign_up(params).await?;
-            let session = Session::new(&resp.user_id, &resp.token, &resp.email);
+            let session = Session::new(&resp.user_id, &resp.token, &resp.email, self.session_expired_at());
             let _ = self.set_session(Some(session))?;
             let user_table = self.save_user(resp.into()).await?;
             let user_profile: UserProfile = user_table.into();
@@ -150,86 +332,591 @@ impl UserSession {
         }
     }
 
+    // Creates a user that only lives on this device. Nothing is sent to the server until
+    // the user is migrated to an account.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn start_anonymous(&self) -> Result<UserProfile, UserError> {
+        if let Ok(session) = self.get_session() {
+            if session.is_anonymous {
+                return self.user_profile().await;
+            }
+        }
+
+        let user_id = uuid();
+        let token = uuid();
+        self.set_session(Some(Session::anonymous(&user_id, &token)))?;
+        let user_table = UserTable::new(user_id, ANONYMOUS_USER_NAME.to_owned(), "".to_owned(), token);
+        let user_table = self.save_user(user_table).await?;
+        let user_profile: UserProfile = user_table.into();
+        let (ret, mut tx) = mpsc::channel(1);
+        let _ = self.status_notifier.send(UserStatus::SignUp {
+            profile: user_profile.clone(),
+            ret,
+        });
+
+        let _ = tx.recv().await;
+        Ok(user_profile)
+    }
+
+    // Signs up the account and moves the data of the current anonymous user into it.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn migrate_to_account(&self, mut params: SignUpParams) -> Result<UserProfile, UserError> {
+        let anonymous = self.get_session()?;
+        if !anonymous.is_anonymous {
+            return Err(UserError::not_anonymous());
+        }
+
+        params.device_id = device_id();
+        params.device_name = device_name();
+        let resp = self.server.sign_up(params).await?;
+        self.database.close_user_db(&anonymous.user_id)?;
+        let anonymous_dir = format!("{}/{}", self.config.root_dir, anonymous.user_id);
+        let user_dir = format!("{}/{}", self.config.root_dir, resp.user_id);
+        std::fs::rename(&anonymous_dir, &user_dir).map_err(|e| UserError::internal().context(e))?;
+
+        let session = Session::new(&resp.user_id, &resp.token, &resp.email, self.session_expired_at());
+        self.set_session(Some(session))?;
+        self.remove_account(&anonymous.user_id);
+        let _ = diesel::update(dsl::user_table.filter(dsl::id.eq(&anonymous.user_id)))
+            .set((
+                dsl::id.eq(&resp.user_id),
+                dsl::name.eq(&resp.name),
+                dsl::email.eq(&resp.email),
+            ))
+            .execute(&*(self.db_write_connection()?))?;
+
+        let user_profile = self.user_profile().await?;
+        let (ret, mut tx) = mpsc::channel(1);
+        let _ = self.status_notifier.send(UserStatus::Migrated {
+            anonymous_user_id: anonymous.user_id,
+            profile: user_profile.clone(),
+            ret,
+        });
+
+        let _ = tx.recv().await;
+        Ok(user_profile)
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn sign_out(&self) -> Result<(), UserError> {
         let session = self.get_session()?;
         let _ =
-            diesel::delete(dsl::user_table.filter(dsl::id.eq(&session.user_id))).execute(&*(self.db_connection()?))?;
+            diesel::delete(dsl::user_table.filter(dsl::id.eq(&session.user_id))).execute(&*(self.db_write_connection()?))?;
         let _ = self.database.close_user_db(&session.user_id)?;
         let _ = self.set_session(None)?;
+        self.remove_account(&session.user_id);
+        self.ws_controller.disconnect();
         let _ = self.status_notifier.send(UserStatus::Logout {
             token: session.token.clone(),
         });
-        let _ = self.sign_out_on_server(&session.token).await?;
+        if !session.is_anonymous {
+            let _ = self.sign_out_on_server(&session.token).await?;
+        }
 
         Ok(())
     }
 
+    // Deletes the account on the server, then wipes everything the user left on this
+    // device. The progress is pushed with DeleteAccountProgress notifications.
+    #[tracing::instrument(level = "debug", skip(self, confirmation))]
+    pub async fn delete_account(&self, confirmation: DeleteAccountConfirmation) -> Result<(), UserError> {
+        let session = self.get_session()?;
+        let expected = match session.is_anonymous {
+            true => DELETE_ACCOUNT_CONFIRMATION,
+            false => session.email.as_str(),
+        };
+        if !confirmation.confirmation.eq_ignore_ascii_case(expected) {
+            return Err(UserError::delete_account_not_confirmed());
+        }
+
+        let mut progress = DeleteAccountNotifier::new(&session.token);
+        if !session.is_anonymous {
+            progress.notify(DeleteAccountStage::DeleteRemote);
+            let timestamp = timestamp();
+            let params = DeleteAccountParams {
+                user_id: session.user_id.clone(),
+                password: confirmation.password,
+                timestamp,
+                signature: sign(&session.token, &delete_account_message(&session.user_id, timestamp)),
+            };
+            match self.server.delete_account(&session.token, params).await {
+                Ok(_) => {},
+                // Offline, the local data is deleted anyway.
+                Err(e) if e.code == ErrorCode::ServerError.value() => {
+                    log::warn!("Delete the account on the server failed: {:?}", e);
+                    progress.notify(DeleteAccountStage::SkipRemote);
+                },
+                Err(e) => return Err(e),
+            }
+        }
+
+        let user_dir = format!("{}/{}", self.config.root_dir, session.user_id);
+        let user_dir = Path::new(&user_dir);
+        let wipe_error = |e: std::io::Error| UserError::internal().context(e);
+
+        progress.notify(DeleteAccountStage::WipeDatabase);
+        self.database.close_user_db(&session.user_id)?;
+        secure_remove_database(user_dir).map_err(wipe_error)?;
+
+        progress.notify(DeleteAccountStage::WipeCache);
+        for cache_dir in CACHE_DIRS.iter() {
+            secure_remove_dir(&user_dir.join(cache_dir)).map_err(wipe_error)?;
+        }
+
+        progress.notify(DeleteAccountStage::WipeAttachments);
+        secure_remove_dir(&user_dir.join(ATTACHMENT_DIR)).map_err(wipe_error)?;
+        secure_remove_dir(user_dir).map_err(wipe_error)?;
+
+        self.set_session(None)?;
+        self.remove_account(&session.user_id);
+        let _ = self.status_notifier.send(UserStatus::Logout {
+            token: session.token.clone(),
+        });
+        progress.notify(DeleteAccountStage::Completed);
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn update_user(&self, params: UpdateUserParams) -> Result<(), UserError> {
         let session = self.get_session()?;
+        // Only the fields that change are written and sent to the server.
+        let profile = self.read_local_user_profile(&params.id)?;
+        let params = params.without_unchanged(&profile);
+        if params.is_empty() {
+            return Ok(());
+        }
+
         let changeset = UserTableChangeset::new(params.clone());
-        diesel_update_table!(user_table, changeset, &*self.db_connection()?);
+        diesel_update_table!(user_table, changeset, &*self.db_write_connection()?);
+
+        if let Some(email) = &params.email {
+            if params.id == session.user_id && email != &session.email {
+                let mut session = session.clone();
+                session.email = email.clone();
+                self.set_session(Some(session))?;
+            }
+        }
+
+        if !session.is_anonymous {
+            self.update_user_on_server(&session.token, params).await?;
+        }
+        self.notify_user_profile_changed(&session)?;
+        Ok(())
+    }
+
+    // Updates the profile of the current user and notifies the observers with the new one.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn update_user_profile(&self, params: UpdateUserProfileParams) -> Result<UserProfile, UserError> {
+        let session = self.get_session()?;
+        self.update_user(params.into_update_user_params(&session.user_id)).await?;
+        self.read_local_user_profile(&session.user_id)
+    }
+
+    // Restores the persisted session without asking for the credentials. The
+    // expired session will be removed, and the user must sign in again.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn auto_sign_in(&self) -> Result<UserProfile, UserError> {
+        let session = self.get_session()?;
+        if session.is_expired_at(self.config.clock.timestamp()) {
+            self.set_session(None)?;
+            self.remove_account(&session.user_id);
+            return Err(UserError::session_expired());
+        }
+
+        self.user_profile().await
+    }
+
+    pub fn list_accounts(&self) -> RepeatedAccount {
+        let current_user_id = self.get_session().map(|session| session.user_id).ok();
+        let items = self
+            .read_accounts()
+            .into_iter()
+            .map(|session| Account {
+                is_current: Some(&session.user_id) == current_user_id.as_ref(),
+                user_id: session.user_id,
+                email: session.email,
+            })
+            .collect::<Vec<Account>>();
+        RepeatedAccount { items }
+    }
+
+    // Makes one of the signed in accounts the current user. The databases are
+    // stored in each user's own directory, the one of the previous user is
+    // closed when the session is set.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn switch_account(&self, params: SwitchAccountParams) -> Result<UserProfile, UserError> {
+        let session = match self
+            .read_accounts()
+            .into_iter()
+            .find(|session| session.user_id == params.user_id)
+        {
+            None => return Err(UserError::user_not_exist()),
+            Some(session) => session,
+        };
+
+        if session.is_expired_at(self.config.clock.timestamp()) {
+            self.remove_account(&session.user_id);
+            return Err(UserError::session_expired());
+        }
+
+        if let Ok(current) = self.get_session() {
+            if current.user_id == session.user_id {
+                return self.user_profile().await;
+            }
+        }
+
+        self.set_session(Some(session.clone()))?;
+        dart_notify(&session.token, UserNotification::UserAuthChanged).send();
+        let _ = self.status_notifier.send(UserStatus::Login {
+            token: session.token.clone(),
+        });
+        self.user_profile().await
+    }
+
+    // When a session that starts now expires, by the clock of the config.
+    fn session_expired_at(&self) -> i64 { self.config.clock.timestamp() + self.config.session_expiration }
+
+    // Seconds until the session expires. None if the session was persisted
+    // without the expiration.
+    pub fn session_expires_in(&self) -> Result<Option<i64>, UserError> {
+        let session = self.get_session()?;
+        Ok(session
+            .expired_at
+            .map(|expired_at| expired_at - self.config.clock.timestamp()))
+    }
+
+    // Exchanges the current token for a new one and extends the session.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn refresh_token(&self) -> Result<String, UserError> {
+        let session = self.get_session()?;
+        let resp = self.server.refresh_token(&session.token).await;
+        let resp = self.check_revoked(&session, resp)?;
+        self.update_token(session, &resp.token)?;
+        Ok(resp.token)
+    }
+
+    // The server verifies the old password and revokes every token issued before the
+    // change, so only this session stays signed in, with the token it gets back.
+    #[tracing::instrument(level = "debug", skip(self, params))]
+    pub async fn update_password(&self, params: UpdatePasswordParams) -> Result<(), UserError> {
+        self.config.password_policy.validate(&params.new_password)?;
+        if params.old_password == params.new_password {
+            return Err(UserError::password_not_changed());
+        }
+
+        let session = self.get_session()?;
+        let resp = self.server.update_password(&session.token, params).await;
+        let resp = self.check_revoked(&session, resp)?;
+        self.update_token(session, &resp.token)?;
+        Ok(())
+    }
+
+    // The sessions that signed in to the account on every device, including this one.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn list_sessions(&self) -> Result<RepeatedDeviceSession, UserError> {
+        let session = self.get_session()?;
+        if session.is_anonymous {
+            return Ok(RepeatedDeviceSession::default());
+        }
+
+        let sessions = self.server.list_sessions(&session.token).await;
+        self.check_revoked(&session, sessions)
+    }
+
+    // The revoked session fails every request after, the device it belongs to must sign
+    // in again. Revoking the session of this device takes effect immediately.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn revoke_session(&self, params: RevokeSessionParams) -> Result<(), UserError> {
+        let is_current = self
+            .list_sessions()
+            .await?
+            .items
+            .iter()
+            .any(|device_session| device_session.id == params.session_id && device_session.is_current);
+
+        let session = self.get_session()?;
+        let result = self.server.revoke_session(&session.token, params).await;
+        self.check_revoked(&session, result)?;
+        if is_current {
+            self.mark_session_revoked(session)?;
+        }
+        Ok(())
+    }
+
+    // The code is sent to the email of the current user, confirm_email completes the
+    // verification with it.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn send_verification_email(&self) -> Result<(), UserError> {
+        let session = self.get_session()?;
+        if session.is_anonymous {
+            return Err(UserError::email_empty());
+        }
+
+        let result = self.server.send_verification_email(&session.token).await;
+        self.check_revoked(&session, result)
+    }
+
+    // Polls the status from the server. The status saved on this device is returned if
+    // the server can't be reached.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn email_verification(&self) -> Result<EmailVerification, UserError> {
+        let session = self.get_session()?;
+        if session.is_anonymous {
+            return Ok(EmailVerification::default());
+        }
+
+        match self.server.get_verification(&session.token).await {
+            Ok(verification) => {
+                self.save_email_verification(&session, &verification).await?;
+                Ok(verification)
+            },
+            Err(e) if e.code == ErrorCode::ServerError.value() => Ok(EmailVerification {
+                email: session.email.clone(),
+                is_verified: self.is_email_verified(),
+                is_local: true,
+            }),
+            Err(e) => self.check_revoked(&session, Err(e)),
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, params))]
+    pub async fn confirm_email(&self, params: ConfirmEmailParams) -> Result<EmailVerification, UserError> {
+        let session = self.get_session()?;
+        if session.is_anonymous {
+            return Err(UserError::email_empty());
+        }
+
+        let result = self.server.confirm_email(&session.token, params).await;
+        let verification = self.check_revoked(&session, result)?;
+        self.save_email_verification(&session, &verification).await?;
+        Ok(verification)
+    }
+
+    // Reads the flag saved on this device, it's false if there is no user.
+    pub fn is_email_verified(&self) -> bool {
+        let user_id = match self.get_session() {
+            Ok(session) => session.user_id,
+            Err(_) => return false,
+        };
+        match self.read_local_user_profile(&user_id) {
+            Ok(user_profile) => user_profile.email_verified,
+            Err(_) => false,
+        }
+    }
+
+    pub fn is_session_revoked(&self) -> bool {
+        match self.get_session() {
+            Ok(session) => session.revoked,
+            Err(_) => false,
+        }
+    }
 
-        let _ = self.update_user_on_server(&session.token, params).await?;
+    fn update_token(&self, session: Session, token: &str) -> Result<(), UserError> {
+        let session = Session::new(&session.user_id, token, &session.email, self.session_expired_at());
+        self.set_session(Some(session))?;
         Ok(())
     }
 
     pub async fn init_user(&self) -> Result<(), UserError> {
-        let (_, token) = self.get_session()?.into_part();
-        let _ = self.start_ws_connection(&token).await?;
+        let session = self.get_session()?;
+        if session.is_anonymous {
+            return Ok(());
+        }
+
+        // The documents are synced after the email is verified, see confirm_email.
+        if !self.is_email_verified() {
+            tracing::debug!("Sync is disabled until the email is verified");
+            return Ok(());
+        }
+        let _ = self.start_ws_connection(&session.token).await?;
 
         Ok(())
     }
 
     pub async fn check_user(&self) -> Result<UserProfile, UserError> {
-        let (user_id, token) = self.get_session()?.into_part();
-
-        let user = dsl::user_table
-            .filter(user_table::id.eq(&user_id))
-            .first::<UserTable>(&*(self.db_connection()?))?;
+        let session = self.get_session()?;
+        let (user_id, token) = session.clone().into_part();
+        let user_profile = self.read_local_user_profile(&user_id)?;
 
-        let _ = self.read_user_profile_on_server(&token)?;
-        Ok(user.into())
+        if !session.is_anonymous {
+            let _ = self.read_user_profile_on_server(&token)?;
+        }
+        Ok(user_profile)
     }
 
     pub async fn user_profile(&self) -> Result<UserProfile, UserError> {
-        let (user_id, token) = self.get_session()?.into_part();
-        let user = dsl::user_table
-            .filter(user_table::id.eq(&user_id))
-            .first::<UserTable>(&*(self.db_connection()?))?;
+        let session = self.get_session()?;
+        let (user_id, token) = session.clone().into_part();
+        let user_profile = self.read_local_user_profile(&user_id)?;
+
+        if !session.is_anonymous {
+            let _ = self.read_user_profile_on_server(&token)?;
+        }
+        Ok(user_profile)
+    }
 
-        let _ = self.read_user_profile_on_server(&token)?;
-        Ok(user.into())
+    pub fn set_preference(&self, preference: Preference) -> Result<(), UserError> {
+        let conn = self.db_write_connection()?;
+        self.preferences.set(preference, &conn)
     }
 
+    pub fn get_preference(&self, key: &str) -> Result<Preference, UserError> {
+        let conn = self.db_connection()?;
+        self.preferences.get(key, &conn)
+    }
+
+    pub fn subscribe_preference(&self, params: SubscribePreferenceParams) -> Result<RepeatedPreference, UserError> {
+        let conn = self.db_connection()?;
+        self.preferences.subscribe_keys(params.keys, &conn)
+    }
+
+    pub fn read_setting(&self) -> Result<AppSetting, UserError> {
+        let conn = self.db_connection()?;
+        self.settings.read(&conn)
+    }
+
+    pub fn update_setting(&self, params: UpdateSettingParams) -> Result<AppSetting, UserError> {
+        let conn = self.db_write_connection()?;
+        self.settings.update(params, &conn)
+    }
+
+    pub fn reset_setting(&self, section: SettingSection) -> Result<AppSetting, UserError> {
+        let conn = self.db_write_connection()?;
+        self.settings.reset(section, &conn)
+    }
+
+    // The mutating events the AuditLog recorded, the newest first.
+    pub fn read_audit_log(&self, request: ReadAuditLogRequest) -> Result<RepeatedAuditRecord, UserError> {
+        let conn = self.db_connection()?;
+        read_audit_log(request, &conn)
+    }
+
+    // Receives every preference change of the current user.
+    pub fn preference_subscribe(&self) -> broadcast::Receiver<Preference> { self.preferences.subscribe() }
+
+    // Receives a SettingChange for each section of the current user that changes.
+    pub fn setting_subscribe(&self) -> broadcast::Receiver<SettingChange> { self.settings.subscribe() }
+
     pub fn user_dir(&self) -> Result<String, UserError> {
         let session = self.get_session()?;
         Ok(format!("{}/{}", self.config.root_dir, session.user_id))
     }
 
+    // The oldest backups are removed once there are more than max_backups.
+    pub fn create_backup(&self) -> Result<DatabaseBackup, UserError> {
+        let user_id = self.get_session()?.user_id;
+        self.database.create_backup(&user_id, self.config.max_backups)
+    }
+
+    pub fn list_backups(&self) -> Result<RepeatedDatabaseBackup, UserError> {
+        let user_id = self.get_session()?.user_id;
+        let items = self.database.list_backups(&user_id)?;
+        Ok(RepeatedDatabaseBackup { items })
+    }
+
+    // Replaces the rows of the user database with the ones of the backup, the
+    // backups taken before the last migrations are migrated first.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub fn restore_backup(&self, params: RestoreBackupParams) -> Result<(), UserError> {
+        let user_id = self.get_session()?.user_id;
+        self.database.restore_backup(&user_id, &params.backup_id)
+    }
+
+    // The secrets, like the token of the session, aren't exported.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub fn export_all_data(&self, format: DataExportFormat) -> Result<DataExport, UserError> {
+        let user_id = self.get_session()?.user_id;
+        self.database.export_data(&user_id, format)
+    }
+
+    // Reclaims the free pages of the user database and truncates its write-ahead
+    // log.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub fn compact_storage(&self) -> Result<StorageCompaction, UserError> {
+        let user_id = self.get_session()?.user_id;
+        self.database.compact(&user_id)
+    }
+
     pub fn user_id(&self) -> Result<String, UserError> { Ok(self.get_session()?.user_id) }
 
     pub fn token(&self) -> Result<String, UserError> { Ok(self.get_session()?.token) }
 
+    // The store the sessions are kept in, shared with the plugins.
+    pub fn secret_store(&self) -> Arc<dyn SecretStore> { self.secret_store.clone() }
+
+    // The secret that the keys of the encrypted documents are derived from. It's created
+    // the first time it's read and it never leaves the secret store of the user.
+    pub fn document_secret(&self) -> Result<String, UserError> {
+        let key = format!("{}_document_secret", self.get_session()?.user_id);
+        let secret = self
+            .secret_store
+            .get(&key)
+            .map_err(|e| UserError::new(ErrorCode::InternalError, &e))?;
+        match secret {
+            Some(secret) => Ok(secret),
+            None => {
+                let secret = generate_secret();
+                let _ = self
+                    .secret_store
+                    .set(&key, &secret)
+                    .map_err(|e| UserError::new(ErrorCode::InternalError, &e))?;
+                Ok(secret)
+            },
+        }
+    }
+
+    // The key that the synced payloads of the encrypted workspace are encrypted
+    // with. The other members get it from their invitations, the server never sees it.
+    pub fn workspace_key(&self, workspace_id: &str) -> Result<Option<String>, UserError> {
+        let key = workspace_key_name(&self.get_session()?.user_id, workspace_id);
+        self.secret_store
+            .get(&key)
+            .map_err(|e| UserError::new(ErrorCode::InternalError, &e))
+    }
+
+    pub fn set_workspace_key(&self, workspace_id: &str, secret: &str) -> Result<(), UserError> {
+        let key = workspace_key_name(&self.get_session()?.user_id, workspace_id);
+        self.secret_store
+            .set(&key, secret)
+            .map_err(|e| UserError::new(ErrorCode::InternalError, &e))
+    }
+
     pub fn add_ws_handler(&self, handler: Arc<dyn WsMessageHandler>) {
         let _ = self.ws_controller.add_handler(handler);
     }
 }
 
 impl UserSession {
+    // The token isn't stored in the user table, it comes from the session.
+    fn read_local_user_profile(&self, user_id: &str) -> Result<UserProfile, UserError> {
+        let user = dsl::user_table
+            .filter(user_table::id.eq(user_id))
+            .first::<UserTable>(&*(self.db_connection()?))?;
+        let mut user_profile: UserProfile = user.into();
+        user_profile.token = self.get_session()?.token;
+        Ok(user_profile)
+    }
+
+    fn notify_user_profile_changed(&self, session: &Session) -> Result<(), UserError> {
+        let user_profile = self.read_local_user_profile(&session.user_id)?;
+        dart_notify(&session.token, UserNotification::UserProfileChanged)
+            .payload(user_profile)
+            .send();
+        Ok(())
+    }
+
     fn read_user_profile_on_server(&self, token: &str) -> Result<(), UserError> {
         let server = self.server.clone();
         let token = token.to_owned();
         tokio::spawn(async move {
             match server.get_user(&token).await {
                 Ok(profile) => {
-                    dart_notify(&token, UserNotification::UserProfileUpdated)
+                    dart_notify(&token, UserNotification::UserProfileChanged)
                         .payload(profile)
                         .send();
                 },
                 Err(e) => {
-                    dart_notify(&token, UserNotification::UserProfileUpdated)
+                    dart_notify(&token, UserNotification::UserProfileChanged)
                         .error(e)
                         .send();
                 },
@@ -267,30 +954,187 @@ impl UserSession {
         Ok(())
     }
 
-    async fn save_user(&self, user: UserTable) -> Result<UserTable, UserError> {
+    async fn save_signed_in_user(&self, resp: SignInResponse) -> Result<UserProfile, UserError> {
+        let session = Session::new(&resp.user_id, &resp.token, &resp.email, self.session_expired_at());
+        self.set_session(Some(session))?;
+        let user_table = self.save_user(resp.into()).await?;
+        let user_profile: UserProfile = user_table.into();
+        let _ = self.status_notifier.send(UserStatus::Login {
+            token: user_profile.token.clone(),
+        });
+        Ok(user_profile)
+    }
+
+    async fn save_email_verification(
+        &self,
+        session: &Session,
+        verification: &EmailVerification,
+    ) -> Result<(), UserError> {
+        if self.is_email_verified() == verification.is_verified {
+            return Ok(());
+        }
+
+        let _ = diesel::update(dsl::user_table.filter(dsl::id.eq(&session.user_id)))
+            .set(dsl::email_verified.eq(verification.is_verified))
+            .execute(&*(self.db_write_connection()?))?;
+        dart_notify(&session.token, UserNotification::EmailVerificationChanged)
+            .payload(verification.clone())
+            .send();
+
+        if verification.is_verified {
+            let _ = self.start_ws_connection(&session.token).await?;
+        }
+        Ok(())
+    }
+
+    // Marks the session revoked if the server says so, the result is returned as is.
+    fn check_revoked<T>(&self, session: &Session, result: Result<T, UserError>) -> Result<T, UserError> {
+        if let Err(e) = &result {
+            if e.code == ErrorCode::SessionRevoked.value() && !session.revoked {
+                self.mark_session_revoked(session.clone())?;
+            }
+        }
+        result
+    }
+
+    fn mark_session_revoked(&self, mut session: Session) -> Result<(), UserError> {
+        tracing::debug!("Session of {} was revoked", session.user_id);
+        session.revoked = true;
+        self.set_session(Some(session.clone()))?;
+        dart_notify(&session.token, UserNotification::SessionRevoked)
+            .error(UserError::session_revoked())
+            .send();
+        let _ = self.status_notifier.send(UserStatus::Expired { token: session.token });
+        Ok(())
+    }
+
+    // The user signs in again after the session was revoked, the rows left by the
+    // revoked session would conflict with the new ones.
+    fn clear_revoked_session(&self) -> Result<(), UserError> {
+        let session = match self.get_session() {
+            Ok(session) if session.revoked => session,
+            _ => return Ok(()),
+        };
+
         let conn = self.db_connection()?;
-        let _ = diesel::insert_into(user_table::table)
-            .values(user.clone())
-            .execute(&*conn)?;
+        let _ = diesel::delete(dsl::user_table.filter(dsl::id.eq(&session.user_id))).execute(&*conn)?;
+        drop(conn);
+        self.database.close_user_db(&session.user_id)?;
+        self.set_session(None)?;
+        self.remove_account(&session.user_id);
+        Ok(())
+    }
+
+    fn oauth_provider(&self, provider: &OAuthProviderType) -> Result<Arc<dyn OAuthProvider>, UserError> {
+        match self.config.oauth_providers.get(provider) {
+            None => Err(UserError::oauth_provider_not_supported()),
+            Some(oauth_provider) => Ok(oauth_provider.clone()),
+        }
+    }
+
+    async fn save_user(&self, user: UserTable) -> Result<UserTable, UserError> {
+        let conn = self.db_write_connection()?;
+        let mut row = user.clone();
+        row.token = "".to_owned();
+        let _ = diesel::insert_into(user_table::table).values(row).execute(&*conn)?;
         Ok(user)
     }
 
     fn set_session(&self, session: Option<Session>) -> Result<(), UserError> {
         tracing::debug!("Set user session: {:?}", session);
+        let current_user_id = self.session.read().as_ref().map(|session| session.user_id.clone());
+        if current_user_id != session.as_ref().map(|session| session.user_id.clone()) {
+            self.preferences.clear_subscriptions();
+        }
         match &session {
-            None => {
-                KV::remove(&self.config.session_cache_key).map_err(|e| UserError::new(ErrorCode::InternalError, &e))?
+            None => self
+                .secret_store
+                .remove(&self.config.session_cache_key)
+                .map_err(|e| UserError::new(ErrorCode::InternalError, &e))?,
+            Some(session) => {
+                let s: String = session.clone().into();
+                self.secret_store
+                    .set(&self.config.session_cache_key, &s)
+                    .map_err(|e| UserError::new(ErrorCode::InternalError, &e))?;
+                self.save_account(session.clone());
             },
-            Some(session) => KV::set_str(&self.config.session_cache_key, session.clone().into()),
         }
+
+        // Only the database of the current user stays opened, so the modules
+        // can't read the ones of the other accounts.
+        let user_id = session.as_ref().map(|session| session.user_id.clone());
+        let _ = self.database.close_other_user_dbs(user_id.as_deref())?;
         *self.session.write() = session;
+        if let Some(user_id) = user_id {
+            // The failed migrations are notified with DatabaseMigrationFailed.
+            if let Err(e) = self.database.open_user_db(&user_id) {
+                log::error!("Open user db failed: {:?}", e);
+            }
+        }
         Ok(())
     }
 
+    fn read_accounts(&self) -> Vec<Session> {
+        match self.read_secret(&self.config.accounts_cache_key) {
+            None => vec![],
+            Some(s) => serde_json::from_str(&s).unwrap_or_else(|e| {
+                log::error!("Deserialize accounts failed: {:?}", e);
+                vec![]
+            }),
+        }
+    }
+
+    fn write_accounts(&self, accounts: Vec<Session>) {
+        match serde_json::to_string(&accounts) {
+            Ok(s) => match self.secret_store.set(&self.config.accounts_cache_key, &s) {
+                Ok(_) => {},
+                Err(e) => log::error!("Save accounts failed: {}", e),
+            },
+            Err(e) => log::error!("Serialize accounts failed: {:?}", e),
+        }
+    }
+
+    fn read_secret(&self, key: &str) -> Option<String> {
+        match self.secret_store.get(key) {
+            Ok(Some(s)) => return Some(s),
+            Ok(None) => {},
+            Err(e) => {
+                log::error!("Read {} from the {} secret store failed: {}", key, self.secret_store.name(), e);
+                return None;
+            },
+        }
+
+        // The sessions were kept in the KV before the secret store was introduced, move
+        // them over the first time they are read.
+        let s = KV::get_str(key)?;
+        match self.secret_store.set(key, &s) {
+            Ok(_) => {
+                let _ = KV::remove(key);
+            },
+            Err(e) => log::error!("Move {} to the {} secret store failed: {}", key, self.secret_store.name(), e),
+        }
+        Some(s)
+    }
+
+    fn save_account(&self, session: Session) {
+        let mut accounts = self.read_accounts();
+        match accounts.iter_mut().find(|account| account.user_id == session.user_id) {
+            None => accounts.push(session),
+            Some(account) => *account = session,
+        }
+        self.write_accounts(accounts);
+    }
+
+    fn remove_account(&self, user_id: &str) {
+        let mut accounts = self.read_accounts();
+        accounts.retain(|account| account.user_id != user_id);
+        self.write_accounts(accounts);
+    }
+
     fn get_session(&self) -> Result<Session, UserError> {
         let mut session = { (*self.session.read()).clone() };
         if session.is_none() {
-            match KV::get_str(&self.config.session_cache_key) {
+            match self.read_secret(&self.config.session_cache_key) {
                 None => {},
                 Some(s) => {
                     session = Some(Session::from(s));
@@ -307,7 +1151,7 @@ impl UserSession {
 
     fn is_login(&self, email: &str) -> bool {
         match self.get_session() {
-            Ok(session) => session.email == email,
+            Ok(session) => session.email == email && !session.revoked,
             Err(_) => false,
         }
     }
@@ -316,32 +1160,28 @@ impl UserSession {
     pub async fn start_ws_connection(&self, token: &str) -> Result<(), UserError> {
         if cfg!(feature = "http_server") {
             let addr = format!("{}/{}", self.server.ws_addr(), token);
-            self.listen_on_websocket();
+            self.listen_on_websocket(token);
             let _ = self.ws_controller.start_connect(addr).await?;
         }
         Ok(())
     }
 
-    #[tracing::instrument(level = "debug", skip(self))]
-    fn listen_on_websocket(&self) {
+    // The controller connects again by itself when the connection is lost, the
+    // states are passed to the UI with WsConnectStateChanged.
+    #[tracing::instrument(level = "debug", skip(self, token))]
+    fn listen_on_websocket(&self, token: &str) {
         let mut notify = self.ws_controller.state_subscribe();
-        let ws_controller = self.ws_controller.clone();
-        let _ = tokio::spawn(async move {
+        let token = token.to_owned();
+        let listener = tokio::spawn(async move {
             loop {
                 match notify.recv().await {
                     Ok(state) => {
                         tracing::info!("Websocket state changed: {}", state);
-                        match state {
-                            WsState::Init => {},
-                            WsState::Connected(_) => {},
-                            WsState::Disconnected(_) => match ws_controller.retry().await {
-                                Ok(_) => {},
-                                Err(e) => {
-                                    log::error!("Retry websocket connect failed: {:?}", e);
-                                },
-                            },
-                        }
+                        dart_notify(&token, UserNotification::WsConnectStateChanged)
+                            .payload(WsConnectState::from(&state))
+                            .send();
                     },
+                    Err(broadcast::error::RecvError::Lagged(_)) => {},
                     Err(e) => {
                         log::error!("Websocket state notify error: {:?}", e);
                         break;
@@ -349,9 +1189,21 @@ impl UserSession {
                 }
             }
         });
+        if let Some(listener) = self.ws_listener.write().replace(listener) {
+            listener.abort();
+        }
     }
 }
 
+// Every request to the server is sent with the token of the current session.
+impl TokenProvider for UserSession {
+    fn token(&self) -> Option<String> { UserSession::token(self).ok() }
+}
+
+fn workspace_key_name(user_id: &str, workspace_id: &str) -> String {
+    format!("{}_{}_workspace_key", user_id, workspace_id)
+}
+
 pub async fn update_user(
     _server: Server,
     pool: Arc<ConnectionPool>,
@@ -363,6 +1215,38 @@ pub async fn update_user(
     Ok(())
 }
 
+struct DeleteAccountNotifier {
+    token: String,
+    finished: i32,
+}
+
+impl DeleteAccountNotifier {
+    // DeleteRemote or SkipRemote, the three wipes and Completed
+    const TOTAL: i32 = 5;
+
+    fn new(token: &str) -> Self {
+        Self {
+            token: token.to_owned(),
+            finished: 0,
+        }
+    }
+
+    fn notify(&mut self, stage: DeleteAccountStage) {
+        // The remote is skipped after trying to delete it, it's still the same stage.
+        if stage != DeleteAccountStage::SkipRemote {
+            self.finished += 1;
+        }
+        let progress = DeleteAccountProgress {
+            stage,
+            finished: self.finished - 1,
+            total: Self::TOTAL,
+        };
+        dart_notify(&self.token, UserNotification::DeleteAccountProgress)
+            .payload(progress)
+            .send();
+    }
+}
+
 impl UserDatabaseConnection for UserSession {
     fn get_connection(&self) -> Result<DBConnection, String> { self.db_connection().map_err(|e| format!("{:?}", e)) }
 }
@@ -372,14 +1256,48 @@ struct Session {
     user_id: String,
     token: String,
     email: String,
+
+    // Unix timestamp in seconds. The sessions persisted before the expiration
+    // was introduced don't have it and never expire.
+    #[serde(default)]
+    expired_at: Option<i64>,
+
+    // The anonymous user only exists on this device, its token is useless for the server.
+    #[serde(default)]
+    is_anonymous: bool,
+
+    // Another device revoked the session, it's kept until the user signs in again.
+    #[serde(default)]
+    revoked: bool,
 }
 
 impl Session {
-    pub fn new(user_id: &str, token: &str, email: &str) -> Self {
+    pub fn new(user_id: &str, token: &str, email: &str, expired_at: i64) -> Self {
         Self {
             user_id: user_id.to_owned(),
             token: token.to_owned(),
             email: email.to_owned(),
+            expired_at: Some(expired_at),
+            is_anonymous: false,
+            revoked: false,
+        }
+    }
+
+    pub fn anonymous(user_id: &str, token: &str) -> Self {
+        Self {
+            user_id: user_id.to_owned(),
+            token: token.to_owned(),
+            email: "".to_owned(),
+            expired_at: None,
+            is_anonymous: true,
+            revoked: false,
+        }
+    }
+
+    pub fn is_expired_at(&self, now: i64) -> bool {
+        match self.expired_at {
+            None => false,
+            Some(expired_at) => expired_at <= now,
         }
     }
 
@@ -0,0 +1,31 @@
+use crate::{errors::UserError, services::user::UserSession};
+use flowy_dispatch::prelude::*;
+use futures_core::future::BoxFuture;
+use std::sync::Arc;
+
+// Rejects the events with EmailNotVerified until the email of the user is verified. The
+// flag saved on this device is used, so the events are still gated while offline.
+pub struct VerificationGuard {
+    session: Arc<UserSession>,
+    events: Vec<Event>,
+}
+
+impl VerificationGuard {
+    pub fn new(session: Arc<UserSession>, events: Vec<Event>) -> Self { Self { session, events } }
+}
+
+impl EventGuard for VerificationGuard {
+    fn check(
+        &self,
+        request: &ModuleRequest,
+        _permission: Option<EventPermission>,
+    ) -> BoxFuture<'static, Result<(), EventResponse>> {
+        let is_rejected = self.events.contains(&request.event) && !self.session.is_email_verified();
+        Box::pin(async move {
+            if is_rejected {
+                return Err(UserError::email_not_verified().as_response());
+            }
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,21 @@
+use crate::entities::AuditRecord;
+
+#[derive(Clone, Debug, Queryable)]
+pub(crate) struct AuditLogTable {
+    pub(crate) id: i32,
+    pub(crate) user_id: String,
+    pub(crate) event: String,
+    pub(crate) object_id: String,
+    pub(crate) create_time: i64,
+}
+
+impl std::convert::From<AuditLogTable> for AuditRecord {
+    fn from(table: AuditLogTable) -> Self {
+        AuditRecord {
+            user_id: table.user_id,
+            event: table.event,
+            object_id: table.object_id,
+            create_time: table.create_time,
+        }
+    }
+}
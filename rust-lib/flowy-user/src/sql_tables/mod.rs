@@ -1,3 +1,9 @@
+mod audit;
+mod preference;
+mod setting;
 mod user;
 
+pub(crate) use audit::*;
+pub use preference::*;
+pub(crate) use setting::*;
 pub use user::*;
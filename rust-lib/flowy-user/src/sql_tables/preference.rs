@@ -0,0 +1,45 @@
+use crate::entities::{Preference, PreferenceValue};
+use flowy_database::schema::user_preference_table;
+
+#[derive(Clone, Debug, Default, Queryable, Identifiable, Insertable, AsChangeset)]
+#[table_name = "user_preference_table"]
+#[primary_key(key)]
+pub struct UserPreferenceTable {
+    pub(crate) key: String,
+    pub(crate) str_value: Option<String>,
+    pub(crate) int_value: Option<i64>,
+    pub(crate) float_value: Option<f64>,
+    pub(crate) bool_value: Option<bool>,
+}
+
+impl std::convert::From<Preference> for UserPreferenceTable {
+    fn from(preference: Preference) -> Self {
+        let PreferenceValue {
+            str_value,
+            int_value,
+            float_value,
+            bool_value,
+        } = preference.value;
+        UserPreferenceTable {
+            key: preference.key,
+            str_value,
+            int_value,
+            float_value,
+            bool_value,
+        }
+    }
+}
+
+impl std::convert::From<UserPreferenceTable> for Preference {
+    fn from(table: UserPreferenceTable) -> Self {
+        Preference {
+            key: table.key,
+            value: PreferenceValue {
+                str_value: table.str_value,
+                int_value: table.int_value,
+                float_value: table.float_value,
+                bool_value: table.bool_value,
+            },
+        }
+    }
+}
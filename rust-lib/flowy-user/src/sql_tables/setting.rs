@@ -0,0 +1,10 @@
+use flowy_database::schema::user_setting_table;
+
+// The data is the protobuf of the section, e.g. EditorSetting.
+#[derive(Clone, Debug, Default, Queryable, Identifiable, Insertable, AsChangeset)]
+#[table_name = "user_setting_table"]
+#[primary_key(section)]
+pub(crate) struct UserSettingTable {
+    pub(crate) section: String,
+    pub(crate) data: Vec<u8>,
+}
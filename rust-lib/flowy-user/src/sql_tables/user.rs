@@ -10,6 +10,8 @@ pub struct UserTable {
     pub(crate) token: String,
     pub(crate) email: String,
     pub(crate) workspace: String, // deprecated
+    pub(crate) avatar: String,
+    pub(crate) email_verified: bool,
 }
 
 impl UserTable {
@@ -20,6 +22,8 @@ impl UserTable {
             email,
             token,
             workspace: "".to_owned(),
+            avatar: "".to_owned(),
+            email_verified: false,
         }
     }
 
@@ -44,6 +48,8 @@ impl std::convert::Into<UserProfile> for UserTable {
             email: self.email,
             name: self.name,
             token: self.token,
+            avatar: self.avatar,
+            email_verified: self.email_verified,
         }
     }
 }
@@ -55,15 +61,21 @@ pub struct UserTableChangeset {
     pub workspace: Option<String>, // deprecated
     pub name: Option<String>,
     pub email: Option<String>,
+    pub avatar: Option<String>,
+    pub email_verified: Option<bool>,
 }
 
 impl UserTableChangeset {
     pub fn new(params: UpdateUserParams) -> Self {
+        // The new email must be verified again
+        let email_verified = params.email.as_ref().map(|_| false);
         UserTableChangeset {
             id: params.id,
             workspace: None,
             name: params.name,
             email: params.email,
+            avatar: params.avatar,
+            email_verified,
         }
     }
 }
@@ -0,0 +1,141 @@
+use flowy_database::{schema::user_table, QueryDsl, RunQueryDsl};
+use flowy_test::{
+    builder::{FlowyWorkspaceTest, UserTest},
+    workspace::*,
+    FlowyTest,
+};
+use flowy_user::{errors::ErrorCode, event::UserEvent::*, prelude::*};
+use flowy_workspace::{
+    entities::{view::QueryViewRequest, workspace::CurrentWorkspaceSetting},
+    event::WorkspaceEvent::ReadCurWorkspace,
+};
+use serial_test::*;
+
+async fn switch_account(test: &FlowyTest, user_id: &str) -> UserProfile {
+    UserTest::new(test.sdk())
+        .event(SwitchAccount)
+        .request(SwitchAccountRequest::new(user_id))
+        .async_send()
+        .await
+        .parse::<UserProfile>()
+}
+
+fn read_user_ids(test: &FlowyTest) -> Vec<String> {
+    let conn = test.sdk.user_session.db_connection().unwrap();
+    user_table::table.select(user_table::id).load::<String>(&*conn).unwrap()
+}
+
+#[tokio::test]
+#[serial]
+async fn account_list_after_sign_up() {
+    let test = FlowyTest::setup();
+    let first = test.init_user().await;
+    let second = test.init_user().await;
+
+    let accounts = UserTest::new(test.sdk())
+        .event(ListAccounts)
+        .sync_send()
+        .parse::<RepeatedAccount>();
+
+    assert_eq!(accounts.items.len(), 2);
+    assert_eq!(accounts.items[0].user_id, first.id);
+    assert!(!accounts.items[0].is_current);
+    assert_eq!(accounts.items[1].user_id, second.id);
+    assert!(accounts.items[1].is_current);
+}
+
+#[tokio::test]
+#[serial]
+async fn account_switch() {
+    let test = FlowyTest::setup();
+    let first = test.init_user().await;
+    let _ = test.init_user().await;
+
+    let user_profile = UserTest::new(test.sdk())
+        .event(SwitchAccount)
+        .request(SwitchAccountRequest::new(&first.id))
+        .async_send()
+        .await
+        .parse::<UserProfile>();
+    assert_eq!(user_profile, first);
+
+    let user_profile = UserTest::new(test.sdk())
+        .event(GetUserProfile)
+        .sync_send()
+        .parse::<UserProfile>();
+    assert_eq!(user_profile, first);
+}
+
+#[tokio::test]
+#[serial]
+async fn account_sign_out_remove_account() {
+    let test = FlowyTest::setup();
+    let first = test.init_user().await;
+    let _ = test.init_user().await;
+    let _ = UserTest::new(test.sdk()).event(SignOut).sync_send();
+
+    let accounts = UserTest::new(test.sdk())
+        .event(ListAccounts)
+        .sync_send()
+        .parse::<RepeatedAccount>();
+    assert_eq!(accounts.items.len(), 1);
+    assert_eq!(accounts.items[0].user_id, first.id);
+    assert!(!accounts.items[0].is_current);
+}
+
+#[tokio::test]
+async fn account_switch_to_unknown_account() {
+    let test = FlowyTest::setup();
+    assert_eq!(
+        UserTest::new(test.sdk())
+            .event(SwitchAccount)
+            .request(SwitchAccountRequest::new("unknown"))
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::UserNotExist.value()
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn account_switch_isolates_database() {
+    let test = FlowyTest::setup();
+    let first = test.init_user().await;
+    let kv = &test.sdk.kv_store;
+    kv.set("account_test", &first.id).unwrap();
+
+    let second = test.init_user().await;
+    assert_eq!(read_user_ids(&test), vec![second.id.clone()]);
+    assert_eq!(kv.get::<String>("account_test").unwrap(), None);
+    kv.set("account_test", &second.id).unwrap();
+
+    let _ = switch_account(&test, &first.id).await;
+    assert_eq!(read_user_ids(&test), vec![first.id.clone()]);
+    assert_eq!(kv.get::<String>("account_test").unwrap(), Some(first.id.clone()));
+}
+
+#[tokio::test]
+#[serial]
+async fn account_switch_keeps_latest_view() {
+    let test = FlowyTest::setup();
+    let first = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = open_view(
+        &test.sdk,
+        QueryViewRequest {
+            view_ids: vec![view_test.view.id.clone()],
+        },
+    )
+    .await;
+
+    let _ = test.init_user().await;
+    let _ = switch_account(&test, &first.id).await;
+    let setting = FlowyWorkspaceTest::new(test.sdk())
+        .event(ReadCurWorkspace)
+        .async_send()
+        .await
+        .parse::<CurrentWorkspaceSetting>();
+    assert_eq!(setting.latest_view.unwrap().id, view_test.view.id);
+}
@@ -0,0 +1,88 @@
+use crate::helper::*;
+use flowy_test::{builder::UserTest, workspace::*, FlowyTest};
+use flowy_user::{errors::ErrorCode, event::UserEvent::*, prelude::*};
+use flowy_workspace::entities::workspace::QueryWorkspaceRequest;
+use serial_test::*;
+
+async fn start_anonymous(test: &FlowyTest) -> UserProfile {
+    UserTest::new(test.sdk())
+        .event(StartAnonymous)
+        .async_send()
+        .await
+        .parse::<UserProfile>()
+}
+
+#[tokio::test]
+#[serial]
+async fn anonymous_start() {
+    let test = FlowyTest::setup();
+    let user_profile = start_anonymous(&test).await;
+    assert!(!user_profile.id.is_empty());
+    assert!(user_profile.email.is_empty());
+
+    // the anonymous user gets the default workspace without the server
+    let workspaces = read_workspace(&test.sdk(), QueryWorkspaceRequest::new(None)).await;
+    assert_eq!(workspaces.len(), 1);
+
+    // starting again keeps the same anonymous user
+    assert_eq!(start_anonymous(&test).await, user_profile);
+}
+
+#[tokio::test]
+#[serial]
+async fn anonymous_migrate_to_account() {
+    let test = FlowyTest::setup();
+    let anonymous = start_anonymous(&test).await;
+    let workspace = read_workspace(&test.sdk(), QueryWorkspaceRequest::new(None))
+        .await
+        .pop()
+        .unwrap();
+    let app = create_app(&test.sdk(), "App A", "AppFlowy Github Project", &workspace.id).await;
+
+    let email = random_email();
+    let request = SignUpRequest {
+        email: email.clone(),
+        name: "app flowy".to_owned(),
+        password: login_password(),
+    };
+    let user_profile = UserTest::new(test.sdk())
+        .event(MigrateToAccount)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<UserProfile>();
+    assert_ne!(user_profile.id, anonymous.id);
+    assert_eq!(user_profile.email, email);
+
+    // the workspace of the anonymous user belongs to the account now
+    let workspaces = read_workspace(&test.sdk(), QueryWorkspaceRequest::new(None)).await;
+    assert_eq!(workspaces.len(), 1);
+    assert_eq!(workspaces[0].id, workspace.id);
+    assert_eq!(workspaces[0].apps.items.last().unwrap(), &app);
+
+    let accounts = UserTest::new(test.sdk())
+        .event(ListAccounts)
+        .sync_send()
+        .parse::<RepeatedAccount>();
+    assert!(accounts.items.iter().all(|account| account.user_id != anonymous.id));
+}
+
+#[tokio::test]
+#[serial]
+async fn anonymous_migrate_signed_in_account() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let request = SignUpRequest {
+        email: random_email(),
+        name: "app flowy".to_owned(),
+        password: login_password(),
+    };
+    let code = UserTest::new(test.sdk())
+        .event(MigrateToAccount)
+        .request(request)
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::NotAnonymousUser.value());
+}
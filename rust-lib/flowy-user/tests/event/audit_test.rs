@@ -0,0 +1,77 @@
+use flowy_test::{builder::UserTest, workspace::*, FlowyTestSDK};
+use flowy_user::{event::UserEvent::*, prelude::*};
+use flowy_workspace::entities::app::QueryAppRequest;
+
+async fn read_audit_log(sdk: &FlowyTestSDK, request: ReadAuditLogRequest) -> Vec<AuditRecord> {
+    UserTest::new(sdk.clone())
+        .event(ReadAuditLog)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<RepeatedAuditRecord>()
+        .items
+}
+
+#[tokio::test]
+async fn audit_log_records_the_mutating_events() {
+    let test = AppTest::new().await;
+    test.move_app_to_trash().await;
+
+    let records = read_audit_log(
+        &test.sdk,
+        ReadAuditLogRequest {
+            object_id: Some(test.app.id.clone()),
+            ..Default::default()
+        },
+    )
+    .await;
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].event, "UpdateApp");
+    assert!(!records[0].user_id.is_empty());
+
+    let records = read_audit_log(
+        &test.sdk,
+        ReadAuditLogRequest {
+            object_id: Some(test.workspace.id.clone()),
+            ..Default::default()
+        },
+    )
+    .await;
+    assert!(records.iter().any(|record| record.event == "CreateApp"));
+}
+
+#[tokio::test]
+async fn audit_log_skips_the_read_events() {
+    let test = AppTest::new().await;
+    let request = QueryAppRequest {
+        app_ids: vec![test.app.id.clone()],
+    };
+    let _ = read_app(&test.sdk, request).await;
+
+    let records = read_audit_log(&test.sdk, ReadAuditLogRequest::default()).await;
+    assert!(records.iter().all(|record| record.event != "ReadApp"));
+}
+
+#[tokio::test]
+async fn audit_log_filtered_by_time() {
+    let test = AppTest::new().await;
+    let records = read_audit_log(
+        &test.sdk,
+        ReadAuditLogRequest {
+            from_time: Some(flowy_infra::timestamp() + 60),
+            ..Default::default()
+        },
+    )
+    .await;
+    assert!(records.is_empty());
+
+    let records = read_audit_log(
+        &test.sdk,
+        ReadAuditLogRequest {
+            limit: Some(1),
+            ..Default::default()
+        },
+    )
+    .await;
+    assert_eq!(records.len(), 1);
+}
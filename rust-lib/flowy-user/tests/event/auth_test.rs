@@ -1,6 +1,17 @@
 use crate::helper::*;
-use flowy_test::{builder::UserTest, FlowyTest};
-use flowy_user::{errors::ErrorCode, event::UserEvent::*, prelude::*};
+use flowy_infra::{secret::SecretStoreKind, uuid};
+use flowy_net::config::ServerConfig;
+use flowy_test::{
+    builder::UserTest,
+    prelude::{root_dir, FlowySDKConfig},
+    FlowyTest,
+};
+use flowy_user::{
+    errors::{ErrorCode, UserError},
+    event::UserEvent::*,
+    prelude::*,
+};
+use serial_test::*;
 
 #[tokio::test]
 async fn sign_up_with_invalid_email() {
@@ -106,3 +117,160 @@ async fn sign_in_with_invalid_password() {
             .assert_error();
     }
 }
+
+fn sdk_config(name: &str) -> FlowySDKConfig {
+    FlowySDKConfig::new(&root_dir(), ServerConfig::default(), name)
+        .log_filter("debug")
+        .secret_store(SecretStoreKind::File)
+}
+
+#[tokio::test]
+#[serial]
+async fn auto_sign_in_restore_session() {
+    let name = uuid();
+    let test = FlowyTest::setup_with_config(sdk_config(&name));
+    let user_profile = test.init_user().await;
+
+    // The sdk with the same name reads the session persisted by the previous one.
+    let test = FlowyTest::setup_with_config(sdk_config(&name));
+    let user = UserTest::new(test.sdk())
+        .event(AutoSignIn)
+        .async_send()
+        .await
+        .parse::<UserProfile>();
+    assert_eq!(user_profile, user);
+}
+
+#[tokio::test]
+async fn auto_sign_in_without_session() {
+    let test = FlowyTest::setup();
+    assert_eq!(
+        UserTest::new(test.sdk())
+            .event(AutoSignIn)
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::UserUnauthorized.value()
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn auto_sign_in_with_expired_session() {
+    let test = FlowyTest::setup_with_config(sdk_config(&uuid()).session_expiration(0));
+    let _ = test.init_user().await;
+
+    assert_eq!(
+        UserTest::new(test.sdk())
+            .event(AutoSignIn)
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::SessionExpired.value()
+    );
+
+    // The expired session is removed
+    assert_eq!(
+        UserTest::new(test.sdk())
+            .event(AutoSignIn)
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::UserUnauthorized.value()
+    );
+}
+
+async fn wrong_password_sign_in(test: &FlowyTest, email: &str) -> UserError {
+    let request = SignInRequest {
+        email: email.to_owned(),
+        password: "HelloWorld!456".to_string(),
+        name: "".to_string(),
+    };
+    UserTest::new(test.sdk())
+        .event(SignIn)
+        .request(request)
+        .async_send()
+        .await
+        .error()
+}
+
+#[tokio::test]
+#[serial]
+async fn sign_in_is_locked_after_too_many_failed_attempts() {
+    let test = FlowyTest::setup();
+    let sign_up_context = test.sign_up().await;
+    let email = sign_up_context.user_profile.email.clone();
+    let _ = UserTest::new(test.sdk()).event(SignOut).sync_send();
+
+    for _ in 0..5 {
+        assert_eq!(
+            wrong_password_sign_in(&test, &email).await.code,
+            ErrorCode::PasswordNotMatch.value()
+        );
+    }
+
+    // Locked now, even with the right password
+    let request = SignInRequest {
+        email,
+        password: sign_up_context.password,
+        name: "".to_string(),
+    };
+    let error = UserTest::new(test.sdk())
+        .event(SignIn)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::TooManyAttempts.value());
+    assert!(error.retry_after > 0 && error.retry_after <= 30);
+}
+
+#[tokio::test]
+#[serial]
+async fn sign_in_lockout_is_kept_after_restart() {
+    let test = FlowyTest::setup();
+    let email = test.sign_up().await.user_profile.email;
+    let _ = UserTest::new(test.sdk()).event(SignOut).sync_send();
+    for _ in 0..5 {
+        let _ = wrong_password_sign_in(&test, &email).await;
+    }
+
+    let test = FlowyTest::setup();
+    assert_eq!(
+        wrong_password_sign_in(&test, &email).await.code,
+        ErrorCode::TooManyAttempts.value()
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn sign_in_resets_failed_attempts() {
+    let test = FlowyTest::setup();
+    let sign_up_context = test.sign_up().await;
+    let email = sign_up_context.user_profile.email.clone();
+    let _ = UserTest::new(test.sdk()).event(SignOut).sync_send();
+    for _ in 0..4 {
+        let _ = wrong_password_sign_in(&test, &email).await;
+    }
+
+    let request = SignInRequest {
+        email: email.clone(),
+        password: sign_up_context.password,
+        name: "".to_string(),
+    };
+    let _ = UserTest::new(test.sdk())
+        .event(SignIn)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<UserProfile>();
+    let _ = UserTest::new(test.sdk()).event(SignOut).sync_send();
+
+    assert_eq!(
+        wrong_password_sign_in(&test, &email).await.code,
+        ErrorCode::PasswordNotMatch.value()
+    );
+}
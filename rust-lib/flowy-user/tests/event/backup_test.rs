@@ -0,0 +1,109 @@
+use flowy_infra::{secret::SecretStoreKind, uuid};
+use flowy_net::config::ServerConfig;
+use flowy_test::{
+    builder::UserTest,
+    prelude::{root_dir, FlowySDKConfig},
+    FlowyTest,
+};
+use flowy_user::{errors::ErrorCode, event::UserEvent::*, prelude::*};
+use serial_test::*;
+
+async fn create_backup(test: &FlowyTest) -> DatabaseBackup {
+    UserTest::new(test.sdk())
+        .event(CreateBackup)
+        .async_send()
+        .await
+        .parse::<DatabaseBackup>()
+}
+
+async fn list_backups(test: &FlowyTest) -> Vec<DatabaseBackup> {
+    UserTest::new(test.sdk())
+        .event(ListBackups)
+        .async_send()
+        .await
+        .parse::<RepeatedDatabaseBackup>()
+        .items
+}
+
+fn restore_backup(test: &FlowyTest, backup_id: &str) -> UserTest {
+    UserTest::new(test.sdk())
+        .event(RestoreBackup)
+        .request(RestoreBackupRequest::new(backup_id))
+}
+
+fn backup_path(user_id: &str, backup_id: &str) -> String {
+    format!("{}/{}/backups/{}.db", root_dir(), user_id, backup_id)
+}
+
+#[tokio::test]
+#[serial]
+async fn backup_created_and_listed() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    assert!(list_backups(&test).await.is_empty());
+
+    let first = create_backup(&test).await;
+    let second = create_backup(&test).await;
+    assert!(second.created_time > first.created_time);
+    assert!(second.size > 0);
+    assert_eq!(list_backups(&test).await, vec![second, first]);
+}
+
+#[tokio::test]
+#[serial]
+async fn backup_oldest_removed() {
+    let config = FlowySDKConfig::new(&root_dir(), ServerConfig::default(), &uuid())
+        .secret_store(SecretStoreKind::File)
+        .max_backups(2);
+    let test = FlowyTest::setup_with_config(config);
+    let _ = test.init_user().await;
+
+    let mut created = vec![];
+    for _ in 0..3 {
+        created.push(create_backup(&test).await);
+    }
+    let ids: Vec<String> = list_backups(&test).await.into_iter().map(|backup| backup.id).collect();
+    assert_eq!(ids, vec![created[2].id.clone(), created[1].id.clone()]);
+}
+
+#[tokio::test]
+#[serial]
+async fn backup_restored() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let kv = &test.sdk.kv_store;
+    kv.set("backup_test", &1).unwrap();
+    let backup = create_backup(&test).await;
+    kv.set("backup_test", &2).unwrap();
+    kv.set("backup_test_after", &3).unwrap();
+
+    restore_backup(&test, &backup.id).async_send().await.assert_success();
+    assert_eq!(kv.get::<i32>("backup_test").unwrap(), Some(1));
+    assert_eq!(kv.get::<i32>("backup_test_after").unwrap(), None);
+}
+
+#[tokio::test]
+#[serial]
+async fn backup_corrupted_not_restored() {
+    let test = FlowyTest::setup();
+    let user = test.init_user().await;
+    let kv = &test.sdk.kv_store;
+    let backup = create_backup(&test).await;
+    kv.set("backup_test", &2).unwrap();
+    std::fs::write(backup_path(&user.id, &backup.id), b"SQLite format 3\0 but not really").unwrap();
+
+    let error = restore_backup(&test, &backup.id).async_send().await.error();
+    assert_eq!(error.code, ErrorCode::BackupCorrupted.value());
+    assert_eq!(kv.get::<i32>("backup_test").unwrap(), Some(2));
+}
+
+#[tokio::test]
+#[serial]
+async fn backup_restore_missing() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    for id in ["123", "../flowy-database"] {
+        let error = restore_backup(&test, id).async_send().await.error();
+        assert_eq!(error.code, ErrorCode::BackupNotExist.value());
+    }
+}
@@ -0,0 +1,86 @@
+use flowy_database::{dsl::sql, sql_types::Integer, RunQueryDsl};
+use flowy_infra::{secret::SecretStoreKind, uuid};
+use flowy_net::config::ServerConfig;
+use flowy_test::{
+    builder::UserTest,
+    prelude::{root_dir, FlowySDKConfig},
+    FlowyTest,
+};
+use flowy_user::{event::UserEvent::*, prelude::*};
+use serial_test::*;
+use std::time::Duration;
+
+async fn compact_storage(test: &FlowyTest) -> StorageCompaction {
+    UserTest::new(test.sdk())
+        .event(CompactStorage)
+        .async_send()
+        .await
+        .parse::<StorageCompaction>()
+}
+
+// Leaves about 1MB of free pages in the user database.
+fn write_and_delete(test: &FlowyTest) {
+    let kv = &test.sdk.kv_store;
+    let value = "x".repeat(10 * 1024);
+    for i in 0..100 {
+        kv.set(&format!("compaction_test_{}", i), &value).unwrap();
+    }
+    for i in 0..100 {
+        assert!(kv.delete(&format!("compaction_test_{}", i)).unwrap());
+    }
+}
+
+fn pragma(test: &FlowyTest, name: &str) -> i32 {
+    let conn = test.sdk.user_session.db_connection().unwrap();
+    sql::<Integer>(&format!("PRAGMA {}", name))
+        .get_result::<i32>(&*conn)
+        .unwrap()
+}
+
+#[tokio::test]
+#[serial]
+async fn compaction_reclaims_free_pages() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    write_and_delete(&test);
+    assert!(pragma(&test, "freelist_count") > 0);
+
+    // The first one vacuums the database to enable the incremental vacuum.
+    let compaction = compact_storage(&test).await;
+    assert!(compaction.reclaimed_bytes > 0);
+    assert_eq!(
+        compaction.reclaimed_bytes,
+        compaction.size_before - compaction.size_after
+    );
+    assert_eq!(pragma(&test, "auto_vacuum"), 2);
+    assert_eq!(pragma(&test, "freelist_count"), 0);
+
+    write_and_delete(&test);
+    let compaction = compact_storage(&test).await;
+    assert!(compaction.reclaimed_bytes > 0);
+    assert_eq!(pragma(&test, "freelist_count"), 0);
+    assert_eq!(compact_storage(&test).await.reclaimed_bytes, 0);
+}
+
+#[tokio::test]
+#[serial]
+async fn compaction_scheduled_when_idle() {
+    let config = FlowySDKConfig::new(&root_dir(), ServerConfig::default(), &uuid())
+        .secret_store(SecretStoreKind::File)
+        .compaction_idle_period(Duration::from_millis(200));
+    let test = FlowyTest::setup_with_config(config);
+    let _ = test.init_user().await;
+    write_and_delete(&test);
+    assert!(pragma(&test, "freelist_count") > 0);
+
+    // The database may have been compacted while it was written, the event ends
+    // that idle period.
+    let _ = UserTest::new(test.sdk())
+        .event(GetUserProfile)
+        .async_send()
+        .await
+        .parse::<UserProfile>();
+    tokio::time::sleep(Duration::from_millis(1000)).await;
+    assert_eq!(pragma(&test, "auto_vacuum"), 2);
+    assert_eq!(pragma(&test, "freelist_count"), 0);
+}
@@ -0,0 +1,45 @@
+use flowy_test::{builder::UserTest, prelude::*, FlowyTest};
+use serial_test::*;
+
+async fn read_crash_bundle(test: &FlowyTest, name: &str) -> EventResponse {
+    let request = CrashBundleIdentifier { name: name.to_owned() };
+    let request = ModuleRequest::new(SystemEvent::ReadCrashBundle).payload(request.into_bytes().unwrap());
+    EventDispatch::async_send(test.sdk().dispatch(), request).await
+}
+
+#[tokio::test]
+#[serial]
+async fn crash_bundle_listed_and_read() {
+    let test = FlowyTest::setup();
+    tracing::error!(target: "flowy_user::crash_test", "before the crash");
+    let path = write_crash_bundle("crash test").unwrap();
+
+    let bundles = UserTest::new(test.sdk())
+        .event(SystemEvent::ListCrashBundles)
+        .async_send()
+        .await
+        .parse::<RepeatedCrashBundle>();
+    let bundle = &bundles.items[0];
+    assert_eq!(bundle.path, path.to_string_lossy());
+    assert!(bundle.size > 0);
+
+    let response = read_crash_bundle(&test, &bundle.name).await;
+    let content = response
+        .parse::<CrashBundleContent, DispatchError>()
+        .unwrap()
+        .unwrap()
+        .content;
+    assert!(content.starts_with("reason: crash test\n"));
+    assert!(content.contains("[dispatch]"));
+    assert!(content.contains("flowy_user::crash_test"));
+    assert!(!content.contains("before the crash"));
+}
+
+#[tokio::test]
+#[serial]
+async fn crash_bundle_read_outside_the_directory() {
+    let test = FlowyTest::setup();
+    let _ = write_crash_bundle("crash test").unwrap();
+    let response = read_crash_bundle(&test, "../crash-0.txt").await;
+    assert_ne!(response.status_code, StatusCode::Ok);
+}
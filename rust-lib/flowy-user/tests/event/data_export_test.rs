@@ -0,0 +1,81 @@
+use flowy_database::{schema::user_table, Connection, QueryDsl, RunQueryDsl, SqliteConnection};
+use flowy_test::{builder::UserTest, FlowyTest};
+use flowy_user::{event::UserEvent::*, prelude::*, services::user::DATA_EXPORT_VERSION};
+use serial_test::*;
+use std::path::Path;
+
+async fn export_all_data(test: &FlowyTest, format: DataExportFormat) -> DataExport {
+    UserTest::new(test.sdk())
+        .event(ExportAllData)
+        .request(ExportAllDataRequest { format })
+        .async_send()
+        .await
+        .parse::<DataExport>()
+}
+
+fn read_manifest(export: &DataExport) -> serde_json::Value {
+    let manifest = std::fs::read_to_string(Path::new(&export.dir).join("manifest.json")).unwrap();
+    serde_json::from_str(&manifest).unwrap()
+}
+
+// The secrets can't be found in any file of the export.
+fn assert_not_exported(export: &DataExport, secret: &str) {
+    for entry in std::fs::read_dir(&export.dir).unwrap() {
+        let bytes = std::fs::read(entry.unwrap().path()).unwrap();
+        assert!(!bytes.windows(secret.len()).any(|window| window == secret.as_bytes()));
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn data_export_json_lines() {
+    let test = FlowyTest::setup();
+    let user = test.init_user().await;
+    let export = export_all_data(&test, DataExportFormat::JsonLines).await;
+    assert_eq!(export.version, DATA_EXPORT_VERSION);
+    assert_eq!(export.format, DataExportFormat::JsonLines);
+
+    let manifest = read_manifest(&export);
+    assert_eq!(manifest["version"], DATA_EXPORT_VERSION);
+    assert_eq!(manifest["format"], "json_lines");
+    assert_eq!(manifest["tables"].as_array().unwrap().len(), export.tables.len());
+    let user_table = export.tables.iter().find(|table| table.name == "user_table").unwrap();
+    assert_eq!(user_table.rows, 1);
+
+    let lines = std::fs::read_to_string(Path::new(&export.dir).join("user_table.jsonl")).unwrap();
+    let rows: Vec<serde_json::Value> = lines.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0]["id"], user.id.as_str());
+    assert_eq!(rows[0]["email"], user.email.as_str());
+    assert!(rows[0].get("token").is_none());
+    assert_not_exported(&export, &user.token);
+}
+
+#[tokio::test]
+#[serial]
+async fn data_export_sqlite() {
+    let test = FlowyTest::setup();
+    let user = test.init_user().await;
+    let export = export_all_data(&test, DataExportFormat::Sqlite).await;
+    assert_eq!(read_manifest(&export)["format"], "sqlite");
+
+    let path = Path::new(&export.dir).join("data.db");
+    let conn = SqliteConnection::establish(&path.to_string_lossy()).unwrap();
+    let users = user_table::table
+        .select((user_table::id, user_table::token))
+        .load::<(String, String)>(&conn)
+        .unwrap();
+    assert_eq!(users, vec![(user.id.clone(), "".to_owned())]);
+    assert_not_exported(&export, &user.token);
+}
+
+#[tokio::test]
+#[serial]
+async fn data_export_kept_in_new_directory() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let first = export_all_data(&test, DataExportFormat::JsonLines).await;
+    let second = export_all_data(&test, DataExportFormat::JsonLines).await;
+    assert_ne!(first.dir, second.dir);
+    assert!(Path::new(&first.dir).join("manifest.json").exists());
+}
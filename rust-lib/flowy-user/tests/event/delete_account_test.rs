@@ -0,0 +1,79 @@
+use flowy_test::{builder::UserTest, prelude::root_dir, FlowyTest};
+use flowy_user::{errors::ErrorCode, event::UserEvent::*, prelude::*};
+use serial_test::*;
+
+#[tokio::test]
+#[serial]
+async fn delete_account_success() {
+    let test = FlowyTest::setup();
+    let context = test.sign_up().await;
+    let user_dir = format!("{}/{}", root_dir(), context.user_profile.id);
+    assert!(std::path::Path::new(&user_dir).exists());
+
+    let _ = UserTest::new(test.sdk())
+        .event(DeleteAccount)
+        .request(DeleteAccountRequest::new(&context.user_profile.email, &context.password))
+        .async_send()
+        .await
+        .assert_success();
+
+    assert!(!std::path::Path::new(&user_dir).exists());
+    let _ = UserTest::new(test.sdk()).event(GetUserProfile).sync_send().error();
+}
+
+#[tokio::test]
+#[serial]
+async fn delete_account_without_confirmation() {
+    let test = FlowyTest::setup();
+    let context = test.sign_up().await;
+
+    let code = UserTest::new(test.sdk())
+        .event(DeleteAccount)
+        .request(DeleteAccountRequest::new("someone@appflowy.io", &context.password))
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::DeleteAccountNotConfirmed.value());
+}
+
+#[tokio::test]
+#[serial]
+async fn delete_account_with_wrong_password() {
+    let test = FlowyTest::setup();
+    let context = test.sign_up().await;
+
+    let code = UserTest::new(test.sdk())
+        .event(DeleteAccount)
+        .request(DeleteAccountRequest::new(&context.user_profile.email, "WrongPassword!123"))
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::PasswordNotMatch.value());
+
+    // nothing is deleted
+    let user_profile = UserTest::new(test.sdk())
+        .event(GetUserProfile)
+        .sync_send()
+        .parse::<UserProfile>();
+    assert_eq!(user_profile.id, context.user_profile.id);
+}
+
+#[tokio::test]
+#[serial]
+async fn delete_anonymous_account() {
+    let test = FlowyTest::setup();
+    let _ = UserTest::new(test.sdk())
+        .event(StartAnonymous)
+        .async_send()
+        .await
+        .parse::<UserProfile>();
+
+    let _ = UserTest::new(test.sdk())
+        .event(DeleteAccount)
+        .request(DeleteAccountRequest::new("delete", ""))
+        .async_send()
+        .await
+        .assert_success();
+}
@@ -0,0 +1,95 @@
+use crate::helper::*;
+use flowy_database::{DatabaseKey, PoolConfig, DB_NAME};
+use flowy_infra::{secret::SecretStoreKind, uuid};
+use flowy_net::config::ServerConfig;
+use flowy_test::{
+    prelude::{root_dir, FlowySDKConfig},
+    FlowyTest,
+};
+use flowy_user::{errors::ErrorCode, event::UserEvent::*, prelude::*};
+use serial_test::*;
+use std::cell::Cell;
+
+fn database_file(dir: &str) -> String { format!("{}/{}", dir, DB_NAME) }
+
+#[cfg(feature = "sqlcipher")]
+#[tokio::test]
+#[serial]
+async fn encryption_of_missing_database_skipped() {
+    let dir = format!("{}/{}", root_dir(), uuid());
+    let encrypted = flowy_database::encrypt(&dir, &DatabaseKey::new([1; 32]), |_| {}).unwrap();
+    assert!(!encrypted);
+}
+
+#[cfg(not(feature = "sqlcipher"))]
+#[tokio::test]
+#[serial]
+async fn encryption_unsupported_keeps_database() {
+    let dir = format!("{}/{}", root_dir(), uuid());
+    let _ = flowy_database::init_with_config(&dir, PoolConfig::default(), &[]).unwrap();
+    let plaintext = std::fs::read(database_file(&dir)).unwrap();
+
+    let progress_count = Cell::new(0);
+    let error = flowy_database::encrypt(&dir, &DatabaseKey::new([1; 32]), |_| {
+        progress_count.set(progress_count.get() + 1)
+    })
+    .unwrap_err();
+    assert!(error.is_cipher_unsupported());
+    assert_eq!(progress_count.get(), 0);
+    assert_eq!(std::fs::read(database_file(&dir)).unwrap(), plaintext);
+    assert!(!std::path::Path::new(&format!("{}.encrypting", database_file(&dir))).exists());
+
+    // The ones that don't exist yet would be created encrypted.
+    let dir = format!("{}/{}", root_dir(), uuid());
+    let error = flowy_database::encrypt(&dir, &DatabaseKey::new([1; 32]), |_| {}).unwrap_err();
+    assert!(error.is_cipher_unsupported());
+}
+
+#[cfg(not(feature = "sqlcipher"))]
+#[tokio::test]
+#[serial]
+async fn encryption_unsupported_when_signing_up() {
+    let config = FlowySDKConfig::new(&root_dir(), ServerConfig::default(), &uuid())
+        .secret_store(SecretStoreKind::File)
+        .encrypt_database(true);
+    let test = FlowyTest::setup_with_config(config);
+    let request = SignUpRequest {
+        email: random_email(),
+        name: "app flowy".to_owned(),
+        password: login_password(),
+    };
+
+    let error = UserTest::new(test.sdk())
+        .event(SignUp)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::DatabaseEncryptionUnsupported.value());
+}
+
+#[cfg(feature = "sqlcipher")]
+#[tokio::test]
+#[serial]
+async fn encryption_keeps_rows() {
+    use flowy_database::{schema::migration_table, QueryDsl, RunQueryDsl};
+
+    let dir = format!("{}/{}", root_dir(), uuid());
+    let count = |pool_config: PoolConfig| {
+        let database = flowy_database::init_with_config(&dir, pool_config, &[]).unwrap();
+        let conn = database.get_connection().unwrap();
+        migration_table::table.count().get_result::<i64>(&*conn).unwrap()
+    };
+    let expected = count(PoolConfig::default());
+
+    let key = DatabaseKey::new([1; 32]);
+    let progress_count = Cell::new(0);
+    let encrypted = flowy_database::encrypt(&dir, &key, |_| progress_count.set(progress_count.get() + 1)).unwrap();
+    assert!(encrypted);
+    assert!(progress_count.get() > 1);
+    assert!(!flowy_database::encrypt(&dir, &key, |_| {}).unwrap());
+    assert_eq!(count(PoolConfig::default().encryption_key(key)), expected);
+
+    // It can't be opened without the key.
+    assert!(flowy_database::init_with_config(&dir, PoolConfig::default(), &[]).is_err());
+}
@@ -0,0 +1,43 @@
+use crate::helper::*;
+use flowy_test::{golden::Golden, FlowyTest};
+use flowy_user::{event::UserEvent::*, prelude::*};
+
+#[tokio::test]
+async fn sign_up_response_golden() {
+    let test = FlowyTest::setup_in_memory();
+    let request = SignUpRequest {
+        email: random_email(),
+        name: "app flowy".to_owned(),
+        password: login_password(),
+    };
+    let response = UserTest::new(test.sdk)
+        .event(SignUp)
+        .request(request)
+        .async_send()
+        .await
+        .response();
+
+    Golden::new("sign_up")
+        .redact("id")
+        .redact("email")
+        .redact("token")
+        .assert::<flowy_user_infra::protobuf::UserProfile>(&response);
+}
+
+#[tokio::test]
+async fn sign_up_with_invalid_email_golden() {
+    let test = FlowyTest::setup_in_memory();
+    let request = SignUpRequest {
+        email: "annie@".to_owned(),
+        name: "app flowy".to_owned(),
+        password: login_password(),
+    };
+    let response = UserTest::new(test.sdk)
+        .event(SignUp)
+        .request(request)
+        .async_send()
+        .await
+        .response();
+
+    Golden::new("sign_up_with_invalid_email").assert::<flowy_user::protobuf::UserError>(&response);
+}
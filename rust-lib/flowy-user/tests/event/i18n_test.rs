@@ -0,0 +1,72 @@
+use crate::helper::*;
+use flowy_infra::kv::KV;
+use flowy_test::{
+    builder::UserTest,
+    prelude::{LocaleSetting, SetLocaleRequest, StatusCode, SystemEvent, Translations, TranslationsRequest},
+    FlowyTest,
+};
+use flowy_user::{errors::ErrorCode, event::UserEvent::*, prelude::*};
+use serial_test::*;
+
+fn set_locale(test: &FlowyTest, locale: &str) -> UserTest {
+    UserTest::new(test.sdk())
+        .event(SystemEvent::SetLocale)
+        .request(SetLocaleRequest {
+            locale: locale.to_owned(),
+        })
+}
+
+#[tokio::test]
+#[serial]
+async fn i18n_set_locale_translates_the_errors() {
+    let test = FlowyTest::setup();
+    let setting = set_locale(&test, "fr_FR").async_send().await.parse::<LocaleSetting>();
+    assert_eq!(setting.locale, "fr");
+    assert!(setting.locales.contains(&"en".to_owned()));
+    assert_eq!(KV::get_str("locale").unwrap(), "fr");
+
+    let error = UserTest::new(test.sdk())
+        .event(SignIn)
+        .request(SignInRequest {
+            email: "not an email".to_owned(),
+            password: login_password(),
+            ..Default::default()
+        })
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::EmailFormatInvalid.value());
+    assert_eq!(error.msg, "Le format de l'e-mail n'est pas valide");
+
+    let translations = UserTest::new(test.sdk())
+        .event(SystemEvent::GetTranslations)
+        .request(TranslationsRequest::default())
+        .async_send()
+        .await
+        .parse::<Translations>();
+    assert_eq!(translations.locale, "fr");
+    let translation = translations
+        .items
+        .iter()
+        .find(|translation| translation.key == "error.user.EmailFormatInvalid")
+        .unwrap();
+    assert_eq!(translation.value, error.msg);
+
+    let setting = set_locale(&test, "en").async_send().await.parse::<LocaleSetting>();
+    assert_eq!(setting.locale, "en");
+}
+
+#[tokio::test]
+#[serial]
+async fn i18n_locale_without_catalog_is_rejected() {
+    let test = FlowyTest::setup();
+    let response = set_locale(&test, "xx").async_send().await.response();
+    assert_eq!(response.status_code, StatusCode::Err);
+
+    let setting = UserTest::new(test.sdk())
+        .event(SystemEvent::ReadLocale)
+        .async_send()
+        .await
+        .parse::<LocaleSetting>();
+    assert_eq!(setting.locale, "en");
+}
@@ -0,0 +1,47 @@
+use flowy_database::{schema::user_table, QueryDsl, RunQueryDsl};
+use flowy_test::{builder::UserTest, workspace::*, FlowyTest};
+use flowy_user::{event::UserEvent::*, prelude::*};
+use flowy_workspace::entities::workspace::QueryWorkspaceRequest;
+use std::path::Path;
+
+async fn sign_up_and_create_workspace(test: &FlowyTest) -> UserProfile {
+    let user = test.init_user().await;
+    let workspace = create_workspace(&test.sdk, "Memory", "").await;
+    let workspaces = read_workspace(
+        &test.sdk,
+        QueryWorkspaceRequest {
+            workspace_id: Some(workspace.id.clone()),
+        },
+    )
+    .await;
+    assert_eq!(workspaces.len(), 1);
+    assert_eq!(workspaces[0].name, "Memory");
+    user
+}
+
+#[tokio::test]
+async fn in_memory_storage_not_written_to_disk() {
+    let test = FlowyTest::setup_in_memory();
+    let user = sign_up_and_create_workspace(&test).await;
+    let profile = UserTest::new(test.sdk())
+        .event(GetUserProfile)
+        .async_send()
+        .await
+        .parse::<UserProfile>();
+    assert_eq!(profile.id, user.id);
+
+    let user_dir = test.sdk.user_session.user_dir().unwrap();
+    assert!(!Path::new(&user_dir).join("flowy-database.db").exists());
+}
+
+#[tokio::test]
+async fn in_memory_storage_in_parallel() {
+    let tests = vec![FlowyTest::setup_in_memory(), FlowyTest::setup_in_memory()];
+    let users = futures::future::join_all(tests.iter().map(sign_up_and_create_workspace)).await;
+    assert_ne!(users[0].id, users[1].id);
+    for (test, user) in tests.iter().zip(users.iter()) {
+        let conn = test.sdk.user_session.db_connection().unwrap();
+        let ids = user_table::table.select(user_table::id).load::<String>(&*conn).unwrap();
+        assert_eq!(ids, vec![user.id.clone()]);
+    }
+}
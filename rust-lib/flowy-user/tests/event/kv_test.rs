@@ -0,0 +1,57 @@
+use flowy_database::KVError;
+use flowy_test::FlowyTest;
+use serde::{Deserialize, Serialize};
+use serial_test::*;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct SyncCursor {
+    rev_id: i64,
+    token: String,
+}
+
+#[tokio::test]
+#[serial]
+async fn kv_set_get_and_delete() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let kv = &test.sdk.kv_store;
+    let cursor = SyncCursor {
+        rev_id: 3,
+        token: "abc".to_owned(),
+    };
+
+    assert_eq!(kv.get::<SyncCursor>("sync:cursor").unwrap(), None);
+    kv.set("sync:cursor", &cursor).unwrap();
+    assert_eq!(kv.get::<SyncCursor>("sync:cursor").unwrap(), Some(cursor));
+    match kv.get::<i64>("sync:cursor") {
+        Err(KVError::Encoding(_)) => {},
+        result => panic!("Read with the wrong type: {:?}", result),
+    }
+
+    kv.set("sync:cursor", &"replaced").unwrap();
+    assert_eq!(kv.get::<String>("sync:cursor").unwrap().unwrap(), "replaced");
+    assert!(kv.delete("sync:cursor").unwrap());
+    assert!(!kv.delete("sync:cursor").unwrap());
+    assert_eq!(kv.get::<String>("sync:cursor").unwrap(), None);
+}
+
+#[tokio::test]
+#[serial]
+async fn kv_scan_by_prefix() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let kv = &test.sdk.kv_store;
+    for key in ["cache_b", "cache_a", "cacheXa", "CACHE_c", "other"] {
+        kv.set(key, &key.len()).unwrap();
+    }
+
+    // The '_' of the prefix isn't a wildcard and the keys are case sensitive.
+    let keys: Vec<String> = kv
+        .scan_prefix::<usize>("cache_")
+        .unwrap()
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect();
+    assert_eq!(keys, vec!["cache_a".to_owned(), "cache_b".to_owned()]);
+    assert!(kv.scan_prefix::<usize>("missing").unwrap().is_empty());
+}
@@ -0,0 +1,19 @@
+use flowy_test::{builder::UserTest, FlowyTest};
+use flowy_user::{event::UserEvent::*, prelude::*};
+use serial_test::*;
+
+#[tokio::test]
+#[serial]
+async fn log_files_read_without_user() {
+    let test = FlowyTest::setup();
+    let files = UserTest::new(test.sdk())
+        .event(ReadLogFiles)
+        .async_send()
+        .await
+        .parse::<RepeatedLogFile>();
+
+    // The sdk that was set up first decides where the records are written.
+    let current = &files.items[0];
+    assert!(current.path.ends_with("flowy-client.log"));
+    assert!(std::path::Path::new(&current.path).exists());
+}
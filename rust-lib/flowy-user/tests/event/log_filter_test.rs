@@ -0,0 +1,66 @@
+use flowy_infra::kv::KV;
+use flowy_test::{
+    builder::UserTest,
+    prelude::{LogFilterSetting, RepeatedLogRecord, SetLogFilterRequest, SubscribeLogRequest, SystemEvent},
+    FlowyTest,
+};
+use serial_test::*;
+
+async fn set_log_filter(test: &FlowyTest, filter: &str) -> LogFilterSetting {
+    let request = SetLogFilterRequest {
+        filter: filter.to_owned(),
+    };
+    UserTest::new(test.sdk())
+        .event(SystemEvent::SetLogFilter)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<LogFilterSetting>()
+}
+
+async fn recent_messages(test: &FlowyTest, target: &str) -> Vec<String> {
+    let request = SubscribeLogRequest {
+        level: "trace".to_owned(),
+        targets: vec![target.to_owned()],
+    };
+    let records = UserTest::new(test.sdk())
+        .event(SystemEvent::SubscribeLog)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<RepeatedLogRecord>();
+    UserTest::new(test.sdk())
+        .event(SystemEvent::UnsubscribeLog)
+        .async_send()
+        .await;
+    records.items.into_iter().map(|record| record.message).collect()
+}
+
+#[tokio::test]
+#[serial]
+async fn log_filter_is_set_and_reset() {
+    let test = FlowyTest::setup();
+    let target = "flowy_user::log_filter_test";
+
+    let setting = set_log_filter(&test, "info,flowy_user=warn").await;
+    assert_eq!(setting.filter, "info,flowy_user=warn");
+    assert!(!setting.is_default);
+    assert_eq!(KV::get_str("log_filter").unwrap(), "info,flowy_user=warn");
+    tracing::info!(target: "flowy_user::log_filter_test", "filtered info");
+    let messages = recent_messages(&test, target).await;
+    assert!(!messages.contains(&"filtered info".to_owned()));
+
+    let setting = UserTest::new(test.sdk())
+        .event(SystemEvent::ReadLogFilter)
+        .async_send()
+        .await
+        .parse::<LogFilterSetting>();
+    assert_eq!(setting.filter, "info,flowy_user=warn");
+
+    let setting = set_log_filter(&test, "").await;
+    assert!(setting.is_default);
+    assert!(KV::get_str("log_filter").is_none());
+    tracing::info!(target: "flowy_user::log_filter_test", "passed info");
+    let messages = recent_messages(&test, target).await;
+    assert!(messages.contains(&"passed info".to_owned()));
+}
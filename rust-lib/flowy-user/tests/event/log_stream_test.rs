@@ -0,0 +1,58 @@
+use flowy_test::{
+    builder::UserTest,
+    prelude::{RepeatedLogRecord, SubscribeLogRequest, SystemEvent},
+    FlowyTest,
+};
+use serial_test::*;
+
+async fn subscribe_log(test: &FlowyTest, level: &str, target: &str) -> RepeatedLogRecord {
+    let request = SubscribeLogRequest {
+        level: level.to_owned(),
+        targets: vec![target.to_owned()],
+    };
+    UserTest::new(test.sdk())
+        .event(SystemEvent::SubscribeLog)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<RepeatedLogRecord>()
+}
+
+#[tokio::test]
+#[serial]
+async fn log_subscription_returns_the_recent_records() {
+    let test = FlowyTest::setup();
+    let target = "flowy_user::log_stream_test";
+    tracing::info!(target: "flowy_user::log_stream_test", "recent info");
+    tracing::warn!(target: "flowy_user::log_stream_test", count = 2, "recent warn");
+
+    let records = subscribe_log(&test, "warn", target).await;
+    let last = records.items.last().unwrap();
+    assert_eq!(last.level, "WARN");
+    assert_eq!(last.target, target);
+    assert_eq!(last.message, "recent warn count=2");
+    assert!(records.items.iter().all(|record| record.message != "recent info"));
+
+    let records = subscribe_log(&test, "", target).await;
+    assert!(records.items.iter().any(|record| record.message == "recent info"));
+
+    UserTest::new(test.sdk())
+        .event(SystemEvent::UnsubscribeLog)
+        .async_send()
+        .await;
+}
+
+#[tokio::test]
+#[serial]
+async fn log_subscription_filters_the_targets() {
+    let test = FlowyTest::setup();
+    tracing::warn!(target: "flowy_user::log_stream_test", "other target");
+
+    let records = subscribe_log(&test, "trace", "flowy_user::log_stream_test_filtered").await;
+    assert!(records.items.iter().all(|record| record.message != "other target"));
+
+    UserTest::new(test.sdk())
+        .event(SystemEvent::UnsubscribeLog)
+        .async_send()
+        .await;
+}
@@ -1,3 +1,29 @@
+mod account_test;
+mod anonymous_test;
+mod audit_test;
 mod auth_test;
+mod backup_test;
+mod compaction_test;
+mod crash_test;
+mod data_export_test;
+mod delete_account_test;
+mod encryption_test;
+mod golden_test;
 mod helper;
+mod i18n_test;
+mod in_memory_test;
+mod kv_test;
+mod log_file_test;
+mod log_filter_test;
+mod log_stream_test;
+mod migration_test;
+mod oauth_test;
+mod password_test;
+mod preference_test;
+mod row_change_test;
+mod secret_test;
+mod session_test;
+mod setting_test;
+mod token_test;
 mod user_profile_test;
+mod verification_test;
@@ -0,0 +1,106 @@
+use crate::helper::*;
+use flowy_database::{
+    schema::migration_table,
+    Connection,
+    InitError,
+    PoolConfig,
+    QueryDsl,
+    RunQueryDsl,
+    RustMigration,
+    SqliteConnection,
+};
+use flowy_infra::{secret::SecretStoreKind, uuid};
+use flowy_net::config::ServerConfig;
+use flowy_test::{
+    prelude::{root_dir, FlowySDKConfig},
+    FlowyTest,
+};
+use flowy_user::{errors::ErrorCode, event::UserEvent::*, prelude::*};
+use serial_test::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static RUN_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+fn counted_migration(conn: &SqliteConnection) -> Result<(), flowy_database::Error> {
+    RUN_COUNT.fetch_add(1, Ordering::SeqCst);
+    conn.execute("CREATE TABLE migration_test_table (id TEXT NOT NULL PRIMARY KEY)")?;
+    Ok(())
+}
+
+fn failed_migration(_conn: &SqliteConnection) -> Result<(), flowy_database::Error> {
+    Err(flowy_database::Error::RollbackTransaction)
+}
+
+fn applied_versions(conn: &SqliteConnection) -> Vec<String> {
+    migration_table::table
+        .select(migration_table::version)
+        .load::<String>(conn)
+        .unwrap()
+}
+
+#[tokio::test]
+#[serial]
+async fn migration_applied_once() {
+    let migration = RustMigration {
+        version: "2021-11-01-migration-test",
+        run: counted_migration,
+    };
+    let dir = format!("{}/{}", root_dir(), uuid());
+    RUN_COUNT.store(0, Ordering::SeqCst);
+    for _ in 0..2 {
+        let database = flowy_database::init_with_config(&dir, PoolConfig::default(), &[migration.clone()]).unwrap();
+        let conn = database.get_connection().unwrap();
+        assert_eq!(applied_versions(&*conn), vec![migration.version.to_owned()]);
+    }
+    assert_eq!(RUN_COUNT.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+#[serial]
+async fn migration_failed_is_rolled_back() {
+    let migrations = vec![
+        RustMigration {
+            version: "2021-11-01-migration-test",
+            run: counted_migration,
+        },
+        RustMigration {
+            version: "2021-11-02-migration-test",
+            run: failed_migration,
+        },
+    ];
+    let dir = format!("{}/{}", root_dir(), uuid());
+    match flowy_database::init_with_config(&dir, PoolConfig::default(), &migrations) {
+        Err(InitError::Migration(e)) => assert_eq!(e.version, "2021-11-02-migration-test"),
+        _ => panic!("The migration should fail"),
+    }
+
+    // The migrations applied before the failed one are kept.
+    let database = flowy_database::init_with_config(&dir, PoolConfig::default(), &migrations[..1]).unwrap();
+    let conn = database.get_connection().unwrap();
+    assert_eq!(applied_versions(&*conn), vec!["2021-11-01-migration-test".to_owned()]);
+}
+
+#[tokio::test]
+#[serial]
+async fn migration_failed_when_signing_up() {
+    let config = FlowySDKConfig::new(&root_dir(), ServerConfig::default(), &uuid())
+        .secret_store(SecretStoreKind::File)
+        .migration(RustMigration {
+            version: "2021-11-02-migration-test",
+            run: failed_migration,
+        });
+    let test = FlowyTest::setup_with_config(config);
+    let request = SignUpRequest {
+        email: random_email(),
+        name: "app flowy".to_owned(),
+        password: login_password(),
+    };
+
+    let error = UserTest::new(test.sdk())
+        .event(SignUp)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::DatabaseMigrationFailed.value());
+}
@@ -0,0 +1,107 @@
+use flowy_infra::{secret::SecretStoreKind, uuid};
+use flowy_net::config::ServerConfig;
+use flowy_test::{builder::UserTest, prelude::*, FlowyTest};
+use flowy_user::{errors::ErrorCode, event::UserEvent::*, prelude::*, services::user::OAuthConfig};
+use serial_test::*;
+
+fn oauth_test() -> FlowyTest {
+    let config = FlowySDKConfig::new(&root_dir(), ServerConfig::default(), &uuid())
+        .secret_store(SecretStoreKind::File)
+        .oauth(
+            OAuthProviderType::Google,
+            OAuthConfig::new("google_client_id", "appflowy://oauth/google"),
+        )
+        .oauth(
+            OAuthProviderType::GitHub,
+            OAuthConfig::new("github_client_id", "appflowy://oauth/github").scopes(vec!["user:email"]),
+        );
+    FlowyTest::setup_with_config(config)
+}
+
+fn oauth_url(test: &FlowyTest, provider: OAuthProviderType) -> OAuthURL {
+    UserTest::new(test.sdk())
+        .event(GetOAuthURL)
+        .request(OAuthURLRequest::new(provider))
+        .sync_send()
+        .parse::<OAuthURL>()
+}
+
+#[tokio::test]
+#[serial]
+async fn oauth_authorization_url() {
+    let test = oauth_test();
+    let google = oauth_url(&test, OAuthProviderType::Google);
+    assert!(google.url.starts_with("https://accounts.google.com/o/oauth2/v2/auth?"));
+    assert!(google.url.contains("client_id=google_client_id"));
+    assert!(google.url.contains("redirect_uri=appflowy%3A%2F%2Foauth%2Fgoogle"));
+    assert!(google.url.contains(&format!("state={}", google.state)));
+
+    let github = oauth_url(&test, OAuthProviderType::GitHub);
+    assert!(github.url.starts_with("https://github.com/login/oauth/authorize?"));
+    assert!(github.url.contains("scope=user%3Aemail"));
+    assert_ne!(github.state, google.state);
+}
+
+#[tokio::test]
+#[serial]
+async fn oauth_authorization_url_with_unsupported_provider() {
+    let test = FlowyTest::setup();
+    let code = UserTest::new(test.sdk())
+        .event(GetOAuthURL)
+        .request(OAuthURLRequest::new(OAuthProviderType::GitHub))
+        .sync_send()
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::OAuthProviderNotSupported.value());
+}
+
+#[tokio::test]
+#[serial]
+async fn oauth_sign_in_success() {
+    let test = oauth_test();
+    let oauth_url = oauth_url(&test, OAuthProviderType::Google);
+    let user_profile = UserTest::new(test.sdk())
+        .event(OAuthSignIn)
+        .request(OAuthSignInRequest::new(
+            OAuthProviderType::Google,
+            "authorization_code",
+            &oauth_url.state,
+        ))
+        .async_send()
+        .await
+        .parse::<UserProfile>();
+
+    let current = UserTest::new(test.sdk())
+        .event(GetUserProfile)
+        .sync_send()
+        .parse::<UserProfile>();
+    assert_eq!(current, user_profile);
+}
+
+#[tokio::test]
+#[serial]
+async fn oauth_sign_in_with_invalid_state() {
+    let test = oauth_test();
+    let oauth_url = oauth_url(&test, OAuthProviderType::Google);
+    let request = |provider, state: &str| OAuthSignInRequest::new(provider, "authorization_code", state);
+
+    // the state was issued for another provider
+    let code = UserTest::new(test.sdk())
+        .event(OAuthSignIn)
+        .request(request(OAuthProviderType::GitHub, &oauth_url.state))
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::OAuthStateInvalid.value());
+
+    // the state can't be used again after a failed attempt
+    let code = UserTest::new(test.sdk())
+        .event(OAuthSignIn)
+        .request(request(OAuthProviderType::Google, &oauth_url.state))
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::OAuthStateInvalid.value());
+}
@@ -0,0 +1,100 @@
+use flowy_infra::{secret::SecretStoreKind, uuid};
+use flowy_net::config::ServerConfig;
+use flowy_test::{builder::UserTest, prelude::*, FlowyTest};
+use flowy_user::{errors::ErrorCode, event::UserEvent::*, prelude::*, services::user::PasswordPolicy};
+use serial_test::*;
+
+#[tokio::test]
+#[serial]
+async fn password_update_success() {
+    let test = FlowyTest::setup();
+    let context = test.sign_up().await;
+
+    let _ = UserTest::new(test.sdk())
+        .event(UpdatePassword)
+        .request(UpdatePasswordRequest::new(&context.password, "HelloFlowy!456"))
+        .async_send()
+        .await
+        .assert_success();
+
+    let user_profile = UserTest::new(test.sdk())
+        .event(GetUserProfile)
+        .sync_send()
+        .parse::<UserProfile>();
+    assert_eq!(user_profile.id, context.user_profile.id);
+    assert_ne!(user_profile.token, context.user_profile.token);
+}
+
+#[tokio::test]
+#[serial]
+async fn password_update_with_wrong_old_password() {
+    let test = FlowyTest::setup();
+    let _ = test.sign_up().await;
+
+    let code = UserTest::new(test.sdk())
+        .event(UpdatePassword)
+        .request(UpdatePasswordRequest::new("WrongPassword!123", "HelloFlowy!456"))
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::PasswordNotMatch.value());
+}
+
+#[tokio::test]
+#[serial]
+async fn password_update_rejects_each_failed_rule() {
+    let test = FlowyTest::setup();
+    let context = test.sign_up().await;
+
+    let weak_passwords = vec![
+        ("He1!", ErrorCode::PasswordTooShort),
+        ("HelloFlowy!", ErrorCode::PasswordMissingDigit),
+        ("HELLOFLOWY!456", ErrorCode::PasswordMissingLowercase),
+        ("helloflowy!456", ErrorCode::PasswordMissingUppercase),
+        ("HelloFlowy456", ErrorCode::PasswordMissingSpecialCharacter),
+        (context.password.as_str(), ErrorCode::PasswordNotChanged),
+    ];
+
+    for (new_password, expected) in weak_passwords {
+        let code = UserTest::new(test.sdk())
+            .event(UpdatePassword)
+            .request(UpdatePasswordRequest::new(&context.password, new_password))
+            .async_send()
+            .await
+            .error()
+            .code;
+        assert_eq!(code, expected.value(), "{}", new_password);
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn password_update_with_configured_policy() {
+    let policy = PasswordPolicy {
+        min_len: 16,
+        require_special_character: false,
+        ..Default::default()
+    };
+    let config = FlowySDKConfig::new(&root_dir(), ServerConfig::default(), &uuid())
+        .secret_store(SecretStoreKind::File)
+        .password_policy(policy);
+    let test = FlowyTest::setup_with_config(config);
+    let context = test.sign_up().await;
+
+    let code = UserTest::new(test.sdk())
+        .event(UpdatePassword)
+        .request(UpdatePasswordRequest::new(&context.password, "HelloFlowy!456"))
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::PasswordTooShort.value());
+
+    let _ = UserTest::new(test.sdk())
+        .event(UpdatePassword)
+        .request(UpdatePasswordRequest::new(&context.password, "HelloFlowyWorld456"))
+        .async_send()
+        .await
+        .assert_success();
+}
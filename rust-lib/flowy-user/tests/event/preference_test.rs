@@ -0,0 +1,140 @@
+use flowy_test::{builder::UserTest, FlowyTest};
+use flowy_user::{errors::ErrorCode, event::UserEvent::*, prelude::*};
+use serial_test::*;
+
+fn set_preference(test: &FlowyTest, key: &str, value: PreferenceValue) -> UserTest {
+    UserTest::new(test.sdk())
+        .event(SetPreference)
+        .request(SetPreferenceRequest::new(key, value))
+}
+
+async fn get_preference(test: &FlowyTest, key: &str) -> Preference {
+    UserTest::new(test.sdk())
+        .event(GetPreference)
+        .request(GetPreferenceRequest::new(key))
+        .async_send()
+        .await
+        .parse::<Preference>()
+}
+
+#[tokio::test]
+#[serial]
+async fn preference_set_and_get() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    set_preference(&test, "appearance.theme", PreferenceValue::str("dark"))
+        .async_send()
+        .await
+        .assert_success();
+    set_preference(&test, "editor.font_size", PreferenceValue::int(14))
+        .async_send()
+        .await
+        .assert_success();
+    set_preference(&test, "editor.spell_check", PreferenceValue::bool(false))
+        .async_send()
+        .await
+        .assert_success();
+
+    let theme = get_preference(&test, "appearance.theme").await;
+    assert_eq!(theme.value.as_str(), Some("dark"));
+    assert_eq!(get_preference(&test, "editor.font_size").await.value.as_int(), Some(14));
+    assert_eq!(
+        get_preference(&test, "editor.spell_check").await.value.as_bool(),
+        Some(false)
+    );
+
+    set_preference(&test, "appearance.theme", PreferenceValue::str("light"))
+        .async_send()
+        .await
+        .assert_success();
+    let theme = get_preference(&test, "appearance.theme").await;
+    assert_eq!(theme.value.as_str(), Some("light"));
+}
+
+#[tokio::test]
+#[serial]
+async fn preference_get_not_exist() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    assert_eq!(
+        UserTest::new(test.sdk())
+            .event(GetPreference)
+            .request(GetPreferenceRequest::new("editor.font_size"))
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::PreferenceNotExist.value()
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn preference_type_can_not_be_changed() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    set_preference(&test, "editor.font_size", PreferenceValue::int(14))
+        .async_send()
+        .await
+        .assert_success();
+
+    let error = set_preference(&test, "editor.font_size", PreferenceValue::str("14"))
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::PreferenceTypeMismatch.value());
+
+    let error = set_preference(&test, "editor.font_size", PreferenceValue::default())
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::PreferenceValueInvalid.value());
+}
+
+#[tokio::test]
+#[serial]
+async fn preference_is_stored_per_user() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    set_preference(&test, "appearance.theme", PreferenceValue::str("dark"))
+        .async_send()
+        .await
+        .assert_success();
+
+    let _ = test.init_user().await;
+    UserTest::new(test.sdk())
+        .event(GetPreference)
+        .request(GetPreferenceRequest::new("appearance.theme"))
+        .async_send()
+        .await
+        .assert_error();
+}
+
+#[tokio::test]
+#[serial]
+async fn preference_subscribe_receives_changes() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    set_preference(&test, "appearance.theme", PreferenceValue::str("dark"))
+        .async_send()
+        .await
+        .assert_success();
+
+    let current = UserTest::new(test.sdk())
+        .event(SubscribePreference)
+        .request(SubscribePreferenceRequest::new(vec!["appearance.theme", "editor.font_size"]))
+        .async_send()
+        .await
+        .parse::<RepeatedPreference>();
+    assert_eq!(current.items.len(), 1);
+    assert_eq!(current.items[0].value.as_str(), Some("dark"));
+
+    let mut rx = test.sdk().user_session.preference_subscribe();
+    set_preference(&test, "editor.font_size", PreferenceValue::int(16))
+        .async_send()
+        .await
+        .assert_success();
+    let changed = rx.recv().await.unwrap();
+    assert_eq!(changed.key, "editor.font_size");
+    assert_eq!(changed.value.as_int(), Some(16));
+}
@@ -0,0 +1,61 @@
+use flowy_test::{builder::UserTest, workspace::*, FlowyTest};
+use flowy_user::{event::UserEvent::*, prelude::*};
+use serial_test::*;
+use std::time::Duration;
+use tokio::sync::broadcast;
+
+// Waits for the change of the row, the other changes are skipped.
+async fn wait_for_change(
+    receiver: &mut broadcast::Receiver<RepeatedDatabaseRowChange>,
+    table: &str,
+    key: &str,
+    ty: RowChangeType,
+) -> bool {
+    let expected = DatabaseRowChange {
+        table: table.to_owned(),
+        key: key.to_owned(),
+        ty,
+    };
+    let received = async {
+        loop {
+            match receiver.recv().await {
+                Ok(changes) if changes.items.contains(&expected) => return true,
+                Ok(_) => {},
+                Err(_) => return false,
+            }
+        }
+    };
+    tokio::time::timeout(Duration::from_secs(5), received)
+        .await
+        .unwrap_or(false)
+}
+
+#[tokio::test]
+#[serial]
+async fn row_change_of_workspace() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let mut receiver = test.sdk.user_session.row_change_subscribe();
+
+    let workspace = create_workspace(&test.sdk, "Workspace", "").await;
+    assert!(wait_for_change(&mut receiver, "workspace_table", &workspace.id, RowChangeType::Insert).await);
+
+    let app = create_app(&test.sdk, "App", "", &workspace.id).await;
+    assert!(wait_for_change(&mut receiver, "app_table", &app.id, RowChangeType::Insert).await);
+
+    // The deleted apps are moved to the trash.
+    delete_app(&test.sdk, &app.id).await;
+    assert!(wait_for_change(&mut receiver, "trash_table", &app.id, RowChangeType::Insert).await);
+}
+
+#[tokio::test]
+#[serial]
+async fn row_change_of_user() {
+    let test = FlowyTest::setup();
+    let user = test.init_user().await;
+    let mut receiver = test.sdk.user_session.row_change_subscribe();
+
+    let request = UpdateUserRequest::new(&user.id).name("renamed");
+    let _ = UserTest::new(test.sdk()).event(UpdateUser).request(request).sync_send();
+    assert!(wait_for_change(&mut receiver, "user_table", &user.id, RowChangeType::Update).await);
+}
@@ -0,0 +1,76 @@
+use flowy_infra::{
+    kv::KV,
+    secret::{FileSecretStore, SecretStore, SecretStoreKind},
+    uuid,
+};
+use flowy_net::config::ServerConfig;
+use flowy_test::{
+    builder::UserTest,
+    prelude::{root_dir, FlowySDKConfig},
+    FlowyTest,
+};
+use flowy_user::{event::UserEvent::*, prelude::*};
+use serial_test::*;
+
+fn sdk_config(name: &str) -> FlowySDKConfig {
+    FlowySDKConfig::new(&root_dir(), ServerConfig::default(), name).secret_store(SecretStoreKind::File)
+}
+
+fn session_cache_key(name: &str) -> String { format!("{}_session_cache", name) }
+
+fn file_contains(path: &std::path::Path, token: &str) -> bool {
+    match std::fs::read(path) {
+        Ok(bytes) => bytes.windows(token.len()).any(|window| window == token.as_bytes()),
+        Err(_) => false,
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn secret_token_is_not_stored_in_plaintext() {
+    let test = FlowyTest::setup_with_config(sdk_config(&uuid()));
+    let user_profile = test.init_user().await;
+    assert!(!user_profile.token.is_empty());
+
+    let root = std::path::PathBuf::from(root_dir());
+    let mut paths = vec![root.join("kv.db"), root.join("kv.db-wal")];
+    if let Ok(entries) = std::fs::read_dir(root.join(&user_profile.id)) {
+        paths.extend(entries.flatten().map(|entry| entry.path()));
+    }
+    for path in paths {
+        assert!(!file_contains(&path, &user_profile.token), "{:?} contains the token", path);
+    }
+
+    // The profile still carries the token
+    let user = UserTest::new(test.sdk())
+        .event(GetUserProfile)
+        .async_send()
+        .await
+        .parse::<UserProfile>();
+    assert_eq!(user.token, user_profile.token);
+}
+
+#[tokio::test]
+#[serial]
+async fn secret_legacy_session_is_moved_to_secret_store() {
+    let name = uuid();
+    let key = session_cache_key(&name);
+    let test = FlowyTest::setup_with_config(sdk_config(&name));
+    let user_profile = test.init_user().await;
+
+    // Put the session back to where the previous versions kept it
+    let store = FileSecretStore::new(&root_dir());
+    let session = store.get(&key).unwrap().unwrap();
+    store.remove(&key).unwrap();
+    KV::set_str(&key, session);
+
+    let test = FlowyTest::setup_with_config(sdk_config(&name));
+    let user = UserTest::new(test.sdk())
+        .event(AutoSignIn)
+        .async_send()
+        .await
+        .parse::<UserProfile>();
+    assert_eq!(user_profile, user);
+    assert!(KV::get_str(&key).is_none());
+    assert!(store.get(&key).unwrap().is_some());
+}
@@ -0,0 +1,123 @@
+use flowy_test::{builder::UserTest, FlowyTest};
+use flowy_user::{errors::ErrorCode, event::UserEvent::*, prelude::*};
+use serial_test::*;
+
+// Signs in the account of the test on another sdk, so that it has two sessions.
+async fn sign_in_on_another_device(email: &str, password: &str) -> FlowyTest {
+    let other = FlowyTest::setup();
+    let request = SignInRequest {
+        email: email.to_owned(),
+        password: password.to_owned(),
+        name: "".to_string(),
+    };
+    let _ = UserTest::new(other.sdk())
+        .event(SignIn)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<UserProfile>();
+    other
+}
+
+async fn list_sessions(test: &FlowyTest) -> RepeatedDeviceSession {
+    UserTest::new(test.sdk())
+        .event(ListSessions)
+        .async_send()
+        .await
+        .parse::<RepeatedDeviceSession>()
+}
+
+#[tokio::test]
+#[serial]
+async fn list_sessions_of_every_device() {
+    let test = FlowyTest::setup();
+    let context = test.sign_up().await;
+    let other = sign_in_on_another_device(&context.user_profile.email, &context.password).await;
+
+    let sessions = list_sessions(&test).await;
+    assert_eq!(sessions.items.len(), 2);
+    assert_eq!(sessions.items.iter().filter(|session| session.is_current).count(), 1);
+    assert!(sessions.items.iter().all(|session| !session.device_id.is_empty()));
+
+    let other_sessions = list_sessions(&other).await;
+    let current = sessions.items.iter().find(|session| session.is_current).unwrap();
+    let other_current = other_sessions.items.iter().find(|session| session.is_current).unwrap();
+    assert_ne!(current.id, other_current.id);
+}
+
+#[tokio::test]
+#[serial]
+async fn revoked_session_fails_subsequent_events() {
+    let test = FlowyTest::setup();
+    let context = test.sign_up().await;
+    let other = sign_in_on_another_device(&context.user_profile.email, &context.password).await;
+
+    let session_id = list_sessions(&test)
+        .await
+        .items
+        .into_iter()
+        .find(|session| session.is_current)
+        .unwrap()
+        .id;
+    let _ = UserTest::new(other.sdk())
+        .event(RevokeSession)
+        .request(RevokeSessionRequest::new(&session_id))
+        .async_send()
+        .await
+        .assert_success();
+    assert_eq!(list_sessions(&other).await.items.len(), 1);
+
+    let code = UserTest::new(test.sdk()).event(ListSessions).async_send().await.error().code;
+    assert_eq!(code, ErrorCode::SessionRevoked.value());
+
+    let code = UserTest::new(test.sdk()).event(GetUserProfile).async_send().await.error().code;
+    assert_eq!(code, ErrorCode::SessionRevoked.value());
+
+    // Signing in again starts a new session
+    let request = SignInRequest {
+        email: context.user_profile.email.clone(),
+        password: context.password.clone(),
+        name: "".to_string(),
+    };
+    let _ = UserTest::new(test.sdk())
+        .event(SignIn)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<UserProfile>();
+    assert_eq!(list_sessions(&test).await.items.len(), 2);
+}
+
+#[tokio::test]
+#[serial]
+async fn revoke_the_current_session() {
+    let test = FlowyTest::setup();
+    let _ = test.sign_up().await;
+
+    let session_id = list_sessions(&test).await.items[0].id.clone();
+    let _ = UserTest::new(test.sdk())
+        .event(RevokeSession)
+        .request(RevokeSessionRequest::new(&session_id))
+        .async_send()
+        .await
+        .assert_success();
+
+    let code = UserTest::new(test.sdk()).event(GetUserProfile).async_send().await.error().code;
+    assert_eq!(code, ErrorCode::SessionRevoked.value());
+}
+
+#[tokio::test]
+#[serial]
+async fn revoke_session_with_empty_id() {
+    let test = FlowyTest::setup();
+    let _ = test.sign_up().await;
+
+    let code = UserTest::new(test.sdk())
+        .event(RevokeSession)
+        .request(RevokeSessionRequest::new(" "))
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::SessionIdIsEmpty.value());
+}
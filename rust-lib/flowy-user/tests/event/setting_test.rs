@@ -0,0 +1,179 @@
+use flowy_database::{
+    schema::{user_preference_table, user_preference_table::dsl},
+    ExpressionMethods,
+    PoolConfig,
+    QueryDsl,
+    RunQueryDsl,
+};
+use flowy_infra::uuid;
+use flowy_test::{builder::UserTest, prelude::root_dir, FlowyTest};
+use flowy_user::{
+    errors::ErrorCode,
+    event::UserEvent::*,
+    prelude::*,
+    services::setting::{read_app_setting, SETTING_MIGRATION},
+};
+use serial_test::*;
+
+async fn update_setting(test: &FlowyTest, request: UpdateSettingRequest) -> UserTest {
+    UserTest::new(test.sdk())
+        .event(UpdateSetting)
+        .request(request)
+        .async_send()
+        .await
+}
+
+async fn read_setting(test: &FlowyTest) -> AppSetting {
+    UserTest::new(test.sdk())
+        .event(ReadSetting)
+        .async_send()
+        .await
+        .parse::<AppSetting>()
+}
+
+#[tokio::test]
+#[serial]
+async fn setting_read_defaults_and_update() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    assert_eq!(read_setting(&test).await, AppSetting::default());
+
+    let editor = EditorSetting {
+        font_size: 18,
+        ..Default::default()
+    };
+    let setting = update_setting(
+        &test,
+        UpdateSettingRequest {
+            editor: Some(editor.clone()),
+            ..Default::default()
+        },
+    )
+    .await
+    .parse::<AppSetting>();
+    assert_eq!(setting.editor, editor);
+    assert_eq!(setting.appearance, AppearanceSetting::default());
+    assert_eq!(read_setting(&test).await, setting);
+
+    // The setting follows the user.
+    let _ = test.init_user().await;
+    assert_eq!(read_setting(&test).await, AppSetting::default());
+}
+
+#[tokio::test]
+#[serial]
+async fn setting_update_is_checked_against_the_schema() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let error = update_setting(&test, UpdateSettingRequest::default()).await.error();
+    assert_eq!(error.code, ErrorCode::SettingIsEmpty.value());
+
+    let error = update_setting(
+        &test,
+        UpdateSettingRequest {
+            sync: Some(SyncSetting {
+                interval_secs: 1,
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    )
+    .await
+    .error();
+    assert_eq!(error.code, ErrorCode::SettingValueInvalid.value());
+
+    let error = update_setting(
+        &test,
+        UpdateSettingRequest {
+            shortcuts: Some(ShortcutSetting {
+                items: vec![Shortcut::new("undo", "Ctrl+Z"), Shortcut::new("redo", "ctrl+z")],
+            }),
+            ..Default::default()
+        },
+    )
+    .await
+    .error();
+    assert_eq!(error.code, ErrorCode::ShortcutConflict.value());
+    assert_eq!(read_setting(&test).await, AppSetting::default());
+}
+
+#[tokio::test]
+#[serial]
+async fn setting_changes_are_streamed_and_reset() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let mut rx = test.sdk().user_session.setting_subscribe();
+    let appearance = AppearanceSetting {
+        theme: ThemeMode::Dark,
+        ..Default::default()
+    };
+    let _ = update_setting(
+        &test,
+        UpdateSettingRequest {
+            appearance: Some(appearance.clone()),
+            editor: Some(EditorSetting::default()),
+            ..Default::default()
+        },
+    )
+    .await
+    .parse::<AppSetting>();
+
+    // The editor is the same as before, only the appearance changed.
+    let change = rx.recv().await.unwrap();
+    assert_eq!(change.section, SettingSection::Appearance);
+    assert_eq!(change.setting.appearance, appearance);
+    assert!(rx.try_recv().is_err());
+
+    let setting = UserTest::new(test.sdk())
+        .event(ResetSetting)
+        .request(ResetSettingRequest {
+            section: SettingSection::Appearance,
+        })
+        .async_send()
+        .await
+        .parse::<AppSetting>();
+    assert_eq!(setting, AppSetting::default());
+    let change = rx.recv().await.unwrap();
+    assert_eq!(change.section, SettingSection::Appearance);
+    assert_eq!(change.setting.appearance.theme, ThemeMode::System);
+}
+
+#[tokio::test]
+#[serial]
+async fn setting_migrates_the_preference_keys() {
+    let dir = format!("{}/{}", root_dir(), uuid());
+    let database = flowy_database::init_with_config(&dir, PoolConfig::default(), &[]).unwrap();
+    let conn = database.get_connection().unwrap();
+    let _ = flowy_database::insert_into(user_preference_table::table)
+        .values(&vec![
+            (dsl::key.eq("appearance.theme"), dsl::str_value.eq("dark")),
+            (dsl::key.eq("editor.font_size"), dsl::str_value.eq("big")),
+        ])
+        .execute(&*conn)
+        .unwrap();
+    let _ = flowy_database::insert_into(user_preference_table::table)
+        .values(&vec![
+            (dsl::key.eq("editor.tab_size"), dsl::int_value.eq(2)),
+            (dsl::key.eq("sync.interval"), dsl::int_value.eq(60)),
+        ])
+        .execute(&*conn)
+        .unwrap();
+    drop(conn);
+    drop(database);
+
+    let database = flowy_database::init_with_config(&dir, PoolConfig::default(), &[SETTING_MIGRATION]).unwrap();
+    let conn = database.get_connection().unwrap();
+    let setting = read_app_setting(&*conn).unwrap();
+    assert_eq!(setting.appearance.theme, ThemeMode::Dark);
+    assert_eq!(setting.editor.tab_size, 2);
+    // The value that doesn't fit its section is dropped.
+    assert_eq!(setting.editor.font_size, EditorSetting::default().font_size);
+    assert_eq!(setting.sync, SyncSetting::default());
+
+    // The keys that aren't settings are kept.
+    let keys = dsl::user_preference_table
+        .select(user_preference_table::key)
+        .load::<String>(&*conn)
+        .unwrap();
+    assert_eq!(keys, vec!["sync.interval".to_owned()]);
+}
@@ -0,0 +1,150 @@
+use crate::helper::*;
+use flowy_dispatch::{prelude::*, Error};
+use flowy_infra::{clock::ManualClock, secret::SecretStoreKind, uuid};
+use flowy_net::config::ServerConfig;
+use flowy_test::{
+    builder::UserTest,
+    prelude::{root_dir, FlowySDKConfig},
+    FlowyTest,
+};
+use flowy_user::{errors::UserError, event::UserEvent::*, prelude::*, services::user::TokenRefresher};
+use serial_test::*;
+use std::{sync::Arc, time::Duration};
+
+fn sdk_config(session_expiration: i64) -> FlowySDKConfig {
+    FlowySDKConfig::new(&root_dir(), ServerConfig::default(), &uuid())
+        .log_filter("debug")
+        .secret_store(SecretStoreKind::File)
+        .session_expiration(session_expiration)
+}
+
+#[tokio::test]
+#[serial]
+async fn token_refresh_near_expiry() {
+    let test = FlowyTest::setup_with_config(sdk_config(60));
+    let user_profile = test.init_user().await;
+
+    let refresher = TokenRefresher::new(test.sdk.user_session.clone());
+    assert!(refresher.refresh_if_needed().await.unwrap());
+
+    assert_ne!(test.sdk.user_session.token().unwrap(), user_profile.token);
+
+    // The refresher of the sdk checks the session when it starts, it may refresh
+    // the token again after the user signed up.
+    let user = UserTest::new(test.sdk())
+        .event(GetUserProfile)
+        .sync_send()
+        .parse::<UserProfile>();
+    assert_eq!(user.token, test.sdk.user_session.token().unwrap());
+}
+
+#[tokio::test]
+#[serial]
+async fn token_refresh_not_needed() {
+    let test = FlowyTest::setup();
+    let user_profile = test.init_user().await;
+
+    let refresher = TokenRefresher::new(test.sdk.user_session.clone());
+    assert!(!refresher.refresh_if_needed().await.unwrap());
+    assert_eq!(test.sdk.user_session.token().unwrap(), user_profile.token);
+}
+
+#[tokio::test]
+#[serial]
+async fn token_refresh_skipped_while_offline() {
+    let test = FlowyTest::setup_with_config(sdk_config(60));
+    let user_profile = test.init_user().await;
+    let connectivity = test.sdk.user_session.connectivity.clone();
+    while !connectivity.report(false) {}
+
+    let refresher = TokenRefresher::new(test.sdk.user_session.clone());
+    assert!(!refresher.refresh_if_needed().await.unwrap());
+    assert_eq!(test.sdk.user_session.token().unwrap(), user_profile.token);
+
+    while !connectivity.report(true) {}
+    assert!(refresher.refresh_if_needed().await.unwrap());
+}
+
+#[tokio::test]
+#[serial]
+async fn token_refresh_after_unauthorized_response() {
+    let test = FlowyTest::setup();
+    let user_profile = test.init_user().await;
+
+    let refresher = TokenRefresher::new(test.sdk.user_session.clone());
+    let request = ModuleRequest::new(GetUserProfile);
+    refresher.dispatched(&request);
+    assert!(
+        refresher
+            .intercept(&request, &UserError::unauthorized().as_response())
+            .await
+    );
+    assert_ne!(test.sdk.user_session.token().unwrap(), user_profile.token);
+
+    // Other errors are returned to the caller directly
+    refresher.dispatched(&request);
+    assert!(
+        !refresher
+            .intercept(&request, &UserError::user_not_exist().as_response())
+            .await
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn token_of_cancelled_request_is_dropped() {
+    let test = FlowyTest::setup();
+    let user_profile = test.init_user().await;
+
+    let refresher = TokenRefresher::new(test.sdk.user_session.clone());
+    let request = ModuleRequest::new(GetUserProfile);
+    refresher.dispatched(&request);
+    refresher.cancelled(&request);
+    assert!(
+        !refresher
+            .intercept(&request, &UserError::unauthorized().as_response())
+            .await
+    );
+    assert_eq!(test.sdk.user_session.token().unwrap(), user_profile.token);
+}
+
+#[tokio::test]
+#[serial]
+async fn token_refresh_once_for_the_same_token() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    // Both requests were sent with the same token, the second response comes
+    // after the first one refreshed it.
+    let refresher = TokenRefresher::new(test.sdk.user_session.clone());
+    let first = ModuleRequest::new(GetUserProfile);
+    let second = ModuleRequest::new(GetUserProfile);
+    refresher.dispatched(&first);
+    refresher.dispatched(&second);
+    assert!(
+        refresher
+            .intercept(&first, &UserError::unauthorized().as_response())
+            .await
+    );
+    let refreshed_token = test.sdk.user_session.token().unwrap();
+    assert!(
+        refresher
+            .intercept(&second, &UserError::unauthorized().as_response())
+            .await
+    );
+    assert_eq!(test.sdk.user_session.token().unwrap(), refreshed_token);
+}
+
+#[tokio::test]
+#[serial]
+async fn session_expires_by_the_clock() {
+    let clock = Arc::new(ManualClock::now());
+    let test = FlowyTest::setup_with_config(sdk_config(60 * 60).clock(clock.clone()));
+    let _ = test.init_user().await;
+    assert_eq!(test.sdk.user_session.session_expires_in().unwrap(), Some(60 * 60));
+
+    clock.advance(Duration::from_secs(60 * 60));
+    assert_eq!(test.sdk.user_session.session_expires_in().unwrap(), Some(0));
+    let error = test.sdk.user_session.auto_sign_in().await.unwrap_err();
+    assert_eq!(error.code, UserError::session_expired().code);
+}
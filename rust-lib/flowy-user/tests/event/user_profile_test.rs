@@ -45,6 +45,25 @@ async fn user_update_with_name() {
     assert_eq!(user_profile.name, new_name,);
 }
 
+#[tokio::test]
+#[serial]
+async fn user_update_with_unchanged_name() {
+    let test = FlowyTest::setup();
+    let user = test.init_user().await;
+    let request = UpdateUserRequest::new(&user.id).name(&user.name);
+    let _ = UserTest::new(test.sdk())
+        .event(UpdateUser)
+        .request(request)
+        .sync_send()
+        .assert_success();
+
+    let user_profile = UserTest::new(test.sdk())
+        .event(GetUserProfile)
+        .sync_send()
+        .parse::<UserProfile>();
+    assert_eq!(user_profile, user);
+}
+
 #[tokio::test]
 #[serial]
 async fn user_update_with_email() {
@@ -124,3 +143,50 @@ async fn user_update_with_invalid_name() {
         .sync_send()
         .assert_error();
 }
+
+#[tokio::test]
+#[serial]
+async fn user_profile_update() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let new_email = format!("{}@gmail.com", uuid());
+    let avatar = "https://appflowy.io/avatar.png";
+    let request = UpdateUserProfileRequest::new()
+        .name("hello_world")
+        .avatar(avatar)
+        .email(&new_email);
+    let updated_profile = UserTest::new(test.sdk())
+        .event(UpdateUserProfile)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<UserProfile>();
+    assert_eq!(updated_profile.name, "hello_world");
+    assert_eq!(updated_profile.avatar, avatar);
+    assert_eq!(updated_profile.email, new_email);
+
+    let user_profile = UserTest::new(test.sdk())
+        .event(GetUserProfile)
+        .async_send()
+        .await
+        .parse::<UserProfile>();
+    assert_eq!(user_profile, updated_profile);
+}
+
+#[tokio::test]
+#[serial]
+async fn user_profile_update_with_invalid_avatar() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let request = UpdateUserProfileRequest::new().avatar("avatar.png");
+    assert_eq!(
+        UserTest::new(test.sdk())
+            .event(UpdateUserProfile)
+            .request(request)
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::UserAvatarInvalid.value()
+    );
+}
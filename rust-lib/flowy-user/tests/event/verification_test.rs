@@ -0,0 +1,98 @@
+use flowy_test::{builder::UserTest, FlowyTest};
+use flowy_user::{errors::ErrorCode, event::UserEvent::*, prelude::*};
+use flowy_workspace::event::WorkspaceEvent::CopyLink;
+use serial_test::*;
+
+async fn verify_email(test: &FlowyTest, email: &str) -> EmailVerification {
+    let _ = UserTest::new(test.sdk())
+        .event(SendVerificationEmail)
+        .async_send()
+        .await
+        .assert_success();
+    let code = UserServerMock::verification_code(email).unwrap();
+
+    UserTest::new(test.sdk())
+        .event(ConfirmEmail)
+        .request(ConfirmEmailRequest::new(&code))
+        .async_send()
+        .await
+        .parse::<EmailVerification>()
+}
+
+#[tokio::test]
+#[serial]
+async fn email_is_not_verified_after_sign_up() {
+    let test = FlowyTest::setup();
+    let user_profile = test.init_user().await;
+    assert!(!user_profile.email_verified);
+
+    let verification = UserTest::new(test.sdk())
+        .event(GetEmailVerification)
+        .async_send()
+        .await
+        .parse::<EmailVerification>();
+    assert_eq!(verification.email, user_profile.email);
+    assert!(!verification.is_verified);
+    assert!(!verification.is_local);
+}
+
+#[tokio::test]
+#[serial]
+async fn confirm_email_with_the_sent_code() {
+    let test = FlowyTest::setup();
+    let user_profile = test.init_user().await;
+
+    let verification = verify_email(&test, &user_profile.email).await;
+    assert!(verification.is_verified);
+
+    let user_profile = UserTest::new(test.sdk())
+        .event(GetUserProfile)
+        .async_send()
+        .await
+        .parse::<UserProfile>();
+    assert!(user_profile.email_verified);
+}
+
+#[tokio::test]
+#[serial]
+async fn confirm_email_with_invalid_code() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let _ = UserTest::new(test.sdk())
+        .event(SendVerificationEmail)
+        .async_send()
+        .await
+        .assert_success();
+
+    let code = UserTest::new(test.sdk())
+        .event(ConfirmEmail)
+        .request(ConfirmEmailRequest::new("wrong code"))
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::VerificationCodeInvalid.value());
+
+    let code = UserTest::new(test.sdk())
+        .event(ConfirmEmail)
+        .request(ConfirmEmailRequest::new(" "))
+        .async_send()
+        .await
+        .error()
+        .code;
+    assert_eq!(code, ErrorCode::VerificationCodeIsEmpty.value());
+}
+
+#[tokio::test]
+#[serial]
+async fn sharing_requires_verified_email() {
+    let test = FlowyTest::setup();
+    let user_profile = test.init_user().await;
+
+    let code = UserTest::new(test.sdk()).event(CopyLink).async_send().await.error().code;
+    assert_eq!(code, ErrorCode::EmailNotVerified.value());
+
+    // The guard reads the flag saved on this device
+    let _ = verify_email(&test, &user_profile.email).await;
+    assert!(test.sdk.user_session.is_email_verified());
+}
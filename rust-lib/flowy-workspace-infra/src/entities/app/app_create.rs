@@ -7,7 +7,7 @@ use crate::{
         workspace::WorkspaceId,
     },
 };
-use flowy_derive::ProtoBuf;
+use flowy_derive::{FromPayload, ProtoBuf};
 use std::convert::TryInto;
 
 #[derive(ProtoBuf, Default)]
@@ -25,13 +25,14 @@ pub struct CreateAppRequest {
     pub color_style: ColorStyle,
 }
 
-#[derive(ProtoBuf, Default, Debug, Clone)]
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
 pub struct ColorStyle {
     #[pb(index = 1)]
     pub theme_color: String,
 }
 
-#[derive(ProtoBuf, Default, Debug)]
+#[derive(ProtoBuf, Default, Debug, FromPayload)]
+#[payload(from = "CreateAppRequest")]
 pub struct CreateAppParams {
     #[pb(index = 1)]
     pub workspace_id: String,
@@ -1,5 +1,5 @@
 use crate::{errors::ErrorCode, parser::app::AppId};
-use flowy_derive::ProtoBuf;
+use flowy_derive::{FromPayload, ProtoBuf};
 use std::convert::TryInto;
 
 #[derive(Default, ProtoBuf, Clone)]
@@ -8,7 +8,8 @@ pub struct QueryAppRequest {
     pub app_ids: Vec<String>,
 }
 
-#[derive(ProtoBuf, Default, Clone, Debug)]
+#[derive(ProtoBuf, Default, Clone, Debug, FromPayload)]
+#[payload(from = "QueryAppRequest")]
 pub struct AppIdentifier {
     #[pb(index = 1)]
     pub app_id: String,
@@ -1,9 +1,10 @@
 use crate::{
     entities::app::ColorStyle,
     errors::ErrorCode,
+    impl_field_diff,
     parser::app::{AppColorStyle, AppId, AppName},
 };
-use flowy_derive::ProtoBuf;
+use flowy_derive::{FromPayload, ProtoBuf};
 use std::convert::TryInto;
 
 #[derive(ProtoBuf, Default)]
@@ -24,7 +25,8 @@ pub struct UpdateAppRequest {
     pub is_trash: Option<bool>,
 }
 
-#[derive(ProtoBuf, Default, Clone, Debug)]
+#[derive(ProtoBuf, Default, Clone, Debug, FromPayload)]
+#[payload(from = "UpdateAppRequest")]
 pub struct UpdateAppParams {
     #[pb(index = 1)]
     pub app_id: String,
@@ -42,6 +44,8 @@ pub struct UpdateAppParams {
     pub is_trash: Option<bool>,
 }
 
+impl_field_diff!(UpdateAppParams, name, desc, color_style, is_trash);
+
 impl UpdateAppParams {
     pub fn new(app_id: &str) -> Self {
         Self {
@@ -1,9 +1,12 @@
 pub mod app;
+pub mod search;
 pub mod share;
+pub mod sync;
+pub mod template;
 pub mod trash;
 pub mod view;
 pub mod workspace;
 
 pub mod prelude {
-    pub use crate::entities::{app::*, share::*, trash::*, view::*, workspace::*};
+    pub use crate::entities::{app::*, search::*, share::*, sync::*, template::*, trash::*, view::*, workspace::*};
 }
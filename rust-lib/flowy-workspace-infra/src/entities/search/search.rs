@@ -0,0 +1,71 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+// Searches the titles of the views and the content of the documents in all the
+// workspaces of the user. The words of the query are all matched, the last one
+// as a prefix so the results show up while typing, and the quoted phrases are
+// matched as they are. The filters narrow the results:
+//
+//     "meeting notes" budget type:document workspace:<workspace_id>
+//
+// The type is view or document. At most limit results are returned, the
+// default is used if it's not positive.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct SearchRequest {
+    #[pb(index = 1)]
+    pub query: String,
+
+    #[pb(index = 2)]
+    pub limit: i64,
+}
+
+// A view matches by its title, a document by its content. The id of the
+// document is the one of its view.
+#[derive(ProtoBuf_Enum, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SearchResultType {
+    View     = 0,
+    Document = 1,
+}
+
+impl std::default::Default for SearchResultType {
+    fn default() -> Self { SearchResultType::View }
+}
+
+// The utf16 range of a matched term in the snippet.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct SearchResultHighlight {
+    #[pb(index = 1)]
+    pub start: i64,
+
+    #[pb(index = 2)]
+    pub end: i64,
+}
+
+// The snippet is the title of the view, or the text around the terms of the
+// best matching line of the document.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct SearchResult {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub ty: SearchResultType,
+
+    #[pb(index = 3)]
+    pub workspace_id: String,
+
+    #[pb(index = 4)]
+    pub title: String,
+
+    #[pb(index = 5)]
+    pub snippet: String,
+
+    #[pb(index = 6)]
+    pub highlights: Vec<SearchResultHighlight>,
+}
+
+// The best result comes first.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedSearchResult {
+    #[pb(index = 1)]
+    pub items: Vec<SearchResult>,
+}
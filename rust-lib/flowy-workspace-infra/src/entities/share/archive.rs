@@ -0,0 +1,188 @@
+use crate::{
+    entities::{view::ViewType, workspace::Workspace},
+    errors::ErrorCode,
+    parser::workspace::{WorkspaceId, WorkspaceName},
+};
+use flowy_derive::{FromPayload, ProtoBuf};
+use std::convert::TryInto;
+
+// Bump it when the archive can't be read by the older clients anymore.
+pub const WORKSPACE_ARCHIVE_VERSION: i64 = 1;
+
+#[derive(Default, ProtoBuf)]
+pub struct ExportWorkspaceRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+}
+
+#[derive(Default, Debug, FromPayload)]
+#[payload(from = "ExportWorkspaceRequest")]
+pub struct ExportWorkspaceParams {
+    pub workspace_id: String,
+}
+
+impl TryInto<ExportWorkspaceParams> for ExportWorkspaceRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ExportWorkspaceParams, Self::Error> {
+        let workspace_id = WorkspaceId::parse(self.workspace_id)?.0;
+        Ok(ExportWorkspaceParams { workspace_id })
+    }
+}
+
+// Restores the archive at the path into a new workspace, it's named after the
+// archived workspace if the name isn't set.
+#[derive(Default, ProtoBuf)]
+pub struct ImportWorkspaceRequest {
+    #[pb(index = 1)]
+    pub path: String,
+
+    #[pb(index = 2, one_of)]
+    pub name: Option<String>,
+}
+
+#[derive(Default, Debug, FromPayload)]
+#[payload(from = "ImportWorkspaceRequest")]
+pub struct ImportWorkspaceParams {
+    pub path: String,
+    pub name: Option<String>,
+}
+
+impl TryInto<ImportWorkspaceParams> for ImportWorkspaceRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ImportWorkspaceParams, Self::Error> {
+        if self.path.trim().is_empty() {
+            return Err(ErrorCode::WorkspaceArchiveInvalid);
+        }
+
+        let name = match self.name {
+            None => None,
+            Some(name) => Some(WorkspaceName::parse(name)?.0),
+        };
+        Ok(ImportWorkspaceParams { path: self.path, name })
+    }
+}
+
+// The portable copy of the workspace. The views are kept in the order they're
+// restored, each one after the view or the app it belongs to, and their data is
+// kept as it is whatever their type is. The ids are the ones of the exported
+// workspace, they're replaced when the archive is imported.
+#[derive(Default, ProtoBuf)]
+pub struct WorkspaceArchive {
+    #[pb(index = 1)]
+    pub version: i64,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub desc: String,
+
+    #[pb(index = 4)]
+    pub apps: Vec<ArchivedApp>,
+
+    #[pb(index = 5)]
+    pub views: Vec<ArchivedView>,
+
+    #[pb(index = 6)]
+    pub assets: Vec<ArchivedAsset>,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct ArchivedApp {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub desc: String,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct ArchivedView {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub belong_to_id: String,
+
+    #[pb(index = 3)]
+    pub name: String,
+
+    #[pb(index = 4)]
+    pub desc: String,
+
+    #[pb(index = 5)]
+    pub thumbnail: String,
+
+    #[pb(index = 6)]
+    pub view_type: ViewType,
+
+    #[pb(index = 7)]
+    pub data: String,
+
+    #[pb(index = 8)]
+    pub icon: String,
+
+    // The id of the archived asset, it's restored with the other assets.
+    #[pb(index = 9)]
+    pub cover: String,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct ArchivedAsset {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub doc_id: String,
+
+    #[pb(index = 3)]
+    pub name: String,
+
+    #[pb(index = 4)]
+    pub mime_type: String,
+
+    #[pb(index = 5)]
+    pub data: Vec<u8>,
+}
+
+// The items of the archive that couldn't be restored, the id is the one in the
+// archive. The views that belong to the app or the view that failed aren't
+// restored either, the view is kept if only its assets failed.
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct ImportFailure {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub msg: String,
+}
+
+#[derive(Default, ProtoBuf, Debug)]
+pub struct ImportWorkspaceResult {
+    #[pb(index = 1)]
+    pub workspace: Workspace,
+
+    #[pb(index = 2)]
+    pub failures: Vec<ImportFailure>,
+}
+
+// Sent after each app and view of the archive is restored.
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct ImportWorkspaceProgress {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub done: i32,
+
+    #[pb(index = 3)]
+    pub total: i32,
+}
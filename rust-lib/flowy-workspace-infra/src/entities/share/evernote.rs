@@ -0,0 +1,75 @@
+use crate::{
+    entities::{share::ImportFailure, workspace::Workspace},
+    errors::ErrorCode,
+    parser::workspace::WorkspaceName,
+};
+use flowy_derive::{FromPayload, ProtoBuf, ProtoBuf_Enum};
+use std::convert::TryInto;
+
+pub const DEFAULT_EVERNOTE_WORKSPACE_NAME: &str = "Evernote";
+
+// Where the tags of the notes go. With Grid, each notebook gets a grid view
+// with a row per note, with Metadata they're the desc of the views of the
+// notes.
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
+pub enum EnexTagMapping {
+    Grid     = 0,
+    Metadata = 1,
+}
+
+impl std::default::Default for EnexTagMapping {
+    fn default() -> Self { EnexTagMapping::Grid }
+}
+
+// Imports the notebooks exported from Evernote into a new workspace, one .enex
+// file per notebook.
+#[derive(Default, ProtoBuf)]
+pub struct ImportEnexRequest {
+    #[pb(index = 1)]
+    pub paths: Vec<String>,
+
+    #[pb(index = 2, one_of)]
+    pub name: Option<String>,
+
+    #[pb(index = 3)]
+    pub tag_mapping: EnexTagMapping,
+}
+
+#[derive(Default, Debug, FromPayload)]
+#[payload(from = "ImportEnexRequest")]
+pub struct ImportEnexParams {
+    pub paths: Vec<String>,
+    pub name: String,
+    pub tag_mapping: EnexTagMapping,
+}
+
+impl TryInto<ImportEnexParams> for ImportEnexRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ImportEnexParams, Self::Error> {
+        if self.paths.is_empty() || self.paths.iter().any(|path| path.trim().is_empty()) {
+            return Err(ErrorCode::EnexInvalid);
+        }
+
+        let name = match self.name {
+            None => DEFAULT_EVERNOTE_WORKSPACE_NAME.to_owned(),
+            Some(name) => WorkspaceName::parse(name)?.0,
+        };
+        Ok(ImportEnexParams {
+            paths: self.paths,
+            name,
+            tag_mapping: self.tag_mapping,
+        })
+    }
+}
+
+// The notes that failed are reported with their notebook and their position in
+// it, e.g. Recipes#3, the other notes are imported.
+#[derive(Default, ProtoBuf, Debug)]
+pub struct ImportEnexResult {
+    #[pb(index = 1)]
+    pub workspace: Workspace,
+
+    #[pb(index = 2)]
+    pub failures: Vec<ImportFailure>,
+}
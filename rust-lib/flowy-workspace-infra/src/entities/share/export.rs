@@ -1,5 +1,5 @@
 use crate::errors::ErrorCode;
-use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_derive::{FromPayload, ProtoBuf, ProtoBuf_Enum};
 use std::convert::TryInto;
 
 #[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
@@ -7,6 +7,7 @@ pub enum ExportType {
     Text     = 0,
     Markdown = 1,
     Link     = 2,
+    Archive  = 3,
 }
 
 impl std::default::Default for ExportType {
@@ -19,6 +20,7 @@ impl std::convert::From<i32> for ExportType {
             0 => ExportType::Text,
             1 => ExportType::Markdown,
             2 => ExportType::Link,
+            3 => ExportType::Archive,
             _ => {
                 log::error!("Invalid export type: {}", val);
                 ExportType::Text
@@ -36,7 +38,8 @@ pub struct ExportRequest {
     pub export_type: ExportType,
 }
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, FromPayload)]
+#[payload(from = "ExportRequest")]
 pub struct ExportParams {
     pub doc_id: String,
     pub export_type: ExportType,
@@ -52,6 +55,8 @@ impl TryInto<ExportParams> for ExportRequest {
     }
 }
 
+// The large documents are written to the file at the path instead of being
+// returned in the data.
 #[derive(Default, ProtoBuf)]
 pub struct ExportData {
     #[pb(index = 1)]
@@ -59,4 +64,25 @@ pub struct ExportData {
 
     #[pb(index = 2)]
     pub export_type: ExportType,
+
+    #[pb(index = 3)]
+    pub path: String,
+}
+
+impl ExportData {
+    pub fn inline(data: String, export_type: ExportType) -> Self {
+        Self {
+            data,
+            export_type,
+            path: "".to_owned(),
+        }
+    }
+
+    pub fn file(path: String, export_type: ExportType) -> Self {
+        Self {
+            data: "".to_owned(),
+            export_type,
+            path,
+        }
+    }
 }
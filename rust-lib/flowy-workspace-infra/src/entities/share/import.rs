@@ -0,0 +1,57 @@
+use crate::{
+    errors::ErrorCode,
+    parser::{app::AppId, view::ViewName},
+};
+use flowy_derive::{FromPayload, ProtoBuf, ProtoBuf_Enum};
+use std::convert::TryInto;
+
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
+pub enum ImportType {
+    Markdown = 0,
+    Html     = 1,
+}
+
+impl std::default::Default for ImportType {
+    fn default() -> Self { ImportType::Markdown }
+}
+
+// Creates a doc view in the app, or in the view, with the imported data as its
+// content.
+#[derive(Default, ProtoBuf)]
+pub struct ImportRequest {
+    #[pb(index = 1)]
+    pub belong_to_id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub import_type: ImportType,
+
+    #[pb(index = 4)]
+    pub data: String,
+}
+
+#[derive(Default, Debug, FromPayload)]
+#[payload(from = "ImportRequest")]
+pub struct ImportParams {
+    pub belong_to_id: String,
+    pub name: String,
+    pub import_type: ImportType,
+    pub data: String,
+}
+
+impl TryInto<ImportParams> for ImportRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ImportParams, Self::Error> {
+        let name = ViewName::parse(self.name)?.0;
+        let belong_to_id = AppId::parse(self.belong_to_id)?.0;
+        Ok(ImportParams {
+            belong_to_id,
+            name,
+            import_type: self.import_type,
+            data: self.data,
+        })
+    }
+}
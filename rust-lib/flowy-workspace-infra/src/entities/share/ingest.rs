@@ -0,0 +1,146 @@
+use crate::{
+    entities::view::View,
+    errors::ErrorCode,
+    parser::{app::AppId, view::ViewName},
+};
+use flowy_derive::{FromPayload, ProtoBuf, ProtoBuf_Enum};
+use std::convert::TryInto;
+use unicode_segmentation::UnicodeSegmentation;
+use validator::validate_url;
+
+pub const DEFAULT_SHARED_IMAGE_NAME: &str = "Image";
+// The name of the document, if it isn't given, is the first line of the text
+// or the url, cut to the graphemes.
+const MAX_SHARED_NAME_LEN: usize = 64;
+
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
+pub enum SharedContentType {
+    Text  = 0,
+    Url   = 1,
+    Image = 2,
+}
+
+impl std::default::Default for SharedContentType {
+    fn default() -> Self { SharedContentType::Text }
+}
+
+// NewDocument creates a doc view in the app, or in the view. Inbox appends the
+// content to the document of the inbox view of the current workspace, see
+// INBOX_VIEW_SETTING.
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
+pub enum IngestTarget {
+    NewDocument = 0,
+    Inbox       = 1,
+}
+
+impl std::default::Default for IngestTarget {
+    fn default() -> Self { IngestTarget::NewDocument }
+}
+
+// The content that the OS share sheet or the clipboard hands over. The text is
+// the text or the url, the data and its mime type are the image.
+#[derive(Default, ProtoBuf)]
+pub struct IngestSharedContentRequest {
+    #[pb(index = 1)]
+    pub content_type: SharedContentType,
+
+    #[pb(index = 2)]
+    pub text: String,
+
+    #[pb(index = 3)]
+    pub data: Vec<u8>,
+
+    #[pb(index = 4)]
+    pub mime_type: String,
+
+    #[pb(index = 5, one_of)]
+    pub name: Option<String>,
+
+    #[pb(index = 6)]
+    pub target: IngestTarget,
+
+    #[pb(index = 7, one_of)]
+    pub belong_to_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum SharedContent {
+    Text(String),
+    Url(String),
+    Image { data: Vec<u8>, mime_type: String },
+}
+
+#[derive(Debug, Clone)]
+pub enum IngestDestination {
+    NewDocument { belong_to_id: String },
+    Inbox,
+}
+
+#[derive(Debug, FromPayload)]
+#[payload(from = "IngestSharedContentRequest")]
+pub struct IngestSharedContentParams {
+    pub content: SharedContent,
+    pub name: String,
+    pub destination: IngestDestination,
+}
+
+impl TryInto<IngestSharedContentParams> for IngestSharedContentRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<IngestSharedContentParams, Self::Error> {
+        let content = match self.content_type {
+            SharedContentType::Text if !self.text.trim().is_empty() => SharedContent::Text(self.text),
+            SharedContentType::Url if validate_url(self.text.trim()) => SharedContent::Url(self.text.trim().to_owned()),
+            SharedContentType::Image if !self.data.is_empty() && self.mime_type.starts_with("image/") => {
+                SharedContent::Image {
+                    data: self.data,
+                    mime_type: self.mime_type,
+                }
+            },
+            _ => return Err(ErrorCode::SharedContentInvalid),
+        };
+
+        let name = match self.name {
+            Some(name) => ViewName::parse(name)?.0,
+            None => default_name(&content),
+        };
+        let destination = match self.target {
+            IngestTarget::NewDocument => {
+                let belong_to_id = AppId::parse(self.belong_to_id.unwrap_or_default())?.0;
+                IngestDestination::NewDocument { belong_to_id }
+            },
+            IngestTarget::Inbox => IngestDestination::Inbox,
+        };
+        Ok(IngestSharedContentParams {
+            content,
+            name,
+            destination,
+        })
+    }
+}
+
+fn default_name(content: &SharedContent) -> String {
+    match content {
+        SharedContent::Text(text) => {
+            let line = text
+                .lines()
+                .map(|line| line.trim())
+                .find(|line| !line.is_empty())
+                .unwrap_or("");
+            line.graphemes(true).take(MAX_SHARED_NAME_LEN).collect()
+        },
+        SharedContent::Url(url) => url.graphemes(true).take(MAX_SHARED_NAME_LEN).collect(),
+        SharedContent::Image { .. } => DEFAULT_SHARED_IMAGE_NAME.to_owned(),
+    }
+}
+
+// The view is the created document, or the inbox view that the content was
+// appended to.
+#[derive(Default, ProtoBuf, Debug)]
+pub struct IngestSharedContentResult {
+    #[pb(index = 1)]
+    pub view: View,
+
+    #[pb(index = 2)]
+    pub appended: bool,
+}
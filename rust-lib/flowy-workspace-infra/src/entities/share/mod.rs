@@ -1,3 +1,13 @@
+mod archive;
+mod evernote;
 mod export;
+mod import;
+mod ingest;
+mod notion;
 
+pub use archive::*;
+pub use evernote::*;
 pub use export::*;
+pub use import::*;
+pub use ingest::*;
+pub use notion::*;
@@ -0,0 +1,89 @@
+use crate::{
+    entities::{share::ImportFailure, workspace::Workspace},
+    errors::ErrorCode,
+    parser::workspace::WorkspaceName,
+};
+use flowy_derive::{FromPayload, ProtoBuf, ProtoBuf_Enum};
+use std::convert::TryInto;
+
+pub const DEFAULT_NOTION_WORKSPACE_NAME: &str = "Notion";
+
+// Imports the pages of the Notion export zip at the path into a new workspace.
+#[derive(Default, ProtoBuf)]
+pub struct ImportNotionRequest {
+    #[pb(index = 1)]
+    pub path: String,
+
+    #[pb(index = 2, one_of)]
+    pub name: Option<String>,
+}
+
+#[derive(Default, Debug, FromPayload)]
+#[payload(from = "ImportNotionRequest")]
+pub struct ImportNotionParams {
+    pub path: String,
+    pub name: String,
+}
+
+impl TryInto<ImportNotionParams> for ImportNotionRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ImportNotionParams, Self::Error> {
+        if self.path.trim().is_empty() {
+            return Err(ErrorCode::NotionExportInvalid);
+        }
+
+        let name = match self.name {
+            None => DEFAULT_NOTION_WORKSPACE_NAME.to_owned(),
+            Some(name) => WorkspaceName::parse(name)?.0,
+        };
+        Ok(ImportNotionParams { path: self.path, name })
+    }
+}
+
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
+pub enum UnconvertedKind {
+    Table    = 0,
+    Callout  = 1,
+    Toggle   = 2,
+    Equation = 3,
+    Html     = 4,
+    File     = 5,
+    Cell     = 6,
+}
+
+impl std::default::Default for UnconvertedKind {
+    fn default() -> Self { UnconvertedKind::Table }
+}
+
+// The element of a page that was imported as plain text, or the file of the
+// export that wasn't imported. The page is its path in the export, the view is
+// the one it was imported into, empty for the files.
+#[derive(Default, ProtoBuf, Debug, Clone, PartialEq)]
+pub struct UnconvertedElement {
+    #[pb(index = 1)]
+    pub page: String,
+
+    #[pb(index = 2)]
+    pub view_id: String,
+
+    #[pb(index = 3)]
+    pub kind: UnconvertedKind,
+
+    #[pb(index = 4)]
+    pub detail: String,
+}
+
+// The pages that failed are reported with their path in the export, the views
+// of their child pages aren't created either.
+#[derive(Default, ProtoBuf, Debug)]
+pub struct ImportNotionResult {
+    #[pb(index = 1)]
+    pub workspace: Workspace,
+
+    #[pb(index = 2)]
+    pub failures: Vec<ImportFailure>,
+
+    #[pb(index = 3)]
+    pub unconverted: Vec<UnconvertedElement>,
+}
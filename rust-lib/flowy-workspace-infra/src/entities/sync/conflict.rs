@@ -0,0 +1,235 @@
+use crate::{entities::sync::RemoteChange, impl_def_and_def_mut};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+// What's kept when an object changed both locally and on another device since
+// the last sync. The documents are merged instead, their deltas are
+// transformed.
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum ConflictStrategy {
+    PreferLocal    = 0,
+    PreferRemote   = 1,
+    // The change made last is kept.
+    LastWriterWins = 2,
+    // Neither is applied until the user picks one.
+    SurfaceToUser  = 3,
+}
+
+impl std::default::Default for ConflictStrategy {
+    fn default() -> Self { ConflictStrategy::PreferLocal }
+}
+
+impl std::convert::From<i64> for ConflictStrategy {
+    fn from(value: i64) -> Self {
+        match value {
+            1 => ConflictStrategy::PreferRemote,
+            2 => ConflictStrategy::LastWriterWins,
+            3 => ConflictStrategy::SurfaceToUser,
+            _ => ConflictStrategy::PreferLocal,
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, ProtoBuf_Enum, Clone, Copy, Hash)]
+pub enum SyncEntityType {
+    Workspace = 0,
+    App       = 1,
+    View      = 2,
+}
+
+impl std::default::Default for SyncEntityType {
+    fn default() -> Self { SyncEntityType::View }
+}
+
+// When the workspace is synced without being asked to, SyncWorkspace always
+// syncs it.
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum SyncTrigger {
+    // The local changes are sent as they're made, and the workspace is synced
+    // each time the connection is back.
+    Automatic = 0,
+    Manual    = 1,
+    // Every interval_seconds, and when the connection is back.
+    Interval  = 2,
+    // Once no local change was made for debounce_millis, and when the connection
+    // is back.
+    OnChange  = 3,
+}
+
+impl std::default::Default for SyncTrigger {
+    fn default() -> Self { SyncTrigger::Automatic }
+}
+
+impl std::convert::From<i64> for SyncTrigger {
+    fn from(value: i64) -> Self {
+        match value {
+            1 => SyncTrigger::Manual,
+            2 => SyncTrigger::Interval,
+            3 => SyncTrigger::OnChange,
+            _ => SyncTrigger::Automatic,
+        }
+    }
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct SyncSettings {
+    #[pb(index = 1)]
+    pub workspace_strategy: ConflictStrategy,
+
+    #[pb(index = 2)]
+    pub app_strategy: ConflictStrategy,
+
+    #[pb(index = 3)]
+    pub view_strategy: ConflictStrategy,
+
+    #[pb(index = 4)]
+    pub trigger: SyncTrigger,
+
+    // The defaults of flowy_workspace are used when they're 0.
+    #[pb(index = 5)]
+    pub interval_seconds: i64,
+
+    #[pb(index = 6)]
+    pub debounce_millis: i64,
+
+    // Nothing is synced in the background while the host reports the network as
+    // metered.
+    #[pb(index = 7)]
+    pub unmetered_only: bool,
+
+    // The rates the sync traffic is kept under, it's not limited when they're 0.
+    #[pb(index = 8)]
+    pub upload_bytes_per_second: i64,
+
+    #[pb(index = 9)]
+    pub download_bytes_per_second: i64,
+
+    // How long the sent changes wait for the ones made after them, so they're
+    // sent together. They're sent right away when it's 0.
+    #[pb(index = 10)]
+    pub batch_window_millis: i64,
+
+    // The changes are synced with the devices of the same account on the local
+    // network too, without the server.
+    #[pb(index = 11)]
+    pub lan_sync: bool,
+}
+
+impl SyncSettings {
+    pub fn strategy(&self, entity: SyncEntityType) -> ConflictStrategy {
+        match entity {
+            SyncEntityType::Workspace => self.workspace_strategy,
+            SyncEntityType::App => self.app_strategy,
+            SyncEntityType::View => self.view_strategy,
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum ConflictResolution {
+    // It waits for the user, see ResolveConflictRequest.
+    Pending       = 0,
+    LocalKept     = 1,
+    RemoteApplied = 2,
+    // The values the user submitted, see SubmitConflictResolutionRequest.
+    Merged        = 3,
+}
+
+impl std::default::Default for ConflictResolution {
+    fn default() -> Self { ConflictResolution::Pending }
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct SyncConflict {
+    #[pb(index = 1)]
+    pub object_id: String,
+
+    #[pb(index = 2)]
+    pub entity: SyncEntityType,
+
+    #[pb(index = 3)]
+    pub strategy: ConflictStrategy,
+
+    #[pb(index = 4)]
+    pub resolution: ConflictResolution,
+
+    // When the last local change of the object was made.
+    #[pb(index = 5)]
+    pub local_time: i64,
+
+    #[pb(index = 6)]
+    pub remote_time: i64,
+
+    // The remote change, see RemoteChange.
+    #[pb(index = 7)]
+    pub remote_change: RemoteChange,
+
+    // The conflicting fields before the local changes and with them, encoded
+    // like the data of the remote change. The base misses the fields that
+    // weren't changed on this device since it was opened, or were changed
+    // before the update.
+    #[pb(index = 8)]
+    pub base: Vec<u8>,
+
+    #[pb(index = 9)]
+    pub local: Vec<u8>,
+
+    #[pb(index = 10)]
+    pub fields: Vec<ConflictField>,
+}
+
+// One of the fields that changed both locally and on the other device, the
+// color style of an app is its theme color.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct ConflictField {
+    #[pb(index = 1)]
+    pub name: String,
+
+    // None if it isn't known.
+    #[pb(index = 2, one_of)]
+    pub base: Option<String>,
+
+    #[pb(index = 3)]
+    pub local: String,
+
+    #[pb(index = 4)]
+    pub remote: String,
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedSyncConflict {
+    #[pb(index = 1)]
+    pub items: Vec<SyncConflict>,
+}
+
+impl_def_and_def_mut!(RepeatedSyncConflict, SyncConflict);
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct ResolveConflictRequest {
+    #[pb(index = 1)]
+    pub object_id: String,
+
+    // The remote change is applied and the local ones are dropped otherwise.
+    #[pb(index = 2)]
+    pub keep_local: bool,
+}
+
+// The values the user picked, or typed, for the fields of the conflict. The
+// fields that aren't in it keep their local values. They're applied and sent to
+// the server like a local change.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct SubmitConflictResolutionRequest {
+    #[pb(index = 1)]
+    pub object_id: String,
+
+    #[pb(index = 2)]
+    pub fields: Vec<ResolvedConflictField>,
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct ResolvedConflictField {
+    #[pb(index = 1)]
+    pub name: String,
+
+    #[pb(index = 2)]
+    pub value: String,
+}
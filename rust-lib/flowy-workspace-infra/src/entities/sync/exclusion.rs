@@ -0,0 +1,60 @@
+use crate::{entities::sync::SyncEntityType, impl_def_and_def_mut};
+use flowy_derive::ProtoBuf;
+
+// Only the workspaces and the apps can be excluded, their views and documents
+// are with them.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct SyncExclusionRequest {
+    #[pb(index = 1)]
+    pub object_id: String,
+
+    #[pb(index = 2)]
+    pub entity: SyncEntityType,
+
+    // The object is synced again if it's false, see SyncBackfill.
+    #[pb(index = 3)]
+    pub excluded: bool,
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct SyncExclusion {
+    #[pb(index = 1)]
+    pub object_id: String,
+
+    #[pb(index = 2)]
+    pub entity: SyncEntityType,
+
+    #[pb(index = 3)]
+    pub exclude_time: i64,
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedSyncExclusion {
+    #[pb(index = 1)]
+    pub items: Vec<SyncExclusion>,
+}
+
+impl_def_and_def_mut!(RepeatedSyncExclusion, SyncExclusion);
+
+// The progress of sending the local state of an object that was synced again,
+// reported after each of the objects in it.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct SyncBackfill {
+    #[pb(index = 1)]
+    pub object_id: String,
+
+    #[pb(index = 2)]
+    pub total: i64,
+
+    #[pb(index = 3)]
+    pub done: i64,
+
+    // The backfill stops at the first error, the changes that were made while
+    // excluded are still sent with the next sync.
+    #[pb(index = 4)]
+    pub error: String,
+}
+
+impl SyncBackfill {
+    pub fn is_finished(&self) -> bool { self.done == self.total || !self.error.is_empty() }
+}
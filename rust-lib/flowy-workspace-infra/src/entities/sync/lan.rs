@@ -0,0 +1,73 @@
+use crate::impl_def_and_def_mut;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+// A device of the same account that was found on the local network, see
+// SyncSettings::lan_sync.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct LanPeer {
+    #[pb(index = 1)]
+    pub device_id: String,
+
+    // The ip and the port it's reached at.
+    #[pb(index = 2)]
+    pub address: String,
+
+    #[pb(index = 3)]
+    pub last_seen: i64,
+
+    // The last change of the peer that was pulled from it.
+    #[pb(index = 4)]
+    pub pulled_seq: i64,
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedLanPeer {
+    #[pb(index = 1)]
+    pub items: Vec<LanPeer>,
+}
+
+impl_def_and_def_mut!(RepeatedLanPeer, LanPeer);
+
+// The messages between the devices on the local network, the data of each type:
+// Hello: LanHello, sent first by both sides
+// Pull: LanPullRequest
+// Changes: RepeatedRemoteChange, the rev_id of each change is its seq in the
+// log of the device that made it.
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
+pub enum LanMessageType {
+    Unknown = 0,
+    Hello   = 1,
+    Pull    = 2,
+    Changes = 3,
+}
+
+impl std::default::Default for LanMessageType {
+    fn default() -> Self { LanMessageType::Unknown }
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct LanMessage {
+    #[pb(index = 1)]
+    pub ty: LanMessageType,
+
+    #[pb(index = 2)]
+    pub data: Vec<u8>,
+}
+
+// The account is the digest of the user id, the devices of other accounts are
+// turned away.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct LanHello {
+    #[pb(index = 1)]
+    pub account: String,
+
+    #[pb(index = 2)]
+    pub device_id: String,
+}
+
+// The changes after the seq, in the order they were made.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct LanPullRequest {
+    #[pb(index = 1)]
+    pub seq: i64,
+}
@@ -0,0 +1,15 @@
+mod conflict;
+mod exclusion;
+mod lan;
+mod remote_change;
+mod server_push;
+mod sync_state;
+mod sync_status;
+
+pub use conflict::*;
+pub use exclusion::*;
+pub use lan::*;
+pub use remote_change::*;
+pub use server_push::*;
+pub use sync_state::*;
+pub use sync_status::*;
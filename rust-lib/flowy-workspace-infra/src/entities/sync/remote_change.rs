@@ -0,0 +1,61 @@
+use crate::impl_def_and_def_mut;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+// The data of each type of change:
+// UpdateWorkspace: UpdateWorkspaceParams
+// CreateApp: App
+// UpdateApp: UpdateAppParams
+// CreateView: View
+// UpdateView: UpdateViewParams
+// DocDelta: DocDelta, the delta is composed with the document.
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
+pub enum RemoteChangeType {
+    Unknown         = 0,
+    UpdateWorkspace = 1,
+    CreateApp       = 2,
+    UpdateApp       = 3,
+    CreateView      = 4,
+    UpdateView      = 5,
+    DocDelta        = 6,
+}
+
+impl std::default::Default for RemoteChangeType {
+    fn default() -> Self { RemoteChangeType::Unknown }
+}
+
+// A change that the server was told about by another device or another member
+// of the workspace. The revisions are given by the server, they increase with
+// each change.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct RemoteChange {
+    #[pb(index = 1)]
+    pub rev_id: i64,
+
+    #[pb(index = 2)]
+    pub object_id: String,
+
+    #[pb(index = 3)]
+    pub ty: RemoteChangeType,
+
+    #[pb(index = 4)]
+    pub data: Vec<u8>,
+
+    // When the change was made on the other device.
+    #[pb(index = 5)]
+    pub modified_time: i64,
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedRemoteChange {
+    #[pb(index = 1)]
+    pub items: Vec<RemoteChange>,
+}
+
+impl_def_and_def_mut!(RepeatedRemoteChange, RemoteChange);
+
+// The changes after the revision, in the order of their revisions.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct PullChangesParams {
+    #[pb(index = 1)]
+    pub rev_id: i64,
+}
@@ -0,0 +1,29 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+// The data of each type of push:
+// RemoteChanges: RepeatedRemoteChange, in the order of their revisions.
+// MemberJoined: WorkspaceMember, the role of a member that changed is pushed
+// the same way.
+// MemberLeft: WorkspaceMember
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
+pub enum ServerPushType {
+    Unknown       = 0,
+    RemoteChanges = 1,
+    MemberJoined  = 2,
+    MemberLeft    = 3,
+}
+
+impl std::default::Default for ServerPushType {
+    fn default() -> Self { ServerPushType::Unknown }
+}
+
+// What the server pushes through the websocket while it's connected, so the
+// changes of the others show up without waiting for the next sync.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct ServerPush {
+    #[pb(index = 1)]
+    pub ty: ServerPushType,
+
+    #[pb(index = 2)]
+    pub data: Vec<u8>,
+}
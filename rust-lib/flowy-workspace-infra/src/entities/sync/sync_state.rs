@@ -0,0 +1,39 @@
+use flowy_derive::ProtoBuf;
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct SyncState {
+    // The local changes that the server wasn't told about yet.
+    #[pb(index = 1)]
+    pub pending_operations: i64,
+
+    // The revision of the last remote change that was pulled.
+    #[pb(index = 2)]
+    pub rev_id: i64,
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct SyncResult {
+    #[pb(index = 1)]
+    pub pushed: i64,
+
+    #[pb(index = 2)]
+    pub pulled: i64,
+
+    #[pb(index = 3)]
+    pub state: SyncState,
+}
+
+// The bytes of the changes that the sync sent and received since the app was
+// started, the ones of all the users.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct SyncTraffic {
+    #[pb(index = 1)]
+    pub uploaded_bytes: i64,
+
+    #[pb(index = 2)]
+    pub downloaded_bytes: i64,
+
+    // How long the sync waited to stay under the rate limits.
+    #[pb(index = 3)]
+    pub throttled_millis: i64,
+}
@@ -0,0 +1,51 @@
+use crate::impl_def_and_def_mut;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
+pub enum SyncStatusType {
+    Synced  = 0,
+    // The local changes wait to be sent, e.g. while offline.
+    Pending = 1,
+    Syncing = 2,
+    // The last time they were sent failed, they're sent again with the next sync.
+    Failed  = 3,
+    // The user excluded it from the sync, its changes are kept local.
+    Excluded = 4,
+}
+
+impl std::default::Default for SyncStatusType {
+    fn default() -> Self { SyncStatusType::Synced }
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct ObjectSyncStatus {
+    #[pb(index = 1)]
+    pub object_id: String,
+
+    #[pb(index = 2)]
+    pub ty: SyncStatusType,
+
+    // The operations of the object and the revisions of its document that the
+    // server wasn't told about yet.
+    #[pb(index = 3)]
+    pub pending_operations: i64,
+
+    // Empty unless it Failed.
+    #[pb(index = 4)]
+    pub error: String,
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedObjectSyncStatus {
+    #[pb(index = 1)]
+    pub items: Vec<ObjectSyncStatus>,
+}
+
+impl_def_and_def_mut!(RepeatedObjectSyncStatus, ObjectSyncStatus);
+
+// The ids of the workspaces, apps or views.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct QuerySyncStatusRequest {
+    #[pb(index = 1)]
+    pub object_ids: Vec<String>,
+}
@@ -0,0 +1,5 @@
+mod template;
+mod workspace_template;
+
+pub use template::*;
+pub use workspace_template::*;
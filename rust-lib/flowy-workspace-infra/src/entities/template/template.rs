@@ -0,0 +1,112 @@
+use crate::{
+    errors::ErrorCode,
+    impl_def_and_def_mut,
+    parser::{
+        app::AppId,
+        template::{TemplateId, TemplateName},
+        view::{ViewId, ViewName},
+    },
+};
+use flowy_derive::{FromPayload, ProtoBuf};
+use std::convert::TryInto;
+
+// The data of the template is the delta of the document, the variables in it
+// like {{title}} or {{date}} are filled when a document is created from it.
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct Template {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub desc: String,
+
+    #[pb(index = 4)]
+    pub built_in: bool,
+
+    #[pb(index = 5)]
+    pub data: String,
+
+    #[pb(index = 6)]
+    pub create_time: i64,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedTemplate {
+    #[pb(index = 1)]
+    pub items: Vec<Template>,
+}
+
+impl_def_and_def_mut!(RepeatedTemplate, Template);
+
+#[derive(Default, ProtoBuf)]
+pub struct CreateViewFromTemplateRequest {
+    #[pb(index = 1)]
+    pub belong_to_id: String,
+
+    #[pb(index = 2)]
+    pub template_id: String,
+
+    #[pb(index = 3)]
+    pub name: String,
+}
+
+#[derive(Default, Debug, FromPayload)]
+#[payload(from = "CreateViewFromTemplateRequest")]
+pub struct CreateViewFromTemplateParams {
+    pub belong_to_id: String,
+    pub template_id: String,
+    pub name: String,
+}
+
+impl TryInto<CreateViewFromTemplateParams> for CreateViewFromTemplateRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CreateViewFromTemplateParams, Self::Error> {
+        let belong_to_id = AppId::parse(self.belong_to_id)?.0;
+        let template_id = TemplateId::parse(self.template_id)?.0;
+        let name = ViewName::parse(self.name)?.0;
+        Ok(CreateViewFromTemplateParams {
+            belong_to_id,
+            template_id,
+            name,
+        })
+    }
+}
+
+// Saves the content of the view as a template of the user.
+#[derive(Default, ProtoBuf)]
+pub struct SaveTemplateRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub desc: String,
+}
+
+#[derive(Default, Debug, FromPayload)]
+#[payload(from = "SaveTemplateRequest")]
+pub struct SaveTemplateParams {
+    pub view_id: String,
+    pub name: String,
+    pub desc: String,
+}
+
+impl TryInto<SaveTemplateParams> for SaveTemplateRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<SaveTemplateParams, Self::Error> {
+        let view_id = ViewId::parse(self.view_id)?.0;
+        let name = TemplateName::parse(self.name)?.0;
+        Ok(SaveTemplateParams {
+            view_id,
+            name,
+            desc: self.desc,
+        })
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{
+    errors::ErrorCode,
+    impl_def_and_def_mut,
+    parser::{template::TemplateId, workspace::WorkspaceName},
+};
+use flowy_derive::{FromPayload, ProtoBuf};
+use std::convert::TryInto;
+
+// The starter kit of a workspace. The apps are created in their order with the
+// workspace, and each view is a document created from the view template it
+// names.
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct WorkspaceTemplate {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub desc: String,
+
+    #[pb(index = 4)]
+    pub apps: Vec<WorkspaceTemplateApp>,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct WorkspaceTemplateApp {
+    #[pb(index = 1)]
+    pub name: String,
+
+    #[pb(index = 2)]
+    pub desc: String,
+
+    #[pb(index = 3)]
+    pub views: Vec<WorkspaceTemplateView>,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct WorkspaceTemplateView {
+    #[pb(index = 1)]
+    pub name: String,
+
+    #[pb(index = 2)]
+    pub template_id: String,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedWorkspaceTemplate {
+    #[pb(index = 1)]
+    pub items: Vec<WorkspaceTemplate>,
+}
+
+impl_def_and_def_mut!(RepeatedWorkspaceTemplate, WorkspaceTemplate);
+
+// The workspace is named after the template if the name isn't set.
+#[derive(Default, ProtoBuf)]
+pub struct CreateWorkspaceFromTemplateRequest {
+    #[pb(index = 1)]
+    pub template_id: String,
+
+    #[pb(index = 2, one_of)]
+    pub name: Option<String>,
+}
+
+#[derive(Default, Debug, FromPayload)]
+#[payload(from = "CreateWorkspaceFromTemplateRequest")]
+pub struct CreateWorkspaceFromTemplateParams {
+    pub template_id: String,
+    pub name: Option<String>,
+}
+
+impl TryInto<CreateWorkspaceFromTemplateParams> for CreateWorkspaceFromTemplateRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CreateWorkspaceFromTemplateParams, Self::Error> {
+        let template_id = TemplateId::parse(self.template_id)?.0;
+        let name = match self.name {
+            None => None,
+            Some(name) => Some(WorkspaceName::parse(name)?.0),
+        };
+        Ok(CreateWorkspaceFromTemplateParams { template_id, name })
+    }
+}
@@ -114,6 +114,11 @@ pub struct Trash {
 
     #[pb(index = 5)]
     pub ty: TrashType,
+
+    // When the item was moved to the trash, it's purged once the retention period
+    // elapsed.
+    #[pb(index = 6)]
+    pub deleted_time: i64,
 }
 
 #[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
@@ -1,7 +1,19 @@
+pub use view_batch::*;
 pub use view_create::*;
+pub use view_duplicate::*;
+pub use view_favorite::*;
+pub use view_garbage::*;
+pub use view_move::*;
 pub use view_query::*;
+pub use view_tree::*;
 pub use view_update::*;
 
+mod view_batch;
 mod view_create;
+mod view_duplicate;
+mod view_favorite;
+mod view_garbage;
+mod view_move;
 mod view_query;
+mod view_tree;
 mod view_update;
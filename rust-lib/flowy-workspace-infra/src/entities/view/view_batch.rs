@@ -0,0 +1,168 @@
+use crate::{
+    entities::view::{MoveViewParams, RepeatedView, View},
+    errors::ErrorCode,
+    parser::{
+        app::AppId,
+        view::{ViewId, ViewName},
+    },
+};
+use flowy_derive::{FromPayload, ProtoBuf, ProtoBuf_Enum};
+use std::convert::TryInto;
+
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
+pub enum ViewOperationType {
+    Move   = 0,
+    Rename = 1,
+    Delete = 2,
+}
+
+impl std::default::Default for ViewOperationType {
+    fn default() -> Self { ViewOperationType::Move }
+}
+
+// The belong_to_id and the prev_view_id are the ones of the MoveViewRequest,
+// they're only read by the move, and the name by the rename.
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct ViewOperation {
+    #[pb(index = 1)]
+    pub ty: ViewOperationType,
+
+    #[pb(index = 2)]
+    pub view_id: String,
+
+    #[pb(index = 3, one_of)]
+    pub belong_to_id: Option<String>,
+
+    #[pb(index = 4, one_of)]
+    pub prev_view_id: Option<String>,
+
+    #[pb(index = 5, one_of)]
+    pub name: Option<String>,
+}
+
+impl ViewOperation {
+    pub fn move_to(view_id: &str, belong_to_id: &str, prev_view_id: Option<&str>) -> Self {
+        ViewOperation {
+            ty: ViewOperationType::Move,
+            view_id: view_id.to_owned(),
+            belong_to_id: Some(belong_to_id.to_owned()),
+            prev_view_id: prev_view_id.map(|prev_view_id| prev_view_id.to_owned()),
+            name: None,
+        }
+    }
+
+    pub fn rename(view_id: &str, name: &str) -> Self {
+        ViewOperation {
+            ty: ViewOperationType::Rename,
+            view_id: view_id.to_owned(),
+            name: Some(name.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    pub fn delete(view_id: &str) -> Self {
+        ViewOperation {
+            ty: ViewOperationType::Delete,
+            view_id: view_id.to_owned(),
+            ..Default::default()
+        }
+    }
+}
+
+// The operations are applied in their order, each one sees the changes of the
+// ones before it. Nothing is changed if any of them fails.
+#[derive(Default, ProtoBuf)]
+pub struct BatchViewOperationRequest {
+    #[pb(index = 1)]
+    pub operations: Vec<ViewOperation>,
+}
+
+// The belong_to_id is empty unless it's a move, and the name unless it's a
+// rename.
+#[derive(Default, Debug, Clone)]
+pub struct ViewOperationParams {
+    pub ty: ViewOperationType,
+    pub view_id: String,
+    pub belong_to_id: String,
+    pub prev_view_id: Option<String>,
+    pub name: String,
+}
+
+impl ViewOperationParams {
+    pub fn move_params(&self) -> MoveViewParams {
+        MoveViewParams {
+            view_id: self.view_id.clone(),
+            belong_to_id: self.belong_to_id.clone(),
+            prev_view_id: self.prev_view_id.clone(),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, FromPayload)]
+#[payload(from = "BatchViewOperationRequest")]
+pub struct BatchViewOperationParams {
+    pub operations: Vec<ViewOperationParams>,
+}
+
+impl TryInto<ViewOperationParams> for ViewOperation {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ViewOperationParams, Self::Error> {
+        let mut params = ViewOperationParams {
+            view_id: ViewId::parse(self.view_id)?.0,
+            ..Default::default()
+        };
+        match self.ty {
+            ViewOperationType::Move => {
+                params.belong_to_id = AppId::parse(self.belong_to_id.unwrap_or_default())?.0;
+                params.prev_view_id = match self.prev_view_id {
+                    None => None,
+                    Some(prev_view_id) => Some(ViewId::parse(prev_view_id)?.0),
+                };
+            },
+            ViewOperationType::Rename => {
+                params.name = ViewName::parse(self.name.unwrap_or_default())?.0;
+            },
+            ViewOperationType::Delete => {},
+        }
+        params.ty = self.ty;
+        Ok(params)
+    }
+}
+
+impl TryInto<BatchViewOperationParams> for BatchViewOperationRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<BatchViewOperationParams, Self::Error> {
+        let operations = self
+            .operations
+            .into_iter()
+            .map(|operation| operation.try_into())
+            .collect::<Result<Vec<ViewOperationParams>, ErrorCode>>()?;
+        Ok(BatchViewOperationParams { operations })
+    }
+}
+
+// The batch is notified once with the views it changed. The belongings are the
+// views of the apps and the views whose children were moved, renamed or
+// deleted, without the ones in the trash.
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct BatchViewChangeset {
+    #[pb(index = 1)]
+    pub updated_views: Vec<View>,
+
+    #[pb(index = 2)]
+    pub deleted_view_ids: Vec<String>,
+
+    #[pb(index = 3)]
+    pub belongings: Vec<ViewBelongings>,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct ViewBelongings {
+    #[pb(index = 1)]
+    pub belong_to_id: String,
+
+    #[pb(index = 2)]
+    pub views: RepeatedView,
+}
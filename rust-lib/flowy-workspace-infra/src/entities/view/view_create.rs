@@ -7,14 +7,17 @@ use crate::{
         view::{ViewName, ViewThumbnail},
     },
 };
-use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_derive::{FromPayload, ProtoBuf, ProtoBuf_Enum};
 use flowy_document_infra::user_default::doc_initial_string;
 use std::convert::TryInto;
 
+// The grid of a grid view has the id of the view, like the document of a doc
+// view.
 #[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
 pub enum ViewType {
     Blank = 0,
     Doc   = 1,
+    Grid  = 2,
 }
 
 impl std::default::Default for ViewType {
@@ -24,6 +27,7 @@ impl std::default::Default for ViewType {
 impl std::convert::From<i32> for ViewType {
     fn from(val: i32) -> Self {
         match val {
+            2 => ViewType::Grid,
             1 => ViewType::Doc,
             0 => ViewType::Blank,
             _ => {
@@ -50,9 +54,15 @@ pub struct CreateViewRequest {
 
     #[pb(index = 5)]
     pub view_type: ViewType,
+
+    // The view takes the first unique name instead of failing if another view
+    // that it belongs to has the same name.
+    #[pb(index = 6)]
+    pub auto_rename: bool,
 }
 
-#[derive(Default, ProtoBuf, Debug, Clone)]
+#[derive(Default, ProtoBuf, Debug, Clone, FromPayload)]
+#[payload(from = "CreateViewRequest")]
 pub struct CreateViewParams {
     #[pb(index = 1)]
     pub belong_to_id: String,
@@ -71,6 +81,10 @@ pub struct CreateViewParams {
 
     #[pb(index = 6)]
     pub data: String,
+
+    // The name is made unique before the view is sent to the server.
+    #[pb(skip)]
+    pub auto_rename: bool,
 }
 
 impl CreateViewParams {
@@ -82,6 +96,7 @@ impl CreateViewParams {
             thumbnail,
             view_type,
             data: doc_initial_string(),
+            auto_rename: false,
         }
     }
 }
@@ -98,13 +113,9 @@ impl TryInto<CreateViewParams> for CreateViewRequest {
             Some(thumbnail) => ViewThumbnail::parse(thumbnail)?.0,
         };
 
-        Ok(CreateViewParams::new(
-            belong_to_id,
-            name,
-            self.desc,
-            self.view_type,
-            thumbnail,
-        ))
+        let mut params = CreateViewParams::new(belong_to_id, name, self.desc, self.view_type, thumbnail);
+        params.auto_rename = self.auto_rename;
+        Ok(params)
     }
 }
 
@@ -136,6 +147,14 @@ pub struct View {
 
     #[pb(index = 9)]
     pub create_time: i64,
+
+    // The emoji or the name of the icon that is shown before the name.
+    #[pb(index = 10)]
+    pub icon: String,
+
+    // The id of the asset of the document that is shown above it.
+    #[pb(index = 11)]
+    pub cover: String,
 }
 
 #[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
@@ -154,6 +173,7 @@ impl std::convert::Into<Trash> for View {
             modified_time: self.modified_time,
             create_time: self.create_time,
             ty: TrashType::View,
+            deleted_time: chrono::Utc::now().timestamp(),
         }
     }
 }
@@ -0,0 +1,63 @@
+use crate::{
+    errors::ErrorCode,
+    parser::{
+        app::AppId,
+        view::{ViewId, ViewName},
+    },
+};
+use flowy_derive::{FromPayload, ProtoBuf};
+use std::convert::TryInto;
+
+// Copies the view with its document and the assets of the document. The copy is
+// put next to the view if belong_to_id isn't set, it's named after the view if
+// the name isn't set.
+#[derive(Default, ProtoBuf)]
+pub struct DuplicateDocumentRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2, one_of)]
+    pub belong_to_id: Option<String>,
+
+    #[pb(index = 3, one_of)]
+    pub name: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, FromPayload)]
+#[payload(from = "DuplicateDocumentRequest")]
+pub struct DuplicateDocumentParams {
+    pub view_id: String,
+    pub belong_to_id: Option<String>,
+    pub name: Option<String>,
+}
+
+impl DuplicateDocumentParams {
+    pub fn new(view_id: &str) -> Self {
+        Self {
+            view_id: view_id.to_owned(),
+            belong_to_id: None,
+            name: None,
+        }
+    }
+}
+
+impl TryInto<DuplicateDocumentParams> for DuplicateDocumentRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<DuplicateDocumentParams, Self::Error> {
+        let view_id = ViewId::parse(self.view_id)?.0;
+        let belong_to_id = match self.belong_to_id {
+            None => None,
+            Some(belong_to_id) => Some(AppId::parse(belong_to_id)?.0),
+        };
+        let name = match self.name {
+            None => None,
+            Some(name) => Some(ViewName::parse(name)?.0),
+        };
+        Ok(DuplicateDocumentParams {
+            view_id,
+            belong_to_id,
+            name,
+        })
+    }
+}
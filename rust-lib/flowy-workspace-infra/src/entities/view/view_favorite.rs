@@ -0,0 +1,34 @@
+use crate::{errors::ErrorCode, parser::view::ViewId};
+use flowy_derive::{FromPayload, ProtoBuf};
+use std::convert::TryInto;
+
+// Reorders the favorites of the user, the view is put after the prev_view_id, or
+// first if it isn't set.
+#[derive(Default, ProtoBuf)]
+pub struct MoveFavoriteRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2, one_of)]
+    pub prev_view_id: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, FromPayload)]
+#[payload(from = "MoveFavoriteRequest")]
+pub struct MoveFavoriteParams {
+    pub view_id: String,
+    pub prev_view_id: Option<String>,
+}
+
+impl TryInto<MoveFavoriteParams> for MoveFavoriteRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<MoveFavoriteParams, Self::Error> {
+        let view_id = ViewId::parse(self.view_id)?.0;
+        let prev_view_id = match self.prev_view_id {
+            None => None,
+            Some(prev_view_id) => Some(ViewId::parse(prev_view_id)?.0),
+        };
+        Ok(MoveFavoriteParams { view_id, prev_view_id })
+    }
+}
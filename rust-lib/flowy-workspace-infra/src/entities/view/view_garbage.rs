@@ -0,0 +1,20 @@
+use flowy_derive::ProtoBuf;
+
+// What the garbage collection removed. The views are the ones whose app or
+// parent view was deleted, with the views that belonged to them, and the
+// documents are the ones that no view owned. The blobs are the asset files that
+// no asset referred to.
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct GarbageCollectionResult {
+    #[pb(index = 1)]
+    pub view_ids: Vec<String>,
+
+    #[pb(index = 2)]
+    pub doc_ids: Vec<String>,
+
+    #[pb(index = 3)]
+    pub blob_count: i64,
+
+    #[pb(index = 4)]
+    pub blob_size: i64,
+}
@@ -0,0 +1,47 @@
+use crate::{
+    errors::ErrorCode,
+    parser::{app::AppId, view::ViewId},
+};
+use flowy_derive::{FromPayload, ProtoBuf};
+use std::convert::TryInto;
+
+// Moves the view to the app, which may be in another workspace, or reorders it
+// if it's the app of the view. The view is put after the prev_view_id, or first
+// if it isn't set.
+#[derive(Default, ProtoBuf)]
+pub struct MoveViewRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub belong_to_id: String,
+
+    #[pb(index = 3, one_of)]
+    pub prev_view_id: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, FromPayload)]
+#[payload(from = "MoveViewRequest")]
+pub struct MoveViewParams {
+    pub view_id: String,
+    pub belong_to_id: String,
+    pub prev_view_id: Option<String>,
+}
+
+impl TryInto<MoveViewParams> for MoveViewRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<MoveViewParams, Self::Error> {
+        let view_id = ViewId::parse(self.view_id)?.0;
+        let belong_to_id = AppId::parse(self.belong_to_id)?.0;
+        let prev_view_id = match self.prev_view_id {
+            None => None,
+            Some(prev_view_id) => Some(ViewId::parse(prev_view_id)?.0),
+        };
+        Ok(MoveViewParams {
+            view_id,
+            belong_to_id,
+            prev_view_id,
+        })
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{errors::ErrorCode, parser::view::ViewId};
-use flowy_derive::ProtoBuf;
+use flowy_derive::{FromPayload, ProtoBuf};
 use flowy_document_infra::entities::doc::DocIdentifier;
 use std::convert::TryInto;
 
@@ -9,7 +9,8 @@ pub struct QueryViewRequest {
     pub view_ids: Vec<String>,
 }
 
-#[derive(Default, ProtoBuf, Clone, Debug)]
+#[derive(Default, ProtoBuf, Clone, Debug, FromPayload)]
+#[payload(from = "QueryViewRequest")]
 pub struct ViewIdentifier {
     #[pb(index = 1)]
     pub view_id: String,
@@ -39,7 +40,8 @@ impl TryInto<ViewIdentifier> for QueryViewRequest {
     }
 }
 
-#[derive(Default, ProtoBuf)]
+#[derive(Default, ProtoBuf, FromPayload)]
+#[payload(from = "QueryViewRequest")]
 pub struct ViewIdentifiers {
     #[pb(index = 1)]
     pub view_ids: Vec<String>,
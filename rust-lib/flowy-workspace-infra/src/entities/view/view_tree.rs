@@ -0,0 +1,39 @@
+use crate::{errors::ErrorCode, parser::app::AppId};
+use flowy_derive::{FromPayload, ProtoBuf};
+use std::convert::TryInto;
+
+// The deepest level of the tree that is read at once, the views below it are
+// read with another request starting at the views of the last level.
+pub const MAX_VIEW_TREE_DEPTH: i32 = 16;
+
+// Reads the views that belong to the app or the view, with their belongings
+// filled down to the depth. The depth is 1 if only the direct children are
+// read, it's the max depth if it isn't set or it's greater.
+#[derive(Default, ProtoBuf)]
+pub struct ReadViewTreeRequest {
+    #[pb(index = 1)]
+    pub belong_to_id: String,
+
+    #[pb(index = 2)]
+    pub depth: i32,
+}
+
+#[derive(Default, Debug, Clone, FromPayload)]
+#[payload(from = "ReadViewTreeRequest")]
+pub struct ReadViewTreeParams {
+    pub belong_to_id: String,
+    pub depth: i32,
+}
+
+impl TryInto<ReadViewTreeParams> for ReadViewTreeRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ReadViewTreeParams, Self::Error> {
+        let belong_to_id = AppId::parse(self.belong_to_id)?.0;
+        let depth = match self.depth {
+            depth if depth <= 0 || depth > MAX_VIEW_TREE_DEPTH => MAX_VIEW_TREE_DEPTH,
+            depth => depth,
+        };
+        Ok(ReadViewTreeParams { belong_to_id, depth })
+    }
+}
@@ -1,8 +1,9 @@
 use crate::{
     errors::ErrorCode,
-    parser::view::{ViewDesc, ViewId, ViewName, ViewThumbnail},
+    impl_field_diff,
+    parser::view::{ViewCover, ViewDesc, ViewIcon, ViewId, ViewName, ViewThumbnail},
 };
-use flowy_derive::ProtoBuf;
+use flowy_derive::{FromPayload, ProtoBuf};
 use std::convert::TryInto;
 
 #[derive(Default, ProtoBuf)]
@@ -18,9 +19,22 @@ pub struct UpdateViewRequest {
 
     #[pb(index = 4, one_of)]
     pub thumbnail: Option<String>,
+
+    // Clears the icon if it's empty.
+    #[pb(index = 5, one_of)]
+    pub icon: Option<String>,
+
+    // The id of an asset of the view's document, clears the cover if it's empty.
+    #[pb(index = 6, one_of)]
+    pub cover: Option<String>,
+
+    // Same as the auto_rename of CreateViewRequest, it's used if the name is set.
+    #[pb(index = 7)]
+    pub auto_rename: bool,
 }
 
-#[derive(Default, ProtoBuf, Clone, Debug)]
+#[derive(Default, ProtoBuf, Clone, Debug, FromPayload)]
+#[payload(from = "UpdateViewRequest")]
 pub struct UpdateViewParams {
     #[pb(index = 1)]
     pub view_id: String,
@@ -33,8 +47,19 @@ pub struct UpdateViewParams {
 
     #[pb(index = 4, one_of)]
     pub thumbnail: Option<String>,
+
+    #[pb(index = 5, one_of)]
+    pub icon: Option<String>,
+
+    #[pb(index = 6, one_of)]
+    pub cover: Option<String>,
+
+    #[pb(skip)]
+    pub auto_rename: bool,
 }
 
+impl_field_diff!(UpdateViewParams, name, desc, thumbnail, icon, cover);
+
 impl UpdateViewParams {
     pub fn new(view_id: &str) -> Self {
         Self {
@@ -52,6 +77,16 @@ impl UpdateViewParams {
         self.desc = Some(desc.to_owned());
         self
     }
+
+    pub fn icon(mut self, icon: &str) -> Self {
+        self.icon = Some(icon.to_owned());
+        self
+    }
+
+    pub fn cover(mut self, cover: &str) -> Self {
+        self.cover = Some(cover.to_owned());
+        self
+    }
 }
 
 impl TryInto<UpdateViewParams> for UpdateViewRequest {
@@ -75,11 +110,24 @@ impl TryInto<UpdateViewParams> for UpdateViewRequest {
             Some(thumbnail) => Some(ViewThumbnail::parse(thumbnail)?.0),
         };
 
+        let icon = match self.icon {
+            None => None,
+            Some(icon) => Some(ViewIcon::parse(icon)?.0),
+        };
+
+        let cover = match self.cover {
+            None => None,
+            Some(cover) => Some(ViewCover::parse(cover)?.0),
+        };
+
         Ok(UpdateViewParams {
             view_id,
             name,
             desc,
             thumbnail,
+            icon,
+            cover,
+            auto_rename: self.auto_rename,
         })
     }
 }
@@ -1,9 +1,17 @@
 pub use workspace_create::*;
+pub use workspace_encryption::*;
+pub use workspace_integrity::*;
+pub use workspace_invitation::*;
+pub use workspace_member::*;
 pub use workspace_query::*;
 pub use workspace_setting::*;
 pub use workspace_update::*;
 
 mod workspace_create;
+mod workspace_encryption;
+mod workspace_integrity;
+mod workspace_invitation;
+mod workspace_member;
 mod workspace_query;
 mod workspace_setting;
 mod workspace_update;
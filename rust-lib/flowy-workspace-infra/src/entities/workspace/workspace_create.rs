@@ -4,7 +4,7 @@ use crate::{
     impl_def_and_def_mut,
     parser::workspace::{WorkspaceDesc, WorkspaceName},
 };
-use flowy_derive::ProtoBuf;
+use flowy_derive::{FromPayload, ProtoBuf};
 use std::convert::TryInto;
 
 #[derive(ProtoBuf, Default)]
@@ -16,7 +16,8 @@ pub struct CreateWorkspaceRequest {
     pub desc: String,
 }
 
-#[derive(Clone, ProtoBuf, Default, Debug)]
+#[derive(Clone, ProtoBuf, Default, Debug, FromPayload)]
+#[payload(from = "CreateWorkspaceRequest")]
 pub struct CreateWorkspaceParams {
     #[pb(index = 1)]
     pub name: String,
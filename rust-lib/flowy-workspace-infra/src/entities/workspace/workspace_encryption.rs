@@ -0,0 +1,67 @@
+use crate::{errors::*, parser::workspace::WorkspaceId};
+use flowy_derive::{FromPayload, ProtoBuf};
+use std::convert::TryInto;
+
+// The synced payloads of the documents of the encrypted workspace are encrypted
+// with its key, the server never sees their content.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct WorkspaceEncryption {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub enabled: bool,
+}
+
+// The wrapped_key and the invite_secret are the ones of the
+// WorkspaceInvitation.
+#[derive(ProtoBuf, Default)]
+pub struct AcceptWorkspaceInvitationRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub wrapped_key: String,
+
+    #[pb(index = 3)]
+    pub invite_secret: String,
+}
+
+impl AcceptWorkspaceInvitationRequest {
+    pub fn new(workspace_id: &str, wrapped_key: &str, invite_secret: &str) -> Self {
+        Self {
+            workspace_id: workspace_id.to_owned(),
+            wrapped_key: wrapped_key.to_owned(),
+            invite_secret: invite_secret.to_owned(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, FromPayload)]
+#[payload(from = "AcceptWorkspaceInvitationRequest")]
+pub struct AcceptWorkspaceInvitationParams {
+    pub workspace_id: String,
+    // None if the workspace isn't encrypted.
+    pub wrapped_key: Option<String>,
+    pub invite_secret: String,
+}
+
+impl TryInto<AcceptWorkspaceInvitationParams> for AcceptWorkspaceInvitationRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<AcceptWorkspaceInvitationParams, Self::Error> {
+        let workspace_id = WorkspaceId::parse(self.workspace_id)?.0;
+        let wrapped_key = match self.wrapped_key.is_empty() {
+            true => None,
+            false => Some(self.wrapped_key),
+        };
+        if wrapped_key.is_some() && self.invite_secret.is_empty() {
+            return Err(ErrorCode::InvitationSecretInvalid);
+        }
+        Ok(AcceptWorkspaceInvitationParams {
+            workspace_id,
+            wrapped_key,
+            invite_secret: self.invite_secret,
+        })
+    }
+}
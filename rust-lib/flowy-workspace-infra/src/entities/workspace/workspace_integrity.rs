@@ -0,0 +1,59 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct CheckStorageIntegrityRequest {
+    // Only reports the issues if it's false.
+    #[pb(index = 1)]
+    pub repair: bool,
+}
+
+#[derive(ProtoBuf_Enum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrityIssueType {
+    Unknown             = 0,
+    // The workspace of the app doesn't exist. It's moved to the current workspace.
+    AppWithoutWorkspace = 1,
+    // The app or the view the view belongs to doesn't exist. It's moved to the
+    // first app of the current workspace.
+    ViewWithoutParent   = 2,
+    // The view belongs to one of its descendants, none of them can be reached
+    // from an app. It's moved like the views without parent.
+    ViewCycle           = 3,
+    // The app or the view in the trash doesn't exist. The trash is removed.
+    TrashWithoutItem    = 4,
+    // The last view opened in the app doesn't exist. It's cleared.
+    LastViewMissing     = 5,
+}
+
+impl std::default::Default for IntegrityIssueType {
+    fn default() -> Self { IntegrityIssueType::Unknown }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct IntegrityIssue {
+    #[pb(index = 1)]
+    pub ty: IntegrityIssueType,
+
+    // The id of the app, view or trash.
+    #[pb(index = 2)]
+    pub id: String,
+
+    #[pb(index = 3)]
+    pub repaired: bool,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct StorageIntegrityReport {
+    // The messages of SQLite's integrity check, empty if the file is sound.
+    // The corruptions it finds aren't repaired.
+    #[pb(index = 1)]
+    pub database_errors: Vec<String>,
+
+    #[pb(index = 2)]
+    pub issues: Vec<IntegrityIssue>,
+}
+
+impl StorageIntegrityReport {
+    pub fn is_healthy(&self) -> bool {
+        self.database_errors.is_empty() && self.issues.iter().all(|issue| issue.repaired)
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{
+    entities::workspace::WorkspaceRole,
+    errors::*,
+    impl_def_and_def_mut,
+    parser::workspace::{MemberEmail, WorkspaceId},
+};
+use flowy_derive::{FromPayload, ProtoBuf};
+use std::convert::TryInto;
+
+// The invitation is pending until the invited user joins the workspace, the
+// email is invited once per workspace.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct WorkspaceInvitation {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub email: String,
+
+    #[pb(index = 3)]
+    pub role: WorkspaceRole,
+
+    #[pb(index = 4)]
+    pub invited_time: i64,
+
+    // The key of the encrypted workspace, encrypted with the invite_secret. It's
+    // empty if the workspace isn't encrypted.
+    #[pb(index = 5)]
+    pub wrapped_key: String,
+
+    // Only the invitation that InviteWorkspaceMember returns has it, it's given to
+    // the invited user along with the invitation, e.g. in the link, and it's
+    // never kept or sent to the server.
+    #[pb(index = 6)]
+    pub invite_secret: String,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf)]
+pub struct RepeatedWorkspaceInvitation {
+    #[pb(index = 1)]
+    pub items: Vec<WorkspaceInvitation>,
+}
+
+impl_def_and_def_mut!(RepeatedWorkspaceInvitation, WorkspaceInvitation);
+
+// The role is ignored if default_role is set, the member_default_role setting
+// of the workspace is used instead. Inviting the email again changes the role.
+#[derive(ProtoBuf, Default)]
+pub struct InviteWorkspaceMemberRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub email: String,
+
+    #[pb(index = 3)]
+    pub role: WorkspaceRole,
+
+    #[pb(index = 4)]
+    pub default_role: bool,
+}
+
+impl InviteWorkspaceMemberRequest {
+    pub fn new(workspace_id: &str, email: &str, role: Option<WorkspaceRole>) -> Self {
+        Self {
+            workspace_id: workspace_id.to_owned(),
+            email: email.to_owned(),
+            role: role.unwrap_or_default(),
+            default_role: role.is_none(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, FromPayload)]
+#[payload(from = "InviteWorkspaceMemberRequest")]
+pub struct InviteWorkspaceMemberParams {
+    pub workspace_id: String,
+    pub email: String,
+    pub role: Option<WorkspaceRole>,
+}
+
+impl TryInto<InviteWorkspaceMemberParams> for InviteWorkspaceMemberRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<InviteWorkspaceMemberParams, Self::Error> {
+        let workspace_id = WorkspaceId::parse(self.workspace_id)?.0;
+        let email = MemberEmail::parse(self.email)?.0;
+        Ok(InviteWorkspaceMemberParams {
+            workspace_id,
+            email,
+            role: match self.default_role {
+                true => None,
+                false => Some(self.role),
+            },
+        })
+    }
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct CancelWorkspaceInvitationRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub email: String,
+}
+
+impl CancelWorkspaceInvitationRequest {
+    pub fn new(workspace_id: &str, email: &str) -> Self {
+        Self {
+            workspace_id: workspace_id.to_owned(),
+            email: email.to_owned(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, FromPayload)]
+#[payload(from = "CancelWorkspaceInvitationRequest")]
+pub struct WorkspaceInvitationIdentifier {
+    pub workspace_id: String,
+    pub email: String,
+}
+
+impl TryInto<WorkspaceInvitationIdentifier> for CancelWorkspaceInvitationRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<WorkspaceInvitationIdentifier, Self::Error> {
+        let workspace_id = WorkspaceId::parse(self.workspace_id)?.0;
+        let email = MemberEmail::parse(self.email)?.0;
+        Ok(WorkspaceInvitationIdentifier { workspace_id, email })
+    }
+}
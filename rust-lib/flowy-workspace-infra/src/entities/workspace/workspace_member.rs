@@ -0,0 +1,123 @@
+use crate::{errors::*, impl_def_and_def_mut, parser::workspace::WorkspaceId};
+use flowy_derive::{FromPayload, ProtoBuf, ProtoBuf_Enum};
+use std::convert::TryInto;
+
+// Viewer is the first value, a request that misses the role never grants more than reading.
+#[derive(ProtoBuf_Enum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkspaceRole {
+    Viewer = 0,
+    Editor = 1,
+    Owner  = 2,
+}
+
+impl std::default::Default for WorkspaceRole {
+    fn default() -> Self { WorkspaceRole::Viewer }
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct WorkspaceMember {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub user_id: String,
+
+    #[pb(index = 3)]
+    pub role: WorkspaceRole,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf)]
+pub struct RepeatedWorkspaceMember {
+    #[pb(index = 1)]
+    pub items: Vec<WorkspaceMember>,
+}
+
+impl_def_and_def_mut!(RepeatedWorkspaceMember, WorkspaceMember);
+
+#[derive(ProtoBuf, Default)]
+pub struct UpdateWorkspaceMemberRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub user_id: String,
+
+    #[pb(index = 3)]
+    pub role: WorkspaceRole,
+}
+
+impl UpdateWorkspaceMemberRequest {
+    pub fn new(workspace_id: &str, user_id: &str, role: WorkspaceRole) -> Self {
+        Self {
+            workspace_id: workspace_id.to_owned(),
+            user_id: user_id.to_owned(),
+            role,
+        }
+    }
+}
+
+#[derive(Clone, Debug, FromPayload)]
+#[payload(from = "UpdateWorkspaceMemberRequest")]
+pub struct UpdateWorkspaceMemberParams {
+    pub workspace_id: String,
+    pub user_id: String,
+    pub role: WorkspaceRole,
+}
+
+impl TryInto<UpdateWorkspaceMemberParams> for UpdateWorkspaceMemberRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateWorkspaceMemberParams, Self::Error> {
+        let workspace_id = WorkspaceId::parse(self.workspace_id)?;
+        if self.user_id.trim().is_empty() {
+            return Err(ErrorCode::UserIdIsEmpty);
+        }
+
+        Ok(UpdateWorkspaceMemberParams {
+            workspace_id: workspace_id.0,
+            user_id: self.user_id,
+            role: self.role,
+        })
+    }
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct RemoveWorkspaceMemberRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub user_id: String,
+}
+
+impl RemoveWorkspaceMemberRequest {
+    pub fn new(workspace_id: &str, user_id: &str) -> Self {
+        Self {
+            workspace_id: workspace_id.to_owned(),
+            user_id: user_id.to_owned(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, FromPayload)]
+#[payload(from = "RemoveWorkspaceMemberRequest")]
+pub struct RemoveWorkspaceMemberParams {
+    pub workspace_id: String,
+    pub user_id: String,
+}
+
+impl TryInto<RemoveWorkspaceMemberParams> for RemoveWorkspaceMemberRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<RemoveWorkspaceMemberParams, Self::Error> {
+        let workspace_id = WorkspaceId::parse(self.workspace_id)?;
+        if self.user_id.trim().is_empty() {
+            return Err(ErrorCode::UserIdIsEmpty);
+        }
+
+        Ok(RemoveWorkspaceMemberParams {
+            workspace_id: workspace_id.0,
+            user_id: self.user_id,
+        })
+    }
+}
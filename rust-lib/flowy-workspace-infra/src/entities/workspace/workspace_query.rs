@@ -1,5 +1,5 @@
 use crate::{errors::*, parser::workspace::WorkspaceId};
-use flowy_derive::ProtoBuf;
+use flowy_derive::{FromPayload, ProtoBuf};
 use std::convert::TryInto;
 
 #[derive(Default, ProtoBuf, Clone)]
@@ -14,7 +14,8 @@ impl QueryWorkspaceRequest {
 }
 
 // Read all workspaces if the workspace_id is None
-#[derive(Clone, ProtoBuf, Default, Debug)]
+#[derive(Clone, ProtoBuf, Default, Debug, FromPayload)]
+#[payload(from = "QueryWorkspaceRequest")]
 pub struct WorkspaceIdentifier {
     #[pb(index = 1, one_of)]
     pub workspace_id: Option<String>,
@@ -1,5 +1,14 @@
-use crate::entities::{view::View, workspace::Workspace};
-use flowy_derive::ProtoBuf;
+use crate::{
+    entities::{
+        view::View,
+        workspace::{Workspace, WorkspaceRole},
+    },
+    errors::ErrorCode,
+    impl_def_and_def_mut,
+    parser::workspace::WorkspaceId,
+};
+use flowy_derive::{FromPayload, ProtoBuf};
+use std::convert::TryInto;
 
 #[derive(Default, ProtoBuf, Clone)]
 pub struct CurrentWorkspaceSetting {
@@ -9,3 +18,195 @@ pub struct CurrentWorkspaceSetting {
     #[pb(index = 2, one_of)]
     pub latest_view: Option<View>,
 }
+
+// The settings that are known by the workspace, the values of the other keys
+// are kept as they're set. The type of a key can't be changed once it's set.
+//
+// The id of the view that is opened with the workspace, it's a str.
+pub const DEFAULT_VIEW_SETTING: &str = "default_view";
+// The layout of the sidebar that the members share, it's a str.
+pub const SIDEBAR_LAYOUT_SETTING: &str = "sidebar_layout";
+// The role of the members that are added without one, it's the int value of a
+// WorkspaceRole other than the owner.
+pub const MEMBER_DEFAULT_ROLE_SETTING: &str = "member_default_role";
+// The id of the doc view that the shared content is appended to, it's a str.
+pub const INBOX_VIEW_SETTING: &str = "inbox_view";
+
+// Only one of the values is set, it's the type of the setting.
+#[derive(ProtoBuf, Default, Debug, PartialEq, Clone)]
+pub struct WorkspaceSettingValue {
+    #[pb(index = 1, one_of)]
+    pub str_value: Option<String>,
+
+    #[pb(index = 2, one_of)]
+    pub int_value: Option<i64>,
+
+    #[pb(index = 3, one_of)]
+    pub float_value: Option<f64>,
+
+    #[pb(index = 4, one_of)]
+    pub bool_value: Option<bool>,
+}
+
+impl WorkspaceSettingValue {
+    pub fn str(value: &str) -> Self {
+        Self {
+            str_value: Some(value.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    pub fn int(value: i64) -> Self {
+        Self {
+            int_value: Some(value),
+            ..Default::default()
+        }
+    }
+
+    pub fn float(value: f64) -> Self {
+        Self {
+            float_value: Some(value),
+            ..Default::default()
+        }
+    }
+
+    pub fn bool(value: bool) -> Self {
+        Self {
+            bool_value: Some(value),
+            ..Default::default()
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> { self.str_value.as_deref() }
+
+    pub fn as_int(&self) -> Option<i64> { self.int_value }
+
+    pub fn type_name(&self) -> Option<&'static str> {
+        match (&self.str_value, &self.int_value, &self.float_value, &self.bool_value) {
+            (Some(_), None, None, None) => Some("str"),
+            (None, Some(_), None, None) => Some("int"),
+            (None, None, Some(_), None) => Some("float"),
+            (None, None, None, Some(_)) => Some("bool"),
+            _ => None,
+        }
+    }
+}
+
+#[derive(ProtoBuf, Default, Debug, PartialEq, Clone, FromPayload)]
+#[payload(from = "SetWorkspaceSettingRequest")]
+pub struct WorkspaceSettingItem {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub key: String,
+
+    #[pb(index = 3)]
+    pub value: WorkspaceSettingValue,
+}
+
+#[derive(ProtoBuf, Default, Debug, PartialEq, Clone)]
+pub struct RepeatedWorkspaceSettingItem {
+    #[pb(index = 1)]
+    pub items: Vec<WorkspaceSettingItem>,
+}
+
+impl_def_and_def_mut!(RepeatedWorkspaceSettingItem, WorkspaceSettingItem);
+
+#[derive(ProtoBuf, Default)]
+pub struct SetWorkspaceSettingRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub key: String,
+
+    #[pb(index = 3)]
+    pub value: WorkspaceSettingValue,
+}
+
+impl SetWorkspaceSettingRequest {
+    pub fn new(workspace_id: &str, key: &str, value: WorkspaceSettingValue) -> Self {
+        Self {
+            workspace_id: workspace_id.to_owned(),
+            key: key.to_owned(),
+            value,
+        }
+    }
+}
+
+impl TryInto<WorkspaceSettingItem> for SetWorkspaceSettingRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<WorkspaceSettingItem, Self::Error> {
+        let workspace_id = WorkspaceId::parse(self.workspace_id)?.0;
+        let key = parse_setting_key(self.key)?;
+        let expected_type = match key.as_str() {
+            DEFAULT_VIEW_SETTING | SIDEBAR_LAYOUT_SETTING | INBOX_VIEW_SETTING => Some("str"),
+            MEMBER_DEFAULT_ROLE_SETTING => Some("int"),
+            _ => None,
+        };
+        match self.value.type_name() {
+            None => return Err(ErrorCode::WorkspaceSettingInvalid),
+            Some(type_name) if expected_type.map_or(false, |expected_type| expected_type != type_name) => {
+                return Err(ErrorCode::WorkspaceSettingInvalid);
+            },
+            Some(_) => {},
+        }
+
+        if key == MEMBER_DEFAULT_ROLE_SETTING {
+            let roles = [WorkspaceRole::Viewer as i64, WorkspaceRole::Editor as i64];
+            if !self.value.as_int().map_or(false, |role| roles.contains(&role)) {
+                return Err(ErrorCode::WorkspaceSettingInvalid);
+            }
+        }
+
+        Ok(WorkspaceSettingItem {
+            workspace_id,
+            key,
+            value: self.value,
+        })
+    }
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct GetWorkspaceSettingRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub key: String,
+}
+
+impl GetWorkspaceSettingRequest {
+    pub fn new(workspace_id: &str, key: &str) -> Self {
+        Self {
+            workspace_id: workspace_id.to_owned(),
+            key: key.to_owned(),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, FromPayload)]
+#[payload(from = "GetWorkspaceSettingRequest")]
+pub struct WorkspaceSettingKey {
+    pub workspace_id: String,
+    pub key: String,
+}
+
+impl TryInto<WorkspaceSettingKey> for GetWorkspaceSettingRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<WorkspaceSettingKey, Self::Error> {
+        let workspace_id = WorkspaceId::parse(self.workspace_id)?.0;
+        let key = parse_setting_key(self.key)?;
+        Ok(WorkspaceSettingKey { workspace_id, key })
+    }
+}
+
+fn parse_setting_key(key: String) -> Result<String, ErrorCode> {
+    if key.trim().is_empty() {
+        return Err(ErrorCode::WorkspaceSettingKeyInvalid);
+    }
+    Ok(key)
+}
@@ -1,23 +1,25 @@
 use crate::{
     errors::*,
+    impl_field_diff,
     parser::workspace::{WorkspaceId, WorkspaceName},
 };
-use flowy_derive::ProtoBuf;
+use flowy_derive::{FromPayload, ProtoBuf};
 use std::convert::TryInto;
 
 #[derive(ProtoBuf, Default)]
 pub struct UpdateWorkspaceRequest {
     #[pb(index = 1)]
-    id: String,
+    pub id: String,
 
     #[pb(index = 2, one_of)]
-    name: Option<String>,
+    pub name: Option<String>,
 
     #[pb(index = 3, one_of)]
-    desc: Option<String>,
+    pub desc: Option<String>,
 }
 
-#[derive(Clone, ProtoBuf, Default, Debug)]
+#[derive(Clone, ProtoBuf, Default, Debug, FromPayload)]
+#[payload(from = "UpdateWorkspaceRequest")]
 pub struct UpdateWorkspaceParams {
     #[pb(index = 1)]
     pub id: String,
@@ -29,6 +31,8 @@ pub struct UpdateWorkspaceParams {
     pub desc: Option<String>,
 }
 
+impl_field_diff!(UpdateWorkspaceParams, name, desc);
+
 impl TryInto<UpdateWorkspaceParams> for UpdateWorkspaceRequest {
     type Error = ErrorCode;
 
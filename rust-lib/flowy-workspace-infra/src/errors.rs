@@ -22,6 +22,21 @@ pub enum ErrorCode {
     #[display(fmt = "Workspace description too long")]
     WorkspaceNameTooLong = 4,
 
+    #[display(fmt = "Workspace must keep at least one owner")]
+    WorkspaceOwnerRequired = 5,
+
+    #[display(fmt = "The last workspace can not be deleted")]
+    WorkspaceLastOne     = 6,
+
+    #[display(fmt = "The workspace archive is invalid")]
+    WorkspaceArchiveInvalid = 7,
+
+    #[display(fmt = "Workspace setting key can not be empty or whitespace")]
+    WorkspaceSettingKeyInvalid = 8,
+
+    #[display(fmt = "The workspace setting value is invalid")]
+    WorkspaceSettingInvalid = 9,
+
     #[display(fmt = "App id can not be empty or whitespace")]
     AppIdInvalid         = 10,
 
@@ -46,12 +61,63 @@ pub enum ErrorCode {
     #[display(fmt = "View name too long")]
     ViewNameTooLong      = 25,
 
+    #[display(fmt = "The view can't be moved into itself or the views that belong to it")]
+    ViewParentInvalid    = 26,
+
+    #[display(fmt = "View icon too long")]
+    ViewIconTooLong      = 27,
+
+    #[display(fmt = "The cover of the view must be an asset of its document")]
+    ViewCoverInvalid     = 28,
+
+    #[display(fmt = "A view with the same name already exists")]
+    ViewNameConflict     = 29,
+
+    #[display(fmt = "Template id can not be empty or whitespace")]
+    TemplateIdInvalid    = 30,
+
+    #[display(fmt = "Template name can not be empty or whitespace")]
+    TemplateNameInvalid  = 31,
+
+    #[display(fmt = "Template name too long")]
+    TemplateNameTooLong  = 32,
+
+    #[display(fmt = "The Notion export is invalid")]
+    NotionExportInvalid  = 33,
+
+    #[display(fmt = "The Evernote export is invalid")]
+    EnexInvalid          = 34,
+
+    #[display(fmt = "The shared content is empty or invalid")]
+    SharedContentInvalid = 35,
+
+    #[display(fmt = "The inbox view is not set or is not a document")]
+    InboxViewInvalid     = 36,
+
     #[display(fmt = "User unauthorized")]
     UserUnauthorized     = 100,
 
+    #[display(fmt = "User id can not be empty or whitespace")]
+    UserIdIsEmpty        = 101,
+
+    #[display(fmt = "The role in the workspace doesn't allow the operation")]
+    PermissionDenied     = 102,
+
+    #[display(fmt = "The email of the invited member is invalid")]
+    MemberEmailInvalid   = 103,
+
+    #[display(fmt = "The secret of the invitation doesn't match its key")]
+    InvitationSecretInvalid = 104,
+
     #[display(fmt = "Workspace websocket error")]
     WsConnectError       = 200,
 
+    #[display(fmt = "The network is unreachable")]
+    NetworkUnreachable   = 201,
+
+    #[display(fmt = "The field isn't one of the conflict or its value is invalid")]
+    SyncConflictFieldInvalid = 202,
+
     #[display(fmt = "Server error")]
     InternalError        = 1000,
     #[display(fmt = "Record not found")]
@@ -28,3 +28,44 @@ macro_rules! impl_def_and_def_mut {
         }
     };
 }
+
+// The update params only set the fields that change, the others are None.
+#[macro_export]
+macro_rules! impl_field_diff {
+    ($target:ident, $($field:ident),+) => {
+        impl $target {
+            pub fn is_empty(&self) -> bool { $(self.$field.is_none())&&+ }
+
+            // Drops the fields that are set to the same values in current.
+            pub fn without_unchanged(mut self, current: &$target) -> Self {
+                $(
+                    if self.$field.is_some() && self.$field == current.$field {
+                        self.$field = None;
+                    }
+                )+
+                self
+            }
+
+            // The fields set in newer replace these ones.
+            pub fn merge(&mut self, newer: $target) {
+                $(
+                    if newer.$field.is_some() {
+                        self.$field = newer.$field;
+                    }
+                )+
+            }
+
+            // Moves the fields that other sets too into the returned params.
+            pub fn take_fields_of(&mut self, other: &$target) -> Self {
+                let mut taken = self.clone();
+                $(
+                    taken.$field = match other.$field.is_some() {
+                        true => self.$field.take(),
+                        false => None,
+                    };
+                )+
+                taken
+            }
+        }
+    };
+}
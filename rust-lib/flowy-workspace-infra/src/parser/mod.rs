@@ -1,4 +1,5 @@
 pub mod app;
+pub mod template;
 pub mod trash;
 pub mod view;
 pub mod workspace;
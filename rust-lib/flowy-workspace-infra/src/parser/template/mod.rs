@@ -0,0 +1,5 @@
+mod template_id;
+mod template_name;
+
+pub use template_id::*;
+pub use template_name::*;
@@ -0,0 +1,18 @@
+use crate::errors::ErrorCode;
+
+#[derive(Debug)]
+pub struct TemplateId(pub String);
+
+impl TemplateId {
+    pub fn parse(s: String) -> Result<TemplateId, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::TemplateIdInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for TemplateId {
+    fn as_ref(&self) -> &str { &self.0 }
+}
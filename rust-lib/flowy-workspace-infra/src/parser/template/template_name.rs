@@ -0,0 +1,23 @@
+use crate::errors::ErrorCode;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Debug)]
+pub struct TemplateName(pub String);
+
+impl TemplateName {
+    pub fn parse(s: String) -> Result<TemplateName, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::TemplateNameInvalid);
+        }
+
+        if s.graphemes(true).count() > 256 {
+            return Err(ErrorCode::TemplateNameTooLong);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for TemplateName {
+    fn as_ref(&self) -> &str { &self.0 }
+}
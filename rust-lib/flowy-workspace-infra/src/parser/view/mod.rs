@@ -1,11 +1,15 @@
 mod delta_data;
+mod view_cover;
 mod view_desc;
+mod view_icon;
 mod view_id;
 mod view_name;
 mod view_thumbnail;
 
 pub use delta_data::*;
+pub use view_cover::*;
 pub use view_desc::*;
+pub use view_icon::*;
 pub use view_id::*;
 pub use view_name::*;
 pub use view_thumbnail::*;
@@ -0,0 +1,19 @@
+use crate::errors::ErrorCode;
+
+// The id of the asset of the view's document, it's cleared if it's empty.
+#[derive(Debug)]
+pub struct ViewCover(pub String);
+
+impl ViewCover {
+    pub fn parse(s: String) -> Result<ViewCover, ErrorCode> {
+        if !s.is_empty() && s.trim().is_empty() {
+            return Err(ErrorCode::ViewCoverInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for ViewCover {
+    fn as_ref(&self) -> &str { &self.0 }
+}
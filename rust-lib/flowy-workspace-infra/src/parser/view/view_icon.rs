@@ -0,0 +1,20 @@
+use crate::errors::ErrorCode;
+use unicode_segmentation::UnicodeSegmentation;
+
+// The emoji or the name of the icon, it's cleared if it's empty.
+#[derive(Debug)]
+pub struct ViewIcon(pub String);
+
+impl ViewIcon {
+    pub fn parse(s: String) -> Result<ViewIcon, ErrorCode> {
+        if s.graphemes(true).count() > 64 {
+            return Err(ErrorCode::ViewIconTooLong);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for ViewIcon {
+    fn as_ref(&self) -> &str { &self.0 }
+}
@@ -0,0 +1,21 @@
+use crate::errors::ErrorCode;
+use validator::validate_email;
+
+#[derive(Debug)]
+pub struct MemberEmail(pub String);
+
+impl MemberEmail {
+    // The same address can be typed with another case, it's stored in lower case.
+    pub fn parse(s: String) -> Result<MemberEmail, ErrorCode> {
+        let s = s.trim().to_lowercase();
+        if !validate_email(&s) {
+            return Err(ErrorCode::MemberEmailInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for MemberEmail {
+    fn as_ref(&self) -> &str { &self.0 }
+}
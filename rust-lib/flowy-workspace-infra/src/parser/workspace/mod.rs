@@ -1,7 +1,9 @@
+mod member_email;
 mod workspace_desc;
 mod workspace_id;
 mod workspace_name;
 
+pub use member_email::*;
 pub use workspace_desc::*;
 pub use workspace_id::*;
 pub use workspace_name::*;
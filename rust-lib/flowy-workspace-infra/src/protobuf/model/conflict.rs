@@ -0,0 +1,2394 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `conflict.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SyncSettings {
+    // message fields
+    pub workspace_strategy: ConflictStrategy,
+    pub app_strategy: ConflictStrategy,
+    pub view_strategy: ConflictStrategy,
+    pub trigger: SyncTrigger,
+    pub interval_seconds: i64,
+    pub debounce_millis: i64,
+    pub unmetered_only: bool,
+    pub upload_bytes_per_second: i64,
+    pub download_bytes_per_second: i64,
+    pub batch_window_millis: i64,
+    pub lan_sync: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SyncSettings {
+    fn default() -> &'a SyncSettings {
+        <SyncSettings as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SyncSettings {
+    pub fn new() -> SyncSettings {
+        ::std::default::Default::default()
+    }
+
+    // .ConflictStrategy workspace_strategy = 1;
+
+
+    pub fn get_workspace_strategy(&self) -> ConflictStrategy {
+        self.workspace_strategy
+    }
+    pub fn clear_workspace_strategy(&mut self) {
+        self.workspace_strategy = ConflictStrategy::PreferLocal;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_strategy(&mut self, v: ConflictStrategy) {
+        self.workspace_strategy = v;
+    }
+
+    // .ConflictStrategy app_strategy = 2;
+
+
+    pub fn get_app_strategy(&self) -> ConflictStrategy {
+        self.app_strategy
+    }
+    pub fn clear_app_strategy(&mut self) {
+        self.app_strategy = ConflictStrategy::PreferLocal;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_app_strategy(&mut self, v: ConflictStrategy) {
+        self.app_strategy = v;
+    }
+
+    // .ConflictStrategy view_strategy = 3;
+
+
+    pub fn get_view_strategy(&self) -> ConflictStrategy {
+        self.view_strategy
+    }
+    pub fn clear_view_strategy(&mut self) {
+        self.view_strategy = ConflictStrategy::PreferLocal;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_strategy(&mut self, v: ConflictStrategy) {
+        self.view_strategy = v;
+    }
+
+    // .SyncTrigger trigger = 4;
+
+
+    pub fn get_trigger(&self) -> SyncTrigger {
+        self.trigger
+    }
+    pub fn clear_trigger(&mut self) {
+        self.trigger = SyncTrigger::Automatic;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_trigger(&mut self, v: SyncTrigger) {
+        self.trigger = v;
+    }
+
+    // int64 interval_seconds = 5;
+
+
+    pub fn get_interval_seconds(&self) -> i64 {
+        self.interval_seconds
+    }
+    pub fn clear_interval_seconds(&mut self) {
+        self.interval_seconds = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_interval_seconds(&mut self, v: i64) {
+        self.interval_seconds = v;
+    }
+
+    // int64 debounce_millis = 6;
+
+
+    pub fn get_debounce_millis(&self) -> i64 {
+        self.debounce_millis
+    }
+    pub fn clear_debounce_millis(&mut self) {
+        self.debounce_millis = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_debounce_millis(&mut self, v: i64) {
+        self.debounce_millis = v;
+    }
+
+    // bool unmetered_only = 7;
+
+
+    pub fn get_unmetered_only(&self) -> bool {
+        self.unmetered_only
+    }
+    pub fn clear_unmetered_only(&mut self) {
+        self.unmetered_only = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_unmetered_only(&mut self, v: bool) {
+        self.unmetered_only = v;
+    }
+
+    // int64 upload_bytes_per_second = 8;
+
+
+    pub fn get_upload_bytes_per_second(&self) -> i64 {
+        self.upload_bytes_per_second
+    }
+    pub fn clear_upload_bytes_per_second(&mut self) {
+        self.upload_bytes_per_second = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_upload_bytes_per_second(&mut self, v: i64) {
+        self.upload_bytes_per_second = v;
+    }
+
+    // int64 download_bytes_per_second = 9;
+
+
+    pub fn get_download_bytes_per_second(&self) -> i64 {
+        self.download_bytes_per_second
+    }
+    pub fn clear_download_bytes_per_second(&mut self) {
+        self.download_bytes_per_second = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_download_bytes_per_second(&mut self, v: i64) {
+        self.download_bytes_per_second = v;
+    }
+
+    // int64 batch_window_millis = 10;
+
+
+    pub fn get_batch_window_millis(&self) -> i64 {
+        self.batch_window_millis
+    }
+    pub fn clear_batch_window_millis(&mut self) {
+        self.batch_window_millis = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_batch_window_millis(&mut self, v: i64) {
+        self.batch_window_millis = v;
+    }
+
+    // bool lan_sync = 11;
+
+
+    pub fn get_lan_sync(&self) -> bool {
+        self.lan_sync
+    }
+    pub fn clear_lan_sync(&mut self) {
+        self.lan_sync = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_lan_sync(&mut self, v: bool) {
+        self.lan_sync = v;
+    }
+}
+
+impl ::protobuf::Message for SyncSettings {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.workspace_strategy, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.app_strategy, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.view_strategy, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.trigger, 4, &mut self.unknown_fields)?
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.interval_seconds = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.debounce_millis = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.unmetered_only = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.upload_bytes_per_second = tmp;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.download_bytes_per_second = tmp;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.batch_window_millis = tmp;
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.lan_sync = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.workspace_strategy != ConflictStrategy::PreferLocal {
+            my_size += ::protobuf::rt::enum_size(1, self.workspace_strategy);
+        }
+        if self.app_strategy != ConflictStrategy::PreferLocal {
+            my_size += ::protobuf::rt::enum_size(2, self.app_strategy);
+        }
+        if self.view_strategy != ConflictStrategy::PreferLocal {
+            my_size += ::protobuf::rt::enum_size(3, self.view_strategy);
+        }
+        if self.trigger != SyncTrigger::Automatic {
+            my_size += ::protobuf::rt::enum_size(4, self.trigger);
+        }
+        if self.interval_seconds != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.interval_seconds, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.debounce_millis != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.debounce_millis, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.unmetered_only != false {
+            my_size += 2;
+        }
+        if self.upload_bytes_per_second != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.upload_bytes_per_second, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.download_bytes_per_second != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.download_bytes_per_second, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.batch_window_millis != 0 {
+            my_size += ::protobuf::rt::value_size(10, self.batch_window_millis, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.lan_sync != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.workspace_strategy != ConflictStrategy::PreferLocal {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.workspace_strategy))?;
+        }
+        if self.app_strategy != ConflictStrategy::PreferLocal {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.app_strategy))?;
+        }
+        if self.view_strategy != ConflictStrategy::PreferLocal {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.view_strategy))?;
+        }
+        if self.trigger != SyncTrigger::Automatic {
+            os.write_enum(4, ::protobuf::ProtobufEnum::value(&self.trigger))?;
+        }
+        if self.interval_seconds != 0 {
+            os.write_int64(5, self.interval_seconds)?;
+        }
+        if self.debounce_millis != 0 {
+            os.write_int64(6, self.debounce_millis)?;
+        }
+        if self.unmetered_only != false {
+            os.write_bool(7, self.unmetered_only)?;
+        }
+        if self.upload_bytes_per_second != 0 {
+            os.write_int64(8, self.upload_bytes_per_second)?;
+        }
+        if self.download_bytes_per_second != 0 {
+            os.write_int64(9, self.download_bytes_per_second)?;
+        }
+        if self.batch_window_millis != 0 {
+            os.write_int64(10, self.batch_window_millis)?;
+        }
+        if self.lan_sync != false {
+            os.write_bool(11, self.lan_sync)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SyncSettings {
+        SyncSettings::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ConflictStrategy>>(
+                "workspace_strategy",
+                |m: &SyncSettings| { &m.workspace_strategy },
+                |m: &mut SyncSettings| { &mut m.workspace_strategy },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ConflictStrategy>>(
+                "app_strategy",
+                |m: &SyncSettings| { &m.app_strategy },
+                |m: &mut SyncSettings| { &mut m.app_strategy },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ConflictStrategy>>(
+                "view_strategy",
+                |m: &SyncSettings| { &m.view_strategy },
+                |m: &mut SyncSettings| { &mut m.view_strategy },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<SyncTrigger>>(
+                "trigger",
+                |m: &SyncSettings| { &m.trigger },
+                |m: &mut SyncSettings| { &mut m.trigger },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "interval_seconds",
+                |m: &SyncSettings| { &m.interval_seconds },
+                |m: &mut SyncSettings| { &mut m.interval_seconds },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "debounce_millis",
+                |m: &SyncSettings| { &m.debounce_millis },
+                |m: &mut SyncSettings| { &mut m.debounce_millis },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "unmetered_only",
+                |m: &SyncSettings| { &m.unmetered_only },
+                |m: &mut SyncSettings| { &mut m.unmetered_only },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "upload_bytes_per_second",
+                |m: &SyncSettings| { &m.upload_bytes_per_second },
+                |m: &mut SyncSettings| { &mut m.upload_bytes_per_second },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "download_bytes_per_second",
+                |m: &SyncSettings| { &m.download_bytes_per_second },
+                |m: &mut SyncSettings| { &mut m.download_bytes_per_second },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "batch_window_millis",
+                |m: &SyncSettings| { &m.batch_window_millis },
+                |m: &mut SyncSettings| { &mut m.batch_window_millis },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "lan_sync",
+                |m: &SyncSettings| { &m.lan_sync },
+                |m: &mut SyncSettings| { &mut m.lan_sync },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SyncSettings>(
+                "SyncSettings",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SyncSettings {
+        static instance: ::protobuf::rt::LazyV2<SyncSettings> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SyncSettings::new)
+    }
+}
+
+impl ::protobuf::Clear for SyncSettings {
+    fn clear(&mut self) {
+        self.workspace_strategy = ConflictStrategy::PreferLocal;
+        self.app_strategy = ConflictStrategy::PreferLocal;
+        self.view_strategy = ConflictStrategy::PreferLocal;
+        self.trigger = SyncTrigger::Automatic;
+        self.interval_seconds = 0;
+        self.debounce_millis = 0;
+        self.unmetered_only = false;
+        self.upload_bytes_per_second = 0;
+        self.download_bytes_per_second = 0;
+        self.batch_window_millis = 0;
+        self.lan_sync = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SyncSettings {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyncSettings {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SyncConflict {
+    // message fields
+    pub object_id: ::std::string::String,
+    pub entity: SyncEntityType,
+    pub strategy: ConflictStrategy,
+    pub resolution: ConflictResolution,
+    pub local_time: i64,
+    pub remote_time: i64,
+    pub remote_change: ::protobuf::SingularPtrField<super::remote_change::RemoteChange>,
+    pub base: ::std::vec::Vec<u8>,
+    pub local: ::std::vec::Vec<u8>,
+    pub fields: ::protobuf::RepeatedField<ConflictField>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SyncConflict {
+    fn default() -> &'a SyncConflict {
+        <SyncConflict as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SyncConflict {
+    pub fn new() -> SyncConflict {
+        ::std::default::Default::default()
+    }
+
+    // string object_id = 1;
+
+
+    pub fn get_object_id(&self) -> &str {
+        &self.object_id
+    }
+    pub fn clear_object_id(&mut self) {
+        self.object_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object_id(&mut self, v: ::std::string::String) {
+        self.object_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_object_id(&mut self) -> &mut ::std::string::String {
+        &mut self.object_id
+    }
+
+    // Take field
+    pub fn take_object_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.object_id, ::std::string::String::new())
+    }
+
+    // .SyncEntityType entity = 2;
+
+
+    pub fn get_entity(&self) -> SyncEntityType {
+        self.entity
+    }
+    pub fn clear_entity(&mut self) {
+        self.entity = SyncEntityType::Workspace;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_entity(&mut self, v: SyncEntityType) {
+        self.entity = v;
+    }
+
+    // .ConflictStrategy strategy = 3;
+
+
+    pub fn get_strategy(&self) -> ConflictStrategy {
+        self.strategy
+    }
+    pub fn clear_strategy(&mut self) {
+        self.strategy = ConflictStrategy::PreferLocal;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_strategy(&mut self, v: ConflictStrategy) {
+        self.strategy = v;
+    }
+
+    // .ConflictResolution resolution = 4;
+
+
+    pub fn get_resolution(&self) -> ConflictResolution {
+        self.resolution
+    }
+    pub fn clear_resolution(&mut self) {
+        self.resolution = ConflictResolution::Pending;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resolution(&mut self, v: ConflictResolution) {
+        self.resolution = v;
+    }
+
+    // int64 local_time = 5;
+
+
+    pub fn get_local_time(&self) -> i64 {
+        self.local_time
+    }
+    pub fn clear_local_time(&mut self) {
+        self.local_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_local_time(&mut self, v: i64) {
+        self.local_time = v;
+    }
+
+    // int64 remote_time = 6;
+
+
+    pub fn get_remote_time(&self) -> i64 {
+        self.remote_time
+    }
+    pub fn clear_remote_time(&mut self) {
+        self.remote_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_remote_time(&mut self, v: i64) {
+        self.remote_time = v;
+    }
+
+    // .RemoteChange remote_change = 7;
+
+
+    pub fn get_remote_change(&self) -> &super::remote_change::RemoteChange {
+        self.remote_change.as_ref().unwrap_or_else(|| <super::remote_change::RemoteChange as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_remote_change(&mut self) {
+        self.remote_change.clear();
+    }
+
+    pub fn has_remote_change(&self) -> bool {
+        self.remote_change.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_remote_change(&mut self, v: super::remote_change::RemoteChange) {
+        self.remote_change = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_remote_change(&mut self) -> &mut super::remote_change::RemoteChange {
+        if self.remote_change.is_none() {
+            self.remote_change.set_default();
+        }
+        self.remote_change.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_remote_change(&mut self) -> super::remote_change::RemoteChange {
+        self.remote_change.take().unwrap_or_else(|| super::remote_change::RemoteChange::new())
+    }
+
+    // bytes base = 8;
+
+
+    pub fn get_base(&self) -> &[u8] {
+        &self.base
+    }
+    pub fn clear_base(&mut self) {
+        self.base.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_base(&mut self, v: ::std::vec::Vec<u8>) {
+        self.base = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_base(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.base
+    }
+
+    // Take field
+    pub fn take_base(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.base, ::std::vec::Vec::new())
+    }
+
+    // bytes local = 9;
+
+
+    pub fn get_local(&self) -> &[u8] {
+        &self.local
+    }
+    pub fn clear_local(&mut self) {
+        self.local.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_local(&mut self, v: ::std::vec::Vec<u8>) {
+        self.local = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_local(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.local
+    }
+
+    // Take field
+    pub fn take_local(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.local, ::std::vec::Vec::new())
+    }
+
+    // repeated .ConflictField fields = 10;
+
+
+    pub fn get_fields(&self) -> &[ConflictField] {
+        &self.fields
+    }
+    pub fn clear_fields(&mut self) {
+        self.fields.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fields(&mut self, v: ::protobuf::RepeatedField<ConflictField>) {
+        self.fields = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_fields(&mut self) -> &mut ::protobuf::RepeatedField<ConflictField> {
+        &mut self.fields
+    }
+
+    // Take field
+    pub fn take_fields(&mut self) -> ::protobuf::RepeatedField<ConflictField> {
+        ::std::mem::replace(&mut self.fields, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for SyncConflict {
+    fn is_initialized(&self) -> bool {
+        for v in &self.remote_change {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.fields {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.object_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.entity, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.strategy, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.resolution, 4, &mut self.unknown_fields)?
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.local_time = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.remote_time = tmp;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.remote_change)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.base)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.local)?;
+                },
+                10 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.fields)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.object_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.object_id);
+        }
+        if self.entity != SyncEntityType::Workspace {
+            my_size += ::protobuf::rt::enum_size(2, self.entity);
+        }
+        if self.strategy != ConflictStrategy::PreferLocal {
+            my_size += ::protobuf::rt::enum_size(3, self.strategy);
+        }
+        if self.resolution != ConflictResolution::Pending {
+            my_size += ::protobuf::rt::enum_size(4, self.resolution);
+        }
+        if self.local_time != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.local_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.remote_time != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.remote_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.remote_change.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.base.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(8, &self.base);
+        }
+        if !self.local.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(9, &self.local);
+        }
+        for value in &self.fields {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.object_id.is_empty() {
+            os.write_string(1, &self.object_id)?;
+        }
+        if self.entity != SyncEntityType::Workspace {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.entity))?;
+        }
+        if self.strategy != ConflictStrategy::PreferLocal {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.strategy))?;
+        }
+        if self.resolution != ConflictResolution::Pending {
+            os.write_enum(4, ::protobuf::ProtobufEnum::value(&self.resolution))?;
+        }
+        if self.local_time != 0 {
+            os.write_int64(5, self.local_time)?;
+        }
+        if self.remote_time != 0 {
+            os.write_int64(6, self.remote_time)?;
+        }
+        if let Some(ref v) = self.remote_change.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.base.is_empty() {
+            os.write_bytes(8, &self.base)?;
+        }
+        if !self.local.is_empty() {
+            os.write_bytes(9, &self.local)?;
+        }
+        for v in &self.fields {
+            os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SyncConflict {
+        SyncConflict::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "object_id",
+                |m: &SyncConflict| { &m.object_id },
+                |m: &mut SyncConflict| { &mut m.object_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<SyncEntityType>>(
+                "entity",
+                |m: &SyncConflict| { &m.entity },
+                |m: &mut SyncConflict| { &mut m.entity },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ConflictStrategy>>(
+                "strategy",
+                |m: &SyncConflict| { &m.strategy },
+                |m: &mut SyncConflict| { &mut m.strategy },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ConflictResolution>>(
+                "resolution",
+                |m: &SyncConflict| { &m.resolution },
+                |m: &mut SyncConflict| { &mut m.resolution },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "local_time",
+                |m: &SyncConflict| { &m.local_time },
+                |m: &mut SyncConflict| { &mut m.local_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "remote_time",
+                |m: &SyncConflict| { &m.remote_time },
+                |m: &mut SyncConflict| { &mut m.remote_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::remote_change::RemoteChange>>(
+                "remote_change",
+                |m: &SyncConflict| { &m.remote_change },
+                |m: &mut SyncConflict| { &mut m.remote_change },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "base",
+                |m: &SyncConflict| { &m.base },
+                |m: &mut SyncConflict| { &mut m.base },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "local",
+                |m: &SyncConflict| { &m.local },
+                |m: &mut SyncConflict| { &mut m.local },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ConflictField>>(
+                "fields",
+                |m: &SyncConflict| { &m.fields },
+                |m: &mut SyncConflict| { &mut m.fields },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SyncConflict>(
+                "SyncConflict",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SyncConflict {
+        static instance: ::protobuf::rt::LazyV2<SyncConflict> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SyncConflict::new)
+    }
+}
+
+impl ::protobuf::Clear for SyncConflict {
+    fn clear(&mut self) {
+        self.object_id.clear();
+        self.entity = SyncEntityType::Workspace;
+        self.strategy = ConflictStrategy::PreferLocal;
+        self.resolution = ConflictResolution::Pending;
+        self.local_time = 0;
+        self.remote_time = 0;
+        self.remote_change.clear();
+        self.base.clear();
+        self.local.clear();
+        self.fields.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SyncConflict {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyncConflict {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ConflictField {
+    // message fields
+    pub name: ::std::string::String,
+    pub local: ::std::string::String,
+    pub remote: ::std::string::String,
+    // message oneof groups
+    pub one_of_base: ::std::option::Option<ConflictField_oneof_one_of_base>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ConflictField {
+    fn default() -> &'a ConflictField {
+        <ConflictField as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ConflictField_oneof_one_of_base {
+    base(::std::string::String),
+}
+
+impl ConflictField {
+    pub fn new() -> ConflictField {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // string base = 2;
+
+
+    pub fn get_base(&self) -> &str {
+        match self.one_of_base {
+            ::std::option::Option::Some(ConflictField_oneof_one_of_base::base(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_base(&mut self) {
+        self.one_of_base = ::std::option::Option::None;
+    }
+
+    pub fn has_base(&self) -> bool {
+        match self.one_of_base {
+            ::std::option::Option::Some(ConflictField_oneof_one_of_base::base(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_base(&mut self, v: ::std::string::String) {
+        self.one_of_base = ::std::option::Option::Some(ConflictField_oneof_one_of_base::base(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_base(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ConflictField_oneof_one_of_base::base(_)) = self.one_of_base {
+        } else {
+            self.one_of_base = ::std::option::Option::Some(ConflictField_oneof_one_of_base::base(::std::string::String::new()));
+        }
+        match self.one_of_base {
+            ::std::option::Option::Some(ConflictField_oneof_one_of_base::base(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_base(&mut self) -> ::std::string::String {
+        if self.has_base() {
+            match self.one_of_base.take() {
+                ::std::option::Option::Some(ConflictField_oneof_one_of_base::base(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string local = 3;
+
+
+    pub fn get_local(&self) -> &str {
+        &self.local
+    }
+    pub fn clear_local(&mut self) {
+        self.local.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_local(&mut self, v: ::std::string::String) {
+        self.local = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_local(&mut self) -> &mut ::std::string::String {
+        &mut self.local
+    }
+
+    // Take field
+    pub fn take_local(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.local, ::std::string::String::new())
+    }
+
+    // string remote = 4;
+
+
+    pub fn get_remote(&self) -> &str {
+        &self.remote
+    }
+    pub fn clear_remote(&mut self) {
+        self.remote.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_remote(&mut self, v: ::std::string::String) {
+        self.remote = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_remote(&mut self) -> &mut ::std::string::String {
+        &mut self.remote
+    }
+
+    // Take field
+    pub fn take_remote(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.remote, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ConflictField {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_base = ::std::option::Option::Some(ConflictField_oneof_one_of_base::base(is.read_string()?));
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.local)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.remote)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if !self.local.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.local);
+        }
+        if !self.remote.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.remote);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_base {
+            match v {
+                &ConflictField_oneof_one_of_base::base(ref v) => {
+                    my_size += ::protobuf::rt::string_size(2, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if !self.local.is_empty() {
+            os.write_string(3, &self.local)?;
+        }
+        if !self.remote.is_empty() {
+            os.write_string(4, &self.remote)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_base {
+            match v {
+                &ConflictField_oneof_one_of_base::base(ref v) => {
+                    os.write_string(2, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ConflictField {
+        ConflictField::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &ConflictField| { &m.name },
+                |m: &mut ConflictField| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "base",
+                ConflictField::has_base,
+                ConflictField::get_base,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "local",
+                |m: &ConflictField| { &m.local },
+                |m: &mut ConflictField| { &mut m.local },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "remote",
+                |m: &ConflictField| { &m.remote },
+                |m: &mut ConflictField| { &mut m.remote },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ConflictField>(
+                "ConflictField",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ConflictField {
+        static instance: ::protobuf::rt::LazyV2<ConflictField> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ConflictField::new)
+    }
+}
+
+impl ::protobuf::Clear for ConflictField {
+    fn clear(&mut self) {
+        self.name.clear();
+        self.one_of_base = ::std::option::Option::None;
+        self.local.clear();
+        self.remote.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ConflictField {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ConflictField {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedSyncConflict {
+    // message fields
+    pub items: ::protobuf::RepeatedField<SyncConflict>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedSyncConflict {
+    fn default() -> &'a RepeatedSyncConflict {
+        <RepeatedSyncConflict as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedSyncConflict {
+    pub fn new() -> RepeatedSyncConflict {
+        ::std::default::Default::default()
+    }
+
+    // repeated .SyncConflict items = 1;
+
+
+    pub fn get_items(&self) -> &[SyncConflict] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<SyncConflict>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<SyncConflict> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<SyncConflict> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedSyncConflict {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedSyncConflict {
+        RepeatedSyncConflict::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SyncConflict>>(
+                "items",
+                |m: &RepeatedSyncConflict| { &m.items },
+                |m: &mut RepeatedSyncConflict| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedSyncConflict>(
+                "RepeatedSyncConflict",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedSyncConflict {
+        static instance: ::protobuf::rt::LazyV2<RepeatedSyncConflict> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedSyncConflict::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedSyncConflict {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedSyncConflict {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedSyncConflict {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ResolveConflictRequest {
+    // message fields
+    pub object_id: ::std::string::String,
+    pub keep_local: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ResolveConflictRequest {
+    fn default() -> &'a ResolveConflictRequest {
+        <ResolveConflictRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ResolveConflictRequest {
+    pub fn new() -> ResolveConflictRequest {
+        ::std::default::Default::default()
+    }
+
+    // string object_id = 1;
+
+
+    pub fn get_object_id(&self) -> &str {
+        &self.object_id
+    }
+    pub fn clear_object_id(&mut self) {
+        self.object_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object_id(&mut self, v: ::std::string::String) {
+        self.object_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_object_id(&mut self) -> &mut ::std::string::String {
+        &mut self.object_id
+    }
+
+    // Take field
+    pub fn take_object_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.object_id, ::std::string::String::new())
+    }
+
+    // bool keep_local = 2;
+
+
+    pub fn get_keep_local(&self) -> bool {
+        self.keep_local
+    }
+    pub fn clear_keep_local(&mut self) {
+        self.keep_local = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_keep_local(&mut self, v: bool) {
+        self.keep_local = v;
+    }
+}
+
+impl ::protobuf::Message for ResolveConflictRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.object_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.keep_local = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.object_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.object_id);
+        }
+        if self.keep_local != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.object_id.is_empty() {
+            os.write_string(1, &self.object_id)?;
+        }
+        if self.keep_local != false {
+            os.write_bool(2, self.keep_local)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ResolveConflictRequest {
+        ResolveConflictRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "object_id",
+                |m: &ResolveConflictRequest| { &m.object_id },
+                |m: &mut ResolveConflictRequest| { &mut m.object_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "keep_local",
+                |m: &ResolveConflictRequest| { &m.keep_local },
+                |m: &mut ResolveConflictRequest| { &mut m.keep_local },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ResolveConflictRequest>(
+                "ResolveConflictRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ResolveConflictRequest {
+        static instance: ::protobuf::rt::LazyV2<ResolveConflictRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ResolveConflictRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ResolveConflictRequest {
+    fn clear(&mut self) {
+        self.object_id.clear();
+        self.keep_local = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ResolveConflictRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ResolveConflictRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SubmitConflictResolutionRequest {
+    // message fields
+    pub object_id: ::std::string::String,
+    pub fields: ::protobuf::RepeatedField<ResolvedConflictField>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SubmitConflictResolutionRequest {
+    fn default() -> &'a SubmitConflictResolutionRequest {
+        <SubmitConflictResolutionRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SubmitConflictResolutionRequest {
+    pub fn new() -> SubmitConflictResolutionRequest {
+        ::std::default::Default::default()
+    }
+
+    // string object_id = 1;
+
+
+    pub fn get_object_id(&self) -> &str {
+        &self.object_id
+    }
+    pub fn clear_object_id(&mut self) {
+        self.object_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object_id(&mut self, v: ::std::string::String) {
+        self.object_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_object_id(&mut self) -> &mut ::std::string::String {
+        &mut self.object_id
+    }
+
+    // Take field
+    pub fn take_object_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.object_id, ::std::string::String::new())
+    }
+
+    // repeated .ResolvedConflictField fields = 2;
+
+
+    pub fn get_fields(&self) -> &[ResolvedConflictField] {
+        &self.fields
+    }
+    pub fn clear_fields(&mut self) {
+        self.fields.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fields(&mut self, v: ::protobuf::RepeatedField<ResolvedConflictField>) {
+        self.fields = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_fields(&mut self) -> &mut ::protobuf::RepeatedField<ResolvedConflictField> {
+        &mut self.fields
+    }
+
+    // Take field
+    pub fn take_fields(&mut self) -> ::protobuf::RepeatedField<ResolvedConflictField> {
+        ::std::mem::replace(&mut self.fields, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for SubmitConflictResolutionRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.fields {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.object_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.fields)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.object_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.object_id);
+        }
+        for value in &self.fields {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.object_id.is_empty() {
+            os.write_string(1, &self.object_id)?;
+        }
+        for v in &self.fields {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SubmitConflictResolutionRequest {
+        SubmitConflictResolutionRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "object_id",
+                |m: &SubmitConflictResolutionRequest| { &m.object_id },
+                |m: &mut SubmitConflictResolutionRequest| { &mut m.object_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResolvedConflictField>>(
+                "fields",
+                |m: &SubmitConflictResolutionRequest| { &m.fields },
+                |m: &mut SubmitConflictResolutionRequest| { &mut m.fields },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SubmitConflictResolutionRequest>(
+                "SubmitConflictResolutionRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SubmitConflictResolutionRequest {
+        static instance: ::protobuf::rt::LazyV2<SubmitConflictResolutionRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SubmitConflictResolutionRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SubmitConflictResolutionRequest {
+    fn clear(&mut self) {
+        self.object_id.clear();
+        self.fields.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SubmitConflictResolutionRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SubmitConflictResolutionRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ResolvedConflictField {
+    // message fields
+    pub name: ::std::string::String,
+    pub value: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ResolvedConflictField {
+    fn default() -> &'a ResolvedConflictField {
+        <ResolvedConflictField as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ResolvedConflictField {
+    pub fn new() -> ResolvedConflictField {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // string value = 2;
+
+
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::string::String) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::string::String {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.value, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ResolvedConflictField {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if !self.value.is_empty() {
+            os.write_string(2, &self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ResolvedConflictField {
+        ResolvedConflictField::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &ResolvedConflictField| { &m.name },
+                |m: &mut ResolvedConflictField| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "value",
+                |m: &ResolvedConflictField| { &m.value },
+                |m: &mut ResolvedConflictField| { &mut m.value },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ResolvedConflictField>(
+                "ResolvedConflictField",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ResolvedConflictField {
+        static instance: ::protobuf::rt::LazyV2<ResolvedConflictField> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ResolvedConflictField::new)
+    }
+}
+
+impl ::protobuf::Clear for ResolvedConflictField {
+    fn clear(&mut self) {
+        self.name.clear();
+        self.value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ResolvedConflictField {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ResolvedConflictField {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum ConflictStrategy {
+    PreferLocal = 0,
+    PreferRemote = 1,
+    LastWriterWins = 2,
+    SurfaceToUser = 3,
+}
+
+impl ::protobuf::ProtobufEnum for ConflictStrategy {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ConflictStrategy> {
+        match value {
+            0 => ::std::option::Option::Some(ConflictStrategy::PreferLocal),
+            1 => ::std::option::Option::Some(ConflictStrategy::PreferRemote),
+            2 => ::std::option::Option::Some(ConflictStrategy::LastWriterWins),
+            3 => ::std::option::Option::Some(ConflictStrategy::SurfaceToUser),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [ConflictStrategy] = &[
+            ConflictStrategy::PreferLocal,
+            ConflictStrategy::PreferRemote,
+            ConflictStrategy::LastWriterWins,
+            ConflictStrategy::SurfaceToUser,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<ConflictStrategy>("ConflictStrategy", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for ConflictStrategy {
+}
+
+impl ::std::default::Default for ConflictStrategy {
+    fn default() -> Self {
+        ConflictStrategy::PreferLocal
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ConflictStrategy {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum SyncTrigger {
+    Automatic = 0,
+    Manual = 1,
+    Interval = 2,
+    OnChange = 3,
+}
+
+impl ::protobuf::ProtobufEnum for SyncTrigger {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<SyncTrigger> {
+        match value {
+            0 => ::std::option::Option::Some(SyncTrigger::Automatic),
+            1 => ::std::option::Option::Some(SyncTrigger::Manual),
+            2 => ::std::option::Option::Some(SyncTrigger::Interval),
+            3 => ::std::option::Option::Some(SyncTrigger::OnChange),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [SyncTrigger] = &[
+            SyncTrigger::Automatic,
+            SyncTrigger::Manual,
+            SyncTrigger::Interval,
+            SyncTrigger::OnChange,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<SyncTrigger>("SyncTrigger", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for SyncTrigger {
+}
+
+impl ::std::default::Default for SyncTrigger {
+    fn default() -> Self {
+        SyncTrigger::Automatic
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyncTrigger {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum SyncEntityType {
+    Workspace = 0,
+    App = 1,
+    View = 2,
+}
+
+impl ::protobuf::ProtobufEnum for SyncEntityType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<SyncEntityType> {
+        match value {
+            0 => ::std::option::Option::Some(SyncEntityType::Workspace),
+            1 => ::std::option::Option::Some(SyncEntityType::App),
+            2 => ::std::option::Option::Some(SyncEntityType::View),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [SyncEntityType] = &[
+            SyncEntityType::Workspace,
+            SyncEntityType::App,
+            SyncEntityType::View,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<SyncEntityType>("SyncEntityType", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for SyncEntityType {
+}
+
+impl ::std::default::Default for SyncEntityType {
+    fn default() -> Self {
+        SyncEntityType::Workspace
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyncEntityType {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum ConflictResolution {
+    Pending = 0,
+    LocalKept = 1,
+    RemoteApplied = 2,
+    Merged = 3,
+}
+
+impl ::protobuf::ProtobufEnum for ConflictResolution {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ConflictResolution> {
+        match value {
+            0 => ::std::option::Option::Some(ConflictResolution::Pending),
+            1 => ::std::option::Option::Some(ConflictResolution::LocalKept),
+            2 => ::std::option::Option::Some(ConflictResolution::RemoteApplied),
+            3 => ::std::option::Option::Some(ConflictResolution::Merged),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [ConflictResolution] = &[
+            ConflictResolution::Pending,
+            ConflictResolution::LocalKept,
+            ConflictResolution::RemoteApplied,
+            ConflictResolution::Merged,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<ConflictResolution>("ConflictResolution", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for ConflictResolution {
+}
+
+impl ::std::default::Default for ConflictResolution {
+    fn default() -> Self {
+        ConflictResolution::Pending
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ConflictResolution {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0econflict.proto\x1a\x13remote_change.proto\"\xb6\x04\n\x0cSyncSetti\
+    ngs\x12B\n\x12workspace_strategy\x18\x01\x20\x01(\x0e2\x11.ConflictStrat\
+    egyR\x11workspaceStrategyB\0\x126\n\x0capp_strategy\x18\x02\x20\x01(\x0e\
+    2\x11.ConflictStrategyR\x0bappStrategyB\0\x128\n\rview_strategy\x18\x03\
+    \x20\x01(\x0e2\x11.ConflictStrategyR\x0cviewStrategyB\0\x12(\n\x07trigge\
+    r\x18\x04\x20\x01(\x0e2\x0c.SyncTriggerR\x07triggerB\0\x12+\n\x10interva\
+    l_seconds\x18\x05\x20\x01(\x03R\x0fintervalSecondsB\0\x12)\n\x0fdebounce\
+    _millis\x18\x06\x20\x01(\x03R\x0edebounceMillisB\0\x12'\n\x0eunmetered_o\
+    nly\x18\x07\x20\x01(\x08R\runmeteredOnlyB\0\x127\n\x17upload_bytes_per_s\
+    econd\x18\x08\x20\x01(\x03R\x14uploadBytesPerSecondB\0\x12;\n\x19downloa\
+    d_bytes_per_second\x18\t\x20\x01(\x03R\x16downloadBytesPerSecondB\0\x120\
+    \n\x13batch_window_millis\x18\n\x20\x01(\x03R\x11batchWindowMillisB\0\
+    \x12\x1b\n\x08lan_sync\x18\x0b\x20\x01(\x08R\x07lanSyncB\0:\0\"\x94\x03\
+    \n\x0cSyncConflict\x12\x1d\n\tobject_id\x18\x01\x20\x01(\tR\x08objectIdB\
+    \0\x12)\n\x06entity\x18\x02\x20\x01(\x0e2\x0f.SyncEntityTypeR\x06entityB\
+    \0\x12/\n\x08strategy\x18\x03\x20\x01(\x0e2\x11.ConflictStrategyR\x08str\
+    ategyB\0\x125\n\nresolution\x18\x04\x20\x01(\x0e2\x13.ConflictResolution\
+    R\nresolutionB\0\x12\x1f\n\nlocal_time\x18\x05\x20\x01(\x03R\tlocalTimeB\
+    \0\x12!\n\x0bremote_time\x18\x06\x20\x01(\x03R\nremoteTimeB\0\x124\n\rre\
+    mote_change\x18\x07\x20\x01(\x0b2\r.RemoteChangeR\x0cremoteChangeB\0\x12\
+    \x14\n\x04base\x18\x08\x20\x01(\x0cR\x04baseB\0\x12\x16\n\x05local\x18\t\
+    \x20\x01(\x0cR\x05localB\0\x12(\n\x06fields\x18\n\x20\x03(\x0b2\x0e.Conf\
+    lictFieldR\x06fieldsB\0:\0\"\x80\x01\n\rConflictField\x12\x14\n\x04name\
+    \x18\x01\x20\x01(\tR\x04nameB\0\x12\x16\n\x04base\x18\x02\x20\x01(\tH\0R\
+    \x04baseB\0\x12\x16\n\x05local\x18\x03\x20\x01(\tR\x05localB\0\x12\x18\n\
+    \x06remote\x18\x04\x20\x01(\tR\x06remoteB\0B\r\n\x0bone_of_base:\0\"?\n\
+    \x14RepeatedSyncConflict\x12%\n\x05items\x18\x01\x20\x03(\x0b2\r.SyncCon\
+    flictR\x05itemsB\0:\0\"Z\n\x16ResolveConflictRequest\x12\x1d\n\tobject_i\
+    d\x18\x01\x20\x01(\tR\x08objectIdB\0\x12\x1f\n\nkeep_local\x18\x02\x20\
+    \x01(\x08R\tkeepLocalB\0:\0\"t\n\x1fSubmitConflictResolutionRequest\x12\
+    \x1d\n\tobject_id\x18\x01\x20\x01(\tR\x08objectIdB\0\x120\n\x06fields\
+    \x18\x02\x20\x03(\x0b2\x16.ResolvedConflictFieldR\x06fieldsB\0:\0\"G\n\
+    \x15ResolvedConflictField\x12\x14\n\x04name\x18\x01\x20\x01(\tR\x04nameB\
+    \0\x12\x16\n\x05value\x18\x02\x20\x01(\tR\x05valueB\0:\0*^\n\x10Conflict\
+    Strategy\x12\x0f\n\x0bPreferLocal\x10\0\x12\x10\n\x0cPreferRemote\x10\
+    \x01\x12\x12\n\x0eLastWriterWins\x10\x02\x12\x11\n\rSurfaceToUser\x10\
+    \x03\x1a\0*F\n\x0bSyncTrigger\x12\r\n\tAutomatic\x10\0\x12\n\n\x06Manual\
+    \x10\x01\x12\x0c\n\x08Interval\x10\x02\x12\x0c\n\x08OnChange\x10\x03\x1a\
+    \0*4\n\x0eSyncEntityType\x12\r\n\tWorkspace\x10\0\x12\x07\n\x03App\x10\
+    \x01\x12\x08\n\x04View\x10\x02\x1a\0*Q\n\x12ConflictResolution\x12\x0b\n\
+    \x07Pending\x10\0\x12\r\n\tLocalKept\x10\x01\x12\x11\n\rRemoteApplied\
+    \x10\x02\x12\n\n\x06Merged\x10\x03\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
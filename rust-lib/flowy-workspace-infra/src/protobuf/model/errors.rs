@@ -30,6 +30,11 @@ pub enum ErrorCode {
     AppColorStyleInvalid = 2,
     WorkspaceDescTooLong = 3,
     WorkspaceNameTooLong = 4,
+    WorkspaceOwnerRequired = 5,
+    WorkspaceLastOne = 6,
+    WorkspaceArchiveInvalid = 7,
+    WorkspaceSettingKeyInvalid = 8,
+    WorkspaceSettingInvalid = 9,
     AppIdInvalid = 10,
     AppNameInvalid = 11,
     ViewNameInvalid = 20,
@@ -38,8 +43,25 @@ pub enum ErrorCode {
     ViewDescTooLong = 23,
     ViewDataInvalid = 24,
     ViewNameTooLong = 25,
+    ViewParentInvalid = 26,
+    ViewIconTooLong = 27,
+    ViewCoverInvalid = 28,
+    ViewNameConflict = 29,
+    TemplateIdInvalid = 30,
+    TemplateNameInvalid = 31,
+    TemplateNameTooLong = 32,
+    NotionExportInvalid = 33,
+    EnexInvalid = 34,
+    SharedContentInvalid = 35,
+    InboxViewInvalid = 36,
     UserUnauthorized = 100,
+    UserIdIsEmpty = 101,
+    PermissionDenied = 102,
+    MemberEmailInvalid = 103,
+    InvitationSecretInvalid = 104,
     WsConnectError = 200,
+    NetworkUnreachable = 201,
+    SyncConflictFieldInvalid = 202,
     InternalError = 1000,
     RecordNotFound = 1001,
 }
@@ -56,6 +78,11 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             2 => ::std::option::Option::Some(ErrorCode::AppColorStyleInvalid),
             3 => ::std::option::Option::Some(ErrorCode::WorkspaceDescTooLong),
             4 => ::std::option::Option::Some(ErrorCode::WorkspaceNameTooLong),
+            5 => ::std::option::Option::Some(ErrorCode::WorkspaceOwnerRequired),
+            6 => ::std::option::Option::Some(ErrorCode::WorkspaceLastOne),
+            7 => ::std::option::Option::Some(ErrorCode::WorkspaceArchiveInvalid),
+            8 => ::std::option::Option::Some(ErrorCode::WorkspaceSettingKeyInvalid),
+            9 => ::std::option::Option::Some(ErrorCode::WorkspaceSettingInvalid),
             10 => ::std::option::Option::Some(ErrorCode::AppIdInvalid),
             11 => ::std::option::Option::Some(ErrorCode::AppNameInvalid),
             20 => ::std::option::Option::Some(ErrorCode::ViewNameInvalid),
@@ -64,8 +91,25 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             23 => ::std::option::Option::Some(ErrorCode::ViewDescTooLong),
             24 => ::std::option::Option::Some(ErrorCode::ViewDataInvalid),
             25 => ::std::option::Option::Some(ErrorCode::ViewNameTooLong),
+            26 => ::std::option::Option::Some(ErrorCode::ViewParentInvalid),
+            27 => ::std::option::Option::Some(ErrorCode::ViewIconTooLong),
+            28 => ::std::option::Option::Some(ErrorCode::ViewCoverInvalid),
+            29 => ::std::option::Option::Some(ErrorCode::ViewNameConflict),
+            30 => ::std::option::Option::Some(ErrorCode::TemplateIdInvalid),
+            31 => ::std::option::Option::Some(ErrorCode::TemplateNameInvalid),
+            32 => ::std::option::Option::Some(ErrorCode::TemplateNameTooLong),
+            33 => ::std::option::Option::Some(ErrorCode::NotionExportInvalid),
+            34 => ::std::option::Option::Some(ErrorCode::EnexInvalid),
+            35 => ::std::option::Option::Some(ErrorCode::SharedContentInvalid),
+            36 => ::std::option::Option::Some(ErrorCode::InboxViewInvalid),
             100 => ::std::option::Option::Some(ErrorCode::UserUnauthorized),
+            101 => ::std::option::Option::Some(ErrorCode::UserIdIsEmpty),
+            102 => ::std::option::Option::Some(ErrorCode::PermissionDenied),
+            103 => ::std::option::Option::Some(ErrorCode::MemberEmailInvalid),
+            104 => ::std::option::Option::Some(ErrorCode::InvitationSecretInvalid),
             200 => ::std::option::Option::Some(ErrorCode::WsConnectError),
+            201 => ::std::option::Option::Some(ErrorCode::NetworkUnreachable),
+            202 => ::std::option::Option::Some(ErrorCode::SyncConflictFieldInvalid),
             1000 => ::std::option::Option::Some(ErrorCode::InternalError),
             1001 => ::std::option::Option::Some(ErrorCode::RecordNotFound),
             _ => ::std::option::Option::None
@@ -79,6 +123,11 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             ErrorCode::AppColorStyleInvalid,
             ErrorCode::WorkspaceDescTooLong,
             ErrorCode::WorkspaceNameTooLong,
+            ErrorCode::WorkspaceOwnerRequired,
+            ErrorCode::WorkspaceLastOne,
+            ErrorCode::WorkspaceArchiveInvalid,
+            ErrorCode::WorkspaceSettingKeyInvalid,
+            ErrorCode::WorkspaceSettingInvalid,
             ErrorCode::AppIdInvalid,
             ErrorCode::AppNameInvalid,
             ErrorCode::ViewNameInvalid,
@@ -87,8 +136,25 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             ErrorCode::ViewDescTooLong,
             ErrorCode::ViewDataInvalid,
             ErrorCode::ViewNameTooLong,
+            ErrorCode::ViewParentInvalid,
+            ErrorCode::ViewIconTooLong,
+            ErrorCode::ViewCoverInvalid,
+            ErrorCode::ViewNameConflict,
+            ErrorCode::TemplateIdInvalid,
+            ErrorCode::TemplateNameInvalid,
+            ErrorCode::TemplateNameTooLong,
+            ErrorCode::NotionExportInvalid,
+            ErrorCode::EnexInvalid,
+            ErrorCode::SharedContentInvalid,
+            ErrorCode::InboxViewInvalid,
             ErrorCode::UserUnauthorized,
+            ErrorCode::UserIdIsEmpty,
+            ErrorCode::PermissionDenied,
+            ErrorCode::MemberEmailInvalid,
+            ErrorCode::InvitationSecretInvalid,
             ErrorCode::WsConnectError,
+            ErrorCode::NetworkUnreachable,
+            ErrorCode::SyncConflictFieldInvalid,
             ErrorCode::InternalError,
             ErrorCode::RecordNotFound,
         ];
@@ -119,53 +185,29 @@ impl ::protobuf::reflect::ProtobufValue for ErrorCode {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0cerrors.proto*\x86\x03\n\tErrorCode\x12\x18\n\x14WorkspaceNameInval\
+    \n\x0cerrors.proto*\xa5\x07\n\tErrorCode\x12\x18\n\x14WorkspaceNameInval\
     id\x10\0\x12\x16\n\x12WorkspaceIdInvalid\x10\x01\x12\x18\n\x14AppColorSt\
     yleInvalid\x10\x02\x12\x18\n\x14WorkspaceDescTooLong\x10\x03\x12\x18\n\
-    \x14WorkspaceNameTooLong\x10\x04\x12\x10\n\x0cAppIdInvalid\x10\n\x12\x12\
-    \n\x0eAppNameInvalid\x10\x0b\x12\x13\n\x0fViewNameInvalid\x10\x14\x12\
-    \x18\n\x14ViewThumbnailInvalid\x10\x15\x12\x11\n\rViewIdInvalid\x10\x16\
-    \x12\x13\n\x0fViewDescTooLong\x10\x17\x12\x13\n\x0fViewDataInvalid\x10\
-    \x18\x12\x13\n\x0fViewNameTooLong\x10\x19\x12\x14\n\x10UserUnauthorized\
-    \x10d\x12\x13\n\x0eWsConnectError\x10\xc8\x01\x12\x12\n\rInternalError\
-    \x10\xe8\x07\x12\x13\n\x0eRecordNotFound\x10\xe9\x07J\xe3\x05\n\x06\x12\
-    \x04\0\0\x14\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\
-    \x02\0\x14\x01\n\n\n\x03\x05\0\x01\x12\x03\x02\x05\x0e\n\x0b\n\x04\x05\0\
-    \x02\0\x12\x03\x03\x04\x1d\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x04\
-    \x18\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x03\x1b\x1c\n\x0b\n\x04\x05\0\
-    \x02\x01\x12\x03\x04\x04\x1b\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\
-    \x04\x16\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x04\x19\x1a\n\x0b\n\x04\
-    \x05\0\x02\x02\x12\x03\x05\x04\x1d\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\
-    \x05\x04\x18\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x05\x1b\x1c\n\x0b\n\
-    \x04\x05\0\x02\x03\x12\x03\x06\x04\x1d\n\x0c\n\x05\x05\0\x02\x03\x01\x12\
-    \x03\x06\x04\x18\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x06\x1b\x1c\n\x0b\
-    \n\x04\x05\0\x02\x04\x12\x03\x07\x04\x1d\n\x0c\n\x05\x05\0\x02\x04\x01\
-    \x12\x03\x07\x04\x18\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\x07\x1b\x1c\n\
-    \x0b\n\x04\x05\0\x02\x05\x12\x03\x08\x04\x16\n\x0c\n\x05\x05\0\x02\x05\
-    \x01\x12\x03\x08\x04\x10\n\x0c\n\x05\x05\0\x02\x05\x02\x12\x03\x08\x13\
-    \x15\n\x0b\n\x04\x05\0\x02\x06\x12\x03\t\x04\x18\n\x0c\n\x05\x05\0\x02\
-    \x06\x01\x12\x03\t\x04\x12\n\x0c\n\x05\x05\0\x02\x06\x02\x12\x03\t\x15\
-    \x17\n\x0b\n\x04\x05\0\x02\x07\x12\x03\n\x04\x19\n\x0c\n\x05\x05\0\x02\
-    \x07\x01\x12\x03\n\x04\x13\n\x0c\n\x05\x05\0\x02\x07\x02\x12\x03\n\x16\
-    \x18\n\x0b\n\x04\x05\0\x02\x08\x12\x03\x0b\x04\x1e\n\x0c\n\x05\x05\0\x02\
-    \x08\x01\x12\x03\x0b\x04\x18\n\x0c\n\x05\x05\0\x02\x08\x02\x12\x03\x0b\
-    \x1b\x1d\n\x0b\n\x04\x05\0\x02\t\x12\x03\x0c\x04\x17\n\x0c\n\x05\x05\0\
-    \x02\t\x01\x12\x03\x0c\x04\x11\n\x0c\n\x05\x05\0\x02\t\x02\x12\x03\x0c\
-    \x14\x16\n\x0b\n\x04\x05\0\x02\n\x12\x03\r\x04\x19\n\x0c\n\x05\x05\0\x02\
-    \n\x01\x12\x03\r\x04\x13\n\x0c\n\x05\x05\0\x02\n\x02\x12\x03\r\x16\x18\n\
-    \x0b\n\x04\x05\0\x02\x0b\x12\x03\x0e\x04\x19\n\x0c\n\x05\x05\0\x02\x0b\
-    \x01\x12\x03\x0e\x04\x13\n\x0c\n\x05\x05\0\x02\x0b\x02\x12\x03\x0e\x16\
-    \x18\n\x0b\n\x04\x05\0\x02\x0c\x12\x03\x0f\x04\x19\n\x0c\n\x05\x05\0\x02\
-    \x0c\x01\x12\x03\x0f\x04\x13\n\x0c\n\x05\x05\0\x02\x0c\x02\x12\x03\x0f\
-    \x16\x18\n\x0b\n\x04\x05\0\x02\r\x12\x03\x10\x04\x1b\n\x0c\n\x05\x05\0\
-    \x02\r\x01\x12\x03\x10\x04\x14\n\x0c\n\x05\x05\0\x02\r\x02\x12\x03\x10\
-    \x17\x1a\n\x0b\n\x04\x05\0\x02\x0e\x12\x03\x11\x04\x19\n\x0c\n\x05\x05\0\
-    \x02\x0e\x01\x12\x03\x11\x04\x12\n\x0c\n\x05\x05\0\x02\x0e\x02\x12\x03\
-    \x11\x15\x18\n\x0b\n\x04\x05\0\x02\x0f\x12\x03\x12\x04\x19\n\x0c\n\x05\
-    \x05\0\x02\x0f\x01\x12\x03\x12\x04\x11\n\x0c\n\x05\x05\0\x02\x0f\x02\x12\
-    \x03\x12\x14\x18\n\x0b\n\x04\x05\0\x02\x10\x12\x03\x13\x04\x1a\n\x0c\n\
-    \x05\x05\0\x02\x10\x01\x12\x03\x13\x04\x12\n\x0c\n\x05\x05\0\x02\x10\x02\
-    \x12\x03\x13\x15\x19b\x06proto3\
+    \x14WorkspaceNameTooLong\x10\x04\x12\x1a\n\x16WorkspaceOwnerRequired\x10\
+    \x05\x12\x14\n\x10WorkspaceLastOne\x10\x06\x12\x1b\n\x17WorkspaceArchive\
+    Invalid\x10\x07\x12\x1e\n\x1aWorkspaceSettingKeyInvalid\x10\x08\x12\x1b\
+    \n\x17WorkspaceSettingInvalid\x10\t\x12\x10\n\x0cAppIdInvalid\x10\n\x12\
+    \x12\n\x0eAppNameInvalid\x10\x0b\x12\x13\n\x0fViewNameInvalid\x10\x14\
+    \x12\x18\n\x14ViewThumbnailInvalid\x10\x15\x12\x11\n\rViewIdInvalid\x10\
+    \x16\x12\x13\n\x0fViewDescTooLong\x10\x17\x12\x13\n\x0fViewDataInvalid\
+    \x10\x18\x12\x13\n\x0fViewNameTooLong\x10\x19\x12\x15\n\x11ViewParentInv\
+    alid\x10\x1a\x12\x13\n\x0fViewIconTooLong\x10\x1b\x12\x14\n\x10ViewCover\
+    Invalid\x10\x1c\x12\x14\n\x10ViewNameConflict\x10\x1d\x12\x15\n\x11Templ\
+    ateIdInvalid\x10\x1e\x12\x17\n\x13TemplateNameInvalid\x10\x1f\x12\x17\n\
+    \x13TemplateNameTooLong\x10\x20\x12\x17\n\x13NotionExportInvalid\x10!\
+    \x12\x0f\n\x0bEnexInvalid\x10\"\x12\x18\n\x14SharedContentInvalid\x10#\
+    \x12\x14\n\x10InboxViewInvalid\x10$\x12\x14\n\x10UserUnauthorized\x10d\
+    \x12\x11\n\rUserIdIsEmpty\x10e\x12\x14\n\x10PermissionDenied\x10f\x12\
+    \x16\n\x12MemberEmailInvalid\x10g\x12\x1b\n\x17InvitationSecretInvalid\
+    \x10h\x12\x13\n\x0eWsConnectError\x10\xc8\x01\x12\x17\n\x12NetworkUnreac\
+    hable\x10\xc9\x01\x12\x1d\n\x18SyncConflictFieldInvalid\x10\xca\x01\x12\
+    \x12\n\rInternalError\x10\xe8\x07\x12\x13\n\x0eRecordNotFound\x10\xe9\
+    \x07\x1a\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,592 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `evernote.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ImportEnexRequest {
+    // message fields
+    pub paths: ::protobuf::RepeatedField<::std::string::String>,
+    pub tag_mapping: EnexTagMapping,
+    // message oneof groups
+    pub one_of_name: ::std::option::Option<ImportEnexRequest_oneof_one_of_name>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ImportEnexRequest {
+    fn default() -> &'a ImportEnexRequest {
+        <ImportEnexRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ImportEnexRequest_oneof_one_of_name {
+    name(::std::string::String),
+}
+
+impl ImportEnexRequest {
+    pub fn new() -> ImportEnexRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated string paths = 1;
+
+
+    pub fn get_paths(&self) -> &[::std::string::String] {
+        &self.paths
+    }
+    pub fn clear_paths(&mut self) {
+        self.paths.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_paths(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.paths = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_paths(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.paths
+    }
+
+    // Take field
+    pub fn take_paths(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.paths, ::protobuf::RepeatedField::new())
+    }
+
+    // string name = 2;
+
+
+    pub fn get_name(&self) -> &str {
+        match self.one_of_name {
+            ::std::option::Option::Some(ImportEnexRequest_oneof_one_of_name::name(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_name(&mut self) {
+        self.one_of_name = ::std::option::Option::None;
+    }
+
+    pub fn has_name(&self) -> bool {
+        match self.one_of_name {
+            ::std::option::Option::Some(ImportEnexRequest_oneof_one_of_name::name(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.one_of_name = ::std::option::Option::Some(ImportEnexRequest_oneof_one_of_name::name(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ImportEnexRequest_oneof_one_of_name::name(_)) = self.one_of_name {
+        } else {
+            self.one_of_name = ::std::option::Option::Some(ImportEnexRequest_oneof_one_of_name::name(::std::string::String::new()));
+        }
+        match self.one_of_name {
+            ::std::option::Option::Some(ImportEnexRequest_oneof_one_of_name::name(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        if self.has_name() {
+            match self.one_of_name.take() {
+                ::std::option::Option::Some(ImportEnexRequest_oneof_one_of_name::name(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // .EnexTagMapping tag_mapping = 3;
+
+
+    pub fn get_tag_mapping(&self) -> EnexTagMapping {
+        self.tag_mapping
+    }
+    pub fn clear_tag_mapping(&mut self) {
+        self.tag_mapping = EnexTagMapping::Grid;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tag_mapping(&mut self, v: EnexTagMapping) {
+        self.tag_mapping = v;
+    }
+}
+
+impl ::protobuf::Message for ImportEnexRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.paths)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_name = ::std::option::Option::Some(ImportEnexRequest_oneof_one_of_name::name(is.read_string()?));
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.tag_mapping, 3, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.paths {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        if self.tag_mapping != EnexTagMapping::Grid {
+            my_size += ::protobuf::rt::enum_size(3, self.tag_mapping);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &ImportEnexRequest_oneof_one_of_name::name(ref v) => {
+                    my_size += ::protobuf::rt::string_size(2, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.paths {
+            os.write_string(1, &v)?;
+        };
+        if self.tag_mapping != EnexTagMapping::Grid {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.tag_mapping))?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &ImportEnexRequest_oneof_one_of_name::name(ref v) => {
+                    os.write_string(2, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ImportEnexRequest {
+        ImportEnexRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "paths",
+                |m: &ImportEnexRequest| { &m.paths },
+                |m: &mut ImportEnexRequest| { &mut m.paths },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "name",
+                ImportEnexRequest::has_name,
+                ImportEnexRequest::get_name,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<EnexTagMapping>>(
+                "tag_mapping",
+                |m: &ImportEnexRequest| { &m.tag_mapping },
+                |m: &mut ImportEnexRequest| { &mut m.tag_mapping },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ImportEnexRequest>(
+                "ImportEnexRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ImportEnexRequest {
+        static instance: ::protobuf::rt::LazyV2<ImportEnexRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ImportEnexRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ImportEnexRequest {
+    fn clear(&mut self) {
+        self.paths.clear();
+        self.one_of_name = ::std::option::Option::None;
+        self.tag_mapping = EnexTagMapping::Grid;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ImportEnexRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ImportEnexRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ImportEnexResult {
+    // message fields
+    pub workspace: ::protobuf::SingularPtrField<super::workspace_create::Workspace>,
+    pub failures: ::protobuf::RepeatedField<super::archive::ImportFailure>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ImportEnexResult {
+    fn default() -> &'a ImportEnexResult {
+        <ImportEnexResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ImportEnexResult {
+    pub fn new() -> ImportEnexResult {
+        ::std::default::Default::default()
+    }
+
+    // .Workspace workspace = 1;
+
+
+    pub fn get_workspace(&self) -> &super::workspace_create::Workspace {
+        self.workspace.as_ref().unwrap_or_else(|| <super::workspace_create::Workspace as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_workspace(&mut self) {
+        self.workspace.clear();
+    }
+
+    pub fn has_workspace(&self) -> bool {
+        self.workspace.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace(&mut self, v: super::workspace_create::Workspace) {
+        self.workspace = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace(&mut self) -> &mut super::workspace_create::Workspace {
+        if self.workspace.is_none() {
+            self.workspace.set_default();
+        }
+        self.workspace.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_workspace(&mut self) -> super::workspace_create::Workspace {
+        self.workspace.take().unwrap_or_else(|| super::workspace_create::Workspace::new())
+    }
+
+    // repeated .ImportFailure failures = 2;
+
+
+    pub fn get_failures(&self) -> &[super::archive::ImportFailure] {
+        &self.failures
+    }
+    pub fn clear_failures(&mut self) {
+        self.failures.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_failures(&mut self, v: ::protobuf::RepeatedField<super::archive::ImportFailure>) {
+        self.failures = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_failures(&mut self) -> &mut ::protobuf::RepeatedField<super::archive::ImportFailure> {
+        &mut self.failures
+    }
+
+    // Take field
+    pub fn take_failures(&mut self) -> ::protobuf::RepeatedField<super::archive::ImportFailure> {
+        ::std::mem::replace(&mut self.failures, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for ImportEnexResult {
+    fn is_initialized(&self) -> bool {
+        for v in &self.workspace {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.failures {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.workspace)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.failures)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.workspace.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.failures {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.workspace.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.failures {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ImportEnexResult {
+        ImportEnexResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::workspace_create::Workspace>>(
+                "workspace",
+                |m: &ImportEnexResult| { &m.workspace },
+                |m: &mut ImportEnexResult| { &mut m.workspace },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::archive::ImportFailure>>(
+                "failures",
+                |m: &ImportEnexResult| { &m.failures },
+                |m: &mut ImportEnexResult| { &mut m.failures },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ImportEnexResult>(
+                "ImportEnexResult",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ImportEnexResult {
+        static instance: ::protobuf::rt::LazyV2<ImportEnexResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ImportEnexResult::new)
+    }
+}
+
+impl ::protobuf::Clear for ImportEnexResult {
+    fn clear(&mut self) {
+        self.workspace.clear();
+        self.failures.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ImportEnexResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ImportEnexResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum EnexTagMapping {
+    Grid = 0,
+    Metadata = 1,
+}
+
+impl ::protobuf::ProtobufEnum for EnexTagMapping {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<EnexTagMapping> {
+        match value {
+            0 => ::std::option::Option::Some(EnexTagMapping::Grid),
+            1 => ::std::option::Option::Some(EnexTagMapping::Metadata),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [EnexTagMapping] = &[
+            EnexTagMapping::Grid,
+            EnexTagMapping::Metadata,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<EnexTagMapping>("EnexTagMapping", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for EnexTagMapping {
+}
+
+impl ::std::default::Default for EnexTagMapping {
+    fn default() -> Self {
+        EnexTagMapping::Grid
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EnexTagMapping {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0eevernote.proto\x1a\x16workspace_create.proto\x1a\rarchive.proto\"\
+    \x88\x01\n\x11ImportEnexRequest\x12\x16\n\x05paths\x18\x01\x20\x03(\tR\
+    \x05pathsB\0\x12\x16\n\x04name\x18\x02\x20\x01(\tH\0R\x04nameB\0\x122\n\
+    \x0btag_mapping\x18\x03\x20\x01(\x0e2\x0f.EnexTagMappingR\ntagMappingB\0\
+    B\r\n\x0bone_of_name:\0\"n\n\x10ImportEnexResult\x12*\n\tworkspace\x18\
+    \x01\x20\x01(\x0b2\n.WorkspaceR\tworkspaceB\0\x12,\n\x08failures\x18\x02\
+    \x20\x03(\x0b2\x0e.ImportFailureR\x08failuresB\0:\0**\n\x0eEnexTagMappin\
+    g\x12\x08\n\x04Grid\x10\0\x12\x0c\n\x08Metadata\x10\x01\x1a\0B\0b\x06pro\
+    to3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
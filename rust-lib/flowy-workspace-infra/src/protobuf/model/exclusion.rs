@@ -0,0 +1,938 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `exclusion.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SyncExclusionRequest {
+    // message fields
+    pub object_id: ::std::string::String,
+    pub entity: super::conflict::SyncEntityType,
+    pub excluded: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SyncExclusionRequest {
+    fn default() -> &'a SyncExclusionRequest {
+        <SyncExclusionRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SyncExclusionRequest {
+    pub fn new() -> SyncExclusionRequest {
+        ::std::default::Default::default()
+    }
+
+    // string object_id = 1;
+
+
+    pub fn get_object_id(&self) -> &str {
+        &self.object_id
+    }
+    pub fn clear_object_id(&mut self) {
+        self.object_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object_id(&mut self, v: ::std::string::String) {
+        self.object_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_object_id(&mut self) -> &mut ::std::string::String {
+        &mut self.object_id
+    }
+
+    // Take field
+    pub fn take_object_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.object_id, ::std::string::String::new())
+    }
+
+    // .SyncEntityType entity = 2;
+
+
+    pub fn get_entity(&self) -> super::conflict::SyncEntityType {
+        self.entity
+    }
+    pub fn clear_entity(&mut self) {
+        self.entity = super::conflict::SyncEntityType::Workspace;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_entity(&mut self, v: super::conflict::SyncEntityType) {
+        self.entity = v;
+    }
+
+    // bool excluded = 3;
+
+
+    pub fn get_excluded(&self) -> bool {
+        self.excluded
+    }
+    pub fn clear_excluded(&mut self) {
+        self.excluded = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_excluded(&mut self, v: bool) {
+        self.excluded = v;
+    }
+}
+
+impl ::protobuf::Message for SyncExclusionRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.object_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.entity, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.excluded = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.object_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.object_id);
+        }
+        if self.entity != super::conflict::SyncEntityType::Workspace {
+            my_size += ::protobuf::rt::enum_size(2, self.entity);
+        }
+        if self.excluded != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.object_id.is_empty() {
+            os.write_string(1, &self.object_id)?;
+        }
+        if self.entity != super::conflict::SyncEntityType::Workspace {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.entity))?;
+        }
+        if self.excluded != false {
+            os.write_bool(3, self.excluded)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SyncExclusionRequest {
+        SyncExclusionRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "object_id",
+                |m: &SyncExclusionRequest| { &m.object_id },
+                |m: &mut SyncExclusionRequest| { &mut m.object_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::conflict::SyncEntityType>>(
+                "entity",
+                |m: &SyncExclusionRequest| { &m.entity },
+                |m: &mut SyncExclusionRequest| { &mut m.entity },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "excluded",
+                |m: &SyncExclusionRequest| { &m.excluded },
+                |m: &mut SyncExclusionRequest| { &mut m.excluded },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SyncExclusionRequest>(
+                "SyncExclusionRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SyncExclusionRequest {
+        static instance: ::protobuf::rt::LazyV2<SyncExclusionRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SyncExclusionRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SyncExclusionRequest {
+    fn clear(&mut self) {
+        self.object_id.clear();
+        self.entity = super::conflict::SyncEntityType::Workspace;
+        self.excluded = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SyncExclusionRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyncExclusionRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SyncExclusion {
+    // message fields
+    pub object_id: ::std::string::String,
+    pub entity: super::conflict::SyncEntityType,
+    pub exclude_time: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SyncExclusion {
+    fn default() -> &'a SyncExclusion {
+        <SyncExclusion as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SyncExclusion {
+    pub fn new() -> SyncExclusion {
+        ::std::default::Default::default()
+    }
+
+    // string object_id = 1;
+
+
+    pub fn get_object_id(&self) -> &str {
+        &self.object_id
+    }
+    pub fn clear_object_id(&mut self) {
+        self.object_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object_id(&mut self, v: ::std::string::String) {
+        self.object_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_object_id(&mut self) -> &mut ::std::string::String {
+        &mut self.object_id
+    }
+
+    // Take field
+    pub fn take_object_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.object_id, ::std::string::String::new())
+    }
+
+    // .SyncEntityType entity = 2;
+
+
+    pub fn get_entity(&self) -> super::conflict::SyncEntityType {
+        self.entity
+    }
+    pub fn clear_entity(&mut self) {
+        self.entity = super::conflict::SyncEntityType::Workspace;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_entity(&mut self, v: super::conflict::SyncEntityType) {
+        self.entity = v;
+    }
+
+    // int64 exclude_time = 3;
+
+
+    pub fn get_exclude_time(&self) -> i64 {
+        self.exclude_time
+    }
+    pub fn clear_exclude_time(&mut self) {
+        self.exclude_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exclude_time(&mut self, v: i64) {
+        self.exclude_time = v;
+    }
+}
+
+impl ::protobuf::Message for SyncExclusion {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.object_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.entity, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.exclude_time = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.object_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.object_id);
+        }
+        if self.entity != super::conflict::SyncEntityType::Workspace {
+            my_size += ::protobuf::rt::enum_size(2, self.entity);
+        }
+        if self.exclude_time != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.exclude_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.object_id.is_empty() {
+            os.write_string(1, &self.object_id)?;
+        }
+        if self.entity != super::conflict::SyncEntityType::Workspace {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.entity))?;
+        }
+        if self.exclude_time != 0 {
+            os.write_int64(3, self.exclude_time)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SyncExclusion {
+        SyncExclusion::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "object_id",
+                |m: &SyncExclusion| { &m.object_id },
+                |m: &mut SyncExclusion| { &mut m.object_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::conflict::SyncEntityType>>(
+                "entity",
+                |m: &SyncExclusion| { &m.entity },
+                |m: &mut SyncExclusion| { &mut m.entity },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "exclude_time",
+                |m: &SyncExclusion| { &m.exclude_time },
+                |m: &mut SyncExclusion| { &mut m.exclude_time },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SyncExclusion>(
+                "SyncExclusion",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SyncExclusion {
+        static instance: ::protobuf::rt::LazyV2<SyncExclusion> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SyncExclusion::new)
+    }
+}
+
+impl ::protobuf::Clear for SyncExclusion {
+    fn clear(&mut self) {
+        self.object_id.clear();
+        self.entity = super::conflict::SyncEntityType::Workspace;
+        self.exclude_time = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SyncExclusion {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyncExclusion {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedSyncExclusion {
+    // message fields
+    pub items: ::protobuf::RepeatedField<SyncExclusion>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedSyncExclusion {
+    fn default() -> &'a RepeatedSyncExclusion {
+        <RepeatedSyncExclusion as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedSyncExclusion {
+    pub fn new() -> RepeatedSyncExclusion {
+        ::std::default::Default::default()
+    }
+
+    // repeated .SyncExclusion items = 1;
+
+
+    pub fn get_items(&self) -> &[SyncExclusion] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<SyncExclusion>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<SyncExclusion> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<SyncExclusion> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedSyncExclusion {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedSyncExclusion {
+        RepeatedSyncExclusion::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SyncExclusion>>(
+                "items",
+                |m: &RepeatedSyncExclusion| { &m.items },
+                |m: &mut RepeatedSyncExclusion| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedSyncExclusion>(
+                "RepeatedSyncExclusion",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedSyncExclusion {
+        static instance: ::protobuf::rt::LazyV2<RepeatedSyncExclusion> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedSyncExclusion::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedSyncExclusion {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedSyncExclusion {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedSyncExclusion {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SyncBackfill {
+    // message fields
+    pub object_id: ::std::string::String,
+    pub total: i64,
+    pub done: i64,
+    pub error: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SyncBackfill {
+    fn default() -> &'a SyncBackfill {
+        <SyncBackfill as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SyncBackfill {
+    pub fn new() -> SyncBackfill {
+        ::std::default::Default::default()
+    }
+
+    // string object_id = 1;
+
+
+    pub fn get_object_id(&self) -> &str {
+        &self.object_id
+    }
+    pub fn clear_object_id(&mut self) {
+        self.object_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object_id(&mut self, v: ::std::string::String) {
+        self.object_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_object_id(&mut self) -> &mut ::std::string::String {
+        &mut self.object_id
+    }
+
+    // Take field
+    pub fn take_object_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.object_id, ::std::string::String::new())
+    }
+
+    // int64 total = 2;
+
+
+    pub fn get_total(&self) -> i64 {
+        self.total
+    }
+    pub fn clear_total(&mut self) {
+        self.total = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total(&mut self, v: i64) {
+        self.total = v;
+    }
+
+    // int64 done = 3;
+
+
+    pub fn get_done(&self) -> i64 {
+        self.done
+    }
+    pub fn clear_done(&mut self) {
+        self.done = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_done(&mut self, v: i64) {
+        self.done = v;
+    }
+
+    // string error = 4;
+
+
+    pub fn get_error(&self) -> &str {
+        &self.error
+    }
+    pub fn clear_error(&mut self) {
+        self.error.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.error = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        &mut self.error
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for SyncBackfill {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.object_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.total = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.done = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.object_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.object_id);
+        }
+        if self.total != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.total, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.done != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.done, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.error);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.object_id.is_empty() {
+            os.write_string(1, &self.object_id)?;
+        }
+        if self.total != 0 {
+            os.write_int64(2, self.total)?;
+        }
+        if self.done != 0 {
+            os.write_int64(3, self.done)?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(4, &self.error)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SyncBackfill {
+        SyncBackfill::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "object_id",
+                |m: &SyncBackfill| { &m.object_id },
+                |m: &mut SyncBackfill| { &mut m.object_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "total",
+                |m: &SyncBackfill| { &m.total },
+                |m: &mut SyncBackfill| { &mut m.total },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "done",
+                |m: &SyncBackfill| { &m.done },
+                |m: &mut SyncBackfill| { &mut m.done },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "error",
+                |m: &SyncBackfill| { &m.error },
+                |m: &mut SyncBackfill| { &mut m.error },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SyncBackfill>(
+                "SyncBackfill",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SyncBackfill {
+        static instance: ::protobuf::rt::LazyV2<SyncBackfill> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SyncBackfill::new)
+    }
+}
+
+impl ::protobuf::Clear for SyncBackfill {
+    fn clear(&mut self) {
+        self.object_id.clear();
+        self.total = 0;
+        self.done = 0;
+        self.error.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SyncBackfill {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyncBackfill {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0fexclusion.proto\x1a\x0econflict.proto\"\x80\x01\n\x14SyncExclusion\
+    Request\x12\x1d\n\tobject_id\x18\x01\x20\x01(\tR\x08objectIdB\0\x12)\n\
+    \x06entity\x18\x02\x20\x01(\x0e2\x0f.SyncEntityTypeR\x06entityB\0\x12\
+    \x1c\n\x08excluded\x18\x03\x20\x01(\x08R\x08excludedB\0:\0\"\x80\x01\n\r\
+    SyncExclusion\x12\x1d\n\tobject_id\x18\x01\x20\x01(\tR\x08objectIdB\0\
+    \x12)\n\x06entity\x18\x02\x20\x01(\x0e2\x0f.SyncEntityTypeR\x06entityB\0\
+    \x12#\n\x0cexclude_time\x18\x03\x20\x01(\x03R\x0bexcludeTimeB\0:\0\"A\n\
+    \x15RepeatedSyncExclusion\x12&\n\x05items\x18\x01\x20\x03(\x0b2\x0e.Sync\
+    ExclusionR\x05itemsB\0:\0\"u\n\x0cSyncBackfill\x12\x1d\n\tobject_id\x18\
+    \x01\x20\x01(\tR\x08objectIdB\0\x12\x16\n\x05total\x18\x02\x20\x01(\x03R\
+    \x05totalB\0\x12\x14\n\x04done\x18\x03\x20\x01(\x03R\x04doneB\0\x12\x16\
+    \n\x05error\x18\x04\x20\x01(\tR\x05errorB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
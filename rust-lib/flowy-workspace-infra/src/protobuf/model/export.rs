@@ -218,6 +218,7 @@ pub struct ExportData {
     // message fields
     pub data: ::std::string::String,
     pub export_type: ExportType,
+    pub path: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -274,6 +275,32 @@ impl ExportData {
     pub fn set_export_type(&mut self, v: ExportType) {
         self.export_type = v;
     }
+
+    // string path = 3;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
 }
 
 impl ::protobuf::Message for ExportData {
@@ -291,6 +318,9 @@ impl ::protobuf::Message for ExportData {
                 2 => {
                     ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.export_type, 2, &mut self.unknown_fields)?
                 },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -309,6 +339,9 @@ impl ::protobuf::Message for ExportData {
         if self.export_type != ExportType::Text {
             my_size += ::protobuf::rt::enum_size(2, self.export_type);
         }
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.path);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -321,6 +354,9 @@ impl ::protobuf::Message for ExportData {
         if self.export_type != ExportType::Text {
             os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.export_type))?;
         }
+        if !self.path.is_empty() {
+            os.write_string(3, &self.path)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -369,6 +405,11 @@ impl ::protobuf::Message for ExportData {
                 |m: &ExportData| { &m.export_type },
                 |m: &mut ExportData| { &mut m.export_type },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "path",
+                |m: &ExportData| { &m.path },
+                |m: &mut ExportData| { &mut m.path },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExportData>(
                 "ExportData",
                 fields,
@@ -387,6 +428,7 @@ impl ::protobuf::Clear for ExportData {
     fn clear(&mut self) {
         self.data.clear();
         self.export_type = ExportType::Text;
+        self.path.clear();
         self.unknown_fields.clear();
     }
 }
@@ -408,6 +450,7 @@ pub enum ExportType {
     Text = 0,
     Markdown = 1,
     Link = 2,
+    Archive = 3,
 }
 
 impl ::protobuf::ProtobufEnum for ExportType {
@@ -420,6 +463,7 @@ impl ::protobuf::ProtobufEnum for ExportType {
             0 => ::std::option::Option::Some(ExportType::Text),
             1 => ::std::option::Option::Some(ExportType::Markdown),
             2 => ::std::option::Option::Some(ExportType::Link),
+            3 => ::std::option::Option::Some(ExportType::Archive),
             _ => ::std::option::Option::None
         }
     }
@@ -429,6 +473,7 @@ impl ::protobuf::ProtobufEnum for ExportType {
             ExportType::Text,
             ExportType::Markdown,
             ExportType::Link,
+            ExportType::Archive,
         ];
         values
     }
@@ -457,33 +502,14 @@ impl ::protobuf::reflect::ProtobufValue for ExportType {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0cexport.proto\"T\n\rExportRequest\x12\x15\n\x06doc_id\x18\x01\x20\
-    \x01(\tR\x05docId\x12,\n\x0bexport_type\x18\x02\x20\x01(\x0e2\x0b.Export\
-    TypeR\nexportType\"N\n\nExportData\x12\x12\n\x04data\x18\x01\x20\x01(\tR\
-    \x04data\x12,\n\x0bexport_type\x18\x02\x20\x01(\x0e2\x0b.ExportTypeR\nex\
-    portType*.\n\nExportType\x12\x08\n\x04Text\x10\0\x12\x0c\n\x08Markdown\
-    \x10\x01\x12\x08\n\x04Link\x10\x02J\xb1\x03\n\x06\x12\x04\0\0\x0e\x01\n\
-    \x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x05\x01\n\n\
-    \n\x03\x04\0\x01\x12\x03\x02\x08\x15\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\
-    \x04\x16\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\
-    \x02\0\x01\x12\x03\x03\x0b\x11\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\
-    \x14\x15\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x1f\n\x0c\n\x05\x04\0\
-    \x02\x01\x06\x12\x03\x04\x04\x0e\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\
-    \x04\x0f\x1a\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x1d\x1e\n\n\n\x02\
-    \x04\x01\x12\x04\x06\0\t\x01\n\n\n\x03\x04\x01\x01\x12\x03\x06\x08\x12\n\
-    \x0b\n\x04\x04\x01\x02\0\x12\x03\x07\x04\x14\n\x0c\n\x05\x04\x01\x02\0\
-    \x05\x12\x03\x07\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x07\x0b\x0f\
-    \n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x07\x12\x13\n\x0b\n\x04\x04\x01\
-    \x02\x01\x12\x03\x08\x04\x1f\n\x0c\n\x05\x04\x01\x02\x01\x06\x12\x03\x08\
-    \x04\x0e\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x08\x0f\x1a\n\x0c\n\x05\
-    \x04\x01\x02\x01\x03\x12\x03\x08\x1d\x1e\n\n\n\x02\x05\0\x12\x04\n\0\x0e\
-    \x01\n\n\n\x03\x05\0\x01\x12\x03\n\x05\x0f\n\x0b\n\x04\x05\0\x02\0\x12\
-    \x03\x0b\x04\r\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x0b\x04\x08\n\x0c\n\
-    \x05\x05\0\x02\0\x02\x12\x03\x0b\x0b\x0c\n\x0b\n\x04\x05\0\x02\x01\x12\
-    \x03\x0c\x04\x11\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x0c\x04\x0c\n\x0c\
-    \n\x05\x05\0\x02\x01\x02\x12\x03\x0c\x0f\x10\n\x0b\n\x04\x05\0\x02\x02\
-    \x12\x03\r\x04\r\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\r\x04\x08\n\x0c\n\
-    \x05\x05\0\x02\x02\x02\x12\x03\r\x0b\x0cb\x06proto3\
+    \n\x0cexport.proto\"Z\n\rExportRequest\x12\x17\n\x06doc_id\x18\x01\x20\
+    \x01(\tR\x05docIdB\0\x12.\n\x0bexport_type\x18\x02\x20\x01(\x0e2\x0b.Exp\
+    ortTypeR\nexportTypeB\0:\0\"j\n\nExportData\x12\x14\n\x04data\x18\x01\
+    \x20\x01(\tR\x04dataB\0\x12.\n\x0bexport_type\x18\x02\x20\x01(\x0e2\x0b.\
+    ExportTypeR\nexportTypeB\0\x12\x14\n\x04path\x18\x03\x20\x01(\tR\x04path\
+    B\0:\0*=\n\nExportType\x12\x08\n\x04Text\x10\0\x12\x0c\n\x08Markdown\x10\
+    \x01\x12\x08\n\x04Link\x10\x02\x12\x0b\n\x07Archive\x10\x03\x1a\0B\0b\
+    \x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,832 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `ingest.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct IngestSharedContentRequest {
+    // message fields
+    pub content_type: SharedContentType,
+    pub text: ::std::string::String,
+    pub data: ::std::vec::Vec<u8>,
+    pub mime_type: ::std::string::String,
+    pub target: IngestTarget,
+    // message oneof groups
+    pub one_of_name: ::std::option::Option<IngestSharedContentRequest_oneof_one_of_name>,
+    pub one_of_belong_to_id: ::std::option::Option<IngestSharedContentRequest_oneof_one_of_belong_to_id>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a IngestSharedContentRequest {
+    fn default() -> &'a IngestSharedContentRequest {
+        <IngestSharedContentRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum IngestSharedContentRequest_oneof_one_of_name {
+    name(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum IngestSharedContentRequest_oneof_one_of_belong_to_id {
+    belong_to_id(::std::string::String),
+}
+
+impl IngestSharedContentRequest {
+    pub fn new() -> IngestSharedContentRequest {
+        ::std::default::Default::default()
+    }
+
+    // .SharedContentType content_type = 1;
+
+
+    pub fn get_content_type(&self) -> SharedContentType {
+        self.content_type
+    }
+    pub fn clear_content_type(&mut self) {
+        self.content_type = SharedContentType::Text;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content_type(&mut self, v: SharedContentType) {
+        self.content_type = v;
+    }
+
+    // string text = 2;
+
+
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+    pub fn clear_text(&mut self) {
+        self.text.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_text(&mut self, v: ::std::string::String) {
+        self.text = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_text(&mut self) -> &mut ::std::string::String {
+        &mut self.text
+    }
+
+    // Take field
+    pub fn take_text(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.text, ::std::string::String::new())
+    }
+
+    // bytes data = 3;
+
+
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
+    }
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.data
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
+    }
+
+    // string mime_type = 4;
+
+
+    pub fn get_mime_type(&self) -> &str {
+        &self.mime_type
+    }
+    pub fn clear_mime_type(&mut self) {
+        self.mime_type.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mime_type(&mut self, v: ::std::string::String) {
+        self.mime_type = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_mime_type(&mut self) -> &mut ::std::string::String {
+        &mut self.mime_type
+    }
+
+    // Take field
+    pub fn take_mime_type(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.mime_type, ::std::string::String::new())
+    }
+
+    // string name = 5;
+
+
+    pub fn get_name(&self) -> &str {
+        match self.one_of_name {
+            ::std::option::Option::Some(IngestSharedContentRequest_oneof_one_of_name::name(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_name(&mut self) {
+        self.one_of_name = ::std::option::Option::None;
+    }
+
+    pub fn has_name(&self) -> bool {
+        match self.one_of_name {
+            ::std::option::Option::Some(IngestSharedContentRequest_oneof_one_of_name::name(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.one_of_name = ::std::option::Option::Some(IngestSharedContentRequest_oneof_one_of_name::name(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(IngestSharedContentRequest_oneof_one_of_name::name(_)) = self.one_of_name {
+        } else {
+            self.one_of_name = ::std::option::Option::Some(IngestSharedContentRequest_oneof_one_of_name::name(::std::string::String::new()));
+        }
+        match self.one_of_name {
+            ::std::option::Option::Some(IngestSharedContentRequest_oneof_one_of_name::name(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        if self.has_name() {
+            match self.one_of_name.take() {
+                ::std::option::Option::Some(IngestSharedContentRequest_oneof_one_of_name::name(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // .IngestTarget target = 6;
+
+
+    pub fn get_target(&self) -> IngestTarget {
+        self.target
+    }
+    pub fn clear_target(&mut self) {
+        self.target = IngestTarget::NewDocument;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: IngestTarget) {
+        self.target = v;
+    }
+
+    // string belong_to_id = 7;
+
+
+    pub fn get_belong_to_id(&self) -> &str {
+        match self.one_of_belong_to_id {
+            ::std::option::Option::Some(IngestSharedContentRequest_oneof_one_of_belong_to_id::belong_to_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_belong_to_id(&mut self) {
+        self.one_of_belong_to_id = ::std::option::Option::None;
+    }
+
+    pub fn has_belong_to_id(&self) -> bool {
+        match self.one_of_belong_to_id {
+            ::std::option::Option::Some(IngestSharedContentRequest_oneof_one_of_belong_to_id::belong_to_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_belong_to_id(&mut self, v: ::std::string::String) {
+        self.one_of_belong_to_id = ::std::option::Option::Some(IngestSharedContentRequest_oneof_one_of_belong_to_id::belong_to_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_belong_to_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(IngestSharedContentRequest_oneof_one_of_belong_to_id::belong_to_id(_)) = self.one_of_belong_to_id {
+        } else {
+            self.one_of_belong_to_id = ::std::option::Option::Some(IngestSharedContentRequest_oneof_one_of_belong_to_id::belong_to_id(::std::string::String::new()));
+        }
+        match self.one_of_belong_to_id {
+            ::std::option::Option::Some(IngestSharedContentRequest_oneof_one_of_belong_to_id::belong_to_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_belong_to_id(&mut self) -> ::std::string::String {
+        if self.has_belong_to_id() {
+            match self.one_of_belong_to_id.take() {
+                ::std::option::Option::Some(IngestSharedContentRequest_oneof_one_of_belong_to_id::belong_to_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for IngestSharedContentRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.content_type, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.text)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.mime_type)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_name = ::std::option::Option::Some(IngestSharedContentRequest_oneof_one_of_name::name(is.read_string()?));
+                },
+                6 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.target, 6, &mut self.unknown_fields)?
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_belong_to_id = ::std::option::Option::Some(IngestSharedContentRequest_oneof_one_of_belong_to_id::belong_to_id(is.read_string()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.content_type != SharedContentType::Text {
+            my_size += ::protobuf::rt::enum_size(1, self.content_type);
+        }
+        if !self.text.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.text);
+        }
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.data);
+        }
+        if !self.mime_type.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.mime_type);
+        }
+        if self.target != IngestTarget::NewDocument {
+            my_size += ::protobuf::rt::enum_size(6, self.target);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &IngestSharedContentRequest_oneof_one_of_name::name(ref v) => {
+                    my_size += ::protobuf::rt::string_size(5, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_belong_to_id {
+            match v {
+                &IngestSharedContentRequest_oneof_one_of_belong_to_id::belong_to_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(7, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.content_type != SharedContentType::Text {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.content_type))?;
+        }
+        if !self.text.is_empty() {
+            os.write_string(2, &self.text)?;
+        }
+        if !self.data.is_empty() {
+            os.write_bytes(3, &self.data)?;
+        }
+        if !self.mime_type.is_empty() {
+            os.write_string(4, &self.mime_type)?;
+        }
+        if self.target != IngestTarget::NewDocument {
+            os.write_enum(6, ::protobuf::ProtobufEnum::value(&self.target))?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &IngestSharedContentRequest_oneof_one_of_name::name(ref v) => {
+                    os.write_string(5, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_belong_to_id {
+            match v {
+                &IngestSharedContentRequest_oneof_one_of_belong_to_id::belong_to_id(ref v) => {
+                    os.write_string(7, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> IngestSharedContentRequest {
+        IngestSharedContentRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<SharedContentType>>(
+                "content_type",
+                |m: &IngestSharedContentRequest| { &m.content_type },
+                |m: &mut IngestSharedContentRequest| { &mut m.content_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "text",
+                |m: &IngestSharedContentRequest| { &m.text },
+                |m: &mut IngestSharedContentRequest| { &mut m.text },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "data",
+                |m: &IngestSharedContentRequest| { &m.data },
+                |m: &mut IngestSharedContentRequest| { &mut m.data },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "mime_type",
+                |m: &IngestSharedContentRequest| { &m.mime_type },
+                |m: &mut IngestSharedContentRequest| { &mut m.mime_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "name",
+                IngestSharedContentRequest::has_name,
+                IngestSharedContentRequest::get_name,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<IngestTarget>>(
+                "target",
+                |m: &IngestSharedContentRequest| { &m.target },
+                |m: &mut IngestSharedContentRequest| { &mut m.target },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "belong_to_id",
+                IngestSharedContentRequest::has_belong_to_id,
+                IngestSharedContentRequest::get_belong_to_id,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<IngestSharedContentRequest>(
+                "IngestSharedContentRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static IngestSharedContentRequest {
+        static instance: ::protobuf::rt::LazyV2<IngestSharedContentRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(IngestSharedContentRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for IngestSharedContentRequest {
+    fn clear(&mut self) {
+        self.content_type = SharedContentType::Text;
+        self.text.clear();
+        self.data.clear();
+        self.mime_type.clear();
+        self.one_of_name = ::std::option::Option::None;
+        self.target = IngestTarget::NewDocument;
+        self.one_of_belong_to_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for IngestSharedContentRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for IngestSharedContentRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct IngestSharedContentResult {
+    // message fields
+    pub view: ::protobuf::SingularPtrField<super::view_create::View>,
+    pub appended: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a IngestSharedContentResult {
+    fn default() -> &'a IngestSharedContentResult {
+        <IngestSharedContentResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl IngestSharedContentResult {
+    pub fn new() -> IngestSharedContentResult {
+        ::std::default::Default::default()
+    }
+
+    // .View view = 1;
+
+
+    pub fn get_view(&self) -> &super::view_create::View {
+        self.view.as_ref().unwrap_or_else(|| <super::view_create::View as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_view(&mut self) {
+        self.view.clear();
+    }
+
+    pub fn has_view(&self) -> bool {
+        self.view.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view(&mut self, v: super::view_create::View) {
+        self.view = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view(&mut self) -> &mut super::view_create::View {
+        if self.view.is_none() {
+            self.view.set_default();
+        }
+        self.view.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_view(&mut self) -> super::view_create::View {
+        self.view.take().unwrap_or_else(|| super::view_create::View::new())
+    }
+
+    // bool appended = 2;
+
+
+    pub fn get_appended(&self) -> bool {
+        self.appended
+    }
+    pub fn clear_appended(&mut self) {
+        self.appended = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_appended(&mut self, v: bool) {
+        self.appended = v;
+    }
+}
+
+impl ::protobuf::Message for IngestSharedContentResult {
+    fn is_initialized(&self) -> bool {
+        for v in &self.view {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.view)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.appended = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.view.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.appended != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.view.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.appended != false {
+            os.write_bool(2, self.appended)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> IngestSharedContentResult {
+        IngestSharedContentResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::view_create::View>>(
+                "view",
+                |m: &IngestSharedContentResult| { &m.view },
+                |m: &mut IngestSharedContentResult| { &mut m.view },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "appended",
+                |m: &IngestSharedContentResult| { &m.appended },
+                |m: &mut IngestSharedContentResult| { &mut m.appended },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<IngestSharedContentResult>(
+                "IngestSharedContentResult",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static IngestSharedContentResult {
+        static instance: ::protobuf::rt::LazyV2<IngestSharedContentResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(IngestSharedContentResult::new)
+    }
+}
+
+impl ::protobuf::Clear for IngestSharedContentResult {
+    fn clear(&mut self) {
+        self.view.clear();
+        self.appended = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for IngestSharedContentResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for IngestSharedContentResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum SharedContentType {
+    Text = 0,
+    Url = 1,
+    Image = 2,
+}
+
+impl ::protobuf::ProtobufEnum for SharedContentType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<SharedContentType> {
+        match value {
+            0 => ::std::option::Option::Some(SharedContentType::Text),
+            1 => ::std::option::Option::Some(SharedContentType::Url),
+            2 => ::std::option::Option::Some(SharedContentType::Image),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [SharedContentType] = &[
+            SharedContentType::Text,
+            SharedContentType::Url,
+            SharedContentType::Image,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<SharedContentType>("SharedContentType", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for SharedContentType {
+}
+
+impl ::std::default::Default for SharedContentType {
+    fn default() -> Self {
+        SharedContentType::Text
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SharedContentType {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum IngestTarget {
+    NewDocument = 0,
+    Inbox = 1,
+}
+
+impl ::protobuf::ProtobufEnum for IngestTarget {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<IngestTarget> {
+        match value {
+            0 => ::std::option::Option::Some(IngestTarget::NewDocument),
+            1 => ::std::option::Option::Some(IngestTarget::Inbox),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [IngestTarget] = &[
+            IngestTarget::NewDocument,
+            IngestTarget::Inbox,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<IngestTarget>("IngestTarget", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for IngestTarget {
+}
+
+impl ::std::default::Default for IngestTarget {
+    fn default() -> Self {
+        IngestTarget::NewDocument
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for IngestTarget {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0cingest.proto\x1a\x11view_create.proto\"\xaf\x02\n\x1aIngestSharedC\
+    ontentRequest\x127\n\x0ccontent_type\x18\x01\x20\x01(\x0e2\x12.SharedCon\
+    tentTypeR\x0bcontentTypeB\0\x12\x14\n\x04text\x18\x02\x20\x01(\tR\x04tex\
+    tB\0\x12\x14\n\x04data\x18\x03\x20\x01(\x0cR\x04dataB\0\x12\x1d\n\tmime_\
+    type\x18\x04\x20\x01(\tR\x08mimeTypeB\0\x12\x16\n\x04name\x18\x05\x20\
+    \x01(\tH\0R\x04nameB\0\x12'\n\x06target\x18\x06\x20\x01(\x0e2\r.IngestTa\
+    rgetR\x06targetB\0\x12$\n\x0cbelong_to_id\x18\x07\x20\x01(\tH\x01R\nbelo\
+    ngToIdB\0B\r\n\x0bone_of_nameB\x15\n\x13one_of_belong_to_id:\0\"X\n\x19I\
+    ngestSharedContentResult\x12\x1b\n\x04view\x18\x01\x20\x01(\x0b2\x05.Vie\
+    wR\x04viewB\0\x12\x1c\n\x08appended\x18\x02\x20\x01(\x08R\x08appendedB\0\
+    :\0*3\n\x11SharedContentType\x12\x08\n\x04Text\x10\0\x12\x07\n\x03Url\
+    \x10\x01\x12\t\n\x05Image\x10\x02\x1a\0*,\n\x0cIngestTarget\x12\x0f\n\
+    \x0bNewDocument\x10\0\x12\t\n\x05Inbox\x10\x01\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -38,3 +38,78 @@ pub use trash_create::*;
 
 mod export; 
 pub use export::*; 
+
+mod workspace_member; 
+pub use workspace_member::*; 
+
+mod import; 
+pub use import::*; 
+
+mod template; 
+pub use template::*; 
+
+mod view_duplicate; 
+pub use view_duplicate::*; 
+
+mod view_move; 
+pub use view_move::*; 
+
+mod view_favorite; 
+pub use view_favorite::*; 
+
+mod archive; 
+pub use archive::*; 
+
+mod view_tree; 
+pub use view_tree::*; 
+
+mod workspace_invitation; 
+pub use workspace_invitation::*; 
+
+mod view_batch; 
+pub use view_batch::*; 
+
+mod workspace_template; 
+pub use workspace_template::*; 
+
+mod view_garbage; 
+pub use view_garbage::*; 
+
+mod workspace_integrity; 
+pub use workspace_integrity::*; 
+
+mod remote_change; 
+pub use remote_change::*; 
+
+mod sync_state; 
+pub use sync_state::*; 
+
+mod conflict; 
+pub use conflict::*; 
+
+mod sync_status; 
+pub use sync_status::*; 
+
+mod exclusion; 
+pub use exclusion::*; 
+
+mod workspace_encryption; 
+pub use workspace_encryption::*; 
+
+mod server_push; 
+pub use server_push::*; 
+
+mod lan; 
+pub use lan::*; 
+
+mod search; 
+pub use search::*; 
+
+mod notion; 
+pub use notion::*; 
+
+mod evernote; 
+pub use evernote::*; 
+
+mod ingest; 
+pub use ingest::*; 
@@ -0,0 +1,905 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `notion.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ImportNotionRequest {
+    // message fields
+    pub path: ::std::string::String,
+    // message oneof groups
+    pub one_of_name: ::std::option::Option<ImportNotionRequest_oneof_one_of_name>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ImportNotionRequest {
+    fn default() -> &'a ImportNotionRequest {
+        <ImportNotionRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ImportNotionRequest_oneof_one_of_name {
+    name(::std::string::String),
+}
+
+impl ImportNotionRequest {
+    pub fn new() -> ImportNotionRequest {
+        ::std::default::Default::default()
+    }
+
+    // string path = 1;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
+
+    // string name = 2;
+
+
+    pub fn get_name(&self) -> &str {
+        match self.one_of_name {
+            ::std::option::Option::Some(ImportNotionRequest_oneof_one_of_name::name(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_name(&mut self) {
+        self.one_of_name = ::std::option::Option::None;
+    }
+
+    pub fn has_name(&self) -> bool {
+        match self.one_of_name {
+            ::std::option::Option::Some(ImportNotionRequest_oneof_one_of_name::name(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.one_of_name = ::std::option::Option::Some(ImportNotionRequest_oneof_one_of_name::name(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ImportNotionRequest_oneof_one_of_name::name(_)) = self.one_of_name {
+        } else {
+            self.one_of_name = ::std::option::Option::Some(ImportNotionRequest_oneof_one_of_name::name(::std::string::String::new()));
+        }
+        match self.one_of_name {
+            ::std::option::Option::Some(ImportNotionRequest_oneof_one_of_name::name(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        if self.has_name() {
+            match self.one_of_name.take() {
+                ::std::option::Option::Some(ImportNotionRequest_oneof_one_of_name::name(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for ImportNotionRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_name = ::std::option::Option::Some(ImportNotionRequest_oneof_one_of_name::name(is.read_string()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &ImportNotionRequest_oneof_one_of_name::name(ref v) => {
+                    my_size += ::protobuf::rt::string_size(2, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &ImportNotionRequest_oneof_one_of_name::name(ref v) => {
+                    os.write_string(2, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ImportNotionRequest {
+        ImportNotionRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "path",
+                |m: &ImportNotionRequest| { &m.path },
+                |m: &mut ImportNotionRequest| { &mut m.path },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "name",
+                ImportNotionRequest::has_name,
+                ImportNotionRequest::get_name,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ImportNotionRequest>(
+                "ImportNotionRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ImportNotionRequest {
+        static instance: ::protobuf::rt::LazyV2<ImportNotionRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ImportNotionRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ImportNotionRequest {
+    fn clear(&mut self) {
+        self.path.clear();
+        self.one_of_name = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ImportNotionRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ImportNotionRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UnconvertedElement {
+    // message fields
+    pub page: ::std::string::String,
+    pub view_id: ::std::string::String,
+    pub kind: UnconvertedKind,
+    pub detail: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UnconvertedElement {
+    fn default() -> &'a UnconvertedElement {
+        <UnconvertedElement as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UnconvertedElement {
+    pub fn new() -> UnconvertedElement {
+        ::std::default::Default::default()
+    }
+
+    // string page = 1;
+
+
+    pub fn get_page(&self) -> &str {
+        &self.page
+    }
+    pub fn clear_page(&mut self) {
+        self.page.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_page(&mut self, v: ::std::string::String) {
+        self.page = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_page(&mut self) -> &mut ::std::string::String {
+        &mut self.page
+    }
+
+    // Take field
+    pub fn take_page(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.page, ::std::string::String::new())
+    }
+
+    // string view_id = 2;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // .UnconvertedKind kind = 3;
+
+
+    pub fn get_kind(&self) -> UnconvertedKind {
+        self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind = UnconvertedKind::Table;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: UnconvertedKind) {
+        self.kind = v;
+    }
+
+    // string detail = 4;
+
+
+    pub fn get_detail(&self) -> &str {
+        &self.detail
+    }
+    pub fn clear_detail(&mut self) {
+        self.detail.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_detail(&mut self, v: ::std::string::String) {
+        self.detail = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_detail(&mut self) -> &mut ::std::string::String {
+        &mut self.detail
+    }
+
+    // Take field
+    pub fn take_detail(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.detail, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for UnconvertedElement {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.page)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.kind, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.detail)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.page.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.page);
+        }
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.view_id);
+        }
+        if self.kind != UnconvertedKind::Table {
+            my_size += ::protobuf::rt::enum_size(3, self.kind);
+        }
+        if !self.detail.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.detail);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.page.is_empty() {
+            os.write_string(1, &self.page)?;
+        }
+        if !self.view_id.is_empty() {
+            os.write_string(2, &self.view_id)?;
+        }
+        if self.kind != UnconvertedKind::Table {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.kind))?;
+        }
+        if !self.detail.is_empty() {
+            os.write_string(4, &self.detail)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UnconvertedElement {
+        UnconvertedElement::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "page",
+                |m: &UnconvertedElement| { &m.page },
+                |m: &mut UnconvertedElement| { &mut m.page },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &UnconvertedElement| { &m.view_id },
+                |m: &mut UnconvertedElement| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<UnconvertedKind>>(
+                "kind",
+                |m: &UnconvertedElement| { &m.kind },
+                |m: &mut UnconvertedElement| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "detail",
+                |m: &UnconvertedElement| { &m.detail },
+                |m: &mut UnconvertedElement| { &mut m.detail },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UnconvertedElement>(
+                "UnconvertedElement",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UnconvertedElement {
+        static instance: ::protobuf::rt::LazyV2<UnconvertedElement> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UnconvertedElement::new)
+    }
+}
+
+impl ::protobuf::Clear for UnconvertedElement {
+    fn clear(&mut self) {
+        self.page.clear();
+        self.view_id.clear();
+        self.kind = UnconvertedKind::Table;
+        self.detail.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UnconvertedElement {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UnconvertedElement {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ImportNotionResult {
+    // message fields
+    pub workspace: ::protobuf::SingularPtrField<super::workspace_create::Workspace>,
+    pub failures: ::protobuf::RepeatedField<super::archive::ImportFailure>,
+    pub unconverted: ::protobuf::RepeatedField<UnconvertedElement>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ImportNotionResult {
+    fn default() -> &'a ImportNotionResult {
+        <ImportNotionResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ImportNotionResult {
+    pub fn new() -> ImportNotionResult {
+        ::std::default::Default::default()
+    }
+
+    // .Workspace workspace = 1;
+
+
+    pub fn get_workspace(&self) -> &super::workspace_create::Workspace {
+        self.workspace.as_ref().unwrap_or_else(|| <super::workspace_create::Workspace as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_workspace(&mut self) {
+        self.workspace.clear();
+    }
+
+    pub fn has_workspace(&self) -> bool {
+        self.workspace.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace(&mut self, v: super::workspace_create::Workspace) {
+        self.workspace = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace(&mut self) -> &mut super::workspace_create::Workspace {
+        if self.workspace.is_none() {
+            self.workspace.set_default();
+        }
+        self.workspace.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_workspace(&mut self) -> super::workspace_create::Workspace {
+        self.workspace.take().unwrap_or_else(|| super::workspace_create::Workspace::new())
+    }
+
+    // repeated .ImportFailure failures = 2;
+
+
+    pub fn get_failures(&self) -> &[super::archive::ImportFailure] {
+        &self.failures
+    }
+    pub fn clear_failures(&mut self) {
+        self.failures.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_failures(&mut self, v: ::protobuf::RepeatedField<super::archive::ImportFailure>) {
+        self.failures = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_failures(&mut self) -> &mut ::protobuf::RepeatedField<super::archive::ImportFailure> {
+        &mut self.failures
+    }
+
+    // Take field
+    pub fn take_failures(&mut self) -> ::protobuf::RepeatedField<super::archive::ImportFailure> {
+        ::std::mem::replace(&mut self.failures, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .UnconvertedElement unconverted = 3;
+
+
+    pub fn get_unconverted(&self) -> &[UnconvertedElement] {
+        &self.unconverted
+    }
+    pub fn clear_unconverted(&mut self) {
+        self.unconverted.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_unconverted(&mut self, v: ::protobuf::RepeatedField<UnconvertedElement>) {
+        self.unconverted = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_unconverted(&mut self) -> &mut ::protobuf::RepeatedField<UnconvertedElement> {
+        &mut self.unconverted
+    }
+
+    // Take field
+    pub fn take_unconverted(&mut self) -> ::protobuf::RepeatedField<UnconvertedElement> {
+        ::std::mem::replace(&mut self.unconverted, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for ImportNotionResult {
+    fn is_initialized(&self) -> bool {
+        for v in &self.workspace {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.failures {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.unconverted {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.workspace)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.failures)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.unconverted)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.workspace.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.failures {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.unconverted {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.workspace.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.failures {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.unconverted {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ImportNotionResult {
+        ImportNotionResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::workspace_create::Workspace>>(
+                "workspace",
+                |m: &ImportNotionResult| { &m.workspace },
+                |m: &mut ImportNotionResult| { &mut m.workspace },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::archive::ImportFailure>>(
+                "failures",
+                |m: &ImportNotionResult| { &m.failures },
+                |m: &mut ImportNotionResult| { &mut m.failures },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UnconvertedElement>>(
+                "unconverted",
+                |m: &ImportNotionResult| { &m.unconverted },
+                |m: &mut ImportNotionResult| { &mut m.unconverted },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ImportNotionResult>(
+                "ImportNotionResult",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ImportNotionResult {
+        static instance: ::protobuf::rt::LazyV2<ImportNotionResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ImportNotionResult::new)
+    }
+}
+
+impl ::protobuf::Clear for ImportNotionResult {
+    fn clear(&mut self) {
+        self.workspace.clear();
+        self.failures.clear();
+        self.unconverted.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ImportNotionResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ImportNotionResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum UnconvertedKind {
+    Table = 0,
+    Callout = 1,
+    Toggle = 2,
+    Equation = 3,
+    Html = 4,
+    File = 5,
+    Cell = 6,
+}
+
+impl ::protobuf::ProtobufEnum for UnconvertedKind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<UnconvertedKind> {
+        match value {
+            0 => ::std::option::Option::Some(UnconvertedKind::Table),
+            1 => ::std::option::Option::Some(UnconvertedKind::Callout),
+            2 => ::std::option::Option::Some(UnconvertedKind::Toggle),
+            3 => ::std::option::Option::Some(UnconvertedKind::Equation),
+            4 => ::std::option::Option::Some(UnconvertedKind::Html),
+            5 => ::std::option::Option::Some(UnconvertedKind::File),
+            6 => ::std::option::Option::Some(UnconvertedKind::Cell),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [UnconvertedKind] = &[
+            UnconvertedKind::Table,
+            UnconvertedKind::Callout,
+            UnconvertedKind::Toggle,
+            UnconvertedKind::Equation,
+            UnconvertedKind::Html,
+            UnconvertedKind::File,
+            UnconvertedKind::Cell,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<UnconvertedKind>("UnconvertedKind", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for UnconvertedKind {
+}
+
+impl ::std::default::Default for UnconvertedKind {
+    fn default() -> Self {
+        UnconvertedKind::Table
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UnconvertedKind {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0cnotion.proto\x1a\x16workspace_create.proto\x1a\rarchive.proto\"T\n\
+    \x13ImportNotionRequest\x12\x14\n\x04path\x18\x01\x20\x01(\tR\x04pathB\0\
+    \x12\x16\n\x04name\x18\x02\x20\x01(\tH\0R\x04nameB\0B\r\n\x0bone_of_name\
+    :\0\"\x89\x01\n\x12UnconvertedElement\x12\x14\n\x04page\x18\x01\x20\x01(\
+    \tR\x04pageB\0\x12\x19\n\x07view_id\x18\x02\x20\x01(\tR\x06viewIdB\0\x12\
+    &\n\x04kind\x18\x03\x20\x01(\x0e2\x10.UnconvertedKindR\x04kindB\0\x12\
+    \x18\n\x06detail\x18\x04\x20\x01(\tR\x06detailB\0:\0\"\xa9\x01\n\x12Impo\
+    rtNotionResult\x12*\n\tworkspace\x18\x01\x20\x01(\x0b2\n.WorkspaceR\twor\
+    kspaceB\0\x12,\n\x08failures\x18\x02\x20\x03(\x0b2\x0e.ImportFailureR\
+    \x08failuresB\0\x127\n\x0bunconverted\x18\x03\x20\x03(\x0b2\x13.Unconver\
+    tedElementR\x0bunconvertedB\0:\0*c\n\x0fUnconvertedKind\x12\t\n\x05Table\
+    \x10\0\x12\x0b\n\x07Callout\x10\x01\x12\n\n\x06Toggle\x10\x02\x12\x0c\n\
+    \x08Equation\x10\x03\x12\x08\n\x04Html\x10\x04\x12\x08\n\x04File\x10\x05\
+    \x12\x08\n\x04Cell\x10\x06\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,701 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `sync_state.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SyncState {
+    // message fields
+    pub pending_operations: i64,
+    pub rev_id: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SyncState {
+    fn default() -> &'a SyncState {
+        <SyncState as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SyncState {
+    pub fn new() -> SyncState {
+        ::std::default::Default::default()
+    }
+
+    // int64 pending_operations = 1;
+
+
+    pub fn get_pending_operations(&self) -> i64 {
+        self.pending_operations
+    }
+    pub fn clear_pending_operations(&mut self) {
+        self.pending_operations = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pending_operations(&mut self, v: i64) {
+        self.pending_operations = v;
+    }
+
+    // int64 rev_id = 2;
+
+
+    pub fn get_rev_id(&self) -> i64 {
+        self.rev_id
+    }
+    pub fn clear_rev_id(&mut self) {
+        self.rev_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rev_id(&mut self, v: i64) {
+        self.rev_id = v;
+    }
+}
+
+impl ::protobuf::Message for SyncState {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.pending_operations = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.rev_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.pending_operations != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.pending_operations, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.rev_id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.rev_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.pending_operations != 0 {
+            os.write_int64(1, self.pending_operations)?;
+        }
+        if self.rev_id != 0 {
+            os.write_int64(2, self.rev_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SyncState {
+        SyncState::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "pending_operations",
+                |m: &SyncState| { &m.pending_operations },
+                |m: &mut SyncState| { &mut m.pending_operations },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "rev_id",
+                |m: &SyncState| { &m.rev_id },
+                |m: &mut SyncState| { &mut m.rev_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SyncState>(
+                "SyncState",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SyncState {
+        static instance: ::protobuf::rt::LazyV2<SyncState> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SyncState::new)
+    }
+}
+
+impl ::protobuf::Clear for SyncState {
+    fn clear(&mut self) {
+        self.pending_operations = 0;
+        self.rev_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SyncState {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyncState {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SyncResult {
+    // message fields
+    pub pushed: i64,
+    pub pulled: i64,
+    pub state: ::protobuf::SingularPtrField<SyncState>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SyncResult {
+    fn default() -> &'a SyncResult {
+        <SyncResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SyncResult {
+    pub fn new() -> SyncResult {
+        ::std::default::Default::default()
+    }
+
+    // int64 pushed = 1;
+
+
+    pub fn get_pushed(&self) -> i64 {
+        self.pushed
+    }
+    pub fn clear_pushed(&mut self) {
+        self.pushed = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pushed(&mut self, v: i64) {
+        self.pushed = v;
+    }
+
+    // int64 pulled = 2;
+
+
+    pub fn get_pulled(&self) -> i64 {
+        self.pulled
+    }
+    pub fn clear_pulled(&mut self) {
+        self.pulled = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pulled(&mut self, v: i64) {
+        self.pulled = v;
+    }
+
+    // .SyncState state = 3;
+
+
+    pub fn get_state(&self) -> &SyncState {
+        self.state.as_ref().unwrap_or_else(|| <SyncState as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_state(&mut self) {
+        self.state.clear();
+    }
+
+    pub fn has_state(&self) -> bool {
+        self.state.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: SyncState) {
+        self.state = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_state(&mut self) -> &mut SyncState {
+        if self.state.is_none() {
+            self.state.set_default();
+        }
+        self.state.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_state(&mut self) -> SyncState {
+        self.state.take().unwrap_or_else(|| SyncState::new())
+    }
+}
+
+impl ::protobuf::Message for SyncResult {
+    fn is_initialized(&self) -> bool {
+        for v in &self.state {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.pushed = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.pulled = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.state)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.pushed != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.pushed, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.pulled != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.pulled, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.state.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.pushed != 0 {
+            os.write_int64(1, self.pushed)?;
+        }
+        if self.pulled != 0 {
+            os.write_int64(2, self.pulled)?;
+        }
+        if let Some(ref v) = self.state.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SyncResult {
+        SyncResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "pushed",
+                |m: &SyncResult| { &m.pushed },
+                |m: &mut SyncResult| { &mut m.pushed },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "pulled",
+                |m: &SyncResult| { &m.pulled },
+                |m: &mut SyncResult| { &mut m.pulled },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SyncState>>(
+                "state",
+                |m: &SyncResult| { &m.state },
+                |m: &mut SyncResult| { &mut m.state },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SyncResult>(
+                "SyncResult",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SyncResult {
+        static instance: ::protobuf::rt::LazyV2<SyncResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SyncResult::new)
+    }
+}
+
+impl ::protobuf::Clear for SyncResult {
+    fn clear(&mut self) {
+        self.pushed = 0;
+        self.pulled = 0;
+        self.state.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SyncResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyncResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SyncTraffic {
+    // message fields
+    pub uploaded_bytes: i64,
+    pub downloaded_bytes: i64,
+    pub throttled_millis: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SyncTraffic {
+    fn default() -> &'a SyncTraffic {
+        <SyncTraffic as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SyncTraffic {
+    pub fn new() -> SyncTraffic {
+        ::std::default::Default::default()
+    }
+
+    // int64 uploaded_bytes = 1;
+
+
+    pub fn get_uploaded_bytes(&self) -> i64 {
+        self.uploaded_bytes
+    }
+    pub fn clear_uploaded_bytes(&mut self) {
+        self.uploaded_bytes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_uploaded_bytes(&mut self, v: i64) {
+        self.uploaded_bytes = v;
+    }
+
+    // int64 downloaded_bytes = 2;
+
+
+    pub fn get_downloaded_bytes(&self) -> i64 {
+        self.downloaded_bytes
+    }
+    pub fn clear_downloaded_bytes(&mut self) {
+        self.downloaded_bytes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_downloaded_bytes(&mut self, v: i64) {
+        self.downloaded_bytes = v;
+    }
+
+    // int64 throttled_millis = 3;
+
+
+    pub fn get_throttled_millis(&self) -> i64 {
+        self.throttled_millis
+    }
+    pub fn clear_throttled_millis(&mut self) {
+        self.throttled_millis = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_throttled_millis(&mut self, v: i64) {
+        self.throttled_millis = v;
+    }
+}
+
+impl ::protobuf::Message for SyncTraffic {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.uploaded_bytes = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.downloaded_bytes = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.throttled_millis = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.uploaded_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.uploaded_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.downloaded_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.downloaded_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.throttled_millis != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.throttled_millis, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.uploaded_bytes != 0 {
+            os.write_int64(1, self.uploaded_bytes)?;
+        }
+        if self.downloaded_bytes != 0 {
+            os.write_int64(2, self.downloaded_bytes)?;
+        }
+        if self.throttled_millis != 0 {
+            os.write_int64(3, self.throttled_millis)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SyncTraffic {
+        SyncTraffic::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "uploaded_bytes",
+                |m: &SyncTraffic| { &m.uploaded_bytes },
+                |m: &mut SyncTraffic| { &mut m.uploaded_bytes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "downloaded_bytes",
+                |m: &SyncTraffic| { &m.downloaded_bytes },
+                |m: &mut SyncTraffic| { &mut m.downloaded_bytes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "throttled_millis",
+                |m: &SyncTraffic| { &m.throttled_millis },
+                |m: &mut SyncTraffic| { &mut m.throttled_millis },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SyncTraffic>(
+                "SyncTraffic",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SyncTraffic {
+        static instance: ::protobuf::rt::LazyV2<SyncTraffic> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SyncTraffic::new)
+    }
+}
+
+impl ::protobuf::Clear for SyncTraffic {
+    fn clear(&mut self) {
+        self.uploaded_bytes = 0;
+        self.downloaded_bytes = 0;
+        self.throttled_millis = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SyncTraffic {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyncTraffic {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x10sync_state.proto\"W\n\tSyncState\x12/\n\x12pending_operations\x18\
+    \x01\x20\x01(\x03R\x11pendingOperationsB\0\x12\x17\n\x06rev_id\x18\x02\
+    \x20\x01(\x03R\x05revIdB\0:\0\"f\n\nSyncResult\x12\x18\n\x06pushed\x18\
+    \x01\x20\x01(\x03R\x06pushedB\0\x12\x18\n\x06pulled\x18\x02\x20\x01(\x03\
+    R\x06pulledB\0\x12\"\n\x05state\x18\x03\x20\x01(\x0b2\n.SyncStateR\x05st\
+    ateB\0:\0\"\x92\x01\n\x0bSyncTraffic\x12'\n\x0euploaded_bytes\x18\x01\
+    \x20\x01(\x03R\ruploadedBytesB\0\x12+\n\x10downloaded_bytes\x18\x02\x20\
+    \x01(\x03R\x0fdownloadedBytesB\0\x12+\n\x10throttled_millis\x18\x03\x20\
+    \x01(\x03R\x0fthrottledMillisB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,699 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `sync_status.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ObjectSyncStatus {
+    // message fields
+    pub object_id: ::std::string::String,
+    pub ty: SyncStatusType,
+    pub pending_operations: i64,
+    pub error: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ObjectSyncStatus {
+    fn default() -> &'a ObjectSyncStatus {
+        <ObjectSyncStatus as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ObjectSyncStatus {
+    pub fn new() -> ObjectSyncStatus {
+        ::std::default::Default::default()
+    }
+
+    // string object_id = 1;
+
+
+    pub fn get_object_id(&self) -> &str {
+        &self.object_id
+    }
+    pub fn clear_object_id(&mut self) {
+        self.object_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object_id(&mut self, v: ::std::string::String) {
+        self.object_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_object_id(&mut self) -> &mut ::std::string::String {
+        &mut self.object_id
+    }
+
+    // Take field
+    pub fn take_object_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.object_id, ::std::string::String::new())
+    }
+
+    // .SyncStatusType ty = 2;
+
+
+    pub fn get_ty(&self) -> SyncStatusType {
+        self.ty
+    }
+    pub fn clear_ty(&mut self) {
+        self.ty = SyncStatusType::Synced;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ty(&mut self, v: SyncStatusType) {
+        self.ty = v;
+    }
+
+    // int64 pending_operations = 3;
+
+
+    pub fn get_pending_operations(&self) -> i64 {
+        self.pending_operations
+    }
+    pub fn clear_pending_operations(&mut self) {
+        self.pending_operations = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pending_operations(&mut self, v: i64) {
+        self.pending_operations = v;
+    }
+
+    // string error = 4;
+
+
+    pub fn get_error(&self) -> &str {
+        &self.error
+    }
+    pub fn clear_error(&mut self) {
+        self.error.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.error = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        &mut self.error
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ObjectSyncStatus {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.object_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.ty, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.pending_operations = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.object_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.object_id);
+        }
+        if self.ty != SyncStatusType::Synced {
+            my_size += ::protobuf::rt::enum_size(2, self.ty);
+        }
+        if self.pending_operations != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.pending_operations, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.error);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.object_id.is_empty() {
+            os.write_string(1, &self.object_id)?;
+        }
+        if self.ty != SyncStatusType::Synced {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.ty))?;
+        }
+        if self.pending_operations != 0 {
+            os.write_int64(3, self.pending_operations)?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(4, &self.error)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ObjectSyncStatus {
+        ObjectSyncStatus::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "object_id",
+                |m: &ObjectSyncStatus| { &m.object_id },
+                |m: &mut ObjectSyncStatus| { &mut m.object_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<SyncStatusType>>(
+                "ty",
+                |m: &ObjectSyncStatus| { &m.ty },
+                |m: &mut ObjectSyncStatus| { &mut m.ty },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "pending_operations",
+                |m: &ObjectSyncStatus| { &m.pending_operations },
+                |m: &mut ObjectSyncStatus| { &mut m.pending_operations },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "error",
+                |m: &ObjectSyncStatus| { &m.error },
+                |m: &mut ObjectSyncStatus| { &mut m.error },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ObjectSyncStatus>(
+                "ObjectSyncStatus",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ObjectSyncStatus {
+        static instance: ::protobuf::rt::LazyV2<ObjectSyncStatus> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ObjectSyncStatus::new)
+    }
+}
+
+impl ::protobuf::Clear for ObjectSyncStatus {
+    fn clear(&mut self) {
+        self.object_id.clear();
+        self.ty = SyncStatusType::Synced;
+        self.pending_operations = 0;
+        self.error.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ObjectSyncStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ObjectSyncStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedObjectSyncStatus {
+    // message fields
+    pub items: ::protobuf::RepeatedField<ObjectSyncStatus>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedObjectSyncStatus {
+    fn default() -> &'a RepeatedObjectSyncStatus {
+        <RepeatedObjectSyncStatus as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedObjectSyncStatus {
+    pub fn new() -> RepeatedObjectSyncStatus {
+        ::std::default::Default::default()
+    }
+
+    // repeated .ObjectSyncStatus items = 1;
+
+
+    pub fn get_items(&self) -> &[ObjectSyncStatus] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<ObjectSyncStatus>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<ObjectSyncStatus> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<ObjectSyncStatus> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedObjectSyncStatus {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedObjectSyncStatus {
+        RepeatedObjectSyncStatus::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ObjectSyncStatus>>(
+                "items",
+                |m: &RepeatedObjectSyncStatus| { &m.items },
+                |m: &mut RepeatedObjectSyncStatus| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedObjectSyncStatus>(
+                "RepeatedObjectSyncStatus",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedObjectSyncStatus {
+        static instance: ::protobuf::rt::LazyV2<RepeatedObjectSyncStatus> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedObjectSyncStatus::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedObjectSyncStatus {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedObjectSyncStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedObjectSyncStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QuerySyncStatusRequest {
+    // message fields
+    pub object_ids: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QuerySyncStatusRequest {
+    fn default() -> &'a QuerySyncStatusRequest {
+        <QuerySyncStatusRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QuerySyncStatusRequest {
+    pub fn new() -> QuerySyncStatusRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated string object_ids = 1;
+
+
+    pub fn get_object_ids(&self) -> &[::std::string::String] {
+        &self.object_ids
+    }
+    pub fn clear_object_ids(&mut self) {
+        self.object_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.object_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_object_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.object_ids
+    }
+
+    // Take field
+    pub fn take_object_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.object_ids, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for QuerySyncStatusRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.object_ids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.object_ids {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.object_ids {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QuerySyncStatusRequest {
+        QuerySyncStatusRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "object_ids",
+                |m: &QuerySyncStatusRequest| { &m.object_ids },
+                |m: &mut QuerySyncStatusRequest| { &mut m.object_ids },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QuerySyncStatusRequest>(
+                "QuerySyncStatusRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QuerySyncStatusRequest {
+        static instance: ::protobuf::rt::LazyV2<QuerySyncStatusRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QuerySyncStatusRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for QuerySyncStatusRequest {
+    fn clear(&mut self) {
+        self.object_ids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QuerySyncStatusRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QuerySyncStatusRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum SyncStatusType {
+    Synced = 0,
+    Pending = 1,
+    Syncing = 2,
+    Failed = 3,
+    Excluded = 4,
+}
+
+impl ::protobuf::ProtobufEnum for SyncStatusType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<SyncStatusType> {
+        match value {
+            0 => ::std::option::Option::Some(SyncStatusType::Synced),
+            1 => ::std::option::Option::Some(SyncStatusType::Pending),
+            2 => ::std::option::Option::Some(SyncStatusType::Syncing),
+            3 => ::std::option::Option::Some(SyncStatusType::Failed),
+            4 => ::std::option::Option::Some(SyncStatusType::Excluded),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [SyncStatusType] = &[
+            SyncStatusType::Synced,
+            SyncStatusType::Pending,
+            SyncStatusType::Syncing,
+            SyncStatusType::Failed,
+            SyncStatusType::Excluded,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<SyncStatusType>("SyncStatusType", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for SyncStatusType {
+}
+
+impl ::std::default::Default for SyncStatusType {
+    fn default() -> Self {
+        SyncStatusType::Synced
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyncStatusType {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x11sync_status.proto\"\x9f\x01\n\x10ObjectSyncStatus\x12\x1d\n\tobjec\
+    t_id\x18\x01\x20\x01(\tR\x08objectIdB\0\x12!\n\x02ty\x18\x02\x20\x01(\
+    \x0e2\x0f.SyncStatusTypeR\x02tyB\0\x12/\n\x12pending_operations\x18\x03\
+    \x20\x01(\x03R\x11pendingOperationsB\0\x12\x16\n\x05error\x18\x04\x20\
+    \x01(\tR\x05errorB\0:\0\"G\n\x18RepeatedObjectSyncStatus\x12)\n\x05items\
+    \x18\x01\x20\x03(\x0b2\x11.ObjectSyncStatusR\x05itemsB\0:\0\";\n\x16Quer\
+    ySyncStatusRequest\x12\x1f\n\nobject_ids\x18\x01\x20\x03(\tR\tobjectIdsB\
+    \0:\0*R\n\x0eSyncStatusType\x12\n\n\x06Synced\x10\0\x12\x0b\n\x07Pending\
+    \x10\x01\x12\x0b\n\x07Syncing\x10\x02\x12\n\n\x06Failed\x10\x03\x12\x0c\
+    \n\x08Excluded\x10\x04\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,1180 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `view_batch.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ViewOperation {
+    // message fields
+    pub ty: ViewOperationType,
+    pub view_id: ::std::string::String,
+    // message oneof groups
+    pub one_of_belong_to_id: ::std::option::Option<ViewOperation_oneof_one_of_belong_to_id>,
+    pub one_of_prev_view_id: ::std::option::Option<ViewOperation_oneof_one_of_prev_view_id>,
+    pub one_of_name: ::std::option::Option<ViewOperation_oneof_one_of_name>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ViewOperation {
+    fn default() -> &'a ViewOperation {
+        <ViewOperation as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ViewOperation_oneof_one_of_belong_to_id {
+    belong_to_id(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ViewOperation_oneof_one_of_prev_view_id {
+    prev_view_id(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ViewOperation_oneof_one_of_name {
+    name(::std::string::String),
+}
+
+impl ViewOperation {
+    pub fn new() -> ViewOperation {
+        ::std::default::Default::default()
+    }
+
+    // .ViewOperationType ty = 1;
+
+
+    pub fn get_ty(&self) -> ViewOperationType {
+        self.ty
+    }
+    pub fn clear_ty(&mut self) {
+        self.ty = ViewOperationType::Move;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ty(&mut self, v: ViewOperationType) {
+        self.ty = v;
+    }
+
+    // string view_id = 2;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // string belong_to_id = 3;
+
+
+    pub fn get_belong_to_id(&self) -> &str {
+        match self.one_of_belong_to_id {
+            ::std::option::Option::Some(ViewOperation_oneof_one_of_belong_to_id::belong_to_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_belong_to_id(&mut self) {
+        self.one_of_belong_to_id = ::std::option::Option::None;
+    }
+
+    pub fn has_belong_to_id(&self) -> bool {
+        match self.one_of_belong_to_id {
+            ::std::option::Option::Some(ViewOperation_oneof_one_of_belong_to_id::belong_to_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_belong_to_id(&mut self, v: ::std::string::String) {
+        self.one_of_belong_to_id = ::std::option::Option::Some(ViewOperation_oneof_one_of_belong_to_id::belong_to_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_belong_to_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ViewOperation_oneof_one_of_belong_to_id::belong_to_id(_)) = self.one_of_belong_to_id {
+        } else {
+            self.one_of_belong_to_id = ::std::option::Option::Some(ViewOperation_oneof_one_of_belong_to_id::belong_to_id(::std::string::String::new()));
+        }
+        match self.one_of_belong_to_id {
+            ::std::option::Option::Some(ViewOperation_oneof_one_of_belong_to_id::belong_to_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_belong_to_id(&mut self) -> ::std::string::String {
+        if self.has_belong_to_id() {
+            match self.one_of_belong_to_id.take() {
+                ::std::option::Option::Some(ViewOperation_oneof_one_of_belong_to_id::belong_to_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string prev_view_id = 4;
+
+
+    pub fn get_prev_view_id(&self) -> &str {
+        match self.one_of_prev_view_id {
+            ::std::option::Option::Some(ViewOperation_oneof_one_of_prev_view_id::prev_view_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_prev_view_id(&mut self) {
+        self.one_of_prev_view_id = ::std::option::Option::None;
+    }
+
+    pub fn has_prev_view_id(&self) -> bool {
+        match self.one_of_prev_view_id {
+            ::std::option::Option::Some(ViewOperation_oneof_one_of_prev_view_id::prev_view_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_prev_view_id(&mut self, v: ::std::string::String) {
+        self.one_of_prev_view_id = ::std::option::Option::Some(ViewOperation_oneof_one_of_prev_view_id::prev_view_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_prev_view_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ViewOperation_oneof_one_of_prev_view_id::prev_view_id(_)) = self.one_of_prev_view_id {
+        } else {
+            self.one_of_prev_view_id = ::std::option::Option::Some(ViewOperation_oneof_one_of_prev_view_id::prev_view_id(::std::string::String::new()));
+        }
+        match self.one_of_prev_view_id {
+            ::std::option::Option::Some(ViewOperation_oneof_one_of_prev_view_id::prev_view_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_prev_view_id(&mut self) -> ::std::string::String {
+        if self.has_prev_view_id() {
+            match self.one_of_prev_view_id.take() {
+                ::std::option::Option::Some(ViewOperation_oneof_one_of_prev_view_id::prev_view_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string name = 5;
+
+
+    pub fn get_name(&self) -> &str {
+        match self.one_of_name {
+            ::std::option::Option::Some(ViewOperation_oneof_one_of_name::name(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_name(&mut self) {
+        self.one_of_name = ::std::option::Option::None;
+    }
+
+    pub fn has_name(&self) -> bool {
+        match self.one_of_name {
+            ::std::option::Option::Some(ViewOperation_oneof_one_of_name::name(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.one_of_name = ::std::option::Option::Some(ViewOperation_oneof_one_of_name::name(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ViewOperation_oneof_one_of_name::name(_)) = self.one_of_name {
+        } else {
+            self.one_of_name = ::std::option::Option::Some(ViewOperation_oneof_one_of_name::name(::std::string::String::new()));
+        }
+        match self.one_of_name {
+            ::std::option::Option::Some(ViewOperation_oneof_one_of_name::name(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        if self.has_name() {
+            match self.one_of_name.take() {
+                ::std::option::Option::Some(ViewOperation_oneof_one_of_name::name(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for ViewOperation {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.ty, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_belong_to_id = ::std::option::Option::Some(ViewOperation_oneof_one_of_belong_to_id::belong_to_id(is.read_string()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_prev_view_id = ::std::option::Option::Some(ViewOperation_oneof_one_of_prev_view_id::prev_view_id(is.read_string()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_name = ::std::option::Option::Some(ViewOperation_oneof_one_of_name::name(is.read_string()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.ty != ViewOperationType::Move {
+            my_size += ::protobuf::rt::enum_size(1, self.ty);
+        }
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.view_id);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_belong_to_id {
+            match v {
+                &ViewOperation_oneof_one_of_belong_to_id::belong_to_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_prev_view_id {
+            match v {
+                &ViewOperation_oneof_one_of_prev_view_id::prev_view_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(4, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &ViewOperation_oneof_one_of_name::name(ref v) => {
+                    my_size += ::protobuf::rt::string_size(5, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.ty != ViewOperationType::Move {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.ty))?;
+        }
+        if !self.view_id.is_empty() {
+            os.write_string(2, &self.view_id)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_belong_to_id {
+            match v {
+                &ViewOperation_oneof_one_of_belong_to_id::belong_to_id(ref v) => {
+                    os.write_string(3, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_prev_view_id {
+            match v {
+                &ViewOperation_oneof_one_of_prev_view_id::prev_view_id(ref v) => {
+                    os.write_string(4, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &ViewOperation_oneof_one_of_name::name(ref v) => {
+                    os.write_string(5, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ViewOperation {
+        ViewOperation::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ViewOperationType>>(
+                "ty",
+                |m: &ViewOperation| { &m.ty },
+                |m: &mut ViewOperation| { &mut m.ty },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &ViewOperation| { &m.view_id },
+                |m: &mut ViewOperation| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "belong_to_id",
+                ViewOperation::has_belong_to_id,
+                ViewOperation::get_belong_to_id,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "prev_view_id",
+                ViewOperation::has_prev_view_id,
+                ViewOperation::get_prev_view_id,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "name",
+                ViewOperation::has_name,
+                ViewOperation::get_name,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ViewOperation>(
+                "ViewOperation",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ViewOperation {
+        static instance: ::protobuf::rt::LazyV2<ViewOperation> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ViewOperation::new)
+    }
+}
+
+impl ::protobuf::Clear for ViewOperation {
+    fn clear(&mut self) {
+        self.ty = ViewOperationType::Move;
+        self.view_id.clear();
+        self.one_of_belong_to_id = ::std::option::Option::None;
+        self.one_of_prev_view_id = ::std::option::Option::None;
+        self.one_of_name = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ViewOperation {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ViewOperation {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BatchViewOperationRequest {
+    // message fields
+    pub operations: ::protobuf::RepeatedField<ViewOperation>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BatchViewOperationRequest {
+    fn default() -> &'a BatchViewOperationRequest {
+        <BatchViewOperationRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchViewOperationRequest {
+    pub fn new() -> BatchViewOperationRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated .ViewOperation operations = 1;
+
+
+    pub fn get_operations(&self) -> &[ViewOperation] {
+        &self.operations
+    }
+    pub fn clear_operations(&mut self) {
+        self.operations.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_operations(&mut self, v: ::protobuf::RepeatedField<ViewOperation>) {
+        self.operations = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_operations(&mut self) -> &mut ::protobuf::RepeatedField<ViewOperation> {
+        &mut self.operations
+    }
+
+    // Take field
+    pub fn take_operations(&mut self) -> ::protobuf::RepeatedField<ViewOperation> {
+        ::std::mem::replace(&mut self.operations, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for BatchViewOperationRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.operations {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.operations)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.operations {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.operations {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BatchViewOperationRequest {
+        BatchViewOperationRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ViewOperation>>(
+                "operations",
+                |m: &BatchViewOperationRequest| { &m.operations },
+                |m: &mut BatchViewOperationRequest| { &mut m.operations },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BatchViewOperationRequest>(
+                "BatchViewOperationRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BatchViewOperationRequest {
+        static instance: ::protobuf::rt::LazyV2<BatchViewOperationRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BatchViewOperationRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for BatchViewOperationRequest {
+    fn clear(&mut self) {
+        self.operations.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BatchViewOperationRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchViewOperationRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BatchViewChangeset {
+    // message fields
+    pub updated_views: ::protobuf::RepeatedField<super::view_create::View>,
+    pub deleted_view_ids: ::protobuf::RepeatedField<::std::string::String>,
+    pub belongings: ::protobuf::RepeatedField<ViewBelongings>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BatchViewChangeset {
+    fn default() -> &'a BatchViewChangeset {
+        <BatchViewChangeset as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchViewChangeset {
+    pub fn new() -> BatchViewChangeset {
+        ::std::default::Default::default()
+    }
+
+    // repeated .View updated_views = 1;
+
+
+    pub fn get_updated_views(&self) -> &[super::view_create::View] {
+        &self.updated_views
+    }
+    pub fn clear_updated_views(&mut self) {
+        self.updated_views.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_updated_views(&mut self, v: ::protobuf::RepeatedField<super::view_create::View>) {
+        self.updated_views = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_updated_views(&mut self) -> &mut ::protobuf::RepeatedField<super::view_create::View> {
+        &mut self.updated_views
+    }
+
+    // Take field
+    pub fn take_updated_views(&mut self) -> ::protobuf::RepeatedField<super::view_create::View> {
+        ::std::mem::replace(&mut self.updated_views, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string deleted_view_ids = 2;
+
+
+    pub fn get_deleted_view_ids(&self) -> &[::std::string::String] {
+        &self.deleted_view_ids
+    }
+    pub fn clear_deleted_view_ids(&mut self) {
+        self.deleted_view_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deleted_view_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.deleted_view_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_deleted_view_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.deleted_view_ids
+    }
+
+    // Take field
+    pub fn take_deleted_view_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.deleted_view_ids, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .ViewBelongings belongings = 3;
+
+
+    pub fn get_belongings(&self) -> &[ViewBelongings] {
+        &self.belongings
+    }
+    pub fn clear_belongings(&mut self) {
+        self.belongings.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_belongings(&mut self, v: ::protobuf::RepeatedField<ViewBelongings>) {
+        self.belongings = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_belongings(&mut self) -> &mut ::protobuf::RepeatedField<ViewBelongings> {
+        &mut self.belongings
+    }
+
+    // Take field
+    pub fn take_belongings(&mut self) -> ::protobuf::RepeatedField<ViewBelongings> {
+        ::std::mem::replace(&mut self.belongings, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for BatchViewChangeset {
+    fn is_initialized(&self) -> bool {
+        for v in &self.updated_views {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.belongings {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.updated_views)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.deleted_view_ids)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.belongings)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.updated_views {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.deleted_view_ids {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in &self.belongings {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.updated_views {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.deleted_view_ids {
+            os.write_string(2, &v)?;
+        };
+        for v in &self.belongings {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BatchViewChangeset {
+        BatchViewChangeset::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::view_create::View>>(
+                "updated_views",
+                |m: &BatchViewChangeset| { &m.updated_views },
+                |m: &mut BatchViewChangeset| { &mut m.updated_views },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "deleted_view_ids",
+                |m: &BatchViewChangeset| { &m.deleted_view_ids },
+                |m: &mut BatchViewChangeset| { &mut m.deleted_view_ids },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ViewBelongings>>(
+                "belongings",
+                |m: &BatchViewChangeset| { &m.belongings },
+                |m: &mut BatchViewChangeset| { &mut m.belongings },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BatchViewChangeset>(
+                "BatchViewChangeset",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BatchViewChangeset {
+        static instance: ::protobuf::rt::LazyV2<BatchViewChangeset> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BatchViewChangeset::new)
+    }
+}
+
+impl ::protobuf::Clear for BatchViewChangeset {
+    fn clear(&mut self) {
+        self.updated_views.clear();
+        self.deleted_view_ids.clear();
+        self.belongings.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BatchViewChangeset {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchViewChangeset {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ViewBelongings {
+    // message fields
+    pub belong_to_id: ::std::string::String,
+    pub views: ::protobuf::SingularPtrField<super::view_create::RepeatedView>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ViewBelongings {
+    fn default() -> &'a ViewBelongings {
+        <ViewBelongings as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ViewBelongings {
+    pub fn new() -> ViewBelongings {
+        ::std::default::Default::default()
+    }
+
+    // string belong_to_id = 1;
+
+
+    pub fn get_belong_to_id(&self) -> &str {
+        &self.belong_to_id
+    }
+    pub fn clear_belong_to_id(&mut self) {
+        self.belong_to_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_belong_to_id(&mut self, v: ::std::string::String) {
+        self.belong_to_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_belong_to_id(&mut self) -> &mut ::std::string::String {
+        &mut self.belong_to_id
+    }
+
+    // Take field
+    pub fn take_belong_to_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.belong_to_id, ::std::string::String::new())
+    }
+
+    // .RepeatedView views = 2;
+
+
+    pub fn get_views(&self) -> &super::view_create::RepeatedView {
+        self.views.as_ref().unwrap_or_else(|| <super::view_create::RepeatedView as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_views(&mut self) {
+        self.views.clear();
+    }
+
+    pub fn has_views(&self) -> bool {
+        self.views.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_views(&mut self, v: super::view_create::RepeatedView) {
+        self.views = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_views(&mut self) -> &mut super::view_create::RepeatedView {
+        if self.views.is_none() {
+            self.views.set_default();
+        }
+        self.views.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_views(&mut self) -> super::view_create::RepeatedView {
+        self.views.take().unwrap_or_else(|| super::view_create::RepeatedView::new())
+    }
+}
+
+impl ::protobuf::Message for ViewBelongings {
+    fn is_initialized(&self) -> bool {
+        for v in &self.views {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.belong_to_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.views)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.belong_to_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.belong_to_id);
+        }
+        if let Some(ref v) = self.views.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.belong_to_id.is_empty() {
+            os.write_string(1, &self.belong_to_id)?;
+        }
+        if let Some(ref v) = self.views.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ViewBelongings {
+        ViewBelongings::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "belong_to_id",
+                |m: &ViewBelongings| { &m.belong_to_id },
+                |m: &mut ViewBelongings| { &mut m.belong_to_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::view_create::RepeatedView>>(
+                "views",
+                |m: &ViewBelongings| { &m.views },
+                |m: &mut ViewBelongings| { &mut m.views },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ViewBelongings>(
+                "ViewBelongings",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ViewBelongings {
+        static instance: ::protobuf::rt::LazyV2<ViewBelongings> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ViewBelongings::new)
+    }
+}
+
+impl ::protobuf::Clear for ViewBelongings {
+    fn clear(&mut self) {
+        self.belong_to_id.clear();
+        self.views.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ViewBelongings {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ViewBelongings {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum ViewOperationType {
+    Move = 0,
+    Rename = 1,
+    Delete = 2,
+}
+
+impl ::protobuf::ProtobufEnum for ViewOperationType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ViewOperationType> {
+        match value {
+            0 => ::std::option::Option::Some(ViewOperationType::Move),
+            1 => ::std::option::Option::Some(ViewOperationType::Rename),
+            2 => ::std::option::Option::Some(ViewOperationType::Delete),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [ViewOperationType] = &[
+            ViewOperationType::Move,
+            ViewOperationType::Rename,
+            ViewOperationType::Delete,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<ViewOperationType>("ViewOperationType", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for ViewOperationType {
+}
+
+impl ::std::default::Default for ViewOperationType {
+    fn default() -> Self {
+        ViewOperationType::Move
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ViewOperationType {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x10view_batch.proto\x1a\x11view_create.proto\"\xf3\x01\n\rViewOperati\
+    on\x12$\n\x02ty\x18\x01\x20\x01(\x0e2\x12.ViewOperationTypeR\x02tyB\0\
+    \x12\x19\n\x07view_id\x18\x02\x20\x01(\tR\x06viewIdB\0\x12$\n\x0cbelong_\
+    to_id\x18\x03\x20\x01(\tH\0R\nbelongToIdB\0\x12$\n\x0cprev_view_id\x18\
+    \x04\x20\x01(\tH\x01R\nprevViewIdB\0\x12\x16\n\x04name\x18\x05\x20\x01(\
+    \tH\x02R\x04nameB\0B\x15\n\x13one_of_belong_to_idB\x15\n\x13one_of_prev_\
+    view_idB\r\n\x0bone_of_name:\0\"O\n\x19BatchViewOperationRequest\x120\n\
+    \noperations\x18\x01\x20\x03(\x0b2\x0e.ViewOperationR\noperationsB\0:\0\
+    \"\xa3\x01\n\x12BatchViewChangeset\x12,\n\rupdated_views\x18\x01\x20\x03\
+    (\x0b2\x05.ViewR\x0cupdatedViewsB\0\x12*\n\x10deleted_view_ids\x18\x02\
+    \x20\x03(\tR\x0edeletedViewIdsB\0\x121\n\nbelongings\x18\x03\x20\x03(\
+    \x0b2\x0f.ViewBelongingsR\nbelongingsB\0:\0\"]\n\x0eViewBelongings\x12\"\
+    \n\x0cbelong_to_id\x18\x01\x20\x01(\tR\nbelongToIdB\0\x12%\n\x05views\
+    \x18\x02\x20\x01(\x0b2\r.RepeatedViewR\x05viewsB\0:\0*7\n\x11ViewOperati\
+    onType\x12\x08\n\x04Move\x10\0\x12\n\n\x06Rename\x10\x01\x12\n\n\x06Dele\
+    te\x10\x02\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -30,6 +30,7 @@ pub struct CreateViewRequest {
     pub name: ::std::string::String,
     pub desc: ::std::string::String,
     pub view_type: ViewType,
+    pub auto_rename: bool,
     // message oneof groups
     pub one_of_thumbnail: ::std::option::Option<CreateViewRequest_oneof_one_of_thumbnail>,
     // special fields
@@ -194,6 +195,21 @@ impl CreateViewRequest {
     pub fn set_view_type(&mut self, v: ViewType) {
         self.view_type = v;
     }
+
+    // bool auto_rename = 6;
+
+
+    pub fn get_auto_rename(&self) -> bool {
+        self.auto_rename
+    }
+    pub fn clear_auto_rename(&mut self) {
+        self.auto_rename = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_auto_rename(&mut self, v: bool) {
+        self.auto_rename = v;
+    }
 }
 
 impl ::protobuf::Message for CreateViewRequest {
@@ -223,6 +239,13 @@ impl ::protobuf::Message for CreateViewRequest {
                 5 => {
                     ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.view_type, 5, &mut self.unknown_fields)?
                 },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.auto_rename = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -247,6 +270,9 @@ impl ::protobuf::Message for CreateViewRequest {
         if self.view_type != ViewType::Blank {
             my_size += ::protobuf::rt::enum_size(5, self.view_type);
         }
+        if self.auto_rename != false {
+            my_size += 2;
+        }
         if let ::std::option::Option::Some(ref v) = self.one_of_thumbnail {
             match v {
                 &CreateViewRequest_oneof_one_of_thumbnail::thumbnail(ref v) => {
@@ -272,6 +298,9 @@ impl ::protobuf::Message for CreateViewRequest {
         if self.view_type != ViewType::Blank {
             os.write_enum(5, ::protobuf::ProtobufEnum::value(&self.view_type))?;
         }
+        if self.auto_rename != false {
+            os.write_bool(6, self.auto_rename)?;
+        }
         if let ::std::option::Option::Some(ref v) = self.one_of_thumbnail {
             match v {
                 &CreateViewRequest_oneof_one_of_thumbnail::thumbnail(ref v) => {
@@ -342,6 +371,11 @@ impl ::protobuf::Message for CreateViewRequest {
                 |m: &CreateViewRequest| { &m.view_type },
                 |m: &mut CreateViewRequest| { &mut m.view_type },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "auto_rename",
+                |m: &CreateViewRequest| { &m.auto_rename },
+                |m: &mut CreateViewRequest| { &mut m.auto_rename },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateViewRequest>(
                 "CreateViewRequest",
                 fields,
@@ -363,6 +397,7 @@ impl ::protobuf::Clear for CreateViewRequest {
         self.desc.clear();
         self.one_of_thumbnail = ::std::option::Option::None;
         self.view_type = ViewType::Blank;
+        self.auto_rename = false;
         self.unknown_fields.clear();
     }
 }
@@ -749,6 +784,8 @@ pub struct View {
     pub belongings: ::protobuf::SingularPtrField<RepeatedView>,
     pub modified_time: i64,
     pub create_time: i64,
+    pub icon: ::std::string::String,
+    pub cover: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -961,6 +998,58 @@ impl View {
     pub fn set_create_time(&mut self, v: i64) {
         self.create_time = v;
     }
+
+    // string icon = 10;
+
+
+    pub fn get_icon(&self) -> &str {
+        &self.icon
+    }
+    pub fn clear_icon(&mut self) {
+        self.icon.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_icon(&mut self, v: ::std::string::String) {
+        self.icon = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_icon(&mut self) -> &mut ::std::string::String {
+        &mut self.icon
+    }
+
+    // Take field
+    pub fn take_icon(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.icon, ::std::string::String::new())
+    }
+
+    // string cover = 11;
+
+
+    pub fn get_cover(&self) -> &str {
+        &self.cover
+    }
+    pub fn clear_cover(&mut self) {
+        self.cover.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cover(&mut self, v: ::std::string::String) {
+        self.cover = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_cover(&mut self) -> &mut ::std::string::String {
+        &mut self.cover
+    }
+
+    // Take field
+    pub fn take_cover(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.cover, ::std::string::String::new())
+    }
 }
 
 impl ::protobuf::Message for View {
@@ -1016,6 +1105,12 @@ impl ::protobuf::Message for View {
                     let tmp = is.read_int64()?;
                     self.create_time = tmp;
                 },
+                10 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.icon)?;
+                },
+                11 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.cover)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1056,6 +1151,12 @@ impl ::protobuf::Message for View {
         if self.create_time != 0 {
             my_size += ::protobuf::rt::value_size(9, self.create_time, ::protobuf::wire_format::WireTypeVarint);
         }
+        if !self.icon.is_empty() {
+            my_size += ::protobuf::rt::string_size(10, &self.icon);
+        }
+        if !self.cover.is_empty() {
+            my_size += ::protobuf::rt::string_size(11, &self.cover);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1091,6 +1192,12 @@ impl ::protobuf::Message for View {
         if self.create_time != 0 {
             os.write_int64(9, self.create_time)?;
         }
+        if !self.icon.is_empty() {
+            os.write_string(10, &self.icon)?;
+        }
+        if !self.cover.is_empty() {
+            os.write_string(11, &self.cover)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1174,6 +1281,16 @@ impl ::protobuf::Message for View {
                 |m: &View| { &m.create_time },
                 |m: &mut View| { &mut m.create_time },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "icon",
+                |m: &View| { &m.icon },
+                |m: &mut View| { &mut m.icon },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "cover",
+                |m: &View| { &m.cover },
+                |m: &mut View| { &mut m.cover },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<View>(
                 "View",
                 fields,
@@ -1199,6 +1316,8 @@ impl ::protobuf::Clear for View {
         self.belongings.clear();
         self.modified_time = 0;
         self.create_time = 0;
+        self.icon.clear();
+        self.cover.clear();
         self.unknown_fields.clear();
     }
 }
@@ -1385,6 +1504,7 @@ impl ::protobuf::reflect::ProtobufValue for RepeatedView {
 pub enum ViewType {
     Blank = 0,
     Doc = 1,
+    Grid = 2,
 }
 
 impl ::protobuf::ProtobufEnum for ViewType {
@@ -1396,6 +1516,7 @@ impl ::protobuf::ProtobufEnum for ViewType {
         match value {
             0 => ::std::option::Option::Some(ViewType::Blank),
             1 => ::std::option::Option::Some(ViewType::Doc),
+            2 => ::std::option::Option::Some(ViewType::Grid),
             _ => ::std::option::Option::None
         }
     }
@@ -1404,6 +1525,7 @@ impl ::protobuf::ProtobufEnum for ViewType {
         static values: &'static [ViewType] = &[
             ViewType::Blank,
             ViewType::Doc,
+            ViewType::Grid,
         ];
         values
     }
@@ -1432,96 +1554,29 @@ impl ::protobuf::reflect::ProtobufValue for ViewType {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x11view_create.proto\"\xb9\x01\n\x11CreateViewRequest\x12\x20\n\x0cbe\
-    long_to_id\x18\x01\x20\x01(\tR\nbelongToId\x12\x12\n\x04name\x18\x02\x20\
-    \x01(\tR\x04name\x12\x12\n\x04desc\x18\x03\x20\x01(\tR\x04desc\x12\x1e\n\
-    \tthumbnail\x18\x04\x20\x01(\tH\0R\tthumbnail\x12&\n\tview_type\x18\x05\
-    \x20\x01(\x0e2\t.ViewTypeR\x08viewTypeB\x12\n\x10one_of_thumbnail\"\xb6\
-    \x01\n\x10CreateViewParams\x12\x20\n\x0cbelong_to_id\x18\x01\x20\x01(\tR\
-    \nbelongToId\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x12\n\x04\
-    desc\x18\x03\x20\x01(\tR\x04desc\x12\x1c\n\tthumbnail\x18\x04\x20\x01(\t\
-    R\tthumbnail\x12&\n\tview_type\x18\x05\x20\x01(\x0e2\t.ViewTypeR\x08view\
-    Type\x12\x12\n\x04data\x18\x06\x20\x01(\tR\x04data\"\x97\x02\n\x04View\
-    \x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\x12\x20\n\x0cbelong_to_id\x18\
-    \x02\x20\x01(\tR\nbelongToId\x12\x12\n\x04name\x18\x03\x20\x01(\tR\x04na\
-    me\x12\x12\n\x04desc\x18\x04\x20\x01(\tR\x04desc\x12&\n\tview_type\x18\
-    \x05\x20\x01(\x0e2\t.ViewTypeR\x08viewType\x12\x18\n\x07version\x18\x06\
-    \x20\x01(\x03R\x07version\x12-\n\nbelongings\x18\x07\x20\x01(\x0b2\r.Rep\
-    eatedViewR\nbelongings\x12#\n\rmodified_time\x18\x08\x20\x01(\x03R\x0cmo\
-    difiedTime\x12\x1f\n\x0bcreate_time\x18\t\x20\x01(\x03R\ncreateTime\"+\n\
-    \x0cRepeatedView\x12\x1b\n\x05items\x18\x01\x20\x03(\x0b2\x05.ViewR\x05i\
-    tems*\x1e\n\x08ViewType\x12\t\n\x05Blank\x10\0\x12\x07\n\x03Doc\x10\x01J\
-    \x88\x0b\n\x06\x12\x04\0\0\"\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\
-    \x02\x04\0\x12\x04\x02\0\x08\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x19\
-    \n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x1c\n\x0c\n\x05\x04\0\x02\0\x05\
-    \x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x17\n\x0c\
-    \n\x05\x04\0\x02\0\x03\x12\x03\x03\x1a\x1b\n\x0b\n\x04\x04\0\x02\x01\x12\
-    \x03\x04\x04\x14\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\n\n\x0c\n\
-    \x05\x04\0\x02\x01\x01\x12\x03\x04\x0b\x0f\n\x0c\n\x05\x04\0\x02\x01\x03\
-    \x12\x03\x04\x12\x13\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x04\x14\n\x0c\
-    \n\x05\x04\0\x02\x02\x05\x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\x02\x01\
-    \x12\x03\x05\x0b\x0f\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\x12\x13\n\
-    \x0b\n\x04\x04\0\x08\0\x12\x03\x06\x044\n\x0c\n\x05\x04\0\x08\0\x01\x12\
-    \x03\x06\n\x1a\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x06\x1d2\n\x0c\n\x05\
-    \x04\0\x02\x03\x05\x12\x03\x06\x1d#\n\x0c\n\x05\x04\0\x02\x03\x01\x12\
-    \x03\x06$-\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x0601\n\x0b\n\x04\x04\0\
-    \x02\x04\x12\x03\x07\x04\x1b\n\x0c\n\x05\x04\0\x02\x04\x06\x12\x03\x07\
-    \x04\x0c\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x07\r\x16\n\x0c\n\x05\x04\
-    \0\x02\x04\x03\x12\x03\x07\x19\x1a\n\n\n\x02\x04\x01\x12\x04\t\0\x10\x01\
-    \n\n\n\x03\x04\x01\x01\x12\x03\t\x08\x18\n\x0b\n\x04\x04\x01\x02\0\x12\
-    \x03\n\x04\x1c\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\n\x04\n\n\x0c\n\x05\
-    \x04\x01\x02\0\x01\x12\x03\n\x0b\x17\n\x0c\n\x05\x04\x01\x02\0\x03\x12\
-    \x03\n\x1a\x1b\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x0b\x04\x14\n\x0c\n\
-    \x05\x04\x01\x02\x01\x05\x12\x03\x0b\x04\n\n\x0c\n\x05\x04\x01\x02\x01\
-    \x01\x12\x03\x0b\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x0b\x12\
-    \x13\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\x0c\x04\x14\n\x0c\n\x05\x04\x01\
-    \x02\x02\x05\x12\x03\x0c\x04\n\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\
-    \x0c\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\x0c\x12\x13\n\x0b\n\
-    \x04\x04\x01\x02\x03\x12\x03\r\x04\x19\n\x0c\n\x05\x04\x01\x02\x03\x05\
-    \x12\x03\r\x04\n\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\r\x0b\x14\n\x0c\
-    \n\x05\x04\x01\x02\x03\x03\x12\x03\r\x17\x18\n\x0b\n\x04\x04\x01\x02\x04\
-    \x12\x03\x0e\x04\x1b\n\x0c\n\x05\x04\x01\x02\x04\x06\x12\x03\x0e\x04\x0c\
-    \n\x0c\n\x05\x04\x01\x02\x04\x01\x12\x03\x0e\r\x16\n\x0c\n\x05\x04\x01\
-    \x02\x04\x03\x12\x03\x0e\x19\x1a\n\x0b\n\x04\x04\x01\x02\x05\x12\x03\x0f\
-    \x04\x14\n\x0c\n\x05\x04\x01\x02\x05\x05\x12\x03\x0f\x04\n\n\x0c\n\x05\
-    \x04\x01\x02\x05\x01\x12\x03\x0f\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x05\x03\
-    \x12\x03\x0f\x12\x13\n\n\n\x02\x04\x02\x12\x04\x11\0\x1b\x01\n\n\n\x03\
-    \x04\x02\x01\x12\x03\x11\x08\x0c\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x12\
-    \x04\x12\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x12\x04\n\n\x0c\n\x05\x04\
-    \x02\x02\0\x01\x12\x03\x12\x0b\r\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\
-    \x12\x10\x11\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x13\x04\x1c\n\x0c\n\x05\
-    \x04\x02\x02\x01\x05\x12\x03\x13\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\
-    \x12\x03\x13\x0b\x17\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x13\x1a\x1b\
-    \n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x14\x04\x14\n\x0c\n\x05\x04\x02\x02\
-    \x02\x05\x12\x03\x14\x04\n\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x14\
-    \x0b\x0f\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x14\x12\x13\n\x0b\n\x04\
-    \x04\x02\x02\x03\x12\x03\x15\x04\x14\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\
-    \x03\x15\x04\n\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x15\x0b\x0f\n\x0c\
-    \n\x05\x04\x02\x02\x03\x03\x12\x03\x15\x12\x13\n\x0b\n\x04\x04\x02\x02\
-    \x04\x12\x03\x16\x04\x1b\n\x0c\n\x05\x04\x02\x02\x04\x06\x12\x03\x16\x04\
-    \x0c\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\x03\x16\r\x16\n\x0c\n\x05\x04\
-    \x02\x02\x04\x03\x12\x03\x16\x19\x1a\n\x0b\n\x04\x04\x02\x02\x05\x12\x03\
-    \x17\x04\x16\n\x0c\n\x05\x04\x02\x02\x05\x05\x12\x03\x17\x04\t\n\x0c\n\
-    \x05\x04\x02\x02\x05\x01\x12\x03\x17\n\x11\n\x0c\n\x05\x04\x02\x02\x05\
-    \x03\x12\x03\x17\x14\x15\n\x0b\n\x04\x04\x02\x02\x06\x12\x03\x18\x04\x20\
-    \n\x0c\n\x05\x04\x02\x02\x06\x06\x12\x03\x18\x04\x10\n\x0c\n\x05\x04\x02\
-    \x02\x06\x01\x12\x03\x18\x11\x1b\n\x0c\n\x05\x04\x02\x02\x06\x03\x12\x03\
-    \x18\x1e\x1f\n\x0b\n\x04\x04\x02\x02\x07\x12\x03\x19\x04\x1c\n\x0c\n\x05\
-    \x04\x02\x02\x07\x05\x12\x03\x19\x04\t\n\x0c\n\x05\x04\x02\x02\x07\x01\
-    \x12\x03\x19\n\x17\n\x0c\n\x05\x04\x02\x02\x07\x03\x12\x03\x19\x1a\x1b\n\
-    \x0b\n\x04\x04\x02\x02\x08\x12\x03\x1a\x04\x1a\n\x0c\n\x05\x04\x02\x02\
-    \x08\x05\x12\x03\x1a\x04\t\n\x0c\n\x05\x04\x02\x02\x08\x01\x12\x03\x1a\n\
-    \x15\n\x0c\n\x05\x04\x02\x02\x08\x03\x12\x03\x1a\x18\x19\n\n\n\x02\x04\
-    \x03\x12\x04\x1c\0\x1e\x01\n\n\n\x03\x04\x03\x01\x12\x03\x1c\x08\x14\n\
-    \x0b\n\x04\x04\x03\x02\0\x12\x03\x1d\x04\x1c\n\x0c\n\x05\x04\x03\x02\0\
-    \x04\x12\x03\x1d\x04\x0c\n\x0c\n\x05\x04\x03\x02\0\x06\x12\x03\x1d\r\x11\
-    \n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x1d\x12\x17\n\x0c\n\x05\x04\x03\
-    \x02\0\x03\x12\x03\x1d\x1a\x1b\n\n\n\x02\x05\0\x12\x04\x1f\0\"\x01\n\n\n\
-    \x03\x05\0\x01\x12\x03\x1f\x05\r\n\x0b\n\x04\x05\0\x02\0\x12\x03\x20\x04\
-    \x0e\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x20\x04\t\n\x0c\n\x05\x05\0\x02\
-    \0\x02\x12\x03\x20\x0c\r\n\x0b\n\x04\x05\0\x02\x01\x12\x03!\x04\x0c\n\
-    \x0c\n\x05\x05\0\x02\x01\x01\x12\x03!\x04\x07\n\x0c\n\x05\x05\0\x02\x01\
-    \x02\x12\x03!\n\x0bb\x06proto3\
+    \n\x11view_create.proto\"\xe8\x01\n\x11CreateViewRequest\x12\"\n\x0cbelo\
+    ng_to_id\x18\x01\x20\x01(\tR\nbelongToIdB\0\x12\x14\n\x04name\x18\x02\
+    \x20\x01(\tR\x04nameB\0\x12\x14\n\x04desc\x18\x03\x20\x01(\tR\x04descB\0\
+    \x12\x20\n\tthumbnail\x18\x04\x20\x01(\tH\0R\tthumbnailB\0\x12(\n\tview_\
+    type\x18\x05\x20\x01(\x0e2\t.ViewTypeR\x08viewTypeB\0\x12!\n\x0bauto_ren\
+    ame\x18\x06\x20\x01(\x08R\nautoRenameB\0B\x12\n\x10one_of_thumbnail:\0\"\
+    \xc4\x01\n\x10CreateViewParams\x12\"\n\x0cbelong_to_id\x18\x01\x20\x01(\
+    \tR\nbelongToIdB\0\x12\x14\n\x04name\x18\x02\x20\x01(\tR\x04nameB\0\x12\
+    \x14\n\x04desc\x18\x03\x20\x01(\tR\x04descB\0\x12\x1e\n\tthumbnail\x18\
+    \x04\x20\x01(\tR\tthumbnailB\0\x12(\n\tview_type\x18\x05\x20\x01(\x0e2\t\
+    .ViewTypeR\x08viewTypeB\0\x12\x14\n\x04data\x18\x06\x20\x01(\tR\x04dataB\
+    \0:\0\"\xd9\x02\n\x04View\x12\x10\n\x02id\x18\x01\x20\x01(\tR\x02idB\0\
+    \x12\"\n\x0cbelong_to_id\x18\x02\x20\x01(\tR\nbelongToIdB\0\x12\x14\n\
+    \x04name\x18\x03\x20\x01(\tR\x04nameB\0\x12\x14\n\x04desc\x18\x04\x20\
+    \x01(\tR\x04descB\0\x12(\n\tview_type\x18\x05\x20\x01(\x0e2\t.ViewTypeR\
+    \x08viewTypeB\0\x12\x1a\n\x07version\x18\x06\x20\x01(\x03R\x07versionB\0\
+    \x12/\n\nbelongings\x18\x07\x20\x01(\x0b2\r.RepeatedViewR\nbelongingsB\0\
+    \x12%\n\rmodified_time\x18\x08\x20\x01(\x03R\x0cmodifiedTimeB\0\x12!\n\
+    \x0bcreate_time\x18\t\x20\x01(\x03R\ncreateTimeB\0\x12\x14\n\x04icon\x18\
+    \n\x20\x01(\tR\x04iconB\0\x12\x16\n\x05cover\x18\x0b\x20\x01(\tR\x05cove\
+    rB\0:\0\"/\n\x0cRepeatedView\x12\x1d\n\x05items\x18\x01\x20\x03(\x0b2\
+    \x05.ViewR\x05itemsB\0:\0**\n\x08ViewType\x12\t\n\x05Blank\x10\0\x12\x07\
+    \n\x03Doc\x10\x01\x12\x08\n\x04Grid\x10\x02\x1a\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,366 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `view_duplicate.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DuplicateDocumentRequest {
+    // message fields
+    pub view_id: ::std::string::String,
+    // message oneof groups
+    pub one_of_belong_to_id: ::std::option::Option<DuplicateDocumentRequest_oneof_one_of_belong_to_id>,
+    pub one_of_name: ::std::option::Option<DuplicateDocumentRequest_oneof_one_of_name>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DuplicateDocumentRequest {
+    fn default() -> &'a DuplicateDocumentRequest {
+        <DuplicateDocumentRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum DuplicateDocumentRequest_oneof_one_of_belong_to_id {
+    belong_to_id(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum DuplicateDocumentRequest_oneof_one_of_name {
+    name(::std::string::String),
+}
+
+impl DuplicateDocumentRequest {
+    pub fn new() -> DuplicateDocumentRequest {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // string belong_to_id = 2;
+
+
+    pub fn get_belong_to_id(&self) -> &str {
+        match self.one_of_belong_to_id {
+            ::std::option::Option::Some(DuplicateDocumentRequest_oneof_one_of_belong_to_id::belong_to_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_belong_to_id(&mut self) {
+        self.one_of_belong_to_id = ::std::option::Option::None;
+    }
+
+    pub fn has_belong_to_id(&self) -> bool {
+        match self.one_of_belong_to_id {
+            ::std::option::Option::Some(DuplicateDocumentRequest_oneof_one_of_belong_to_id::belong_to_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_belong_to_id(&mut self, v: ::std::string::String) {
+        self.one_of_belong_to_id = ::std::option::Option::Some(DuplicateDocumentRequest_oneof_one_of_belong_to_id::belong_to_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_belong_to_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(DuplicateDocumentRequest_oneof_one_of_belong_to_id::belong_to_id(_)) = self.one_of_belong_to_id {
+        } else {
+            self.one_of_belong_to_id = ::std::option::Option::Some(DuplicateDocumentRequest_oneof_one_of_belong_to_id::belong_to_id(::std::string::String::new()));
+        }
+        match self.one_of_belong_to_id {
+            ::std::option::Option::Some(DuplicateDocumentRequest_oneof_one_of_belong_to_id::belong_to_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_belong_to_id(&mut self) -> ::std::string::String {
+        if self.has_belong_to_id() {
+            match self.one_of_belong_to_id.take() {
+                ::std::option::Option::Some(DuplicateDocumentRequest_oneof_one_of_belong_to_id::belong_to_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string name = 3;
+
+
+    pub fn get_name(&self) -> &str {
+        match self.one_of_name {
+            ::std::option::Option::Some(DuplicateDocumentRequest_oneof_one_of_name::name(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_name(&mut self) {
+        self.one_of_name = ::std::option::Option::None;
+    }
+
+    pub fn has_name(&self) -> bool {
+        match self.one_of_name {
+            ::std::option::Option::Some(DuplicateDocumentRequest_oneof_one_of_name::name(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.one_of_name = ::std::option::Option::Some(DuplicateDocumentRequest_oneof_one_of_name::name(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(DuplicateDocumentRequest_oneof_one_of_name::name(_)) = self.one_of_name {
+        } else {
+            self.one_of_name = ::std::option::Option::Some(DuplicateDocumentRequest_oneof_one_of_name::name(::std::string::String::new()));
+        }
+        match self.one_of_name {
+            ::std::option::Option::Some(DuplicateDocumentRequest_oneof_one_of_name::name(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        if self.has_name() {
+            match self.one_of_name.take() {
+                ::std::option::Option::Some(DuplicateDocumentRequest_oneof_one_of_name::name(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for DuplicateDocumentRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_belong_to_id = ::std::option::Option::Some(DuplicateDocumentRequest_oneof_one_of_belong_to_id::belong_to_id(is.read_string()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_name = ::std::option::Option::Some(DuplicateDocumentRequest_oneof_one_of_name::name(is.read_string()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_belong_to_id {
+            match v {
+                &DuplicateDocumentRequest_oneof_one_of_belong_to_id::belong_to_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(2, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &DuplicateDocumentRequest_oneof_one_of_name::name(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_belong_to_id {
+            match v {
+                &DuplicateDocumentRequest_oneof_one_of_belong_to_id::belong_to_id(ref v) => {
+                    os.write_string(2, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &DuplicateDocumentRequest_oneof_one_of_name::name(ref v) => {
+                    os.write_string(3, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DuplicateDocumentRequest {
+        DuplicateDocumentRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &DuplicateDocumentRequest| { &m.view_id },
+                |m: &mut DuplicateDocumentRequest| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "belong_to_id",
+                DuplicateDocumentRequest::has_belong_to_id,
+                DuplicateDocumentRequest::get_belong_to_id,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "name",
+                DuplicateDocumentRequest::has_name,
+                DuplicateDocumentRequest::get_name,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DuplicateDocumentRequest>(
+                "DuplicateDocumentRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DuplicateDocumentRequest {
+        static instance: ::protobuf::rt::LazyV2<DuplicateDocumentRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DuplicateDocumentRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for DuplicateDocumentRequest {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.one_of_belong_to_id = ::std::option::Option::None;
+        self.one_of_name = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DuplicateDocumentRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DuplicateDocumentRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x14view_duplicate.proto\"\x9b\x01\n\x18DuplicateDocumentRequest\x12\
+    \x19\n\x07view_id\x18\x01\x20\x01(\tR\x06viewIdB\0\x12$\n\x0cbelong_to_i\
+    d\x18\x02\x20\x01(\tH\0R\nbelongToIdB\0\x12\x16\n\x04name\x18\x03\x20\
+    \x01(\tH\x01R\x04nameB\0B\x15\n\x13one_of_belong_to_idB\r\n\x0bone_of_na\
+    me:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,313 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `view_garbage.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GarbageCollectionResult {
+    // message fields
+    pub view_ids: ::protobuf::RepeatedField<::std::string::String>,
+    pub doc_ids: ::protobuf::RepeatedField<::std::string::String>,
+    pub blob_count: i64,
+    pub blob_size: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GarbageCollectionResult {
+    fn default() -> &'a GarbageCollectionResult {
+        <GarbageCollectionResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GarbageCollectionResult {
+    pub fn new() -> GarbageCollectionResult {
+        ::std::default::Default::default()
+    }
+
+    // repeated string view_ids = 1;
+
+
+    pub fn get_view_ids(&self) -> &[::std::string::String] {
+        &self.view_ids
+    }
+    pub fn clear_view_ids(&mut self) {
+        self.view_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.view_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_view_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.view_ids
+    }
+
+    // Take field
+    pub fn take_view_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.view_ids, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string doc_ids = 2;
+
+
+    pub fn get_doc_ids(&self) -> &[::std::string::String] {
+        &self.doc_ids
+    }
+    pub fn clear_doc_ids(&mut self) {
+        self.doc_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.doc_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_doc_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.doc_ids
+    }
+
+    // Take field
+    pub fn take_doc_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.doc_ids, ::protobuf::RepeatedField::new())
+    }
+
+    // int64 blob_count = 3;
+
+
+    pub fn get_blob_count(&self) -> i64 {
+        self.blob_count
+    }
+    pub fn clear_blob_count(&mut self) {
+        self.blob_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_blob_count(&mut self, v: i64) {
+        self.blob_count = v;
+    }
+
+    // int64 blob_size = 4;
+
+
+    pub fn get_blob_size(&self) -> i64 {
+        self.blob_size
+    }
+    pub fn clear_blob_size(&mut self) {
+        self.blob_size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_blob_size(&mut self, v: i64) {
+        self.blob_size = v;
+    }
+}
+
+impl ::protobuf::Message for GarbageCollectionResult {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.view_ids)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.doc_ids)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.blob_count = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.blob_size = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.view_ids {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in &self.doc_ids {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        if self.blob_count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.blob_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.blob_size != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.blob_size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.view_ids {
+            os.write_string(1, &v)?;
+        };
+        for v in &self.doc_ids {
+            os.write_string(2, &v)?;
+        };
+        if self.blob_count != 0 {
+            os.write_int64(3, self.blob_count)?;
+        }
+        if self.blob_size != 0 {
+            os.write_int64(4, self.blob_size)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GarbageCollectionResult {
+        GarbageCollectionResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_ids",
+                |m: &GarbageCollectionResult| { &m.view_ids },
+                |m: &mut GarbageCollectionResult| { &mut m.view_ids },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_ids",
+                |m: &GarbageCollectionResult| { &m.doc_ids },
+                |m: &mut GarbageCollectionResult| { &mut m.doc_ids },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "blob_count",
+                |m: &GarbageCollectionResult| { &m.blob_count },
+                |m: &mut GarbageCollectionResult| { &mut m.blob_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "blob_size",
+                |m: &GarbageCollectionResult| { &m.blob_size },
+                |m: &mut GarbageCollectionResult| { &mut m.blob_size },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GarbageCollectionResult>(
+                "GarbageCollectionResult",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GarbageCollectionResult {
+        static instance: ::protobuf::rt::LazyV2<GarbageCollectionResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GarbageCollectionResult::new)
+    }
+}
+
+impl ::protobuf::Clear for GarbageCollectionResult {
+    fn clear(&mut self) {
+        self.view_ids.clear();
+        self.doc_ids.clear();
+        self.blob_count = 0;
+        self.blob_size = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GarbageCollectionResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GarbageCollectionResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x12view_garbage.proto\"\x93\x01\n\x17GarbageCollectionResult\x12\x1b\
+    \n\x08view_ids\x18\x01\x20\x03(\tR\x07viewIdsB\0\x12\x19\n\x07doc_ids\
+    \x18\x02\x20\x03(\tR\x06docIdsB\0\x12\x1f\n\nblob_count\x18\x03\x20\x01(\
+    \x03R\tblobCountB\0\x12\x1d\n\tblob_size\x18\x04\x20\x01(\x03R\x08blobSi\
+    zeB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
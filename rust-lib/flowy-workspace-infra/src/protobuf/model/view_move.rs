@@ -0,0 +1,326 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `view_move.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MoveViewRequest {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub belong_to_id: ::std::string::String,
+    // message oneof groups
+    pub one_of_prev_view_id: ::std::option::Option<MoveViewRequest_oneof_one_of_prev_view_id>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MoveViewRequest {
+    fn default() -> &'a MoveViewRequest {
+        <MoveViewRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum MoveViewRequest_oneof_one_of_prev_view_id {
+    prev_view_id(::std::string::String),
+}
+
+impl MoveViewRequest {
+    pub fn new() -> MoveViewRequest {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // string belong_to_id = 2;
+
+
+    pub fn get_belong_to_id(&self) -> &str {
+        &self.belong_to_id
+    }
+    pub fn clear_belong_to_id(&mut self) {
+        self.belong_to_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_belong_to_id(&mut self, v: ::std::string::String) {
+        self.belong_to_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_belong_to_id(&mut self) -> &mut ::std::string::String {
+        &mut self.belong_to_id
+    }
+
+    // Take field
+    pub fn take_belong_to_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.belong_to_id, ::std::string::String::new())
+    }
+
+    // string prev_view_id = 3;
+
+
+    pub fn get_prev_view_id(&self) -> &str {
+        match self.one_of_prev_view_id {
+            ::std::option::Option::Some(MoveViewRequest_oneof_one_of_prev_view_id::prev_view_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_prev_view_id(&mut self) {
+        self.one_of_prev_view_id = ::std::option::Option::None;
+    }
+
+    pub fn has_prev_view_id(&self) -> bool {
+        match self.one_of_prev_view_id {
+            ::std::option::Option::Some(MoveViewRequest_oneof_one_of_prev_view_id::prev_view_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_prev_view_id(&mut self, v: ::std::string::String) {
+        self.one_of_prev_view_id = ::std::option::Option::Some(MoveViewRequest_oneof_one_of_prev_view_id::prev_view_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_prev_view_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(MoveViewRequest_oneof_one_of_prev_view_id::prev_view_id(_)) = self.one_of_prev_view_id {
+        } else {
+            self.one_of_prev_view_id = ::std::option::Option::Some(MoveViewRequest_oneof_one_of_prev_view_id::prev_view_id(::std::string::String::new()));
+        }
+        match self.one_of_prev_view_id {
+            ::std::option::Option::Some(MoveViewRequest_oneof_one_of_prev_view_id::prev_view_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_prev_view_id(&mut self) -> ::std::string::String {
+        if self.has_prev_view_id() {
+            match self.one_of_prev_view_id.take() {
+                ::std::option::Option::Some(MoveViewRequest_oneof_one_of_prev_view_id::prev_view_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for MoveViewRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.belong_to_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_prev_view_id = ::std::option::Option::Some(MoveViewRequest_oneof_one_of_prev_view_id::prev_view_id(is.read_string()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if !self.belong_to_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.belong_to_id);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_prev_view_id {
+            match v {
+                &MoveViewRequest_oneof_one_of_prev_view_id::prev_view_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if !self.belong_to_id.is_empty() {
+            os.write_string(2, &self.belong_to_id)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_prev_view_id {
+            match v {
+                &MoveViewRequest_oneof_one_of_prev_view_id::prev_view_id(ref v) => {
+                    os.write_string(3, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MoveViewRequest {
+        MoveViewRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &MoveViewRequest| { &m.view_id },
+                |m: &mut MoveViewRequest| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "belong_to_id",
+                |m: &MoveViewRequest| { &m.belong_to_id },
+                |m: &mut MoveViewRequest| { &mut m.belong_to_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "prev_view_id",
+                MoveViewRequest::has_prev_view_id,
+                MoveViewRequest::get_prev_view_id,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MoveViewRequest>(
+                "MoveViewRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MoveViewRequest {
+        static instance: ::protobuf::rt::LazyV2<MoveViewRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MoveViewRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for MoveViewRequest {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.belong_to_id.clear();
+        self.one_of_prev_view_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MoveViewRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MoveViewRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0fview_move.proto\"\x8f\x01\n\x0fMoveViewRequest\x12\x19\n\x07view_i\
+    d\x18\x01\x20\x01(\tR\x06viewIdB\0\x12\"\n\x0cbelong_to_id\x18\x02\x20\
+    \x01(\tR\nbelongToIdB\0\x12$\n\x0cprev_view_id\x18\x03\x20\x01(\tH\0R\np\
+    revViewIdB\0B\x15\n\x13one_of_prev_view_id:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
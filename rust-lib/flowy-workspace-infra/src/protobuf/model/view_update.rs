@@ -27,10 +27,13 @@
 pub struct UpdateViewRequest {
     // message fields
     pub view_id: ::std::string::String,
+    pub auto_rename: bool,
     // message oneof groups
     pub one_of_name: ::std::option::Option<UpdateViewRequest_oneof_one_of_name>,
     pub one_of_desc: ::std::option::Option<UpdateViewRequest_oneof_one_of_desc>,
     pub one_of_thumbnail: ::std::option::Option<UpdateViewRequest_oneof_one_of_thumbnail>,
+    pub one_of_icon: ::std::option::Option<UpdateViewRequest_oneof_one_of_icon>,
+    pub one_of_cover: ::std::option::Option<UpdateViewRequest_oneof_one_of_cover>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -57,6 +60,16 @@ pub enum UpdateViewRequest_oneof_one_of_thumbnail {
     thumbnail(::std::string::String),
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateViewRequest_oneof_one_of_icon {
+    icon(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateViewRequest_oneof_one_of_cover {
+    cover(::std::string::String),
+}
+
 impl UpdateViewRequest {
     pub fn new() -> UpdateViewRequest {
         ::std::default::Default::default()
@@ -234,6 +247,119 @@ impl UpdateViewRequest {
             ::std::string::String::new()
         }
     }
+
+    // string icon = 5;
+
+
+    pub fn get_icon(&self) -> &str {
+        match self.one_of_icon {
+            ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_icon::icon(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_icon(&mut self) {
+        self.one_of_icon = ::std::option::Option::None;
+    }
+
+    pub fn has_icon(&self) -> bool {
+        match self.one_of_icon {
+            ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_icon::icon(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_icon(&mut self, v: ::std::string::String) {
+        self.one_of_icon = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_icon::icon(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_icon(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_icon::icon(_)) = self.one_of_icon {
+        } else {
+            self.one_of_icon = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_icon::icon(::std::string::String::new()));
+        }
+        match self.one_of_icon {
+            ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_icon::icon(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_icon(&mut self) -> ::std::string::String {
+        if self.has_icon() {
+            match self.one_of_icon.take() {
+                ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_icon::icon(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string cover = 6;
+
+
+    pub fn get_cover(&self) -> &str {
+        match self.one_of_cover {
+            ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_cover::cover(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_cover(&mut self) {
+        self.one_of_cover = ::std::option::Option::None;
+    }
+
+    pub fn has_cover(&self) -> bool {
+        match self.one_of_cover {
+            ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_cover::cover(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cover(&mut self, v: ::std::string::String) {
+        self.one_of_cover = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_cover::cover(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cover(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_cover::cover(_)) = self.one_of_cover {
+        } else {
+            self.one_of_cover = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_cover::cover(::std::string::String::new()));
+        }
+        match self.one_of_cover {
+            ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_cover::cover(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_cover(&mut self) -> ::std::string::String {
+        if self.has_cover() {
+            match self.one_of_cover.take() {
+                ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_cover::cover(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // bool auto_rename = 7;
+
+
+    pub fn get_auto_rename(&self) -> bool {
+        self.auto_rename
+    }
+    pub fn clear_auto_rename(&mut self) {
+        self.auto_rename = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_auto_rename(&mut self, v: bool) {
+        self.auto_rename = v;
+    }
 }
 
 impl ::protobuf::Message for UpdateViewRequest {
@@ -266,6 +392,25 @@ impl ::protobuf::Message for UpdateViewRequest {
                     }
                     self.one_of_thumbnail = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_thumbnail::thumbnail(is.read_string()?));
                 },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_icon = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_icon::icon(is.read_string()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_cover = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_cover::cover(is.read_string()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.auto_rename = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -281,6 +426,9 @@ impl ::protobuf::Message for UpdateViewRequest {
         if !self.view_id.is_empty() {
             my_size += ::protobuf::rt::string_size(1, &self.view_id);
         }
+        if self.auto_rename != false {
+            my_size += 2;
+        }
         if let ::std::option::Option::Some(ref v) = self.one_of_name {
             match v {
                 &UpdateViewRequest_oneof_one_of_name::name(ref v) => {
@@ -302,6 +450,20 @@ impl ::protobuf::Message for UpdateViewRequest {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_icon {
+            match v {
+                &UpdateViewRequest_oneof_one_of_icon::icon(ref v) => {
+                    my_size += ::protobuf::rt::string_size(5, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_cover {
+            match v {
+                &UpdateViewRequest_oneof_one_of_cover::cover(ref v) => {
+                    my_size += ::protobuf::rt::string_size(6, &v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -311,6 +473,9 @@ impl ::protobuf::Message for UpdateViewRequest {
         if !self.view_id.is_empty() {
             os.write_string(1, &self.view_id)?;
         }
+        if self.auto_rename != false {
+            os.write_bool(7, self.auto_rename)?;
+        }
         if let ::std::option::Option::Some(ref v) = self.one_of_name {
             match v {
                 &UpdateViewRequest_oneof_one_of_name::name(ref v) => {
@@ -332,6 +497,20 @@ impl ::protobuf::Message for UpdateViewRequest {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_icon {
+            match v {
+                &UpdateViewRequest_oneof_one_of_icon::icon(ref v) => {
+                    os.write_string(5, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_cover {
+            match v {
+                &UpdateViewRequest_oneof_one_of_cover::cover(ref v) => {
+                    os.write_string(6, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -390,6 +569,21 @@ impl ::protobuf::Message for UpdateViewRequest {
                 UpdateViewRequest::has_thumbnail,
                 UpdateViewRequest::get_thumbnail,
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "icon",
+                UpdateViewRequest::has_icon,
+                UpdateViewRequest::get_icon,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "cover",
+                UpdateViewRequest::has_cover,
+                UpdateViewRequest::get_cover,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "auto_rename",
+                |m: &UpdateViewRequest| { &m.auto_rename },
+                |m: &mut UpdateViewRequest| { &mut m.auto_rename },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateViewRequest>(
                 "UpdateViewRequest",
                 fields,
@@ -410,6 +604,9 @@ impl ::protobuf::Clear for UpdateViewRequest {
         self.one_of_name = ::std::option::Option::None;
         self.one_of_desc = ::std::option::Option::None;
         self.one_of_thumbnail = ::std::option::Option::None;
+        self.one_of_icon = ::std::option::Option::None;
+        self.one_of_cover = ::std::option::Option::None;
+        self.auto_rename = false;
         self.unknown_fields.clear();
     }
 }
@@ -434,6 +631,8 @@ pub struct UpdateViewParams {
     pub one_of_name: ::std::option::Option<UpdateViewParams_oneof_one_of_name>,
     pub one_of_desc: ::std::option::Option<UpdateViewParams_oneof_one_of_desc>,
     pub one_of_thumbnail: ::std::option::Option<UpdateViewParams_oneof_one_of_thumbnail>,
+    pub one_of_icon: ::std::option::Option<UpdateViewParams_oneof_one_of_icon>,
+    pub one_of_cover: ::std::option::Option<UpdateViewParams_oneof_one_of_cover>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -460,6 +659,16 @@ pub enum UpdateViewParams_oneof_one_of_thumbnail {
     thumbnail(::std::string::String),
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateViewParams_oneof_one_of_icon {
+    icon(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateViewParams_oneof_one_of_cover {
+    cover(::std::string::String),
+}
+
 impl UpdateViewParams {
     pub fn new() -> UpdateViewParams {
         ::std::default::Default::default()
@@ -637,6 +846,104 @@ impl UpdateViewParams {
             ::std::string::String::new()
         }
     }
+
+    // string icon = 5;
+
+
+    pub fn get_icon(&self) -> &str {
+        match self.one_of_icon {
+            ::std::option::Option::Some(UpdateViewParams_oneof_one_of_icon::icon(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_icon(&mut self) {
+        self.one_of_icon = ::std::option::Option::None;
+    }
+
+    pub fn has_icon(&self) -> bool {
+        match self.one_of_icon {
+            ::std::option::Option::Some(UpdateViewParams_oneof_one_of_icon::icon(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_icon(&mut self, v: ::std::string::String) {
+        self.one_of_icon = ::std::option::Option::Some(UpdateViewParams_oneof_one_of_icon::icon(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_icon(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateViewParams_oneof_one_of_icon::icon(_)) = self.one_of_icon {
+        } else {
+            self.one_of_icon = ::std::option::Option::Some(UpdateViewParams_oneof_one_of_icon::icon(::std::string::String::new()));
+        }
+        match self.one_of_icon {
+            ::std::option::Option::Some(UpdateViewParams_oneof_one_of_icon::icon(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_icon(&mut self) -> ::std::string::String {
+        if self.has_icon() {
+            match self.one_of_icon.take() {
+                ::std::option::Option::Some(UpdateViewParams_oneof_one_of_icon::icon(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string cover = 6;
+
+
+    pub fn get_cover(&self) -> &str {
+        match self.one_of_cover {
+            ::std::option::Option::Some(UpdateViewParams_oneof_one_of_cover::cover(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_cover(&mut self) {
+        self.one_of_cover = ::std::option::Option::None;
+    }
+
+    pub fn has_cover(&self) -> bool {
+        match self.one_of_cover {
+            ::std::option::Option::Some(UpdateViewParams_oneof_one_of_cover::cover(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cover(&mut self, v: ::std::string::String) {
+        self.one_of_cover = ::std::option::Option::Some(UpdateViewParams_oneof_one_of_cover::cover(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cover(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateViewParams_oneof_one_of_cover::cover(_)) = self.one_of_cover {
+        } else {
+            self.one_of_cover = ::std::option::Option::Some(UpdateViewParams_oneof_one_of_cover::cover(::std::string::String::new()));
+        }
+        match self.one_of_cover {
+            ::std::option::Option::Some(UpdateViewParams_oneof_one_of_cover::cover(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_cover(&mut self) -> ::std::string::String {
+        if self.has_cover() {
+            match self.one_of_cover.take() {
+                ::std::option::Option::Some(UpdateViewParams_oneof_one_of_cover::cover(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for UpdateViewParams {
@@ -669,6 +976,18 @@ impl ::protobuf::Message for UpdateViewParams {
                     }
                     self.one_of_thumbnail = ::std::option::Option::Some(UpdateViewParams_oneof_one_of_thumbnail::thumbnail(is.read_string()?));
                 },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_icon = ::std::option::Option::Some(UpdateViewParams_oneof_one_of_icon::icon(is.read_string()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_cover = ::std::option::Option::Some(UpdateViewParams_oneof_one_of_cover::cover(is.read_string()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -705,6 +1024,20 @@ impl ::protobuf::Message for UpdateViewParams {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_icon {
+            match v {
+                &UpdateViewParams_oneof_one_of_icon::icon(ref v) => {
+                    my_size += ::protobuf::rt::string_size(5, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_cover {
+            match v {
+                &UpdateViewParams_oneof_one_of_cover::cover(ref v) => {
+                    my_size += ::protobuf::rt::string_size(6, &v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -735,6 +1068,20 @@ impl ::protobuf::Message for UpdateViewParams {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_icon {
+            match v {
+                &UpdateViewParams_oneof_one_of_icon::icon(ref v) => {
+                    os.write_string(5, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_cover {
+            match v {
+                &UpdateViewParams_oneof_one_of_cover::cover(ref v) => {
+                    os.write_string(6, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -793,6 +1140,16 @@ impl ::protobuf::Message for UpdateViewParams {
                 UpdateViewParams::has_thumbnail,
                 UpdateViewParams::get_thumbnail,
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "icon",
+                UpdateViewParams::has_icon,
+                UpdateViewParams::get_icon,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "cover",
+                UpdateViewParams::has_cover,
+                UpdateViewParams::get_cover,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateViewParams>(
                 "UpdateViewParams",
                 fields,
@@ -813,6 +1170,8 @@ impl ::protobuf::Clear for UpdateViewParams {
         self.one_of_name = ::std::option::Option::None;
         self.one_of_desc = ::std::option::Option::None;
         self.one_of_thumbnail = ::std::option::Option::None;
+        self.one_of_icon = ::std::option::Option::None;
+        self.one_of_cover = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -830,47 +1189,21 @@ impl ::protobuf::reflect::ProtobufValue for UpdateViewParams {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x11view_update.proto\"\xaa\x01\n\x11UpdateViewRequest\x12\x17\n\x07vi\
-    ew_id\x18\x01\x20\x01(\tR\x06viewId\x12\x14\n\x04name\x18\x02\x20\x01(\t\
-    H\0R\x04name\x12\x14\n\x04desc\x18\x03\x20\x01(\tH\x01R\x04desc\x12\x1e\
-    \n\tthumbnail\x18\x04\x20\x01(\tH\x02R\tthumbnailB\r\n\x0bone_of_nameB\r\
-    \n\x0bone_of_descB\x12\n\x10one_of_thumbnail\"\xa9\x01\n\x10UpdateViewPa\
-    rams\x12\x17\n\x07view_id\x18\x01\x20\x01(\tR\x06viewId\x12\x14\n\x04nam\
-    e\x18\x02\x20\x01(\tH\0R\x04name\x12\x14\n\x04desc\x18\x03\x20\x01(\tH\
-    \x01R\x04desc\x12\x1e\n\tthumbnail\x18\x04\x20\x01(\tH\x02R\tthumbnailB\
-    \r\n\x0bone_of_nameB\r\n\x0bone_of_descB\x12\n\x10one_of_thumbnailJ\x9c\
-    \x05\n\x06\x12\x04\0\0\r\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\
-    \x04\0\x12\x04\x02\0\x07\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x19\n\
-    \x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x17\n\x0c\n\x05\x04\0\x02\0\x05\
-    \x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x12\n\x0c\
-    \n\x05\x04\0\x02\0\x03\x12\x03\x03\x15\x16\n\x0b\n\x04\x04\0\x08\0\x12\
-    \x03\x04\x04*\n\x0c\n\x05\x04\0\x08\0\x01\x12\x03\x04\n\x15\n\x0b\n\x04\
-    \x04\0\x02\x01\x12\x03\x04\x18(\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\
-    \x04\x18\x1e\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x1f#\n\x0c\n\x05\
-    \x04\0\x02\x01\x03\x12\x03\x04&'\n\x0b\n\x04\x04\0\x08\x01\x12\x03\x05\
-    \x04*\n\x0c\n\x05\x04\0\x08\x01\x01\x12\x03\x05\n\x15\n\x0b\n\x04\x04\0\
-    \x02\x02\x12\x03\x05\x18(\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\x18\
-    \x1e\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\x1f#\n\x0c\n\x05\x04\0\
-    \x02\x02\x03\x12\x03\x05&'\n\x0b\n\x04\x04\0\x08\x02\x12\x03\x06\x044\n\
-    \x0c\n\x05\x04\0\x08\x02\x01\x12\x03\x06\n\x1a\n\x0b\n\x04\x04\0\x02\x03\
-    \x12\x03\x06\x1d2\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x06\x1d#\n\x0c\n\
-    \x05\x04\0\x02\x03\x01\x12\x03\x06$-\n\x0c\n\x05\x04\0\x02\x03\x03\x12\
-    \x03\x0601\n\n\n\x02\x04\x01\x12\x04\x08\0\r\x01\n\n\n\x03\x04\x01\x01\
-    \x12\x03\x08\x08\x18\n\x0b\n\x04\x04\x01\x02\0\x12\x03\t\x04\x17\n\x0c\n\
-    \x05\x04\x01\x02\0\x05\x12\x03\t\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\
-    \x03\t\x0b\x12\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\t\x15\x16\n\x0b\n\
-    \x04\x04\x01\x08\0\x12\x03\n\x04*\n\x0c\n\x05\x04\x01\x08\0\x01\x12\x03\
-    \n\n\x15\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\n\x18(\n\x0c\n\x05\x04\x01\
-    \x02\x01\x05\x12\x03\n\x18\x1e\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\n\
-    \x1f#\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\n&'\n\x0b\n\x04\x04\x01\
-    \x08\x01\x12\x03\x0b\x04*\n\x0c\n\x05\x04\x01\x08\x01\x01\x12\x03\x0b\n\
-    \x15\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\x0b\x18(\n\x0c\n\x05\x04\x01\
-    \x02\x02\x05\x12\x03\x0b\x18\x1e\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\
-    \x0b\x1f#\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\x0b&'\n\x0b\n\x04\x04\
-    \x01\x08\x02\x12\x03\x0c\x044\n\x0c\n\x05\x04\x01\x08\x02\x01\x12\x03\
-    \x0c\n\x1a\n\x0b\n\x04\x04\x01\x02\x03\x12\x03\x0c\x1d2\n\x0c\n\x05\x04\
-    \x01\x02\x03\x05\x12\x03\x0c\x1d#\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\
-    \x03\x0c$-\n\x0c\n\x05\x04\x01\x02\x03\x03\x12\x03\x0c01b\x06proto3\
+    \n\x11view_update.proto\"\xa8\x02\n\x11UpdateViewRequest\x12\x19\n\x07vi\
+    ew_id\x18\x01\x20\x01(\tR\x06viewIdB\0\x12\x16\n\x04name\x18\x02\x20\x01\
+    (\tH\0R\x04nameB\0\x12\x16\n\x04desc\x18\x03\x20\x01(\tH\x01R\x04descB\0\
+    \x12\x20\n\tthumbnail\x18\x04\x20\x01(\tH\x02R\tthumbnailB\0\x12\x16\n\
+    \x04icon\x18\x05\x20\x01(\tH\x03R\x04iconB\0\x12\x18\n\x05cover\x18\x06\
+    \x20\x01(\tH\x04R\x05coverB\0\x12!\n\x0bauto_rename\x18\x07\x20\x01(\x08\
+    R\nautoRenameB\0B\r\n\x0bone_of_nameB\r\n\x0bone_of_descB\x12\n\x10one_o\
+    f_thumbnailB\r\n\x0bone_of_iconB\x0e\n\x0cone_of_cover:\0\"\x84\x02\n\
+    \x10UpdateViewParams\x12\x19\n\x07view_id\x18\x01\x20\x01(\tR\x06viewIdB\
+    \0\x12\x16\n\x04name\x18\x02\x20\x01(\tH\0R\x04nameB\0\x12\x16\n\x04desc\
+    \x18\x03\x20\x01(\tH\x01R\x04descB\0\x12\x20\n\tthumbnail\x18\x04\x20\
+    \x01(\tH\x02R\tthumbnailB\0\x12\x16\n\x04icon\x18\x05\x20\x01(\tH\x03R\
+    \x04iconB\0\x12\x18\n\x05cover\x18\x06\x20\x01(\tH\x04R\x05coverB\0B\r\n\
+    \x0bone_of_nameB\r\n\x0bone_of_descB\x12\n\x10one_of_thumbnailB\r\n\x0bo\
+    ne_of_iconB\x0e\n\x0cone_of_cover:\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,482 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `workspace_encryption.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkspaceEncryption {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    pub enabled: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WorkspaceEncryption {
+    fn default() -> &'a WorkspaceEncryption {
+        <WorkspaceEncryption as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WorkspaceEncryption {
+    pub fn new() -> WorkspaceEncryption {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // bool enabled = 2;
+
+
+    pub fn get_enabled(&self) -> bool {
+        self.enabled
+    }
+    pub fn clear_enabled(&mut self) {
+        self.enabled = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enabled(&mut self, v: bool) {
+        self.enabled = v;
+    }
+}
+
+impl ::protobuf::Message for WorkspaceEncryption {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.enabled = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if self.enabled != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if self.enabled != false {
+            os.write_bool(2, self.enabled)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkspaceEncryption {
+        WorkspaceEncryption::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &WorkspaceEncryption| { &m.workspace_id },
+                |m: &mut WorkspaceEncryption| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "enabled",
+                |m: &WorkspaceEncryption| { &m.enabled },
+                |m: &mut WorkspaceEncryption| { &mut m.enabled },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WorkspaceEncryption>(
+                "WorkspaceEncryption",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WorkspaceEncryption {
+        static instance: ::protobuf::rt::LazyV2<WorkspaceEncryption> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WorkspaceEncryption::new)
+    }
+}
+
+impl ::protobuf::Clear for WorkspaceEncryption {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.enabled = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkspaceEncryption {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkspaceEncryption {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AcceptWorkspaceInvitationRequest {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    pub wrapped_key: ::std::string::String,
+    pub invite_secret: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AcceptWorkspaceInvitationRequest {
+    fn default() -> &'a AcceptWorkspaceInvitationRequest {
+        <AcceptWorkspaceInvitationRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AcceptWorkspaceInvitationRequest {
+    pub fn new() -> AcceptWorkspaceInvitationRequest {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string wrapped_key = 2;
+
+
+    pub fn get_wrapped_key(&self) -> &str {
+        &self.wrapped_key
+    }
+    pub fn clear_wrapped_key(&mut self) {
+        self.wrapped_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_wrapped_key(&mut self, v: ::std::string::String) {
+        self.wrapped_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_wrapped_key(&mut self) -> &mut ::std::string::String {
+        &mut self.wrapped_key
+    }
+
+    // Take field
+    pub fn take_wrapped_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.wrapped_key, ::std::string::String::new())
+    }
+
+    // string invite_secret = 3;
+
+
+    pub fn get_invite_secret(&self) -> &str {
+        &self.invite_secret
+    }
+    pub fn clear_invite_secret(&mut self) {
+        self.invite_secret.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_invite_secret(&mut self, v: ::std::string::String) {
+        self.invite_secret = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_invite_secret(&mut self) -> &mut ::std::string::String {
+        &mut self.invite_secret
+    }
+
+    // Take field
+    pub fn take_invite_secret(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.invite_secret, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for AcceptWorkspaceInvitationRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.wrapped_key)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.invite_secret)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if !self.wrapped_key.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.wrapped_key);
+        }
+        if !self.invite_secret.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.invite_secret);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if !self.wrapped_key.is_empty() {
+            os.write_string(2, &self.wrapped_key)?;
+        }
+        if !self.invite_secret.is_empty() {
+            os.write_string(3, &self.invite_secret)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AcceptWorkspaceInvitationRequest {
+        AcceptWorkspaceInvitationRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &AcceptWorkspaceInvitationRequest| { &m.workspace_id },
+                |m: &mut AcceptWorkspaceInvitationRequest| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "wrapped_key",
+                |m: &AcceptWorkspaceInvitationRequest| { &m.wrapped_key },
+                |m: &mut AcceptWorkspaceInvitationRequest| { &mut m.wrapped_key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "invite_secret",
+                |m: &AcceptWorkspaceInvitationRequest| { &m.invite_secret },
+                |m: &mut AcceptWorkspaceInvitationRequest| { &mut m.invite_secret },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AcceptWorkspaceInvitationRequest>(
+                "AcceptWorkspaceInvitationRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AcceptWorkspaceInvitationRequest {
+        static instance: ::protobuf::rt::LazyV2<AcceptWorkspaceInvitationRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AcceptWorkspaceInvitationRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for AcceptWorkspaceInvitationRequest {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.wrapped_key.clear();
+        self.invite_secret.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AcceptWorkspaceInvitationRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AcceptWorkspaceInvitationRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x1aworkspace_encryption.proto\"X\n\x13WorkspaceEncryption\x12#\n\x0cw\
+    orkspace_id\x18\x01\x20\x01(\tR\x0bworkspaceIdB\0\x12\x1a\n\x07enabled\
+    \x18\x02\x20\x01(\x08R\x07enabledB\0:\0\"\x93\x01\n\x20AcceptWorkspaceIn\
+    vitationRequest\x12#\n\x0cworkspace_id\x18\x01\x20\x01(\tR\x0bworkspaceI\
+    dB\0\x12!\n\x0bwrapped_key\x18\x02\x20\x01(\tR\nwrappedKeyB\0\x12%\n\rin\
+    vite_secret\x18\x03\x20\x01(\tR\x0cinviteSecretB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
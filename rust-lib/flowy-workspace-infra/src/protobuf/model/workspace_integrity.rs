@@ -0,0 +1,696 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `workspace_integrity.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CheckStorageIntegrityRequest {
+    // message fields
+    pub repair: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CheckStorageIntegrityRequest {
+    fn default() -> &'a CheckStorageIntegrityRequest {
+        <CheckStorageIntegrityRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CheckStorageIntegrityRequest {
+    pub fn new() -> CheckStorageIntegrityRequest {
+        ::std::default::Default::default()
+    }
+
+    // bool repair = 1;
+
+
+    pub fn get_repair(&self) -> bool {
+        self.repair
+    }
+    pub fn clear_repair(&mut self) {
+        self.repair = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_repair(&mut self, v: bool) {
+        self.repair = v;
+    }
+}
+
+impl ::protobuf::Message for CheckStorageIntegrityRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.repair = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.repair != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.repair != false {
+            os.write_bool(1, self.repair)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CheckStorageIntegrityRequest {
+        CheckStorageIntegrityRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "repair",
+                |m: &CheckStorageIntegrityRequest| { &m.repair },
+                |m: &mut CheckStorageIntegrityRequest| { &mut m.repair },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CheckStorageIntegrityRequest>(
+                "CheckStorageIntegrityRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CheckStorageIntegrityRequest {
+        static instance: ::protobuf::rt::LazyV2<CheckStorageIntegrityRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CheckStorageIntegrityRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CheckStorageIntegrityRequest {
+    fn clear(&mut self) {
+        self.repair = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CheckStorageIntegrityRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CheckStorageIntegrityRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct IntegrityIssue {
+    // message fields
+    pub ty: IntegrityIssueType,
+    pub id: ::std::string::String,
+    pub repaired: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a IntegrityIssue {
+    fn default() -> &'a IntegrityIssue {
+        <IntegrityIssue as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl IntegrityIssue {
+    pub fn new() -> IntegrityIssue {
+        ::std::default::Default::default()
+    }
+
+    // .IntegrityIssueType ty = 1;
+
+
+    pub fn get_ty(&self) -> IntegrityIssueType {
+        self.ty
+    }
+    pub fn clear_ty(&mut self) {
+        self.ty = IntegrityIssueType::Unknown;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ty(&mut self, v: IntegrityIssueType) {
+        self.ty = v;
+    }
+
+    // string id = 2;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // bool repaired = 3;
+
+
+    pub fn get_repaired(&self) -> bool {
+        self.repaired
+    }
+    pub fn clear_repaired(&mut self) {
+        self.repaired = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_repaired(&mut self, v: bool) {
+        self.repaired = v;
+    }
+}
+
+impl ::protobuf::Message for IntegrityIssue {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.ty, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.repaired = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.ty != IntegrityIssueType::Unknown {
+            my_size += ::protobuf::rt::enum_size(1, self.ty);
+        }
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.id);
+        }
+        if self.repaired != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.ty != IntegrityIssueType::Unknown {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.ty))?;
+        }
+        if !self.id.is_empty() {
+            os.write_string(2, &self.id)?;
+        }
+        if self.repaired != false {
+            os.write_bool(3, self.repaired)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> IntegrityIssue {
+        IntegrityIssue::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<IntegrityIssueType>>(
+                "ty",
+                |m: &IntegrityIssue| { &m.ty },
+                |m: &mut IntegrityIssue| { &mut m.ty },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &IntegrityIssue| { &m.id },
+                |m: &mut IntegrityIssue| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "repaired",
+                |m: &IntegrityIssue| { &m.repaired },
+                |m: &mut IntegrityIssue| { &mut m.repaired },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<IntegrityIssue>(
+                "IntegrityIssue",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static IntegrityIssue {
+        static instance: ::protobuf::rt::LazyV2<IntegrityIssue> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(IntegrityIssue::new)
+    }
+}
+
+impl ::protobuf::Clear for IntegrityIssue {
+    fn clear(&mut self) {
+        self.ty = IntegrityIssueType::Unknown;
+        self.id.clear();
+        self.repaired = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for IntegrityIssue {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for IntegrityIssue {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StorageIntegrityReport {
+    // message fields
+    pub database_errors: ::protobuf::RepeatedField<::std::string::String>,
+    pub issues: ::protobuf::RepeatedField<IntegrityIssue>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StorageIntegrityReport {
+    fn default() -> &'a StorageIntegrityReport {
+        <StorageIntegrityReport as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StorageIntegrityReport {
+    pub fn new() -> StorageIntegrityReport {
+        ::std::default::Default::default()
+    }
+
+    // repeated string database_errors = 1;
+
+
+    pub fn get_database_errors(&self) -> &[::std::string::String] {
+        &self.database_errors
+    }
+    pub fn clear_database_errors(&mut self) {
+        self.database_errors.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_database_errors(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.database_errors = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_database_errors(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.database_errors
+    }
+
+    // Take field
+    pub fn take_database_errors(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.database_errors, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .IntegrityIssue issues = 2;
+
+
+    pub fn get_issues(&self) -> &[IntegrityIssue] {
+        &self.issues
+    }
+    pub fn clear_issues(&mut self) {
+        self.issues.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_issues(&mut self, v: ::protobuf::RepeatedField<IntegrityIssue>) {
+        self.issues = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_issues(&mut self) -> &mut ::protobuf::RepeatedField<IntegrityIssue> {
+        &mut self.issues
+    }
+
+    // Take field
+    pub fn take_issues(&mut self) -> ::protobuf::RepeatedField<IntegrityIssue> {
+        ::std::mem::replace(&mut self.issues, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for StorageIntegrityReport {
+    fn is_initialized(&self) -> bool {
+        for v in &self.issues {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.database_errors)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.issues)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.database_errors {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in &self.issues {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.database_errors {
+            os.write_string(1, &v)?;
+        };
+        for v in &self.issues {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StorageIntegrityReport {
+        StorageIntegrityReport::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "database_errors",
+                |m: &StorageIntegrityReport| { &m.database_errors },
+                |m: &mut StorageIntegrityReport| { &mut m.database_errors },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<IntegrityIssue>>(
+                "issues",
+                |m: &StorageIntegrityReport| { &m.issues },
+                |m: &mut StorageIntegrityReport| { &mut m.issues },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<StorageIntegrityReport>(
+                "StorageIntegrityReport",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static StorageIntegrityReport {
+        static instance: ::protobuf::rt::LazyV2<StorageIntegrityReport> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(StorageIntegrityReport::new)
+    }
+}
+
+impl ::protobuf::Clear for StorageIntegrityReport {
+    fn clear(&mut self) {
+        self.database_errors.clear();
+        self.issues.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StorageIntegrityReport {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StorageIntegrityReport {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum IntegrityIssueType {
+    Unknown = 0,
+    AppWithoutWorkspace = 1,
+    ViewWithoutParent = 2,
+    ViewCycle = 3,
+    TrashWithoutItem = 4,
+    LastViewMissing = 5,
+}
+
+impl ::protobuf::ProtobufEnum for IntegrityIssueType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<IntegrityIssueType> {
+        match value {
+            0 => ::std::option::Option::Some(IntegrityIssueType::Unknown),
+            1 => ::std::option::Option::Some(IntegrityIssueType::AppWithoutWorkspace),
+            2 => ::std::option::Option::Some(IntegrityIssueType::ViewWithoutParent),
+            3 => ::std::option::Option::Some(IntegrityIssueType::ViewCycle),
+            4 => ::std::option::Option::Some(IntegrityIssueType::TrashWithoutItem),
+            5 => ::std::option::Option::Some(IntegrityIssueType::LastViewMissing),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [IntegrityIssueType] = &[
+            IntegrityIssueType::Unknown,
+            IntegrityIssueType::AppWithoutWorkspace,
+            IntegrityIssueType::ViewWithoutParent,
+            IntegrityIssueType::ViewCycle,
+            IntegrityIssueType::TrashWithoutItem,
+            IntegrityIssueType::LastViewMissing,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<IntegrityIssueType>("IntegrityIssueType", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for IntegrityIssueType {
+}
+
+impl ::std::default::Default for IntegrityIssueType {
+    fn default() -> Self {
+        IntegrityIssueType::Unknown
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for IntegrityIssueType {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x19workspace_integrity.proto\":\n\x1cCheckStorageIntegrityRequest\x12\
+    \x18\n\x06repair\x18\x01\x20\x01(\x08R\x06repairB\0:\0\"i\n\x0eIntegrity\
+    Issue\x12%\n\x02ty\x18\x01\x20\x01(\x0e2\x13.IntegrityIssueTypeR\x02tyB\
+    \0\x12\x10\n\x02id\x18\x02\x20\x01(\tR\x02idB\0\x12\x1c\n\x08repaired\
+    \x18\x03\x20\x01(\x08R\x08repairedB\0:\0\"p\n\x16StorageIntegrityReport\
+    \x12)\n\x0fdatabase_errors\x18\x01\x20\x03(\tR\x0edatabaseErrorsB\0\x12)\
+    \n\x06issues\x18\x02\x20\x03(\x0b2\x0f.IntegrityIssueR\x06issuesB\0:\0*\
+    \x8d\x01\n\x12IntegrityIssueType\x12\x0b\n\x07Unknown\x10\0\x12\x17\n\
+    \x13AppWithoutWorkspace\x10\x01\x12\x15\n\x11ViewWithoutParent\x10\x02\
+    \x12\r\n\tViewCycle\x10\x03\x12\x14\n\x10TrashWithoutItem\x10\x04\x12\
+    \x13\n\x0fLastViewMissing\x10\x05\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
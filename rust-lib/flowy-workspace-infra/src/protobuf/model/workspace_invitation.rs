@@ -0,0 +1,1039 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `workspace_invitation.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkspaceInvitation {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    pub email: ::std::string::String,
+    pub role: super::workspace_member::WorkspaceRole,
+    pub invited_time: i64,
+    pub wrapped_key: ::std::string::String,
+    pub invite_secret: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WorkspaceInvitation {
+    fn default() -> &'a WorkspaceInvitation {
+        <WorkspaceInvitation as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WorkspaceInvitation {
+    pub fn new() -> WorkspaceInvitation {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string email = 2;
+
+
+    pub fn get_email(&self) -> &str {
+        &self.email
+    }
+    pub fn clear_email(&mut self) {
+        self.email.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_email(&mut self, v: ::std::string::String) {
+        self.email = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_email(&mut self) -> &mut ::std::string::String {
+        &mut self.email
+    }
+
+    // Take field
+    pub fn take_email(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.email, ::std::string::String::new())
+    }
+
+    // .WorkspaceRole role = 3;
+
+
+    pub fn get_role(&self) -> super::workspace_member::WorkspaceRole {
+        self.role
+    }
+    pub fn clear_role(&mut self) {
+        self.role = super::workspace_member::WorkspaceRole::Viewer;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: super::workspace_member::WorkspaceRole) {
+        self.role = v;
+    }
+
+    // int64 invited_time = 4;
+
+
+    pub fn get_invited_time(&self) -> i64 {
+        self.invited_time
+    }
+    pub fn clear_invited_time(&mut self) {
+        self.invited_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_invited_time(&mut self, v: i64) {
+        self.invited_time = v;
+    }
+
+    // string wrapped_key = 5;
+
+
+    pub fn get_wrapped_key(&self) -> &str {
+        &self.wrapped_key
+    }
+    pub fn clear_wrapped_key(&mut self) {
+        self.wrapped_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_wrapped_key(&mut self, v: ::std::string::String) {
+        self.wrapped_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_wrapped_key(&mut self) -> &mut ::std::string::String {
+        &mut self.wrapped_key
+    }
+
+    // Take field
+    pub fn take_wrapped_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.wrapped_key, ::std::string::String::new())
+    }
+
+    // string invite_secret = 6;
+
+
+    pub fn get_invite_secret(&self) -> &str {
+        &self.invite_secret
+    }
+    pub fn clear_invite_secret(&mut self) {
+        self.invite_secret.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_invite_secret(&mut self, v: ::std::string::String) {
+        self.invite_secret = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_invite_secret(&mut self) -> &mut ::std::string::String {
+        &mut self.invite_secret
+    }
+
+    // Take field
+    pub fn take_invite_secret(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.invite_secret, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for WorkspaceInvitation {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.email)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.role, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.invited_time = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.wrapped_key)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.invite_secret)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if !self.email.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.email);
+        }
+        if self.role != super::workspace_member::WorkspaceRole::Viewer {
+            my_size += ::protobuf::rt::enum_size(3, self.role);
+        }
+        if self.invited_time != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.invited_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.wrapped_key.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.wrapped_key);
+        }
+        if !self.invite_secret.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.invite_secret);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if !self.email.is_empty() {
+            os.write_string(2, &self.email)?;
+        }
+        if self.role != super::workspace_member::WorkspaceRole::Viewer {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.role))?;
+        }
+        if self.invited_time != 0 {
+            os.write_int64(4, self.invited_time)?;
+        }
+        if !self.wrapped_key.is_empty() {
+            os.write_string(5, &self.wrapped_key)?;
+        }
+        if !self.invite_secret.is_empty() {
+            os.write_string(6, &self.invite_secret)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkspaceInvitation {
+        WorkspaceInvitation::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &WorkspaceInvitation| { &m.workspace_id },
+                |m: &mut WorkspaceInvitation| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "email",
+                |m: &WorkspaceInvitation| { &m.email },
+                |m: &mut WorkspaceInvitation| { &mut m.email },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::workspace_member::WorkspaceRole>>(
+                "role",
+                |m: &WorkspaceInvitation| { &m.role },
+                |m: &mut WorkspaceInvitation| { &mut m.role },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "invited_time",
+                |m: &WorkspaceInvitation| { &m.invited_time },
+                |m: &mut WorkspaceInvitation| { &mut m.invited_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "wrapped_key",
+                |m: &WorkspaceInvitation| { &m.wrapped_key },
+                |m: &mut WorkspaceInvitation| { &mut m.wrapped_key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "invite_secret",
+                |m: &WorkspaceInvitation| { &m.invite_secret },
+                |m: &mut WorkspaceInvitation| { &mut m.invite_secret },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WorkspaceInvitation>(
+                "WorkspaceInvitation",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WorkspaceInvitation {
+        static instance: ::protobuf::rt::LazyV2<WorkspaceInvitation> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WorkspaceInvitation::new)
+    }
+}
+
+impl ::protobuf::Clear for WorkspaceInvitation {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.email.clear();
+        self.role = super::workspace_member::WorkspaceRole::Viewer;
+        self.invited_time = 0;
+        self.wrapped_key.clear();
+        self.invite_secret.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkspaceInvitation {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkspaceInvitation {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedWorkspaceInvitation {
+    // message fields
+    pub items: ::protobuf::RepeatedField<WorkspaceInvitation>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedWorkspaceInvitation {
+    fn default() -> &'a RepeatedWorkspaceInvitation {
+        <RepeatedWorkspaceInvitation as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedWorkspaceInvitation {
+    pub fn new() -> RepeatedWorkspaceInvitation {
+        ::std::default::Default::default()
+    }
+
+    // repeated .WorkspaceInvitation items = 1;
+
+
+    pub fn get_items(&self) -> &[WorkspaceInvitation] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<WorkspaceInvitation>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<WorkspaceInvitation> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<WorkspaceInvitation> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedWorkspaceInvitation {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedWorkspaceInvitation {
+        RepeatedWorkspaceInvitation::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<WorkspaceInvitation>>(
+                "items",
+                |m: &RepeatedWorkspaceInvitation| { &m.items },
+                |m: &mut RepeatedWorkspaceInvitation| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedWorkspaceInvitation>(
+                "RepeatedWorkspaceInvitation",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedWorkspaceInvitation {
+        static instance: ::protobuf::rt::LazyV2<RepeatedWorkspaceInvitation> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedWorkspaceInvitation::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedWorkspaceInvitation {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedWorkspaceInvitation {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedWorkspaceInvitation {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct InviteWorkspaceMemberRequest {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    pub email: ::std::string::String,
+    pub role: super::workspace_member::WorkspaceRole,
+    pub default_role: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a InviteWorkspaceMemberRequest {
+    fn default() -> &'a InviteWorkspaceMemberRequest {
+        <InviteWorkspaceMemberRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl InviteWorkspaceMemberRequest {
+    pub fn new() -> InviteWorkspaceMemberRequest {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string email = 2;
+
+
+    pub fn get_email(&self) -> &str {
+        &self.email
+    }
+    pub fn clear_email(&mut self) {
+        self.email.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_email(&mut self, v: ::std::string::String) {
+        self.email = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_email(&mut self) -> &mut ::std::string::String {
+        &mut self.email
+    }
+
+    // Take field
+    pub fn take_email(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.email, ::std::string::String::new())
+    }
+
+    // .WorkspaceRole role = 3;
+
+
+    pub fn get_role(&self) -> super::workspace_member::WorkspaceRole {
+        self.role
+    }
+    pub fn clear_role(&mut self) {
+        self.role = super::workspace_member::WorkspaceRole::Viewer;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: super::workspace_member::WorkspaceRole) {
+        self.role = v;
+    }
+
+    // bool default_role = 4;
+
+
+    pub fn get_default_role(&self) -> bool {
+        self.default_role
+    }
+    pub fn clear_default_role(&mut self) {
+        self.default_role = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_default_role(&mut self, v: bool) {
+        self.default_role = v;
+    }
+}
+
+impl ::protobuf::Message for InviteWorkspaceMemberRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.email)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.role, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.default_role = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if !self.email.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.email);
+        }
+        if self.role != super::workspace_member::WorkspaceRole::Viewer {
+            my_size += ::protobuf::rt::enum_size(3, self.role);
+        }
+        if self.default_role != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if !self.email.is_empty() {
+            os.write_string(2, &self.email)?;
+        }
+        if self.role != super::workspace_member::WorkspaceRole::Viewer {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.role))?;
+        }
+        if self.default_role != false {
+            os.write_bool(4, self.default_role)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> InviteWorkspaceMemberRequest {
+        InviteWorkspaceMemberRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &InviteWorkspaceMemberRequest| { &m.workspace_id },
+                |m: &mut InviteWorkspaceMemberRequest| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "email",
+                |m: &InviteWorkspaceMemberRequest| { &m.email },
+                |m: &mut InviteWorkspaceMemberRequest| { &mut m.email },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::workspace_member::WorkspaceRole>>(
+                "role",
+                |m: &InviteWorkspaceMemberRequest| { &m.role },
+                |m: &mut InviteWorkspaceMemberRequest| { &mut m.role },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "default_role",
+                |m: &InviteWorkspaceMemberRequest| { &m.default_role },
+                |m: &mut InviteWorkspaceMemberRequest| { &mut m.default_role },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<InviteWorkspaceMemberRequest>(
+                "InviteWorkspaceMemberRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static InviteWorkspaceMemberRequest {
+        static instance: ::protobuf::rt::LazyV2<InviteWorkspaceMemberRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(InviteWorkspaceMemberRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for InviteWorkspaceMemberRequest {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.email.clear();
+        self.role = super::workspace_member::WorkspaceRole::Viewer;
+        self.default_role = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for InviteWorkspaceMemberRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for InviteWorkspaceMemberRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CancelWorkspaceInvitationRequest {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    pub email: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CancelWorkspaceInvitationRequest {
+    fn default() -> &'a CancelWorkspaceInvitationRequest {
+        <CancelWorkspaceInvitationRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CancelWorkspaceInvitationRequest {
+    pub fn new() -> CancelWorkspaceInvitationRequest {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string email = 2;
+
+
+    pub fn get_email(&self) -> &str {
+        &self.email
+    }
+    pub fn clear_email(&mut self) {
+        self.email.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_email(&mut self, v: ::std::string::String) {
+        self.email = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_email(&mut self) -> &mut ::std::string::String {
+        &mut self.email
+    }
+
+    // Take field
+    pub fn take_email(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.email, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for CancelWorkspaceInvitationRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.email)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if !self.email.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.email);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if !self.email.is_empty() {
+            os.write_string(2, &self.email)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CancelWorkspaceInvitationRequest {
+        CancelWorkspaceInvitationRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &CancelWorkspaceInvitationRequest| { &m.workspace_id },
+                |m: &mut CancelWorkspaceInvitationRequest| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "email",
+                |m: &CancelWorkspaceInvitationRequest| { &m.email },
+                |m: &mut CancelWorkspaceInvitationRequest| { &mut m.email },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CancelWorkspaceInvitationRequest>(
+                "CancelWorkspaceInvitationRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CancelWorkspaceInvitationRequest {
+        static instance: ::protobuf::rt::LazyV2<CancelWorkspaceInvitationRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CancelWorkspaceInvitationRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CancelWorkspaceInvitationRequest {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.email.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CancelWorkspaceInvitationRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CancelWorkspaceInvitationRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x1aworkspace_invitation.proto\x1a\x16workspace_member.proto\"\xe9\x01\
+    \n\x13WorkspaceInvitation\x12#\n\x0cworkspace_id\x18\x01\x20\x01(\tR\x0b\
+    workspaceIdB\0\x12\x16\n\x05email\x18\x02\x20\x01(\tR\x05emailB\0\x12$\n\
+    \x04role\x18\x03\x20\x01(\x0e2\x0e.WorkspaceRoleR\x04roleB\0\x12#\n\x0ci\
+    nvited_time\x18\x04\x20\x01(\x03R\x0binvitedTimeB\0\x12!\n\x0bwrapped_ke\
+    y\x18\x05\x20\x01(\tR\nwrappedKeyB\0\x12%\n\rinvite_secret\x18\x06\x20\
+    \x01(\tR\x0cinviteSecretB\0:\0\"M\n\x1bRepeatedWorkspaceInvitation\x12,\
+    \n\x05items\x18\x01\x20\x03(\x0b2\x14.WorkspaceInvitationR\x05itemsB\0:\
+    \0\"\xa8\x01\n\x1cInviteWorkspaceMemberRequest\x12#\n\x0cworkspace_id\
+    \x18\x01\x20\x01(\tR\x0bworkspaceIdB\0\x12\x16\n\x05email\x18\x02\x20\
+    \x01(\tR\x05emailB\0\x12$\n\x04role\x18\x03\x20\x01(\x0e2\x0e.WorkspaceR\
+    oleR\x04roleB\0\x12#\n\x0cdefault_role\x18\x04\x20\x01(\x08R\x0bdefaultR\
+    oleB\0:\0\"a\n\x20CancelWorkspaceInvitationRequest\x12#\n\x0cworkspace_i\
+    d\x18\x01\x20\x01(\tR\x0bworkspaceIdB\0\x12\x16\n\x05email\x18\x02\x20\
+    \x01(\tR\x05emailB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -287,21 +287,1279 @@ impl ::protobuf::reflect::ProtobufValue for CurrentWorkspaceSetting {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkspaceSettingValue {
+    // message oneof groups
+    pub one_of_str_value: ::std::option::Option<WorkspaceSettingValue_oneof_one_of_str_value>,
+    pub one_of_int_value: ::std::option::Option<WorkspaceSettingValue_oneof_one_of_int_value>,
+    pub one_of_float_value: ::std::option::Option<WorkspaceSettingValue_oneof_one_of_float_value>,
+    pub one_of_bool_value: ::std::option::Option<WorkspaceSettingValue_oneof_one_of_bool_value>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WorkspaceSettingValue {
+    fn default() -> &'a WorkspaceSettingValue {
+        <WorkspaceSettingValue as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum WorkspaceSettingValue_oneof_one_of_str_value {
+    str_value(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum WorkspaceSettingValue_oneof_one_of_int_value {
+    int_value(i64),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum WorkspaceSettingValue_oneof_one_of_float_value {
+    float_value(f64),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum WorkspaceSettingValue_oneof_one_of_bool_value {
+    bool_value(bool),
+}
+
+impl WorkspaceSettingValue {
+    pub fn new() -> WorkspaceSettingValue {
+        ::std::default::Default::default()
+    }
+
+    // string str_value = 1;
+
+
+    pub fn get_str_value(&self) -> &str {
+        match self.one_of_str_value {
+            ::std::option::Option::Some(WorkspaceSettingValue_oneof_one_of_str_value::str_value(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_str_value(&mut self) {
+        self.one_of_str_value = ::std::option::Option::None;
+    }
+
+    pub fn has_str_value(&self) -> bool {
+        match self.one_of_str_value {
+            ::std::option::Option::Some(WorkspaceSettingValue_oneof_one_of_str_value::str_value(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_str_value(&mut self, v: ::std::string::String) {
+        self.one_of_str_value = ::std::option::Option::Some(WorkspaceSettingValue_oneof_one_of_str_value::str_value(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_str_value(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(WorkspaceSettingValue_oneof_one_of_str_value::str_value(_)) = self.one_of_str_value {
+        } else {
+            self.one_of_str_value = ::std::option::Option::Some(WorkspaceSettingValue_oneof_one_of_str_value::str_value(::std::string::String::new()));
+        }
+        match self.one_of_str_value {
+            ::std::option::Option::Some(WorkspaceSettingValue_oneof_one_of_str_value::str_value(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_str_value(&mut self) -> ::std::string::String {
+        if self.has_str_value() {
+            match self.one_of_str_value.take() {
+                ::std::option::Option::Some(WorkspaceSettingValue_oneof_one_of_str_value::str_value(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // int64 int_value = 2;
+
+
+    pub fn get_int_value(&self) -> i64 {
+        match self.one_of_int_value {
+            ::std::option::Option::Some(WorkspaceSettingValue_oneof_one_of_int_value::int_value(v)) => v,
+            _ => 0,
+        }
+    }
+    pub fn clear_int_value(&mut self) {
+        self.one_of_int_value = ::std::option::Option::None;
+    }
+
+    pub fn has_int_value(&self) -> bool {
+        match self.one_of_int_value {
+            ::std::option::Option::Some(WorkspaceSettingValue_oneof_one_of_int_value::int_value(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_int_value(&mut self, v: i64) {
+        self.one_of_int_value = ::std::option::Option::Some(WorkspaceSettingValue_oneof_one_of_int_value::int_value(v))
+    }
+
+    // double float_value = 3;
+
+
+    pub fn get_float_value(&self) -> f64 {
+        match self.one_of_float_value {
+            ::std::option::Option::Some(WorkspaceSettingValue_oneof_one_of_float_value::float_value(v)) => v,
+            _ => 0.,
+        }
+    }
+    pub fn clear_float_value(&mut self) {
+        self.one_of_float_value = ::std::option::Option::None;
+    }
+
+    pub fn has_float_value(&self) -> bool {
+        match self.one_of_float_value {
+            ::std::option::Option::Some(WorkspaceSettingValue_oneof_one_of_float_value::float_value(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_float_value(&mut self, v: f64) {
+        self.one_of_float_value = ::std::option::Option::Some(WorkspaceSettingValue_oneof_one_of_float_value::float_value(v))
+    }
+
+    // bool bool_value = 4;
+
+
+    pub fn get_bool_value(&self) -> bool {
+        match self.one_of_bool_value {
+            ::std::option::Option::Some(WorkspaceSettingValue_oneof_one_of_bool_value::bool_value(v)) => v,
+            _ => false,
+        }
+    }
+    pub fn clear_bool_value(&mut self) {
+        self.one_of_bool_value = ::std::option::Option::None;
+    }
+
+    pub fn has_bool_value(&self) -> bool {
+        match self.one_of_bool_value {
+            ::std::option::Option::Some(WorkspaceSettingValue_oneof_one_of_bool_value::bool_value(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bool_value(&mut self, v: bool) {
+        self.one_of_bool_value = ::std::option::Option::Some(WorkspaceSettingValue_oneof_one_of_bool_value::bool_value(v))
+    }
+}
+
+impl ::protobuf::Message for WorkspaceSettingValue {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_str_value = ::std::option::Option::Some(WorkspaceSettingValue_oneof_one_of_str_value::str_value(is.read_string()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_int_value = ::std::option::Option::Some(WorkspaceSettingValue_oneof_one_of_int_value::int_value(is.read_int64()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_float_value = ::std::option::Option::Some(WorkspaceSettingValue_oneof_one_of_float_value::float_value(is.read_double()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_bool_value = ::std::option::Option::Some(WorkspaceSettingValue_oneof_one_of_bool_value::bool_value(is.read_bool()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.one_of_str_value {
+            match v {
+                &WorkspaceSettingValue_oneof_one_of_str_value::str_value(ref v) => {
+                    my_size += ::protobuf::rt::string_size(1, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_int_value {
+            match v {
+                &WorkspaceSettingValue_oneof_one_of_int_value::int_value(v) => {
+                    my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_float_value {
+            match v {
+                &WorkspaceSettingValue_oneof_one_of_float_value::float_value(v) => {
+                    my_size += 9;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_bool_value {
+            match v {
+                &WorkspaceSettingValue_oneof_one_of_bool_value::bool_value(v) => {
+                    my_size += 2;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.one_of_str_value {
+            match v {
+                &WorkspaceSettingValue_oneof_one_of_str_value::str_value(ref v) => {
+                    os.write_string(1, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_int_value {
+            match v {
+                &WorkspaceSettingValue_oneof_one_of_int_value::int_value(v) => {
+                    os.write_int64(2, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_float_value {
+            match v {
+                &WorkspaceSettingValue_oneof_one_of_float_value::float_value(v) => {
+                    os.write_double(3, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_bool_value {
+            match v {
+                &WorkspaceSettingValue_oneof_one_of_bool_value::bool_value(v) => {
+                    os.write_bool(4, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkspaceSettingValue {
+        WorkspaceSettingValue::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "str_value",
+                WorkspaceSettingValue::has_str_value,
+                WorkspaceSettingValue::get_str_value,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_i64_accessor::<_>(
+                "int_value",
+                WorkspaceSettingValue::has_int_value,
+                WorkspaceSettingValue::get_int_value,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_f64_accessor::<_>(
+                "float_value",
+                WorkspaceSettingValue::has_float_value,
+                WorkspaceSettingValue::get_float_value,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor::<_>(
+                "bool_value",
+                WorkspaceSettingValue::has_bool_value,
+                WorkspaceSettingValue::get_bool_value,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WorkspaceSettingValue>(
+                "WorkspaceSettingValue",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WorkspaceSettingValue {
+        static instance: ::protobuf::rt::LazyV2<WorkspaceSettingValue> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WorkspaceSettingValue::new)
+    }
+}
+
+impl ::protobuf::Clear for WorkspaceSettingValue {
+    fn clear(&mut self) {
+        self.one_of_str_value = ::std::option::Option::None;
+        self.one_of_int_value = ::std::option::Option::None;
+        self.one_of_float_value = ::std::option::Option::None;
+        self.one_of_bool_value = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkspaceSettingValue {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkspaceSettingValue {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkspaceSettingItem {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    pub key: ::std::string::String,
+    pub value: ::protobuf::SingularPtrField<WorkspaceSettingValue>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WorkspaceSettingItem {
+    fn default() -> &'a WorkspaceSettingItem {
+        <WorkspaceSettingItem as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WorkspaceSettingItem {
+    pub fn new() -> WorkspaceSettingItem {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string key = 2;
+
+
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+
+    // .WorkspaceSettingValue value = 3;
+
+
+    pub fn get_value(&self) -> &WorkspaceSettingValue {
+        self.value.as_ref().unwrap_or_else(|| <WorkspaceSettingValue as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    pub fn has_value(&self) -> bool {
+        self.value.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: WorkspaceSettingValue) {
+        self.value = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut WorkspaceSettingValue {
+        if self.value.is_none() {
+            self.value.set_default();
+        }
+        self.value.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> WorkspaceSettingValue {
+        self.value.take().unwrap_or_else(|| WorkspaceSettingValue::new())
+    }
+}
+
+impl ::protobuf::Message for WorkspaceSettingItem {
+    fn is_initialized(&self) -> bool {
+        for v in &self.value {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.key);
+        }
+        if let Some(ref v) = self.value.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if !self.key.is_empty() {
+            os.write_string(2, &self.key)?;
+        }
+        if let Some(ref v) = self.value.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkspaceSettingItem {
+        WorkspaceSettingItem::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &WorkspaceSettingItem| { &m.workspace_id },
+                |m: &mut WorkspaceSettingItem| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "key",
+                |m: &WorkspaceSettingItem| { &m.key },
+                |m: &mut WorkspaceSettingItem| { &mut m.key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<WorkspaceSettingValue>>(
+                "value",
+                |m: &WorkspaceSettingItem| { &m.value },
+                |m: &mut WorkspaceSettingItem| { &mut m.value },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WorkspaceSettingItem>(
+                "WorkspaceSettingItem",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WorkspaceSettingItem {
+        static instance: ::protobuf::rt::LazyV2<WorkspaceSettingItem> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WorkspaceSettingItem::new)
+    }
+}
+
+impl ::protobuf::Clear for WorkspaceSettingItem {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.key.clear();
+        self.value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkspaceSettingItem {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkspaceSettingItem {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedWorkspaceSettingItem {
+    // message fields
+    pub items: ::protobuf::RepeatedField<WorkspaceSettingItem>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedWorkspaceSettingItem {
+    fn default() -> &'a RepeatedWorkspaceSettingItem {
+        <RepeatedWorkspaceSettingItem as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedWorkspaceSettingItem {
+    pub fn new() -> RepeatedWorkspaceSettingItem {
+        ::std::default::Default::default()
+    }
+
+    // repeated .WorkspaceSettingItem items = 1;
+
+
+    pub fn get_items(&self) -> &[WorkspaceSettingItem] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<WorkspaceSettingItem>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<WorkspaceSettingItem> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<WorkspaceSettingItem> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedWorkspaceSettingItem {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedWorkspaceSettingItem {
+        RepeatedWorkspaceSettingItem::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<WorkspaceSettingItem>>(
+                "items",
+                |m: &RepeatedWorkspaceSettingItem| { &m.items },
+                |m: &mut RepeatedWorkspaceSettingItem| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedWorkspaceSettingItem>(
+                "RepeatedWorkspaceSettingItem",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedWorkspaceSettingItem {
+        static instance: ::protobuf::rt::LazyV2<RepeatedWorkspaceSettingItem> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedWorkspaceSettingItem::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedWorkspaceSettingItem {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedWorkspaceSettingItem {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedWorkspaceSettingItem {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetWorkspaceSettingRequest {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    pub key: ::std::string::String,
+    pub value: ::protobuf::SingularPtrField<WorkspaceSettingValue>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetWorkspaceSettingRequest {
+    fn default() -> &'a SetWorkspaceSettingRequest {
+        <SetWorkspaceSettingRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetWorkspaceSettingRequest {
+    pub fn new() -> SetWorkspaceSettingRequest {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string key = 2;
+
+
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+
+    // .WorkspaceSettingValue value = 3;
+
+
+    pub fn get_value(&self) -> &WorkspaceSettingValue {
+        self.value.as_ref().unwrap_or_else(|| <WorkspaceSettingValue as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    pub fn has_value(&self) -> bool {
+        self.value.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: WorkspaceSettingValue) {
+        self.value = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut WorkspaceSettingValue {
+        if self.value.is_none() {
+            self.value.set_default();
+        }
+        self.value.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> WorkspaceSettingValue {
+        self.value.take().unwrap_or_else(|| WorkspaceSettingValue::new())
+    }
+}
+
+impl ::protobuf::Message for SetWorkspaceSettingRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.value {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.key);
+        }
+        if let Some(ref v) = self.value.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if !self.key.is_empty() {
+            os.write_string(2, &self.key)?;
+        }
+        if let Some(ref v) = self.value.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetWorkspaceSettingRequest {
+        SetWorkspaceSettingRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &SetWorkspaceSettingRequest| { &m.workspace_id },
+                |m: &mut SetWorkspaceSettingRequest| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "key",
+                |m: &SetWorkspaceSettingRequest| { &m.key },
+                |m: &mut SetWorkspaceSettingRequest| { &mut m.key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<WorkspaceSettingValue>>(
+                "value",
+                |m: &SetWorkspaceSettingRequest| { &m.value },
+                |m: &mut SetWorkspaceSettingRequest| { &mut m.value },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetWorkspaceSettingRequest>(
+                "SetWorkspaceSettingRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetWorkspaceSettingRequest {
+        static instance: ::protobuf::rt::LazyV2<SetWorkspaceSettingRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetWorkspaceSettingRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SetWorkspaceSettingRequest {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.key.clear();
+        self.value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetWorkspaceSettingRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetWorkspaceSettingRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GetWorkspaceSettingRequest {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    pub key: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetWorkspaceSettingRequest {
+    fn default() -> &'a GetWorkspaceSettingRequest {
+        <GetWorkspaceSettingRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetWorkspaceSettingRequest {
+    pub fn new() -> GetWorkspaceSettingRequest {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string key = 2;
+
+
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GetWorkspaceSettingRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.key);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if !self.key.is_empty() {
+            os.write_string(2, &self.key)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetWorkspaceSettingRequest {
+        GetWorkspaceSettingRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &GetWorkspaceSettingRequest| { &m.workspace_id },
+                |m: &mut GetWorkspaceSettingRequest| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "key",
+                |m: &GetWorkspaceSettingRequest| { &m.key },
+                |m: &mut GetWorkspaceSettingRequest| { &mut m.key },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetWorkspaceSettingRequest>(
+                "GetWorkspaceSettingRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GetWorkspaceSettingRequest {
+        static instance: ::protobuf::rt::LazyV2<GetWorkspaceSettingRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GetWorkspaceSettingRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for GetWorkspaceSettingRequest {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.key.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetWorkspaceSettingRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetWorkspaceSettingRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x17workspace_setting.proto\x1a\x11view_create.proto\x1a\x16workspace_\
-    create.proto\"\x83\x01\n\x17CurrentWorkspaceSetting\x12(\n\tworkspace\
-    \x18\x01\x20\x01(\x0b2\n.WorkspaceR\tworkspace\x12(\n\x0blatest_view\x18\
-    \x02\x20\x01(\x0b2\x05.ViewH\0R\nlatestViewB\x14\n\x12one_of_latest_view\
-    J\xc9\x01\n\x06\x12\x04\0\0\x07\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\
-    \n\x02\x03\0\x12\x03\x01\0\x1b\n\t\n\x02\x03\x01\x12\x03\x02\0\x20\n\n\n\
-    \x02\x04\0\x12\x04\x04\0\x07\x01\n\n\n\x03\x04\0\x01\x12\x03\x04\x08\x1f\
-    \n\x0b\n\x04\x04\0\x02\0\x12\x03\x05\x04\x1c\n\x0c\n\x05\x04\0\x02\0\x06\
-    \x12\x03\x05\x04\r\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x05\x0e\x17\n\x0c\
-    \n\x05\x04\0\x02\0\x03\x12\x03\x05\x1a\x1b\n\x0b\n\x04\x04\0\x08\0\x12\
-    \x03\x06\x046\n\x0c\n\x05\x04\0\x08\0\x01\x12\x03\x06\n\x1c\n\x0b\n\x04\
-    \x04\0\x02\x01\x12\x03\x06\x1f4\n\x0c\n\x05\x04\0\x02\x01\x06\x12\x03\
-    \x06\x1f#\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x06$/\n\x0c\n\x05\x04\0\
-    \x02\x01\x03\x12\x03\x0623b\x06proto3\
+    create.proto\"\x89\x01\n\x17CurrentWorkspaceSetting\x12*\n\tworkspace\
+    \x18\x01\x20\x01(\x0b2\n.WorkspaceR\tworkspaceB\0\x12*\n\x0blatest_view\
+    \x18\x02\x20\x01(\x0b2\x05.ViewH\0R\nlatestViewB\0B\x14\n\x12one_of_late\
+    st_view:\0\"\xf6\x01\n\x15WorkspaceSettingValue\x12\x1f\n\tstr_value\x18\
+    \x01\x20\x01(\tH\0R\x08strValueB\0\x12\x1f\n\tint_value\x18\x02\x20\x01(\
+    \x03H\x01R\x08intValueB\0\x12#\n\x0bfloat_value\x18\x03\x20\x01(\x01H\
+    \x02R\nfloatValueB\0\x12!\n\nbool_value\x18\x04\x20\x01(\x08H\x03R\tbool\
+    ValueB\0B\x12\n\x10one_of_str_valueB\x12\n\x10one_of_int_valueB\x14\n\
+    \x12one_of_float_valueB\x13\n\x11one_of_bool_value:\0\"\x81\x01\n\x14Wor\
+    kspaceSettingItem\x12#\n\x0cworkspace_id\x18\x01\x20\x01(\tR\x0bworkspac\
+    eIdB\0\x12\x12\n\x03key\x18\x02\x20\x01(\tR\x03keyB\0\x12.\n\x05value\
+    \x18\x03\x20\x01(\x0b2\x16.WorkspaceSettingValueR\x05valueB\0:\0\"O\n\
+    \x1cRepeatedWorkspaceSettingItem\x12-\n\x05items\x18\x01\x20\x03(\x0b2\
+    \x15.WorkspaceSettingItemR\x05itemsB\0:\0\"\x87\x01\n\x1aSetWorkspaceSet\
+    tingRequest\x12#\n\x0cworkspace_id\x18\x01\x20\x01(\tR\x0bworkspaceIdB\0\
+    \x12\x12\n\x03key\x18\x02\x20\x01(\tR\x03keyB\0\x12.\n\x05value\x18\x03\
+    \x20\x01(\x0b2\x16.WorkspaceSettingValueR\x05valueB\0:\0\"W\n\x1aGetWork\
+    spaceSettingRequest\x12#\n\x0cworkspace_id\x18\x01\x20\x01(\tR\x0bworksp\
+    aceIdB\0\x12\x12\n\x03key\x18\x02\x20\x01(\tR\x03keyB\0:\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -63,5 +63,7 @@ fn create_default_view(app_id: String, time: chrono::DateTime<Utc>) -> View {
         belongings: Default::default(),
         modified_time: time.timestamp(),
         create_time: time.timestamp(),
+        icon: "".to_owned(),
+        cover: "".to_owned(),
     }
 }
@@ -16,6 +16,11 @@ pub struct WorkspaceError {
 
     #[pb(index = 2)]
     pub msg: String,
+
+    // The unique names that can be used instead, only set with
+    // ErrorCode::ViewNameConflict.
+    #[pb(index = 3)]
+    pub suggestions: Vec<String>,
 }
 
 macro_rules! static_workspace_error {
@@ -30,9 +35,16 @@ impl WorkspaceError {
         Self {
             code: code.value(),
             msg: msg.to_owned(),
+            suggestions: vec![],
         }
     }
 
+    pub fn view_name_conflict(suggestions: Vec<String>) -> Self {
+        let mut error: WorkspaceError = ErrorCode::ViewNameConflict.into();
+        error.suggestions = suggestions;
+        error
+    }
+
     static_workspace_error!(workspace_name, ErrorCode::WorkspaceNameInvalid);
     static_workspace_error!(workspace_id, ErrorCode::WorkspaceIdInvalid);
     static_workspace_error!(color_style, ErrorCode::AppColorStyleInvalid);
@@ -44,10 +56,24 @@ impl WorkspaceError {
     static_workspace_error!(invalid_view_id, ErrorCode::ViewIdInvalid);
     static_workspace_error!(view_desc, ErrorCode::ViewDescTooLong);
     static_workspace_error!(view_data, ErrorCode::ViewDataInvalid);
+    static_workspace_error!(view_parent, ErrorCode::ViewParentInvalid);
+    static_workspace_error!(view_cover, ErrorCode::ViewCoverInvalid);
+    static_workspace_error!(workspace_owner_required, ErrorCode::WorkspaceOwnerRequired);
+    static_workspace_error!(workspace_last_one, ErrorCode::WorkspaceLastOne);
+    static_workspace_error!(workspace_archive, ErrorCode::WorkspaceArchiveInvalid);
+    static_workspace_error!(notion_export, ErrorCode::NotionExportInvalid);
+    static_workspace_error!(enex, ErrorCode::EnexInvalid);
+    static_workspace_error!(inbox_view, ErrorCode::InboxViewInvalid);
+    static_workspace_error!(workspace_setting_key, ErrorCode::WorkspaceSettingKeyInvalid);
+    static_workspace_error!(workspace_setting, ErrorCode::WorkspaceSettingInvalid);
     static_workspace_error!(unauthorized, ErrorCode::UserUnauthorized);
+    static_workspace_error!(permission_denied, ErrorCode::PermissionDenied);
+    static_workspace_error!(invitation_secret, ErrorCode::InvitationSecretInvalid);
     static_workspace_error!(internal, ErrorCode::InternalError);
     static_workspace_error!(record_not_found, ErrorCode::RecordNotFound);
     static_workspace_error!(ws, ErrorCode::WsConnectError);
+    static_workspace_error!(network_unreachable, ErrorCode::NetworkUnreachable);
+    static_workspace_error!(conflict_field, ErrorCode::SyncConflictFieldInvalid);
 
     pub fn context<T: Debug>(mut self, error: T) -> Self {
         self.msg = format!("{:?}", error);
@@ -67,6 +93,7 @@ impl std::convert::From<ErrorCode> for WorkspaceError {
         WorkspaceError {
             code: code.value(),
             msg: format!("{}", code),
+            suggestions: vec![],
         }
     }
 }
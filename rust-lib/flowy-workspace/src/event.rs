@@ -5,77 +5,224 @@ use strum_macros::Display;
 #[event_err = "WorkspaceError"]
 pub enum WorkspaceEvent {
     #[event(input = "CreateWorkspaceRequest", output = "Workspace")]
-    CreateWorkspace   = 0,
+    CreateWorkspace      = 0,
 
     #[event(output = "CurrentWorkspaceSetting")]
-    ReadCurWorkspace  = 1,
+    ReadCurWorkspace     = 1,
 
     #[event(input = "QueryWorkspaceRequest", output = "RepeatedWorkspace")]
-    ReadWorkspaces    = 2,
+    ReadWorkspaces       = 2,
 
     #[event(input = "QueryWorkspaceRequest")]
-    DeleteWorkspace   = 3,
+    DeleteWorkspace      = 3,
 
     #[event(input = "QueryWorkspaceRequest", output = "Workspace")]
-    OpenWorkspace     = 4,
+    OpenWorkspace        = 4,
 
     #[event(input = "QueryWorkspaceRequest", output = "RepeatedApp")]
-    ReadWorkspaceApps = 5,
+    ReadWorkspaceApps    = 5,
+
+    #[event(input = "QueryWorkspaceRequest", output = "RepeatedWorkspaceMember")]
+    ReadWorkspaceMembers = 6,
+
+    #[event(input = "UpdateWorkspaceMemberRequest")]
+    UpdateWorkspaceMember = 7,
+
+    #[event(input = "UpdateWorkspaceRequest")]
+    UpdateWorkspace      = 8,
+
+    #[event(input = "SetWorkspaceSettingRequest")]
+    SetWorkspaceSetting  = 9,
+
+    #[event(input = "GetWorkspaceSettingRequest", output = "WorkspaceSettingItem")]
+    GetWorkspaceSetting  = 10,
+
+    #[event(input = "QueryWorkspaceRequest", output = "RepeatedWorkspaceSettingItem")]
+    ReadWorkspaceSettings = 11,
+
+    #[event(input = "InviteWorkspaceMemberRequest", output = "WorkspaceInvitation")]
+    InviteWorkspaceMember = 12,
+
+    #[event(input = "QueryWorkspaceRequest", output = "RepeatedWorkspaceInvitation")]
+    ReadWorkspaceInvitations = 13,
+
+    #[event(input = "CancelWorkspaceInvitationRequest")]
+    CancelWorkspaceInvitation = 14,
+
+    #[event(input = "RemoveWorkspaceMemberRequest")]
+    RemoveWorkspaceMember = 15,
+
+    #[event(input = "QueryWorkspaceRequest", output = "WorkspaceEncryption")]
+    EnableWorkspaceEncryption = 16,
+
+    #[event(input = "QueryWorkspaceRequest", output = "WorkspaceEncryption")]
+    ReadWorkspaceEncryption = 17,
+
+    #[event(input = "AcceptWorkspaceInvitationRequest", output = "WorkspaceEncryption")]
+    AcceptWorkspaceInvitation = 18,
 
     #[event(input = "CreateAppRequest", output = "App")]
-    CreateApp         = 101,
+    CreateApp            = 101,
 
     #[event(input = "QueryAppRequest")]
-    DeleteApp         = 102,
+    DeleteApp            = 102,
 
     #[event(input = "QueryAppRequest", output = "App")]
-    ReadApp           = 103,
+    ReadApp              = 103,
 
     #[event(input = "UpdateAppRequest")]
-    UpdateApp         = 104,
+    UpdateApp            = 104,
 
     #[event(input = "CreateViewRequest", output = "View")]
-    CreateView        = 201,
+    CreateView           = 201,
 
     #[event(input = "QueryViewRequest", output = "View")]
-    ReadView          = 202,
+    ReadView             = 202,
 
     #[event(input = "UpdateViewRequest", output = "View")]
-    UpdateView        = 203,
+    UpdateView           = 203,
 
     #[event(input = "QueryViewRequest")]
-    DeleteView        = 204,
+    DeleteView           = 204,
 
     #[event(input = "QueryViewRequest")]
-    DuplicateView     = 205,
+    DuplicateView        = 205,
 
     #[event()]
-    CopyLink          = 206,
+    CopyLink             = 206,
 
     #[event(input = "QueryViewRequest", output = "DocDelta")]
-    OpenView          = 207,
+    OpenView             = 207,
 
     #[event(input = "QueryViewRequest")]
-    CloseView         = 208,
+    CloseView            = 208,
+
+    #[event(input = "DuplicateDocumentRequest", output = "View")]
+    DuplicateDocument    = 209,
+
+    #[event(input = "MoveViewRequest", output = "View")]
+    MoveView             = 210,
+
+    #[event(input = "QueryViewRequest", output = "RepeatedView")]
+    PinView              = 211,
+
+    #[event(input = "QueryViewRequest", output = "RepeatedView")]
+    UnpinView            = 212,
+
+    #[event(output = "RepeatedView")]
+    ReadFavorites        = 213,
+
+    #[event(input = "MoveFavoriteRequest", output = "RepeatedView")]
+    MoveFavorite         = 214,
+
+    #[event(input = "ReadViewTreeRequest", output = "RepeatedView")]
+    ReadViewTree         = 215,
+
+    #[event(output = "RepeatedView")]
+    ReadRecentViews      = 216,
+
+    #[event(input = "BatchViewOperationRequest", output = "BatchViewChangeset")]
+    BatchViewOperation   = 217,
 
     #[event(output = "RepeatedTrash")]
-    ReadTrash         = 300,
+    ReadTrash            = 300,
 
     #[event(input = "TrashIdentifier")]
-    PutbackTrash      = 301,
+    PutbackTrash         = 301,
 
     #[event(input = "TrashIdentifiers")]
-    DeleteTrash       = 302,
+    DeleteTrash          = 302,
 
     #[event()]
-    RestoreAll        = 303,
+    RestoreAll           = 303,
 
     #[event()]
-    DeleteAll         = 304,
+    DeleteAll            = 304,
+
+    #[event()]
+    PurgeTrash           = 305,
+
+    #[event(output = "GarbageCollectionResult")]
+    RunGarbageCollection = 306,
 
     #[event(input = "DocDelta", output = "DocDelta")]
-    ApplyDocDelta     = 400,
+    ApplyDocDelta        = 400,
 
     #[event(input = "ExportRequest", output = "ExportData")]
-    ExportDocument    = 500,
+    ExportDocument       = 500,
+
+    #[event(input = "ImportRequest", output = "View")]
+    ImportDocument       = 501,
+
+    #[event(input = "ExportWorkspaceRequest", output = "ExportData")]
+    ExportWorkspace      = 502,
+
+    #[event(input = "ImportWorkspaceRequest", output = "ImportWorkspaceResult")]
+    ImportWorkspace      = 503,
+
+    #[event(input = "ImportNotionRequest", output = "ImportNotionResult")]
+    ImportNotion         = 504,
+
+    #[event(input = "ImportEnexRequest", output = "ImportEnexResult")]
+    ImportEnex           = 505,
+
+    #[event(input = "IngestSharedContentRequest", output = "IngestSharedContentResult")]
+    IngestSharedContent  = 506,
+
+    #[event(output = "RepeatedTemplate")]
+    ReadTemplates        = 600,
+
+    #[event(input = "CreateViewFromTemplateRequest", output = "View")]
+    CreateViewFromTemplate = 601,
+
+    #[event(input = "SaveTemplateRequest", output = "Template")]
+    SaveTemplate         = 602,
+
+    #[event(output = "RepeatedWorkspaceTemplate")]
+    ReadWorkspaceTemplates = 603,
+
+    #[event(input = "CreateWorkspaceFromTemplateRequest", output = "Workspace")]
+    CreateWorkspaceFromTemplate = 604,
+
+    #[event(input = "CheckStorageIntegrityRequest", output = "StorageIntegrityReport")]
+    CheckStorageIntegrity = 700,
+
+    #[event(output = "SyncResult")]
+    SyncWorkspace = 800,
+
+    #[event(output = "SyncState")]
+    ReadSyncState = 801,
+
+    #[event(output = "SyncSettings")]
+    ReadSyncSettings = 802,
+
+    #[event(input = "SyncSettings")]
+    UpdateSyncSettings = 803,
+
+    #[event(output = "RepeatedSyncConflict")]
+    ReadSyncConflicts = 804,
+
+    #[event(input = "ResolveConflictRequest")]
+    ResolveSyncConflict = 805,
+
+    #[event(input = "QuerySyncStatusRequest", output = "RepeatedObjectSyncStatus")]
+    GetSyncStatus = 806,
+
+    #[event(input = "SyncExclusionRequest")]
+    SetSyncExclusion = 807,
+
+    #[event(output = "RepeatedSyncExclusion")]
+    ReadSyncExclusions = 808,
+
+    #[event(input = "SubmitConflictResolutionRequest")]
+    SubmitConflictResolution = 809,
+
+    #[event(output = "SyncTraffic")]
+    ReadSyncTraffic = 810,
+
+    #[event(output = "RepeatedLanPeer")]
+    ReadLanPeers = 811,
+
+    #[event(input = "SearchRequest", output = "RepeatedSearchResult")]
+    Search = 900,
 }
@@ -1,38 +1,30 @@
 use crate::{
     entities::{
-        app::{
-            App,
-            AppIdentifier,
-            CreateAppParams,
-            CreateAppRequest,
-            QueryAppRequest,
-            UpdateAppParams,
-            UpdateAppRequest,
-        },
+        app::{App, AppIdentifier, CreateAppParams, UpdateAppParams},
         trash::Trash,
     },
     errors::WorkspaceError,
     services::{AppController, TrashCan, ViewController},
 };
-use flowy_dispatch::prelude::{data_result, Data, DataResult, Unit};
-use std::{convert::TryInto, sync::Arc};
+use flowy_dispatch::prelude::{data_result, DataResult, Params, Unit};
+use std::sync::Arc;
 
 pub(crate) async fn create_app_handler(
-    data: Data<CreateAppRequest>,
+    data: Params<CreateAppParams, WorkspaceError>,
     controller: Unit<Arc<AppController>>,
 ) -> DataResult<App, WorkspaceError> {
-    let params: CreateAppParams = data.into_inner().try_into()?;
+    let params = data.into_inner();
     let detail = controller.create_app_from_params(params).await?;
 
     data_result(detail)
 }
 
 pub(crate) async fn delete_app_handler(
-    data: Data<QueryAppRequest>,
+    data: Params<AppIdentifier, WorkspaceError>,
     controller: Unit<Arc<AppController>>,
     trash_can: Unit<Arc<TrashCan>>,
 ) -> Result<(), WorkspaceError> {
-    let params: AppIdentifier = data.into_inner().try_into()?;
+    let params = data.into_inner();
     let trash = controller
         .read_app_tables(vec![params.app_id])?
         .into_iter()
@@ -45,21 +37,21 @@ pub(crate) async fn delete_app_handler(
 
 #[tracing::instrument(skip(data, controller))]
 pub(crate) async fn update_app_handler(
-    data: Data<UpdateAppRequest>,
+    data: Params<UpdateAppParams, WorkspaceError>,
     controller: Unit<Arc<AppController>>,
 ) -> Result<(), WorkspaceError> {
-    let params: UpdateAppParams = data.into_inner().try_into()?;
+    let params = data.into_inner();
     let _ = controller.update_app(params).await?;
     Ok(())
 }
 
 #[tracing::instrument(skip(data, app_controller, view_controller))]
 pub(crate) async fn read_app_handler(
-    data: Data<QueryAppRequest>,
+    data: Params<AppIdentifier, WorkspaceError>,
     app_controller: Unit<Arc<AppController>>,
     view_controller: Unit<Arc<ViewController>>,
 ) -> DataResult<App, WorkspaceError> {
-    let params: AppIdentifier = data.into_inner().try_into()?;
+    let params = data.into_inner();
     let mut app = app_controller.read_app(params.clone()).await?;
     app.belongings = view_controller.read_views_belong_to(&params.app_id).await?;
 
@@ -4,6 +4,7 @@ use crate::{
     services::TrashCan,
 };
 use flowy_dispatch::prelude::{data_result, Data, DataResult, Unit};
+use flowy_infra::clock::Clock;
 use std::sync::Arc;
 
 #[tracing::instrument(skip(controller), err)]
@@ -42,3 +43,13 @@ pub(crate) async fn delete_all_handler(controller: Unit<Arc<TrashCan>>) -> Resul
     let _ = controller.delete_all().await?;
     Ok(())
 }
+
+// The clock is the one the sdk added to the data of the modules.
+#[tracing::instrument(skip(controller, clock), err)]
+pub(crate) async fn purge_trash_handler(
+    controller: Unit<Arc<TrashCan>>,
+    clock: Unit<Arc<dyn Clock>>,
+) -> Result<(), WorkspaceError> {
+    let _ = controller.purge_expired(clock.timestamp()).await?;
+    Ok(())
+}
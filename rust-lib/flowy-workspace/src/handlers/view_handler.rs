@@ -1,12 +1,18 @@
 use crate::{
     entities::{
+        template::{CreateViewFromTemplateParams, RepeatedTemplate, SaveTemplateParams, Template},
         trash::Trash,
         view::{
+            BatchViewChangeset,
+            BatchViewOperationParams,
             CreateViewParams,
-            CreateViewRequest,
-            QueryViewRequest,
+            DuplicateDocumentParams,
+            GarbageCollectionResult,
+            MoveFavoriteParams,
+            MoveViewParams,
+            ReadViewTreeParams,
+            RepeatedView,
             UpdateViewParams,
-            UpdateViewRequest,
             View,
             ViewIdentifier,
             ViewIdentifiers,
@@ -15,37 +21,53 @@ use crate::{
     errors::WorkspaceError,
     services::{TrashCan, ViewController},
 };
-use flowy_dispatch::prelude::{data_result, Data, DataResult, Unit};
+use flowy_dispatch::prelude::{data_result, Data, DataResult, Params, Unit};
 use flowy_document_infra::entities::doc::DocDelta;
-use flowy_workspace_infra::entities::share::{ExportData, ExportParams, ExportRequest};
-use std::{convert::TryInto, sync::Arc};
+use flowy_workspace_infra::entities::share::{
+    ExportData,
+    ExportParams,
+    ImportParams,
+    IngestSharedContentParams,
+    IngestSharedContentResult,
+};
+use std::sync::Arc;
 
 pub(crate) async fn create_view_handler(
-    data: Data<CreateViewRequest>,
+    data: Params<CreateViewParams, WorkspaceError>,
     controller: Unit<Arc<ViewController>>,
 ) -> DataResult<View, WorkspaceError> {
-    let params: CreateViewParams = data.into_inner().try_into()?;
+    let params = data.into_inner();
     let view = controller.create_view_from_params(params).await?;
     data_result(view)
 }
 
 pub(crate) async fn read_view_handler(
-    data: Data<QueryViewRequest>,
+    data: Params<ViewIdentifier, WorkspaceError>,
     controller: Unit<Arc<ViewController>>,
 ) -> DataResult<View, WorkspaceError> {
-    let params: ViewIdentifier = data.into_inner().try_into()?;
+    let params = data.into_inner();
     let mut view = controller.read_view(params.clone()).await?;
     view.belongings = controller.read_views_belong_to(&params.view_id).await?;
 
     data_result(view)
 }
 
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_view_tree_handler(
+    data: Params<ReadViewTreeParams, WorkspaceError>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedView, WorkspaceError> {
+    let params = data.into_inner();
+    let repeated_view = controller.read_view_tree(params).await?;
+    data_result(repeated_view)
+}
+
 #[tracing::instrument(skip(data, controller), err)]
 pub(crate) async fn update_view_handler(
-    data: Data<UpdateViewRequest>,
+    data: Params<UpdateViewParams, WorkspaceError>,
     controller: Unit<Arc<ViewController>>,
 ) -> Result<(), WorkspaceError> {
-    let params: UpdateViewParams = data.into_inner().try_into()?;
+    let params = data.into_inner();
     let _ = controller.update_view(params).await?;
 
     Ok(())
@@ -61,11 +83,11 @@ pub(crate) async fn apply_doc_delta_handler(
 }
 
 pub(crate) async fn delete_view_handler(
-    data: Data<QueryViewRequest>,
+    data: Params<ViewIdentifiers, WorkspaceError>,
     controller: Unit<Arc<ViewController>>,
     trash_can: Unit<Arc<TrashCan>>,
 ) -> Result<(), WorkspaceError> {
-    let params: ViewIdentifiers = data.into_inner().try_into()?;
+    let params = data.into_inner();
     for view_id in &params.view_ids {
         let _ = controller.delete_view(view_id.into()).await;
     }
@@ -81,39 +103,171 @@ pub(crate) async fn delete_view_handler(
 }
 
 pub(crate) async fn open_view_handler(
-    data: Data<QueryViewRequest>,
+    data: Params<ViewIdentifier, WorkspaceError>,
     controller: Unit<Arc<ViewController>>,
 ) -> DataResult<DocDelta, WorkspaceError> {
-    let params: ViewIdentifier = data.into_inner().try_into()?;
+    let params = data.into_inner();
     let doc = controller.open_view(params.into()).await?;
     data_result(doc)
 }
 
 pub(crate) async fn close_view_handler(
-    data: Data<QueryViewRequest>,
+    data: Params<ViewIdentifier, WorkspaceError>,
     controller: Unit<Arc<ViewController>>,
 ) -> Result<(), WorkspaceError> {
-    let params: ViewIdentifier = data.into_inner().try_into()?;
+    let params = data.into_inner();
     let _ = controller.close_view(params.into()).await?;
     Ok(())
 }
 
 #[tracing::instrument(skip(data, controller), err)]
 pub(crate) async fn duplicate_view_handler(
-    data: Data<QueryViewRequest>,
+    data: Params<ViewIdentifier, WorkspaceError>,
     controller: Unit<Arc<ViewController>>,
 ) -> Result<(), WorkspaceError> {
-    let params: ViewIdentifier = data.into_inner().try_into()?;
+    let params = data.into_inner();
     let _ = controller.duplicate_view(params.into()).await?;
     Ok(())
 }
 
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn duplicate_document_handler(
+    data: Params<DuplicateDocumentParams, WorkspaceError>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<View, WorkspaceError> {
+    let params = data.into_inner();
+    let view = controller.duplicate_document(params).await?;
+    data_result(view)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn move_view_handler(
+    data: Params<MoveViewParams, WorkspaceError>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<View, WorkspaceError> {
+    let params = data.into_inner();
+    let view = controller.move_view(params).await?;
+    data_result(view)
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn run_garbage_collection_handler(
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<GarbageCollectionResult, WorkspaceError> {
+    let result = controller.collect_garbage().await?;
+    data_result(result)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn batch_view_operation_handler(
+    data: Params<BatchViewOperationParams, WorkspaceError>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<BatchViewChangeset, WorkspaceError> {
+    let params = data.into_inner();
+    let changeset = controller.batch_operation(params).await?;
+    data_result(changeset)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn pin_view_handler(
+    data: Params<ViewIdentifiers, WorkspaceError>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedView, WorkspaceError> {
+    let params = data.into_inner();
+    let repeated_view = controller.pin_views(params).await?;
+    data_result(repeated_view)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn unpin_view_handler(
+    data: Params<ViewIdentifiers, WorkspaceError>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedView, WorkspaceError> {
+    let params = data.into_inner();
+    let repeated_view = controller.unpin_views(params).await?;
+    data_result(repeated_view)
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn read_favorites_handler(
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedView, WorkspaceError> {
+    let repeated_view = controller.read_favorites()?;
+    data_result(repeated_view)
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn read_recent_views_handler(
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedView, WorkspaceError> {
+    let repeated_view = controller.read_recent_views()?;
+    data_result(repeated_view)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn move_favorite_handler(
+    data: Params<MoveFavoriteParams, WorkspaceError>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedView, WorkspaceError> {
+    let params = data.into_inner();
+    let repeated_view = controller.move_favorite(params).await?;
+    data_result(repeated_view)
+}
+
 #[tracing::instrument(skip(data, controller), err)]
 pub(crate) async fn export_handler(
-    data: Data<ExportRequest>,
+    data: Params<ExportParams, WorkspaceError>,
     controller: Unit<Arc<ViewController>>,
 ) -> DataResult<ExportData, WorkspaceError> {
-    let params: ExportParams = data.into_inner().try_into()?;
+    let params = data.into_inner();
     let data = controller.export_doc(params.into()).await?;
     data_result(data)
 }
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn import_handler(
+    data: Params<ImportParams, WorkspaceError>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<View, WorkspaceError> {
+    let params = data.into_inner();
+    let view = controller.import_doc(params).await?;
+    data_result(view)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn ingest_shared_content_handler(
+    data: Params<IngestSharedContentParams, WorkspaceError>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<IngestSharedContentResult, WorkspaceError> {
+    let params = data.into_inner();
+    let result = controller.ingest_shared_content(params).await?;
+    data_result(result)
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn read_templates_handler(
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedTemplate, WorkspaceError> {
+    let templates = controller.read_templates()?;
+    data_result(templates)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn create_view_from_template_handler(
+    data: Params<CreateViewFromTemplateParams, WorkspaceError>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<View, WorkspaceError> {
+    let params = data.into_inner();
+    let view = controller.create_view_from_template(params).await?;
+    data_result(view)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn save_template_handler(
+    data: Params<SaveTemplateParams, WorkspaceError>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<Template, WorkspaceError> {
+    let params = data.into_inner();
+    let template = controller.save_template(params).await?;
+    data_result(template)
+}
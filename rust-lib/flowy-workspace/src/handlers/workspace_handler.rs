@@ -1,16 +1,39 @@
-use crate::{errors::WorkspaceError, services::WorkspaceController};
+use crate::{
+    errors::WorkspaceError,
+    services::{WorkspaceController, WorkspacePermission},
+};
 
-use flowy_dispatch::prelude::{data_result, Data, DataResult, Unit};
-use flowy_workspace_infra::entities::{app::RepeatedApp, workspace::*};
-use std::{convert::TryInto, sync::Arc};
+use flowy_dispatch::prelude::{data_result, Data, DataResult, Params, Unit};
+use flowy_workspace_infra::entities::{
+    app::RepeatedApp,
+    search::{RepeatedSearchResult, SearchRequest},
+    share::*,
+    sync::{
+        QuerySyncStatusRequest,
+        RepeatedLanPeer,
+        RepeatedObjectSyncStatus,
+        RepeatedSyncConflict,
+        RepeatedSyncExclusion,
+        ResolveConflictRequest,
+        SubmitConflictResolutionRequest,
+        SyncExclusionRequest,
+        SyncResult,
+        SyncSettings,
+        SyncState,
+        SyncTraffic,
+    },
+    template::{CreateWorkspaceFromTemplateParams, RepeatedWorkspaceTemplate},
+    workspace::*,
+};
+use std::sync::Arc;
 
 #[tracing::instrument(skip(data, controller), err)]
 pub(crate) async fn create_workspace_handler(
-    data: Data<CreateWorkspaceRequest>,
+    data: Params<CreateWorkspaceParams, WorkspaceError>,
     controller: Unit<Arc<WorkspaceController>>,
 ) -> DataResult<Workspace, WorkspaceError> {
     let controller = controller.get_ref().clone();
-    let params: CreateWorkspaceParams = data.into_inner().try_into()?;
+    let params = data.into_inner();
     let detail = controller.create_workspace_from_params(params).await?;
     data_result(detail)
 }
@@ -33,20 +56,339 @@ pub(crate) async fn read_workspace_apps_handler(
 
 #[tracing::instrument(skip(data, controller), err)]
 pub(crate) async fn read_workspaces_handler(
-    data: Data<QueryWorkspaceRequest>,
+    data: Params<WorkspaceIdentifier, WorkspaceError>,
     controller: Unit<Arc<WorkspaceController>>,
 ) -> DataResult<RepeatedWorkspace, WorkspaceError> {
-    let params: WorkspaceIdentifier = data.into_inner().try_into()?;
+    let params = data.into_inner();
     let workspaces = controller.read_workspaces(params).await?;
     data_result(workspaces)
 }
 
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_workspace_handler(
+    data: Params<UpdateWorkspaceParams, WorkspaceError>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> Result<(), WorkspaceError> {
+    let params = data.into_inner();
+    let _ = controller.update_workspace(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn delete_workspace_handler(
+    data: Params<WorkspaceIdentifier, WorkspaceError>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> Result<(), WorkspaceError> {
+    let params = data.into_inner();
+    let workspace_id = params
+        .workspace_id
+        .ok_or_else(|| WorkspaceError::workspace_id().context("The deleted workspace id should not be empty"))?;
+    let _ = controller.delete_workspace(&workspace_id).await?;
+    Ok(())
+}
+
 #[tracing::instrument(skip(data, controller), err)]
 pub(crate) async fn open_workspace_handler(
-    data: Data<QueryWorkspaceRequest>,
+    data: Params<WorkspaceIdentifier, WorkspaceError>,
     controller: Unit<Arc<WorkspaceController>>,
 ) -> DataResult<Workspace, WorkspaceError> {
-    let params: WorkspaceIdentifier = data.into_inner().try_into()?;
+    let params = data.into_inner();
     let workspaces = controller.open_workspace(params).await?;
     data_result(workspaces)
 }
+
+#[tracing::instrument(skip(data, permission), err)]
+pub(crate) async fn read_workspace_members_handler(
+    data: Params<WorkspaceIdentifier, WorkspaceError>,
+    permission: Unit<Arc<WorkspacePermission>>,
+) -> DataResult<RepeatedWorkspaceMember, WorkspaceError> {
+    let params = data.into_inner();
+    let members = permission.read_members(params.workspace_id)?;
+    data_result(members)
+}
+
+#[tracing::instrument(skip(data, permission), err)]
+pub(crate) async fn update_workspace_member_handler(
+    data: Params<UpdateWorkspaceMemberParams, WorkspaceError>,
+    permission: Unit<Arc<WorkspacePermission>>,
+) -> Result<(), WorkspaceError> {
+    let params = data.into_inner();
+    permission.update_member(params)?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, permission), err)]
+pub(crate) async fn remove_workspace_member_handler(
+    data: Params<RemoveWorkspaceMemberParams, WorkspaceError>,
+    permission: Unit<Arc<WorkspacePermission>>,
+) -> Result<(), WorkspaceError> {
+    let params = data.into_inner();
+    permission.remove_member(params)?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, permission), err)]
+pub(crate) async fn invite_workspace_member_handler(
+    data: Params<InviteWorkspaceMemberParams, WorkspaceError>,
+    permission: Unit<Arc<WorkspacePermission>>,
+) -> DataResult<WorkspaceInvitation, WorkspaceError> {
+    let params = data.into_inner();
+    let invitation = permission.invite_member(params)?;
+    data_result(invitation)
+}
+
+#[tracing::instrument(skip(data, permission), err)]
+pub(crate) async fn read_workspace_invitations_handler(
+    data: Params<WorkspaceIdentifier, WorkspaceError>,
+    permission: Unit<Arc<WorkspacePermission>>,
+) -> DataResult<RepeatedWorkspaceInvitation, WorkspaceError> {
+    let params = data.into_inner();
+    let invitations = permission.read_invitations(params.workspace_id)?;
+    data_result(invitations)
+}
+
+#[tracing::instrument(skip(data, permission), err)]
+pub(crate) async fn cancel_workspace_invitation_handler(
+    data: Params<WorkspaceInvitationIdentifier, WorkspaceError>,
+    permission: Unit<Arc<WorkspacePermission>>,
+) -> Result<(), WorkspaceError> {
+    let params = data.into_inner();
+    permission.cancel_invitation(params)?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, permission), err)]
+pub(crate) async fn enable_workspace_encryption_handler(
+    data: Params<WorkspaceIdentifier, WorkspaceError>,
+    permission: Unit<Arc<WorkspacePermission>>,
+) -> DataResult<WorkspaceEncryption, WorkspaceError> {
+    let params = data.into_inner();
+    let encryption = permission.enable_encryption(params.workspace_id)?;
+    data_result(encryption)
+}
+
+#[tracing::instrument(skip(data, permission), err)]
+pub(crate) async fn read_workspace_encryption_handler(
+    data: Params<WorkspaceIdentifier, WorkspaceError>,
+    permission: Unit<Arc<WorkspacePermission>>,
+) -> DataResult<WorkspaceEncryption, WorkspaceError> {
+    let params = data.into_inner();
+    let encryption = permission.read_encryption(params.workspace_id)?;
+    data_result(encryption)
+}
+
+#[tracing::instrument(skip(data, permission), err)]
+pub(crate) async fn accept_workspace_invitation_handler(
+    data: Params<AcceptWorkspaceInvitationParams, WorkspaceError>,
+    permission: Unit<Arc<WorkspacePermission>>,
+) -> DataResult<WorkspaceEncryption, WorkspaceError> {
+    let params = data.into_inner();
+    let encryption = permission.accept_invitation(params)?;
+    data_result(encryption)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn set_workspace_setting_handler(
+    data: Params<WorkspaceSettingItem, WorkspaceError>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> Result<(), WorkspaceError> {
+    let item = data.into_inner();
+    let _ = controller.set_setting(item).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn get_workspace_setting_handler(
+    data: Params<WorkspaceSettingKey, WorkspaceError>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<WorkspaceSettingItem, WorkspaceError> {
+    let params = data.into_inner();
+    let item = controller.get_setting(params).await?;
+    data_result(item)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_workspace_settings_handler(
+    data: Params<WorkspaceIdentifier, WorkspaceError>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<RepeatedWorkspaceSettingItem, WorkspaceError> {
+    let params = data.into_inner();
+    let items = controller.read_settings(params).await?;
+    data_result(items)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn export_workspace_handler(
+    data: Params<ExportWorkspaceParams, WorkspaceError>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<ExportData, WorkspaceError> {
+    let params = data.into_inner();
+    let export_data = controller.export_workspace(params).await?;
+    data_result(export_data)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn import_workspace_handler(
+    data: Params<ImportWorkspaceParams, WorkspaceError>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<ImportWorkspaceResult, WorkspaceError> {
+    let params = data.into_inner();
+    let result = controller.import_workspace(params).await?;
+    data_result(result)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn import_notion_handler(
+    data: Params<ImportNotionParams, WorkspaceError>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<ImportNotionResult, WorkspaceError> {
+    let params = data.into_inner();
+    let result = controller.import_notion(params).await?;
+    data_result(result)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn import_enex_handler(
+    data: Params<ImportEnexParams, WorkspaceError>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<ImportEnexResult, WorkspaceError> {
+    let params = data.into_inner();
+    let result = controller.import_enex(params).await?;
+    data_result(result)
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn read_workspace_templates_handler(
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<RepeatedWorkspaceTemplate, WorkspaceError> {
+    let templates = controller.read_workspace_templates();
+    data_result(templates)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn create_workspace_from_template_handler(
+    data: Params<CreateWorkspaceFromTemplateParams, WorkspaceError>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<Workspace, WorkspaceError> {
+    let params = data.into_inner();
+    let workspace = controller.create_workspace_from_template(params).await?;
+    data_result(workspace)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn check_storage_integrity_handler(
+    data: Data<CheckStorageIntegrityRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<StorageIntegrityReport, WorkspaceError> {
+    let report = controller.check_storage_integrity(data.into_inner().repair).await?;
+    data_result(report)
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn sync_workspace_handler(
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<SyncResult, WorkspaceError> {
+    let result = controller.sync().await?;
+    data_result(result)
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn read_sync_state_handler(
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<SyncState, WorkspaceError> {
+    let state = controller.sync_state()?;
+    data_result(state)
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn read_sync_traffic_handler(
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<SyncTraffic, WorkspaceError> {
+    data_result(controller.sync_traffic())
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn read_lan_peers_handler(
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<RepeatedLanPeer, WorkspaceError> {
+    let peers = controller.read_lan_peers()?;
+    data_result(peers)
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn read_sync_settings_handler(
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<SyncSettings, WorkspaceError> {
+    let settings = controller.sync_settings()?;
+    data_result(settings)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_sync_settings_handler(
+    data: Data<SyncSettings>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> Result<(), WorkspaceError> {
+    let _ = controller.update_sync_settings(data.into_inner())?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn read_sync_conflicts_handler(
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<RepeatedSyncConflict, WorkspaceError> {
+    let conflicts = controller.read_sync_conflicts()?;
+    data_result(conflicts)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn resolve_sync_conflict_handler(
+    data: Data<ResolveConflictRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> Result<(), WorkspaceError> {
+    let _ = controller.resolve_sync_conflict(data.into_inner()).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn submit_conflict_resolution_handler(
+    data: Data<SubmitConflictResolutionRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> Result<(), WorkspaceError> {
+    let _ = controller.submit_conflict_resolution(data.into_inner()).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn get_sync_status_handler(
+    data: Data<QuerySyncStatusRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<RepeatedObjectSyncStatus, WorkspaceError> {
+    let statuses = controller.sync_status(data.into_inner()).await?;
+    data_result(statuses)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn set_sync_exclusion_handler(
+    data: Data<SyncExclusionRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> Result<(), WorkspaceError> {
+    let _ = controller.set_sync_exclusion(data.into_inner())?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn read_sync_exclusions_handler(
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<RepeatedSyncExclusion, WorkspaceError> {
+    let exclusions = controller.read_sync_exclusions()?;
+    data_result(exclusions)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn search_handler(
+    data: Data<SearchRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<RepeatedSearchResult, WorkspaceError> {
+    let results = controller.search(data.into_inner())?;
+    data_result(results)
+}
@@ -1,21 +1,45 @@
 use crate::{
+    entities::{view::QueryViewRequest, workspace::QueryWorkspaceRequest},
     errors::WorkspaceError,
     event::WorkspaceEvent,
     handlers::*,
-    services::{server::construct_workspace_server, AppController, TrashCan, ViewController, WorkspaceController},
+    services::{
+        server::construct_workspace_server,
+        AppController,
+        EvernoteImporter,
+        LanSync,
+        NotionImporter,
+        OperationQueue,
+        SyncEngine,
+        SyncScheduler,
+        SyncThrottle,
+        TrashCan,
+        ViewController,
+        WorkspaceController,
+        WorkspaceKeys,
+        WorkspacePermission,
+    },
 };
-use flowy_database::DBConnection;
+use flowy_database::{DBConnection, WriteConnection};
 use flowy_dispatch::prelude::*;
 use flowy_document::module::FlowyDocument;
 use flowy_net::config::ServerConfig;
 use flowy_sqlite::ConnectionPool;
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 pub trait WorkspaceDeps: WorkspaceUser + WorkspaceDatabase {}
 
 pub trait WorkspaceUser: Send + Sync {
     fn user_id(&self) -> Result<String, WorkspaceError>;
     fn token(&self) -> Result<String, WorkspaceError>;
+    // The server isn't sent anything in the background while it's false.
+    fn is_online(&self) -> bool;
+    // Reported by the host, the users can keep the sync from using such networks.
+    fn is_metered(&self) -> bool;
+    // The keys of the encrypted workspaces, they're kept in the secret store of the
+    // user.
+    fn workspace_key(&self, workspace_id: &str) -> Result<Option<String>, WorkspaceError>;
+    fn set_workspace_key(&self, workspace_id: &str, key: &str) -> Result<(), WorkspaceError>;
 }
 
 pub trait WorkspaceDatabase: Send + Sync {
@@ -26,40 +50,145 @@ pub trait WorkspaceDatabase: Send + Sync {
         let conn = pool.get().map_err(|e| WorkspaceError::internal().context(e))?;
         Ok(conn)
     }
+
+    // Waits for the other writers, the transactions that write should use it.
+    fn db_write_connection(&self) -> Result<WriteConnection, WorkspaceError> {
+        let pool = self.db_pool()?;
+        let conn = pool.get_write().map_err(|e| WorkspaceError::internal().context(e))?;
+        Ok(conn)
+    }
+}
+
+// The grids of the grid views, the workspace creates their views and the grid
+// module their fields, rows and cells.
+pub trait WorkspaceGrid: Send + Sync {
+    // Creates the grid with the id from a table whose first row has the names of
+    // the columns. The values that don't fit the type of their column are
+    // returned, described for the user.
+    fn import_grid(
+        &self,
+        grid_id: &str,
+        name: &str,
+        columns: Vec<String>,
+        rows: Vec<Vec<String>>,
+    ) -> Result<Vec<String>, WorkspaceError>;
+}
+
+// The items are kept in the trash for 30 days.
+pub const DEFAULT_TRASH_RETENTION: i64 = 30 * 24 * 60 * 60;
+pub const DEFAULT_TRASH_PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+pub const DEFAULT_GARBAGE_COLLECTION_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+// Used when the user didn't set the interval or the debounce of the sync
+// trigger, see SyncSettings.
+pub const DEFAULT_SYNC_INTERVAL: Duration = Duration::from_secs(15 * 60);
+pub const DEFAULT_SYNC_DEBOUNCE: Duration = Duration::from_secs(2);
+
+// The scheme of the links to the workspaces and the views, e.g.
+// appflowy://workspace/<workspace_id>/view/<view_id>?action=open.
+pub const LINK_SCHEME: &str = "appflowy";
+
+// The views opened last that are kept in the history of each user.
+pub const MAX_RECENT_VIEWS: usize = 20;
+
+#[derive(Debug, Clone)]
+pub struct WorkspaceConfig {
+    // How long, in seconds, the items are kept in the trash.
+    pub trash_retention: i64,
+    // How often the expired trash is purged.
+    pub trash_purge_interval: Duration,
+    // How often the orphaned views, documents and assets are removed.
+    pub garbage_collection_interval: Duration,
+}
+
+impl std::default::Default for WorkspaceConfig {
+    fn default() -> Self {
+        WorkspaceConfig {
+            trash_retention: DEFAULT_TRASH_RETENTION,
+            trash_purge_interval: DEFAULT_TRASH_PURGE_INTERVAL,
+            garbage_collection_interval: DEFAULT_GARBAGE_COLLECTION_INTERVAL,
+        }
+    }
 }
 
 pub fn mk_workspace(
     user: Arc<dyn WorkspaceUser>,
     database: Arc<dyn WorkspaceDatabase>,
     flowy_document: Arc<FlowyDocument>,
+    grid: Arc<dyn WorkspaceGrid>,
     server_config: &ServerConfig,
+    config: WorkspaceConfig,
 ) -> Arc<WorkspaceController> {
     let server = construct_workspace_server(server_config);
+    let scheduler = Arc::new(SyncScheduler::new(user.clone()));
+    let throttle = Arc::new(SyncThrottle::new(user.clone()));
+    let operations = OperationQueue::new(
+        user.clone(),
+        database.clone(),
+        server.clone(),
+        scheduler.clone(),
+        throttle.clone(),
+    );
+
+    let trash_can = Arc::new(TrashCan::new(
+        database.clone(),
+        server.clone(),
+        user.clone(),
+        operations.clone(),
+        config.trash_retention,
+    ));
 
-    let trash_can = Arc::new(TrashCan::new(database.clone(), server.clone(), user.clone()));
+    // The documents of the encrypted workspaces are synced with their keys.
+    let keys = Arc::new(WorkspaceKeys::new(user.clone(), database.clone()));
+    flowy_document.set_sync_key_provider(keys.clone());
 
     let view_controller = Arc::new(ViewController::new(
         user.clone(),
         database.clone(),
         server.clone(),
         trash_can.clone(),
+        operations.clone(),
         flowy_document,
+        keys.clone(),
     ));
 
     let app_controller = Arc::new(AppController::new(
         user.clone(),
         database.clone(),
         trash_can.clone(),
+        operations.clone(),
         server.clone(),
     ));
 
+    let permission = Arc::new(WorkspacePermission::new(user.clone(), database.clone(), keys));
+    let sync_engine = Arc::new(SyncEngine::new(
+        user.clone(),
+        database.clone(),
+        server.clone(),
+        operations.clone(),
+        app_controller.clone(),
+        view_controller.clone(),
+        trash_can.clone(),
+        scheduler,
+        throttle,
+    ));
+    let lan_sync = LanSync::new(user.clone(), database.clone(), operations.clone(), sync_engine.clone());
+    let notion = NotionImporter::new(app_controller.clone(), view_controller.clone(), grid.clone());
+    let evernote = EvernoteImporter::new(app_controller.clone(), view_controller.clone(), grid);
+
     let workspace_controller = Arc::new(WorkspaceController::new(
         user.clone(),
         database.clone(),
         app_controller.clone(),
         view_controller.clone(),
         trash_can.clone(),
+        permission,
+        operations,
+        sync_engine,
+        lan_sync,
         server.clone(),
+        notion,
+        evernote,
     ));
     workspace_controller
 }
@@ -70,39 +199,218 @@ pub fn create(workspace: Arc<WorkspaceController>) -> Module {
         .data(workspace.clone())
         .data(workspace.app_controller.clone())
         .data(workspace.view_controller.clone())
-        .data(workspace.trash_can.clone());
+        .data(workspace.trash_can.clone())
+        .data(workspace.permission.clone());
 
     module = module
         .event(WorkspaceEvent::CreateWorkspace, create_workspace_handler)
         .event(WorkspaceEvent::ReadCurWorkspace, read_cur_workspace_handler)
         .event(WorkspaceEvent::ReadWorkspaces, read_workspaces_handler)
         .event(WorkspaceEvent::OpenWorkspace, open_workspace_handler)
-        .event(WorkspaceEvent::ReadWorkspaceApps, read_workspace_apps_handler);
+        .event_with_permission(
+            WorkspaceEvent::UpdateWorkspace,
+            update_workspace_handler,
+            EventPermission::Manage,
+        )
+        .event_with_permission(
+            WorkspaceEvent::DeleteWorkspace,
+            delete_workspace_handler,
+            EventPermission::Manage,
+        )
+        .event(WorkspaceEvent::ReadWorkspaceApps, read_workspace_apps_handler)
+        .event(WorkspaceEvent::ReadWorkspaceMembers, read_workspace_members_handler)
+        .event_with_permission(
+            WorkspaceEvent::UpdateWorkspaceMember,
+            update_workspace_member_handler,
+            EventPermission::Manage,
+        )
+        .event_with_permission(
+            WorkspaceEvent::SetWorkspaceSetting,
+            set_workspace_setting_handler,
+            EventPermission::Manage,
+        )
+        .event(WorkspaceEvent::GetWorkspaceSetting, get_workspace_setting_handler)
+        .event(WorkspaceEvent::ReadWorkspaceSettings, read_workspace_settings_handler)
+        .event_with_permission(
+            WorkspaceEvent::InviteWorkspaceMember,
+            invite_workspace_member_handler,
+            EventPermission::Manage,
+        )
+        .event(
+            WorkspaceEvent::ReadWorkspaceInvitations,
+            read_workspace_invitations_handler,
+        )
+        .event_with_permission(
+            WorkspaceEvent::CancelWorkspaceInvitation,
+            cancel_workspace_invitation_handler,
+            EventPermission::Manage,
+        )
+        .event_with_permission(
+            WorkspaceEvent::RemoveWorkspaceMember,
+            remove_workspace_member_handler,
+            EventPermission::Manage,
+        )
+        .event_with_permission(
+            WorkspaceEvent::EnableWorkspaceEncryption,
+            enable_workspace_encryption_handler,
+            EventPermission::Manage,
+        )
+        .event(
+            WorkspaceEvent::ReadWorkspaceEncryption,
+            read_workspace_encryption_handler,
+        )
+        .event(
+            WorkspaceEvent::AcceptWorkspaceInvitation,
+            accept_workspace_invitation_handler,
+        );
 
     module = module
-        .event(WorkspaceEvent::CreateApp, create_app_handler)
+        .event_with_permission(WorkspaceEvent::CreateApp, create_app_handler, EventPermission::Write)
         .event(WorkspaceEvent::ReadApp, read_app_handler)
-        .event(WorkspaceEvent::UpdateApp, update_app_handler)
-        .event(WorkspaceEvent::DeleteApp, delete_app_handler);
+        .event_with_permission(WorkspaceEvent::UpdateApp, update_app_handler, EventPermission::Write)
+        .event_with_permission(WorkspaceEvent::DeleteApp, delete_app_handler, EventPermission::Write);
 
     module = module
-        .event(WorkspaceEvent::CreateView, create_view_handler)
+        .event_with_permission(WorkspaceEvent::CreateView, create_view_handler, EventPermission::Write)
         .event(WorkspaceEvent::ReadView, read_view_handler)
-        .event(WorkspaceEvent::UpdateView, update_view_handler)
-        .event(WorkspaceEvent::DeleteView, delete_view_handler)
-        .event(WorkspaceEvent::DuplicateView, duplicate_view_handler)
+        .event(WorkspaceEvent::ReadViewTree, read_view_tree_handler)
+        .event_with_permission(WorkspaceEvent::UpdateView, update_view_handler, EventPermission::Write)
+        .event_with_permission(WorkspaceEvent::DeleteView, delete_view_handler, EventPermission::Write)
+        .event_with_permission(
+            WorkspaceEvent::DuplicateView,
+            duplicate_view_handler,
+            EventPermission::Write,
+        )
+        .event_with_permission(
+            WorkspaceEvent::DuplicateDocument,
+            duplicate_document_handler,
+            EventPermission::Write,
+        )
+        .event_with_permission(WorkspaceEvent::MoveView, move_view_handler, EventPermission::Write)
+        .event_with_permission(
+            WorkspaceEvent::BatchViewOperation,
+            batch_view_operation_handler,
+            EventPermission::Write,
+        )
+        .event(WorkspaceEvent::PinView, pin_view_handler)
+        .event(WorkspaceEvent::UnpinView, unpin_view_handler)
+        .event(WorkspaceEvent::ReadFavorites, read_favorites_handler)
+        .event(WorkspaceEvent::MoveFavorite, move_favorite_handler)
+        .event(WorkspaceEvent::ReadRecentViews, read_recent_views_handler)
         .event(WorkspaceEvent::OpenView, open_view_handler)
         .event(WorkspaceEvent::CloseView, close_view_handler)
-        .event(WorkspaceEvent::ApplyDocDelta, apply_doc_delta_handler);
+        .event_with_permission(
+            WorkspaceEvent::ApplyDocDelta,
+            apply_doc_delta_handler,
+            EventPermission::Write,
+        );
 
     module = module
         .event(WorkspaceEvent::ReadTrash, read_trash_handler)
-        .event(WorkspaceEvent::PutbackTrash, putback_trash_handler)
-        .event(WorkspaceEvent::DeleteTrash, delete_trash_handler)
-        .event(WorkspaceEvent::RestoreAll, restore_all_handler)
-        .event(WorkspaceEvent::DeleteAll, delete_all_handler);
+        .event_with_permission(
+            WorkspaceEvent::PutbackTrash,
+            putback_trash_handler,
+            EventPermission::Write,
+        )
+        .event_with_permission(
+            WorkspaceEvent::DeleteTrash,
+            delete_trash_handler,
+            EventPermission::Write,
+        )
+        .event_with_permission(WorkspaceEvent::RestoreAll, restore_all_handler, EventPermission::Write)
+        .event_with_permission(WorkspaceEvent::DeleteAll, delete_all_handler, EventPermission::Write)
+        .event(WorkspaceEvent::PurgeTrash, purge_trash_handler)
+        .event(WorkspaceEvent::RunGarbageCollection, run_garbage_collection_handler);
+
+    module = module
+        .event(WorkspaceEvent::CheckStorageIntegrity, check_storage_integrity_handler)
+        .event(WorkspaceEvent::SyncWorkspace, sync_workspace_handler)
+        .event(WorkspaceEvent::ReadSyncState, read_sync_state_handler)
+        .event(WorkspaceEvent::ReadSyncSettings, read_sync_settings_handler)
+        .event(WorkspaceEvent::UpdateSyncSettings, update_sync_settings_handler)
+        .event(WorkspaceEvent::ReadSyncConflicts, read_sync_conflicts_handler)
+        .event(WorkspaceEvent::ResolveSyncConflict, resolve_sync_conflict_handler)
+        .event(WorkspaceEvent::GetSyncStatus, get_sync_status_handler)
+        .event(WorkspaceEvent::SetSyncExclusion, set_sync_exclusion_handler)
+        .event(WorkspaceEvent::ReadSyncExclusions, read_sync_exclusions_handler)
+        .event(
+            WorkspaceEvent::SubmitConflictResolution,
+            submit_conflict_resolution_handler,
+        )
+        .event(WorkspaceEvent::ReadSyncTraffic, read_sync_traffic_handler)
+        .event(WorkspaceEvent::ReadLanPeers, read_lan_peers_handler);
+
+    module = module
+        .event(WorkspaceEvent::ExportDocument, export_handler)
+        .event_with_permission(WorkspaceEvent::ImportDocument, import_handler, EventPermission::Write)
+        .event_with_permission(
+            WorkspaceEvent::IngestSharedContent,
+            ingest_shared_content_handler,
+            EventPermission::Write,
+        )
+        .event(WorkspaceEvent::ExportWorkspace, export_workspace_handler)
+        .event_with_permission(
+            WorkspaceEvent::ImportWorkspace,
+            import_workspace_handler,
+            EventPermission::Write,
+        )
+        .event_with_permission(
+            WorkspaceEvent::ImportNotion,
+            import_notion_handler,
+            EventPermission::Write,
+        )
+        .event_with_permission(WorkspaceEvent::ImportEnex, import_enex_handler, EventPermission::Write);
+
+    module = module
+        .event(WorkspaceEvent::ReadTemplates, read_templates_handler)
+        .event_with_permission(
+            WorkspaceEvent::CreateViewFromTemplate,
+            create_view_from_template_handler,
+            EventPermission::Write,
+        )
+        .event_with_permission(
+            WorkspaceEvent::SaveTemplate,
+            save_template_handler,
+            EventPermission::Write,
+        )
+        .event(WorkspaceEvent::ReadWorkspaceTemplates, read_workspace_templates_handler)
+        .event_with_permission(
+            WorkspaceEvent::CreateWorkspaceFromTemplate,
+            create_workspace_from_template_handler,
+            EventPermission::Write,
+        );
 
-    module = module.event(WorkspaceEvent::ExportDocument, export_handler);
+    module = module.event(WorkspaceEvent::Search, search_handler);
+
+    module = module
+        .link(LINK_SCHEME, "workspace/:workspace_id", workspace_link)
+        .link(LINK_SCHEME, "workspace/:workspace_id/view/:view_id", view_link);
 
     module
 }
+
+fn workspace_link(params: &LinkParams) -> Result<ModuleRequest, LinkError> {
+    let request = QueryWorkspaceRequest::new(Some(params.get("workspace_id")?.to_owned()));
+    link_request(WorkspaceEvent::OpenWorkspace, request)
+}
+
+// The view is opened unless the action is read, which only returns it. The
+// views are found by their ids, the workspace of the link isn't checked.
+fn view_link(params: &LinkParams) -> Result<ModuleRequest, LinkError> {
+    let event = match params.query("action").unwrap_or("open") {
+        "open" => WorkspaceEvent::OpenView,
+        "read" => WorkspaceEvent::ReadView,
+        action => return Err(LinkError::Invalid(format!("unknown action {}", action))),
+    };
+    let request = QueryViewRequest {
+        view_ids: vec![params.get("view_id")?.to_owned()],
+    };
+    link_request(event, request)
+}
+
+fn link_request<T: ToBytes>(event: WorkspaceEvent, request: T) -> Result<ModuleRequest, LinkError> {
+    let payload = request
+        .into_bytes()
+        .map_err(|e| LinkError::Invalid(format!("{:?}", e)))?;
+    Ok(ModuleRequest::new(event).payload(payload))
+}
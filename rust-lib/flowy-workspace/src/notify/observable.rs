@@ -1,6 +1,6 @@
 use flowy_dart_notify::DartNotifyBuilder;
 use flowy_derive::ProtoBuf_Enum;
-const OBSERVABLE_CATEGORY: &'static str = "Workspace";
+pub(crate) const OBSERVABLE_CATEGORY: &'static str = "Workspace";
 
 // Opti: Using the Rust macro to generate the serde code automatically that can
 // be use directly in flutter
@@ -12,11 +12,23 @@ pub(crate) enum WorkspaceNotification {
     WorkspaceUpdated     = 12,
     WorkspaceListUpdated = 13,
     WorkspaceAppsChanged = 14,
+    WorkspaceMembersChanged = 15,
+    WorkspaceImportProgress = 16,
+    WorkspaceSettingChanged = 17,
+    WorkspaceInvitationsChanged = 18,
+    SyncStateChanged     = 19,
+    SyncConflictReported = 20,
     AppUpdated           = 21,
+    ObjectSyncStatusChanged = 22,
+    SyncBackfillProgress = 23,
     AppViewsChanged      = 24,
+    LanPeersChanged      = 25,
     ViewUpdated          = 31,
     ViewDeleted          = 32,
     ViewRestored         = 33,
+    FavoritesChanged     = 34,
+    RecentViewsChanged   = 35,
+    ViewsBatchChanged    = 36,
     UserUnauthorized     = 100,
     TrashUpdated         = 1000,
 }
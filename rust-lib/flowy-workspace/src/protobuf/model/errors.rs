@@ -28,6 +28,7 @@ pub struct WorkspaceError {
     // message fields
     pub code: i32,
     pub msg: ::std::string::String,
+    pub suggestions: ::protobuf::RepeatedField<::std::string::String>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -84,6 +85,31 @@ impl WorkspaceError {
     pub fn take_msg(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.msg, ::std::string::String::new())
     }
+
+    // repeated string suggestions = 3;
+
+
+    pub fn get_suggestions(&self) -> &[::std::string::String] {
+        &self.suggestions
+    }
+    pub fn clear_suggestions(&mut self) {
+        self.suggestions.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_suggestions(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.suggestions = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_suggestions(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.suggestions
+    }
+
+    // Take field
+    pub fn take_suggestions(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.suggestions, ::protobuf::RepeatedField::new())
+    }
 }
 
 impl ::protobuf::Message for WorkspaceError {
@@ -105,6 +131,9 @@ impl ::protobuf::Message for WorkspaceError {
                 2 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.msg)?;
                 },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.suggestions)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -123,6 +152,9 @@ impl ::protobuf::Message for WorkspaceError {
         if !self.msg.is_empty() {
             my_size += ::protobuf::rt::string_size(2, &self.msg);
         }
+        for value in &self.suggestions {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -135,6 +167,9 @@ impl ::protobuf::Message for WorkspaceError {
         if !self.msg.is_empty() {
             os.write_string(2, &self.msg)?;
         }
+        for v in &self.suggestions {
+            os.write_string(3, &v)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -183,6 +218,11 @@ impl ::protobuf::Message for WorkspaceError {
                 |m: &WorkspaceError| { &m.msg },
                 |m: &mut WorkspaceError| { &mut m.msg },
             ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "suggestions",
+                |m: &WorkspaceError| { &m.suggestions },
+                |m: &mut WorkspaceError| { &mut m.suggestions },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<WorkspaceError>(
                 "WorkspaceError",
                 fields,
@@ -201,6 +241,7 @@ impl ::protobuf::Clear for WorkspaceError {
     fn clear(&mut self) {
         self.code = 0;
         self.msg.clear();
+        self.suggestions.clear();
         self.unknown_fields.clear();
     }
 }
@@ -218,16 +259,10 @@ impl ::protobuf::reflect::ProtobufValue for WorkspaceError {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0cerrors.proto\"6\n\x0eWorkspaceError\x12\x12\n\x04code\x18\x01\x20\
-    \x01(\x05R\x04code\x12\x10\n\x03msg\x18\x02\x20\x01(\tR\x03msgJ\x98\x01\
-    \n\x06\x12\x04\0\0\x05\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\
-    \0\x12\x04\x02\0\x05\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x16\n\x0b\n\
-    \x04\x04\0\x02\0\x12\x03\x03\x04\x13\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\
-    \x03\x04\t\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\n\x0e\n\x0c\n\x05\x04\
-    \0\x02\0\x03\x12\x03\x03\x11\x12\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\
-    \x04\x13\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\
-    \0\x02\x01\x01\x12\x03\x04\x0b\x0e\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\
-    \x04\x11\x12b\x06proto3\
+    \n\x0cerrors.proto\"`\n\x0eWorkspaceError\x12\x14\n\x04code\x18\x01\x20\
+    \x01(\x05R\x04codeB\0\x12\x12\n\x03msg\x18\x02\x20\x01(\tR\x03msgB\0\x12\
+    \"\n\x0bsuggestions\x18\x03\x20\x03(\tR\x0bsuggestionsB\0:\0B\0b\x06prot\
+    o3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
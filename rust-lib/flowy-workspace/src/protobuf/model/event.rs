@@ -31,6 +31,19 @@ pub enum WorkspaceEvent {
     DeleteWorkspace = 3,
     OpenWorkspace = 4,
     ReadWorkspaceApps = 5,
+    ReadWorkspaceMembers = 6,
+    UpdateWorkspaceMember = 7,
+    UpdateWorkspace = 8,
+    SetWorkspaceSetting = 9,
+    GetWorkspaceSetting = 10,
+    ReadWorkspaceSettings = 11,
+    InviteWorkspaceMember = 12,
+    ReadWorkspaceInvitations = 13,
+    CancelWorkspaceInvitation = 14,
+    RemoveWorkspaceMember = 15,
+    EnableWorkspaceEncryption = 16,
+    ReadWorkspaceEncryption = 17,
+    AcceptWorkspaceInvitation = 18,
     CreateApp = 101,
     DeleteApp = 102,
     ReadApp = 103,
@@ -43,13 +56,49 @@ pub enum WorkspaceEvent {
     CopyLink = 206,
     OpenView = 207,
     CloseView = 208,
+    DuplicateDocument = 209,
+    MoveView = 210,
+    PinView = 211,
+    UnpinView = 212,
+    ReadFavorites = 213,
+    MoveFavorite = 214,
+    ReadViewTree = 215,
+    ReadRecentViews = 216,
+    BatchViewOperation = 217,
     ReadTrash = 300,
     PutbackTrash = 301,
     DeleteTrash = 302,
     RestoreAll = 303,
     DeleteAll = 304,
+    PurgeTrash = 305,
+    RunGarbageCollection = 306,
     ApplyDocDelta = 400,
     ExportDocument = 500,
+    ImportDocument = 501,
+    ExportWorkspace = 502,
+    ImportWorkspace = 503,
+    ImportNotion = 504,
+    ImportEnex = 505,
+    IngestSharedContent = 506,
+    ReadTemplates = 600,
+    CreateViewFromTemplate = 601,
+    SaveTemplate = 602,
+    ReadWorkspaceTemplates = 603,
+    CreateWorkspaceFromTemplate = 604,
+    CheckStorageIntegrity = 700,
+    SyncWorkspace = 800,
+    ReadSyncState = 801,
+    ReadSyncSettings = 802,
+    UpdateSyncSettings = 803,
+    ReadSyncConflicts = 804,
+    ResolveSyncConflict = 805,
+    GetSyncStatus = 806,
+    SetSyncExclusion = 807,
+    ReadSyncExclusions = 808,
+    SubmitConflictResolution = 809,
+    ReadSyncTraffic = 810,
+    ReadLanPeers = 811,
+    Search = 900,
 }
 
 impl ::protobuf::ProtobufEnum for WorkspaceEvent {
@@ -65,6 +114,19 @@ impl ::protobuf::ProtobufEnum for WorkspaceEvent {
             3 => ::std::option::Option::Some(WorkspaceEvent::DeleteWorkspace),
             4 => ::std::option::Option::Some(WorkspaceEvent::OpenWorkspace),
             5 => ::std::option::Option::Some(WorkspaceEvent::ReadWorkspaceApps),
+            6 => ::std::option::Option::Some(WorkspaceEvent::ReadWorkspaceMembers),
+            7 => ::std::option::Option::Some(WorkspaceEvent::UpdateWorkspaceMember),
+            8 => ::std::option::Option::Some(WorkspaceEvent::UpdateWorkspace),
+            9 => ::std::option::Option::Some(WorkspaceEvent::SetWorkspaceSetting),
+            10 => ::std::option::Option::Some(WorkspaceEvent::GetWorkspaceSetting),
+            11 => ::std::option::Option::Some(WorkspaceEvent::ReadWorkspaceSettings),
+            12 => ::std::option::Option::Some(WorkspaceEvent::InviteWorkspaceMember),
+            13 => ::std::option::Option::Some(WorkspaceEvent::ReadWorkspaceInvitations),
+            14 => ::std::option::Option::Some(WorkspaceEvent::CancelWorkspaceInvitation),
+            15 => ::std::option::Option::Some(WorkspaceEvent::RemoveWorkspaceMember),
+            16 => ::std::option::Option::Some(WorkspaceEvent::EnableWorkspaceEncryption),
+            17 => ::std::option::Option::Some(WorkspaceEvent::ReadWorkspaceEncryption),
+            18 => ::std::option::Option::Some(WorkspaceEvent::AcceptWorkspaceInvitation),
             101 => ::std::option::Option::Some(WorkspaceEvent::CreateApp),
             102 => ::std::option::Option::Some(WorkspaceEvent::DeleteApp),
             103 => ::std::option::Option::Some(WorkspaceEvent::ReadApp),
@@ -77,13 +139,49 @@ impl ::protobuf::ProtobufEnum for WorkspaceEvent {
             206 => ::std::option::Option::Some(WorkspaceEvent::CopyLink),
             207 => ::std::option::Option::Some(WorkspaceEvent::OpenView),
             208 => ::std::option::Option::Some(WorkspaceEvent::CloseView),
+            209 => ::std::option::Option::Some(WorkspaceEvent::DuplicateDocument),
+            210 => ::std::option::Option::Some(WorkspaceEvent::MoveView),
+            211 => ::std::option::Option::Some(WorkspaceEvent::PinView),
+            212 => ::std::option::Option::Some(WorkspaceEvent::UnpinView),
+            213 => ::std::option::Option::Some(WorkspaceEvent::ReadFavorites),
+            214 => ::std::option::Option::Some(WorkspaceEvent::MoveFavorite),
+            215 => ::std::option::Option::Some(WorkspaceEvent::ReadViewTree),
+            216 => ::std::option::Option::Some(WorkspaceEvent::ReadRecentViews),
+            217 => ::std::option::Option::Some(WorkspaceEvent::BatchViewOperation),
             300 => ::std::option::Option::Some(WorkspaceEvent::ReadTrash),
             301 => ::std::option::Option::Some(WorkspaceEvent::PutbackTrash),
             302 => ::std::option::Option::Some(WorkspaceEvent::DeleteTrash),
             303 => ::std::option::Option::Some(WorkspaceEvent::RestoreAll),
             304 => ::std::option::Option::Some(WorkspaceEvent::DeleteAll),
+            305 => ::std::option::Option::Some(WorkspaceEvent::PurgeTrash),
+            306 => ::std::option::Option::Some(WorkspaceEvent::RunGarbageCollection),
             400 => ::std::option::Option::Some(WorkspaceEvent::ApplyDocDelta),
             500 => ::std::option::Option::Some(WorkspaceEvent::ExportDocument),
+            501 => ::std::option::Option::Some(WorkspaceEvent::ImportDocument),
+            502 => ::std::option::Option::Some(WorkspaceEvent::ExportWorkspace),
+            503 => ::std::option::Option::Some(WorkspaceEvent::ImportWorkspace),
+            504 => ::std::option::Option::Some(WorkspaceEvent::ImportNotion),
+            505 => ::std::option::Option::Some(WorkspaceEvent::ImportEnex),
+            506 => ::std::option::Option::Some(WorkspaceEvent::IngestSharedContent),
+            600 => ::std::option::Option::Some(WorkspaceEvent::ReadTemplates),
+            601 => ::std::option::Option::Some(WorkspaceEvent::CreateViewFromTemplate),
+            602 => ::std::option::Option::Some(WorkspaceEvent::SaveTemplate),
+            603 => ::std::option::Option::Some(WorkspaceEvent::ReadWorkspaceTemplates),
+            604 => ::std::option::Option::Some(WorkspaceEvent::CreateWorkspaceFromTemplate),
+            700 => ::std::option::Option::Some(WorkspaceEvent::CheckStorageIntegrity),
+            800 => ::std::option::Option::Some(WorkspaceEvent::SyncWorkspace),
+            801 => ::std::option::Option::Some(WorkspaceEvent::ReadSyncState),
+            802 => ::std::option::Option::Some(WorkspaceEvent::ReadSyncSettings),
+            803 => ::std::option::Option::Some(WorkspaceEvent::UpdateSyncSettings),
+            804 => ::std::option::Option::Some(WorkspaceEvent::ReadSyncConflicts),
+            805 => ::std::option::Option::Some(WorkspaceEvent::ResolveSyncConflict),
+            806 => ::std::option::Option::Some(WorkspaceEvent::GetSyncStatus),
+            807 => ::std::option::Option::Some(WorkspaceEvent::SetSyncExclusion),
+            808 => ::std::option::Option::Some(WorkspaceEvent::ReadSyncExclusions),
+            809 => ::std::option::Option::Some(WorkspaceEvent::SubmitConflictResolution),
+            810 => ::std::option::Option::Some(WorkspaceEvent::ReadSyncTraffic),
+            811 => ::std::option::Option::Some(WorkspaceEvent::ReadLanPeers),
+            900 => ::std::option::Option::Some(WorkspaceEvent::Search),
             _ => ::std::option::Option::None
         }
     }
@@ -96,6 +194,19 @@ impl ::protobuf::ProtobufEnum for WorkspaceEvent {
             WorkspaceEvent::DeleteWorkspace,
             WorkspaceEvent::OpenWorkspace,
             WorkspaceEvent::ReadWorkspaceApps,
+            WorkspaceEvent::ReadWorkspaceMembers,
+            WorkspaceEvent::UpdateWorkspaceMember,
+            WorkspaceEvent::UpdateWorkspace,
+            WorkspaceEvent::SetWorkspaceSetting,
+            WorkspaceEvent::GetWorkspaceSetting,
+            WorkspaceEvent::ReadWorkspaceSettings,
+            WorkspaceEvent::InviteWorkspaceMember,
+            WorkspaceEvent::ReadWorkspaceInvitations,
+            WorkspaceEvent::CancelWorkspaceInvitation,
+            WorkspaceEvent::RemoveWorkspaceMember,
+            WorkspaceEvent::EnableWorkspaceEncryption,
+            WorkspaceEvent::ReadWorkspaceEncryption,
+            WorkspaceEvent::AcceptWorkspaceInvitation,
             WorkspaceEvent::CreateApp,
             WorkspaceEvent::DeleteApp,
             WorkspaceEvent::ReadApp,
@@ -108,13 +219,49 @@ impl ::protobuf::ProtobufEnum for WorkspaceEvent {
             WorkspaceEvent::CopyLink,
             WorkspaceEvent::OpenView,
             WorkspaceEvent::CloseView,
+            WorkspaceEvent::DuplicateDocument,
+            WorkspaceEvent::MoveView,
+            WorkspaceEvent::PinView,
+            WorkspaceEvent::UnpinView,
+            WorkspaceEvent::ReadFavorites,
+            WorkspaceEvent::MoveFavorite,
+            WorkspaceEvent::ReadViewTree,
+            WorkspaceEvent::ReadRecentViews,
+            WorkspaceEvent::BatchViewOperation,
             WorkspaceEvent::ReadTrash,
             WorkspaceEvent::PutbackTrash,
             WorkspaceEvent::DeleteTrash,
             WorkspaceEvent::RestoreAll,
             WorkspaceEvent::DeleteAll,
+            WorkspaceEvent::PurgeTrash,
+            WorkspaceEvent::RunGarbageCollection,
             WorkspaceEvent::ApplyDocDelta,
             WorkspaceEvent::ExportDocument,
+            WorkspaceEvent::ImportDocument,
+            WorkspaceEvent::ExportWorkspace,
+            WorkspaceEvent::ImportWorkspace,
+            WorkspaceEvent::ImportNotion,
+            WorkspaceEvent::ImportEnex,
+            WorkspaceEvent::IngestSharedContent,
+            WorkspaceEvent::ReadTemplates,
+            WorkspaceEvent::CreateViewFromTemplate,
+            WorkspaceEvent::SaveTemplate,
+            WorkspaceEvent::ReadWorkspaceTemplates,
+            WorkspaceEvent::CreateWorkspaceFromTemplate,
+            WorkspaceEvent::CheckStorageIntegrity,
+            WorkspaceEvent::SyncWorkspace,
+            WorkspaceEvent::ReadSyncState,
+            WorkspaceEvent::ReadSyncSettings,
+            WorkspaceEvent::UpdateSyncSettings,
+            WorkspaceEvent::ReadSyncConflicts,
+            WorkspaceEvent::ResolveSyncConflict,
+            WorkspaceEvent::GetSyncStatus,
+            WorkspaceEvent::SetSyncExclusion,
+            WorkspaceEvent::ReadSyncExclusions,
+            WorkspaceEvent::SubmitConflictResolution,
+            WorkspaceEvent::ReadSyncTraffic,
+            WorkspaceEvent::ReadLanPeers,
+            WorkspaceEvent::Search,
         ];
         values
     }
@@ -143,72 +290,47 @@ impl ::protobuf::reflect::ProtobufValue for WorkspaceEvent {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0bevent.proto*\xcb\x03\n\x0eWorkspaceEvent\x12\x13\n\x0fCreateWorksp\
+    \n\x0bevent.proto*\xcd\x0c\n\x0eWorkspaceEvent\x12\x13\n\x0fCreateWorksp\
     ace\x10\0\x12\x14\n\x10ReadCurWorkspace\x10\x01\x12\x12\n\x0eReadWorkspa\
     ces\x10\x02\x12\x13\n\x0fDeleteWorkspace\x10\x03\x12\x11\n\rOpenWorkspac\
-    e\x10\x04\x12\x15\n\x11ReadWorkspaceApps\x10\x05\x12\r\n\tCreateApp\x10e\
-    \x12\r\n\tDeleteApp\x10f\x12\x0b\n\x07ReadApp\x10g\x12\r\n\tUpdateApp\
-    \x10h\x12\x0f\n\nCreateView\x10\xc9\x01\x12\r\n\x08ReadView\x10\xca\x01\
-    \x12\x0f\n\nUpdateView\x10\xcb\x01\x12\x0f\n\nDeleteView\x10\xcc\x01\x12\
-    \x12\n\rDuplicateView\x10\xcd\x01\x12\r\n\x08CopyLink\x10\xce\x01\x12\r\
-    \n\x08OpenView\x10\xcf\x01\x12\x0e\n\tCloseView\x10\xd0\x01\x12\x0e\n\tR\
-    eadTrash\x10\xac\x02\x12\x11\n\x0cPutbackTrash\x10\xad\x02\x12\x10\n\x0b\
-    DeleteTrash\x10\xae\x02\x12\x0f\n\nRestoreAll\x10\xaf\x02\x12\x0e\n\tDel\
-    eteAll\x10\xb0\x02\x12\x12\n\rApplyDocDelta\x10\x90\x03\x12\x13\n\x0eExp\
-    ortDocument\x10\xf4\x03J\xab\x08\n\x06\x12\x04\0\0\x1c\x01\n\x08\n\x01\
-    \x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0\x1c\x01\n\n\n\x03\x05\
-    \0\x01\x12\x03\x02\x05\x13\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\x18\n\
-    \x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x04\x13\n\x0c\n\x05\x05\0\x02\0\
-    \x02\x12\x03\x03\x16\x17\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\x04\x19\n\
-    \x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\x04\x14\n\x0c\n\x05\x05\0\x02\
-    \x01\x02\x12\x03\x04\x17\x18\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x05\x04\
-    \x17\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x05\x04\x12\n\x0c\n\x05\x05\0\
-    \x02\x02\x02\x12\x03\x05\x15\x16\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x06\
-    \x04\x18\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x06\x04\x13\n\x0c\n\x05\
-    \x05\0\x02\x03\x02\x12\x03\x06\x16\x17\n\x0b\n\x04\x05\0\x02\x04\x12\x03\
-    \x07\x04\x16\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x07\x04\x11\n\x0c\n\
-    \x05\x05\0\x02\x04\x02\x12\x03\x07\x14\x15\n\x0b\n\x04\x05\0\x02\x05\x12\
-    \x03\x08\x04\x1a\n\x0c\n\x05\x05\0\x02\x05\x01\x12\x03\x08\x04\x15\n\x0c\
-    \n\x05\x05\0\x02\x05\x02\x12\x03\x08\x18\x19\n\x0b\n\x04\x05\0\x02\x06\
-    \x12\x03\t\x04\x14\n\x0c\n\x05\x05\0\x02\x06\x01\x12\x03\t\x04\r\n\x0c\n\
-    \x05\x05\0\x02\x06\x02\x12\x03\t\x10\x13\n\x0b\n\x04\x05\0\x02\x07\x12\
-    \x03\n\x04\x14\n\x0c\n\x05\x05\0\x02\x07\x01\x12\x03\n\x04\r\n\x0c\n\x05\
-    \x05\0\x02\x07\x02\x12\x03\n\x10\x13\n\x0b\n\x04\x05\0\x02\x08\x12\x03\
-    \x0b\x04\x12\n\x0c\n\x05\x05\0\x02\x08\x01\x12\x03\x0b\x04\x0b\n\x0c\n\
-    \x05\x05\0\x02\x08\x02\x12\x03\x0b\x0e\x11\n\x0b\n\x04\x05\0\x02\t\x12\
-    \x03\x0c\x04\x14\n\x0c\n\x05\x05\0\x02\t\x01\x12\x03\x0c\x04\r\n\x0c\n\
-    \x05\x05\0\x02\t\x02\x12\x03\x0c\x10\x13\n\x0b\n\x04\x05\0\x02\n\x12\x03\
-    \r\x04\x15\n\x0c\n\x05\x05\0\x02\n\x01\x12\x03\r\x04\x0e\n\x0c\n\x05\x05\
-    \0\x02\n\x02\x12\x03\r\x11\x14\n\x0b\n\x04\x05\0\x02\x0b\x12\x03\x0e\x04\
-    \x13\n\x0c\n\x05\x05\0\x02\x0b\x01\x12\x03\x0e\x04\x0c\n\x0c\n\x05\x05\0\
-    \x02\x0b\x02\x12\x03\x0e\x0f\x12\n\x0b\n\x04\x05\0\x02\x0c\x12\x03\x0f\
-    \x04\x15\n\x0c\n\x05\x05\0\x02\x0c\x01\x12\x03\x0f\x04\x0e\n\x0c\n\x05\
-    \x05\0\x02\x0c\x02\x12\x03\x0f\x11\x14\n\x0b\n\x04\x05\0\x02\r\x12\x03\
-    \x10\x04\x15\n\x0c\n\x05\x05\0\x02\r\x01\x12\x03\x10\x04\x0e\n\x0c\n\x05\
-    \x05\0\x02\r\x02\x12\x03\x10\x11\x14\n\x0b\n\x04\x05\0\x02\x0e\x12\x03\
-    \x11\x04\x18\n\x0c\n\x05\x05\0\x02\x0e\x01\x12\x03\x11\x04\x11\n\x0c\n\
-    \x05\x05\0\x02\x0e\x02\x12\x03\x11\x14\x17\n\x0b\n\x04\x05\0\x02\x0f\x12\
-    \x03\x12\x04\x13\n\x0c\n\x05\x05\0\x02\x0f\x01\x12\x03\x12\x04\x0c\n\x0c\
-    \n\x05\x05\0\x02\x0f\x02\x12\x03\x12\x0f\x12\n\x0b\n\x04\x05\0\x02\x10\
-    \x12\x03\x13\x04\x13\n\x0c\n\x05\x05\0\x02\x10\x01\x12\x03\x13\x04\x0c\n\
-    \x0c\n\x05\x05\0\x02\x10\x02\x12\x03\x13\x0f\x12\n\x0b\n\x04\x05\0\x02\
-    \x11\x12\x03\x14\x04\x14\n\x0c\n\x05\x05\0\x02\x11\x01\x12\x03\x14\x04\r\
-    \n\x0c\n\x05\x05\0\x02\x11\x02\x12\x03\x14\x10\x13\n\x0b\n\x04\x05\0\x02\
-    \x12\x12\x03\x15\x04\x14\n\x0c\n\x05\x05\0\x02\x12\x01\x12\x03\x15\x04\r\
-    \n\x0c\n\x05\x05\0\x02\x12\x02\x12\x03\x15\x10\x13\n\x0b\n\x04\x05\0\x02\
-    \x13\x12\x03\x16\x04\x17\n\x0c\n\x05\x05\0\x02\x13\x01\x12\x03\x16\x04\
-    \x10\n\x0c\n\x05\x05\0\x02\x13\x02\x12\x03\x16\x13\x16\n\x0b\n\x04\x05\0\
-    \x02\x14\x12\x03\x17\x04\x16\n\x0c\n\x05\x05\0\x02\x14\x01\x12\x03\x17\
-    \x04\x0f\n\x0c\n\x05\x05\0\x02\x14\x02\x12\x03\x17\x12\x15\n\x0b\n\x04\
-    \x05\0\x02\x15\x12\x03\x18\x04\x15\n\x0c\n\x05\x05\0\x02\x15\x01\x12\x03\
-    \x18\x04\x0e\n\x0c\n\x05\x05\0\x02\x15\x02\x12\x03\x18\x11\x14\n\x0b\n\
-    \x04\x05\0\x02\x16\x12\x03\x19\x04\x14\n\x0c\n\x05\x05\0\x02\x16\x01\x12\
-    \x03\x19\x04\r\n\x0c\n\x05\x05\0\x02\x16\x02\x12\x03\x19\x10\x13\n\x0b\n\
-    \x04\x05\0\x02\x17\x12\x03\x1a\x04\x18\n\x0c\n\x05\x05\0\x02\x17\x01\x12\
-    \x03\x1a\x04\x11\n\x0c\n\x05\x05\0\x02\x17\x02\x12\x03\x1a\x14\x17\n\x0b\
-    \n\x04\x05\0\x02\x18\x12\x03\x1b\x04\x19\n\x0c\n\x05\x05\0\x02\x18\x01\
-    \x12\x03\x1b\x04\x12\n\x0c\n\x05\x05\0\x02\x18\x02\x12\x03\x1b\x15\x18b\
-    \x06proto3\
+    e\x10\x04\x12\x15\n\x11ReadWorkspaceApps\x10\x05\x12\x18\n\x14ReadWorksp\
+    aceMembers\x10\x06\x12\x19\n\x15UpdateWorkspaceMember\x10\x07\x12\x13\n\
+    \x0fUpdateWorkspace\x10\x08\x12\x17\n\x13SetWorkspaceSetting\x10\t\x12\
+    \x17\n\x13GetWorkspaceSetting\x10\n\x12\x19\n\x15ReadWorkspaceSettings\
+    \x10\x0b\x12\x19\n\x15InviteWorkspaceMember\x10\x0c\x12\x1c\n\x18ReadWor\
+    kspaceInvitations\x10\r\x12\x1d\n\x19CancelWorkspaceInvitation\x10\x0e\
+    \x12\x19\n\x15RemoveWorkspaceMember\x10\x0f\x12\x1d\n\x19EnableWorkspace\
+    Encryption\x10\x10\x12\x1b\n\x17ReadWorkspaceEncryption\x10\x11\x12\x1d\
+    \n\x19AcceptWorkspaceInvitation\x10\x12\x12\r\n\tCreateApp\x10e\x12\r\n\
+    \tDeleteApp\x10f\x12\x0b\n\x07ReadApp\x10g\x12\r\n\tUpdateApp\x10h\x12\
+    \x0f\n\nCreateView\x10\xc9\x01\x12\r\n\x08ReadView\x10\xca\x01\x12\x0f\n\
+    \nUpdateView\x10\xcb\x01\x12\x0f\n\nDeleteView\x10\xcc\x01\x12\x12\n\rDu\
+    plicateView\x10\xcd\x01\x12\r\n\x08CopyLink\x10\xce\x01\x12\r\n\x08OpenV\
+    iew\x10\xcf\x01\x12\x0e\n\tCloseView\x10\xd0\x01\x12\x16\n\x11DuplicateD\
+    ocument\x10\xd1\x01\x12\r\n\x08MoveView\x10\xd2\x01\x12\x0c\n\x07PinView\
+    \x10\xd3\x01\x12\x0e\n\tUnpinView\x10\xd4\x01\x12\x12\n\rReadFavorites\
+    \x10\xd5\x01\x12\x11\n\x0cMoveFavorite\x10\xd6\x01\x12\x11\n\x0cReadView\
+    Tree\x10\xd7\x01\x12\x14\n\x0fReadRecentViews\x10\xd8\x01\x12\x17\n\x12B\
+    atchViewOperation\x10\xd9\x01\x12\x0e\n\tReadTrash\x10\xac\x02\x12\x11\n\
+    \x0cPutbackTrash\x10\xad\x02\x12\x10\n\x0bDeleteTrash\x10\xae\x02\x12\
+    \x0f\n\nRestoreAll\x10\xaf\x02\x12\x0e\n\tDeleteAll\x10\xb0\x02\x12\x0f\
+    \n\nPurgeTrash\x10\xb1\x02\x12\x19\n\x14RunGarbageCollection\x10\xb2\x02\
+    \x12\x12\n\rApplyDocDelta\x10\x90\x03\x12\x13\n\x0eExportDocument\x10\
+    \xf4\x03\x12\x13\n\x0eImportDocument\x10\xf5\x03\x12\x14\n\x0fExportWork\
+    space\x10\xf6\x03\x12\x14\n\x0fImportWorkspace\x10\xf7\x03\x12\x11\n\x0c\
+    ImportNotion\x10\xf8\x03\x12\x0f\n\nImportEnex\x10\xf9\x03\x12\x18\n\x13\
+    IngestSharedContent\x10\xfa\x03\x12\x12\n\rReadTemplates\x10\xd8\x04\x12\
+    \x1b\n\x16CreateViewFromTemplate\x10\xd9\x04\x12\x11\n\x0cSaveTemplate\
+    \x10\xda\x04\x12\x1b\n\x16ReadWorkspaceTemplates\x10\xdb\x04\x12\x20\n\
+    \x1bCreateWorkspaceFromTemplate\x10\xdc\x04\x12\x1a\n\x15CheckStorageInt\
+    egrity\x10\xbc\x05\x12\x12\n\rSyncWorkspace\x10\xa0\x06\x12\x12\n\rReadS\
+    yncState\x10\xa1\x06\x12\x15\n\x10ReadSyncSettings\x10\xa2\x06\x12\x17\n\
+    \x12UpdateSyncSettings\x10\xa3\x06\x12\x16\n\x11ReadSyncConflicts\x10\
+    \xa4\x06\x12\x18\n\x13ResolveSyncConflict\x10\xa5\x06\x12\x12\n\rGetSync\
+    Status\x10\xa6\x06\x12\x15\n\x10SetSyncExclusion\x10\xa7\x06\x12\x17\n\
+    \x12ReadSyncExclusions\x10\xa8\x06\x12\x1d\n\x18SubmitConflictResolution\
+    \x10\xa9\x06\x12\x14\n\x0fReadSyncTraffic\x10\xaa\x06\x12\x11\n\x0cReadL\
+    anPeers\x10\xab\x06\x12\x0b\n\x06Search\x10\x84\x07\x1a\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -31,11 +31,23 @@ pub enum WorkspaceNotification {
     WorkspaceUpdated = 12,
     WorkspaceListUpdated = 13,
     WorkspaceAppsChanged = 14,
+    WorkspaceMembersChanged = 15,
+    WorkspaceImportProgress = 16,
+    WorkspaceSettingChanged = 17,
+    WorkspaceInvitationsChanged = 18,
+    SyncStateChanged = 19,
+    SyncConflictReported = 20,
     AppUpdated = 21,
+    ObjectSyncStatusChanged = 22,
+    SyncBackfillProgress = 23,
     AppViewsChanged = 24,
+    LanPeersChanged = 25,
     ViewUpdated = 31,
     ViewDeleted = 32,
     ViewRestored = 33,
+    FavoritesChanged = 34,
+    RecentViewsChanged = 35,
+    ViewsBatchChanged = 36,
     UserUnauthorized = 100,
     TrashUpdated = 1000,
 }
@@ -53,11 +65,23 @@ impl ::protobuf::ProtobufEnum for WorkspaceNotification {
             12 => ::std::option::Option::Some(WorkspaceNotification::WorkspaceUpdated),
             13 => ::std::option::Option::Some(WorkspaceNotification::WorkspaceListUpdated),
             14 => ::std::option::Option::Some(WorkspaceNotification::WorkspaceAppsChanged),
+            15 => ::std::option::Option::Some(WorkspaceNotification::WorkspaceMembersChanged),
+            16 => ::std::option::Option::Some(WorkspaceNotification::WorkspaceImportProgress),
+            17 => ::std::option::Option::Some(WorkspaceNotification::WorkspaceSettingChanged),
+            18 => ::std::option::Option::Some(WorkspaceNotification::WorkspaceInvitationsChanged),
+            19 => ::std::option::Option::Some(WorkspaceNotification::SyncStateChanged),
+            20 => ::std::option::Option::Some(WorkspaceNotification::SyncConflictReported),
             21 => ::std::option::Option::Some(WorkspaceNotification::AppUpdated),
+            22 => ::std::option::Option::Some(WorkspaceNotification::ObjectSyncStatusChanged),
+            23 => ::std::option::Option::Some(WorkspaceNotification::SyncBackfillProgress),
             24 => ::std::option::Option::Some(WorkspaceNotification::AppViewsChanged),
+            25 => ::std::option::Option::Some(WorkspaceNotification::LanPeersChanged),
             31 => ::std::option::Option::Some(WorkspaceNotification::ViewUpdated),
             32 => ::std::option::Option::Some(WorkspaceNotification::ViewDeleted),
             33 => ::std::option::Option::Some(WorkspaceNotification::ViewRestored),
+            34 => ::std::option::Option::Some(WorkspaceNotification::FavoritesChanged),
+            35 => ::std::option::Option::Some(WorkspaceNotification::RecentViewsChanged),
+            36 => ::std::option::Option::Some(WorkspaceNotification::ViewsBatchChanged),
             100 => ::std::option::Option::Some(WorkspaceNotification::UserUnauthorized),
             1000 => ::std::option::Option::Some(WorkspaceNotification::TrashUpdated),
             _ => ::std::option::Option::None
@@ -72,11 +96,23 @@ impl ::protobuf::ProtobufEnum for WorkspaceNotification {
             WorkspaceNotification::WorkspaceUpdated,
             WorkspaceNotification::WorkspaceListUpdated,
             WorkspaceNotification::WorkspaceAppsChanged,
+            WorkspaceNotification::WorkspaceMembersChanged,
+            WorkspaceNotification::WorkspaceImportProgress,
+            WorkspaceNotification::WorkspaceSettingChanged,
+            WorkspaceNotification::WorkspaceInvitationsChanged,
+            WorkspaceNotification::SyncStateChanged,
+            WorkspaceNotification::SyncConflictReported,
             WorkspaceNotification::AppUpdated,
+            WorkspaceNotification::ObjectSyncStatusChanged,
+            WorkspaceNotification::SyncBackfillProgress,
             WorkspaceNotification::AppViewsChanged,
+            WorkspaceNotification::LanPeersChanged,
             WorkspaceNotification::ViewUpdated,
             WorkspaceNotification::ViewDeleted,
             WorkspaceNotification::ViewRestored,
+            WorkspaceNotification::FavoritesChanged,
+            WorkspaceNotification::RecentViewsChanged,
+            WorkspaceNotification::ViewsBatchChanged,
             WorkspaceNotification::UserUnauthorized,
             WorkspaceNotification::TrashUpdated,
         ];
@@ -107,42 +143,21 @@ impl ::protobuf::reflect::ProtobufValue for WorkspaceNotification {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x10observable.proto*\xa2\x02\n\x15WorkspaceNotification\x12\x0b\n\x07\
+    \n\x10observable.proto*\xdd\x04\n\x15WorkspaceNotification\x12\x0b\n\x07\
     Unknown\x10\0\x12\x17\n\x13UserCreateWorkspace\x10\n\x12\x17\n\x13UserDe\
     leteWorkspace\x10\x0b\x12\x14\n\x10WorkspaceUpdated\x10\x0c\x12\x18\n\
     \x14WorkspaceListUpdated\x10\r\x12\x18\n\x14WorkspaceAppsChanged\x10\x0e\
-    \x12\x0e\n\nAppUpdated\x10\x15\x12\x13\n\x0fAppViewsChanged\x10\x18\x12\
-    \x0f\n\x0bViewUpdated\x10\x1f\x12\x0f\n\x0bViewDeleted\x10\x20\x12\x10\n\
-    \x0cViewRestored\x10!\x12\x14\n\x10UserUnauthorized\x10d\x12\x11\n\x0cTr\
-    ashUpdated\x10\xe8\x07J\xbf\x04\n\x06\x12\x04\0\0\x10\x01\n\x08\n\x01\
-    \x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0\x10\x01\n\n\n\x03\x05\
-    \0\x01\x12\x03\x02\x05\x1a\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\x10\n\
-    \x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x04\x0b\n\x0c\n\x05\x05\0\x02\0\
-    \x02\x12\x03\x03\x0e\x0f\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\x04\x1d\n\
-    \x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\x04\x17\n\x0c\n\x05\x05\0\x02\
-    \x01\x02\x12\x03\x04\x1a\x1c\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x05\x04\
-    \x1d\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x05\x04\x17\n\x0c\n\x05\x05\0\
-    \x02\x02\x02\x12\x03\x05\x1a\x1c\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x06\
-    \x04\x1a\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x06\x04\x14\n\x0c\n\x05\
-    \x05\0\x02\x03\x02\x12\x03\x06\x17\x19\n\x0b\n\x04\x05\0\x02\x04\x12\x03\
-    \x07\x04\x1e\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x07\x04\x18\n\x0c\n\
-    \x05\x05\0\x02\x04\x02\x12\x03\x07\x1b\x1d\n\x0b\n\x04\x05\0\x02\x05\x12\
-    \x03\x08\x04\x1e\n\x0c\n\x05\x05\0\x02\x05\x01\x12\x03\x08\x04\x18\n\x0c\
-    \n\x05\x05\0\x02\x05\x02\x12\x03\x08\x1b\x1d\n\x0b\n\x04\x05\0\x02\x06\
-    \x12\x03\t\x04\x14\n\x0c\n\x05\x05\0\x02\x06\x01\x12\x03\t\x04\x0e\n\x0c\
-    \n\x05\x05\0\x02\x06\x02\x12\x03\t\x11\x13\n\x0b\n\x04\x05\0\x02\x07\x12\
-    \x03\n\x04\x19\n\x0c\n\x05\x05\0\x02\x07\x01\x12\x03\n\x04\x13\n\x0c\n\
-    \x05\x05\0\x02\x07\x02\x12\x03\n\x16\x18\n\x0b\n\x04\x05\0\x02\x08\x12\
-    \x03\x0b\x04\x15\n\x0c\n\x05\x05\0\x02\x08\x01\x12\x03\x0b\x04\x0f\n\x0c\
-    \n\x05\x05\0\x02\x08\x02\x12\x03\x0b\x12\x14\n\x0b\n\x04\x05\0\x02\t\x12\
-    \x03\x0c\x04\x15\n\x0c\n\x05\x05\0\x02\t\x01\x12\x03\x0c\x04\x0f\n\x0c\n\
-    \x05\x05\0\x02\t\x02\x12\x03\x0c\x12\x14\n\x0b\n\x04\x05\0\x02\n\x12\x03\
-    \r\x04\x16\n\x0c\n\x05\x05\0\x02\n\x01\x12\x03\r\x04\x10\n\x0c\n\x05\x05\
-    \0\x02\n\x02\x12\x03\r\x13\x15\n\x0b\n\x04\x05\0\x02\x0b\x12\x03\x0e\x04\
-    \x1b\n\x0c\n\x05\x05\0\x02\x0b\x01\x12\x03\x0e\x04\x14\n\x0c\n\x05\x05\0\
-    \x02\x0b\x02\x12\x03\x0e\x17\x1a\n\x0b\n\x04\x05\0\x02\x0c\x12\x03\x0f\
-    \x04\x18\n\x0c\n\x05\x05\0\x02\x0c\x01\x12\x03\x0f\x04\x10\n\x0c\n\x05\
-    \x05\0\x02\x0c\x02\x12\x03\x0f\x13\x17b\x06proto3\
+    \x12\x1b\n\x17WorkspaceMembersChanged\x10\x0f\x12\x1b\n\x17WorkspaceImpo\
+    rtProgress\x10\x10\x12\x1b\n\x17WorkspaceSettingChanged\x10\x11\x12\x1f\
+    \n\x1bWorkspaceInvitationsChanged\x10\x12\x12\x14\n\x10SyncStateChanged\
+    \x10\x13\x12\x18\n\x14SyncConflictReported\x10\x14\x12\x0e\n\nAppUpdated\
+    \x10\x15\x12\x1b\n\x17ObjectSyncStatusChanged\x10\x16\x12\x18\n\x14SyncB\
+    ackfillProgress\x10\x17\x12\x13\n\x0fAppViewsChanged\x10\x18\x12\x13\n\
+    \x0fLanPeersChanged\x10\x19\x12\x0f\n\x0bViewUpdated\x10\x1f\x12\x0f\n\
+    \x0bViewDeleted\x10\x20\x12\x10\n\x0cViewRestored\x10!\x12\x14\n\x10Favo\
+    ritesChanged\x10\"\x12\x16\n\x12RecentViewsChanged\x10#\x12\x15\n\x11Vie\
+    wsBatchChanged\x10$\x12\x14\n\x10UserUnauthorized\x10d\x12\x11\n\x0cTras\
+    hUpdated\x10\xe8\x07\x1a\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -12,7 +12,7 @@ use crate::{
     errors::*,
     module::{WorkspaceDatabase, WorkspaceUser},
     notify::*,
-    services::{server::Server, TrashCan, TrashEvent},
+    services::{server::Server, Operation, OperationQueue, TrashCan, TrashEvent},
     sql_tables::app::{AppTable, AppTableChangeset, AppTableSql},
 };
 
@@ -20,6 +20,7 @@ pub(crate) struct AppController {
     user: Arc<dyn WorkspaceUser>,
     database: Arc<dyn WorkspaceDatabase>,
     trash_can: Arc<TrashCan>,
+    operations: OperationQueue,
     server: Server,
 }
 
@@ -28,12 +29,14 @@ impl AppController {
         user: Arc<dyn WorkspaceUser>,
         database: Arc<dyn WorkspaceDatabase>,
         trash_can: Arc<TrashCan>,
+        operations: OperationQueue,
         server: Server,
     ) -> Self {
         Self {
             user,
             database,
             trash_can,
+            operations,
             server,
         }
     }
@@ -50,7 +53,7 @@ impl AppController {
     }
 
     pub(crate) async fn create_app(&self, app: App) -> Result<App, WorkspaceError> {
-        let conn = &*self.database.db_connection()?;
+        let conn = &*self.database.db_write_connection()?;
         conn.immediate_transaction::<_, WorkspaceError, _>(|| {
             let _ = self.save_app(app.clone(), &*conn)?;
             let _ = notify_apps_changed(&app.workspace_id, self.trash_can.clone(), conn)?;
@@ -80,11 +83,20 @@ impl AppController {
     }
 
     pub(crate) async fn update_app(&self, params: UpdateAppParams) -> Result<(), WorkspaceError> {
-        let changeset = AppTableChangeset::new(params.clone());
-        let app_id = changeset.id.clone();
-        let conn = &*self.database.db_connection()?;
+        let app_id = params.app_id.clone();
+        let conn = &*self.database.db_write_connection()?;
         conn.immediate_transaction::<_, WorkspaceError, _>(|| {
-            let _ = AppTableSql::update_app(changeset, conn)?;
+            // Only the fields that change are written and sent to the server.
+            let current = AppTableSql::read_app(&app_id, conn)?.current_fields();
+            let params = params.without_unchanged(&current);
+            if params.is_empty() {
+                return Ok(());
+            }
+
+            let _ = AppTableSql::update_app(AppTableChangeset::new(params.clone()), conn)?;
+            let _ = self
+                .operations
+                .push_update(Operation::UpdateApp(params), Operation::UpdateApp(current), conn)?;
             let app: App = AppTableSql::read_app(&app_id, conn)?.into();
             send_dart_notification(&app_id, WorkspaceNotification::AppUpdated)
                 .payload(app)
@@ -92,10 +104,29 @@ impl AppController {
             Ok(())
         })?;
 
-        let _ = self.update_app_on_server(params)?;
+        self.operations.flush();
         Ok(())
     }
 
+    // The update another device made, the server already has it so it isn't
+    // pushed to the operations.
+    pub(crate) fn apply_remote_update(&self, params: UpdateAppParams, conn: &SqliteConnection) -> WorkspaceResult<()> {
+        let changeset = AppTableChangeset::new(params);
+        let app_id = changeset.id.clone();
+        let _ = AppTableSql::update_app(changeset, conn)?;
+        let app: App = AppTableSql::read_app(&app_id, conn)?.into();
+        send_dart_notification(&app_id, WorkspaceNotification::AppUpdated)
+            .payload(app.clone())
+            .send();
+        notify_apps_changed(&app.workspace_id, self.trash_can.clone(), conn)
+    }
+
+    pub(crate) fn apply_remote_create(&self, app: App, conn: &SqliteConnection) -> WorkspaceResult<()> {
+        let workspace_id = app.workspace_id.clone();
+        let _ = self.save_app(app, conn)?;
+        notify_apps_changed(&workspace_id, self.trash_can.clone(), conn)
+    }
+
     pub(crate) fn read_app_tables(&self, ids: Vec<String>) -> Result<Vec<AppTable>, WorkspaceError> {
         let conn = &*self.database.db_connection()?;
         let mut app_tables = vec![];
@@ -118,22 +149,6 @@ impl AppController {
         Ok(app)
     }
 
-    #[tracing::instrument(level = "debug", skip(self), err)]
-    fn update_app_on_server(&self, params: UpdateAppParams) -> Result<(), WorkspaceError> {
-        let token = self.user.token()?;
-        let server = self.server.clone();
-        tokio::spawn(async move {
-            match server.update_app(&token, params).await {
-                Ok(_) => {},
-                Err(e) => {
-                    // TODO: retry?
-                    log::error!("Update app failed: {:?}", e);
-                },
-            }
-        });
-        Ok(())
-    }
-
     #[tracing::instrument(level = "debug", skip(self), err)]
     fn read_app_on_server(&self, params: AppIdentifier) -> Result<(), WorkspaceError> {
         let token = self.user.token()?;
@@ -0,0 +1,99 @@
+use crate::{
+    entities::workspace::{AcceptWorkspaceInvitationParams, WorkspaceEncryption},
+    errors::*,
+    module::{WorkspaceDatabase, WorkspaceUser},
+    services::read_view_workspace_id,
+};
+use flowy_document::{
+    errors::DocError,
+    module::{SyncKey, SyncKeyProvider},
+};
+use flowy_infra::secret::{generate_secret, Cipher};
+use std::sync::Arc;
+
+// The workspace is encrypted once its owner creates its key. The documents of
+// the workspace are encrypted with it before they're synced and decrypted after
+// they're pulled. The key is given to the invited members wrapped with the
+// secret of their invitation, so the server only keeps the wrapped key and the
+// secret goes to the member along with the invitation.
+pub(crate) struct WorkspaceKeys {
+    user: Arc<dyn WorkspaceUser>,
+    database: Arc<dyn WorkspaceDatabase>,
+}
+
+impl WorkspaceKeys {
+    pub(crate) fn new(user: Arc<dyn WorkspaceUser>, database: Arc<dyn WorkspaceDatabase>) -> Self {
+        Self { user, database }
+    }
+
+    pub(crate) fn state(&self, workspace_id: &str) -> WorkspaceResult<WorkspaceEncryption> {
+        Ok(WorkspaceEncryption {
+            workspace_id: workspace_id.to_owned(),
+            enabled: self.sync_key(workspace_id)?.is_some(),
+        })
+    }
+
+    pub(crate) fn sync_key(&self, workspace_id: &str) -> WorkspaceResult<Option<SyncKey>> {
+        let key = self.user.workspace_key(workspace_id)?;
+        Ok(key.map(|key| SyncKey {
+            workspace_id: workspace_id.to_owned(),
+            key,
+        }))
+    }
+
+    // The workspace keeps its key once it's created, the payloads that were
+    // encrypted with it can't be read with another one.
+    pub(crate) fn enable(&self, workspace_id: &str) -> WorkspaceResult<WorkspaceEncryption> {
+        if self.sync_key(workspace_id)?.is_none() {
+            let _ = self.user.set_workspace_key(workspace_id, &generate_secret())?;
+        }
+        self.state(workspace_id)
+    }
+
+    // Returns the wrapped key and the secret it's wrapped with, None if the
+    // workspace isn't encrypted. Each invitation gets its own secret.
+    pub(crate) fn wrap_key(&self, workspace_id: &str) -> WorkspaceResult<Option<(String, String)>> {
+        let key = match self.user.workspace_key(workspace_id)? {
+            None => return Ok(None),
+            Some(key) => key,
+        };
+        let invite_secret = generate_secret();
+        let wrapped_key = invitation_cipher(workspace_id, &invite_secret).encrypt_text(&key);
+        Ok(Some((wrapped_key, invite_secret)))
+    }
+
+    pub(crate) fn accept(&self, params: AcceptWorkspaceInvitationParams) -> WorkspaceResult<WorkspaceEncryption> {
+        if let Some(wrapped_key) = &params.wrapped_key {
+            let key = invitation_cipher(&params.workspace_id, &params.invite_secret)
+                .decrypt_text(wrapped_key)
+                .map_err(|e| WorkspaceError::invitation_secret().context(e))?;
+            let _ = self.user.set_workspace_key(&params.workspace_id, &key)?;
+        }
+        self.state(&params.workspace_id)
+    }
+
+    // The text is kept as it is if the workspace isn't encrypted.
+    pub(crate) fn seal_text(&self, workspace_id: &str, text: String) -> WorkspaceResult<String> {
+        match self.sync_key(workspace_id)? {
+            None => Ok(text),
+            Some(key) => Ok(key.cipher().encrypt_text(&text)),
+        }
+    }
+}
+
+impl SyncKeyProvider for WorkspaceKeys {
+    // The document is the one of the view with the same id.
+    fn sync_key(&self, doc_id: &str) -> Result<Option<SyncKey>, DocError> {
+        let conn = &*self.database.db_connection().map_err(doc_error)?;
+        match read_view_workspace_id(doc_id, conn).map_err(doc_error)? {
+            None => Ok(None),
+            Some(workspace_id) => WorkspaceKeys::sync_key(self, &workspace_id).map_err(doc_error),
+        }
+    }
+}
+
+fn invitation_cipher(workspace_id: &str, invite_secret: &str) -> Cipher {
+    Cipher::new(invite_secret.as_bytes(), &format!("{}:invitation", workspace_id))
+}
+
+fn doc_error(error: WorkspaceError) -> DocError { DocError::internal().context(error) }
@@ -0,0 +1,162 @@
+use crate::{
+    errors::{WorkspaceError, WorkspaceResult},
+    services::evernote::xml::{decode_entities, parse_element, XmlElement},
+};
+use chrono::NaiveDateTime;
+use std::path::Path;
+
+const ENEX_DATE_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+pub(crate) struct EnexResource {
+    // The MD5 of the data, the en-media elements of the note refer to it.
+    pub(crate) hash: String,
+    pub(crate) name: String,
+    pub(crate) mime_type: String,
+    pub(crate) data: Vec<u8>,
+}
+
+pub(crate) struct EnexNote {
+    pub(crate) title: String,
+    // The ENML of the note, an en-note element.
+    pub(crate) content: String,
+    pub(crate) tags: Vec<String>,
+    pub(crate) created: Option<i64>,
+    pub(crate) updated: Option<i64>,
+    pub(crate) resources: Vec<EnexResource>,
+}
+
+// The note that couldn't be read, with the title if it has one.
+pub(crate) struct EnexError {
+    pub(crate) title: String,
+    pub(crate) msg: String,
+}
+
+// The notes of an export, each notebook is exported to its own file named
+// after it. The notes are read one by one, the malformed ones don't keep the
+// others from being imported.
+pub(crate) struct EnexNotebook {
+    pub(crate) name: String,
+    pub(crate) notes: Vec<Result<EnexNote, EnexError>>,
+}
+
+impl EnexNotebook {
+    pub(crate) fn open(path: &str) -> WorkspaceResult<Self> {
+        let bytes = std::fs::read(path).map_err(|e| WorkspaceError::enex().context(e))?;
+        let text = String::from_utf8_lossy(&bytes);
+        let text = text.trim_start_matches('\u{feff}');
+        if !text.contains("<en-export") {
+            return Err(WorkspaceError::enex().context(format!("{} isn't an Evernote export", path)));
+        }
+
+        let name = Path::new(path)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().trim().to_owned())
+            .unwrap_or_default();
+        let notes = note_chunks(text)
+            .into_iter()
+            .map(|chunk| {
+                parse_element(chunk).and_then(read_note).map_err(|msg| EnexError {
+                    title: chunk_title(chunk),
+                    msg,
+                })
+            })
+            .collect();
+        Ok(EnexNotebook { name, notes })
+    }
+}
+
+fn read_note(element: XmlElement) -> Result<EnexNote, String> {
+    let content = element.child_text("content").ok_or("The note has no content")?;
+    if !content.contains("<en-note") {
+        return Err("The content of the note isn't ENML".to_owned());
+    }
+
+    let resources = element
+        .elements("resource")
+        .map(read_resource)
+        .collect::<Result<Vec<_>, String>>()?;
+    Ok(EnexNote {
+        title: element.child_text("title").unwrap_or_default().trim().to_owned(),
+        content,
+        tags: element
+            .elements("tag")
+            .map(|tag| tag.text().trim().to_owned())
+            .filter(|tag| !tag.is_empty())
+            .collect(),
+        created: element.child_text("created").and_then(|date| enex_timestamp(&date)),
+        updated: element.child_text("updated").and_then(|date| enex_timestamp(&date)),
+        resources,
+    })
+}
+
+fn read_resource(element: &XmlElement) -> Result<EnexResource, String> {
+    let data = element.child("data").ok_or("The attachment has no data")?;
+    if data.attribute("encoding").unwrap_or("base64") != "base64" {
+        return Err("The data of the attachment isn't base64".to_owned());
+    }
+    let encoded = data.text().split_whitespace().collect::<String>();
+    let data = base64::decode(&encoded).map_err(|e| format!("The data of the attachment is invalid: {}", e))?;
+    let mime_type = element
+        .child_text("mime")
+        .map(|mime| mime.trim().to_owned())
+        .filter(|mime| !mime.is_empty())
+        .unwrap_or_else(|| "application/octet-stream".to_owned());
+    let name = element
+        .child("resource-attributes")
+        .and_then(|attributes| attributes.child_text("file-name"))
+        .map(|name| name.trim().to_owned())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| mime_type.replace('/', "."));
+    Ok(EnexResource {
+        hash: format!("{:x}", md5::compute(&data)),
+        name,
+        mime_type,
+        data,
+    })
+}
+
+fn enex_timestamp(date: &str) -> Option<i64> {
+    NaiveDateTime::parse_from_str(date.trim(), ENEX_DATE_FORMAT)
+        .ok()
+        .map(|date_time| date_time.timestamp())
+}
+
+// The note elements of the export. The CDATA sections are skipped, the ENML
+// and the recognition data of the notes are in them.
+fn note_chunks(text: &str) -> Vec<&str> {
+    let mut chunks = vec![];
+    let mut start = None;
+    let mut index = 0;
+    while let Some(offset) = text[index..].find('<') {
+        index += offset;
+        let rest = &text[index..];
+        if rest.starts_with("<![CDATA[") {
+            index = rest.find("]]>").map_or(text.len(), |end| index + end + 3);
+        } else if rest.starts_with("<note>") || rest.starts_with("<note ") {
+            if let Some(start) = start.replace(index) {
+                chunks.push(&text[start..index]);
+            }
+            index += 1;
+        } else if rest.starts_with("</note>") {
+            if let Some(start) = start.take() {
+                chunks.push(&text[start..index + "</note>".len()]);
+            }
+            index += 1;
+        } else {
+            index += 1;
+        }
+    }
+    // The notes that aren't closed are reported like the other malformed ones.
+    if let Some(start) = start {
+        chunks.push(&text[start..]);
+    }
+    chunks
+}
+
+fn chunk_title(chunk: &str) -> String {
+    let title = chunk
+        .find("<title>")
+        .map(|start| &chunk[start + "<title>".len()..])
+        .and_then(|title| title.find("</title>").map(|end| &title[..end]));
+    decode_entities(title.unwrap_or_default().trim())
+}
@@ -0,0 +1,78 @@
+use crate::services::evernote::{
+    enex::EnexResource,
+    xml::{escape, parse_element},
+};
+use flowy_document::services::{asset::asset_url, import::html_to_delta};
+use flowy_ot::core::Delta;
+
+const ENCRYPTED_TEXT: &str = "[Encrypted content]";
+
+// Converts the ENML of the note to a document. ENML is XHTML with a few
+// elements of its own: the attachments are linked to the assets named after
+// their hash, the checkboxes are kept as text and the encrypted text, which
+// can't be read without the passphrase, is replaced.
+pub(crate) fn enml_to_delta(enml: &str, resources: &[EnexResource]) -> Delta {
+    let mut html = String::with_capacity(enml.len());
+    let mut rest = enml;
+    while let Some(start) = rest.find("<en-") {
+        html.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = match rest.find('>') {
+            None => break,
+            Some(end) => end,
+        };
+        let tag = &rest[..end + 1];
+        rest = &rest[end + 1..];
+        let name = tag[1..]
+            .split(|c: char| c.is_whitespace() || c == '/' || c == '>')
+            .next()
+            .unwrap_or("");
+        match name {
+            "en-media" => html.push_str(&media_html(tag, resources)),
+            "en-todo" => {
+                let checked = parse_element(&self_closed(tag))
+                    .ok()
+                    .and_then(|element| element.attribute("checked").map(|checked| checked == "true"))
+                    .unwrap_or(false);
+                html.push_str(if checked { "☑ " } else { "☐ " });
+            },
+            "en-crypt" => {
+                if !tag.ends_with("/>") {
+                    rest = rest
+                        .find("</en-crypt>")
+                        .map_or("", |end| &rest[end + "</en-crypt>".len()..]);
+                }
+                html.push_str(ENCRYPTED_TEXT);
+            },
+            // The en-note root is dropped, its content is kept.
+            _ => {},
+        }
+    }
+    html.push_str(rest);
+    html_to_delta(&html)
+}
+
+fn media_html(tag: &str, resources: &[EnexResource]) -> String {
+    let element = match parse_element(&self_closed(tag)) {
+        Ok(element) => element,
+        Err(_) => return "".to_owned(),
+    };
+    let hash = element.attribute("hash").unwrap_or("");
+    let resource = match resources.iter().find(|resource| resource.hash == hash) {
+        None => return "".to_owned(),
+        Some(resource) => resource,
+    };
+    let url = escape(&asset_url(hash));
+    let name = escape(&resource.name);
+    match resource.mime_type.starts_with("image/") {
+        true => format!("<img src=\"{}\" alt=\"{}\">", url, name),
+        false => format!("<a href=\"{}\">{}</a>", url, name),
+    }
+}
+
+fn self_closed(tag: &str) -> String {
+    match tag.ends_with("/>") {
+        true => tag.to_owned(),
+        false => format!("{}/>", tag.trim_end_matches('>')),
+    }
+}
@@ -0,0 +1,209 @@
+mod enex;
+mod enml;
+mod xml;
+
+pub(crate) use enex::EnexNotebook;
+
+use crate::{
+    entities::view::{CreateViewParams, ViewType},
+    module::WorkspaceGrid,
+    services::{notify_import_progress, AppController, ViewController},
+};
+use chrono::NaiveDateTime;
+use enex::EnexNote;
+use enml::enml_to_delta;
+use flowy_workspace_infra::entities::{
+    app::{ColorStyle, CreateAppParams},
+    share::{ArchivedAsset, ArchivedView, EnexTagMapping, ImportFailure},
+};
+use std::sync::Arc;
+
+const UNTITLED_NOTE_NAME: &str = "Untitled";
+const TAGS_GRID_NAME: &str = "Tags";
+const TAGS_GRID_COLUMNS: [&str; 4] = ["Note", "Tags", "Created", "Updated"];
+// One of the formats that the grid reads the dates of the imported tables in.
+const TAGS_GRID_DATE_FORMAT: &str = "%Y/%m/%d %H:%M";
+
+// Imports the notebooks of an Evernote export. Each notebook becomes an app
+// with a doc view per note, the attachments of the notes are the assets of
+// their documents. The notes that fail are reported, the others are imported.
+pub(crate) struct EvernoteImporter {
+    app_controller: Arc<AppController>,
+    view_controller: Arc<ViewController>,
+    grid: Arc<dyn WorkspaceGrid>,
+}
+
+impl EvernoteImporter {
+    pub(crate) fn new(
+        app_controller: Arc<AppController>,
+        view_controller: Arc<ViewController>,
+        grid: Arc<dyn WorkspaceGrid>,
+    ) -> Self {
+        Self {
+            app_controller,
+            view_controller,
+            grid,
+        }
+    }
+
+    pub(crate) async fn import(
+        &self,
+        token: &str,
+        workspace_id: &str,
+        notebooks: Vec<EnexNotebook>,
+        tag_mapping: EnexTagMapping,
+    ) -> Vec<ImportFailure> {
+        let mut failures = vec![];
+        let total = notebooks.iter().map(|notebook| notebook.notes.len()).sum::<usize>() as i32;
+        let mut done = 0;
+        for notebook in notebooks {
+            let create_params = CreateAppParams {
+                workspace_id: workspace_id.to_owned(),
+                name: non_empty_name(&notebook.name),
+                desc: "".to_owned(),
+                color_style: ColorStyle::default(),
+            };
+            let app = match self.app_controller.create_app_from_params(create_params).await {
+                Ok(app) => Some(app),
+                Err(e) => {
+                    failures.push(ImportFailure {
+                        id: notebook.name.clone(),
+                        name: notebook.name.clone(),
+                        msg: e.msg,
+                    });
+                    None
+                },
+            };
+
+            let mut imported_notes = vec![];
+            for (index, note) in notebook.notes.into_iter().enumerate() {
+                let failure = match (note, &app) {
+                    (Err(e), _) => Some((e.title, e.msg)),
+                    (Ok(note), None) => Some((note.title, "The notebook wasn't imported".to_owned())),
+                    (Ok(note), Some(app)) => match self.import_note(&app.id, &note, &tag_mapping).await {
+                        Ok(asset_failures) => {
+                            failures.extend(asset_failures);
+                            imported_notes.push(note);
+                            None
+                        },
+                        Err(msg) => Some((note.title, msg)),
+                    },
+                };
+                if let Some((title, msg)) = failure {
+                    failures.push(ImportFailure {
+                        id: format!("{}#{}", notebook.name, index + 1),
+                        name: title,
+                        msg,
+                    });
+                }
+                done += 1;
+                notify_import_progress(token, workspace_id, done, total);
+            }
+
+            let has_tags = imported_notes.iter().any(|note| !note.tags.is_empty());
+            let tags_app = app.as_ref().filter(|_| tag_mapping == EnexTagMapping::Grid && has_tags);
+            if let Some(app) = tags_app {
+                if let Err(msg) = self.import_tags_grid(&app.id, &imported_notes).await {
+                    failures.push(ImportFailure {
+                        id: format!("{}#{}", notebook.name, TAGS_GRID_NAME),
+                        name: TAGS_GRID_NAME.to_owned(),
+                        msg,
+                    });
+                }
+            }
+        }
+        failures
+    }
+
+    // The attachments that fail are returned, the note is kept without them.
+    async fn import_note(
+        &self,
+        app_id: &str,
+        note: &EnexNote,
+        tag_mapping: &EnexTagMapping,
+    ) -> Result<Vec<ImportFailure>, String> {
+        let delta = enml_to_delta(&note.content, &note.resources);
+        let assets = note
+            .resources
+            .iter()
+            .map(|resource| ArchivedAsset {
+                id: resource.hash.clone(),
+                doc_id: "".to_owned(),
+                name: resource.name.clone(),
+                mime_type: resource.mime_type.clone(),
+                data: resource.data.clone(),
+            })
+            .collect::<Vec<_>>();
+        let desc = match tag_mapping {
+            EnexTagMapping::Metadata => note.tags.join(", "),
+            EnexTagMapping::Grid => "".to_owned(),
+        };
+        let archived_view = ArchivedView {
+            belong_to_id: app_id.to_owned(),
+            name: non_empty_name(&note.title),
+            desc,
+            view_type: ViewType::Doc,
+            data: delta.to_json(),
+            ..ArchivedView::default()
+        };
+        let (_, failures) = self
+            .view_controller
+            .restore_view(app_id.to_owned(), archived_view, assets)
+            .await
+            .map_err(|e| e.msg)?;
+        Ok(failures)
+    }
+
+    // A grid view with a row per note, the tags of the notes are the options of
+    // a select field.
+    async fn import_tags_grid(&self, app_id: &str, notes: &[EnexNote]) -> Result<(), String> {
+        let create_params = CreateViewParams {
+            belong_to_id: app_id.to_owned(),
+            name: TAGS_GRID_NAME.to_owned(),
+            desc: "".to_owned(),
+            thumbnail: "".to_owned(),
+            view_type: ViewType::Grid,
+            data: "".to_owned(),
+            auto_rename: true,
+        };
+        let view = self
+            .view_controller
+            .create_view_from_params(create_params)
+            .await
+            .map_err(|e| e.msg)?;
+        let columns = TAGS_GRID_COLUMNS.iter().map(|column| column.to_string()).collect();
+        let rows = notes
+            .iter()
+            .map(|note| {
+                vec![
+                    non_empty_name(&note.title),
+                    note.tags.join(", "),
+                    format_timestamp(note.created),
+                    format_timestamp(note.updated),
+                ]
+            })
+            .collect();
+        let _ = self
+            .grid
+            .import_grid(&view.id, &view.name, columns, rows)
+            .map_err(|e| e.msg)?;
+        Ok(())
+    }
+}
+
+fn non_empty_name(name: &str) -> String {
+    match name.trim().is_empty() {
+        true => UNTITLED_NOTE_NAME.to_owned(),
+        false => name.trim().to_owned(),
+    }
+}
+
+fn format_timestamp(timestamp: Option<i64>) -> String {
+    timestamp
+        .map(|timestamp| {
+            NaiveDateTime::from_timestamp(timestamp, 0)
+                .format(TAGS_GRID_DATE_FORMAT)
+                .to_string()
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,210 @@
+// A small reader for the XML of the exports, the declarations, the doctypes
+// and the comments are skipped. The text of the CDATA sections is kept as is.
+pub(crate) struct XmlElement {
+    pub(crate) name: String,
+    pub(crate) attributes: Vec<(String, String)>,
+    pub(crate) children: Vec<XmlNode>,
+}
+
+pub(crate) enum XmlNode {
+    Element(XmlElement),
+    Text(String),
+}
+
+impl XmlElement {
+    fn new(name: &str, attributes: Vec<(String, String)>) -> Self {
+        Self {
+            name: name.to_owned(),
+            attributes,
+            children: vec![],
+        }
+    }
+
+    pub(crate) fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub(crate) fn child(&self, name: &str) -> Option<&XmlElement> { self.elements(name).next() }
+
+    pub(crate) fn elements<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a XmlElement> + 'a {
+        self.children.iter().filter_map(move |child| match child {
+            XmlNode::Element(element) if element.name == name => Some(element),
+            _ => None,
+        })
+    }
+
+    // The text of the element without the text of its child elements.
+    pub(crate) fn text(&self) -> String {
+        self.children
+            .iter()
+            .filter_map(|child| match child {
+                XmlNode::Text(text) => Some(text.as_str()),
+                XmlNode::Element(_) => None,
+            })
+            .collect()
+    }
+
+    pub(crate) fn child_text(&self, name: &str) -> Option<String> { self.child(name).map(|child| child.text()) }
+}
+
+// Reads the element that the xml is made of.
+pub(crate) fn parse_element(xml: &str) -> Result<XmlElement, String> {
+    let mut stack: Vec<XmlElement> = vec![];
+    let mut root = None;
+    let mut rest = xml;
+    while !rest.is_empty() {
+        let start = rest.find('<').unwrap_or(rest.len());
+        if start > 0 {
+            let text = &rest[..start];
+            match stack.last_mut() {
+                Some(element) => element.children.push(XmlNode::Text(decode_entities(text))),
+                None if text.trim().is_empty() => {},
+                None => return Err("There's text outside of the root element".to_owned()),
+            }
+            rest = &rest[start..];
+            continue;
+        }
+
+        if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+            let end = cdata.find("]]>").ok_or("The CDATA section isn't closed")?;
+            if let Some(element) = stack.last_mut() {
+                element.children.push(XmlNode::Text(cdata[..end].to_owned()));
+            }
+            rest = &cdata[end + 3..];
+            continue;
+        }
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            let end = comment.find("-->").ok_or("The comment isn't closed")?;
+            rest = &comment[end + 3..];
+            continue;
+        }
+        if rest.starts_with("<?") || rest.starts_with("<!") {
+            let end = rest.find('>').ok_or("The declaration isn't closed")?;
+            rest = &rest[end + 1..];
+            continue;
+        }
+
+        let end = tag_end(rest).ok_or("The tag isn't closed")?;
+        let content = &rest[1..end];
+        rest = &rest[end + 1..];
+        if let Some(name) = content.strip_prefix('/') {
+            let element = stack
+                .pop()
+                .ok_or_else(|| format!("</{}> closes nothing", name.trim()))?;
+            if element.name != name.trim() {
+                return Err(format!("<{}> is closed by </{}>", element.name, name.trim()));
+            }
+            match stack.last_mut() {
+                Some(parent) => parent.children.push(XmlNode::Element(element)),
+                None if root.is_none() => root = Some(element),
+                None => return Err("There's more than one root element".to_owned()),
+            }
+            continue;
+        }
+
+        let self_closing = content.ends_with('/');
+        let (name, attributes) = parse_tag(content.trim_end_matches('/'))?;
+        let element = XmlElement::new(name, attributes);
+        match (self_closing, stack.last_mut()) {
+            (false, _) => stack.push(element),
+            (true, Some(parent)) => parent.children.push(XmlNode::Element(element)),
+            (true, None) if root.is_none() => root = Some(element),
+            (true, None) => return Err("There's more than one root element".to_owned()),
+        }
+    }
+
+    if let Some(element) = stack.last() {
+        return Err(format!("<{}> isn't closed", element.name));
+    }
+    root.ok_or_else(|| "There's no element".to_owned())
+}
+
+// The > that ends the tag, the ones in the values of the attributes don't.
+fn tag_end(s: &str) -> Option<usize> {
+    let mut quote = None;
+    for (index, c) in s.char_indices() {
+        match (c, quote) {
+            ('"', None) | ('\'', None) => quote = Some(c),
+            (c, Some(q)) if c == q => quote = None,
+            ('>', None) => return Some(index),
+            _ => {},
+        }
+    }
+    None
+}
+
+fn parse_tag(content: &str) -> Result<(&str, Vec<(String, String)>), String> {
+    let content = content.trim();
+    let name_len = content.find(char::is_whitespace).unwrap_or(content.len());
+    let name = &content[..name_len];
+    if name.is_empty() {
+        return Err("The tag has no name".to_owned());
+    }
+
+    let mut attributes = vec![];
+    let mut rest = content[name_len..].trim_start();
+    while !rest.is_empty() {
+        let key_end = rest
+            .find('=')
+            .ok_or_else(|| format!("The attribute of <{}> has no value", name))?;
+        let key = rest[..key_end].trim();
+        let value = rest[key_end + 1..].trim_start();
+        let quote = value
+            .chars()
+            .next()
+            .filter(|c| *c == '"' || *c == '\'')
+            .ok_or_else(|| format!("The value of {} isn't quoted", key))?;
+        let end = value[1..]
+            .find(quote)
+            .ok_or_else(|| format!("The value of {} isn't closed", key))?;
+        attributes.push((key.to_owned(), decode_entities(&value[1..end + 1])));
+        rest = value[end + 2..].trim_start();
+    }
+    Ok((name, attributes))
+}
+
+pub(crate) fn decode_entities(s: &str) -> String {
+    let mut decoded = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest.find(';').filter(|end| *end <= 10).map(|end| &rest[1..end]);
+        let c = entity.and_then(|entity| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => {
+                let code = match entity.strip_prefix("#x") {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => entity.strip_prefix('#').and_then(|dec| dec.parse::<u32>().ok()),
+                };
+                code.and_then(std::char::from_u32)
+            },
+        });
+        match (c, entity) {
+            (Some(c), Some(entity)) => {
+                decoded.push(c);
+                rest = &rest[entity.len() + 2..];
+            },
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            },
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+pub(crate) fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,164 @@
+use crate::{
+    errors::WorkspaceError,
+    sql_tables::{
+        app::AppTableSql,
+        trash::TrashTableSql,
+        view::{order_key_between, ViewTableSql},
+        workspace::WorkspaceTableSql,
+    },
+};
+use flowy_database::SqliteConnection;
+use flowy_workspace_infra::entities::workspace::{IntegrityIssue, IntegrityIssueType, StorageIntegrityReport};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+// Checks the file with SQLite's integrity check, then the links between the
+// workspaces, the apps, the views and the trash. The issues are repaired if
+// repair is true, the detached apps are moved to the current workspace and the
+// detached views to its first app, so they're left unrepaired if there's none.
+// Run it in a transaction.
+pub(crate) fn check_integrity(
+    current_workspace_id: Option<&str>,
+    repair: bool,
+    conn: &SqliteConnection,
+) -> Result<StorageIntegrityReport, WorkspaceError> {
+    let database_errors = flowy_database::integrity_errors(conn).map_err(|e| WorkspaceError::internal().context(e))?;
+    let mut issues = vec![];
+
+    let workspace_ids: HashSet<String> = WorkspaceTableSql {}.read_workspace_ids(conn)?.into_iter().collect();
+    let current_workspace_id = current_workspace_id.filter(|id| workspace_ids.contains(*id));
+    let apps = AppTableSql::read_app_links(conn)?;
+    for (app_id, workspace_id, _) in &apps {
+        if workspace_ids.contains(workspace_id) {
+            continue;
+        }
+        let repaired = match current_workspace_id {
+            Some(current_workspace_id) if repair => {
+                let _ = AppTableSql::move_app(app_id, current_workspace_id, conn)?;
+                true
+            },
+            _ => false,
+        };
+        issues.push(issue(IntegrityIssueType::AppWithoutWorkspace, app_id, repaired));
+    }
+
+    let app_ids: HashSet<&String> = apps.iter().map(|(app_id, _, _)| app_id).collect();
+    let belongings = ViewTableSql::read_belongings(conn)?;
+    let parents: HashMap<&String, &String> = belongings
+        .iter()
+        .map(|(view_id, parent_id)| (view_id, parent_id))
+        .collect();
+    let mut detached_views = vec![];
+    for (view_id, parent_id) in &belongings {
+        if !app_ids.contains(parent_id) && !parents.contains_key(parent_id) {
+            detached_views.push((view_id, IntegrityIssueType::ViewWithoutParent));
+        }
+    }
+
+    // The views that can't be reached from the apps or from the views without
+    // parent belong to a cycle, or to a descendant of a view of a cycle.
+    let mut children: HashMap<&String, Vec<&String>> = HashMap::new();
+    for (view_id, parent_id) in &belongings {
+        children.entry(parent_id).or_default().push(view_id);
+    }
+    let mut reachable = HashSet::new();
+    let roots: Vec<&String> = app_ids
+        .iter()
+        .copied()
+        .chain(detached_views.iter().map(|(view_id, _)| *view_id))
+        .collect();
+    mark_reachable(roots, &children, &mut reachable);
+    let mut view_ids: Vec<&String> = parents.keys().copied().collect();
+    view_ids.sort();
+    for view_id in view_ids {
+        if reachable.contains(view_id) {
+            continue;
+        }
+        // Going up from the view ends in the cycle, the first view that's seen
+        // twice is detached from it.
+        let mut seen = HashSet::new();
+        let mut cycle_view_id = view_id;
+        while seen.insert(cycle_view_id) {
+            cycle_view_id = parents[cycle_view_id];
+        }
+        detached_views.push((cycle_view_id, IntegrityIssueType::ViewCycle));
+        mark_reachable(vec![cycle_view_id], &children, &mut reachable);
+    }
+
+    let target_app_id = match current_workspace_id {
+        Some(current_workspace_id) if repair => AppTableSql::read_workspace_apps(current_workspace_id, false, conn)?
+            .into_iter()
+            .next()
+            .map(|app| app.id),
+        _ => None,
+    };
+    let mut last_key = match &target_app_id {
+        None => String::new(),
+        Some(app_id) => ViewTableSql::read_views(app_id, conn)?
+            .last()
+            .map_or(String::new(), |view| view.order_key.clone()),
+    };
+    for (view_id, ty) in detached_views {
+        let repaired = match &target_app_id {
+            Some(app_id) => {
+                let order_key = order_key_between(&last_key, None);
+                let _ = ViewTableSql::move_view(view_id, app_id, &order_key, conn)?;
+                last_key = order_key;
+                true
+            },
+            None => false,
+        };
+        issues.push(issue(ty, view_id, repaired));
+    }
+
+    for trash in TrashTableSql::read_all(conn)?.items {
+        if app_ids.contains(&trash.id) || parents.contains_key(&trash.id) {
+            continue;
+        }
+        if repair {
+            let _ = TrashTableSql::delete_trash(&trash.id, conn)?;
+        }
+        issues.push(issue(IntegrityIssueType::TrashWithoutItem, &trash.id, repair));
+    }
+
+    // The column defaults to an empty id.
+    for (app_id, _, last_view_id) in &apps {
+        match last_view_id {
+            Some(last_view_id) if !last_view_id.is_empty() && !parents.contains_key(last_view_id) => {
+                if repair {
+                    let _ = AppTableSql::clear_last_view(app_id, conn)?;
+                }
+                issues.push(issue(IntegrityIssueType::LastViewMissing, app_id, repair));
+            },
+            _ => {},
+        }
+    }
+
+    Ok(StorageIntegrityReport {
+        database_errors,
+        issues,
+    })
+}
+
+fn mark_reachable<'a>(
+    roots: Vec<&'a String>,
+    children: &HashMap<&'a String, Vec<&'a String>>,
+    reachable: &mut HashSet<&'a String>,
+) {
+    let mut queue: VecDeque<&String> = roots.into_iter().collect();
+    while let Some(id) = queue.pop_front() {
+        if !reachable.insert(id) {
+            continue;
+        }
+        if let Some(children) = children.get(id) {
+            queue.extend(children.iter().copied());
+        }
+    }
+}
+
+fn issue(ty: IntegrityIssueType, id: &str, repaired: bool) -> IntegrityIssue {
+    IntegrityIssue {
+        ty,
+        id: id.to_owned(),
+        repaired,
+    }
+}
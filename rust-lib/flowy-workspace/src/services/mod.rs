@@ -1,11 +1,29 @@
 pub(crate) use app_controller::*;
+pub(crate) use encryption::*;
+pub(crate) use evernote::*;
+pub(crate) use integrity::*;
+pub(crate) use notion::*;
+pub(crate) use operation_queue::*;
+pub use permission::*;
+pub(crate) use search::*;
+pub(crate) use sync::*;
+pub(crate) use template::*;
 pub(crate) use trash_can::*;
 pub(crate) use view_controller::*;
 pub use workspace_controller::*;
 
 mod app_controller;
 mod database;
+mod encryption;
+mod evernote;
+mod integrity;
+mod notion;
+mod operation_queue;
+mod permission;
+mod search;
 pub(crate) mod server;
+mod sync;
+mod template;
 mod trash_can;
 mod view_controller;
 mod workspace_controller;
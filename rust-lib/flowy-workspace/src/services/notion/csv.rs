@@ -0,0 +1,32 @@
+// Reads the rows of the CSV, the quoted values can have commas, newlines and
+// doubled quotes. The rows whose values are all empty are dropped.
+pub(crate) fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let mut rows = vec![];
+    let mut row = vec![];
+    let mut value = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                value.push('"');
+                chars.next();
+            },
+            ('"', true) => quoted = false,
+            ('"', false) if value.is_empty() => quoted = true,
+            (',', false) => row.push(std::mem::take(&mut value)),
+            ('\r', false) => {},
+            ('\n', false) => {
+                row.push(std::mem::take(&mut value));
+                rows.push(std::mem::take(&mut row));
+            },
+            (c, _) => value.push(c),
+        }
+    }
+    if !value.is_empty() || !row.is_empty() {
+        row.push(value);
+        rows.push(row);
+    }
+    rows.retain(|row| row.iter().any(|value| !value.trim().is_empty()));
+    rows
+}
@@ -0,0 +1,236 @@
+use crate::errors::{WorkspaceError, WorkspaceResult};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::File,
+    io::{Cursor, Read, Seek},
+};
+use zip::ZipArchive;
+
+// The databases are exported twice by the recent versions, the _all one has
+// the rows that the view of the database hides.
+const ALL_ROWS_SUFFIX: &str = "_all";
+
+// The length of the ids that end the names of the exported pages.
+const PAGE_ID_LENGTH: usize = 32;
+
+pub(crate) enum PageContent {
+    Markdown(String),
+    Csv(String),
+}
+
+pub(crate) struct NotionPage {
+    // The path of the page in the export, the links of the pages are relative
+    // to its directory.
+    pub(crate) path: String,
+    pub(crate) name: String,
+    pub(crate) content: PageContent,
+    pub(crate) parent: Option<usize>,
+    pub(crate) children: Vec<usize>,
+}
+
+// The pages of the export and the other files, keyed by their path. The
+// child pages of a page are in the directory named after the page, e.g.
+// Page 1a2b.md and Page 1a2b/Child 3c4d.md.
+pub(crate) struct NotionExport {
+    pub(crate) pages: Vec<NotionPage>,
+    pub(crate) files: BTreeMap<String, Vec<u8>>,
+}
+
+impl NotionExport {
+    // The large exports are split in zips that are zipped together, they're
+    // read as one.
+    pub(crate) fn open(path: &str) -> WorkspaceResult<Self> {
+        let file = File::open(path).map_err(|e| WorkspaceError::notion_export().context(e))?;
+        let mut entries = BTreeMap::new();
+        let _ = read_zip(file, true, &mut entries)?;
+
+        let mut csv_paths = HashMap::new();
+        let mut pages = vec![];
+        let mut files = BTreeMap::new();
+        for (path, data) in entries {
+            let content = match extension(&path) {
+                Some("md") => PageContent::Markdown(text(&path, data)?),
+                Some("csv") => PageContent::Csv(text(&path, data)?),
+                _ => {
+                    files.insert(path, data);
+                    continue;
+                },
+            };
+            let key = page_key(&path).to_owned();
+            if matches!(content, PageContent::Csv(_)) {
+                // Keeps the _all one of the two exports of the database.
+                match csv_paths.get(&key) {
+                    Some(index) if path.len() < pages[*index].path.len() => continue,
+                    Some(index) => {
+                        pages[*index] = NotionPage::new(path, content);
+                        continue;
+                    },
+                    None => {
+                        csv_paths.insert(key, pages.len());
+                    },
+                }
+            }
+            pages.push(NotionPage::new(path, content));
+        }
+        if pages.is_empty() {
+            return Err(WorkspaceError::notion_export().context("The export has no pages"));
+        }
+
+        let indexes = pages
+            .iter()
+            .enumerate()
+            .map(|(index, page)| (page_key(&page.path).to_owned(), index))
+            .collect::<HashMap<_, _>>();
+        let parents = pages
+            .iter()
+            .map(|page| parent_dir(&page.path).and_then(|dir| indexes.get(dir).copied()))
+            .collect::<Vec<_>>();
+        for (index, parent) in parents.into_iter().enumerate() {
+            if let Some(parent) = parent {
+                pages[index].parent = Some(parent);
+                pages[parent].children.push(index);
+            }
+        }
+        Ok(NotionExport { pages, files })
+    }
+
+    pub(crate) fn roots(&self) -> Vec<usize> {
+        (0..self.pages.len())
+            .filter(|index| self.pages[*index].parent.is_none())
+            .collect()
+    }
+
+    // The page of the path, the links to a database can be to either of its
+    // exports.
+    pub(crate) fn page_index(&self, path: &str) -> Option<usize> {
+        match extension(path) {
+            Some("md") | Some("csv") => {
+                let key = page_key(path);
+                self.pages.iter().position(|page| page_key(&page.path) == key)
+            },
+            _ => None,
+        }
+    }
+}
+
+impl NotionPage {
+    fn new(path: String, content: PageContent) -> Self {
+        let name = page_name(&path);
+        Self {
+            path,
+            name,
+            content,
+            parent: None,
+            children: vec![],
+        }
+    }
+
+    pub(crate) fn is_database(&self) -> bool { matches!(self.content, PageContent::Csv(_)) }
+}
+
+fn read_zip<R: Read + Seek>(reader: R, nested: bool, entries: &mut BTreeMap<String, Vec<u8>>) -> WorkspaceResult<()> {
+    let mut archive = ZipArchive::new(reader).map_err(|e| WorkspaceError::notion_export().context(e))?;
+    for index in 0..archive.len() {
+        let mut file = archive
+            .by_index(index)
+            .map_err(|e| WorkspaceError::notion_export().context(e))?;
+        let path = file.name().replace('\\', "/");
+        if file.is_dir() || path.starts_with("__MACOSX/") || path.ends_with(".DS_Store") {
+            continue;
+        }
+
+        let mut data = Vec::with_capacity(file.size() as usize);
+        let _ = file
+            .read_to_end(&mut data)
+            .map_err(|e| WorkspaceError::notion_export().context(e))?;
+        match nested && extension(&path) == Some("zip") {
+            true => {
+                let _ = read_zip(Cursor::new(data), false, entries)?;
+            },
+            false => {
+                entries.insert(path.trim_start_matches("./").to_owned(), data);
+            },
+        }
+    }
+    Ok(())
+}
+
+fn text(path: &str, data: Vec<u8>) -> WorkspaceResult<String> {
+    let text =
+        String::from_utf8(data).map_err(|e| WorkspaceError::notion_export().context(format!("{}: {}", path, e)))?;
+    Ok(text.trim_start_matches('\u{feff}').to_owned())
+}
+
+pub(crate) fn extension(path: &str) -> Option<&str> {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    name.rsplit_once('.').map(|(_, extension)| extension)
+}
+
+pub(crate) fn parent_dir(path: &str) -> Option<&str> { path.rsplit_once('/').map(|(dir, _)| dir) }
+
+// The path without the extension, it's the one of the directory of the child
+// pages.
+fn page_key(path: &str) -> &str {
+    let key = path.rsplit_once('.').map_or(path, |(key, _)| key);
+    match extension(path) {
+        Some("csv") => key.strip_suffix(ALL_ROWS_SUFFIX).unwrap_or(key),
+        _ => key,
+    }
+}
+
+// The name of the file without its extension and the id that ends it, e.g.
+// Tasks for Tasks 1a2b...d4e5_all.csv.
+pub(crate) fn page_name(path: &str) -> String {
+    let key = page_key(path);
+    let name = key.rsplit('/').next().unwrap_or(key);
+    let name = match name.rsplit_once(' ') {
+        Some((title, id)) if id.len() == PAGE_ID_LENGTH && id.chars().all(|c| c.is_ascii_hexdigit()) => title,
+        _ => name,
+    };
+    name.trim().to_owned()
+}
+
+// The path of the file that the link of the page refers to, None if it's not a
+// relative link. The links are percent-encoded.
+pub(crate) fn resolve_link(page_path: &str, link: &str) -> Option<String> {
+    if link.is_empty() || link.starts_with('#') || link.starts_with('/') || link.contains(':') {
+        return None;
+    }
+    let link = link.split(|c| c == '?' || c == '#').next().unwrap_or(link);
+    let decoded = percent_decode(link);
+    let mut segments = parent_dir(page_path)
+        .map(|dir| dir.split('/').collect::<Vec<_>>())
+        .unwrap_or_default();
+    for segment in decoded.split('/') {
+        match segment {
+            "" | "." => {},
+            ".." => {
+                segments.pop()?;
+            },
+            segment => segments.push(segment),
+        }
+    }
+    Some(segments.join("/"))
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let hex = bytes
+            .get(index + 1..index + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok());
+        match (bytes[index], hex.and_then(|hex| u8::from_str_radix(hex, 16).ok())) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                index += 3;
+            },
+            (byte, _) => {
+                decoded.push(byte);
+                index += 1;
+            },
+        }
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
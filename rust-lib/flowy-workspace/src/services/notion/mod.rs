@@ -0,0 +1,343 @@
+mod csv;
+mod export;
+mod page;
+
+pub(crate) use export::NotionExport;
+
+use crate::{
+    entities::view::{CreateViewParams, View, ViewType},
+    module::{WorkspaceGrid, LINK_SCHEME},
+    services::{notify_import_progress, AppController, ViewController},
+};
+use csv::parse_csv;
+use export::{extension, resolve_link, NotionPage, PageContent};
+use flowy_document::services::asset::asset_url;
+use flowy_workspace_infra::entities::{
+    app::{ColorStyle, CreateAppParams},
+    share::{ArchivedAsset, ArchivedView, ImportFailure, UnconvertedElement, UnconvertedKind},
+};
+use page::{convert_page, markdown_title, replace_links};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::Arc,
+};
+
+const UNTITLED_PAGE_NAME: &str = "Untitled";
+
+#[derive(Default)]
+pub(crate) struct NotionReport {
+    pub(crate) failures: Vec<ImportFailure>,
+    pub(crate) unconverted: Vec<UnconvertedElement>,
+}
+
+#[derive(Clone)]
+enum Parent {
+    Workspace,
+    // The id of the app or the view that the page belongs to.
+    View(String),
+    Failed,
+}
+
+#[derive(Default)]
+struct ImportState {
+    report: NotionReport,
+    // The views of the pages, by the index of the page.
+    view_ids: HashMap<usize, String>,
+    // The pages that link to other pages, their links are pointed to the views
+    // once all of them are imported.
+    linked_pages: Vec<usize>,
+    used_files: HashSet<String>,
+}
+
+// Imports the pages of a Notion export. The top level pages become the apps of
+// the workspace, with a view of their content if they have some, and the other
+// pages the views of the page they're in. The databases become grid views, the
+// content of their rows is imported as the views of the grid view.
+pub(crate) struct NotionImporter {
+    app_controller: Arc<AppController>,
+    view_controller: Arc<ViewController>,
+    grid: Arc<dyn WorkspaceGrid>,
+}
+
+impl NotionImporter {
+    pub(crate) fn new(
+        app_controller: Arc<AppController>,
+        view_controller: Arc<ViewController>,
+        grid: Arc<dyn WorkspaceGrid>,
+    ) -> Self {
+        Self {
+            app_controller,
+            view_controller,
+            grid,
+        }
+    }
+
+    // The pages that fail are reported instead of failing the import, the
+    // progress is sent after each page.
+    pub(crate) async fn import(&self, token: &str, workspace_id: &str, export: NotionExport) -> NotionReport {
+        let mut state = ImportState::default();
+        let mut queue = export
+            .roots()
+            .into_iter()
+            .map(|index| (index, Parent::Workspace))
+            .collect::<VecDeque<_>>();
+        let total = export.pages.len() as i32;
+        let mut done = 0;
+        while let Some((index, parent)) = queue.pop_front() {
+            let page = &export.pages[index];
+            let children_parent = match parent {
+                Parent::Failed => {
+                    state
+                        .report
+                        .failures
+                        .push(page_failure(page, "The page it belongs to wasn't imported".to_owned()));
+                    Parent::Failed
+                },
+                Parent::Workspace => match self.import_app(workspace_id, &export, index, &mut state).await {
+                    Ok(parent) => parent,
+                    Err(msg) => {
+                        state.report.failures.push(page_failure(page, msg));
+                        Parent::Failed
+                    },
+                },
+                Parent::View(belong_to_id) => match self.import_page(&export, index, belong_to_id, &mut state).await {
+                    Ok(Some(view)) => Parent::View(view.id),
+                    Ok(None) => Parent::Failed,
+                    Err(msg) => {
+                        state.report.failures.push(page_failure(page, msg));
+                        Parent::Failed
+                    },
+                },
+            };
+            for child in &page.children {
+                queue.push_back((*child, children_parent.clone()));
+            }
+            done += 1;
+            notify_import_progress(token, workspace_id, done, total);
+        }
+
+        self.link_pages(workspace_id, &export, &mut state).await;
+        for path in export.files.keys() {
+            if !state.used_files.contains(path) {
+                state.report.unconverted.push(UnconvertedElement {
+                    page: path.clone(),
+                    view_id: "".to_owned(),
+                    kind: UnconvertedKind::File,
+                    detail: "No page links to the file".to_owned(),
+                });
+            }
+        }
+        state.report
+    }
+
+    // The app of a top level page, its child pages belong to the app and the
+    // rows of a database to its grid view.
+    async fn import_app(
+        &self,
+        workspace_id: &str,
+        export: &NotionExport,
+        index: usize,
+        state: &mut ImportState,
+    ) -> Result<Parent, String> {
+        let page = &export.pages[index];
+        let create_params = CreateAppParams {
+            workspace_id: workspace_id.to_owned(),
+            name: page_title(page),
+            desc: "".to_owned(),
+            color_style: ColorStyle::default(),
+        };
+        let app = self
+            .app_controller
+            .create_app_from_params(create_params)
+            .await
+            .map_err(|e| e.msg)?;
+        let view = self.import_page(export, index, app.id.clone(), state).await?;
+        match (page.is_database(), view) {
+            (true, Some(view)) => Ok(Parent::View(view.id)),
+            _ => Ok(Parent::View(app.id)),
+        }
+    }
+
+    // The view of the page, None for a top level page without content and for a
+    // database row without content or child pages.
+    async fn import_page(
+        &self,
+        export: &NotionExport,
+        index: usize,
+        belong_to_id: String,
+        state: &mut ImportState,
+    ) -> Result<Option<View>, String> {
+        let page = &export.pages[index];
+        let view = match &page.content {
+            PageContent::Csv(csv) => self.import_database(page, csv, belong_to_id, state).await?,
+            PageContent::Markdown(markdown) => {
+                let parent = page.parent.map(|parent| &export.pages[parent]);
+                let is_row = parent.map_or(false, |parent| parent.is_database());
+                let converted = convert_page(markdown, is_row);
+                if converted.is_empty && (parent.is_none() || (is_row && page.children.is_empty())) {
+                    return Ok(None);
+                }
+
+                let mut assets = HashMap::new();
+                let mut links_pages = false;
+                let delta = replace_links(&converted.delta, |link| {
+                    let path = resolve_link(&page.path, link)?;
+                    if export.page_index(&path).is_some() {
+                        links_pages = true;
+                        return None;
+                    }
+                    let data = export.files.get(&path)?;
+                    assets.entry(path.clone()).or_insert_with(|| ArchivedAsset {
+                        id: path.clone(),
+                        doc_id: "".to_owned(),
+                        name: path.rsplit('/').next().unwrap_or(&path).to_owned(),
+                        mime_type: mime_type(&path).to_owned(),
+                        data: data.clone(),
+                    });
+                    Some(asset_url(&path))
+                });
+                state.used_files.extend(assets.keys().cloned());
+
+                let archived_view = ArchivedView {
+                    id: page.path.clone(),
+                    belong_to_id: belong_to_id.clone(),
+                    name: page_title(page),
+                    view_type: ViewType::Doc,
+                    data: delta.to_json(),
+                    ..ArchivedView::default()
+                };
+                let (view, failures) = self
+                    .view_controller
+                    .restore_view(
+                        belong_to_id,
+                        archived_view,
+                        assets.into_iter().map(|(_, asset)| asset).collect(),
+                    )
+                    .await
+                    .map_err(|e| e.msg)?;
+                state.report.failures.extend(failures);
+                if links_pages {
+                    state.linked_pages.push(index);
+                }
+                for (kind, detail) in converted.unconverted {
+                    state.report.unconverted.push(UnconvertedElement {
+                        page: page.path.clone(),
+                        view_id: view.id.clone(),
+                        kind,
+                        detail,
+                    });
+                }
+                view
+            },
+        };
+        state.view_ids.insert(index, view.id.clone());
+        Ok(Some(view))
+    }
+
+    // The grid has the id of the view, its first column is the one of the names
+    // of the rows.
+    async fn import_database(
+        &self,
+        page: &NotionPage,
+        csv: &str,
+        belong_to_id: String,
+        state: &mut ImportState,
+    ) -> Result<View, String> {
+        let mut rows = parse_csv(csv);
+        let columns = match rows.is_empty() {
+            true => vec![],
+            false => rows.remove(0),
+        };
+        let create_params = CreateViewParams {
+            belong_to_id,
+            name: page_title(page),
+            desc: "".to_owned(),
+            thumbnail: "".to_owned(),
+            view_type: ViewType::Grid,
+            data: "".to_owned(),
+            auto_rename: true,
+        };
+        let view = self
+            .view_controller
+            .create_view_from_params(create_params)
+            .await
+            .map_err(|e| e.msg)?;
+        let invalid_cells = self
+            .grid
+            .import_grid(&view.id, &view.name, columns, rows)
+            .map_err(|e| e.msg)?;
+        for detail in invalid_cells {
+            state.report.unconverted.push(UnconvertedElement {
+                page: page.path.clone(),
+                view_id: view.id.clone(),
+                kind: UnconvertedKind::Cell,
+                detail,
+            });
+        }
+        Ok(view)
+    }
+
+    // Points the links between the pages to the links of their views, the ones
+    // to the pages that weren't imported are kept.
+    async fn link_pages(&self, workspace_id: &str, export: &NotionExport, state: &mut ImportState) {
+        for index in std::mem::take(&mut state.linked_pages) {
+            let page = &export.pages[index];
+            let view_id = match state.view_ids.get(&index) {
+                None => continue,
+                Some(view_id) => view_id.clone(),
+            };
+            let delta = match self.view_controller.read_doc_delta(&view_id).await {
+                Ok(delta) => delta,
+                Err(e) => {
+                    state.report.failures.push(page_failure(page, e.msg));
+                    continue;
+                },
+            };
+            let view_ids = &state.view_ids;
+            let delta = replace_links(&delta, |link| {
+                let path = resolve_link(&page.path, link)?;
+                let view_id = view_ids.get(&export.page_index(&path)?)?;
+                Some(view_url(workspace_id, view_id))
+            });
+            if let Err(e) = self.view_controller.replace_doc_data(&view_id, delta).await {
+                state.report.failures.push(page_failure(page, e.msg));
+            }
+        }
+    }
+}
+
+fn page_title(page: &NotionPage) -> String {
+    let title = match &page.content {
+        PageContent::Markdown(markdown) => markdown_title(markdown).unwrap_or_else(|| page.name.clone()),
+        PageContent::Csv(_) => page.name.clone(),
+    };
+    match title.trim().is_empty() {
+        true => UNTITLED_PAGE_NAME.to_owned(),
+        false => title,
+    }
+}
+
+fn page_failure(page: &NotionPage, msg: String) -> ImportFailure {
+    ImportFailure {
+        id: page.path.clone(),
+        name: page.name.clone(),
+        msg,
+    }
+}
+
+fn view_url(workspace_id: &str, view_id: &str) -> String {
+    format!("{}://workspace/{}/view/{}", LINK_SCHEME, workspace_id, view_id)
+}
+
+fn mime_type(path: &str) -> &'static str {
+    match extension(path).map(|extension| extension.to_lowercase()).as_deref() {
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("svg") => "image/svg+xml",
+        Some("webp") => "image/webp",
+        Some("pdf") => "application/pdf",
+        Some("txt") => "text/plain",
+        _ => "application/octet-stream",
+    }
+}
@@ -0,0 +1,215 @@
+use flowy_document::services::import::markdown_to_delta;
+use flowy_ot::core::{Attribute, AttributeKey, Delta};
+use flowy_workspace_infra::entities::share::UnconvertedKind;
+
+// The longest name of a property of a database row, the longer lines are the
+// content of the row.
+const MAX_PROPERTY_NAME_LENGTH: usize = 64;
+
+pub(crate) struct ConvertedPage {
+    pub(crate) delta: Delta,
+    pub(crate) is_empty: bool,
+    pub(crate) unconverted: Vec<(UnconvertedKind, String)>,
+}
+
+// Converts the Markdown of an exported page to a document. The blocks that the
+// document doesn't have, like the tables, the callouts and the equations, are
+// kept as text and reported. The properties of a database row are dropped,
+// they're imported with the grid of the database.
+pub(crate) fn convert_page(markdown: &str, is_row: bool) -> ConvertedPage {
+    let mut lines = markdown.lines().peekable();
+    while lines.peek().map_or(false, |line| line.trim().is_empty()) {
+        lines.next();
+    }
+    if markdown_title(markdown).is_some() {
+        lines.next();
+    }
+    if is_row {
+        while lines.peek().map_or(false, |line| line.trim().is_empty()) {
+            lines.next();
+        }
+        while lines.peek().map_or(false, |line| is_property(line)) {
+            lines.next();
+        }
+    }
+
+    let mut output = vec![];
+    let mut unconverted = vec![];
+    let mut fence = false;
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            fence = !fence;
+            output.push(line.to_owned());
+            continue;
+        }
+        if fence {
+            output.push(line.to_owned());
+            continue;
+        }
+
+        if trimmed.starts_with("$$") {
+            // The equations are kept as code blocks.
+            let mut equation = vec![trimmed.trim_matches('$').to_owned()];
+            let closed = trimmed.len() > 2 && trimmed.ends_with("$$");
+            if !closed {
+                for line in lines.by_ref() {
+                    if line.trim().starts_with("$$") {
+                        break;
+                    }
+                    equation.push(line.to_owned());
+                }
+            }
+            equation.retain(|line| !line.trim().is_empty());
+            unconverted.push((UnconvertedKind::Equation, first_line(&equation)));
+            output.push("```".to_owned());
+            output.extend(equation);
+            output.push("```".to_owned());
+        } else if trimmed.starts_with('|') {
+            // The rows of the tables are kept as paragraphs.
+            let mut rows = vec![trimmed.to_owned()];
+            while lines.peek().map_or(false, |line| line.trim().starts_with('|')) {
+                rows.push(lines.next().unwrap_or_default().trim().to_owned());
+            }
+            let rows = rows
+                .iter()
+                .map(|row| table_cells(row))
+                .filter(|cells| !cells.iter().all(|cell| is_table_separator(cell)))
+                .collect::<Vec<_>>();
+            unconverted.push((
+                UnconvertedKind::Table,
+                rows.first().map(|row| row.join(", ")).unwrap_or_default(),
+            ));
+            for row in rows {
+                output.push(row.join(" | "));
+                output.push("".to_owned());
+            }
+        } else if trimmed.starts_with("<aside>") {
+            // The callouts are kept as quotes.
+            let mut callout = vec![];
+            let mut line = trimmed.trim_start_matches("<aside>").to_owned();
+            loop {
+                let end = line.contains("</aside>");
+                let text = strip_tags(&line);
+                if !text.trim().is_empty() {
+                    callout.push(text.trim().to_owned());
+                }
+                match (end, lines.next()) {
+                    (false, Some(next)) => line = next.to_owned(),
+                    _ => break,
+                }
+            }
+            unconverted.push((UnconvertedKind::Callout, first_line(&callout)));
+            for text in callout {
+                output.push(format!("> {}", text));
+            }
+            output.push("".to_owned());
+        } else if trimmed.starts_with("<details>") || trimmed.starts_with("<summary>") {
+            let summary = strip_tags(trimmed);
+            unconverted.push((UnconvertedKind::Toggle, summary.trim().to_owned()));
+            if !summary.trim().is_empty() {
+                output.push(summary.trim().to_owned());
+            }
+        } else if trimmed.starts_with("</details>") {
+            continue;
+        } else if is_html(trimmed) {
+            let text = strip_tags(trimmed);
+            unconverted.push((UnconvertedKind::Html, tag_name(trimmed).to_owned()));
+            output.push(text.trim().to_owned());
+        } else {
+            output.push(line.to_owned());
+        }
+    }
+
+    let is_empty = output.iter().all(|line| line.trim().is_empty());
+    ConvertedPage {
+        delta: markdown_to_delta(&output.join("\n")),
+        is_empty,
+        unconverted,
+    }
+}
+
+// The header that starts the page.
+pub(crate) fn markdown_title(markdown: &str) -> Option<String> {
+    markdown
+        .lines()
+        .find(|line| !line.trim().is_empty())
+        .and_then(|line| line.strip_prefix("# "))
+        .map(|title| title.trim().to_owned())
+}
+
+// The links of the document that the function gives a new link for are pointed
+// to it.
+pub(crate) fn replace_links<F>(delta: &Delta, mut f: F) -> Delta
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    let mut delta = delta.clone();
+    for op in delta.ops.iter_mut() {
+        let mut attributes = op.get_attributes();
+        let link = attributes
+            .get(&AttributeKey::Link)
+            .and_then(|value| value.0.clone())
+            .and_then(|link| f(&link));
+        if let Some(link) = link {
+            attributes.add(Attribute::Link(&link));
+            op.set_attributes(attributes);
+        }
+    }
+    delta
+}
+
+// A line like Status: Done.
+fn is_property(line: &str) -> bool {
+    match line.split_once(": ") {
+        Some((name, _)) => {
+            !name.trim().is_empty()
+                && name.len() <= MAX_PROPERTY_NAME_LENGTH
+                && !name.starts_with(|c: char| "#>-*|`<![".contains(c) || c.is_whitespace())
+        },
+        None => false,
+    }
+}
+
+fn table_cells(row: &str) -> Vec<String> {
+    row.trim_matches('|')
+        .split('|')
+        .map(|cell| cell.trim().to_owned())
+        .collect()
+}
+
+fn is_table_separator(cell: &str) -> bool { !cell.is_empty() && cell.chars().all(|c| c == '-' || c == ':') }
+
+fn is_html(line: &str) -> bool {
+    let mut chars = line.chars();
+    chars.next() == Some('<') && chars.next().map_or(false, |c| c.is_ascii_alphabetic())
+}
+
+fn tag_name(line: &str) -> &str {
+    let name = line.trim_start_matches('<');
+    let end = name.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(name.len());
+    &name[..end]
+}
+
+fn strip_tags(line: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in line.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {},
+        }
+    }
+    text
+}
+
+fn first_line(lines: &[String]) -> String {
+    lines
+        .iter()
+        .map(|line| line.trim())
+        .find(|line| !line.is_empty())
+        .unwrap_or_default()
+        .to_owned()
+}
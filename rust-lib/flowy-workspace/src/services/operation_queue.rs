@@ -0,0 +1,641 @@
+use crate::{
+    entities::{
+        app::{ColorStyle, UpdateAppParams},
+        sync::{ObjectSyncStatus, RemoteChange, RemoteChangeType},
+        trash::TrashIdentifiers,
+        view::UpdateViewParams,
+        workspace::{UpdateWorkspaceParams, WorkspaceIdentifier},
+    },
+    errors::*,
+    module::{WorkspaceDatabase, WorkspaceUser},
+    notify::{send_dart_notification, WorkspaceNotification},
+    services::{
+        server::Server,
+        sync::{ExcludedTree, SyncScheduler, SyncStatusTracker, SyncThrottle},
+    },
+    sql_tables::{
+        operation::{OperationTable, OperationTableSql, SqlOperationType},
+        sync::{LanChangeTableSql, SyncBaseTable, SyncBaseTableSql, SyncConflictTableSql},
+    },
+};
+use bytes::Bytes;
+use flowy_database::SqliteConnection;
+use flowy_infra::timestamp;
+use std::{
+    collections::{HashMap, HashSet},
+    convert::{TryFrom, TryInto},
+    sync::{
+        atomic::{AtomicBool, Ordering::SeqCst},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::sync::Mutex;
+
+// The local changes that the server is told about.
+#[derive(Debug, Clone)]
+pub(crate) enum Operation {
+    UpdateWorkspace(UpdateWorkspaceParams),
+    DeleteWorkspace(WorkspaceIdentifier),
+    UpdateApp(UpdateAppParams),
+    UpdateView(UpdateViewParams),
+    CreateTrash(TrashIdentifiers),
+    DeleteTrash(TrashIdentifiers),
+}
+
+impl Operation {
+    fn into_table_data(self) -> Result<(SqlOperationType, Vec<u8>), WorkspaceError> {
+        let (ty, bytes): (SqlOperationType, Result<Bytes, _>) = match self {
+            Operation::UpdateWorkspace(params) => (SqlOperationType::UpdateWorkspace, params.try_into()),
+            Operation::DeleteWorkspace(params) => (SqlOperationType::DeleteWorkspace, params.try_into()),
+            Operation::UpdateApp(params) => (SqlOperationType::UpdateApp, params.try_into()),
+            Operation::UpdateView(params) => (SqlOperationType::UpdateView, params.try_into()),
+            Operation::CreateTrash(params) => (SqlOperationType::CreateTrash, params.try_into()),
+            Operation::DeleteTrash(params) => (SqlOperationType::DeleteTrash, params.try_into()),
+        };
+        let bytes = bytes.map_err(internal_error)?;
+        Ok((ty, bytes.to_vec()))
+    }
+
+    pub(crate) fn into_data(self) -> Result<Vec<u8>, WorkspaceError> {
+        let (_, data) = self.into_table_data()?;
+        Ok(data)
+    }
+
+    // The objects that the operation changes.
+    fn object_ids(&self) -> Vec<String> {
+        match self {
+            Operation::UpdateWorkspace(params) => vec![params.id.clone()],
+            Operation::DeleteWorkspace(params) => params.workspace_id.iter().cloned().collect(),
+            Operation::UpdateApp(params) => vec![params.app_id.clone()],
+            Operation::UpdateView(params) => vec![params.view_id.clone()],
+            Operation::CreateTrash(params) | Operation::DeleteTrash(params) => {
+                params.items.iter().map(|item| item.id.clone()).collect()
+            },
+        }
+    }
+
+    // The update that the remote change makes, None if it isn't one.
+    pub(crate) fn from_remote_update(change: &RemoteChange) -> Result<Option<Operation>, WorkspaceError> {
+        let bytes = Bytes::from(change.data.clone());
+        let operation = match change.ty {
+            RemoteChangeType::UpdateWorkspace => {
+                Operation::UpdateWorkspace(UpdateWorkspaceParams::try_from(bytes).map_err(internal_error)?)
+            },
+            RemoteChangeType::UpdateApp => {
+                Operation::UpdateApp(UpdateAppParams::try_from(bytes).map_err(internal_error)?)
+            },
+            RemoteChangeType::UpdateView => {
+                Operation::UpdateView(UpdateViewParams::try_from(bytes).map_err(internal_error)?)
+            },
+            _ => return Ok(None),
+        };
+        Ok(Some(operation))
+    }
+
+    // The change that the devices on the local network apply, None if it isn't an
+    // update. The other operations are only synced through the server.
+    fn into_lan_change(self) -> Result<Option<(String, RemoteChangeType, Vec<u8>)>, WorkspaceError> {
+        let (object_id, ty) = match &self {
+            Operation::UpdateWorkspace(params) => (params.id.clone(), RemoteChangeType::UpdateWorkspace),
+            Operation::UpdateApp(params) => (params.app_id.clone(), RemoteChangeType::UpdateApp),
+            Operation::UpdateView(params) => (params.view_id.clone(), RemoteChangeType::UpdateView),
+            _ => return Ok(None),
+        };
+        Ok(Some((object_id, ty, self.into_data()?)))
+    }
+
+    // The remote change with the fields of this update instead.
+    pub(crate) fn into_remote_change(self, change: &RemoteChange) -> Result<RemoteChange, WorkspaceError> {
+        let (_, data) = self.into_table_data()?;
+        Ok(RemoteChange { data, ..change.clone() })
+    }
+
+    fn is_update(&self) -> bool {
+        matches!(
+            self,
+            Operation::UpdateWorkspace(_) | Operation::UpdateApp(_) | Operation::UpdateView(_)
+        )
+    }
+
+    pub(crate) fn is_empty_update(&self) -> bool {
+        match self {
+            Operation::UpdateWorkspace(params) => params.is_empty(),
+            Operation::UpdateApp(params) => params.is_empty(),
+            Operation::UpdateView(params) => params.is_empty(),
+            _ => false,
+        }
+    }
+
+    // The fields of the newer update of the same object replace these ones.
+    // Returns false if newer isn't one.
+    fn merge(&mut self, newer: &Operation) -> bool {
+        match (self, newer) {
+            (Operation::UpdateWorkspace(params), Operation::UpdateWorkspace(newer)) if params.id == newer.id => {
+                params.merge(newer.clone())
+            },
+            (Operation::UpdateApp(params), Operation::UpdateApp(newer)) if params.app_id == newer.app_id => {
+                params.merge(newer.clone())
+            },
+            (Operation::UpdateView(params), Operation::UpdateView(newer)) if params.view_id == newer.view_id => {
+                params.merge(newer.clone())
+            },
+            _ => return false,
+        }
+        true
+    }
+
+    // Moves the fields that the update of the same object sets too out of this
+    // one. None if other isn't one.
+    pub(crate) fn take_fields_of(&mut self, other: &Operation) -> Option<Operation> {
+        let taken = match (self, other) {
+            (Operation::UpdateWorkspace(params), Operation::UpdateWorkspace(other)) if params.id == other.id => {
+                Operation::UpdateWorkspace(params.take_fields_of(other))
+            },
+            (Operation::UpdateApp(params), Operation::UpdateApp(other)) if params.app_id == other.app_id => {
+                Operation::UpdateApp(params.take_fields_of(other))
+            },
+            (Operation::UpdateView(params), Operation::UpdateView(other)) if params.view_id == other.view_id => {
+                Operation::UpdateView(params.take_fields_of(other))
+            },
+            _ => return None,
+        };
+        Some(taken)
+    }
+
+    // The fields of the update with their values as text, None for the ones it
+    // doesn't set.
+    pub(crate) fn field_values(&self) -> Vec<(&'static str, Option<String>)> {
+        match self {
+            Operation::UpdateWorkspace(params) => vec![("name", params.name.clone()), ("desc", params.desc.clone())],
+            Operation::UpdateApp(params) => vec![
+                ("name", params.name.clone()),
+                ("desc", params.desc.clone()),
+                (
+                    "color_style",
+                    params.color_style.as_ref().map(|style| style.theme_color.clone()),
+                ),
+                ("is_trash", params.is_trash.map(|is_trash| is_trash.to_string())),
+            ],
+            Operation::UpdateView(params) => vec![
+                ("name", params.name.clone()),
+                ("desc", params.desc.clone()),
+                ("thumbnail", params.thumbnail.clone()),
+                ("icon", params.icon.clone()),
+                ("cover", params.cover.clone()),
+            ],
+            _ => vec![],
+        }
+    }
+
+    // Sets the field of the update from its value as text, see field_values.
+    pub(crate) fn set_field_value(&mut self, name: &str, value: &str) -> Result<(), WorkspaceError> {
+        let value = value.to_owned();
+        match (self, name) {
+            (Operation::UpdateWorkspace(params), "name") => params.name = Some(value),
+            (Operation::UpdateWorkspace(params), "desc") => params.desc = Some(value),
+            (Operation::UpdateApp(params), "name") => params.name = Some(value),
+            (Operation::UpdateApp(params), "desc") => params.desc = Some(value),
+            (Operation::UpdateApp(params), "color_style") => {
+                params.color_style = Some(ColorStyle { theme_color: value })
+            },
+            (Operation::UpdateApp(params), "is_trash") => {
+                let is_trash = value
+                    .parse::<bool>()
+                    .map_err(|e| WorkspaceError::conflict_field().context(e))?;
+                params.is_trash = Some(is_trash);
+            },
+            (Operation::UpdateView(params), "name") => params.name = Some(value),
+            (Operation::UpdateView(params), "desc") => params.desc = Some(value),
+            (Operation::UpdateView(params), "thumbnail") => params.thumbnail = Some(value),
+            (Operation::UpdateView(params), "icon") => params.icon = Some(value),
+            (Operation::UpdateView(params), "cover") => params.cover = Some(value),
+            _ => return Err(WorkspaceError::conflict_field().context(format!("Unknown field {}", name))),
+        }
+        Ok(())
+    }
+
+    fn from_table(table: OperationTable) -> Result<Operation, WorkspaceError> {
+        if table.ty == SqlOperationType::Unknown {
+            return Err(WorkspaceError::internal().context(format!("Unknown operation {}", table.id)));
+        }
+        Operation::from_data(table.ty, table.data)
+    }
+
+    fn from_data(ty: SqlOperationType, data: Vec<u8>) -> Result<Operation, WorkspaceError> {
+        let bytes = Bytes::from(data);
+        let operation = match ty {
+            SqlOperationType::UpdateWorkspace => {
+                Operation::UpdateWorkspace(UpdateWorkspaceParams::try_from(bytes).map_err(internal_error)?)
+            },
+            SqlOperationType::DeleteWorkspace => {
+                Operation::DeleteWorkspace(WorkspaceIdentifier::try_from(bytes).map_err(internal_error)?)
+            },
+            SqlOperationType::UpdateApp => {
+                Operation::UpdateApp(UpdateAppParams::try_from(bytes).map_err(internal_error)?)
+            },
+            SqlOperationType::UpdateView => {
+                Operation::UpdateView(UpdateViewParams::try_from(bytes).map_err(internal_error)?)
+            },
+            SqlOperationType::CreateTrash => {
+                Operation::CreateTrash(TrashIdentifiers::try_from(bytes).map_err(internal_error)?)
+            },
+            SqlOperationType::DeleteTrash => {
+                Operation::DeleteTrash(TrashIdentifiers::try_from(bytes).map_err(internal_error)?)
+            },
+            SqlOperationType::Unknown => {
+                return Err(WorkspaceError::internal().context("Unknown operation"));
+            },
+        };
+        Ok(operation)
+    }
+}
+
+// The operations are saved with the local changes they're made for, in the same
+// transaction, before the UI is answered. They're sent to the server in the
+// order they were saved and marked committed once it accepted them, the ones
+// that weren't sent before the app was closed are sent again when the workspace
+// is initialized.
+#[derive(Clone)]
+pub(crate) struct OperationQueue {
+    user: Arc<dyn WorkspaceUser>,
+    database: Arc<dyn WorkspaceDatabase>,
+    server: Server,
+    sending: Arc<Mutex<()>>,
+    status: SyncStatusTracker,
+    scheduler: Arc<SyncScheduler>,
+    throttle: Arc<SyncThrottle>,
+    // A send waits for the batch window.
+    batching: Arc<AtomicBool>,
+    // The updates are recorded in the log that the devices on the local network
+    // pull while the LAN sync runs.
+    lan_logging: Arc<AtomicBool>,
+}
+
+impl OperationQueue {
+    pub(crate) fn new(
+        user: Arc<dyn WorkspaceUser>,
+        database: Arc<dyn WorkspaceDatabase>,
+        server: Server,
+        scheduler: Arc<SyncScheduler>,
+        throttle: Arc<SyncThrottle>,
+    ) -> Self {
+        Self {
+            user,
+            database,
+            server,
+            sending: Arc::new(Mutex::new(())),
+            status: SyncStatusTracker::default(),
+            scheduler,
+            throttle,
+            batching: Arc::new(AtomicBool::new(false)),
+            lan_logging: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    // The committed operations are only kept until the next time the pending
+    // ones are replayed.
+    pub(crate) fn init(&self) -> Result<(), WorkspaceError> {
+        {
+            let conn = self.database.db_write_connection()?;
+            let _ = OperationTableSql::delete_committed_operations(&*conn)?;
+        }
+        self.flush();
+        Ok(())
+    }
+
+    pub(crate) fn push(&self, operation: Operation, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        if self.lan_logging.load(SeqCst) {
+            if let Some((object_id, ty, data)) = operation.clone().into_lan_change()? {
+                let _ = LanChangeTableSql::create_change(&object_id, ty as i32, data, timestamp(), conn)?;
+            }
+        }
+        let (ty, data) = operation.into_table_data()?;
+        OperationTableSql::create_operation(ty, data, conn)
+    }
+
+    pub(crate) fn set_lan_logging(&self, enabled: bool) { self.lan_logging.store(enabled, SeqCst); }
+
+    // Pushes the local update with the fields of the object before it, they're
+    // the base of the conflicts until the server is told about the update. The
+    // fields that were updated locally before keep their first values.
+    pub(crate) fn push_update(
+        &self,
+        operation: Operation,
+        mut before: Operation,
+        conn: &SqliteConnection,
+    ) -> Result<(), WorkspaceError> {
+        if let (Some(mut base), [object_id]) = (before.take_fields_of(&operation), operation.object_ids().as_slice()) {
+            if let Some(older) = self.read_base(object_id, conn)? {
+                base.merge(&older);
+            }
+            let _ = save_base(object_id, base, conn)?;
+        }
+        self.push(operation, conn)
+    }
+
+    pub(crate) fn read_base(
+        &self,
+        object_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Option<Operation>, WorkspaceError> {
+        match SyncBaseTableSql::read_base(object_id, conn)? {
+            None => Ok(None),
+            Some(table) => Ok(Some(Operation::from_data(table.ty, table.data)?)),
+        }
+    }
+
+    // Sends the pending operations in the background, call it after the
+    // transaction that pushed them was committed. They're kept while the network
+    // is unreachable or the trigger of the user isn't Automatic, the scheduled
+    // sync sends them. The ones pushed within the batch window of the user are
+    // sent together.
+    pub(crate) fn flush(&self) {
+        if !self.scheduler.sends_immediately() {
+            self.scheduler.did_change();
+            return;
+        }
+        let batch_window = self.throttle.batch_window();
+        if batch_window > Duration::from_secs(0) && self.batching.swap(true, SeqCst) {
+            return;
+        }
+        let queue = self.clone();
+        tokio::spawn(async move {
+            if batch_window > Duration::from_secs(0) {
+                tokio::time::sleep(batch_window).await;
+                queue.batching.store(false, SeqCst);
+            }
+            match queue.send_pending().await {
+                Ok(_) => {},
+                Err(e) => log::error!("Send operations failed: {:?}", e),
+            }
+        });
+    }
+
+    // Stops at the operation that the server failed to apply, it's sent again
+    // with the ones after it the next time, so the server gets them in order.
+    // Returns how many operations were sent.
+    pub(crate) async fn send_pending(&self) -> Result<usize, WorkspaceError> {
+        let _guard = self.sending.lock().await;
+        let operations = OperationTableSql::read_pending_operations(&*self.database.db_connection()?)?;
+        if operations.is_empty() {
+            return Ok(0);
+        }
+
+        let token = self.user.token()?;
+        let held_ids = self.held_object_ids()?;
+        let mut count = 0;
+        for (ids, operation) in compact_operations(operations, &held_ids) {
+            let mut object_ids = vec![];
+            if let Some(operation) = operation {
+                object_ids = operation.object_ids();
+                self.status.start(&object_ids);
+                self.notify_status(&object_ids);
+                if let Err(e) = self.send(&token, operation).await {
+                    self.status.fail(&object_ids, &e.msg);
+                    self.notify_status(&object_ids);
+                    return Err(e);
+                }
+                self.status.succeed(&object_ids);
+                count += 1;
+            }
+            {
+                let conn = self.database.db_write_connection()?;
+                for id in ids {
+                    let _ = OperationTableSql::commit_operation(id, &*conn)?;
+                }
+            }
+            self.notify_status(&object_ids);
+        }
+        let _ = self.delete_settled_bases(&*self.database.db_write_connection()?)?;
+        Ok(count)
+    }
+
+    // The server was told about all the local updates of these objects.
+    fn delete_settled_bases(&self, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let pending_ids = self
+            .pending_updates_with(conn)?
+            .into_iter()
+            .map(|(object_id, _)| object_id)
+            .collect::<HashSet<String>>();
+        for object_id in SyncBaseTableSql::read_base_ids(conn)? {
+            if !pending_ids.contains(&object_id) {
+                let _ = SyncBaseTableSql::delete_base(&object_id, conn)?;
+            }
+        }
+        Ok(())
+    }
+
+    // The status of each object, from the operations that weren't sent yet.
+    pub(crate) fn sync_status(&self, object_ids: &[String]) -> Result<Vec<ObjectSyncStatus>, WorkspaceError> {
+        let counts = self.pending_counts()?;
+        Ok(object_ids
+            .iter()
+            .map(|object_id| {
+                let pending = counts.get(object_id).cloned().unwrap_or(0);
+                self.status.status(object_id, pending)
+            })
+            .collect())
+    }
+
+    fn pending_counts(&self) -> Result<HashMap<String, i64>, WorkspaceError> {
+        let operations = OperationTableSql::read_pending_operations(&*self.database.db_connection()?)?;
+        let mut counts: HashMap<String, i64> = HashMap::new();
+        for table in operations {
+            if let Ok(operation) = Operation::from_table(table) {
+                for object_id in operation.object_ids() {
+                    *counts.entry(object_id).or_insert(0) += 1;
+                }
+            }
+        }
+        Ok(counts)
+    }
+
+    fn notify_status(&self, object_ids: &[String]) {
+        let statuses = match self.sync_status(object_ids) {
+            Ok(statuses) => statuses,
+            Err(e) => {
+                log::error!("Read the sync status failed: {:?}", e);
+                return;
+            },
+        };
+        for status in statuses {
+            send_dart_notification(
+                &status.object_id.clone(),
+                WorkspaceNotification::ObjectSyncStatusChanged,
+            )
+            .payload(status)
+            .send();
+        }
+    }
+
+    pub(crate) fn pending_count(&self) -> Result<i64, WorkspaceError> {
+        OperationTableSql::read_pending_count(&*self.database.db_connection()?)
+    }
+
+    // The fields of the objects that were updated locally but the server wasn't
+    // told about yet, merged into one update per object.
+    pub(crate) fn pending_updates(&self) -> Result<HashMap<String, PendingUpdate>, WorkspaceError> {
+        self.pending_updates_with(&*self.database.db_connection()?)
+    }
+
+    fn pending_updates_with(&self, conn: &SqliteConnection) -> Result<HashMap<String, PendingUpdate>, WorkspaceError> {
+        let operations = OperationTableSql::read_pending_operations(conn)?;
+        let mut updates: HashMap<String, PendingUpdate> = HashMap::new();
+        for table in operations {
+            let created_time = table.created_time;
+            let operation = match Operation::from_table(table) {
+                Ok(operation) if operation.is_update() => operation,
+                _ => continue,
+            };
+            for object_id in operation.object_ids() {
+                match updates.get_mut(&object_id) {
+                    Some(update) => {
+                        update.operation.merge(&operation);
+                        update.created_time = created_time;
+                    },
+                    None => {
+                        let update = PendingUpdate {
+                            operation: operation.clone(),
+                            created_time,
+                        };
+                        updates.insert(object_id, update);
+                    },
+                }
+            }
+        }
+        Ok(updates)
+    }
+
+    // Drops the fields that the remote update sets from the pending updates of
+    // the object, e.g. when it's applied instead of them. The updates without
+    // fields left are deleted, the base of the fields too.
+    pub(crate) fn discard_fields(&self, remote: &Operation, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        if let [object_id] = remote.object_ids().as_slice() {
+            if let Some(mut base) = self.read_base(object_id, conn)? {
+                let _ = base.take_fields_of(remote);
+                let _ = match base.is_empty_update() {
+                    true => SyncBaseTableSql::delete_base(object_id, conn)?,
+                    false => save_base(object_id, base, conn)?,
+                };
+            }
+        }
+        for table in OperationTableSql::read_pending_operations(conn)? {
+            let id = table.id;
+            let mut operation = match Operation::from_table(table) {
+                Ok(operation) => operation,
+                Err(_) => continue,
+            };
+            if operation.take_fields_of(remote).is_none() {
+                continue;
+            }
+            if operation.is_empty_update() {
+                let _ = OperationTableSql::delete_operation(id, conn)?;
+            } else {
+                let (_, data) = operation.into_table_data()?;
+                let _ = OperationTableSql::update_operation_data(id, data, conn)?;
+            }
+        }
+        Ok(())
+    }
+
+    // The operations of the objects whose conflict waits for the user aren't sent,
+    // the user could prefer the remote change. The ones of the objects that are
+    // excluded from the sync wait until they're synced again.
+    fn held_object_ids(&self) -> Result<HashSet<String>, WorkspaceError> {
+        let conn = &*self.database.db_connection()?;
+        let conflicts = SyncConflictTableSql::read_conflicts(conn)?;
+        let mut held_ids: HashSet<String> = conflicts.into_iter().map(|table| table.object_id).collect();
+        held_ids.extend(ExcludedTree::read(conn)?.object_ids().cloned());
+        Ok(held_ids)
+    }
+
+    // Sends the operation without saving it, e.g. the local state of an object
+    // that is synced again.
+    pub(crate) async fn send_now(&self, token: &str, operation: Operation) -> Result<(), WorkspaceError> {
+        let object_ids = operation.object_ids();
+        self.status.start(&object_ids);
+        let result = self.send(token, operation).await;
+        match &result {
+            Ok(_) => self.status.succeed(&object_ids),
+            Err(e) => self.status.fail(&object_ids, &e.msg),
+        }
+        self.notify_status(&object_ids);
+        result
+    }
+
+    async fn send(&self, token: &str, operation: Operation) -> Result<(), WorkspaceError> {
+        let bytes = operation.clone().into_data()?.len();
+        self.throttle.upload(bytes).await;
+        match operation {
+            Operation::UpdateWorkspace(params) => self.server.update_workspace(token, params).await,
+            Operation::DeleteWorkspace(params) => self.server.delete_workspace(token, params).await,
+            Operation::UpdateApp(params) => self.server.update_app(token, params).await,
+            Operation::UpdateView(params) => self.server.update_view(token, params).await,
+            Operation::CreateTrash(params) => self.server.create_trash(token, params).await,
+            Operation::DeleteTrash(params) => self.server.delete_trash(token, params).await,
+        }
+    }
+}
+
+fn save_base(object_id: &str, base: Operation, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+    let (ty, data) = base.into_table_data()?;
+    let table = SyncBaseTable {
+        object_id: object_id.to_owned(),
+        ty,
+        data,
+    };
+    SyncBaseTableSql::save_base(table, conn)
+}
+
+#[derive(Clone)]
+pub(crate) struct PendingUpdate {
+    pub(crate) operation: Operation,
+    // When the last of its updates was made.
+    pub(crate) created_time: i64,
+}
+
+// The updates of an object are sent as one, with the fields of all of them,
+// unless another operation of it was made in between. Each of the returned
+// operations is sent with the ids it was merged from, the ones that can't be
+// read are committed without being sent.
+fn compact_operations(
+    operations: Vec<OperationTable>,
+    held_ids: &HashSet<String>,
+) -> Vec<(Vec<i32>, Option<Operation>)> {
+    let mut compacted: Vec<(Vec<i32>, Option<Operation>)> = vec![];
+    // The index of the last operation of each object.
+    let mut last_index: HashMap<String, usize> = HashMap::new();
+    for table in operations {
+        let id = table.id;
+        let operation = match Operation::from_table(table) {
+            Ok(operation) => operation,
+            // It can't be sent again, it would keep the ones after it from being sent.
+            Err(e) => {
+                log::error!("Skip the operation that can't be read: {:?}", e);
+                compacted.push((vec![id], None));
+                continue;
+            },
+        };
+        let object_ids = operation.object_ids();
+        if object_ids.iter().any(|object_id| held_ids.contains(object_id)) {
+            continue;
+        }
+
+        if let [object_id] = object_ids.as_slice() {
+            if let Some(&index) = last_index.get(object_id) {
+                if let (ids, Some(last)) = &mut compacted[index] {
+                    if last.merge(&operation) {
+                        ids.push(id);
+                        continue;
+                    }
+                }
+            }
+        }
+        for object_id in object_ids {
+            last_index.insert(object_id, compacted.len());
+        }
+        compacted.push((vec![id], Some(operation)));
+    }
+    compacted
+}
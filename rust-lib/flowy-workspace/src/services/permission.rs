@@ -0,0 +1,481 @@
+use crate::{
+    entities::{
+        app::{CreateAppRequest, QueryAppRequest, UpdateAppRequest},
+        share::{ImportRequest, IngestSharedContentRequest},
+        template::{CreateViewFromTemplateRequest, SaveTemplateRequest},
+        trash::{TrashIdentifier, TrashIdentifiers},
+        view::{
+            BatchViewOperationRequest,
+            CreateViewRequest,
+            DuplicateDocumentRequest,
+            MoveViewRequest,
+            QueryViewRequest,
+            UpdateViewRequest,
+        },
+        workspace::*,
+    },
+    errors::*,
+    event::WorkspaceEvent,
+    module::{WorkspaceDatabase, WorkspaceUser},
+    notify::*,
+    services::{get_current_workspace, read_object_workspace_id, WorkspaceKeys},
+    sql_tables::{
+        member::{
+            WorkspaceInvitationTable,
+            WorkspaceInvitationTableSql,
+            WorkspaceMemberTable,
+            WorkspaceMemberTableSql,
+        },
+        setting::WorkspaceSettingTableSql,
+        workspace::WorkspaceTableSql,
+    },
+};
+use chrono::Utc;
+use flowy_database::SqliteConnection;
+use flowy_dispatch::prelude::*;
+use flowy_document_infra::entities::doc::DocDelta;
+use futures_core::future::BoxFuture;
+use std::sync::Arc;
+
+// Resolves the roles of the current user in the workspaces that the events
+// change and rejects the events that the roles don't allow. The permission of
+// each event is declared in module::create.
+pub struct WorkspacePermission {
+    user: Arc<dyn WorkspaceUser>,
+    database: Arc<dyn WorkspaceDatabase>,
+    keys: Arc<WorkspaceKeys>,
+}
+
+impl WorkspacePermission {
+    pub(crate) fn new(
+        user: Arc<dyn WorkspaceUser>,
+        database: Arc<dyn WorkspaceDatabase>,
+        keys: Arc<WorkspaceKeys>,
+    ) -> Self {
+        Self { user, database, keys }
+    }
+
+    pub(crate) fn add_owner(&self, workspace_id: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let user_id = self.user.user_id()?;
+        let table = WorkspaceMemberTable::new(workspace_id, &user_id, WorkspaceRole::Owner);
+        WorkspaceMemberTableSql::set_member(table, conn)
+    }
+
+    // The user that isn't a member of a workspace that the request changes has
+    // no permission.
+    fn check_roles(&self, request: &ModuleRequest, permission: EventPermission) -> Result<(), WorkspaceError> {
+        let user_id = self.user.user_id()?;
+        let conn = self.database.db_connection()?;
+        let mut workspace_ids: Vec<String> = vec![];
+        for target in read_targets(request)? {
+            let workspace_id = match target {
+                Target::Workspace(workspace_id) => Some(workspace_id),
+                Target::Object(object_id) => read_object_workspace_id(&object_id, &conn)?,
+            };
+            match workspace_id {
+                Some(workspace_id) if !workspace_ids.contains(&workspace_id) => workspace_ids.push(workspace_id),
+                _ => {},
+            }
+        }
+        // The handler reports the apps and the views that don't exist, the role
+        // is checked in the current workspace then.
+        if workspace_ids.is_empty() {
+            let workspace_id = get_current_workspace(&user_id).map_err(|_| WorkspaceError::permission_denied())?;
+            workspace_ids.push(workspace_id);
+        }
+
+        for workspace_id in workspace_ids {
+            match read_role(&workspace_id, &user_id, &conn)? {
+                Some(role) if role_allows(role, permission) => {},
+                role => {
+                    tracing::debug!(
+                        "{:?} is not allowed to {:?} in {} with the role {:?}",
+                        request.event,
+                        permission,
+                        workspace_id,
+                        role
+                    );
+                    return Err(WorkspaceError::permission_denied());
+                },
+            }
+        }
+        Ok(())
+    }
+
+    // Reads the members of the current workspace if the workspace_id is None
+    pub(crate) fn read_members(&self, workspace_id: Option<String>) -> Result<RepeatedWorkspaceMember, WorkspaceError> {
+        let workspace_id = match workspace_id {
+            Some(workspace_id) => workspace_id,
+            None => get_current_workspace(&self.user.user_id()?)?,
+        };
+        let conn = self.database.db_connection()?;
+        let items = WorkspaceMemberTableSql::read_members(&workspace_id, &conn)?
+            .into_iter()
+            .map(|table| table.into())
+            .collect();
+        Ok(RepeatedWorkspaceMember { items })
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn update_member(&self, params: UpdateWorkspaceMemberParams) -> Result<(), WorkspaceError> {
+        let user_id = self.user.user_id()?;
+        let conn = &*self.database.db_write_connection()?;
+        conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            // The role may have changed since the guard read it.
+            if read_role(&params.workspace_id, &user_id, conn)? != Some(WorkspaceRole::Owner) {
+                return Err(WorkspaceError::permission_denied());
+            }
+
+            let members = WorkspaceMemberTableSql::read_members(&params.workspace_id, conn)?;
+            let other_owner = members
+                .iter()
+                .any(|member| member.user_id != params.user_id && WorkspaceRole::from(member.role) == WorkspaceRole::Owner);
+            if params.role != WorkspaceRole::Owner && !other_owner {
+                return Err(WorkspaceError::workspace_owner_required());
+            }
+
+            let table = WorkspaceMemberTable::new(&params.workspace_id, &params.user_id, params.role);
+            WorkspaceMemberTableSql::set_member(table, conn)?;
+            Ok(())
+        })?;
+
+        self.notify_members_changed(&params.workspace_id)
+    }
+
+    // The members that are removed lose their role at once. The user can't remove
+    // themselves, the workspace always keeps an owner.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn remove_member(&self, params: RemoveWorkspaceMemberParams) -> Result<(), WorkspaceError> {
+        let user_id = self.user.user_id()?;
+        if params.user_id == user_id {
+            return Err(WorkspaceError::permission_denied().context("The user can't remove themselves"));
+        }
+
+        let conn = &*self.database.db_write_connection()?;
+        conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            let _ = self.require_owner(&params.workspace_id, conn)?;
+            if !WorkspaceMemberTableSql::delete_member(&params.workspace_id, &params.user_id, conn)? {
+                return Err(WorkspaceError::record_not_found()
+                    .context(format!("{} is not a member of the workspace", params.user_id)));
+            }
+            Ok(())
+        })?;
+
+        self.notify_members_changed(&params.workspace_id)
+    }
+
+    // The server pushed the member that joined the workspace, or whose role was
+    // changed on another device. The creator of a workspace without members is
+    // kept as its owner.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn apply_remote_member(&self, member: WorkspaceMember) -> Result<(), WorkspaceError> {
+        let user_id = self.user.user_id()?;
+        let conn = &*self.database.db_write_connection()?;
+        conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            let members = WorkspaceMemberTableSql::read_members(&member.workspace_id, conn)?;
+            if members.is_empty() && read_role(&member.workspace_id, &user_id, conn)? == Some(WorkspaceRole::Owner) {
+                let _ = self.add_owner(&member.workspace_id, conn)?;
+            }
+            let table = WorkspaceMemberTable::new(&member.workspace_id, &member.user_id, member.role);
+            WorkspaceMemberTableSql::set_member(table, conn)
+        })?;
+
+        self.notify_members_changed(&member.workspace_id)
+    }
+
+    // The member that left isn't there anymore, it's not an error.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn apply_remote_member_removal(&self, member: WorkspaceMember) -> Result<(), WorkspaceError> {
+        let conn = &*self.database.db_write_connection()?;
+        if !WorkspaceMemberTableSql::delete_member(&member.workspace_id, &member.user_id, conn)? {
+            return Ok(());
+        }
+
+        self.notify_members_changed(&member.workspace_id)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn invite_member(
+        &self,
+        params: InviteWorkspaceMemberParams,
+    ) -> Result<WorkspaceInvitation, WorkspaceError> {
+        let conn = &*self.database.db_write_connection()?;
+        let invitation = conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            let _ = self.require_owner(&params.workspace_id, conn)?;
+            let role = match params.role {
+                Some(role) => role,
+                None => read_member_default_role(&params.workspace_id, conn)?,
+            };
+            let mut table =
+                WorkspaceInvitationTable::new(&params.workspace_id, &params.email, role, Utc::now().timestamp());
+            let invite_secret = match self.keys.wrap_key(&params.workspace_id)? {
+                None => "".to_owned(),
+                Some((wrapped_key, invite_secret)) => {
+                    table = table.wrapped_key(&wrapped_key);
+                    invite_secret
+                },
+            };
+            WorkspaceInvitationTableSql::set_invitation(table.clone(), conn)?;
+            let mut invitation: WorkspaceInvitation = table.into();
+            invitation.invite_secret = invite_secret;
+            Ok(invitation)
+        })?;
+
+        self.notify_invitations_changed(&params.workspace_id)?;
+        Ok(invitation)
+    }
+
+    // Reads the invitations of the current workspace if the workspace_id is None
+    pub(crate) fn read_invitations(
+        &self,
+        workspace_id: Option<String>,
+    ) -> Result<RepeatedWorkspaceInvitation, WorkspaceError> {
+        let workspace_id = match workspace_id {
+            Some(workspace_id) => workspace_id,
+            None => get_current_workspace(&self.user.user_id()?)?,
+        };
+        let conn = self.database.db_connection()?;
+        let items = WorkspaceInvitationTableSql::read_invitations(&workspace_id, &conn)?
+            .into_iter()
+            .map(|table| table.into())
+            .collect();
+        Ok(RepeatedWorkspaceInvitation { items })
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn cancel_invitation(&self, params: WorkspaceInvitationIdentifier) -> Result<(), WorkspaceError> {
+        let conn = &*self.database.db_write_connection()?;
+        conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            let _ = self.require_owner(&params.workspace_id, conn)?;
+            if !WorkspaceInvitationTableSql::delete_invitation(&params.workspace_id, &params.email, conn)? {
+                return Err(WorkspaceError::record_not_found().context(format!("{} wasn't invited", params.email)));
+            }
+            Ok(())
+        })?;
+
+        self.notify_invitations_changed(&params.workspace_id)
+    }
+
+    // Reads the encryption of the current workspace if the workspace_id is None
+    pub(crate) fn read_encryption(&self, workspace_id: Option<String>) -> Result<WorkspaceEncryption, WorkspaceError> {
+        let workspace_id = match workspace_id {
+            Some(workspace_id) => workspace_id,
+            None => get_current_workspace(&self.user.user_id()?)?,
+        };
+        self.keys.state(&workspace_id)
+    }
+
+    // Only the owner creates the key, the other members get it from their
+    // invitations.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn enable_encryption(
+        &self,
+        workspace_id: Option<String>,
+    ) -> Result<WorkspaceEncryption, WorkspaceError> {
+        let workspace_id = match workspace_id {
+            Some(workspace_id) => workspace_id,
+            None => get_current_workspace(&self.user.user_id()?)?,
+        };
+        let conn = &*self.database.db_connection()?;
+        let _ = self.require_owner(&workspace_id, conn)?;
+        self.keys.enable(&workspace_id)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, params), fields(workspace_id = %params.workspace_id), err)]
+    pub(crate) fn accept_invitation(
+        &self,
+        params: AcceptWorkspaceInvitationParams,
+    ) -> Result<WorkspaceEncryption, WorkspaceError> {
+        self.keys.accept(params)
+    }
+
+    fn notify_members_changed(&self, workspace_id: &str) -> Result<(), WorkspaceError> {
+        let members = self.read_members(Some(workspace_id.to_owned()))?;
+        send_dart_notification(workspace_id, WorkspaceNotification::WorkspaceMembersChanged)
+            .payload(members)
+            .send();
+        Ok(())
+    }
+
+    fn notify_invitations_changed(&self, workspace_id: &str) -> Result<(), WorkspaceError> {
+        let invitations = self.read_invitations(Some(workspace_id.to_owned()))?;
+        send_dart_notification(workspace_id, WorkspaceNotification::WorkspaceInvitationsChanged)
+            .payload(invitations)
+            .send();
+        Ok(())
+    }
+
+    // The events that the owner manages check the role again with this in their
+    // transaction, it may have changed since the guard read it.
+    pub(crate) fn require_owner(&self, workspace_id: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let user_id = self.user.user_id()?;
+        match read_role(workspace_id, &user_id, conn)? {
+            None => Err(WorkspaceError::record_not_found().context(format!("Workspace {} not found", workspace_id))),
+            Some(WorkspaceRole::Owner) => Ok(()),
+            Some(_) => Err(WorkspaceError::permission_denied()),
+        }
+    }
+}
+
+impl EventGuard for WorkspacePermission {
+    fn check(
+        &self,
+        request: &ModuleRequest,
+        permission: Option<EventPermission>,
+    ) -> BoxFuture<'static, Result<(), EventResponse>> {
+        let permission = match permission {
+            None => return Box::pin(async { Ok(()) }),
+            Some(permission) => permission,
+        };
+
+        let result = self.check_roles(request, permission).map_err(|e| e.as_response());
+        Box::pin(async move { result })
+    }
+}
+
+// What the event changes, the guard checks the role in the workspace it belongs
+// to.
+enum Target {
+    Workspace(String),
+    // An app, or a view at any depth.
+    Object(String),
+}
+
+// Empty if the event changes the current workspace.
+fn read_targets(request: &ModuleRequest) -> Result<Vec<Target>, WorkspaceError> {
+    let is = |event: WorkspaceEvent| request.event == Event::from(event);
+    let targets = if is(WorkspaceEvent::UpdateWorkspace) {
+        vec![Target::Workspace(parse::<UpdateWorkspaceRequest>(request)?.id)]
+    } else if is(WorkspaceEvent::DeleteWorkspace) || is(WorkspaceEvent::EnableWorkspaceEncryption) {
+        let query = parse::<QueryWorkspaceRequest>(request)?;
+        query.workspace_id.into_iter().map(Target::Workspace).collect()
+    } else if is(WorkspaceEvent::SetWorkspaceSetting) {
+        vec![Target::Workspace(
+            parse::<SetWorkspaceSettingRequest>(request)?.workspace_id,
+        )]
+    } else if is(WorkspaceEvent::UpdateWorkspaceMember) {
+        vec![Target::Workspace(
+            parse::<UpdateWorkspaceMemberRequest>(request)?.workspace_id,
+        )]
+    } else if is(WorkspaceEvent::InviteWorkspaceMember) {
+        vec![Target::Workspace(
+            parse::<InviteWorkspaceMemberRequest>(request)?.workspace_id,
+        )]
+    } else if is(WorkspaceEvent::CancelWorkspaceInvitation) {
+        vec![Target::Workspace(
+            parse::<CancelWorkspaceInvitationRequest>(request)?.workspace_id,
+        )]
+    } else if is(WorkspaceEvent::RemoveWorkspaceMember) {
+        vec![Target::Workspace(
+            parse::<RemoveWorkspaceMemberRequest>(request)?.workspace_id,
+        )]
+    } else if is(WorkspaceEvent::CreateApp) {
+        vec![Target::Workspace(parse::<CreateAppRequest>(request)?.workspace_id)]
+    } else if is(WorkspaceEvent::UpdateApp) {
+        vec![Target::Object(parse::<UpdateAppRequest>(request)?.app_id)]
+    } else if is(WorkspaceEvent::DeleteApp) {
+        parse::<QueryAppRequest>(request)?
+            .app_ids
+            .into_iter()
+            .map(Target::Object)
+            .collect()
+    } else if is(WorkspaceEvent::CreateView) {
+        vec![Target::Object(parse::<CreateViewRequest>(request)?.belong_to_id)]
+    } else if is(WorkspaceEvent::UpdateView) {
+        vec![Target::Object(parse::<UpdateViewRequest>(request)?.view_id)]
+    } else if is(WorkspaceEvent::DeleteView) || is(WorkspaceEvent::DuplicateView) {
+        parse::<QueryViewRequest>(request)?
+            .view_ids
+            .into_iter()
+            .map(Target::Object)
+            .collect()
+    } else if is(WorkspaceEvent::DuplicateDocument) {
+        let request = parse::<DuplicateDocumentRequest>(request)?;
+        std::iter::once(request.view_id)
+            .chain(request.belong_to_id)
+            .map(Target::Object)
+            .collect()
+    } else if is(WorkspaceEvent::MoveView) {
+        let request = parse::<MoveViewRequest>(request)?;
+        vec![Target::Object(request.view_id), Target::Object(request.belong_to_id)]
+    } else if is(WorkspaceEvent::BatchViewOperation) {
+        parse::<BatchViewOperationRequest>(request)?
+            .operations
+            .into_iter()
+            .flat_map(|operation| std::iter::once(operation.view_id).chain(operation.belong_to_id))
+            .map(Target::Object)
+            .collect()
+    } else if is(WorkspaceEvent::ApplyDocDelta) {
+        vec![Target::Object(parse::<DocDelta>(request)?.doc_id)]
+    } else if is(WorkspaceEvent::PutbackTrash) {
+        vec![Target::Object(parse::<TrashIdentifier>(request)?.id)]
+    } else if is(WorkspaceEvent::DeleteTrash) {
+        let identifiers = parse::<TrashIdentifiers>(request)?;
+        identifiers
+            .items
+            .into_iter()
+            .map(|item| Target::Object(item.id))
+            .collect()
+    } else if is(WorkspaceEvent::ImportDocument) {
+        vec![Target::Object(parse::<ImportRequest>(request)?.belong_to_id)]
+    } else if is(WorkspaceEvent::IngestSharedContent) {
+        let request = parse::<IngestSharedContentRequest>(request)?;
+        request.belong_to_id.into_iter().map(Target::Object).collect()
+    } else if is(WorkspaceEvent::CreateViewFromTemplate) {
+        vec![Target::Object(
+            parse::<CreateViewFromTemplateRequest>(request)?.belong_to_id,
+        )]
+    } else if is(WorkspaceEvent::SaveTemplate) {
+        vec![Target::Object(parse::<SaveTemplateRequest>(request)?.view_id)]
+    } else {
+        vec![]
+    };
+    Ok(targets)
+}
+
+fn parse<T: FromBytes>(request: &ModuleRequest) -> Result<T, WorkspaceError> {
+    match request.payload_bytes() {
+        None => Err(WorkspaceError::internal().context(format!("{:?} has no payload", request.event))),
+        Some(bytes) => T::parse_from_bytes_with(bytes, request.payload_encoding()).map_err(internal_error),
+    }
+}
+
+pub(crate) fn role_allows(role: WorkspaceRole, permission: EventPermission) -> bool {
+    match role {
+        WorkspaceRole::Owner => true,
+        WorkspaceRole::Editor => permission <= EventPermission::Write,
+        WorkspaceRole::Viewer => permission == EventPermission::Read,
+    }
+}
+
+// The workspaces created before the members were introduced have no member, their
+// creator is the owner.
+pub(crate) fn read_role(
+    workspace_id: &str,
+    user_id: &str,
+    conn: &SqliteConnection,
+) -> Result<Option<WorkspaceRole>, WorkspaceError> {
+    if let Some(member) = WorkspaceMemberTableSql::read_member(workspace_id, user_id, conn)? {
+        return Ok(Some(member.role.into()));
+    }
+    // The creator that was removed from the workspace isn't its owner anymore.
+    if !WorkspaceMemberTableSql::read_members(workspace_id, conn)?.is_empty() {
+        return Ok(None);
+    }
+
+    let workspace_sql = WorkspaceTableSql {};
+    let workspaces = workspace_sql.read_workspaces(Some(workspace_id.to_owned()), user_id, conn)?;
+    match workspaces.is_empty() {
+        true => Ok(None),
+        false => Ok(Some(WorkspaceRole::Owner)),
+    }
+}
+
+// The role of the members that are invited without one.
+fn read_member_default_role(workspace_id: &str, conn: &SqliteConnection) -> Result<WorkspaceRole, WorkspaceError> {
+    let setting = WorkspaceSettingTableSql::read_setting(workspace_id, MEMBER_DEFAULT_ROLE_SETTING, conn)?;
+    match setting.and_then(|table| table.int_value) {
+        Some(role) if role == WorkspaceRole::Editor as i64 => Ok(WorkspaceRole::Editor),
+        _ => Ok(WorkspaceRole::Viewer),
+    }
+}
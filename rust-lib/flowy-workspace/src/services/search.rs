@@ -0,0 +1,293 @@
+use crate::{
+    entities::{
+        search::{RepeatedSearchResult, SearchRequest, SearchResult, SearchResultHighlight, SearchResultType},
+        view::RepeatedView,
+    },
+    errors::{WorkspaceError, WorkspaceResult},
+    module::WorkspaceDatabase,
+    notify::{WorkspaceNotification, OBSERVABLE_CATEGORY},
+    services::{read_visible_view_workspace_id, TrashCan},
+    sql_tables::{
+        search::{SearchRow, ViewSearchTableSql, SNIPPET_MATCH_END, SNIPPET_MATCH_START},
+        view::ViewTableSql,
+    },
+};
+use flowy_database::SqliteConnection;
+use flowy_dispatch::prelude::{notification_center, Notification, NotificationFilter};
+use parking_lot::Mutex;
+use std::{cmp::Ordering, collections::HashSet, convert::TryFrom, sync::Arc};
+
+pub const DEFAULT_SEARCH_LIMIT: i64 = 20;
+
+// The bm25 of the titles is multiplied by it, a title ranks above a line of a
+// document that matches as well.
+const TITLE_BOOST: f64 = 2.0;
+
+// The matches read for each result. The ones in the trash or outside the
+// filters of the query are dropped after they're read.
+const CANDIDATES_PER_RESULT: i64 = 4;
+
+// Searches the titles of the views and the lines of the documents in all the
+// workspaces. The index of the titles follows the notifications of the views:
+// the views that changed are indexed again before the next search, and the
+// first search of the session reindexes all of them.
+pub(crate) struct WorkspaceSearch {
+    database: Arc<dyn WorkspaceDatabase>,
+    trash_can: Arc<TrashCan>,
+    changes: Arc<Mutex<TitleChanges>>,
+    subscription: u64,
+}
+
+#[derive(Default)]
+struct TitleChanges {
+    reindexed: bool,
+    view_ids: HashSet<String>,
+}
+
+impl TitleChanges {
+    fn record(&mut self, notification: &Notification) {
+        let ty = notification.ty;
+        if ty == WorkspaceNotification::ViewUpdated as i32
+            || ty == WorkspaceNotification::ViewDeleted as i32
+            || ty == WorkspaceNotification::ViewRestored as i32
+        {
+            self.view_ids.insert(notification.id.clone());
+        } else if ty == WorkspaceNotification::AppViewsChanged as i32 {
+            let views = notification
+                .payload
+                .clone()
+                .and_then(|payload| RepeatedView::try_from(payload).ok());
+            match views {
+                Some(views) => self.view_ids.extend(views.items.into_iter().map(|view| view.id)),
+                None => self.reindexed = false,
+            }
+        } else if ty == WorkspaceNotification::ViewsBatchChanged as i32 {
+            self.reindexed = false;
+        }
+    }
+}
+
+impl WorkspaceSearch {
+    pub(crate) fn new(database: Arc<dyn WorkspaceDatabase>, trash_can: Arc<TrashCan>) -> Self {
+        let changes = Arc::new(Mutex::new(TitleChanges::default()));
+        let recorded = changes.clone();
+        let filter = NotificationFilter::all().source(OBSERVABLE_CATEGORY);
+        let subscription =
+            notification_center().subscribe(filter, move |notification| recorded.lock().record(notification));
+        Self {
+            database,
+            trash_can,
+            changes,
+            subscription,
+        }
+    }
+
+    pub(crate) fn search(&self, request: SearchRequest) -> WorkspaceResult<RepeatedSearchResult> {
+        let query = SearchQuery::parse(&request.query);
+        let fts_query = match query.fts_query() {
+            None => return Ok(RepeatedSearchResult::default()),
+            Some(fts_query) => fts_query,
+        };
+        let limit = if request.limit > 0 {
+            request.limit
+        } else {
+            DEFAULT_SEARCH_LIMIT
+        };
+        let conn = &*self.database.db_write_connection()?;
+        let _ = self.update_titles(conn)?;
+
+        let candidates = limit * CANDIDATES_PER_RESULT;
+        let mut matches: Vec<(f64, SearchResultType, SearchRow)> = vec![];
+        if query.accepts(SearchResultType::View) {
+            for row in ViewSearchTableSql::search_titles(&fts_query, candidates, conn)? {
+                matches.push((row.score * TITLE_BOOST, SearchResultType::View, row));
+            }
+        }
+        if query.accepts(SearchResultType::Document) {
+            for row in ViewSearchTableSql::search_documents(&fts_query, candidates, conn)? {
+                matches.push((row.score, SearchResultType::Document, row));
+            }
+        }
+        matches.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+
+        let trash_ids = self.trash_can.trash_ids(conn)?;
+        let mut items = vec![];
+        for (_, ty, row) in matches {
+            if items.len() as i64 >= limit {
+                break;
+            }
+            // The views that were deleted, or whose app is missing, are left out.
+            let workspace_id = match read_visible_view_workspace_id(&row.view_id, &trash_ids, conn) {
+                Ok(Some(workspace_id)) => workspace_id,
+                _ => continue,
+            };
+            if !query.accepts_workspace(&workspace_id) {
+                continue;
+            }
+            let title = match ViewTableSql::read_views_with_ids(&[row.view_id.clone()], conn)?.pop() {
+                Some(view_table) => view_table.name,
+                None => continue,
+            };
+            let (snippet, highlights) = parse_snippet(&row.snippet);
+            items.push(SearchResult {
+                view_id: row.view_id,
+                ty,
+                workspace_id,
+                title,
+                snippet,
+                highlights,
+            });
+        }
+        Ok(RepeatedSearchResult { items })
+    }
+
+    // The changes are taken back if the index isn't written, the next search
+    // tries again.
+    fn update_titles(&self, conn: &SqliteConnection) -> WorkspaceResult<()> {
+        let (reindex, view_ids) = {
+            let mut changes = self.changes.lock();
+            let view_ids = changes.view_ids.drain().collect::<Vec<_>>();
+            let reindex = !changes.reindexed;
+            // A batch that changes while it's reindexed sets it back to false.
+            changes.reindexed = true;
+            (reindex, view_ids)
+        };
+        let result = conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            if reindex {
+                return ViewSearchTableSql::reindex_titles(conn);
+            }
+            for view_id in &view_ids {
+                match ViewTableSql::read_views_with_ids(&[view_id.clone()], conn)?.pop() {
+                    Some(view_table) => ViewSearchTableSql::set_title(view_id, &view_table.name, conn)?,
+                    None => ViewSearchTableSql::delete_titles(&[view_id.clone()], conn)?,
+                }
+            }
+            Ok(())
+        });
+
+        if result.is_err() {
+            let mut changes = self.changes.lock();
+            changes.view_ids.extend(view_ids);
+            if reindex {
+                changes.reindexed = false;
+            }
+        }
+        result
+    }
+}
+
+impl std::ops::Drop for WorkspaceSearch {
+    fn drop(&mut self) { notification_center().unsubscribe(self.subscription); }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct SearchTerm {
+    text: String,
+    phrase: bool,
+}
+
+// The terms of the query and its filters, e.g.
+// "meeting notes" budget type:document workspace:<workspace_id>.
+#[derive(Debug, Default, PartialEq)]
+struct SearchQuery {
+    terms: Vec<SearchTerm>,
+    ty: Option<SearchResultType>,
+    workspace_id: Option<String>,
+}
+
+impl SearchQuery {
+    // The filters with an unknown value are searched as words.
+    fn parse(query: &str) -> Self {
+        let mut parsed = SearchQuery::default();
+        let mut chars = query.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c.is_whitespace() {
+                continue;
+            }
+            if c == '"' {
+                let phrase = chars.by_ref().take_while(|c| *c != '"').collect::<String>();
+                if !phrase.trim().is_empty() {
+                    parsed.terms.push(SearchTerm {
+                        text: phrase.trim().to_owned(),
+                        phrase: true,
+                    });
+                }
+                continue;
+            }
+            let mut word = c.to_string();
+            while let Some(c) = chars.peek() {
+                if c.is_whitespace() || *c == '"' {
+                    break;
+                }
+                word.push(*c);
+                chars.next();
+            }
+            if !parsed.parse_filter(&word) {
+                parsed.terms.push(SearchTerm {
+                    text: word,
+                    phrase: false,
+                });
+            }
+        }
+        parsed
+    }
+
+    fn parse_filter(&mut self, word: &str) -> bool {
+        match word.split_once(':') {
+            Some(("type", "view")) => self.ty = Some(SearchResultType::View),
+            Some(("type", "document")) | Some(("type", "doc")) => self.ty = Some(SearchResultType::Document),
+            Some(("workspace", workspace_id)) if !workspace_id.is_empty() => {
+                self.workspace_id = Some(workspace_id.to_owned())
+            },
+            _ => return false,
+        }
+        true
+    }
+
+    fn accepts(&self, ty: SearchResultType) -> bool { self.ty.map_or(true, |filter| filter == ty) }
+
+    fn accepts_workspace(&self, workspace_id: &str) -> bool {
+        self.workspace_id.as_ref().map_or(true, |filter| filter == workspace_id)
+    }
+
+    // Every term is quoted so the query can't be read as the fts5 syntax, the
+    // last word is matched as a prefix.
+    fn fts_query(&self) -> Option<String> {
+        let last = self.terms.len().checked_sub(1)?;
+        let terms = self
+            .terms
+            .iter()
+            .enumerate()
+            .map(|(index, term)| {
+                let quoted = format!("\"{}\"", term.text.replace('"', "\"\""));
+                if index == last && !term.phrase {
+                    format!("{}*", quoted)
+                } else {
+                    quoted
+                }
+            })
+            .collect::<Vec<_>>();
+        Some(terms.join(" "))
+    }
+}
+
+fn parse_snippet(snippet: &str) -> (String, Vec<SearchResultHighlight>) {
+    let mut text = String::with_capacity(snippet.len());
+    let mut highlights = vec![];
+    let mut offset = 0;
+    let mut start = 0;
+    for c in snippet.chars() {
+        match c {
+            SNIPPET_MATCH_START => start = offset,
+            SNIPPET_MATCH_END => highlights.push(SearchResultHighlight {
+                start: start as i64,
+                end: offset as i64,
+            }),
+            _ => {
+                text.push(c);
+                offset += c.len_utf16();
+            },
+        }
+    }
+    (text, highlights)
+}
@@ -8,6 +8,7 @@ pub use server_api_mock::*;
 use crate::{
     entities::{
         app::{App, AppIdentifier, CreateAppParams, UpdateAppParams},
+        sync::{PullChangesParams, RepeatedRemoteChange},
         trash::{RepeatedTrash, TrashIdentifiers},
         view::{CreateViewParams, UpdateViewParams, View, ViewIdentifier, ViewIdentifiers},
         workspace::{CreateWorkspaceParams, RepeatedWorkspace, UpdateWorkspaceParams, Workspace, WorkspaceIdentifier},
@@ -60,6 +61,9 @@ pub trait WorkspaceServerAPI {
     fn delete_trash(&self, token: &str, params: TrashIdentifiers) -> ResultFuture<(), WorkspaceError>;
 
     fn read_trash(&self, token: &str) -> ResultFuture<RepeatedTrash, WorkspaceError>;
+
+    // Sync
+    fn pull_changes(&self, token: &str, params: PullChangesParams) -> ResultFuture<RepeatedRemoteChange, WorkspaceError>;
 }
 
 pub(crate) fn construct_workspace_server(config: &ServerConfig) -> Arc<dyn WorkspaceServerAPI + Send + Sync> {
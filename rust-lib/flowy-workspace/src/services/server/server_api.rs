@@ -1,6 +1,7 @@
 use crate::{
     entities::{
         app::{App, AppIdentifier, CreateAppParams, UpdateAppParams},
+        sync::{PullChangesParams, RepeatedRemoteChange},
         trash::{RepeatedTrash, TrashIdentifiers},
         view::{CreateViewParams, UpdateViewParams, View, ViewIdentifier, ViewIdentifiers},
         workspace::{CreateWorkspaceParams, RepeatedWorkspace, UpdateWorkspaceParams, Workspace, WorkspaceIdentifier},
@@ -178,4 +179,13 @@ impl WorkspaceServerAPI for WorkspaceServer {
             Ok(repeated_trash)
         })
     }
+
+    fn pull_changes(&self, token: &str, params: PullChangesParams) -> ResultFuture<RepeatedRemoteChange, WorkspaceError> {
+        let token = token.to_owned();
+        let url = self.config.sync_url();
+        ResultFuture::new(async move {
+            let repeated_change = pull_changes_request(&token, params, &url).await?;
+            Ok(repeated_change)
+        })
+    }
 }
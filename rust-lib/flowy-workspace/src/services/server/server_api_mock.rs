@@ -1,6 +1,7 @@
 use crate::{
     entities::{
         app::{App, AppIdentifier, CreateAppParams, RepeatedApp, UpdateAppParams},
+        sync::{PullChangesParams, RepeatedRemoteChange},
         trash::{RepeatedTrash, TrashIdentifiers},
         view::{CreateViewParams, RepeatedView, UpdateViewParams, View, ViewIdentifier, ViewIdentifiers},
         workspace::{CreateWorkspaceParams, RepeatedWorkspace, UpdateWorkspaceParams, Workspace, WorkspaceIdentifier},
@@ -60,6 +61,8 @@ impl WorkspaceServerAPI for WorkspaceServerMock {
             belongings: RepeatedView::default(),
             modified_time: time,
             create_time: time,
+            icon: "".to_owned(),
+            cover: "".to_owned(),
         };
         ResultFuture::new(async { Ok(view) })
     }
@@ -117,4 +120,12 @@ impl WorkspaceServerAPI for WorkspaceServerMock {
             Ok(repeated_trash)
         })
     }
+
+    fn pull_changes(
+        &self,
+        _token: &str,
+        _params: PullChangesParams,
+    ) -> ResultFuture<RepeatedRemoteChange, WorkspaceError> {
+        ResultFuture::new(async { Ok(RepeatedRemoteChange::default()) })
+    }
 }
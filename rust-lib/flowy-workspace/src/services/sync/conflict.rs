@@ -0,0 +1,171 @@
+use crate::{
+    entities::sync::{
+        ConflictField,
+        ConflictResolution,
+        ConflictStrategy,
+        RemoteChange,
+        RemoteChangeType,
+        SyncEntityType,
+        SyncSettings,
+        SyncTrigger,
+    },
+    errors::WorkspaceResult,
+    module::WorkspaceUser,
+    services::Operation,
+};
+use flowy_infra::kv::KV;
+use std::{collections::HashMap, sync::Arc};
+
+const SYNC_CONFLICT_STRATEGY: &str = "sync_conflict_strategy";
+const SYNC_TRIGGER: &str = "sync_trigger";
+const SYNC_INTERVAL_SECONDS: &str = "sync_interval_seconds";
+const SYNC_DEBOUNCE_MILLIS: &str = "sync_debounce_millis";
+const SYNC_UNMETERED_ONLY: &str = "sync_unmetered_only";
+const SYNC_UPLOAD_BYTES_PER_SECOND: &str = "sync_upload_bytes_per_second";
+const SYNC_DOWNLOAD_BYTES_PER_SECOND: &str = "sync_download_bytes_per_second";
+const SYNC_BATCH_WINDOW_MILLIS: &str = "sync_batch_window_millis";
+const SYNC_LAN: &str = "sync_lan";
+
+// The settings are set by each user, the strategies are PreferLocal and the
+// trigger Automatic until then.
+pub(crate) struct SyncSettingsStore {
+    user: Arc<dyn WorkspaceUser>,
+}
+
+impl SyncSettingsStore {
+    pub(crate) fn new(user: Arc<dyn WorkspaceUser>) -> Self { Self { user } }
+
+    pub(crate) fn read(&self) -> WorkspaceResult<SyncSettings> {
+        let user_id = self.user.user_id()?;
+        let strategy = |entity| {
+            KV::get_int(&strategy_key(entity, &user_id))
+                .map(ConflictStrategy::from)
+                .unwrap_or_default()
+        };
+        Ok(SyncSettings {
+            workspace_strategy: strategy(SyncEntityType::Workspace),
+            app_strategy: strategy(SyncEntityType::App),
+            view_strategy: strategy(SyncEntityType::View),
+            trigger: KV::get_int(&user_key(SYNC_TRIGGER, &user_id))
+                .map(SyncTrigger::from)
+                .unwrap_or_default(),
+            interval_seconds: KV::get_int(&user_key(SYNC_INTERVAL_SECONDS, &user_id)).unwrap_or(0),
+            debounce_millis: KV::get_int(&user_key(SYNC_DEBOUNCE_MILLIS, &user_id)).unwrap_or(0),
+            unmetered_only: KV::get_bool(&user_key(SYNC_UNMETERED_ONLY, &user_id)).unwrap_or(false),
+            upload_bytes_per_second: KV::get_int(&user_key(SYNC_UPLOAD_BYTES_PER_SECOND, &user_id)).unwrap_or(0),
+            download_bytes_per_second: KV::get_int(&user_key(SYNC_DOWNLOAD_BYTES_PER_SECOND, &user_id)).unwrap_or(0),
+            batch_window_millis: KV::get_int(&user_key(SYNC_BATCH_WINDOW_MILLIS, &user_id)).unwrap_or(0),
+            lan_sync: KV::get_bool(&user_key(SYNC_LAN, &user_id)).unwrap_or(false),
+        })
+    }
+
+    pub(crate) fn update(&self, settings: SyncSettings) -> WorkspaceResult<()> {
+        let user_id = self.user.user_id()?;
+        for entity in [SyncEntityType::Workspace, SyncEntityType::App, SyncEntityType::View] {
+            KV::set_int(&strategy_key(entity, &user_id), settings.strategy(entity) as i64);
+        }
+        KV::set_int(&user_key(SYNC_TRIGGER, &user_id), settings.trigger as i64);
+        KV::set_int(
+            &user_key(SYNC_INTERVAL_SECONDS, &user_id),
+            settings.interval_seconds.max(0),
+        );
+        KV::set_int(
+            &user_key(SYNC_DEBOUNCE_MILLIS, &user_id),
+            settings.debounce_millis.max(0),
+        );
+        KV::set_bool(&user_key(SYNC_UNMETERED_ONLY, &user_id), settings.unmetered_only);
+        KV::set_int(
+            &user_key(SYNC_UPLOAD_BYTES_PER_SECOND, &user_id),
+            settings.upload_bytes_per_second.max(0),
+        );
+        KV::set_int(
+            &user_key(SYNC_DOWNLOAD_BYTES_PER_SECOND, &user_id),
+            settings.download_bytes_per_second.max(0),
+        );
+        KV::set_int(
+            &user_key(SYNC_BATCH_WINDOW_MILLIS, &user_id),
+            settings.batch_window_millis.max(0),
+        );
+        KV::set_bool(&user_key(SYNC_LAN, &user_id), settings.lan_sync);
+        Ok(())
+    }
+}
+
+fn strategy_key(entity: SyncEntityType, user_id: &str) -> String {
+    format!("{}_{:?}_{}", SYNC_CONFLICT_STRATEGY, entity, user_id)
+}
+
+fn user_key(key: &str, user_id: &str) -> String { format!("{}_{}", key, user_id) }
+
+// The entity whose local changes could collide with the change. The created
+// objects and the deltas of the documents don't.
+pub(crate) fn conflict_entity(ty: &RemoteChangeType) -> Option<SyncEntityType> {
+    match ty {
+        RemoteChangeType::UpdateWorkspace => Some(SyncEntityType::Workspace),
+        RemoteChangeType::UpdateApp => Some(SyncEntityType::App),
+        RemoteChangeType::UpdateView => Some(SyncEntityType::View),
+        _ => None,
+    }
+}
+
+// The local change is kept when both were made at the same time.
+pub(crate) fn resolve_conflict(strategy: ConflictStrategy, local_time: i64, remote_time: i64) -> ConflictResolution {
+    match strategy {
+        ConflictStrategy::PreferLocal => ConflictResolution::LocalKept,
+        ConflictStrategy::PreferRemote => ConflictResolution::RemoteApplied,
+        ConflictStrategy::LastWriterWins if remote_time > local_time => ConflictResolution::RemoteApplied,
+        ConflictStrategy::LastWriterWins => ConflictResolution::LocalKept,
+        ConflictStrategy::SurfaceToUser => ConflictResolution::Pending,
+    }
+}
+
+// Splits the remote update into the fields that weren't updated locally and the
+// ones that were, only the latter conflict. None for the part without fields.
+pub(crate) fn split_remote_update(
+    change: &RemoteChange,
+    local: &Operation,
+) -> WorkspaceResult<(Option<RemoteChange>, Option<RemoteChange>)> {
+    let mut rest = match Operation::from_remote_update(change)? {
+        Some(operation) => operation,
+        None => return Ok((Some(change.clone()), None)),
+    };
+    let conflicting = rest.take_fields_of(local);
+    let into_change = |operation: Operation| match operation.is_empty_update() {
+        true => Ok(None),
+        false => operation.into_remote_change(change).map(Some),
+    };
+    let conflicting = match conflicting {
+        Some(operation) => into_change(operation)?,
+        None => None,
+    };
+    Ok((into_change(rest)?, conflicting))
+}
+
+// The fields of the update that the remote one sets too, without any if there's
+// no update.
+pub(crate) fn fields_of_remote(update: Option<Operation>, remote: &Operation) -> Operation {
+    let mut empty = remote.clone();
+    let _ = empty.take_fields_of(remote);
+    update
+        .and_then(|mut update| update.take_fields_of(remote))
+        .unwrap_or(empty)
+}
+
+// The structured diff of the conflict, one item for each field that the remote
+// update sets.
+pub(crate) fn conflict_fields(base: &Operation, local: &Operation, remote: &Operation) -> Vec<ConflictField> {
+    let base_values = base.field_values().into_iter().collect::<HashMap<_, _>>();
+    let local_values = local.field_values().into_iter().collect::<HashMap<_, _>>();
+    remote
+        .field_values()
+        .into_iter()
+        .flat_map(|(name, remote)| {
+            Some(ConflictField {
+                name: name.to_owned(),
+                base: base_values.get(name).cloned().flatten(),
+                local: local_values.get(name).cloned().flatten().unwrap_or_default(),
+                remote: remote?,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,649 @@
+use crate::{
+    entities::{
+        app::{App, UpdateAppParams},
+        sync::*,
+        view::{UpdateViewParams, View},
+        workspace::{UpdateWorkspaceParams, Workspace},
+    },
+    errors::*,
+    module::{WorkspaceDatabase, WorkspaceUser},
+    notify::{send_dart_notification, WorkspaceNotification},
+    services::{
+        read_local_workspace_apps,
+        server::Server,
+        sync::{
+            conflict_entity,
+            conflict_fields,
+            exclusion_object_type,
+            fields_of_remote,
+            resolve_conflict,
+            split_remote_update,
+            sync_exclusion,
+            ExcludedTree,
+            SyncBackfillTask,
+            SyncScheduler,
+            SyncSettingsStore,
+            SyncThrottle,
+        },
+        AppController,
+        Operation,
+        OperationQueue,
+        PendingUpdate,
+        TrashCan,
+        ViewController,
+    },
+    sql_tables::{
+        app::AppTableSql,
+        sync::{
+            SqlSyncObjectType,
+            SyncConflictTable,
+            SyncConflictTableSql,
+            SyncExclusionTable,
+            SyncExclusionTableSql,
+            SyncRevTableSql,
+        },
+        workspace::{WorkspaceTableChangeset, WorkspaceTableSql},
+    },
+};
+use bytes::Bytes;
+use flowy_database::SqliteConnection;
+use flowy_document_infra::entities::doc::DocDelta;
+use std::{collections::HashMap, convert::TryFrom, sync::Arc, time::Instant};
+use tokio::sync::Mutex;
+
+// The local changes are recorded in the operations as they're made, whether the
+// server can be reached or not. The sync pulls the changes that the other
+// devices made since the last revision it applied, then sends the local ones.
+// The fields of a remote update that were updated locally too are resolved with
+// the strategy of their entity, see SyncSettings, the other ones are applied.
+// The revision of each object is saved in the transaction of its change, so a
+// change is only applied once even if the sync is interrupted. The delta of a
+// document is written with another connection, its revision is saved right
+// after.
+pub(crate) struct SyncEngine {
+    user: Arc<dyn WorkspaceUser>,
+    database: Arc<dyn WorkspaceDatabase>,
+    server: Server,
+    operations: OperationQueue,
+    app_controller: Arc<AppController>,
+    view_controller: Arc<ViewController>,
+    trash_can: Arc<TrashCan>,
+    settings: SyncSettingsStore,
+    scheduler: Arc<SyncScheduler>,
+    throttle: Arc<SyncThrottle>,
+    syncing: Mutex<()>,
+}
+
+impl SyncEngine {
+    pub(crate) fn new(
+        user: Arc<dyn WorkspaceUser>,
+        database: Arc<dyn WorkspaceDatabase>,
+        server: Server,
+        operations: OperationQueue,
+        app_controller: Arc<AppController>,
+        view_controller: Arc<ViewController>,
+        trash_can: Arc<TrashCan>,
+        scheduler: Arc<SyncScheduler>,
+        throttle: Arc<SyncThrottle>,
+    ) -> Self {
+        Self {
+            database,
+            server,
+            operations,
+            app_controller,
+            view_controller,
+            settings: SyncSettingsStore::new(user.clone()),
+            user,
+            trash_can,
+            scheduler,
+            throttle,
+            syncing: Mutex::new(()),
+        }
+    }
+
+    // The changes are pulled before the local ones are sent, the strategies decide
+    // which of the local ones are still sent when they conflict.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn sync(&self) -> WorkspaceResult<SyncResult> {
+        if !self.user.is_online() {
+            return Err(WorkspaceError::network_unreachable());
+        }
+        let _guard = self.syncing.lock().await;
+        let started_at = Instant::now();
+        self.scheduler.did_sync();
+        let token = self.user.token()?;
+        let rev_id = SyncRevTableSql::read_max_rev(&*self.database.db_connection()?)?;
+        let changes = self
+            .server
+            .pull_changes(&token, PullChangesParams { rev_id })
+            .await?
+            .into_inner();
+        self.throttle.download(changes_size(&changes)).await;
+        let pulled = self.apply_changes(changes).await?;
+        let pushed = self.operations.send_pending().await?;
+        let registry = flowy_metrics::registry();
+        registry.counter("sync_pulled_changes_total", &[]).add(pulled as u64);
+        registry.counter("sync_pushed_changes_total", &[]).add(pushed as u64);
+        registry
+            .histogram("sync_duration_ms", &[])
+            .observe_duration(started_at.elapsed());
+
+        let state = self.state()?;
+        self.notify_state(&state);
+        Ok(SyncResult {
+            pushed: pushed as i64,
+            pulled: pulled as i64,
+            state,
+        })
+    }
+
+    pub(crate) fn settings(&self) -> WorkspaceResult<SyncSettings> { self.settings.read() }
+
+    pub(crate) fn traffic(&self) -> SyncTraffic { self.throttle.traffic() }
+
+    // The trigger takes effect right away.
+    pub(crate) fn update_settings(&self, settings: SyncSettings) -> WorkspaceResult<()> {
+        let _ = self.settings.update(settings)?;
+        self.scheduler.wake();
+        Ok(())
+    }
+
+    pub(crate) async fn next_scheduled(&self) { self.scheduler.next().await }
+
+    pub(crate) fn did_reconnect(&self) { self.scheduler.did_reconnect() }
+
+    pub(crate) fn network_did_change(&self) { self.scheduler.wake() }
+
+    pub(crate) fn read_exclusions(&self) -> WorkspaceResult<RepeatedSyncExclusion> {
+        let tables = SyncExclusionTableSql::read_exclusions(&*self.database.db_connection()?)?;
+        let items = tables.into_iter().map(sync_exclusion).collect::<Vec<_>>();
+        Ok(RepeatedSyncExclusion { items })
+    }
+
+    // The changes made while excluded are kept in the operations, they're sent
+    // when the object is synced again and its local state is sent after them.
+    // The apps and views created in it still reach the server, it assigns their
+    // ids.
+    pub(crate) fn set_exclusion(&self, request: SyncExclusionRequest) -> WorkspaceResult<()> {
+        let ty = exclusion_object_type(request.entity)?;
+        let conn = &*self.database.db_write_connection()?;
+        if request.excluded {
+            let _ = self.check_exclusion_object(&request.object_id, ty, conn)?;
+            let table = SyncExclusionTable::new(&request.object_id, ty);
+            return SyncExclusionTableSql::save_exclusion(table, conn);
+        }
+
+        if !SyncExclusionTableSql::delete_exclusion(&request.object_id, conn)? {
+            return Ok(());
+        }
+        let task = SyncBackfillTask {
+            user: self.user.clone(),
+            database: self.database.clone(),
+            operations: self.operations.clone(),
+        };
+        let root = SyncExclusionTable::new(&request.object_id, ty);
+        tokio::spawn(async move { task.run(root).await });
+        Ok(())
+    }
+
+    fn check_exclusion_object(
+        &self,
+        object_id: &str,
+        ty: SqlSyncObjectType,
+        conn: &SqliteConnection,
+    ) -> WorkspaceResult<()> {
+        let exists = match ty {
+            SqlSyncObjectType::Workspace => {
+                let user_id = self.user.user_id()?;
+                !WorkspaceTableSql {}
+                    .read_workspaces(Some(object_id.to_owned()), &user_id, conn)?
+                    .is_empty()
+            },
+            _ => AppTableSql::read_app_links(conn)?
+                .iter()
+                .any(|(app_id, _, _)| app_id == object_id),
+        };
+        match exists {
+            true => Ok(()),
+            false => Err(WorkspaceError::record_not_found().context(format!("{} not found", object_id))),
+        }
+    }
+
+    pub(crate) fn state(&self) -> WorkspaceResult<SyncState> {
+        let pending_operations = self.operations.pending_count()?;
+        let rev_id = SyncRevTableSql::read_max_rev(&*self.database.db_connection()?)?;
+        Ok(SyncState {
+            pending_operations,
+            rev_id,
+        })
+    }
+
+    // The revisions of the opened documents count as pending too, they're sent by
+    // the document itself.
+    pub(crate) async fn object_status(
+        &self,
+        request: QuerySyncStatusRequest,
+    ) -> WorkspaceResult<RepeatedObjectSyncStatus> {
+        let mut items = self.operations.sync_status(&request.object_ids)?;
+        let excluded = ExcludedTree::read(&*self.database.db_connection()?)?;
+        for status in items.iter_mut() {
+            if excluded.contains(&status.object_id) {
+                status.ty = SyncStatusType::Excluded;
+                continue;
+            }
+            let revisions = self.view_controller.pending_revisions(&status.object_id).await as i64;
+            if revisions > 0 {
+                status.pending_operations += revisions;
+                if status.ty == SyncStatusType::Synced {
+                    status.ty = SyncStatusType::Pending;
+                }
+            }
+        }
+        Ok(RepeatedObjectSyncStatus { items })
+    }
+
+    // The changes that the server pushed while it's connected. The revisions
+    // between the last applied one and the first pushed one were missed, e.g.
+    // while the connection was lost, they're pulled with them.
+    pub(crate) async fn apply_pushed_changes(&self, mut changes: Vec<RemoteChange>) -> WorkspaceResult<usize> {
+        let _guard = self.syncing.lock().await;
+        self.throttle.did_download(changes_size(&changes));
+        let rev_id = SyncRevTableSql::read_max_rev(&*self.database.db_connection()?)?;
+        let first_rev_id = changes.iter().map(|change| change.rev_id).min();
+        if matches!(first_rev_id, Some(first_rev_id) if first_rev_id > rev_id + 1) {
+            let token = self.user.token()?;
+            let missed = self
+                .server
+                .pull_changes(&token, PullChangesParams { rev_id })
+                .await?
+                .into_inner();
+            self.throttle.download(changes_size(&missed)).await;
+            changes.extend(missed);
+        }
+        let applied = self.apply_changes(changes).await?;
+
+        let state = self.state()?;
+        self.notify_state(&state);
+        Ok(applied)
+    }
+
+    // The changes pulled from a device of the same account on the local network,
+    // in the order it made them. Their rev_id is their place in the log of that
+    // device, so the revisions of the objects are kept: the same changes are
+    // applied again when they're pulled from the server, with the same values.
+    // The local changes that weren't sent yet conflict with them like with the
+    // ones of the server.
+    pub(crate) async fn apply_peer_changes(&self, changes: Vec<RemoteChange>) -> WorkspaceResult<usize> {
+        let _guard = self.syncing.lock().await;
+        let mut pending = self.operations.pending_updates()?;
+        let mut excluded = ExcludedTree::read(&*self.database.db_connection()?)?;
+        let mut count = 0;
+        for mut change in changes {
+            if excluded.excludes(&change) {
+                continue;
+            }
+            change.rev_id = SyncRevTableSql::read_rev(&change.object_id, &*self.database.db_connection()?)?;
+            let object_id = change.object_id.clone();
+            match self.apply_change(change, &mut pending).await {
+                Ok(true) => count += 1,
+                Ok(false) => {},
+                Err(e) => log::error!("Skip the change of {} from the local network: {:?}", object_id, e),
+            }
+        }
+        if count > 0 {
+            let state = self.state()?;
+            self.notify_state(&state);
+        }
+        Ok(count)
+    }
+
+    // Returns how many changes were applied. The change that can't be applied is
+    // skipped, its revision is saved so it doesn't keep the ones after it from
+    // being pulled. The changes of the excluded objects are skipped the same way.
+    pub(crate) async fn apply_changes(&self, mut changes: Vec<RemoteChange>) -> WorkspaceResult<usize> {
+        changes.sort_by_key(|change| change.rev_id);
+        let mut pending = self.operations.pending_updates()?;
+        let mut excluded = ExcludedTree::read(&*self.database.db_connection()?)?;
+        let mut count = 0;
+        for change in changes {
+            let rev_id = change.rev_id;
+            let object_id = change.object_id.clone();
+            let ty = object_type(&change.ty);
+            let is_applied = {
+                let conn = self.database.db_connection()?;
+                SyncRevTableSql::read_rev(&object_id, &*conn)? >= rev_id
+            };
+            if is_applied {
+                continue;
+            }
+
+            if excluded.excludes(&change) {
+                log::debug!("Skip the remote change {} of the excluded {}", rev_id, object_id);
+            } else {
+                match self.apply_change(change, &mut pending).await {
+                    Ok(true) => count += 1,
+                    Ok(false) => {},
+                    Err(e) => log::error!("Skip the remote change {} of {}: {:?}", rev_id, object_id, e),
+                }
+            }
+            // It's already saved if the change was applied in a transaction.
+            let conn = self.database.db_write_connection()?;
+            let _ = SyncRevTableSql::save_rev(&object_id, ty, rev_id, &*conn)?;
+        }
+        Ok(count)
+    }
+
+    // Returns false if the change wasn't applied because of the local changes.
+    async fn apply_change(
+        &self,
+        change: RemoteChange,
+        pending: &mut HashMap<String, PendingUpdate>,
+    ) -> WorkspaceResult<bool> {
+        let entity = conflict_entity(&change.ty);
+        if let (Some(entity), Some(local)) = (entity, pending.get(&change.object_id).cloned()) {
+            let (rest, conflicting) = split_remote_update(&change, &local.operation)?;
+            let mut is_applied = false;
+            if let Some(rest) = rest {
+                let _ = self.apply_update(&rest)?;
+                is_applied = true;
+            }
+            if let Some(conflicting) = conflicting {
+                if self.resolve_with_strategy(entity, local.created_time, conflicting)? {
+                    // Some of the local fields were dropped.
+                    *pending = self.operations.pending_updates()?;
+                    is_applied = true;
+                }
+            }
+            return Ok(is_applied);
+        }
+
+        if change.ty == RemoteChangeType::DocDelta {
+            // The document writes its own tables with another connection.
+            let delta = DocDelta::try_from(Bytes::from(change.data)).map_err(internal_error)?;
+            let _ = self.view_controller.apply_remote_doc_delta(delta).await?;
+            return Ok(true);
+        }
+
+        let _ = self.apply_update(&change)?;
+        Ok(true)
+    }
+
+    // The fields were updated locally too, the strategy of their entity decides
+    // which values are kept. Returns true if the remote ones were applied.
+    fn resolve_with_strategy(
+        &self,
+        entity: SyncEntityType,
+        local_time: i64,
+        change: RemoteChange,
+    ) -> WorkspaceResult<bool> {
+        let strategy = self.settings.read()?.strategy(entity);
+        let resolution = resolve_conflict(strategy, local_time, change.modified_time);
+        let conn = &*self.database.db_write_connection()?;
+        let _ = conn.immediate_transaction::<_, WorkspaceError, _>(|| match resolution {
+            ConflictResolution::LocalKept | ConflictResolution::Merged => Ok(()),
+            ConflictResolution::Pending => {
+                SyncConflictTableSql::save_conflict(SyncConflictTable::new(change.clone(), local_time), conn)
+            },
+            ConflictResolution::RemoteApplied => self.apply_instead_of_local(&change, conn),
+        })?;
+
+        log::debug!(
+            "The change of {} conflicts, {:?} with {:?}",
+            change.object_id,
+            resolution,
+            strategy
+        );
+        flowy_metrics::registry()
+            .counter("sync_conflicts_total", &[("resolution", &format!("{:?}", resolution))])
+            .inc();
+        let mut conflict = SyncConflict {
+            object_id: change.object_id.clone(),
+            entity,
+            strategy,
+            resolution,
+            local_time,
+            remote_time: change.modified_time,
+            remote_change: change,
+            ..Default::default()
+        };
+        if resolution == ConflictResolution::Pending {
+            conflict = self.with_versions(conflict)?;
+        }
+        self.notify_conflict(conflict);
+        Ok(resolution == ConflictResolution::RemoteApplied)
+    }
+
+    pub(crate) fn read_conflicts(&self) -> WorkspaceResult<RepeatedSyncConflict> {
+        let tables = SyncConflictTableSql::read_conflicts(&*self.database.db_connection()?)?;
+        let items = tables
+            .into_iter()
+            .flat_map(pending_conflict)
+            .map(|conflict| self.with_versions(conflict))
+            .collect::<WorkspaceResult<Vec<_>>>()?;
+        Ok(RepeatedSyncConflict { items })
+    }
+
+    // The base and the local versions are read each time, the local changes
+    // made while the conflict waits for the user are in it.
+    fn with_versions(&self, mut conflict: SyncConflict) -> WorkspaceResult<SyncConflict> {
+        let remote = match Operation::from_remote_update(&conflict.remote_change)? {
+            Some(remote) => remote,
+            None => return Ok(conflict),
+        };
+        let local = self
+            .operations
+            .pending_updates()?
+            .remove(&conflict.object_id)
+            .map(|update| update.operation);
+        let local = fields_of_remote(local, &remote);
+        let base = self
+            .operations
+            .read_base(&conflict.object_id, &*self.database.db_connection()?)?;
+        let base = fields_of_remote(base, &remote);
+
+        conflict.fields = conflict_fields(&base, &local, &remote);
+        conflict.base = base.into_data()?;
+        conflict.local = local.into_data()?;
+        Ok(conflict)
+    }
+
+    // The submitted values replace both the local and the remote ones, they're
+    // applied and sent to the server like a local update. The remote values are
+    // their base.
+    pub(crate) async fn submit_conflict_resolution(
+        &self,
+        request: SubmitConflictResolutionRequest,
+    ) -> WorkspaceResult<()> {
+        let _guard = self.syncing.lock().await;
+        let table = SyncConflictTableSql::read_conflict(&request.object_id, &*self.database.db_connection()?)?
+            .ok_or_else(|| WorkspaceError::record_not_found().context("The conflict was resolved already"))?;
+        let mut conflict =
+            pending_conflict(table).ok_or_else(|| WorkspaceError::internal().context("Unknown conflict"))?;
+        let change = conflict.remote_change.clone();
+        let remote = Operation::from_remote_update(&change)?
+            .ok_or_else(|| WorkspaceError::internal().context("Unknown conflict"))?;
+
+        let local = self
+            .operations
+            .pending_updates()?
+            .remove(&request.object_id)
+            .map(|update| update.operation);
+        let mut merged = fields_of_remote(local, &remote);
+        let conflicting = remote
+            .field_values()
+            .into_iter()
+            .filter(|(_, value)| value.is_some())
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        for field in &request.fields {
+            if !conflicting.contains(&field.name.as_str()) {
+                return Err(WorkspaceError::conflict_field().context(format!("{} doesn't conflict", field.name)));
+            }
+            let _ = merged.set_field_value(&field.name, &field.value)?;
+        }
+
+        let conn = &*self.database.db_write_connection()?;
+        let _ = conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            let _ = self.operations.discard_fields(&remote, conn)?;
+            if !merged.is_empty_update() {
+                let bytes = Bytes::from(merged.clone().into_data()?);
+                let _ = self.apply_in_transaction(&change.object_id, &change.ty, bytes, conn)?;
+                let _ = self.operations.push_update(merged, remote, conn)?;
+            }
+            let _ = SyncConflictTableSql::delete_conflict(&request.object_id, conn)?;
+            SyncRevTableSql::save_rev(&change.object_id, object_type(&change.ty), change.rev_id, conn)
+        })?;
+
+        conflict.resolution = ConflictResolution::Merged;
+        self.notify_conflict(conflict);
+        self.operations.flush();
+        Ok(())
+    }
+
+    // The local changes are sent once the user kept them, or dropped if the
+    // remote change was picked.
+    pub(crate) async fn resolve_pending_conflict(&self, request: ResolveConflictRequest) -> WorkspaceResult<()> {
+        let _guard = self.syncing.lock().await;
+        let conn = &*self.database.db_write_connection()?;
+        let mut conflict = conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            let table = SyncConflictTableSql::read_conflict(&request.object_id, conn)?
+                .ok_or_else(|| WorkspaceError::record_not_found().context("The conflict was resolved already"))?;
+            let conflict = pending_conflict(table.clone())
+                .ok_or_else(|| WorkspaceError::internal().context("Unknown conflict"))?;
+            if !request.keep_local {
+                let _ = self.apply_instead_of_local(&conflict.remote_change, conn)?;
+            }
+            let _ = SyncConflictTableSql::delete_conflict(&request.object_id, conn)?;
+            Ok(conflict)
+        })?;
+
+        conflict.resolution = match request.keep_local {
+            true => ConflictResolution::LocalKept,
+            false => ConflictResolution::RemoteApplied,
+        };
+        self.notify_conflict(conflict);
+        if request.keep_local {
+            self.operations.flush();
+        }
+        Ok(())
+    }
+
+    // The revision is saved in the same transaction.
+    fn apply_update(&self, change: &RemoteChange) -> WorkspaceResult<()> {
+        let conn = &*self.database.db_write_connection()?;
+        conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            let bytes = Bytes::from(change.data.clone());
+            let _ = self.apply_in_transaction(&change.object_id, &change.ty, bytes, conn)?;
+            SyncRevTableSql::save_rev(&change.object_id, object_type(&change.ty), change.rev_id, conn)
+        })
+    }
+
+    fn apply_instead_of_local(&self, change: &RemoteChange, conn: &SqliteConnection) -> WorkspaceResult<()> {
+        if let Some(remote) = Operation::from_remote_update(change)? {
+            let _ = self.operations.discard_fields(&remote, conn)?;
+        }
+        let bytes = Bytes::from(change.data.clone());
+        let _ = self.apply_in_transaction(&change.object_id, &change.ty, bytes, conn)?;
+        SyncRevTableSql::save_rev(&change.object_id, object_type(&change.ty), change.rev_id, conn)
+    }
+
+    fn apply_in_transaction(
+        &self,
+        object_id: &str,
+        ty: &RemoteChangeType,
+        bytes: Bytes,
+        conn: &SqliteConnection,
+    ) -> WorkspaceResult<()> {
+        match ty {
+            RemoteChangeType::UpdateWorkspace => {
+                let params = UpdateWorkspaceParams::try_from(bytes).map_err(internal_error)?;
+                self.apply_workspace_update(params, conn)
+            },
+            RemoteChangeType::CreateApp => {
+                let app = App::try_from(bytes).map_err(internal_error)?;
+                self.app_controller.apply_remote_create(app, conn)
+            },
+            RemoteChangeType::UpdateApp => {
+                let params = UpdateAppParams::try_from(bytes).map_err(internal_error)?;
+                self.app_controller.apply_remote_update(params, conn)
+            },
+            RemoteChangeType::CreateView => {
+                let view = View::try_from(bytes).map_err(internal_error)?;
+                self.view_controller.apply_remote_create(view, conn)
+            },
+            RemoteChangeType::UpdateView => {
+                let params = UpdateViewParams::try_from(bytes).map_err(internal_error)?;
+                self.view_controller.apply_remote_update(params, conn)
+            },
+            RemoteChangeType::DocDelta | RemoteChangeType::Unknown => {
+                Err(WorkspaceError::internal().context(format!("Unknown remote change of {}", object_id)))
+            },
+        }
+    }
+
+    fn apply_workspace_update(&self, params: UpdateWorkspaceParams, conn: &SqliteConnection) -> WorkspaceResult<()> {
+        let workspace_sql = WorkspaceTableSql {};
+        let changeset = WorkspaceTableChangeset::new(params);
+        let workspace_id = changeset.id.clone();
+        let _ = workspace_sql.update_workspace(changeset, conn)?;
+
+        let user_id = self.user.user_id()?;
+        let table = workspace_sql
+            .read_workspaces(Some(workspace_id.clone()), &user_id, conn)?
+            .pop()
+            .ok_or_else(|| {
+                WorkspaceError::record_not_found().context(format!("{} workspace not found", workspace_id))
+            })?;
+        let mut workspace: Workspace = table.into();
+        workspace.apps = read_local_workspace_apps(&workspace_id, self.trash_can.clone(), conn)?;
+        send_dart_notification(&workspace_id, WorkspaceNotification::WorkspaceUpdated)
+            .payload(workspace)
+            .send();
+        Ok(())
+    }
+
+    fn notify_conflict(&self, conflict: SyncConflict) {
+        match self.user.user_id() {
+            Ok(user_id) => send_dart_notification(&user_id, WorkspaceNotification::SyncConflictReported)
+                .payload(conflict)
+                .send(),
+            Err(e) => log::error!("Notify the sync conflict failed: {:?}", e),
+        }
+    }
+
+    fn notify_state(&self, state: &SyncState) {
+        match self.user.user_id() {
+            Ok(user_id) => send_dart_notification(&user_id, WorkspaceNotification::SyncStateChanged)
+                .payload(state.clone())
+                .send(),
+            Err(e) => log::error!("Notify the sync state failed: {:?}", e),
+        }
+    }
+}
+
+// The conflicts that wait for the user were resolved with SurfaceToUser.
+fn pending_conflict(table: SyncConflictTable) -> Option<SyncConflict> {
+    let remote_change = table.remote_change();
+    Some(SyncConflict {
+        object_id: table.object_id,
+        entity: conflict_entity(&remote_change.ty)?,
+        strategy: ConflictStrategy::SurfaceToUser,
+        resolution: ConflictResolution::Pending,
+        local_time: table.local_time,
+        remote_time: table.remote_time,
+        remote_change,
+        ..Default::default()
+    })
+}
+
+fn changes_size(changes: &[RemoteChange]) -> usize { changes.iter().map(|change| change.data.len()).sum() }
+
+fn object_type(ty: &RemoteChangeType) -> SqlSyncObjectType {
+    match ty {
+        RemoteChangeType::UpdateWorkspace => SqlSyncObjectType::Workspace,
+        RemoteChangeType::CreateApp | RemoteChangeType::UpdateApp => SqlSyncObjectType::App,
+        RemoteChangeType::CreateView | RemoteChangeType::UpdateView => SqlSyncObjectType::View,
+        RemoteChangeType::DocDelta => SqlSyncObjectType::Doc,
+        RemoteChangeType::Unknown => SqlSyncObjectType::Unknown,
+    }
+}
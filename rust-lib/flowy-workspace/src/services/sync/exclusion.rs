@@ -0,0 +1,211 @@
+use crate::{
+    entities::{
+        app::App,
+        sync::{RemoteChange, RemoteChangeType, SyncBackfill, SyncEntityType, SyncExclusion},
+        view::View,
+    },
+    errors::*,
+    module::{WorkspaceDatabase, WorkspaceUser},
+    notify::{send_dart_notification, WorkspaceNotification},
+    services::{Operation, OperationQueue},
+    sql_tables::{
+        app::AppTableSql,
+        sync::{SqlSyncObjectType, SyncExclusionTable, SyncExclusionTableSql},
+        view::ViewTableSql,
+        workspace::WorkspaceTableSql,
+    },
+};
+use bytes::Bytes;
+use flowy_database::SqliteConnection;
+use std::{collections::HashSet, convert::TryFrom, sync::Arc};
+
+// The workspaces and apps with everything in them: their apps, their views and
+// the views of these views, the ones in the trash too.
+#[derive(Default)]
+pub(crate) struct ExcludedTree {
+    workspace_ids: HashSet<String>,
+    app_ids: HashSet<String>,
+    view_ids: HashSet<String>,
+}
+
+impl ExcludedTree {
+    pub(crate) fn read(conn: &SqliteConnection) -> WorkspaceResult<Self> {
+        let exclusions = SyncExclusionTableSql::read_exclusions(conn)?;
+        Self::read_roots(&exclusions, conn)
+    }
+
+    fn read_roots(roots: &[SyncExclusionTable], conn: &SqliteConnection) -> WorkspaceResult<Self> {
+        let mut tree = ExcludedTree::default();
+        if roots.is_empty() {
+            return Ok(tree);
+        }
+        for root in roots {
+            match root.ty {
+                SqlSyncObjectType::Workspace => {
+                    tree.workspace_ids.insert(root.object_id.clone());
+                },
+                SqlSyncObjectType::App => {
+                    tree.app_ids.insert(root.object_id.clone());
+                },
+                _ => {},
+            }
+        }
+        for (app_id, workspace_id, _) in AppTableSql::read_app_links(conn)? {
+            if tree.workspace_ids.contains(&workspace_id) {
+                tree.app_ids.insert(app_id);
+            }
+        }
+        // The views that belong to the ones that were found, until none is left.
+        let mut belongings = ViewTableSql::read_belongings(conn)?;
+        loop {
+            let (found, rest): (Vec<_>, Vec<_>) = belongings.into_iter().partition(|(_, belong_to_id)| {
+                tree.app_ids.contains(belong_to_id) || tree.view_ids.contains(belong_to_id)
+            });
+            if found.is_empty() {
+                break;
+            }
+            tree.view_ids.extend(found.into_iter().map(|(view_id, _)| view_id));
+            belongings = rest;
+        }
+        Ok(tree)
+    }
+
+    pub(crate) fn contains(&self, object_id: &str) -> bool {
+        self.workspace_ids.contains(object_id) || self.app_ids.contains(object_id) || self.view_ids.contains(object_id)
+    }
+
+    pub(crate) fn object_ids(&self) -> impl Iterator<Item = &String> {
+        self.workspace_ids
+            .iter()
+            .chain(self.app_ids.iter())
+            .chain(self.view_ids.iter())
+    }
+
+    // The apps and views created remotely in the excluded objects are excluded
+    // too, so their changes that come after are skipped.
+    pub(crate) fn excludes(&mut self, change: &RemoteChange) -> bool {
+        let bytes = Bytes::from(change.data.clone());
+        match change.ty {
+            RemoteChangeType::CreateApp => match App::try_from(bytes) {
+                Ok(app) if self.workspace_ids.contains(&app.workspace_id) => {
+                    self.app_ids.insert(app.id);
+                    true
+                },
+                _ => false,
+            },
+            RemoteChangeType::CreateView => match View::try_from(bytes) {
+                Ok(view) if self.contains(&view.belong_to_id) => {
+                    self.view_ids.insert(view.id);
+                    true
+                },
+                _ => false,
+            },
+            // The document of a view has the same id.
+            _ => self.contains(&change.object_id),
+        }
+    }
+
+    fn remove(&mut self, other: &ExcludedTree) {
+        self.workspace_ids.retain(|id| !other.workspace_ids.contains(id));
+        self.app_ids.retain(|id| !other.app_ids.contains(id));
+        self.view_ids.retain(|id| !other.view_ids.contains(id));
+    }
+}
+
+pub(crate) fn exclusion_object_type(entity: SyncEntityType) -> WorkspaceResult<SqlSyncObjectType> {
+    match entity {
+        SyncEntityType::Workspace => Ok(SqlSyncObjectType::Workspace),
+        SyncEntityType::App => Ok(SqlSyncObjectType::App),
+        SyncEntityType::View => Err(WorkspaceError::invalid_view_id()
+            .context("Only the workspaces and the apps can be excluded from the sync, the views are with their app")),
+    }
+}
+
+pub(crate) fn sync_exclusion(table: SyncExclusionTable) -> SyncExclusion {
+    let entity = match table.ty {
+        SqlSyncObjectType::Workspace => SyncEntityType::Workspace,
+        SqlSyncObjectType::App => SyncEntityType::App,
+        _ => SyncEntityType::View,
+    };
+    SyncExclusion {
+        object_id: table.object_id,
+        entity,
+        exclude_time: table.create_time,
+    }
+}
+
+// Sends the local state of the object that is synced again, the changes of the
+// other devices that were skipped while it was excluded are replaced by it.
+// The operations that were kept are sent first, they include the trash and
+// the deletes that an update can't tell.
+pub(crate) struct SyncBackfillTask {
+    pub(crate) user: Arc<dyn WorkspaceUser>,
+    pub(crate) database: Arc<dyn WorkspaceDatabase>,
+    pub(crate) operations: OperationQueue,
+}
+
+impl SyncBackfillTask {
+    pub(crate) async fn run(self, root: SyncExclusionTable) {
+        let mut progress = SyncBackfill {
+            object_id: root.object_id.clone(),
+            ..Default::default()
+        };
+        if let Err(e) = self.backfill(root, &mut progress).await {
+            log::error!("Backfill {} failed: {:?}", progress.object_id, e);
+            progress.error = e.msg;
+            self.notify(&progress);
+        }
+    }
+
+    async fn backfill(&self, root: SyncExclusionTable, progress: &mut SyncBackfill) -> WorkspaceResult<()> {
+        let updates = self.read_updates(root)?;
+        progress.total = updates.len() as i64;
+        self.notify(progress);
+        if updates.is_empty() {
+            return Ok(());
+        }
+
+        let _ = self.operations.send_pending().await?;
+        let token = self.user.token()?;
+        for update in updates {
+            let _ = self.operations.send_now(&token, update).await?;
+            progress.done += 1;
+            self.notify(progress);
+        }
+        Ok(())
+    }
+
+    // The objects that another exclusion still covers are left out.
+    fn read_updates(&self, root: SyncExclusionTable) -> WorkspaceResult<Vec<Operation>> {
+        let user_id = self.user.user_id()?;
+        let conn = &*self.database.db_connection()?;
+        let mut tree = ExcludedTree::read_roots(&[root], conn)?;
+        tree.remove(&ExcludedTree::read(conn)?);
+
+        let mut updates = vec![];
+        let workspace_sql = WorkspaceTableSql {};
+        for workspace_id in &tree.workspace_ids {
+            if let Some(table) = workspace_sql
+                .read_workspaces(Some(workspace_id.clone()), &user_id, conn)?
+                .pop()
+            {
+                updates.push(Operation::UpdateWorkspace(table.current_fields()));
+            }
+        }
+        for app_id in &tree.app_ids {
+            let table = AppTableSql::read_app(app_id, conn)?;
+            updates.push(Operation::UpdateApp(table.current_fields()));
+        }
+        let view_ids = tree.view_ids.into_iter().collect::<Vec<_>>();
+        for table in ViewTableSql::read_views_with_ids(&view_ids, conn)? {
+            updates.push(Operation::UpdateView(table.current_fields()));
+        }
+        Ok(updates)
+    }
+
+    fn notify(&self, progress: &SyncBackfill) {
+        send_dart_notification(&progress.object_id, WorkspaceNotification::SyncBackfillProgress)
+            .payload(progress.clone())
+            .send();
+    }
+}
@@ -0,0 +1,389 @@
+use crate::{
+    entities::sync::{
+        LanHello,
+        LanMessage,
+        LanMessageType,
+        LanPeer,
+        LanPullRequest,
+        RemoteChange,
+        RepeatedLanPeer,
+        RepeatedRemoteChange,
+    },
+    errors::*,
+    module::{WorkspaceDatabase, WorkspaceUser},
+    notify::{send_dart_notification, WorkspaceNotification},
+    services::{
+        sync::{bind_mdns_socket, decode_packet, encode_announce, encode_query, mdns_group, LanAnnounce, MdnsPacket},
+        OperationQueue,
+        SyncEngine,
+    },
+    sql_tables::sync::{LanChangeTableSql, LanPeerTable, LanPeerTableSql},
+};
+use bytes::Bytes;
+use flowy_document_infra::util::md5;
+use flowy_infra::kv::KV;
+use parking_lot::Mutex;
+use std::{
+    collections::HashSet,
+    convert::{TryFrom, TryInto},
+    net::SocketAddr,
+    sync::Arc,
+    time::Duration,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream, UdpSocket},
+    task::JoinHandle,
+};
+
+const LAN_DEVICE_ID: &str = "lan_device_id";
+const LAN_ANNOUNCE_INTERVAL: Duration = Duration::from_secs(5);
+const LAN_PULL_LIMIT: usize = 500;
+const MAX_LAN_MESSAGE_SIZE: usize = 64 * 1024 * 1024;
+
+// Syncs the changes with the devices of the same account on the local network,
+// without the server, see SyncSettings::lan_sync. The devices are found with
+// mDNS, each one announces the last change in its log and the others pull the
+// ones after what they pulled from it over TCP. Only the updates of the
+// workspaces, the apps and the views made while it runs are in the log, they
+// go through the conflict handling of the engine like the remote changes. The
+// devices are trusted once they tell the same account, the local network is.
+pub(crate) struct LanSync {
+    user: Arc<dyn WorkspaceUser>,
+    database: Arc<dyn WorkspaceDatabase>,
+    operations: OperationQueue,
+    engine: Arc<SyncEngine>,
+    tasks: Mutex<Vec<JoinHandle<()>>>,
+}
+
+impl LanSync {
+    pub(crate) fn new(
+        user: Arc<dyn WorkspaceUser>,
+        database: Arc<dyn WorkspaceDatabase>,
+        operations: OperationQueue,
+        engine: Arc<SyncEngine>,
+    ) -> Self {
+        Self {
+            user,
+            database,
+            operations,
+            engine,
+            tasks: Mutex::new(vec![]),
+        }
+    }
+
+    // The log isn't kept while it's disabled, the devices get the changes made
+    // meanwhile from the server.
+    pub(crate) fn update(&self, enabled: bool) -> WorkspaceResult<()> {
+        if enabled {
+            return self.start();
+        }
+        if self.stop() {
+            let _ = LanChangeTableSql::delete_changes(&*self.database.db_write_connection()?)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn start(&self) -> WorkspaceResult<()> {
+        let mut tasks = self.tasks.lock();
+        if !tasks.is_empty() {
+            return Ok(());
+        }
+        let listener = std::net::TcpListener::bind("0.0.0.0:0").map_err(internal_error)?;
+        let _ = listener.set_nonblocking(true).map_err(internal_error)?;
+        let port = listener.local_addr().map_err(internal_error)?.port();
+        let listener = TcpListener::from_std(listener).map_err(internal_error)?;
+        let socket = Arc::new(bind_mdns_socket().map_err(internal_error)?);
+        let node = Arc::new(LanNode {
+            user: self.user.clone(),
+            database: self.database.clone(),
+            engine: self.engine.clone(),
+            account: md5(self.user.user_id()?),
+            device_id: lan_device_id(),
+            port,
+            pulling: Mutex::new(HashSet::new()),
+        });
+
+        self.operations.set_lan_logging(true);
+        tasks.push(tokio::spawn(accept_peers(node.clone(), listener)));
+        tasks.push(tokio::spawn(announce(node.clone(), socket.clone())));
+        tasks.push(tokio::spawn(discover_peers(node, socket)));
+        log::debug!("LAN sync listens on port {}", port);
+        Ok(())
+    }
+
+    // Returns false if it wasn't running. The pulls that started finish.
+    pub(crate) fn stop(&self) -> bool {
+        let tasks = std::mem::take(&mut *self.tasks.lock());
+        self.operations.set_lan_logging(false);
+        for task in &tasks {
+            task.abort();
+        }
+        !tasks.is_empty()
+    }
+
+    pub(crate) fn read_peers(&self) -> WorkspaceResult<RepeatedLanPeer> {
+        read_lan_peers(&*self.database.db_connection()?)
+    }
+}
+
+// The id that the other devices know this one by, it's kept for all the users.
+fn lan_device_id() -> String {
+    match KV::get_str(LAN_DEVICE_ID) {
+        Some(device_id) => device_id,
+        None => {
+            let device_id = flowy_infra::uuid();
+            KV::set_str(LAN_DEVICE_ID, device_id.clone());
+            device_id
+        },
+    }
+}
+
+fn read_lan_peers(conn: &flowy_database::SqliteConnection) -> WorkspaceResult<RepeatedLanPeer> {
+    let items = LanPeerTableSql::read_peers(conn)?
+        .into_iter()
+        .map(LanPeer::from)
+        .collect::<Vec<_>>();
+    Ok(RepeatedLanPeer { items })
+}
+
+struct LanNode {
+    user: Arc<dyn WorkspaceUser>,
+    database: Arc<dyn WorkspaceDatabase>,
+    engine: Arc<SyncEngine>,
+    account: String,
+    device_id: String,
+    port: u16,
+    // The devices that are pulled from, one pull each at a time.
+    pulling: Mutex<HashSet<String>>,
+}
+
+impl LanNode {
+    fn announcement(&self) -> WorkspaceResult<Vec<u8>> {
+        let seq = LanChangeTableSql::read_last_seq(&*self.database.db_connection()?)?;
+        Ok(encode_announce(&LanAnnounce {
+            account: self.account.clone(),
+            device_id: self.device_id.clone(),
+            port: self.port,
+            seq,
+        }))
+    }
+
+    // Returns the address and what was pulled from it, None if it isn't a device
+    // of the same account.
+    fn did_find(&self, announce: &LanAnnounce, from: SocketAddr) -> WorkspaceResult<Option<(String, i64)>> {
+        if announce.account != self.account || announce.device_id == self.device_id {
+            return Ok(None);
+        }
+        let address = SocketAddr::new(from.ip(), announce.port).to_string();
+        let conn = &*self.database.db_write_connection()?;
+        let peer = LanPeerTableSql::read_peer(&announce.device_id, conn)?;
+        let pulled_seq = peer.as_ref().map(|peer| peer.pulled_seq).unwrap_or(0);
+        let _ = LanPeerTableSql::save_peer(LanPeerTable::new(&announce.device_id, &address, pulled_seq), conn)?;
+        if peer.map(|peer| peer.address) != Some(address.clone()) {
+            self.notify_peers(conn);
+        }
+        Ok(Some((address, pulled_seq)))
+    }
+
+    // Serves the pulls of the other device until it closes the connection.
+    async fn serve(&self, mut stream: TcpStream) -> WorkspaceResult<()> {
+        let _ = self.handshake(&mut stream).await?;
+        while let Some(message) = read_message(&mut stream).await? {
+            if message.ty != LanMessageType::Pull {
+                return Err(WorkspaceError::internal().context(format!("Unexpected {:?} message", message.ty)));
+            }
+            let request = LanPullRequest::try_from(Bytes::from(message.data)).map_err(internal_error)?;
+            let items = LanChangeTableSql::read_changes_after(
+                request.seq,
+                LAN_PULL_LIMIT as i64,
+                &*self.database.db_connection()?,
+            )?
+            .into_iter()
+            .map(|table| table.remote_change())
+            .collect::<Vec<_>>();
+            let data: Bytes = RepeatedRemoteChange { items }.try_into().map_err(internal_error)?;
+            let _ = write_message(&mut stream, LanMessageType::Changes, data.to_vec()).await?;
+        }
+        Ok(())
+    }
+
+    // Pulls the changes after the ones that were pulled already, what was
+    // pulled is saved after each batch so the next pull goes on from there.
+    async fn pull(&self, device_id: &str, address: &str, mut seq: i64) -> WorkspaceResult<usize> {
+        let mut stream = TcpStream::connect(address).await.map_err(internal_error)?;
+        let hello = self.handshake(&mut stream).await?;
+        if hello.device_id != device_id {
+            return Err(WorkspaceError::internal().context(format!("{} isn't {}", address, device_id)));
+        }
+
+        let mut count = 0;
+        loop {
+            let data: Bytes = LanPullRequest { seq }.try_into().map_err(internal_error)?;
+            let _ = write_message(&mut stream, LanMessageType::Pull, data.to_vec()).await?;
+            let changes = match read_message(&mut stream).await? {
+                Some(message) if message.ty == LanMessageType::Changes => {
+                    RepeatedRemoteChange::try_from(Bytes::from(message.data))
+                        .map_err(internal_error)?
+                        .into_inner()
+                },
+                _ => return Err(WorkspaceError::internal().context(format!("{} closed the pull", address))),
+            };
+            let size = changes.len();
+            let last_seq = match changes.iter().map(|change| change.rev_id).max() {
+                Some(last_seq) if last_seq > seq => last_seq,
+                _ => break,
+            };
+            count += self.engine.apply_peer_changes(sorted_by_seq(changes)).await?;
+            seq = last_seq;
+            let conn = &*self.database.db_write_connection()?;
+            let _ = LanPeerTableSql::save_peer(LanPeerTable::new(device_id, address, seq), conn)?;
+            if size < LAN_PULL_LIMIT {
+                break;
+            }
+        }
+        self.notify_peers(&*self.database.db_connection()?);
+        Ok(count)
+    }
+
+    // Both sides tell who they are first, the devices of other accounts are
+    // turned away.
+    async fn handshake(&self, stream: &mut TcpStream) -> WorkspaceResult<LanHello> {
+        let hello = LanHello {
+            account: self.account.clone(),
+            device_id: self.device_id.clone(),
+        };
+        let data: Bytes = hello.try_into().map_err(internal_error)?;
+        let _ = write_message(stream, LanMessageType::Hello, data.to_vec()).await?;
+        let hello = match read_message(stream).await? {
+            Some(message) if message.ty == LanMessageType::Hello => {
+                LanHello::try_from(Bytes::from(message.data)).map_err(internal_error)?
+            },
+            _ => return Err(WorkspaceError::internal().context("The device didn't say hello")),
+        };
+        if hello.account != self.account || hello.device_id == self.device_id {
+            return Err(WorkspaceError::unauthorized().context("The device is of another account"));
+        }
+        Ok(hello)
+    }
+
+    fn notify_peers(&self, conn: &flowy_database::SqliteConnection) {
+        let result = self
+            .user
+            .user_id()
+            .and_then(|user_id| Ok((user_id, read_lan_peers(conn)?)));
+        match result {
+            Ok((user_id, peers)) => send_dart_notification(&user_id, WorkspaceNotification::LanPeersChanged)
+                .payload(peers)
+                .send(),
+            Err(e) => log::error!("Notify the LAN peers failed: {:?}", e),
+        }
+    }
+}
+
+async fn accept_peers(node: Arc<LanNode>, listener: TcpListener) {
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                log::error!("Accept the LAN peer failed: {:?}", e);
+                continue;
+            },
+        };
+        let node = node.clone();
+        tokio::spawn(async move {
+            if let Err(e) = node.serve(stream).await {
+                log::warn!("Serve the LAN peer failed: {:?}", e);
+            }
+        });
+    }
+}
+
+// The devices that run already are asked to announce themselves once, this one
+// announces the last change in its log periodically after.
+async fn announce(node: Arc<LanNode>, socket: Arc<UdpSocket>) {
+    if let Err(e) = socket.send_to(&encode_query(), mdns_group()).await {
+        log::error!("Query the LAN peers failed: {:?}", e);
+    }
+    loop {
+        match node.announcement() {
+            Ok(packet) => {
+                if let Err(e) = socket.send_to(&packet, mdns_group()).await {
+                    log::error!("Announce to the LAN peers failed: {:?}", e);
+                }
+            },
+            Err(e) => log::error!("Read the LAN log failed: {:?}", e),
+        }
+        tokio::time::sleep(LAN_ANNOUNCE_INTERVAL).await;
+    }
+}
+
+async fn discover_peers(node: Arc<LanNode>, socket: Arc<UdpSocket>) {
+    let mut buf = vec![0; 9000];
+    loop {
+        let (len, from) = match socket.recv_from(&mut buf).await {
+            Ok(received) => received,
+            Err(e) => {
+                log::error!("Receive the mDNS packet failed: {:?}", e);
+                continue;
+            },
+        };
+        match decode_packet(&buf[..len]) {
+            MdnsPacket::Query => {
+                if let Ok(packet) = node.announcement() {
+                    let _ = socket.send_to(&packet, mdns_group()).await;
+                }
+            },
+            MdnsPacket::Announce(announce) => match node.did_find(&announce, from) {
+                Ok(Some((address, pulled_seq))) if announce.seq > pulled_seq => {
+                    spawn_pull(node.clone(), announce.device_id, address, pulled_seq);
+                },
+                Ok(_) => {},
+                Err(e) => log::error!("Save the LAN peer failed: {:?}", e),
+            },
+            MdnsPacket::Other => {},
+        }
+    }
+}
+
+fn spawn_pull(node: Arc<LanNode>, device_id: String, address: String, pulled_seq: i64) {
+    if !node.pulling.lock().insert(device_id.clone()) {
+        return;
+    }
+    tokio::spawn(async move {
+        match node.pull(&device_id, &address, pulled_seq).await {
+            Ok(count) => log::debug!("Applied {} changes from the LAN peer {}", count, device_id),
+            Err(e) => log::warn!("Pull from the LAN peer {} failed: {:?}", device_id, e),
+        }
+        node.pulling.lock().remove(&device_id);
+    });
+}
+
+fn sorted_by_seq(mut changes: Vec<RemoteChange>) -> Vec<RemoteChange> {
+    changes.sort_by_key(|change| change.rev_id);
+    changes
+}
+
+// Each message is sent with its length first.
+async fn write_message(stream: &mut TcpStream, ty: LanMessageType, data: Vec<u8>) -> WorkspaceResult<()> {
+    let bytes: Bytes = LanMessage { ty, data }.try_into().map_err(internal_error)?;
+    let _ = stream.write_u32(bytes.len() as u32).await.map_err(internal_error)?;
+    let _ = stream.write_all(&bytes).await.map_err(internal_error)?;
+    Ok(())
+}
+
+// None once the other side closed the connection.
+async fn read_message(stream: &mut TcpStream) -> WorkspaceResult<Option<LanMessage>> {
+    let len = match stream.read_u32().await {
+        Ok(len) => len as usize,
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(internal_error(e)),
+    };
+    if len > MAX_LAN_MESSAGE_SIZE {
+        return Err(WorkspaceError::internal().context(format!("The message of {} bytes is too large", len)));
+    }
+    let mut data = vec![0; len];
+    let _ = stream.read_exact(&mut data).await.map_err(internal_error)?;
+    let message = LanMessage::try_from(Bytes::from(data)).map_err(internal_error)?;
+    Ok(Some(message))
+}
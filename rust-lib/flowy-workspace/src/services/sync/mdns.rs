@@ -0,0 +1,239 @@
+use socket2::{Domain, Protocol, Socket, Type};
+use std::{
+    collections::HashMap,
+    io,
+    net::{Ipv4Addr, SocketAddr, SocketAddrV4},
+};
+use tokio::net::UdpSocket;
+
+const MDNS_ADDR: Ipv4Addr = Ipv4Addr::new(224, 0, 0, 251);
+const MDNS_PORT: u16 = 5353;
+pub(crate) const LAN_SERVICE: &str = "_flowy-sync._tcp.local";
+
+const TYPE_PTR: u16 = 12;
+const TYPE_TXT: u16 = 16;
+const TYPE_SRV: u16 = 33;
+const CLASS_IN: u16 = 1;
+const RECORD_TTL: u32 = 120;
+
+// The other responders on the host, e.g. the one of the system, keep the port
+// too.
+pub(crate) fn bind_mdns_socket() -> io::Result<UdpSocket> {
+    let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
+    let _ = socket.set_reuse_address(true)?;
+    #[cfg(unix)]
+    let _ = socket.set_reuse_port(true)?;
+    let _ = socket.bind(&SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, MDNS_PORT).into())?;
+    let _ = socket.join_multicast_v4(&MDNS_ADDR, &Ipv4Addr::UNSPECIFIED)?;
+    let _ = socket.set_multicast_loop_v4(true)?;
+    let _ = socket.set_nonblocking(true)?;
+    UdpSocket::from_std(socket.into())
+}
+
+pub(crate) fn mdns_group() -> SocketAddr { SocketAddrV4::new(MDNS_ADDR, MDNS_PORT).into() }
+
+// What a device tells the others about itself, in the TXT record of its
+// service. Its ip is the source of the packet, there's no A record.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct LanAnnounce {
+    pub(crate) account: String,
+    pub(crate) device_id: String,
+    pub(crate) port: u16,
+    // The last change in its log.
+    pub(crate) seq: i64,
+}
+
+pub(crate) enum MdnsPacket {
+    // The devices that run the service are asked to announce themselves.
+    Query,
+    Announce(LanAnnounce),
+    Other,
+}
+
+pub(crate) fn encode_query() -> Vec<u8> {
+    let mut packet = header(0, 1, 0);
+    write_name(&mut packet, LAN_SERVICE);
+    write_u16(&mut packet, TYPE_PTR);
+    write_u16(&mut packet, CLASS_IN);
+    packet
+}
+
+pub(crate) fn encode_announce(announce: &LanAnnounce) -> Vec<u8> {
+    let instance = format!("{}.{}", announce.device_id, LAN_SERVICE);
+    let mut packet = header(0x8400, 0, 3);
+
+    let mut ptr = vec![];
+    write_name(&mut ptr, &instance);
+    write_record(&mut packet, LAN_SERVICE, TYPE_PTR, &ptr);
+
+    // The priority and the weight come before the port.
+    let mut srv = vec![];
+    write_u16(&mut srv, 0);
+    write_u16(&mut srv, 0);
+    write_u16(&mut srv, announce.port);
+    write_name(&mut srv, &format!("{}.local", announce.device_id));
+    write_record(&mut packet, &instance, TYPE_SRV, &srv);
+
+    let mut txt = vec![];
+    for entry in [
+        format!("account={}", announce.account),
+        format!("device={}", announce.device_id),
+        format!("seq={}", announce.seq),
+    ] {
+        txt.push(entry.len() as u8);
+        txt.extend_from_slice(entry.as_bytes());
+    }
+    write_record(&mut packet, &instance, TYPE_TXT, &txt);
+    packet
+}
+
+// The packets that can't be read are Other, the network has the ones of the
+// other services too.
+pub(crate) fn decode_packet(packet: &[u8]) -> MdnsPacket { decode(packet).unwrap_or(MdnsPacket::Other) }
+
+fn decode(packet: &[u8]) -> Option<MdnsPacket> {
+    let mut reader = PacketReader { packet, pos: 0 };
+    let _id = reader.u16()?;
+    let flags = reader.u16()?;
+    let questions = reader.u16()?;
+    let records = reader.u16()? as usize + reader.u16()? as usize + reader.u16()? as usize;
+
+    let mut is_query = false;
+    for _ in 0..questions {
+        let name = reader.name()?;
+        let _ = reader.u16()?;
+        let _ = reader.u16()?;
+        is_query |= name.eq_ignore_ascii_case(LAN_SERVICE);
+    }
+    if flags & 0x8000 == 0 {
+        return Some(if is_query { MdnsPacket::Query } else { MdnsPacket::Other });
+    }
+
+    let suffix = format!(".{}", LAN_SERVICE);
+    let mut port = None;
+    let mut txt = HashMap::new();
+    for _ in 0..records {
+        let name = reader.name()?;
+        let ty = reader.u16()?;
+        let _class = reader.u16()?;
+        let _ttl = reader.u32()?;
+        let end = reader.pos + reader.u16()? as usize;
+        if end > packet.len() {
+            return None;
+        }
+        if name.to_ascii_lowercase().ends_with(&suffix) {
+            match ty {
+                TYPE_SRV => {
+                    let _priority = reader.u16()?;
+                    let _weight = reader.u16()?;
+                    port = Some(reader.u16()?);
+                },
+                TYPE_TXT => {
+                    while reader.pos < end {
+                        let len = reader.u8()? as usize;
+                        let entry = String::from_utf8_lossy(reader.bytes(len)?).into_owned();
+                        if let Some((key, value)) = entry.split_once('=') {
+                            txt.insert(key.to_owned(), value.to_owned());
+                        }
+                    }
+                },
+                _ => {},
+            }
+        }
+        reader.pos = end;
+    }
+
+    let announce = LanAnnounce {
+        account: txt.remove("account")?,
+        device_id: txt.remove("device")?,
+        port: port?,
+        seq: txt.get("seq").and_then(|seq| seq.parse().ok()).unwrap_or(0),
+    };
+    Some(MdnsPacket::Announce(announce))
+}
+
+struct PacketReader<'a> {
+    packet: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> PacketReader<'a> {
+    fn u8(&mut self) -> Option<u8> {
+        let value = *self.packet.get(self.pos)?;
+        self.pos += 1;
+        Some(value)
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        let bytes = self.bytes(2)?;
+        Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        let bytes = self.bytes(4)?;
+        Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self.packet.get(self.pos..self.pos + len)?;
+        self.pos += len;
+        Some(bytes)
+    }
+
+    // The names of the other responders can be compressed, the pointers are
+    // followed a limited number of times so a loop of them ends.
+    fn name(&mut self) -> Option<String> {
+        let mut labels = vec![];
+        let mut pos = self.pos;
+        let mut jumped = false;
+        for _ in 0..128 {
+            let len = *self.packet.get(pos)? as usize;
+            if len == 0 {
+                if !jumped {
+                    self.pos = pos + 1;
+                }
+                return Some(labels.join("."));
+            }
+            if len & 0xC0 == 0xC0 {
+                let offset = ((len & 0x3F) << 8) | *self.packet.get(pos + 1)? as usize;
+                if !jumped {
+                    self.pos = pos + 2;
+                }
+                jumped = true;
+                pos = offset;
+                continue;
+            }
+            let label = self.packet.get(pos + 1..pos + 1 + len)?;
+            labels.push(String::from_utf8_lossy(label).into_owned());
+            pos += 1 + len;
+        }
+        None
+    }
+}
+
+fn header(flags: u16, questions: u16, answers: u16) -> Vec<u8> {
+    let mut packet = vec![];
+    for value in [0, flags, questions, answers, 0, 0] {
+        write_u16(&mut packet, value);
+    }
+    packet
+}
+
+fn write_record(packet: &mut Vec<u8>, name: &str, ty: u16, data: &[u8]) {
+    write_name(packet, name);
+    write_u16(packet, ty);
+    write_u16(packet, CLASS_IN);
+    packet.extend_from_slice(&RECORD_TTL.to_be_bytes());
+    write_u16(packet, data.len() as u16);
+    packet.extend_from_slice(data);
+}
+
+fn write_name(packet: &mut Vec<u8>, name: &str) {
+    for label in name.split('.') {
+        packet.push(label.len() as u8);
+        packet.extend_from_slice(label.as_bytes());
+    }
+    packet.push(0);
+}
+
+fn write_u16(packet: &mut Vec<u8>, value: u16) { packet.extend_from_slice(&value.to_be_bytes()); }
@@ -0,0 +1,17 @@
+mod conflict;
+mod engine;
+mod exclusion;
+mod lan;
+mod mdns;
+mod schedule;
+mod status;
+mod throttle;
+
+pub(crate) use conflict::*;
+pub(crate) use engine::*;
+pub(crate) use exclusion::*;
+pub(crate) use lan::*;
+pub(crate) use mdns::*;
+pub(crate) use schedule::*;
+pub(crate) use status::*;
+pub(crate) use throttle::*;
@@ -0,0 +1,128 @@
+use crate::{
+    entities::sync::{SyncSettings, SyncTrigger},
+    module::{WorkspaceUser, DEFAULT_SYNC_DEBOUNCE, DEFAULT_SYNC_INTERVAL},
+    services::sync::SyncSettingsStore,
+};
+use parking_lot::Mutex;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::sync::Notify;
+
+struct ScheduleState {
+    last_synced: Instant,
+    // The last local change that wasn't synced yet.
+    last_change: Option<Instant>,
+    reconnected: bool,
+}
+
+// Decides when the workspace is synced in the background, from the trigger that
+// the user picked and the network that the host reports. The sdk waits for
+// next() and dispatches SyncWorkspace, so the scheduled syncs go through the
+// guards like the other events.
+pub(crate) struct SyncScheduler {
+    user: Arc<dyn WorkspaceUser>,
+    settings: SyncSettingsStore,
+    state: Mutex<ScheduleState>,
+    wakeup: Notify,
+}
+
+impl SyncScheduler {
+    pub(crate) fn new(user: Arc<dyn WorkspaceUser>) -> Self {
+        Self {
+            settings: SyncSettingsStore::new(user.clone()),
+            user,
+            state: Mutex::new(ScheduleState {
+                last_synced: Instant::now(),
+                last_change: None,
+                reconnected: false,
+            }),
+            wakeup: Notify::new(),
+        }
+    }
+
+    // Whether the operations are sent as soon as they're pushed, they wait for
+    // the next sync otherwise.
+    pub(crate) fn sends_immediately(&self) -> bool {
+        match self.settings.read() {
+            Ok(settings) => settings.trigger == SyncTrigger::Automatic && self.is_allowed(&settings),
+            Err(_) => self.user.is_online(),
+        }
+    }
+
+    pub(crate) fn did_change(&self) {
+        self.state.lock().last_change = Some(Instant::now());
+        self.wakeup.notify_one();
+    }
+
+    pub(crate) fn did_reconnect(&self) {
+        self.state.lock().reconnected = true;
+        self.wakeup.notify_one();
+    }
+
+    // The settings or the network changed, what's due is computed again.
+    pub(crate) fn wake(&self) { self.wakeup.notify_one(); }
+
+    // The syncs that were asked for count too.
+    pub(crate) fn did_sync(&self) {
+        let mut state = self.state.lock();
+        state.last_synced = Instant::now();
+        state.last_change = None;
+        state.reconnected = false;
+    }
+
+    // Returns when the workspace should be synced. The attempt counts as a sync
+    // even if it fails, it's tried again with the next trigger.
+    pub(crate) async fn next(&self) {
+        loop {
+            let due = match self.settings.read() {
+                Ok(settings) if self.is_allowed(&settings) => self.due(&settings),
+                // Nobody is signed in, or the network doesn't allow it.
+                _ => None,
+            };
+            match due {
+                Some(due) if due <= Instant::now() => {
+                    self.did_sync();
+                    return;
+                },
+                Some(due) => {
+                    let _ = tokio::time::timeout_at(due.into(), self.wakeup.notified()).await;
+                },
+                None => self.wakeup.notified().await,
+            }
+        }
+    }
+
+    fn is_allowed(&self, settings: &SyncSettings) -> bool {
+        self.user.is_online() && !(settings.unmetered_only && self.user.is_metered())
+    }
+
+    // None if nothing is due until the next wakeup.
+    fn due(&self, settings: &SyncSettings) -> Option<Instant> {
+        let state = self.state.lock();
+        let now = Instant::now();
+        match settings.trigger {
+            SyncTrigger::Manual => None,
+            _ if state.reconnected => Some(now),
+            // The changes that were kept while the network didn't allow them.
+            SyncTrigger::Automatic => state.last_change.map(|_| now),
+            SyncTrigger::Interval => Some(state.last_synced + interval(settings)),
+            SyncTrigger::OnChange => state.last_change.map(|time| time + debounce(settings)),
+        }
+    }
+}
+
+fn interval(settings: &SyncSettings) -> Duration {
+    match settings.interval_seconds {
+        seconds if seconds > 0 => Duration::from_secs(seconds as u64),
+        _ => DEFAULT_SYNC_INTERVAL,
+    }
+}
+
+fn debounce(settings: &SyncSettings) -> Duration {
+    match settings.debounce_millis {
+        millis if millis > 0 => Duration::from_millis(millis as u64),
+        _ => DEFAULT_SYNC_DEBOUNCE,
+    }
+}
@@ -0,0 +1,58 @@
+use crate::entities::sync::{ObjectSyncStatus, SyncStatusType};
+use parking_lot::RwLock;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+// The objects whose operations are being sent and the error of the last send
+// that failed for each object. It's kept in memory, the pending operations are
+// read from the database.
+#[derive(Clone, Default)]
+pub(crate) struct SyncStatusTracker {
+    syncing: Arc<RwLock<HashSet<String>>>,
+    errors: Arc<RwLock<HashMap<String, String>>>,
+}
+
+impl SyncStatusTracker {
+    pub(crate) fn start(&self, object_ids: &[String]) { self.syncing.write().extend(object_ids.iter().cloned()); }
+
+    pub(crate) fn succeed(&self, object_ids: &[String]) {
+        let mut syncing = self.syncing.write();
+        let mut errors = self.errors.write();
+        for object_id in object_ids {
+            syncing.remove(object_id);
+            errors.remove(object_id);
+        }
+    }
+
+    pub(crate) fn fail(&self, object_ids: &[String], error: &str) {
+        let mut syncing = self.syncing.write();
+        let mut errors = self.errors.write();
+        for object_id in object_ids {
+            syncing.remove(object_id);
+            errors.insert(object_id.clone(), error.to_owned());
+        }
+    }
+
+    // The error is shown until the object is sent again, even if more changes
+    // were made since.
+    pub(crate) fn status(&self, object_id: &str, pending_operations: i64) -> ObjectSyncStatus {
+        let error = self.errors.read().get(object_id).cloned();
+        let ty = if self.syncing.read().contains(object_id) {
+            SyncStatusType::Syncing
+        } else if error.is_some() {
+            SyncStatusType::Failed
+        } else if pending_operations > 0 {
+            SyncStatusType::Pending
+        } else {
+            SyncStatusType::Synced
+        };
+        ObjectSyncStatus {
+            object_id: object_id.to_owned(),
+            ty,
+            pending_operations,
+            error: error.unwrap_or_default(),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{entities::sync::SyncTraffic, module::WorkspaceUser, services::sync::SyncSettingsStore};
+use parking_lot::Mutex;
+use std::{
+    sync::{
+        atomic::{AtomicI64, Ordering::SeqCst},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+// Keeps the sync traffic under the rates of the user, see SyncSettings, so the
+// background sync doesn't take the whole connection. Each transfer takes the
+// time its bytes need at the rate, the next one waits until it's over. The
+// bytes are the ones of the changes, without the requests around them.
+pub(crate) struct SyncThrottle {
+    settings: SyncSettingsStore,
+    upload_until: Mutex<Instant>,
+    download_until: Mutex<Instant>,
+    uploaded_bytes: AtomicI64,
+    downloaded_bytes: AtomicI64,
+    throttled_millis: AtomicI64,
+}
+
+impl SyncThrottle {
+    pub(crate) fn new(user: Arc<dyn WorkspaceUser>) -> Self {
+        Self {
+            settings: SyncSettingsStore::new(user),
+            upload_until: Mutex::new(Instant::now()),
+            download_until: Mutex::new(Instant::now()),
+            uploaded_bytes: AtomicI64::new(0),
+            downloaded_bytes: AtomicI64::new(0),
+            throttled_millis: AtomicI64::new(0),
+        }
+    }
+
+    // Waits before the bytes are sent.
+    pub(crate) async fn upload(&self, bytes: usize) {
+        let rate = self
+            .settings
+            .read()
+            .map(|settings| settings.upload_bytes_per_second)
+            .unwrap_or(0);
+        self.wait(&self.upload_until, rate, bytes).await;
+        let _ = self.uploaded_bytes.fetch_add(bytes as i64, SeqCst);
+        flowy_metrics::registry()
+            .counter("sync_uploaded_bytes_total", &[])
+            .add(bytes as u64);
+    }
+
+    // Waits after the bytes were received, so the next pull waits too.
+    pub(crate) async fn download(&self, bytes: usize) {
+        let rate = self
+            .settings
+            .read()
+            .map(|settings| settings.download_bytes_per_second)
+            .unwrap_or(0);
+        self.wait(&self.download_until, rate, bytes).await;
+        self.did_download(bytes);
+    }
+
+    // The changes that the server pushed are only counted, they're received
+    // already.
+    pub(crate) fn did_download(&self, bytes: usize) {
+        let _ = self.downloaded_bytes.fetch_add(bytes as i64, SeqCst);
+        flowy_metrics::registry()
+            .counter("sync_downloaded_bytes_total", &[])
+            .add(bytes as u64);
+    }
+
+    pub(crate) fn batch_window(&self) -> Duration {
+        match self.settings.read() {
+            Ok(settings) if settings.batch_window_millis > 0 => {
+                Duration::from_millis(settings.batch_window_millis as u64)
+            },
+            _ => Duration::from_secs(0),
+        }
+    }
+
+    pub(crate) fn traffic(&self) -> SyncTraffic {
+        SyncTraffic {
+            uploaded_bytes: self.uploaded_bytes.load(SeqCst),
+            downloaded_bytes: self.downloaded_bytes.load(SeqCst),
+            throttled_millis: self.throttled_millis.load(SeqCst),
+        }
+    }
+
+    async fn wait(&self, busy_until: &Mutex<Instant>, rate: i64, bytes: usize) {
+        if rate <= 0 {
+            return;
+        }
+        let delay = {
+            let mut busy_until = busy_until.lock();
+            let now = Instant::now();
+            let start = (*busy_until).max(now);
+            *busy_until = start + Duration::from_secs_f64(bytes as f64 / rate as f64);
+            start - now
+        };
+        if delay > Duration::from_secs(0) {
+            let _ = self.throttled_millis.fetch_add(delay.as_millis() as i64, SeqCst);
+            flowy_metrics::registry()
+                .counter("sync_throttled_ms_total", &[])
+                .add(delay.as_millis() as u64);
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::entities::template::{Template, WorkspaceTemplate, WorkspaceTemplateApp, WorkspaceTemplateView};
+use chrono::{DateTime, Local};
+use flowy_ot::core::{Attribute, Delta, DeltaBuilder, Operation};
+
+// The ids of the built-in templates start with it, so they never collide with
+// the uuid of the templates saved by the user.
+pub(crate) const BUILT_IN_TEMPLATE_PREFIX: &str = "built_in_";
+
+pub(crate) fn built_in_templates() -> Vec<Template> {
+    vec![
+        mk_built_in_template(
+            "meeting_notes",
+            "Meeting notes",
+            "Agenda, notes and action items",
+            meeting_notes(),
+        ),
+        mk_built_in_template("todo_list", "To-do list", "A checklist of the day", todo_list()),
+        mk_built_in_template("journal", "Journal", "A page of the journal", journal()),
+        mk_built_in_template("wiki_page", "Wiki page", "An overview with its links", wiki_page()),
+    ]
+}
+
+pub(crate) fn built_in_template(template_id: &str) -> Option<Template> {
+    built_in_templates()
+        .into_iter()
+        .find(|template| template.id == template_id)
+}
+
+pub(crate) fn built_in_workspace_templates() -> Vec<WorkspaceTemplate> {
+    vec![
+        mk_workspace_template(
+            "team_wiki",
+            "Team wiki",
+            "The pages and the meetings of the team",
+            vec![
+                mk_template_app(
+                    "Wiki",
+                    "The pages everyone should read",
+                    vec![("Home", "wiki_page"), ("Onboarding", "wiki_page")],
+                ),
+                mk_template_app(
+                    "Meetings",
+                    "The notes of the meetings",
+                    vec![("Weekly sync", "meeting_notes")],
+                ),
+            ],
+        ),
+        mk_workspace_template(
+            "personal_tasks",
+            "Personal tasks",
+            "The tasks of the day and a journal",
+            vec![
+                mk_template_app(
+                    "Tasks",
+                    "What needs to be done",
+                    vec![("Today", "todo_list"), ("Someday", "todo_list")],
+                ),
+                mk_template_app("Journal", "A page a day", vec![("Journal", "journal")]),
+            ],
+        ),
+    ]
+}
+
+pub(crate) fn built_in_workspace_template(template_id: &str) -> Option<WorkspaceTemplate> {
+    built_in_workspace_templates()
+        .into_iter()
+        .find(|template| template.id == template_id)
+}
+
+// Replaces the variables in the text of the template: {{title}} with the name
+// of the view, {{date}} and {{time}} with the local time of the creation.
+pub(crate) fn fill_template(delta: Delta, title: &str, now: DateTime<Local>) -> Delta {
+    let variables = [
+        ("{{title}}", title.to_owned()),
+        ("{{date}}", now.format("%Y-%m-%d").to_string()),
+        ("{{time}}", now.format("%H:%M").to_string()),
+    ];
+
+    let mut filled = Delta::new();
+    for op in delta.ops {
+        match op {
+            Operation::Insert(insert) => {
+                let mut s = insert.s.to_string();
+                for (variable, value) in &variables {
+                    s = s.replace(variable, value);
+                }
+                filled.insert(&s, insert.attributes);
+            },
+            op => filled.add(op),
+        }
+    }
+    filled
+}
+
+fn mk_built_in_template(name: &str, display_name: &str, desc: &str, delta: Delta) -> Template {
+    Template {
+        id: format!("{}{}", BUILT_IN_TEMPLATE_PREFIX, name),
+        name: display_name.to_owned(),
+        desc: desc.to_owned(),
+        built_in: true,
+        data: delta.to_json(),
+        create_time: 0,
+    }
+}
+
+// The views are given by their name and the name of their built-in view
+// template.
+fn mk_template_app(name: &str, desc: &str, views: Vec<(&str, &str)>) -> WorkspaceTemplateApp {
+    WorkspaceTemplateApp {
+        name: name.to_owned(),
+        desc: desc.to_owned(),
+        views: views
+            .into_iter()
+            .map(|(name, template_name)| WorkspaceTemplateView {
+                name: name.to_owned(),
+                template_id: format!("{}{}", BUILT_IN_TEMPLATE_PREFIX, template_name),
+            })
+            .collect(),
+    }
+}
+
+fn mk_workspace_template(
+    name: &str,
+    display_name: &str,
+    desc: &str,
+    apps: Vec<WorkspaceTemplateApp>,
+) -> WorkspaceTemplate {
+    WorkspaceTemplate {
+        id: format!("{}{}", BUILT_IN_TEMPLATE_PREFIX, name),
+        name: display_name.to_owned(),
+        desc: desc.to_owned(),
+        apps,
+    }
+}
+
+fn meeting_notes() -> Delta {
+    DeltaBuilder::new()
+        .insert("{{title}}")
+        .insert_with_attributes("\n", Attribute::Header(1).into())
+        .insert("{{date}} {{time}}\n")
+        .insert("Agenda")
+        .insert_with_attributes("\n", Attribute::Header(2).into())
+        .insert_with_attributes("\n", Attribute::Bullet(true).into())
+        .insert("Notes")
+        .insert_with_attributes("\n", Attribute::Header(2).into())
+        .insert("\n")
+        .insert("Action items")
+        .insert_with_attributes("\n", Attribute::Header(2).into())
+        .insert_with_attributes("\n", Attribute::UnChecked(true).into())
+        .build()
+}
+
+fn todo_list() -> Delta {
+    DeltaBuilder::new()
+        .insert("{{title}}")
+        .insert_with_attributes("\n", Attribute::Header(1).into())
+        .insert("{{date}}\n")
+        .insert_with_attributes("\n", Attribute::UnChecked(true).into())
+        .build()
+}
+
+fn journal() -> Delta {
+    DeltaBuilder::new()
+        .insert("{{date}}")
+        .insert_with_attributes("\n", Attribute::Header(1).into())
+        .insert("\n")
+        .build()
+}
+
+fn wiki_page() -> Delta {
+    DeltaBuilder::new()
+        .insert("{{title}}")
+        .insert_with_attributes("\n", Attribute::Header(1).into())
+        .insert("Overview")
+        .insert_with_attributes("\n", Attribute::Header(2).into())
+        .insert("\n")
+        .insert("Links")
+        .insert_with_attributes("\n", Attribute::Header(2).into())
+        .insert_with_attributes("\n", Attribute::Bullet(true).into())
+        .build()
+}
@@ -10,7 +10,7 @@ use crate::{
     errors::{WorkspaceError, WorkspaceResult},
     module::{WorkspaceDatabase, WorkspaceUser},
     notify::{send_anonymous_dart_notification, WorkspaceNotification},
-    services::server::Server,
+    services::{server::Server, Operation, OperationQueue},
     sql_tables::trash::TrashTableSql,
 };
 
@@ -19,10 +19,18 @@ pub struct TrashCan {
     notify: broadcast::Sender<TrashEvent>,
     server: Server,
     user: Arc<dyn WorkspaceUser>,
+    operations: OperationQueue,
+    retention: i64,
 }
 
 impl TrashCan {
-    pub fn new(database: Arc<dyn WorkspaceDatabase>, server: Server, user: Arc<dyn WorkspaceUser>) -> Self {
+    pub fn new(
+        database: Arc<dyn WorkspaceDatabase>,
+        server: Server,
+        user: Arc<dyn WorkspaceUser>,
+        operations: OperationQueue,
+        retention: i64,
+    ) -> Self {
         let (tx, _) = broadcast::channel(10);
 
         Self {
@@ -30,6 +38,8 @@ impl TrashCan {
             notify: tx,
             server,
             user,
+            operations,
+            retention,
         }
     }
 
@@ -39,10 +49,19 @@ impl TrashCan {
     pub async fn putback(&self, trash_id: &str) -> WorkspaceResult<()> {
         let (tx, mut rx) = mpsc::channel::<WorkspaceResult<()>>(1);
         let trash_table = TrashTableSql::read(trash_id, &*self.database.db_connection()?)?;
+        let identifier = TrashIdentifier {
+            id: trash_table.id,
+            ty: trash_table.ty.into(),
+        };
         let _ = thread::scope(|_s| {
-            let conn = self.database.db_connection()?;
+            let conn = self.database.db_write_connection()?;
             conn.immediate_transaction::<_, WorkspaceError, _>(|| {
                 let _ = TrashTableSql::delete_trash(trash_id, &*conn)?;
+                let operation = Operation::DeleteTrash(TrashIdentifiers {
+                    items: vec![identifier.clone()],
+                    delete_all: false,
+                });
+                let _ = self.operations.push(operation, &*conn)?;
                 notify_trash_changed(TrashTableSql::read_all(&conn)?);
                 Ok(())
             })?;
@@ -50,16 +69,7 @@ impl TrashCan {
             Ok::<(), WorkspaceError>(())
         })
         .unwrap()?;
-
-        let identifier = TrashIdentifier {
-            id: trash_table.id,
-            ty: trash_table.ty.into(),
-        };
-
-        let _ = self.delete_trash_on_server(TrashIdentifiers {
-            items: vec![identifier.clone()],
-            delete_all: false,
-        })?;
+        self.operations.flush();
 
         tracing::Span::current().record("putback", &format!("{:?}", &identifier).as_str());
         let _ = self.notify.send(TrashEvent::Putback(vec![identifier].into(), tx));
@@ -70,10 +80,13 @@ impl TrashCan {
     #[tracing::instrument(level = "debug", skip(self)  err)]
     pub async fn restore_all(&self) -> WorkspaceResult<()> {
         let repeated_trash = thread::scope(|_s| {
-            let conn = self.database.db_connection()?;
+            let conn = self.database.db_write_connection()?;
             conn.immediate_transaction::<_, WorkspaceError, _>(|| {
                 let repeated_trash = TrashTableSql::read_all(&*conn)?;
                 let _ = TrashTableSql::delete_all(&*conn)?;
+                let _ = self
+                    .operations
+                    .push(Operation::DeleteTrash(TrashIdentifiers::all()), &*conn)?;
                 Ok(repeated_trash)
             })
         })
@@ -85,7 +98,7 @@ impl TrashCan {
         let _ = rx.recv().await;
 
         notify_trash_changed(RepeatedTrash { items: vec![] });
-        let _ = self.delete_all_trash_on_server().await?;
+        self.operations.flush();
         Ok(())
     }
 
@@ -94,21 +107,36 @@ impl TrashCan {
         let repeated_trash = TrashTableSql::read_all(&*(self.database.db_connection()?))?;
         let trash_identifiers: TrashIdentifiers = repeated_trash.items.clone().into();
         let _ = self.delete_with_identifiers(trash_identifiers.clone()).await?;
+        let _ = self.push_operation(Operation::DeleteTrash(TrashIdentifiers::all()))?;
 
         notify_trash_changed(RepeatedTrash { items: vec![] });
-        let _ = self.delete_all_trash_on_server().await?;
         Ok(())
     }
 
     #[tracing::instrument(level = "debug", skip(self), err)]
     pub async fn delete(&self, trash_identifiers: TrashIdentifiers) -> WorkspaceResult<()> {
         let _ = self.delete_with_identifiers(trash_identifiers.clone()).await?;
+        let _ = self.push_operation(Operation::DeleteTrash(trash_identifiers))?;
         notify_trash_changed(TrashTableSql::read_all(&*(self.database.db_connection()?))?);
-        let _ = self.delete_trash_on_server(trash_identifiers)?;
 
         Ok(())
     }
 
+    // Deletes the items that were kept in the trash longer than the retention
+    // period before now. The PurgeTrash event that calls it is dispatched
+    // periodically.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn purge_expired(&self, now: i64) -> WorkspaceResult<()> {
+        let deadline = now - self.retention;
+        let repeated_trash = TrashTableSql::read_expired(deadline, &*(self.database.db_connection()?))?;
+        if repeated_trash.is_empty() {
+            return Ok(());
+        }
+
+        tracing::debug!("Purge {} expired trash", repeated_trash.len());
+        self.delete(repeated_trash.items.into()).await
+    }
+
     #[tracing::instrument(level = "debug", skip(self), fields(delete_trash_ids), err)]
     pub async fn delete_with_identifiers(&self, trash_identifiers: TrashIdentifiers) -> WorkspaceResult<()> {
         let (tx, mut rx) = mpsc::channel::<WorkspaceResult<()>>(1);
@@ -123,7 +151,7 @@ impl TrashCan {
             },
         }
 
-        let conn = self.database.db_connection()?;
+        let conn = self.database.db_write_connection()?;
         conn.immediate_transaction::<_, WorkspaceError, _>(|| {
             for trash_identifier in &trash_identifiers.items {
                 let _ = TrashTableSql::delete_trash(&trash_identifier.id, &conn)?;
@@ -160,10 +188,12 @@ impl TrashCan {
             .as_str(),
         );
         let _ = thread::scope(|_s| {
-            let conn = self.database.db_connection()?;
+            let conn = self.database.db_write_connection()?;
             conn.immediate_transaction::<_, WorkspaceError, _>(|| {
                 let _ = TrashTableSql::create_trash(repeated_trash.clone(), &*conn)?;
-                let _ = self.create_trash_on_server(repeated_trash);
+                let _ = self
+                    .operations
+                    .push(Operation::CreateTrash(repeated_trash.clone().into()), &*conn)?;
 
                 notify_trash_changed(TrashTableSql::read_all(&conn)?);
                 Ok(())
@@ -171,6 +201,7 @@ impl TrashCan {
             Ok::<(), WorkspaceError>(())
         })
         .unwrap()?;
+        self.operations.flush();
 
         let _ = self.notify.send(TrashEvent::NewTrash(identifiers.into(), tx));
         let _ = rx.recv().await.unwrap()?;
@@ -178,6 +209,13 @@ impl TrashCan {
         Ok(())
     }
 
+    // The trash was created by a batch in its own transaction, the batch notifies
+    // the views instead of the NewTrash event.
+    pub(crate) fn did_create_trash(&self) -> WorkspaceResult<()> {
+        notify_trash_changed(TrashTableSql::read_all(&*self.database.db_connection()?)?);
+        Ok(())
+    }
+
     pub fn subscribe(&self) -> broadcast::Receiver<TrashEvent> { self.notify.subscribe() }
 
     pub fn read_trash(&self, conn: &SqliteConnection) -> Result<RepeatedTrash, WorkspaceError> {
@@ -197,32 +235,13 @@ impl TrashCan {
 }
 
 impl TrashCan {
-    #[tracing::instrument(level = "debug", skip(self, trash), err)]
-    fn create_trash_on_server<T: Into<TrashIdentifiers>>(&self, trash: T) -> WorkspaceResult<()> {
-        let token = self.user.token()?;
-        let trash_identifiers = trash.into();
-        let server = self.server.clone();
-        // TODO: retry?
-        let _ = tokio::spawn(async move {
-            match server.create_trash(&token, trash_identifiers).await {
-                Ok(_) => {},
-                Err(e) => log::error!("Create trash failed: {:?}", e),
-            }
-        });
-        Ok(())
-    }
-
-    #[tracing::instrument(level = "debug", skip(self, trash), err)]
-    fn delete_trash_on_server<T: Into<TrashIdentifiers>>(&self, trash: T) -> WorkspaceResult<()> {
-        let token = self.user.token()?;
-        let trash_identifiers = trash.into();
-        let server = self.server.clone();
-        let _ = tokio::spawn(async move {
-            match server.delete_trash(&token, trash_identifiers).await {
-                Ok(_) => {},
-                Err(e) => log::error!("Delete trash failed: {:?}", e),
-            }
-        });
+    // The operation is sent once the trash was deleted.
+    fn push_operation(&self, operation: Operation) -> WorkspaceResult<()> {
+        {
+            let conn = self.database.db_write_connection()?;
+            let _ = self.operations.push(operation, &*conn)?;
+        }
+        self.operations.flush();
         Ok(())
     }
 
@@ -260,17 +279,10 @@ impl TrashCan {
         });
         Ok(())
     }
-
-    #[tracing::instrument(level = "debug", skip(self), err)]
-    async fn delete_all_trash_on_server(&self) -> WorkspaceResult<()> {
-        let token = self.user.token()?;
-        let server = self.server.clone();
-        server.delete_trash(&token, TrashIdentifiers::all()).await
-    }
 }
 
 #[tracing::instrument(skip(repeated_trash), fields(n_trash))]
-fn notify_trash_changed(repeated_trash: RepeatedTrash) {
+pub(crate) fn notify_trash_changed(repeated_trash: RepeatedTrash) {
     tracing::Span::current().record("n_trash", &repeated_trash.len());
     send_anonymous_dart_notification(WorkspaceNotification::TrashUpdated)
         .payload(repeated_trash)
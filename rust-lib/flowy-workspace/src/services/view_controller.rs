@@ -1,22 +1,104 @@
+use chrono::Local;
 use flowy_database::SqliteConnection;
-use flowy_document_infra::entities::doc::{DocDelta, DocIdentifier};
+use flowy_document_infra::{
+    entities::doc::{DocDelta, DocIdentifier},
+    user_default::doc_initial_delta,
+};
 use futures::{FutureExt, StreamExt};
-use std::{collections::HashSet, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use crate::{
     entities::{
-        trash::{TrashIdentifiers, TrashType},
-        view::{CreateViewParams, RepeatedView, UpdateViewParams, View, ViewIdentifier},
+        template::{CreateViewFromTemplateParams, RepeatedTemplate, SaveTemplateParams, Template},
+        trash::{Trash, TrashIdentifiers, TrashType},
+        view::{
+            BatchViewChangeset,
+            BatchViewOperationParams,
+            CreateViewParams,
+            DuplicateDocumentParams,
+            GarbageCollectionResult,
+            MoveFavoriteParams,
+            MoveViewParams,
+            ReadViewTreeParams,
+            RepeatedView,
+            UpdateViewParams,
+            View,
+            ViewBelongings,
+            ViewIdentifier,
+            ViewIdentifiers,
+            ViewOperationType,
+            ViewType,
+        },
     },
     errors::{internal_error, WorkspaceError, WorkspaceResult},
-    module::{WorkspaceDatabase, WorkspaceUser},
+    module::{WorkspaceDatabase, WorkspaceUser, MAX_RECENT_VIEWS},
     notify::{send_dart_notification, WorkspaceNotification},
-    services::{server::Server, TrashCan, TrashEvent},
-    sql_tables::view::{ViewTable, ViewTableChangeset, ViewTableSql},
+    services::{
+        built_in_template,
+        built_in_templates,
+        fill_template,
+        get_current_workspace,
+        read_role,
+        role_allows,
+        server::Server,
+        Operation,
+        OperationQueue,
+        TrashCan,
+        TrashEvent,
+        WorkspaceKeys,
+    },
+    sql_tables::{
+        app::AppTableSql,
+        favorite::{FavoriteTable, FavoriteTableSql},
+        recent_view::RecentViewTableSql,
+        setting::WorkspaceSettingTableSql,
+        template::{TemplateTable, TemplateTableSql},
+        trash::TrashTableSql,
+        view::{
+            order_key_at,
+            order_key_between,
+            order_keys,
+            unique_view_names,
+            ViewTable,
+            ViewTableChangeset,
+            ViewTableSql,
+            ViewTableType,
+            MAX_NAME_SUGGESTIONS,
+        },
+    },
+};
+use flowy_dispatch::prelude::EventPermission;
+use flowy_document::{
+    entities::asset::CreateAssetRequest,
+    module::FlowyDocument,
+    services::{
+        asset::{asset_url, referenced_assets, replace_asset_links},
+        export::{delta_to_markdown, MAX_INLINE_EXPORT_SIZE},
+        import::{html_to_delta, markdown_to_delta},
+    },
 };
-use flowy_document::module::FlowyDocument;
 use flowy_infra::kv::KV;
-use flowy_workspace_infra::entities::share::{ExportData, ExportParams};
+use flowy_ot::core::{Attribute, Delta, DeltaBuilder};
+use flowy_workspace_infra::entities::{
+    share::{
+        ArchivedAsset,
+        ArchivedView,
+        ExportData,
+        ExportParams,
+        ExportType,
+        ImportFailure,
+        ImportParams,
+        ImportType,
+        IngestDestination,
+        IngestSharedContentParams,
+        IngestSharedContentResult,
+        SharedContent,
+    },
+    workspace::INBOX_VIEW_SETTING,
+};
 
 const LATEST_VIEW_ID: &str = "latest_view_id";
 
@@ -25,7 +107,9 @@ pub(crate) struct ViewController {
     server: Server,
     database: Arc<dyn WorkspaceDatabase>,
     trash_can: Arc<TrashCan>,
+    operations: OperationQueue,
     document: Arc<FlowyDocument>,
+    keys: Arc<WorkspaceKeys>,
 }
 
 impl ViewController {
@@ -34,14 +118,18 @@ impl ViewController {
         database: Arc<dyn WorkspaceDatabase>,
         server: Server,
         trash_can: Arc<TrashCan>,
+        operations: OperationQueue,
         document: Arc<FlowyDocument>,
+        keys: Arc<WorkspaceKeys>,
     ) -> Self {
         Self {
             user,
             server,
             database,
             trash_can,
+            operations,
             document,
+            keys,
         }
     }
 
@@ -52,13 +140,17 @@ impl ViewController {
     }
 
     #[tracing::instrument(level = "debug", skip(self, params), fields(name = %params.name), err)]
-    pub(crate) async fn create_view_from_params(&self, params: CreateViewParams) -> Result<View, WorkspaceError> {
+    pub(crate) async fn create_view_from_params(&self, mut params: CreateViewParams) -> Result<View, WorkspaceError> {
+        params.name = {
+            let conn = &*self.database.db_connection()?;
+            self.resolve_view_name(&params.belong_to_id, &params.name, None, params.auto_rename, conn)?
+        };
         let view = self.create_view_on_server(params.clone()).await?;
         self.create_view(view).await
     }
 
     pub(crate) async fn create_view(&self, view: View) -> Result<View, WorkspaceError> {
-        let conn = &*self.database.db_connection()?;
+        let conn = &*self.database.db_write_connection()?;
         let trash_can = self.trash_can.clone();
 
         conn.immediate_transaction::<_, WorkspaceError, _>(|| {
@@ -77,13 +169,20 @@ impl ViewController {
         Ok(())
     }
 
+    // Creates the document of the view with the delta, in the transaction of the
+    // connection.
+    pub(crate) fn save_doc(&self, view_id: &str, delta: &Delta, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = self.document.create_with_delta(view_id, delta, conn)?;
+        Ok(())
+    }
+
     #[tracing::instrument(skip(self, params), fields(view_id = %params.view_id), err)]
     pub(crate) async fn read_view(&self, params: ViewIdentifier) -> Result<View, WorkspaceError> {
         let conn = self.database.db_connection()?;
         let view_table = ViewTableSql::read_view(&params.view_id, &*conn)?;
 
         let trash_ids = self.trash_can.trash_ids(&conn)?;
-        if trash_ids.contains(&view_table.id) {
+        if is_in_trash(&view_table, &trash_ids, &conn)? {
             return Err(WorkspaceError::record_not_found());
         }
 
@@ -110,7 +209,8 @@ impl ViewController {
         let doc_id = params.doc_id.clone();
         let edit_context = self.document.open(params).await?;
 
-        KV::set_str(LATEST_VIEW_ID, doc_id);
+        KV::set_str(&self.latest_view_key()?, doc_id.clone());
+        let _ = self.record_recent_view(&doc_id)?;
         Ok(edit_context.delta().await.map_err(internal_error)?)
     }
 
@@ -120,52 +220,383 @@ impl ViewController {
         Ok(())
     }
 
+    // Zero unless the document of the view is opened.
+    pub(crate) async fn pending_revisions(&self, view_id: &str) -> usize {
+        self.document.pending_revisions(view_id).await
+    }
+
     #[tracing::instrument(level = "debug", skip(self,params), fields(doc_id = %params.doc_id), err)]
     pub(crate) async fn delete_view(&self, params: DocIdentifier) -> Result<(), WorkspaceError> {
-        if let Some(view_id) = KV::get_str(LATEST_VIEW_ID) {
+        let latest_view_key = self.latest_view_key()?;
+        if let Some(view_id) = KV::get_str(&latest_view_key) {
             if view_id == params.doc_id {
-                let _ = KV::remove(LATEST_VIEW_ID);
+                let _ = KV::remove(&latest_view_key);
             }
         }
         let _ = self.document.close(params).await?;
         Ok(())
     }
 
+    // The views were deleted with their workspace, their documents are deleted
+    // once the transaction is done.
+    pub(crate) async fn delete_view_documents(&self, view_ids: Vec<String>) -> Result<(), WorkspaceError> {
+        for view_id in view_ids {
+            let params: DocIdentifier = view_id.into();
+            let _ = self.delete_view(params.clone()).await?;
+            let _ = self.document.delete(params)?;
+        }
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip(self, params), fields(doc_id = %params.doc_id), err)]
     pub(crate) async fn duplicate_view(&self, params: DocIdentifier) -> Result<(), WorkspaceError> {
-        let view: View = ViewTableSql::read_view(&params.doc_id, &*self.database.db_connection()?)?.into();
-        let delta_data = self
+        let _ = self
+            .duplicate_document(DuplicateDocumentParams::new(&params.doc_id))
+            .await?;
+        Ok(())
+    }
+
+    // The copy keeps the description, the icon, the cover, the thumbnail and the
+    // type of the view. The assets are copied with the document and the copy of
+    // the encrypted document is encrypted too.
+    #[tracing::instrument(level = "debug", skip(self, params), fields(view_id = %params.view_id), err)]
+    pub(crate) async fn duplicate_document(&self, params: DuplicateDocumentParams) -> Result<View, WorkspaceError> {
+        let view_table = {
+            let conn = self.database.db_connection()?;
+            let view_table = ViewTableSql::read_view(&params.view_id, &*conn)?;
+            if is_in_trash(&view_table, &self.trash_can.trash_ids(&conn)?, &conn)? {
+                return Err(WorkspaceError::record_not_found());
+            }
+            view_table
+        };
+        let doc_identifier: DocIdentifier = params.view_id.clone().into();
+        let doc = self
             .document
-            .read_document_data(params, self.database.db_pool()?)
+            .read_document_data(doc_identifier, self.database.db_pool()?)
             .await?;
+        let delta = Delta::from_json(&doc.data).map_err(internal_error)?;
 
-        let duplicate_params = CreateViewParams {
-            belong_to_id: view.belong_to_id.clone(),
-            name: format!("{} (copy)", &view.name),
-            desc: view.desc.clone(),
-            thumbnail: "".to_owned(),
-            view_type: view.view_type.clone(),
-            data: delta_data.data,
+        let thumbnail = view_table.thumbnail.clone();
+        let source: View = view_table.into();
+        let (icon, cover) = (source.icon.clone(), source.cover.clone());
+        let create_params = CreateViewParams {
+            name: params.name.unwrap_or_else(|| format!("{} (copy)", &source.name)),
+            belong_to_id: params.belong_to_id.unwrap_or(source.belong_to_id),
+            desc: source.desc,
+            thumbnail,
+            view_type: source.view_type,
+            data: doc.data,
+            auto_rename: true,
         };
+        let view = self.create_view_from_params(create_params).await?;
 
-        let _ = self.create_view_from_params(duplicate_params).await?;
-        Ok(())
+        let delta = self.document.duplicate_assets(&params.view_id, &view.id, &delta)?;
+        let _ = self.replace_doc_data(&view.id, delta).await?;
+        let cover = match cover.is_empty() {
+            true => None,
+            false => self.copy_asset(&cover, &params.view_id, &view.id)?,
+        };
+        let view = self.update_view_metadata(view, icon, cover).await?;
+        if self.document.encryption(&params.view_id)?.encrypted {
+            let _ = self.document.encrypt(view.id.clone().into()).await?;
+        }
+        Ok(view)
     }
 
     #[tracing::instrument(level = "debug", skip(self, params), err)]
     pub(crate) async fn export_doc(&self, params: ExportParams) -> Result<ExportData, WorkspaceError> {
-        let doc_identifier: DocIdentifier = params.doc_id.into();
+        let doc_identifier: DocIdentifier = params.doc_id.clone().into();
         let doc = self
             .document
             .read_document_data(doc_identifier, self.database.db_pool()?)
             .await?;
 
-        Ok(ExportData {
+        match params.export_type {
+            ExportType::Markdown => {
+                let delta = Delta::from_json(&doc.data).map_err(internal_error)?;
+                let markdown = delta_to_markdown(&delta);
+                if markdown.len() <= MAX_INLINE_EXPORT_SIZE {
+                    return Ok(ExportData::inline(markdown, params.export_type));
+                }
+
+                let path = self.document.write_export(&params.doc_id, "md", markdown.as_bytes())?;
+                Ok(ExportData::file(path, params.export_type))
+            },
+            _ => Ok(ExportData::inline(doc.data, params.export_type)),
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, params), fields(name = %params.name), err)]
+    pub(crate) async fn import_doc(&self, params: ImportParams) -> Result<View, WorkspaceError> {
+        let delta = match params.import_type {
+            ImportType::Markdown => markdown_to_delta(&params.data),
+            ImportType::Html => html_to_delta(&params.data),
+        };
+        self.create_doc_view(params.belong_to_id, params.name, delta).await
+    }
+
+    // The shared content becomes a doc view, or is appended to the document of
+    // the inbox view. The image is attached to the document as an asset, its
+    // link shows the name like the link of the url.
+    #[tracing::instrument(level = "debug", skip(self, params), fields(name = %params.name), err)]
+    pub(crate) async fn ingest_shared_content(
+        &self,
+        params: IngestSharedContentParams,
+    ) -> Result<IngestSharedContentResult, WorkspaceError> {
+        let (view, appended) = match params.destination {
+            IngestDestination::NewDocument { belong_to_id } => {
+                let view = self
+                    .create_doc_view(belong_to_id, params.name.clone(), doc_initial_delta())
+                    .await?;
+                (view, false)
+            },
+            IngestDestination::Inbox => (self.read_inbox_view()?, true),
+        };
+
+        let delta = match params.content {
+            SharedContent::Text(text) => text_delta(&text),
+            SharedContent::Url(url) => link_delta(&params.name, &url),
+            SharedContent::Image { data, mime_type } => {
+                let request = CreateAssetRequest {
+                    doc_id: view.id.clone(),
+                    name: params.name.clone(),
+                    mime_type,
+                    data,
+                };
+                let asset = self.document.attach_asset(request)?;
+                link_delta(&params.name, &asset_url(&asset.id))
+            },
+        };
+        let _ = match appended {
+            true => self.append_doc_delta(&view.id, delta).await?,
+            false => self.replace_doc_data(&view.id, delta).await?,
+        };
+        Ok(IngestSharedContentResult { view, appended })
+    }
+
+    // The doc view of the current workspace that is set as its inbox, see
+    // INBOX_VIEW_SETTING.
+    fn read_inbox_view(&self) -> Result<View, WorkspaceError> {
+        let workspace_id = get_current_workspace(&self.user.user_id()?)?;
+        let conn = &*self.database.db_connection()?;
+        let view_id = WorkspaceSettingTableSql::read_setting(&workspace_id, INBOX_VIEW_SETTING, conn)?
+            .and_then(|table| table.str_value)
+            .ok_or_else(|| WorkspaceError::inbox_view().context(format!("{} is not set", INBOX_VIEW_SETTING)))?;
+
+        let trash_ids = self.trash_can.trash_ids(conn)?;
+        if read_visible_view_workspace_id(&view_id, &trash_ids, conn)?.as_deref() != Some(workspace_id.as_str()) {
+            return Err(WorkspaceError::inbox_view().context(format!("{} is not a view of the workspace", view_id)));
+        }
+        let view_table = ViewTableSql::read_view(&view_id, conn)?;
+        if view_table.view_type != ViewTableType::Docs {
+            return Err(WorkspaceError::inbox_view().context(format!("{} is not a document", view_id)));
+        }
+        Ok(view_table.into())
+    }
+
+    // The view with the assets that its document refers to. The encrypted document
+    // must be unlocked, it's archived as it's read.
+    pub(crate) async fn archive_view(
+        &self,
+        view_table: ViewTable,
+    ) -> Result<(ArchivedView, Vec<ArchivedAsset>), WorkspaceError> {
+        let doc_identifier: DocIdentifier = view_table.id.clone().into();
+        let doc = self
+            .document
+            .read_document_data(doc_identifier, self.database.db_pool()?)
+            .await?;
+        let delta = Delta::from_json(&doc.data).map_err(internal_error)?;
+
+        let referenced = referenced_assets(&delta);
+        let mut assets = vec![];
+        for asset in self.document.list_assets(&view_table.id)?.items {
+            if !referenced.contains(&asset.id) && asset.id != view_table.cover {
+                continue;
+            }
+            let asset_data = self.document.read_asset(&asset.id)?;
+            assets.push(ArchivedAsset {
+                id: asset.id,
+                doc_id: asset.doc_id,
+                name: asset.name,
+                mime_type: asset.mime_type,
+                data: asset_data.data,
+            });
+        }
+
+        let thumbnail = view_table.thumbnail.clone();
+        let view: View = view_table.into();
+        let archived_view = ArchivedView {
+            id: view.id,
+            belong_to_id: view.belong_to_id,
+            name: view.name,
+            desc: view.desc,
+            thumbnail,
+            view_type: view.view_type,
             data: doc.data,
-            export_type: params.export_type,
+            icon: view.icon,
+            cover: view.cover,
+        };
+        Ok((archived_view, assets))
+    }
+
+    // Creates the view from the archived one, the links of its document point to
+    // the restored assets. The assets that can't be restored are returned.
+    pub(crate) async fn restore_view(
+        &self,
+        belong_to_id: String,
+        archived_view: ArchivedView,
+        archived_assets: Vec<ArchivedAsset>,
+    ) -> Result<(View, Vec<ImportFailure>), WorkspaceError> {
+        let delta =
+            Delta::from_json(&archived_view.data).map_err(|e| WorkspaceError::workspace_archive().context(e))?;
+        let (icon, cover) = (archived_view.icon, archived_view.cover);
+        let create_params = CreateViewParams {
+            belong_to_id,
+            name: archived_view.name,
+            desc: archived_view.desc,
+            thumbnail: archived_view.thumbnail,
+            view_type: archived_view.view_type,
+            data: archived_view.data,
+            auto_rename: true,
+        };
+        let view = self.create_view_from_params(create_params).await?;
+
+        let mut failures = vec![];
+        let mut asset_ids = HashMap::new();
+        for archived_asset in archived_assets {
+            let request = CreateAssetRequest {
+                doc_id: view.id.clone(),
+                name: archived_asset.name.clone(),
+                mime_type: archived_asset.mime_type,
+                data: archived_asset.data,
+            };
+            match self.document.attach_asset(request) {
+                Ok(asset) => {
+                    asset_ids.insert(archived_asset.id, asset.id);
+                },
+                Err(e) => failures.push(ImportFailure {
+                    id: archived_asset.id,
+                    name: archived_asset.name,
+                    msg: e.msg,
+                }),
+            }
+        }
+        let _ = self
+            .replace_doc_data(&view.id, replace_asset_links(&delta, &asset_ids))
+            .await?;
+        let cover = asset_ids.get(&cover).cloned();
+        let view = self.update_view_metadata(view, icon, cover).await?;
+        Ok((view, failures))
+    }
+
+    // Removes what the hard deletes left behind: the views whose app or parent
+    // view doesn't exist anymore with the views that belong to them, then the
+    // documents that no view owns and the asset files that no asset refers to.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn collect_garbage(&self) -> Result<GarbageCollectionResult, WorkspaceError> {
+        let conn = &*self.database.db_write_connection()?;
+        let (view_ids, pinned) = conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            let belongings = ViewTableSql::read_belongings(conn)?;
+            let mut parent_ids = AppTableSql::read_app_ids(conn)?
+                .into_iter()
+                .collect::<HashSet<String>>();
+            parent_ids.extend(belongings.iter().map(|(view_id, _)| view_id.clone()));
+            let orphan_ids = belongings
+                .into_iter()
+                .filter(|(_, belong_to_id)| !parent_ids.contains(belong_to_id))
+                .map(|(view_id, _)| view_id)
+                .collect::<Vec<String>>();
+            let view_ids = [read_descendant_ids(&orphan_ids, conn)?, orphan_ids].concat();
+
+            let pinned = is_any_pinned(&self.user, &view_ids, conn)?;
+            let _ = TrashTableSql::delete_trash_with_ids(&view_ids, conn)?;
+            let _ = FavoriteTableSql::delete_view_favorites(&view_ids, conn)?;
+            let _ = RecentViewTableSql::delete_view_recent_views(&view_ids, conn)?;
+            let _ = ViewTableSql::delete_views(&view_ids, conn)?;
+            Ok((view_ids, pinned))
+        })?;
+        if pinned {
+            let _ = notify_favorites_changed(&self.user, self.trash_can.clone(), conn)?;
+        }
+
+        // The document writes its own tables with another connection, so it's
+        // cleaned once the transaction above is done.
+        let owned_ids = ViewTableSql::read_belongings(conn)?
+            .into_iter()
+            .map(|(view_id, _)| view_id)
+            .collect::<HashSet<String>>();
+        let doc_ids = self
+            .document
+            .stored_doc_ids()?
+            .into_iter()
+            .filter(|doc_id| !owned_ids.contains(doc_id))
+            .collect::<Vec<String>>();
+        for doc_id in &doc_ids {
+            let _ = self.document.purge(doc_id.into())?;
+        }
+        let (blob_count, blob_size) = self.document.remove_unreferenced_blobs()?;
+
+        tracing::debug!(
+            "Collect {} views, {} documents and {} blobs",
+            view_ids.len(),
+            doc_ids.len(),
+            blob_count
+        );
+        Ok(GarbageCollectionResult {
+            view_ids,
+            doc_ids,
+            blob_count: blob_count as i64,
+            blob_size: blob_size as i64,
         })
     }
 
+    pub(crate) fn write_export(&self, id: &str, extension: &str, data: &[u8]) -> Result<String, WorkspaceError> {
+        let path = self.document.write_export(id, extension, data)?;
+        Ok(path)
+    }
+
+    pub(crate) fn read_templates(&self) -> Result<RepeatedTemplate, WorkspaceError> {
+        let conn = self.database.db_connection()?;
+        let mut items = built_in_templates();
+        let user_templates = TemplateTableSql::read_templates(&*conn)?;
+        items.extend(user_templates.into_iter().map(|table| table.into()));
+        Ok(RepeatedTemplate { items })
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, params), fields(template_id = %params.template_id), err)]
+    pub(crate) async fn create_view_from_template(
+        &self,
+        params: CreateViewFromTemplateParams,
+    ) -> Result<View, WorkspaceError> {
+        let template = match built_in_template(&params.template_id) {
+            Some(template) => template,
+            None => {
+                let conn = self.database.db_connection()?;
+                match TemplateTableSql::read_template(&params.template_id, &*conn)? {
+                    None => return Err(WorkspaceError::record_not_found().context("The template doesn't exist")),
+                    Some(table) => table.into(),
+                }
+            },
+        };
+
+        let delta = Delta::from_json(&template.data).map_err(internal_error)?;
+        let delta = fill_template(delta, &params.name, Local::now());
+        self.create_doc_view(params.belong_to_id, params.name, delta).await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, params), fields(view_id = %params.view_id), err)]
+    pub(crate) async fn save_template(&self, params: SaveTemplateParams) -> Result<Template, WorkspaceError> {
+        let doc_identifier: DocIdentifier = params.view_id.clone().into();
+        let doc = self
+            .document
+            .read_document_data(doc_identifier, self.database.db_pool()?)
+            .await?;
+
+        let table = TemplateTable::new(&params.name, &params.desc, doc.data);
+        let _ = TemplateTableSql::create_template(table.clone(), &*self.database.db_write_connection()?)?;
+        Ok(table.into())
+    }
+
     // belong_to_id will be the app_id or view_id.
     #[tracing::instrument(level = "debug", skip(self), err)]
     pub(crate) async fn read_views_belong_to(&self, belong_to_id: &str) -> Result<RepeatedView, WorkspaceError> {
@@ -175,35 +606,346 @@ impl ViewController {
         Ok(repeated_view)
     }
 
+    // The views of the tree are sorted like the belongings of the view, the views
+    // in the trash are skipped with the views that belong to them.
+    #[tracing::instrument(level = "debug", skip(self, params), fields(belong_to_id = %params.belong_to_id), err)]
+    pub(crate) async fn read_view_tree(&self, params: ReadViewTreeParams) -> Result<RepeatedView, WorkspaceError> {
+        let conn = self.database.db_connection()?;
+        let trash_ids = self.trash_can.trash_ids(&conn)?;
+        // The belong_to_id is the id of an app if there is no view with it.
+        let view_tables = ViewTableSql::read_views_with_ids(&[params.belong_to_id.clone()], &conn)?;
+        if let Some(view_table) = view_tables.first() {
+            if is_in_trash(view_table, &trash_ids, &conn)? {
+                return Err(WorkspaceError::record_not_found());
+            }
+        }
+        read_local_view_tree(&params.belong_to_id, params.depth, &trash_ids, &conn)
+    }
+
     #[tracing::instrument(level = "debug", skip(self, params), err)]
-    pub(crate) async fn update_view(&self, params: UpdateViewParams) -> Result<View, WorkspaceError> {
-        let conn = &*self.database.db_connection()?;
+    pub(crate) async fn update_view(&self, mut params: UpdateViewParams) -> Result<View, WorkspaceError> {
+        if let Some(cover) = &params.cover {
+            let assets = self.document.list_assets(&params.view_id)?;
+            if !cover.is_empty() && !assets.items.iter().any(|asset| &asset.id == cover) {
+                return Err(WorkspaceError::view_cover());
+            }
+        }
+
+        let conn = &*self.database.db_write_connection()?;
+        let view_table = ViewTableSql::read_view(&params.view_id, conn)?;
+        if let Some(name) = &params.name {
+            let name = self.resolve_view_name(
+                &view_table.belong_to_id,
+                name,
+                Some(&params.view_id),
+                params.auto_rename,
+                conn,
+            )?;
+            params.name = Some(name);
+        }
+        // Only the fields that change are written and sent to the server.
+        let params = params.without_unchanged(&view_table.current_fields());
+        if params.is_empty() {
+            return Ok(view_table.into());
+        }
         let changeset = ViewTableChangeset::new(params.clone());
         let view_id = changeset.id.clone();
+        let before = Operation::UpdateView(view_table.current_fields());
 
         let updated_view = conn.immediate_transaction::<_, WorkspaceError, _>(|| {
             let _ = ViewTableSql::update_view(changeset, conn)?;
+            let _ = self
+                .operations
+                .push_update(Operation::UpdateView(params), before, conn)?;
             let view: View = ViewTableSql::read_view(&view_id, conn)?.into();
             Ok(view)
         })?;
+        self.operations.flush();
         send_dart_notification(&view_id, WorkspaceNotification::ViewUpdated)
             .payload(updated_view.clone())
             .send();
 
         //
         let _ = notify_views_changed(&updated_view.belong_to_id, self.trash_can.clone(), conn)?;
-
-        let _ = self.update_view_on_server(params);
+        let _ = notify_favorites_if_pinned(&self.user, &[view_id], self.trash_can.clone(), conn)?;
         Ok(updated_view)
     }
 
+    // The update another device made, the server already has it so it isn't
+    // pushed to the operations.
+    pub(crate) fn apply_remote_update(&self, params: UpdateViewParams, conn: &SqliteConnection) -> WorkspaceResult<()> {
+        let changeset = ViewTableChangeset::new(params);
+        let view_id = changeset.id.clone();
+        let _ = ViewTableSql::update_view(changeset, conn)?;
+        let view: View = ViewTableSql::read_view(&view_id, conn)?.into();
+        send_dart_notification(&view_id, WorkspaceNotification::ViewUpdated)
+            .payload(view.clone())
+            .send();
+
+        let _ = notify_views_changed(&view.belong_to_id, self.trash_can.clone(), conn)?;
+        notify_favorites_if_pinned(&self.user, &[view_id], self.trash_can.clone(), conn)
+    }
+
+    pub(crate) fn apply_remote_create(&self, view: View, conn: &SqliteConnection) -> WorkspaceResult<()> {
+        let belong_to_id = view.belong_to_id.clone();
+        let _ = self.save_view(view, conn)?;
+        notify_views_changed(&belong_to_id, self.trash_can.clone(), conn)
+    }
+
+    // Moving the view to the app of another workspace needs the write permission in
+    // that workspace, it's checked again in the transaction. The view can be moved
+    // into another view, but not into itself or the views that belong to it.
+    #[tracing::instrument(level = "debug", skip(self, params), fields(view_id = %params.view_id), err)]
+    pub(crate) async fn move_view(&self, params: MoveViewParams) -> Result<View, WorkspaceError> {
+        let user_id = self.user.user_id()?;
+        let conn = &*self.database.db_write_connection()?;
+        let (moved_view, source_id) = conn
+            .immediate_transaction::<_, WorkspaceError, _>(|| self.move_view_in_transaction(&user_id, &params, conn))?;
+
+        send_dart_notification(&moved_view.id, WorkspaceNotification::ViewUpdated)
+            .payload(moved_view.clone())
+            .send();
+        let _ = notify_views_changed(&source_id, self.trash_can.clone(), conn)?;
+        if source_id != moved_view.belong_to_id {
+            let _ = notify_views_changed(&moved_view.belong_to_id, self.trash_can.clone(), conn)?;
+        }
+        Ok(moved_view)
+    }
+
+    // The operations are applied in one transaction, so the batch is kept only if
+    // all of them succeed. The renamed views must have unique names, there is no
+    // auto rename for them.
+    #[tracing::instrument(level = "debug", skip(self, params), fields(n_operations = params.operations.len()), err)]
+    pub(crate) async fn batch_operation(
+        &self,
+        params: BatchViewOperationParams,
+    ) -> Result<BatchViewChangeset, WorkspaceError> {
+        let changeset = self.apply_batch_operation(params)?;
+        for view_id in &changeset.deleted_view_ids {
+            let _ = self.delete_view(view_id.into()).await;
+        }
+
+        send_dart_notification(&self.user.token()?, WorkspaceNotification::ViewsBatchChanged)
+            .payload(changeset.clone())
+            .send();
+        Ok(changeset)
+    }
+
+    fn apply_batch_operation(&self, params: BatchViewOperationParams) -> WorkspaceResult<BatchViewChangeset> {
+        let user_id = self.user.user_id()?;
+        let conn = &*self.database.db_write_connection()?;
+        let mut updated_ids: Vec<String> = vec![];
+        let mut deleted_ids: Vec<String> = vec![];
+        let mut belong_to_ids: Vec<String> = vec![];
+        let mut trash: Vec<Trash> = vec![];
+        let (changeset, hidden_ids, recent_changed) = conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            for operation in &params.operations {
+                let view_id = &operation.view_id;
+                match operation.ty {
+                    ViewOperationType::Move => {
+                        let move_params = operation.move_params();
+                        let (moved_view, source_id) = self.move_view_in_transaction(&user_id, &move_params, conn)?;
+                        updated_ids.push(moved_view.id);
+                        belong_to_ids.push(source_id);
+                        belong_to_ids.push(moved_view.belong_to_id);
+                    },
+                    ViewOperationType::Rename => {
+                        let view_table = ViewTableSql::read_view(view_id, conn)?;
+                        if is_in_trash(&view_table, &self.trash_can.trash_ids(conn)?, conn)? {
+                            return Err(WorkspaceError::record_not_found());
+                        }
+                        let name = self.resolve_view_name(
+                            &view_table.belong_to_id,
+                            &operation.name,
+                            Some(view_id),
+                            false,
+                            conn,
+                        )?;
+                        let mut update_params = UpdateViewParams::new(view_id);
+                        update_params.name = Some(name);
+                        let update_params = update_params.without_unchanged(&view_table.current_fields());
+                        if !update_params.is_empty() {
+                            let _ = ViewTableSql::update_view(ViewTableChangeset::new(update_params.clone()), conn)?;
+                            let before = Operation::UpdateView(view_table.current_fields());
+                            let _ = self
+                                .operations
+                                .push_update(Operation::UpdateView(update_params), before, conn)?;
+                        }
+                        updated_ids.push(view_id.clone());
+                        belong_to_ids.push(view_table.belong_to_id);
+                    },
+                    ViewOperationType::Delete => {
+                        let view_table = ViewTableSql::read_view(view_id, conn)?;
+                        if is_in_trash(&view_table, &self.trash_can.trash_ids(conn)?, conn)? {
+                            return Err(WorkspaceError::record_not_found());
+                        }
+                        belong_to_ids.push(view_table.belong_to_id.clone());
+                        let view_trash: Trash = view_table.into();
+                        let _ = TrashTableSql::create_trash(vec![view_trash.clone()], conn)?;
+                        trash.push(view_trash);
+                        deleted_ids.push(view_id.clone());
+                    },
+                }
+            }
+
+            if !trash.is_empty() {
+                let _ = self
+                    .operations
+                    .push(Operation::CreateTrash(trash.clone().into()), conn)?;
+            }
+
+            // The views that belong to the deleted ones are hidden with them.
+            let hidden_ids = [read_descendant_ids(&deleted_ids, conn)?, deleted_ids.clone()].concat();
+            let recent_changed = RecentViewTableSql::delete_view_recent_views(&hidden_ids, conn)? > 0;
+            let trash_ids = self.trash_can.trash_ids(conn)?;
+            let mut changeset = BatchViewChangeset {
+                deleted_view_ids: deleted_ids.clone(),
+                ..Default::default()
+            };
+            let mut view_ids = HashSet::new();
+            for view_id in &updated_ids {
+                if view_ids.insert(view_id) && !hidden_ids.contains(view_id) {
+                    changeset
+                        .updated_views
+                        .push(ViewTableSql::read_view(view_id, conn)?.into());
+                }
+            }
+            let mut parent_ids = HashSet::new();
+            for belong_to_id in &belong_to_ids {
+                if parent_ids.insert(belong_to_id)
+                    && !hidden_ids.contains(belong_to_id)
+                    && !trash_ids.contains(belong_to_id)
+                {
+                    changeset.belongings.push(ViewBelongings {
+                        belong_to_id: belong_to_id.clone(),
+                        views: read_local_belonging_view(belong_to_id, self.trash_can.clone(), conn)?,
+                    });
+                }
+            }
+            Ok((changeset, hidden_ids, recent_changed))
+        })?;
+
+        self.operations.flush();
+        if !trash.is_empty() {
+            let _ = self.trash_can.did_create_trash()?;
+        }
+        if recent_changed {
+            let _ = notify_recent_views_changed(&self.user, self.trash_can.clone(), conn)?;
+        }
+        let _ = notify_favorites_if_pinned(
+            &self.user,
+            &[updated_ids, hidden_ids].concat(),
+            self.trash_can.clone(),
+            conn,
+        )?;
+        Ok(changeset)
+    }
+
+    // The favorites are kept per user, the views that are pinned again keep their
+    // place and the new ones are put last.
+    #[tracing::instrument(level = "debug", skip(self, params), err)]
+    pub(crate) async fn pin_views(&self, params: ViewIdentifiers) -> Result<RepeatedView, WorkspaceError> {
+        let user_id = self.user.user_id()?;
+        let conn = &*self.database.db_write_connection()?;
+        conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            let trash_ids = self.trash_can.trash_ids(conn)?;
+            let mut favorites = FavoriteTableSql::read_favorites(&user_id, conn)?;
+            for view_id in &params.view_ids {
+                let view_table = ViewTableSql::read_view(view_id, conn)?;
+                if is_in_trash(&view_table, &trash_ids, conn)? {
+                    return Err(WorkspaceError::record_not_found());
+                }
+                if favorites.iter().any(|favorite| &favorite.view_id == view_id) {
+                    continue;
+                }
+
+                let last_key = favorites.last().map_or("", |favorite| favorite.order_key.as_str());
+                let table = FavoriteTable::new(&user_id, view_id, order_key_between(last_key, None));
+                let _ = FavoriteTableSql::create_favorite(table.clone(), conn)?;
+                favorites.push(table);
+            }
+            Ok(())
+        })?;
+        notify_favorites_changed(&self.user, self.trash_can.clone(), conn)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, params), err)]
+    pub(crate) async fn unpin_views(&self, params: ViewIdentifiers) -> Result<RepeatedView, WorkspaceError> {
+        let user_id = self.user.user_id()?;
+        let conn = &*self.database.db_write_connection()?;
+        let _ = FavoriteTableSql::delete_favorites(&user_id, &params.view_ids, conn)?;
+        notify_favorites_changed(&self.user, self.trash_can.clone(), conn)
+    }
+
+    pub(crate) fn read_favorites(&self) -> Result<RepeatedView, WorkspaceError> {
+        let user_id = self.user.user_id()?;
+        let conn = self.database.db_connection()?;
+        read_local_favorites(&user_id, self.trash_can.clone(), &conn)
+    }
+
+    // The views opened last come first, the ones in the trash are removed from the
+    // history when they're trashed.
+    pub(crate) fn read_recent_views(&self) -> Result<RepeatedView, WorkspaceError> {
+        let user_id = self.user.user_id()?;
+        let conn = self.database.db_connection()?;
+        read_local_recent_views(&user_id, self.trash_can.clone(), &conn)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, params), fields(view_id = %params.view_id), err)]
+    pub(crate) async fn move_favorite(&self, params: MoveFavoriteParams) -> Result<RepeatedView, WorkspaceError> {
+        let user_id = self.user.user_id()?;
+        let conn = &*self.database.db_write_connection()?;
+        conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            let mut favorites = FavoriteTableSql::read_favorites(&user_id, conn)?;
+            if !favorites.iter().any(|favorite| favorite.view_id == params.view_id) {
+                let msg = format!("The view {} isn't pinned", params.view_id);
+                return Err(WorkspaceError::record_not_found().context(msg));
+            }
+
+            favorites.retain(|favorite| favorite.view_id != params.view_id);
+            let index = match &params.prev_view_id {
+                None => 0,
+                Some(prev_view_id) => match favorites.iter().position(|favorite| &favorite.view_id == prev_view_id) {
+                    None => {
+                        let msg = format!("The view {} isn't pinned", prev_view_id);
+                        return Err(WorkspaceError::record_not_found().context(msg));
+                    },
+                    Some(position) => position + 1,
+                },
+            };
+
+            let keys = favorites
+                .iter()
+                .map(|favorite| favorite.order_key.as_str())
+                .collect::<Vec<_>>();
+            let order_key = match order_key_at(&keys, index) {
+                Some(order_key) => order_key,
+                None => {
+                    let keys = order_keys(favorites.len());
+                    for (favorite, key) in favorites.iter().zip(&keys) {
+                        let _ = FavoriteTableSql::update_order_key(&user_id, &favorite.view_id, key, conn)?;
+                    }
+                    let keys = keys.iter().map(|key| key.as_str()).collect::<Vec<_>>();
+                    order_key_at(&keys, index).unwrap()
+                },
+            };
+            FavoriteTableSql::update_order_key(&user_id, &params.view_id, &order_key, conn)
+        })?;
+        notify_favorites_changed(&self.user, self.trash_can.clone(), conn)
+    }
+
     pub(crate) async fn apply_doc_delta(&self, params: DocDelta) -> Result<DocDelta, WorkspaceError> {
         let doc = self.document.apply_doc_delta(params).await?;
         Ok(doc)
     }
 
+    pub(crate) async fn apply_remote_doc_delta(&self, params: DocDelta) -> Result<DocDelta, WorkspaceError> {
+        let doc = self.document.apply_remote_delta(params).await?;
+        Ok(doc)
+    }
+
     pub(crate) fn latest_visit_view(&self) -> WorkspaceResult<Option<View>> {
-        match KV::get_str(LATEST_VIEW_ID) {
+        match KV::get_str(&self.latest_view_key()?) {
             None => Ok(None),
             Some(view_id) => {
                 let conn = self.database.db_connection()?;
@@ -213,31 +955,240 @@ impl ViewController {
         }
     }
 
-    pub(crate) fn set_latest_view(&self, view: &View) { KV::set_str(LATEST_VIEW_ID, view.id.clone()); }
+    pub(crate) fn set_latest_view(&self, view: &View) {
+        match self.latest_view_key() {
+            Ok(key) => KV::set_str(&key, view.id.clone()),
+            Err(e) => log::error!("Set latest view failed: {:?}", e),
+        }
+    }
+
+    // Each user keeps their own latest view, the views of the other users aren't
+    // in the database of the current one.
+    fn latest_view_key(&self) -> WorkspaceResult<String> { Ok(format!("{}_{}", LATEST_VIEW_ID, self.user.user_id()?)) }
 }
 
 impl ViewController {
-    #[tracing::instrument(skip(self), err)]
-    async fn create_view_on_server(&self, params: CreateViewParams) -> Result<View, WorkspaceError> {
-        let token = self.user.token()?;
-        let view = self.server.create_view(&token, params).await?;
+    // Returns the name if the other views that belong to the same app or view
+    // don't have it, the ones in the trash aren't counted. Otherwise the first
+    // unique name is returned if auto_rename is set, or the conflict error that
+    // suggests the unique names.
+    fn resolve_view_name(
+        &self,
+        belong_to_id: &str,
+        name: &str,
+        view_id: Option<&str>,
+        auto_rename: bool,
+        conn: &SqliteConnection,
+    ) -> WorkspaceResult<String> {
+        let trash_ids = self.trash_can.trash_ids(conn)?;
+        let taken = ViewTableSql::read_views(belong_to_id, conn)?
+            .into_iter()
+            .filter(|view_table| Some(view_table.id.as_str()) != view_id && !trash_ids.contains(&view_table.id))
+            .map(|view_table| view_table.name)
+            .collect::<Vec<_>>();
+        if !taken.iter().any(|taken_name| taken_name == name) {
+            return Ok(name.to_owned());
+        }
+
+        let mut suggestions = unique_view_names(name, &taken, MAX_NAME_SUGGESTIONS);
+        match auto_rename {
+            true => Ok(suggestions.remove(0)),
+            false => Err(WorkspaceError::view_name_conflict(suggestions)),
+        }
+    }
+
+    // Moves the view and returns it with the id of the app or the view it
+    // belonged to, the caller holds the transaction.
+    fn move_view_in_transaction(
+        &self,
+        user_id: &str,
+        params: &MoveViewParams,
+        conn: &SqliteConnection,
+    ) -> WorkspaceResult<(View, String)> {
+        let view_table = ViewTableSql::read_view(&params.view_id, conn)?;
+        let trash_ids = self.trash_can.trash_ids(conn)?;
+        if is_in_trash(&view_table, &trash_ids, conn)? {
+            return Err(WorkspaceError::record_not_found());
+        }
+
+        let source_id = view_table.belong_to_id.clone();
+        if params.belong_to_id != source_id {
+            let ancestor_ids = read_ancestor_ids(&params.belong_to_id, conn)?;
+            if ancestor_ids.contains(&view_table.id) {
+                return Err(WorkspaceError::view_parent());
+            }
+            if ancestor_ids.iter().any(|ancestor_id| trash_ids.contains(ancestor_id)) {
+                return Err(WorkspaceError::record_not_found().context("The destination is in the trash"));
+            }
+
+            let app_table = AppTableSql::read_app(ancestor_ids.last().unwrap(), conn)?;
+            let role = read_role(&app_table.workspace_id, user_id, conn)?;
+            if !role.map_or(false, |role| role_allows(role, EventPermission::Write)) {
+                return Err(WorkspaceError::permission_denied());
+            }
+        }
+
+        let mut siblings = ViewTableSql::read_views(&params.belong_to_id, conn)?;
+        siblings.retain(|sibling| sibling.id != view_table.id);
+        let index = match &params.prev_view_id {
+            None => 0,
+            Some(prev_view_id) => match siblings.iter().position(|sibling| &sibling.id == prev_view_id) {
+                None => {
+                    let msg = format!("The view {} isn't in {}", prev_view_id, params.belong_to_id);
+                    return Err(WorkspaceError::record_not_found().context(msg));
+                },
+                Some(position) => position + 1,
+            },
+        };
+
+        let keys = siblings
+            .iter()
+            .map(|sibling| sibling.order_key.as_str())
+            .collect::<Vec<_>>();
+        let order_key = match order_key_at(&keys, index) {
+            Some(order_key) => order_key,
+            None => {
+                // The siblings have the same keys, they only get new keys once.
+                let keys = order_keys(siblings.len());
+                for (sibling, key) in siblings.iter().zip(&keys) {
+                    let _ = ViewTableSql::move_view(&sibling.id, &sibling.belong_to_id, key, conn)?;
+                }
+                let keys = keys.iter().map(|key| key.as_str()).collect::<Vec<_>>();
+                order_key_at(&keys, index).unwrap()
+            },
+        };
+        let _ = ViewTableSql::move_view(&view_table.id, &params.belong_to_id, &order_key, conn)?;
+        let moved_view: View = ViewTableSql::read_view(&view_table.id, conn)?.into();
+        Ok((moved_view, source_id))
+    }
+
+    async fn create_doc_view(&self, belong_to_id: String, name: String, delta: Delta) -> Result<View, WorkspaceError> {
+        let create_params = CreateViewParams {
+            belong_to_id,
+            name,
+            desc: "".to_owned(),
+            thumbnail: "".to_owned(),
+            view_type: ViewType::Doc,
+            data: delta.to_json(),
+            auto_rename: true,
+        };
+        let view = self.create_view_from_params(create_params).await?;
+
+        // The server may create the doc with its initial data instead of the
+        // given one, replace it with the delta then.
+        let _ = self.replace_doc_data(&view.id, delta).await?;
         Ok(view)
     }
 
+    fn record_recent_view(&self, view_id: &str) -> WorkspaceResult<()> {
+        let user_id = self.user.user_id()?;
+        let conn = &*self.database.db_write_connection()?;
+        let opened_time = Local::now().timestamp_millis();
+        conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            RecentViewTableSql::record_open(&user_id, view_id, opened_time, MAX_RECENT_VIEWS, conn)
+        })?;
+        let _ = notify_recent_views_changed(&self.user, self.trash_can.clone(), conn)?;
+        Ok(())
+    }
+
+    // Sets the icon and the cover of the view that was copied from another one.
+    async fn update_view_metadata(&self, view: View, icon: String, cover: Option<String>) -> WorkspaceResult<View> {
+        if icon.is_empty() && cover.is_none() {
+            return Ok(view);
+        }
+
+        let params = UpdateViewParams {
+            icon: Some(icon),
+            cover,
+            ..UpdateViewParams::new(&view.id)
+        };
+        self.update_view(params).await
+    }
+
+    // Attaches a copy of the asset to another document, None if the asset was
+    // deleted.
+    fn copy_asset(&self, asset_id: &str, doc_id: &str, new_doc_id: &str) -> WorkspaceResult<Option<String>> {
+        let assets = self.document.list_assets(doc_id)?;
+        let asset = match assets.items.into_iter().find(|asset| asset.id == asset_id) {
+            None => return Ok(None),
+            Some(asset) => asset,
+        };
+
+        let asset_data = self.document.read_asset(asset_id)?;
+        let request = CreateAssetRequest {
+            doc_id: new_doc_id.to_owned(),
+            name: asset.name,
+            mime_type: asset.mime_type,
+            data: asset_data.data,
+        };
+        let asset = self.document.attach_asset(request)?;
+        Ok(Some(asset.id))
+    }
+
+    pub(crate) async fn read_doc_delta(&self, doc_id: &str) -> Result<Delta, WorkspaceError> {
+        let doc_identifier: DocIdentifier = doc_id.to_owned().into();
+        let doc = self
+            .document
+            .read_document_data(doc_identifier, self.database.db_pool()?)
+            .await?;
+        let delta = Delta::from_json(&doc.data).map_err(internal_error)?;
+        Ok(delta)
+    }
+
+    // The delta is added at the end of the document, it replaces the document
+    // if it's empty.
+    async fn append_doc_delta(&self, doc_id: &str, delta: Delta) -> Result<(), WorkspaceError> {
+        let current = self.read_doc_delta(doc_id).await?;
+        if current == doc_initial_delta() {
+            return self.replace_doc_data(doc_id, delta).await;
+        }
+
+        let mut append = DeltaBuilder::new().retain(current.target_len).build();
+        delta.ops.into_iter().for_each(|op| append.add(op));
+        let _ = self
+            .document
+            .apply_doc_delta(DocDelta {
+                doc_id: doc_id.to_owned(),
+                data: append.to_json(),
+            })
+            .await?;
+        Ok(())
+    }
+
+    pub(crate) async fn replace_doc_data(&self, doc_id: &str, delta: Delta) -> Result<(), WorkspaceError> {
+        let doc_identifier: DocIdentifier = doc_id.to_owned().into();
+        let doc = self
+            .document
+            .read_document_data(doc_identifier, self.database.db_pool()?)
+            .await?;
+        let current = Delta::from_json(&doc.data).map_err(internal_error)?;
+        if current != delta {
+            let mut replace = Delta::new();
+            replace.delete(current.target_len);
+            delta.ops.into_iter().for_each(|op| replace.add(op));
+            let _ = self
+                .document
+                .apply_doc_delta(DocDelta {
+                    doc_id: doc_id.to_owned(),
+                    data: replace.to_json(),
+                })
+                .await?;
+        }
+        Ok(())
+    }
+
+    // The initial data of the document is encrypted like its revisions if the
+    // workspace is encrypted.
     #[tracing::instrument(skip(self), err)]
-    fn update_view_on_server(&self, params: UpdateViewParams) -> Result<(), WorkspaceError> {
+    async fn create_view_on_server(&self, mut params: CreateViewParams) -> Result<View, WorkspaceError> {
+        params.data = {
+            let conn = &*self.database.db_connection()?;
+            let workspace_id = read_belong_to_workspace_id(&params.belong_to_id, conn)?;
+            self.keys.seal_text(&workspace_id, params.data)?
+        };
         let token = self.user.token()?;
-        let server = self.server.clone();
-        tokio::spawn(async move {
-            match server.update_view(&token, params).await {
-                Ok(_) => {},
-                Err(e) => {
-                    // TODO: retry?
-                    log::error!("Update view failed: {:?}", e);
-                },
-            }
-        });
-        Ok(())
+        let view = self.server.create_view(&token, params).await?;
+        Ok(view)
     }
 
     #[tracing::instrument(skip(self), err)]
@@ -272,6 +1223,7 @@ impl ViewController {
 
     fn listen_trash_can_event(&self) {
         let mut rx = self.trash_can.subscribe();
+        let user = self.user.clone();
         let database = self.database.clone();
         let document = self.document.clone();
         let trash_can = self.trash_can.clone();
@@ -285,7 +1237,14 @@ impl ViewController {
                 }));
                 match stream.next().await {
                     Some(event) => {
-                        handle_trash_event(database.clone(), document.clone(), trash_can.clone(), event).await
+                        handle_trash_event(
+                            user.clone(),
+                            database.clone(),
+                            document.clone(),
+                            trash_can.clone(),
+                            event,
+                        )
+                        .await
                     },
                     None => {},
                 }
@@ -294,8 +1253,9 @@ impl ViewController {
     }
 }
 
-#[tracing::instrument(level = "trace", skip(database, document, trash_can))]
+#[tracing::instrument(level = "trace", skip(user, database, document, trash_can))]
 async fn handle_trash_event(
+    user: Arc<dyn WorkspaceUser>,
     database: Arc<dyn WorkspaceDatabase>,
     document: Arc<FlowyDocument>,
     trash_can: Arc<TrashCan>,
@@ -307,11 +1267,17 @@ async fn handle_trash_event(
         TrashEvent::NewTrash(identifiers, ret) => {
             let result = || {
                 let conn = &*db_result?;
+                let view_ids = trash_ids_of(&identifiers);
+                let view_ids = [read_descendant_ids(&view_ids, conn)?, view_ids].concat();
                 let view_tables = get_view_table_from(identifiers, conn)?;
                 for view_table in view_tables {
                     let _ = notify_views_changed(&view_table.belong_to_id, trash_can.clone(), conn)?;
                     notify_dart(view_table, WorkspaceNotification::ViewDeleted);
                 }
+                let _ = notify_favorites_if_pinned(&user, &view_ids, trash_can.clone(), conn)?;
+                if RecentViewTableSql::delete_view_recent_views(&view_ids, conn)? > 0 {
+                    let _ = notify_recent_views_changed(&user, trash_can.clone(), conn)?;
+                }
                 Ok::<(), WorkspaceError>(())
             };
             let _ = ret.send(result()).await;
@@ -319,11 +1285,14 @@ async fn handle_trash_event(
         TrashEvent::Putback(identifiers, ret) => {
             let result = || {
                 let conn = &*db_result?;
+                let view_ids = trash_ids_of(&identifiers);
+                let view_ids = [read_descendant_ids(&view_ids, conn)?, view_ids].concat();
                 let view_tables = get_view_table_from(identifiers, conn)?;
                 for view_table in view_tables {
                     let _ = notify_views_changed(&view_table.belong_to_id, trash_can.clone(), conn)?;
                     notify_dart(view_table, WorkspaceNotification::ViewRestored);
                 }
+                let _ = notify_favorites_if_pinned(&user, &view_ids, trash_can.clone(), conn)?;
                 Ok::<(), WorkspaceError>(())
             };
             let _ = ret.send(result()).await;
@@ -331,21 +1300,42 @@ async fn handle_trash_event(
         TrashEvent::Delete(identifiers, ret) => {
             let result = || {
                 let conn = &*db_result?;
-                let _ = conn.immediate_transaction::<_, WorkspaceError, _>(|| {
-                    let mut notify_ids = HashSet::new();
-                    for identifier in identifiers.items {
-                        let view_table = ViewTableSql::read_view(&identifier.id, conn)?;
-                        let _ = ViewTableSql::delete_view(&identifier.id, conn)?;
-                        let _ = document.delete(identifier.id.clone().into())?;
-                        notify_ids.insert(view_table.belong_to_id);
+                let view_ids = trash_ids_of(&identifiers);
+                let (files, pinned) = conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+                    // The views that belong to the deleted views are deleted with them, the ones
+                    // that were trashed on their own are removed from the trash too.
+                    let descendant_ids = read_descendant_ids(&view_ids, conn)?;
+                    let _ = TrashTableSql::delete_trash_with_ids(&descendant_ids, conn)?;
+                    let deleted_ids = [view_ids.clone(), descendant_ids].concat();
+
+                    let pinned = is_any_pinned(&user, &deleted_ids, conn)?;
+                    let _ = FavoriteTableSql::delete_view_favorites(&deleted_ids, conn)?;
+                    let _ = RecentViewTableSql::delete_view_recent_views(&deleted_ids, conn)?;
+                    let notify_ids = ViewTableSql::read_views_with_ids(&view_ids, conn)?
+                        .into_iter()
+                        .map(|view_table| view_table.belong_to_id)
+                        .filter(|belong_to_id| !deleted_ids.contains(belong_to_id))
+                        .collect::<HashSet<String>>();
+                    let _ = ViewTableSql::delete_views(&deleted_ids, conn)?;
+
+                    // The documents are deleted with the views, their files once it's committed.
+                    let mut files = vec![];
+                    for view_id in deleted_ids {
+                        files.push(document.delete_with(view_id.into(), conn)?);
                     }
 
                     for notify_id in notify_ids {
                         let _ = notify_views_changed(&notify_id, trash_can.clone(), conn)?;
                     }
 
-                    Ok(())
+                    Ok((files, pinned))
                 })?;
+                for files in files {
+                    document.remove_files(files);
+                }
+                if pinned {
+                    let _ = notify_favorites_changed(&user, trash_can.clone(), conn)?;
+                }
                 Ok::<(), WorkspaceError>(())
             };
             let _ = ret.send(result()).await;
@@ -383,6 +1373,102 @@ fn notify_views_changed(belong_to_id: &str, trash_can: Arc<TrashCan>, conn: &Sql
     Ok(())
 }
 
+fn trash_ids_of(identifiers: &TrashIdentifiers) -> Vec<String> {
+    identifiers.items.iter().map(|item| item.id.clone()).collect()
+}
+
+// The favorites are sorted by the user, the views that are in the trash are
+// skipped until they're put back.
+fn read_local_favorites(
+    user_id: &str,
+    trash_can: Arc<TrashCan>,
+    conn: &SqliteConnection,
+) -> WorkspaceResult<RepeatedView> {
+    let favorites = FavoriteTableSql::read_favorites(user_id, conn)?;
+    let view_ids = favorites
+        .iter()
+        .map(|favorite| favorite.view_id.clone())
+        .collect::<Vec<_>>();
+    read_visible_views(&view_ids, trash_can, conn)
+}
+
+fn read_local_recent_views(
+    user_id: &str,
+    trash_can: Arc<TrashCan>,
+    conn: &SqliteConnection,
+) -> WorkspaceResult<RepeatedView> {
+    let recent_views = RecentViewTableSql::read_recent_views(user_id, conn)?;
+    let view_ids = recent_views
+        .iter()
+        .map(|recent_view| recent_view.view_id.clone())
+        .collect::<Vec<_>>();
+    read_visible_views(&view_ids, trash_can, conn)
+}
+
+// The views in the order of the ids, the ones that are deleted or hidden by the
+// trash are skipped.
+fn read_visible_views(
+    view_ids: &[String],
+    trash_can: Arc<TrashCan>,
+    conn: &SqliteConnection,
+) -> WorkspaceResult<RepeatedView> {
+    let trash_ids = trash_can.trash_ids(conn)?;
+    let mut view_tables = vec![];
+    for view_table in ViewTableSql::read_views_with_ids(view_ids, conn)? {
+        if !is_in_trash(&view_table, &trash_ids, conn)? {
+            view_tables.push(view_table);
+        }
+    }
+
+    let items = view_ids
+        .iter()
+        .flat_map(|view_id| view_tables.iter().find(|view_table| &view_table.id == view_id))
+        .map(|view_table| view_table.clone().into())
+        .collect::<Vec<View>>();
+    Ok(RepeatedView { items })
+}
+
+fn notify_recent_views_changed(
+    user: &Arc<dyn WorkspaceUser>,
+    trash_can: Arc<TrashCan>,
+    conn: &SqliteConnection,
+) -> WorkspaceResult<()> {
+    let repeated_view = read_local_recent_views(&user.user_id()?, trash_can, conn)?;
+    send_dart_notification(&user.token()?, WorkspaceNotification::RecentViewsChanged)
+        .payload(repeated_view)
+        .send();
+    Ok(())
+}
+
+fn is_any_pinned(user: &Arc<dyn WorkspaceUser>, view_ids: &[String], conn: &SqliteConnection) -> WorkspaceResult<bool> {
+    let favorites = FavoriteTableSql::read_favorites(&user.user_id()?, conn)?;
+    Ok(favorites.iter().any(|favorite| view_ids.contains(&favorite.view_id)))
+}
+
+fn notify_favorites_if_pinned(
+    user: &Arc<dyn WorkspaceUser>,
+    view_ids: &[String],
+    trash_can: Arc<TrashCan>,
+    conn: &SqliteConnection,
+) -> WorkspaceResult<()> {
+    if is_any_pinned(user, view_ids, conn)? {
+        let _ = notify_favorites_changed(user, trash_can, conn)?;
+    }
+    Ok(())
+}
+
+fn notify_favorites_changed(
+    user: &Arc<dyn WorkspaceUser>,
+    trash_can: Arc<TrashCan>,
+    conn: &SqliteConnection,
+) -> WorkspaceResult<RepeatedView> {
+    let repeated_view = read_local_favorites(&user.user_id()?, trash_can, conn)?;
+    send_dart_notification(&user.token()?, WorkspaceNotification::FavoritesChanged)
+        .payload(repeated_view.clone())
+        .send();
+    Ok(repeated_view)
+}
+
 fn read_local_belonging_view(
     belong_to_id: &str,
     trash_can: Arc<TrashCan>,
@@ -399,3 +1485,122 @@ fn read_local_belonging_view(
 
     Ok(RepeatedView { items: views })
 }
+
+fn read_local_view_tree(
+    belong_to_id: &str,
+    depth: i32,
+    trash_ids: &[String],
+    conn: &SqliteConnection,
+) -> WorkspaceResult<RepeatedView> {
+    let mut items = vec![];
+    for view_table in ViewTableSql::read_views(belong_to_id, conn)? {
+        if trash_ids.contains(&view_table.id) {
+            continue;
+        }
+
+        let mut view: View = view_table.into();
+        if depth > 1 {
+            view.belongings = read_local_view_tree(&view.id, depth - 1, trash_ids, conn)?;
+        }
+        items.push(view);
+    }
+    Ok(RepeatedView { items })
+}
+
+// The ids from the belong_to_id up to the app the views belong to, the id of
+// the app is the last one.
+fn read_ancestor_ids(belong_to_id: &str, conn: &SqliteConnection) -> WorkspaceResult<Vec<String>> {
+    let mut ancestor_ids = vec![belong_to_id.to_owned()];
+    loop {
+        let parent_id = ancestor_ids.last().unwrap().clone();
+        match ViewTableSql::read_views_with_ids(&[parent_id], conn)?.pop() {
+            Some(view_table) if !ancestor_ids.contains(&view_table.belong_to_id) => {
+                ancestor_ids.push(view_table.belong_to_id);
+            },
+            _ => return Ok(ancestor_ids),
+        }
+    }
+}
+
+// The ids of the views that belong to the views at any depth, without the ids
+// of the views themselves.
+fn read_descendant_ids(view_ids: &[String], conn: &SqliteConnection) -> WorkspaceResult<Vec<String>> {
+    let mut descendant_ids: Vec<String> = vec![];
+    let mut parent_ids = view_ids.to_vec();
+    while let Some(parent_id) = parent_ids.pop() {
+        for view_table in ViewTableSql::read_views(&parent_id, conn)? {
+            if view_ids.contains(&view_table.id) || descendant_ids.contains(&view_table.id) {
+                continue;
+            }
+            parent_ids.push(view_table.id.clone());
+            descendant_ids.push(view_table.id);
+        }
+    }
+    Ok(descendant_ids)
+}
+
+// The views aren't trashed with the view they belong to, they're hidden until
+// it's put back.
+fn is_in_trash(view_table: &ViewTable, trash_ids: &[String], conn: &SqliteConnection) -> WorkspaceResult<bool> {
+    if trash_ids.contains(&view_table.id) {
+        return Ok(true);
+    }
+    let ancestor_ids = read_ancestor_ids(&view_table.belong_to_id, conn)?;
+    Ok(ancestor_ids.iter().any(|ancestor_id| trash_ids.contains(ancestor_id)))
+}
+
+// The id of the workspace the view belongs to, None if the view doesn't exist
+// or it's hidden by the trash.
+pub(crate) fn read_visible_view_workspace_id(
+    view_id: &str,
+    trash_ids: &[String],
+    conn: &SqliteConnection,
+) -> WorkspaceResult<Option<String>> {
+    let view_table = match ViewTableSql::read_views_with_ids(&[view_id.to_owned()], conn)?.pop() {
+        None => return Ok(None),
+        Some(view_table) => view_table,
+    };
+    if is_in_trash(&view_table, trash_ids, conn)? {
+        return Ok(None);
+    }
+    Ok(Some(read_belong_to_workspace_id(&view_table.belong_to_id, conn)?))
+}
+
+// The workspace of the view whether it's in the trash or not, None if there's
+// no such view.
+pub(crate) fn read_view_workspace_id(view_id: &str, conn: &SqliteConnection) -> WorkspaceResult<Option<String>> {
+    match ViewTableSql::read_views_with_ids(&[view_id.to_owned()], conn)?.pop() {
+        None => Ok(None),
+        Some(view_table) => Ok(Some(read_belong_to_workspace_id(&view_table.belong_to_id, conn)?)),
+    }
+}
+
+// The workspace of the app, or of the view whether it's in the trash or not,
+// None if there's no such app or view.
+pub(crate) fn read_object_workspace_id(object_id: &str, conn: &SqliteConnection) -> WorkspaceResult<Option<String>> {
+    let ancestor_ids = read_ancestor_ids(object_id, conn)?;
+    AppTableSql::read_app_workspace_id(ancestor_ids.last().unwrap(), conn)
+}
+
+// The workspace of the app or of the view that a view belongs to.
+pub(crate) fn read_belong_to_workspace_id(belong_to_id: &str, conn: &SqliteConnection) -> WorkspaceResult<String> {
+    let ancestor_ids = read_ancestor_ids(belong_to_id, conn)?;
+    let app_table = AppTableSql::read_app(ancestor_ids.last().unwrap(), conn)?;
+    Ok(app_table.workspace_id)
+}
+
+// The shared text line by line, it ends with a line break like the documents.
+fn text_delta(text: &str) -> Delta {
+    let text = text.replace("\r\n", "\n");
+    DeltaBuilder::new()
+        .insert(text.trim_end_matches('\n'))
+        .insert("\n")
+        .build()
+}
+
+fn link_delta(name: &str, link: &str) -> Delta {
+    DeltaBuilder::new()
+        .insert_with_attributes(name, Attribute::Link(link).into())
+        .insert("\n")
+        .build()
+}
@@ -2,24 +2,83 @@ use crate::{
     errors::*,
     module::{WorkspaceDatabase, WorkspaceUser},
     notify::*,
-    services::{read_local_workspace_apps, server::Server, AppController, TrashCan, ViewController},
-    sql_tables::workspace::{WorkspaceTable, WorkspaceTableChangeset, WorkspaceTableSql},
+    services::{
+        built_in_workspace_template,
+        built_in_workspace_templates,
+        check_integrity,
+        notify_trash_changed,
+        read_local_workspace_apps,
+        read_role,
+        read_visible_view_workspace_id,
+        server::Server,
+        AppController,
+        EnexNotebook,
+        EvernoteImporter,
+        LanSync,
+        NotionExport,
+        NotionImporter,
+        Operation,
+        OperationQueue,
+        SyncEngine,
+        TrashCan,
+        ViewController,
+        WorkspacePermission,
+        WorkspaceSearch,
+    },
+    sql_tables::{
+        app::AppTableSql,
+        favorite::FavoriteTableSql,
+        member::{WorkspaceInvitationTableSql, WorkspaceMemberTableSql},
+        recent_view::RecentViewTableSql,
+        setting::WorkspaceSettingTableSql,
+        trash::TrashTableSql,
+        view::ViewTableSql,
+        workspace::{WorkspaceTable, WorkspaceTableChangeset, WorkspaceTableSql},
+    },
 };
+use bytes::Bytes;
 use chrono::Utc;
 use flowy_database::SqliteConnection;
-use flowy_document_infra::{entities::doc::DocDelta, user_default::initial_read_me};
+use flowy_dispatch::prelude::EventGuard;
+use flowy_document_infra::user_default::initial_read_me;
 use flowy_infra::kv::KV;
 use flowy_workspace_infra::{
-    entities::{app::RepeatedApp, view::View, workspace::*},
+    entities::{
+        app::{ColorStyle, CreateAppParams, RepeatedApp},
+        search::{RepeatedSearchResult, SearchRequest},
+        share::*,
+        sync::{
+            QuerySyncStatusRequest,
+            RemoteChange,
+            RepeatedLanPeer,
+            RepeatedObjectSyncStatus,
+            RepeatedRemoteChange,
+            RepeatedSyncConflict,
+            RepeatedSyncExclusion,
+            ResolveConflictRequest,
+            ServerPush,
+            ServerPushType,
+            SubmitConflictResolutionRequest,
+            SyncExclusionRequest,
+            SyncResult,
+            SyncSettings,
+            SyncState,
+            SyncTraffic,
+        },
+        template::{CreateViewFromTemplateParams, CreateWorkspaceFromTemplateParams, RepeatedWorkspaceTemplate},
+        view::View,
+        workspace::*,
+    },
     user_default,
 };
-use lazy_static::lazy_static;
 use parking_lot::RwLock;
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    convert::{TryFrom, TryInto},
+    sync::Arc,
+};
 
-lazy_static! {
-    static ref INIT_WORKSPACE: RwLock<HashMap<String, bool>> = RwLock::new(HashMap::new());
-}
+const ARCHIVE_EXTENSION: &str = "flowy";
 
 pub struct WorkspaceController {
     pub user: Arc<dyn WorkspaceUser>,
@@ -28,7 +87,16 @@ pub struct WorkspaceController {
     pub(crate) database: Arc<dyn WorkspaceDatabase>,
     pub(crate) app_controller: Arc<AppController>,
     pub(crate) trash_can: Arc<TrashCan>,
+    pub(crate) permission: Arc<WorkspacePermission>,
+    operations: OperationQueue,
+    sync_engine: Arc<SyncEngine>,
+    lan_sync: LanSync,
     server: Server,
+    search: WorkspaceSearch,
+    notion: NotionImporter,
+    evernote: EvernoteImporter,
+    // The tokens of the sessions the workspace was initialized for.
+    initialized_tokens: RwLock<HashSet<String>>,
 }
 
 impl WorkspaceController {
@@ -38,13 +106,16 @@ impl WorkspaceController {
         app_controller: Arc<AppController>,
         view_controller: Arc<ViewController>,
         trash_can: Arc<TrashCan>,
+        permission: Arc<WorkspacePermission>,
+        operations: OperationQueue,
+        sync_engine: Arc<SyncEngine>,
+        lan_sync: LanSync,
         server: Server,
+        notion: NotionImporter,
+        evernote: EvernoteImporter,
     ) -> Self {
-        if let Ok(token) = user.token() {
-            INIT_WORKSPACE.write().insert(token, false);
-        }
-
         let workspace_sql = Arc::new(WorkspaceTableSql {});
+        let search = WorkspaceSearch::new(database.clone(), trash_can.clone());
         Self {
             user,
             workspace_sql,
@@ -52,23 +123,128 @@ impl WorkspaceController {
             app_controller,
             view_controller,
             trash_can,
+            permission,
+            operations,
+            sync_engine,
+            lan_sync,
             server,
+            search,
+            notion,
+            evernote,
+            initialized_tokens: RwLock::new(HashSet::new()),
+        }
+    }
+
+    pub fn permission_guard(&self) -> Arc<dyn EventGuard> { self.permission.clone() }
+
+    pub(crate) fn search(&self, request: SearchRequest) -> WorkspaceResult<RepeatedSearchResult> {
+        self.search.search(request)
+    }
+
+    // Sends the local changes that the server wasn't told about yet, returns how
+    // many were sent. They're sent in the background after each change too with
+    // the Automatic trigger.
+    pub async fn send_pending_operations(&self) -> WorkspaceResult<usize> { self.operations.send_pending().await }
+
+    // Applies the changes pulled from the server and sends the local ones, e.g.
+    // when the connection is back.
+    pub async fn sync(&self) -> WorkspaceResult<SyncResult> { self.sync_engine.sync().await }
+
+    // Returns when the trigger of the user, see SyncSettings, wants the workspace
+    // to be synced and the network allows it.
+    pub async fn next_scheduled_sync(&self) { self.sync_engine.next_scheduled().await }
+
+    pub fn sync_did_reconnect(&self) { self.sync_engine.did_reconnect() }
+
+    // E.g. the network isn't metered anymore.
+    pub fn sync_network_did_change(&self) { self.sync_engine.network_did_change() }
+
+    pub fn sync_state(&self) -> WorkspaceResult<SyncState> { self.sync_engine.state() }
+
+    pub fn sync_settings(&self) -> WorkspaceResult<SyncSettings> { self.sync_engine.settings() }
+
+    // The settings are kept even if the LAN sync can't start, e.g. the network
+    // doesn't allow multicast.
+    pub fn update_sync_settings(&self, settings: SyncSettings) -> WorkspaceResult<()> {
+        let lan_sync = settings.lan_sync;
+        let _ = self.sync_engine.update_settings(settings)?;
+        if let Err(e) = self.lan_sync.update(lan_sync) {
+            log::error!("Start the LAN sync failed: {:?}", e);
+        }
+        Ok(())
+    }
+
+    // The devices of the same account that were found on the local network.
+    pub fn read_lan_peers(&self) -> WorkspaceResult<RepeatedLanPeer> { self.lan_sync.read_peers() }
+
+    pub fn sync_traffic(&self) -> SyncTraffic { self.sync_engine.traffic() }
+
+    // The conflicts that wait for the user, see ConflictStrategy::SurfaceToUser.
+    pub fn read_sync_conflicts(&self) -> WorkspaceResult<RepeatedSyncConflict> { self.sync_engine.read_conflicts() }
+
+    pub async fn resolve_sync_conflict(&self, request: ResolveConflictRequest) -> WorkspaceResult<()> {
+        self.sync_engine.resolve_pending_conflict(request).await
+    }
+
+    pub async fn submit_conflict_resolution(&self, request: SubmitConflictResolutionRequest) -> WorkspaceResult<()> {
+        self.sync_engine.submit_conflict_resolution(request).await
+    }
+
+    // Returns how many of the changes were applied.
+    pub async fn sync_status(&self, request: QuerySyncStatusRequest) -> WorkspaceResult<RepeatedObjectSyncStatus> {
+        self.sync_engine.object_status(request).await
+    }
+
+    // Re-including the object sends its local state in the background, see
+    // SyncBackfill.
+    pub fn set_sync_exclusion(&self, request: SyncExclusionRequest) -> WorkspaceResult<()> {
+        self.sync_engine.set_exclusion(request)
+    }
+
+    pub fn read_sync_exclusions(&self) -> WorkspaceResult<RepeatedSyncExclusion> { self.sync_engine.read_exclusions() }
+
+    pub async fn apply_remote_changes(&self, changes: Vec<RemoteChange>) -> WorkspaceResult<usize> {
+        self.sync_engine.apply_changes(changes).await
+    }
+
+    // The pushes are applied like the changes that are pulled, each one notifies
+    // the objects it changed.
+    pub async fn receive_server_push(&self, push: ServerPush) -> WorkspaceResult<()> {
+        let bytes = Bytes::from(push.data);
+        match push.ty {
+            ServerPushType::RemoteChanges => {
+                let changes = RepeatedRemoteChange::try_from(bytes).map_err(internal_error)?;
+                let _ = self.sync_engine.apply_pushed_changes(changes.into_inner()).await?;
+            },
+            ServerPushType::MemberJoined => {
+                let member = WorkspaceMember::try_from(bytes).map_err(internal_error)?;
+                let _ = self.permission.apply_remote_member(member)?;
+            },
+            ServerPushType::MemberLeft => {
+                let member = WorkspaceMember::try_from(bytes).map_err(internal_error)?;
+                let _ = self.permission.apply_remote_member_removal(member)?;
+            },
+            ServerPushType::Unknown => return Err(WorkspaceError::internal().context("Unknown server push")),
         }
+        Ok(())
     }
 
     async fn init(&self, token: &str) -> Result<(), WorkspaceError> {
         log::debug!("Start initializing workspace");
-        if let Some(is_init) = INIT_WORKSPACE.read().get(token) {
-            if *is_init {
-                return Ok(());
-            }
+        if !self.initialized_tokens.write().insert(token.to_owned()) {
+            return Ok(());
         }
         log::debug!("Finish initializing workspace");
-        INIT_WORKSPACE.write().insert(token.to_owned(), true);
         let _ = self.server.init();
         let _ = self.trash_can.init()?;
         let _ = self.view_controller.init()?;
         let _ = self.app_controller.init()?;
+        let _ = self.operations.init()?;
+        if self.sync_engine.settings()?.lan_sync {
+            if let Err(e) = self.lan_sync.start() {
+                log::error!("Start the LAN sync failed: {:?}", e);
+            }
+        }
 
         Ok(())
     }
@@ -83,6 +259,7 @@ impl WorkspaceController {
 
     pub async fn user_did_logout(&self) {
         // TODO: (nathan) do something here
+        let _ = self.lan_sync.stop();
     }
 
     pub async fn user_session_expired(&self) {
@@ -91,28 +268,39 @@ impl WorkspaceController {
 
     pub async fn user_did_sign_up(&self, _token: &str) -> WorkspaceResult<()> {
         log::debug!("Create user default workspace");
+        let user_id = self.user.user_id()?;
         let time = Utc::now();
         let mut workspace = user_default::create_default_workspace(time);
         let apps = workspace.take_apps().into_inner();
         let cloned_workspace = workspace.clone();
 
-        let _ = self.create_workspace(workspace).await?;
-        for mut app in apps {
-            let views = app.take_belongings().into_inner();
-            let _ = self.app_controller.create_app(app).await?;
-            for (index, view) in views.into_iter().enumerate() {
-                if index == 0 {
-                    let delta = initial_read_me();
-                    let doc_delta = DocDelta {
-                        doc_id: view.id.clone(),
-                        data: delta.to_json(),
-                    };
-                    let _ = self.view_controller.apply_doc_delta(doc_delta).await?;
-
-                    self.view_controller.set_latest_view(&view);
+        // The default workspace is created with its apps, views and the read me
+        // document at once, the user doesn't get a part of them.
+        let latest_view = {
+            let conn = self.database.db_write_connection()?;
+            conn.transaction::<_, WorkspaceError, _>(|conn| {
+                let mut latest_view = None;
+                let workspace_table = WorkspaceTable::new(workspace.clone(), &user_id);
+                self.workspace_sql.create_workspace(workspace_table, conn)?;
+                self.permission.add_owner(&workspace.id, conn)?;
+                for mut app in apps {
+                    let views = app.take_belongings().into_inner();
+                    let _ = self.app_controller.save_app(app, conn)?;
+                    for (index, view) in views.into_iter().enumerate() {
+                        if index == 0 {
+                            let _ = self.view_controller.save_doc(&view.id, &initial_read_me(), conn)?;
+                            latest_view = Some(view.clone());
+                        }
+                        let _ = self.view_controller.save_view(view, conn)?;
+                    }
                 }
-                let _ = self.view_controller.create_view(view).await?;
-            }
+                Ok(latest_view)
+            })?
+        };
+
+        set_current_workspace(&user_id, &workspace.id);
+        if let Some(view) = latest_view {
+            self.view_controller.set_latest_view(&view);
         }
 
         let token = self.user.token()?;
@@ -129,6 +317,31 @@ impl WorkspaceController {
         Ok(())
     }
 
+    // The anonymous user's database was moved to the account already, only the
+    // owner of the workspaces needs to be changed.
+    pub async fn user_did_migrate(&self, anonymous_user_id: &str, token: &str) -> WorkspaceResult<()> {
+        let user_id = self.user.user_id()?;
+        {
+            let conn = &*self.database.db_write_connection()?;
+            conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+                self.workspace_sql
+                    .transfer_workspaces(anonymous_user_id, &user_id, conn)?;
+                WorkspaceMemberTableSql::transfer_members(anonymous_user_id, &user_id, conn)?;
+                FavoriteTableSql::transfer_favorites(anonymous_user_id, &user_id, conn)?;
+                RecentViewTableSql::transfer_recent_views(anonymous_user_id, &user_id, conn)?;
+                let repeated_workspace = self.read_local_workspaces(None, &user_id, conn)?;
+                send_dart_notification(token, WorkspaceNotification::UserCreateWorkspace)
+                    .payload(repeated_workspace)
+                    .send();
+                Ok(())
+            })?;
+        }
+
+        log::debug!("workspace initialize after migration");
+        self.init(token).await?;
+        Ok(())
+    }
+
     pub(crate) async fn create_workspace_from_params(
         &self,
         params: CreateWorkspaceParams,
@@ -141,7 +354,7 @@ impl WorkspaceController {
         let user_id = self.user.user_id()?;
         let token = self.user.token()?;
         let workspace_table = WorkspaceTable::new(workspace.clone(), &user_id);
-        let conn = &*self.database.db_connection()?;
+        let conn = &*self.database.db_write_connection()?;
         //[[immediate_transaction]]
         // https://sqlite.org/lang_transaction.html
         // IMMEDIATE cause the database connection to start a new write immediately,
@@ -155,6 +368,7 @@ impl WorkspaceController {
         // reading the database while the transaction is underway.
         conn.immediate_transaction::<_, WorkspaceError, _>(|| {
             self.workspace_sql.create_workspace(workspace_table, conn)?;
+            self.permission.add_owner(&workspace.id, conn)?;
             let repeated_workspace = self.read_local_workspaces(None, &user_id, conn)?;
             send_dart_notification(&token, WorkspaceNotification::UserCreateWorkspace)
                 .payload(repeated_workspace)
@@ -163,19 +377,41 @@ impl WorkspaceController {
             Ok(())
         })?;
 
-        set_current_workspace(&workspace.id);
+        set_current_workspace(&user_id, &workspace.id);
 
         Ok(workspace)
     }
 
-    #[allow(dead_code)]
     pub(crate) async fn update_workspace(&self, params: UpdateWorkspaceParams) -> Result<(), WorkspaceError> {
-        let changeset = WorkspaceTableChangeset::new(params.clone());
-        let workspace_id = changeset.id.clone();
-        let conn = &*self.database.db_connection()?;
+        let workspace_id = params.id.clone();
+        let user_id = self.user.user_id()?;
+        let conn = &*self.database.db_write_connection()?;
         conn.immediate_transaction::<_, WorkspaceError, _>(|| {
-            let _ = self.workspace_sql.update_workspace(changeset, conn)?;
-            let user_id = self.user.user_id()?;
+            let _ = self.permission.require_owner(&workspace_id, conn)?;
+            // Only the fields that change are written and sent to the server.
+            let current = self
+                .workspace_sql
+                .read_workspaces(Some(workspace_id.clone()), &user_id, conn)?
+                .pop()
+                .map(|table| table.current_fields());
+            let params = match &current {
+                Some(current) => params.without_unchanged(current),
+                None => params,
+            };
+            if params.is_empty() {
+                return Ok(());
+            }
+
+            let _ = self
+                .workspace_sql
+                .update_workspace(WorkspaceTableChangeset::new(params.clone()), conn)?;
+            let operation = Operation::UpdateWorkspace(params);
+            let _ = match current {
+                Some(current) => self
+                    .operations
+                    .push_update(operation, Operation::UpdateWorkspace(current), conn)?,
+                None => self.operations.push(operation, conn)?,
+            };
             let workspace = self.read_local_workspace(workspace_id.clone(), &user_id, conn)?;
             send_dart_notification(&workspace_id, WorkspaceNotification::WorkspaceUpdated)
                 .payload(workspace)
@@ -183,29 +419,257 @@ impl WorkspaceController {
 
             Ok(())
         })?;
-
-        let _ = self.update_workspace_on_server(params)?;
+        self.operations.flush();
 
         Ok(())
     }
 
-    #[allow(dead_code)]
+    // The apps and the views of the workspace are deleted with it, including the
+    // ones in the trash. The last workspace of the user can't be deleted, the
+    // first one left is opened if the current workspace was deleted.
+    #[tracing::instrument(level = "debug", skip(self), err)]
     pub(crate) async fn delete_workspace(&self, workspace_id: &str) -> Result<(), WorkspaceError> {
+        let view_ids = self.delete_local_workspace(workspace_id)?;
+        // The document writes its own tables with another connection, it would wait
+        // for the transaction if it was deleted inside of it.
+        let _ = self.view_controller.delete_view_documents(view_ids).await?;
+        self.operations.flush();
+        Ok(())
+    }
+
+    // The apps and the views in the trash aren't exported. The archive is written
+    // to the export directory of the user.
+    #[tracing::instrument(level = "debug", skip(self, params), fields(workspace_id = %params.workspace_id), err)]
+    pub(crate) async fn export_workspace(&self, params: ExportWorkspaceParams) -> Result<ExportData, WorkspaceError> {
         let user_id = self.user.user_id()?;
+        let (workspace, apps, view_tables) = {
+            let conn = &*self.database.db_connection()?;
+            let workspace = self.read_local_workspace(params.workspace_id.clone(), &user_id, conn)?;
+            let apps = read_local_workspace_apps(&workspace.id, self.trash_can.clone(), conn)?.into_inner();
+            let trash_ids = self.trash_can.trash_ids(conn)?;
+
+            // Each view is put after the view or the app it belongs to.
+            let mut view_tables = vec![];
+            let mut belong_to_ids = apps.iter().rev().map(|app| app.id.clone()).collect::<Vec<_>>();
+            while let Some(belong_to_id) = belong_to_ids.pop() {
+                for view_table in ViewTableSql::read_views(&belong_to_id, conn)? {
+                    if trash_ids.contains(&view_table.id) {
+                        continue;
+                    }
+                    belong_to_ids.push(view_table.id.clone());
+                    view_tables.push(view_table);
+                }
+            }
+            (workspace, apps, view_tables)
+        };
+
+        let mut archive = WorkspaceArchive {
+            version: WORKSPACE_ARCHIVE_VERSION,
+            name: workspace.name,
+            desc: workspace.desc,
+            apps: apps
+                .into_iter()
+                .map(|app| ArchivedApp {
+                    id: app.id,
+                    name: app.name,
+                    desc: app.desc,
+                })
+                .collect(),
+            ..Default::default()
+        };
+        for view_table in view_tables {
+            let (archived_view, archived_assets) = self.view_controller.archive_view(view_table).await?;
+            archive.views.push(archived_view);
+            archive.assets.extend(archived_assets);
+        }
+
+        let bytes: Bytes = archive.try_into().map_err(internal_error)?;
+        let path = self
+            .view_controller
+            .write_export(&workspace.id, ARCHIVE_EXTENSION, &bytes)?;
+        Ok(ExportData::file(path, ExportType::Archive))
+    }
+
+    // The ids of the archive are replaced by the ones of the restored items. The
+    // items that fail are reported instead of failing the import, the workspace
+    // is kept with the items that were restored.
+    #[tracing::instrument(level = "debug", skip(self, params), fields(path = %params.path), err)]
+    pub(crate) async fn import_workspace(
+        &self,
+        params: ImportWorkspaceParams,
+    ) -> Result<ImportWorkspaceResult, WorkspaceError> {
+        let bytes = std::fs::read(&params.path).map_err(|e| WorkspaceError::workspace_archive().context(e))?;
+        let archive = WorkspaceArchive::try_from(Bytes::from(bytes))
+            .map_err(|e| WorkspaceError::workspace_archive().context(e))?;
+        if archive.version > WORKSPACE_ARCHIVE_VERSION {
+            let msg = format!("The archive version {} isn't supported", archive.version);
+            return Err(WorkspaceError::workspace_archive().context(msg));
+        }
+
+        let create_params = CreateWorkspaceParams {
+            name: params.name.unwrap_or(archive.name),
+            desc: archive.desc,
+        };
+        let workspace = self.create_workspace_from_params(create_params).await?;
         let token = self.user.token()?;
-        let conn = &*self.database.db_connection()?;
-        conn.immediate_transaction::<_, WorkspaceError, _>(|| {
-            let _ = self.workspace_sql.delete_workspace(workspace_id, conn)?;
-            let repeated_workspace = self.read_local_workspaces(None, &user_id, conn)?;
-            send_dart_notification(&token, WorkspaceNotification::UserDeleteWorkspace)
-                .payload(repeated_workspace)
-                .send();
+        let total = (archive.apps.len() + archive.views.len()) as i32;
+        let mut done = 0;
+        let mut failures = vec![];
+        let mut ids = HashMap::new();
+        for archived_app in archive.apps {
+            let create_params = CreateAppParams {
+                workspace_id: workspace.id.clone(),
+                name: archived_app.name.clone(),
+                desc: archived_app.desc,
+                color_style: ColorStyle::default(),
+            };
+            match self.app_controller.create_app_from_params(create_params).await {
+                Ok(app) => {
+                    ids.insert(archived_app.id, app.id);
+                },
+                Err(e) => failures.push(ImportFailure {
+                    id: archived_app.id,
+                    name: archived_app.name,
+                    msg: e.msg,
+                }),
+            }
+            done += 1;
+            notify_import_progress(&token, &workspace.id, done, total);
+        }
 
-            Ok(())
-        })?;
+        let mut archived_assets = HashMap::<String, Vec<ArchivedAsset>>::new();
+        for archived_asset in archive.assets {
+            archived_assets
+                .entry(archived_asset.doc_id.clone())
+                .or_default()
+                .push(archived_asset);
+        }
+        for archived_view in archive.views {
+            let id = archived_view.id.clone();
+            let name = archived_view.name.clone();
+            match ids.get(&archived_view.belong_to_id).cloned() {
+                None => failures.push(ImportFailure {
+                    id,
+                    name,
+                    msg: "The app or the view it belongs to wasn't imported".to_owned(),
+                }),
+                Some(belong_to_id) => {
+                    let assets = archived_assets.remove(&id).unwrap_or_default();
+                    let restored = self
+                        .view_controller
+                        .restore_view(belong_to_id, archived_view, assets)
+                        .await;
+                    match restored {
+                        Ok((view, asset_failures)) => {
+                            ids.insert(id, view.id);
+                            failures.extend(asset_failures);
+                        },
+                        Err(e) => failures.push(ImportFailure { id, name, msg: e.msg }),
+                    }
+                },
+            }
+            done += 1;
+            notify_import_progress(&token, &workspace.id, done, total);
+        }
 
-        let _ = self.delete_workspace_on_server(workspace_id)?;
-        Ok(())
+        let user_id = self.user.user_id()?;
+        let workspace = self.read_local_workspace(workspace.id, &user_id, &*self.database.db_connection()?)?;
+        Ok(ImportWorkspaceResult { workspace, failures })
+    }
+
+    // The pages of the export are imported into a new workspace, see
+    // NotionImporter. The export is read before the workspace is created, so an
+    // invalid one leaves nothing behind.
+    #[tracing::instrument(level = "debug", skip(self, params), fields(path = %params.path), err)]
+    pub(crate) async fn import_notion(&self, params: ImportNotionParams) -> Result<ImportNotionResult, WorkspaceError> {
+        let export = NotionExport::open(&params.path)?;
+        let create_params = CreateWorkspaceParams {
+            name: params.name,
+            desc: "".to_owned(),
+        };
+        let workspace = self.create_workspace_from_params(create_params).await?;
+        let token = self.user.token()?;
+        let report = self.notion.import(&token, &workspace.id, export).await;
+
+        let user_id = self.user.user_id()?;
+        let workspace = self.read_local_workspace(workspace.id, &user_id, &*self.database.db_connection()?)?;
+        Ok(ImportNotionResult {
+            workspace,
+            failures: report.failures,
+            unconverted: report.unconverted,
+        })
+    }
+
+    // The notebooks are imported into a new workspace, see EvernoteImporter. The
+    // files are read before the workspace is created, the notes that can't be
+    // read are reported with the others that fail.
+    #[tracing::instrument(level = "debug", skip(self, params), err)]
+    pub(crate) async fn import_enex(&self, params: ImportEnexParams) -> Result<ImportEnexResult, WorkspaceError> {
+        let notebooks = params
+            .paths
+            .iter()
+            .map(|path| EnexNotebook::open(path))
+            .collect::<Result<Vec<_>, WorkspaceError>>()?;
+        let create_params = CreateWorkspaceParams {
+            name: params.name,
+            desc: "".to_owned(),
+        };
+        let workspace = self.create_workspace_from_params(create_params).await?;
+        let token = self.user.token()?;
+        let failures = self
+            .evernote
+            .import(&token, &workspace.id, notebooks, params.tag_mapping)
+            .await;
+
+        let user_id = self.user.user_id()?;
+        let workspace = self.read_local_workspace(workspace.id, &user_id, &*self.database.db_connection()?)?;
+        Ok(ImportEnexResult { workspace, failures })
+    }
+
+    pub(crate) fn read_workspace_templates(&self) -> RepeatedWorkspaceTemplate {
+        RepeatedWorkspaceTemplate {
+            items: built_in_workspace_templates(),
+        }
+    }
+
+    // Creates the workspace with the apps of the template, the documents of their
+    // views are filled from the view templates. The new workspace becomes the
+    // current one like any created workspace.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn create_workspace_from_template(
+        &self,
+        params: CreateWorkspaceFromTemplateParams,
+    ) -> Result<Workspace, WorkspaceError> {
+        let template = match built_in_workspace_template(&params.template_id) {
+            None => return Err(WorkspaceError::record_not_found().context("The template doesn't exist")),
+            Some(template) => template,
+        };
+
+        let create_params = CreateWorkspaceParams {
+            name: params.name.unwrap_or(template.name),
+            desc: template.desc,
+        };
+        let workspace = self.create_workspace_from_params(create_params).await?;
+        for template_app in template.apps {
+            let create_params = CreateAppParams {
+                workspace_id: workspace.id.clone(),
+                name: template_app.name,
+                desc: template_app.desc,
+                color_style: ColorStyle::default(),
+            };
+            let app = self.app_controller.create_app_from_params(create_params).await?;
+            for template_view in template_app.views {
+                let create_params = CreateViewFromTemplateParams {
+                    belong_to_id: app.id.clone(),
+                    template_id: template_view.template_id,
+                    name: template_view.name,
+                };
+                let _ = self.view_controller.create_view_from_template(create_params).await?;
+            }
+        }
+
+        let user_id = self.user.user_id()?;
+        self.read_local_workspace(workspace.id, &user_id, &*self.database.db_connection()?)
     }
 
     pub(crate) async fn open_workspace(&self, params: WorkspaceIdentifier) -> Result<Workspace, WorkspaceError> {
@@ -213,7 +677,7 @@ impl WorkspaceController {
         let conn = self.database.db_connection()?;
         if let Some(workspace_id) = params.workspace_id.clone() {
             let workspace = self.read_local_workspace(workspace_id, &user_id, &*conn)?;
-            set_current_workspace(&workspace.id);
+            set_current_workspace(&user_id, &workspace.id);
             Ok(workspace)
         } else {
             return Err(WorkspaceError::workspace_id().context("Opened workspace id should not be empty"));
@@ -232,8 +696,8 @@ impl WorkspaceController {
     }
 
     pub(crate) async fn read_current_workspace(&self) -> Result<CurrentWorkspaceSetting, WorkspaceError> {
-        let workspace_id = get_current_workspace()?;
         let user_id = self.user.user_id()?;
+        let workspace_id = get_current_workspace(&user_id)?;
         let params = WorkspaceIdentifier {
             workspace_id: Some(workspace_id.clone()),
         };
@@ -250,13 +714,116 @@ impl WorkspaceController {
     }
 
     pub(crate) async fn read_current_workspace_apps(&self) -> Result<RepeatedApp, WorkspaceError> {
-        let workspace_id = get_current_workspace()?;
+        let workspace_id = get_current_workspace(&self.user.user_id()?)?;
         let conn = self.database.db_connection()?;
         let repeated_app = self.read_local_apps(&workspace_id, &*conn)?;
         // TODO: read from server
         Ok(repeated_app)
     }
 
+    // The detached apps and views are moved to the current workspace, so nothing
+    // is lost, the user can delete them then.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn check_storage_integrity(&self, repair: bool) -> Result<StorageIntegrityReport, WorkspaceError> {
+        let workspace_id = get_current_workspace(&self.user.user_id()?).ok();
+        let conn = &*self.database.db_write_connection()?;
+        let report = conn
+            .immediate_transaction::<_, WorkspaceError, _>(|| check_integrity(workspace_id.as_deref(), repair, conn))?;
+
+        let (trash_repaired, apps_repaired): (Vec<&IntegrityIssue>, Vec<&IntegrityIssue>) = report
+            .issues
+            .iter()
+            .filter(|issue| issue.repaired)
+            .partition(|issue| issue.ty == IntegrityIssueType::TrashWithoutItem);
+        if let Some(workspace_id) = &workspace_id {
+            if !apps_repaired.is_empty() {
+                let repeated_app = read_local_workspace_apps(workspace_id, self.trash_can.clone(), conn)?;
+                send_dart_notification(workspace_id, WorkspaceNotification::WorkspaceAppsChanged)
+                    .payload(repeated_app)
+                    .send();
+            }
+        }
+        if !trash_repaired.is_empty() {
+            notify_trash_changed(TrashTableSql::read_all(conn)?);
+        }
+        Ok(report)
+    }
+
+    // Only the owners change the settings of the workspace, they're shared by its
+    // members.
+    #[tracing::instrument(level = "debug", skip(self, item), fields(key = %item.key), err)]
+    pub(crate) async fn set_setting(&self, item: WorkspaceSettingItem) -> Result<(), WorkspaceError> {
+        let conn = &*self.database.db_write_connection()?;
+        let changed = conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            let _ = self.permission.require_owner(&item.workspace_id, conn)?;
+            if let Some(stored) = WorkspaceSettingTableSql::read_setting(&item.workspace_id, &item.key, conn)? {
+                let stored: WorkspaceSettingItem = stored.into();
+                if stored.value.type_name() != item.value.type_name() {
+                    return Err(WorkspaceError::workspace_setting().context(format!(
+                        "{} is {:?}, but got {:?}",
+                        item.key,
+                        stored.value.type_name(),
+                        item.value.type_name()
+                    )));
+                }
+
+                if stored == item {
+                    return Ok(false);
+                }
+            }
+
+            if item.key == DEFAULT_VIEW_SETTING || item.key == INBOX_VIEW_SETTING {
+                let view_id = item.value.as_str().unwrap_or("");
+                let trash_ids = self.trash_can.trash_ids(conn)?;
+                let workspace_id = read_visible_view_workspace_id(view_id, &trash_ids, conn)?;
+                if workspace_id.as_deref() != Some(item.workspace_id.as_str()) {
+                    return Err(WorkspaceError::workspace_setting()
+                        .context(format!("{} is not a view of the workspace", view_id)));
+                }
+            }
+
+            let _ = WorkspaceSettingTableSql::set_setting(item.clone().into(), conn)?;
+            Ok(true)
+        })?;
+
+        if changed {
+            send_dart_notification(&item.workspace_id, WorkspaceNotification::WorkspaceSettingChanged)
+                .payload(item)
+                .send();
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn get_setting(
+        &self,
+        params: WorkspaceSettingKey,
+    ) -> Result<WorkspaceSettingItem, WorkspaceError> {
+        let conn = &*self.database.db_connection()?;
+        let _ = self.require_member(&params.workspace_id, conn)?;
+        match WorkspaceSettingTableSql::read_setting(&params.workspace_id, &params.key, conn)? {
+            None => Err(WorkspaceError::record_not_found().context(format!("{} is not set", params.key))),
+            Some(table) => Ok(table.into()),
+        }
+    }
+
+    // Reads the settings of the current workspace if the workspace_id is None
+    pub(crate) async fn read_settings(
+        &self,
+        params: WorkspaceIdentifier,
+    ) -> Result<RepeatedWorkspaceSettingItem, WorkspaceError> {
+        let workspace_id = match params.workspace_id {
+            Some(workspace_id) => workspace_id,
+            None => get_current_workspace(&self.user.user_id()?)?,
+        };
+        let conn = &*self.database.db_connection()?;
+        let _ = self.require_member(&workspace_id, conn)?;
+        let items = WorkspaceSettingTableSql::read_settings(&workspace_id, conn)?
+            .into_iter()
+            .map(|table| table.into())
+            .collect();
+        Ok(RepeatedWorkspaceSettingItem { items })
+    }
+
     #[tracing::instrument(level = "debug", skip(self, conn), err)]
     fn read_local_workspaces(
         &self,
@@ -308,44 +875,74 @@ impl WorkspaceController {
         Ok((token, server))
     }
 
-    #[tracing::instrument(level = "debug", skip(self), err)]
-    async fn create_workspace_on_server(&self, params: CreateWorkspaceParams) -> Result<Workspace, WorkspaceError> {
-        let token = self.user.token()?;
-        let workspace = self.server.create_workspace(&token, params).await?;
-        Ok(workspace)
+    fn require_member(&self, workspace_id: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let user_id = self.user.user_id()?;
+        match read_role(workspace_id, &user_id, conn)? {
+            None => Err(WorkspaceError::record_not_found().context(format!("Workspace {} not found", workspace_id))),
+            Some(_) => Ok(()),
+        }
     }
 
-    #[tracing::instrument(level = "debug", skip(self), err)]
-    fn update_workspace_on_server(&self, params: UpdateWorkspaceParams) -> Result<(), WorkspaceError> {
-        let (token, server) = self.token_with_server()?;
-        tokio::spawn(async move {
-            match server.update_workspace(&token, params).await {
-                Ok(_) => {},
-                Err(e) => {
-                    // TODO: retry?
-                    log::error!("Update workspace failed: {:?}", e);
-                },
+    // Returns the ids of the deleted views.
+    fn delete_local_workspace(&self, workspace_id: &str) -> Result<Vec<String>, WorkspaceError> {
+        let user_id = self.user.user_id()?;
+        let token = self.user.token()?;
+        let conn = &*self.database.db_write_connection()?;
+        conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            let _ = self.permission.require_owner(workspace_id, conn)?;
+            if self.workspace_sql.read_workspaces(None, &user_id, conn)?.len() <= 1 {
+                return Err(WorkspaceError::workspace_last_one());
             }
-        });
-        Ok(())
+
+            let mut app_ids = vec![];
+            for is_trash in [false, true] {
+                for app_table in AppTableSql::read_workspace_apps(workspace_id, is_trash, conn)? {
+                    app_ids.push(app_table.id);
+                }
+            }
+            // The views may belong to other views.
+            let mut view_ids = vec![];
+            let mut belong_to_ids = app_ids.clone();
+            while let Some(belong_to_id) = belong_to_ids.pop() {
+                for view_table in ViewTableSql::read_views(&belong_to_id, conn)? {
+                    belong_to_ids.push(view_table.id.clone());
+                    view_ids.push(view_table.id);
+                }
+            }
+
+            let _ = ViewTableSql::delete_views(&view_ids, conn)?;
+            let _ = FavoriteTableSql::delete_view_favorites(&view_ids, conn)?;
+            let _ = RecentViewTableSql::delete_view_recent_views(&view_ids, conn)?;
+            let _ = AppTableSql::delete_workspace_apps(workspace_id, conn)?;
+            let trash_ids = app_ids.iter().chain(view_ids.iter()).cloned().collect::<Vec<_>>();
+            let _ = TrashTableSql::delete_trash_with_ids(&trash_ids, conn)?;
+            let _ = WorkspaceMemberTableSql::delete_members(workspace_id, conn)?;
+            let _ = WorkspaceInvitationTableSql::delete_invitations(workspace_id, conn)?;
+            let _ = WorkspaceSettingTableSql::delete_workspace_settings(workspace_id, conn)?;
+            let _ = self.workspace_sql.delete_workspace(workspace_id, conn)?;
+            let identifier = WorkspaceIdentifier {
+                workspace_id: Some(workspace_id.to_owned()),
+            };
+            let _ = self.operations.push(Operation::DeleteWorkspace(identifier), conn)?;
+
+            let repeated_workspace = self.read_local_workspaces(None, &user_id, conn)?;
+            if get_current_workspace(&user_id).ok().as_deref() == Some(workspace_id) {
+                if let Some(workspace) = repeated_workspace.items.first() {
+                    set_current_workspace(&user_id, &workspace.id);
+                }
+            }
+            send_dart_notification(&token, WorkspaceNotification::UserDeleteWorkspace)
+                .payload(repeated_workspace)
+                .send();
+            Ok(view_ids)
+        })
     }
 
     #[tracing::instrument(level = "debug", skip(self), err)]
-    fn delete_workspace_on_server(&self, workspace_id: &str) -> Result<(), WorkspaceError> {
-        let params = WorkspaceIdentifier {
-            workspace_id: Some(workspace_id.to_string()),
-        };
-        let (token, server) = self.token_with_server()?;
-        tokio::spawn(async move {
-            match server.delete_workspace(&token, params).await {
-                Ok(_) => {},
-                Err(e) => {
-                    // TODO: retry?
-                    log::error!("Delete workspace failed: {:?}", e);
-                },
-            }
-        });
-        Ok(())
+    async fn create_workspace_on_server(&self, params: CreateWorkspaceParams) -> Result<Workspace, WorkspaceError> {
+        let token = self.user.token()?;
+        let workspace = self.server.create_workspace(&token, params).await?;
+        Ok(workspace)
     }
 
     #[tracing::instrument(level = "debug", skip(self), err)]
@@ -398,10 +995,29 @@ impl WorkspaceController {
 
 const CURRENT_WORKSPACE_ID: &str = "current_workspace_id";
 
-fn set_current_workspace(workspace_id: &str) { KV::set_str(CURRENT_WORKSPACE_ID, workspace_id.to_owned()); }
+// The role checks depend on the current workspace, so every user keeps their
+// own.
+fn current_workspace_key(user_id: &str) -> String { format!("{}_{}", CURRENT_WORKSPACE_ID, user_id) }
+
+fn set_current_workspace(user_id: &str, workspace_id: &str) {
+    KV::set_str(&current_workspace_key(user_id), workspace_id.to_owned());
+}
+
+pub(crate) fn notify_import_progress(token: &str, workspace_id: &str, done: i32, total: i32) {
+    let progress = ImportWorkspaceProgress {
+        workspace_id: workspace_id.to_owned(),
+        done,
+        total,
+    };
+    send_dart_notification(token, WorkspaceNotification::WorkspaceImportProgress)
+        .payload(progress)
+        .send();
+}
 
-fn get_current_workspace() -> Result<String, WorkspaceError> {
-    match KV::get_str(CURRENT_WORKSPACE_ID) {
+pub(crate) fn get_current_workspace(user_id: &str) -> Result<String, WorkspaceError> {
+    // Fallback to the key that was shared by all the users
+    let workspace_id = KV::get_str(&current_workspace_key(user_id)).or_else(|| KV::get_str(CURRENT_WORKSPACE_ID));
+    match workspace_id {
         None => Err(WorkspaceError::record_not_found()
             .context("Current workspace not found or should call open workspace first")),
         Some(workspace_id) => Ok(workspace_id),
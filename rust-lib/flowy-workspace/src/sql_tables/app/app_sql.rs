@@ -1,6 +1,7 @@
 use flowy_database::{
     prelude::*,
     schema::{app_table, app_table::dsl},
+    OptionalExtension,
     SqliteConnection,
 };
 
@@ -34,6 +35,18 @@ impl AppTableSql {
         Ok(app_table)
     }
 
+    pub(crate) fn read_app_workspace_id(
+        app_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Option<String>, WorkspaceError> {
+        let workspace_id = dsl::app_table
+            .filter(app_table::id.eq(app_id))
+            .select(app_table::workspace_id)
+            .first::<String>(conn)
+            .optional()?;
+        Ok(workspace_id)
+    }
+
     pub(crate) fn read_workspace_apps(
         workspace_id: &str,
         is_trash: bool,
@@ -48,6 +61,35 @@ impl AppTableSql {
         Ok(app_table)
     }
 
+    pub(crate) fn read_app_ids(conn: &SqliteConnection) -> Result<Vec<String>, WorkspaceError> {
+        let app_ids = dsl::app_table.select(app_table::id).load::<String>(conn)?;
+        Ok(app_ids)
+    }
+
+    // The id, the workspace id and the last view id of every app.
+    pub(crate) fn read_app_links(
+        conn: &SqliteConnection,
+    ) -> Result<Vec<(String, String, Option<String>)>, WorkspaceError> {
+        let links = dsl::app_table
+            .select((app_table::id, app_table::workspace_id, app_table::last_view_id))
+            .load::<(String, String, Option<String>)>(conn)?;
+        Ok(links)
+    }
+
+    pub(crate) fn move_app(app_id: &str, workspace_id: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = diesel::update(dsl::app_table.filter(app_table::id.eq(app_id)))
+            .set(app_table::workspace_id.eq(workspace_id))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn clear_last_view(app_id: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = diesel::update(dsl::app_table.filter(app_table::id.eq(app_id)))
+            .set(app_table::last_view_id.eq(None::<String>))
+            .execute(conn)?;
+        Ok(())
+    }
+
     pub(crate) fn delete_app(app_id: &str, conn: &SqliteConnection) -> Result<AppTable, WorkspaceError> {
         let app_table = dsl::app_table
             .filter(app_table::id.eq(app_id))
@@ -56,6 +98,11 @@ impl AppTableSql {
         Ok(app_table)
     }
 
+    pub(crate) fn delete_workspace_apps(workspace_id: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = diesel::delete(dsl::app_table.filter(app_table::workspace_id.eq(workspace_id))).execute(conn)?;
+        Ok(())
+    }
+
     // pub(crate) fn read_views_belong_to_app(
     //     &self,
     //     app_id: &str,
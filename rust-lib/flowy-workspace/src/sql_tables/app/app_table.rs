@@ -4,6 +4,7 @@ use diesel::sql_types::Binary;
 use serde::{Deserialize, Serialize, __private::TryFrom};
 
 use flowy_database::schema::app_table;
+use flowy_infra::timestamp;
 
 use crate::{
     entities::{
@@ -45,6 +46,19 @@ impl AppTable {
             is_trash: false,
         }
     }
+
+    // The fields that an update can change, to drop the ones it doesn't.
+    pub(crate) fn current_fields(&self) -> UpdateAppParams {
+        UpdateAppParams {
+            app_id: self.id.clone(),
+            name: Some(self.name.clone()),
+            desc: Some(self.desc.clone()),
+            color_style: Some(ColorStyle {
+                theme_color: self.color_style.theme_color.clone(),
+            }),
+            is_trash: Some(self.is_trash),
+        }
+    }
 }
 
 impl std::convert::Into<Trash> for AppTable {
@@ -55,6 +69,7 @@ impl std::convert::Into<Trash> for AppTable {
             modified_time: self.modified_time,
             create_time: self.create_time,
             ty: TrashType::App,
+            deleted_time: timestamp(),
         }
     }
 }
@@ -0,0 +1,73 @@
+use flowy_database::{
+    prelude::*,
+    schema::{favorite_table, favorite_table::dsl},
+    SqliteConnection,
+};
+
+use crate::{
+    errors::WorkspaceError,
+    sql_tables::favorite::{favorite_id, FavoriteTable},
+};
+
+pub(crate) struct FavoriteTableSql {}
+
+impl FavoriteTableSql {
+    pub(crate) fn create_favorite(table: FavoriteTable, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = table.insert(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_favorites(user_id: &str, conn: &SqliteConnection) -> Result<Vec<FavoriteTable>, WorkspaceError> {
+        let favorites = FavoriteTable::query()
+            .filter(favorite_table::user_id.eq(user_id))
+            .order((favorite_table::order_key.asc(), favorite_table::create_time.asc()))
+            .load::<FavoriteTable>(conn)?;
+        Ok(favorites)
+    }
+
+    pub(crate) fn update_order_key(
+        user_id: &str,
+        view_id: &str,
+        order_key: &str,
+        conn: &SqliteConnection,
+    ) -> Result<(), WorkspaceError> {
+        let _ = diesel::update(dsl::favorite_table.filter(favorite_table::id.eq(favorite_id(user_id, view_id))))
+            .set(favorite_table::order_key.eq(order_key))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    // The views that aren't pinned are skipped.
+    pub(crate) fn delete_favorites(
+        user_id: &str,
+        view_ids: &[String],
+        conn: &SqliteConnection,
+    ) -> Result<(), WorkspaceError> {
+        let ids = view_ids
+            .iter()
+            .map(|view_id| favorite_id(user_id, view_id))
+            .collect::<Vec<_>>();
+        let _ = diesel::delete(dsl::favorite_table.filter(favorite_table::id.eq_any(ids))).execute(conn)?;
+        Ok(())
+    }
+
+    // The favorites of the deleted views of every user.
+    pub(crate) fn delete_view_favorites(view_ids: &[String], conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = diesel::delete(dsl::favorite_table.filter(favorite_table::view_id.eq_any(view_ids))).execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn transfer_favorites(
+        from_user_id: &str,
+        to_user_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<(), WorkspaceError> {
+        for favorite in Self::read_favorites(from_user_id, conn)? {
+            let _ = Self::delete_favorites(to_user_id, &[favorite.view_id.clone()], conn)?;
+            let table = FavoriteTable::new(to_user_id, &favorite.view_id, favorite.order_key);
+            let _ = Self::create_favorite(table, conn)?;
+        }
+        let _ = diesel::delete(dsl::favorite_table.filter(favorite_table::user_id.eq(from_user_id))).execute(conn)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use flowy_database::schema::favorite_table;
+use flowy_derive::SqlTable;
+use flowy_infra::timestamp;
+
+#[derive(PartialEq, Clone, Debug, Queryable, SqlTable)]
+#[table_name = "favorite_table"]
+pub(crate) struct FavoriteTable {
+    pub id: String,
+    pub user_id: String,
+    pub view_id: String,
+    // The favorites are sorted by it, see view_order.
+    pub order_key: String,
+    pub create_time: i64,
+}
+
+impl FavoriteTable {
+    pub(crate) fn new(user_id: &str, view_id: &str, order_key: String) -> Self {
+        FavoriteTable {
+            id: favorite_id(user_id, view_id),
+            user_id: user_id.to_owned(),
+            view_id: view_id.to_owned(),
+            order_key,
+            create_time: timestamp(),
+        }
+    }
+}
+
+// A user pins the view once, the id is made of both.
+pub(crate) fn favorite_id(user_id: &str, view_id: &str) -> String { format!("{}:{}", user_id, view_id) }
@@ -0,0 +1,5 @@
+mod favorite_sql;
+mod favorite_table;
+
+pub(crate) use favorite_sql::*;
+pub(crate) use favorite_table::*;
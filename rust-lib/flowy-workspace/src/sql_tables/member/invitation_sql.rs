@@ -0,0 +1,54 @@
+use flowy_database::{
+    prelude::*,
+    schema::{workspace_invitation_table, workspace_invitation_table::dsl},
+    SqliteConnection,
+};
+
+use crate::{
+    errors::WorkspaceError,
+    sql_tables::member::{invitation_id, WorkspaceInvitationTable},
+};
+
+pub(crate) struct WorkspaceInvitationTableSql {}
+
+impl WorkspaceInvitationTableSql {
+    pub(crate) fn set_invitation(
+        table: WorkspaceInvitationTable,
+        conn: &SqliteConnection,
+    ) -> Result<(), WorkspaceError> {
+        let _ = diesel::replace_into(workspace_invitation_table::table)
+            .values(&table)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    // The invitations sent first are read first.
+    pub(crate) fn read_invitations(
+        workspace_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<WorkspaceInvitationTable>, WorkspaceError> {
+        let invitations = dsl::workspace_invitation_table
+            .filter(workspace_invitation_table::workspace_id.eq(workspace_id))
+            .order(workspace_invitation_table::invited_time.asc())
+            .load::<WorkspaceInvitationTable>(conn)?;
+        Ok(invitations)
+    }
+
+    // Returns false if the email wasn't invited.
+    pub(crate) fn delete_invitation(
+        workspace_id: &str,
+        email: &str,
+        conn: &SqliteConnection,
+    ) -> Result<bool, WorkspaceError> {
+        let filter = dsl::workspace_invitation_table
+            .filter(workspace_invitation_table::id.eq(invitation_id(workspace_id, email)));
+        let count = diesel::delete(filter).execute(conn)?;
+        Ok(count > 0)
+    }
+
+    pub(crate) fn delete_invitations(workspace_id: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let filter = dsl::workspace_invitation_table.filter(workspace_invitation_table::workspace_id.eq(workspace_id));
+        let _ = diesel::delete(filter).execute(conn)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{
+    entities::workspace::{WorkspaceInvitation, WorkspaceRole},
+    sql_tables::member::SqlWorkspaceRole,
+};
+use flowy_database::schema::workspace_invitation_table;
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable)]
+#[table_name = "workspace_invitation_table"]
+pub(crate) struct WorkspaceInvitationTable {
+    pub id: String,
+    pub workspace_id: String,
+    pub email: String,
+    pub role: SqlWorkspaceRole,
+    pub invited_time: i64,
+    // The key of the encrypted workspace, encrypted with the secret that only the
+    // invited user gets. Empty if the workspace isn't encrypted.
+    pub wrapped_key: String,
+}
+
+impl WorkspaceInvitationTable {
+    pub(crate) fn new(workspace_id: &str, email: &str, role: WorkspaceRole, invited_time: i64) -> Self {
+        WorkspaceInvitationTable {
+            id: invitation_id(workspace_id, email),
+            workspace_id: workspace_id.to_owned(),
+            email: email.to_owned(),
+            role: role.into(),
+            invited_time,
+            wrapped_key: "".to_owned(),
+        }
+    }
+
+    pub(crate) fn wrapped_key(mut self, wrapped_key: &str) -> Self {
+        self.wrapped_key = wrapped_key.to_owned();
+        self
+    }
+}
+
+// The email is invited to the workspace once, the id is made of both.
+pub(crate) fn invitation_id(workspace_id: &str, email: &str) -> String { format!("{}:{}", workspace_id, email) }
+
+impl std::convert::From<WorkspaceInvitationTable> for WorkspaceInvitation {
+    fn from(table: WorkspaceInvitationTable) -> Self {
+        WorkspaceInvitation {
+            workspace_id: table.workspace_id,
+            email: table.email,
+            role: table.role.into(),
+            invited_time: table.invited_time,
+            wrapped_key: table.wrapped_key,
+            invite_secret: "".to_owned(),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use flowy_database::{
+    prelude::*,
+    schema::{workspace_member_table, workspace_member_table::dsl},
+    OptionalExtension,
+    SqliteConnection,
+};
+
+use crate::{
+    errors::WorkspaceError,
+    sql_tables::member::{member_id, WorkspaceMemberTable, WorkspaceMemberTableChangeset},
+};
+
+pub(crate) struct WorkspaceMemberTableSql {}
+
+impl WorkspaceMemberTableSql {
+    pub(crate) fn set_member(table: WorkspaceMemberTable, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        match diesel_record_count!(workspace_member_table, &table.id, conn) {
+            0 => diesel_insert_table!(workspace_member_table, &table, conn),
+            _ => {
+                let changeset = WorkspaceMemberTableChangeset {
+                    id: table.id,
+                    role: Some(table.role),
+                };
+                diesel_update_table!(workspace_member_table, changeset, conn)
+            },
+        }
+        Ok(())
+    }
+
+    pub(crate) fn read_member(
+        workspace_id: &str,
+        user_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Option<WorkspaceMemberTable>, WorkspaceError> {
+        let member = dsl::workspace_member_table
+            .filter(workspace_member_table::id.eq(member_id(workspace_id, user_id)))
+            .first::<WorkspaceMemberTable>(conn)
+            .optional()?;
+        Ok(member)
+    }
+
+    pub(crate) fn transfer_members(
+        from_user_id: &str,
+        to_user_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<(), WorkspaceError> {
+        for member in Self::read_members_of_user(from_user_id, conn)? {
+            let table = WorkspaceMemberTable::new(&member.workspace_id, to_user_id, member.role.into());
+            Self::set_member(table, conn)?;
+        }
+        let _ = diesel::delete(dsl::workspace_member_table.filter(workspace_member_table::user_id.eq(from_user_id)))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    fn read_members_of_user(
+        user_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<WorkspaceMemberTable>, WorkspaceError> {
+        let members = dsl::workspace_member_table
+            .filter(workspace_member_table::user_id.eq(user_id))
+            .load::<WorkspaceMemberTable>(conn)?;
+        Ok(members)
+    }
+
+    pub(crate) fn read_members(
+        workspace_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<WorkspaceMemberTable>, WorkspaceError> {
+        let members = dsl::workspace_member_table
+            .filter(workspace_member_table::workspace_id.eq(workspace_id))
+            .load::<WorkspaceMemberTable>(conn)?;
+        Ok(members)
+    }
+
+    // Returns false if the user isn't a member of the workspace.
+    pub(crate) fn delete_member(
+        workspace_id: &str,
+        user_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<bool, WorkspaceError> {
+        let filter =
+            dsl::workspace_member_table.filter(workspace_member_table::id.eq(member_id(workspace_id, user_id)));
+        let count = diesel::delete(filter).execute(conn)?;
+        Ok(count > 0)
+    }
+
+    pub(crate) fn delete_members(workspace_id: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let filter = dsl::workspace_member_table.filter(workspace_member_table::workspace_id.eq(workspace_id));
+        let _ = diesel::delete(filter).execute(conn)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use crate::entities::workspace::{WorkspaceMember, WorkspaceRole};
+use diesel::sql_types::Integer;
+use flowy_database::schema::workspace_member_table;
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable)]
+#[table_name = "workspace_member_table"]
+pub(crate) struct WorkspaceMemberTable {
+    pub id: String,
+    pub workspace_id: String,
+    pub user_id: String,
+    pub role: SqlWorkspaceRole,
+}
+
+impl WorkspaceMemberTable {
+    pub(crate) fn new(workspace_id: &str, user_id: &str, role: WorkspaceRole) -> Self {
+        WorkspaceMemberTable {
+            id: member_id(workspace_id, user_id),
+            workspace_id: workspace_id.to_owned(),
+            user_id: user_id.to_owned(),
+            role: role.into(),
+        }
+    }
+}
+
+// A user is a member of the workspace once, the id is made of both.
+pub(crate) fn member_id(workspace_id: &str, user_id: &str) -> String { format!("{}:{}", workspace_id, user_id) }
+
+impl std::convert::From<WorkspaceMemberTable> for WorkspaceMember {
+    fn from(table: WorkspaceMemberTable) -> Self {
+        WorkspaceMember {
+            workspace_id: table.workspace_id,
+            user_id: table.user_id,
+            role: table.role.into(),
+        }
+    }
+}
+
+#[derive(AsChangeset, Identifiable, Clone, Default, Debug)]
+#[table_name = "workspace_member_table"]
+pub(crate) struct WorkspaceMemberTableChangeset {
+    pub id: String,
+    pub role: Option<SqlWorkspaceRole>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, FromSqlRow, AsExpression)]
+#[repr(i32)]
+#[sql_type = "Integer"]
+pub(crate) enum SqlWorkspaceRole {
+    Viewer = 0,
+    Editor = 1,
+    Owner  = 2,
+}
+
+impl std::convert::From<i32> for SqlWorkspaceRole {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => SqlWorkspaceRole::Editor,
+            2 => SqlWorkspaceRole::Owner,
+            _o => SqlWorkspaceRole::Viewer,
+        }
+    }
+}
+
+impl_sql_integer_expression!(SqlWorkspaceRole);
+
+impl std::convert::From<SqlWorkspaceRole> for WorkspaceRole {
+    fn from(role: SqlWorkspaceRole) -> Self {
+        match role {
+            SqlWorkspaceRole::Viewer => WorkspaceRole::Viewer,
+            SqlWorkspaceRole::Editor => WorkspaceRole::Editor,
+            SqlWorkspaceRole::Owner => WorkspaceRole::Owner,
+        }
+    }
+}
+
+impl std::convert::From<WorkspaceRole> for SqlWorkspaceRole {
+    fn from(role: WorkspaceRole) -> Self {
+        match role {
+            WorkspaceRole::Viewer => SqlWorkspaceRole::Viewer,
+            WorkspaceRole::Editor => SqlWorkspaceRole::Editor,
+            WorkspaceRole::Owner => SqlWorkspaceRole::Owner,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod invitation_sql;
+mod invitation_table;
+mod member_sql;
+mod member_table;
+
+pub(crate) use invitation_sql::*;
+pub(crate) use invitation_table::*;
+pub(crate) use member_sql::*;
+pub(crate) use member_table::*;
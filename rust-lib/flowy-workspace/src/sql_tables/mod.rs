@@ -1,4 +1,12 @@
 pub mod app;
+pub mod favorite;
+pub mod member;
+pub mod operation;
+pub mod recent_view;
+pub mod search;
+pub mod setting;
+pub mod sync;
+pub mod template;
 pub mod trash;
 pub mod view;
 pub mod workspace;
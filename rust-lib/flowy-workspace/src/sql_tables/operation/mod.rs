@@ -0,0 +1,5 @@
+mod operation_sql;
+mod operation_table;
+
+pub(crate) use operation_sql::*;
+pub(crate) use operation_table::*;
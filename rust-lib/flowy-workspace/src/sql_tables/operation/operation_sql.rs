@@ -0,0 +1,73 @@
+use flowy_database::{
+    prelude::*,
+    schema::{operation_table, operation_table::dsl},
+    SqliteConnection,
+};
+use flowy_infra::timestamp;
+
+use crate::{
+    errors::WorkspaceError,
+    sql_tables::operation::{OperationState, OperationTable, SqlOperationType},
+};
+
+pub(crate) struct OperationTableSql {}
+
+impl OperationTableSql {
+    pub(crate) fn create_operation(
+        ty: SqlOperationType,
+        data: Vec<u8>,
+        conn: &SqliteConnection,
+    ) -> Result<(), WorkspaceError> {
+        let _ = diesel::insert_into(operation_table::table)
+            .values((
+                dsl::ty.eq(ty),
+                dsl::data.eq(data),
+                dsl::state.eq(OperationState::Pending),
+                dsl::created_time.eq(timestamp()),
+            ))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    // In the order they were created.
+    pub(crate) fn read_pending_operations(conn: &SqliteConnection) -> Result<Vec<OperationTable>, WorkspaceError> {
+        let operations = dsl::operation_table
+            .filter(operation_table::state.eq(OperationState::Pending))
+            .order(operation_table::id.asc())
+            .load::<OperationTable>(conn)?;
+        Ok(operations)
+    }
+
+    pub(crate) fn read_pending_count(conn: &SqliteConnection) -> Result<i64, WorkspaceError> {
+        let count = dsl::operation_table
+            .filter(operation_table::state.eq(OperationState::Pending))
+            .count()
+            .get_result::<i64>(conn)?;
+        Ok(count)
+    }
+
+    pub(crate) fn commit_operation(id: i32, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = diesel::update(dsl::operation_table.filter(operation_table::id.eq(id)))
+            .set(operation_table::state.eq(OperationState::Committed))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn update_operation_data(id: i32, data: Vec<u8>, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = diesel::update(dsl::operation_table.filter(operation_table::id.eq(id)))
+            .set(operation_table::data.eq(data))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn delete_operation(id: i32, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = diesel::delete(dsl::operation_table.filter(operation_table::id.eq(id))).execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn delete_committed_operations(conn: &SqliteConnection) -> Result<usize, WorkspaceError> {
+        let count = diesel::delete(dsl::operation_table.filter(operation_table::state.eq(OperationState::Committed)))
+            .execute(conn)?;
+        Ok(count)
+    }
+}
@@ -0,0 +1,62 @@
+use diesel::sql_types::Integer;
+use flowy_database::schema::operation_table;
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable)]
+#[table_name = "operation_table"]
+pub(crate) struct OperationTable {
+    pub id: i32,
+    pub ty: SqlOperationType,
+    // The params of the request that is sent to the server.
+    pub data: Vec<u8>,
+    pub state: OperationState,
+    pub created_time: i64,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, FromSqlRow, AsExpression)]
+#[repr(i32)]
+#[sql_type = "Integer"]
+pub(crate) enum SqlOperationType {
+    Unknown         = 0,
+    UpdateWorkspace = 1,
+    DeleteWorkspace = 2,
+    UpdateApp       = 3,
+    UpdateView      = 4,
+    CreateTrash     = 5,
+    DeleteTrash     = 6,
+}
+
+impl std::convert::From<i32> for SqlOperationType {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => SqlOperationType::UpdateWorkspace,
+            2 => SqlOperationType::DeleteWorkspace,
+            3 => SqlOperationType::UpdateApp,
+            4 => SqlOperationType::UpdateView,
+            5 => SqlOperationType::CreateTrash,
+            6 => SqlOperationType::DeleteTrash,
+            _o => SqlOperationType::Unknown,
+        }
+    }
+}
+
+impl_sql_integer_expression!(SqlOperationType);
+
+// The operation is pending until the server accepted it, it's committed then.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, FromSqlRow, AsExpression)]
+#[repr(i32)]
+#[sql_type = "Integer"]
+pub(crate) enum OperationState {
+    Pending   = 0,
+    Committed = 1,
+}
+
+impl std::convert::From<i32> for OperationState {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => OperationState::Committed,
+            _o => OperationState::Pending,
+        }
+    }
+}
+
+impl_sql_integer_expression!(OperationState);
@@ -0,0 +1,5 @@
+mod recent_view_sql;
+mod recent_view_table;
+
+pub(crate) use recent_view_sql::*;
+pub(crate) use recent_view_table::*;
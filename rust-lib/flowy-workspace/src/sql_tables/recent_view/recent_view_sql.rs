@@ -0,0 +1,93 @@
+use flowy_database::{
+    prelude::*,
+    schema::{recent_view_table, recent_view_table::dsl},
+    SqliteConnection,
+};
+
+use crate::{
+    errors::WorkspaceError,
+    sql_tables::recent_view::{recent_view_id, RecentViewTable},
+};
+
+pub(crate) struct RecentViewTableSql {}
+
+impl RecentViewTableSql {
+    // The view that is opened again is moved to the front of the history, the
+    // oldest ones are dropped once there are more than max_count.
+    pub(crate) fn record_open(
+        user_id: &str,
+        view_id: &str,
+        opened_time: i64,
+        max_count: usize,
+        conn: &SqliteConnection,
+    ) -> Result<(), WorkspaceError> {
+        // The views opened within the same millisecond are still read in order.
+        let opened_time = match Self::read_recent_views(user_id, conn)?.first() {
+            Some(latest) if latest.opened_time >= opened_time => latest.opened_time + 1,
+            _ => opened_time,
+        };
+
+        let _ = Self::delete_recent_views(user_id, &[view_id.to_owned()], conn)?;
+        let table = RecentViewTable::new(user_id, view_id, opened_time);
+        diesel_insert_table!(recent_view_table, &table, conn);
+
+        let dropped_ids = Self::read_recent_views(user_id, conn)?
+            .into_iter()
+            .skip(max_count)
+            .map(|table| table.id)
+            .collect::<Vec<_>>();
+        let _ =
+            diesel::delete(dsl::recent_view_table.filter(recent_view_table::id.eq_any(dropped_ids))).execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_recent_views(
+        user_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<RecentViewTable>, WorkspaceError> {
+        let recent_views = dsl::recent_view_table
+            .filter(recent_view_table::user_id.eq(user_id))
+            .order(recent_view_table::opened_time.desc())
+            .load::<RecentViewTable>(conn)?;
+        Ok(recent_views)
+    }
+
+    pub(crate) fn delete_recent_views(
+        user_id: &str,
+        view_ids: &[String],
+        conn: &SqliteConnection,
+    ) -> Result<(), WorkspaceError> {
+        let ids = view_ids
+            .iter()
+            .map(|view_id| recent_view_id(user_id, view_id))
+            .collect::<Vec<_>>();
+        let _ = diesel::delete(dsl::recent_view_table.filter(recent_view_table::id.eq_any(ids))).execute(conn)?;
+        Ok(())
+    }
+
+    // Removes the views from the history of every user, returns how many were
+    // there.
+    pub(crate) fn delete_view_recent_views(
+        view_ids: &[String],
+        conn: &SqliteConnection,
+    ) -> Result<usize, WorkspaceError> {
+        let count =
+            diesel::delete(dsl::recent_view_table.filter(recent_view_table::view_id.eq_any(view_ids))).execute(conn)?;
+        Ok(count)
+    }
+
+    pub(crate) fn transfer_recent_views(
+        from_user_id: &str,
+        to_user_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<(), WorkspaceError> {
+        for recent_view in Self::read_recent_views(from_user_id, conn)? {
+            let _ = Self::delete_recent_views(to_user_id, &[recent_view.view_id.clone()], conn)?;
+            let table = RecentViewTable::new(to_user_id, &recent_view.view_id, recent_view.opened_time);
+            diesel_insert_table!(recent_view_table, &table, conn);
+        }
+        let _ =
+            diesel::delete(dsl::recent_view_table.filter(recent_view_table::user_id.eq(from_user_id))).execute(conn)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use flowy_database::schema::recent_view_table;
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable)]
+#[table_name = "recent_view_table"]
+pub(crate) struct RecentViewTable {
+    pub id: String,
+    pub user_id: String,
+    pub view_id: String,
+    // In milliseconds, the views opened last are read first.
+    pub opened_time: i64,
+}
+
+impl RecentViewTable {
+    pub(crate) fn new(user_id: &str, view_id: &str, opened_time: i64) -> Self {
+        RecentViewTable {
+            id: recent_view_id(user_id, view_id),
+            user_id: user_id.to_owned(),
+            view_id: view_id.to_owned(),
+            opened_time,
+        }
+    }
+}
+
+// The view is kept once in the history of the user, the id is made of both.
+pub(crate) fn recent_view_id(user_id: &str, view_id: &str) -> String { format!("{}:{}", user_id, view_id) }
@@ -0,0 +1,5 @@
+mod search_sql;
+mod search_table;
+
+pub(crate) use search_sql::*;
+pub(crate) use search_table::*;
@@ -0,0 +1,116 @@
+use crate::{
+    errors::WorkspaceError,
+    sql_tables::search::{NewViewSearchTable, SearchRow, ViewSearchTable},
+};
+use flowy_database::{
+    prelude::*,
+    schema::{view_search_table, view_search_table::dsl, view_table},
+    sql_types::{BigInt, Text},
+    SqliteConnection,
+};
+use std::collections::HashMap;
+
+// How many words around the matched terms are in the snippets of the documents.
+const SNIPPET_WORDS: i64 = 16;
+
+pub(crate) struct ViewSearchTableSql {}
+
+impl ViewSearchTableSql {
+    // The title is deleted and inserted again if it changed, the index only
+    // follows the inserts and the deletes.
+    pub(crate) fn set_title(view_id: &str, name: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let indexed = dsl::view_search_table
+            .filter(view_search_table::view_id.eq(view_id))
+            .load::<ViewSearchTable>(conn)?;
+        if indexed.iter().any(|table| table.name == name) {
+            return Ok(());
+        }
+        let _ = Self::delete_titles(&[view_id.to_owned()], conn)?;
+        let _ = diesel::insert_into(dsl::view_search_table)
+            .values(NewViewSearchTable::new(view_id, name))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn delete_titles(view_ids: &[String], conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ =
+            diesel::delete(dsl::view_search_table.filter(view_search_table::view_id.eq_any(view_ids))).execute(conn)?;
+        Ok(())
+    }
+
+    // Makes the index hold the titles of all the views, e.g. the ones that
+    // changed while no search subscribed to their notifications.
+    pub(crate) fn reindex_titles(conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let mut titles = view_table::dsl::view_table
+            .select((view_table::id, view_table::name))
+            .load::<(String, String)>(conn)?
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+        let mut stale_ids = vec![];
+        for table in dsl::view_search_table.load::<ViewSearchTable>(conn)? {
+            match titles.get(&table.view_id) {
+                Some(name) if name == &table.name => {
+                    titles.remove(&table.view_id);
+                },
+                _ => stale_ids.push(table.view_id),
+            }
+        }
+        if !stale_ids.is_empty() {
+            let _ = Self::delete_titles(&stale_ids, conn)?;
+        }
+        let new_titles = titles
+            .iter()
+            .map(|(view_id, name)| NewViewSearchTable::new(view_id, name))
+            .collect::<Vec<_>>();
+        if !new_titles.is_empty() {
+            let _ = diesel::insert_into(dsl::view_search_table)
+                .values(new_titles)
+                .execute(conn)?;
+        }
+        Ok(())
+    }
+
+    // The snippet is the whole title. The query is a fts5 query.
+    pub(crate) fn search_titles(
+        query: &str,
+        limit: i64,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<SearchRow>, WorkspaceError> {
+        let sql = "SELECT view_search_table.view_id AS view_id, \
+                highlight(view_search_fts, 0, char(2), char(3)) AS snippet, \
+                bm25(view_search_fts) AS score \
+            FROM view_search_fts JOIN view_search_table ON view_search_table.id = view_search_fts.rowid \
+            WHERE view_search_fts MATCH ? ORDER BY score LIMIT ?";
+        let rows = diesel::sql_query(sql)
+            .bind::<Text, _>(query)
+            .bind::<BigInt, _>(limit)
+            .load::<SearchRow>(conn)?;
+        Ok(rows)
+    }
+
+    // The lines of the documents are indexed by the document module. They're
+    // ranked like its search does, by the bm25 of the best matching line of
+    // each document, and the id of a document is the one of its view.
+    pub(crate) fn search_documents(
+        query: &str,
+        limit: i64,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<SearchRow>, WorkspaceError> {
+        let sql = format!(
+            "SELECT doc_id AS view_id, snippet, MIN(score) AS score FROM ( \
+                SELECT doc_search_table.doc_id AS doc_id, \
+                    snippet(doc_search_fts, 0, char(2), char(3), '…', {}) AS snippet, \
+                    bm25(doc_search_fts) AS score \
+                FROM doc_search_fts JOIN doc_search_table ON doc_search_table.id = doc_search_fts.rowid \
+                WHERE doc_search_fts MATCH ? LIMIT -1 \
+            ) \
+            GROUP BY doc_id ORDER BY score LIMIT ?",
+            SNIPPET_WORDS
+        );
+        let rows = diesel::sql_query(sql)
+            .bind::<Text, _>(query)
+            .bind::<BigInt, _>(limit)
+            .load::<SearchRow>(conn)?;
+        Ok(rows)
+    }
+}
@@ -0,0 +1,45 @@
+use flowy_database::{
+    schema::view_search_table,
+    sql_types::{Double, Text},
+};
+
+// The title of a view in the full text index.
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable)]
+#[table_name = "view_search_table"]
+pub(crate) struct ViewSearchTable {
+    pub(crate) id: i32,
+    pub(crate) view_id: String,
+    pub(crate) name: String,
+}
+
+#[derive(Clone, Debug, Insertable)]
+#[table_name = "view_search_table"]
+pub(crate) struct NewViewSearchTable {
+    pub(crate) view_id: String,
+    pub(crate) name: String,
+}
+
+impl NewViewSearchTable {
+    pub(crate) fn new(view_id: &str, name: &str) -> Self {
+        Self {
+            view_id: view_id.to_owned(),
+            name: name.to_owned(),
+        }
+    }
+}
+
+// The title or the best matching line of the document of the view. The
+// matched terms of the snippet are wrapped by the SNIPPET_MATCH_START and
+// SNIPPET_MATCH_END. The lower the bm25 score, the better the match.
+#[derive(Clone, Debug, QueryableByName)]
+pub(crate) struct SearchRow {
+    #[sql_type = "Text"]
+    pub(crate) view_id: String,
+    #[sql_type = "Text"]
+    pub(crate) snippet: String,
+    #[sql_type = "Double"]
+    pub(crate) score: f64,
+}
+
+pub(crate) const SNIPPET_MATCH_START: char = '\u{2}';
+pub(crate) const SNIPPET_MATCH_END: char = '\u{3}';
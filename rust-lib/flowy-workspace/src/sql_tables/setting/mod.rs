@@ -0,0 +1,5 @@
+mod setting_sql;
+mod setting_table;
+
+pub(crate) use setting_sql::*;
+pub(crate) use setting_table::*;
@@ -0,0 +1,52 @@
+use flowy_database::{
+    prelude::*,
+    schema::{workspace_setting_table, workspace_setting_table::dsl},
+    OptionalExtension,
+    SqliteConnection,
+};
+
+use crate::{
+    errors::WorkspaceError,
+    sql_tables::setting::{workspace_setting_id, WorkspaceSettingTable},
+};
+
+pub(crate) struct WorkspaceSettingTableSql {}
+
+impl WorkspaceSettingTableSql {
+    pub(crate) fn set_setting(table: WorkspaceSettingTable, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = diesel::replace_into(workspace_setting_table::table)
+            .values(&table)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_setting(
+        workspace_id: &str,
+        key: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Option<WorkspaceSettingTable>, WorkspaceError> {
+        let table = dsl::workspace_setting_table
+            .filter(workspace_setting_table::id.eq(workspace_setting_id(workspace_id, key)))
+            .first::<WorkspaceSettingTable>(conn)
+            .optional()?;
+        Ok(table)
+    }
+
+    pub(crate) fn read_settings(
+        workspace_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<WorkspaceSettingTable>, WorkspaceError> {
+        let tables = dsl::workspace_setting_table
+            .filter(workspace_setting_table::workspace_id.eq(workspace_id))
+            .order(workspace_setting_table::key.asc())
+            .load::<WorkspaceSettingTable>(conn)?;
+        Ok(tables)
+    }
+
+    pub(crate) fn delete_workspace_settings(workspace_id: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ =
+            diesel::delete(dsl::workspace_setting_table.filter(workspace_setting_table::workspace_id.eq(workspace_id)))
+                .execute(conn)?;
+        Ok(())
+    }
+}
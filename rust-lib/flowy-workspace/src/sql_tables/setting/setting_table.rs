@@ -0,0 +1,52 @@
+use flowy_database::schema::workspace_setting_table;
+use flowy_workspace_infra::entities::workspace::{WorkspaceSettingItem, WorkspaceSettingValue};
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable)]
+#[table_name = "workspace_setting_table"]
+pub(crate) struct WorkspaceSettingTable {
+    pub id: String,
+    pub workspace_id: String,
+    pub key: String,
+    pub str_value: Option<String>,
+    pub int_value: Option<i64>,
+    pub float_value: Option<f64>,
+    pub bool_value: Option<bool>,
+}
+
+impl std::convert::From<WorkspaceSettingItem> for WorkspaceSettingTable {
+    fn from(item: WorkspaceSettingItem) -> Self {
+        let WorkspaceSettingValue {
+            str_value,
+            int_value,
+            float_value,
+            bool_value,
+        } = item.value;
+        WorkspaceSettingTable {
+            id: workspace_setting_id(&item.workspace_id, &item.key),
+            workspace_id: item.workspace_id,
+            key: item.key,
+            str_value,
+            int_value,
+            float_value,
+            bool_value,
+        }
+    }
+}
+
+impl std::convert::From<WorkspaceSettingTable> for WorkspaceSettingItem {
+    fn from(table: WorkspaceSettingTable) -> Self {
+        WorkspaceSettingItem {
+            workspace_id: table.workspace_id,
+            key: table.key,
+            value: WorkspaceSettingValue {
+                str_value: table.str_value,
+                int_value: table.int_value,
+                float_value: table.float_value,
+                bool_value: table.bool_value,
+            },
+        }
+    }
+}
+
+// The key is set once per workspace, the id is made of both.
+pub(crate) fn workspace_setting_id(workspace_id: &str, key: &str) -> String { format!("{}:{}", workspace_id, key) }
@@ -0,0 +1,34 @@
+use flowy_database::{
+    prelude::*,
+    schema::{sync_base_table, sync_base_table::dsl},
+    OptionalExtension,
+    SqliteConnection,
+};
+
+use crate::{errors::WorkspaceError, sql_tables::sync::SyncBaseTable};
+
+pub(crate) struct SyncBaseTableSql {}
+
+impl SyncBaseTableSql {
+    pub(crate) fn save_base(table: SyncBaseTable, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = table.upsert(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_base(object_id: &str, conn: &SqliteConnection) -> Result<Option<SyncBaseTable>, WorkspaceError> {
+        let table = SyncBaseTable::read(object_id, conn).optional()?;
+        Ok(table)
+    }
+
+    pub(crate) fn read_base_ids(conn: &SqliteConnection) -> Result<Vec<String>, WorkspaceError> {
+        let ids = dsl::sync_base_table
+            .select(sync_base_table::object_id)
+            .load::<String>(conn)?;
+        Ok(ids)
+    }
+
+    pub(crate) fn delete_base(object_id: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = diesel::delete(dsl::sync_base_table.filter(sync_base_table::object_id.eq(object_id))).execute(conn)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+use crate::sql_tables::operation::SqlOperationType;
+use flowy_database::schema::sync_base_table;
+use flowy_derive::SqlTable;
+
+// The values that the fields updated locally had before, since the last time
+// the server was told about them. It's encoded like the update.
+#[derive(PartialEq, Clone, Debug, Queryable, SqlTable)]
+#[table_name = "sync_base_table"]
+#[primary_key(object_id)]
+pub(crate) struct SyncBaseTable {
+    pub object_id: String,
+    pub ty: SqlOperationType,
+    pub data: Vec<u8>,
+}
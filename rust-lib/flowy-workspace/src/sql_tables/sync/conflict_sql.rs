@@ -0,0 +1,40 @@
+use flowy_database::{
+    prelude::*,
+    schema::{sync_conflict_table, sync_conflict_table::dsl},
+    OptionalExtension,
+    SqliteConnection,
+};
+
+use crate::{errors::WorkspaceError, sql_tables::sync::SyncConflictTable};
+
+pub(crate) struct SyncConflictTableSql {}
+
+impl SyncConflictTableSql {
+    // The newer remote change of the object replaces the one that was waiting.
+    pub(crate) fn save_conflict(table: SyncConflictTable, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = table.upsert(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_conflict(
+        object_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Option<SyncConflictTable>, WorkspaceError> {
+        let table = SyncConflictTable::read(object_id, conn).optional()?;
+        Ok(table)
+    }
+
+    // In the order they were found.
+    pub(crate) fn read_conflicts(conn: &SqliteConnection) -> Result<Vec<SyncConflictTable>, WorkspaceError> {
+        let tables = SyncConflictTable::query()
+            .order(sync_conflict_table::create_time.asc())
+            .load::<SyncConflictTable>(conn)?;
+        Ok(tables)
+    }
+
+    pub(crate) fn delete_conflict(object_id: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = diesel::delete(dsl::sync_conflict_table.filter(sync_conflict_table::object_id.eq(object_id)))
+            .execute(conn)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use flowy_database::schema::sync_conflict_table;
+use flowy_derive::SqlTable;
+use flowy_infra::timestamp;
+use flowy_workspace_infra::entities::sync::{RemoteChange, RemoteChangeType};
+
+// The remote change that waits for the user, one per object.
+#[derive(PartialEq, Clone, Debug, Queryable, SqlTable)]
+#[table_name = "sync_conflict_table"]
+#[primary_key(object_id)]
+pub(crate) struct SyncConflictTable {
+    pub object_id: String,
+    pub change_ty: i32,
+    pub rev_id: i64,
+    pub data: Vec<u8>,
+    pub remote_time: i64,
+    pub local_time: i64,
+    pub create_time: i64,
+}
+
+impl SyncConflictTable {
+    pub(crate) fn new(change: RemoteChange, local_time: i64) -> Self {
+        SyncConflictTable {
+            object_id: change.object_id,
+            change_ty: change.ty as i32,
+            rev_id: change.rev_id,
+            data: change.data,
+            remote_time: change.modified_time,
+            local_time,
+            create_time: timestamp(),
+        }
+    }
+
+    pub(crate) fn remote_change(&self) -> RemoteChange {
+        RemoteChange {
+            rev_id: self.rev_id,
+            object_id: self.object_id.clone(),
+            ty: remote_change_type(self.change_ty),
+            data: self.data.clone(),
+            modified_time: self.remote_time,
+        }
+    }
+}
+
+pub(crate) fn remote_change_type(value: i32) -> RemoteChangeType {
+    match value {
+        1 => RemoteChangeType::UpdateWorkspace,
+        2 => RemoteChangeType::CreateApp,
+        3 => RemoteChangeType::UpdateApp,
+        4 => RemoteChangeType::CreateView,
+        5 => RemoteChangeType::UpdateView,
+        6 => RemoteChangeType::DocDelta,
+        _ => RemoteChangeType::Unknown,
+    }
+}
@@ -0,0 +1,30 @@
+use flowy_database::{
+    prelude::*,
+    schema::{sync_exclusion_table, sync_exclusion_table::dsl},
+    SqliteConnection,
+};
+
+use crate::{errors::WorkspaceError, sql_tables::sync::SyncExclusionTable};
+
+pub(crate) struct SyncExclusionTableSql {}
+
+impl SyncExclusionTableSql {
+    pub(crate) fn save_exclusion(table: SyncExclusionTable, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = table.upsert(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_exclusions(conn: &SqliteConnection) -> Result<Vec<SyncExclusionTable>, WorkspaceError> {
+        let tables = SyncExclusionTable::query()
+            .order(sync_exclusion_table::create_time.asc())
+            .load::<SyncExclusionTable>(conn)?;
+        Ok(tables)
+    }
+
+    // Returns false if the object wasn't excluded.
+    pub(crate) fn delete_exclusion(object_id: &str, conn: &SqliteConnection) -> Result<bool, WorkspaceError> {
+        let count = diesel::delete(dsl::sync_exclusion_table.filter(sync_exclusion_table::object_id.eq(object_id)))
+            .execute(conn)?;
+        Ok(count > 0)
+    }
+}
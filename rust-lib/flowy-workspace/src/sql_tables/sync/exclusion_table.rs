@@ -0,0 +1,24 @@
+use crate::sql_tables::sync::SqlSyncObjectType;
+use flowy_database::schema::sync_exclusion_table;
+use flowy_derive::SqlTable;
+use flowy_infra::timestamp;
+
+// The workspace or the app that isn't synced, with everything in it.
+#[derive(PartialEq, Clone, Debug, Queryable, SqlTable)]
+#[table_name = "sync_exclusion_table"]
+#[primary_key(object_id)]
+pub(crate) struct SyncExclusionTable {
+    pub object_id: String,
+    pub ty: SqlSyncObjectType,
+    pub create_time: i64,
+}
+
+impl SyncExclusionTable {
+    pub(crate) fn new(object_id: &str, ty: SqlSyncObjectType) -> Self {
+        SyncExclusionTable {
+            object_id: object_id.to_owned(),
+            ty,
+            create_time: timestamp(),
+        }
+    }
+}
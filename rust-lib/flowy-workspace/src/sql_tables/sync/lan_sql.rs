@@ -0,0 +1,82 @@
+use flowy_database::{
+    prelude::*,
+    schema::{lan_change_table, lan_change_table::dsl, lan_peer_table},
+    OptionalExtension,
+    SqliteConnection,
+};
+
+use crate::{
+    errors::WorkspaceError,
+    sql_tables::sync::{LanChangeTable, LanPeerTable},
+};
+
+pub(crate) struct LanChangeTableSql {}
+
+impl LanChangeTableSql {
+    pub(crate) fn create_change(
+        object_id: &str,
+        change_ty: i32,
+        data: Vec<u8>,
+        modified_time: i64,
+        conn: &SqliteConnection,
+    ) -> Result<(), WorkspaceError> {
+        let _ = diesel::insert_into(lan_change_table::table)
+            .values((
+                dsl::object_id.eq(object_id),
+                dsl::change_ty.eq(change_ty),
+                dsl::data.eq(data),
+                dsl::modified_time.eq(modified_time),
+            ))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    // In the order they were made.
+    pub(crate) fn read_changes_after(
+        seq: i64,
+        limit: i64,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<LanChangeTable>, WorkspaceError> {
+        let changes = dsl::lan_change_table
+            .filter(lan_change_table::seq.gt(seq as i32))
+            .order(lan_change_table::seq.asc())
+            .limit(limit)
+            .load::<LanChangeTable>(conn)?;
+        Ok(changes)
+    }
+
+    pub(crate) fn read_last_seq(conn: &SqliteConnection) -> Result<i64, WorkspaceError> {
+        let seq = dsl::lan_change_table
+            .select(diesel::dsl::max(lan_change_table::seq))
+            .first::<Option<i32>>(conn)?;
+        Ok(seq.unwrap_or(0) as i64)
+    }
+
+    // The seqs aren't reused, the peers keep pulling after the ones they pulled.
+    pub(crate) fn delete_changes(conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = diesel::delete(dsl::lan_change_table).execute(conn)?;
+        Ok(())
+    }
+}
+
+pub(crate) struct LanPeerTableSql {}
+
+impl LanPeerTableSql {
+    pub(crate) fn save_peer(table: LanPeerTable, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = table.upsert(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_peer(device_id: &str, conn: &SqliteConnection) -> Result<Option<LanPeerTable>, WorkspaceError> {
+        let table = LanPeerTable::read(device_id, conn).optional()?;
+        Ok(table)
+    }
+
+    // The last seen comes first.
+    pub(crate) fn read_peers(conn: &SqliteConnection) -> Result<Vec<LanPeerTable>, WorkspaceError> {
+        let tables = LanPeerTable::query()
+            .order(lan_peer_table::last_seen.desc())
+            .load::<LanPeerTable>(conn)?;
+        Ok(tables)
+    }
+}
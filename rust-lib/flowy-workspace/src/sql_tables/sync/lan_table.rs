@@ -0,0 +1,62 @@
+use crate::sql_tables::sync::remote_change_type;
+use flowy_database::schema::lan_peer_table;
+use flowy_derive::SqlTable;
+use flowy_infra::timestamp;
+use flowy_workspace_infra::entities::sync::{LanPeer, RemoteChange};
+
+// The local change that the devices on the local network pull, the seq is its
+// place in the log of this device.
+#[derive(PartialEq, Clone, Debug, Queryable)]
+pub(crate) struct LanChangeTable {
+    pub seq: i32,
+    pub object_id: String,
+    pub change_ty: i32,
+    pub data: Vec<u8>,
+    pub modified_time: i64,
+}
+
+impl LanChangeTable {
+    pub(crate) fn remote_change(self) -> RemoteChange {
+        RemoteChange {
+            object_id: self.object_id,
+            ty: remote_change_type(self.change_ty),
+            data: self.data,
+            rev_id: self.seq as i64,
+            modified_time: self.modified_time,
+        }
+    }
+}
+
+// The device that was found on the local network and how far its log was
+// pulled.
+#[derive(PartialEq, Clone, Debug, Queryable, SqlTable)]
+#[table_name = "lan_peer_table"]
+#[primary_key(device_id)]
+pub(crate) struct LanPeerTable {
+    pub device_id: String,
+    pub address: String,
+    pub pulled_seq: i64,
+    pub last_seen: i64,
+}
+
+impl LanPeerTable {
+    pub(crate) fn new(device_id: &str, address: &str, pulled_seq: i64) -> Self {
+        LanPeerTable {
+            device_id: device_id.to_owned(),
+            address: address.to_owned(),
+            pulled_seq,
+            last_seen: timestamp(),
+        }
+    }
+}
+
+impl std::convert::From<LanPeerTable> for LanPeer {
+    fn from(table: LanPeerTable) -> Self {
+        LanPeer {
+            device_id: table.device_id,
+            address: table.address,
+            last_seen: table.last_seen,
+            pulled_seq: table.pulled_seq,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+mod base_sql;
+mod base_table;
+mod conflict_sql;
+mod conflict_table;
+mod exclusion_sql;
+mod exclusion_table;
+mod lan_sql;
+mod lan_table;
+mod sync_sql;
+mod sync_table;
+
+pub(crate) use base_sql::*;
+pub(crate) use base_table::*;
+pub(crate) use conflict_sql::*;
+pub(crate) use conflict_table::*;
+pub(crate) use exclusion_sql::*;
+pub(crate) use exclusion_table::*;
+pub(crate) use lan_sql::*;
+pub(crate) use lan_table::*;
+pub(crate) use sync_sql::*;
+pub(crate) use sync_table::*;
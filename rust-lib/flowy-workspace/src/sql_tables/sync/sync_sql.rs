@@ -0,0 +1,47 @@
+use flowy_database::{
+    prelude::*,
+    schema::{sync_rev_table, sync_rev_table::dsl},
+    OptionalExtension,
+    SqliteConnection,
+};
+
+use crate::{
+    errors::WorkspaceError,
+    sql_tables::sync::{SqlSyncObjectType, SyncRevTable},
+};
+
+pub(crate) struct SyncRevTableSql {}
+
+impl SyncRevTableSql {
+    // The revision is only moved forward.
+    pub(crate) fn save_rev(
+        object_id: &str,
+        ty: SqlSyncObjectType,
+        rev_id: i64,
+        conn: &SqliteConnection,
+    ) -> Result<(), WorkspaceError> {
+        if Self::read_rev(object_id, conn)? >= rev_id {
+            return Ok(());
+        }
+        let _ = SyncRevTable::new(object_id, ty, rev_id).upsert(conn)?;
+        Ok(())
+    }
+
+    // It's 0 if no remote change was applied to the object.
+    pub(crate) fn read_rev(object_id: &str, conn: &SqliteConnection) -> Result<i64, WorkspaceError> {
+        let rev_id = dsl::sync_rev_table
+            .filter(sync_rev_table::object_id.eq(object_id))
+            .select(sync_rev_table::rev_id)
+            .first::<i64>(conn)
+            .optional()?;
+        Ok(rev_id.unwrap_or(0))
+    }
+
+    // The changes are pulled from it.
+    pub(crate) fn read_max_rev(conn: &SqliteConnection) -> Result<i64, WorkspaceError> {
+        let rev_id = dsl::sync_rev_table
+            .select(diesel::dsl::max(sync_rev_table::rev_id))
+            .first::<Option<i64>>(conn)?;
+        Ok(rev_id.unwrap_or(0))
+    }
+}
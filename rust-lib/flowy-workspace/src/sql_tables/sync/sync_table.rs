@@ -0,0 +1,51 @@
+use diesel::sql_types::Integer;
+use flowy_database::schema::sync_rev_table;
+use flowy_derive::SqlTable;
+use flowy_infra::timestamp;
+
+// The revision of the last remote change that was applied to the object.
+#[derive(PartialEq, Clone, Debug, Queryable, SqlTable)]
+#[table_name = "sync_rev_table"]
+#[primary_key(object_id)]
+pub(crate) struct SyncRevTable {
+    pub object_id: String,
+    pub ty: SqlSyncObjectType,
+    pub rev_id: i64,
+    pub sync_time: i64,
+}
+
+impl SyncRevTable {
+    pub(crate) fn new(object_id: &str, ty: SqlSyncObjectType, rev_id: i64) -> Self {
+        SyncRevTable {
+            object_id: object_id.to_owned(),
+            ty,
+            rev_id,
+            sync_time: timestamp(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, FromSqlRow, AsExpression)]
+#[repr(i32)]
+#[sql_type = "Integer"]
+pub(crate) enum SqlSyncObjectType {
+    Unknown   = 0,
+    Workspace = 1,
+    App       = 2,
+    View      = 3,
+    Doc       = 4,
+}
+
+impl std::convert::From<i32> for SqlSyncObjectType {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => SqlSyncObjectType::Workspace,
+            2 => SqlSyncObjectType::App,
+            3 => SqlSyncObjectType::View,
+            4 => SqlSyncObjectType::Doc,
+            _o => SqlSyncObjectType::Unknown,
+        }
+    }
+}
+
+impl_sql_integer_expression!(SqlSyncObjectType);
@@ -0,0 +1,5 @@
+mod template_sql;
+mod template_table;
+
+pub(crate) use template_sql::*;
+pub(crate) use template_table::*;
@@ -0,0 +1,27 @@
+use flowy_database::{prelude::*, schema::template_table, OptionalExtension, SqliteConnection};
+
+use crate::{errors::WorkspaceError, sql_tables::template::TemplateTable};
+
+pub(crate) struct TemplateTableSql {}
+
+impl TemplateTableSql {
+    pub(crate) fn create_template(table: TemplateTable, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = table.insert(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_template(
+        template_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Option<TemplateTable>, WorkspaceError> {
+        let template = TemplateTable::read(template_id, conn).optional()?;
+        Ok(template)
+    }
+
+    pub(crate) fn read_templates(conn: &SqliteConnection) -> Result<Vec<TemplateTable>, WorkspaceError> {
+        let templates = TemplateTable::query()
+            .order(template_table::create_time.asc())
+            .load::<TemplateTable>(conn)?;
+        Ok(templates)
+    }
+}
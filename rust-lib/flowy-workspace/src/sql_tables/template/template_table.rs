@@ -0,0 +1,39 @@
+use crate::entities::template::Template;
+use flowy_database::schema::template_table;
+use flowy_derive::SqlTable;
+use flowy_infra::{timestamp, uuid};
+
+#[derive(PartialEq, Clone, Debug, Queryable, SqlTable)]
+#[table_name = "template_table"]
+pub(crate) struct TemplateTable {
+    pub id: String,
+    pub name: String,
+    pub desc: String,
+    pub data: String,
+    pub create_time: i64,
+}
+
+impl TemplateTable {
+    pub(crate) fn new(name: &str, desc: &str, data: String) -> Self {
+        TemplateTable {
+            id: uuid(),
+            name: name.to_owned(),
+            desc: desc.to_owned(),
+            data,
+            create_time: timestamp(),
+        }
+    }
+}
+
+impl std::convert::From<TemplateTable> for Template {
+    fn from(table: TemplateTable) -> Self {
+        Template {
+            id: table.id,
+            name: table.name,
+            desc: table.desc,
+            built_in: false,
+            data: table.data,
+            create_time: table.create_time,
+        }
+    }
+}
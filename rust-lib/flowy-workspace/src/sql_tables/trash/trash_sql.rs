@@ -3,6 +3,7 @@ use flowy_database::{
     schema::{trash_table, trash_table::dsl},
     SqliteConnection,
 };
+use flowy_infra::timestamp;
 
 use crate::{
     entities::trash::{RepeatedTrash, Trash},
@@ -15,7 +16,12 @@ pub struct TrashTableSql {}
 impl TrashTableSql {
     pub(crate) fn create_trash(repeated_trash: Vec<Trash>, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
         for trash in repeated_trash {
-            let trash_table: TrashTable = trash.into();
+            let mut trash_table: TrashTable = trash.into();
+            // The trash read from the server doesn't know when it was deleted, its
+            // retention starts when it's saved.
+            if trash_table.deleted_time == 0 {
+                trash_table.deleted_time = timestamp();
+            }
             match diesel_record_count!(trash_table, &trash_table.id, conn) {
                 0 => diesel_insert_table!(trash_table, &trash_table, conn),
                 _ => {
@@ -29,7 +35,16 @@ impl TrashTableSql {
     }
 
     pub(crate) fn read_all(conn: &SqliteConnection) -> Result<RepeatedTrash, WorkspaceError> {
-        let trash_tables = dsl::trash_table.load::<TrashTable>(conn)?;
+        let trash_tables = TrashTable::read_all(conn)?;
+        let items = trash_tables.into_iter().map(|t| t.into()).collect::<Vec<Trash>>();
+        Ok(RepeatedTrash { items })
+    }
+
+    // The trash that was deleted before the deadline.
+    pub(crate) fn read_expired(deadline: i64, conn: &SqliteConnection) -> Result<RepeatedTrash, WorkspaceError> {
+        let trash_tables = TrashTable::query()
+            .filter(trash_table::deleted_time.le(deadline))
+            .load::<TrashTable>(conn)?;
         let items = trash_tables.into_iter().map(|t| t.into()).collect::<Vec<Trash>>();
         Ok(RepeatedTrash { items })
     }
@@ -40,14 +55,19 @@ impl TrashTableSql {
     }
 
     pub(crate) fn read(trash_id: &str, conn: &SqliteConnection) -> Result<TrashTable, WorkspaceError> {
-        let trash_table = dsl::trash_table
-            .filter(trash_table::id.eq(trash_id))
-            .first::<TrashTable>(conn)?;
+        let trash_table = TrashTable::read(trash_id, conn)?;
         Ok(trash_table)
     }
 
     pub(crate) fn delete_trash(trash_id: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
-        diesel_delete_table!(trash_table, trash_id, conn);
+        let affected_row = TrashTable::delete(trash_id, conn)?;
+        debug_assert_eq!(affected_row, 1);
+        Ok(())
+    }
+
+    // The ids that aren't in the trash are skipped.
+    pub(crate) fn delete_trash_with_ids(trash_ids: &[String], conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = diesel::delete(dsl::trash_table.filter(trash_table::id.eq_any(trash_ids))).execute(conn)?;
         Ok(())
     }
 }
@@ -1,8 +1,9 @@
 use crate::entities::trash::{Trash, TrashType};
 use diesel::sql_types::Integer;
 use flowy_database::schema::trash_table;
+use flowy_derive::SqlTable;
 
-#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations, SqlTable)]
 #[table_name = "trash_table"]
 pub(crate) struct TrashTable {
     pub id: String,
@@ -11,6 +12,7 @@ pub(crate) struct TrashTable {
     pub modified_time: i64,
     pub create_time: i64,
     pub ty: SqlTrashType,
+    pub deleted_time: i64,
 }
 impl std::convert::Into<Trash> for TrashTable {
     fn into(self) -> Trash {
@@ -20,6 +22,7 @@ impl std::convert::Into<Trash> for TrashTable {
             modified_time: self.modified_time,
             create_time: self.create_time,
             ty: self.ty.into(),
+            deleted_time: self.deleted_time,
         }
     }
 }
@@ -33,6 +36,7 @@ impl std::convert::From<Trash> for TrashTable {
             modified_time: trash.modified_time,
             create_time: trash.create_time,
             ty: trash.ty.into(),
+            deleted_time: trash.deleted_time,
         }
     }
 }
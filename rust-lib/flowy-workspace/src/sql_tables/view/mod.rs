@@ -1,5 +1,9 @@
+mod view_name;
+mod view_order;
 mod view_sql;
 mod view_table;
 
+pub(crate) use view_name::*;
+pub(crate) use view_order::*;
 pub use view_sql::*;
 pub use view_table::*;
@@ -0,0 +1,23 @@
+// The names that are suggested when a sibling already has the name. The number
+// of "Doc (2)" is replaced instead of adding another one, it's "Doc (3)" then.
+pub(crate) const MAX_NAME_SUGGESTIONS: usize = 3;
+
+pub(crate) fn unique_view_names(name: &str, taken: &[String], count: usize) -> Vec<String> {
+    let base = strip_name_number(name);
+    (2..)
+        .map(|number| format!("{} ({})", base, number))
+        .filter(|name| !taken.contains(name))
+        .take(count)
+        .collect()
+}
+
+fn strip_name_number(name: &str) -> &str {
+    let stripped = name
+        .strip_suffix(')')
+        .and_then(|name| name.rsplit_once(" ("))
+        .filter(|(_, number)| !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()));
+    match stripped {
+        Some((base, _)) if !base.is_empty() => base,
+        _ => name,
+    }
+}
@@ -0,0 +1,67 @@
+// The order keys are compared as strings, a view can be put between two others
+// by giving it a key between theirs, so the keys of the siblings are never
+// rewritten. The views created before the keys were introduced have an empty
+// key, they're sorted by their create time.
+const DIGITS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+// Returns the key that sorts after the lower one and before the upper one, or
+// after the lower one if there is no upper bound. Check the gap between them
+// first.
+pub(crate) fn order_key_between(lower: &str, upper: Option<&str>) -> String {
+    debug_assert!(has_order_gap(lower, upper));
+    let lower = lower.as_bytes();
+    let mut upper = upper.map(|upper| upper.as_bytes());
+    let mut key = String::new();
+    let mut index = 0;
+    loop {
+        let low = lower.get(index).map_or(0, |c| digit(*c));
+        let high = match upper {
+            None => DIGITS.len(),
+            Some(upper) => upper.get(index).map_or(0, |c| digit(*c)),
+        };
+        if high > low + 1 {
+            key.push(DIGITS[(low + high) / 2] as char);
+            return key;
+        }
+
+        // The key is already less than the upper one once its digit is less.
+        key.push(DIGITS[low] as char);
+        if high == low + 1 {
+            upper = None;
+        }
+        index += 1;
+    }
+}
+
+// There is no key between the equal keys, and none between "1" and "10". The
+// keys made here never end with the first digit, the keys can be made again if
+// there is no gap.
+pub(crate) fn has_order_gap(lower: &str, upper: Option<&str>) -> bool {
+    upper.map_or(true, |upper| lower < upper && !upper.ends_with(DIGITS[0] as char))
+}
+
+fn digit(c: u8) -> usize { DIGITS.iter().position(|d| *d == c).unwrap_or(0) }
+
+// The key that puts an item at the index of the sorted keys, None if there is no
+// key between the keys of its neighbours.
+pub(crate) fn order_key_at(keys: &[&str], index: usize) -> Option<String> {
+    let lower = match index {
+        0 => "",
+        _ => keys[index - 1],
+    };
+    let upper = keys.get(index).copied();
+    match has_order_gap(lower, upper) {
+        true => Some(order_key_between(lower, upper)),
+        false => None,
+    }
+}
+
+// The keys of the items that are sorted again.
+pub(crate) fn order_keys(count: usize) -> Vec<String> {
+    let mut keys: Vec<String> = Vec::with_capacity(count);
+    for _ in 0..count {
+        let lower = keys.last().map_or("", |key| key.as_str());
+        keys.push(order_key_between(lower, None));
+    }
+    keys
+}
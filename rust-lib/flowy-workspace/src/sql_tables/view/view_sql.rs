@@ -1,20 +1,32 @@
 use flowy_database::{
     prelude::*,
     schema::{view_table, view_table::dsl},
+    OptionalExtension,
     SqliteConnection,
 };
 
 use crate::{
     errors::WorkspaceError,
-    sql_tables::view::{ViewTable, ViewTableChangeset},
+    sql_tables::view::{order_key_between, ViewTable, ViewTableChangeset},
 };
+use flowy_infra::timestamp;
 
 pub struct ViewTableSql {}
 
 impl ViewTableSql {
-    pub(crate) fn create_view(view_table: ViewTable, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+    // The new view is put after its siblings.
+    pub(crate) fn create_view(mut view_table: ViewTable, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
         match diesel_record_count!(view_table, &view_table.id, conn) {
-            0 => diesel_insert_table!(view_table, &view_table, conn),
+            0 => {
+                let last_key = dsl::view_table
+                    .filter(view_table::belong_to_id.eq(&view_table.belong_to_id))
+                    .select(view_table::order_key)
+                    .order(view_table::order_key.desc())
+                    .first::<String>(conn)
+                    .optional()?;
+                view_table.order_key = order_key_between(&last_key.unwrap_or_default(), None);
+                diesel_insert_table!(view_table, &view_table, conn)
+            },
             _ => {
                 let changeset = ViewTableChangeset::from_table(view_table);
                 diesel_update_table!(view_table, changeset, conn)
@@ -42,22 +54,62 @@ impl ViewTableSql {
     pub(crate) fn read_views(belong_to_id: &str, conn: &SqliteConnection) -> Result<Vec<ViewTable>, WorkspaceError> {
         let view_tables = dsl::view_table
             .filter(view_table::belong_to_id.eq(belong_to_id))
-            .order(view_table::create_time.asc())
+            .order((view_table::order_key.asc(), view_table::create_time.asc()))
             .into_boxed()
             .load::<ViewTable>(conn)?;
 
         Ok(view_tables)
     }
 
+    // The views that don't exist are skipped.
+    pub(crate) fn read_views_with_ids(
+        view_ids: &[String],
+        conn: &SqliteConnection,
+    ) -> Result<Vec<ViewTable>, WorkspaceError> {
+        let view_tables = dsl::view_table
+            .filter(view_table::id.eq_any(view_ids))
+            .load::<ViewTable>(conn)?;
+        Ok(view_tables)
+    }
+
+    // The id and the belong_to_id of all the views.
+    pub(crate) fn read_belongings(conn: &SqliteConnection) -> Result<Vec<(String, String)>, WorkspaceError> {
+        let belongings = dsl::view_table
+            .select((view_table::id, view_table::belong_to_id))
+            .load::<(String, String)>(conn)?;
+        Ok(belongings)
+    }
+
     pub(crate) fn update_view(changeset: ViewTableChangeset, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
         diesel_update_table!(view_table, changeset, conn);
         Ok(())
     }
 
+    pub(crate) fn move_view(
+        view_id: &str,
+        belong_to_id: &str,
+        order_key: &str,
+        conn: &SqliteConnection,
+    ) -> Result<(), WorkspaceError> {
+        let _ = diesel::update(dsl::view_table.filter(view_table::id.eq(view_id)))
+            .set((
+                view_table::belong_to_id.eq(belong_to_id),
+                view_table::order_key.eq(order_key),
+                view_table::modified_time.eq(timestamp()),
+            ))
+            .execute(conn)?;
+        Ok(())
+    }
+
     pub(crate) fn delete_view(view_id: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
         diesel_delete_table!(view_table, view_id, conn);
         Ok(())
     }
+
+    pub(crate) fn delete_views(view_ids: &[String], conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = diesel::delete(dsl::view_table.filter(view_table::id.eq_any(view_ids))).execute(conn)?;
+        Ok(())
+    }
 }
 
 // pub(crate) fn read_views(
@@ -25,6 +25,10 @@ pub(crate) struct ViewTable {
     pub view_type: ViewTableType,
     pub version: i64,
     pub is_trash: bool,
+    // The views of the same app are sorted by it, see view_order.
+    pub order_key: String,
+    pub icon: String,
+    pub cover: String,
 }
 
 impl ViewTable {
@@ -32,6 +36,7 @@ impl ViewTable {
         let view_type = match view.view_type {
             ViewType::Blank => ViewTableType::Docs,
             ViewType::Doc => ViewTableType::Docs,
+            ViewType::Grid => ViewTableType::Grid,
         };
 
         ViewTable {
@@ -46,6 +51,22 @@ impl ViewTable {
             view_type,
             version: 0,
             is_trash: false,
+            order_key: "".to_owned(),
+            icon: view.icon,
+            cover: view.cover,
+        }
+    }
+
+    // The fields that an update can change, to drop the ones it doesn't.
+    pub(crate) fn current_fields(&self) -> UpdateViewParams {
+        UpdateViewParams {
+            view_id: self.id.clone(),
+            name: Some(self.name.clone()),
+            desc: Some(self.desc.clone()),
+            thumbnail: Some(self.thumbnail.clone()),
+            icon: Some(self.icon.clone()),
+            cover: Some(self.cover.clone()),
+            auto_rename: false,
         }
     }
 }
@@ -54,6 +75,7 @@ impl std::convert::Into<View> for ViewTable {
     fn into(self) -> View {
         let view_type = match self.view_type {
             ViewTableType::Docs => ViewType::Doc,
+            ViewTableType::Grid => ViewType::Grid,
         };
 
         View {
@@ -66,6 +88,8 @@ impl std::convert::Into<View> for ViewTable {
             modified_time: self.modified_time,
             version: self.version,
             create_time: self.create_time,
+            icon: self.icon,
+            cover: self.cover,
         }
     }
 }
@@ -78,6 +102,7 @@ impl std::convert::Into<Trash> for ViewTable {
             modified_time: self.modified_time,
             create_time: self.create_time,
             ty: TrashType::View,
+            deleted_time: timestamp(),
         }
     }
 }
@@ -89,6 +114,8 @@ pub(crate) struct ViewTableChangeset {
     pub name: Option<String>,
     pub desc: Option<String>,
     pub thumbnail: Option<String>,
+    pub icon: Option<String>,
+    pub cover: Option<String>,
     pub modified_time: i64,
 }
 
@@ -99,6 +126,8 @@ impl ViewTableChangeset {
             name: params.name,
             desc: params.desc,
             thumbnail: params.thumbnail,
+            icon: params.icon,
+            cover: params.cover,
             modified_time: timestamp(),
         }
     }
@@ -109,6 +138,8 @@ impl ViewTableChangeset {
             name: Some(table.name),
             desc: Some(table.desc),
             thumbnail: Some(table.thumbnail),
+            icon: Some(table.icon),
+            cover: Some(table.cover),
             modified_time: table.modified_time,
         }
     }
@@ -119,6 +150,7 @@ impl ViewTableChangeset {
 #[sql_type = "Integer"]
 pub enum ViewTableType {
     Docs = 0,
+    Grid = 1,
 }
 
 impl std::default::Default for ViewTableType {
@@ -129,6 +161,7 @@ impl std::convert::From<i32> for ViewTableType {
     fn from(value: i32) -> Self {
         match value {
             0 => ViewTableType::Docs,
+            1 => ViewTableType::Grid,
             o => {
                 log::error!("Unsupported view type {}, fallback to ViewType::Docs", o);
                 ViewTableType::Docs
@@ -48,7 +48,24 @@ impl WorkspaceTableSql {
         Ok(workspaces)
     }
 
-    #[allow(dead_code)]
+    // The ids of the workspaces of all the users.
+    pub(crate) fn read_workspace_ids(&self, conn: &SqliteConnection) -> Result<Vec<String>, WorkspaceError> {
+        let workspace_ids = dsl::workspace_table.select(workspace_table::id).load::<String>(conn)?;
+        Ok(workspace_ids)
+    }
+
+    pub(crate) fn transfer_workspaces(
+        &self,
+        from_user_id: &str,
+        to_user_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<(), WorkspaceError> {
+        let _ = diesel::update(dsl::workspace_table.filter(workspace_table::user_id.eq(from_user_id)))
+            .set(workspace_table::user_id.eq(to_user_id))
+            .execute(conn)?;
+        Ok(())
+    }
+
     pub(crate) fn update_workspace(
         &self,
         changeset: WorkspaceTableChangeset,
@@ -58,7 +75,6 @@ impl WorkspaceTableSql {
         Ok(())
     }
 
-    #[allow(dead_code)]
     pub(crate) fn delete_workspace(&self, workspace_id: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
         diesel_delete_table!(workspace_table, workspace_id, conn);
         Ok(())
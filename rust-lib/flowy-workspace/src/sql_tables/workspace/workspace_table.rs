@@ -29,6 +29,15 @@ impl WorkspaceTable {
             version: 0,
         }
     }
+
+    // The fields that an update can change, to drop the ones it doesn't.
+    pub(crate) fn current_fields(&self) -> UpdateWorkspaceParams {
+        UpdateWorkspaceParams {
+            id: self.id.clone(),
+            name: Some(self.name.clone()),
+            desc: Some(self.desc.clone()),
+        }
+    }
 }
 
 impl std::convert::Into<Workspace> for WorkspaceTable {
@@ -0,0 +1,152 @@
+use bytes::Bytes;
+use flowy_document::module::SyncKey;
+use flowy_document_infra::entities::doc::DocDelta;
+use flowy_ot::core::{Attributes, Delta, DeltaBuilder};
+use flowy_test::{builder::*, workspace::*, FlowyTest, FlowyTestSDK};
+use flowy_workspace::{
+    entities::{
+        sync::{RemoteChange, RemoteChangeType},
+        view::QueryViewRequest,
+        workspace::*,
+    },
+    errors::ErrorCode,
+    event::WorkspaceEvent::*,
+};
+use std::convert::TryInto;
+
+async fn enable_encryption(sdk: &FlowyTestSDK, workspace_id: &str) -> WorkspaceEncryption {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(EnableWorkspaceEncryption)
+        .request(QueryWorkspaceRequest::new(Some(workspace_id.to_owned())))
+        .async_send()
+        .await
+        .parse::<WorkspaceEncryption>()
+}
+
+async fn read_encryption(sdk: &FlowyTestSDK, workspace_id: &str) -> WorkspaceEncryption {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(ReadWorkspaceEncryption)
+        .request(QueryWorkspaceRequest::new(Some(workspace_id.to_owned())))
+        .async_send()
+        .await
+        .parse::<WorkspaceEncryption>()
+}
+
+async fn invite_member(sdk: &FlowyTestSDK, workspace_id: &str, email: &str) -> WorkspaceInvitation {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(InviteWorkspaceMember)
+        .request(InviteWorkspaceMemberRequest::new(workspace_id, email, None))
+        .async_send()
+        .await
+        .parse::<WorkspaceInvitation>()
+}
+
+async fn accept_invitation(sdk: &FlowyTestSDK, invitation: &WorkspaceInvitation, secret: &str) -> FlowyWorkspaceTest {
+    let request = AcceptWorkspaceInvitationRequest::new(&invitation.workspace_id, &invitation.wrapped_key, secret);
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(AcceptWorkspaceInvitation)
+        .request(request)
+        .async_send()
+        .await
+}
+
+fn workspace_key(sdk: &FlowyTestSDK, workspace_id: &str) -> Option<String> {
+    sdk.user_session.workspace_key(workspace_id).unwrap()
+}
+
+#[tokio::test]
+async fn workspace_encryption_enable() {
+    let test = WorkspaceTest::new().await;
+    let workspace_id = test.workspace.id.clone();
+    assert!(!read_encryption(&test.sdk, &workspace_id).await.enabled);
+
+    assert!(enable_encryption(&test.sdk, &workspace_id).await.enabled);
+    assert!(read_encryption(&test.sdk, &workspace_id).await.enabled);
+
+    // The key isn't replaced, the payloads encrypted with it stay readable.
+    let key = workspace_key(&test.sdk, &workspace_id).unwrap();
+    let _ = enable_encryption(&test.sdk, &workspace_id).await;
+    assert_eq!(workspace_key(&test.sdk, &workspace_id).unwrap(), key);
+}
+
+#[tokio::test]
+async fn workspace_encryption_key_shared_with_invitation() {
+    let test = WorkspaceTest::new().await;
+    let workspace_id = test.workspace.id.clone();
+    let invitation = invite_member(&test.sdk, &workspace_id, "bob@example.com").await;
+    assert!(invitation.wrapped_key.is_empty());
+    assert!(invitation.invite_secret.is_empty());
+
+    let _ = enable_encryption(&test.sdk, &workspace_id).await;
+    let invitation = invite_member(&test.sdk, &workspace_id, "bob@example.com").await;
+    assert!(!invitation.wrapped_key.is_empty());
+    assert!(!invitation.invite_secret.is_empty());
+    let key = workspace_key(&test.sdk, &workspace_id).unwrap();
+    assert!(!invitation.wrapped_key.contains(&key));
+
+    // The secret isn't kept with the invitation.
+    let invitations = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ReadWorkspaceInvitations)
+        .request(QueryWorkspaceRequest::new(Some(workspace_id.clone())))
+        .async_send()
+        .await
+        .parse::<RepeatedWorkspaceInvitation>();
+    assert_eq!(invitations[0].wrapped_key, invitation.wrapped_key);
+    assert!(invitations[0].invite_secret.is_empty());
+
+    let member = FlowyTest::setup();
+    let _ = member.init_user().await;
+    let other_secret = invite_member(&test.sdk, &workspace_id, "alice@example.com")
+        .await
+        .invite_secret;
+    let error = accept_invitation(&member.sdk, &invitation, &other_secret).await.error();
+    assert_eq!(error.code, ErrorCode::InvitationSecretInvalid.value());
+    assert_eq!(workspace_key(&member.sdk, &workspace_id), None);
+
+    let encryption = accept_invitation(&member.sdk, &invitation, &invitation.invite_secret)
+        .await
+        .parse::<WorkspaceEncryption>();
+    assert!(encryption.enabled);
+    assert_eq!(workspace_key(&member.sdk, &workspace_id).unwrap(), key);
+}
+
+#[tokio::test]
+async fn workspace_encryption_decrypts_pulled_doc_delta() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let workspace_id = view_test.workspace.id.clone();
+    let _ = enable_encryption(&test.sdk, &workspace_id).await;
+    let cipher = SyncKey {
+        workspace_id: workspace_id.clone(),
+        key: workspace_key(&test.sdk, &workspace_id).unwrap(),
+    }
+    .cipher();
+
+    let view_ids = vec![view_test.view.id.clone()];
+    let doc = open_view(
+        &test.sdk,
+        QueryViewRequest {
+            view_ids: view_ids.clone(),
+        },
+    )
+    .await;
+    let mut delta = DeltaBuilder::new().insert("secret ").build();
+    delta.retain(Delta::from_json(&doc.data).unwrap().target_len, Attributes::default());
+    let doc_delta = DocDelta {
+        doc_id: view_test.view.id.clone(),
+        data: cipher.encrypt_text(&delta.to_json()),
+    };
+    let data: Bytes = doc_delta.try_into().unwrap();
+    let change = RemoteChange {
+        rev_id: 1,
+        object_id: view_test.view.id.clone(),
+        ty: RemoteChangeType::DocDelta,
+        data: data.to_vec(),
+        modified_time: 0,
+    };
+    assert_eq!(test.sdk.workspace.apply_remote_changes(vec![change]).await.unwrap(), 1);
+
+    let doc = open_view(&test.sdk, QueryViewRequest { view_ids }).await;
+    assert!(doc.data.contains("secret "));
+}
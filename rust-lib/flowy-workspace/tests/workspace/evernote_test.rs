@@ -0,0 +1,148 @@
+use flowy_infra::uuid;
+use flowy_ot::core::{AttributeKey, Delta};
+use flowy_test::{builder::*, workspace::*, FlowyTestSDK};
+use flowy_workspace::{
+    entities::{
+        app::{App, QueryAppRequest},
+        share::*,
+        view::{QueryViewRequest, ViewType},
+    },
+    errors::ErrorCode,
+    event::WorkspaceEvent::*,
+};
+
+async fn import_enex(sdk: &FlowyTestSDK, paths: Vec<String>, tag_mapping: EnexTagMapping) -> FlowyWorkspaceTest {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(ImportEnex)
+        .request(ImportEnexRequest {
+            paths,
+            name: None,
+            tag_mapping,
+        })
+        .async_send()
+        .await
+}
+
+fn write_enex(name: &str, notes: &[String]) -> String {
+    let dir = std::env::temp_dir().join(uuid());
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(format!("{}.enex", name));
+    let enex = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!DOCTYPE en-export SYSTEM \"http://xml.evernote.com/pub/evernote-export3.dtd\">\n<en-export application=\"Evernote\">\n{}\n</en-export>\n",
+        notes.join("\n")
+    );
+    std::fs::write(&path, enex).unwrap();
+    path.to_string_lossy().to_string()
+}
+
+fn note(title: &str, enml: &str, tags: &[&str], resources: &str) -> String {
+    let tags = tags.iter().map(|tag| format!("<tag>{}</tag>", tag)).collect::<String>();
+    format!(
+        "<note><title>{}</title><content><![CDATA[<?xml version=\"1.0\" encoding=\"UTF-8\"?><!DOCTYPE en-note SYSTEM \"http://xml.evernote.com/pub/enml2.dtd\"><en-note>{}</en-note>]]></content><created>20211117T120000Z</created><updated>20211118T083000Z</updated>{}{}</note>",
+        title, enml, tags, resources
+    )
+}
+
+fn recipes() -> String {
+    let hash = format!("{:x}", md5::compute(&[1u8, 2, 3]));
+    let photo = "<resource><data encoding=\"base64\">AQ\n ID</data><mime>image/png</mime><resource-attributes><file-name>photo.png</file-name></resource-attributes></resource>";
+    write_enex(
+        "Recipes",
+        &[
+            note(
+                "Pancakes",
+                &format!(
+                    "<div><en-todo checked=\"true\"/>Flour</div><div><en-media hash=\"{}\" type=\"image/png\"/></div>",
+                    hash
+                ),
+                &["breakfast", "sweet"],
+                photo,
+            ),
+            "<note><title>Broken</title><created>20211117T120000Z</note>".to_owned(),
+            note("Soup", "<div>Boil the water</div>", &["dinner"], ""),
+        ],
+    )
+}
+
+async fn read_imported_app(sdk: &FlowyTestSDK, result: &ImportEnexResult) -> App {
+    let app = result.workspace.apps.first_or_crash();
+    read_app(
+        sdk,
+        QueryAppRequest {
+            app_ids: vec![app.id.clone()],
+        },
+    )
+    .await
+}
+
+async fn read_view_delta(sdk: &FlowyTestSDK, view_id: &str) -> Delta {
+    let request = QueryViewRequest {
+        view_ids: vec![view_id.to_owned()],
+    };
+    let doc = open_view(sdk, request).await;
+    Delta::from_json(&doc.data).unwrap()
+}
+
+#[tokio::test]
+async fn enex_import_keeps_the_valid_notes() {
+    let test = WorkspaceTest::new().await;
+    let result = import_enex(&test.sdk, vec![recipes()], EnexTagMapping::Grid)
+        .await
+        .parse::<ImportEnexResult>();
+    assert_eq!(result.workspace.name, DEFAULT_EVERNOTE_WORKSPACE_NAME);
+    assert_eq!(result.failures.len(), 1);
+    assert_eq!(result.failures[0].id, "Recipes#2");
+    assert_eq!(result.failures[0].name, "Broken");
+
+    let app = read_imported_app(&test.sdk, &result).await;
+    assert_eq!(app.name, "Recipes");
+    let views = &app.belongings.items;
+    let names = views.iter().map(|view| view.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["Pancakes", "Soup", "Tags"]);
+    assert_eq!(views[2].view_type, ViewType::Grid);
+
+    // The checkbox is kept as text and the image links to the asset of the
+    // document.
+    let delta = read_view_delta(&test.sdk, &views[0].id).await;
+    assert!(delta.apply("").unwrap().contains("☑ Flour"));
+    let link = delta
+        .ops
+        .iter()
+        .find_map(|op| {
+            op.get_attributes()
+                .get(&AttributeKey::Link)
+                .and_then(|value| value.0.clone())
+        })
+        .unwrap();
+    assert!(link.starts_with("asset://"));
+    assert_eq!(
+        read_view_delta(&test.sdk, &views[1].id).await.apply("").unwrap(),
+        "Boil the water\n"
+    );
+}
+
+#[tokio::test]
+async fn enex_import_tags_as_metadata() {
+    let test = WorkspaceTest::new().await;
+    let result = import_enex(&test.sdk, vec![recipes()], EnexTagMapping::Metadata)
+        .await
+        .parse::<ImportEnexResult>();
+    let app = read_imported_app(&test.sdk, &result).await;
+    let views = &app.belongings.items;
+    assert_eq!(views.len(), 2);
+    assert_eq!(views[0].desc, "breakfast, sweet");
+    assert_eq!(views[1].desc, "dinner");
+}
+
+#[tokio::test]
+async fn enex_import_invalid_export() {
+    let test = WorkspaceTest::new().await;
+    let path = std::env::temp_dir().join(format!("{}.enex", uuid()));
+    std::fs::write(&path, "<html></html>").unwrap();
+    let paths = vec![recipes(), path.to_string_lossy().to_string()];
+    let error = import_enex(&test.sdk, paths, EnexTagMapping::Grid).await.error();
+    assert_eq!(error.code, ErrorCode::EnexInvalid.value());
+
+    let error = import_enex(&test.sdk, vec![], EnexTagMapping::Grid).await.error();
+    assert_eq!(error.code, ErrorCode::EnexInvalid.value());
+}
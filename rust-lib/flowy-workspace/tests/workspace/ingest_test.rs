@@ -0,0 +1,151 @@
+use flowy_ot::core::{AttributeKey, Delta};
+use flowy_test::{builder::*, workspace::*, FlowyTest, FlowyTestSDK};
+use flowy_workspace::{
+    entities::{
+        share::*,
+        view::QueryViewRequest,
+        workspace::{SetWorkspaceSettingRequest, WorkspaceSettingValue, INBOX_VIEW_SETTING},
+    },
+    errors::ErrorCode,
+    event::WorkspaceEvent::*,
+};
+
+async fn ingest(sdk: &FlowyTestSDK, request: IngestSharedContentRequest) -> FlowyWorkspaceTest {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(IngestSharedContent)
+        .request(request)
+        .async_send()
+        .await
+}
+
+fn shared_text(text: &str, target: IngestTarget, belong_to_id: Option<String>) -> IngestSharedContentRequest {
+    IngestSharedContentRequest {
+        content_type: SharedContentType::Text,
+        text: text.to_owned(),
+        target,
+        belong_to_id,
+        ..IngestSharedContentRequest::default()
+    }
+}
+
+async fn set_inbox(sdk: &FlowyTestSDK, workspace_id: &str, view_id: &str) {
+    let _ = FlowyWorkspaceTest::new(sdk.clone())
+        .event(SetWorkspaceSetting)
+        .request(SetWorkspaceSettingRequest::new(
+            workspace_id,
+            INBOX_VIEW_SETTING,
+            WorkspaceSettingValue::str(view_id),
+        ))
+        .async_send()
+        .await;
+}
+
+async fn read_view_delta(sdk: &FlowyTestSDK, view_id: &str) -> Delta {
+    let request = QueryViewRequest {
+        view_ids: vec![view_id.to_owned()],
+    };
+    let doc = open_view(sdk, request).await;
+    Delta::from_json(&doc.data).unwrap()
+}
+
+fn links(delta: &Delta) -> Vec<String> {
+    delta
+        .ops
+        .iter()
+        .filter_map(|op| {
+            op.get_attributes()
+                .get(&AttributeKey::Link)
+                .and_then(|value| value.0.clone())
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn ingest_text_as_new_document() {
+    let test = AppTest::new().await;
+    let request = shared_text(
+        "\n Groceries\r\nMilk\r\n",
+        IngestTarget::NewDocument,
+        Some(test.app.id.clone()),
+    );
+    let result = ingest(&test.sdk, request).await.parse::<IngestSharedContentResult>();
+    assert!(!result.appended);
+    assert_eq!(result.view.name, "Groceries");
+    assert_eq!(result.view.belong_to_id, test.app.id);
+
+    let delta = read_view_delta(&test.sdk, &result.view.id).await;
+    assert_eq!(delta.apply("").unwrap(), "\n Groceries\nMilk\n");
+}
+
+#[tokio::test]
+async fn ingest_url_and_image_into_inbox() {
+    let flowy_test = FlowyTest::setup();
+    let _ = flowy_test.init_user().await;
+    let test = ViewTest::new(&flowy_test).await;
+    set_inbox(&test.sdk, &test.workspace.id, &test.view.id).await;
+
+    let url = IngestSharedContentRequest {
+        content_type: SharedContentType::Url,
+        text: "https://appflowy.io".to_owned(),
+        name: Some("AppFlowy".to_owned()),
+        target: IngestTarget::Inbox,
+        ..IngestSharedContentRequest::default()
+    };
+    let result = ingest(&test.sdk, url).await.parse::<IngestSharedContentResult>();
+    assert!(result.appended);
+    assert_eq!(result.view.id, test.view.id);
+
+    let image = IngestSharedContentRequest {
+        content_type: SharedContentType::Image,
+        data: vec![1, 2, 3],
+        mime_type: "image/png".to_owned(),
+        target: IngestTarget::Inbox,
+        ..IngestSharedContentRequest::default()
+    };
+    let result = ingest(&test.sdk, image).await.parse::<IngestSharedContentResult>();
+    assert_eq!(result.view.id, test.view.id);
+
+    let delta = read_view_delta(&test.sdk, &test.view.id).await;
+    assert_eq!(
+        delta.apply("").unwrap(),
+        format!("AppFlowy\n{}\n", DEFAULT_SHARED_IMAGE_NAME)
+    );
+    let links = links(&delta);
+    assert_eq!(links.len(), 2);
+    assert_eq!(links[0], "https://appflowy.io");
+    assert!(links[1].starts_with("asset://"));
+}
+
+#[tokio::test]
+async fn ingest_invalid_content() {
+    let test = AppTest::new().await;
+    let belong_to_id = Some(test.app.id.clone());
+    let error = ingest(
+        &test.sdk,
+        shared_text(" \n", IngestTarget::NewDocument, belong_to_id.clone()),
+    )
+    .await
+    .error();
+    assert_eq!(error.code, ErrorCode::SharedContentInvalid.value());
+
+    let mut url = shared_text("not a url", IngestTarget::NewDocument, belong_to_id.clone());
+    url.content_type = SharedContentType::Url;
+    let error = ingest(&test.sdk, url).await.error();
+    assert_eq!(error.code, ErrorCode::SharedContentInvalid.value());
+
+    let image = IngestSharedContentRequest {
+        content_type: SharedContentType::Image,
+        data: vec![1, 2, 3],
+        mime_type: "text/plain".to_owned(),
+        belong_to_id,
+        ..IngestSharedContentRequest::default()
+    };
+    let error = ingest(&test.sdk, image).await.error();
+    assert_eq!(error.code, ErrorCode::SharedContentInvalid.value());
+
+    // The inbox view isn't set.
+    let error = ingest(&test.sdk, shared_text("Milk", IngestTarget::Inbox, None))
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::InboxViewInvalid.value());
+}
@@ -0,0 +1,133 @@
+use flowy_database::{
+    prelude::*,
+    schema::{app_table, trash_table, view_table},
+    SqliteConnection,
+};
+use flowy_test::{builder::FlowyWorkspaceTest, workspace::*, FlowyTest, FlowyTestSDK};
+use flowy_workspace::{
+    entities::{
+        app::QueryAppRequest,
+        view::QueryViewRequest,
+        workspace::{CheckStorageIntegrityRequest, IntegrityIssueType, StorageIntegrityReport},
+    },
+    event::WorkspaceEvent::*,
+};
+
+async fn check_storage_integrity(sdk: &FlowyTestSDK, repair: bool) -> StorageIntegrityReport {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(CheckStorageIntegrity)
+        .request(CheckStorageIntegrityRequest { repair })
+        .async_send()
+        .await
+        .parse::<StorageIntegrityReport>()
+}
+
+fn issue_types(report: &StorageIntegrityReport) -> Vec<(IntegrityIssueType, String)> {
+    let mut types: Vec<(IntegrityIssueType, String)> =
+        report.issues.iter().map(|issue| (issue.ty, issue.id.clone())).collect();
+    types.sort_by_key(|(ty, id)| (*ty as i32, id.clone()));
+    types
+}
+
+fn set_view_parent(view_id: &str, belong_to_id: &str, conn: &SqliteConnection) {
+    let _ = diesel::update(view_table::dsl::view_table.filter(view_table::id.eq(view_id)))
+        .set(view_table::belong_to_id.eq(belong_to_id))
+        .execute(conn)
+        .unwrap();
+}
+
+#[tokio::test]
+async fn integrity_healthy_storage() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let _ = ViewTest::new(&test).await;
+
+    let report = check_storage_integrity(&test.sdk, false).await;
+    assert!(report.database_errors.is_empty());
+    assert!(report.issues.is_empty());
+    assert!(report.is_healthy());
+}
+
+#[tokio::test]
+async fn integrity_detached_items_repaired() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let detached_app = create_app(&test.sdk, "Detached", "", &view_test.workspace.id).await;
+    let detached_view = create_view(&test.sdk, &view_test.app.id).await;
+
+    let conn = test.sdk.user_session.db_connection().unwrap();
+    let _ = diesel::update(app_table::dsl::app_table.filter(app_table::id.eq(&detached_app.id)))
+        .set(app_table::workspace_id.eq("missing_workspace"))
+        .execute(&*conn)
+        .unwrap();
+    let _ = diesel::update(app_table::dsl::app_table.filter(app_table::id.eq(&view_test.app.id)))
+        .set(app_table::last_view_id.eq("missing_view"))
+        .execute(&*conn)
+        .unwrap();
+    set_view_parent(&detached_view.id, "missing_app", &*conn);
+    let _ = diesel::insert_into(trash_table::table)
+        .values((
+            trash_table::id.eq("missing_trash"),
+            trash_table::name.eq(""),
+            trash_table::desc.eq(""),
+            trash_table::modified_time.eq(0),
+            trash_table::create_time.eq(0),
+            trash_table::ty.eq(1),
+            trash_table::deleted_time.eq(0),
+        ))
+        .execute(&*conn)
+        .unwrap();
+
+    let expected = vec![
+        (IntegrityIssueType::AppWithoutWorkspace, detached_app.id.clone()),
+        (IntegrityIssueType::ViewWithoutParent, detached_view.id.clone()),
+        (IntegrityIssueType::TrashWithoutItem, "missing_trash".to_owned()),
+        (IntegrityIssueType::LastViewMissing, view_test.app.id.clone()),
+    ];
+    let report = check_storage_integrity(&test.sdk, false).await;
+    assert_eq!(issue_types(&report), expected);
+    assert!(report.issues.iter().all(|issue| !issue.repaired));
+    assert!(!report.is_healthy());
+
+    // Nothing was changed by the first check.
+    let report = check_storage_integrity(&test.sdk, true).await;
+    assert_eq!(issue_types(&report), expected);
+    assert!(report.is_healthy());
+    assert!(check_storage_integrity(&test.sdk, false).await.issues.is_empty());
+
+    let app = read_app(
+        &test.sdk,
+        QueryAppRequest {
+            app_ids: vec![detached_app.id.clone()],
+        },
+    )
+    .await;
+    assert_eq!(app.workspace_id, view_test.workspace.id);
+    let view = read_view(
+        &test.sdk,
+        QueryViewRequest {
+            view_ids: vec![detached_view.id.clone()],
+        },
+    )
+    .await;
+    assert_eq!(view.belong_to_id, view_test.app.id);
+}
+
+#[tokio::test]
+async fn integrity_view_cycle_repaired() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let view = create_view(&test.sdk, &view_test.app.id).await;
+
+    let conn = test.sdk.user_session.db_connection().unwrap();
+    set_view_parent(&view.id, &view_test.view.id, &*conn);
+    set_view_parent(&view_test.view.id, &view.id, &*conn);
+
+    let report = check_storage_integrity(&test.sdk, true).await;
+    assert_eq!(report.issues.len(), 1);
+    assert_eq!(report.issues[0].ty, IntegrityIssueType::ViewCycle);
+    assert!(report.issues[0].repaired);
+    assert!(check_storage_integrity(&test.sdk, false).await.issues.is_empty());
+}
@@ -1,4 +1,18 @@
 mod app_test;
+mod encryption_test;
+mod evernote_test;
+mod ingest_test;
+mod integrity_test;
+mod member_test;
+mod memory_test;
+mod metrics_test;
+mod notification_test;
+mod notion_test;
+mod operation_test;
+mod plugin_test;
+mod search_test;
+mod setting_test;
+mod sync_test;
 // mod helper;
 mod view_test;
 mod workspace_test;
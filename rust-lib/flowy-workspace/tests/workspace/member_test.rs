@@ -0,0 +1,306 @@
+use flowy_test::{builder::*, workspace::*, FlowyTest, FlowyTestSDK};
+use flowy_workspace::{
+    entities::{
+        app::CreateAppRequest,
+        sync::{ServerPush, ServerPushType},
+        workspace::*,
+    },
+    errors::ErrorCode,
+    event::WorkspaceEvent::*,
+};
+use std::convert::TryInto;
+
+async fn update_member(sdk: &FlowyTestSDK, request: UpdateWorkspaceMemberRequest) -> FlowyWorkspaceTest {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(UpdateWorkspaceMember)
+        .request(request)
+        .async_send()
+        .await
+}
+
+async fn remove_member(sdk: &FlowyTestSDK, workspace_id: &str, user_id: &str) -> FlowyWorkspaceTest {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(RemoveWorkspaceMember)
+        .request(RemoveWorkspaceMemberRequest::new(workspace_id, user_id))
+        .async_send()
+        .await
+}
+
+async fn invite_member(
+    sdk: &FlowyTestSDK,
+    workspace_id: &str,
+    email: &str,
+    role: Option<WorkspaceRole>,
+) -> FlowyWorkspaceTest {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(InviteWorkspaceMember)
+        .request(InviteWorkspaceMemberRequest::new(workspace_id, email, role))
+        .async_send()
+        .await
+}
+
+async fn cancel_invitation(sdk: &FlowyTestSDK, workspace_id: &str, email: &str) -> FlowyWorkspaceTest {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(CancelWorkspaceInvitation)
+        .request(CancelWorkspaceInvitationRequest::new(workspace_id, email))
+        .async_send()
+        .await
+}
+
+async fn read_invitations(sdk: &FlowyTestSDK, workspace_id: &str) -> RepeatedWorkspaceInvitation {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(ReadWorkspaceInvitations)
+        .request(QueryWorkspaceRequest::new(Some(workspace_id.to_owned())))
+        .async_send()
+        .await
+        .parse::<RepeatedWorkspaceInvitation>()
+}
+
+async fn read_members(sdk: &FlowyTestSDK, workspace_id: &str) -> RepeatedWorkspaceMember {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(ReadWorkspaceMembers)
+        .request(QueryWorkspaceRequest::new(Some(workspace_id.to_owned())))
+        .async_send()
+        .await
+        .parse::<RepeatedWorkspaceMember>()
+}
+
+async fn create_app_error(sdk: &FlowyTestSDK, workspace_id: &str) -> i32 {
+    let request = CreateAppRequest {
+        workspace_id: workspace_id.to_owned(),
+        name: "App".to_owned(),
+        desc: "".to_owned(),
+        color_style: Default::default(),
+    };
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(CreateApp)
+        .request(request)
+        .async_send()
+        .await
+        .error()
+        .code
+}
+
+fn server_push(ty: ServerPushType, member: WorkspaceMember) -> ServerPush {
+    let data: bytes::Bytes = member.try_into().unwrap();
+    ServerPush {
+        ty,
+        data: data.to_vec(),
+    }
+}
+
+#[tokio::test]
+async fn workspace_creator_is_owner() {
+    let test = FlowyTest::setup();
+    let user = test.init_user().await;
+    let workspace = create_workspace(&test.sdk, "Workspace", "").await;
+
+    let members = read_members(&test.sdk, &workspace.id).await;
+    assert_eq!(members.len(), 1);
+    assert_eq!(members[0].user_id, user.id);
+    assert_eq!(members[0].role, WorkspaceRole::Owner);
+}
+
+#[tokio::test]
+async fn workspace_keeps_one_owner() {
+    let test = FlowyTest::setup();
+    let user = test.init_user().await;
+    let workspace = create_workspace(&test.sdk, "Workspace", "").await;
+
+    let request = UpdateWorkspaceMemberRequest::new(&workspace.id, &user.id, WorkspaceRole::Editor);
+    let error = update_member(&test.sdk, request).await.error();
+    assert_eq!(error.code, ErrorCode::WorkspaceOwnerRequired.value());
+}
+
+#[tokio::test]
+async fn workspace_viewer_can_not_mutate() {
+    let test = FlowyTest::setup();
+    let user = test.init_user().await;
+    let workspace = create_workspace(&test.sdk, "Workspace", "").await;
+    let app = create_app(&test.sdk, "App", "", &workspace.id).await;
+
+    // hand the workspace to another owner, then the user can only read it
+    let request = UpdateWorkspaceMemberRequest::new(&workspace.id, "other_user", WorkspaceRole::Owner);
+    let _ = update_member(&test.sdk, request).await;
+    let request = UpdateWorkspaceMemberRequest::new(&workspace.id, &user.id, WorkspaceRole::Viewer);
+    let _ = update_member(&test.sdk, request).await;
+    let members = read_members(&test.sdk, &workspace.id).await;
+    assert!(members.contains(&WorkspaceMember {
+        workspace_id: workspace.id.clone(),
+        user_id: user.id.clone(),
+        role: WorkspaceRole::Viewer,
+    }));
+
+    let request = CreateAppRequest {
+        workspace_id: workspace.id.clone(),
+        name: "App B".to_owned(),
+        desc: "".to_owned(),
+        color_style: Default::default(),
+    };
+    let error = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(CreateApp)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::PermissionDenied.value());
+
+    let request = UpdateWorkspaceMemberRequest::new(&workspace.id, &user.id, WorkspaceRole::Owner);
+    let error = update_member(&test.sdk, request).await.error();
+    assert_eq!(error.code, ErrorCode::PermissionDenied.value());
+
+    let workspace = read_workspace(&test.sdk, QueryWorkspaceRequest::new(Some(workspace.id.clone())))
+        .await
+        .pop()
+        .unwrap();
+    assert_eq!(workspace.apps.first_or_crash(), &app);
+}
+
+#[tokio::test]
+async fn workspace_removed_member_can_not_mutate() {
+    let test = FlowyTest::setup();
+    let user = test.init_user().await;
+    let workspace = create_workspace(&test.sdk, "Workspace", "").await;
+    let request = UpdateWorkspaceMemberRequest::new(&workspace.id, "other_user", WorkspaceRole::Owner);
+    let _ = update_member(&test.sdk, request).await;
+    let member = WorkspaceMember {
+        workspace_id: workspace.id.clone(),
+        user_id: user.id.clone(),
+        role: WorkspaceRole::Owner,
+    };
+    let push = server_push(ServerPushType::MemberLeft, member);
+    test.sdk.workspace.receive_server_push(push).await.unwrap();
+    assert_eq!(
+        create_app_error(&test.sdk, &workspace.id).await,
+        ErrorCode::PermissionDenied.value()
+    );
+
+    // The role is checked in the workspace the app is created in, not in the
+    // current one.
+    let current_workspace = create_workspace(&test.sdk, "Current Workspace", "").await;
+    let _ = create_app(&test.sdk, "App", "", &current_workspace.id).await;
+    assert_eq!(
+        create_app_error(&test.sdk, &workspace.id).await,
+        ErrorCode::PermissionDenied.value()
+    );
+}
+
+#[tokio::test]
+async fn workspace_invite_member() {
+    let test = WorkspaceTest::new().await;
+    let workspace_id = test.workspace.id.clone();
+    let default_role = SetWorkspaceSettingRequest::new(
+        &workspace_id,
+        MEMBER_DEFAULT_ROLE_SETTING,
+        WorkspaceSettingValue::int(WorkspaceRole::Editor as i64),
+    );
+    let _ = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(SetWorkspaceSetting)
+        .request(default_role)
+        .async_send()
+        .await;
+
+    let invitation = invite_member(&test.sdk, &workspace_id, "Bob@Example.com", None)
+        .await
+        .parse::<WorkspaceInvitation>();
+    assert_eq!(invitation.email, "bob@example.com");
+    assert_eq!(invitation.role, WorkspaceRole::Editor);
+    let _ = invite_member(
+        &test.sdk,
+        &workspace_id,
+        "alice@example.com",
+        Some(WorkspaceRole::Owner),
+    )
+    .await;
+
+    // The email is invited once, its role is changed.
+    let _ = invite_member(&test.sdk, &workspace_id, "bob@example.com", Some(WorkspaceRole::Viewer)).await;
+    let invitations = read_invitations(&test.sdk, &workspace_id).await;
+    let items = invitations
+        .iter()
+        .map(|invitation| (invitation.email.as_str(), invitation.role))
+        .collect::<Vec<_>>();
+    assert!(items.contains(&("bob@example.com", WorkspaceRole::Viewer)));
+    assert!(items.contains(&("alice@example.com", WorkspaceRole::Owner)));
+    assert_eq!(items.len(), 2);
+
+    let _ = cancel_invitation(&test.sdk, &workspace_id, "alice@example.com").await;
+    assert_eq!(read_invitations(&test.sdk, &workspace_id).await.len(), 1);
+    let error = cancel_invitation(&test.sdk, &workspace_id, "alice@example.com")
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::RecordNotFound.value());
+}
+
+#[tokio::test]
+async fn workspace_invite_with_invalid_email() {
+    let test = WorkspaceTest::new().await;
+    for email in ["", "bob", "bob@"] {
+        let error = invite_member(&test.sdk, &test.workspace.id, email, None).await.error();
+        assert_eq!(error.code, ErrorCode::MemberEmailInvalid.value());
+    }
+}
+
+#[tokio::test]
+async fn workspace_remove_member() {
+    let test = FlowyTest::setup();
+    let user = test.init_user().await;
+    let workspace = create_workspace(&test.sdk, "Workspace", "").await;
+    let request = UpdateWorkspaceMemberRequest::new(&workspace.id, "other_user", WorkspaceRole::Editor);
+    let _ = update_member(&test.sdk, request).await;
+    assert_eq!(read_members(&test.sdk, &workspace.id).await.len(), 2);
+
+    let _ = remove_member(&test.sdk, &workspace.id, "other_user").await;
+    let members = read_members(&test.sdk, &workspace.id).await;
+    assert_eq!(members.len(), 1);
+    assert_eq!(members[0].user_id, user.id);
+
+    let error = remove_member(&test.sdk, &workspace.id, "other_user").await.error();
+    assert_eq!(error.code, ErrorCode::RecordNotFound.value());
+    let error = remove_member(&test.sdk, &workspace.id, &user.id).await.error();
+    assert_eq!(error.code, ErrorCode::PermissionDenied.value());
+}
+
+#[tokio::test]
+async fn workspace_role_change_applies_to_next_event() {
+    let test = FlowyTest::setup();
+    let user = test.init_user().await;
+    let workspace = create_workspace(&test.sdk, "Workspace", "").await;
+    let request = UpdateWorkspaceMemberRequest::new(&workspace.id, "other_user", WorkspaceRole::Owner);
+    let _ = update_member(&test.sdk, request).await;
+    let request = UpdateWorkspaceMemberRequest::new(&workspace.id, &user.id, WorkspaceRole::Editor);
+    let _ = update_member(&test.sdk, request).await;
+
+    // The editor still writes to the workspace, but can't manage it anymore.
+    let _ = create_app(&test.sdk, "App", "", &workspace.id).await;
+    let error = invite_member(&test.sdk, &workspace.id, "bob@example.com", None)
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::PermissionDenied.value());
+    let error = remove_member(&test.sdk, &workspace.id, "other_user").await.error();
+    assert_eq!(error.code, ErrorCode::PermissionDenied.value());
+}
+
+#[tokio::test]
+async fn workspace_member_pushed_by_server() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let workspace = create_workspace(&test.sdk, "Workspace", "").await;
+    let member = WorkspaceMember {
+        workspace_id: workspace.id.clone(),
+        user_id: "other_user".to_owned(),
+        role: WorkspaceRole::Editor,
+    };
+
+    let push = server_push(ServerPushType::MemberJoined, member.clone());
+    test.sdk.workspace.receive_server_push(push).await.unwrap();
+    let members = read_members(&test.sdk, &workspace.id).await;
+    assert_eq!(members.len(), 2);
+    assert!(members.contains(&member));
+
+    let push = server_push(ServerPushType::MemberLeft, member.clone());
+    test.sdk.workspace.receive_server_push(push).await.unwrap();
+    let members = read_members(&test.sdk, &workspace.id).await;
+    assert_eq!(members.len(), 1);
+    assert!(!members.contains(&member));
+}
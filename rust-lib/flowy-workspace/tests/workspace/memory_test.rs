@@ -0,0 +1,73 @@
+use flowy_test::{
+    builder::*,
+    prelude::{MemoryUsage, SystemEvent},
+    workspace::*,
+    FlowyTest,
+    FlowyTestSDK,
+};
+use flowy_workspace::entities::view::QueryViewRequest;
+
+async fn read_memory(sdk: &FlowyTestSDK) -> MemoryUsage {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(SystemEvent::Memory)
+        .async_send()
+        .await
+        .parse::<MemoryUsage>()
+}
+
+fn item(memory: &MemoryUsage, subsystem: &str, name: &str) -> (i64, i64) {
+    memory
+        .items
+        .iter()
+        .find(|item| item.subsystem == subsystem && item.name == name)
+        .map(|item| (item.count, item.bytes))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn memory_counts_the_open_documents() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = open_view(
+        &test.sdk,
+        QueryViewRequest {
+            view_ids: vec![view_test.view.id.clone()],
+        },
+    )
+    .await;
+    let memory = read_memory(&test.sdk).await;
+    let (open_documents, document_bytes) = item(&memory, "document", "open_documents");
+    assert!(open_documents >= 1);
+    assert!(document_bytes > 0);
+
+    let _ = item(&memory, "log", "records");
+    if cfg!(target_os = "linux") {
+        assert!(memory.rss.unwrap() > 0);
+    }
+}
+
+#[tokio::test]
+async fn memory_budget_is_emptied_on_low_memory() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = open_view(
+        &test.sdk,
+        QueryViewRequest {
+            view_ids: vec![view_test.view.id.clone()],
+        },
+    )
+    .await;
+    let (documents, bytes) = item(&read_memory(&test.sdk).await, "memory_budget", "documents");
+    assert!(documents >= 1);
+    assert!(bytes > 0);
+
+    let _ = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(SystemEvent::LowMemory)
+        .async_send()
+        .await;
+    let memory = read_memory(&test.sdk).await;
+    assert_eq!(item(&memory, "memory_budget", "documents"), (0, 0));
+    assert_eq!(item(&memory, "memory_budget", "blobs"), (0, 0));
+}
@@ -0,0 +1,46 @@
+use flowy_test::{
+    builder::*,
+    prelude::{MetricsSnapshot, SystemEvent},
+    workspace::*,
+    FlowyTest,
+    FlowyTestSDK,
+};
+
+async fn read_metrics(sdk: &FlowyTestSDK) -> MetricsSnapshot {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(SystemEvent::Metrics)
+        .async_send()
+        .await
+        .parse::<MetricsSnapshot>()
+}
+
+fn counter(metrics: &MetricsSnapshot, name: &str, event: &str) -> i64 {
+    metrics
+        .counters
+        .iter()
+        .filter(|counter| counter.name == name)
+        .filter(|counter| counter.labels.iter().any(|label| label.value == event))
+        .map(|counter| counter.value)
+        .sum()
+}
+
+#[tokio::test]
+async fn metrics_count_the_dispatched_events() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let before = read_metrics(&test.sdk).await;
+    let _ = create_workspace(&test.sdk, "Metrics", "").await;
+    let _ = create_workspace(&test.sdk, "Metrics", "").await;
+
+    // The metrics are the ones of the process, the other tests dispatch events
+    // too.
+    let after = read_metrics(&test.sdk).await;
+    let count = |metrics: &MetricsSnapshot| counter(metrics, "dispatch_events_total", "CreateWorkspace");
+    assert!(count(&after) >= count(&before) + 2);
+    assert!(after
+        .histograms
+        .iter()
+        .any(|histogram| histogram.name == "dispatch_handler_duration_ms" && histogram.count > 0));
+    assert!(after.text.contains("# TYPE dispatch_events_total counter"));
+    assert!(after.text.contains("db_writes_total"));
+}
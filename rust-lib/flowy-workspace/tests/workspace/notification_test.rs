@@ -0,0 +1,60 @@
+use flowy_test::EventTester;
+use flowy_workspace::{
+    entities::{
+        app::{App, CreateAppRequest, RepeatedApp},
+        workspace::{CreateWorkspaceRequest, QueryWorkspaceRequest, Workspace},
+    },
+    errors::{ErrorCode, WorkspaceError},
+    event::WorkspaceEvent::*,
+    protobuf::WorkspaceNotification,
+};
+
+async fn open_new_workspace(tester: &EventTester) -> Workspace {
+    let request = CreateWorkspaceRequest {
+        name: "Workspace".to_owned(),
+        desc: "".to_owned(),
+    };
+    let workspace = tester.send(CreateWorkspace, request).await.parse::<Workspace>();
+    let request = QueryWorkspaceRequest {
+        workspace_id: Some(workspace.id.clone()),
+    };
+    let _ = tester.send(OpenWorkspace, request).await;
+    workspace
+}
+
+#[tokio::test]
+async fn create_app_notifies_the_apps_of_the_workspace() {
+    let tester = EventTester::new();
+    let _ = tester.sign_up().await;
+    let workspace = open_new_workspace(&tester).await;
+
+    let request = CreateAppRequest {
+        workspace_id: workspace.id.clone(),
+        name: "App".to_owned(),
+        desc: "".to_owned(),
+        color_style: Default::default(),
+    };
+    let app = tester.send(CreateApp, request).await.parse::<App>();
+    let ty = WorkspaceNotification::WorkspaceAppsChanged as i32;
+    let apps = tester
+        .expect_notification_payload::<_, RepeatedApp>(&workspace.id, ty)
+        .await;
+    assert!(apps.items.iter().any(|other| other.id == app.id));
+}
+
+#[tokio::test]
+async fn create_app_with_empty_name_fails() {
+    let tester = EventTester::new();
+    let _ = tester.sign_up().await;
+    let workspace = open_new_workspace(&tester).await;
+
+    let request = CreateAppRequest {
+        workspace_id: workspace.id.clone(),
+        name: "".to_owned(),
+        desc: "".to_owned(),
+        color_style: Default::default(),
+    };
+    let error = tester.send(CreateApp, request).await.error::<WorkspaceError>();
+    assert_eq!(error.code, ErrorCode::AppNameInvalid.value());
+    assert!(tester.notifications(&workspace.id).is_empty());
+}
@@ -0,0 +1,177 @@
+use flowy_infra::uuid;
+use flowy_ot::core::{AttributeKey, Delta};
+use flowy_test::{builder::*, workspace::*, FlowyTestSDK};
+use flowy_workspace::{
+    entities::{
+        app::QueryAppRequest,
+        share::*,
+        view::{QueryViewRequest, View, ViewType},
+    },
+    errors::ErrorCode,
+    event::WorkspaceEvent::*,
+};
+use std::io::Write;
+use zip::{write::FileOptions, ZipWriter};
+
+const HOME_PATH: &str = "Export/Home 0123456789abcdef0123456789abcdef.md";
+const NOTES_PATH: &str = "Export/Home 0123456789abcdef0123456789abcdef/Notes 11111111111111111111111111111111.md";
+const PHOTO_PATH: &str =
+    "Export/Home 0123456789abcdef0123456789abcdef/Notes 11111111111111111111111111111111/photo.png";
+const TASKS_PATH: &str = "Export/Tasks 22222222222222222222222222222222.csv";
+const ALL_TASKS_PATH: &str = "Export/Tasks 22222222222222222222222222222222_all.csv";
+const WRITE_PATH: &str = "Export/Tasks 22222222222222222222222222222222/Write 33333333333333333333333333333333.md";
+const REVIEW_PATH: &str = "Export/Tasks 22222222222222222222222222222222/Review 44444444444444444444444444444444.md";
+const UNUSED_PATH: &str = "Export/unused.pdf";
+
+async fn import_notion(sdk: &FlowyTestSDK, path: &str, name: Option<&str>) -> FlowyWorkspaceTest {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(ImportNotion)
+        .request(ImportNotionRequest {
+            path: path.to_owned(),
+            name: name.map(|name| name.to_owned()),
+        })
+        .async_send()
+        .await
+}
+
+fn write_export(files: Vec<(&str, Vec<u8>)>) -> String {
+    let path = std::env::temp_dir().join(format!("{}.zip", uuid()));
+    let mut zip = ZipWriter::new(std::fs::File::create(&path).unwrap());
+    for (name, data) in files {
+        zip.start_file(name, FileOptions::default()).unwrap();
+        zip.write_all(&data).unwrap();
+    }
+    zip.finish().unwrap();
+    path.to_string_lossy().to_string()
+}
+
+fn notion_export() -> String {
+    let home = "# Home\n\nWelcome, see [Notes](Home%200123456789abcdef0123456789abcdef/Notes%2011111111111111111111111111111111.md)\n";
+    let notes = "# Notes\n\n![photo](Notes%2011111111111111111111111111111111/photo.png)\n\n| Name | Value |\n| --- | --- |\n| a | 1 |\n";
+    let mut tasks = "Name,Estimate\n".to_owned();
+    for index in 1..10 {
+        tasks.push_str(&format!("Task {},{}\n", index, index));
+    }
+    tasks.push_str("Write,n/a\n");
+    write_export(vec![
+        (HOME_PATH, home.as_bytes().to_vec()),
+        (NOTES_PATH, notes.as_bytes().to_vec()),
+        (PHOTO_PATH, vec![1, 2, 3]),
+        (TASKS_PATH, "Name,Estimate\nTask 1,1\n".as_bytes().to_vec()),
+        (ALL_TASKS_PATH, tasks.into_bytes()),
+        (
+            WRITE_PATH,
+            "# Write\n\nEstimate: n/a\n\nDraft the post\n".as_bytes().to_vec(),
+        ),
+        (REVIEW_PATH, "# Review\n\nEstimate: 2\n".as_bytes().to_vec()),
+        (UNUSED_PATH, vec![4, 5, 6]),
+    ])
+}
+
+async fn read_belongings(sdk: &FlowyTestSDK, view_id: &str) -> Vec<View> {
+    let request = QueryViewRequest {
+        view_ids: vec![view_id.to_owned()],
+    };
+    read_view(sdk, request).await.belongings.items
+}
+
+async fn read_view_delta(sdk: &FlowyTestSDK, view_id: &str) -> Delta {
+    let request = QueryViewRequest {
+        view_ids: vec![view_id.to_owned()],
+    };
+    let doc = open_view(sdk, request).await;
+    Delta::from_json(&doc.data).unwrap()
+}
+
+fn links(delta: &Delta) -> Vec<String> {
+    delta
+        .ops
+        .iter()
+        .flat_map(|op| {
+            op.get_attributes()
+                .get(&AttributeKey::Link)
+                .and_then(|value| value.0.clone())
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn notion_import_rebuilds_the_pages() {
+    let test = WorkspaceTest::new().await;
+    let result = import_notion(&test.sdk, &notion_export(), None)
+        .await
+        .parse::<ImportNotionResult>();
+    assert!(result.failures.is_empty());
+    assert_eq!(result.workspace.name, DEFAULT_NOTION_WORKSPACE_NAME);
+
+    let apps = &result.workspace.apps.items;
+    assert_eq!(apps.len(), 2);
+    let home = apps.iter().find(|app| app.name == "Home").unwrap();
+    let home = read_app(
+        &test.sdk,
+        QueryAppRequest {
+            app_ids: vec![home.id.clone()],
+        },
+    )
+    .await;
+    let home_view = home.belongings.items.iter().find(|view| view.name == "Home").unwrap();
+    let notes_view = home.belongings.items.iter().find(|view| view.name == "Notes").unwrap();
+
+    // The link to the page points to its view, the one to the image to the asset.
+    let home_links = links(&read_view_delta(&test.sdk, &home_view.id).await);
+    assert_eq!(home_links.len(), 1);
+    assert!(home_links[0].ends_with(&format!("/view/{}", notes_view.id)));
+    let notes_delta = read_view_delta(&test.sdk, &notes_view.id).await;
+    assert!(links(&notes_delta)[0].starts_with("asset://"));
+    assert!(notes_delta.apply("").unwrap().contains("a | 1"));
+
+    // The rows of the database belong to its grid view, the ones without content
+    // aren't imported.
+    let tasks = apps.iter().find(|app| app.name == "Tasks").unwrap();
+    let tasks = read_app(
+        &test.sdk,
+        QueryAppRequest {
+            app_ids: vec![tasks.id.clone()],
+        },
+    )
+    .await;
+    let grid_view = tasks.belongings.first_or_crash();
+    assert_eq!(grid_view.view_type, ViewType::Grid);
+    let rows = read_belongings(&test.sdk, &grid_view.id).await;
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].name, "Write");
+    let row_text = read_view_delta(&test.sdk, &rows[0].id).await.apply("").unwrap();
+    assert!(row_text.contains("Draft the post"));
+    assert!(!row_text.contains("Estimate"));
+
+    let unconverted = result
+        .unconverted
+        .iter()
+        .map(|element| (element.kind.clone(), element.detail.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(unconverted.len(), 3);
+    assert!(unconverted.contains(&(UnconvertedKind::Table, "Name, Value")));
+    assert!(unconverted.contains(&(UnconvertedKind::Cell, "Row 10, Estimate: n/a")));
+    let file = result
+        .unconverted
+        .iter()
+        .find(|element| element.kind == UnconvertedKind::File)
+        .unwrap();
+    assert_eq!(file.page, UNUSED_PATH);
+}
+
+#[tokio::test]
+async fn notion_import_invalid_export() {
+    let test = WorkspaceTest::new().await;
+    let path = std::env::temp_dir().join(format!("{}.zip", uuid()));
+    std::fs::write(&path, "not a zip").unwrap();
+    let error = import_notion(&test.sdk, &path.to_string_lossy(), None).await.error();
+    assert_eq!(error.code, ErrorCode::NotionExportInvalid.value());
+
+    let path = write_export(vec![(UNUSED_PATH, vec![1])]);
+    let error = import_notion(&test.sdk, &path, Some("Notion")).await.error();
+    assert_eq!(error.code, ErrorCode::NotionExportInvalid.value());
+
+    let error = import_notion(&test.sdk, "", None).await.error();
+    assert_eq!(error.code, ErrorCode::NotionExportInvalid.value());
+}
@@ -0,0 +1,73 @@
+use bytes::Bytes;
+use flowy_database::{prelude::*, schema::operation_table, SqliteConnection};
+use flowy_test::{workspace::*, FlowyTest};
+use flowy_workspace::entities::view::{UpdateViewParams, UpdateViewRequest};
+use std::convert::TryInto;
+
+// The values of the ty and state columns.
+const UPDATE_VIEW: i32 = 4;
+const PENDING: i32 = 0;
+const COMMITTED: i32 = 1;
+
+fn read_operations(conn: &SqliteConnection) -> Vec<(i32, i32)> {
+    operation_table::table
+        .select((operation_table::ty, operation_table::state))
+        .order(operation_table::id.asc())
+        .load::<(i32, i32)>(conn)
+        .unwrap()
+}
+
+fn insert_pending_operation(params: UpdateViewParams, conn: &SqliteConnection) {
+    let data: Bytes = params.try_into().unwrap();
+    let _ = diesel::insert_into(operation_table::table)
+        .values((
+            operation_table::ty.eq(UPDATE_VIEW),
+            operation_table::data.eq(data.to_vec()),
+            operation_table::state.eq(PENDING),
+        ))
+        .execute(conn)
+        .unwrap();
+}
+
+#[tokio::test]
+async fn operation_saved_with_view_update() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+
+    let request = UpdateViewRequest {
+        view_id: view_test.view.id.clone(),
+        name: Some("123".to_owned()),
+        ..Default::default()
+    };
+    update_view(&test.sdk, request).await;
+    let conn = test.sdk.user_session.db_connection().unwrap();
+    assert!(read_operations(&*conn).iter().any(|(ty, _)| *ty == UPDATE_VIEW));
+
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    assert!(read_operations(&*conn).iter().all(|(_, state)| *state == COMMITTED));
+}
+
+#[tokio::test]
+async fn operation_pending_sent_again() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+
+    let conn = test.sdk.user_session.db_connection().unwrap();
+    let count = read_operations(&*conn).len();
+    for name in ["1", "2"] {
+        let mut params = UpdateViewParams::new(&view_test.view.id);
+        params.name = Some(name.to_owned());
+        insert_pending_operation(params, &*conn);
+    }
+
+    // The operations that were saved before the app was closed, the ones sent in
+    // the background when the workspace was initialized may send them first.
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    assert_eq!(test.sdk.workspace.send_pending_operations().await.unwrap(), 0);
+    let operations = read_operations(&*conn);
+    assert_eq!(operations.len(), count + 2);
+    assert!(operations.iter().all(|(_, state)| *state == COMMITTED));
+}
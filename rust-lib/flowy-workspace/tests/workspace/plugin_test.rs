@@ -0,0 +1,89 @@
+use flowy_infra::uuid;
+use flowy_net::config::ServerConfig;
+use flowy_test::{prelude::*, FlowyTest};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+#[derive(Default)]
+struct CounterPlugin {
+    events: Vec<String>,
+    started: AtomicUsize,
+    signed_in: AtomicUsize,
+}
+
+impl CounterPlugin {
+    fn new(events: &[&str]) -> Arc<Self> {
+        Arc::new(Self {
+            events: events.iter().map(|event| event.to_string()).collect(),
+            ..Default::default()
+        })
+    }
+}
+
+async fn count(counter: Unit<AtomicUsize>) -> String { (counter.fetch_add(1, Ordering::SeqCst) + 1).to_string() }
+
+impl FlowyPlugin for CounterPlugin {
+    fn name(&self) -> &str { "counter" }
+
+    fn events(&self) -> Vec<String> { self.events.clone() }
+
+    fn build_module(&self, _context: &PluginContext) -> Module {
+        self.events
+            .iter()
+            .fold(Module::new().data(AtomicUsize::new(0)), |module, event| {
+                module.event(event.clone(), count)
+            })
+    }
+
+    fn on_start(&self, _dispatch: Arc<EventDispatch>) { self.started.fetch_add(1, Ordering::SeqCst); }
+
+    fn on_sign_in(&self, _user_id: &str) { self.signed_in.fetch_add(1, Ordering::SeqCst); }
+}
+
+fn setup_with_plugin(plugin: Arc<CounterPlugin>) -> FlowyTest {
+    let config = FlowySDKConfig::new(&root_dir(), ServerConfig::default(), &uuid())
+        .in_memory_storage(true)
+        .plugin(plugin);
+    FlowyTest::setup_with_config(config)
+}
+
+async fn send(test: &FlowyTest, event: &str) -> EventResponse {
+    EventDispatch::async_send(test.sdk.dispatch(), ModuleRequest::new(event)).await
+}
+
+#[tokio::test]
+async fn plugin_events_are_dispatched_like_the_ones_of_the_sdk() {
+    let plugin = CounterPlugin::new(&["counter/increment"]);
+    let test = setup_with_plugin(plugin.clone());
+    assert_eq!(plugin.started.load(Ordering::SeqCst), 1);
+
+    for expected in &["1", "2"] {
+        let response = send(&test, "counter/increment").await;
+        assert_eq!(response.payload.as_bytes(), Some(expected.as_bytes()));
+    }
+
+    // The plugins are told after the workspace, once the sign up returned.
+    let _ = test.init_user().await;
+    for _ in 0..100 {
+        if plugin.signed_in.load(Ordering::SeqCst) > 0 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(plugin.signed_in.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn plugin_with_an_event_of_the_sdk_is_left_out() {
+    let plugin = CounterPlugin::new(&["counter/increment", "__system/memory"]);
+    let test = setup_with_plugin(plugin.clone());
+    assert_eq!(plugin.started.load(Ordering::SeqCst), 0);
+
+    let response = send(&test, "counter/increment").await;
+    assert_ne!(response.status_code, StatusCode::Ok);
+}
@@ -0,0 +1,111 @@
+use flowy_test::{builder::FlowyWorkspaceTest, workspace::*, FlowyTest, FlowyTestSDK};
+use flowy_workspace::{
+    entities::{
+        search::{RepeatedSearchResult, SearchRequest, SearchResultType},
+        view::{QueryViewRequest, UpdateViewRequest},
+    },
+    event::WorkspaceEvent::*,
+};
+
+async fn search(sdk: &FlowyTestSDK, query: &str) -> RepeatedSearchResult {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(Search)
+        .request(SearchRequest {
+            query: query.to_owned(),
+            limit: 0,
+        })
+        .async_send()
+        .await
+        .parse::<RepeatedSearchResult>()
+}
+
+async fn rename_view(sdk: &FlowyTestSDK, view_id: &str, name: &str) {
+    let request = UpdateViewRequest {
+        view_id: view_id.to_owned(),
+        name: Some(name.to_owned()),
+        ..Default::default()
+    };
+    update_view(sdk, request).await;
+}
+
+fn view_ids(results: &RepeatedSearchResult) -> Vec<String> {
+    results.items.iter().map(|result| result.view_id.clone()).collect()
+}
+
+#[tokio::test]
+async fn search_view_title_with_prefix() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    rename_view(&test.sdk, &view_test.view.id, "Quarterly budget").await;
+
+    let results = search(&test.sdk, "quarterly bud").await;
+    assert_eq!(view_ids(&results), vec![view_test.view.id.clone()]);
+    let result = &results.items[0];
+    assert_eq!(result.ty, SearchResultType::View);
+    assert_eq!(result.workspace_id, view_test.workspace.id);
+    assert_eq!(result.title, "Quarterly budget");
+    assert_eq!(result.snippet, "Quarterly budget");
+    assert_eq!(result.highlights.len(), 2);
+    assert_eq!((result.highlights[0].start, result.highlights[0].end), (0, 9));
+}
+
+#[tokio::test]
+async fn search_follows_renamed_view() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    rename_view(&test.sdk, &view_test.view.id, "Roadmap").await;
+    assert_eq!(view_ids(&search(&test.sdk, "roadmap").await).len(), 1);
+
+    rename_view(&test.sdk, &view_test.view.id, "Retrospective").await;
+    assert!(search(&test.sdk, "roadmap").await.items.is_empty());
+    assert_eq!(
+        view_ids(&search(&test.sdk, "retrospective").await),
+        vec![view_test.view.id.clone()]
+    );
+}
+
+#[tokio::test]
+async fn search_phrase_and_filters() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    rename_view(&test.sdk, &view_test.view.id, "Meeting notes").await;
+
+    assert_eq!(search(&test.sdk, "\"meeting notes\"").await.items.len(), 1);
+    assert!(search(&test.sdk, "\"notes meeting\"").await.items.is_empty());
+    assert!(search(&test.sdk, "meeting type:document").await.items.is_empty());
+    assert_eq!(search(&test.sdk, "meeting type:view").await.items.len(), 1);
+
+    let query = format!("meeting workspace:{}", view_test.workspace.id);
+    assert_eq!(search(&test.sdk, &query).await.items.len(), 1);
+    assert!(search(&test.sdk, "meeting workspace:other").await.items.is_empty());
+}
+
+#[tokio::test]
+async fn search_skips_trashed_view() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    rename_view(&test.sdk, &view_test.view.id, "Draft").await;
+    assert_eq!(search(&test.sdk, "draft").await.items.len(), 1);
+
+    delete_view(
+        &test.sdk,
+        QueryViewRequest {
+            view_ids: vec![view_test.view.id.clone()],
+        },
+    )
+    .await;
+    assert!(search(&test.sdk, "draft").await.items.is_empty());
+}
+
+#[tokio::test]
+async fn search_empty_query() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let _ = ViewTest::new(&test).await;
+
+    assert!(search(&test.sdk, "  type:view ").await.items.is_empty());
+}
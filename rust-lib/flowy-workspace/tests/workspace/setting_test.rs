@@ -0,0 +1,177 @@
+use flowy_test::{builder::*, workspace::*, FlowyTest, FlowyTestSDK};
+use flowy_workspace::{
+    entities::{view::QueryViewRequest, workspace::*},
+    errors::ErrorCode,
+    event::WorkspaceEvent::*,
+};
+
+async fn set_setting(
+    sdk: &FlowyTestSDK,
+    workspace_id: &str,
+    key: &str,
+    value: WorkspaceSettingValue,
+) -> FlowyWorkspaceTest {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(SetWorkspaceSetting)
+        .request(SetWorkspaceSettingRequest::new(workspace_id, key, value))
+        .async_send()
+        .await
+}
+
+async fn get_setting(sdk: &FlowyTestSDK, workspace_id: &str, key: &str) -> FlowyWorkspaceTest {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(GetWorkspaceSetting)
+        .request(GetWorkspaceSettingRequest::new(workspace_id, key))
+        .async_send()
+        .await
+}
+
+async fn read_settings(sdk: &FlowyTestSDK, workspace_id: Option<String>) -> RepeatedWorkspaceSettingItem {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(ReadWorkspaceSettings)
+        .request(QueryWorkspaceRequest::new(workspace_id))
+        .async_send()
+        .await
+        .parse::<RepeatedWorkspaceSettingItem>()
+}
+
+#[tokio::test]
+async fn setting_set_then_get() {
+    let test = WorkspaceTest::new().await;
+    let value = WorkspaceSettingValue::str("compact");
+    let _ = set_setting(&test.sdk, &test.workspace.id, SIDEBAR_LAYOUT_SETTING, value.clone()).await;
+    let item = get_setting(&test.sdk, &test.workspace.id, SIDEBAR_LAYOUT_SETTING)
+        .await
+        .parse::<WorkspaceSettingItem>();
+    assert_eq!(item.value, value);
+
+    let _ = set_setting(
+        &test.sdk,
+        &test.workspace.id,
+        "show_icons",
+        WorkspaceSettingValue::bool(true),
+    )
+    .await;
+    let items = read_settings(&test.sdk, None).await;
+    let keys = items.iter().map(|item| item.key.as_str()).collect::<Vec<_>>();
+    assert_eq!(keys, vec!["show_icons", SIDEBAR_LAYOUT_SETTING]);
+}
+
+#[tokio::test]
+async fn setting_is_distinct_per_workspace() {
+    let test = WorkspaceTest::new().await;
+    let workspace = create_workspace(&test.sdk, "Other", "").await;
+    let _ = set_setting(
+        &test.sdk,
+        &test.workspace.id,
+        SIDEBAR_LAYOUT_SETTING,
+        WorkspaceSettingValue::str("compact"),
+    )
+    .await;
+
+    let error = get_setting(&test.sdk, &workspace.id, SIDEBAR_LAYOUT_SETTING)
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::RecordNotFound.value());
+    assert!(read_settings(&test.sdk, Some(workspace.id.clone())).await.is_empty());
+}
+
+#[tokio::test]
+async fn setting_with_invalid_key_or_type() {
+    let test = WorkspaceTest::new().await;
+    let error = set_setting(&test.sdk, &test.workspace.id, " ", WorkspaceSettingValue::int(1))
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::WorkspaceSettingKeyInvalid.value());
+
+    let error = set_setting(
+        &test.sdk,
+        &test.workspace.id,
+        SIDEBAR_LAYOUT_SETTING,
+        WorkspaceSettingValue::int(1),
+    )
+    .await
+    .error();
+    assert_eq!(error.code, ErrorCode::WorkspaceSettingInvalid.value());
+
+    // The type of the other keys is the one they're set with first.
+    let _ = set_setting(&test.sdk, &test.workspace.id, "columns", WorkspaceSettingValue::int(2)).await;
+    let error = set_setting(
+        &test.sdk,
+        &test.workspace.id,
+        "columns",
+        WorkspaceSettingValue::str("2"),
+    )
+    .await
+    .error();
+    assert_eq!(error.code, ErrorCode::WorkspaceSettingInvalid.value());
+}
+
+#[tokio::test]
+async fn setting_member_default_role() {
+    let test = WorkspaceTest::new().await;
+    let owner = WorkspaceSettingValue::int(WorkspaceRole::Owner as i64);
+    let error = set_setting(&test.sdk, &test.workspace.id, MEMBER_DEFAULT_ROLE_SETTING, owner)
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::WorkspaceSettingInvalid.value());
+
+    let editor = WorkspaceSettingValue::int(WorkspaceRole::Editor as i64);
+    let _ = set_setting(
+        &test.sdk,
+        &test.workspace.id,
+        MEMBER_DEFAULT_ROLE_SETTING,
+        editor.clone(),
+    )
+    .await;
+    let item = get_setting(&test.sdk, &test.workspace.id, MEMBER_DEFAULT_ROLE_SETTING)
+        .await
+        .parse::<WorkspaceSettingItem>();
+    assert_eq!(item.value, editor);
+}
+
+#[tokio::test]
+async fn setting_default_view() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+    let value = WorkspaceSettingValue::str(&test.view.id);
+    let _ = set_setting(&test.sdk, &test.workspace.id, DEFAULT_VIEW_SETTING, value.clone()).await;
+    let item = get_setting(&test.sdk, &test.workspace.id, DEFAULT_VIEW_SETTING)
+        .await
+        .parse::<WorkspaceSettingItem>();
+    assert_eq!(item.value, value);
+
+    // The view must belong to the workspace.
+    let workspace = create_workspace(&test.sdk, "Other", "").await;
+    let app = create_app(&test.sdk, "App", "", &workspace.id).await;
+    let other_view = create_view(&test.sdk, &app.id).await;
+    let error = set_setting(
+        &test.sdk,
+        &test.workspace.id,
+        DEFAULT_VIEW_SETTING,
+        WorkspaceSettingValue::str(&other_view.id),
+    )
+    .await
+    .error();
+    assert_eq!(error.code, ErrorCode::WorkspaceSettingInvalid.value());
+
+    // The view in the trash can't be opened with the workspace.
+    let view = create_view(&test.sdk, &test.app.id).await;
+    delete_view(
+        &test.sdk,
+        QueryViewRequest {
+            view_ids: vec![view.id.clone()],
+        },
+    )
+    .await;
+    let error = set_setting(
+        &test.sdk,
+        &test.workspace.id,
+        DEFAULT_VIEW_SETTING,
+        WorkspaceSettingValue::str(&view.id),
+    )
+    .await
+    .error();
+    assert_eq!(error.code, ErrorCode::WorkspaceSettingInvalid.value());
+}
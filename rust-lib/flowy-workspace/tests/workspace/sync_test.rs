@@ -0,0 +1,915 @@
+use bytes::Bytes;
+use flowy_database::{prelude::*, schema::operation_table};
+use flowy_document_infra::entities::doc::DocDelta;
+use flowy_ot::core::{Attributes, Delta, DeltaBuilder};
+use flowy_test::{builder::FlowyWorkspaceTest, workspace::*, FlowyTest};
+use flowy_workspace::{
+    entities::{
+        app::{App, QueryAppRequest, UpdateAppParams},
+        sync::{
+            ConflictResolution,
+            ConflictStrategy,
+            QuerySyncStatusRequest,
+            RemoteChange,
+            RemoteChangeType,
+            RepeatedLanPeer,
+            RepeatedObjectSyncStatus,
+            RepeatedRemoteChange,
+            RepeatedSyncConflict,
+            RepeatedSyncExclusion,
+            ResolveConflictRequest,
+            ResolvedConflictField,
+            ServerPush,
+            ServerPushType,
+            SubmitConflictResolutionRequest,
+            SyncEntityType,
+            SyncExclusionRequest,
+            SyncResult,
+            SyncSettings,
+            SyncState,
+            SyncStatusType,
+            SyncTraffic,
+            SyncTrigger,
+        },
+        view::{QueryViewRequest, UpdateViewParams, UpdateViewRequest, View},
+    },
+    errors::ErrorCode,
+    event::WorkspaceEvent::*,
+};
+use std::{
+    convert::{TryFrom, TryInto},
+    time::Duration,
+};
+
+fn remote_change<T: TryInto<Bytes>>(rev_id: i64, object_id: &str, ty: RemoteChangeType, data: T) -> RemoteChange {
+    let data: Bytes = data.try_into().ok().unwrap();
+    RemoteChange {
+        rev_id,
+        object_id: object_id.to_owned(),
+        ty,
+        data: data.to_vec(),
+        modified_time: 0,
+    }
+}
+
+fn rename_view(rev_id: i64, view_id: &str, name: &str) -> RemoteChange {
+    let mut params = UpdateViewParams::new(view_id);
+    params.name = Some(name.to_owned());
+    remote_change(rev_id, view_id, RemoteChangeType::UpdateView, params)
+}
+
+fn pending_rename(test: &FlowyTest, view_id: &str, name: &str, created_time: i64) {
+    pending_update(test, UpdateViewParams::new(view_id).name(name), created_time);
+}
+
+// The local change that wasn't sent yet, e.g. while offline.
+fn pending_update(test: &FlowyTest, params: UpdateViewParams, created_time: i64) {
+    let data: Bytes = params.try_into().unwrap();
+    let conn = test.sdk.user_session.db_connection().unwrap();
+    let _ = diesel::insert_into(operation_table::table)
+        .values((
+            operation_table::ty.eq(4),
+            operation_table::data.eq(data.to_vec()),
+            operation_table::created_time.eq(created_time),
+        ))
+        .execute(&*conn)
+        .unwrap();
+}
+
+async fn set_view_strategy(test: &FlowyTest, view_strategy: ConflictStrategy) {
+    let settings = SyncSettings {
+        view_strategy,
+        ..Default::default()
+    };
+    let _ = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(UpdateSyncSettings)
+        .request(settings)
+        .async_send()
+        .await;
+}
+
+async fn read_conflicts(test: &FlowyTest) -> RepeatedSyncConflict {
+    FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ReadSyncConflicts)
+        .async_send()
+        .await
+        .parse::<RepeatedSyncConflict>()
+}
+
+async fn view_name(test: &ViewTest) -> String { read_current_view(test).await.name }
+
+async fn read_current_view(test: &ViewTest) -> View {
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    read_view(&test.sdk, request).await
+}
+
+#[tokio::test]
+async fn sync_apply_remote_view_update() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+
+    let changes = vec![rename_view(3, &view_test.view.id, "remote")];
+    assert_eq!(test.sdk.workspace.apply_remote_changes(changes).await.unwrap(), 1);
+    assert_eq!(view_name(&view_test).await, "remote");
+
+    let state = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ReadSyncState)
+        .async_send()
+        .await
+        .parse::<SyncState>();
+    assert_eq!(state.rev_id, 3);
+    assert_eq!(state.pending_operations, 0);
+}
+
+fn push_changes(changes: Vec<RemoteChange>) -> ServerPush {
+    let data: Bytes = RepeatedRemoteChange { items: changes }.try_into().unwrap();
+    ServerPush {
+        ty: ServerPushType::RemoteChanges,
+        data: data.to_vec(),
+    }
+}
+
+#[tokio::test]
+async fn sync_apply_pushed_changes() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    let view_id = view_test.view.id.clone();
+
+    let push = push_changes(vec![rename_view(1, &view_id, "pushed")]);
+    test.sdk.workspace.receive_server_push(push).await.unwrap();
+    assert_eq!(view_name(&view_test).await, "pushed");
+
+    // The revisions before it were missed, the change is applied with the ones
+    // that are pulled.
+    let push = push_changes(vec![rename_view(4, &view_id, "after the gap")]);
+    test.sdk.workspace.receive_server_push(push).await.unwrap();
+    assert_eq!(view_name(&view_test).await, "after the gap");
+
+    let state = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ReadSyncState)
+        .async_send()
+        .await
+        .parse::<SyncState>();
+    assert_eq!(state.rev_id, 4);
+}
+
+#[tokio::test]
+async fn sync_skip_applied_revisions() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    let view_id = view_test.view.id.clone();
+
+    // The changes are applied in the order of their revisions.
+    let changes = vec![rename_view(5, &view_id, "second"), rename_view(4, &view_id, "first")];
+    assert_eq!(test.sdk.workspace.apply_remote_changes(changes).await.unwrap(), 2);
+    assert_eq!(view_name(&view_test).await, "second");
+
+    let changes = vec![
+        rename_view(4, &view_id, "first"),
+        rename_view(5, &view_id, "second again"),
+    ];
+    assert_eq!(test.sdk.workspace.apply_remote_changes(changes).await.unwrap(), 0);
+    assert_eq!(view_name(&view_test).await, "second");
+}
+
+#[tokio::test]
+async fn sync_local_changes_win() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+
+    pending_rename(&test, &view_test.view.id, "local", 0);
+    assert_eq!(test.sdk.workspace.sync_state().unwrap().pending_operations, 1);
+
+    let changes = vec![rename_view(1, &view_test.view.id, "remote")];
+    assert_eq!(test.sdk.workspace.apply_remote_changes(changes).await.unwrap(), 0);
+    assert_ne!(view_name(&view_test).await, "remote");
+    assert_eq!(test.sdk.workspace.sync_state().unwrap().rev_id, 1);
+
+    let result = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(SyncWorkspace)
+        .async_send()
+        .await
+        .parse::<SyncResult>();
+    assert_eq!(result.pushed, 1);
+    assert_eq!(result.state.pending_operations, 0);
+}
+
+#[tokio::test]
+async fn sync_apply_remote_creates() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+
+    let app = App {
+        id: flowy_infra::uuid(),
+        workspace_id: view_test.workspace.id.clone(),
+        name: "remote app".to_owned(),
+        ..Default::default()
+    };
+    let view = View {
+        id: flowy_infra::uuid(),
+        belong_to_id: view_test.app.id.clone(),
+        name: "remote view".to_owned(),
+        ..Default::default()
+    };
+    let mut params = UpdateAppParams::new(&app.id);
+    params.name = Some("renamed app".to_owned());
+    let changes = vec![
+        remote_change(1, &app.id, RemoteChangeType::CreateApp, app.clone()),
+        remote_change(2, &view.id, RemoteChangeType::CreateView, view.clone()),
+        remote_change(3, &app.id, RemoteChangeType::UpdateApp, params),
+        remote_change(4, "unknown", RemoteChangeType::Unknown, Bytes::new()),
+    ];
+    assert_eq!(test.sdk.workspace.apply_remote_changes(changes).await.unwrap(), 3);
+
+    let request = QueryAppRequest {
+        app_ids: vec![app.id.clone()],
+    };
+    assert_eq!(read_app(&test.sdk, request).await.name, "renamed app");
+    let request = QueryViewRequest {
+        view_ids: vec![view.id.clone()],
+    };
+    assert_eq!(read_view(&test.sdk, request).await.name, "remote view");
+    assert_eq!(test.sdk.workspace.sync_state().unwrap().rev_id, 4);
+}
+
+#[tokio::test]
+async fn sync_apply_remote_doc_delta() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let view_ids = vec![view_test.view.id.clone()];
+    let doc = open_view(
+        &test.sdk,
+        QueryViewRequest {
+            view_ids: view_ids.clone(),
+        },
+    )
+    .await;
+
+    let mut delta = DeltaBuilder::new().insert("remote ").build();
+    delta.retain(Delta::from_json(&doc.data).unwrap().target_len, Attributes::default());
+    let doc_delta = DocDelta {
+        doc_id: view_test.view.id.clone(),
+        data: delta.to_json(),
+    };
+    let changes = vec![remote_change(
+        1,
+        &view_test.view.id,
+        RemoteChangeType::DocDelta,
+        doc_delta,
+    )];
+    assert_eq!(test.sdk.workspace.apply_remote_changes(changes).await.unwrap(), 1);
+
+    let doc = open_view(&test.sdk, QueryViewRequest { view_ids }).await;
+    assert!(doc.data.contains("remote "));
+}
+
+fn rename_view_at(rev_id: i64, view_id: &str, name: &str, modified_time: i64) -> RemoteChange {
+    let mut change = rename_view(rev_id, view_id, name);
+    change.modified_time = modified_time;
+    change
+}
+
+#[tokio::test]
+async fn sync_settings_update() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let settings = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ReadSyncSettings)
+        .async_send()
+        .await
+        .parse::<SyncSettings>();
+    assert_eq!(settings, SyncSettings::default());
+
+    set_view_strategy(&test, ConflictStrategy::LastWriterWins).await;
+    let settings = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ReadSyncSettings)
+        .async_send()
+        .await
+        .parse::<SyncSettings>();
+    assert_eq!(settings.view_strategy, ConflictStrategy::LastWriterWins);
+    assert_eq!(settings.app_strategy, ConflictStrategy::PreferLocal);
+}
+
+#[tokio::test]
+async fn sync_conflict_prefer_remote() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    set_view_strategy(&test, ConflictStrategy::PreferRemote).await;
+
+    pending_rename(&test, &view_test.view.id, "local", 0);
+    let changes = vec![rename_view(1, &view_test.view.id, "remote")];
+    assert_eq!(test.sdk.workspace.apply_remote_changes(changes).await.unwrap(), 1);
+    assert_eq!(view_name(&view_test).await, "remote");
+
+    // The local change was dropped.
+    assert_eq!(test.sdk.workspace.sync_state().unwrap().pending_operations, 0);
+}
+
+#[tokio::test]
+async fn sync_conflict_last_writer_wins() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    set_view_strategy(&test, ConflictStrategy::LastWriterWins).await;
+    let view_id = view_test.view.id.clone();
+
+    pending_rename(&test, &view_id, "local", 100);
+    let changes = vec![rename_view_at(1, &view_id, "older", 50)];
+    assert_eq!(test.sdk.workspace.apply_remote_changes(changes).await.unwrap(), 0);
+    assert_ne!(view_name(&view_test).await, "older");
+    assert_eq!(test.sdk.workspace.sync_state().unwrap().pending_operations, 1);
+
+    let changes = vec![rename_view_at(2, &view_id, "newer", 200)];
+    assert_eq!(test.sdk.workspace.apply_remote_changes(changes).await.unwrap(), 1);
+    assert_eq!(view_name(&view_test).await, "newer");
+    assert_eq!(test.sdk.workspace.sync_state().unwrap().pending_operations, 0);
+}
+
+#[tokio::test]
+async fn sync_conflict_surface_to_user_apply_remote() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    set_view_strategy(&test, ConflictStrategy::SurfaceToUser).await;
+    let view_id = view_test.view.id.clone();
+
+    pending_rename(&test, &view_id, "local", 100);
+    let changes = vec![rename_view_at(1, &view_id, "remote", 50)];
+    assert_eq!(test.sdk.workspace.apply_remote_changes(changes).await.unwrap(), 0);
+    let conflicts = read_conflicts(&test).await;
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].object_id, view_id);
+    assert_eq!(conflicts[0].resolution, ConflictResolution::Pending);
+    assert_eq!(conflicts[0].local_time, 100);
+    assert_eq!(conflicts[0].remote_time, 50);
+
+    // The local change waits for the user.
+    assert_eq!(test.sdk.workspace.send_pending_operations().await.unwrap(), 0);
+
+    let request = ResolveConflictRequest {
+        object_id: view_id.clone(),
+        keep_local: false,
+    };
+    let _ = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ResolveSyncConflict)
+        .request(request)
+        .async_send()
+        .await;
+    assert_eq!(view_name(&view_test).await, "remote");
+    assert!(read_conflicts(&test).await.is_empty());
+    assert_eq!(test.sdk.workspace.sync_state().unwrap().pending_operations, 0);
+}
+
+#[tokio::test]
+async fn sync_conflict_surface_to_user_keep_local() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    set_view_strategy(&test, ConflictStrategy::SurfaceToUser).await;
+    let view_id = view_test.view.id.clone();
+
+    pending_rename(&test, &view_id, "local", 100);
+    let changes = vec![rename_view_at(1, &view_id, "remote", 200)];
+    assert_eq!(test.sdk.workspace.apply_remote_changes(changes).await.unwrap(), 0);
+
+    let request = ResolveConflictRequest {
+        object_id: view_id.clone(),
+        keep_local: true,
+    };
+    let _ = test.sdk.workspace.resolve_sync_conflict(request).await.unwrap();
+    assert_ne!(view_name(&view_test).await, "remote");
+    assert!(read_conflicts(&test).await.is_empty());
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    assert_eq!(test.sdk.workspace.sync_state().unwrap().pending_operations, 0);
+
+    // It was resolved already.
+    let request = ResolveConflictRequest {
+        object_id: view_id,
+        keep_local: true,
+    };
+    assert!(test.sdk.workspace.resolve_sync_conflict(request).await.is_err());
+}
+
+#[tokio::test]
+async fn sync_skip_unchanged_fields() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+
+    let request = UpdateViewRequest {
+        view_id: view_test.view.id.clone(),
+        name: Some(view_test.view.name.clone()),
+        desc: Some(view_test.view.desc.clone()),
+        ..Default::default()
+    };
+    update_view(&test.sdk, request).await;
+    assert_eq!(test.sdk.workspace.sync_state().unwrap().pending_operations, 0);
+}
+
+#[tokio::test]
+async fn sync_send_pending_updates_as_one() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    let view_id = view_test.view.id.clone();
+
+    pending_rename(&test, &view_id, "first", 0);
+    pending_update(&test, UpdateViewParams::new(&view_id).desc("desc"), 0);
+    pending_rename(&test, &view_id, "second", 0);
+    assert_eq!(test.sdk.workspace.sync_state().unwrap().pending_operations, 3);
+
+    assert_eq!(test.sdk.workspace.send_pending_operations().await.unwrap(), 1);
+    assert_eq!(test.sdk.workspace.sync_state().unwrap().pending_operations, 0);
+}
+
+#[tokio::test]
+async fn sync_conflict_only_local_fields() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    let view_id = view_test.view.id.clone();
+
+    // The desc wasn't changed locally, it's applied with PreferLocal too.
+    pending_rename(&test, &view_id, "local", 0);
+    let params = UpdateViewParams::new(&view_id).name("remote").desc("remote desc");
+    let changes = vec![remote_change(1, &view_id, RemoteChangeType::UpdateView, params)];
+    assert_eq!(test.sdk.workspace.apply_remote_changes(changes).await.unwrap(), 1);
+    let view = read_current_view(&view_test).await;
+    assert_ne!(view.name, "remote");
+    assert_eq!(view.desc, "remote desc");
+    assert_eq!(test.sdk.workspace.sync_state().unwrap().pending_operations, 1);
+}
+
+#[tokio::test]
+async fn sync_conflict_prefer_remote_keeps_other_fields() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    set_view_strategy(&test, ConflictStrategy::PreferRemote).await;
+    let view_id = view_test.view.id.clone();
+
+    pending_update(
+        &test,
+        UpdateViewParams::new(&view_id).name("local").desc("local desc"),
+        0,
+    );
+    let changes = vec![rename_view(1, &view_id, "remote")];
+    assert_eq!(test.sdk.workspace.apply_remote_changes(changes).await.unwrap(), 1);
+    assert_eq!(view_name(&view_test).await, "remote");
+
+    // The local desc is still sent.
+    assert_eq!(test.sdk.workspace.sync_state().unwrap().pending_operations, 1);
+    assert!(read_conflicts(&test).await.is_empty());
+}
+
+async fn sync_status(test: &FlowyTest, object_ids: Vec<String>) -> RepeatedObjectSyncStatus {
+    FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(GetSyncStatus)
+        .request(QuerySyncStatusRequest { object_ids })
+        .async_send()
+        .await
+        .parse::<RepeatedObjectSyncStatus>()
+}
+
+#[tokio::test]
+async fn sync_status_synced_after_send() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+
+    let statuses = sync_status(&test, vec![view_test.view.id.clone()]).await;
+    assert_eq!(statuses.len(), 1);
+    assert_eq!(statuses[0].object_id, view_test.view.id);
+    assert_eq!(statuses[0].ty, SyncStatusType::Synced);
+    assert_eq!(statuses[0].pending_operations, 0);
+    assert!(statuses[0].error.is_empty());
+}
+
+#[tokio::test]
+async fn sync_status_pending_per_object() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    let view_id = view_test.view.id.clone();
+    let app_id = view_test.app.id.clone();
+    pending_rename(&test, &view_id, "first", 1);
+    pending_rename(&test, &view_id, "second", 2);
+
+    let statuses = sync_status(&test, vec![view_id.clone(), app_id.clone()]).await;
+    assert_eq!(statuses[0].object_id, view_id);
+    assert_eq!(statuses[0].ty, SyncStatusType::Pending);
+    assert_eq!(statuses[0].pending_operations, 2);
+    assert_eq!(statuses[1].object_id, app_id);
+    assert_eq!(statuses[1].ty, SyncStatusType::Synced);
+
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    let statuses = sync_status(&test, vec![view_id]).await;
+    assert_eq!(statuses[0].ty, SyncStatusType::Synced);
+    assert_eq!(statuses[0].pending_operations, 0);
+}
+
+#[tokio::test]
+async fn sync_skipped_while_offline() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    let connectivity = test.sdk.user_session.connectivity.clone();
+    while !connectivity.report(false) {}
+
+    pending_rename(&test, &view_test.view.id, "offline", 1);
+    let error = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(SyncWorkspace)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::NetworkUnreachable.value());
+    assert_eq!(test.sdk.workspace.sync_state().unwrap().pending_operations, 1);
+}
+
+async fn update_sync_settings(test: &FlowyTest, settings: SyncSettings) {
+    let _ = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(UpdateSyncSettings)
+        .request(settings)
+        .async_send()
+        .await;
+}
+
+async fn rename_current_view(test: &FlowyTest, view_test: &ViewTest, name: &str) {
+    let request = UpdateViewRequest {
+        view_id: view_test.view.id.clone(),
+        name: Some(name.to_owned()),
+        ..Default::default()
+    };
+    update_view(&test.sdk, request).await;
+}
+
+#[tokio::test]
+async fn sync_settings_update_trigger() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let settings = SyncSettings {
+        trigger: SyncTrigger::Interval,
+        interval_seconds: 60,
+        debounce_millis: 500,
+        unmetered_only: true,
+        ..Default::default()
+    };
+    update_sync_settings(&test, settings.clone()).await;
+
+    let read = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ReadSyncSettings)
+        .async_send()
+        .await
+        .parse::<SyncSettings>();
+    assert_eq!(read, settings);
+}
+
+#[tokio::test]
+async fn sync_manual_trigger_keeps_changes() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    let settings = SyncSettings {
+        trigger: SyncTrigger::Manual,
+        ..Default::default()
+    };
+    update_sync_settings(&test, settings).await;
+
+    rename_current_view(&test, &view_test, "manual").await;
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(test.sdk.workspace.sync_state().unwrap().pending_operations, 1);
+
+    let result = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(SyncWorkspace)
+        .async_send()
+        .await
+        .parse::<SyncResult>();
+    assert_eq!(result.pushed, 1);
+    assert_eq!(result.state.pending_operations, 0);
+}
+
+#[tokio::test]
+async fn sync_on_change_after_debounce() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    let settings = SyncSettings {
+        trigger: SyncTrigger::OnChange,
+        debounce_millis: 200,
+        ..Default::default()
+    };
+    update_sync_settings(&test, settings).await;
+
+    rename_current_view(&test, &view_test, "first").await;
+    rename_current_view(&test, &view_test, "second").await;
+    assert!(test.sdk.workspace.sync_state().unwrap().pending_operations > 0);
+
+    tokio::time::sleep(Duration::from_millis(800)).await;
+    assert_eq!(test.sdk.workspace.sync_state().unwrap().pending_operations, 0);
+}
+
+#[tokio::test]
+async fn sync_wait_for_unmetered_network() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    let settings = SyncSettings {
+        unmetered_only: true,
+        ..Default::default()
+    };
+    update_sync_settings(&test, settings).await;
+    let connectivity = test.sdk.user_session.connectivity.clone();
+    assert!(connectivity.set_metered(true));
+
+    rename_current_view(&test, &view_test, "metered").await;
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(test.sdk.workspace.sync_state().unwrap().pending_operations, 1);
+
+    assert!(connectivity.set_metered(false));
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert_eq!(test.sdk.workspace.sync_state().unwrap().pending_operations, 0);
+}
+
+#[tokio::test]
+async fn sync_batch_window_sends_together() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    let settings = SyncSettings {
+        batch_window_millis: 300,
+        ..Default::default()
+    };
+    update_sync_settings(&test, settings).await;
+
+    rename_current_view(&test, &view_test, "first").await;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    rename_current_view(&test, &view_test, "second").await;
+    assert!(test.sdk.workspace.sync_state().unwrap().pending_operations > 0);
+
+    tokio::time::sleep(Duration::from_millis(800)).await;
+    assert_eq!(test.sdk.workspace.sync_state().unwrap().pending_operations, 0);
+    assert!(test.sdk.workspace.sync_traffic().uploaded_bytes > 0);
+}
+
+#[tokio::test]
+async fn sync_upload_rate_limit() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    let settings = SyncSettings {
+        trigger: SyncTrigger::Manual,
+        upload_bytes_per_second: 100,
+        ..Default::default()
+    };
+    update_sync_settings(&test, settings).await;
+
+    // The second one waits until the bytes of the first one were sent at the rate.
+    rename_current_view(&test, &view_test, "first").await;
+    assert_eq!(test.sdk.workspace.send_pending_operations().await.unwrap(), 1);
+    rename_current_view(&test, &view_test, "second").await;
+    assert_eq!(test.sdk.workspace.send_pending_operations().await.unwrap(), 1);
+
+    let traffic = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ReadSyncTraffic)
+        .async_send()
+        .await
+        .parse::<SyncTraffic>();
+    assert!(traffic.uploaded_bytes > 0);
+    assert!(traffic.throttled_millis > 0);
+    assert_eq!(traffic.downloaded_bytes, 0);
+}
+
+async fn set_sync_exclusion(test: &FlowyTest, object_id: &str, entity: SyncEntityType, excluded: bool) {
+    let request = SyncExclusionRequest {
+        object_id: object_id.to_owned(),
+        entity,
+        excluded,
+    };
+    let _ = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(SetSyncExclusion)
+        .request(request)
+        .async_send()
+        .await;
+}
+
+async fn read_sync_exclusions(test: &FlowyTest) -> RepeatedSyncExclusion {
+    FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ReadSyncExclusions)
+        .async_send()
+        .await
+        .parse::<RepeatedSyncExclusion>()
+}
+
+#[tokio::test]
+async fn sync_excluded_app_stays_local() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    let view_id = view_test.view.id.clone();
+    set_sync_exclusion(&test, &view_test.app.id, SyncEntityType::App, true).await;
+
+    let exclusions = read_sync_exclusions(&test).await;
+    assert_eq!(exclusions.len(), 1);
+    assert_eq!(exclusions[0].object_id, view_test.app.id);
+    assert_eq!(exclusions[0].entity, SyncEntityType::App);
+
+    rename_current_view(&test, &view_test, "local").await;
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(test.sdk.workspace.sync_state().unwrap().pending_operations, 1);
+    let statuses = sync_status(&test, vec![view_id.clone()]).await;
+    assert_eq!(statuses[0].ty, SyncStatusType::Excluded);
+
+    // The remote changes are skipped but not pulled again.
+    let changes = vec![rename_view(3, &view_id, "remote")];
+    assert_eq!(test.sdk.workspace.apply_remote_changes(changes).await.unwrap(), 0);
+    assert_eq!(view_name(&view_test).await, "local");
+    assert_eq!(test.sdk.workspace.sync_state().unwrap().rev_id, 3);
+}
+
+#[tokio::test]
+async fn sync_included_app_backfilled() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    set_sync_exclusion(&test, &view_test.app.id, SyncEntityType::App, true).await;
+    rename_current_view(&test, &view_test, "local").await;
+
+    set_sync_exclusion(&test, &view_test.app.id, SyncEntityType::App, false).await;
+    assert!(read_sync_exclusions(&test).await.is_empty());
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(test.sdk.workspace.sync_state().unwrap().pending_operations, 0);
+    let statuses = sync_status(&test, vec![view_test.view.id.clone()]).await;
+    assert_eq!(statuses[0].ty, SyncStatusType::Synced);
+}
+
+#[tokio::test]
+async fn sync_exclude_view_fails() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let request = SyncExclusionRequest {
+        object_id: view_test.view.id.clone(),
+        entity: SyncEntityType::View,
+        excluded: true,
+    };
+    let error = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(SetSyncExclusion)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::ViewIdInvalid.value());
+    assert!(read_sync_exclusions(&test).await.is_empty());
+}
+
+// The local rename waits for the sync, so the remote one conflicts with it.
+async fn surface_renames(test: &FlowyTest, view_test: &ViewTest) {
+    let settings = SyncSettings {
+        view_strategy: ConflictStrategy::SurfaceToUser,
+        trigger: SyncTrigger::Manual,
+        ..Default::default()
+    };
+    update_sync_settings(test, settings).await;
+    rename_current_view(test, view_test, "local").await;
+    let changes = vec![rename_view_at(1, &view_test.view.id, "remote", 50)];
+    assert_eq!(test.sdk.workspace.apply_remote_changes(changes).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn sync_conflict_three_way_payload() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    surface_renames(&test, &view_test).await;
+
+    let conflicts = read_conflicts(&test).await;
+    assert_eq!(conflicts.len(), 1);
+    let conflict = &conflicts[0];
+    assert_eq!(conflict.fields.len(), 1);
+    assert_eq!(conflict.fields[0].name, "name");
+    assert_eq!(conflict.fields[0].base, Some(view_test.view.name.clone()));
+    assert_eq!(conflict.fields[0].local, "local");
+    assert_eq!(conflict.fields[0].remote, "remote");
+
+    let base = UpdateViewParams::try_from(Bytes::from(conflict.base.clone())).unwrap();
+    assert_eq!(base.name, Some(view_test.view.name.clone()));
+    let local = UpdateViewParams::try_from(Bytes::from(conflict.local.clone())).unwrap();
+    assert_eq!(local.name, Some("local".to_owned()));
+}
+
+#[tokio::test]
+async fn sync_conflict_submit_resolution() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    surface_renames(&test, &view_test).await;
+
+    let request = SubmitConflictResolutionRequest {
+        object_id: view_test.view.id.clone(),
+        fields: vec![ResolvedConflictField {
+            name: "name".to_owned(),
+            value: "merged".to_owned(),
+        }],
+    };
+    let _ = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(SubmitConflictResolution)
+        .request(request)
+        .async_send()
+        .await;
+    assert_eq!(view_name(&view_test).await, "merged");
+    assert!(read_conflicts(&test).await.is_empty());
+
+    let result = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(SyncWorkspace)
+        .async_send()
+        .await
+        .parse::<SyncResult>();
+    assert_eq!(result.pushed, 1);
+    assert_eq!(result.state.pending_operations, 0);
+}
+
+#[tokio::test]
+async fn sync_conflict_submit_unknown_field() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let view_test = ViewTest::new(&test).await;
+    let _ = test.sdk.workspace.send_pending_operations().await.unwrap();
+    surface_renames(&test, &view_test).await;
+
+    let request = SubmitConflictResolutionRequest {
+        object_id: view_test.view.id.clone(),
+        fields: vec![ResolvedConflictField {
+            name: "desc".to_owned(),
+            value: "merged".to_owned(),
+        }],
+    };
+    let error = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(SubmitConflictResolution)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::SyncConflictFieldInvalid.value());
+    assert_eq!(read_conflicts(&test).await.len(), 1);
+}
+
+#[tokio::test]
+async fn sync_lan_settings_update() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let settings = SyncSettings {
+        lan_sync: true,
+        ..Default::default()
+    };
+    update_sync_settings(&test, settings).await;
+    assert!(test.sdk.workspace.sync_settings().unwrap().lan_sync);
+
+    update_sync_settings(&test, SyncSettings::default()).await;
+    assert!(!test.sdk.workspace.sync_settings().unwrap().lan_sync);
+}
+
+#[tokio::test]
+async fn sync_lan_peers_empty() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let peers = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ReadLanPeers)
+        .async_send()
+        .await
+        .parse::<RepeatedLanPeer>();
+    assert!(peers.is_empty());
+}
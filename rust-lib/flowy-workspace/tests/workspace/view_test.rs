@@ -1,9 +1,32 @@
-use flowy_test::{workspace::*, FlowyTest};
-use flowy_workspace::entities::{
-    app::QueryAppRequest,
-    trash::{TrashIdentifier, TrashType},
-    view::*,
+use flowy_database::PoolConfig;
+use flowy_document::{
+    entities::asset::{AssetData, AssetIdentifier, CreateAssetRequest, DocAsset},
+    event::DocumentEvent::{AttachAsset, ReadAsset},
+    services::{asset::referenced_assets, export::MAX_INLINE_EXPORT_SIZE},
 };
+use flowy_document_infra::entities::doc::DocDelta;
+use flowy_infra::{clock::ManualClock, secret::SecretStoreKind, uuid};
+use flowy_net::config::ServerConfig;
+use flowy_ot::core::{Attribute, Attributes, Delta, DeltaBuilder};
+use flowy_test::{
+    builder::{DocTest, FlowyWorkspaceTest},
+    prelude::{root_dir, FlowySDKConfig},
+    workspace::*,
+    FlowyTest,
+};
+use flowy_workspace::{
+    entities::{
+        app::QueryAppRequest,
+        share::{ExportData, ExportRequest, ExportType, ImportRequest, ImportType},
+        template::{CreateViewFromTemplateRequest, RepeatedTemplate, SaveTemplateRequest, Template},
+        trash::{TrashIdentifier, TrashIdentifiers, TrashType},
+        view::*,
+    },
+    errors::ErrorCode,
+    event::{WorkspaceEvent, WorkspaceEvent::*},
+    module::MAX_RECENT_VIEWS,
+};
+use std::{sync::Arc, time::Duration};
 
 #[tokio::test]
 #[should_panic]
@@ -83,6 +106,75 @@ async fn view_delete_all_permanent() {
     assert_eq!(read_trash(&test.sdk).await.len(), 0);
 }
 
+#[tokio::test]
+async fn view_purge_trash_keeps_unexpired() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    test.delete_views(vec![test.view.id.clone()]).await;
+    let _ = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(PurgeTrash)
+        .async_send()
+        .await;
+
+    let repeated_trash = read_trash(&test.sdk).await;
+    assert_eq!(repeated_trash.len(), 1);
+    assert!(repeated_trash[0].deleted_time > 0);
+}
+
+#[tokio::test]
+async fn view_purge_trash_after_retention() {
+    let config = FlowySDKConfig::new(&root_dir(), ServerConfig::default(), &uuid())
+        .secret_store(SecretStoreKind::File)
+        .trash_retention(0)
+        .trash_purge_interval(Duration::from_millis(100));
+    let test = FlowyTest::setup_with_config(config);
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    test.delete_views(vec![test.view.id.clone()]).await;
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    assert_eq!(read_trash(&test.sdk).await.len(), 0);
+    let query = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let _ = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ReadView)
+        .request(query)
+        .async_send()
+        .await
+        .error();
+}
+
+#[tokio::test]
+async fn view_purge_trash_by_the_clock() {
+    let clock = Arc::new(ManualClock::now());
+    let config = FlowySDKConfig::new(&root_dir(), ServerConfig::default(), &uuid())
+        .secret_store(SecretStoreKind::File)
+        .trash_retention(60)
+        .trash_purge_interval(Duration::from_secs(60 * 60))
+        .clock(clock.clone());
+    let test = FlowyTest::setup_with_config(config);
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    test.delete_views(vec![test.view.id.clone()]).await;
+    assert_eq!(read_trash(&test.sdk).await.len(), 1);
+
+    clock.advance(Duration::from_secs(60 * 60));
+    let mut purged = false;
+    for _ in 0..20 {
+        if read_trash(&test.sdk).await.is_empty() {
+            purged = true;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    assert!(purged);
+}
+
 #[tokio::test]
 async fn view_open_doc() {
     let test = FlowyTest::setup();
@@ -94,3 +186,1225 @@ async fn view_open_doc() {
     };
     let _ = open_view(&test.sdk, request).await;
 }
+
+async fn apply_doc_delta(test: &ViewTest, mut delta: Delta) {
+    let doc = open_view(
+        &test.sdk,
+        QueryViewRequest {
+            view_ids: vec![test.view.id.clone()],
+        },
+    )
+    .await;
+    delta.retain(Delta::from_json(&doc.data).unwrap().target_len, Attributes::default());
+    let _ = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ApplyDocDelta)
+        .request(DocDelta {
+            doc_id: test.view.id.clone(),
+            data: delta.to_json(),
+        })
+        .async_send()
+        .await;
+}
+
+async fn export_doc(test: &ViewTest, export_type: ExportType) -> ExportData {
+    FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ExportDocument)
+        .request(ExportRequest {
+            doc_id: test.view.id.clone(),
+            export_type,
+        })
+        .async_send()
+        .await
+        .parse::<ExportData>()
+}
+
+#[tokio::test]
+async fn view_export_markdown() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let delta = DeltaBuilder::new()
+        .insert("Title")
+        .insert_with_attributes("\n", Attribute::Header(1).into())
+        .insert("123")
+        .build();
+    apply_doc_delta(&test, delta).await;
+
+    let data = export_doc(&test, ExportType::Markdown).await;
+    assert_eq!(data.data, "# Title\n\n123\n");
+    assert!(data.path.is_empty());
+}
+
+#[tokio::test]
+async fn view_export_large_markdown_to_file() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let s = "a".repeat(MAX_INLINE_EXPORT_SIZE);
+    apply_doc_delta(&test, DeltaBuilder::new().insert(&s).build()).await;
+
+    let data = export_doc(&test, ExportType::Markdown).await;
+    assert!(data.data.is_empty());
+    assert!(data.path.ends_with(&format!("{}.md", test.view.id)));
+    assert_eq!(std::fs::read_to_string(&data.path).unwrap(), format!("{}\n", s));
+}
+
+fn import_request(test: &ViewTest, name: &str, import_type: ImportType, data: &str) -> ImportRequest {
+    ImportRequest {
+        belong_to_id: test.app.id.clone(),
+        name: name.to_owned(),
+        import_type,
+        data: data.to_owned(),
+    }
+}
+
+async fn import_doc(test: &ViewTest, request: ImportRequest) -> Delta {
+    let view = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ImportDocument)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<View>();
+    assert_eq!(view.view_type, ViewType::Doc);
+
+    let doc = open_view(
+        &test.sdk,
+        QueryViewRequest {
+            view_ids: vec![view.id.clone()],
+        },
+    )
+    .await;
+    Delta::from_json(&doc.data).unwrap()
+}
+
+#[tokio::test]
+async fn view_import_markdown() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = import_request(&test, "Imported", ImportType::Markdown, "# Title\n\n**123**\n");
+    let delta = import_doc(&test, request).await;
+    let expected = DeltaBuilder::new()
+        .insert("Title")
+        .insert_with_attributes("\n", Attribute::Header(1).into())
+        .insert_with_attributes("123", Attribute::Bold(true).into())
+        .insert("\n")
+        .build();
+    assert_eq!(delta, expected);
+}
+
+#[tokio::test]
+async fn view_import_html() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let html = "<html><head><title>Page</title></head><body><h2>Title</h2><p><em>123</em></p><custom>456</custom></body></html>";
+    let request = import_request(&test, "Imported", ImportType::Html, html);
+    let delta = import_doc(&test, request).await;
+    let expected = DeltaBuilder::new()
+        .insert("Title")
+        .insert_with_attributes("\n", Attribute::Header(2).into())
+        .insert_with_attributes("123", Attribute::Italic(true).into())
+        .insert("\n456\n")
+        .build();
+    assert_eq!(delta, expected);
+}
+
+#[tokio::test]
+async fn view_import_with_empty_name() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let error = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ImportDocument)
+        .request(import_request(&test, "", ImportType::Markdown, "123"))
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::ViewNameInvalid.value());
+}
+
+async fn read_templates(test: &ViewTest) -> RepeatedTemplate {
+    FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ReadTemplates)
+        .async_send()
+        .await
+        .parse::<RepeatedTemplate>()
+}
+
+fn template_request(test: &ViewTest, template_id: &str, name: &str) -> CreateViewFromTemplateRequest {
+    CreateViewFromTemplateRequest {
+        belong_to_id: test.app.id.clone(),
+        template_id: template_id.to_owned(),
+        name: name.to_owned(),
+    }
+}
+
+async fn create_view_from_template(test: &ViewTest, template_id: &str, name: &str) -> Delta {
+    let view = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(CreateViewFromTemplate)
+        .request(template_request(test, template_id, name))
+        .async_send()
+        .await
+        .parse::<View>();
+    assert_eq!(view.name, name);
+
+    let doc = open_view(
+        &test.sdk,
+        QueryViewRequest {
+            view_ids: vec![view.id.clone()],
+        },
+    )
+    .await;
+    Delta::from_json(&doc.data).unwrap()
+}
+
+#[tokio::test]
+async fn view_read_built_in_templates() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let templates = read_templates(&test).await;
+    assert!(!templates.is_empty());
+    assert!(templates.iter().all(|template| template.built_in));
+}
+
+#[tokio::test]
+async fn view_create_from_built_in_template() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let template = read_templates(&test)
+        .await
+        .into_inner()
+        .into_iter()
+        .find(|template| template.name == "To-do list")
+        .unwrap();
+    let delta = create_view_from_template(&test, &template.id, "Groceries").await;
+    let date = chrono::Local::now().format("%Y-%m-%d").to_string();
+    let expected = DeltaBuilder::new()
+        .insert("Groceries")
+        .insert_with_attributes("\n", Attribute::Header(1).into())
+        .insert(&format!("{}\n", date))
+        .insert_with_attributes("\n", Attribute::UnChecked(true).into())
+        .build();
+    assert_eq!(delta, expected);
+}
+
+#[tokio::test]
+async fn view_save_template_then_create_from_it() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = import_request(&test, "Letter", ImportType::Markdown, "Dear {{title}}\n");
+    let view = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ImportDocument)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<View>();
+
+    let template = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(SaveTemplate)
+        .request(SaveTemplateRequest {
+            view_id: view.id.clone(),
+            name: "Letter".to_owned(),
+            desc: "".to_owned(),
+        })
+        .async_send()
+        .await
+        .parse::<Template>();
+    assert!(!template.built_in);
+    assert!(read_templates(&test).await.contains(&template));
+
+    let delta = create_view_from_template(&test, &template.id, "Friend").await;
+    assert_eq!(delta, DeltaBuilder::new().insert("Dear Friend\n").build());
+}
+
+#[tokio::test]
+async fn view_create_from_template_not_exist() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let error = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(CreateViewFromTemplate)
+        .request(template_request(&test, "unknown", "Untitled"))
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::RecordNotFound.value());
+}
+
+#[tokio::test]
+async fn view_save_template_with_empty_name() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let error = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(SaveTemplate)
+        .request(SaveTemplateRequest {
+            view_id: test.view.id.clone(),
+            name: "".to_owned(),
+            desc: "".to_owned(),
+        })
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::TemplateNameInvalid.value());
+}
+
+async fn duplicate_document(test: &ViewTest, request: DuplicateDocumentRequest) -> View {
+    FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(DuplicateDocument)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<View>()
+}
+
+async fn open_view_delta(test: &ViewTest, view_id: &str) -> Delta {
+    let doc = open_view(
+        &test.sdk,
+        QueryViewRequest {
+            view_ids: vec![view_id.to_owned()],
+        },
+    )
+    .await;
+    Delta::from_json(&doc.data).unwrap()
+}
+
+async fn insert_image(test: &ViewTest, view_id: &str, data: Vec<u8>) -> DocAsset {
+    let asset = DocTest::new(test.sdk.clone())
+        .event(AttachAsset)
+        .request(CreateAssetRequest {
+            doc_id: view_id.to_owned(),
+            name: "image.png".to_owned(),
+            mime_type: "image/png".to_owned(),
+            data,
+        })
+        .async_send()
+        .await
+        .parse::<DocAsset>();
+
+    let len = open_view_delta(test, view_id).await.target_len;
+    let delta = DeltaBuilder::new()
+        .insert("Photo ")
+        .insert_with_attributes("image", Attribute::Link(&asset.url).into())
+        .retain(len)
+        .build();
+    FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ApplyDocDelta)
+        .request(DocDelta {
+            doc_id: view_id.to_owned(),
+            data: delta.to_json(),
+        })
+        .async_send()
+        .await;
+    asset
+}
+
+#[tokio::test]
+async fn view_duplicate_document_with_assets() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let asset = insert_image(&test, &test.view.id, vec![1, 2, 3]).await;
+    let other_app = create_app(&test.sdk, "Other", "", &test.workspace.id).await;
+
+    let view = duplicate_document(
+        &test,
+        DuplicateDocumentRequest {
+            view_id: test.view.id.clone(),
+            belong_to_id: Some(other_app.id.clone()),
+            name: Some("Copy".to_owned()),
+        },
+    )
+    .await;
+    assert_ne!(view.id, test.view.id);
+    assert_eq!(view.name, "Copy");
+    assert_eq!(view.belong_to_id, other_app.id);
+    assert_eq!(view.view_type, test.view.view_type);
+
+    let query = QueryAppRequest {
+        app_ids: vec![other_app.id.clone()],
+    };
+    let belongings = read_app(&test.sdk, query).await.belongings;
+    assert_eq!(
+        belongings.items.iter().map(|view| view.id.clone()).collect::<Vec<_>>(),
+        vec![view.id.clone()]
+    );
+
+    // The copy links to the copy of the asset, the text is kept.
+    let source = open_view_delta(&test, &test.view.id).await;
+    let copy = open_view_delta(&test, &view.id).await;
+    assert_eq!(copy.apply("").unwrap(), source.apply("").unwrap());
+    let copied_assets = referenced_assets(&copy).into_iter().collect::<Vec<_>>();
+    assert_eq!(copied_assets.len(), 1);
+    assert_ne!(copied_assets[0], asset.id);
+
+    let data = DocTest::new(test.sdk.clone())
+        .event(ReadAsset)
+        .request(AssetIdentifier {
+            asset_id: copied_assets[0].clone(),
+        })
+        .async_send()
+        .await
+        .parse::<AssetData>();
+    assert_eq!(data.asset.doc_id, view.id);
+    assert_eq!(data.data, vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn view_duplicate_document_next_to_the_view() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let view = duplicate_document(
+        &test,
+        DuplicateDocumentRequest {
+            view_id: test.view.id.clone(),
+            belong_to_id: None,
+            name: None,
+        },
+    )
+    .await;
+    assert_eq!(view.belong_to_id, test.app.id);
+    assert_eq!(view.name, format!("{} (copy)", test.view.name));
+    assert_eq!(
+        open_view_delta(&test, &view.id).await,
+        open_view_delta(&test, &test.view.id).await
+    );
+}
+
+#[tokio::test]
+async fn view_duplicate_document_in_trash() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    test.delete_views(vec![test.view.id.clone()]).await;
+    let error = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(DuplicateDocument)
+        .request(DuplicateDocumentRequest {
+            view_id: test.view.id.clone(),
+            belong_to_id: None,
+            name: None,
+        })
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::RecordNotFound.value());
+}
+
+async fn move_view(
+    test: &ViewTest,
+    view_id: &str,
+    belong_to_id: &str,
+    prev_view_id: Option<&str>,
+) -> FlowyWorkspaceTest {
+    FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(MoveView)
+        .request(MoveViewRequest {
+            view_id: view_id.to_owned(),
+            belong_to_id: belong_to_id.to_owned(),
+            prev_view_id: prev_view_id.map(|prev_view_id| prev_view_id.to_owned()),
+        })
+        .async_send()
+        .await
+}
+
+async fn read_app_view_ids(test: &ViewTest, app_id: &str) -> Vec<String> {
+    let query = QueryAppRequest {
+        app_ids: vec![app_id.to_owned()],
+    };
+    read_app(&test.sdk, query)
+        .await
+        .belongings
+        .iter()
+        .map(|view| view.id.clone())
+        .collect()
+}
+
+#[tokio::test]
+async fn view_move_within_app() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let app_id = test.app.id.clone();
+    let view1 = test.view.id.clone();
+    let view2 = create_view(&test.sdk, &app_id).await.id;
+    let view3 = create_view(&test.sdk, &app_id).await.id;
+    assert_eq!(
+        read_app_view_ids(&test, &app_id).await,
+        vec![view1.clone(), view2.clone(), view3.clone()]
+    );
+
+    let _ = move_view(&test, &view3, &app_id, None).await;
+    assert_eq!(
+        read_app_view_ids(&test, &app_id).await,
+        vec![view3.clone(), view1.clone(), view2.clone()]
+    );
+
+    let _ = move_view(&test, &view3, &app_id, Some(&view1)).await;
+    assert_eq!(
+        read_app_view_ids(&test, &app_id).await,
+        vec![view1.clone(), view3.clone(), view2.clone()]
+    );
+
+    let _ = move_view(&test, &view1, &app_id, Some(&view2)).await;
+    assert_eq!(
+        read_app_view_ids(&test, &app_id).await,
+        vec![view3.clone(), view2.clone(), view1.clone()]
+    );
+
+    // The new view is put after the moved ones.
+    let view4 = create_view(&test.sdk, &app_id).await.id;
+    assert_eq!(
+        read_app_view_ids(&test, &app_id).await,
+        vec![view3, view2, view1, view4]
+    );
+}
+
+#[tokio::test]
+async fn view_move_to_another_app() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let app2 = create_app(&test.sdk, "App 2", "", &test.workspace.id).await;
+    let view2 = create_view(&test.sdk, &app2.id).await.id;
+
+    let view = move_view(&test, &test.view.id, &app2.id, Some(&view2))
+        .await
+        .parse::<View>();
+    assert_eq!(view.belong_to_id, app2.id);
+    assert!(read_app_view_ids(&test, &test.app.id).await.is_empty());
+    assert_eq!(
+        read_app_view_ids(&test, &app2.id).await,
+        vec![view2, test.view.id.clone()]
+    );
+}
+
+#[tokio::test]
+async fn view_move_after_unknown_view() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let error = move_view(&test, &test.view.id, &test.app.id, Some("unknown view"))
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::RecordNotFound.value());
+}
+
+async fn send_favorites_event(test: &ViewTest, event: WorkspaceEvent, view_ids: Vec<String>) -> Vec<String> {
+    FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(event)
+        .request(QueryViewRequest { view_ids })
+        .async_send()
+        .await
+        .parse::<RepeatedView>()
+        .iter()
+        .map(|view| view.id.clone())
+        .collect()
+}
+
+async fn read_favorite_ids(test: &ViewTest) -> Vec<String> {
+    FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ReadFavorites)
+        .async_send()
+        .await
+        .parse::<RepeatedView>()
+        .iter()
+        .map(|view| view.id.clone())
+        .collect()
+}
+
+async fn move_favorite(test: &ViewTest, view_id: &str, prev_view_id: Option<&str>) -> FlowyWorkspaceTest {
+    FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(MoveFavorite)
+        .request(MoveFavoriteRequest {
+            view_id: view_id.to_owned(),
+            prev_view_id: prev_view_id.map(|prev_view_id| prev_view_id.to_owned()),
+        })
+        .async_send()
+        .await
+}
+
+#[tokio::test]
+async fn view_pin_and_unpin() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let view2 = create_view(&test.sdk, &test.app.id).await.id;
+    assert!(read_favorite_ids(&test).await.is_empty());
+
+    let favorites = send_favorites_event(&test, PinView, vec![view2.clone(), test.view.id.clone()]).await;
+    assert_eq!(favorites, vec![view2.clone(), test.view.id.clone()]);
+
+    // Pinning the view again keeps its place.
+    let favorites = send_favorites_event(&test, PinView, vec![view2.clone()]).await;
+    assert_eq!(favorites, vec![view2.clone(), test.view.id.clone()]);
+
+    let favorites = send_favorites_event(&test, UnpinView, vec![view2]).await;
+    assert_eq!(favorites, vec![test.view.id.clone()]);
+    assert_eq!(read_favorite_ids(&test).await, vec![test.view.id.clone()]);
+}
+
+#[tokio::test]
+async fn view_move_favorite() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let view1 = test.view.id.clone();
+    let view2 = create_view(&test.sdk, &test.app.id).await.id;
+    let view3 = create_view(&test.sdk, &test.app.id).await.id;
+    let _ = send_favorites_event(&test, PinView, vec![view1.clone(), view2.clone(), view3.clone()]).await;
+
+    let favorites = move_favorite(&test, &view3, None).await.parse::<RepeatedView>();
+    assert_eq!(favorites.items[0].id, view3);
+
+    let _ = move_favorite(&test, &view1, Some(&view2)).await;
+    assert_eq!(
+        read_favorite_ids(&test).await,
+        vec![view3.clone(), view2.clone(), view1]
+    );
+
+    let error = move_favorite(&test, &view2, Some("unknown view")).await.error();
+    assert_eq!(error.code, ErrorCode::RecordNotFound.value());
+}
+
+#[tokio::test]
+async fn view_favorite_in_trash() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let _ = send_favorites_event(&test, PinView, vec![test.view.id.clone()]).await;
+
+    test.delete_views(vec![test.view.id.clone()]).await;
+    assert!(read_favorite_ids(&test).await.is_empty());
+
+    putback_trash(
+        &test.sdk,
+        TrashIdentifier {
+            id: test.view.id.clone(),
+            ty: TrashType::View,
+        },
+    )
+    .await;
+    assert_eq!(read_favorite_ids(&test).await, vec![test.view.id.clone()]);
+}
+
+async fn read_view_tree(test: &ViewTest, belong_to_id: &str, depth: i32) -> RepeatedView {
+    FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ReadViewTree)
+        .request(ReadViewTreeRequest {
+            belong_to_id: belong_to_id.to_owned(),
+            depth,
+        })
+        .async_send()
+        .await
+        .parse::<RepeatedView>()
+}
+
+async fn read_view_error_code(test: &ViewTest, view_id: &str) -> i32 {
+    FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ReadView)
+        .request(QueryViewRequest {
+            view_ids: vec![view_id.to_owned()],
+        })
+        .async_send()
+        .await
+        .error()
+        .code
+}
+
+#[tokio::test]
+async fn view_read_nested_tree() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let child = create_view(&test.sdk, &test.view.id).await;
+    let grandchild = create_view(&test.sdk, &child.id).await;
+
+    let tree = read_view_tree(&test, &test.app.id, 0).await;
+    assert_eq!(tree.len(), 1);
+    assert_eq!(tree[0].id, test.view.id);
+    assert_eq!(tree[0].belongings[0].id, child.id);
+    assert_eq!(tree[0].belongings[0].belongings[0].id, grandchild.id);
+
+    let tree = read_view_tree(&test, &test.app.id, 2).await;
+    assert_eq!(tree[0].belongings[0].id, child.id);
+    assert!(tree[0].belongings[0].belongings.is_empty());
+
+    let tree = read_view_tree(&test, &child.id, 1).await;
+    assert_eq!(tree.len(), 1);
+    assert_eq!(tree[0].id, grandchild.id);
+}
+
+#[tokio::test]
+async fn view_move_into_child() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let child = create_view(&test.sdk, &test.view.id).await;
+    let grandchild = create_view(&test.sdk, &child.id).await;
+
+    let error = move_view(&test, &test.view.id, &grandchild.id, None).await.error();
+    assert_eq!(error.code, ErrorCode::ViewParentInvalid.value());
+    let error = move_view(&test, &test.view.id, &test.view.id, None).await.error();
+    assert_eq!(error.code, ErrorCode::ViewParentInvalid.value());
+
+    // The grandchild can be moved up into the app.
+    let view = move_view(&test, &grandchild.id, &test.app.id, None)
+        .await
+        .parse::<View>();
+    assert_eq!(view.belong_to_id, test.app.id);
+    assert_eq!(
+        read_app_view_ids(&test, &test.app.id).await,
+        vec![grandchild.id.clone(), test.view.id.clone()]
+    );
+}
+
+#[tokio::test]
+async fn view_trash_then_putback_subtree() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let child = create_view(&test.sdk, &test.view.id).await;
+    let grandchild = create_view(&test.sdk, &child.id).await;
+
+    test.delete_views(vec![child.id.clone()]).await;
+    assert_eq!(
+        read_view_error_code(&test, &grandchild.id).await,
+        ErrorCode::RecordNotFound.value()
+    );
+    assert!(read_view_tree(&test, &test.app.id, 0).await[0].belongings.is_empty());
+    assert_eq!(read_trash(&test.sdk).await.len(), 1);
+
+    putback_trash(
+        &test.sdk,
+        TrashIdentifier {
+            id: child.id.clone(),
+            ty: TrashType::View,
+        },
+    )
+    .await;
+    let query = QueryViewRequest {
+        view_ids: vec![grandchild.id.clone()],
+    };
+    assert_eq!(read_view(&test.sdk, query).await.id, grandchild.id);
+}
+
+#[tokio::test]
+async fn view_delete_subtree_permanent() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let child = create_view(&test.sdk, &test.view.id).await;
+    let grandchild = create_view(&test.sdk, &child.id).await;
+
+    // The grandchild trashed on its own leaves the trash with the view.
+    test.delete_views(vec![grandchild.id.clone(), test.view.id.clone()])
+        .await;
+    FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(DeleteTrash)
+        .request(TrashIdentifiers {
+            items: vec![TrashIdentifier {
+                id: test.view.id.clone(),
+                ty: TrashType::View,
+            }],
+            delete_all: false,
+        })
+        .async_send()
+        .await;
+
+    assert!(read_app_view_ids(&test, &test.app.id).await.is_empty());
+    assert_eq!(read_trash(&test.sdk).await.len(), 0);
+    // The views are deleted rather than hidden, reading them fails.
+    let _ = read_view_error_code(&test, &child.id).await;
+    let _ = read_view_error_code(&test, &grandchild.id).await;
+    assert!(read_view_tree(&test, &test.app.id, 0).await.is_empty());
+}
+
+async fn attach_cover(test: &ViewTest, view_id: &str) -> DocAsset {
+    DocTest::new(test.sdk.clone())
+        .event(AttachAsset)
+        .request(CreateAssetRequest {
+            doc_id: view_id.to_owned(),
+            name: "cover.png".to_owned(),
+            mime_type: "image/png".to_owned(),
+            data: vec![7, 8, 9],
+        })
+        .async_send()
+        .await
+        .parse::<DocAsset>()
+}
+
+fn update_metadata_request(view_id: &str, icon: &str, cover: &str) -> UpdateViewRequest {
+    UpdateViewRequest {
+        view_id: view_id.to_owned(),
+        icon: Some(icon.to_owned()),
+        cover: Some(cover.to_owned()),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn view_update_icon_and_cover() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let cover = attach_cover(&test, &test.view.id).await;
+    update_view(&test.sdk, update_metadata_request(&test.view.id, "🚀", &cover.id)).await;
+
+    let query = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let view = read_view(&test.sdk, query).await;
+    assert_eq!(view.icon, "🚀");
+    assert_eq!(view.cover, cover.id);
+
+    // The sidebar reads them with the belongings of the app.
+    let query_app = QueryAppRequest {
+        app_ids: vec![test.app.id.clone()],
+    };
+    let belongings = read_app(&test.sdk, query_app).await.belongings;
+    assert_eq!(belongings[0].icon, "🚀");
+    assert_eq!(belongings[0].cover, cover.id);
+
+    update_view(&test.sdk, update_metadata_request(&test.view.id, "", "")).await;
+    let query = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let view = read_view(&test.sdk, query).await;
+    assert!(view.icon.is_empty());
+    assert!(view.cover.is_empty());
+}
+
+#[tokio::test]
+async fn view_update_cover_of_another_document() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let other_view = create_view(&test.sdk, &test.app.id).await;
+    let cover = attach_cover(&test, &other_view.id).await;
+    let error = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(UpdateView)
+        .request(update_metadata_request(&test.view.id, "", &cover.id))
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::ViewCoverInvalid.value());
+}
+
+#[tokio::test]
+async fn view_duplicate_document_with_icon_and_cover() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let cover = attach_cover(&test, &test.view.id).await;
+    update_view(&test.sdk, update_metadata_request(&test.view.id, "📝", &cover.id)).await;
+
+    let request = DuplicateDocumentRequest {
+        view_id: test.view.id.clone(),
+        belong_to_id: None,
+        name: None,
+    };
+    let view = duplicate_document(&test, request).await;
+    assert_eq!(view.icon, "📝");
+    assert_ne!(view.cover, cover.id);
+
+    // The copy of the cover belongs to the copy of the document.
+    let data = DocTest::new(test.sdk.clone())
+        .event(ReadAsset)
+        .request(AssetIdentifier { asset_id: view.cover })
+        .async_send()
+        .await
+        .parse::<AssetData>();
+    assert_eq!(data.asset.doc_id, view.id);
+    assert_eq!(data.data, vec![7, 8, 9]);
+}
+
+async fn read_recent_view_ids(test: &ViewTest) -> Vec<String> {
+    FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ReadRecentViews)
+        .async_send()
+        .await
+        .parse::<RepeatedView>()
+        .iter()
+        .map(|view| view.id.clone())
+        .collect()
+}
+
+async fn open_views(test: &ViewTest, view_ids: &[&str]) {
+    for view_id in view_ids {
+        let _ = open_view_delta(test, view_id).await;
+    }
+}
+
+#[tokio::test]
+async fn view_read_recent_views() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let view1 = test.view.id.clone();
+    let view2 = create_view(&test.sdk, &test.app.id).await.id;
+    let view3 = create_view(&test.sdk, &test.app.id).await.id;
+    assert!(read_recent_view_ids(&test).await.is_empty());
+
+    open_views(&test, &[&view1, &view2, &view3]).await;
+    assert_eq!(
+        read_recent_view_ids(&test).await,
+        vec![view3.clone(), view2.clone(), view1.clone()]
+    );
+
+    // The view opened again is moved to the front.
+    open_views(&test, &[&view1]).await;
+    assert_eq!(read_recent_view_ids(&test).await, vec![view1, view3, view2]);
+}
+
+#[tokio::test]
+async fn view_recent_views_are_bounded() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let mut view_ids = vec![test.view.id.clone()];
+    for _ in 0..MAX_RECENT_VIEWS {
+        view_ids.push(create_view(&test.sdk, &test.app.id).await.id);
+    }
+    for view_id in &view_ids {
+        open_views(&test, &[view_id]).await;
+    }
+
+    let recent_view_ids = read_recent_view_ids(&test).await;
+    assert_eq!(recent_view_ids.len(), MAX_RECENT_VIEWS);
+    assert_eq!(recent_view_ids.first(), view_ids.last());
+    assert!(!recent_view_ids.contains(&test.view.id));
+}
+
+#[tokio::test]
+async fn view_recent_views_prune_trash() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let child = create_view(&test.sdk, &test.view.id).await.id;
+    let other = create_view(&test.sdk, &test.app.id).await.id;
+    open_views(&test, &[&test.view.id, &child, &other]).await;
+
+    // The trashed view leaves the history with the views that belong to it, and it
+    // doesn't come back when it's put back.
+    test.delete_views(vec![test.view.id.clone()]).await;
+    assert_eq!(read_recent_view_ids(&test).await, vec![other.clone()]);
+    putback_trash(
+        &test.sdk,
+        TrashIdentifier {
+            id: test.view.id.clone(),
+            ty: TrashType::View,
+        },
+    )
+    .await;
+    assert_eq!(read_recent_view_ids(&test).await, vec![other]);
+}
+
+fn create_view_request(belong_to_id: &str, name: &str, auto_rename: bool) -> CreateViewRequest {
+    CreateViewRequest {
+        belong_to_id: belong_to_id.to_owned(),
+        name: name.to_owned(),
+        desc: "".to_owned(),
+        thumbnail: None,
+        view_type: ViewType::Doc,
+        auto_rename,
+    }
+}
+
+#[tokio::test]
+async fn view_create_with_conflicting_name() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+    let _ = create_view_with_request(&test.sdk, create_view_request(&test.app.id, "Doc", false)).await;
+    let _ = create_view_with_request(&test.sdk, create_view_request(&test.app.id, "Doc (3)", false)).await;
+
+    let error = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(CreateView)
+        .request(create_view_request(&test.app.id, "Doc", false))
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::ViewNameConflict.value());
+    assert_eq!(error.suggestions, vec!["Doc (2)", "Doc (4)", "Doc (5)"]);
+
+    // The number of the name is replaced.
+    let view = create_view_with_request(&test.sdk, create_view_request(&test.app.id, "Doc (3)", true)).await;
+    assert_eq!(view.name, "Doc (2)");
+    let view = create_view_with_request(&test.sdk, create_view_request(&test.app.id, "Doc", true)).await;
+    assert_eq!(view.name, "Doc (4)");
+
+    // The views of other apps or views aren't siblings.
+    let view = create_view_with_request(&test.sdk, create_view_request(&test.view.id, "Doc", false)).await;
+    assert_eq!(view.name, "Doc");
+}
+
+#[tokio::test]
+async fn view_rename_with_conflicting_name() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+    let view = create_view_with_request(&test.sdk, create_view_request(&test.app.id, "Doc", false)).await;
+
+    let request = UpdateViewRequest {
+        view_id: test.view.id.clone(),
+        name: Some("Doc".to_owned()),
+        ..Default::default()
+    };
+    let error = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(UpdateView)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::ViewNameConflict.value());
+
+    // The view keeps its own name.
+    let request = UpdateViewRequest {
+        view_id: view.id.clone(),
+        name: Some("Doc".to_owned()),
+        ..Default::default()
+    };
+    update_view(&test.sdk, request).await;
+    let request = UpdateViewRequest {
+        view_id: test.view.id.clone(),
+        name: Some("Doc".to_owned()),
+        auto_rename: true,
+        ..Default::default()
+    };
+    update_view(&test.sdk, request).await;
+    let query = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    assert_eq!(read_view(&test.sdk, query).await.name, "Doc (2)");
+}
+
+#[tokio::test]
+async fn view_create_with_name_of_trashed_view() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+    let view = create_view_with_request(&test.sdk, create_view_request(&test.app.id, "Doc", false)).await;
+    test.delete_views(vec![view.id.clone()]).await;
+
+    let view = create_view_with_request(&test.sdk, create_view_request(&test.app.id, "Doc", false)).await;
+    assert_eq!(view.name, "Doc");
+}
+
+fn batch_view_operation(test: &ViewTest, operations: Vec<ViewOperation>) -> FlowyWorkspaceTest {
+    FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(BatchViewOperation)
+        .request(BatchViewOperationRequest { operations })
+}
+
+#[tokio::test]
+async fn view_batch_operation() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+    let app2 = create_app(&test.sdk, "App 2", "", &test.workspace.id).await;
+    let view2 = create_view_with_request(&test.sdk, create_view_request(&test.app.id, "Doc", false)).await;
+    let view3 = create_view_with_request(&test.sdk, create_view_request(&test.app.id, "Doc 3", false)).await;
+
+    let operations = vec![
+        ViewOperation::move_to(&test.view.id, &app2.id, None),
+        ViewOperation::rename(&view2.id, "Renamed"),
+        ViewOperation::delete(&view3.id),
+    ];
+    let changeset = batch_view_operation(&test, operations)
+        .async_send()
+        .await
+        .parse::<BatchViewChangeset>();
+    assert_eq!(changeset.updated_views.len(), 2);
+    assert_eq!(changeset.deleted_view_ids, vec![view3.id.clone()]);
+    let mut belong_to_ids = changeset
+        .belongings
+        .iter()
+        .map(|belongings| belongings.belong_to_id.clone())
+        .collect::<Vec<_>>();
+    belong_to_ids.sort();
+    let mut app_ids = vec![test.app.id.clone(), app2.id.clone()];
+    app_ids.sort();
+    assert_eq!(belong_to_ids, app_ids);
+
+    assert_eq!(read_app_view_ids(&test, &test.app.id).await, vec![view2.id.clone()]);
+    assert_eq!(read_app_view_ids(&test, &app2.id).await, vec![test.view.id.clone()]);
+    let query = QueryViewRequest {
+        view_ids: vec![view2.id.clone()],
+    };
+    assert_eq!(read_view(&test.sdk, query).await.name, "Renamed");
+    assert_eq!(read_trash(&test.sdk).await.len(), 1);
+}
+
+#[tokio::test]
+async fn view_batch_operation_is_all_or_nothing() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+    let app2 = create_app(&test.sdk, "App 2", "", &test.workspace.id).await;
+    let view2 = create_view_with_request(&test.sdk, create_view_request(&test.app.id, "Doc", false)).await;
+
+    // The view can't be moved once it's deleted by the batch.
+    let operations = vec![
+        ViewOperation::rename(&test.view.id, "Renamed"),
+        ViewOperation::delete(&view2.id),
+        ViewOperation::move_to(&view2.id, &app2.id, None),
+    ];
+    let error = batch_view_operation(&test, operations).async_send().await.error();
+    assert_eq!(error.code, ErrorCode::RecordNotFound.value());
+
+    assert_eq!(
+        read_app_view_ids(&test, &test.app.id).await,
+        vec![test.view.id.clone(), view2.id.clone()]
+    );
+    let query = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    assert_ne!(read_view(&test.sdk, query).await.name, "Renamed");
+    assert_eq!(read_trash(&test.sdk).await.len(), 0);
+
+    // The renamed views can't take the names of their siblings.
+    let operations = vec![
+        ViewOperation::rename(&view2.id, "Other"),
+        ViewOperation::rename(&test.view.id, "Other"),
+    ];
+    let error = batch_view_operation(&test, operations).async_send().await.error();
+    assert_eq!(error.code, ErrorCode::ViewNameConflict.value());
+    let query = QueryViewRequest {
+        view_ids: vec![view2.id.clone()],
+    };
+    assert_eq!(read_view(&test.sdk, query).await.name, "Doc");
+}
+
+async fn run_garbage_collection(test: &ViewTest) -> GarbageCollectionResult {
+    FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(RunGarbageCollection)
+        .async_send()
+        .await
+        .parse::<GarbageCollectionResult>()
+}
+
+#[tokio::test]
+async fn view_garbage_collection_removes_orphaned_views() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+    let child = create_view(&test.sdk, &test.view.id).await;
+    let _ = open_view(
+        &test.sdk,
+        QueryViewRequest {
+            view_ids: vec![child.id.clone()],
+        },
+    )
+    .await;
+
+    // The views are left behind once their app is deleted from the trash.
+    delete_app(&test.sdk, &test.app.id).await;
+    let _ = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(DeleteAll)
+        .async_send()
+        .await;
+
+    let mut result = run_garbage_collection(&test).await;
+    result.view_ids.sort();
+    let mut view_ids = vec![test.view.id.clone(), child.id.clone()];
+    view_ids.sort();
+    assert_eq!(result.view_ids, view_ids);
+    assert!(result.doc_ids.contains(&child.id));
+
+    // There is nothing left to collect.
+    let result = run_garbage_collection(&test).await;
+    assert!(result.view_ids.is_empty());
+    assert!(result.doc_ids.is_empty());
+}
+
+#[tokio::test]
+async fn view_garbage_collection_removes_unreferenced_blobs() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+    let asset = insert_image(&test, &test.view.id, vec![1, 2, 3]).await;
+    let stray_path = std::path::Path::new(&asset.path).with_file_name(uuid());
+    std::fs::write(&stray_path, vec![0; 8]).unwrap();
+
+    let result = run_garbage_collection(&test).await;
+    assert!(result.view_ids.is_empty());
+    assert!(!result.doc_ids.contains(&test.view.id));
+    assert_eq!(result.blob_count, 1);
+    assert_eq!(result.blob_size, 8);
+    assert!(!stray_path.exists());
+    assert!(std::path::Path::new(&asset.path).exists());
+}
+
+#[tokio::test]
+async fn view_delete_permanent_removes_doc_assets() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+    let asset = insert_image(&test, &test.view.id, vec![1, 2, 3]).await;
+    assert!(std::path::Path::new(&asset.path).exists());
+
+    test.delete_views_permanent(vec![test.view.id.clone()]).await;
+    assert!(!std::path::Path::new(&asset.path).exists());
+}
+
+#[tokio::test]
+async fn view_create_concurrently() {
+    // Less connections than writers, they wait for each other.
+    let config = FlowySDKConfig::new(&root_dir(), ServerConfig::default(), &uuid())
+        .secret_store(SecretStoreKind::File)
+        .db_pool(PoolConfig::default().max_size(2));
+    let test = FlowyTest::setup_with_config(config);
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+
+    let handles = (0..10)
+        .map(|_| {
+            let sdk = test.sdk.clone();
+            let app_id = test.app.id.clone();
+            tokio::spawn(async move { create_view(&sdk, &app_id).await })
+        })
+        .collect::<Vec<_>>();
+    let mut view_ids = vec![test.view.id.clone()];
+    for handle in handles {
+        view_ids.push(handle.await.unwrap().id);
+    }
+
+    let mut belongings = read_app_view_ids(&test, &test.app.id).await;
+    belongings.sort();
+    view_ids.sort();
+    assert_eq!(belongings, view_ids);
+}
@@ -1,9 +1,29 @@
-use flowy_test::{builder::*, workspace::*, FlowyTest};
+use bytes::Bytes;
+use flowy_document::{
+    entities::asset::{AssetData, AssetIdentifier, CreateAssetRequest, DocAsset},
+    event::DocumentEvent::{AttachAsset, ReadAsset},
+    services::asset::referenced_assets,
+};
+use flowy_document_infra::{
+    entities::doc::DocDelta,
+    user_default::{doc_initial_delta, initial_read_me},
+};
+use flowy_infra::uuid;
+use flowy_ot::core::{Attribute, Delta, DeltaBuilder, OperationTransformable};
+use flowy_test::{builder::*, workspace::*, FlowyTest, FlowyTestSDK};
 use flowy_workspace::{
-    entities::workspace::{CreateWorkspaceRequest, QueryWorkspaceRequest},
+    entities::{
+        app::QueryAppRequest,
+        share::*,
+        template::{CreateWorkspaceFromTemplateRequest, RepeatedWorkspaceTemplate},
+        view::{CreateViewRequest, QueryViewRequest, UpdateViewRequest, ViewType},
+        workspace::{CreateWorkspaceRequest, QueryWorkspaceRequest, UpdateWorkspaceRequest},
+    },
+    errors::ErrorCode,
     event::WorkspaceEvent::*,
     prelude::*,
 };
+use std::convert::TryInto;
 
 #[tokio::test]
 async fn workspace_read_all() {
@@ -12,6 +32,30 @@ async fn workspace_read_all() {
     assert_eq!(workspace.len(), 2);
 }
 
+#[tokio::test]
+async fn workspace_default_created_after_sign_up() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let workspaces = read_workspace(&test.sdk, QueryWorkspaceRequest::new(None)).await;
+    assert_eq!(workspaces.len(), 1);
+
+    let workspace = read_workspace(&test.sdk, QueryWorkspaceRequest::new(Some(workspaces[0].id.clone())))
+        .await
+        .pop()
+        .unwrap();
+    let app = &workspace.apps[0];
+    let query = QueryAppRequest {
+        app_ids: vec![app.id.clone()],
+    };
+    let app = read_app(&test.sdk, query).await;
+    let query = QueryViewRequest {
+        view_ids: vec![app.belongings[0].id.clone()],
+    };
+    let doc = open_view(&test.sdk, query).await;
+    let read_me = doc_initial_delta().compose(&initial_read_me()).unwrap();
+    assert_eq!(Delta::from_json(&doc.data).unwrap(), read_me);
+}
+
 #[tokio::test]
 async fn workspace_read() {
     let test = WorkspaceTest::new().await;
@@ -81,4 +125,351 @@ async fn workspace_update_with_invalid_name() {
     }
 }
 
-// TODO 1) delete workspace, but can't delete the last workspace
+#[tokio::test]
+async fn workspace_update() {
+    let test = WorkspaceTest::new().await;
+    let request = UpdateWorkspaceRequest {
+        id: test.workspace.id.clone(),
+        name: Some("Renamed".to_owned()),
+        desc: Some("New desc".to_owned()),
+    };
+    let _ = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(UpdateWorkspace)
+        .request(request)
+        .async_send()
+        .await;
+
+    let request = QueryWorkspaceRequest::new(Some(test.workspace.id.clone()));
+    let workspace = read_workspace(&test.sdk, request).await.pop().unwrap();
+    assert_eq!(workspace.name, "Renamed");
+    assert_eq!(workspace.desc, "New desc");
+}
+
+async fn delete_workspace(sdk: &FlowyTestSDK, workspace_id: &str) -> FlowyWorkspaceTest {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(DeleteWorkspace)
+        .request(QueryWorkspaceRequest::new(Some(workspace_id.to_owned())))
+        .async_send()
+        .await
+}
+
+#[tokio::test]
+async fn workspace_delete_with_apps_and_views() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+    let _ = delete_workspace(&test.sdk, &test.workspace.id).await;
+
+    let workspaces = read_workspace(&test.sdk, QueryWorkspaceRequest::new(None)).await;
+    assert!(workspaces.iter().all(|workspace| workspace.id != test.workspace.id));
+
+    // The view is deleted with the app of the workspace.
+    let _ = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ReadView)
+        .request(QueryViewRequest {
+            view_ids: vec![test.view.id.clone()],
+        })
+        .async_send()
+        .await
+        .error();
+
+    // The workspace that's left is opened.
+    let setting = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ReadCurWorkspace)
+        .async_send()
+        .await
+        .parse::<CurrentWorkspaceSetting>();
+    assert_eq!(setting.workspace.id, workspaces[0].id);
+}
+
+#[tokio::test]
+async fn workspace_delete_last_one() {
+    let test = WorkspaceTest::new().await;
+    let _ = delete_workspace(&test.sdk, &test.workspace.id).await;
+
+    let workspaces = read_workspace(&test.sdk, QueryWorkspaceRequest::new(None)).await;
+    assert_eq!(workspaces.len(), 1);
+    let error = delete_workspace(&test.sdk, &workspaces[0].id).await.error();
+    assert_eq!(error.code, ErrorCode::WorkspaceLastOne.value());
+}
+
+async fn export_workspace(sdk: &FlowyTestSDK, workspace_id: &str) -> ExportData {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(ExportWorkspace)
+        .request(ExportWorkspaceRequest {
+            workspace_id: workspace_id.to_owned(),
+        })
+        .async_send()
+        .await
+        .parse::<ExportData>()
+}
+
+async fn import_workspace(sdk: &FlowyTestSDK, path: &str, name: Option<&str>) -> FlowyWorkspaceTest {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(ImportWorkspace)
+        .request(ImportWorkspaceRequest {
+            path: path.to_owned(),
+            name: name.map(|name| name.to_owned()),
+        })
+        .async_send()
+        .await
+}
+
+async fn read_view_delta(sdk: &FlowyTestSDK, view_id: &str) -> Delta {
+    let request = QueryViewRequest {
+        view_ids: vec![view_id.to_owned()],
+    };
+    let doc = open_view(sdk, request).await;
+    Delta::from_json(&doc.data).unwrap()
+}
+
+fn write_archive(archive: WorkspaceArchive) -> String {
+    let bytes: Bytes = archive.try_into().unwrap();
+    let path = std::env::temp_dir().join(format!("{}.flowy", uuid()));
+    std::fs::write(&path, bytes).unwrap();
+    path.to_string_lossy().to_string()
+}
+
+#[tokio::test]
+async fn workspace_export_then_import() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+    let child_view = create_view_with_request(
+        &test.sdk,
+        CreateViewRequest {
+            belong_to_id: test.view.id.clone(),
+            name: "Child".to_owned(),
+            desc: "".to_owned(),
+            thumbnail: None,
+            view_type: ViewType::Doc,
+            auto_rename: false,
+        },
+    )
+    .await;
+
+    let asset = DocTest::new(test.sdk.clone())
+        .event(AttachAsset)
+        .request(CreateAssetRequest {
+            doc_id: test.view.id.clone(),
+            name: "image.png".to_owned(),
+            mime_type: "image/png".to_owned(),
+            data: vec![1, 2, 3],
+        })
+        .async_send()
+        .await
+        .parse::<DocAsset>();
+    let len = read_view_delta(&test.sdk, &test.view.id).await.target_len;
+    let delta = DeltaBuilder::new()
+        .insert("Photo ")
+        .insert_with_attributes("image", Attribute::Link(&asset.url).into())
+        .retain(len)
+        .build();
+    let _ = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ApplyDocDelta)
+        .request(DocDelta {
+            doc_id: test.view.id.clone(),
+            data: delta.to_json(),
+        })
+        .async_send()
+        .await;
+    let request = UpdateViewRequest {
+        view_id: test.view.id.clone(),
+        icon: Some("📦".to_owned()),
+        cover: Some(asset.id.clone()),
+        ..Default::default()
+    };
+    update_view(&test.sdk, request).await;
+
+    let export_data = export_workspace(&test.sdk, &test.workspace.id).await;
+    assert_eq!(export_data.export_type, ExportType::Archive);
+    let result = import_workspace(&test.sdk, &export_data.path, Some("Imported"))
+        .await
+        .parse::<ImportWorkspaceResult>();
+    assert!(result.failures.is_empty());
+    assert_eq!(result.workspace.name, "Imported");
+    assert_ne!(result.workspace.id, test.workspace.id);
+
+    let app = result.workspace.apps.first_or_crash();
+    assert_eq!(app.name, test.app.name);
+    let app = read_app(
+        &test.sdk,
+        QueryAppRequest {
+            app_ids: vec![app.id.clone()],
+        },
+    )
+    .await;
+    let view = app.belongings.first_or_crash();
+    assert_ne!(view.id, test.view.id);
+    assert_eq!(view.name, test.view.name);
+    assert_eq!(view.icon, "📦");
+    assert!(!view.cover.is_empty());
+    assert_ne!(view.cover, asset.id);
+
+    // The view keeps its child and the link points to the copy of the asset.
+    let parent_view = read_view(
+        &test.sdk,
+        QueryViewRequest {
+            view_ids: vec![view.id.clone()],
+        },
+    )
+    .await;
+    let child = parent_view.belongings.first_or_crash();
+    assert_ne!(child.id, child_view.id);
+    assert_eq!(child.name, "Child");
+
+    let delta = read_view_delta(&test.sdk, &view.id).await;
+    assert!(delta.apply("").unwrap().starts_with("Photo image"));
+    let asset_ids = referenced_assets(&delta);
+    assert_eq!(asset_ids.len(), 1);
+    assert!(!asset_ids.contains(&asset.id));
+    let asset_data = DocTest::new(test.sdk.clone())
+        .event(ReadAsset)
+        .request(AssetIdentifier {
+            asset_id: asset_ids.into_iter().next().unwrap(),
+        })
+        .async_send()
+        .await
+        .parse::<AssetData>();
+    assert_eq!(asset_data.data, vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn workspace_import_with_failures() {
+    let test = WorkspaceTest::new().await;
+    let path = write_archive(WorkspaceArchive {
+        version: WORKSPACE_ARCHIVE_VERSION,
+        name: "Archived".to_owned(),
+        desc: "".to_owned(),
+        apps: vec![ArchivedApp {
+            id: "app_1".to_owned(),
+            name: "App".to_owned(),
+            desc: "".to_owned(),
+        }],
+        views: vec![
+            ArchivedView {
+                id: "view_1".to_owned(),
+                belong_to_id: "app_1".to_owned(),
+                name: "View".to_owned(),
+                view_type: ViewType::Doc,
+                data: DeltaBuilder::new().insert("Hello\n").build().to_json(),
+                ..Default::default()
+            },
+            ArchivedView {
+                id: "view_2".to_owned(),
+                belong_to_id: "unknown app".to_owned(),
+                name: "Orphan".to_owned(),
+                view_type: ViewType::Doc,
+                data: DeltaBuilder::new().insert("\n").build().to_json(),
+                ..Default::default()
+            },
+        ],
+        assets: vec![],
+    });
+
+    let result = import_workspace(&test.sdk, &path, None)
+        .await
+        .parse::<ImportWorkspaceResult>();
+    assert_eq!(result.workspace.name, "Archived");
+    assert_eq!(result.failures.len(), 1);
+    assert_eq!(result.failures[0].id, "view_2");
+
+    let app = read_app(
+        &test.sdk,
+        QueryAppRequest {
+            app_ids: vec![result.workspace.apps.first_or_crash().id.clone()],
+        },
+    )
+    .await;
+    let view = app.belongings.first_or_crash();
+    assert_eq!(view.name, "View");
+    assert_eq!(read_view_delta(&test.sdk, &view.id).await.apply("").unwrap(), "Hello\n");
+}
+
+#[tokio::test]
+async fn workspace_import_invalid_archive() {
+    let test = WorkspaceTest::new().await;
+    let path = std::env::temp_dir().join(format!("{}.flowy", uuid()));
+    std::fs::write(&path, "not an archive").unwrap();
+
+    let error = import_workspace(&test.sdk, &path.to_string_lossy(), None).await.error();
+    assert_eq!(error.code, ErrorCode::WorkspaceArchiveInvalid.value());
+
+    let error = import_workspace(&test.sdk, "", None).await.error();
+    assert_eq!(error.code, ErrorCode::WorkspaceArchiveInvalid.value());
+}
+
+async fn create_workspace_from_template(
+    sdk: &FlowyTestSDK,
+    template_id: &str,
+    name: Option<&str>,
+) -> FlowyWorkspaceTest {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(CreateWorkspaceFromTemplate)
+        .request(CreateWorkspaceFromTemplateRequest {
+            template_id: template_id.to_owned(),
+            name: name.map(|name| name.to_owned()),
+        })
+        .async_send()
+        .await
+}
+
+#[tokio::test]
+async fn workspace_create_from_template() {
+    let test = WorkspaceTest::new().await;
+    let templates = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(ReadWorkspaceTemplates)
+        .async_send()
+        .await
+        .parse::<RepeatedWorkspaceTemplate>();
+    let template = templates
+        .iter()
+        .find(|template| template.name == "Team wiki")
+        .unwrap()
+        .clone();
+
+    let workspace = create_workspace_from_template(&test.sdk, &template.id, None)
+        .await
+        .parse::<Workspace>();
+    assert_eq!(workspace.name, "Team wiki");
+    let app_names = workspace.apps.iter().map(|app| app.name.clone()).collect::<Vec<_>>();
+    let template_app_names = template.apps.iter().map(|app| app.name.clone()).collect::<Vec<_>>();
+    assert_eq!(app_names, template_app_names);
+
+    let app = read_app(
+        &test.sdk,
+        QueryAppRequest {
+            app_ids: vec![workspace.apps.first_or_crash().id.clone()],
+        },
+    )
+    .await;
+    let view_names = app.belongings.iter().map(|view| view.name.clone()).collect::<Vec<_>>();
+    assert_eq!(view_names, vec!["Home".to_owned(), "Onboarding".to_owned()]);
+
+    // The title of the seed document is the name of the view.
+    let view = app.belongings.first_or_crash();
+    let text = read_view_delta(&test.sdk, &view.id).await.apply("").unwrap();
+    assert!(text.starts_with("Home\n"));
+}
+
+#[tokio::test]
+async fn workspace_create_from_template_with_name() {
+    let test = WorkspaceTest::new().await;
+    let workspace = create_workspace_from_template(&test.sdk, "built_in_personal_tasks", Some("Mine"))
+        .await
+        .parse::<Workspace>();
+    assert_eq!(workspace.name, "Mine");
+    assert_eq!(workspace.apps.len(), 2);
+}
+
+#[tokio::test]
+async fn workspace_create_from_template_not_exist() {
+    let test = WorkspaceTest::new().await;
+    let error = create_workspace_from_template(&test.sdk, "unknown", None).await.error();
+    assert_eq!(error.code, ErrorCode::RecordNotFound.value());
+
+    let error = create_workspace_from_template(&test.sdk, "built_in_personal_tasks", Some(""))
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::WorkspaceNameInvalid.value());
+}
@@ -0,0 +1,45 @@
+use flowy_infra::retry::jitter;
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct WsConfig {
+    // The delay before the first attempt to connect again once the connection is
+    // lost. It doubles after each failed attempt, up to the max_reconnect_delay.
+    pub reconnect_delay: Duration,
+    pub max_reconnect_delay: Duration,
+    // It's connected again until it succeeds when it's None.
+    pub max_reconnect_attempts: Option<usize>,
+    // How often the server is pinged while connected.
+    pub ping_interval: Duration,
+    // The connection is dropped and connected again when nothing was received from
+    // the server for this long, not even the pongs.
+    pub pong_timeout: Duration,
+}
+
+impl std::default::Default for WsConfig {
+    fn default() -> Self {
+        WsConfig {
+            reconnect_delay: Duration::from_secs(1),
+            max_reconnect_delay: Duration::from_secs(60),
+            max_reconnect_attempts: None,
+            ping_interval: Duration::from_secs(10),
+            pong_timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+impl WsConfig {
+    // The delays before the attempts to connect again. Each one is shortened by up
+    // to half at random, so the clients that lost the connection together don't
+    // connect again together.
+    pub fn backoff(&self) -> impl Iterator<Item = Duration> {
+        let (delay, max_delay) = (self.reconnect_delay, self.max_reconnect_delay);
+        (0..).map(move |attempt: u32| {
+            let delay = delay
+                .checked_mul(2u32.saturating_pow(attempt))
+                .unwrap_or(max_delay)
+                .min(max_delay);
+            delay / 2 + jitter(delay / 2)
+        })
+    }
+}
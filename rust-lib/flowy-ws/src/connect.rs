@@ -163,6 +163,8 @@ impl Future for WsStream {
 fn post_message(tx: MsgSender, message: Result<Message, Error>) -> Result<(), WsError> {
     match message {
         Ok(Message::Binary(bytes)) => tx.unbounded_send(Message::Binary(bytes)).map_err(internal_error),
+        // The pongs tell that the connection is alive, see WsConfig::pong_timeout.
+        Ok(Message::Pong(bytes)) => tx.unbounded_send(Message::Pong(bytes)).map_err(internal_error),
         Ok(_) => Ok(()),
         Err(e) => Err(WsError::internal().context(e)),
     }
@@ -0,0 +1,143 @@
+use crate::NetworkStateType;
+use futures_core::future::BoxFuture;
+use parking_lot::Mutex;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::{net::TcpStream, sync::broadcast, time::timeout};
+
+#[derive(Debug, Clone)]
+pub struct ConnectivityConfig {
+    // The host:port that's connected to, to know if the network is reachable. It
+    // isn't probed when it's None, the network is assumed to be reachable.
+    pub endpoint: Option<String>,
+    pub probe_interval: Duration,
+    // The probe fails if it isn't connected within this long.
+    pub probe_timeout: Duration,
+    // How many probes in a row must disagree with the current state before it
+    // changes, so a flapping network isn't reported on each probe.
+    pub confirmations: usize,
+}
+
+impl std::default::Default for ConnectivityConfig {
+    fn default() -> Self {
+        ConnectivityConfig {
+            endpoint: None,
+            probe_interval: Duration::from_secs(30),
+            probe_timeout: Duration::from_secs(5),
+            confirmations: 2,
+        }
+    }
+}
+
+struct Reachability {
+    state: NetworkStateType,
+    // The probes in a row that disagreed with the state.
+    disagreements: usize,
+}
+
+// Whether the network is reachable. The sync and the token refresh are skipped
+// while it's offline, so they don't keep sending requests that can't succeed.
+pub struct Connectivity {
+    config: ConnectivityConfig,
+    reachability: Mutex<Reachability>,
+    state_notify: broadcast::Sender<NetworkStateType>,
+    // Only the host knows it, it isn't probed.
+    metered: AtomicBool,
+    metered_notify: broadcast::Sender<bool>,
+}
+
+impl Connectivity {
+    pub fn new(config: ConnectivityConfig) -> Self {
+        let (state_notify, _) = broadcast::channel(10);
+        let (metered_notify, _) = broadcast::channel(10);
+        Self {
+            config,
+            reachability: Mutex::new(Reachability {
+                state: NetworkStateType::Online,
+                disagreements: 0,
+            }),
+            state_notify,
+            metered: AtomicBool::new(false),
+            metered_notify,
+        }
+    }
+
+    pub fn state(&self) -> NetworkStateType { self.reachability.lock().state }
+
+    pub fn is_online(&self) -> bool { self.state() == NetworkStateType::Online }
+
+    // Only the changes of the state are sent.
+    pub fn state_subscribe(&self) -> broadcast::Receiver<NetworkStateType> { self.state_notify.subscribe() }
+
+    pub fn is_metered(&self) -> bool { self.metered.load(Ordering::SeqCst) }
+
+    // Only the changes are sent.
+    pub fn metered_subscribe(&self) -> broadcast::Receiver<bool> { self.metered_notify.subscribe() }
+
+    // Returns true if it changed.
+    pub fn set_metered(&self, metered: bool) -> bool {
+        if self.metered.swap(metered, Ordering::SeqCst) == metered {
+            return false;
+        }
+        tracing::info!("Network metered: {}", metered);
+        let _ = self.metered_notify.send(metered);
+        true
+    }
+
+    // Records the result of a probe. The platform can report the changes it
+    // detects too. Returns true if the state changed.
+    pub fn report(&self, reachable: bool) -> bool {
+        let observed = match reachable {
+            true => NetworkStateType::Online,
+            false => NetworkStateType::Offline,
+        };
+        let mut reachability = self.reachability.lock();
+        if observed == reachability.state {
+            reachability.disagreements = 0;
+            return false;
+        }
+
+        reachability.disagreements += 1;
+        if reachability.disagreements < self.config.confirmations.max(1) {
+            return false;
+        }
+        reachability.state = observed;
+        reachability.disagreements = 0;
+        drop(reachability);
+
+        tracing::info!("Network state changed: {:?}", observed);
+        let _ = self.state_notify.send(observed);
+        true
+    }
+
+    // Returns true if the endpoint could be connected to, or if there's none.
+    pub async fn probe(&self) -> bool {
+        match &self.config.endpoint {
+            None => true,
+            Some(endpoint) => matches!(
+                timeout(self.config.probe_timeout, TcpStream::connect(endpoint.as_str())).await,
+                Ok(Ok(_))
+            ),
+        }
+    }
+
+    // Probes the endpoint periodically, it returns right away if there's none.
+    pub fn run(self: Arc<Self>) -> BoxFuture<'static, ()> {
+        Box::pin(async move {
+            if self.config.endpoint.is_none() {
+                return;
+            }
+            let mut interval = tokio::time::interval(self.config.probe_interval);
+            loop {
+                interval.tick().await;
+                let reachable = self.probe().await;
+                self.report(reachable);
+            }
+        })
+    }
+}
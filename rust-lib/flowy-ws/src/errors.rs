@@ -43,6 +43,8 @@ impl WsError {
     static_user_error!(internal, ErrorCode::InternalError);
     static_user_error!(unsupported_message, ErrorCode::UnsupportedMessage);
     static_user_error!(unauthorized, ErrorCode::Unauthorized);
+    static_user_error!(connection_closed, ErrorCode::ConnectionClosed);
+    static_user_error!(ping_timeout, ErrorCode::PingTimeout);
 }
 
 pub fn internal_error<T>(e: T) -> WsError
@@ -57,6 +59,8 @@ pub enum ErrorCode {
     InternalError      = 0,
     UnsupportedMessage = 1,
     Unauthorized       = 2,
+    ConnectionClosed   = 3,
+    PingTimeout        = 4,
 }
 
 impl std::default::Default for ErrorCode {
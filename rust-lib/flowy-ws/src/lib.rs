@@ -1,8 +1,14 @@
+mod config;
 pub mod connect;
+mod connectivity;
 pub mod errors;
 mod msg;
 pub mod protobuf;
+mod state;
 mod ws;
 
+pub use config::*;
+pub use connectivity::*;
 pub use msg::*;
+pub use state::*;
 pub use ws::*;
@@ -11,11 +11,18 @@ pub struct WsMessage {
 
     #[pb(index = 2)]
     pub data: Vec<u8>,
+
+    // The object the message is about, e.g. the id of the document. The messages
+    // with an object id are passed to the channel of the object if it's
+    // subscribed, to the handler of the module otherwise.
+    #[pb(index = 3)]
+    pub object_id: String,
 }
 
 #[derive(ProtoBuf_Enum, Debug, Clone, Eq, PartialEq, Hash)]
 pub enum WsModule {
-    Doc = 0,
+    Doc       = 0,
+    Workspace = 1,
 }
 
 impl std::default::Default for WsModule {
@@ -26,6 +33,7 @@ impl ToString for WsModule {
     fn to_string(&self) -> String {
         match self {
             WsModule::Doc => "0".to_string(),
+            WsModule::Workspace => "1".to_string(),
         }
     }
 }
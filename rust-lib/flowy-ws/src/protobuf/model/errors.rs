@@ -218,6 +218,8 @@ pub enum ErrorCode {
     InternalError = 0,
     UnsupportedMessage = 1,
     Unauthorized = 2,
+    ConnectionClosed = 3,
+    PingTimeout = 4,
 }
 
 impl ::protobuf::ProtobufEnum for ErrorCode {
@@ -230,6 +232,8 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             0 => ::std::option::Option::Some(ErrorCode::InternalError),
             1 => ::std::option::Option::Some(ErrorCode::UnsupportedMessage),
             2 => ::std::option::Option::Some(ErrorCode::Unauthorized),
+            3 => ::std::option::Option::Some(ErrorCode::ConnectionClosed),
+            4 => ::std::option::Option::Some(ErrorCode::PingTimeout),
             _ => ::std::option::Option::None
         }
     }
@@ -239,6 +243,8 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             ErrorCode::InternalError,
             ErrorCode::UnsupportedMessage,
             ErrorCode::Unauthorized,
+            ErrorCode::ConnectionClosed,
+            ErrorCode::PingTimeout,
         ];
         values
     }
@@ -267,25 +273,12 @@ impl ::protobuf::reflect::ProtobufValue for ErrorCode {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0cerrors.proto\";\n\x07WsError\x12\x1e\n\x04code\x18\x01\x20\x01(\
-    \x0e2\n.ErrorCodeR\x04code\x12\x10\n\x03msg\x18\x02\x20\x01(\tR\x03msg*H\
-    \n\tErrorCode\x12\x11\n\rInternalError\x10\0\x12\x16\n\x12UnsupportedMes\
-    sage\x10\x01\x12\x10\n\x0cUnauthorized\x10\x02J\xab\x02\n\x06\x12\x04\0\
-    \0\n\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\
-    \x05\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x0f\n\x0b\n\x04\x04\0\x02\0\
-    \x12\x03\x03\x04\x17\n\x0c\n\x05\x04\0\x02\0\x06\x12\x03\x03\x04\r\n\x0c\
-    \n\x05\x04\0\x02\0\x01\x12\x03\x03\x0e\x12\n\x0c\n\x05\x04\0\x02\0\x03\
-    \x12\x03\x03\x15\x16\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x13\n\x0c\
-    \n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\x01\x01\
-    \x12\x03\x04\x0b\x0e\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x11\x12\n\
-    \n\n\x02\x05\0\x12\x04\x06\0\n\x01\n\n\n\x03\x05\0\x01\x12\x03\x06\x05\
-    \x0e\n\x0b\n\x04\x05\0\x02\0\x12\x03\x07\x04\x16\n\x0c\n\x05\x05\0\x02\0\
-    \x01\x12\x03\x07\x04\x11\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x07\x14\x15\
-    \n\x0b\n\x04\x05\0\x02\x01\x12\x03\x08\x04\x1b\n\x0c\n\x05\x05\0\x02\x01\
-    \x01\x12\x03\x08\x04\x16\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x08\x19\
-    \x1a\n\x0b\n\x04\x05\0\x02\x02\x12\x03\t\x04\x15\n\x0c\n\x05\x05\0\x02\
-    \x02\x01\x12\x03\t\x04\x10\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\t\x13\
-    \x14b\x06proto3\
+    \n\x0cerrors.proto\"A\n\x07WsError\x12\x20\n\x04code\x18\x01\x20\x01(\
+    \x0e2\n.ErrorCodeR\x04codeB\0\x12\x12\n\x03msg\x18\x02\x20\x01(\tR\x03ms\
+    gB\0:\0*q\n\tErrorCode\x12\x11\n\rInternalError\x10\0\x12\x16\n\x12Unsup\
+    portedMessage\x10\x01\x12\x10\n\x0cUnauthorized\x10\x02\x12\x14\n\x10Con\
+    nectionClosed\x10\x03\x12\x0f\n\x0bPingTimeout\x10\x04\x1a\0B\0b\x06prot\
+    o3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -5,3 +5,6 @@ pub use errors::*;
 
 mod msg; 
 pub use msg::*; 
+
+mod state; 
+pub use state::*; 
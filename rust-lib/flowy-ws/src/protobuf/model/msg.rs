@@ -28,6 +28,7 @@ pub struct WsMessage {
     // message fields
     pub module: WsModule,
     pub data: ::std::vec::Vec<u8>,
+    pub object_id: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -84,6 +85,32 @@ impl WsMessage {
     pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
         ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
     }
+
+    // string object_id = 3;
+
+
+    pub fn get_object_id(&self) -> &str {
+        &self.object_id
+    }
+    pub fn clear_object_id(&mut self) {
+        self.object_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object_id(&mut self, v: ::std::string::String) {
+        self.object_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_object_id(&mut self) -> &mut ::std::string::String {
+        &mut self.object_id
+    }
+
+    // Take field
+    pub fn take_object_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.object_id, ::std::string::String::new())
+    }
 }
 
 impl ::protobuf::Message for WsMessage {
@@ -101,6 +128,9 @@ impl ::protobuf::Message for WsMessage {
                 2 => {
                     ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
                 },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.object_id)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -119,6 +149,9 @@ impl ::protobuf::Message for WsMessage {
         if !self.data.is_empty() {
             my_size += ::protobuf::rt::bytes_size(2, &self.data);
         }
+        if !self.object_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.object_id);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -131,6 +164,9 @@ impl ::protobuf::Message for WsMessage {
         if !self.data.is_empty() {
             os.write_bytes(2, &self.data)?;
         }
+        if !self.object_id.is_empty() {
+            os.write_string(3, &self.object_id)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -179,6 +215,11 @@ impl ::protobuf::Message for WsMessage {
                 |m: &WsMessage| { &m.data },
                 |m: &mut WsMessage| { &mut m.data },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "object_id",
+                |m: &WsMessage| { &m.object_id },
+                |m: &mut WsMessage| { &mut m.object_id },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<WsMessage>(
                 "WsMessage",
                 fields,
@@ -197,6 +238,7 @@ impl ::protobuf::Clear for WsMessage {
     fn clear(&mut self) {
         self.module = WsModule::Doc;
         self.data.clear();
+        self.object_id.clear();
         self.unknown_fields.clear();
     }
 }
@@ -216,6 +258,7 @@ impl ::protobuf::reflect::ProtobufValue for WsMessage {
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum WsModule {
     Doc = 0,
+    Workspace = 1,
 }
 
 impl ::protobuf::ProtobufEnum for WsModule {
@@ -226,6 +269,7 @@ impl ::protobuf::ProtobufEnum for WsModule {
     fn from_i32(value: i32) -> ::std::option::Option<WsModule> {
         match value {
             0 => ::std::option::Option::Some(WsModule::Doc),
+            1 => ::std::option::Option::Some(WsModule::Workspace),
             _ => ::std::option::Option::None
         }
     }
@@ -233,6 +277,7 @@ impl ::protobuf::ProtobufEnum for WsModule {
     fn values() -> &'static [Self] {
         static values: &'static [WsModule] = &[
             WsModule::Doc,
+            WsModule::Workspace,
         ];
         values
     }
@@ -261,20 +306,11 @@ impl ::protobuf::reflect::ProtobufValue for WsModule {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\tmsg.proto\"B\n\tWsMessage\x12!\n\x06module\x18\x01\x20\x01(\x0e2\t.W\
-    sModuleR\x06module\x12\x12\n\x04data\x18\x02\x20\x01(\x0cR\x04data*\x13\
-    \n\x08WsModule\x12\x07\n\x03Doc\x10\0J\xd9\x01\n\x06\x12\x04\0\0\x08\x01\
-    \n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x05\x01\n\
-    \n\n\x03\x04\0\x01\x12\x03\x02\x08\x11\n\x0b\n\x04\x04\0\x02\0\x12\x03\
-    \x03\x04\x18\n\x0c\n\x05\x04\0\x02\0\x06\x12\x03\x03\x04\x0c\n\x0c\n\x05\
-    \x04\0\x02\0\x01\x12\x03\x03\r\x13\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\
-    \x03\x16\x17\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x13\n\x0c\n\x05\
-    \x04\0\x02\x01\x05\x12\x03\x04\x04\t\n\x0c\n\x05\x04\0\x02\x01\x01\x12\
-    \x03\x04\n\x0e\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x11\x12\n\n\n\
-    \x02\x05\0\x12\x04\x06\0\x08\x01\n\n\n\x03\x05\0\x01\x12\x03\x06\x05\r\n\
-    \x0b\n\x04\x05\0\x02\0\x12\x03\x07\x04\x0c\n\x0c\n\x05\x05\0\x02\0\x01\
-    \x12\x03\x07\x04\x07\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x07\n\x0bb\x06p\
-    roto3\
+    \n\tmsg.proto\"g\n\tWsMessage\x12#\n\x06module\x18\x01\x20\x01(\x0e2\t.W\
+    sModuleR\x06moduleB\0\x12\x14\n\x04data\x18\x02\x20\x01(\x0cR\x04dataB\0\
+    \x12\x1d\n\tobject_id\x18\x03\x20\x01(\tR\x08objectIdB\0:\0*$\n\x08WsMod\
+    ule\x12\x07\n\x03Doc\x10\0\x12\r\n\tWorkspace\x10\x01\x1a\0B\0b\x06proto\
+    3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,598 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `state.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WsConnectState {
+    // message fields
+    pub ty: WsConnectStateType,
+    pub attempt: i64,
+    pub delay: i64,
+    pub msg: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WsConnectState {
+    fn default() -> &'a WsConnectState {
+        <WsConnectState as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WsConnectState {
+    pub fn new() -> WsConnectState {
+        ::std::default::Default::default()
+    }
+
+    // .WsConnectStateType ty = 1;
+
+
+    pub fn get_ty(&self) -> WsConnectStateType {
+        self.ty
+    }
+    pub fn clear_ty(&mut self) {
+        self.ty = WsConnectStateType::Init;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ty(&mut self, v: WsConnectStateType) {
+        self.ty = v;
+    }
+
+    // int64 attempt = 2;
+
+
+    pub fn get_attempt(&self) -> i64 {
+        self.attempt
+    }
+    pub fn clear_attempt(&mut self) {
+        self.attempt = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attempt(&mut self, v: i64) {
+        self.attempt = v;
+    }
+
+    // int64 delay = 3;
+
+
+    pub fn get_delay(&self) -> i64 {
+        self.delay
+    }
+    pub fn clear_delay(&mut self) {
+        self.delay = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_delay(&mut self, v: i64) {
+        self.delay = v;
+    }
+
+    // string msg = 4;
+
+
+    pub fn get_msg(&self) -> &str {
+        &self.msg
+    }
+    pub fn clear_msg(&mut self) {
+        self.msg.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_msg(&mut self, v: ::std::string::String) {
+        self.msg = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_msg(&mut self) -> &mut ::std::string::String {
+        &mut self.msg
+    }
+
+    // Take field
+    pub fn take_msg(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.msg, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for WsConnectState {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.ty, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.attempt = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.delay = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.msg)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.ty != WsConnectStateType::Init {
+            my_size += ::protobuf::rt::enum_size(1, self.ty);
+        }
+        if self.attempt != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.attempt, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.delay != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.delay, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.msg.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.msg);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.ty != WsConnectStateType::Init {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.ty))?;
+        }
+        if self.attempt != 0 {
+            os.write_int64(2, self.attempt)?;
+        }
+        if self.delay != 0 {
+            os.write_int64(3, self.delay)?;
+        }
+        if !self.msg.is_empty() {
+            os.write_string(4, &self.msg)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WsConnectState {
+        WsConnectState::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<WsConnectStateType>>(
+                "ty",
+                |m: &WsConnectState| { &m.ty },
+                |m: &mut WsConnectState| { &mut m.ty },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "attempt",
+                |m: &WsConnectState| { &m.attempt },
+                |m: &mut WsConnectState| { &mut m.attempt },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "delay",
+                |m: &WsConnectState| { &m.delay },
+                |m: &mut WsConnectState| { &mut m.delay },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "msg",
+                |m: &WsConnectState| { &m.msg },
+                |m: &mut WsConnectState| { &mut m.msg },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WsConnectState>(
+                "WsConnectState",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WsConnectState {
+        static instance: ::protobuf::rt::LazyV2<WsConnectState> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WsConnectState::new)
+    }
+}
+
+impl ::protobuf::Clear for WsConnectState {
+    fn clear(&mut self) {
+        self.ty = WsConnectStateType::Init;
+        self.attempt = 0;
+        self.delay = 0;
+        self.msg.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WsConnectState {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WsConnectState {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct NetworkState {
+    // message fields
+    pub ty: NetworkStateType,
+    pub metered: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a NetworkState {
+    fn default() -> &'a NetworkState {
+        <NetworkState as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl NetworkState {
+    pub fn new() -> NetworkState {
+        ::std::default::Default::default()
+    }
+
+    // .NetworkStateType ty = 1;
+
+
+    pub fn get_ty(&self) -> NetworkStateType {
+        self.ty
+    }
+    pub fn clear_ty(&mut self) {
+        self.ty = NetworkStateType::Online;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ty(&mut self, v: NetworkStateType) {
+        self.ty = v;
+    }
+
+    // bool metered = 2;
+
+
+    pub fn get_metered(&self) -> bool {
+        self.metered
+    }
+    pub fn clear_metered(&mut self) {
+        self.metered = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_metered(&mut self, v: bool) {
+        self.metered = v;
+    }
+}
+
+impl ::protobuf::Message for NetworkState {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.ty, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.metered = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.ty != NetworkStateType::Online {
+            my_size += ::protobuf::rt::enum_size(1, self.ty);
+        }
+        if self.metered != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.ty != NetworkStateType::Online {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.ty))?;
+        }
+        if self.metered != false {
+            os.write_bool(2, self.metered)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> NetworkState {
+        NetworkState::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<NetworkStateType>>(
+                "ty",
+                |m: &NetworkState| { &m.ty },
+                |m: &mut NetworkState| { &mut m.ty },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "metered",
+                |m: &NetworkState| { &m.metered },
+                |m: &mut NetworkState| { &mut m.metered },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<NetworkState>(
+                "NetworkState",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static NetworkState {
+        static instance: ::protobuf::rt::LazyV2<NetworkState> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(NetworkState::new)
+    }
+}
+
+impl ::protobuf::Clear for NetworkState {
+    fn clear(&mut self) {
+        self.ty = NetworkStateType::Online;
+        self.metered = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for NetworkState {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NetworkState {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum WsConnectStateType {
+    Init = 0,
+    Connected = 1,
+    Disconnected = 2,
+    Reconnecting = 3,
+}
+
+impl ::protobuf::ProtobufEnum for WsConnectStateType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<WsConnectStateType> {
+        match value {
+            0 => ::std::option::Option::Some(WsConnectStateType::Init),
+            1 => ::std::option::Option::Some(WsConnectStateType::Connected),
+            2 => ::std::option::Option::Some(WsConnectStateType::Disconnected),
+            3 => ::std::option::Option::Some(WsConnectStateType::Reconnecting),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [WsConnectStateType] = &[
+            WsConnectStateType::Init,
+            WsConnectStateType::Connected,
+            WsConnectStateType::Disconnected,
+            WsConnectStateType::Reconnecting,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<WsConnectStateType>("WsConnectStateType", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for WsConnectStateType {
+}
+
+impl ::std::default::Default for WsConnectStateType {
+    fn default() -> Self {
+        WsConnectStateType::Init
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WsConnectStateType {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum NetworkStateType {
+    Online = 0,
+    Offline = 1,
+}
+
+impl ::protobuf::ProtobufEnum for NetworkStateType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<NetworkStateType> {
+        match value {
+            0 => ::std::option::Option::Some(NetworkStateType::Online),
+            1 => ::std::option::Option::Some(NetworkStateType::Offline),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [NetworkStateType] = &[
+            NetworkStateType::Online,
+            NetworkStateType::Offline,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<NetworkStateType>("NetworkStateType", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for NetworkStateType {
+}
+
+impl ::std::default::Default for NetworkStateType {
+    fn default() -> Self {
+        NetworkStateType::Online
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NetworkStateType {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0bstate.proto\"\x81\x01\n\x0eWsConnectState\x12%\n\x02ty\x18\x01\x20\
+    \x01(\x0e2\x13.WsConnectStateTypeR\x02tyB\0\x12\x1a\n\x07attempt\x18\x02\
+    \x20\x01(\x03R\x07attemptB\0\x12\x16\n\x05delay\x18\x03\x20\x01(\x03R\
+    \x05delayB\0\x12\x12\n\x03msg\x18\x04\x20\x01(\tR\x03msgB\0:\0\"Q\n\x0cN\
+    etworkState\x12#\n\x02ty\x18\x01\x20\x01(\x0e2\x11.NetworkStateTypeR\x02\
+    tyB\0\x12\x1a\n\x07metered\x18\x02\x20\x01(\x08R\x07meteredB\0:\0*S\n\
+    \x12WsConnectStateType\x12\x08\n\x04Init\x10\0\x12\r\n\tConnected\x10\
+    \x01\x12\x10\n\x0cDisconnected\x10\x02\x12\x10\n\x0cReconnecting\x10\x03\
+    \x1a\0*-\n\x10NetworkStateType\x12\n\n\x06Online\x10\0\x12\x0b\n\x07Offl\
+    ine\x10\x01\x1a\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
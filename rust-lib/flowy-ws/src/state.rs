@@ -0,0 +1,77 @@
+use crate::WsState;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+#[derive(ProtoBuf_Enum, Debug, Clone, PartialEq, Eq)]
+pub enum WsConnectStateType {
+    Init         = 0,
+    Connected    = 1,
+    Disconnected = 2,
+    Reconnecting = 3,
+}
+
+impl std::default::Default for WsConnectStateType {
+    fn default() -> Self { WsConnectStateType::Init }
+}
+
+// The state of the connection that's shown to the user.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct WsConnectState {
+    #[pb(index = 1)]
+    pub ty: WsConnectStateType,
+
+    // The attempt to connect again, starting from 1. It's only set when
+    // reconnecting.
+    #[pb(index = 2)]
+    pub attempt: i64,
+
+    // The milliseconds until the attempt.
+    #[pb(index = 3)]
+    pub delay: i64,
+
+    // Why it's disconnected.
+    #[pb(index = 4)]
+    pub msg: String,
+}
+
+impl std::convert::From<&WsState> for WsConnectState {
+    fn from(state: &WsState) -> Self {
+        match state {
+            WsState::Init => WsConnectState::default(),
+            WsState::Connected(_) => WsConnectState {
+                ty: WsConnectStateType::Connected,
+                ..Default::default()
+            },
+            WsState::Disconnected(e) => WsConnectState {
+                ty: WsConnectStateType::Disconnected,
+                msg: e.msg.clone(),
+                ..Default::default()
+            },
+            WsState::Reconnecting { attempt, delay } => WsConnectState {
+                ty: WsConnectStateType::Reconnecting,
+                attempt: *attempt as i64,
+                delay: delay.as_millis() as i64,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(ProtoBuf_Enum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkStateType {
+    Online  = 0,
+    Offline = 1,
+}
+
+impl std::default::Default for NetworkStateType {
+    fn default() -> Self { NetworkStateType::Online }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct NetworkState {
+    #[pb(index = 1)]
+    pub ty: NetworkStateType,
+
+    // Reported by the host, e.g. a cellular connection.
+    #[pb(index = 2)]
+    pub metered: bool,
+}
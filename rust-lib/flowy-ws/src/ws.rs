@@ -1,25 +1,29 @@
 use crate::{
     connect::{WsConnectionFuture, WsStream},
-    errors::WsError,
+    errors::{ErrorCode, WsError},
+    WsConfig,
     WsMessage,
     WsModule,
 };
 use bytes::Bytes;
 use dashmap::DashMap;
-use flowy_infra::retry::{Action, FixedInterval, Retry};
 use flowy_net::errors::ServerError;
 use futures_channel::mpsc::{UnboundedReceiver, UnboundedSender};
 use futures_core::{ready, Stream};
-use parking_lot::RwLock;
+use futures_util::StreamExt;
+use parking_lot::{Mutex, RwLock};
 use pin_project::pin_project;
 use std::{
     convert::TryFrom,
     fmt::Formatter,
     future::Future,
     pin::Pin,
-    sync::Arc,
+    sync::{
+        atomic::{AtomicUsize, Ordering::SeqCst},
+        Arc,
+    },
     task::{Context, Poll},
-    time::Duration,
+    time::{Duration, Instant},
 };
 use tokio::sync::{broadcast, oneshot};
 use tokio_tungstenite::tungstenite::{
@@ -29,7 +33,9 @@ use tokio_tungstenite::tungstenite::{
 
 pub type MsgReceiver = UnboundedReceiver<Message>;
 pub type MsgSender = UnboundedSender<Message>;
-type Handlers = DashMap<WsModule, Arc<dyn WsMessageHandler>>;
+type Handlers = Arc<DashMap<WsModule, Arc<dyn WsMessageHandler>>>;
+// The value is the id of the channel with its sender.
+type Channels = Arc<DashMap<(WsModule, String), (usize, UnboundedSender<WsMessage>)>>;
 
 pub trait WsMessageHandler: Sync + Send + 'static {
     fn source(&self) -> WsModule;
@@ -41,6 +47,8 @@ pub enum WsState {
     Init,
     Connected(Arc<WsSender>),
     Disconnected(WsError),
+    // It's connected again after the delay, the attempts start from 1.
+    Reconnecting { attempt: usize, delay: Duration },
 }
 
 impl std::fmt::Display for WsState {
@@ -49,6 +57,7 @@ impl std::fmt::Display for WsState {
             WsState::Init => f.write_str("Init"),
             WsState::Connected(_) => f.write_str("Connected"),
             WsState::Disconnected(_) => f.write_str("Disconnected"),
+            WsState::Reconnecting { attempt, delay } => write!(f, "Reconnecting({}, {:?})", attempt, delay),
         }
     }
 }
@@ -59,21 +68,32 @@ impl std::fmt::Debug for WsState {
 
 pub struct WsController {
     handlers: Handlers,
+    channels: Channels,
     state_notify: Arc<broadcast::Sender<WsState>>,
     sender: Arc<RwLock<Option<Arc<WsSender>>>>,
     addr: Arc<RwLock<Option<String>>>,
+    config: WsConfig,
+    // Each connect starts a session and disconnect ends it. The connection of an
+    // ended session isn't connected again.
+    session_id: Arc<AtomicUsize>,
+    channel_id: AtomicUsize,
 }
 
 impl WsController {
-    pub fn new() -> Self {
+    pub fn new() -> Self { Self::with_config(WsConfig::default()) }
+
+    pub fn with_config(config: WsConfig) -> Self {
         let (state_notify, _) = broadcast::channel(16);
-        let controller = Self {
-            handlers: DashMap::new(),
+        Self {
+            handlers: Arc::new(DashMap::new()),
+            channels: Arc::new(DashMap::new()),
             sender: Arc::new(RwLock::new(None)),
             state_notify: Arc::new(state_notify),
             addr: Arc::new(RwLock::new(None)),
-        };
-        controller
+            config,
+            session_id: Arc::new(AtomicUsize::new(0)),
+            channel_id: AtomicUsize::new(0),
+        }
     }
 
     pub fn add_handler(&self, handler: Arc<dyn WsMessageHandler>) -> Result<(), WsError> {
@@ -85,56 +105,17 @@ impl WsController {
         Ok(())
     }
 
+    // Connects to the address and keeps the connection until disconnect is
+    // called: it's connected again with the backoff of the config whenever it's
+    // lost. Returns the result of the first attempt, the attempts after a failed
+    // one go on in the background.
     pub async fn start_connect(&self, addr: String) -> Result<(), ServerError> {
         *self.addr.write() = Some(addr.clone());
-
-        let strategy = FixedInterval::from_millis(5000).take(3);
-        self.connect(addr, strategy).await
-    }
-
-    async fn connect<T, I>(&self, addr: String, strategy: T) -> Result<(), ServerError>
-    where
-        T: IntoIterator<IntoIter = I, Item = Duration>,
-        I: Iterator<Item = Duration> + Send + 'static,
-    {
-        let (ret, rx) = oneshot::channel::<Result<(), ServerError>>();
-        *self.addr.write() = Some(addr.clone());
-
-        let action = WsConnectAction {
-            addr,
-            handlers: self.handlers.clone(),
-        };
-
-        let retry = Retry::spawn(strategy, action);
-        let sender_holder = self.sender.clone();
-        let state_notify = self.state_notify.clone();
-
-        tokio::spawn(async move {
-            match retry.await {
-                Ok(result) => {
-                    let WsConnectResult {
-                        stream,
-                        handlers_fut,
-                        sender,
-                    } = result;
-                    let sender = Arc::new(sender);
-                    *sender_holder.write() = Some(sender.clone());
-
-                    let _ = state_notify.send(WsState::Connected(sender));
-                    let _ = ret.send(Ok(()));
-                    spawn_stream_and_handlers(stream, handlers_fut, state_notify).await;
-                },
-                Err(e) => {
-                    //
-                    let _ = state_notify.send(WsState::Disconnected(e.clone()));
-                    let _ = ret.send(Err(ServerError::internal().context(e)));
-                },
-            }
-        });
-
-        rx.await?
+        self.connect(addr).await
     }
 
+    // Connects again right away instead of waiting for the backoff, e.g. when the
+    // network is back.
     pub async fn retry(&self) -> Result<(), ServerError> {
         let addr = self
             .addr
@@ -142,8 +123,37 @@ impl WsController {
             .as_ref()
             .expect("must call start_connect first")
             .clone();
-        let strategy = FixedInterval::from_millis(5000);
-        self.connect(addr, strategy).await
+        self.connect(addr).await
+    }
+
+    // Closes the connection, it's not connected again until start_connect is
+    // called.
+    pub fn disconnect(&self) {
+        let _ = self.session_id.fetch_add(1, SeqCst);
+        self.close_connection();
+        let _ = self
+            .state_notify
+            .send(WsState::Disconnected(WsError::connection_closed()));
+    }
+
+    // The messages of the module about the object are passed to the channel
+    // instead of the handler of the module, until the channel is dropped. The
+    // channel is kept while it's connected again.
+    pub fn subscribe(&self, module: WsModule, object_id: &str) -> WsChannel {
+        let (tx, rx) = futures_channel::mpsc::unbounded();
+        let id = self.channel_id.fetch_add(1, SeqCst);
+        let key = (module.clone(), object_id.to_owned());
+        if self.channels.insert(key, (id, tx)).is_some() {
+            log::error!("The channel of {:?} {} is replaced", module, object_id);
+        }
+        WsChannel {
+            id,
+            module,
+            object_id: object_id.to_owned(),
+            receiver: rx,
+            sender: self.sender.clone(),
+            channels: self.channels.clone(),
+        }
     }
 
     pub fn state_subscribe(&self) -> broadcast::Receiver<WsState> { self.state_notify.subscribe() }
@@ -154,25 +164,194 @@ impl WsController {
             Some(sender) => Ok(sender.clone()),
         }
     }
+
+    async fn connect(&self, addr: String) -> Result<(), ServerError> {
+        let (ret, rx) = oneshot::channel::<Result<(), ServerError>>();
+        let id = self.session_id.fetch_add(1, SeqCst) + 1;
+        self.close_connection();
+        let session = WsSession {
+            id,
+            current_id: self.session_id.clone(),
+            handlers: self.handlers.clone(),
+            channels: self.channels.clone(),
+            state_notify: self.state_notify.clone(),
+            sender: self.sender.clone(),
+            config: self.config.clone(),
+        };
+        tokio::spawn(session.run(addr, ret));
+        rx.await?
+    }
+
+    // The session of the connection is ended before, so it isn't connected again.
+    fn close_connection(&self) {
+        if let Some(sender) = self.sender.write().take() {
+            let _ = sender.send_disconnect("disconnect");
+        }
+    }
 }
 
-async fn spawn_stream_and_handlers(
-    stream: WsStream,
-    handlers: WsHandlerFuture,
+struct WsSession {
+    id: usize,
+    current_id: Arc<AtomicUsize>,
+    handlers: Handlers,
+    channels: Channels,
     state_notify: Arc<broadcast::Sender<WsState>>,
-) {
+    sender: Arc<RwLock<Option<Arc<WsSender>>>>,
+    config: WsConfig,
+}
+
+impl WsSession {
+    fn is_current(&self) -> bool { self.current_id.load(SeqCst) == self.id }
+
+    fn notify(&self, state: WsState) {
+        if self.is_current() {
+            let _ = self.state_notify.send(state);
+        }
+    }
+
+    async fn run(self, addr: String, ret: oneshot::Sender<Result<(), ServerError>>) {
+        let mut ret = Some(ret);
+        let mut backoff = self.config.backoff();
+        let mut attempt = 0;
+        loop {
+            let last_received = Arc::new(Mutex::new(Instant::now()));
+            let connection = WsConnectActionFut::new(
+                addr.clone(),
+                self.handlers.clone(),
+                self.channels.clone(),
+                last_received.clone(),
+            );
+            match connection.await {
+                Ok(WsConnectResult {
+                    stream,
+                    handlers_fut,
+                    sender,
+                }) => {
+                    let sender = Arc::new(sender);
+                    if !self.is_current() {
+                        let _ = sender.send_disconnect("disconnect");
+                        break;
+                    }
+                    *self.sender.write() = Some(sender.clone());
+                    self.notify(WsState::Connected(sender.clone()));
+                    if let Some(ret) = ret.take() {
+                        let _ = ret.send(Ok(()));
+                    }
+                    attempt = 0;
+                    backoff = self.config.backoff();
+
+                    let keep_alive = keep_alive(sender.clone(), last_received, self.config.clone());
+                    let error = spawn_stream_and_handlers(stream, handlers_fut, keep_alive).await;
+                    {
+                        let mut holder = self.sender.write();
+                        if matches!(&*holder, Some(current) if Arc::ptr_eq(current, &sender)) {
+                            *holder = None;
+                        }
+                    }
+                    if !self.is_current() {
+                        break;
+                    }
+                    self.notify(WsState::Disconnected(error));
+                },
+                Err(e) => {
+                    if let Some(ret) = ret.take() {
+                        let _ = ret.send(Err(ServerError::internal().context(e.clone())));
+                    }
+                    if !self.is_current() {
+                        break;
+                    }
+                    self.notify(WsState::Disconnected(e.clone()));
+                    // The token isn't accepted, connecting again won't help.
+                    if e.code == ErrorCode::Unauthorized {
+                        break;
+                    }
+                },
+            }
+
+            attempt += 1;
+            if matches!(self.config.max_reconnect_attempts, Some(max) if attempt > max) {
+                tracing::debug!("Stop reconnecting after {} attempts", attempt - 1);
+                break;
+            }
+            let delay = backoff.next().unwrap_or(self.config.max_reconnect_delay);
+            self.notify(WsState::Reconnecting { attempt, delay });
+            tokio::time::sleep(delay).await;
+            if !self.is_current() {
+                break;
+            }
+        }
+    }
+}
+
+// Returns why the connection ended.
+async fn spawn_stream_and_handlers<F>(stream: WsStream, handlers: WsHandlerFuture, keep_alive: F) -> WsError
+where
+    F: Future<Output = WsError>,
+{
     tokio::select! {
         result = stream => {
             match result {
-                Ok(_) => {},
+                Ok(_) => WsError::connection_closed(),
                 Err(e) => {
                     log::error!("websocket error: {:?}", e);
-                    let _ = state_notify.send(WsState::Disconnected(e)).unwrap();
+                    e
                 }
             }
         },
-        result = handlers => tracing::debug!("handlers completed {:?}", result),
-    };
+        result = handlers => {
+            tracing::debug!("handlers completed {:?}", result);
+            WsError::connection_closed()
+        },
+        error = keep_alive => error,
+    }
+}
+
+async fn keep_alive(sender: Arc<WsSender>, last_received: Arc<Mutex<Instant>>, config: WsConfig) -> WsError {
+    let mut interval = tokio::time::interval(config.ping_interval);
+    loop {
+        let _ = interval.tick().await;
+        if last_received.lock().elapsed() > config.pong_timeout {
+            tracing::debug!("No pong was received for {:?}", config.pong_timeout);
+            return WsError::ping_timeout();
+        }
+        if let Err(e) = sender.send_ping() {
+            return e;
+        }
+    }
+}
+
+// The messages of the module about the object, see WsController::subscribe.
+pub struct WsChannel {
+    id: usize,
+    module: WsModule,
+    object_id: String,
+    receiver: UnboundedReceiver<WsMessage>,
+    sender: Arc<RwLock<Option<Arc<WsSender>>>>,
+    channels: Channels,
+}
+
+impl WsChannel {
+    pub fn object_id(&self) -> &str { &self.object_id }
+
+    // Fails if it isn't connected, the data isn't kept until it's connected again.
+    pub fn send(&self, data: Vec<u8>) -> Result<(), WsError> {
+        let sender = self.sender.read().clone().ok_or_else(WsError::connection_closed)?;
+        sender.send_msg(WsMessage {
+            module: self.module.clone(),
+            data,
+            object_id: self.object_id.clone(),
+        })
+    }
+
+    pub async fn recv(&mut self) -> Option<WsMessage> { self.receiver.next().await }
+}
+
+impl Drop for WsChannel {
+    fn drop(&mut self) {
+        let key = (self.module.clone(), self.object_id.clone());
+        let id = self.id;
+        let _ = self.channels.remove_if(&key, |_, (channel_id, _)| *channel_id == id);
+    }
 }
 
 #[pin_project]
@@ -181,12 +360,22 @@ pub struct WsHandlerFuture {
     msg_rx: MsgReceiver,
     // Opti: Hashmap would be better
     handlers: Handlers,
+    channels: Channels,
+    last_received: Arc<Mutex<Instant>>,
 }
 
 impl WsHandlerFuture {
-    fn new(handlers: Handlers, msg_rx: MsgReceiver) -> Self { Self { msg_rx, handlers } }
+    fn new(handlers: Handlers, channels: Channels, last_received: Arc<Mutex<Instant>>, msg_rx: MsgReceiver) -> Self {
+        Self {
+            msg_rx,
+            handlers,
+            channels,
+            last_received,
+        }
+    }
 
     fn handler_ws_message(&self, message: Message) {
+        *self.last_received.lock() = Instant::now();
         match message {
             Message::Binary(bytes) => self.handle_binary_message(bytes),
             _ => {},
@@ -196,9 +385,25 @@ impl WsHandlerFuture {
     fn handle_binary_message(&self, bytes: Vec<u8>) {
         let bytes = Bytes::from(bytes);
         match WsMessage::try_from(bytes) {
-            Ok(message) => match self.handlers.get(&message.module) {
-                None => log::error!("Can't find any handler for message: {:?}", message),
-                Some(handler) => handler.receive_message(message.clone()),
+            Ok(message) => {
+                let channel = match message.object_id.is_empty() {
+                    true => None,
+                    false => self
+                        .channels
+                        .get(&(message.module.clone(), message.object_id.clone()))
+                        .map(|channel| channel.1.clone()),
+                };
+                match channel {
+                    Some(channel) => {
+                        if let Err(e) = channel.unbounded_send(message) {
+                            log::error!("Send message to the channel failed: {:?}", e);
+                        }
+                    },
+                    None => match self.handlers.get(&message.module) {
+                        None => log::error!("Can't find any handler for message: {:?}", message),
+                        Some(handler) => handler.receive_message(message.clone()),
+                    },
+                }
             },
             Err(e) => {
                 log::error!("Deserialize binary ws message failed: {:?}", e);
@@ -240,6 +445,7 @@ impl WsSender {
         let msg = WsMessage {
             module: source.clone(),
             data: text.as_bytes().to_vec(),
+            object_id: "".to_owned(),
         };
         self.send_msg(msg)
     }
@@ -248,6 +454,7 @@ impl WsSender {
         let msg = WsMessage {
             module: source.clone(),
             data: bytes,
+            object_id: "".to_owned(),
         };
         self.send_msg(msg)
     }
@@ -264,11 +471,14 @@ impl WsSender {
             .map_err(|e| WsError::internal().context(e))?;
         Ok(())
     }
-}
 
-struct WsConnectAction {
-    addr: String,
-    handlers: Handlers,
+    fn send_ping(&self) -> Result<(), WsError> {
+        let _ = self
+            .ws_tx
+            .unbounded_send(Message::Ping(vec![]))
+            .map_err(|e| WsError::internal().context(e))?;
+        Ok(())
+    }
 }
 
 struct WsConnectResult {
@@ -287,7 +497,7 @@ struct WsConnectActionFut {
 }
 
 impl WsConnectActionFut {
-    fn new(addr: String, handlers: Handlers) -> Self {
+    fn new(addr: String, handlers: Handlers, channels: Channels, last_received: Arc<Mutex<Instant>>) -> Self {
         //                Stream                             User
         //               ┌───────────────┐                 ┌──────────────┐
         // ┌──────┐      │  ┌─────────┐  │    ┌────────┐   │  ┌────────┐  │
@@ -301,7 +511,7 @@ impl WsConnectActionFut {
         let (msg_tx, msg_rx) = futures_channel::mpsc::unbounded();
         let (ws_tx, ws_rx) = futures_channel::mpsc::unbounded();
         let sender = WsSender { ws_tx };
-        let handlers_fut = WsHandlerFuture::new(handlers, msg_rx);
+        let handlers_fut = WsHandlerFuture::new(handlers, channels, last_received, msg_rx);
         let conn = WsConnectionFuture::new(msg_tx, ws_rx, addr.clone());
         Self {
             addr,
@@ -330,15 +540,3 @@ impl Future for WsConnectActionFut {
         }
     }
 }
-
-impl Action for WsConnectAction {
-    type Future = Pin<Box<dyn Future<Output = Result<Self::Item, Self::Error>> + Send + Sync>>;
-    type Item = WsConnectResult;
-    type Error = WsError;
-
-    fn run(&mut self) -> Self::Future {
-        let addr = self.addr.clone();
-        let handlers = self.handlers.clone();
-        Box::pin(WsConnectActionFut::new(addr, handlers))
-    }
-}
@@ -0,0 +1,75 @@
+use flowy_ws::{Connectivity, ConnectivityConfig, NetworkStateType};
+use std::{sync::Arc, time::Duration};
+use tokio::{net::TcpListener, time::timeout};
+
+fn test_config(endpoint: Option<String>) -> ConnectivityConfig {
+    ConnectivityConfig {
+        endpoint,
+        probe_interval: Duration::from_millis(20),
+        probe_timeout: Duration::from_millis(200),
+        confirmations: 2,
+    }
+}
+
+// An address that nothing listens on.
+async fn closed_endpoint() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    listener.local_addr().unwrap().to_string()
+}
+
+#[test]
+fn connectivity_debounce_flapping() {
+    let connectivity = Connectivity::new(test_config(None));
+    assert!(connectivity.is_online());
+
+    assert!(!connectivity.report(false));
+    assert!(!connectivity.report(true));
+    assert!(!connectivity.report(false));
+    assert!(connectivity.is_online());
+
+    assert!(connectivity.report(false));
+    assert_eq!(connectivity.state(), NetworkStateType::Offline);
+
+    assert!(!connectivity.report(true));
+    assert!(connectivity.report(true));
+    assert!(connectivity.is_online());
+}
+
+#[tokio::test]
+async fn connectivity_probe_endpoint() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let endpoint = listener.local_addr().unwrap().to_string();
+    assert!(Connectivity::new(test_config(Some(endpoint))).probe().await);
+
+    let endpoint = closed_endpoint().await;
+    assert!(!Connectivity::new(test_config(Some(endpoint))).probe().await);
+}
+
+#[tokio::test]
+async fn connectivity_notify_offline() {
+    let connectivity = Arc::new(Connectivity::new(test_config(Some(closed_endpoint().await))));
+    let mut subscribe = connectivity.state_subscribe();
+    tokio::spawn(connectivity.clone().run());
+
+    let state = timeout(Duration::from_secs(5), subscribe.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(state, NetworkStateType::Offline);
+    assert!(!connectivity.is_online());
+}
+
+#[tokio::test]
+async fn connectivity_notify_metered() {
+    let connectivity = Connectivity::new(test_config(None));
+    let mut subscribe = connectivity.metered_subscribe();
+    assert!(!connectivity.is_metered());
+
+    assert!(connectivity.set_metered(true));
+    assert!(!connectivity.set_metered(true));
+    assert!(connectivity.is_metered());
+    assert!(subscribe.recv().await.unwrap());
+
+    assert!(connectivity.set_metered(false));
+    assert!(!subscribe.recv().await.unwrap());
+}
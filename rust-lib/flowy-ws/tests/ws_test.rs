@@ -0,0 +1,189 @@
+use flowy_ws::{errors::ErrorCode, WsConfig, WsController, WsModule, WsState};
+use futures_util::{SinkExt, StreamExt};
+use std::{sync::Arc, time::Duration};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::{broadcast, mpsc},
+    time::timeout,
+};
+use tokio_tungstenite::{accept_async, tungstenite::Message, WebSocketStream};
+
+fn test_config() -> WsConfig {
+    WsConfig {
+        reconnect_delay: Duration::from_millis(20),
+        max_reconnect_delay: Duration::from_millis(100),
+        max_reconnect_attempts: None,
+        ping_interval: Duration::from_millis(50),
+        pong_timeout: Duration::from_secs(5),
+    }
+}
+
+// Accepts the connections and passes them to the test.
+async fn start_server() -> (String, mpsc::UnboundedReceiver<WebSocketStream<TcpStream>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = format!("ws://{}", listener.local_addr().unwrap());
+    let (tx, rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            if let Ok(stream) = accept_async(stream).await {
+                if tx.send(stream).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+    (addr, rx)
+}
+
+async fn next_state(notify: &mut broadcast::Receiver<WsState>) -> WsState {
+    timeout(Duration::from_secs(5), notify.recv())
+        .await
+        .expect("Wait for the state timeout")
+        .unwrap()
+}
+
+#[tokio::test]
+async fn ws_reconnect_after_connection_lost() {
+    let (addr, mut connections) = start_server().await;
+    let controller = WsController::with_config(test_config());
+    let mut notify = controller.state_subscribe();
+    let _ = controller.start_connect(addr).await.unwrap();
+    assert!(matches!(next_state(&mut notify).await, WsState::Connected(_)));
+
+    let connection = connections.recv().await.unwrap();
+    drop(connection);
+    assert!(matches!(next_state(&mut notify).await, WsState::Disconnected(_)));
+    match next_state(&mut notify).await {
+        WsState::Reconnecting { attempt, delay } => {
+            assert_eq!(attempt, 1);
+            assert!(delay <= Duration::from_millis(20));
+        },
+        state => panic!("Expected reconnecting, got {}", state),
+    }
+    assert!(matches!(next_state(&mut notify).await, WsState::Connected(_)));
+    let _connection = connections.recv().await.unwrap();
+    assert!(controller.sender().is_ok());
+}
+
+#[tokio::test]
+async fn ws_not_reconnect_after_disconnect() {
+    let (addr, mut connections) = start_server().await;
+    let controller = WsController::with_config(test_config());
+    let mut notify = controller.state_subscribe();
+    let _ = controller.start_connect(addr).await.unwrap();
+    assert!(matches!(next_state(&mut notify).await, WsState::Connected(_)));
+    let _connection = connections.recv().await.unwrap();
+
+    controller.disconnect();
+    match next_state(&mut notify).await {
+        WsState::Disconnected(e) => assert_eq!(e.code, ErrorCode::ConnectionClosed),
+        state => panic!("Expected disconnected, got {}", state),
+    }
+    assert!(controller.sender().is_err());
+    assert!(timeout(Duration::from_millis(300), connections.recv()).await.is_err());
+    assert!(notify.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn ws_reconnect_when_pong_timeout() {
+    let (addr, mut connections) = start_server().await;
+    let config = WsConfig {
+        pong_timeout: Duration::from_millis(200),
+        ..test_config()
+    };
+    let controller = WsController::with_config(config);
+    let mut notify = controller.state_subscribe();
+    let _ = controller.start_connect(addr).await.unwrap();
+    assert!(matches!(next_state(&mut notify).await, WsState::Connected(_)));
+
+    // The pings are answered when the connection is read, it never is.
+    let _connection = connections.recv().await.unwrap();
+    match next_state(&mut notify).await {
+        WsState::Disconnected(e) => assert_eq!(e.code, ErrorCode::PingTimeout),
+        state => panic!("Expected disconnected, got {}", state),
+    }
+    assert!(matches!(next_state(&mut notify).await, WsState::Reconnecting { .. }));
+    assert!(matches!(next_state(&mut notify).await, WsState::Connected(_)));
+}
+
+#[tokio::test]
+async fn ws_keep_alive_with_pong() {
+    let (addr, mut connections) = start_server().await;
+    let config = WsConfig {
+        pong_timeout: Duration::from_millis(200),
+        ..test_config()
+    };
+    let controller = WsController::with_config(config);
+    let mut notify = controller.state_subscribe();
+    let _ = controller.start_connect(addr).await.unwrap();
+    assert!(matches!(next_state(&mut notify).await, WsState::Connected(_)));
+
+    let mut connection = connections.recv().await.unwrap();
+    let mut pings = 0;
+    while pings < 8 {
+        match connection.next().await.unwrap().unwrap() {
+            Message::Ping(_) => pings += 1,
+            message => panic!("Expected ping, got {:?}", message),
+        }
+        let _ = connection.flush().await;
+    }
+    assert!(notify.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn ws_channel_receive_the_messages_of_its_object() {
+    let (addr, mut connections) = start_server().await;
+    let controller = Arc::new(WsController::with_config(test_config()));
+    let mut first = controller.subscribe(WsModule::Doc, "a");
+    let mut second = controller.subscribe(WsModule::Doc, "b");
+    let _ = controller.start_connect(addr).await.unwrap();
+
+    // Echoes the messages.
+    let mut connection = connections.recv().await.unwrap();
+    tokio::spawn(async move {
+        while let Some(Ok(message)) = connection.next().await {
+            if message.is_binary() && connection.send(message).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    second.send(vec![2]).unwrap();
+    first.send(vec![1]).unwrap();
+    let message = timeout(Duration::from_secs(5), first.recv()).await.unwrap().unwrap();
+    assert_eq!(message.object_id, "a");
+    assert_eq!(message.data, vec![1]);
+    let message = timeout(Duration::from_secs(5), second.recv()).await.unwrap().unwrap();
+    assert_eq!(message.object_id, "b");
+    assert_eq!(message.data, vec![2]);
+
+    // The messages of the dropped channel aren't kept.
+    drop(second);
+    let mut second = controller.subscribe(WsModule::Doc, "b");
+    second.send(vec![3]).unwrap();
+    let message = timeout(Duration::from_secs(5), second.recv()).await.unwrap().unwrap();
+    assert_eq!(message.data, vec![3]);
+}
+
+#[tokio::test]
+async fn ws_channel_send_fail_when_disconnected() {
+    let controller = WsController::with_config(test_config());
+    let channel = controller.subscribe(WsModule::Doc, "a");
+    assert_eq!(channel.object_id(), "a");
+    assert_eq!(channel.send(vec![1]).unwrap_err().code, ErrorCode::ConnectionClosed);
+}
+
+#[test]
+fn ws_backoff_delay() {
+    let config = WsConfig {
+        reconnect_delay: Duration::from_secs(1),
+        max_reconnect_delay: Duration::from_secs(10),
+        ..WsConfig::default()
+    };
+    let delays = config.backoff().take(8).collect::<Vec<Duration>>();
+    let expected = [1, 2, 4, 8, 10, 10, 10, 10];
+    for (delay, expected) in delays.iter().zip(expected.iter()) {
+        let max = Duration::from_secs(*expected);
+        assert!(*delay >= max / 2 && *delay <= max, "{:?} isn't within {:?}", delay, max);
+    }
+}
@@ -0,0 +1,31 @@
+#![no_main]
+use flowy_ot::core::{Delta, OperationTransformable};
+use libfuzzer_sys::fuzz_target;
+
+// Two deltas separated by a zero byte. The engine expects the lengths of the
+// deltas to match, the ones that don't are only applied to a string.
+fuzz_target!(|data: &[u8]| {
+    let mut parts = data.splitn(2, |b| *b == 0);
+    let a = match parts.next().map(Delta::from_bytes) {
+        Some(Ok(delta)) => delta,
+        _ => return,
+    };
+    let b = match parts.next().map(Delta::from_bytes) {
+        Some(Ok(delta)) => delta,
+        _ => return,
+    };
+
+    let s = "a".repeat(a.base_len.min(4096));
+    if let Ok(applied) = a.apply(&s) {
+        let _ = a.invert_str(&s).apply(&applied);
+    }
+    if a.target_len == b.base_len {
+        let _ = a.compose(&b);
+    }
+    if a.base_len == b.target_len {
+        let _ = a.invert(&b);
+    }
+    if a.base_len == b.base_len {
+        let _ = a.transform(&b);
+    }
+});
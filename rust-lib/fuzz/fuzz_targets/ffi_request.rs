@@ -0,0 +1,10 @@
+#![no_main]
+use dart_ffi::model::FFIRequest;
+use flowy_dispatch::prelude::ModuleRequest;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(request) = FFIRequest::from_bytes(data) {
+        let _: ModuleRequest = request.into();
+    }
+});
@@ -0,0 +1,36 @@
+#![no_main]
+use bytes::Bytes;
+use flowy_dispatch::prelude::{Data, FromBytes, Payload};
+use flowy_user_infra::entities::*;
+use flowy_workspace_infra::entities::prelude::*;
+use libfuzzer_sys::fuzz_target;
+use std::convert::{TryFrom, TryInto};
+
+// Parsed the way the handlers do, then validated into the params.
+fn parse<T, P>(bytes: Bytes)
+where
+    T: FromBytes + TryInto<P>,
+{
+    if let Ok(data) = Data::<T>::try_from(Payload::Bytes(bytes)) {
+        let _: Result<P, _> = data.into_inner().try_into();
+    }
+}
+
+// The first byte picks the request.
+fuzz_target!(|data: &[u8]| {
+    let (selector, payload) = match data.split_first() {
+        Some((selector, payload)) => (*selector, Bytes::copy_from_slice(payload)),
+        None => return,
+    };
+    match selector % 9 {
+        0 => parse::<SignUpRequest, SignUpParams>(payload),
+        1 => parse::<SignInRequest, SignInParams>(payload),
+        2 => parse::<UpdateUserRequest, UpdateUserParams>(payload),
+        3 => parse::<CreateWorkspaceRequest, CreateWorkspaceParams>(payload),
+        4 => parse::<CreateAppRequest, CreateAppParams>(payload),
+        5 => parse::<UpdateAppRequest, UpdateAppParams>(payload),
+        6 => parse::<CreateViewRequest, CreateViewParams>(payload),
+        7 => parse::<UpdateViewRequest, UpdateViewParams>(payload),
+        _ => parse::<ImportRequest, ImportParams>(payload),
+    }
+});
@@ -1,6 +1,7 @@
 mod config;
 mod dart_event;
 mod proto;
+mod ts_gen;
 mod util;
 use clap::{App, Arg};
 
@@ -33,6 +34,14 @@ fn main() {
         };
         code_gen.gen();
     }
+
+    if let Some(ref matches) = matches.subcommand_matches("ts-gen") {
+        let rust_source = matches.value_of("rust_source").unwrap().to_string();
+        let output = matches.value_of("output").unwrap().to_string();
+
+        let code_gen = ts_gen::TsCodeGen { rust_source, output };
+        code_gen.gen();
+    }
 }
 
 pub fn app<'a, 'b>() -> App<'a, 'b> {
@@ -75,6 +84,21 @@ pub fn app<'a, 'b>() -> App<'a, 'b> {
                         .long("output")
                         .value_name("DIRECTORY"),
                 ),
+        )
+        .subcommand(
+            App::new("ts-gen")
+                .about("Generate the typescript interfaces and event enums from rust ast")
+                .arg(
+                    Arg::with_name("rust_source")
+                        .long("rust_source")
+                        .value_name("DIRECTORY")
+                        .help("Directory of the cargo workspace"),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .long("output")
+                        .value_name("PATH"),
+                ),
         );
 
     app
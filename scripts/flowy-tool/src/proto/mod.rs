@@ -4,5 +4,6 @@ mod proto_gen;
 mod proto_info;
 mod template;
 
+pub use ast::{get_ast_enums, get_ast_structs};
 pub use builder::*;
 pub use proto_gen::*;
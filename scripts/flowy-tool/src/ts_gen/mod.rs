@@ -0,0 +1,4 @@
+mod ts_gen;
+mod ts_template;
+
+pub use ts_gen::*;
@@ -0,0 +1,199 @@
+use super::ts_template::*;
+
+use crate::dart_event::{parse_event_crate, DartEventCrate};
+use crate::proto::{get_ast_enums, get_ast_structs};
+use crate::util::*;
+use flowy_ast::event_ast::EventASTContext;
+use std::collections::{HashMap, HashSet};
+use walkdir::WalkDir;
+
+pub struct TsCodeGen {
+    pub rust_source: String,
+    pub output: String,
+}
+
+impl TsCodeGen {
+    pub fn gen(&self) {
+        let crates = parse_ts_crates(self.rust_source.as_ref());
+
+        // Each crate is rendered into its own namespace, because names like ErrorCode are
+        // declared by more than one crate. A type that isn't declared by the crate itself is
+        // qualified with the namespace of the crate that declares it.
+        let mut declared_in: HashMap<String, String> = HashMap::new();
+        crates.iter().for_each(|ts_crate| {
+            ts_crate.declared_names().into_iter().for_each(|name| {
+                declared_in
+                    .entry(name)
+                    .or_insert_with(|| ts_crate.namespace.clone());
+            });
+        });
+
+        let mut render_result = String::from(TS_IMPORTED);
+        for ts_crate in &crates {
+            let declared = ts_crate.declared_names();
+            let resolve = |name: &str| -> String {
+                if declared.contains(name) {
+                    return name.to_owned();
+                }
+                match declared_in.get(name) {
+                    Some(namespace) => format!("{}.{}", namespace, name),
+                    None => name.to_owned(),
+                }
+            };
+
+            let mut content = String::new();
+            ts_crate.render_types(&mut content, &resolve);
+            ts_crate.render_events(&mut content, &resolve);
+            if content.is_empty() {
+                continue;
+            }
+
+            render_result.push_str(&format!("\nexport namespace {} {{\n", ts_crate.namespace));
+            content.lines().for_each(|line| {
+                if !line.is_empty() {
+                    render_result.push_str("    ");
+                    render_result.push_str(line);
+                }
+                render_result.push_str("\n");
+            });
+            render_result.push_str("}\n");
+        }
+
+        if let Some(parent) = std::path::Path::new(&self.output).parent() {
+            create_dir_if_not_exist(parent.to_str().unwrap());
+        }
+        save_content_to_file_with_diff_prompt(render_result.as_ref(), self.output.as_str(), true);
+    }
+}
+
+struct TsCrate {
+    namespace: String,
+    files: Vec<syn::File>,
+    events: Vec<EventASTContext>,
+}
+
+impl TsCrate {
+    fn event_tys(&self) -> HashSet<String> {
+        self.events
+            .iter()
+            .map(|event_ast| event_ast.event_ty.to_string())
+            .collect::<HashSet<String>>()
+    }
+
+    fn declared_names(&self) -> HashSet<String> {
+        let mut names = self.event_tys();
+        self.files.iter().for_each(|ast| {
+            get_ast_structs(ast).into_iter().for_each(|s| {
+                names.insert(s.name);
+            });
+            get_ast_enums(ast).into_iter().for_each(|e| {
+                names.insert(e.name);
+            });
+        });
+        names
+    }
+
+    fn render_types(&self, content: &mut String, resolve: &dyn Fn(&str) -> String) {
+        // The event enums are also ProtoBuf_Enum, but the web client addresses events by
+        // name, so they are rendered as string enums in render_events instead.
+        let event_tys = self.event_tys();
+        for ast in &self.files {
+            get_ast_structs(ast).iter().for_each(|s| {
+                let mut template = InterfaceTemplate::new(&s.name);
+                s.fields
+                    .iter()
+                    .filter(|f| f.attrs.pb_index().is_some())
+                    .for_each(|f| template.set_field(f, resolve));
+                push_rendered(content, template.render());
+            });
+
+            get_ast_enums(ast)
+                .iter()
+                .filter(|e| !event_tys.contains(&e.name))
+                .for_each(|e| {
+                    let mut template = EnumTemplate::new(&e.name);
+                    e.attrs.iter().for_each(|item| template.set_item(item));
+                    push_rendered(content, template.render());
+                });
+        }
+    }
+
+    fn render_events(&self, content: &mut String, resolve: &dyn Fn(&str) -> String) {
+        let mut event_templates: Vec<(String, EventTemplate)> = vec![];
+        for event_ast in &self.events {
+            let event_ty = event_ast.event_ty.to_string();
+            match event_templates.iter_mut().find(|(ty, _)| ty == &event_ty) {
+                Some((_, template)) => template.set_event(event_ast, resolve),
+                None => {
+                    let mut template = EventTemplate::new(&event_ty);
+                    template.set_event(event_ast, resolve);
+                    event_templates.push((event_ty, template));
+                }
+            }
+        }
+        event_templates
+            .iter_mut()
+            .for_each(|(_, template)| push_rendered(content, template.render()));
+    }
+}
+
+fn push_rendered(result: &mut String, rendered: Option<String>) {
+    if let Some(content) = rendered {
+        if !result.is_empty() {
+            result.push_str("\n");
+        }
+        result.push_str(content.as_ref());
+    }
+}
+
+/// Reads the `proto_crates` and `event_files` of every Flowy.toml under `root`, which are
+/// the same sources the pb-gen and dart-event targets use.
+fn parse_ts_crates(root: &str) -> Vec<TsCrate> {
+    let mut crates = WalkDir::new(root)
+        .into_iter()
+        .filter_entry(|e| !is_hidden(e))
+        .filter_map(|e| e.ok())
+        .filter(|e| is_crate_dir(e))
+        .flat_map(|e| parse_crate_config_from(&e))
+        .map(|config| {
+            let mut paths = config
+                .proto_paths()
+                .iter()
+                .map(|proto_path| {
+                    let mut files = vec![];
+                    walk_dir(
+                        proto_path,
+                        |e| {
+                            e.file_type().is_file()
+                                && e.path().extension().map_or(false, |ext| ext == "rs")
+                        },
+                        |path, name| {
+                            if name != "mod" {
+                                files.push(path);
+                            }
+                        },
+                    );
+                    files
+                })
+                .flatten()
+                .collect::<Vec<String>>();
+            paths.sort();
+
+            let files = paths
+                .iter()
+                .map(|path| {
+                    syn::parse_file(read_file(path).unwrap().as_ref())
+                        .expect("Unable to parse file")
+                })
+                .collect::<Vec<syn::File>>();
+
+            TsCrate {
+                namespace: config.folder_name.replace("-", "_"),
+                files,
+                events: parse_event_crate(&DartEventCrate::from_config(&config)),
+            }
+        })
+        .collect::<Vec<TsCrate>>();
+    crates.sort_by(|a, b| a.namespace.cmp(&b.namespace));
+    crates
+}
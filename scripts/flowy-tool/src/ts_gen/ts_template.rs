@@ -0,0 +1,175 @@
+use crate::util::get_tera;
+use flowy_ast::*;
+use phf::phf_map;
+use serde::Serialize;
+use tera::Context;
+
+pub const TS_IMPORTED: &'static str = r#"/// Auto gen code from rust ast, do not edit
+"#;
+
+// Rust primitive types that have a direct TypeScript counterpart. Any other type is
+// referenced by its name, qualified with its namespace when declared by another crate.
+static RUST_TYPE_MAP: phf::Map<&'static str, &'static str> = phf_map! {
+    "String" => "string",
+    "i64" => "number",
+    "i32" => "number",
+    "u64" => "number",
+    "u32" => "number",
+    "u8" => "number",
+    "f64" => "number",
+    "f32" => "number",
+    "bool" => "boolean",
+};
+
+fn ts_type(ty: &str, resolve: &dyn Fn(&str) -> String) -> String {
+    match RUST_TYPE_MAP.get(ty) {
+        Some(ts_ty) => ts_ty.to_string(),
+        None => resolve(ty),
+    }
+}
+
+pub struct InterfaceTemplate {
+    context: Context,
+    fields: Vec<String>,
+}
+
+impl InterfaceTemplate {
+    pub fn new(name: &str) -> Self {
+        let mut context = Context::new();
+        context.insert("struct_name", name);
+        InterfaceTemplate {
+            context,
+            fields: vec![],
+        }
+    }
+
+    pub fn set_field(&mut self, field: &ASTField, resolve: &dyn Fn(&str) -> String) {
+        let name = field.name().unwrap().to_string();
+        let ty: &str = &field.ty_as_str();
+
+        match field.bracket_category {
+            Some(BracketCategory::Opt) => match &field.bracket_inner_ty {
+                None => {}
+                Some(inner_ty) => match inner_ty.to_string().as_str() {
+                    // Option<Vec<u8>> is encoded as bytes, same as the proto template
+                    "Vec" => self.fields.push(format!("{}?: Uint8Array;", name)),
+                    _ => self
+                        .fields
+                        .push(format!("{}?: {};", name, ts_type(ty, resolve))),
+                },
+            },
+            Some(BracketCategory::Map((ref k, ref v))) => {
+                self.fields.push(format!(
+                    "{}: Record<{}, {}>;",
+                    name,
+                    ts_type(k, resolve),
+                    ts_type(v, resolve)
+                ));
+            }
+            Some(BracketCategory::Vec) => {
+                if ty == "u8" {
+                    self.fields.push(format!("{}: Uint8Array;", name));
+                } else {
+                    self.fields
+                        .push(format!("{}: {}[];", name, ts_type(ty, resolve)));
+                }
+            }
+            Some(BracketCategory::Other) => {
+                self.fields
+                    .push(format!("{}: {};", name, ts_type(ty, resolve)))
+            }
+            None => {}
+        }
+    }
+
+    pub fn render(&mut self) -> Option<String> {
+        self.context.insert("fields", &self.fields);
+        render("interface.tera", &self.context)
+    }
+}
+
+pub struct EnumTemplate {
+    context: Context,
+    items: Vec<String>,
+}
+
+impl EnumTemplate {
+    pub fn new(name: &str) -> Self {
+        let mut context = Context::new();
+        context.insert("enum_name", name);
+        EnumTemplate {
+            context,
+            items: vec![],
+        }
+    }
+
+    pub fn set_item(&mut self, item: &ASTEnumVariant) {
+        self.items.push(format!(
+            "{} = {},",
+            item.attrs.enum_item_name, item.attrs.value
+        ));
+    }
+
+    pub fn render(&mut self) -> Option<String> {
+        self.context.insert("items", &self.items);
+        render("enum.tera", &self.context)
+    }
+}
+
+#[derive(Serialize)]
+pub struct EventItem {
+    pub name: String,
+    pub input: String,
+    pub output: String,
+    pub error: String,
+}
+
+pub struct EventTemplate {
+    context: Context,
+    events: Vec<EventItem>,
+}
+
+impl EventTemplate {
+    pub fn new(event_ty: &str) -> Self {
+        let mut context = Context::new();
+        context.insert("event_ty", event_ty);
+        EventTemplate {
+            context,
+            events: vec![],
+        }
+    }
+
+    pub fn set_event(
+        &mut self,
+        event_ast: &event_ast::EventASTContext,
+        resolve: &dyn Fn(&str) -> String,
+    ) {
+        let ident_or_null = |path: &Option<syn::Path>| match path {
+            Some(path) => resolve(&path.get_ident().unwrap().to_string()),
+            None => "null".to_owned(),
+        };
+
+        self.events.push(EventItem {
+            name: event_ast.event.to_string(),
+            input: ident_or_null(&event_ast.event_input),
+            output: ident_or_null(&event_ast.event_output),
+            error: resolve(&event_ast.event_error),
+        });
+    }
+
+    pub fn render(&mut self) -> Option<String> {
+        self.context.insert("events", &self.events);
+        render("event.tera", &self.context)
+    }
+}
+
+fn render(template: &str, context: &Context) -> Option<String> {
+    let tera = get_tera("ts_gen");
+    match tera.render(template, context) {
+        Ok(r) => Some(r),
+        Err(e) => {
+            log::error!("{:?}", e);
+            None
+        }
+    }
+}